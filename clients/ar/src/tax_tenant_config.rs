@@ -129,6 +129,9 @@ mod tests {
             app_id: None,
             roles: vec![],
             perms: vec!["service.internal".to_string()],
+            scopes: Default::default(),
+            amr: Vec::new(),
+            auth_time: None,
             actor_type: ActorType::Service,
             issued_at: Utc::now(),
             expires_at: Utc::now() + chrono::Duration::hours(1),
@@ -1098,6 +1098,14 @@ export interface components {
              *     For Serial-tracked items, quantity is derived from serial_codes.len().
              */
             quantity: number;
+            /**
+             * Format: uuid
+             * @description Active reservation this issue consumes. Its held quantity counts as
+             *     available to this issue, and the hold is settled (status `fulfilled`)
+             *     in the same transaction, so the units are never released to other
+             *     demand in between.
+             */
+            reservation_id?: string | null;
             /**
              * @description Required for Serial-tracked items. Each code must be on_hand for this item.
              *     Quantity is derived from this list; the `quantity` field is ignored.
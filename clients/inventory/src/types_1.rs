@@ -404,6 +404,9 @@ pub struct IssueRequest {
     pub lot_code: Option<String>,
     /// Quantity to issue (must be > 0 for None/Lot-tracked items).
     pub quantity: i64,
+    /// Active reservation this issue consumes. Its held quantity counts as
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reservation_id: Option<uuid::Uuid>,
     /// Required for Serial-tracked items. Each code must be on_hand for this item.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub serial_codes: Option<serde_json::Value>,
@@ -0,0 +1,108 @@
+//! Client methods for the "Condition Monitoring" endpoints.
+//!
+//! Auto-generated by client-codegen — do not edit.
+
+use crate::*;
+use platform_sdk::{build_query_url, parse_response, ClientError, PlatformClient, VerifiedClaims};
+
+/// Typed HTTP client for Condition Monitoring endpoints.
+pub struct ConditionMonitoringClient {
+    client: PlatformClient,
+}
+
+impl ConditionMonitoringClient {
+    pub fn new(client: PlatformClient) -> Self {
+        Self { client }
+    }
+
+    /// GET `/api/maintenance/assignments/{assignment_id}/forecast`
+    pub async fn get_assignment_forecast(
+        &self,
+        claims: &VerifiedClaims,
+        assignment_id: uuid::Uuid,
+        window_hours: Option<i32>,
+    ) -> Result<UsageForecast, ClientError> {
+        let path = format!("/api/maintenance/assignments/{}/forecast", assignment_id);
+        #[derive(serde::Serialize)]
+        struct Query {
+            window_hours: Option<i32>,
+        }
+        let query = Query { window_hours };
+        let url = build_query_url(&path, &query)?;
+        let resp = self
+            .client
+            .get(&url, claims)
+            .await
+            .map_err(ClientError::Network)?;
+        parse_response(resp).await
+    }
+
+    /// GET `/api/maintenance/plans/{plan_id}/condition-rules`
+    pub async fn list_condition_rules(
+        &self,
+        claims: &VerifiedClaims,
+        plan_id: uuid::Uuid,
+    ) -> Result<PaginatedResponse<ConditionRule>, ClientError> {
+        let path = format!("/api/maintenance/plans/{}/condition-rules", plan_id);
+        let url = path;
+        let resp = self
+            .client
+            .get(&url, claims)
+            .await
+            .map_err(ClientError::Network)?;
+        parse_response(resp).await
+    }
+
+    /// POST `/api/maintenance/plans/{plan_id}/condition-rules`
+    pub async fn create_condition_rule(
+        &self,
+        claims: &VerifiedClaims,
+        plan_id: uuid::Uuid,
+        body: &CreateConditionRuleRequest,
+    ) -> Result<ConditionRule, ClientError> {
+        let path = format!("/api/maintenance/plans/{}/condition-rules", plan_id);
+        let url = path;
+        let resp = self
+            .client
+            .post(&url, body, claims)
+            .await
+            .map_err(ClientError::Network)?;
+        parse_response(resp).await
+    }
+
+    /// DELETE `/api/maintenance/plans/{plan_id}/condition-rules/{rule_id}`
+    pub async fn deactivate_condition_rule(
+        &self,
+        claims: &VerifiedClaims,
+        plan_id: uuid::Uuid,
+        rule_id: uuid::Uuid,
+    ) -> Result<ConditionRule, ClientError> {
+        let path = format!(
+            "/api/maintenance/plans/{}/condition-rules/{}",
+            plan_id, rule_id
+        );
+        let url = path;
+        let resp = self
+            .client
+            .delete(&url, claims)
+            .await
+            .map_err(ClientError::Network)?;
+        parse_response(resp).await
+    }
+
+    /// GET `/api/maintenance/work-orders/{wo_id}/trigger-readings`
+    pub async fn list_trigger_readings(
+        &self,
+        claims: &VerifiedClaims,
+        wo_id: uuid::Uuid,
+    ) -> Result<PaginatedResponse<WorkOrderTriggerReading>, ClientError> {
+        let path = format!("/api/maintenance/work-orders/{}/trigger-readings", wo_id);
+        let url = path;
+        let resp = self
+            .client
+            .get(&url, claims)
+            .await
+            .map_err(ClientError::Network)?;
+        parse_response(resp).await
+    }
+}
@@ -4,25 +4,35 @@
 
 pub mod assets;
 pub mod calibration;
+pub mod condition_monitoring;
 pub mod downtime;
 pub mod health;
 pub mod meters;
 pub mod plans;
+pub mod reliability;
+pub mod spare_parts;
 pub mod types_1;
 pub mod types_2;
+pub mod types_3;
 pub mod work_order_labor;
 pub mod work_order_parts;
 pub mod work_orders;
 
 pub use assets::AssetsClient;
 pub use calibration::CalibrationClient;
+pub use condition_monitoring::ConditionMonitoringClient;
 pub use downtime::DowntimeClient;
 pub use downtime::ListDowntimeQuery;
 pub use health::HealthClient;
 pub use meters::MetersClient;
 pub use plans::PlansClient;
+pub use reliability::GetReliabilityQuery;
+pub use reliability::GetReliabilityTrendQuery;
+pub use reliability::ReliabilityClient;
+pub use spare_parts::SparePartsClient;
 pub use types_1::*;
 pub use types_2::*;
+pub use types_3::*;
 pub use work_order_labor::WorkOrderLaborClient;
 pub use work_order_parts::WorkOrderPartsClient;
 pub use work_orders::WorkOrdersClient;
@@ -43,6 +53,13 @@ impl platform_sdk::PlatformService for CalibrationClient {
     }
 }
 
+impl platform_sdk::PlatformService for ConditionMonitoringClient {
+    const SERVICE_NAME: &'static str = "maintenance";
+    fn from_platform_client(client: platform_sdk::PlatformClient) -> Self {
+        Self::new(client)
+    }
+}
+
 impl platform_sdk::PlatformService for DowntimeClient {
     const SERVICE_NAME: &'static str = "maintenance";
     fn from_platform_client(client: platform_sdk::PlatformClient) -> Self {
@@ -71,6 +88,20 @@ impl platform_sdk::PlatformService for PlansClient {
     }
 }
 
+impl platform_sdk::PlatformService for ReliabilityClient {
+    const SERVICE_NAME: &'static str = "maintenance";
+    fn from_platform_client(client: platform_sdk::PlatformClient) -> Self {
+        Self::new(client)
+    }
+}
+
+impl platform_sdk::PlatformService for SparePartsClient {
+    const SERVICE_NAME: &'static str = "maintenance";
+    fn from_platform_client(client: platform_sdk::PlatformClient) -> Self {
+        Self::new(client)
+    }
+}
+
 impl platform_sdk::PlatformService for WorkOrderLaborClient {
     const SERVICE_NAME: &'static str = "maintenance";
     fn from_platform_client(client: platform_sdk::PlatformClient) -> Self {
@@ -0,0 +1,119 @@
+//! Client methods for the "Reliability" endpoints.
+//!
+//! Auto-generated by client-codegen — do not edit.
+
+use crate::*;
+use platform_sdk::{build_query_url, parse_response, ClientError, PlatformClient, VerifiedClaims};
+
+/// Query parameters for [`get_reliability`].
+#[derive(Debug, Clone, Default, serde::Serialize)]
+pub struct GetReliabilityQuery {
+    pub from: chrono::DateTime<chrono::Utc>,
+    pub to: chrono::DateTime<chrono::Utc>,
+    pub asset_id: Option<uuid::Uuid>,
+    pub asset_type: Option<String>,
+    pub failure_code: Option<String>,
+    pub group_by: Option<ReliabilityGroupBy>,
+}
+
+/// Query parameters for [`get_reliability_trend`].
+#[derive(Debug, Clone, Default, serde::Serialize)]
+pub struct GetReliabilityTrendQuery {
+    pub from: chrono::DateTime<chrono::Utc>,
+    pub to: chrono::DateTime<chrono::Utc>,
+    pub asset_id: Option<uuid::Uuid>,
+    pub asset_type: Option<String>,
+    pub failure_code: Option<String>,
+    pub bucket: Option<TrendBucket>,
+}
+
+/// Typed HTTP client for Reliability endpoints.
+pub struct ReliabilityClient {
+    client: PlatformClient,
+}
+
+impl ReliabilityClient {
+    pub fn new(client: PlatformClient) -> Self {
+        Self { client }
+    }
+
+    /// GET `/api/maintenance/reliability`
+    pub async fn get_reliability(
+        &self,
+        claims: &VerifiedClaims,
+        query: &GetReliabilityQuery,
+    ) -> Result<ReliabilityReport, ClientError> {
+        let path = format!("/api/maintenance/reliability");
+        let url = build_query_url(&path, query)?;
+        let resp = self
+            .client
+            .get(&url, claims)
+            .await
+            .map_err(ClientError::Network)?;
+        parse_response(resp).await
+    }
+
+    /// GET `/api/maintenance/reliability/pareto`
+    pub async fn get_failure_pareto(
+        &self,
+        claims: &VerifiedClaims,
+        from: chrono::DateTime<chrono::Utc>,
+        to: chrono::DateTime<chrono::Utc>,
+        asset_id: Option<uuid::Uuid>,
+        asset_type: Option<String>,
+    ) -> Result<PaginatedResponse<FailureParetoEntry>, ClientError> {
+        let path = format!("/api/maintenance/reliability/pareto");
+        #[derive(serde::Serialize)]
+        struct Query {
+            from: chrono::DateTime<chrono::Utc>,
+            to: chrono::DateTime<chrono::Utc>,
+            asset_id: Option<uuid::Uuid>,
+            asset_type: Option<String>,
+        }
+        let query = Query {
+            from,
+            to,
+            asset_id,
+            asset_type,
+        };
+        let url = build_query_url(&path, &query)?;
+        let resp = self
+            .client
+            .get(&url, claims)
+            .await
+            .map_err(ClientError::Network)?;
+        parse_response(resp).await
+    }
+
+    /// POST `/api/maintenance/reliability/snapshots`
+    pub async fn publish_snapshot(
+        &self,
+        claims: &VerifiedClaims,
+        body: &PublishSnapshotRequest,
+    ) -> Result<ReliabilitySnapshot, ClientError> {
+        let path = format!("/api/maintenance/reliability/snapshots");
+        let url = path;
+        let resp = self
+            .client
+            .post(&url, body, claims)
+            .await
+            .map_err(ClientError::Network)?;
+        parse_response(resp).await
+    }
+
+    /// GET `/api/maintenance/reliability/trend`
+    pub async fn get_reliability_trend(
+        &self,
+        claims: &VerifiedClaims,
+        query: &GetReliabilityTrendQuery,
+    ) -> Result<PaginatedResponse<ReliabilityTrendPoint>, ClientError> {
+        let path = format!("/api/maintenance/reliability/trend");
+        let url = build_query_url(&path, query)?;
+        let resp = self
+            .client
+            .get(&url, claims)
+            .await
+            .map_err(ClientError::Network)?;
+        parse_response(resp).await
+    }
+}
@@ -0,0 +1,100 @@
+//! Client methods for the "Spare Parts" endpoints.
+//!
+//! Auto-generated by client-codegen — do not edit.
+
+use crate::*;
+use platform_sdk::{parse_response, ClientError, PlatformClient, VerifiedClaims};
+
+/// Typed HTTP client for Spare Parts endpoints.
+pub struct SparePartsClient {
+    client: PlatformClient,
+}
+
+impl SparePartsClient {
+    pub fn new(client: PlatformClient) -> Self {
+        Self { client }
+    }
+
+    /// GET `/api/maintenance/plans/{plan_id}/parts`
+    pub async fn list_plan_parts(
+        &self,
+        claims: &VerifiedClaims,
+        plan_id: uuid::Uuid,
+    ) -> Result<PaginatedResponse<PlanPart>, ClientError> {
+        let path = format!("/api/maintenance/plans/{}/parts", plan_id);
+        let url = path;
+        let resp = self
+            .client
+            .get(&url, claims)
+            .await
+            .map_err(ClientError::Network)?;
+        parse_response(resp).await
+    }
+
+    /// POST `/api/maintenance/plans/{plan_id}/parts`
+    pub async fn add_plan_part(
+        &self,
+        claims: &VerifiedClaims,
+        plan_id: uuid::Uuid,
+        body: &AddPlanPartRequest,
+    ) -> Result<PlanPart, ClientError> {
+        let path = format!("/api/maintenance/plans/{}/parts", plan_id);
+        let url = path;
+        let resp = self
+            .client
+            .post(&url, body, claims)
+            .await
+            .map_err(ClientError::Network)?;
+        parse_response(resp).await
+    }
+
+    /// GET `/api/maintenance/spare-part-policies`
+    pub async fn list_policies(
+        &self,
+        claims: &VerifiedClaims,
+    ) -> Result<PaginatedResponse<SparePartPolicy>, ClientError> {
+        let path = format!("/api/maintenance/spare-part-policies");
+        let url = path;
+        let resp = self
+            .client
+            .get(&url, claims)
+            .await
+            .map_err(ClientError::Network)?;
+        parse_response(resp).await
+    }
+
+    /// POST `/api/maintenance/spare-part-policies`
+    pub async fn upsert_policy(
+        &self,
+        claims: &VerifiedClaims,
+        body: &UpsertSparePartPolicyRequest,
+    ) -> Result<SparePartPolicy, ClientError> {
+        let path = format!("/api/maintenance/spare-part-policies");
+        let url = path;
+        let resp = self
+            .client
+            .post(&url, body, claims)
+            .await
+            .map_err(ClientError::Network)?;
+        parse_response(resp).await
+    }
+
+    /// POST `/api/maintenance/spare-part-policies/{policy_id}/recompute`
+    pub async fn recompute_policy(
+        &self,
+        claims: &VerifiedClaims,
+        policy_id: uuid::Uuid,
+    ) -> Result<SparePartRecompute, ClientError> {
+        let path = format!(
+            "/api/maintenance/spare-part-policies/{}/recompute",
+            policy_id
+        );
+        let url = path;
+        let resp = self
+            .client
+            .post(&url, &serde_json::Value::Null, claims)
+            .await
+            .map_err(ClientError::Network)?;
+        parse_response(resp).await
+    }
+}
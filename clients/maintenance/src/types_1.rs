@@ -51,12 +51,34 @@ pub struct AddPartRequest {
     pub currency: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub inventory_issue_ref: Option<uuid::Uuid>,
+    /// Inventory item to reserve and issue. Omit for a standalone part.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub item_id: Option<uuid::Uuid>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub location_id: Option<uuid::Uuid>,
+    /// Lot to issue from (lot-tracked items).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lot_code: Option<String>,
     pub part_description: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub part_ref: Option<String>,
     pub quantity: i32,
+    /// Serials to issue (serial-tracked items); count must equal `quantity`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub serial_codes: Option<serde_json::Value>,
     pub tenant_id: String,
     pub unit_cost_minor: i64,
+    /// Required with `item_id`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub warehouse_id: Option<uuid::Uuid>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AddPlanPartRequest {
+    pub item_id: uuid::Uuid,
+    pub part_description: String,
+    pub quantity: i32,
+    pub tenant_id: String,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -196,6 +218,73 @@ pub struct CalibrationStatusResponse {
     pub status: CalibrationStatus,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConditionRule {
+    pub created_at: chrono::DateTime<chrono::Utc>,
+    pub id: uuid::Uuid,
+    pub is_active: bool,
+    /// usage_forecast: fire when the projected due date is this close.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lead_days: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lower_limit: Option<f64>,
+    pub meter_type_id: uuid::Uuid,
+    pub name: String,
+    pub plan_id: uuid::Uuid,
+    pub rule_type: ConditionRuleType,
+    pub tenant_id: String,
+    pub updated_at: chrono::DateTime<chrono::Utc>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub upper_limit: Option<f64>,
+    /// rate_of_change / usage_forecast: lookback before the latest reading.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub window_hours: Option<i32>,
+    /// moving_average: number of most recent readings averaged.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub window_size: Option<i32>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub enum ConditionRuleType {
+    #[serde(rename = "threshold")]
+    Threshold,
+    #[serde(rename = "rate_of_change")]
+    RateOfChange,
+    #[serde(rename = "moving_average")]
+    MovingAverage,
+    #[serde(rename = "usage_forecast")]
+    UsageForecast,
+}
+
+impl ConditionRuleType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ConditionRuleType::Threshold => "threshold",
+            ConditionRuleType::RateOfChange => "rate_of_change",
+            ConditionRuleType::MovingAverage => "moving_average",
+            ConditionRuleType::UsageForecast => "usage_forecast",
+        }
+    }
+}
+
+/// Why a condition rule fired, carried on `maintenance.plan.due`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConditionTrigger {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lower_limit: Option<f64>,
+    pub meter_type_id: uuid::Uuid,
+    /// Latest reading, rate per hour, average or days until due (forecast).
+    pub observed_value: f64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub projected_due_date: Option<chrono::NaiveDate>,
+    pub readings: Vec<ReadingPoint>,
+    pub rule_id: uuid::Uuid,
+    pub rule_name: String,
+    pub rule_type: ConditionRuleType,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub upper_limit: Option<f64>,
+}
+
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct CreateAssetRequest {
     pub asset_tag: String,
@@ -222,6 +311,24 @@ pub struct CreateAssetRequest {
     pub tenant_id: String,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateConditionRuleRequest {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lead_days: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lower_limit: Option<f64>,
+    pub meter_type_id: uuid::Uuid,
+    pub name: String,
+    pub rule_type: String,
+    pub tenant_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub upper_limit: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub window_hours: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub window_size: Option<i32>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CreateDowntimeRequest {
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -246,6 +353,9 @@ pub struct CreateDowntimeRequest {
 
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct CreateMeterTypeRequest {
+    /// `counter` (default) or `gauge`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub kind: Option<String>,
     pub name: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub rollover_value: Option<i64>,
@@ -325,122 +435,12 @@ pub struct DowntimeEvent {
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct MaintenancePlan {
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub asset_type_filter: Option<String>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub calendar_interval_days: Option<i32>,
-    pub created_at: chrono::DateTime<chrono::Utc>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub description: Option<String>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub estimated_cost_minor: Option<i64>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub estimated_duration_minutes: Option<i32>,
-    pub id: uuid::Uuid,
-    pub is_active: bool,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub meter_interval: Option<i64>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub meter_type_id: Option<uuid::Uuid>,
-    pub name: String,
-    pub priority: Priority,
-    pub schedule_type: ScheduleType,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub task_checklist: Option<serde_json::Value>,
-    pub tenant_id: String,
-    pub updated_at: chrono::DateTime<chrono::Utc>,
-}
-
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct MeterReading {
-    pub asset_id: uuid::Uuid,
-    pub created_at: chrono::DateTime<chrono::Utc>,
-    pub id: uuid::Uuid,
-    pub meter_type_id: uuid::Uuid,
-    pub reading_value: i64,
-    pub recorded_at: chrono::DateTime<chrono::Utc>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub recorded_by: Option<String>,
-    pub tenant_id: String,
-}
-
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct MeterType {
-    pub created_at: chrono::DateTime<chrono::Utc>,
-    pub id: uuid::Uuid,
-    pub name: String,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub rollover_value: Option<i64>,
-    pub tenant_id: String,
-    pub unit_label: String,
-    pub updated_at: chrono::DateTime<chrono::Utc>,
-}
-
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct PlanAssignment {
-    pub asset_id: uuid::Uuid,
-    pub created_at: chrono::DateTime<chrono::Utc>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub due_notified_at: Option<chrono::DateTime<chrono::Utc>>,
-    pub id: uuid::Uuid,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_completed_at: Option<chrono::DateTime<chrono::Utc>>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_meter_reading: Option<i64>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub next_due_date: Option<chrono::NaiveDate>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub next_due_meter: Option<i64>,
-    pub plan_id: uuid::Uuid,
-    pub state: String,
-    pub tenant_id: String,
-    pub updated_at: chrono::DateTime<chrono::Utc>,
-}
-
-/// Priority level for work orders and maintenance plans.
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
-pub enum Priority {
-    #[serde(rename = "low")]
-    Low,
-    #[serde(rename = "medium")]
-    Medium,
-    #[serde(rename = "high")]
-    High,
-    #[serde(rename = "critical")]
-    Critical,
-}
-
-impl Priority {
-    pub fn as_str(&self) -> &'static str {
-        match self {
-            Priority::Low => "low",
-            Priority::Medium => "medium",
-            Priority::High => "high",
-            Priority::Critical => "critical",
-        }
-    }
-}
-
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct RecordCalibrationRequest {
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub doc_revision_id: Option<uuid::Uuid>,
-    pub due_at: chrono::DateTime<chrono::Utc>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub idempotency_key: Option<String>,
-    pub performed_at: chrono::DateTime<chrono::Utc>,
-    pub result: String,
-    pub tenant_id: String,
-}
-
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct RecordReadingRequest {
-    pub meter_type_id: uuid::Uuid,
-    pub reading_value: i64,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub recorded_at: Option<chrono::DateTime<chrono::Utc>>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub recorded_by: Option<String>,
-    pub tenant_id: String,
+pub struct FailureParetoEntry {
+    /// Running share including this and all higher-ranked codes (percent).
+    pub cumulative_pct: f64,
+    pub downtime_hours: f64,
+    pub failure_code: String,
+    pub failure_count: i64,
+    /// Share of all failures in the window (percent).
+    pub share_pct: f64,
 }
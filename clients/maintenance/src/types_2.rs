@@ -8,6 +8,249 @@ use crate::*;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MaintenancePlan {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub asset_type_filter: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub calendar_interval_days: Option<i32>,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub estimated_cost_minor: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub estimated_duration_minutes: Option<i32>,
+    pub id: uuid::Uuid,
+    pub is_active: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub meter_interval: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub meter_type_id: Option<uuid::Uuid>,
+    pub name: String,
+    pub priority: Priority,
+    pub schedule_type: ScheduleType,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub task_checklist: Option<serde_json::Value>,
+    pub tenant_id: String,
+    pub updated_at: chrono::DateTime<chrono::Utc>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MeterReading {
+    pub asset_id: uuid::Uuid,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+    pub id: uuid::Uuid,
+    pub meter_type_id: uuid::Uuid,
+    pub reading_value: i64,
+    pub recorded_at: chrono::DateTime<chrono::Utc>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recorded_by: Option<String>,
+    pub tenant_id: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MeterType {
+    pub created_at: chrono::DateTime<chrono::Utc>,
+    pub id: uuid::Uuid,
+    /// `counter` or `gauge`.
+    pub kind: String,
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rollover_value: Option<i64>,
+    pub tenant_id: String,
+    pub unit_label: String,
+    pub updated_at: chrono::DateTime<chrono::Utc>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PlanAssignment {
+    pub asset_id: uuid::Uuid,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub due_notified_at: Option<chrono::DateTime<chrono::Utc>>,
+    pub id: uuid::Uuid,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_completed_at: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_meter_reading: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_due_date: Option<chrono::NaiveDate>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_due_meter: Option<i64>,
+    pub plan_id: uuid::Uuid,
+    pub state: String,
+    pub tenant_id: String,
+    pub updated_at: chrono::DateTime<chrono::Utc>,
+}
+
+/// Spare part consumed by each occurrence of a maintenance plan.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PlanPart {
+    pub created_at: chrono::DateTime<chrono::Utc>,
+    pub id: uuid::Uuid,
+    pub item_id: uuid::Uuid,
+    pub part_description: String,
+    pub plan_id: uuid::Uuid,
+    pub quantity: i32,
+    pub tenant_id: String,
+}
+
+/// Priority level for work orders and maintenance plans.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub enum Priority {
+    #[serde(rename = "low")]
+    Low,
+    #[serde(rename = "medium")]
+    Medium,
+    #[serde(rename = "high")]
+    High,
+    #[serde(rename = "critical")]
+    Critical,
+}
+
+impl Priority {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Priority::Low => "low",
+            Priority::Medium => "medium",
+            Priority::High => "high",
+            Priority::Critical => "critical",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PublishSnapshotRequest {
+    pub from: chrono::DateTime<chrono::Utc>,
+    pub tenant_id: String,
+    pub to: chrono::DateTime<chrono::Utc>,
+}
+
+/// A meter reading as seen by rule evaluation.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReadingPoint {
+    pub meter_reading_id: uuid::Uuid,
+    pub reading_value: i64,
+    pub recorded_at: chrono::DateTime<chrono::Utc>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RecordCalibrationRequest {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub doc_revision_id: Option<uuid::Uuid>,
+    pub due_at: chrono::DateTime<chrono::Utc>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub idempotency_key: Option<String>,
+    pub performed_at: chrono::DateTime<chrono::Utc>,
+    pub result: String,
+    pub tenant_id: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RecordReadingRequest {
+    pub meter_type_id: uuid::Uuid,
+    pub reading_value: i64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recorded_at: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recorded_by: Option<String>,
+    pub tenant_id: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReliabilityGroup {
+    /// Asset id, asset type or failure code, depending on the grouping.
+    pub key: String,
+    /// Asset tag when grouped by asset.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub label: Option<String>,
+    pub metrics: ReliabilityMetrics,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub enum ReliabilityGroupBy {
+    #[serde(rename = "asset")]
+    Asset,
+    #[serde(rename = "asset_type")]
+    AssetType,
+    #[serde(rename = "failure_code")]
+    FailureCode,
+}
+
+impl ReliabilityGroupBy {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ReliabilityGroupBy::Asset => "asset",
+            ReliabilityGroupBy::AssetType => "asset_type",
+            ReliabilityGroupBy::FailureCode => "failure_code",
+        }
+    }
+}
+
+/// Reliability figures for one scope over a window. Hours are rounded to
+/// two decimals, availability to four.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReliabilityMetrics {
+    /// Uptime / operating time (0–1).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub availability: Option<f64>,
+    pub downtime_hours: f64,
+    pub failure_count: i64,
+    /// Mean time between failures; `null` when there were no failures.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mtbf_hours: Option<f64>,
+    /// Mean time to failure; `null` when there were no failures.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mttf_hours: Option<f64>,
+    /// Mean time to repair; `null` when no failure was restored.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mttr_hours: Option<f64>,
+    pub operating_hours: f64,
+    /// Failures in the window that have been restored.
+    pub repair_count: i64,
+    pub uptime_hours: f64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReliabilityReport {
+    pub from: chrono::DateTime<chrono::Utc>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub group_by: Option<ReliabilityGroupBy>,
+    pub groups: Vec<ReliabilityGroup>,
+    pub overall: ReliabilityMetrics,
+    pub to: chrono::DateTime<chrono::Utc>,
+}
+
+/// Published reliability snapshot, as carried by the
+/// `maintenance.reliability.snapshot` event.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReliabilitySnapshot {
+    pub asset_types: Vec<ReliabilityGroup>,
+    pub assets: Vec<ReliabilityGroup>,
+    pub event_id: uuid::Uuid,
+    pub from: chrono::DateTime<chrono::Utc>,
+    pub overall: ReliabilityMetrics,
+    pub pareto: Vec<FailureParetoEntry>,
+    pub to: chrono::DateTime<chrono::Utc>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReliabilityTrendPoint {
+    pub bucket_end: chrono::DateTime<chrono::Utc>,
+    pub bucket_start: chrono::DateTime<chrono::Utc>,
+    pub metrics: ReliabilityMetrics,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReturnPartRequest {
+    pub quantity: i32,
+    /// Serials going back to stock (serial-tracked parts); count must equal `quantity`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub serial_codes: Option<serde_json::Value>,
+    pub tenant_id: String,
+}
+
 /// Schedule type for maintenance plans.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 pub enum ScheduleType {
@@ -17,6 +260,8 @@ pub enum ScheduleType {
     Meter,
     #[serde(rename = "both")]
     Both,
+    #[serde(rename = "condition")]
+    Condition,
 }
 
 impl ScheduleType {
@@ -25,6 +270,65 @@ impl ScheduleType {
             ScheduleType::Calendar => "calendar",
             ScheduleType::Meter => "meter",
             ScheduleType::Both => "both",
+            ScheduleType::Condition => "condition",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SparePartPolicy {
+    pub created_at: chrono::DateTime<chrono::Utc>,
+    /// Planning horizon; demand inside it drives the max.
+    pub horizon_days: i32,
+    pub id: uuid::Uuid,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub inventory_reorder_policy_id: Option<uuid::Uuid>,
+    pub item_id: uuid::Uuid,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_computed_at: Option<chrono::DateTime<chrono::Utc>>,
+    /// Replenishment lead time; demand inside it drives the min.
+    pub lead_time_days: i32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_qty: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min_qty: Option<i32>,
+    pub safety_stock: i32,
+    pub tenant_id: String,
+    pub updated_at: chrono::DateTime<chrono::Utc>,
+}
+
+/// Result of a policy recompute: the updated policy and the demand behind it.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SparePartRecompute {
+    /// Unissued quantity on open work orders.
+    pub open_work_order_demand: i64,
+    /// Planned quantity due within the horizon (excludes open work orders).
+    pub planned_horizon_demand: i64,
+    /// Planned quantity due within the lead time (excludes open work orders).
+    pub planned_lead_time_demand: i64,
+    pub policy: SparePartPolicy,
+}
+
+/// Where a part line stands against Inventory.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub enum StockStatus {
+    #[serde(rename = "standalone")]
+    Standalone,
+    #[serde(rename = "reserved")]
+    Reserved,
+    #[serde(rename = "issued")]
+    Issued,
+    #[serde(rename = "released")]
+    Released,
+}
+
+impl StockStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            StockStatus::Standalone => "standalone",
+            StockStatus::Reserved => "reserved",
+            StockStatus::Issued => "issued",
+            StockStatus::Released => "released",
         }
     }
 }
@@ -37,12 +341,35 @@ pub struct TransitionRequest {
     pub completed_at: Option<chrono::DateTime<chrono::Utc>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub downtime_minutes: Option<i32>,
+    /// Failure mode; accepted only when completing a corrective work order.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub failure_code: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub notes: Option<String>,
     pub status: String,
     pub tenant_id: String,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub enum TrendBucket {
+    #[serde(rename = "day")]
+    Day,
+    #[serde(rename = "week")]
+    Week,
+    #[serde(rename = "month")]
+    Month,
+}
+
+impl TrendBucket {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TrendBucket::Day => "day",
+            TrendBucket::Week => "week",
+            TrendBucket::Month => "month",
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct UpdateAssetRequest {
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -90,124 +417,3 @@ pub struct UpdatePlanRequest {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub task_checklist: Option<serde_json::Value>,
 }
-
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct WoLabor {
-    pub created_at: chrono::DateTime<chrono::Utc>,
-    pub currency: String,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub description: Option<String>,
-    pub hours_decimal: String,
-    pub id: uuid::Uuid,
-    pub rate_minor: i64,
-    pub technician_ref: String,
-    pub tenant_id: String,
-    pub work_order_id: uuid::Uuid,
-}
-
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct WoPart {
-    pub created_at: chrono::DateTime<chrono::Utc>,
-    pub currency: String,
-    pub id: uuid::Uuid,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub inventory_issue_ref: Option<uuid::Uuid>,
-    pub part_description: String,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub part_ref: Option<String>,
-    pub quantity: i32,
-    pub tenant_id: String,
-    pub unit_cost_minor: i64,
-    pub work_order_id: uuid::Uuid,
-}
-
-/// Work order status — tracks lifecycle position within the state machine.
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
-pub enum WoStatus {
-    #[serde(rename = "draft")]
-    Draft,
-    #[serde(rename = "awaiting_approval")]
-    AwaitingApproval,
-    #[serde(rename = "scheduled")]
-    Scheduled,
-    #[serde(rename = "in_progress")]
-    InProgress,
-    #[serde(rename = "on_hold")]
-    OnHold,
-    #[serde(rename = "completed")]
-    Completed,
-    #[serde(rename = "closed")]
-    Closed,
-    #[serde(rename = "cancelled")]
-    Cancelled,
-}
-
-impl WoStatus {
-    pub fn as_str(&self) -> &'static str {
-        match self {
-            WoStatus::Draft => "draft",
-            WoStatus::AwaitingApproval => "awaiting_approval",
-            WoStatus::Scheduled => "scheduled",
-            WoStatus::InProgress => "in_progress",
-            WoStatus::OnHold => "on_hold",
-            WoStatus::Completed => "completed",
-            WoStatus::Closed => "closed",
-            WoStatus::Cancelled => "cancelled",
-        }
-    }
-}
-
-/// Work order type — classifies the nature of the maintenance work.
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
-pub enum WoType {
-    #[serde(rename = "preventive")]
-    Preventive,
-    #[serde(rename = "corrective")]
-    Corrective,
-    #[serde(rename = "inspection")]
-    Inspection,
-}
-
-impl WoType {
-    pub fn as_str(&self) -> &'static str {
-        match self {
-            WoType::Preventive => "preventive",
-            WoType::Corrective => "corrective",
-            WoType::Inspection => "inspection",
-        }
-    }
-}
-
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct WorkOrder {
-    pub asset_id: uuid::Uuid,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub assigned_to: Option<String>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub checklist: Option<serde_json::Value>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub closed_at: Option<chrono::DateTime<chrono::Utc>>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub completed_at: Option<chrono::DateTime<chrono::Utc>>,
-    pub created_at: chrono::DateTime<chrono::Utc>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub description: Option<String>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub downtime_minutes: Option<i32>,
-    pub id: uuid::Uuid,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub notes: Option<String>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub plan_assignment_id: Option<uuid::Uuid>,
-    pub priority: Priority,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub scheduled_date: Option<chrono::NaiveDate>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub started_at: Option<chrono::DateTime<chrono::Utc>>,
-    pub status: WoStatus,
-    pub tenant_id: String,
-    pub title: String,
-    pub updated_at: chrono::DateTime<chrono::Utc>,
-    pub wo_number: String,
-    pub wo_type: WoType,
-}
@@ -0,0 +1,211 @@
+//! Type definitions for the Maintenance Service client.
+//!
+//! Auto-generated by client-codegen — do not edit.
+
+#![allow(unused_imports)]
+
+use crate::*;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UpsertSparePartPolicyRequest {
+    pub horizon_days: i32,
+    pub item_id: uuid::Uuid,
+    pub lead_time_days: i32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub safety_stock: Option<i32>,
+    pub tenant_id: String,
+}
+
+/// Projected due date for a meter-scheduled assignment from its usage rate.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UsageForecast {
+    pub assignment_id: uuid::Uuid,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub latest_reading: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub latest_recorded_at: Option<chrono::DateTime<chrono::Utc>>,
+    pub meter_type_id: uuid::Uuid,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_due_date: Option<chrono::NaiveDate>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_due_meter: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub projected_due_date: Option<chrono::NaiveDate>,
+    /// None when fewer than two readings or no usage in the window.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub usage_rate_per_day: Option<f64>,
+    pub window_hours: i32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WoLabor {
+    pub created_at: chrono::DateTime<chrono::Utc>,
+    pub currency: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    pub hours_decimal: String,
+    pub id: uuid::Uuid,
+    pub rate_minor: i64,
+    pub technician_ref: String,
+    pub tenant_id: String,
+    pub work_order_id: uuid::Uuid,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WoPart {
+    pub created_at: chrono::DateTime<chrono::Utc>,
+    pub currency: String,
+    pub id: uuid::Uuid,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub inventory_issue_ref: Option<uuid::Uuid>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub issued_at: Option<chrono::DateTime<chrono::Utc>>,
+    /// Actual FIFO cost of the issued quantity.
+    pub issued_cost_minor: i64,
+    pub issued_quantity: i32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub item_id: Option<uuid::Uuid>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub location_id: Option<uuid::Uuid>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lot_code: Option<String>,
+    pub part_description: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub part_ref: Option<String>,
+    pub quantity: i32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reservation_id: Option<uuid::Uuid>,
+    pub returned_cost_minor: i64,
+    pub returned_quantity: i32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub serial_codes: Option<serde_json::Value>,
+    pub stock_status: StockStatus,
+    pub tenant_id: String,
+    pub unit_cost_minor: i64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub warehouse_id: Option<uuid::Uuid>,
+    pub work_order_id: uuid::Uuid,
+}
+
+/// One return of unused stock from a work order part.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WoPartReturn {
+    pub cost_minor: i64,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+    pub id: uuid::Uuid,
+    pub part_id: uuid::Uuid,
+    pub quantity: i32,
+    pub receipt_ref: uuid::Uuid,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub serial_codes: Option<serde_json::Value>,
+    pub tenant_id: String,
+    pub work_order_id: uuid::Uuid,
+}
+
+/// Work order status — tracks lifecycle position within the state machine.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub enum WoStatus {
+    #[serde(rename = "draft")]
+    Draft,
+    #[serde(rename = "awaiting_approval")]
+    AwaitingApproval,
+    #[serde(rename = "scheduled")]
+    Scheduled,
+    #[serde(rename = "in_progress")]
+    InProgress,
+    #[serde(rename = "on_hold")]
+    OnHold,
+    #[serde(rename = "completed")]
+    Completed,
+    #[serde(rename = "closed")]
+    Closed,
+    #[serde(rename = "cancelled")]
+    Cancelled,
+}
+
+impl WoStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            WoStatus::Draft => "draft",
+            WoStatus::AwaitingApproval => "awaiting_approval",
+            WoStatus::Scheduled => "scheduled",
+            WoStatus::InProgress => "in_progress",
+            WoStatus::OnHold => "on_hold",
+            WoStatus::Completed => "completed",
+            WoStatus::Closed => "closed",
+            WoStatus::Cancelled => "cancelled",
+        }
+    }
+}
+
+/// Work order type — classifies the nature of the maintenance work.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub enum WoType {
+    #[serde(rename = "preventive")]
+    Preventive,
+    #[serde(rename = "corrective")]
+    Corrective,
+    #[serde(rename = "inspection")]
+    Inspection,
+}
+
+impl WoType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            WoType::Preventive => "preventive",
+            WoType::Corrective => "corrective",
+            WoType::Inspection => "inspection",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WorkOrder {
+    pub asset_id: uuid::Uuid,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub assigned_to: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub checklist: Option<serde_json::Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub closed_at: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub completed_at: Option<chrono::DateTime<chrono::Utc>>,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub downtime_minutes: Option<i32>,
+    /// Failure mode recorded on completion of a corrective work order.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub failure_code: Option<String>,
+    pub id: uuid::Uuid,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub notes: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub plan_assignment_id: Option<uuid::Uuid>,
+    pub priority: Priority,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scheduled_date: Option<chrono::NaiveDate>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub started_at: Option<chrono::DateTime<chrono::Utc>>,
+    pub status: WoStatus,
+    pub tenant_id: String,
+    pub title: String,
+    pub updated_at: chrono::DateTime<chrono::Utc>,
+    pub wo_number: String,
+    pub wo_type: WoType,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WorkOrderTriggerReading {
+    pub created_at: chrono::DateTime<chrono::Utc>,
+    pub id: uuid::Uuid,
+    pub meter_reading_id: uuid::Uuid,
+    pub reading_value: i64,
+    pub recorded_at: chrono::DateTime<chrono::Utc>,
+    pub rule_id: uuid::Uuid,
+    pub tenant_id: String,
+    pub work_order_id: uuid::Uuid,
+}
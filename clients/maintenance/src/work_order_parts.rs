@@ -15,6 +15,22 @@ impl WorkOrderPartsClient {
         Self { client }
     }
 
+    /// GET `/api/maintenance/work-orders/{wo_id}/part-returns`
+    pub async fn list_part_returns(
+        &self,
+        claims: &VerifiedClaims,
+        wo_id: uuid::Uuid,
+    ) -> Result<PaginatedResponse<WoPartReturn>, ClientError> {
+        let path = format!("/api/maintenance/work-orders/{}/part-returns", wo_id);
+        let url = path;
+        let resp = self
+            .client
+            .get(&url, claims)
+            .await
+            .map_err(ClientError::Network)?;
+        parse_response(resp).await
+    }
+
     /// GET `/api/maintenance/work-orders/{wo_id}/parts`
     pub async fn list_parts(
         &self,
@@ -64,4 +80,25 @@ impl WorkOrderPartsClient {
             .map_err(ClientError::Network)?;
         parse_empty(resp).await
     }
+
+    /// POST `/api/maintenance/work-orders/{wo_id}/parts/{part_id}/return`
+    pub async fn return_part(
+        &self,
+        claims: &VerifiedClaims,
+        wo_id: uuid::Uuid,
+        part_id: uuid::Uuid,
+        body: &ReturnPartRequest,
+    ) -> Result<WoPartReturn, ClientError> {
+        let path = format!(
+            "/api/maintenance/work-orders/{}/parts/{}/return",
+            wo_id, part_id
+        );
+        let url = path;
+        let resp = self
+            .client
+            .post(&url, body, claims)
+            .await
+            .map_err(ClientError::Network)?;
+        parse_response(resp).await
+    }
 }
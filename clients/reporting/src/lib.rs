@@ -6,6 +6,7 @@ pub mod admin;
 pub mod aging;
 pub mod forecast;
 pub mod kpis;
+pub mod maintenance;
 pub mod statements;
 pub mod types;
 
@@ -13,6 +14,7 @@ pub use admin::AdminClient;
 pub use aging::AgingClient;
 pub use forecast::ForecastClient;
 pub use kpis::KpisClient;
+pub use maintenance::MaintenanceClient;
 pub use statements::StatementsClient;
 pub use types::*;
 
@@ -46,6 +48,13 @@ impl platform_sdk::PlatformService for KpisClient {
     }
 }
 
+impl platform_sdk::PlatformService for MaintenanceClient {
+    const SERVICE_NAME: &'static str = "reporting";
+    fn from_platform_client(client: platform_sdk::PlatformClient) -> Self {
+        Self::new(client)
+    }
+}
+
 impl platform_sdk::PlatformService for StatementsClient {
     const SERVICE_NAME: &'static str = "reporting";
     fn from_platform_client(client: platform_sdk::PlatformClient) -> Self {
@@ -0,0 +1,45 @@
+//! Client methods for the "Maintenance" endpoints.
+//!
+//! Auto-generated by client-codegen — do not edit.
+
+use crate::*;
+use platform_sdk::{build_query_url, parse_response, ClientError, PlatformClient, VerifiedClaims};
+
+/// Typed HTTP client for Maintenance endpoints.
+pub struct MaintenanceClient {
+    client: PlatformClient,
+}
+
+impl MaintenanceClient {
+    pub fn new(client: PlatformClient) -> Self {
+        Self { client }
+    }
+
+    /// GET `/api/reporting/asset-reliability`
+    pub async fn get_asset_reliability(
+        &self,
+        claims: &VerifiedClaims,
+        period_start: Option<chrono::DateTime<chrono::Utc>>,
+        period_end: Option<chrono::DateTime<chrono::Utc>>,
+    ) -> Result<AssetReliabilityReport, ClientError> {
+        let path = format!("/api/reporting/asset-reliability");
+        #[derive(serde::Serialize)]
+        struct Query {
+            #[serde(skip_serializing_if = "Option::is_none")]
+            period_start: Option<chrono::DateTime<chrono::Utc>>,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            period_end: Option<chrono::DateTime<chrono::Utc>>,
+        }
+        let query = Query {
+            period_start,
+            period_end,
+        };
+        let url = build_query_url(&path, &query)?;
+        let resp = self
+            .client
+            .get(&url, claims)
+            .await
+            .map_err(ClientError::Network)?;
+        parse_response(resp).await
+    }
+}
@@ -77,6 +77,16 @@ pub struct ArAgingSummary {
     pub total_minor: i64,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AssetReliabilityReport {
+    pub pareto: Vec<FailureParetoRow>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub period_end: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub period_start: Option<chrono::DateTime<chrono::Utc>>,
+    pub rows: Vec<ReliabilityRow>,
+}
+
 /// An open invoice flagged as at-risk (P(30) < 0.40).
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AtRiskItem {
@@ -166,6 +176,16 @@ pub struct CurrencySummary {
     pub total_minor: i64,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FailureParetoRow {
+    pub cumulative_pct: f64,
+    pub downtime_hours: f64,
+    pub failure_code: String,
+    pub failure_count: i64,
+    pub rank: i32,
+    pub share_pct: f64,
+}
+
 /// Expected collection for a single forecast horizon.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ForecastHorizon {
@@ -229,6 +249,30 @@ pub struct RebuildRequest {
     pub to: chrono::NaiveDate,
 }
 
+/// Reliability metrics for one scope of a snapshot window.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReliabilityRow {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub availability: Option<f64>,
+    pub downtime_hours: f64,
+    pub failure_count: i64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mtbf_hours: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mttf_hours: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mttr_hours: Option<f64>,
+    pub operating_hours: f64,
+    pub repair_count: i64,
+    /// `all` for the tenant row, otherwise the asset id or asset type.
+    pub scope_key: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scope_label: Option<String>,
+    /// `tenant`, `asset` or `asset_type`.
+    pub scope_type: String,
+    pub uptime_hours: f64,
+}
+
 /// Summary of a completed snapshot run.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SnapshotRunResult {
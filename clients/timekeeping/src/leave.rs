@@ -0,0 +1,302 @@
+//! Client methods for the "Leave" endpoints.
+//!
+//! Auto-generated by client-codegen — do not edit.
+
+use crate::*;
+use platform_sdk::{build_query_url, parse_response, ClientError, PlatformClient, VerifiedClaims};
+
+/// Typed HTTP client for Leave endpoints.
+pub struct LeaveClient {
+    client: PlatformClient,
+}
+
+impl LeaveClient {
+    pub fn new(client: PlatformClient) -> Self {
+        Self { client }
+    }
+
+    /// POST `/api/timekeeping/leave/accruals/run`
+    pub async fn run_accruals(
+        &self,
+        claims: &VerifiedClaims,
+        body: &RunAccrualsRequest,
+    ) -> Result<AccrualRunResult, ClientError> {
+        let path = format!("/api/timekeeping/leave/accruals/run");
+        let url = path;
+        let resp = self
+            .client
+            .post(&url, body, claims)
+            .await
+            .map_err(ClientError::Network)?;
+        parse_response(resp).await
+    }
+
+    /// GET `/api/timekeeping/leave/balances`
+    pub async fn leave_balances(
+        &self,
+        claims: &VerifiedClaims,
+        employee_id: uuid::Uuid,
+        as_of: Option<chrono::NaiveDate>,
+    ) -> Result<Vec<LeaveBalance>, ClientError> {
+        let path = format!("/api/timekeeping/leave/balances");
+        #[derive(serde::Serialize)]
+        struct Query {
+            employee_id: uuid::Uuid,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            as_of: Option<chrono::NaiveDate>,
+        }
+        let query = Query { employee_id, as_of };
+        let url = build_query_url(&path, &query)?;
+        let resp = self
+            .client
+            .get(&url, claims)
+            .await
+            .map_err(ClientError::Network)?;
+        parse_response(resp).await
+    }
+
+    /// GET `/api/timekeeping/leave/holiday-calendars`
+    pub async fn list_holiday_calendars(
+        &self,
+        claims: &VerifiedClaims,
+    ) -> Result<Vec<HolidayCalendar>, ClientError> {
+        let path = format!("/api/timekeeping/leave/holiday-calendars");
+        let url = path;
+        let resp = self
+            .client
+            .get(&url, claims)
+            .await
+            .map_err(ClientError::Network)?;
+        parse_response(resp).await
+    }
+
+    /// POST `/api/timekeeping/leave/holiday-calendars`
+    pub async fn create_holiday_calendar(
+        &self,
+        claims: &VerifiedClaims,
+        body: &CreateHolidayCalendarRequest,
+    ) -> Result<HolidayCalendar, ClientError> {
+        let path = format!("/api/timekeeping/leave/holiday-calendars");
+        let url = path;
+        let resp = self
+            .client
+            .post(&url, body, claims)
+            .await
+            .map_err(ClientError::Network)?;
+        parse_response(resp).await
+    }
+
+    /// POST `/api/timekeeping/leave/holiday-calendars/{id}/holidays`
+    pub async fn add_holiday(
+        &self,
+        claims: &VerifiedClaims,
+        id: uuid::Uuid,
+        body: &CreateHolidayRequest,
+    ) -> Result<Holiday, ClientError> {
+        let path = format!("/api/timekeeping/leave/holiday-calendars/{}/holidays", id);
+        let url = path;
+        let resp = self
+            .client
+            .post(&url, body, claims)
+            .await
+            .map_err(ClientError::Network)?;
+        parse_response(resp).await
+    }
+
+    /// GET `/api/timekeeping/leave/ledger`
+    pub async fn leave_ledger(
+        &self,
+        claims: &VerifiedClaims,
+        employee_id: uuid::Uuid,
+        leave_type_id: Option<uuid::Uuid>,
+    ) -> Result<Vec<LedgerEntry>, ClientError> {
+        let path = format!("/api/timekeeping/leave/ledger");
+        #[derive(serde::Serialize)]
+        struct Query {
+            employee_id: uuid::Uuid,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            leave_type_id: Option<uuid::Uuid>,
+        }
+        let query = Query {
+            employee_id,
+            leave_type_id,
+        };
+        let url = build_query_url(&path, &query)?;
+        let resp = self
+            .client
+            .get(&url, claims)
+            .await
+            .map_err(ClientError::Network)?;
+        parse_response(resp).await
+    }
+
+    /// GET `/api/timekeeping/leave/policies`
+    pub async fn list_accrual_policies(
+        &self,
+        claims: &VerifiedClaims,
+    ) -> Result<Vec<AccrualPolicy>, ClientError> {
+        let path = format!("/api/timekeeping/leave/policies");
+        let url = path;
+        let resp = self
+            .client
+            .get(&url, claims)
+            .await
+            .map_err(ClientError::Network)?;
+        parse_response(resp).await
+    }
+
+    /// POST `/api/timekeeping/leave/policies`
+    pub async fn create_accrual_policy(
+        &self,
+        claims: &VerifiedClaims,
+        body: &CreateAccrualPolicyRequest,
+    ) -> Result<AccrualPolicy, ClientError> {
+        let path = format!("/api/timekeeping/leave/policies");
+        let url = path;
+        let resp = self
+            .client
+            .post(&url, body, claims)
+            .await
+            .map_err(ClientError::Network)?;
+        parse_response(resp).await
+    }
+
+    /// GET `/api/timekeeping/leave/requests`
+    pub async fn list_leave_requests(
+        &self,
+        claims: &VerifiedClaims,
+        employee_id: Option<uuid::Uuid>,
+        status: Option<&str>,
+    ) -> Result<Vec<LeaveRequest>, ClientError> {
+        let path = format!("/api/timekeeping/leave/requests");
+        #[derive(serde::Serialize)]
+        struct Query {
+            #[serde(skip_serializing_if = "Option::is_none")]
+            employee_id: Option<uuid::Uuid>,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            status: Option<String>,
+        }
+        let query = Query {
+            employee_id,
+            status: status.map(|s| s.to_string()),
+        };
+        let url = build_query_url(&path, &query)?;
+        let resp = self
+            .client
+            .get(&url, claims)
+            .await
+            .map_err(ClientError::Network)?;
+        parse_response(resp).await
+    }
+
+    /// POST `/api/timekeeping/leave/requests`
+    pub async fn submit_leave_request(
+        &self,
+        claims: &VerifiedClaims,
+        body: &SubmitLeaveRequest,
+    ) -> Result<LeaveRequest, ClientError> {
+        let path = format!("/api/timekeeping/leave/requests");
+        let url = path;
+        let resp = self
+            .client
+            .post(&url, body, claims)
+            .await
+            .map_err(ClientError::Network)?;
+        parse_response(resp).await
+    }
+
+    /// POST `/api/timekeeping/leave/requests/approve`
+    pub async fn approve_leave_request(
+        &self,
+        claims: &VerifiedClaims,
+        body: &ReviewLeaveRequest,
+    ) -> Result<LeaveRequest, ClientError> {
+        let path = format!("/api/timekeeping/leave/requests/approve");
+        let url = path;
+        let resp = self
+            .client
+            .post(&url, body, claims)
+            .await
+            .map_err(ClientError::Network)?;
+        parse_response(resp).await
+    }
+
+    /// POST `/api/timekeeping/leave/requests/recall`
+    pub async fn recall_leave_request(
+        &self,
+        claims: &VerifiedClaims,
+        body: &ReviewLeaveRequest,
+    ) -> Result<LeaveRequest, ClientError> {
+        let path = format!("/api/timekeeping/leave/requests/recall");
+        let url = path;
+        let resp = self
+            .client
+            .post(&url, body, claims)
+            .await
+            .map_err(ClientError::Network)?;
+        parse_response(resp).await
+    }
+
+    /// POST `/api/timekeeping/leave/requests/reject`
+    pub async fn reject_leave_request(
+        &self,
+        claims: &VerifiedClaims,
+        body: &ReviewLeaveRequest,
+    ) -> Result<LeaveRequest, ClientError> {
+        let path = format!("/api/timekeeping/leave/requests/reject");
+        let url = path;
+        let resp = self
+            .client
+            .post(&url, body, claims)
+            .await
+            .map_err(ClientError::Network)?;
+        parse_response(resp).await
+    }
+
+    /// GET `/api/timekeeping/leave/requests/{id}`
+    pub async fn get_leave_request(
+        &self,
+        claims: &VerifiedClaims,
+        id: uuid::Uuid,
+    ) -> Result<LeaveRequest, ClientError> {
+        let path = format!("/api/timekeeping/leave/requests/{}", id);
+        let url = path;
+        let resp = self
+            .client
+            .get(&url, claims)
+            .await
+            .map_err(ClientError::Network)?;
+        parse_response(resp).await
+    }
+
+    /// GET `/api/timekeeping/leave/types`
+    pub async fn list_leave_types(
+        &self,
+        claims: &VerifiedClaims,
+    ) -> Result<Vec<LeaveType>, ClientError> {
+        let path = format!("/api/timekeeping/leave/types");
+        let url = path;
+        let resp = self
+            .client
+            .get(&url, claims)
+            .await
+            .map_err(ClientError::Network)?;
+        parse_response(resp).await
+    }
+
+    /// POST `/api/timekeeping/leave/types`
+    pub async fn create_leave_type(
+        &self,
+        claims: &VerifiedClaims,
+        body: &CreateLeaveTypeRequest,
+    ) -> Result<LeaveType, ClientError> {
+        let path = format!("/api/timekeeping/leave/types");
+        let url = path;
+        let resp = self
+            .client
+            .post(&url, body, claims)
+            .await
+            .map_err(ClientError::Network)?;
+        parse_response(resp).await
+    }
+}
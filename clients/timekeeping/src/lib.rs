@@ -8,6 +8,8 @@ pub mod billing;
 pub mod employees;
 pub mod entries;
 pub mod exports;
+pub mod leave;
+pub mod pay_rules;
 pub mod projects;
 pub mod rollups;
 pub mod tasks;
@@ -20,6 +22,8 @@ pub use billing::BillingClient;
 pub use employees::EmployeesClient;
 pub use entries::EntriesClient;
 pub use exports::ExportsClient;
+pub use leave::LeaveClient;
+pub use pay_rules::PayRulesClient;
 pub use projects::ProjectsClient;
 pub use rollups::RollupsClient;
 pub use tasks::TasksClient;
@@ -70,6 +74,20 @@ impl platform_sdk::PlatformService for ExportsClient {
     }
 }
 
+impl platform_sdk::PlatformService for LeaveClient {
+    const SERVICE_NAME: &'static str = "timekeeping";
+    fn from_platform_client(client: platform_sdk::PlatformClient) -> Self {
+        Self::new(client)
+    }
+}
+
+impl platform_sdk::PlatformService for PayRulesClient {
+    const SERVICE_NAME: &'static str = "timekeeping";
+    fn from_platform_client(client: platform_sdk::PlatformClient) -> Self {
+        Self::new(client)
+    }
+}
+
 impl platform_sdk::PlatformService for ProjectsClient {
     const SERVICE_NAME: &'static str = "timekeeping";
     fn from_platform_client(client: platform_sdk::PlatformClient) -> Self {
@@ -0,0 +1,80 @@
+//! Client methods for the "Pay Rules" endpoints.
+//!
+//! Auto-generated by client-codegen — do not edit.
+
+use crate::*;
+use platform_sdk::{parse_empty, parse_response, ClientError, PlatformClient, VerifiedClaims};
+
+/// Typed HTTP client for Pay Rules endpoints.
+pub struct PayRulesClient {
+    client: PlatformClient,
+}
+
+impl PayRulesClient {
+    pub fn new(client: PlatformClient) -> Self {
+        Self { client }
+    }
+
+    /// GET `/api/timekeeping/pay-rule-sets`
+    pub async fn list_pay_rule_sets(
+        &self,
+        claims: &VerifiedClaims,
+    ) -> Result<Vec<PayRuleSet>, ClientError> {
+        let path = format!("/api/timekeeping/pay-rule-sets");
+        let url = path;
+        let resp = self
+            .client
+            .get(&url, claims)
+            .await
+            .map_err(ClientError::Network)?;
+        parse_response(resp).await
+    }
+
+    /// POST `/api/timekeeping/pay-rule-sets`
+    pub async fn create_pay_rule_set(
+        &self,
+        claims: &VerifiedClaims,
+        body: &CreatePayRuleSetRequest,
+    ) -> Result<PayRuleSet, ClientError> {
+        let path = format!("/api/timekeeping/pay-rule-sets");
+        let url = path;
+        let resp = self
+            .client
+            .post(&url, body, claims)
+            .await
+            .map_err(ClientError::Network)?;
+        parse_response(resp).await
+    }
+
+    /// DELETE `/api/timekeeping/pay-rule-sets/{id}`
+    pub async fn deactivate_pay_rule_set(
+        &self,
+        claims: &VerifiedClaims,
+        id: uuid::Uuid,
+    ) -> Result<(), ClientError> {
+        let path = format!("/api/timekeeping/pay-rule-sets/{}", id);
+        let url = path;
+        let resp = self
+            .client
+            .delete(&url, claims)
+            .await
+            .map_err(ClientError::Network)?;
+        parse_empty(resp).await
+    }
+
+    /// GET `/api/timekeeping/pay-rule-sets/{id}`
+    pub async fn get_pay_rule_set(
+        &self,
+        claims: &VerifiedClaims,
+        id: uuid::Uuid,
+    ) -> Result<PayRuleSet, ClientError> {
+        let path = format!("/api/timekeeping/pay-rule-sets/{}", id);
+        let url = path;
+        let resp = self
+            .client
+            .get(&url, claims)
+            .await
+            .map_err(ClientError::Network)?;
+        parse_response(resp).await
+    }
+}
@@ -33,6 +33,54 @@ pub struct DataResponse<T> {
     pub data: Vec<T>,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub enum AccrualMethod {
+    #[serde(rename = "per_hour_worked")]
+    PerHourWorked,
+    #[serde(rename = "per_pay_period")]
+    PerPayPeriod,
+    #[serde(rename = "front_loaded")]
+    FrontLoaded,
+}
+
+impl AccrualMethod {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AccrualMethod::PerHourWorked => "per_hour_worked",
+            AccrualMethod::PerPayPeriod => "per_pay_period",
+            AccrualMethod::FrontLoaded => "front_loaded",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AccrualPolicy {
+    pub accrual_minutes: i32,
+    pub active: bool,
+    pub app_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cap_minutes: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub carryover_max_minutes: Option<i32>,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub employee_group: Option<String>,
+    pub id: uuid::Uuid,
+    pub leave_type_id: uuid::Uuid,
+    pub method: AccrualMethod,
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub per_worked_minutes: Option<i32>,
+}
+
+/// Ledger rows posted by one accrual run.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AccrualRunResult {
+    pub period_end: chrono::NaiveDate,
+    pub period_start: chrono::NaiveDate,
+    pub posted: Vec<LedgerEntry>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Allocation {
     pub active: bool,
@@ -163,6 +211,23 @@ pub struct CorrectEntryRequest {
     pub task_id: Option<uuid::Uuid>,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateAccrualPolicyRequest {
+    pub accrual_minutes: i32,
+    pub app_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cap_minutes: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub carryover_max_minutes: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub employee_group: Option<String>,
+    pub leave_type_id: uuid::Uuid,
+    pub method: AccrualMethod,
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub per_worked_minutes: Option<i32>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CreateAllocationRequest {
     pub allocated_minutes_per_week: i32,
@@ -233,6 +298,70 @@ pub struct CreateExportRunRequest {
     pub period_start: chrono::NaiveDate,
 }
 
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct CreateHolidayCalendarRequest {
+    pub app_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub employee_group: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub holidays: Option<Vec<CreateHolidayRequest>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub leave_type_id: Option<uuid::Uuid>,
+    pub name: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateHolidayRequest {
+    pub holiday_date: chrono::NaiveDate,
+    pub name: String,
+    /// Defaults to 480 (one 8-hour day)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub paid_minutes: Option<i32>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateLeaveTypeRequest {
+    pub app_id: String,
+    pub category: LeaveCategory,
+    pub code: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_paid: Option<bool>,
+    pub name: String,
+    /// Defaults to false for holidays, true otherwise
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tracks_balance: Option<bool>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct CreatePayRuleSetRequest {
+    pub app_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub daily_dt_after_minutes: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub daily_ot_after_minutes: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub double_time_weekdays: Option<Vec<i32>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dt_multiplier_bp: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub employee_group: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub meal_break_after_minutes: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub meal_break_minutes: Option<i32>,
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ot_multiplier_bp: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shift_differentials: Option<Vec<CreateShiftDifferentialRequest>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timezone: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub week_start_day: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub weekly_ot_after_minutes: Option<i32>,
+}
+
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct CreateProjectRequest {
     pub app_id: String,
@@ -246,6 +375,14 @@ pub struct CreateProjectRequest {
     pub project_code: String,
 }
 
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct CreateShiftDifferentialRequest {
+    pub end_time: String,
+    pub name: String,
+    pub premium_minor_per_hour: i64,
+    pub start_time: String,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CreateTaskRequest {
     pub app_id: String,
@@ -308,139 +445,3 @@ pub struct ExportArtifact {
     pub json: serde_json::Value,
     pub run: ExportRun,
 }
-
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct ExportRun {
-    pub app_id: String,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub completed_at: Option<chrono::DateTime<chrono::Utc>>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub content_hash: Option<String>,
-    pub created_at: chrono::DateTime<chrono::Utc>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub error_message: Option<String>,
-    pub export_type: String,
-    pub id: uuid::Uuid,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub metadata: Option<serde_json::Value>,
-    pub period_end: chrono::NaiveDate,
-    pub period_start: chrono::NaiveDate,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub record_count: Option<i32>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub started_at: Option<chrono::DateTime<chrono::Utc>>,
-    pub status: ExportStatus,
-}
-
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
-pub enum ExportStatus {
-    Pending,
-    InProgress,
-    Completed,
-    Failed,
-}
-
-impl ExportStatus {
-    pub fn as_str(&self) -> &'static str {
-        match self {
-            ExportStatus::Pending => "Pending",
-            ExportStatus::InProgress => "InProgress",
-            ExportStatus::Completed => "Completed",
-            ExportStatus::Failed => "Failed",
-        }
-    }
-}
-
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct Project {
-    pub active: bool,
-    pub app_id: String,
-    pub billable: bool,
-    pub created_at: chrono::DateTime<chrono::Utc>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub description: Option<String>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub gl_account_ref: Option<String>,
-    pub id: uuid::Uuid,
-    pub name: String,
-    pub project_code: String,
-    pub updated_at: chrono::DateTime<chrono::Utc>,
-}
-
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct ProjectRollup {
-    pub entry_count: i64,
-    pub project_id: uuid::Uuid,
-    pub project_name: String,
-    pub total_minutes: i64,
-}
-
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct RecallApprovalRequest {
-    pub actor_id: uuid::Uuid,
-    pub app_id: String,
-    pub approval_id: uuid::Uuid,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub notes: Option<String>,
-}
-
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct ReviewApprovalRequest {
-    pub actor_id: uuid::Uuid,
-    pub app_id: String,
-    pub approval_id: uuid::Uuid,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub notes: Option<String>,
-}
-
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct SubmitApprovalRequest {
-    pub actor_id: uuid::Uuid,
-    pub app_id: String,
-    pub employee_id: uuid::Uuid,
-    pub period_end: chrono::NaiveDate,
-    pub period_start: chrono::NaiveDate,
-}
-
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct Task {
-    pub active: bool,
-    pub app_id: String,
-    pub created_at: chrono::DateTime<chrono::Utc>,
-    pub id: uuid::Uuid,
-    pub name: String,
-    pub project_id: uuid::Uuid,
-    pub task_code: String,
-    pub updated_at: chrono::DateTime<chrono::Utc>,
-}
-
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct TaskRollup {
-    pub entry_count: i64,
-    pub project_id: uuid::Uuid,
-    pub task_id: uuid::Uuid,
-    pub task_name: String,
-    pub total_minutes: i64,
-}
-
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct TimesheetEntry {
-    pub app_id: String,
-    pub created_at: chrono::DateTime<chrono::Utc>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_by: Option<uuid::Uuid>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub description: Option<String>,
-    pub employee_id: uuid::Uuid,
-    pub entry_id: uuid::Uuid,
-    pub entry_type: EntryType,
-    pub id: i64,
-    pub is_current: bool,
-    pub minutes: i32,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub project_id: Option<uuid::Uuid>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub task_id: Option<uuid::Uuid>,
-    pub version: i32,
-    pub work_date: chrono::NaiveDate,
-}
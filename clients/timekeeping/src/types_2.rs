@@ -8,6 +8,341 @@ use crate::*;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExportRun {
+    pub app_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub completed_at: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub content_hash: Option<String>,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error_message: Option<String>,
+    pub export_type: String,
+    pub id: uuid::Uuid,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<serde_json::Value>,
+    pub period_end: chrono::NaiveDate,
+    pub period_start: chrono::NaiveDate,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub record_count: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub started_at: Option<chrono::DateTime<chrono::Utc>>,
+    pub status: ExportStatus,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub enum ExportStatus {
+    Pending,
+    InProgress,
+    Completed,
+    Failed,
+}
+
+impl ExportStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ExportStatus::Pending => "Pending",
+            ExportStatus::InProgress => "InProgress",
+            ExportStatus::Completed => "Completed",
+            ExportStatus::Failed => "Failed",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Holiday {
+    pub calendar_id: uuid::Uuid,
+    pub holiday_date: chrono::NaiveDate,
+    pub id: uuid::Uuid,
+    pub name: String,
+    pub paid_minutes: i32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HolidayCalendar {
+    pub active: bool,
+    pub app_id: String,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub employee_group: Option<String>,
+    pub holidays: Vec<Holiday>,
+    pub id: uuid::Uuid,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub leave_type_id: Option<uuid::Uuid>,
+    pub name: String,
+}
+
+/// Balance of one leave type for one employee as of a date.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LeaveBalance {
+    pub as_of: chrono::NaiveDate,
+    pub balance_minutes: i64,
+    pub employee_id: uuid::Uuid,
+    pub leave_type_code: String,
+    pub leave_type_id: uuid::Uuid,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub enum LeaveCategory {
+    #[serde(rename = "vacation")]
+    Vacation,
+    #[serde(rename = "sick")]
+    Sick,
+    #[serde(rename = "holiday")]
+    Holiday,
+    #[serde(rename = "other")]
+    Other,
+}
+
+impl LeaveCategory {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            LeaveCategory::Vacation => "vacation",
+            LeaveCategory::Sick => "sick",
+            LeaveCategory::Holiday => "holiday",
+            LeaveCategory::Other => "other",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LeaveDay {
+    pub leave_date: chrono::NaiveDate,
+    pub minutes: i32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LeaveRequest {
+    pub app_id: String,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+    pub days: Vec<LeaveDay>,
+    pub employee_id: uuid::Uuid,
+    pub end_date: chrono::NaiveDate,
+    pub id: uuid::Uuid,
+    pub leave_type_id: uuid::Uuid,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reviewed_at: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reviewer_id: Option<uuid::Uuid>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reviewer_notes: Option<String>,
+    pub start_date: chrono::NaiveDate,
+    pub status: ApprovalStatus,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub submitted_at: Option<chrono::DateTime<chrono::Utc>>,
+    pub total_minutes: i32,
+    pub updated_at: chrono::DateTime<chrono::Utc>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LeaveType {
+    pub active: bool,
+    pub app_id: String,
+    pub category: LeaveCategory,
+    pub code: String,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+    pub id: uuid::Uuid,
+    pub is_paid: bool,
+    pub name: String,
+    pub tracks_balance: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LedgerEntry {
+    pub app_id: String,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+    pub effective_date: chrono::NaiveDate,
+    pub employee_id: uuid::Uuid,
+    /// accrual | front_load | forfeit | usage | adjustment
+    pub entry_kind: String,
+    pub id: i64,
+    pub leave_type_id: uuid::Uuid,
+    /// Signed minutes: usage and forfeit rows are negative
+    pub minutes: i32,
+    pub source_ref: String,
+}
+
+/// Minutes of a single approved entry split into pay buckets.
+///
+/// `regular + overtime + double_time + meal_break_deducted` always equals the
+/// entry's recorded minutes. Premium minutes are an add-on (shift differential)
+/// and overlap the other buckets rather than adding to them.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PayBuckets {
+    pub double_time_minutes: i32,
+    pub meal_break_deducted_minutes: i32,
+    pub overtime_minutes: i32,
+    /// Shift-differential premium in minor units (already priced).
+    pub premium_minor: i64,
+    pub premium_minutes: i32,
+    pub regular_minutes: i32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PayRuleSet {
+    pub app_id: String,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub daily_dt_after_minutes: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub daily_ot_after_minutes: Option<i32>,
+    pub double_time_weekdays: Vec<i32>,
+    pub dt_multiplier_bp: i32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub employee_group: Option<String>,
+    pub id: uuid::Uuid,
+    pub is_active: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub meal_break_after_minutes: Option<i32>,
+    pub meal_break_minutes: i32,
+    pub name: String,
+    pub ot_multiplier_bp: i32,
+    pub shift_differentials: Vec<ShiftDifferential>,
+    pub timezone: String,
+    pub updated_at: chrono::DateTime<chrono::Utc>,
+    pub week_start_day: i32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub weekly_ot_after_minutes: Option<i32>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Project {
+    pub active: bool,
+    pub app_id: String,
+    pub billable: bool,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub gl_account_ref: Option<String>,
+    pub id: uuid::Uuid,
+    pub name: String,
+    pub project_code: String,
+    pub updated_at: chrono::DateTime<chrono::Utc>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProjectRollup {
+    pub entry_count: i64,
+    pub project_id: uuid::Uuid,
+    pub project_name: String,
+    pub total_minutes: i64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RecallApprovalRequest {
+    pub actor_id: uuid::Uuid,
+    pub app_id: String,
+    pub approval_id: uuid::Uuid,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub notes: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReviewApprovalRequest {
+    pub actor_id: uuid::Uuid,
+    pub app_id: String,
+    pub approval_id: uuid::Uuid,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub notes: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReviewLeaveRequest {
+    pub actor_id: uuid::Uuid,
+    pub app_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub notes: Option<String>,
+    pub request_id: uuid::Uuid,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RunAccrualsRequest {
+    pub app_id: String,
+    pub period_end: chrono::NaiveDate,
+    pub period_start: chrono::NaiveDate,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ShiftDifferential {
+    pub end_time: String,
+    pub id: uuid::Uuid,
+    pub name: String,
+    pub premium_minor_per_hour: i64,
+    pub rule_set_id: uuid::Uuid,
+    pub start_time: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SubmitApprovalRequest {
+    pub actor_id: uuid::Uuid,
+    pub app_id: String,
+    pub employee_id: uuid::Uuid,
+    pub period_end: chrono::NaiveDate,
+    pub period_start: chrono::NaiveDate,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SubmitLeaveRequest {
+    pub actor_id: uuid::Uuid,
+    pub app_id: String,
+    pub employee_id: uuid::Uuid,
+    pub end_date: chrono::NaiveDate,
+    pub leave_type_id: uuid::Uuid,
+    /// Minutes per working day; defaults to 480
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub minutes_per_day: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
+    pub start_date: chrono::NaiveDate,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Task {
+    pub active: bool,
+    pub app_id: String,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+    pub id: uuid::Uuid,
+    pub name: String,
+    pub project_id: uuid::Uuid,
+    pub task_code: String,
+    pub updated_at: chrono::DateTime<chrono::Utc>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TaskRollup {
+    pub entry_count: i64,
+    pub project_id: uuid::Uuid,
+    pub task_id: uuid::Uuid,
+    pub task_name: String,
+    pub total_minutes: i64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TimesheetEntry {
+    pub app_id: String,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_by: Option<uuid::Uuid>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    pub employee_id: uuid::Uuid,
+    pub entry_id: uuid::Uuid,
+    pub entry_type: EntryType,
+    pub id: i64,
+    pub is_current: bool,
+    pub minutes: i32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub project_id: Option<uuid::Uuid>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub task_id: Option<uuid::Uuid>,
+    pub version: i32,
+    pub work_date: chrono::NaiveDate,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct UpdateAllocationRequest {
     #[serde(skip_serializing_if = "Option::is_none")]
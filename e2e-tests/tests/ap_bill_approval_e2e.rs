@@ -95,6 +95,9 @@ fn make_verified_claims() -> VerifiedClaims {
         app_id: None,
         roles: vec![],
         perms: vec![permissions::AP_MUTATE.to_string()],
+        scopes: Default::default(),
+        amr: Vec::new(),
+        auth_time: None,
         actor_type: ActorType::User,
         issued_at: Utc::now(),
         expires_at: Utc::now() + chrono::Duration::hours(1),
@@ -192,6 +195,7 @@ async fn create_bill_domain(
             tax_minor: None,
             entered_by: "ap-clerk-e2e".to_string(),
             fx_rate_id: None,
+            business_unit: None,
             lines: vec![CreateBillLineRequest {
                 description: Some("E2E services".to_string()),
                 item_id: None,
@@ -77,6 +77,7 @@ async fn create_approved_bill(
             tax_minor: None,
             entered_by: "ap-clerk-e2e".to_string(),
             fx_rate_id: None,
+            business_unit: None,
             lines: vec![CreateBillLineRequest {
                 description: Some("E2E item".to_string()),
                 item_id: None,
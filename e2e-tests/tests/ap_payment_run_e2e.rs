@@ -119,6 +119,9 @@ fn make_verified_claims() -> VerifiedClaims {
         app_id: None,
         roles: vec![],
         perms: vec![permissions::AP_MUTATE.to_string()],
+        scopes: Default::default(),
+        amr: Vec::new(),
+        auth_time: None,
         actor_type: ActorType::User,
         issued_at: Utc::now(),
         expires_at: Utc::now() + chrono::Duration::hours(1),
@@ -364,6 +367,7 @@ async fn test_ap_payment_run_full_lifecycle_with_party_id() {
             tax_minor: None,
             entered_by: "ap-clerk-e2e".to_string(),
             fx_rate_id: None,
+            business_unit: None,
             lines: vec![CreateBillLineRequest {
                 description: Some("E2E services".to_string()),
                 item_id: None,
@@ -661,6 +665,7 @@ async fn test_bill_vendor_party_id_queryable() {
             tax_minor: None,
             entered_by: "ap-clerk-e2e".to_string(),
             fx_rate_id: None,
+            business_unit: None,
             lines: vec![CreateBillLineRequest {
                 description: Some("Party bill item".to_string()),
                 item_id: None,
@@ -268,6 +268,7 @@ async fn test_po_receipt_bill_approve_gl_full_spine() {
             tax_minor: None,
             entered_by: "ap-clerk-e2e".to_string(),
             fx_rate_id: None,
+            business_unit: None,
             lines: vec![CreateBillLineRequest {
                 description: Some("Office widgets".to_string()),
                 item_id: None,
@@ -611,6 +612,7 @@ async fn test_price_mismatch_blocks_approval_without_override() {
             tax_minor: None,
             entered_by: "ap-clerk".to_string(),
             fx_rate_id: None,
+            business_unit: None,
             lines: vec![CreateBillLineRequest {
                 description: Some("Widgets".to_string()),
                 item_id: None,
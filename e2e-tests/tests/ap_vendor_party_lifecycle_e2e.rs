@@ -111,6 +111,9 @@ fn make_verified_claims() -> VerifiedClaims {
         app_id: None,
         roles: vec![],
         perms: vec![permissions::AP_MUTATE.to_string()],
+        scopes: Default::default(),
+        amr: Vec::new(),
+        auth_time: None,
         actor_type: ActorType::User,
         issued_at: Utc::now(),
         expires_at: Utc::now() + chrono::Duration::hours(1),
@@ -168,6 +171,9 @@ async fn inject_party_claims(mut req: AxumRequest, next: Next) -> Response {
             permissions::PARTY_MUTATE.to_string(),
             permissions::PARTY_READ.to_string(),
         ],
+        scopes: Default::default(),
+        amr: Vec::new(),
+        auth_time: None,
         actor_type: ActorType::Service,
         issued_at: Utc::now(),
         expires_at: Utc::now() + chrono::Duration::hours(1),
@@ -86,6 +86,9 @@ fn make_verified_claims() -> VerifiedClaims {
             permissions::AR_MUTATE.to_string(),
             permissions::AR_READ.to_string(),
         ],
+        scopes: Default::default(),
+        amr: Vec::new(),
+        auth_time: None,
         actor_type: ActorType::User,
         issued_at: Utc::now(),
         expires_at: Utc::now() + chrono::Duration::hours(1),
@@ -154,6 +157,9 @@ async fn inject_party_claims(mut req: AxumRequest, next: Next) -> Response {
             permissions::PARTY_MUTATE.to_string(),
             permissions::PARTY_READ.to_string(),
         ],
+        scopes: Default::default(),
+        amr: Vec::new(),
+        auth_time: None,
         actor_type: ActorType::Service,
         issued_at: Utc::now(),
         expires_at: Utc::now() + chrono::Duration::hours(1),
@@ -64,6 +64,9 @@ fn make_test_claims(tenant_id: &str) -> VerifiedClaims {
             "production.mutate".to_string(),
             "production.read".to_string(),
         ],
+        scopes: Default::default(),
+        amr: Vec::new(),
+        auth_time: None,
         actor_type: ActorType::User,
         issued_at: chrono::Utc::now(),
         expires_at: chrono::Utc::now() + chrono::Duration::hours(1),
@@ -168,6 +168,7 @@ async fn test_e2e_ap_bill_approve_triggers_gl_posting() {
             tax_minor: None,
             entered_by: "e2e-clerk".to_string(),
             fx_rate_id: None,
+            business_unit: None,
             lines: vec![CreateBillLineRequest {
                 description: Some("Consulting services".to_string()),
                 item_id: None,
@@ -339,6 +340,7 @@ async fn test_e2e_ap_payment_run_marks_bill_paid_after_gl_posting() {
             tax_minor: None,
             entered_by: "e2e-clerk".to_string(),
             fx_rate_id: None,
+            business_unit: None,
             lines: vec![CreateBillLineRequest {
                 description: Some("Server hosting".to_string()),
                 item_id: None,
@@ -139,6 +139,9 @@ async fn test_webhook_ingest_accepted_and_routed() {
         app_id: None,
         roles: vec![],
         perms: vec!["integrations.mutate".to_string()],
+        scopes: Default::default(),
+        amr: Vec::new(),
+        auth_time: None,
         actor_type: ActorType::Service,
         issued_at: Utc::now(),
         expires_at: Utc::now() + chrono::Duration::hours(1),
@@ -249,6 +252,9 @@ async fn test_webhook_replay_does_not_double_route() {
                 app_id: None,
                 roles: vec![],
                 perms: vec!["integrations.mutate".to_string()],
+                scopes: Default::default(),
+                amr: Vec::new(),
+                auth_time: None,
                 actor_type: ActorType::Service,
                 issued_at: Utc::now(),
                 expires_at: Utc::now() + chrono::Duration::hours(1),
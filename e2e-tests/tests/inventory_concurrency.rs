@@ -154,6 +154,7 @@ async fn inventory_concurrency_no_oversell_10_way() {
                     uom_id: None,
                     lot_code: None,
                     serial_codes: None,
+                    reservation_id: None,
                 },
                 None,
             )
@@ -316,6 +317,7 @@ async fn inventory_concurrency_conservation_invariant() {
                     uom_id: None,
                     lot_code: None,
                     serial_codes: None,
+                    reservation_id: None,
                 },
                 None,
             )
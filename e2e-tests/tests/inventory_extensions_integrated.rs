@@ -207,6 +207,7 @@ async fn lot_tracked_flow() {
             uom_id: None,
             lot_code: Some("LOT-2026-01".to_string()),
             serial_codes: None,
+            reservation_id: None,
         },
         None,
     )
@@ -238,6 +239,7 @@ async fn lot_tracked_flow() {
             uom_id: None,
             lot_code: None,
             serial_codes: None,
+            reservation_id: None,
         },
         None,
     )
@@ -321,6 +323,7 @@ async fn serial_tracked_flow() {
             uom_id: None,
             lot_code: None,
             serial_codes: Some(vec!["SN-001".to_string(), "SN-002".to_string()]),
+            reservation_id: None,
         },
         None,
     )
@@ -352,6 +355,7 @@ async fn serial_tracked_flow() {
             uom_id: None,
             lot_code: None,
             serial_codes: None,
+            reservation_id: None,
         },
         None,
     )
@@ -780,6 +784,7 @@ async fn uom_receipt_issue_converts_correctly() {
             uom_id: Some(uom_box.id),
             lot_code: None,
             serial_codes: None,
+            reservation_id: None,
         },
         None,
     )
@@ -871,6 +876,7 @@ async fn valuation_snapshot_matches_fifo() {
             uom_id: None,
             lot_code: None,
             serial_codes: None,
+            reservation_id: None,
         },
         None,
     )
@@ -1004,6 +1010,7 @@ async fn low_stock_dedup_and_rearm() {
             uom_id: None,
             lot_code: None,
             serial_codes: None,
+            reservation_id: None,
         },
         None,
     )
@@ -1039,6 +1046,7 @@ async fn low_stock_dedup_and_rearm() {
             uom_id: None,
             lot_code: None,
             serial_codes: None,
+            reservation_id: None,
         },
         None,
     )
@@ -1098,6 +1106,7 @@ async fn low_stock_dedup_and_rearm() {
             uom_id: None,
             lot_code: None,
             serial_codes: None,
+            reservation_id: None,
         },
         None,
     )
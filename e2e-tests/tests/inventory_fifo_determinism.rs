@@ -107,6 +107,7 @@ fn issue_req(tenant_id: &str, item_id: Uuid, warehouse_id: Uuid, qty: i64) -> Is
         uom_id: None,
         lot_code: None,
         serial_codes: None,
+        reservation_id: None,
     }
 }
 
@@ -423,6 +423,7 @@ async fn inventory_idempotency_duplicate_issue_is_replay() {
         uom_id: None,
         lot_code: None,
         serial_codes: None,
+        reservation_id: None,
     };
 
     let (i1, is_replay1) = process_issue(&pool, &issue_req, None)
@@ -558,6 +559,7 @@ async fn inventory_idempotency_full_pipeline_no_double_post() -> Result<()> {
         uom_id: None,
         lot_code: None,
         serial_codes: None,
+        reservation_id: None,
     };
     let (issue1, _) = process_issue(&inv_pool, &issue_req, None)
         .await
@@ -825,6 +827,7 @@ async fn inventory_idempotency_issue_then_gl_no_double() -> Result<()> {
         uom_id: None,
         lot_code: None,
         serial_codes: None,
+        reservation_id: None,
     };
 
     // Issue twice (same idempotency_key)
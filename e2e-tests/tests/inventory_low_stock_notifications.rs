@@ -319,6 +319,7 @@ async fn low_stock_signal_emitted_and_notification_persisted() {
             uom_id: None,
             lot_code: None,
             serial_codes: None,
+            reservation_id: None,
         },
         None,
     )
@@ -388,6 +389,7 @@ async fn low_stock_dedup_no_second_signal_while_below_threshold() {
             uom_id: None,
             lot_code: None,
             serial_codes: None,
+            reservation_id: None,
         },
         None,
     )
@@ -419,6 +421,7 @@ async fn low_stock_dedup_no_second_signal_while_below_threshold() {
             uom_id: None,
             lot_code: None,
             serial_codes: None,
+            reservation_id: None,
         },
         None,
     )
@@ -474,6 +477,7 @@ async fn low_stock_rearm_after_recovery() {
             uom_id: None,
             lot_code: None,
             serial_codes: None,
+            reservation_id: None,
         },
         None,
     )
@@ -534,6 +538,7 @@ async fn low_stock_rearm_after_recovery() {
             uom_id: None,
             lot_code: None,
             serial_codes: None,
+            reservation_id: None,
         },
         None,
     )
@@ -362,6 +362,7 @@ async fn inventory_full_pipeline_receipt_reserve_issue_gl_cogs() -> Result<()> {
             uom_id: None,
             lot_code: None,
             serial_codes: None,
+            reservation_id: None,
         },
         None,
     )
@@ -526,6 +527,7 @@ async fn inventory_gl_period_close_protection() -> Result<()> {
             uom_id: None,
             lot_code: None,
             serial_codes: None,
+            reservation_id: None,
         },
         None,
     )
@@ -459,6 +459,7 @@ async fn inventory_issue_source_type_tagged() {
         uom_id: None,
         lot_code: None,
         serial_codes: None,
+        reservation_id: None,
     };
 
     let (result, _is_replay) = process_issue(&inv_pool, &issue_req, None)
@@ -225,6 +225,9 @@ fn make_claims(tenant_id: &str) -> VerifiedClaims {
         app_id: Some(tid),
         roles: vec!["operator".to_string()],
         perms: vec!["ap.mutate".to_string(), "ar.mutate".to_string()],
+        scopes: Default::default(),
+        amr: Vec::new(),
+        auth_time: None,
         actor_type: ActorType::User,
         issued_at: Utc::now(),
         expires_at: Utc::now() + chrono::Duration::hours(1),
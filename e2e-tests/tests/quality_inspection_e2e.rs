@@ -151,6 +151,8 @@ async fn inspection_plan_create_get_activate() {
         ],
         sampling_method: Some("random".to_string()),
         sample_size: Some(5),
+        inspection_level: None,
+        aql: None,
     };
 
     let plan = service::create_inspection_plan(&pool, &t, &req, &corr, None)
@@ -458,6 +460,7 @@ async fn in_process_and_final_inspection_types() {
     let ip_req = CreateInProcessInspectionRequest {
         wo_id,
         op_instance_id: op_id,
+        workcenter_id: None,
         plan_id: None,
         lot_id: None,
         part_id: Some(part_id),
@@ -554,6 +557,8 @@ async fn plan_linked_to_inspection() {
         }],
         sampling_method: None,
         sample_size: Some(3),
+        inspection_level: None,
+        aql: None,
     };
 
     let plan = service::create_inspection_plan(&pool, &t, &plan_req, &corr, None)
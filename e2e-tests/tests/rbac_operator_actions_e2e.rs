@@ -28,6 +28,9 @@ fn test_claims(perms: Vec<&str>) -> VerifiedClaims {
         app_id: None,
         roles: vec!["operator".into()],
         perms: perms.into_iter().map(|s| s.to_string()).collect(),
+        scopes: Default::default(),
+        amr: Vec::new(),
+        auth_time: None,
         actor_type: security::ActorType::User,
         issued_at: Utc::now(),
         expires_at: Utc::now() + chrono::Duration::minutes(15),
@@ -389,6 +389,7 @@ async fn sr_inbound_close_inventory_receipt_gl_cogs_full_pipeline() -> Result<()
             uom_id: None,
             lot_code: None,
             serial_codes: None,
+            reservation_id: None,
         },
         None,
     )
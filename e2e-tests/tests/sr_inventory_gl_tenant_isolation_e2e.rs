@@ -210,6 +210,7 @@ async fn sr_inventory_gl_cross_tenant_isolation() -> Result<()> {
             uom_id: None,
             lot_code: None,
             serial_codes: None,
+            reservation_id: None,
         },
         None,
     )
@@ -168,6 +168,7 @@ async fn inventory_oversell_at_scale_e2e() {
                         uom_id: None,
                         lot_code: None,
                         serial_codes: None,
+                        reservation_id: None,
                     },
                     None,
                 )
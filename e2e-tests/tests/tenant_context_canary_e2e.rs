@@ -169,6 +169,9 @@ fn user_claims(tenant_id: Uuid) -> VerifiedClaims {
         app_id: None,
         roles: vec!["operator".to_string()],
         perms: vec!["service.internal".to_string()],
+        scopes: Default::default(),
+        amr: Vec::new(),
+        auth_time: None,
         actor_type: ActorType::User,
         issued_at: chrono::Utc::now(),
         expires_at: chrono::Utc::now() + chrono::TimeDelta::hours(1),
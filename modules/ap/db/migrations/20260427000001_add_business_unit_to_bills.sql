@@ -0,0 +1,8 @@
+-- Add optional business_unit to vendor bills for attribute-based data scoping.
+-- Users whose AP grants are scoped to a business unit only see and act on
+-- bills tagged with that unit. NULL = untagged (visible to unscoped users only).
+
+ALTER TABLE vendor_bills ADD COLUMN IF NOT EXISTS business_unit TEXT;
+
+CREATE INDEX IF NOT EXISTS idx_vendor_bills_tenant_business_unit
+    ON vendor_bills(tenant_id, business_unit) WHERE business_unit IS NOT NULL;
@@ -214,6 +214,7 @@ async fn bench_create_bill(
         tax_minor: None,
         entered_by: "bench".to_string(),
         fx_rate_id: None,
+        business_unit: None,
         lines: vec![CreateBillLineRequest {
             description: Some("Bench service".to_string()),
             item_id: None,
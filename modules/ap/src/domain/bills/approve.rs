@@ -137,7 +137,8 @@ pub async fn approve_bill(
         MutationClass::StateTransition,
         "VendorBill".to_string(),
         bill_id.to_string(),
-    );
+    )
+    .with_tenant(tenant_id);
     AuditWriter::write_in_tx(&mut tx, audit_req)
         .await
         .map_err(|e| match e {
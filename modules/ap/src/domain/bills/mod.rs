@@ -184,6 +184,8 @@ pub struct VendorBill {
     pub fx_rate_id: Option<Uuid>,
     pub entered_by: String,
     pub entered_at: DateTime<Utc>,
+    /// Business unit for data-scoped access (NULL = untagged)
+    pub business_unit: Option<String>,
 }
 
 /// A single line on a vendor bill as returned from the DB.
@@ -247,6 +249,9 @@ pub struct CreateBillRequest {
     /// GL fx_rates UUID — supply when bill currency != tenant functional currency.
     /// Reuses GL FX infrastructure; do not pass a raw rate value here.
     pub fx_rate_id: Option<Uuid>,
+    /// Business unit the bill belongs to; scoped approvers only see their own
+    #[serde(default)]
+    pub business_unit: Option<String>,
     pub lines: Vec<CreateBillLineRequest>,
 }
 
@@ -393,6 +398,7 @@ mod tests {
             tax_minor: None,
             entered_by: "user-1".to_string(),
             fx_rate_id: None,
+            business_unit: None,
             lines: vec![sample_line()],
         }
     }
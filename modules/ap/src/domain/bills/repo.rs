@@ -6,6 +6,7 @@
 
 use chrono::DateTime;
 use chrono::Utc;
+use security::ScopeSql;
 use sqlx::PgConnection;
 use sqlx::PgPool;
 use uuid::Uuid;
@@ -27,7 +28,7 @@ pub async fn fetch_bill(
         r#"
         SELECT bill_id, tenant_id, vendor_id, vendor_invoice_ref, currency,
                total_minor, tax_minor, invoice_date, due_date, status, fx_rate_id,
-               entered_by, entered_at
+               entered_by, entered_at, business_unit
         FROM vendor_bills
         WHERE bill_id = $1 AND tenant_id = $2
         "#,
@@ -61,78 +62,37 @@ pub async fn fetch_bill_lines(
     Ok(lines)
 }
 
-/// List bills for a tenant with optional vendor filter and voided inclusion.
+/// List bills for a tenant with optional vendor filter and voided inclusion,
+/// restricted to rows matching `scope` (parameters numbered from `$4`).
 pub async fn list_bills(
     pool: &PgPool,
     tenant_id: &str,
     vendor_id: Option<Uuid>,
     include_voided: bool,
+    scope: &ScopeSql,
 ) -> Result<Vec<VendorBill>, BillError> {
-    let bills = match (vendor_id, include_voided) {
-        (Some(vid), true) => {
-            sqlx::query_as::<_, VendorBill>(
-                r#"
-                SELECT bill_id, tenant_id, vendor_id, vendor_invoice_ref, currency,
-                       total_minor, tax_minor, invoice_date, due_date, status, fx_rate_id,
-                       entered_by, entered_at
-                FROM vendor_bills
-                WHERE tenant_id = $1 AND vendor_id = $2
-                ORDER BY invoice_date DESC
-                "#,
-            )
-            .bind(tenant_id)
-            .bind(vid)
-            .fetch_all(pool)
-            .await?
-        }
-        (Some(vid), false) => {
-            sqlx::query_as::<_, VendorBill>(
-                r#"
-                SELECT bill_id, tenant_id, vendor_id, vendor_invoice_ref, currency,
-                       total_minor, tax_minor, invoice_date, due_date, status, fx_rate_id,
-                       entered_by, entered_at
-                FROM vendor_bills
-                WHERE tenant_id = $1 AND vendor_id = $2 AND status != 'voided'
-                ORDER BY invoice_date DESC
-                "#,
-            )
-            .bind(tenant_id)
-            .bind(vid)
-            .fetch_all(pool)
-            .await?
-        }
-        (None, true) => {
-            sqlx::query_as::<_, VendorBill>(
-                r#"
-                SELECT bill_id, tenant_id, vendor_id, vendor_invoice_ref, currency,
-                       total_minor, tax_minor, invoice_date, due_date, status, fx_rate_id,
-                       entered_by, entered_at
-                FROM vendor_bills
-                WHERE tenant_id = $1
-                ORDER BY invoice_date DESC
-                "#,
-            )
-            .bind(tenant_id)
-            .fetch_all(pool)
-            .await?
-        }
-        (None, false) => {
-            sqlx::query_as::<_, VendorBill>(
-                r#"
-                SELECT bill_id, tenant_id, vendor_id, vendor_invoice_ref, currency,
-                       total_minor, tax_minor, invoice_date, due_date, status, fx_rate_id,
-                       entered_by, entered_at
-                FROM vendor_bills
-                WHERE tenant_id = $1 AND status != 'voided'
-                ORDER BY invoice_date DESC
-                "#,
-            )
-            .bind(tenant_id)
-            .fetch_all(pool)
-            .await?
-        }
-    };
-    Ok(bills)
+    let sql = format!(
+        r#"
+        SELECT bill_id, tenant_id, vendor_id, vendor_invoice_ref, currency,
+               total_minor, tax_minor, invoice_date, due_date, status, fx_rate_id,
+               entered_by, entered_at, business_unit
+        FROM vendor_bills
+        WHERE tenant_id = $1
+          AND ($2::uuid IS NULL OR vendor_id = $2)
+          AND ($3 OR status != 'voided')
+          AND {}
+        ORDER BY invoice_date DESC
+        "#,
+        scope.clause
+    );
+    let mut query = sqlx::query_as::<_, VendorBill>(&sql)
+        .bind(tenant_id)
+        .bind(vendor_id)
+        .bind(include_voided);
+    for values in &scope.binds {
+        query = query.bind(values);
+    }
+    Ok(query.fetch_all(pool).await?)
 }
 
 // ============================================================================
@@ -181,19 +141,20 @@ pub async fn insert_bill(
     fx_rate_id: Option<Uuid>,
     entered_by: &str,
     entered_at: DateTime<Utc>,
+    business_unit: Option<&str>,
 ) -> Result<VendorBill, BillError> {
     let bill: VendorBill = sqlx::query_as(
         r#"
         INSERT INTO vendor_bills (
             bill_id, tenant_id, vendor_id, vendor_invoice_ref, currency,
             total_minor, tax_minor, invoice_date, due_date, status, fx_rate_id,
-            entered_by, entered_at
+            entered_by, entered_at, business_unit
         )
-        VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, 'open', $10, $11, $12)
+        VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, 'open', $10, $11, $12, $13)
         RETURNING
             bill_id, tenant_id, vendor_id, vendor_invoice_ref, currency,
             total_minor, tax_minor, invoice_date, due_date, status, fx_rate_id,
-            entered_by, entered_at
+            entered_by, entered_at, business_unit
         "#,
     )
     .bind(bill_id)
@@ -208,6 +169,7 @@ pub async fn insert_bill(
     .bind(fx_rate_id)
     .bind(entered_by)
     .bind(entered_at)
+    .bind(business_unit)
     .fetch_one(&mut *conn)
     .await
     .map_err(|e| {
@@ -303,7 +265,7 @@ pub async fn approve_bill_status(
         RETURNING
             bill_id, tenant_id, vendor_id, vendor_invoice_ref, currency,
             total_minor, tax_minor, invoice_date, due_date, status, fx_rate_id,
-            entered_by, entered_at
+            entered_by, entered_at, business_unit
         "#,
     )
     .bind(bill_id)
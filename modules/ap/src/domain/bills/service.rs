@@ -8,6 +8,7 @@
 //! Raw SQL lives in `super::repo`; this module owns business logic only.
 
 use chrono::Utc;
+use security::ScopeSql;
 use sqlx::PgPool;
 use uuid::Uuid;
 
@@ -51,7 +52,26 @@ pub async fn list_bills(
     vendor_id: Option<Uuid>,
     include_voided: bool,
 ) -> Result<Vec<super::VendorBill>, BillError> {
-    repo::list_bills(pool, tenant_id, vendor_id, include_voided).await
+    list_bills_scoped(
+        pool,
+        tenant_id,
+        vendor_id,
+        include_voided,
+        &ScopeSql::unrestricted(),
+    )
+    .await
+}
+
+/// [`list_bills`] restricted to the caller's data scope. Build `scope` with
+/// `ScopeFilter::to_sql(4)`.
+pub async fn list_bills_scoped(
+    pool: &PgPool,
+    tenant_id: &str,
+    vendor_id: Option<Uuid>,
+    include_voided: bool,
+    scope: &ScopeSql,
+) -> Result<Vec<super::VendorBill>, BillError> {
+    repo::list_bills(pool, tenant_id, vendor_id, include_voided, scope).await
 }
 
 // ============================================================================
@@ -112,6 +132,7 @@ pub async fn create_bill(
         req.fx_rate_id,
         req.entered_by.trim(),
         now,
+        req.business_unit.as_deref().map(str::trim),
     )
     .await?;
 
@@ -163,7 +184,8 @@ pub async fn create_bill(
         MutationClass::Create,
         "VendorBill".to_string(),
         bill_id.to_string(),
-    );
+    )
+    .with_tenant(tenant_id);
     AuditWriter::write_in_tx(&mut tx, audit_req)
         .await
         .map_err(|e| match e {
@@ -312,6 +334,7 @@ mod tests {
             tax_minor: Some(500),
             entered_by: "user-ap".to_string(),
             fx_rate_id: None,
+            business_unit: None,
             lines: vec![
                 super::super::CreateBillLineRequest {
                     description: Some("Consulting services".to_string()),
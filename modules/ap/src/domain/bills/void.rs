@@ -89,7 +89,7 @@ pub async fn void_bill(
             r#"
             SELECT bill_id, tenant_id, vendor_id, vendor_invoice_ref, currency,
                    total_minor, tax_minor, invoice_date, due_date, status, fx_rate_id,
-                   entered_by, entered_at
+                   entered_by, entered_at, business_unit
             FROM vendor_bills
             WHERE bill_id = $1 AND tenant_id = $2
             "#,
@@ -154,7 +154,7 @@ pub async fn void_bill(
         RETURNING
             bill_id, tenant_id, vendor_id, vendor_invoice_ref, currency,
             total_minor, tax_minor, invoice_date, due_date, status, fx_rate_id,
-            entered_by, entered_at
+            entered_by, entered_at, business_unit
         "#,
     )
     .bind(bill_id)
@@ -170,7 +170,8 @@ pub async fn void_bill(
         MutationClass::Reversal,
         "VendorBill".to_string(),
         bill_id.to_string(),
-    );
+    )
+    .with_tenant(tenant_id);
     AuditWriter::write_in_tx(&mut tx, audit_req)
         .await
         .map_err(|e| match e {
@@ -7,6 +7,9 @@
 //! POST /api/ap/bills/:id/approve  — approve a bill (enforces match policy)
 //! POST /api/ap/bills/:id/void     — void a bill (requires reason)
 //! POST /api/ap/bills/:id/tax-quote — quote tax for a bill draft
+//!
+//! Reads, approve and void honour business-unit data scopes on the caller's
+//! `ap.read` / `ap.mutate` grants (see `security::scope`).
 
 use axum::{
     extract::{Path, Query, State},
@@ -17,14 +20,18 @@ use axum::{
 use chrono::NaiveDate;
 use event_bus::TracingContext;
 use platform_http_contracts::{ApiError, PaginatedResponse};
-use security::VerifiedClaims;
+use security::{
+    check_scope, permissions, scope_access, ScopeAccess, ScopeFilter, VerifiedClaims,
+    SCOPE_BUSINESS_UNIT,
+};
 use serde::Deserialize;
 use std::sync::Arc;
 use utoipa::IntoParams;
 use uuid::Uuid;
 
 use crate::domain::bills::{
-    approve, service, void, ApproveBillRequest, CreateBillRequest, VoidBillRequest,
+    approve, repo, service, void, ApproveBillRequest, CreateBillRequest, VendorBill,
+    VoidBillRequest,
 };
 use crate::domain::r#match::{service as match_service, RunMatchRequest};
 use crate::domain::tax::{self, ZeroTaxProvider};
@@ -93,6 +100,60 @@ fn correlation_from_headers(headers: &HeaderMap) -> String {
         .to_string()
 }
 
+/// Deny `perm` on a bill outside the caller's business-unit scope.
+fn check_bill_scope(
+    claims: &Option<Extension<VerifiedClaims>>,
+    perm: &str,
+    bill: &VendorBill,
+) -> Result<(), ApiError> {
+    let Some(Extension(claims)) = claims else {
+        return Err(ApiError::unauthorized("Missing or invalid authentication"));
+    };
+    let attrs: Vec<(&str, &str)> = bill
+        .business_unit
+        .as_deref()
+        .map(|bu| (SCOPE_BUSINESS_UNIT, bu))
+        .into_iter()
+        .collect();
+    check_scope(claims, perm, &attrs)
+        .map_err(|_| ApiError::forbidden("Bill is outside your data scope"))
+}
+
+/// Deny creating a bill outside the caller's business-unit scope. Callers
+/// restricted to business units must name one.
+fn check_new_bill_scope(
+    claims: &Option<Extension<VerifiedClaims>>,
+    req: &CreateBillRequest,
+) -> Result<(), ApiError> {
+    let Some(Extension(claims)) = claims else {
+        return Err(ApiError::unauthorized("Missing or invalid authentication"));
+    };
+    let perm = permissions::AP_MUTATE;
+    match req.business_unit.as_deref() {
+        Some(bu) => check_scope(claims, perm, &[(SCOPE_BUSINESS_UNIT, bu)])
+            .map_err(|_| ApiError::forbidden("Business unit is outside your data scope")),
+        None if matches!(scope_access(claims, perm), ScopeAccess::Restricted(_)) => Err(
+            ApiError::forbidden("business_unit is required for callers with a data scope"),
+        ),
+        None => Ok(()),
+    }
+}
+
+/// Load the bill and apply [`check_bill_scope`] before a mutation.
+/// A missing bill passes so the domain layer reports the 404.
+async fn guard_bill_scope(
+    state: &AppState,
+    claims: &Option<Extension<VerifiedClaims>>,
+    tenant_id: &str,
+    bill_id: Uuid,
+) -> Result<(), ApiError> {
+    match repo::fetch_bill(&state.pool, tenant_id, bill_id).await {
+        Ok(Some(bill)) => check_bill_scope(claims, permissions::AP_MUTATE, &bill),
+        Ok(None) => Ok(()),
+        Err(e) => Err(ApiError::from(e)),
+    }
+}
+
 // ============================================================================
 // Query params
 // ============================================================================
@@ -125,6 +186,9 @@ pub async fn create_bill(
         Ok(id) => id,
         Err(e) => return with_request_id(e, &tracing_ctx).into_response(),
     };
+    if let Err(e) = check_new_bill_scope(&claims, &req) {
+        return with_request_id(e, &tracing_ctx).into_response();
+    }
     let correlation_id = correlation_from_headers(&headers);
 
     // Period pre-validation: fail fast before any DB writes.
@@ -156,7 +220,10 @@ pub async fn get_bill(
     };
 
     match service::get_bill(&state.pool, &tenant_id, bill_id).await {
-        Ok(Some(bill)) => Json(bill).into_response(),
+        Ok(Some(bill)) => match check_bill_scope(&claims, permissions::AP_READ, &bill.bill) {
+            Ok(()) => Json(bill).into_response(),
+            Err(e) => with_request_id(e, &tracing_ctx).into_response(),
+        },
         Ok(None) => with_request_id(
             ApiError::not_found(format!("Bill {} not found", bill_id)),
             &tracing_ctx,
@@ -181,11 +248,25 @@ pub async fn list_bills(
         Err(e) => return with_request_id(e, &tracing_ctx).into_response(),
     };
 
-    match service::list_bills(
+    let scope = match &claims {
+        Some(Extension(c)) => ScopeFilter::new(c, permissions::AP_READ)
+            .column(SCOPE_BUSINESS_UNIT, "business_unit")
+            .to_sql(4),
+        None => {
+            return with_request_id(
+                ApiError::unauthorized("Missing or invalid authentication"),
+                &tracing_ctx,
+            )
+            .into_response()
+        }
+    };
+
+    match service::list_bills_scoped(
         &state.pool,
         &tenant_id,
         query.vendor_id,
         query.include_voided,
+        &scope,
     )
     .await
     {
@@ -217,6 +298,9 @@ pub async fn approve_bill(
         Ok(id) => id,
         Err(e) => return with_request_id(e, &tracing_ctx).into_response(),
     };
+    if let Err(e) = guard_bill_scope(&state, &claims, &tenant_id, bill_id).await {
+        return with_request_id(e, &tracing_ctx).into_response();
+    }
     let correlation_id = correlation_from_headers(&headers);
     let provider = ZeroTaxProvider;
 
@@ -251,6 +335,9 @@ pub async fn void_bill(
         Ok(id) => id,
         Err(e) => return with_request_id(e, &tracing_ctx).into_response(),
     };
+    if let Err(e) = guard_bill_scope(&state, &claims, &tenant_id, bill_id).await {
+        return with_request_id(e, &tracing_ctx).into_response();
+    }
     let correlation_id = correlation_from_headers(&headers);
     let provider = ZeroTaxProvider;
 
@@ -371,3 +458,63 @@ pub async fn match_bill(
         Err(e) => with_request_id(ApiError::from(e), &tracing_ctx).into_response(),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use security::{claims::ActorType, DataScopes};
+
+    fn claims(scopes: DataScopes) -> Option<Extension<VerifiedClaims>> {
+        Some(Extension(VerifiedClaims {
+            user_id: Uuid::new_v4(),
+            tenant_id: Uuid::new_v4(),
+            app_id: None,
+            roles: vec!["ap_clerk".to_string()],
+            perms: vec![permissions::AP_MUTATE.to_string()],
+            scopes,
+            amr: Vec::new(),
+            auth_time: None,
+            actor_type: ActorType::User,
+            issued_at: chrono::Utc::now(),
+            expires_at: chrono::Utc::now() + chrono::Duration::hours(1),
+            token_id: Uuid::new_v4(),
+            version: "1".to_string(),
+        }))
+    }
+
+    fn bill(business_unit: Option<&str>) -> CreateBillRequest {
+        CreateBillRequest {
+            vendor_id: Uuid::new_v4(),
+            vendor_invoice_ref: "INV-1".to_string(),
+            currency: "USD".to_string(),
+            invoice_date: chrono::Utc::now(),
+            due_date: None,
+            tax_minor: None,
+            entered_by: "clerk".to_string(),
+            fx_rate_id: None,
+            business_unit: business_unit.map(str::to_string),
+            lines: vec![],
+        }
+    }
+
+    #[test]
+    fn new_bill_scope_checks_business_unit() {
+        let east = DataScopes::default().restrict(
+            permissions::AP_MUTATE,
+            [(SCOPE_BUSINESS_UNIT.to_string(), vec!["east".to_string()])].into(),
+        );
+        let scoped = claims(east);
+        assert!(check_new_bill_scope(&scoped, &bill(Some("east"))).is_ok());
+        assert!(check_new_bill_scope(&scoped, &bill(Some("west"))).is_err());
+        assert!(
+            check_new_bill_scope(&scoped, &bill(None)).is_err(),
+            "scoped callers must name a business unit"
+        );
+
+        let unscoped = claims(DataScopes::default());
+        assert!(check_new_bill_scope(&unscoped, &bill(None)).is_ok());
+        assert!(check_new_bill_scope(&unscoped, &bill(Some("west"))).is_ok());
+
+        assert!(check_new_bill_scope(&None, &bill(None)).is_err());
+    }
+}
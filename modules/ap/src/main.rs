@@ -6,8 +6,10 @@ use std::sync::Arc;
 use utoipa::OpenApi;
 
 use ap::{http, metrics, AppState};
-use platform_sdk::ModuleBuilder;
+use axum::http::Method;
+use platform_sdk::{authz_gate::AuthzGateConfig, ModuleBuilder};
 use security::{permissions, RequirePermissionsLayer};
+use std::time::Duration;
 
 async fn openapi_json() -> Json<utoipa::openapi::OpenApi> {
     Json(http::ApiDoc::openapi())
@@ -15,10 +17,26 @@ async fn openapi_json() -> Json<utoipa::openapi::OpenApi> {
 
 static MIGRATOR: sqlx::migrate::Migrator = sqlx::migrate!("./db/migrations");
 
+/// Payment runs move money: callers must have completed MFA this recently.
+const PAYMENT_RUN_MFA_MAX_AGE: Duration = Duration::from_secs(15 * 60);
+
 #[tokio::main]
 async fn main() {
     ModuleBuilder::from_manifest("module.toml")
         .migrator(&MIGRATOR)
+        .authz_gate(
+            AuthzGateConfig::default()
+                .require_recent_mfa(
+                    Method::POST,
+                    "/api/ap/payment-runs",
+                    PAYMENT_RUN_MFA_MAX_AGE,
+                )
+                .require_recent_mfa(
+                    Method::POST,
+                    "/api/ap/payment-runs/{run_id}/execute",
+                    PAYMENT_RUN_MFA_MAX_AGE,
+                ),
+        )
         .routes(|ctx| {
             let ap_metrics =
                 Arc::new(metrics::ApMetrics::new().expect("AP: failed to create metrics"));
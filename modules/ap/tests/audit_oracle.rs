@@ -70,6 +70,7 @@ fn make_bill_req(vendor_id: Uuid, ref_num: &str) -> CreateBillRequest {
         tax_minor: None,
         entered_by: "ap-clerk".to_string(),
         fx_rate_id: None,
+        business_unit: None,
         lines: vec![CreateBillLineRequest {
             description: Some("Consulting".to_string()),
             item_id: None,
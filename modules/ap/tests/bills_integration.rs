@@ -101,6 +101,7 @@ fn make_bill_req(vendor_id: Uuid, ref_num: &str) -> CreateBillRequest {
         tax_minor: None,
         entered_by: "ap-clerk".to_string(),
         fx_rate_id: None,
+        business_unit: None,
         lines: vec![CreateBillLineRequest {
             description: Some("Consulting services".to_string()),
             item_id: None,
@@ -254,6 +255,7 @@ async fn test_run_match_two_way() {
             tax_minor: None,
             entered_by: "clerk".to_string(),
             fx_rate_id: None,
+            business_unit: None,
             lines: vec![CreateBillLineRequest {
                 description: Some("Consulting services".to_string()),
                 item_id: None,
@@ -106,6 +106,7 @@ async fn bill_creation_writes_outbox_atomically() {
             tax_minor: None,
             entered_by: "atom-test".to_string(),
             fx_rate_id: None,
+            business_unit: None,
             lines: vec![CreateBillLineRequest {
                 description: Some("Atomicity test".to_string()),
                 item_id: None,
@@ -223,6 +224,7 @@ async fn failed_guard_leaves_no_outbox_event() {
             tax_minor: None,
             entered_by: "atom-test".to_string(),
             fx_rate_id: None,
+            business_unit: None,
             lines: vec![CreateBillLineRequest {
                 description: Some("Should not persist".to_string()),
                 item_id: None,
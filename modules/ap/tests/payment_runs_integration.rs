@@ -79,6 +79,7 @@ async fn make_approved_bill(
             tax_minor: None,
             entered_by: "clerk".to_string(),
             fx_rate_id: None,
+            business_unit: None,
             lines: vec![CreateBillLineRequest {
                 description: Some("Service".to_string()),
                 item_id: None,
@@ -120,6 +120,7 @@ async fn create_test_bill(
         tax_minor: None,
         entered_by: "test-user".to_string(),
         fx_rate_id: None,
+        business_unit: None,
         lines: vec![CreateBillLineRequest {
             description: Some("Service".to_string()),
             item_id: None,
@@ -73,6 +73,7 @@ fn bill_req(vendor_id: Uuid, ref_num: &str) -> CreateBillRequest {
         tax_minor: None,
         entered_by: "boundary-test".to_string(),
         fx_rate_id: None,
+        business_unit: None,
         lines: vec![CreateBillLineRequest {
             description: Some("Boundary test line".to_string()),
             item_id: None,
@@ -1 +1,2 @@
+pub mod rma_credit_consumer;
 pub mod shipping_cost_consumer;
@@ -0,0 +1,129 @@
+//! AR consumer for shipping_receiving.rma.credit_requested events.
+//!
+//! When a credit or refund RMA is resolved in Shipping-Receiving, this consumer
+//! issues a credit note against the original invoice for the net credit
+//! (returned value less restocking fee).
+//!
+//! ## Idempotency
+//! Uses the AR processed_events table, and the payload's `credit_note_id` is
+//! the credit note's idempotency anchor — a redelivery never issues twice.
+//!
+//! ## Skipped cases
+//! - amount_minor <= 0 → nothing to credit, skip.
+//! - Invoice not found for (ar_invoice_id, tenant) → log warning, skip.
+//! - Credit would exceed the invoice balance → log warning, skip so finance can
+//!   settle the RMA manually. The event is never retried into a loop.
+
+use chrono::Utc;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::credit_notes::{issue_credit_note, CreditNoteError, IssueCreditNoteRequest};
+use crate::events::{is_event_processed, mark_event_processed};
+
+const EVENT_TYPE: &str = "shipping_receiving.rma.credit_requested";
+const PROCESSOR: &str = "ar-rma-credit-consumer";
+
+// ============================================================================
+// Local payload mirror (anti-corruption layer)
+// mirrors shipping_receiving::events::contracts::rma::RmaCreditRequestedPayload
+// ============================================================================
+
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct RmaCreditRequestedPayload {
+    pub tenant_id: String,
+    pub rma_authorization_id: Uuid,
+    pub rma_number: String,
+    pub customer_id: Uuid,
+    pub ar_invoice_id: i32,
+    pub credit_note_id: Uuid,
+    pub gross_credit_minor: i64,
+    pub restocking_fee_minor: i64,
+    pub amount_minor: i64,
+    pub currency: String,
+    pub reason_code: String,
+    pub refund: bool,
+    pub resolved_at: chrono::DateTime<Utc>,
+}
+
+// ============================================================================
+// Public processing function (testable without NATS)
+// ============================================================================
+
+/// Process a single rma.credit_requested event for the AR side.
+///
+/// Returns Ok in all cases where the event is handled gracefully (including
+/// skips). Returns Err only on unexpected database errors.
+pub async fn handle_rma_credit_requested(
+    pool: &PgPool,
+    event_id: Uuid,
+    payload: &RmaCreditRequestedPayload,
+) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+    if is_event_processed(pool, event_id).await? {
+        tracing::debug!(
+            event_id = %event_id,
+            "AR: duplicate rma.credit_requested event ignored"
+        );
+        return Ok(());
+    }
+
+    if payload.amount_minor <= 0 {
+        tracing::debug!(
+            event_id = %event_id,
+            rma_number = %payload.rma_number,
+            "AR: RMA carries no net credit — skipping credit note"
+        );
+        mark_event_processed(pool, event_id, EVENT_TYPE, PROCESSOR).await?;
+        return Ok(());
+    }
+
+    let reason = if payload.refund {
+        "rma_refund"
+    } else {
+        "rma_return"
+    };
+    let result = issue_credit_note(
+        pool,
+        IssueCreditNoteRequest {
+            credit_note_id: payload.credit_note_id,
+            app_id: payload.tenant_id.clone(),
+            customer_id: payload.customer_id.to_string(),
+            invoice_id: payload.ar_invoice_id,
+            amount_minor: payload.amount_minor,
+            currency: payload.currency.to_lowercase(),
+            reason: reason.to_string(),
+            reference_id: Some(payload.rma_number.clone()),
+            issued_by: Some("shipping-receiving-rma".to_string()),
+            correlation_id: payload.rma_authorization_id.to_string(),
+            causation_id: Some(event_id.to_string()),
+        },
+    )
+    .await;
+
+    match result {
+        Ok(_) => {
+            tracing::info!(
+                event_id = %event_id,
+                rma_number = %payload.rma_number,
+                invoice_id = payload.ar_invoice_id,
+                amount_minor = payload.amount_minor,
+                restocking_fee_minor = payload.restocking_fee_minor,
+                "AR: credit note issued for RMA"
+            );
+        }
+        Err(e @ CreditNoteError::InvoiceNotFound { .. })
+        | Err(e @ CreditNoteError::OverCreditBalance { .. }) => {
+            tracing::warn!(
+                event_id = %event_id,
+                rma_number = %payload.rma_number,
+                tenant_id = %payload.tenant_id,
+                error = %e,
+                "AR: cannot credit RMA against invoice — skipping credit note"
+            );
+        }
+        Err(e) => return Err(Box::new(e)),
+    }
+
+    mark_event_processed(pool, event_id, EVENT_TYPE, PROCESSOR).await?;
+    Ok(())
+}
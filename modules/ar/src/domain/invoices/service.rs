@@ -220,7 +220,8 @@ pub async fn create_invoice(
         MutationClass::Create,
         "Invoice".to_string(),
         invoice.id.to_string(),
-    );
+    )
+    .with_tenant(app_id);
     AuditWriter::write_in_tx(&mut tx, audit_req)
         .await
         .map_err(|e| {
@@ -425,7 +426,8 @@ pub async fn finalize_invoice(
         MutationClass::StateTransition,
         "Invoice".to_string(),
         invoice.id.to_string(),
-    );
+    )
+    .with_tenant(app_id);
     AuditWriter::write_in_tx(&mut tx, audit_req)
         .await
         .map_err(|e| {
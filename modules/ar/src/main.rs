@@ -14,6 +14,10 @@ async fn main() {
             "shipping_receiving.shipping_cost.incurred",
             on_shipping_cost_incurred,
         )
+        .consumer(
+            "shipping_receiving.rma.credit_requested",
+            on_rma_credit_requested,
+        )
         .routes(|ctx| {
             // Register AR prometheus metrics with the global registry.
             // The SDK's /metrics endpoint uses prometheus::gather() which
@@ -62,6 +66,23 @@ async fn on_shipping_cost_incurred(
         .map_err(|e| ConsumerError::Processing(e.to_string()))
 }
 
+/// SDK consumer handler for shipping_receiving.rma.credit_requested.
+async fn on_rma_credit_requested(
+    ctx: ModuleContext,
+    envelope: EventEnvelope<serde_json::Value>,
+) -> Result<(), ConsumerError> {
+    let pool = ctx.pool();
+    let event_id = envelope.event_id;
+
+    let payload: consumers::rma_credit_consumer::RmaCreditRequestedPayload =
+        serde_json::from_value(envelope.payload)
+            .map_err(|e| ConsumerError::Processing(format!("payload parse: {e}")))?;
+
+    consumers::rma_credit_consumer::handle_rma_credit_requested(pool, event_id, &payload)
+        .await
+        .map_err(|e| ConsumerError::Processing(e.to_string()))
+}
+
 /// SDK consumer handler for payments.events.payment.succeeded.
 ///
 /// Adapts the existing `consumer_tasks::handle_payment_succeeded` business logic
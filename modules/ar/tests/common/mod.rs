@@ -68,6 +68,9 @@ pub fn app(pool: &PgPool) -> Router {
         app_id: None,
         roles: vec!["admin".to_string()],
         perms: vec!["ar.mutate".to_string(), "ar.read".to_string()],
+        scopes: Default::default(),
+        amr: Vec::new(),
+        auth_time: None,
         actor_type: ActorType::User,
         issued_at: Utc::now(),
         expires_at: Utc::now() + chrono::Duration::hours(1),
@@ -33,6 +33,9 @@ fn app_with_tax(pool: &PgPool, tenant_id: Uuid) -> Router {
         app_id: None,
         roles: vec!["admin".to_string(), "tenant_admin".to_string()],
         perms: vec!["ar.mutate".to_string(), "ar.read".to_string()],
+        scopes: Default::default(),
+        amr: Vec::new(),
+        auth_time: None,
         actor_type: ActorType::User,
         issued_at: Utc::now(),
         expires_at: Utc::now() + chrono::Duration::hours(1),
@@ -0,0 +1,13 @@
+-- event_dedupe: event IDs a targeted replay (event_consumer::replay) has
+-- already run through GL's handler registry. Same shape as
+-- platform/event-consumer/sql/event_dedupe.sql.
+
+CREATE TABLE IF NOT EXISTS event_dedupe (
+    event_id      UUID        PRIMARY KEY,
+    subject       TEXT        NOT NULL,
+    first_seen_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+    last_seen_at  TIMESTAMPTZ NOT NULL DEFAULT now()
+);
+
+CREATE INDEX IF NOT EXISTS idx_event_dedupe_first_seen
+    ON event_dedupe (first_seen_at);
@@ -15,16 +15,20 @@ use crate::services::journal_service::JournalError;
 
 // Re-export posting types and functions for backward compatibility
 pub use super::gl_inventory_posting::{
-    process_inventory_cogs_posting, process_inventory_wip_posting,
+    process_inventory_cogs_posting, process_inventory_maintenance_posting,
+    process_inventory_wip_posting, process_landed_cost_posting, process_maintenance_return_posting,
     process_production_receipt_posting, ConsumedLayer, ItemIssuedPayload, ItemReceivedPayload,
-    SourceRef, SOURCE_TYPE_PRODUCTION, SOURCE_TYPE_PURCHASE, SOURCE_TYPE_SALES_ORDER,
+    LandedCostAllocatedPayload, LandedCostChargeLine, SourceRef, SOURCE_TYPE_MAINTENANCE,
+    SOURCE_TYPE_PRODUCTION, SOURCE_TYPE_PURCHASE, SOURCE_TYPE_RETURN, SOURCE_TYPE_SALES_ORDER,
 };
 
 /// Start the GL inventory consumer tasks.
 ///
 /// Subscribes to:
-/// - `inventory.item_issued` — branches on source_type for COGS vs WIP
-/// - `inventory.item_received` — handles production receipts (FG at rolled-up cost)
+/// - `inventory.item_issued` — branches on source_type for COGS vs WIP vs maintenance expense
+/// - `inventory.item_received` — handles production receipts (FG at rolled-up cost) and
+///   maintenance part returns (maintenance expense reversal)
+/// - `inventory.landed_cost_allocated` — capitalizes landed cost vouchers
 pub async fn start_gl_inventory_consumer(bus: Arc<dyn EventBus>, pool: PgPool) {
     let bus_issued = bus.clone();
     let pool_issued = pool.clone();
@@ -32,6 +36,12 @@ pub async fn start_gl_inventory_consumer(bus: Arc<dyn EventBus>, pool: PgPool) {
         start_item_issued_consumer(bus_issued, pool_issued).await;
     });
 
+    let bus_landed = bus.clone();
+    let pool_landed = pool.clone();
+    tokio::spawn(async move {
+        start_landed_cost_consumer(bus_landed, pool_landed).await;
+    });
+
     tokio::spawn(async move {
         start_item_received_consumer(bus, pool).await;
     });
@@ -191,6 +201,83 @@ async fn start_item_received_consumer(bus: Arc<dyn EventBus>, pool: PgPool) {
     tracing::warn!("GL inventory item_received consumer stopped");
 }
 
+async fn start_landed_cost_consumer(bus: Arc<dyn EventBus>, pool: PgPool) {
+    tracing::info!("Starting GL inventory landed_cost_allocated consumer");
+
+    let subject = "inventory.landed_cost_allocated";
+    let mut stream = match bus.subscribe(subject).await {
+        Ok(s) => s,
+        Err(e) => {
+            tracing::error!("Failed to subscribe to {}: {}", subject, e);
+            return;
+        }
+    };
+
+    tracing::info!("Subscribed to {}", subject);
+    let retry_config = RetryConfig::default();
+
+    while let Some(msg) = stream.next().await {
+        let (event_id, tenant_id, correlation_id, source_module) =
+            match extract_correlation_fields(&msg) {
+                Ok(fields) => fields,
+                Err(e) => {
+                    tracing::error!(
+                        subject = %msg.subject,
+                        error = %e,
+                        "Failed to extract correlation fields"
+                    );
+                    continue;
+                }
+            };
+
+        let span = tracing::info_span!(
+            "process_inventory_landed_cost_gl_posting",
+            event_id = %event_id,
+            tenant_id = %tenant_id,
+            correlation_id = %correlation_id.as_deref().unwrap_or("none"),
+            source_module = %source_module.as_deref().unwrap_or("unknown")
+        );
+
+        async {
+            let pool_clone = pool.clone();
+            let msg_clone = msg.clone();
+
+            let result = retry_with_backoff(
+                || {
+                    let pool = pool_clone.clone();
+                    let msg = msg_clone.clone();
+                    async move {
+                        process_landed_cost_message(&pool, &msg)
+                            .await
+                            .map_err(format_error_for_retry)
+                    }
+                },
+                &retry_config,
+                "gl_inventory_landed_cost_consumer",
+            )
+            .await;
+
+            if let Err(error_msg) = result {
+                tracing::error!(
+                    error = %error_msg,
+                    "Landed cost GL posting failed after retries, sending to DLQ"
+                );
+                crate::dlq::handle_processing_error(
+                    &pool,
+                    &msg,
+                    &error_msg,
+                    retry_config.max_attempts as i32,
+                )
+                .await;
+            }
+        }
+        .instrument(span)
+        .await;
+    }
+
+    tracing::warn!("GL inventory landed_cost_allocated consumer stopped");
+}
+
 // ============================================================================
 // Internal message processing
 // ============================================================================
@@ -204,44 +291,52 @@ async fn process_item_issued_message(
             ProcessingError::Validation(format!("Failed to parse item_issued envelope: {}", e))
         })?;
 
-    let source_type = &envelope.payload.source_ref.source_type;
+    handle_item_issued(
+        pool,
+        envelope.event_id,
+        &envelope.tenant_id,
+        &envelope.source_module,
+        &envelope.payload,
+    )
+    .await
+}
+
+/// Post an `inventory.item_issued` payload on the GL path for its source_type.
+pub(crate) async fn handle_item_issued(
+    pool: &PgPool,
+    event_id: Uuid,
+    tenant_id: &str,
+    source_module: &str,
+    payload: &ItemIssuedPayload,
+) -> Result<(), ProcessingError> {
+    let source_type = &payload.source_ref.source_type;
 
     tracing::info!(
-        event_id = %envelope.event_id,
-        tenant_id = %envelope.tenant_id,
-        item_id = %envelope.payload.item_id,
-        sku = %envelope.payload.sku,
-        quantity = %envelope.payload.quantity,
-        total_cost_minor = %envelope.payload.total_cost_minor,
+        event_id = %event_id,
+        tenant_id = %tenant_id,
+        item_id = %payload.item_id,
+        sku = %payload.sku,
+        quantity = %payload.quantity,
+        total_cost_minor = %payload.total_cost_minor,
         source_type = %source_type,
         "Processing inventory GL posting"
     );
 
     let result = match source_type.as_str() {
         SOURCE_TYPE_PURCHASE | SOURCE_TYPE_SALES_ORDER => {
-            process_inventory_cogs_posting(
-                pool,
-                envelope.event_id,
-                &envelope.tenant_id,
-                &envelope.source_module,
-                &envelope.payload,
-            )
-            .await
+            process_inventory_cogs_posting(pool, event_id, tenant_id, source_module, payload).await
         }
         SOURCE_TYPE_PRODUCTION => {
-            process_inventory_wip_posting(
-                pool,
-                envelope.event_id,
-                &envelope.tenant_id,
-                &envelope.source_module,
-                &envelope.payload,
-            )
-            .await
+            process_inventory_wip_posting(pool, event_id, tenant_id, source_module, payload).await
+        }
+        SOURCE_TYPE_MAINTENANCE => {
+            process_inventory_maintenance_posting(pool, event_id, tenant_id, source_module, payload)
+                .await
         }
         unknown => {
             return Err(ProcessingError::Validation(format!(
                 "Unknown source_type '{}' on item_issued event {} — cannot determine GL path",
-                unknown, envelope.event_id
+                unknown, event_id
             )));
         }
     };
@@ -249,7 +344,7 @@ async fn process_item_issued_message(
     match result {
         Ok(entry_id) => {
             tracing::info!(
-                event_id = %envelope.event_id,
+                event_id = %event_id,
                 entry_id = %entry_id,
                 source_type = %source_type,
                 "Inventory GL journal entry created"
@@ -287,72 +382,175 @@ async fn process_item_received_message(
             ProcessingError::Validation(format!("Failed to parse item_received envelope: {}", e))
         })?;
 
-    let source_type = &envelope.payload.source_type;
+    handle_item_received(
+        pool,
+        envelope.event_id,
+        &envelope.tenant_id,
+        &envelope.source_module,
+        &envelope.payload,
+    )
+    .await
+}
+
+/// Post an `inventory.item_received` payload on the GL path for its source_type.
+pub(crate) async fn handle_item_received(
+    pool: &PgPool,
+    event_id: Uuid,
+    tenant_id: &str,
+    source_module: &str,
+    payload: &ItemReceivedPayload,
+) -> Result<(), ProcessingError> {
+    let source_type = &payload.source_type;
 
-    match source_type.as_str() {
+    let result = match source_type.as_str() {
         SOURCE_TYPE_PRODUCTION => {
             tracing::info!(
-                event_id = %envelope.event_id,
-                tenant_id = %envelope.tenant_id,
-                item_id = %envelope.payload.item_id,
-                sku = %envelope.payload.sku,
-                quantity = %envelope.payload.quantity,
-                unit_cost_minor = %envelope.payload.unit_cost_minor,
+                event_id = %event_id,
+                tenant_id = %tenant_id,
+                item_id = %payload.item_id,
+                sku = %payload.sku,
+                quantity = %payload.quantity,
+                unit_cost_minor = %payload.unit_cost_minor,
                 "Processing production receipt GL posting (FG at rolled-up cost)"
             );
 
-            match process_production_receipt_posting(
-                pool,
-                envelope.event_id,
-                &envelope.tenant_id,
-                &envelope.source_module,
-                &envelope.payload,
-            )
-            .await
-            {
-                Ok(entry_id) => {
-                    tracing::info!(
-                        event_id = %envelope.event_id,
-                        entry_id = %entry_id,
-                        "Production receipt GL journal entry created"
-                    );
-                    Ok(())
-                }
-                Err(JournalError::DuplicateEvent(event_id)) => {
-                    tracing::info!(
-                        event_id = %event_id,
-                        "Duplicate item_received event ignored"
-                    );
-                    Ok(())
-                }
-                Err(JournalError::Validation(e)) => {
-                    Err(ProcessingError::Validation(format!("Validation: {}", e)))
-                }
-                Err(JournalError::InvalidDate(e)) => {
-                    Err(ProcessingError::Validation(format!("Invalid date: {}", e)))
-                }
-                Err(JournalError::Period(e)) => {
-                    Err(ProcessingError::Validation(format!("Period error: {}", e)))
-                }
-                Err(JournalError::Balance(e)) => {
-                    Err(ProcessingError::Retriable(format!("Balance error: {}", e)))
-                }
-                Err(JournalError::Database(e)) => {
-                    Err(ProcessingError::Retriable(format!("Database error: {}", e)))
-                }
-            }
+            process_production_receipt_posting(pool, event_id, tenant_id, source_module, payload)
+                .await
+        }
+        SOURCE_TYPE_RETURN => {
+            tracing::info!(
+                event_id = %event_id,
+                tenant_id = %tenant_id,
+                item_id = %payload.item_id,
+                sku = %payload.sku,
+                quantity = %payload.quantity,
+                unit_cost_minor = %payload.unit_cost_minor,
+                "Processing maintenance return GL posting"
+            );
+
+            process_maintenance_return_posting(pool, event_id, tenant_id, source_module, payload)
+                .await
         }
         SOURCE_TYPE_PURCHASE => {
             tracing::debug!(
-                event_id = %envelope.event_id,
+                event_id = %event_id,
                 "Skipping purchase receipt — GL handled by AP module"
             );
+            return Ok(());
+        }
+        unknown => {
+            return Err(ProcessingError::Validation(format!(
+                "Unknown source_type '{}' on item_received event {} — cannot determine GL path",
+                unknown, event_id
+            )));
+        }
+    };
+
+    match result {
+        Ok(entry_id) => {
+            tracing::info!(
+                event_id = %event_id,
+                entry_id = %entry_id,
+                source_type = %source_type,
+                "Inventory receipt GL journal entry created"
+            );
             Ok(())
         }
-        unknown => Err(ProcessingError::Validation(format!(
-            "Unknown source_type '{}' on item_received event {} — cannot determine GL path",
-            unknown, envelope.event_id
-        ))),
+        Err(JournalError::DuplicateEvent(event_id)) => {
+            tracing::info!(
+                event_id = %event_id,
+                "Duplicate item_received event ignored"
+            );
+            Ok(())
+        }
+        Err(JournalError::Validation(e)) => {
+            Err(ProcessingError::Validation(format!("Validation: {}", e)))
+        }
+        Err(JournalError::InvalidDate(e)) => {
+            Err(ProcessingError::Validation(format!("Invalid date: {}", e)))
+        }
+        Err(JournalError::Period(e)) => {
+            Err(ProcessingError::Validation(format!("Period error: {}", e)))
+        }
+        Err(JournalError::Balance(e)) => {
+            Err(ProcessingError::Retriable(format!("Balance error: {}", e)))
+        }
+        Err(JournalError::Database(e)) => {
+            Err(ProcessingError::Retriable(format!("Database error: {}", e)))
+        }
+    }
+}
+
+async fn process_landed_cost_message(
+    pool: &PgPool,
+    msg: &BusMessage,
+) -> Result<(), ProcessingError> {
+    let envelope: EventEnvelope<LandedCostAllocatedPayload> = serde_json::from_slice(&msg.payload)
+        .map_err(|e| {
+            ProcessingError::Validation(format!(
+                "Failed to parse landed_cost_allocated envelope: {}",
+                e
+            ))
+        })?;
+
+    handle_landed_cost(
+        pool,
+        envelope.event_id,
+        &envelope.tenant_id,
+        &envelope.source_module,
+        &envelope.payload,
+    )
+    .await
+}
+
+/// Post an `inventory.landed_cost_allocated` payload.
+pub(crate) async fn handle_landed_cost(
+    pool: &PgPool,
+    event_id: Uuid,
+    tenant_id: &str,
+    source_module: &str,
+    payload: &LandedCostAllocatedPayload,
+) -> Result<(), ProcessingError> {
+    tracing::info!(
+        event_id = %event_id,
+        tenant_id = %tenant_id,
+        voucher_id = %payload.voucher_id,
+        capitalized_minor = %payload.capitalized_minor,
+        variance_minor = %payload.variance_minor,
+        "Processing landed cost GL posting"
+    );
+
+    match process_landed_cost_posting(pool, event_id, tenant_id, source_module, payload).await {
+        Ok(entry_id) => {
+            tracing::info!(
+                event_id = %event_id,
+                entry_id = %entry_id,
+                "Landed cost GL journal entry created"
+            );
+            Ok(())
+        }
+        Err(JournalError::DuplicateEvent(event_id)) => {
+            tracing::info!(
+                event_id = %event_id,
+                "Duplicate landed_cost_allocated event ignored"
+            );
+            Ok(())
+        }
+        Err(JournalError::Validation(e)) => {
+            Err(ProcessingError::Validation(format!("Validation: {}", e)))
+        }
+        Err(JournalError::InvalidDate(e)) => {
+            Err(ProcessingError::Validation(format!("Invalid date: {}", e)))
+        }
+        Err(JournalError::Period(e)) => {
+            Err(ProcessingError::Validation(format!("Period error: {}", e)))
+        }
+        Err(JournalError::Balance(e)) => {
+            Err(ProcessingError::Retriable(format!("Balance error: {}", e)))
+        }
+        Err(JournalError::Database(e)) => {
+            Err(ProcessingError::Retriable(format!("Database error: {}", e)))
+        }
     }
 }
 
@@ -383,7 +581,7 @@ fn extract_correlation_fields(
 }
 
 #[derive(Debug)]
-enum ProcessingError {
+pub(crate) enum ProcessingError {
     Validation(String),
     Retriable(String),
 }
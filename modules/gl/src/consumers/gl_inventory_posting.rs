@@ -3,7 +3,13 @@
 //! Source type branching:
 //! - **purchase / sales_order** → COGS path: DR COGS / CR INVENTORY
 //! - **production** → WIP path: DR WIP / CR INVENTORY (raw material consumed)
+//! - **maintenance** → expense path: DR MAINTENANCE_EXPENSE / CR INVENTORY (spare parts
+//!   issued to a work order)
 //! - **production receipt** → FG path: DR INVENTORY / CR WIP (finished goods at rolled-up cost)
+//! - **return receipt** → DR INVENTORY / CR MAINTENANCE_EXPENSE (unused spare parts returned
+//!   from a work order)
+//! - **landed cost** → DR INVENTORY (on-hand share) + DR COGS (consumed share) /
+//!   CR each charge's clearing account (where the AP bill line was posted)
 
 use chrono::{DateTime, Utc};
 use uuid::Uuid;
@@ -62,6 +68,28 @@ pub struct ItemReceivedPayload {
     pub received_at: DateTime<Utc>,
 }
 
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct LandedCostChargeLine {
+    pub ap_bill_id: Uuid,
+    pub ap_bill_line_id: Uuid,
+    pub cost_type: String,
+    pub amount_minor: i64,
+    pub clearing_account_ref: String,
+}
+
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct LandedCostAllocatedPayload {
+    pub voucher_id: Uuid,
+    pub tenant_id: String,
+    pub allocation_basis: String,
+    pub currency: String,
+    pub total_amount_minor: i64,
+    pub capitalized_minor: i64,
+    pub variance_minor: i64,
+    pub charges: Vec<LandedCostChargeLine>,
+    pub allocated_at: DateTime<Utc>,
+}
+
 // ============================================================================
 // Known source_type values
 // ============================================================================
@@ -69,6 +97,8 @@ pub struct ItemReceivedPayload {
 pub const SOURCE_TYPE_PURCHASE: &str = "purchase";
 pub const SOURCE_TYPE_SALES_ORDER: &str = "sales_order";
 pub const SOURCE_TYPE_PRODUCTION: &str = "production";
+pub const SOURCE_TYPE_MAINTENANCE: &str = "maintenance";
+pub const SOURCE_TYPE_RETURN: &str = "return";
 
 // ============================================================================
 // Posting functions (testable without NATS)
@@ -192,6 +222,65 @@ pub async fn process_inventory_wip_posting(
     .await
 }
 
+/// Process an item_issued event — maintenance path (maintenance source_type).
+///
+/// Journal entry: DR MAINTENANCE_EXPENSE / CR INVENTORY (spare parts issued to a work order)
+pub async fn process_inventory_maintenance_posting(
+    pool: &PgPool,
+    event_id: Uuid,
+    tenant_id: &str,
+    source_module: &str,
+    payload: &ItemIssuedPayload,
+) -> Result<Uuid, JournalError> {
+    let amount = payload.total_cost_minor as f64 / 100.0;
+
+    let posting = GlPostingRequestV1 {
+        posting_date: payload.issued_at.format("%Y-%m-%d").to_string(),
+        currency: payload.currency.to_uppercase(),
+        source_doc_type: SourceDocType::InventoryIssue,
+        source_doc_id: payload.issue_line_id.to_string(),
+        description: format!(
+            "Maintenance — issued {} units of {} to work order {}",
+            payload.quantity, payload.sku, payload.source_ref.source_id
+        ),
+        lines: vec![
+            JournalLine {
+                account_ref: "MAINTENANCE_EXPENSE".to_string(),
+                debit: amount,
+                credit: 0.0,
+                memo: Some(format!(
+                    "Maintenance expense — {} units SKU {} consumed",
+                    payload.quantity, payload.sku
+                )),
+                dimensions: None,
+            },
+            JournalLine {
+                account_ref: "INVENTORY".to_string(),
+                debit: 0.0,
+                credit: amount,
+                memo: Some(format!(
+                    "Inventory reduction — issued {} units SKU {} to maintenance",
+                    payload.quantity, payload.sku
+                )),
+                dimensions: None,
+            },
+        ],
+    };
+
+    let subject = format!("inventory.item_issued.{}", event_id);
+
+    process_gl_posting_request(
+        pool,
+        event_id,
+        tenant_id,
+        source_module,
+        &subject,
+        &posting,
+        None,
+    )
+    .await
+}
+
 /// Process an item_received event for production receipts (FG at rolled-up cost).
 ///
 /// Journal entry: DR INVENTORY / CR WIP (finished goods received)
@@ -251,3 +340,162 @@ pub async fn process_production_receipt_posting(
     )
     .await
 }
+
+/// Process an item_received event for return receipts (return source_type).
+///
+/// Inventory receives `return` receipts from maintenance work orders handing
+/// back unused spare parts, so the issue's expense is reversed.
+///
+/// Journal entry: DR INVENTORY / CR MAINTENANCE_EXPENSE
+pub async fn process_maintenance_return_posting(
+    pool: &PgPool,
+    event_id: Uuid,
+    tenant_id: &str,
+    source_module: &str,
+    payload: &ItemReceivedPayload,
+) -> Result<Uuid, JournalError> {
+    let total_cost_minor = payload.quantity * payload.unit_cost_minor;
+    let amount = total_cost_minor as f64 / 100.0;
+
+    let posting = GlPostingRequestV1 {
+        posting_date: payload.received_at.format("%Y-%m-%d").to_string(),
+        currency: payload.currency.to_uppercase(),
+        source_doc_type: SourceDocType::InventoryReceipt,
+        source_doc_id: payload.receipt_line_id.to_string(),
+        description: format!(
+            "Maintenance return — {} units of {} returned to stock",
+            payload.quantity, payload.sku
+        ),
+        lines: vec![
+            JournalLine {
+                account_ref: "INVENTORY".to_string(),
+                debit: amount,
+                credit: 0.0,
+                memo: Some(format!(
+                    "Inventory restored — {} units SKU {} returned",
+                    payload.quantity, payload.sku
+                )),
+                dimensions: None,
+            },
+            JournalLine {
+                account_ref: "MAINTENANCE_EXPENSE".to_string(),
+                debit: 0.0,
+                credit: amount,
+                memo: Some(format!(
+                    "Maintenance expense reversed — {} units SKU {} unused",
+                    payload.quantity, payload.sku
+                )),
+                dimensions: None,
+            },
+        ],
+    };
+
+    let subject = format!("inventory.item_received.{}", event_id);
+
+    process_gl_posting_request(
+        pool,
+        event_id,
+        tenant_id,
+        source_module,
+        &subject,
+        &posting,
+        None,
+    )
+    .await
+}
+
+/// Process an inventory.landed_cost_allocated event (landed cost voucher).
+///
+/// Journal entry: DR INVENTORY (capitalized) + DR COGS (variance on consumed
+/// units) / CR clearing account per charge. Zero-amount lines are omitted;
+/// charges sharing a clearing account are credited on one line.
+pub async fn process_landed_cost_posting(
+    pool: &PgPool,
+    event_id: Uuid,
+    tenant_id: &str,
+    source_module: &str,
+    payload: &LandedCostAllocatedPayload,
+) -> Result<Uuid, JournalError> {
+    let mut lines = Vec::new();
+    if payload.capitalized_minor > 0 {
+        lines.push(JournalLine {
+            account_ref: "INVENTORY".to_string(),
+            debit: payload.capitalized_minor as f64 / 100.0,
+            credit: 0.0,
+            memo: Some(format!(
+                "Landed cost capitalized — voucher {}",
+                payload.voucher_id
+            )),
+            dimensions: None,
+        });
+    }
+    if payload.variance_minor > 0 {
+        lines.push(JournalLine {
+            account_ref: "COGS".to_string(),
+            debit: payload.variance_minor as f64 / 100.0,
+            credit: 0.0,
+            memo: Some(format!(
+                "Landed cost variance on consumed units — voucher {}",
+                payload.voucher_id
+            )),
+            dimensions: None,
+        });
+    }
+
+    let mut credits: Vec<(&str, i64, Vec<&str>)> = Vec::new();
+    for charge in &payload.charges {
+        match credits
+            .iter_mut()
+            .find(|(account, _, _)| *account == charge.clearing_account_ref)
+        {
+            Some(entry) => {
+                entry.1 += charge.amount_minor;
+                if !entry.2.contains(&charge.cost_type.as_str()) {
+                    entry.2.push(&charge.cost_type);
+                }
+            }
+            None => credits.push((
+                &charge.clearing_account_ref,
+                charge.amount_minor,
+                vec![&charge.cost_type],
+            )),
+        }
+    }
+    for (account, amount_minor, cost_types) in credits {
+        lines.push(JournalLine {
+            account_ref: account.to_string(),
+            debit: 0.0,
+            credit: amount_minor as f64 / 100.0,
+            memo: Some(format!(
+                "Landed cost ({}) reclassified to inventory",
+                cost_types.join(", ")
+            )),
+            dimensions: None,
+        });
+    }
+
+    let posting = GlPostingRequestV1 {
+        posting_date: payload.allocated_at.format("%Y-%m-%d").to_string(),
+        currency: payload.currency.to_uppercase(),
+        source_doc_type: SourceDocType::InventoryReceipt,
+        source_doc_id: payload.voucher_id.to_string(),
+        description: format!(
+            "Landed cost voucher {} — allocated by {}",
+            payload.voucher_id, payload.allocation_basis
+        ),
+        lines,
+    };
+
+    let subject = format!("inventory.landed_cost_allocated.{}", event_id);
+
+    process_gl_posting_request(
+        pool,
+        event_id,
+        tenant_id,
+        source_module,
+        &subject,
+        &posting,
+        None,
+    )
+    .await
+}
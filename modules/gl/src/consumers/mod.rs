@@ -10,4 +10,5 @@ pub mod gl_inventory_posting;
 pub mod gl_posting_consumer;
 pub mod gl_reversal_consumer;
 pub mod gl_writeoff_consumer;
+pub mod replay;
 pub mod timekeeping_labor_cost;
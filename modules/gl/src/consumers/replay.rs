@@ -0,0 +1,298 @@
+//! GL handler registry for targeted replay
+//!
+//! Maps the envelope events GL posts from to the same posting functions the
+//! live consumers call, so `event_consumer::replay::Replayer` can re-deliver
+//! a slice of a stream into GL only (see `tools/event-replay`). Live
+//! consumption still runs through the per-subject consumers in this module.
+//!
+//! Postings stay idempotent on `event_id` through `processed_events`: an event
+//! GL already posted is reported as handled without a second journal entry,
+//! even when replay bypasses `event_dedupe`.
+
+use event_consumer::{HandlerContext, HandlerError, HandlerRegistry, RegistryBuilder};
+use serde::de::DeserializeOwned;
+use sqlx::PgPool;
+
+use super::ap_vendor_bill_approved_consumer::{
+    process_ap_bill_approved_posting, VendorBillApprovedPayload,
+};
+use super::ar_tax_liability_posting::{
+    process_tax_committed_posting, process_tax_voided_posting, TaxCommittedPayload,
+    TaxVoidedPayload,
+};
+use super::gl_credit_note_consumer::{process_credit_note_posting, CreditNoteIssuedPayload};
+use super::gl_fx_realized_consumer::{process_fx_realized_posting, InvoiceSettledFxPayload};
+use super::gl_inventory_consumer::{
+    handle_item_issued, handle_item_received, handle_landed_cost, ItemIssuedPayload,
+    ItemReceivedPayload, LandedCostAllocatedPayload, ProcessingError,
+};
+use super::gl_writeoff_consumer::{process_writeoff_posting, InvoiceWrittenOffPayload};
+use super::timekeeping_labor_cost::{process_labor_cost_posting, LaborCostPostingPayload};
+use crate::services::journal_service::JournalError;
+
+/// Schema version of every payload registered here.
+pub const SCHEMA_VERSION: &str = "1.0.0";
+
+/// Registry of GL's replayable event handlers, backed by `pool`.
+pub fn handler_registry(pool: PgPool) -> HandlerRegistry {
+    RegistryBuilder::new()
+        .register("inventory.item_issued", SCHEMA_VERSION, {
+            let pool = pool.clone();
+            move |ctx: HandlerContext, payload| {
+                let pool = pool.clone();
+                async move {
+                    let payload: ItemIssuedPayload = decode(payload)?;
+                    handle_item_issued(
+                        &pool,
+                        ctx.event_id,
+                        &ctx.tenant_id,
+                        &ctx.source_module,
+                        &payload,
+                    )
+                    .await
+                    .map_err(processing_error)
+                }
+            }
+        })
+        .register("inventory.item_received", SCHEMA_VERSION, {
+            let pool = pool.clone();
+            move |ctx: HandlerContext, payload| {
+                let pool = pool.clone();
+                async move {
+                    let payload: ItemReceivedPayload = decode(payload)?;
+                    handle_item_received(
+                        &pool,
+                        ctx.event_id,
+                        &ctx.tenant_id,
+                        &ctx.source_module,
+                        &payload,
+                    )
+                    .await
+                    .map_err(processing_error)
+                }
+            }
+        })
+        .register("inventory.landed_cost_allocated", SCHEMA_VERSION, {
+            let pool = pool.clone();
+            move |ctx: HandlerContext, payload| {
+                let pool = pool.clone();
+                async move {
+                    let payload: LandedCostAllocatedPayload = decode(payload)?;
+                    handle_landed_cost(
+                        &pool,
+                        ctx.event_id,
+                        &ctx.tenant_id,
+                        &ctx.source_module,
+                        &payload,
+                    )
+                    .await
+                    .map_err(processing_error)
+                }
+            }
+        })
+        .register("ap.vendor_bill_approved", SCHEMA_VERSION, {
+            let pool = pool.clone();
+            move |ctx: HandlerContext, payload| {
+                let pool = pool.clone();
+                async move {
+                    let payload: VendorBillApprovedPayload = decode(payload)?;
+                    journal_outcome(
+                        process_ap_bill_approved_posting(
+                            &pool,
+                            ctx.event_id,
+                            &ctx.tenant_id,
+                            &ctx.source_module,
+                            &payload,
+                        )
+                        .await,
+                    )
+                }
+            }
+        })
+        .register("tax.committed", SCHEMA_VERSION, {
+            let pool = pool.clone();
+            move |ctx: HandlerContext, payload| {
+                let pool = pool.clone();
+                async move {
+                    let payload: TaxCommittedPayload = decode(payload)?;
+                    journal_outcome(
+                        process_tax_committed_posting(
+                            &pool,
+                            ctx.event_id,
+                            &ctx.tenant_id,
+                            &ctx.source_module,
+                            &payload,
+                        )
+                        .await,
+                    )
+                }
+            }
+        })
+        .register("tax.voided", SCHEMA_VERSION, {
+            let pool = pool.clone();
+            move |ctx: HandlerContext, payload| {
+                let pool = pool.clone();
+                async move {
+                    let payload: TaxVoidedPayload = decode(payload)?;
+                    journal_outcome(
+                        process_tax_voided_posting(
+                            &pool,
+                            ctx.event_id,
+                            &ctx.tenant_id,
+                            &ctx.source_module,
+                            &payload,
+                        )
+                        .await,
+                    )
+                }
+            }
+        })
+        .register("ar.credit_note_issued", SCHEMA_VERSION, {
+            let pool = pool.clone();
+            move |ctx: HandlerContext, payload| {
+                let pool = pool.clone();
+                async move {
+                    let payload: CreditNoteIssuedPayload = decode(payload)?;
+                    journal_outcome(
+                        process_credit_note_posting(
+                            &pool,
+                            ctx.event_id,
+                            &ctx.tenant_id,
+                            &ctx.source_module,
+                            &payload,
+                        )
+                        .await,
+                    )
+                }
+            }
+        })
+        .register("ar.invoice_settled_fx", SCHEMA_VERSION, {
+            let pool = pool.clone();
+            move |ctx: HandlerContext, payload| {
+                let pool = pool.clone();
+                async move {
+                    let payload: InvoiceSettledFxPayload = decode(payload)?;
+                    journal_outcome(
+                        process_fx_realized_posting(
+                            &pool,
+                            ctx.event_id,
+                            &ctx.tenant_id,
+                            &ctx.source_module,
+                            &payload,
+                        )
+                        .await,
+                    )
+                }
+            }
+        })
+        .register("ar.invoice_written_off", SCHEMA_VERSION, {
+            let pool = pool.clone();
+            move |ctx: HandlerContext, payload| {
+                let pool = pool.clone();
+                async move {
+                    let payload: InvoiceWrittenOffPayload = decode(payload)?;
+                    journal_outcome(
+                        process_writeoff_posting(
+                            &pool,
+                            ctx.event_id,
+                            &ctx.tenant_id,
+                            &ctx.source_module,
+                            &payload,
+                        )
+                        .await,
+                    )
+                }
+            }
+        })
+        .register(
+            "timekeeping.labor_cost",
+            SCHEMA_VERSION,
+            move |ctx: HandlerContext, payload| {
+                let pool = pool.clone();
+                async move {
+                    let payload: LaborCostPostingPayload = decode(payload)?;
+                    journal_outcome(
+                        process_labor_cost_posting(
+                            &pool,
+                            ctx.event_id,
+                            &ctx.tenant_id,
+                            &ctx.source_module,
+                            &payload,
+                        )
+                        .await,
+                    )
+                }
+            },
+        )
+        .build()
+}
+
+fn decode<P: DeserializeOwned>(payload: serde_json::Value) -> Result<P, HandlerError> {
+    serde_json::from_value(payload)
+        .map_err(|e| HandlerError::Permanent(format!("Failed to parse payload: {}", e)))
+}
+
+/// Same classification as the live consumers: an already-posted event is a
+/// success, bad input is permanent, database trouble is transient.
+fn journal_outcome<T>(result: Result<T, JournalError>) -> Result<(), HandlerError> {
+    match result {
+        Ok(_) | Err(JournalError::DuplicateEvent(_)) => Ok(()),
+        Err(JournalError::Validation(e)) => {
+            Err(HandlerError::Permanent(format!("Validation: {}", e)))
+        }
+        Err(JournalError::InvalidDate(e)) => {
+            Err(HandlerError::Permanent(format!("Invalid date: {}", e)))
+        }
+        Err(JournalError::Period(e)) => {
+            Err(HandlerError::Permanent(format!("Period error: {}", e)))
+        }
+        Err(JournalError::Balance(e)) => {
+            Err(HandlerError::Transient(format!("Balance error: {}", e)))
+        }
+        Err(JournalError::Database(e)) => {
+            Err(HandlerError::Transient(format!("Database error: {}", e)))
+        }
+    }
+}
+
+fn processing_error(error: ProcessingError) -> HandlerError {
+    match error {
+        ProcessingError::Validation(m) => HandlerError::Permanent(m),
+        ProcessingError::Retriable(m) => HandlerError::Transient(m),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use event_consumer::LookupResult;
+
+    #[tokio::test]
+    async fn registers_every_envelope_posting_event() {
+        let pool = sqlx::postgres::PgPoolOptions::new()
+            .connect_lazy("postgres://localhost/gl_unused")
+            .expect("lazy pool");
+        let registry = handler_registry(pool);
+        for event_type in [
+            "inventory.item_issued",
+            "inventory.item_received",
+            "inventory.landed_cost_allocated",
+            "ap.vendor_bill_approved",
+            "tax.committed",
+            "tax.voided",
+            "ar.credit_note_issued",
+            "ar.invoice_settled_fx",
+            "ar.invoice_written_off",
+            "timekeeping.labor_cost",
+        ] {
+            assert!(
+                matches!(
+                    registry.lookup(event_type, SCHEMA_VERSION),
+                    LookupResult::Found(_)
+                ),
+                "{event_type} not registered"
+            );
+        }
+        assert_eq!(registry.len(), 10);
+    }
+}
@@ -1,9 +1,11 @@
 use axum::{
+    http::Method,
     routing::{get, post},
     Json, Router,
 };
 use security::{permissions, RequirePermissionsLayer};
 use std::sync::Arc;
+use std::time::Duration;
 use utoipa::OpenApi;
 
 use gl_rs::{
@@ -45,14 +47,22 @@ use gl_rs::{
     http::trial_balance::get_trial_balance,
     start_gl_posting_consumer, start_gl_reversal_consumer, AppState,
 };
-use platform_sdk::ModuleBuilder;
+use platform_sdk::{authz_gate::AuthzGateConfig, ModuleBuilder};
 
 static MIGRATOR: sqlx::migrate::Migrator = sqlx::migrate!("./db/migrations");
 
+/// Closing a period is irreversible without a reopen request: require a recent MFA.
+const PERIOD_CLOSE_MFA_MAX_AGE: Duration = Duration::from_secs(15 * 60);
+
 #[tokio::main]
 async fn main() {
     ModuleBuilder::from_manifest("module.toml")
         .migrator(&MIGRATOR)
+        .authz_gate(AuthzGateConfig::default().require_recent_mfa(
+            Method::POST,
+            "/api/gl/periods/{period_id}/close",
+            PERIOD_CLOSE_MFA_MAX_AGE,
+        ))
         .routes_async(|ctx| async move {
             let pool = ctx.pool().clone();
             let config = Config::from_env().unwrap_or_else(|err| {
@@ -174,7 +174,8 @@ pub async fn process_gl_posting_request(
             MutationClass::Create,
             "JournalEntry".to_string(),
             entry_id.to_string(),
-        );
+        )
+        .with_tenant(tenant_id);
         AuditWriter::write_in_tx(&mut tx, audit_req)
             .await
             .map_err(|e| match e {
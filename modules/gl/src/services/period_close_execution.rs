@@ -297,7 +297,8 @@ pub async fn close_period_with_tz(
         MutationClass::StateTransition,
         "AccountingPeriod".to_string(),
         period_id.to_string(),
-    );
+    )
+    .with_tenant(tenant_id);
     AuditWriter::write_in_tx(&mut tx, audit_req)
         .await
         .map_err(|e| match e {
@@ -1,9 +1,13 @@
 //! GL Inventory Consumer — source_type branching tests (bd-2vc9u)
 //!
-//! Tests the three GL posting paths:
+//! Tests the six GL posting paths:
 //! 1. Purchase issue → COGS (DR COGS / CR INVENTORY)
 //! 2. Production issue → WIP (DR WIP / CR INVENTORY)
 //! 3. Production receipt → FG (DR INVENTORY / CR WIP)
+//! 4. Maintenance issue → expense (DR MAINTENANCE_EXPENSE / CR INVENTORY)
+//! 5. Landed cost voucher → DR INVENTORY + DR COGS / CR clearing accounts
+//! 6. Maintenance part return → DR INVENTORY / CR MAINTENANCE_EXPENSE
+//!
 //! Plus: idempotency holds across all paths.
 //!
 //! Real GL database, no mocks.
@@ -13,9 +17,10 @@ mod common;
 use chrono::Utc;
 use common::{cleanup_test_tenant, get_test_pool, setup_test_account, setup_test_period};
 use gl_rs::consumers::gl_inventory_consumer::{
-    process_inventory_cogs_posting, process_inventory_wip_posting,
+    process_inventory_cogs_posting, process_inventory_maintenance_posting,
+    process_inventory_wip_posting, process_landed_cost_posting, process_maintenance_return_posting,
     process_production_receipt_posting, ConsumedLayer, ItemIssuedPayload, ItemReceivedPayload,
-    SourceRef,
+    LandedCostAllocatedPayload, LandedCostChargeLine, SourceRef,
 };
 use gl_rs::services::journal_service::JournalError;
 use serial_test::serial;
@@ -132,6 +137,25 @@ async fn setup_gl_accounts(pool: &sqlx::PgPool, tenant_id: &str) {
     )
     .await;
     setup_test_account(pool, tenant_id, "WIP", "Work in Process", "asset", "debit").await;
+    setup_test_account(
+        pool,
+        tenant_id,
+        "MAINTENANCE_EXPENSE",
+        "Maintenance Expense",
+        "expense",
+        "debit",
+    )
+    .await;
+    setup_test_account(
+        pool,
+        tenant_id,
+        "FREIGHT_IN",
+        "Freight In",
+        "expense",
+        "debit",
+    )
+    .await;
+    setup_test_account(pool, tenant_id, "DUTY", "Import Duty", "expense", "debit").await;
 
     setup_test_period(
         pool,
@@ -321,3 +345,186 @@ async fn production_receipt_posting_is_idempotent() {
     cleanup_with_processed_events(&pool, &tenant_id).await;
     pool.close().await;
 }
+
+// ============================================================================
+// Test 6: Maintenance issue → expense path (DR MAINTENANCE_EXPENSE / CR INVENTORY)
+// ============================================================================
+
+#[tokio::test]
+#[serial]
+async fn maintenance_issue_posts_expense_journal() {
+    let pool = get_test_pool().await;
+    let tenant_id = test_tenant();
+    setup_gl_accounts(&pool, &tenant_id).await;
+
+    let payload = make_issue_payload(&tenant_id, "maintenance");
+    let event_id = Uuid::new_v4();
+
+    let entry_id =
+        process_inventory_maintenance_posting(&pool, event_id, &tenant_id, "inventory", &payload)
+            .await
+            .expect("maintenance posting must succeed");
+    assert_ne!(entry_id, Uuid::nil());
+
+    let lines = get_journal_lines(&pool, event_id).await;
+    assert_eq!(lines.len(), 2, "exactly 2 journal lines");
+
+    let exp = lines
+        .iter()
+        .find(|(a, _, _)| a == "MAINTENANCE_EXPENSE")
+        .unwrap();
+    let inv = lines.iter().find(|(a, _, _)| a == "INVENTORY").unwrap();
+
+    assert_eq!(exp.1, 50_000, "MAINTENANCE_EXPENSE debit = $500.00");
+    assert_eq!(exp.2, 0, "MAINTENANCE_EXPENSE credit = 0");
+    assert_eq!(inv.1, 0, "INVENTORY debit = 0");
+    assert_eq!(inv.2, 50_000, "INVENTORY credit = $500.00");
+    assert_eq!(exp.1 + inv.1, exp.2 + inv.2, "balanced");
+
+    cleanup_with_processed_events(&pool, &tenant_id).await;
+    pool.close().await;
+}
+
+// ============================================================================
+// Test 6b: Maintenance part return → expense reversal (DR INVENTORY / CR MAINTENANCE_EXPENSE)
+// ============================================================================
+
+#[tokio::test]
+#[serial]
+async fn maintenance_return_posts_expense_reversal_journal() {
+    let pool = get_test_pool().await;
+    let tenant_id = test_tenant();
+    setup_gl_accounts(&pool, &tenant_id).await;
+
+    let payload = make_received_payload(&tenant_id, "return");
+    let event_id = Uuid::new_v4();
+
+    // 5 units x $200.00 = $1000.00 = 100_000 minor
+    let entry_id =
+        process_maintenance_return_posting(&pool, event_id, &tenant_id, "inventory", &payload)
+            .await
+            .expect("maintenance return posting must succeed");
+    assert_ne!(entry_id, Uuid::nil());
+
+    let lines = get_journal_lines(&pool, event_id).await;
+    assert_eq!(lines.len(), 2, "exactly 2 journal lines");
+
+    let inv = lines.iter().find(|(a, _, _)| a == "INVENTORY").unwrap();
+    let exp = lines
+        .iter()
+        .find(|(a, _, _)| a == "MAINTENANCE_EXPENSE")
+        .unwrap();
+
+    assert_eq!(inv.1, 100_000, "INVENTORY debit = $1000.00");
+    assert_eq!(inv.2, 0, "INVENTORY credit = 0");
+    assert_eq!(exp.1, 0, "MAINTENANCE_EXPENSE debit = 0");
+    assert_eq!(exp.2, 100_000, "MAINTENANCE_EXPENSE credit = $1000.00");
+    assert_eq!(inv.1 + exp.1, inv.2 + exp.2, "balanced");
+
+    let dup =
+        process_maintenance_return_posting(&pool, event_id, &tenant_id, "inventory", &payload)
+            .await;
+    assert!(
+        matches!(dup, Err(JournalError::DuplicateEvent(_))),
+        "redelivered return must be deduplicated"
+    );
+
+    cleanup_with_processed_events(&pool, &tenant_id).await;
+    pool.close().await;
+}
+
+// ============================================================================
+// Test 7: Landed cost voucher → capitalized + variance / clearing accounts
+// ============================================================================
+
+fn landed_charge(amount_minor: i64, cost_type: &str, account: &str) -> LandedCostChargeLine {
+    LandedCostChargeLine {
+        ap_bill_id: Uuid::new_v4(),
+        ap_bill_line_id: Uuid::new_v4(),
+        cost_type: cost_type.to_string(),
+        amount_minor,
+        clearing_account_ref: account.to_string(),
+    }
+}
+
+#[tokio::test]
+#[serial]
+async fn landed_cost_posts_inventory_and_cogs_against_clearing_accounts() {
+    let pool = get_test_pool().await;
+    let tenant_id = test_tenant();
+    setup_gl_accounts(&pool, &tenant_id).await;
+
+    // $120 freight over two bill lines + $30 duty; $90 still on hand, $60 consumed.
+    let payload = LandedCostAllocatedPayload {
+        voucher_id: Uuid::new_v4(),
+        tenant_id: tenant_id.clone(),
+        allocation_basis: "quantity".to_string(),
+        currency: "usd".to_string(),
+        total_amount_minor: 15_000,
+        capitalized_minor: 9_000,
+        variance_minor: 6_000,
+        charges: vec![
+            landed_charge(8_000, "freight", "FREIGHT_IN"),
+            landed_charge(4_000, "freight", "FREIGHT_IN"),
+            landed_charge(3_000, "duty", "DUTY"),
+        ],
+        allocated_at: Utc::now(),
+    };
+    let event_id = Uuid::new_v4();
+
+    process_landed_cost_posting(&pool, event_id, &tenant_id, "inventory", &payload)
+        .await
+        .expect("landed cost posting must succeed");
+
+    let lines = get_journal_lines(&pool, event_id).await;
+    assert_eq!(lines.len(), 4, "INVENTORY, COGS, FREIGHT_IN, DUTY");
+    let find = |acct: &str| lines.iter().find(|(a, _, _)| a == acct).unwrap().clone();
+    assert_eq!(find("INVENTORY"), ("INVENTORY".to_string(), 9_000, 0));
+    assert_eq!(find("COGS"), ("COGS".to_string(), 6_000, 0));
+    assert_eq!(find("FREIGHT_IN"), ("FREIGHT_IN".to_string(), 0, 12_000));
+    assert_eq!(find("DUTY"), ("DUTY".to_string(), 0, 3_000));
+
+    let second =
+        process_landed_cost_posting(&pool, event_id, &tenant_id, "inventory", &payload).await;
+    assert!(matches!(second, Err(JournalError::DuplicateEvent(_))));
+
+    cleanup_with_processed_events(&pool, &tenant_id).await;
+    pool.close().await;
+}
+
+#[tokio::test]
+#[serial]
+async fn landed_cost_fully_on_hand_omits_cogs_line() {
+    let pool = get_test_pool().await;
+    let tenant_id = test_tenant();
+    setup_gl_accounts(&pool, &tenant_id).await;
+
+    let payload = LandedCostAllocatedPayload {
+        voucher_id: Uuid::new_v4(),
+        tenant_id: tenant_id.clone(),
+        allocation_basis: "value".to_string(),
+        currency: "usd".to_string(),
+        total_amount_minor: 2_500,
+        capitalized_minor: 2_500,
+        variance_minor: 0,
+        charges: vec![landed_charge(2_500, "freight", "FREIGHT_IN")],
+        allocated_at: Utc::now(),
+    };
+    let event_id = Uuid::new_v4();
+
+    process_landed_cost_posting(&pool, event_id, &tenant_id, "inventory", &payload)
+        .await
+        .expect("landed cost posting must succeed");
+
+    let lines = get_journal_lines(&pool, event_id).await;
+    assert_eq!(
+        lines,
+        vec![
+            ("INVENTORY".to_string(), 2_500, 0),
+            ("FREIGHT_IN".to_string(), 0, 2_500),
+        ]
+    );
+
+    cleanup_with_processed_events(&pool, &tenant_id).await;
+    pool.close().await;
+}
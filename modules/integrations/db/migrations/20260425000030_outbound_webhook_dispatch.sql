@@ -0,0 +1,60 @@
+-- Outbound Webhook Dispatch
+--
+-- The dispatcher subscribes to platform subjects, fans each event out to the
+-- tenant's matching webhooks, and POSTs an HMAC-SHA256 signed payload.
+--
+-- signing_secret_enc: AES-256-GCM ciphertext (nonce || ct) of the raw signing
+--                     secret under INTEGRATIONS_SECRETS_KEY. Needed to sign;
+--                     NULL for webhooks created before dispatch existed —
+--                     rotate the secret to start delivering to them.
+--
+-- consecutive_failures: failed attempts since the last success. The dispatcher
+--                       sets status = 'disabled' once it reaches the threshold.
+
+ALTER TABLE integrations_outbound_webhooks
+    ADD COLUMN signing_secret_enc   BYTEA,
+    ADD COLUMN consecutive_failures INT         NOT NULL DEFAULT 0,
+    ADD COLUMN last_failure_at      TIMESTAMPTZ,
+    ADD COLUMN disabled_reason      TEXT;
+
+-- ============================================================================
+-- Dispatched event log
+-- ============================================================================
+--
+-- One row per platform event the dispatcher has fanned out. Redelivery reads
+-- the original envelope from here, so any past event can be re-sent to a
+-- webhook — including webhooks that did not exist when it first fired.
+
+CREATE TABLE integrations_outbound_webhook_events (
+    event_id     UUID        NOT NULL PRIMARY KEY,
+    tenant_id    TEXT        NOT NULL,
+    subject      TEXT        NOT NULL,
+    event_type   TEXT        NOT NULL,
+    envelope     JSONB       NOT NULL,
+    received_at  TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_outbound_webhook_events_tenant
+    ON integrations_outbound_webhook_events (tenant_id, received_at DESC);
+
+-- ============================================================================
+-- Delivery attempt linkage
+-- ============================================================================
+--
+-- event_id groups the attempts of one event to one webhook. next_retry_at on
+-- the latest failed attempt schedules the next one; the retry worker clears it
+-- when it claims the row, so each attempt is retried at most once.
+
+ALTER TABLE integrations_outbound_webhook_deliveries
+    ADD COLUMN event_id      UUID,
+    ADD COLUMN is_redelivery BOOLEAN NOT NULL DEFAULT FALSE;
+
+-- Retry worker scan: due attempts only
+CREATE INDEX idx_outbound_deliveries_retry_due
+    ON integrations_outbound_webhook_deliveries (next_retry_at)
+    WHERE next_retry_at IS NOT NULL;
+
+-- Dispatch dedupe: the first attempt of an event to a webhook is made once
+CREATE UNIQUE INDEX idx_outbound_deliveries_first_attempt
+    ON integrations_outbound_webhook_deliveries (webhook_id, event_id)
+    WHERE event_id IS NOT NULL AND attempt_number = 1 AND NOT is_redelivery;
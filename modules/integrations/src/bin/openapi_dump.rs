@@ -13,6 +13,9 @@ use integrations_rs::domain::external_refs::{
     CreateExternalRefRequest, ExternalRef, UpdateExternalRefRequest,
 };
 use integrations_rs::domain::oauth::{ConnectionStatus, OAuthConnectionInfo};
+use integrations_rs::domain::outbound_webhooks::{
+    OutboundWebhookDelivery, RedeliverRequest, RotatedSecret,
+};
 use integrations_rs::http::qbo_invoice::{UpdateInvoiceRequest, UpdateInvoiceResponse};
 use integrations_rs::http::qbo_taxrate::{TaxRateItem, TaxrateListResponse};
 use integrations_rs::http::sync::PushEntityRequest;
@@ -58,6 +61,10 @@ use platform_http_contracts::{ApiError, FieldError, PaginatedResponse, Paginatio
         integrations_rs::http::qbo_taxrate::list_taxrates,
         // SyncPush
         integrations_rs::http::sync::push_entity,
+        // Outbound Webhooks
+        integrations_rs::http::outbound_webhooks::list_deliveries,
+        integrations_rs::http::outbound_webhooks::redeliver,
+        integrations_rs::http::outbound_webhooks::rotate_secret,
     ),
     components(schemas(
         // External refs
@@ -73,10 +80,12 @@ use platform_http_contracts::{ApiError, FieldError, PaginatedResponse, Paginatio
         TaxrateListResponse, TaxRateItem,
         // SyncPush
         PushEntityRequest,
+        // Outbound Webhooks
+        OutboundWebhookDelivery, RedeliverRequest, RotatedSecret,
         // Shared envelopes
         ApiError, FieldError, PaginatedResponse<ExternalRef>,
         PaginatedResponse<ConnectorCapabilities>, PaginatedResponse<ConnectorConfig>,
-        PaginationMeta,
+        PaginatedResponse<OutboundWebhookDelivery>, PaginationMeta,
     )),
     security(
         ("bearer" = [])
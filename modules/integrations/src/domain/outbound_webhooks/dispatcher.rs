@@ -0,0 +1,776 @@
+//! Outbound webhook dispatcher — bus subscription, signed delivery, retries.
+//!
+//! ## Flow
+//! 1. Subscribe to the configured platform subjects (`OUTBOUND_WEBHOOK_SUBJECTS`,
+//!    comma-separated; defaults to invoice, order and shipment subjects).
+//! 2. For each event, log it to `integrations_outbound_webhook_events`
+//!    (duplicate bus deliveries stop here) and find the tenant's active
+//!    webhooks whose `event_types` match the subject or envelope `event_type`.
+//! 3. POST the envelope JSON, signed per [`super::signing`], and record the
+//!    attempt in `integrations_outbound_webhook_deliveries`.
+//! 4. A failed attempt gets `next_retry_at` with exponential backoff until
+//!    [`MAX_ATTEMPTS`]; the retry worker claims due attempts and makes the next.
+//! 5. After [`DISABLE_AFTER_CONSECUTIVE_FAILURES`] failed attempts with no
+//!    success in between, the webhook is disabled and `outbound_webhook.updated`
+//!    is emitted.
+//!
+//! Redelivery re-sends any logged event to one webhook as a new attempt chain.
+
+use chrono::{DateTime, Duration, Utc};
+use event_bus::{BusMessage, EventBus};
+use futures::StreamExt;
+use sqlx::PgPool;
+use std::sync::Arc;
+use tokio::sync::watch;
+use uuid::Uuid;
+
+use crate::domain::webhooks::secret_store::decrypt_token;
+use crate::events::{
+    build_outbound_webhook_updated_envelope, OutboundWebhookUpdatedPayload,
+    EVENT_TYPE_OUTBOUND_WEBHOOK_UPDATED,
+};
+use crate::outbox::enqueue_event_tx;
+
+use super::models::{
+    DispatchedEvent, OutboundWebhook, OutboundWebhookDelivery, OutboundWebhookError,
+    RecordDeliveryRequest,
+};
+use super::repo;
+use super::signing;
+
+// ── Constants ─────────────────────────────────────────────────────────────────
+
+/// Subjects subscribed when `OUTBOUND_WEBHOOK_SUBJECTS` is unset.
+pub const DEFAULT_SUBJECTS: &[&str] = &["ar.events.ar.>", "sales_orders.>", "shipping_receiving.>"];
+
+/// Attempts per event per webhook, including the first.
+pub const MAX_ATTEMPTS: i32 = 8;
+/// Delay before the second attempt; doubles per attempt.
+pub const BASE_BACKOFF_SECS: i64 = 30;
+/// Upper bound on the delay between attempts.
+pub const MAX_BACKOFF_SECS: i64 = 6 * 3600;
+/// Failed attempts in a row (across events) before the webhook is disabled.
+pub const DISABLE_AFTER_CONSECUTIVE_FAILURES: i32 = 20;
+/// Due retries claimed per worker tick.
+pub const RETRY_BATCH_SIZE: i64 = 50;
+
+const REQUEST_TIMEOUT_SECS: u64 = 10;
+const RESPONSE_BODY_LIMIT: usize = 2048;
+
+// ── Matching and backoff ──────────────────────────────────────────────────────
+
+/// Subjects from `OUTBOUND_WEBHOOK_SUBJECTS`, or [`DEFAULT_SUBJECTS`].
+pub fn subjects_from_env() -> Vec<String> {
+    std::env::var("OUTBOUND_WEBHOOK_SUBJECTS")
+        .ok()
+        .map(|v| {
+            v.split(',')
+                .map(|s| s.trim().to_string())
+                .filter(|s| !s.is_empty())
+                .collect::<Vec<_>>()
+        })
+        .filter(|v| !v.is_empty())
+        .unwrap_or_else(|| DEFAULT_SUBJECTS.iter().map(|s| s.to_string()).collect())
+}
+
+/// NATS-style match: `*` matches one token, a trailing `>` one or more.
+pub fn event_type_matches(pattern: &str, event_type: &str) -> bool {
+    let mut p = pattern.split('.');
+    let mut t = event_type.split('.');
+    loop {
+        match (p.next(), t.next()) {
+            (Some(">"), Some(_)) => return true,
+            (Some("*"), Some(_)) => {}
+            (Some(a), Some(b)) if a == b => {}
+            (None, None) => return true,
+            _ => return false,
+        }
+    }
+}
+
+/// True when the webhook subscribes to the subject or envelope event type.
+/// An empty `event_types` array subscribes to everything.
+pub fn webhook_subscribes(webhook: &OutboundWebhook, subject: &str, event_type: &str) -> bool {
+    let Some(types) = webhook.event_types.as_array() else {
+        return false;
+    };
+    types.is_empty()
+        || types.iter().filter_map(|t| t.as_str()).any(|pattern| {
+            event_type_matches(pattern, subject) || event_type_matches(pattern, event_type)
+        })
+}
+
+/// Delay after failed attempt `attempt` (1-based) before the next one.
+pub fn backoff_delay(attempt: i32) -> Duration {
+    let exp = (attempt - 1).clamp(0, 20) as u32;
+    let secs = BASE_BACKOFF_SECS.saturating_mul(1_i64 << exp);
+    Duration::seconds(secs.min(MAX_BACKOFF_SECS))
+}
+
+/// Build the log row for a bus message. None when the payload is not an
+/// envelope carrying `event_id` and `tenant_id`.
+pub fn event_from_message(msg: &BusMessage, received_at: DateTime<Utc>) -> Option<DispatchedEvent> {
+    let envelope: serde_json::Value = serde_json::from_slice(&msg.payload).ok()?;
+    let event_id = envelope
+        .get("event_id")
+        .and_then(|v| v.as_str())
+        .and_then(|s| Uuid::parse_str(s).ok())?;
+    let tenant_id = envelope
+        .get("tenant_id")
+        .and_then(|v| v.as_str())
+        .filter(|s| !s.is_empty())?
+        .to_string();
+    let event_type = envelope
+        .get("event_type")
+        .and_then(|v| v.as_str())
+        .unwrap_or(&msg.subject)
+        .to_string();
+    Some(DispatchedEvent {
+        event_id,
+        tenant_id,
+        subject: msg.subject.clone(),
+        event_type,
+        envelope,
+        received_at,
+    })
+}
+
+// ── Transport ─────────────────────────────────────────────────────────────────
+
+/// Response from a receiver endpoint.
+#[derive(Debug, Clone)]
+pub struct SendResponse {
+    pub status: u16,
+    pub body: String,
+}
+
+/// Trait for the HTTP POST — allows injecting test implementations.
+#[async_trait::async_trait]
+pub trait WebhookSender: Send + Sync {
+    /// Transport errors (DNS, TLS, timeout) are `Err`; any HTTP status is `Ok`.
+    async fn post(
+        &self,
+        url: &str,
+        headers: &[(&'static str, String)],
+        body: Vec<u8>,
+    ) -> Result<SendResponse, String>;
+}
+
+/// Production sender: reqwest with a request timeout and no redirects.
+pub struct HttpWebhookSender {
+    client: reqwest::Client,
+}
+
+impl HttpWebhookSender {
+    pub fn new() -> Self {
+        let client = reqwest::Client::builder()
+            .timeout(std::time::Duration::from_secs(REQUEST_TIMEOUT_SECS))
+            .redirect(reqwest::redirect::Policy::none())
+            .build()
+            .expect("reqwest client builds with static config");
+        Self { client }
+    }
+}
+
+impl Default for HttpWebhookSender {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait::async_trait]
+impl WebhookSender for HttpWebhookSender {
+    async fn post(
+        &self,
+        url: &str,
+        headers: &[(&'static str, String)],
+        body: Vec<u8>,
+    ) -> Result<SendResponse, String> {
+        let mut req = self
+            .client
+            .post(url)
+            .header("Content-Type", "application/json");
+        for (name, value) in headers {
+            req = req.header(*name, value);
+        }
+        let resp = req.body(body).send().await.map_err(|e| e.to_string())?;
+        let status = resp.status().as_u16();
+        let body = resp.text().await.unwrap_or_default();
+        Ok(SendResponse { status, body })
+    }
+}
+
+// ── Dispatcher ────────────────────────────────────────────────────────────────
+
+struct AttemptCtx<'a> {
+    webhook: &'a OutboundWebhook,
+    event: &'a DispatchedEvent,
+    number: i32,
+    is_redelivery: bool,
+}
+
+enum AttemptOutcome {
+    Delivered(SendResponse),
+    Failed {
+        error: String,
+        response: Option<SendResponse>,
+    },
+    /// No decryptable signing secret; nothing was sent.
+    Unsigned,
+}
+
+pub struct Dispatcher {
+    pool: PgPool,
+    sender: Arc<dyn WebhookSender>,
+    /// INTEGRATIONS_SECRETS_KEY — decrypts per-webhook signing secrets.
+    secrets_key: [u8; 32],
+}
+
+impl Dispatcher {
+    pub fn new(pool: PgPool, sender: Arc<dyn WebhookSender>, secrets_key: [u8; 32]) -> Self {
+        Self {
+            pool,
+            sender,
+            secrets_key,
+        }
+    }
+
+    /// Fan one bus message out to the tenant's matching webhooks.
+    /// Returns the number of first attempts made.
+    pub async fn handle_message(&self, msg: &BusMessage) -> Result<usize, OutboundWebhookError> {
+        let Some(event) = event_from_message(msg, Utc::now()) else {
+            tracing::debug!(subject = %msg.subject, "outbound_webhooks: not an envelope, skipped");
+            return Ok(0);
+        };
+        self.dispatch(&event).await
+    }
+
+    /// Log the event and make the first attempt to each matching webhook.
+    pub async fn dispatch(&self, event: &DispatchedEvent) -> Result<usize, OutboundWebhookError> {
+        if !repo::insert_event(&self.pool, event).await? {
+            tracing::debug!(event_id = %event.event_id, "outbound_webhooks: duplicate event skipped");
+            return Ok(0);
+        }
+        let webhooks = repo::list_active_by_tenant(&self.pool, &event.tenant_id).await?;
+        let mut sent = 0;
+        for webhook in webhooks
+            .iter()
+            .filter(|w| webhook_subscribes(w, &event.subject, &event.event_type))
+        {
+            match self.attempt(webhook, event, 1, false).await {
+                Ok(_) => sent += 1,
+                Err(OutboundWebhookError::DuplicateIdempotencyKey(_)) => {}
+                // The event is already logged, so a bus redelivery would be
+                // skipped: leave a retry for this webhook and carry on.
+                Err(e) => self.record_dispatch_error(webhook, event, &e).await,
+            }
+        }
+        Ok(sent)
+    }
+
+    /// Re-send a logged event to one webhook as a new attempt chain.
+    pub async fn redeliver(
+        &self,
+        tenant_id: &str,
+        webhook_id: Uuid,
+        event_id: Uuid,
+    ) -> Result<OutboundWebhookDelivery, OutboundWebhookError> {
+        let webhook = repo::get_by_id(&self.pool, tenant_id, webhook_id)
+            .await?
+            .ok_or(OutboundWebhookError::NotFound)?;
+        let event = repo::get_event(&self.pool, tenant_id, event_id)
+            .await?
+            .ok_or(OutboundWebhookError::EventNotFound)?;
+        self.attempt(&webhook, &event, 1, true).await
+    }
+
+    /// Make the next attempt for every failed attempt whose retry is due.
+    /// Returns the number of attempts made.
+    pub async fn process_due_retries(&self) -> Result<usize, OutboundWebhookError> {
+        let due = repo::claim_due_retries(&self.pool, RETRY_BATCH_SIZE).await?;
+        let mut sent = 0;
+        for prev in due {
+            match self.retry(&prev).await {
+                Ok(true) => sent += 1,
+                Ok(false) => {}
+                Err(e) => {
+                    // Claiming cleared next_retry_at; put it back or the
+                    // chain is stranded.
+                    let retry_at = Utc::now() + backoff_delay(prev.attempt_number);
+                    tracing::error!(
+                        delivery_id = %prev.id,
+                        webhook_id = %prev.webhook_id,
+                        error = %e,
+                        %retry_at,
+                        "outbound_webhooks: retry failed, rescheduled"
+                    );
+                    if let Err(e) = repo::reschedule_retry(&self.pool, prev.id, retry_at).await {
+                        tracing::error!(
+                            delivery_id = %prev.id,
+                            error = %e,
+                            "outbound_webhooks: could not reschedule retry"
+                        );
+                    }
+                }
+            }
+        }
+        Ok(sent)
+    }
+
+    /// Next attempt after `prev`. `Ok(false)` when the chain ends here
+    /// (webhook gone or no longer active, event no longer logged).
+    async fn retry(&self, prev: &OutboundWebhookDelivery) -> Result<bool, OutboundWebhookError> {
+        let Some(event_id) = prev.event_id else {
+            return Ok(false);
+        };
+        let Some(webhook) = repo::get_by_id(&self.pool, &prev.tenant_id, prev.webhook_id).await?
+        else {
+            return Ok(false);
+        };
+        if webhook.status != "active" {
+            tracing::info!(
+                webhook_id = %webhook.id,
+                status = %webhook.status,
+                "outbound_webhooks: retry dropped, webhook not active"
+            );
+            return Ok(false);
+        }
+        let Some(event) = repo::get_event(&self.pool, &prev.tenant_id, event_id).await? else {
+            return Ok(false);
+        };
+        self.attempt(
+            &webhook,
+            &event,
+            prev.attempt_number + 1,
+            prev.is_redelivery,
+        )
+        .await?;
+        Ok(true)
+    }
+
+    /// Record a first attempt that failed inside the dispatcher (before a
+    /// delivery row was written) as a failed delivery with a retry due.
+    async fn record_dispatch_error(
+        &self,
+        webhook: &OutboundWebhook,
+        event: &DispatchedEvent,
+        error: &OutboundWebhookError,
+    ) {
+        tracing::error!(
+            webhook_id = %webhook.id,
+            event_id = %event.event_id,
+            error = %error,
+            "outbound_webhooks: dispatch failed, retry scheduled"
+        );
+        let ctx = AttemptCtx {
+            webhook,
+            event,
+            number: 1,
+            is_redelivery: false,
+        };
+        let outcome = AttemptOutcome::Failed {
+            error: format!("dispatch error: {}", error),
+            response: None,
+        };
+        match self.record(&ctx, outcome).await {
+            Ok(_) | Err(OutboundWebhookError::DuplicateIdempotencyKey(_)) => {}
+            Err(e) => tracing::error!(
+                webhook_id = %webhook.id,
+                event_id = %event.event_id,
+                error = %e,
+                "outbound_webhooks: could not record failed dispatch"
+            ),
+        }
+    }
+
+    /// Sign, send and record one attempt; schedules the retry or disables the
+    /// webhook on failure. An `Err` means no delivery row was written.
+    async fn attempt(
+        &self,
+        webhook: &OutboundWebhook,
+        event: &DispatchedEvent,
+        number: i32,
+        is_redelivery: bool,
+    ) -> Result<OutboundWebhookDelivery, OutboundWebhookError> {
+        let ctx = AttemptCtx {
+            webhook,
+            event,
+            number,
+            is_redelivery,
+        };
+        let secret =
+            match repo::get_signing_secret_enc(&self.pool, &webhook.tenant_id, webhook.id).await? {
+                Some(enc) => decrypt_token(&self.secrets_key, &enc).ok(),
+                None => None,
+            };
+        let Some(secret) = secret else {
+            return self.record(&ctx, AttemptOutcome::Unsigned).await;
+        };
+
+        let body = serde_json::to_vec(&event.envelope).unwrap_or_default();
+        let timestamp = Utc::now().timestamp();
+        let headers = [
+            (
+                signing::HEADER_SIGNATURE,
+                signing::signature_header(&secret, timestamp, &body),
+            ),
+            (signing::HEADER_TIMESTAMP, timestamp.to_string()),
+            (signing::HEADER_WEBHOOK_ID, webhook.id.to_string()),
+            (signing::HEADER_EVENT_ID, event.event_id.to_string()),
+            (signing::HEADER_EVENT_TYPE, event.event_type.clone()),
+            (signing::HEADER_ATTEMPT, number.to_string()),
+        ];
+        let outcome = match self.sender.post(&webhook.url, &headers, body).await {
+            Ok(resp) if (200..300).contains(&resp.status) => AttemptOutcome::Delivered(resp),
+            Ok(resp) => AttemptOutcome::Failed {
+                error: format!("receiver returned HTTP {}", resp.status),
+                response: Some(resp),
+            },
+            Err(error) => AttemptOutcome::Failed {
+                error,
+                response: None,
+            },
+        };
+        let delivered = matches!(outcome, AttemptOutcome::Delivered(_));
+
+        let delivery = self.record(&ctx, outcome).await?;
+        // The attempt is recorded; failure bookkeeping errors must not make
+        // the caller treat it as unsent.
+        if let Err(e) = self.track_failures(webhook, delivered).await {
+            tracing::error!(
+                webhook_id = %webhook.id,
+                error = %e,
+                "outbound_webhooks: failure counter update failed"
+            );
+        }
+        Ok(delivery)
+    }
+
+    async fn track_failures(
+        &self,
+        webhook: &OutboundWebhook,
+        delivered: bool,
+    ) -> Result<(), OutboundWebhookError> {
+        if delivered {
+            repo::reset_failures(&self.pool, webhook.id).await?;
+        } else {
+            let failures = repo::increment_failures(&self.pool, webhook.id).await?;
+            if failures >= DISABLE_AFTER_CONSECUTIVE_FAILURES {
+                self.disable(webhook, failures).await?;
+            }
+        }
+        Ok(())
+    }
+
+    async fn record(
+        &self,
+        ctx: &AttemptCtx<'_>,
+        outcome: AttemptOutcome,
+    ) -> Result<OutboundWebhookDelivery, OutboundWebhookError> {
+        let now = Utc::now();
+        let retry_at = || (ctx.number < MAX_ATTEMPTS).then(|| now + backoff_delay(ctx.number));
+        let (response, error_message, next_retry_at, delivered_at) = match outcome {
+            AttemptOutcome::Delivered(resp) => (Some(resp), None, None, Some(now)),
+            AttemptOutcome::Failed { error, response } => (response, Some(error), retry_at(), None),
+            // Not retryable until the secret is rotated; redeliver afterwards.
+            AttemptOutcome::Unsigned => (
+                None,
+                Some("signing secret unavailable — rotate the webhook secret".to_string()),
+                None,
+                None,
+            ),
+        };
+        let (status_code, response_body) = match response {
+            Some(r) => (
+                Some(r.status as i32),
+                Some(r.body.chars().take(RESPONSE_BODY_LIMIT).collect()),
+            ),
+            None => (None, None),
+        };
+        let req = RecordDeliveryRequest {
+            webhook_id: ctx.webhook.id,
+            tenant_id: ctx.webhook.tenant_id.clone(),
+            event_type: ctx.event.event_type.clone(),
+            payload: ctx.event.envelope.clone(),
+            status_code,
+            response_body,
+            error_message,
+            attempt_number: ctx.number,
+            next_retry_at,
+            delivered_at,
+            event_id: Some(ctx.event.event_id),
+            is_redelivery: ctx.is_redelivery,
+        };
+        match repo::insert_delivery(&self.pool, &req).await {
+            Ok(d) => Ok(d),
+            Err(sqlx::Error::Database(db)) if db.is_unique_violation() => {
+                // Another dispatcher instance already made this first attempt.
+                Err(OutboundWebhookError::DuplicateIdempotencyKey(format!(
+                    "{}:{}",
+                    ctx.webhook.id, ctx.event.event_id
+                )))
+            }
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    async fn disable(
+        &self,
+        webhook: &OutboundWebhook,
+        failures: i32,
+    ) -> Result<(), OutboundWebhookError> {
+        let reason = format!("{} consecutive failed deliveries", failures);
+        let mut tx = self.pool.begin().await?;
+        let Some(disabled) = repo::disable_active(&mut tx, webhook.id, &reason).await? else {
+            tx.rollback().await?;
+            return Ok(());
+        };
+
+        let event_id = Uuid::new_v4();
+        let envelope = build_outbound_webhook_updated_envelope(
+            event_id,
+            disabled.tenant_id.clone(),
+            Uuid::new_v4().to_string(),
+            None,
+            OutboundWebhookUpdatedPayload {
+                webhook_id: disabled.id,
+                tenant_id: disabled.tenant_id.clone(),
+                url: disabled.url.clone(),
+                status: disabled.status.clone(),
+            },
+        );
+        enqueue_event_tx(
+            &mut tx,
+            event_id,
+            EVENT_TYPE_OUTBOUND_WEBHOOK_UPDATED,
+            "outbound_webhook",
+            &disabled.id.to_string(),
+            &disabled.tenant_id,
+            &envelope,
+        )
+        .await?;
+        tx.commit().await?;
+
+        tracing::warn!(
+            webhook_id = %disabled.id,
+            tenant_id = %disabled.tenant_id,
+            failures,
+            "outbound_webhooks: webhook auto-disabled after sustained failures"
+        );
+        Ok(())
+    }
+}
+
+// ── Workers ───────────────────────────────────────────────────────────────────
+
+/// Resolves once `true` is sent. A dropped sender never resolves, so workers
+/// keep running when the caller does not hold on to the shutdown handle.
+async fn shutdown_signal(mut shutdown_rx: watch::Receiver<bool>) {
+    if shutdown_rx.wait_for(|stop| *stop).await.is_err() {
+        std::future::pending::<()>().await;
+    }
+}
+
+/// Subscribe to `subjects` and dispatch every message until shutdown.
+pub fn spawn_dispatcher(
+    dispatcher: Arc<Dispatcher>,
+    bus: Arc<dyn EventBus>,
+    subjects: Vec<String>,
+    shutdown_rx: watch::Receiver<bool>,
+) -> tokio::task::JoinHandle<()> {
+    tokio::spawn(async move {
+        let mut streams = Vec::with_capacity(subjects.len());
+        for subject in &subjects {
+            match bus.subscribe(subject).await {
+                Ok(s) => streams.push(s),
+                Err(e) => tracing::error!(
+                    error = %e,
+                    subject = %subject,
+                    "outbound_webhooks: failed to subscribe"
+                ),
+            }
+        }
+        if streams.is_empty() {
+            tracing::error!("outbound_webhooks: no subscriptions — dispatcher not started");
+            return;
+        }
+        let mut stream = futures::stream::select_all(streams);
+
+        tracing::info!(subjects = ?subjects, "outbound_webhooks: dispatcher started");
+
+        let shutdown = shutdown_signal(shutdown_rx);
+        tokio::pin!(shutdown);
+        loop {
+            tokio::select! {
+                maybe_msg = stream.next() => {
+                    match maybe_msg {
+                        Some(msg) => {
+                            if let Err(e) = dispatcher.handle_message(&msg).await {
+                                tracing::error!(
+                                    error = %e,
+                                    subject = %msg.subject,
+                                    "outbound_webhooks: dispatch failed"
+                                );
+                            }
+                        }
+                        None => {
+                            tracing::warn!("outbound_webhooks: event stream ended");
+                            break;
+                        }
+                    }
+                }
+                _ = &mut shutdown => {
+                    tracing::info!("outbound_webhooks: dispatcher shutting down");
+                    break;
+                }
+            }
+        }
+    })
+}
+
+/// Poll for due retries every `interval` until shutdown.
+pub fn spawn_retry_worker(
+    dispatcher: Arc<Dispatcher>,
+    interval: std::time::Duration,
+    shutdown_rx: watch::Receiver<bool>,
+) -> tokio::task::JoinHandle<()> {
+    tokio::spawn(async move {
+        let mut ticker = tokio::time::interval(interval);
+        let shutdown = shutdown_signal(shutdown_rx);
+        tokio::pin!(shutdown);
+        loop {
+            tokio::select! {
+                _ = ticker.tick() => {
+                    match dispatcher.process_due_retries().await {
+                        Ok(0) => {}
+                        Ok(n) => tracing::info!(attempts = n, "outbound_webhooks: retries sent"),
+                        Err(e) => tracing::error!(error = %e, "outbound_webhooks: retry tick failed"),
+                    }
+                }
+                _ = &mut shutdown => {
+                    tracing::info!("outbound_webhooks: retry worker shutting down");
+                    break;
+                }
+            }
+        }
+    })
+}
+
+// ============================================================================
+// Tests (unit-level — no DB required)
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn webhook(event_types: serde_json::Value) -> OutboundWebhook {
+        OutboundWebhook {
+            id: Uuid::new_v4(),
+            tenant_id: "t1".into(),
+            url: "https://example.com/hook".into(),
+            event_types,
+            signing_secret_hash: String::new(),
+            status: "active".into(),
+            idempotency_key: None,
+            description: None,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+            deleted_at: None,
+            consecutive_failures: 0,
+            last_failure_at: None,
+            disabled_reason: None,
+        }
+    }
+
+    #[test]
+    fn wildcard_matching() {
+        assert!(event_type_matches(
+            "ar.events.ar.invoice_opened",
+            "ar.events.ar.invoice_opened"
+        ));
+        assert!(event_type_matches(
+            "ar.events.ar.*",
+            "ar.events.ar.invoice_paid"
+        ));
+        assert!(event_type_matches(
+            "sales_orders.>",
+            "sales_orders.order_shipped"
+        ));
+        assert!(!event_type_matches("sales_orders.>", "sales_orders"));
+        assert!(!event_type_matches("ar.*", "ar.events.ar.invoice_paid"));
+        assert!(!event_type_matches(
+            "ar.events.ar.invoice_paid",
+            "ar.events.ar.invoice_opened"
+        ));
+    }
+
+    #[test]
+    fn subscription_filter() {
+        let all = webhook(serde_json::json!([]));
+        assert!(webhook_subscribes(
+            &all,
+            "sales_orders.order_shipped",
+            "order_shipped"
+        ));
+
+        let invoices = webhook(serde_json::json!([
+            "ar.events.ar.invoice_*",
+            "invoice.paid"
+        ]));
+        // Patterns are token-wise; a partial token is a literal.
+        assert!(!webhook_subscribes(
+            &invoices,
+            "ar.events.ar.invoice_opened",
+            "x"
+        ));
+        assert!(webhook_subscribes(
+            &invoices,
+            "ar.events.ar.invoice_paid",
+            "invoice.paid"
+        ));
+
+        let shipments = webhook(serde_json::json!(["shipping_receiving.outbound_shipped"]));
+        assert!(webhook_subscribes(
+            &shipments,
+            "shipping_receiving.outbound_shipped",
+            "x"
+        ));
+        assert!(!webhook_subscribes(
+            &shipments,
+            "sales_orders.order_shipped",
+            "x"
+        ));
+    }
+
+    #[test]
+    fn backoff_doubles_then_caps() {
+        assert_eq!(backoff_delay(1), Duration::seconds(30));
+        assert_eq!(backoff_delay(2), Duration::seconds(60));
+        assert_eq!(backoff_delay(4), Duration::seconds(240));
+        assert_eq!(backoff_delay(30), Duration::seconds(MAX_BACKOFF_SECS));
+    }
+
+    #[test]
+    fn message_requires_event_id_and_tenant() {
+        let id = Uuid::new_v4();
+        let ok = BusMessage::new(
+            "sales_orders.order_shipped".into(),
+            serde_json::to_vec(&serde_json::json!({
+                "event_id": id, "tenant_id": "t1", "payload": {}
+            }))
+            .unwrap(),
+        );
+        let e = event_from_message(&ok, Utc::now()).expect("envelope");
+        assert_eq!(e.event_id, id);
+        assert_eq!(e.event_type, "sales_orders.order_shipped");
+
+        let no_tenant = BusMessage::new(
+            "sales_orders.order_shipped".into(),
+            serde_json::to_vec(&serde_json::json!({ "event_id": id })).unwrap(),
+        );
+        assert!(event_from_message(&no_tenant, Utc::now()).is_none());
+        let garbage = BusMessage::new("x".into(), b"not json".to_vec());
+        assert!(event_from_message(&garbage, Utc::now()).is_none());
+    }
+}
@@ -1,13 +1,18 @@
 //! Outbound webhook management — tenant-scoped webhook subscriptions,
-//! delivery audit logging, and signing secret lifecycle.
+//! delivery audit logging, signing secret lifecycle, and the dispatcher that
+//! pushes signed platform events to subscriber URLs.
 
+pub mod dispatcher;
 pub mod guards;
 pub mod models;
 pub mod repo;
 pub mod service;
+pub mod signing;
 
+pub use dispatcher::{Dispatcher, HttpWebhookSender, SendResponse, WebhookSender};
 pub use models::{
-    CreateOutboundWebhookRequest, OutboundWebhook, OutboundWebhookDelivery, OutboundWebhookError,
-    RecordDeliveryRequest, UpdateOutboundWebhookRequest,
+    CreateOutboundWebhookRequest, DispatchedEvent, OutboundWebhook, OutboundWebhookDelivery,
+    OutboundWebhookError, RecordDeliveryRequest, RedeliverRequest, RotatedSecret,
+    UpdateOutboundWebhookRequest,
 };
 pub use service::OutboundWebhookService;
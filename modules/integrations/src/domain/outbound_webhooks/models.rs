@@ -3,10 +3,11 @@
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
+use utoipa::ToSchema;
 use uuid::Uuid;
 
 /// Outbound webhook subscription row.
-#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow, ToSchema)]
 pub struct OutboundWebhook {
     pub id: Uuid,
     pub tenant_id: String,
@@ -19,10 +20,15 @@ pub struct OutboundWebhook {
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
     pub deleted_at: Option<DateTime<Utc>>,
+    /// Failed delivery attempts since the last success.
+    pub consecutive_failures: i32,
+    pub last_failure_at: Option<DateTime<Utc>>,
+    /// Set when the dispatcher auto-disables the webhook.
+    pub disabled_reason: Option<String>,
 }
 
 /// Delivery attempt audit record.
-#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow, ToSchema)]
 pub struct OutboundWebhookDelivery {
     pub id: Uuid,
     pub webhook_id: Uuid,
@@ -36,6 +42,20 @@ pub struct OutboundWebhookDelivery {
     pub next_retry_at: Option<DateTime<Utc>>,
     pub delivered_at: Option<DateTime<Utc>>,
     pub created_at: DateTime<Utc>,
+    /// Platform event this attempt delivered (None for manually logged rows).
+    pub event_id: Option<Uuid>,
+    pub is_redelivery: bool,
+}
+
+/// Platform event fanned out by the dispatcher, kept for redelivery.
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow, ToSchema)]
+pub struct DispatchedEvent {
+    pub event_id: Uuid,
+    pub tenant_id: String,
+    pub subject: String,
+    pub event_type: String,
+    pub envelope: serde_json::Value,
+    pub received_at: DateTime<Utc>,
 }
 
 // ============================================================================
@@ -73,6 +93,21 @@ pub struct RecordDeliveryRequest {
     pub attempt_number: i32,
     pub next_retry_at: Option<DateTime<Utc>>,
     pub delivered_at: Option<DateTime<Utc>>,
+    pub event_id: Option<Uuid>,
+    pub is_redelivery: bool,
+}
+
+/// Manual redelivery of a past event to one webhook.
+#[derive(Debug, Clone, Deserialize, ToSchema)]
+pub struct RedeliverRequest {
+    pub event_id: Uuid,
+}
+
+/// Response for secret rotation — the raw secret is shown once.
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct RotatedSecret {
+    pub webhook_id: Uuid,
+    pub signing_secret: String,
 }
 
 // ============================================================================
@@ -90,6 +125,12 @@ pub enum OutboundWebhookError {
     #[error("Duplicate idempotency key: {0}")]
     DuplicateIdempotencyKey(String),
 
+    #[error("Event not found")]
+    EventNotFound,
+
+    #[error("Signing key not configured")]
+    SigningKeyUnavailable,
+
     #[error("Database error: {0}")]
     Database(#[from] sqlx::Error),
 }
@@ -1,9 +1,10 @@
 //! Repository layer for outbound webhook persistence.
 
+use chrono::{DateTime, Utc};
 use sqlx::PgPool;
 use uuid::Uuid;
 
-use super::models::{OutboundWebhook, OutboundWebhookDelivery};
+use super::models::{DispatchedEvent, OutboundWebhook, OutboundWebhookDelivery};
 
 pub async fn get_by_id(
     pool: &PgPool,
@@ -12,7 +13,8 @@ pub async fn get_by_id(
 ) -> Result<Option<OutboundWebhook>, sqlx::Error> {
     sqlx::query_as::<_, OutboundWebhook>(
         r#"SELECT id, tenant_id, url, event_types, signing_secret_hash,
-                  status, idempotency_key, description, created_at, updated_at, deleted_at
+                  status, idempotency_key, description, created_at, updated_at, deleted_at,
+                  consecutive_failures, last_failure_at, disabled_reason
            FROM integrations_outbound_webhooks
            WHERE id = $1 AND tenant_id = $2 AND deleted_at IS NULL"#,
     )
@@ -28,7 +30,8 @@ pub async fn list_by_tenant(
 ) -> Result<Vec<OutboundWebhook>, sqlx::Error> {
     sqlx::query_as::<_, OutboundWebhook>(
         r#"SELECT id, tenant_id, url, event_types, signing_secret_hash,
-                  status, idempotency_key, description, created_at, updated_at, deleted_at
+                  status, idempotency_key, description, created_at, updated_at, deleted_at,
+                  consecutive_failures, last_failure_at, disabled_reason
            FROM integrations_outbound_webhooks
            WHERE tenant_id = $1 AND deleted_at IS NULL
            ORDER BY created_at DESC"#,
@@ -45,7 +48,8 @@ pub async fn find_by_idempotency_key(
 ) -> Result<Option<OutboundWebhook>, sqlx::Error> {
     sqlx::query_as::<_, OutboundWebhook>(
         r#"SELECT id, tenant_id, url, event_types, signing_secret_hash,
-                  status, idempotency_key, description, created_at, updated_at, deleted_at
+                  status, idempotency_key, description, created_at, updated_at, deleted_at,
+                  consecutive_failures, last_failure_at, disabled_reason
            FROM integrations_outbound_webhooks
            WHERE tenant_id = $1 AND idempotency_key = $2 AND deleted_at IS NULL"#,
     )
@@ -71,7 +75,8 @@ pub async fn insert(
                 status, idempotency_key, description)
            VALUES ($1, $2, $3, $4, $5, 'active', $6, $7)
            RETURNING id, tenant_id, url, event_types, signing_secret_hash,
-                     status, idempotency_key, description, created_at, updated_at, deleted_at"#,
+                     status, idempotency_key, description, created_at, updated_at, deleted_at,
+                     consecutive_failures, last_failure_at, disabled_reason"#,
     )
     .bind(id)
     .bind(tenant_id)
@@ -91,7 +96,8 @@ pub async fn fetch_for_update(
 ) -> Result<Option<OutboundWebhook>, sqlx::Error> {
     sqlx::query_as::<_, OutboundWebhook>(
         r#"SELECT id, tenant_id, url, event_types, signing_secret_hash,
-                  status, idempotency_key, description, created_at, updated_at, deleted_at
+                  status, idempotency_key, description, created_at, updated_at, deleted_at,
+                  consecutive_failures, last_failure_at, disabled_reason
            FROM integrations_outbound_webhooks
            WHERE id = $1 AND tenant_id = $2 AND deleted_at IS NULL
            FOR UPDATE"#,
@@ -113,10 +119,14 @@ pub async fn update_fields(
 ) -> Result<OutboundWebhook, sqlx::Error> {
     sqlx::query_as::<_, OutboundWebhook>(
         r#"UPDATE integrations_outbound_webhooks
-           SET url = $1, event_types = $2, status = $3, description = $4, updated_at = NOW()
+           SET url = $1, event_types = $2, status = $3, description = $4, updated_at = NOW(),
+               consecutive_failures = CASE WHEN $3 = 'active' AND status <> 'active'
+                                           THEN 0 ELSE consecutive_failures END,
+               disabled_reason = CASE WHEN $3 = 'disabled' THEN disabled_reason END
            WHERE id = $5 AND tenant_id = $6 AND deleted_at IS NULL
            RETURNING id, tenant_id, url, event_types, signing_secret_hash,
-                     status, idempotency_key, description, created_at, updated_at, deleted_at"#,
+                     status, idempotency_key, description, created_at, updated_at, deleted_at,
+                     consecutive_failures, last_failure_at, disabled_reason"#,
     )
     .bind(url)
     .bind(event_types)
@@ -151,11 +161,12 @@ pub async fn insert_delivery(
     sqlx::query_as::<_, OutboundWebhookDelivery>(
         r#"INSERT INTO integrations_outbound_webhook_deliveries
                (webhook_id, tenant_id, event_type, payload, status_code,
-                response_body, error_message, attempt_number, next_retry_at, delivered_at)
-           VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)
+                response_body, error_message, attempt_number, next_retry_at, delivered_at,
+                event_id, is_redelivery)
+           VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12)
            RETURNING id, webhook_id, tenant_id, event_type, payload, status_code,
                      response_body, error_message, attempt_number, next_retry_at,
-                     delivered_at, created_at"#,
+                     delivered_at, created_at, event_id, is_redelivery"#,
     )
     .bind(req.webhook_id)
     .bind(&req.tenant_id)
@@ -167,6 +178,8 @@ pub async fn insert_delivery(
     .bind(req.attempt_number)
     .bind(req.next_retry_at)
     .bind(req.delivered_at)
+    .bind(req.event_id)
+    .bind(req.is_redelivery)
     .fetch_one(pool)
     .await
 }
@@ -179,7 +192,7 @@ pub async fn list_deliveries(
     sqlx::query_as::<_, OutboundWebhookDelivery>(
         r#"SELECT id, webhook_id, tenant_id, event_type, payload, status_code,
                   response_body, error_message, attempt_number, next_retry_at,
-                  delivered_at, created_at
+                  delivered_at, created_at, event_id, is_redelivery
            FROM integrations_outbound_webhook_deliveries
            WHERE webhook_id = $1 AND tenant_id = $2
            ORDER BY created_at DESC"#,
@@ -189,3 +202,185 @@ pub async fn list_deliveries(
     .fetch_all(pool)
     .await
 }
+
+// ============================================================================
+// Dispatch
+// ============================================================================
+
+pub async fn set_signing_secret(
+    tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+    webhook_id: Uuid,
+    tenant_id: &str,
+    secret_hash: &str,
+    secret_enc: &[u8],
+) -> Result<sqlx::postgres::PgQueryResult, sqlx::Error> {
+    sqlx::query(
+        r#"UPDATE integrations_outbound_webhooks
+           SET signing_secret_hash = $1, signing_secret_enc = $2, updated_at = NOW()
+           WHERE id = $3 AND tenant_id = $4 AND deleted_at IS NULL"#,
+    )
+    .bind(secret_hash)
+    .bind(secret_enc)
+    .bind(webhook_id)
+    .bind(tenant_id)
+    .execute(&mut **tx)
+    .await
+}
+
+pub async fn get_signing_secret_enc(
+    pool: &PgPool,
+    tenant_id: &str,
+    webhook_id: Uuid,
+) -> Result<Option<Vec<u8>>, sqlx::Error> {
+    let row: Option<(Option<Vec<u8>>,)> = sqlx::query_as(
+        r#"SELECT signing_secret_enc FROM integrations_outbound_webhooks
+           WHERE id = $1 AND tenant_id = $2"#,
+    )
+    .bind(webhook_id)
+    .bind(tenant_id)
+    .fetch_optional(pool)
+    .await?;
+    Ok(row.and_then(|(enc,)| enc))
+}
+
+pub async fn list_active_by_tenant(
+    pool: &PgPool,
+    tenant_id: &str,
+) -> Result<Vec<OutboundWebhook>, sqlx::Error> {
+    sqlx::query_as::<_, OutboundWebhook>(
+        r#"SELECT id, tenant_id, url, event_types, signing_secret_hash,
+                  status, idempotency_key, description, created_at, updated_at, deleted_at,
+                  consecutive_failures, last_failure_at, disabled_reason
+           FROM integrations_outbound_webhooks
+           WHERE tenant_id = $1 AND status = 'active' AND deleted_at IS NULL
+           ORDER BY created_at"#,
+    )
+    .bind(tenant_id)
+    .fetch_all(pool)
+    .await
+}
+
+/// Log a dispatched event. Returns false when the event was already logged
+/// (bus redelivery), so the caller skips the fan-out.
+pub async fn insert_event(pool: &PgPool, event: &DispatchedEvent) -> Result<bool, sqlx::Error> {
+    let result = sqlx::query(
+        r#"INSERT INTO integrations_outbound_webhook_events
+               (event_id, tenant_id, subject, event_type, envelope, received_at)
+           VALUES ($1, $2, $3, $4, $5, $6)
+           ON CONFLICT (event_id) DO NOTHING"#,
+    )
+    .bind(event.event_id)
+    .bind(&event.tenant_id)
+    .bind(&event.subject)
+    .bind(&event.event_type)
+    .bind(&event.envelope)
+    .bind(event.received_at)
+    .execute(pool)
+    .await?;
+    Ok(result.rows_affected() == 1)
+}
+
+pub async fn get_event(
+    pool: &PgPool,
+    tenant_id: &str,
+    event_id: Uuid,
+) -> Result<Option<DispatchedEvent>, sqlx::Error> {
+    sqlx::query_as::<_, DispatchedEvent>(
+        r#"SELECT event_id, tenant_id, subject, event_type, envelope, received_at
+           FROM integrations_outbound_webhook_events
+           WHERE event_id = $1 AND tenant_id = $2"#,
+    )
+    .bind(event_id)
+    .bind(tenant_id)
+    .fetch_optional(pool)
+    .await
+}
+
+/// Claim failed attempts whose retry is due, clearing `next_retry_at` so no
+/// other worker retries the same attempt.
+pub async fn claim_due_retries(
+    pool: &PgPool,
+    limit: i64,
+) -> Result<Vec<OutboundWebhookDelivery>, sqlx::Error> {
+    sqlx::query_as::<_, OutboundWebhookDelivery>(
+        r#"UPDATE integrations_outbound_webhook_deliveries
+           SET next_retry_at = NULL
+           WHERE id IN (
+               SELECT id FROM integrations_outbound_webhook_deliveries
+               WHERE next_retry_at IS NOT NULL AND next_retry_at <= NOW()
+               ORDER BY next_retry_at
+               LIMIT $1
+               FOR UPDATE SKIP LOCKED
+           )
+           RETURNING id, webhook_id, tenant_id, event_type, payload, status_code,
+                     response_body, error_message, attempt_number, next_retry_at,
+                     delivered_at, created_at, event_id, is_redelivery"#,
+    )
+    .bind(limit)
+    .fetch_all(pool)
+    .await
+}
+
+/// Put a claimed attempt back on the retry schedule.
+pub async fn reschedule_retry(
+    pool: &PgPool,
+    delivery_id: Uuid,
+    next_retry_at: DateTime<Utc>,
+) -> Result<(), sqlx::Error> {
+    sqlx::query(
+        r#"UPDATE integrations_outbound_webhook_deliveries
+           SET next_retry_at = $2
+           WHERE id = $1 AND delivered_at IS NULL"#,
+    )
+    .bind(delivery_id)
+    .bind(next_retry_at)
+    .execute(pool)
+    .await?;
+    Ok(())
+}
+
+pub async fn reset_failures(pool: &PgPool, webhook_id: Uuid) -> Result<(), sqlx::Error> {
+    sqlx::query(
+        r#"UPDATE integrations_outbound_webhooks
+           SET consecutive_failures = 0
+           WHERE id = $1 AND consecutive_failures <> 0"#,
+    )
+    .bind(webhook_id)
+    .execute(pool)
+    .await?;
+    Ok(())
+}
+
+/// Count a failed attempt; returns the new consecutive failure count.
+pub async fn increment_failures(pool: &PgPool, webhook_id: Uuid) -> Result<i32, sqlx::Error> {
+    let (count,): (i32,) = sqlx::query_as(
+        r#"UPDATE integrations_outbound_webhooks
+           SET consecutive_failures = consecutive_failures + 1, last_failure_at = NOW()
+           WHERE id = $1
+           RETURNING consecutive_failures"#,
+    )
+    .bind(webhook_id)
+    .fetch_one(pool)
+    .await?;
+    Ok(count)
+}
+
+/// Disable an active webhook. Returns None when it was no longer active.
+pub async fn disable_active(
+    tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+    webhook_id: Uuid,
+    reason: &str,
+) -> Result<Option<OutboundWebhook>, sqlx::Error> {
+    sqlx::query_as::<_, OutboundWebhook>(
+        r#"UPDATE integrations_outbound_webhooks
+           SET status = 'disabled', disabled_reason = $2, updated_at = NOW()
+           WHERE id = $1 AND status = 'active' AND deleted_at IS NULL
+           RETURNING id, tenant_id, url, event_types, signing_secret_hash,
+                     status, idempotency_key, description, created_at, updated_at, deleted_at,
+                     consecutive_failures, last_failure_at, disabled_reason"#,
+    )
+    .bind(webhook_id)
+    .bind(reason)
+    .fetch_optional(&mut **tx)
+    .await
+}
@@ -2,11 +2,16 @@
 //!
 //! CRUD operations for tenant-scoped outbound webhooks, plus delivery logging.
 //! Every mutation emits an event through the outbox for downstream consumers.
+//!
+//! With a secrets key ([`OutboundWebhookService::with_secrets_key`]) the
+//! signing secret is also stored AES-256-GCM encrypted so the dispatcher can
+//! sign deliveries; without one only the hash is kept.
 
 use sha2::{Digest, Sha256};
 use sqlx::PgPool;
 use uuid::Uuid;
 
+use crate::domain::webhooks::secret_store::encrypt_token;
 use crate::events::{
     build_outbound_webhook_created_envelope, build_outbound_webhook_deleted_envelope,
     build_outbound_webhook_updated_envelope, OutboundWebhookCreatedPayload,
@@ -25,11 +30,21 @@ use super::repo;
 
 pub struct OutboundWebhookService {
     pool: PgPool,
+    secrets_key: Option<[u8; 32]>,
 }
 
 impl OutboundWebhookService {
     pub fn new(pool: PgPool) -> Self {
-        Self { pool }
+        Self {
+            pool,
+            secrets_key: None,
+        }
+    }
+
+    /// Store signing secrets encrypted under INTEGRATIONS_SECRETS_KEY.
+    pub fn with_secrets_key(mut self, key: [u8; 32]) -> Self {
+        self.secrets_key = Some(key);
+        self
     }
 
     // ========================================================================
@@ -100,6 +115,18 @@ impl OutboundWebhookService {
         )
         .await?;
 
+        if let Some(key) = self.secrets_key {
+            let secret_enc = encrypt_token(&key, &raw_secret);
+            repo::set_signing_secret(
+                &mut tx,
+                webhook.id,
+                &req.tenant_id,
+                &secret_hash,
+                &secret_enc,
+            )
+            .await?;
+        }
+
         // ── Outbox ──────────────────────────────────────────────────────
         let event_id = Uuid::new_v4();
         let correlation_id = Uuid::new_v4().to_string();
@@ -263,6 +290,66 @@ impl OutboundWebhookService {
         Ok(())
     }
 
+    // ========================================================================
+    // Secret rotation — Mutation → Outbox
+    // ========================================================================
+
+    /// Replace the signing secret. Returns the new raw secret (shown once).
+    /// Requires a secrets key so the dispatcher can sign with it.
+    pub async fn rotate_secret(
+        &self,
+        tenant_id: &str,
+        webhook_id: Uuid,
+    ) -> Result<String, OutboundWebhookError> {
+        let key = self
+            .secrets_key
+            .ok_or(OutboundWebhookError::SigningKeyUnavailable)?;
+
+        let raw_secret = format!("whsec_{}", Uuid::new_v4().as_simple());
+        let secret_hash = hex::encode(Sha256::digest(raw_secret.as_bytes()));
+        let secret_enc = encrypt_token(&key, &raw_secret);
+
+        let mut tx = self.pool.begin().await?;
+
+        let existing = repo::fetch_for_update(&mut tx, webhook_id, tenant_id)
+            .await?
+            .ok_or(OutboundWebhookError::NotFound)?;
+
+        repo::set_signing_secret(&mut tx, webhook_id, tenant_id, &secret_hash, &secret_enc).await?;
+
+        // ── Outbox ──────────────────────────────────────────────────────
+        let event_id = Uuid::new_v4();
+        let correlation_id = Uuid::new_v4().to_string();
+
+        let envelope = build_outbound_webhook_updated_envelope(
+            event_id,
+            tenant_id.to_string(),
+            correlation_id,
+            None,
+            OutboundWebhookUpdatedPayload {
+                webhook_id,
+                tenant_id: tenant_id.to_string(),
+                url: existing.url.clone(),
+                status: existing.status.clone(),
+            },
+        );
+
+        enqueue_event_tx(
+            &mut tx,
+            event_id,
+            EVENT_TYPE_OUTBOUND_WEBHOOK_UPDATED,
+            "outbound_webhook",
+            &webhook_id.to_string(),
+            tenant_id,
+            &envelope,
+        )
+        .await?;
+
+        tx.commit().await?;
+
+        Ok(raw_secret)
+    }
+
     // ========================================================================
     // Delivery logging
     // ========================================================================
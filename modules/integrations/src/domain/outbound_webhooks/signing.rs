@@ -0,0 +1,130 @@
+//! Outbound webhook signing — HMAC-SHA256 over `"{timestamp}.{body}"`.
+//!
+//! Receivers recompute the MAC with their signing secret and compare it to
+//! the `v1` value in `X-Webhook-Signature`. The timestamp is part of the
+//! signed content so a captured request cannot be replayed with a new one;
+//! receivers should also reject timestamps outside their tolerance window.
+
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+
+type HmacSha256 = Hmac<Sha256>;
+
+/// `t={unix_seconds},v1={hex_hmac}`
+pub const HEADER_SIGNATURE: &str = "X-Webhook-Signature";
+/// Unix seconds at signing time (also embedded in the signature header).
+pub const HEADER_TIMESTAMP: &str = "X-Webhook-Timestamp";
+pub const HEADER_WEBHOOK_ID: &str = "X-Webhook-Id";
+pub const HEADER_EVENT_ID: &str = "X-Webhook-Event-Id";
+pub const HEADER_EVENT_TYPE: &str = "X-Webhook-Event-Type";
+pub const HEADER_ATTEMPT: &str = "X-Webhook-Attempt";
+
+/// Hex HMAC-SHA256 of `"{timestamp}.{body}"` under `secret`.
+pub fn compute_signature(secret: &str, timestamp: i64, body: &[u8]) -> String {
+    let mut mac = HmacSha256::new_from_slice(secret.as_bytes()).expect("HMAC accepts any key size");
+    mac.update(timestamp.to_string().as_bytes());
+    mac.update(b".");
+    mac.update(body);
+    hex::encode(mac.finalize().into_bytes())
+}
+
+/// Value for the `X-Webhook-Signature` header.
+pub fn signature_header(secret: &str, timestamp: i64, body: &[u8]) -> String {
+    format!(
+        "t={},v1={}",
+        timestamp,
+        compute_signature(secret, timestamp, body)
+    )
+}
+
+/// Verify a signature header against `body`, rejecting timestamps more than
+/// `tolerance_secs` away from `now`. Reference implementation for receivers.
+pub fn verify_signature_header(
+    secret: &str,
+    header: &str,
+    body: &[u8],
+    now: i64,
+    tolerance_secs: i64,
+) -> bool {
+    let mut timestamp = None;
+    let mut v1 = None;
+    for part in header.split(',') {
+        match part.trim().split_once('=') {
+            Some(("t", t)) => timestamp = t.parse::<i64>().ok(),
+            Some(("v1", s)) => v1 = hex::decode(s).ok(),
+            _ => {}
+        }
+    }
+    let (Some(ts), Some(sig)) = (timestamp, v1) else {
+        return false;
+    };
+    if (now - ts).abs() > tolerance_secs {
+        return false;
+    }
+    let mut mac = HmacSha256::new_from_slice(secret.as_bytes()).expect("HMAC accepts any key size");
+    mac.update(ts.to_string().as_bytes());
+    mac.update(b".");
+    mac.update(body);
+    mac.verify_slice(&sig).is_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SECRET: &str = "whsec_test";
+
+    #[test]
+    fn header_round_trips() {
+        let body = br#"{"event_id":"e1"}"#;
+        let header = signature_header(SECRET, 1_700_000_000, body);
+        assert!(header.starts_with("t=1700000000,v1="));
+        assert!(verify_signature_header(
+            SECRET,
+            &header,
+            body,
+            1_700_000_060,
+            300
+        ));
+    }
+
+    #[test]
+    fn tampered_body_or_wrong_secret_fails() {
+        let body = br#"{"amount":100}"#;
+        let header = signature_header(SECRET, 1_700_000_000, body);
+        assert!(!verify_signature_header(
+            SECRET,
+            &header,
+            br#"{"amount":999}"#,
+            1_700_000_000,
+            300
+        ));
+        assert!(!verify_signature_header(
+            "whsec_other",
+            &header,
+            body,
+            1_700_000_000,
+            300
+        ));
+    }
+
+    #[test]
+    fn stale_timestamp_fails() {
+        let body = b"{}";
+        let header = signature_header(SECRET, 1_700_000_000, body);
+        assert!(!verify_signature_header(
+            SECRET,
+            &header,
+            body,
+            1_700_000_301,
+            300
+        ));
+        assert!(!verify_signature_header(
+            SECRET,
+            "v1=abcd",
+            body,
+            1_700_000_000,
+            300
+        ));
+    }
+}
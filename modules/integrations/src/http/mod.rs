@@ -4,6 +4,7 @@ pub mod oauth_validation;
 pub mod external_refs;
 pub mod internal;
 pub mod oauth;
+pub mod outbound_webhooks;
 pub mod qbo_invoice;
 pub mod qbo_settings;
 pub mod qbo_taxrate;
@@ -87,6 +88,15 @@ pub fn router(state: Arc<AppState>) -> Router {
             "/api/integrations/carriers/{carrier_type}/credentials",
             post(carrier_credentials::set_carrier_credentials),
         )
+        // Outbound webhooks — redelivery and secret rotation
+        .route(
+            "/api/integrations/outbound-webhooks/{id}/redeliver",
+            post(outbound_webhooks::redeliver),
+        )
+        .route(
+            "/api/integrations/outbound-webhooks/{id}/rotate-secret",
+            post(outbound_webhooks::rotate_secret),
+        )
         .route_layer(RequirePermissionsLayer::new(&[
             permissions::INTEGRATIONS_MUTATE,
         ]))
@@ -139,6 +149,11 @@ pub fn router(state: Arc<AppState>) -> Router {
             "/api/integrations/carriers/{carrier_type}/credentials/status",
             get(carrier_credentials::carrier_credentials_status),
         )
+        // Outbound webhooks — delivery log
+        .route(
+            "/api/integrations/outbound-webhooks/{id}/deliveries",
+            get(outbound_webhooks::list_deliveries),
+        )
         .route_layer(RequirePermissionsLayer::new(&[
             permissions::INTEGRATIONS_READ,
         ]))
@@ -0,0 +1,156 @@
+//! HTTP handlers for outbound webhook delivery operations.
+//!
+//! Routes:
+//!   GET  /api/integrations/outbound-webhooks/{id}/deliveries    — delivery attempts (READ)
+//!   POST /api/integrations/outbound-webhooks/{id}/redeliver     — re-send a past event (MUTATE)
+//!   POST /api/integrations/outbound-webhooks/{id}/rotate-secret — new signing secret (MUTATE)
+//!
+//! Tenant identity derived from JWT `VerifiedClaims`.
+
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::IntoResponse,
+    Extension, Json,
+};
+use platform_http_contracts::{ApiError, PaginatedResponse};
+use security::VerifiedClaims;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::domain::outbound_webhooks::{
+    Dispatcher, HttpWebhookSender, OutboundWebhookDelivery, OutboundWebhookError,
+    OutboundWebhookService, RedeliverRequest, RotatedSecret,
+};
+use crate::AppState;
+use platform_sdk::extract_tenant;
+
+// ============================================================================
+// Helpers
+// ============================================================================
+
+fn outbound_webhook_error(e: OutboundWebhookError) -> ApiError {
+    match e {
+        OutboundWebhookError::NotFound => ApiError::not_found("Outbound webhook not found"),
+        OutboundWebhookError::EventNotFound => ApiError::not_found("Event not found"),
+        OutboundWebhookError::Validation(msg) => ApiError::new(422, "validation_error", msg),
+        OutboundWebhookError::DuplicateIdempotencyKey(msg) => ApiError::conflict(msg),
+        OutboundWebhookError::SigningKeyUnavailable => {
+            ApiError::internal("Signing key not configured")
+        }
+        OutboundWebhookError::Database(e) => {
+            tracing::error!(error = %e, "Outbound webhook DB error");
+            ApiError::internal("Internal database error")
+        }
+    }
+}
+
+// ============================================================================
+// Handlers
+// ============================================================================
+
+#[utoipa::path(
+    get,
+    path = "/api/integrations/outbound-webhooks/{id}/deliveries",
+    params(("id" = Uuid, Path, description = "Outbound webhook ID")),
+    responses(
+        (status = 200, description = "Delivery attempts, newest first", body = PaginatedResponse<OutboundWebhookDelivery>),
+        (status = 404, description = "Webhook not found"),
+    ),
+    security(("bearer" = [])),
+    tag = "Outbound Webhooks"
+)]
+pub async fn list_deliveries(
+    State(state): State<Arc<AppState>>,
+    claims: Option<Extension<VerifiedClaims>>,
+    Path(id): Path<Uuid>,
+) -> impl IntoResponse {
+    let app_id = match extract_tenant(&claims) {
+        Ok(id) => id,
+        Err(e) => return e.into_response(),
+    };
+    let svc = OutboundWebhookService::new(state.pool.clone());
+
+    match svc.get(&app_id, id).await {
+        Ok(Some(_)) => {}
+        Ok(None) => return outbound_webhook_error(OutboundWebhookError::NotFound).into_response(),
+        Err(e) => return outbound_webhook_error(e).into_response(),
+    }
+    match svc.list_deliveries(&app_id, id).await {
+        Ok(deliveries) => {
+            let total = deliveries.len() as i64;
+            let resp = PaginatedResponse::new(deliveries, 1, total, total);
+            (StatusCode::OK, Json(resp)).into_response()
+        }
+        Err(e) => outbound_webhook_error(e).into_response(),
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/integrations/outbound-webhooks/{id}/redeliver",
+    params(("id" = Uuid, Path, description = "Outbound webhook ID")),
+    request_body = RedeliverRequest,
+    responses(
+        (status = 200, description = "Attempt made; failures are retried with backoff", body = OutboundWebhookDelivery),
+        (status = 404, description = "Webhook or event not found"),
+    ),
+    security(("bearer" = [])),
+    tag = "Outbound Webhooks"
+)]
+pub async fn redeliver(
+    State(state): State<Arc<AppState>>,
+    claims: Option<Extension<VerifiedClaims>>,
+    Path(id): Path<Uuid>,
+    Json(req): Json<RedeliverRequest>,
+) -> impl IntoResponse {
+    let app_id = match extract_tenant(&claims) {
+        Ok(id) => id,
+        Err(e) => return e.into_response(),
+    };
+    let dispatcher = Dispatcher::new(
+        state.pool.clone(),
+        Arc::new(HttpWebhookSender::new()),
+        state.webhooks_key,
+    );
+
+    match dispatcher.redeliver(&app_id, id, req.event_id).await {
+        Ok(delivery) => (StatusCode::OK, Json(delivery)).into_response(),
+        Err(e) => outbound_webhook_error(e).into_response(),
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/integrations/outbound-webhooks/{id}/rotate-secret",
+    params(("id" = Uuid, Path, description = "Outbound webhook ID")),
+    responses(
+        (status = 200, description = "New signing secret (shown once)", body = RotatedSecret),
+        (status = 404, description = "Webhook not found"),
+    ),
+    security(("bearer" = [])),
+    tag = "Outbound Webhooks"
+)]
+pub async fn rotate_secret(
+    State(state): State<Arc<AppState>>,
+    claims: Option<Extension<VerifiedClaims>>,
+    Path(id): Path<Uuid>,
+) -> impl IntoResponse {
+    let app_id = match extract_tenant(&claims) {
+        Ok(id) => id,
+        Err(e) => return e.into_response(),
+    };
+    let svc = OutboundWebhookService::new(state.pool.clone()).with_secrets_key(state.webhooks_key);
+
+    match svc.rotate_secret(&app_id, id).await {
+        Ok(signing_secret) => (
+            StatusCode::OK,
+            Json(RotatedSecret {
+                webhook_id: id,
+                signing_secret,
+            }),
+        )
+            .into_response(),
+        Err(e) => outbound_webhook_error(e).into_response(),
+    }
+}
@@ -9,6 +9,10 @@ use integrations_rs::{
     },
     domain::external_refs::{CreateExternalRefRequest, ExternalRef, UpdateExternalRefRequest},
     domain::oauth::{refresh, ConnectionStatus, OAuthConnectionInfo},
+    domain::outbound_webhooks::{
+        dispatcher, Dispatcher, HttpWebhookSender, OutboundWebhookDelivery, RedeliverRequest,
+        RotatedSecret,
+    },
     http,
     http::qbo_invoice::{UpdateInvoiceRequest, UpdateInvoiceResponse},
     metrics, AppState,
@@ -48,6 +52,9 @@ use platform_sdk::ModuleBuilder;
         integrations_rs::http::oauth::import_tokens,
         integrations_rs::http::webhooks::inbound_webhook,
         integrations_rs::http::qbo_invoice::update_invoice,
+        integrations_rs::http::outbound_webhooks::list_deliveries,
+        integrations_rs::http::outbound_webhooks::redeliver,
+        integrations_rs::http::outbound_webhooks::rotate_secret,
     ),
     components(schemas(
         ExternalRef, CreateExternalRefRequest, UpdateExternalRefRequest,
@@ -56,8 +63,10 @@ use platform_sdk::ModuleBuilder;
         OAuthConnectionInfo, ConnectionStatus,
         integrations_rs::http::oauth::ImportTokensRequest,
         UpdateInvoiceRequest, UpdateInvoiceResponse,
+        OutboundWebhookDelivery, RedeliverRequest, RotatedSecret,
         ApiError, FieldError,
-        PaginatedResponse<ExternalRef>, PaginatedResponse<ConnectorConfig>, PaginationMeta,
+        PaginatedResponse<ExternalRef>, PaginatedResponse<ConnectorConfig>,
+        PaginatedResponse<OutboundWebhookDelivery>, PaginationMeta,
     )),
     security(("bearer" = [])),
     modifiers(&SecurityAddon),
@@ -499,6 +508,29 @@ async fn main() {
             );
             tracing::info!("Integrations: eBay fulfillment consumer started");
 
+            let webhook_dispatcher = Arc::new(Dispatcher::new(
+                ctx.pool().clone(),
+                Arc::new(HttpWebhookSender::new()),
+                webhooks_key,
+            ));
+            let subjects = dispatcher::subjects_from_env();
+            let (_dispatch_shutdown_tx, dispatch_shutdown_rx) = tokio::sync::watch::channel(false);
+            dispatcher::spawn_dispatcher(
+                webhook_dispatcher.clone(),
+                bus.clone(),
+                subjects.clone(),
+                dispatch_shutdown_rx.clone(),
+            );
+            dispatcher::spawn_retry_worker(
+                webhook_dispatcher,
+                std::time::Duration::from_secs(15),
+                dispatch_shutdown_rx,
+            );
+            tracing::info!(
+                subjects = ?subjects,
+                "Integrations: outbound webhook dispatcher started (15s retry poll)"
+            );
+
             tokio::spawn(
                 integrations_rs::domain::sync::push_attempts::run_watchdog_task(ctx.pool().clone()),
             );
@@ -78,6 +78,9 @@ fn build_connect_router(pool: sqlx::PgPool) -> Router {
         app_id: None,
         roles: vec![],
         perms: vec![],
+        scopes: Default::default(),
+        amr: Vec::new(),
+        auth_time: None,
         actor_type: ActorType::User,
         issued_at: Utc::now(),
         expires_at: Utc::now() + chrono::Duration::minutes(15),
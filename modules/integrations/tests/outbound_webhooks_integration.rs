@@ -168,6 +168,8 @@ async fn test_outbound_webhook_delivery_log_success() {
             attempt_number: 1,
             next_retry_at: None,
             delivered_at: Some(Utc::now()),
+            event_id: None,
+            is_redelivery: false,
         })
         .await
         .expect("record delivery failed");
@@ -227,6 +229,8 @@ async fn test_outbound_webhook_delivery_failure() {
             attempt_number: 1,
             next_retry_at: Some(retry_at),
             delivered_at: None,
+            event_id: None,
+            is_redelivery: false,
         })
         .await
         .expect("record delivery failed");
@@ -256,6 +260,8 @@ async fn test_outbound_webhook_delivery_failure() {
             attempt_number: 2,
             next_retry_at: None,
             delivered_at: Some(Utc::now()),
+            event_id: None,
+            is_redelivery: false,
         })
         .await
         .expect("retry delivery failed");
@@ -127,6 +127,9 @@ fn make_claims(app_id: &str, perms: Vec<&str>) -> VerifiedClaims {
         app_id: Some(app_id.parse().unwrap_or_else(|_| Uuid::new_v4())),
         roles: vec![],
         perms: perms.into_iter().map(String::from).collect(),
+        scopes: Default::default(),
+        amr: Vec::new(),
+        auth_time: None,
         actor_type: ActorType::User,
         issued_at: Utc::now(),
         expires_at: Utc::now() + chrono::Duration::minutes(15),
@@ -156,6 +159,9 @@ fn build_taxrate_router_no_permission(pool: sqlx::PgPool) -> Router {
         app_id: Some(Uuid::new_v4()),
         roles: vec![],
         perms: vec![],
+        scopes: Default::default(),
+        amr: Vec::new(),
+        auth_time: None,
         actor_type: ActorType::User,
         issued_at: Utc::now(),
         expires_at: Utc::now() + chrono::Duration::minutes(15),
@@ -55,6 +55,9 @@ fn test_claims(tenant_id: Uuid) -> VerifiedClaims {
         app_id: None,
         roles: vec!["admin".into()],
         perms: vec!["integrations.mutate".into(), "integrations.read".into()],
+        scopes: Default::default(),
+        amr: Vec::new(),
+        auth_time: None,
         actor_type: ActorType::User,
         issued_at: Utc::now(),
         expires_at: Utc::now() + chrono::Duration::minutes(15),
@@ -98,6 +98,9 @@ fn claims_with_pull_perm(tenant_id: Uuid) -> VerifiedClaims {
         app_id: None,
         roles: vec!["admin".into()],
         perms: vec!["integrations.sync.pull".into()],
+        scopes: Default::default(),
+        amr: Vec::new(),
+        auth_time: None,
         actor_type: ActorType::User,
         issued_at: Utc::now(),
         expires_at: Utc::now() + chrono::Duration::minutes(15),
@@ -113,6 +116,9 @@ fn claims_without_pull_perm(tenant_id: Uuid) -> VerifiedClaims {
         app_id: None,
         roles: vec!["admin".into()],
         perms: vec!["integrations.read".into()],
+        scopes: Default::default(),
+        amr: Vec::new(),
+        auth_time: None,
         actor_type: ActorType::User,
         issued_at: Utc::now(),
         expires_at: Utc::now() + chrono::Duration::minutes(15),
@@ -0,0 +1,114 @@
+-- Inventory: Pick Waves, Pick Tasks and Pick Confirmations
+--
+-- Warehouse execution layer between released demand (sales-order releases,
+-- production component demand) and the stock issue.
+--
+-- inv_pick_tasks:
+--   One row per demand line. Each task holds its own warehouse-level
+--   reservation (inventory_reservations) for quantity_requested from
+--   creation until confirmation.
+--
+-- inv_pick_waves:
+--   Groups open tasks of one warehouse into a wave/batch worked as a single
+--   pick list. A wave completes once none of its tasks remain open.
+--
+-- inv_pick_confirmations:
+--   One row per scanned pick against a task. Each row posts exactly one
+--   stock issue (issue_line_id = inventory_ledger.entry_id).
+--
+-- Task status machine:
+--   open → picked  (quantity_picked = quantity_requested)
+--   open → short   (quantity_picked < quantity_requested)
+--
+-- Short resolution:
+--   re_reserved = remainder re-reserved on a follow-up task (parent_task_id)
+--   backordered = remainder recorded as quantity_backordered; no follow-up
+
+CREATE TABLE inv_pick_waves (
+    id            UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    tenant_id     TEXT NOT NULL,
+    warehouse_id  UUID NOT NULL,
+    wave_number   TEXT NOT NULL,
+    status        TEXT NOT NULL DEFAULT 'released',
+    created_by    TEXT,
+    released_at   TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+    completed_at  TIMESTAMP WITH TIME ZONE,
+    created_at    TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+    updated_at    TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+
+    CONSTRAINT inv_pick_waves_status_check
+        CHECK (status IN ('released', 'completed')),
+
+    CONSTRAINT inv_pick_waves_number_unique
+        UNIQUE (tenant_id, wave_number)
+);
+
+CREATE INDEX idx_inv_pick_waves_tenant_wh
+    ON inv_pick_waves(tenant_id, warehouse_id, status);
+
+CREATE TABLE inv_pick_tasks (
+    id                    UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    tenant_id             TEXT NOT NULL,
+    warehouse_id          UUID NOT NULL,
+    wave_id               UUID REFERENCES inv_pick_waves(id),
+    item_id               UUID NOT NULL REFERENCES items(id),
+    source_type           TEXT NOT NULL,
+    source_id             TEXT NOT NULL,
+    source_line_id        TEXT,
+    currency              TEXT NOT NULL,
+    quantity_requested    BIGINT NOT NULL,
+    quantity_picked       BIGINT NOT NULL DEFAULT 0,
+    quantity_backordered  BIGINT NOT NULL DEFAULT 0,
+    reservation_id        UUID NOT NULL REFERENCES inventory_reservations(id),
+    parent_task_id        UUID REFERENCES inv_pick_tasks(id),
+    status                TEXT NOT NULL DEFAULT 'open',
+    short_resolution      TEXT,
+    confirmed_by          TEXT,
+    confirmed_at          TIMESTAMP WITH TIME ZONE,
+    created_at            TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+    updated_at            TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+
+    CONSTRAINT inv_pick_tasks_source_type_check
+        CHECK (source_type IN ('sales_order', 'production')),
+
+    CONSTRAINT inv_pick_tasks_status_check
+        CHECK (status IN ('open', 'picked', 'short')),
+
+    CONSTRAINT inv_pick_tasks_short_resolution_check
+        CHECK (short_resolution IS NULL OR short_resolution IN ('re_reserved', 'backordered')),
+
+    CONSTRAINT inv_pick_tasks_quantities_check
+        CHECK (quantity_requested > 0
+               AND quantity_picked >= 0
+               AND quantity_backordered >= 0
+               AND quantity_picked <= quantity_requested),
+
+    -- A reservation backs at most one pick task.
+    CONSTRAINT inv_pick_tasks_reservation_unique
+        UNIQUE (reservation_id)
+);
+
+CREATE INDEX idx_inv_pick_tasks_tenant_wh_status
+    ON inv_pick_tasks(tenant_id, warehouse_id, status);
+
+CREATE INDEX idx_inv_pick_tasks_wave
+    ON inv_pick_tasks(wave_id)
+    WHERE wave_id IS NOT NULL;
+
+CREATE INDEX idx_inv_pick_tasks_source
+    ON inv_pick_tasks(tenant_id, source_type, source_id);
+
+CREATE TABLE inv_pick_confirmations (
+    id             UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    tenant_id      TEXT NOT NULL,
+    task_id        UUID NOT NULL REFERENCES inv_pick_tasks(id),
+    location_id    UUID REFERENCES locations(id),
+    lot_code       TEXT,
+    serial_codes   TEXT[],
+    quantity       BIGINT NOT NULL CHECK (quantity > 0),
+    issue_line_id  UUID NOT NULL,
+    confirmed_at   TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_inv_pick_confirmations_task
+    ON inv_pick_confirmations(task_id);
@@ -0,0 +1,114 @@
+-- Inventory: Directed putaway
+--
+-- A warehouse opts into directed putaway by configuring a receiving dock
+-- location. Receipts into that warehouse without an explicit location land on
+-- the dock, and every receipt on the dock produces a putaway task whose target
+-- bin is suggested by the warehouse's rules (lowest priority first):
+--
+--   fixed_bin               → the item's home bin
+--   zone_by_classification  → a bin in the zone mapped to one of the item's classifications
+--   nearest_empty_bin       → the empty bin with enough capacity and lowest travel sequence
+--   consolidate_lot         → a bin already holding the same lot (or item, when untracked)
+--
+-- Confirming a task moves the stock from the dock to the bin with a
+-- transfer_out/transfer_in ledger pair inside the warehouse.
+--
+-- Bin attributes used by the strategies (zone, capacity, travel sequence) live
+-- in inv_putaway_bin_profiles so the locations catalog stays unchanged.
+
+CREATE TABLE inv_putaway_configs (
+    tenant_id        TEXT NOT NULL,
+    warehouse_id     UUID NOT NULL,
+    dock_location_id UUID NOT NULL REFERENCES locations(id),
+    is_active        BOOLEAN NOT NULL DEFAULT TRUE,
+    updated_by       TEXT,
+    created_at       TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+    updated_at       TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+
+    PRIMARY KEY (tenant_id, warehouse_id)
+);
+
+CREATE TABLE inv_putaway_bin_profiles (
+    location_id      UUID PRIMARY KEY REFERENCES locations(id),
+    tenant_id        TEXT NOT NULL,
+    warehouse_id     UUID NOT NULL,
+    zone             TEXT,
+    -- Maximum units the bin holds across all items; NULL = unlimited
+    capacity_units   BIGINT CHECK (capacity_units IS NULL OR capacity_units > 0),
+    -- Walking order from the dock; lower = nearer
+    travel_sequence  INTEGER NOT NULL DEFAULT 0,
+    updated_at       TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_inv_putaway_bin_profiles_wh
+    ON inv_putaway_bin_profiles (tenant_id, warehouse_id, zone);
+
+CREATE TABLE inv_putaway_rules (
+    id                    UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    tenant_id             TEXT NOT NULL,
+    warehouse_id          UUID NOT NULL,
+    strategy              TEXT NOT NULL
+        CHECK (strategy IN ('fixed_bin', 'zone_by_classification',
+                            'nearest_empty_bin', 'consolidate_lot')),
+    priority              INTEGER NOT NULL DEFAULT 100,
+    -- fixed_bin
+    item_id               UUID REFERENCES items(id),
+    location_id           UUID REFERENCES locations(id),
+    -- zone_by_classification
+    classification_system TEXT,
+    classification_code   TEXT,
+    -- zone_by_classification (required) / nearest_empty_bin (optional filter)
+    zone                  TEXT,
+    is_active             BOOLEAN NOT NULL DEFAULT TRUE,
+    updated_by            TEXT,
+    created_at            TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+    updated_at            TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+
+    CONSTRAINT inv_putaway_rules_fixed_bin_fields CHECK (
+        strategy <> 'fixed_bin' OR (item_id IS NOT NULL AND location_id IS NOT NULL)
+    ),
+    CONSTRAINT inv_putaway_rules_zone_fields CHECK (
+        strategy <> 'zone_by_classification'
+        OR (classification_system IS NOT NULL AND classification_code IS NOT NULL
+            AND zone IS NOT NULL)
+    )
+);
+
+CREATE INDEX idx_inv_putaway_rules_wh
+    ON inv_putaway_rules (tenant_id, warehouse_id, priority)
+    WHERE is_active = TRUE;
+
+CREATE TABLE inv_putaway_tasks (
+    id                      UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    tenant_id               TEXT NOT NULL,
+    warehouse_id            UUID NOT NULL,
+    item_id                 UUID NOT NULL REFERENCES items(id),
+    lot_id                  UUID REFERENCES inventory_lots(id),
+    -- Receipt that produced the task; its layer is what gets moved
+    receipt_ledger_entry_id BIGINT NOT NULL REFERENCES inventory_ledger(id),
+    layer_id                UUID NOT NULL REFERENCES inventory_layers(id),
+    source_type             TEXT NOT NULL,
+    from_location_id        UUID NOT NULL REFERENCES locations(id),
+    suggested_location_id   UUID REFERENCES locations(id),
+    rule_id                 UUID REFERENCES inv_putaway_rules(id),
+    strategy                TEXT,
+    quantity                BIGINT NOT NULL CHECK (quantity > 0),
+    status                  TEXT NOT NULL DEFAULT 'open'
+        CHECK (status IN ('open', 'completed')),
+    to_location_id          UUID REFERENCES locations(id),
+    transfer_out_ledger_id  BIGINT REFERENCES inventory_ledger(id),
+    transfer_in_ledger_id   BIGINT REFERENCES inventory_ledger(id),
+    confirmed_by            TEXT,
+    confirmed_at            TIMESTAMP WITH TIME ZONE,
+    created_at              TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+    updated_at              TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+
+    CONSTRAINT inv_putaway_tasks_layer_unique UNIQUE (layer_id)
+);
+
+CREATE INDEX idx_inv_putaway_tasks_open
+    ON inv_putaway_tasks (tenant_id, warehouse_id, created_at)
+    WHERE status = 'open';
+CREATE INDEX idx_inv_putaway_tasks_suggested
+    ON inv_putaway_tasks (suggested_location_id)
+    WHERE status = 'open';
@@ -0,0 +1,87 @@
+-- Inventory: Landed Cost Vouchers
+--
+-- A landed-cost voucher capitalizes freight, duty, brokerage and similar
+-- charges billed in AP onto the receipt layers they relate to.
+--
+-- inv_landed_cost_vouchers:
+--   One header per voucher. Vouchers are posted on creation; the totals are
+--   the split of the charges between inventory value and COGS variance.
+--   total_amount_minor = capitalized_minor + variance_minor.
+--
+-- inv_landed_cost_charges:
+--   The AP bill lines carried by the voucher. A bill line can be allocated
+--   once per tenant (UNIQUE on ap_bill_line_id). clearing_account_ref is the
+--   GL account the bill line was posted to; the capitalization entry credits it.
+--
+-- inv_landed_cost_allocations:
+--   One row per receipt (FIFO layer) on the voucher. The allocated amount is
+--   split by the layer's consumed share: the on-hand share raises the layer
+--   unit cost (unit_cost_before → unit_cost_after), the consumed share and
+--   any per-unit rounding remainder go to COGS variance.
+
+CREATE TABLE inv_landed_cost_vouchers (
+    id                  UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    tenant_id           TEXT NOT NULL,
+    allocation_basis    TEXT NOT NULL
+                        CHECK (allocation_basis IN ('quantity', 'weight', 'value', 'volume')),
+    currency            TEXT NOT NULL,
+    total_amount_minor  BIGINT NOT NULL CHECK (total_amount_minor > 0),
+    capitalized_minor   BIGINT NOT NULL CHECK (capitalized_minor >= 0),
+    variance_minor      BIGINT NOT NULL CHECK (variance_minor >= 0),
+    description         TEXT,
+    created_by          TEXT,
+    -- inventory.landed_cost_allocated outbox event
+    event_id            UUID NOT NULL UNIQUE,
+    created_at          TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+
+    CONSTRAINT landed_cost_voucher_split
+        CHECK (capitalized_minor + variance_minor = total_amount_minor)
+);
+
+CREATE INDEX idx_landed_cost_vouchers_tenant
+    ON inv_landed_cost_vouchers(tenant_id, created_at DESC);
+
+CREATE TABLE inv_landed_cost_charges (
+    id                    UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    voucher_id            UUID NOT NULL REFERENCES inv_landed_cost_vouchers(id),
+    tenant_id             TEXT NOT NULL,
+    ap_bill_id            UUID NOT NULL,
+    ap_bill_line_id       UUID NOT NULL,
+    cost_type             TEXT NOT NULL
+                          CHECK (cost_type IN ('freight', 'duty', 'brokerage', 'insurance', 'other')),
+    amount_minor          BIGINT NOT NULL CHECK (amount_minor > 0),
+    clearing_account_ref  TEXT NOT NULL,
+    created_at            TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+
+    CONSTRAINT landed_cost_bill_line_once UNIQUE (tenant_id, ap_bill_line_id)
+);
+
+CREATE INDEX idx_landed_cost_charges_voucher ON inv_landed_cost_charges(voucher_id);
+CREATE INDEX idx_landed_cost_charges_bill    ON inv_landed_cost_charges(tenant_id, ap_bill_id);
+
+CREATE TABLE inv_landed_cost_allocations (
+    id                      UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    voucher_id              UUID NOT NULL REFERENCES inv_landed_cost_vouchers(id),
+    tenant_id               TEXT NOT NULL,
+    -- Receipt business key (inventory_ledger.entry_id of the 'received' row)
+    receipt_line_id         UUID NOT NULL,
+    layer_id                UUID NOT NULL REFERENCES inventory_layers(id),
+    item_id                 UUID NOT NULL REFERENCES items(id),
+    warehouse_id            UUID NOT NULL,
+    basis_value             DOUBLE PRECISION NOT NULL CHECK (basis_value > 0),
+    quantity_received       BIGINT NOT NULL,
+    quantity_remaining      BIGINT NOT NULL,
+    allocated_minor         BIGINT NOT NULL CHECK (allocated_minor >= 0),
+    capitalized_minor       BIGINT NOT NULL CHECK (capitalized_minor >= 0),
+    variance_minor          BIGINT NOT NULL CHECK (variance_minor >= 0),
+    unit_cost_before_minor  BIGINT NOT NULL,
+    unit_cost_after_minor   BIGINT NOT NULL,
+
+    CONSTRAINT landed_cost_allocation_split
+        CHECK (capitalized_minor + variance_minor = allocated_minor),
+    CONSTRAINT landed_cost_allocation_once UNIQUE (voucher_id, layer_id)
+);
+
+CREATE INDEX idx_landed_cost_allocations_layer ON inv_landed_cost_allocations(layer_id);
+CREATE INDEX idx_landed_cost_allocations_receipt
+    ON inv_landed_cost_allocations(tenant_id, receipt_line_id);
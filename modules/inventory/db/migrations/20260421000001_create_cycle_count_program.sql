@@ -0,0 +1,107 @@
+-- Inventory: ABC analysis and cycle-count program
+--
+-- A tenant's cycle-count program classifies items A/B/C by annual usage
+-- value (issued layer consumptions over a lookback window), writes the class
+-- as an item classification (system 'abc'), and schedules partial cycle count
+-- tasks so every stocked (warehouse, item) is counted at its class frequency.
+--
+-- Scheduled tasks are ordinary cycle_count_tasks tagged with scheduled_for;
+-- approving any task that counts an item advances its next due date.
+--
+-- Depends on: 016 (cycle count tables), item_classifications
+
+CREATE TABLE inv_cycle_count_programs (
+    tenant_id                 TEXT PRIMARY KEY,
+    -- Items making up the first a_cutoff_pct of usage value are A, up to b_cutoff_pct B
+    a_cutoff_pct              DOUBLE PRECISION NOT NULL DEFAULT 80,
+    b_cutoff_pct              DOUBLE PRECISION NOT NULL DEFAULT 95,
+    a_counts_per_year         INTEGER NOT NULL DEFAULT 12,
+    b_counts_per_year         INTEGER NOT NULL DEFAULT 4,
+    c_counts_per_year         INTEGER NOT NULL DEFAULT 1,
+    lookback_days             INTEGER NOT NULL DEFAULT 365,
+    -- The scheduler re-runs the ABC analysis when the last run is older than this
+    reclassify_interval_days  INTEGER NOT NULL DEFAULT 30,
+    is_active                 BOOLEAN NOT NULL DEFAULT TRUE,
+    updated_by                TEXT,
+    created_at                TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+    updated_at                TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+
+    CONSTRAINT cycle_count_program_cutoffs
+        CHECK (a_cutoff_pct > 0 AND a_cutoff_pct < b_cutoff_pct AND b_cutoff_pct <= 100),
+    CONSTRAINT cycle_count_program_frequencies
+        CHECK (a_counts_per_year BETWEEN 1 AND 365
+           AND b_counts_per_year BETWEEN 1 AND 365
+           AND c_counts_per_year BETWEEN 1 AND 365),
+    CONSTRAINT cycle_count_program_windows
+        CHECK (lookback_days > 0 AND reclassify_interval_days > 0)
+);
+
+CREATE TABLE inv_abc_runs (
+    id                      UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    tenant_id               TEXT NOT NULL,
+    as_of                   TIMESTAMP WITH TIME ZONE NOT NULL,
+    lookback_days           INTEGER NOT NULL,
+    a_cutoff_pct            DOUBLE PRECISION NOT NULL,
+    b_cutoff_pct            DOUBLE PRECISION NOT NULL,
+    total_usage_value_minor BIGINT NOT NULL DEFAULT 0,
+    item_count              INTEGER NOT NULL DEFAULT 0,
+    a_count                 INTEGER NOT NULL DEFAULT 0,
+    b_count                 INTEGER NOT NULL DEFAULT 0,
+    c_count                 INTEGER NOT NULL DEFAULT 0,
+    -- Items whose class differs from their previous 'abc' classification
+    reclassified_count      INTEGER NOT NULL DEFAULT 0,
+    created_by              TEXT,
+    created_at              TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_abc_runs_tenant ON inv_abc_runs(tenant_id, created_at DESC);
+
+CREATE TABLE inv_abc_run_lines (
+    run_id             UUID NOT NULL REFERENCES inv_abc_runs(id),
+    tenant_id          TEXT NOT NULL,
+    item_id            UUID NOT NULL REFERENCES items(id),
+    rank               INTEGER NOT NULL,
+    usage_quantity     BIGINT NOT NULL,
+    usage_value_minor  BIGINT NOT NULL,
+    -- Share of total usage value of the items ranked above and including this one
+    cumulative_pct     DOUBLE PRECISION NOT NULL,
+    abc_class          TEXT NOT NULL CHECK (abc_class IN ('A', 'B', 'C')),
+    previous_class     TEXT,
+
+    PRIMARY KEY (run_id, item_id)
+);
+
+-- One row per stocked (warehouse, item): its class, count interval and due date.
+CREATE TABLE inv_cycle_count_schedule (
+    tenant_id        TEXT NOT NULL,
+    warehouse_id     UUID NOT NULL,
+    item_id          UUID NOT NULL REFERENCES items(id),
+    abc_class        TEXT NOT NULL CHECK (abc_class IN ('A', 'B', 'C')),
+    interval_days    INTEGER NOT NULL CHECK (interval_days > 0),
+    last_counted_at  TIMESTAMP WITH TIME ZONE,
+    next_due_date    DATE NOT NULL,
+    updated_at       TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+
+    PRIMARY KEY (tenant_id, warehouse_id, item_id)
+);
+
+CREATE INDEX idx_cycle_count_schedule_due
+    ON inv_cycle_count_schedule(tenant_id, next_due_date);
+
+-- Scheduled tasks: one per location and day
+ALTER TABLE cycle_count_tasks
+    ADD COLUMN scheduled_for DATE,
+    ADD COLUMN approved_at   TIMESTAMP WITH TIME ZONE;
+
+CREATE UNIQUE INDEX cycle_count_tasks_scheduled_once
+    ON cycle_count_tasks(tenant_id, location_id, scheduled_for)
+    WHERE scheduled_for IS NOT NULL;
+
+CREATE INDEX idx_cycle_tasks_approved
+    ON cycle_count_tasks(tenant_id, warehouse_id, approved_at)
+    WHERE status = 'approved';
+
+-- Snapshots taken with expected_qty, for accuracy KPIs
+ALTER TABLE cycle_count_lines
+    ADD COLUMN unit_cost_minor BIGINT,
+    ADD COLUMN abc_class       TEXT;
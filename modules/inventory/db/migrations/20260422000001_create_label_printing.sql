@@ -0,0 +1,75 @@
+-- Inventory: Label templates and SSCC allocation
+--
+-- Templates hold a label-render layout (printer dpi, size in dots and the
+-- text / barcode / box elements with {{field}} placeholders). A template may
+-- be the tenant's default for a label type; labels rendered without an
+-- explicit template use it, falling back to a built-in layout.
+--
+-- SSCC-18 serials are allocated per tenant from the configured GS1 company
+-- prefix and extension digit. Counters are kept per (prefix, extension) so
+-- switching the configuration back and forth never reissues a code.
+
+CREATE TABLE inv_label_templates (
+    id           UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    tenant_id    TEXT NOT NULL,
+    name         TEXT NOT NULL,
+    -- item_label | lot_label | sscc_label; NULL = general purpose
+    label_type   TEXT CHECK (label_type IS NULL
+                             OR label_type IN ('item_label', 'lot_label', 'sscc_label')),
+    is_default   BOOLEAN NOT NULL DEFAULT FALSE,
+    dpi          INTEGER NOT NULL CHECK (dpi BETWEEN 100 AND 600),
+    width_dots   INTEGER NOT NULL CHECK (width_dots > 0),
+    height_dots  INTEGER NOT NULL CHECK (height_dots > 0),
+    elements     JSONB NOT NULL,
+    is_active    BOOLEAN NOT NULL DEFAULT TRUE,
+    updated_by   TEXT,
+    created_at   TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+    updated_at   TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+
+    CONSTRAINT inv_label_templates_name_unique UNIQUE (tenant_id, name),
+    CONSTRAINT inv_label_templates_default_typed CHECK (NOT is_default OR label_type IS NOT NULL)
+);
+
+-- One active default per label type
+CREATE UNIQUE INDEX idx_inv_label_templates_default
+    ON inv_label_templates (tenant_id, label_type)
+    WHERE is_default = TRUE AND is_active = TRUE;
+
+CREATE TABLE inv_sscc_configs (
+    tenant_id          TEXT PRIMARY KEY,
+    gs1_company_prefix TEXT NOT NULL CHECK (gs1_company_prefix ~ '^[0-9]{7,10}$'),
+    extension_digit    SMALLINT NOT NULL CHECK (extension_digit BETWEEN 0 AND 9),
+    updated_by         TEXT,
+    created_at         TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+    updated_at         TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW()
+);
+
+CREATE TABLE inv_sscc_counters (
+    tenant_id          TEXT NOT NULL,
+    gs1_company_prefix TEXT NOT NULL,
+    extension_digit    SMALLINT NOT NULL,
+    -- Next unused serial reference
+    next_serial        BIGINT NOT NULL DEFAULT 0 CHECK (next_serial >= 0),
+    updated_at         TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+
+    PRIMARY KEY (tenant_id, gs1_company_prefix, extension_digit)
+);
+
+CREATE TABLE inv_sscc_serials (
+    id                 UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    tenant_id          TEXT NOT NULL,
+    sscc               TEXT NOT NULL CHECK (sscc ~ '^[0-9]{18}$'),
+    gs1_company_prefix TEXT NOT NULL,
+    extension_digit    SMALLINT NOT NULL,
+    serial_reference   BIGINT NOT NULL,
+    -- What the carton/pallet belongs to, e.g. shipment / ship-123
+    reference_type     TEXT,
+    reference_id       TEXT,
+    allocated_by       TEXT,
+    allocated_at       TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+
+    CONSTRAINT inv_sscc_serials_unique UNIQUE (tenant_id, sscc)
+);
+
+CREATE INDEX idx_inv_sscc_serials_reference
+    ON inv_sscc_serials (tenant_id, reference_type, reference_id);
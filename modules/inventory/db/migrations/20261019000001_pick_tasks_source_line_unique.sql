@@ -0,0 +1,10 @@
+-- Inventory: one pick task per demand source line
+--
+-- Event consumers create pick tasks from sales-order release and production
+-- component demand. Redelivered or re-released events must not task the same
+-- line twice, so an original (non follow-up) task is unique per source line.
+-- Short-pick follow-up tasks carry their parent's source line and are exempt.
+
+CREATE UNIQUE INDEX inv_pick_tasks_source_line_unique
+    ON inv_pick_tasks(tenant_id, source_type, source_id, source_line_id)
+    WHERE parent_task_id IS NULL AND source_line_id IS NOT NULL;
@@ -0,0 +1,16 @@
+-- Inventory: lot-level quarantine
+--
+-- A lot rejected by quality inspection is quarantined as a whole: its layers
+-- are no longer offered by pick lists, counted as reservable, or consumable
+-- by lot-restricted issues. The status bucket transfer (available →
+-- quarantine) keeps item-level availability in step; this flag pins the hold
+-- to the lot itself.
+
+ALTER TABLE inventory_lots
+    ADD COLUMN quarantined_at TIMESTAMP WITH TIME ZONE,
+    -- Inspection whose rejection quarantined the lot
+    ADD COLUMN quarantine_inspection_id UUID;
+
+CREATE INDEX idx_lots_quarantined
+    ON inventory_lots (tenant_id, item_id)
+    WHERE quarantined_at IS NOT NULL;
@@ -0,0 +1,20 @@
+-- Inventory: dead letter queue for consumed events
+--
+-- Events a consumer could not process after all retries. Rows keep the full
+-- envelope so the event can be inspected and replayed once the cause is fixed.
+
+CREATE TABLE inv_failed_events (
+    id            BIGSERIAL PRIMARY KEY,
+    event_id      UUID NOT NULL,
+    subject       TEXT NOT NULL,
+    tenant_id     TEXT NOT NULL,
+    envelope_json JSONB NOT NULL,
+    error         TEXT NOT NULL,
+    failed_at     TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+    retry_count   INT NOT NULL DEFAULT 0,
+
+    CONSTRAINT inv_failed_events_event_id_unique UNIQUE (event_id)
+);
+
+CREATE INDEX idx_inv_failed_events_subject ON inv_failed_events(subject);
+CREATE INDEX idx_inv_failed_events_failed_at ON inv_failed_events(failed_at);
@@ -198,6 +198,7 @@ async fn bench_issue(
         serial_codes: None,
         location_id: None,
         uom_id: None,
+        reservation_id: None,
     };
     let start = Instant::now();
     process_issue(pool, &req, None).await.expect("issue");
@@ -84,6 +84,7 @@ pub async fn process_component_issue_request(
             uom_id: None,
             lot_code: None,
             serial_codes: None,
+            reservation_id: None,
         };
 
         let (result, _is_replay) = issue_service::process_issue(pool, &req, None)
@@ -0,0 +1,110 @@
+//! Dead letter queue for consumed events.
+//!
+//! Consumers that retry with backoff hand an event here once retries are
+//! exhausted, so a failure is never only a log line. Rows in
+//! `inv_failed_events` keep the full envelope for inspection and replay.
+
+use event_bus::BusMessage;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+/// Insert (or refresh) a failed event in `inv_failed_events`.
+pub async fn insert_failed_event(
+    pool: &PgPool,
+    event_id: Uuid,
+    subject: &str,
+    tenant_id: &str,
+    envelope_json: &serde_json::Value,
+    error: &str,
+    retry_count: i32,
+) -> Result<(), sqlx::Error> {
+    sqlx::query(
+        r#"
+        INSERT INTO inv_failed_events
+            (event_id, subject, tenant_id, envelope_json, error, retry_count)
+        VALUES ($1, $2, $3, $4, $5, $6)
+        ON CONFLICT (event_id) DO UPDATE
+        SET retry_count = EXCLUDED.retry_count,
+            error = EXCLUDED.error,
+            failed_at = NOW()
+        "#,
+    )
+    .bind(event_id)
+    .bind(subject)
+    .bind(tenant_id)
+    .bind(envelope_json)
+    .bind(error)
+    .bind(retry_count)
+    .execute(pool)
+    .await?;
+    Ok(())
+}
+
+/// Route a message that failed after `retry_count` attempts to the DLQ.
+///
+/// A message whose envelope cannot be read (no event_id or tenant_id) is
+/// logged instead; it could never be replayed from the table.
+pub async fn handle_processing_error(
+    pool: &PgPool,
+    msg: &BusMessage,
+    error: &str,
+    retry_count: i32,
+) {
+    let envelope = match serde_json::from_slice::<serde_json::Value>(&msg.payload) {
+        Ok(env) => env,
+        Err(parse_err) => {
+            tracing::error!(
+                subject = %msg.subject,
+                error = %error,
+                parse_error = %parse_err,
+                "Failed to process event and could not parse envelope for DLQ"
+            );
+            return;
+        }
+    };
+
+    let event_id = envelope
+        .get("event_id")
+        .and_then(|v| v.as_str())
+        .and_then(|s| Uuid::parse_str(s).ok());
+    let tenant_id = envelope.get("tenant_id").and_then(|v| v.as_str());
+
+    let (Some(event_id), Some(tenant_id)) = (event_id, tenant_id) else {
+        tracing::error!(
+            subject = %msg.subject,
+            error = %error,
+            "Failed to extract event_id or tenant_id from envelope for DLQ"
+        );
+        return;
+    };
+
+    match insert_failed_event(
+        pool,
+        event_id,
+        &msg.subject,
+        tenant_id,
+        &envelope,
+        error,
+        retry_count,
+    )
+    .await
+    {
+        Ok(()) => tracing::error!(
+            event_id = %event_id,
+            subject = %msg.subject,
+            tenant_id = %tenant_id,
+            retry_count,
+            error = %error,
+            "Event moved to DLQ after retries exhausted"
+        ),
+        Err(dlq_err) => tracing::error!(
+            event_id = %event_id,
+            subject = %msg.subject,
+            tenant_id = %tenant_id,
+            retry_count,
+            error = %error,
+            dlq_error = %dlq_err,
+            "Failed to write to DLQ - event may be lost!"
+        ),
+    }
+}
@@ -1,2 +1,5 @@
 pub mod component_issue_consumer;
+pub mod dlq;
 pub mod fg_receipt_consumer;
+pub mod pick_demand_consumer;
+pub mod quality_lot_consumer;
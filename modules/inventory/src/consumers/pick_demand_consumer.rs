@@ -0,0 +1,372 @@
+//! Consumers that turn released demand into pick tasks.
+//!
+//! - `sales.so.released`: one task per sales-order line, keyed by the SO line id.
+//! - `production.component_issue.requested`: one task per requested component,
+//!   keyed by the event id and the item's position in the request. Started
+//!   instead of the direct-issue consumer when `INVENTORY_COMPONENT_PICKING`
+//!   is enabled, so components are picked (and issued on confirmation) rather
+//!   than issued straight away.
+//!
+//! Both go through `task_service::create_pick_task_for_line`, which is
+//! idempotent on the source line: redelivered or re-released events leave
+//! already-tasked lines untouched. Lines that cannot be tasked (no warehouse,
+//! unknown SKU, not enough stock) are skipped with a warning; database
+//! failures abort the event.
+
+use event_bus::{BusMessage, EventBus, EventEnvelope};
+use futures::StreamExt;
+use serde::de::DeserializeOwned;
+use serde::Deserialize;
+use sqlx::PgPool;
+use std::sync::Arc;
+use tracing::Instrument;
+use uuid::Uuid;
+
+use super::component_issue_consumer::ComponentIssueRequestedPayload;
+use crate::domain::barcode_resolver_repo::find_item_by_sku;
+use crate::domain::guards::GuardError;
+use crate::domain::picking::{
+    models::{PickError, PickSourceType, PickTask},
+    task_service::{create_pick_task_for_line, DemandLine, DemandLineOutcome},
+};
+
+pub const SUBJECT_SO_RELEASED: &str = "sales.so.released";
+pub const SUBJECT_COMPONENT_ISSUE_REQUESTED: &str = "production.component_issue.requested";
+
+// ============================================================================
+// Local payload types (mirrors the sales SO_RELEASED event)
+// ============================================================================
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct SoReleasedPayload {
+    pub tenant_id: String,
+    pub so_id: Uuid,
+    pub so_number: String,
+    pub currency: String,
+    pub lines: Vec<SoReleasedLine>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct SoReleasedLine {
+    pub line_id: Uuid,
+    pub sku: Option<String>,
+    pub quantity: i64,
+    pub warehouse_id: Option<Uuid>,
+}
+
+/// What an event produced, line by line.
+#[derive(Debug, Default)]
+pub struct PickDemandSummary {
+    pub created: Vec<PickTask>,
+    pub already_tasked: usize,
+    pub skipped: usize,
+}
+
+// ============================================================================
+// Processing functions (testable without NATS)
+// ============================================================================
+
+/// Create pick tasks for the lines of a released sales order.
+pub async fn process_so_released(
+    pool: &PgPool,
+    payload: &SoReleasedPayload,
+    correlation_id: Option<&str>,
+    causation_id: Option<&str>,
+) -> Result<PickDemandSummary, ConsumerError> {
+    let corr = correlation_id
+        .map(|s| s.to_string())
+        .unwrap_or_else(|| Uuid::new_v4().to_string());
+    let source_id = payload.so_id.to_string();
+
+    let mut summary = PickDemandSummary::default();
+    for line in &payload.lines {
+        let source_line_id = line.line_id.to_string();
+
+        let (Some(warehouse_id), Some(sku)) = (line.warehouse_id, line.sku.as_deref()) else {
+            tracing::warn!(
+                so_number = %payload.so_number,
+                line_id = %line.line_id,
+                "SO line has no warehouse or SKU; no pick task created"
+            );
+            summary.skipped += 1;
+            continue;
+        };
+
+        let item = find_item_by_sku(pool, &payload.tenant_id, sku)
+            .await
+            .map_err(|e| ConsumerError::Database(e.to_string()))?;
+        let Some(item) = item else {
+            tracing::warn!(
+                so_number = %payload.so_number,
+                line_id = %line.line_id,
+                sku = %sku,
+                "SO line SKU is not an active item; no pick task created"
+            );
+            summary.skipped += 1;
+            continue;
+        };
+
+        let demand = DemandLine {
+            tenant_id: &payload.tenant_id,
+            warehouse_id,
+            source_type: PickSourceType::SalesOrder,
+            source_id: &source_id,
+            source_line_id: &source_line_id,
+            item_id: item.id,
+            quantity: line.quantity,
+            currency: &payload.currency,
+            correlation_id: &corr,
+            causation_id,
+        };
+        record(
+            &mut summary,
+            create_pick_task_for_line(pool, &demand).await,
+            &demand,
+        )?;
+    }
+
+    Ok(summary)
+}
+
+/// Create pick tasks for the components of a production issue request.
+pub async fn process_component_demand(
+    pool: &PgPool,
+    event_id: Uuid,
+    payload: &ComponentIssueRequestedPayload,
+    correlation_id: Option<&str>,
+    causation_id: Option<&str>,
+) -> Result<PickDemandSummary, ConsumerError> {
+    let corr = correlation_id
+        .map(|s| s.to_string())
+        .unwrap_or_else(|| Uuid::new_v4().to_string());
+    let source_id = payload.work_order_id.to_string();
+
+    let mut summary = PickDemandSummary::default();
+    for (idx, item) in payload.items.iter().enumerate() {
+        let source_line_id = component_line_id(event_id, idx);
+        let demand = DemandLine {
+            tenant_id: &payload.tenant_id,
+            warehouse_id: item.warehouse_id,
+            source_type: PickSourceType::Production,
+            source_id: &source_id,
+            source_line_id: &source_line_id,
+            item_id: item.item_id,
+            quantity: item.quantity,
+            currency: &item.currency,
+            correlation_id: &corr,
+            causation_id,
+        };
+        record(
+            &mut summary,
+            create_pick_task_for_line(pool, &demand).await,
+            &demand,
+        )?;
+    }
+
+    Ok(summary)
+}
+
+/// Component requests carry no line ids; each request is distinct demand, so
+/// its lines are keyed by the event and position within it.
+fn component_line_id(event_id: Uuid, idx: usize) -> String {
+    format!("{}:{}", event_id, idx)
+}
+
+fn record(
+    summary: &mut PickDemandSummary,
+    outcome: Result<DemandLineOutcome, PickError>,
+    line: &DemandLine<'_>,
+) -> Result<(), ConsumerError> {
+    match outcome {
+        Ok(DemandLineOutcome::Created(task)) => {
+            tracing::info!(
+                task_id = %task.id,
+                source_id = %line.source_id,
+                source_line_id = %line.source_line_id,
+                quantity = task.quantity_requested,
+                "Pick task created from demand"
+            );
+            summary.created.push(task);
+        }
+        Ok(DemandLineOutcome::AlreadyTasked) => {
+            tracing::info!(
+                source_id = %line.source_id,
+                source_line_id = %line.source_line_id,
+                "Demand line already has a pick task; skipping"
+            );
+            summary.already_tasked += 1;
+        }
+        Err(
+            e @ (PickError::Database(_)
+            | PickError::Serialization(_)
+            | PickError::Guard(GuardError::Database(_))),
+        ) => {
+            return Err(ConsumerError::Database(e.to_string()));
+        }
+        Err(e) => {
+            tracing::warn!(
+                error = %e,
+                source_id = %line.source_id,
+                source_line_id = %line.source_line_id,
+                "Demand line could not be tasked; no pick task created"
+            );
+            summary.skipped += 1;
+        }
+    }
+    Ok(())
+}
+
+// ============================================================================
+// NATS consumers (production entry points)
+// ============================================================================
+
+/// Start the sales-order release consumer.
+pub async fn start_so_release_pick_consumer(bus: Arc<dyn EventBus>, pool: PgPool) {
+    tokio::spawn(async move {
+        run_consumer(bus, pool, SUBJECT_SO_RELEASED).await;
+    });
+}
+
+/// Start the production component demand consumer.
+pub async fn start_component_pick_consumer(bus: Arc<dyn EventBus>, pool: PgPool) {
+    tokio::spawn(async move {
+        run_consumer(bus, pool, SUBJECT_COMPONENT_ISSUE_REQUESTED).await;
+    });
+}
+
+async fn run_consumer(bus: Arc<dyn EventBus>, pool: PgPool, subject: &'static str) {
+    tracing::info!(subject, "Starting pick demand consumer");
+
+    let mut stream = match bus.subscribe(subject).await {
+        Ok(s) => s,
+        Err(e) => {
+            tracing::error!("Failed to subscribe to {}: {}", subject, e);
+            return;
+        }
+    };
+
+    tracing::info!("Subscribed to {}", subject);
+
+    while let Some(msg) = stream.next().await {
+        let span = tracing::info_span!("process_pick_demand", subject);
+        async {
+            match process_message(&pool, subject, &msg).await {
+                Ok(summary) => tracing::info!(
+                    created = summary.created.len(),
+                    already_tasked = summary.already_tasked,
+                    skipped = summary.skipped,
+                    "Pick demand processed"
+                ),
+                Err(e) => tracing::error!(error = %e, "Pick demand processing failed"),
+            }
+        }
+        .instrument(span)
+        .await;
+    }
+
+    tracing::warn!(subject, "Pick demand consumer stopped");
+}
+
+async fn process_message(
+    pool: &PgPool,
+    subject: &str,
+    msg: &BusMessage,
+) -> Result<PickDemandSummary, ConsumerError> {
+    if subject == SUBJECT_SO_RELEASED {
+        let envelope: EventEnvelope<SoReleasedPayload> = parse(msg)?;
+        tracing::info!(
+            event_id = %envelope.event_id,
+            tenant_id = %envelope.tenant_id,
+            so_id = %envelope.payload.so_id,
+            line_count = envelope.payload.lines.len(),
+            "Creating pick tasks for released sales order"
+        );
+        process_so_released(
+            pool,
+            &envelope.payload,
+            envelope.correlation_id.as_deref(),
+            envelope.causation_id.as_deref(),
+        )
+        .await
+    } else {
+        let envelope: EventEnvelope<ComponentIssueRequestedPayload> = parse(msg)?;
+        tracing::info!(
+            event_id = %envelope.event_id,
+            tenant_id = %envelope.tenant_id,
+            work_order_id = %envelope.payload.work_order_id,
+            item_count = envelope.payload.items.len(),
+            "Creating pick tasks for component demand"
+        );
+        process_component_demand(
+            pool,
+            envelope.event_id,
+            &envelope.payload,
+            envelope.correlation_id.as_deref(),
+            envelope.causation_id.as_deref(),
+        )
+        .await
+    }
+}
+
+fn parse<P: DeserializeOwned>(msg: &BusMessage) -> Result<EventEnvelope<P>, ConsumerError> {
+    serde_json::from_slice(&msg.payload)
+        .map_err(|e| ConsumerError::Validation(format!("Failed to parse envelope: {}", e)))
+}
+
+// ============================================================================
+// Error type
+// ============================================================================
+
+#[derive(Debug, thiserror::Error)]
+pub enum ConsumerError {
+    #[error("Validation error: {0}")]
+    Validation(String),
+
+    #[error("Database error: {0}")]
+    Database(String),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn component_lines_are_keyed_by_event_and_position() {
+        let event_id = Uuid::new_v4();
+        assert_eq!(
+            component_line_id(event_id, 0),
+            component_line_id(event_id, 0)
+        );
+        assert_ne!(
+            component_line_id(event_id, 0),
+            component_line_id(event_id, 1)
+        );
+        assert_ne!(
+            component_line_id(event_id, 0),
+            component_line_id(Uuid::new_v4(), 0)
+        );
+    }
+
+    #[test]
+    fn so_released_payload_ignores_fields_picking_does_not_use() {
+        let json = serde_json::json!({
+            "tenant_id": "t1",
+            "so_id": Uuid::new_v4(),
+            "so_number": "SO-1",
+            "customer_id": Uuid::new_v4(),
+            "currency": "usd",
+            "ship_to_address": null,
+            "lines": [{
+                "line_id": Uuid::new_v4(),
+                "sku": "SKU-1",
+                "quantity": 3,
+                "unit_of_measure": "ea",
+                "warehouse_id": null
+            }],
+            "released_at": "2026-10-19T00:00:00Z"
+        });
+        let payload: SoReleasedPayload = serde_json::from_value(json).expect("parse");
+        assert_eq!(payload.lines.len(), 1);
+        assert_eq!(payload.lines[0].warehouse_id, None);
+    }
+}
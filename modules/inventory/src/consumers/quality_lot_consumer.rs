@@ -0,0 +1,241 @@
+//! Consumer for `quality_inspection.lot_dispositioned` events.
+//!
+//! When AQL evaluation rejects an inspected lot, the lot itself is marked
+//! quarantined (`inventory_lots.quarantined_at`), which takes its layers out
+//! of pick lists, reservable stock and lot-restricted issues. Its remaining
+//! on-hand quantity is then moved from the `available` bucket to `quarantine`
+//! in each warehouse holding it, so item-level availability agrees. Accepted
+//! lots need no movement — receipts already land in `available`.
+//!
+//! Idempotent: the lot update keeps the first quarantine, and each status
+//! transfer carries an idempotency key derived from the event id and
+//! warehouse. A failed event is retried with backoff and then written to
+//! `inv_failed_events` (see `consumers::dlq`).
+
+use event_bus::consumer_retry::{retry_with_backoff, RetryConfig};
+use event_bus::{BusMessage, EventBus, EventEnvelope};
+use futures::StreamExt;
+use serde::Deserialize;
+use sqlx::PgPool;
+use std::sync::Arc;
+use tracing::Instrument;
+use uuid::Uuid;
+
+use super::dlq;
+use crate::domain::status::{
+    models::InvItemStatus,
+    transfer_service::{process_status_transfer, StatusTransferRequest, StatusTransferResult},
+};
+
+const DECISION_REJECTED: &str = "rejected";
+
+// ============================================================================
+// Local payload types (mirrors quality_inspection::events)
+// ============================================================================
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct LotDispositionedPayload {
+    pub inspection_id: Uuid,
+    pub tenant_id: String,
+    pub lot_id: Uuid,
+    pub decision: String,
+    pub defective_units: i32,
+    pub sample_size: i32,
+}
+
+// ============================================================================
+// Processing function (testable without NATS)
+// ============================================================================
+
+/// Quarantine a rejected lot and its remaining quantity.
+///
+/// Returns one transfer per warehouse holding the lot; empty for accepted
+/// lots or lots with nothing left on hand. Any failure is returned so the
+/// consumer can retry the event; the lot stays quarantined either way.
+pub async fn process_lot_dispositioned(
+    pool: &PgPool,
+    event_id: Uuid,
+    payload: &LotDispositionedPayload,
+    correlation_id: Option<&str>,
+    causation_id: Option<&str>,
+) -> Result<Vec<StatusTransferResult>, ConsumerError> {
+    if payload.decision != DECISION_REJECTED {
+        tracing::info!(
+            lot_id = %payload.lot_id,
+            decision = %payload.decision,
+            "Lot accepted by quality inspection; no stock movement"
+        );
+        return Ok(Vec::new());
+    }
+
+    let item_id: Option<Uuid> = sqlx::query_scalar(
+        r#"
+        UPDATE inventory_lots
+        SET quarantined_at = COALESCE(quarantined_at, NOW()),
+            quarantine_inspection_id = COALESCE(quarantine_inspection_id, $3)
+        WHERE id = $1 AND tenant_id = $2
+        RETURNING item_id
+        "#,
+    )
+    .bind(payload.lot_id)
+    .bind(&payload.tenant_id)
+    .bind(payload.inspection_id)
+    .fetch_optional(pool)
+    .await
+    .map_err(|e| ConsumerError::Database(format!("Failed to quarantine lot: {}", e)))?;
+    let item_id = item_id.ok_or_else(|| {
+        ConsumerError::Validation(format!("Lot {} not found for tenant", payload.lot_id))
+    })?;
+
+    let on_hand: Vec<(Uuid, i64)> = sqlx::query_as(
+        r#"
+        SELECT warehouse_id, SUM(quantity_remaining)::BIGINT
+        FROM inventory_layers
+        WHERE tenant_id = $1 AND lot_id = $2 AND quantity_remaining > 0
+        GROUP BY warehouse_id
+        ORDER BY warehouse_id
+        "#,
+    )
+    .bind(&payload.tenant_id)
+    .bind(payload.lot_id)
+    .fetch_all(pool)
+    .await
+    .map_err(|e| ConsumerError::Database(format!("Failed to load lot on-hand: {}", e)))?;
+
+    let mut transfers = Vec::with_capacity(on_hand.len());
+    for (warehouse_id, quantity) in on_hand {
+        let req = StatusTransferRequest {
+            tenant_id: payload.tenant_id.clone(),
+            item_id,
+            warehouse_id,
+            from_status: InvItemStatus::Available,
+            to_status: InvItemStatus::Quarantine,
+            quantity,
+            idempotency_key: format!("qi-lot-rejected-{}-{}", event_id, warehouse_id),
+            correlation_id: correlation_id.map(String::from),
+            causation_id: causation_id.map(String::from),
+        };
+        let (result, is_replay) = process_status_transfer(pool, &req)
+            .await
+            .map_err(|e| ConsumerError::Transfer(e.to_string()))?;
+
+        tracing::info!(
+            lot_id = %payload.lot_id,
+            warehouse_id = %warehouse_id,
+            quantity,
+            is_replay,
+            inspection_id = %payload.inspection_id,
+            "Rejected lot quarantined"
+        );
+        transfers.push(result);
+    }
+
+    Ok(transfers)
+}
+
+// ============================================================================
+// NATS consumer (production entry point)
+// ============================================================================
+
+/// Start the quality lot disposition consumer.
+///
+/// Subscribes to `quality_inspection.lot_dispositioned` and quarantines
+/// rejected lots.
+pub async fn start_quality_lot_consumer(bus: Arc<dyn EventBus>, pool: PgPool) {
+    tokio::spawn(async move {
+        run_consumer(bus, pool).await;
+    });
+}
+
+async fn run_consumer(bus: Arc<dyn EventBus>, pool: PgPool) {
+    tracing::info!("Starting quality lot disposition consumer");
+
+    let subject = "quality_inspection.lot_dispositioned";
+    let mut stream = match bus.subscribe(subject).await {
+        Ok(s) => s,
+        Err(e) => {
+            tracing::error!("Failed to subscribe to {}: {}", subject, e);
+            return;
+        }
+    };
+
+    tracing::info!("Subscribed to {}", subject);
+
+    let retry_config = RetryConfig::default();
+
+    while let Some(msg) = stream.next().await {
+        let span = tracing::info_span!("process_quality_lot_disposition");
+        async {
+            let result = retry_with_backoff(
+                || async {
+                    process_message(&pool, &msg)
+                        .await
+                        .map_err(|e| e.to_string())
+                },
+                &retry_config,
+                "quality_lot_consumer",
+            )
+            .await;
+
+            if let Err(error_msg) = result {
+                tracing::error!(
+                    error = %error_msg,
+                    "Quality lot disposition failed after retries, sending to DLQ"
+                );
+                dlq::handle_processing_error(
+                    &pool,
+                    &msg,
+                    &error_msg,
+                    retry_config.max_attempts as i32,
+                )
+                .await;
+            }
+        }
+        .instrument(span)
+        .await;
+    }
+
+    tracing::warn!("Quality lot disposition consumer stopped");
+}
+
+async fn process_message(pool: &PgPool, msg: &BusMessage) -> Result<(), ConsumerError> {
+    let envelope: EventEnvelope<LotDispositionedPayload> = serde_json::from_slice(&msg.payload)
+        .map_err(|e| ConsumerError::Validation(format!("Failed to parse envelope: {}", e)))?;
+
+    tracing::info!(
+        event_id = %envelope.event_id,
+        tenant_id = %envelope.tenant_id,
+        lot_id = %envelope.payload.lot_id,
+        decision = %envelope.payload.decision,
+        defective_units = envelope.payload.defective_units,
+        sample_size = envelope.payload.sample_size,
+        "Processing quality lot disposition"
+    );
+
+    process_lot_dispositioned(
+        pool,
+        envelope.event_id,
+        &envelope.payload,
+        envelope.correlation_id.as_deref(),
+        envelope.causation_id.as_deref(),
+    )
+    .await?;
+
+    Ok(())
+}
+
+// ============================================================================
+// Error type
+// ============================================================================
+
+#[derive(Debug, thiserror::Error)]
+pub enum ConsumerError {
+    #[error("Validation error: {0}")]
+    Validation(String),
+
+    #[error("Status transfer error: {0}")]
+    Transfer(String),
+
+    #[error("Database error: {0}")]
+    Database(String),
+}
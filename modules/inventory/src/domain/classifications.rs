@@ -11,7 +11,7 @@
 
 use chrono::{DateTime, Duration, Utc};
 use serde::{Deserialize, Serialize};
-use sqlx::PgPool;
+use sqlx::{PgPool, Postgres, Transaction};
 use thiserror::Error;
 use uuid::Uuid;
 
@@ -182,43 +182,25 @@ async fn find_idempotency_key(
 }
 
 // ============================================================================
-// Assign classification service
+// Shared mutation
 // ============================================================================
 
-/// Assign a classification and optional commodity code to an item.
+/// Insert a classification row with its outbox event and change history.
 ///
-/// Pattern: Guard → Mutation → Outbox (single transaction).
-/// Returns `(ItemClassification, is_replay)`.
-pub async fn assign_classification(
-    pool: &PgPool,
+/// `previous_code` is recorded as the "before" of the change when the row
+/// replaces another code of the same system.
+async fn insert_classification_in_tx(
+    tx: &mut Transaction<'_, Postgres>,
     req: &AssignClassificationRequest,
-) -> Result<(ItemClassification, bool), ClassificationError> {
-    // --- Guard: validate inputs ---
-    validate_request(req)?;
-
-    // --- Guard: idempotency check ---
-    let request_hash = serde_json::to_string(req)?;
-    if let Some(record) = find_idempotency_key(pool, &req.tenant_id, &req.idempotency_key).await? {
-        if record.request_hash != request_hash {
-            return Err(ClassificationError::ConflictingIdempotencyKey);
-        }
-        let result: ItemClassification = serde_json::from_str(&record.response_body)?;
-        return Ok((result, true));
-    }
-
-    // --- Guard: item must exist and be active ---
-    guard_item_exists_active(pool, req.item_id, &req.tenant_id).await?;
-
-    // --- Mutation + Outbox in single transaction ---
+    previous_code: Option<&str>,
+    now: DateTime<Utc>,
+) -> Result<ItemClassification, ClassificationError> {
     let event_id = Uuid::new_v4();
-    let now = Utc::now();
     let correlation_id = req
         .correlation_id
         .clone()
         .unwrap_or_else(|| Uuid::new_v4().to_string());
 
-    let mut tx = pool.begin().await?;
-
     let classification = sqlx::query_as::<_, ItemClassification>(
         r#"
         INSERT INTO item_classifications
@@ -241,7 +223,7 @@ pub async fn assign_classification(
     .bind(req.assigned_by.trim())
     .bind(&req.idempotency_key)
     .bind(now)
-    .fetch_one(&mut *tx)
+    .fetch_one(&mut **tx)
     .await
     .map_err(|e| {
         if let sqlx::Error::Database(ref dbe) = e {
@@ -296,13 +278,16 @@ pub async fn assign_classification(
     .bind(&envelope_json)
     .bind(&correlation_id)
     .bind(&req.causation_id)
-    .execute(&mut *tx)
+    .execute(&mut **tx)
     .await?;
 
     // Change history
     let diff = serde_json::json!({
         "classification_system": { "after": classification.classification_system },
-        "classification_code": { "after": classification.classification_code },
+        "classification_code": {
+            "before": previous_code,
+            "after": classification.classification_code,
+        },
         "classification_label": { "after": classification.classification_label },
         "commodity_system": { "after": classification.commodity_system },
         "commodity_code": { "after": classification.commodity_code },
@@ -319,10 +304,47 @@ pub async fn assign_classification(
         correlation_id: Some(correlation_id.clone()),
         causation_id: req.causation_id.clone(),
     };
-    record_change_in_tx(&mut tx, &change_req)
+    record_change_in_tx(tx, &change_req)
         .await
         .map_err(|e| ClassificationError::Database(sqlx::Error::Protocol(e.to_string())))?;
 
+    Ok(classification)
+}
+
+// ============================================================================
+// Assign classification service
+// ============================================================================
+
+/// Assign a classification and optional commodity code to an item.
+///
+/// Pattern: Guard → Mutation → Outbox (single transaction).
+/// Returns `(ItemClassification, is_replay)`.
+pub async fn assign_classification(
+    pool: &PgPool,
+    req: &AssignClassificationRequest,
+) -> Result<(ItemClassification, bool), ClassificationError> {
+    // --- Guard: validate inputs ---
+    validate_request(req)?;
+
+    // --- Guard: idempotency check ---
+    let request_hash = serde_json::to_string(req)?;
+    if let Some(record) = find_idempotency_key(pool, &req.tenant_id, &req.idempotency_key).await? {
+        if record.request_hash != request_hash {
+            return Err(ClassificationError::ConflictingIdempotencyKey);
+        }
+        let result: ItemClassification = serde_json::from_str(&record.response_body)?;
+        return Ok((result, true));
+    }
+
+    // --- Guard: item must exist and be active ---
+    guard_item_exists_active(pool, req.item_id, &req.tenant_id).await?;
+
+    // --- Mutation + Outbox in single transaction ---
+    let now = Utc::now();
+    let mut tx = pool.begin().await?;
+
+    let classification = insert_classification_in_tx(&mut tx, req, None, now).await?;
+
     // Idempotency key
     let response_json = serde_json::to_string(&classification)?;
     let expires_at = now + Duration::days(7);
@@ -346,6 +368,62 @@ pub async fn assign_classification(
     Ok((classification, false))
 }
 
+// ============================================================================
+// Replace classification (single code per system)
+// ============================================================================
+
+/// Make `req.classification_code` the item's only code in its system,
+/// removing any other code it holds there, inside the caller's transaction.
+///
+/// For systems that hold one code per item, such as the computed ABC class.
+/// Returns `None` when the item already holds the code (nothing is written
+/// beyond dropping stray codes), else the new row.
+pub async fn replace_classification_in_tx(
+    tx: &mut Transaction<'_, Postgres>,
+    req: &AssignClassificationRequest,
+) -> Result<Option<ItemClassification>, ClassificationError> {
+    validate_request(req)?;
+    let system = req.classification_system.trim();
+    let code = req.classification_code.trim();
+
+    let held: Vec<String> = sqlx::query_scalar(
+        r#"
+        SELECT classification_code FROM item_classifications
+        WHERE tenant_id = $1 AND item_id = $2 AND classification_system = $3
+        ORDER BY created_at DESC
+        FOR UPDATE
+        "#,
+    )
+    .bind(&req.tenant_id)
+    .bind(req.item_id)
+    .bind(system)
+    .fetch_all(&mut **tx)
+    .await?;
+
+    if held.iter().any(|c| c != code) {
+        sqlx::query(
+            r#"
+            DELETE FROM item_classifications
+            WHERE tenant_id = $1 AND item_id = $2 AND classification_system = $3
+              AND classification_code <> $4
+            "#,
+        )
+        .bind(&req.tenant_id)
+        .bind(req.item_id)
+        .bind(system)
+        .bind(code)
+        .execute(&mut **tx)
+        .await?;
+    }
+    if held.iter().any(|c| c == code) {
+        return Ok(None);
+    }
+
+    let previous = held.first().map(String::as_str);
+    let classification = insert_classification_in_tx(tx, req, previous, Utc::now()).await?;
+    Ok(Some(classification))
+}
+
 // ============================================================================
 // Query: list classifications for an item
 // ============================================================================
@@ -0,0 +1,187 @@
+//! ABC classification by annual usage value (pure).
+//!
+//! Items are ranked by usage value, highest first (ties by item id). An item
+//! is A while the share of usage value ranked above it is below the A cutoff,
+//! B while below the B cutoff, else C. Measuring the share *above* the item
+//! keeps the single dominant item in A even when it alone exceeds the cutoff.
+//! Items without usage are always C.
+
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash, ToSchema)]
+pub enum AbcClass {
+    A,
+    B,
+    C,
+}
+
+impl AbcClass {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AbcClass::A => "A",
+            AbcClass::B => "B",
+            AbcClass::C => "C",
+        }
+    }
+
+    pub fn parse(s: &str) -> Option<Self> {
+        match s {
+            "A" => Some(AbcClass::A),
+            "B" => Some(AbcClass::B),
+            "C" => Some(AbcClass::C),
+            _ => None,
+        }
+    }
+}
+
+/// Usage of one item over the lookback window.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ItemUsage {
+    pub item_id: Uuid,
+    pub usage_quantity: i64,
+    pub usage_value_minor: i64,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct RankedItem {
+    pub item_id: Uuid,
+    /// 1-based
+    pub rank: i32,
+    pub usage_quantity: i64,
+    pub usage_value_minor: i64,
+    /// Share of total usage value up to and including this item, in percent
+    pub cumulative_pct: f64,
+    pub class: AbcClass,
+}
+
+/// Rank and classify items. `a_cutoff_pct` < `b_cutoff_pct`, both in percent.
+pub fn classify(
+    mut usage: Vec<ItemUsage>,
+    a_cutoff_pct: f64,
+    b_cutoff_pct: f64,
+) -> Vec<RankedItem> {
+    usage.sort_by(|x, y| {
+        y.usage_value_minor
+            .cmp(&x.usage_value_minor)
+            .then(x.item_id.cmp(&y.item_id))
+    });
+    let total: i128 = usage
+        .iter()
+        .map(|u| u.usage_value_minor.max(0) as i128)
+        .sum();
+
+    let mut above: i128 = 0;
+    usage
+        .into_iter()
+        .enumerate()
+        .map(|(i, u)| {
+            let value = u.usage_value_minor.max(0) as i128;
+            let pct = |v: i128| {
+                if total == 0 {
+                    0.0
+                } else {
+                    v as f64 * 100.0 / total as f64
+                }
+            };
+            let above_pct = pct(above);
+            above += value;
+            let class = if value == 0 {
+                AbcClass::C
+            } else if above_pct < a_cutoff_pct {
+                AbcClass::A
+            } else if above_pct < b_cutoff_pct {
+                AbcClass::B
+            } else {
+                AbcClass::C
+            };
+            RankedItem {
+                item_id: u.item_id,
+                rank: i as i32 + 1,
+                usage_quantity: u.usage_quantity,
+                usage_value_minor: u.usage_value_minor,
+                cumulative_pct: pct(above),
+                class,
+            }
+        })
+        .collect()
+}
+
+/// Days between counts for a class counted `counts_per_year` times a year.
+pub fn interval_days(counts_per_year: i32) -> i32 {
+    (365 / counts_per_year.max(1)).max(1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn usage(n: u128, value: i64) -> ItemUsage {
+        ItemUsage {
+            item_id: Uuid::from_u128(n),
+            usage_quantity: value / 100,
+            usage_value_minor: value,
+        }
+    }
+
+    fn classes(ranked: &[RankedItem]) -> Vec<&'static str> {
+        ranked.iter().map(|r| r.class.as_str()).collect()
+    }
+
+    #[test]
+    fn pareto_split_by_share_above() {
+        // 50 / 25 / 10 / 8 / 5 / 2 (of 100)
+        let ranked = classify(
+            vec![
+                usage(6, 200),
+                usage(1, 5000),
+                usage(4, 800),
+                usage(2, 2500),
+                usage(5, 500),
+                usage(3, 1000),
+            ],
+            80.0,
+            95.0,
+        );
+        let ids: Vec<u128> = ranked.iter().map(|r| r.item_id.as_u128()).collect();
+        assert_eq!(ids, vec![1, 2, 3, 4, 5, 6]);
+        // above: 0, 50, 75 → A; 85, 93 → B; 98 → C
+        assert_eq!(classes(&ranked), vec!["A", "A", "A", "B", "B", "C"]);
+        assert!((ranked[2].cumulative_pct - 85.0).abs() < 1e-9);
+        assert!((ranked[5].cumulative_pct - 100.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn dominant_item_stays_a() {
+        let ranked = classify(vec![usage(1, 9_900), usage(2, 100)], 80.0, 95.0);
+        assert_eq!(classes(&ranked), vec!["A", "C"]);
+    }
+
+    #[test]
+    fn zero_usage_is_c_and_no_usage_at_all_is_all_c() {
+        let ranked = classify(vec![usage(1, 100), usage(2, 0)], 80.0, 95.0);
+        assert_eq!(classes(&ranked), vec!["A", "C"]);
+
+        let ranked = classify(vec![usage(1, 0), usage(2, 0)], 80.0, 95.0);
+        assert_eq!(classes(&ranked), vec!["C", "C"]);
+        assert_eq!(ranked[0].cumulative_pct, 0.0);
+    }
+
+    #[test]
+    fn ties_rank_by_item_id() {
+        let ranked = classify(vec![usage(9, 100), usage(3, 100)], 50.0, 95.0);
+        assert_eq!(ranked[0].item_id, Uuid::from_u128(3));
+        assert_eq!(ranked[0].rank, 1);
+        assert_eq!(classes(&ranked), vec!["A", "B"]);
+    }
+
+    #[test]
+    fn interval_from_frequency() {
+        assert_eq!(interval_days(12), 30);
+        assert_eq!(interval_days(4), 91);
+        assert_eq!(interval_days(1), 365);
+        assert_eq!(interval_days(365), 1);
+        assert_eq!(interval_days(0), 365);
+    }
+}
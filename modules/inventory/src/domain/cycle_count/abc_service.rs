@@ -0,0 +1,122 @@
+//! ABC analysis run.
+//!
+//! Ranks the tenant's active items by usage value (cost of issued layer
+//! consumptions over the program's lookback window), classifies them A/B/C
+//! and, in one transaction:
+//!   1. records the run and one line per item
+//!   2. writes changed classes through `classifications` (system 'abc'),
+//!      which emits `inventory.classification_assigned` and change history
+//!   3. moves scheduled (warehouse, item) rows to their new class interval
+
+use chrono::{Duration, Utc};
+use sqlx::PgPool;
+use std::collections::HashMap;
+use uuid::Uuid;
+
+use super::abc::{classify, AbcClass};
+use super::program::{
+    AbcRun, AbcRunDetail, CycleCountProgram, ProgramError, RunAbcRequest, ABC_CLASSIFICATION_SYSTEM,
+};
+use super::program_repo;
+use crate::domain::classifications::{replace_classification_in_tx, AssignClassificationRequest};
+
+/// Classify the tenant's items under `program` and return the run.
+pub async fn run_abc_analysis(
+    pool: &PgPool,
+    program: &CycleCountProgram,
+    req: &RunAbcRequest,
+) -> Result<AbcRunDetail, ProgramError> {
+    let now = Utc::now();
+    let as_of = req.as_of.unwrap_or(now);
+    if as_of > now {
+        return Err(ProgramError::Validation(
+            "as_of must not be in the future".to_string(),
+        ));
+    }
+    let tenant_id = program.tenant_id.as_str();
+    let from = as_of - Duration::days(program.lookback_days as i64);
+
+    let mut tx = pool.begin().await?;
+
+    let usage = program_repo::item_usage(&mut tx, tenant_id, from, as_of).await?;
+    let ranked = classify(usage, program.a_cutoff_pct, program.b_cutoff_pct);
+    let previous: HashMap<Uuid, String> = program_repo::current_classes(&mut tx, tenant_id)
+        .await?
+        .into_iter()
+        .collect();
+
+    let count = |c: AbcClass| ranked.iter().filter(|r| r.class == c).count() as i32;
+    let reclassified = ranked
+        .iter()
+        .filter(|r| previous.get(&r.item_id).map(String::as_str) != Some(r.class.as_str()))
+        .count() as i32;
+
+    let run = program_repo::insert_run(
+        &mut tx,
+        &AbcRun {
+            id: Uuid::new_v4(),
+            tenant_id: tenant_id.to_string(),
+            as_of,
+            lookback_days: program.lookback_days,
+            a_cutoff_pct: program.a_cutoff_pct,
+            b_cutoff_pct: program.b_cutoff_pct,
+            total_usage_value_minor: ranked.iter().map(|r| r.usage_value_minor).sum(),
+            item_count: ranked.len() as i32,
+            a_count: count(AbcClass::A),
+            b_count: count(AbcClass::B),
+            c_count: count(AbcClass::C),
+            reclassified_count: reclassified,
+            created_by: req.created_by.clone(),
+            created_at: now,
+        },
+    )
+    .await?;
+
+    for item in &ranked {
+        let prev = previous.get(&item.item_id).map(String::as_str);
+        program_repo::insert_run_line(&mut tx, run.id, tenant_id, item, prev).await?;
+
+        if prev != Some(item.class.as_str()) {
+            let assign = AssignClassificationRequest {
+                tenant_id: tenant_id.to_string(),
+                item_id: item.item_id,
+                revision_id: None,
+                classification_system: ABC_CLASSIFICATION_SYSTEM.to_string(),
+                classification_code: item.class.as_str().to_string(),
+                classification_label: Some(format!(
+                    "ABC class {} (annual usage value)",
+                    item.class.as_str()
+                )),
+                commodity_system: None,
+                commodity_code: None,
+                assigned_by: req
+                    .created_by
+                    .clone()
+                    .unwrap_or_else(|| "abc-analysis".to_string()),
+                idempotency_key: format!("abc-{}-{}", run.id, item.item_id),
+                correlation_id: Some(run.id.to_string()),
+                causation_id: None,
+            };
+            replace_classification_in_tx(&mut tx, &assign).await?;
+        }
+    }
+
+    program_repo::reclass_schedule(&mut tx, program, as_of.date_naive()).await?;
+
+    tx.commit().await?;
+
+    let lines = program_repo::list_run_lines(pool, tenant_id, run.id).await?;
+    Ok(AbcRunDetail { run, lines })
+}
+
+pub async fn get_abc_run(
+    pool: &PgPool,
+    tenant_id: &str,
+    run_id: Uuid,
+) -> Result<AbcRunDetail, ProgramError> {
+    let run = program_repo::find_run(pool, tenant_id, run_id)
+        .await?
+        .ok_or(ProgramError::RunNotFound)?;
+    let lines = program_repo::list_run_lines(pool, tenant_id, run_id).await?;
+    Ok(AbcRunDetail { run, lines })
+}
@@ -0,0 +1,157 @@
+//! Cycle count accuracy KPIs.
+//!
+//! Over approved count lines in a period: hit rate (lines whose count matched
+//! the expected quantity) and value variance at the unit cost snapshotted
+//! when the line was created, by ABC class and bin, by class, and overall.
+
+use chrono::{DateTime, Utc};
+use sqlx::PgPool;
+use std::collections::BTreeMap;
+use uuid::Uuid;
+
+use super::program::{AccuracyBucket, AccuracyReport, ProgramError};
+use super::program_repo;
+
+pub async fn accuracy_report(
+    pool: &PgPool,
+    tenant_id: &str,
+    warehouse_id: Uuid,
+    from: DateTime<Utc>,
+    to: DateTime<Utc>,
+) -> Result<AccuracyReport, ProgramError> {
+    if from >= to {
+        return Err(ProgramError::Validation(
+            "from must be before to".to_string(),
+        ));
+    }
+    let rows =
+        program_repo::accuracy_by_class_location(pool, tenant_id, warehouse_id, from, to).await?;
+    let (overall, by_class, by_class_location) = summarize(rows);
+    Ok(AccuracyReport {
+        warehouse_id,
+        from,
+        to,
+        overall,
+        by_class,
+        by_class_location,
+    })
+}
+
+/// Roll (class, bin) buckets up by class and overall, filling in the rates.
+fn summarize(
+    rows: Vec<AccuracyBucket>,
+) -> (AccuracyBucket, Vec<AccuracyBucket>, Vec<AccuracyBucket>) {
+    let mut overall = AccuracyBucket::default();
+    let mut classes: BTreeMap<Option<String>, AccuracyBucket> = BTreeMap::new();
+    for row in &rows {
+        add(&mut overall, row);
+        let class = classes
+            .entry(row.abc_class.clone())
+            .or_insert_with(|| AccuracyBucket {
+                abc_class: row.abc_class.clone(),
+                ..Default::default()
+            });
+        add(class, row);
+    }
+    // Classified first, A → C, then lines counted while unclassified
+    let mut by_class: Vec<AccuracyBucket> = classes.into_values().map(with_rates).collect();
+    by_class.sort_by_key(|b| (b.abc_class.is_none(), b.abc_class.clone()));
+    (
+        with_rates(overall),
+        by_class,
+        rows.into_iter().map(with_rates).collect(),
+    )
+}
+
+fn add(into: &mut AccuracyBucket, row: &AccuracyBucket) {
+    into.lines_counted += row.lines_counted;
+    into.hits += row.hits;
+    into.expected_value_minor += row.expected_value_minor;
+    into.net_variance_value_minor += row.net_variance_value_minor;
+    into.abs_variance_value_minor += row.abs_variance_value_minor;
+}
+
+fn with_rates(mut b: AccuracyBucket) -> AccuracyBucket {
+    b.hit_rate_pct = if b.lines_counted == 0 {
+        0.0
+    } else {
+        b.hits as f64 * 100.0 / b.lines_counted as f64
+    };
+    b.value_accuracy_pct = if b.expected_value_minor > 0 {
+        (100.0 - b.abs_variance_value_minor as f64 * 100.0 / b.expected_value_minor as f64).max(0.0)
+    } else if b.abs_variance_value_minor == 0 {
+        100.0
+    } else {
+        0.0
+    };
+    b
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn bucket(
+        class: Option<&str>,
+        lines: i64,
+        hits: i64,
+        expected: i64,
+        net: i64,
+        abs: i64,
+    ) -> AccuracyBucket {
+        AccuracyBucket {
+            abc_class: class.map(str::to_string),
+            location_id: Some(Uuid::new_v4()),
+            lines_counted: lines,
+            hits,
+            expected_value_minor: expected,
+            net_variance_value_minor: net,
+            abs_variance_value_minor: abs,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn rolls_up_by_class_and_overall() {
+        let (overall, by_class, rows) = summarize(vec![
+            bucket(Some("A"), 4, 3, 10_000, -500, 500),
+            bucket(Some("A"), 1, 1, 2_000, 0, 0),
+            bucket(Some("C"), 5, 5, 1_000, 0, 0),
+            bucket(None, 2, 0, 1_000, 100, 300),
+        ]);
+        assert_eq!(rows.len(), 4);
+        assert_eq!(overall.lines_counted, 12);
+        assert_eq!(overall.hits, 9);
+        assert!((overall.hit_rate_pct - 75.0).abs() < 1e-9);
+        assert_eq!(overall.net_variance_value_minor, -400);
+        assert_eq!(overall.abs_variance_value_minor, 800);
+        assert!(overall.location_id.is_none());
+
+        let classes: Vec<Option<&str>> = by_class.iter().map(|b| b.abc_class.as_deref()).collect();
+        assert_eq!(classes, vec![Some("A"), Some("C"), None]);
+        let a = &by_class[0];
+        assert_eq!(a.lines_counted, 5);
+        assert!((a.hit_rate_pct - 80.0).abs() < 1e-9);
+        // 500 of 12_000 off → 95.83%
+        assert!((a.value_accuracy_pct - (100.0 - 500.0 * 100.0 / 12_000.0)).abs() < 1e-9);
+        assert_eq!(by_class[1].value_accuracy_pct, 100.0);
+    }
+
+    #[test]
+    fn value_accuracy_without_expected_value() {
+        let b = with_rates(bucket(Some("B"), 1, 0, 0, 200, 200));
+        assert_eq!(b.value_accuracy_pct, 0.0);
+        assert_eq!(b.hit_rate_pct, 0.0);
+
+        let (overall, by_class, _) = summarize(vec![]);
+        assert_eq!(overall.lines_counted, 0);
+        assert_eq!(overall.value_accuracy_pct, 100.0);
+        assert!(by_class.is_empty());
+    }
+
+    #[test]
+    fn variance_beyond_expected_value_floors_at_zero() {
+        let b = with_rates(bucket(Some("A"), 1, 0, 100, 500, 500));
+        assert_eq!(b.value_accuracy_pct, 0.0);
+    }
+}
@@ -8,8 +8,8 @@
 //!   3. Upserts item_on_hand + item_on_hand_by_status projections
 //!   4. Emits inventory.adjusted to inv_outbox (one event per adjusted line)
 //!
-//! Then moves the task from 'submitted' → 'approved' and emits
-//! inventory.cycle_count_approved.
+//! Then moves the task from 'submitted' → 'approved', advances the counted
+//! items' cycle-count schedule and emits inventory.cycle_count_approved.
 //!
 //! All mutations occur in one transaction — either everything commits or nothing.
 //!
@@ -26,7 +26,7 @@ use sqlx::PgPool;
 use thiserror::Error;
 use uuid::Uuid;
 
-use super::repo;
+use super::{program_repo, repo};
 use crate::events::cycle_count_approved::{
     build_cycle_count_approved_envelope, CycleCountApprovedLine, CycleCountApprovedPayload,
     EVENT_TYPE_CYCLE_COUNT_APPROVED,
@@ -266,6 +266,17 @@ pub async fn approve_cycle_count(
     // --- Mutation: move task to 'approved' ---
     repo::update_task_approved(&mut tx, approved_at, req.task_id, &req.tenant_id).await?;
 
+    // --- Mutation: counted items are next due one class interval from now ---
+    let counted_items: Vec<Uuid> = lines.iter().map(|l| l.item_id).collect();
+    program_repo::mark_counted(
+        &mut tx,
+        &req.tenant_id,
+        task.warehouse_id,
+        &counted_items,
+        approved_at,
+    )
+    .await?;
+
     // --- Outbox: inventory.cycle_count_approved ---
     let approve_payload = CycleCountApprovedPayload {
         task_id: req.task_id,
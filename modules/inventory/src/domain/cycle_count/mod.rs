@@ -1,4 +1,10 @@
+pub mod abc;
+pub mod abc_service;
+pub mod accuracy;
 pub mod approve_service;
+pub mod program;
+pub mod program_repo;
 pub mod repo;
+pub mod scheduler;
 pub mod submit_service;
 pub mod task_service;
@@ -0,0 +1,335 @@
+//! Cycle-count program types: configuration, ABC runs, count schedule,
+//! generated tasks and accuracy KPIs.
+
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::PgPool;
+use thiserror::Error;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use super::abc::{interval_days, AbcClass};
+use super::program_repo;
+use crate::domain::classifications::ClassificationError;
+
+/// Classification system the computed ABC class is written under.
+pub const ABC_CLASSIFICATION_SYSTEM: &str = "abc";
+
+// ============================================================================
+// Records
+// ============================================================================
+
+/// A tenant's cycle-count program. Present and active = scheduler on.
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow, ToSchema)]
+pub struct CycleCountProgram {
+    pub tenant_id: String,
+    /// Items within the first `a_cutoff_pct` percent of usage value are A
+    pub a_cutoff_pct: f64,
+    /// ... up to `b_cutoff_pct` B, the rest C
+    pub b_cutoff_pct: f64,
+    pub a_counts_per_year: i32,
+    pub b_counts_per_year: i32,
+    pub c_counts_per_year: i32,
+    /// Usage window of the ABC analysis
+    pub lookback_days: i32,
+    /// The scheduler re-runs the ABC analysis when the last run is older than this
+    pub reclassify_interval_days: i32,
+    pub is_active: bool,
+    pub updated_by: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl CycleCountProgram {
+    /// Days between counts of an item of `class`.
+    pub fn interval_days(&self, class: AbcClass) -> i32 {
+        interval_days(match class {
+            AbcClass::A => self.a_counts_per_year,
+            AbcClass::B => self.b_counts_per_year,
+            AbcClass::C => self.c_counts_per_year,
+        })
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow, ToSchema)]
+pub struct AbcRun {
+    pub id: Uuid,
+    pub tenant_id: String,
+    /// End of the usage window
+    pub as_of: DateTime<Utc>,
+    pub lookback_days: i32,
+    pub a_cutoff_pct: f64,
+    pub b_cutoff_pct: f64,
+    pub total_usage_value_minor: i64,
+    pub item_count: i32,
+    pub a_count: i32,
+    pub b_count: i32,
+    pub c_count: i32,
+    /// Items whose class changed in this run
+    pub reclassified_count: i32,
+    pub created_by: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow, ToSchema)]
+pub struct AbcRunLine {
+    pub item_id: Uuid,
+    pub sku: String,
+    /// 1 = highest usage value
+    pub rank: i32,
+    /// Units issued in the window
+    pub usage_quantity: i64,
+    /// Cost of the units issued in the window
+    pub usage_value_minor: i64,
+    /// Share of total usage value up to and including this item (percent)
+    pub cumulative_pct: f64,
+    /// A | B | C
+    pub abc_class: String,
+    /// Class before this run; None when the item was unclassified
+    pub previous_class: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct AbcRunDetail {
+    #[serde(flatten)]
+    pub run: AbcRun,
+    pub lines: Vec<AbcRunLine>,
+}
+
+/// When a stocked (warehouse, item) is next counted.
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow, ToSchema)]
+pub struct CountScheduleEntry {
+    pub warehouse_id: Uuid,
+    pub item_id: Uuid,
+    pub abc_class: String,
+    pub interval_days: i32,
+    pub last_counted_at: Option<DateTime<Utc>>,
+    pub next_due_date: NaiveDate,
+}
+
+// ============================================================================
+// Requests / results
+// ============================================================================
+
+/// Input for PUT /api/inventory/cycle-count-program
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct UpsertCycleCountProgramRequest {
+    #[serde(default = "default_a_cutoff")]
+    pub a_cutoff_pct: f64,
+    #[serde(default = "default_b_cutoff")]
+    pub b_cutoff_pct: f64,
+    #[serde(default = "default_a_counts")]
+    pub a_counts_per_year: i32,
+    #[serde(default = "default_b_counts")]
+    pub b_counts_per_year: i32,
+    #[serde(default = "default_c_counts")]
+    pub c_counts_per_year: i32,
+    #[serde(default = "default_lookback")]
+    pub lookback_days: i32,
+    #[serde(default = "default_reclassify")]
+    pub reclassify_interval_days: i32,
+    #[serde(default = "default_true")]
+    pub is_active: bool,
+    pub updated_by: Option<String>,
+}
+
+/// Input for POST /api/inventory/abc-runs
+#[derive(Debug, Default, Serialize, Deserialize, ToSchema)]
+pub struct RunAbcRequest {
+    /// End of the usage window; defaults to now
+    pub as_of: Option<DateTime<Utc>>,
+    pub created_by: Option<String>,
+}
+
+/// Input for POST /api/inventory/cycle-count-program/generate
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct GenerateCountTasksRequest {
+    pub warehouse_id: Uuid,
+    /// Day the tasks are for; defaults to today (UTC)
+    pub scheduled_for: Option<NaiveDate>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, ToSchema)]
+pub struct GenerateCountTasksResult {
+    pub scheduled_for: Option<NaiveDate>,
+    pub tasks_created: usize,
+    pub items_scheduled: usize,
+    pub task_ids: Vec<Uuid>,
+}
+
+fn default_a_cutoff() -> f64 {
+    80.0
+}
+fn default_b_cutoff() -> f64 {
+    95.0
+}
+fn default_a_counts() -> i32 {
+    12
+}
+fn default_b_counts() -> i32 {
+    4
+}
+fn default_c_counts() -> i32 {
+    1
+}
+fn default_lookback() -> i32 {
+    365
+}
+fn default_reclassify() -> i32 {
+    30
+}
+fn default_true() -> bool {
+    true
+}
+
+// ============================================================================
+// Accuracy KPIs
+// ============================================================================
+
+/// Count accuracy of approved cycle count lines in one bucket.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct AccuracyBucket {
+    /// A | B | C; None = all classes (or lines counted while unclassified)
+    pub abc_class: Option<String>,
+    /// None = all locations
+    pub location_id: Option<Uuid>,
+    pub lines_counted: i64,
+    /// Lines whose count matched the expected quantity
+    pub hits: i64,
+    pub hit_rate_pct: f64,
+    /// Expected quantity at the snapshot unit cost
+    pub expected_value_minor: i64,
+    /// Σ variance × unit cost (gains positive, losses negative)
+    pub net_variance_value_minor: i64,
+    /// Σ |variance| × unit cost
+    pub abs_variance_value_minor: i64,
+    /// 100 − abs variance value as a percentage of expected value (floored at 0)
+    pub value_accuracy_pct: f64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct AccuracyReport {
+    pub warehouse_id: Uuid,
+    pub from: DateTime<Utc>,
+    pub to: DateTime<Utc>,
+    pub overall: AccuracyBucket,
+    pub by_class: Vec<AccuracyBucket>,
+    pub by_class_location: Vec<AccuracyBucket>,
+}
+
+// ============================================================================
+// Errors
+// ============================================================================
+
+#[derive(Debug, Error)]
+pub enum ProgramError {
+    #[error("Validation error: {0}")]
+    Validation(String),
+
+    #[error("Cycle-count program not configured for this tenant")]
+    ProgramNotFound,
+
+    #[error("Cycle-count program is inactive")]
+    ProgramInactive,
+
+    #[error("ABC run not found")]
+    RunNotFound,
+
+    #[error("Classification error: {0}")]
+    Classification(#[from] ClassificationError),
+
+    #[error("Database error: {0}")]
+    Database(#[from] sqlx::Error),
+}
+
+// ============================================================================
+// Configuration service
+// ============================================================================
+
+/// Create or replace the tenant's program.
+pub async fn upsert_program(
+    pool: &PgPool,
+    tenant_id: &str,
+    req: &UpsertCycleCountProgramRequest,
+) -> Result<CycleCountProgram, ProgramError> {
+    validate_program(req)?;
+    Ok(program_repo::upsert_program(pool, tenant_id, req).await?)
+}
+
+pub async fn get_program(
+    pool: &PgPool,
+    tenant_id: &str,
+) -> Result<CycleCountProgram, ProgramError> {
+    program_repo::find_program(pool, tenant_id)
+        .await?
+        .ok_or(ProgramError::ProgramNotFound)
+}
+
+fn validate_program(req: &UpsertCycleCountProgramRequest) -> Result<(), ProgramError> {
+    if !(req.a_cutoff_pct > 0.0 && req.a_cutoff_pct < req.b_cutoff_pct && req.b_cutoff_pct <= 100.0)
+    {
+        return Err(ProgramError::Validation(
+            "cutoffs must satisfy 0 < a_cutoff_pct < b_cutoff_pct <= 100".to_string(),
+        ));
+    }
+    for (field, n) in [
+        ("a_counts_per_year", req.a_counts_per_year),
+        ("b_counts_per_year", req.b_counts_per_year),
+        ("c_counts_per_year", req.c_counts_per_year),
+    ] {
+        if !(1..=365).contains(&n) {
+            return Err(ProgramError::Validation(format!(
+                "{} must be between 1 and 365",
+                field
+            )));
+        }
+    }
+    if req.lookback_days <= 0 || req.reclassify_interval_days <= 0 {
+        return Err(ProgramError::Validation(
+            "lookback_days and reclassify_interval_days must be > 0".to_string(),
+        ));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn req() -> UpsertCycleCountProgramRequest {
+        serde_json::from_str("{}").expect("defaults")
+    }
+
+    #[test]
+    fn defaults_are_valid() {
+        let r = req();
+        assert_eq!(r.a_cutoff_pct, 80.0);
+        assert_eq!(r.a_counts_per_year, 12);
+        assert!(r.is_active);
+        assert!(validate_program(&r).is_ok());
+    }
+
+    #[test]
+    fn cutoffs_must_be_ordered() {
+        let mut r = req();
+        r.a_cutoff_pct = 95.0;
+        r.b_cutoff_pct = 80.0;
+        assert!(matches!(
+            validate_program(&r),
+            Err(ProgramError::Validation(_))
+        ));
+        r.a_cutoff_pct = 0.0;
+        r.b_cutoff_pct = 50.0;
+        assert!(validate_program(&r).is_err());
+    }
+
+    #[test]
+    fn frequencies_and_windows_bounded() {
+        let mut r = req();
+        r.c_counts_per_year = 0;
+        assert!(validate_program(&r).is_err());
+        let mut r = req();
+        r.lookback_days = 0;
+        assert!(validate_program(&r).is_err());
+    }
+}
@@ -0,0 +1,574 @@
+//! Cycle-count program repository: configuration, ABC runs, count schedule
+//! and accuracy reads.
+
+use chrono::{DateTime, NaiveDate, Utc};
+use sqlx::{PgPool, Postgres, Transaction};
+use uuid::Uuid;
+
+use super::abc::{AbcClass, ItemUsage, RankedItem};
+use super::program::{
+    AbcRun, AbcRunLine, AccuracyBucket, CountScheduleEntry, CycleCountProgram,
+    UpsertCycleCountProgramRequest, ABC_CLASSIFICATION_SYSTEM,
+};
+
+// ============================================================================
+// Program configuration
+// ============================================================================
+
+pub async fn upsert_program(
+    pool: &PgPool,
+    tenant_id: &str,
+    req: &UpsertCycleCountProgramRequest,
+) -> Result<CycleCountProgram, sqlx::Error> {
+    sqlx::query_as::<_, CycleCountProgram>(
+        r#"
+        INSERT INTO inv_cycle_count_programs
+            (tenant_id, a_cutoff_pct, b_cutoff_pct, a_counts_per_year, b_counts_per_year,
+             c_counts_per_year, lookback_days, reclassify_interval_days, is_active, updated_by)
+        VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)
+        ON CONFLICT (tenant_id) DO UPDATE
+            SET a_cutoff_pct             = EXCLUDED.a_cutoff_pct,
+                b_cutoff_pct             = EXCLUDED.b_cutoff_pct,
+                a_counts_per_year        = EXCLUDED.a_counts_per_year,
+                b_counts_per_year        = EXCLUDED.b_counts_per_year,
+                c_counts_per_year        = EXCLUDED.c_counts_per_year,
+                lookback_days            = EXCLUDED.lookback_days,
+                reclassify_interval_days = EXCLUDED.reclassify_interval_days,
+                is_active                = EXCLUDED.is_active,
+                updated_by               = EXCLUDED.updated_by,
+                updated_at               = NOW()
+        RETURNING *
+        "#,
+    )
+    .bind(tenant_id)
+    .bind(req.a_cutoff_pct)
+    .bind(req.b_cutoff_pct)
+    .bind(req.a_counts_per_year)
+    .bind(req.b_counts_per_year)
+    .bind(req.c_counts_per_year)
+    .bind(req.lookback_days)
+    .bind(req.reclassify_interval_days)
+    .bind(req.is_active)
+    .bind(&req.updated_by)
+    .fetch_one(pool)
+    .await
+}
+
+pub async fn find_program(
+    pool: &PgPool,
+    tenant_id: &str,
+) -> Result<Option<CycleCountProgram>, sqlx::Error> {
+    sqlx::query_as::<_, CycleCountProgram>(
+        "SELECT * FROM inv_cycle_count_programs WHERE tenant_id = $1",
+    )
+    .bind(tenant_id)
+    .fetch_optional(pool)
+    .await
+}
+
+pub(crate) async fn list_active_programs(
+    pool: &PgPool,
+) -> Result<Vec<CycleCountProgram>, sqlx::Error> {
+    sqlx::query_as::<_, CycleCountProgram>(
+        "SELECT * FROM inv_cycle_count_programs WHERE is_active ORDER BY tenant_id",
+    )
+    .fetch_all(pool)
+    .await
+}
+
+// ============================================================================
+// ABC runs
+// ============================================================================
+
+#[derive(sqlx::FromRow)]
+struct UsageRow {
+    item_id: Uuid,
+    usage_quantity: i64,
+    usage_value_minor: i64,
+}
+
+/// Issued quantity and cost per active item in (`from`, `to`]. Items without
+/// issues are included with zero usage.
+pub(crate) async fn item_usage(
+    tx: &mut Transaction<'_, Postgres>,
+    tenant_id: &str,
+    from: DateTime<Utc>,
+    to: DateTime<Utc>,
+) -> Result<Vec<ItemUsage>, sqlx::Error> {
+    let rows = sqlx::query_as::<_, UsageRow>(
+        r#"
+        SELECT i.id AS item_id,
+               COALESCE(u.qty, 0)::BIGINT   AS usage_quantity,
+               COALESCE(u.value, 0)::BIGINT AS usage_value_minor
+        FROM items i
+        LEFT JOIN (
+            SELECT le.item_id,
+                   SUM(lc.quantity_consumed) AS qty,
+                   SUM(lc.quantity_consumed * lc.unit_cost_minor) AS value
+            FROM layer_consumptions lc
+            JOIN inventory_ledger le ON le.id = lc.ledger_entry_id
+            WHERE le.tenant_id = $1
+              AND le.entry_type = 'issued'
+              AND le.posted_at > $2
+              AND le.posted_at <= $3
+            GROUP BY le.item_id
+        ) u ON u.item_id = i.id
+        WHERE i.tenant_id = $1 AND i.active = TRUE
+        "#,
+    )
+    .bind(tenant_id)
+    .bind(from)
+    .bind(to)
+    .fetch_all(&mut **tx)
+    .await?;
+    Ok(rows
+        .into_iter()
+        .map(|r| ItemUsage {
+            item_id: r.item_id,
+            usage_quantity: r.usage_quantity,
+            usage_value_minor: r.usage_value_minor,
+        })
+        .collect())
+}
+
+/// Current ABC class code per item.
+pub(crate) async fn current_classes(
+    tx: &mut Transaction<'_, Postgres>,
+    tenant_id: &str,
+) -> Result<Vec<(Uuid, String)>, sqlx::Error> {
+    sqlx::query_as::<_, (Uuid, String)>(
+        r#"
+        SELECT DISTINCT ON (item_id) item_id, classification_code
+        FROM item_classifications
+        WHERE tenant_id = $1 AND classification_system = $2
+        ORDER BY item_id, created_at DESC
+        "#,
+    )
+    .bind(tenant_id)
+    .bind(ABC_CLASSIFICATION_SYSTEM)
+    .fetch_all(&mut **tx)
+    .await
+}
+
+pub(crate) async fn insert_run(
+    tx: &mut Transaction<'_, Postgres>,
+    run: &AbcRun,
+) -> Result<AbcRun, sqlx::Error> {
+    sqlx::query_as::<_, AbcRun>(
+        r#"
+        INSERT INTO inv_abc_runs
+            (id, tenant_id, as_of, lookback_days, a_cutoff_pct, b_cutoff_pct,
+             total_usage_value_minor, item_count, a_count, b_count, c_count,
+             reclassified_count, created_by, created_at)
+        VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14)
+        RETURNING *
+        "#,
+    )
+    .bind(run.id)
+    .bind(&run.tenant_id)
+    .bind(run.as_of)
+    .bind(run.lookback_days)
+    .bind(run.a_cutoff_pct)
+    .bind(run.b_cutoff_pct)
+    .bind(run.total_usage_value_minor)
+    .bind(run.item_count)
+    .bind(run.a_count)
+    .bind(run.b_count)
+    .bind(run.c_count)
+    .bind(run.reclassified_count)
+    .bind(&run.created_by)
+    .bind(run.created_at)
+    .fetch_one(&mut **tx)
+    .await
+}
+
+pub(crate) async fn insert_run_line(
+    tx: &mut Transaction<'_, Postgres>,
+    run_id: Uuid,
+    tenant_id: &str,
+    item: &RankedItem,
+    previous_class: Option<&str>,
+) -> Result<(), sqlx::Error> {
+    sqlx::query(
+        r#"
+        INSERT INTO inv_abc_run_lines
+            (run_id, tenant_id, item_id, rank, usage_quantity, usage_value_minor,
+             cumulative_pct, abc_class, previous_class)
+        VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
+        "#,
+    )
+    .bind(run_id)
+    .bind(tenant_id)
+    .bind(item.item_id)
+    .bind(item.rank)
+    .bind(item.usage_quantity)
+    .bind(item.usage_value_minor)
+    .bind(item.cumulative_pct)
+    .bind(item.class.as_str())
+    .bind(previous_class)
+    .execute(&mut **tx)
+    .await?;
+    Ok(())
+}
+
+pub async fn find_run(
+    pool: &PgPool,
+    tenant_id: &str,
+    run_id: Uuid,
+) -> Result<Option<AbcRun>, sqlx::Error> {
+    sqlx::query_as::<_, AbcRun>("SELECT * FROM inv_abc_runs WHERE tenant_id = $1 AND id = $2")
+        .bind(tenant_id)
+        .bind(run_id)
+        .fetch_optional(pool)
+        .await
+}
+
+pub async fn list_run_lines(
+    pool: &PgPool,
+    tenant_id: &str,
+    run_id: Uuid,
+) -> Result<Vec<AbcRunLine>, sqlx::Error> {
+    sqlx::query_as::<_, AbcRunLine>(
+        r#"
+        SELECT l.item_id, i.sku, l.rank, l.usage_quantity, l.usage_value_minor,
+               l.cumulative_pct, l.abc_class, l.previous_class
+        FROM inv_abc_run_lines l
+        JOIN items i ON i.id = l.item_id
+        WHERE l.tenant_id = $1 AND l.run_id = $2
+        ORDER BY l.rank
+        "#,
+    )
+    .bind(tenant_id)
+    .bind(run_id)
+    .fetch_all(pool)
+    .await
+}
+
+/// `as_of` of the tenant's latest ABC run.
+pub(crate) async fn latest_run_as_of(
+    pool: &PgPool,
+    tenant_id: &str,
+) -> Result<Option<DateTime<Utc>>, sqlx::Error> {
+    sqlx::query_scalar("SELECT MAX(as_of) FROM inv_abc_runs WHERE tenant_id = $1")
+        .bind(tenant_id)
+        .fetch_one(pool)
+        .await
+}
+
+// ============================================================================
+// Count schedule
+// ============================================================================
+
+/// Bring scheduled (warehouse, item) rows in line with the items' current
+/// ABC class. A row whose class changed keeps its due date unless the new
+/// interval makes it due sooner.
+pub(crate) async fn reclass_schedule(
+    tx: &mut Transaction<'_, Postgres>,
+    program: &CycleCountProgram,
+    today: NaiveDate,
+) -> Result<u64, sqlx::Error> {
+    let result = sqlx::query(
+        r#"
+        WITH cls AS (
+            SELECT DISTINCT ON (item_id) item_id, classification_code AS abc_class
+            FROM item_classifications
+            WHERE tenant_id = $1 AND classification_system = $2
+            ORDER BY item_id, created_at DESC
+        ), target AS (
+            SELECT s.warehouse_id, s.item_id, c.abc_class,
+                   CASE c.abc_class WHEN 'A' THEN $3 WHEN 'B' THEN $4 ELSE $5 END AS interval_days
+            FROM inv_cycle_count_schedule s
+            JOIN cls c ON c.item_id = s.item_id
+            WHERE s.tenant_id = $1 AND s.abc_class <> c.abc_class
+        )
+        UPDATE inv_cycle_count_schedule s
+        SET abc_class     = t.abc_class,
+            interval_days = t.interval_days,
+            next_due_date = LEAST(
+                s.next_due_date,
+                COALESCE(s.last_counted_at::DATE, $6::DATE) + t.interval_days),
+            updated_at    = NOW()
+        FROM target t
+        WHERE s.tenant_id = $1 AND s.warehouse_id = t.warehouse_id AND s.item_id = t.item_id
+        "#,
+    )
+    .bind(&program.tenant_id)
+    .bind(ABC_CLASSIFICATION_SYSTEM)
+    .bind(program.interval_days(AbcClass::A))
+    .bind(program.interval_days(AbcClass::B))
+    .bind(program.interval_days(AbcClass::C))
+    .bind(today)
+    .execute(&mut **tx)
+    .await?;
+    Ok(result.rows_affected())
+}
+
+/// Add schedule rows for (warehouse, item) pairs stocked in a bin but not yet
+/// scheduled. First due dates are spread over the class interval so a class
+/// is counted evenly across days rather than all at once; unclassified items
+/// count as C.
+pub(crate) async fn seed_schedule(
+    tx: &mut Transaction<'_, Postgres>,
+    program: &CycleCountProgram,
+    warehouse_id: Option<Uuid>,
+    today: NaiveDate,
+) -> Result<u64, sqlx::Error> {
+    let result = sqlx::query(
+        r#"
+        WITH cls AS (
+            SELECT DISTINCT ON (item_id) item_id, classification_code AS abc_class
+            FROM item_classifications
+            WHERE tenant_id = $1 AND classification_system = $2
+            ORDER BY item_id, created_at DESC
+        ), fresh AS (
+            SELECT DISTINCT oh.warehouse_id, oh.item_id, COALESCE(c.abc_class, 'C') AS abc_class
+            FROM item_on_hand oh
+            LEFT JOIN cls c ON c.item_id = oh.item_id
+            WHERE oh.tenant_id = $1
+              AND ($3::UUID IS NULL OR oh.warehouse_id = $3)
+              AND oh.location_id IS NOT NULL
+              AND oh.quantity_on_hand > 0
+              AND NOT EXISTS (
+                  SELECT 1 FROM inv_cycle_count_schedule s
+                  WHERE s.tenant_id = $1
+                    AND s.warehouse_id = oh.warehouse_id
+                    AND s.item_id = oh.item_id)
+        ), sized AS (
+            SELECT f.*,
+                   CASE f.abc_class WHEN 'A' THEN $4 WHEN 'B' THEN $5 ELSE $6 END AS interval_days,
+                   ROW_NUMBER() OVER (PARTITION BY f.warehouse_id, f.abc_class ORDER BY f.item_id) - 1
+                       AS slot
+            FROM fresh f
+        )
+        INSERT INTO inv_cycle_count_schedule
+            (tenant_id, warehouse_id, item_id, abc_class, interval_days, next_due_date)
+        SELECT $1, warehouse_id, item_id, abc_class, interval_days,
+               $7::DATE + (slot % interval_days)::INTEGER
+        FROM sized
+        ON CONFLICT (tenant_id, warehouse_id, item_id) DO NOTHING
+        "#,
+    )
+    .bind(&program.tenant_id)
+    .bind(ABC_CLASSIFICATION_SYSTEM)
+    .bind(warehouse_id)
+    .bind(program.interval_days(AbcClass::A))
+    .bind(program.interval_days(AbcClass::B))
+    .bind(program.interval_days(AbcClass::C))
+    .bind(today)
+    .execute(&mut **tx)
+    .await?;
+    Ok(result.rows_affected())
+}
+
+/// A due (warehouse, item) and one active bin holding it.
+#[derive(Debug, sqlx::FromRow)]
+pub(crate) struct DueBinItem {
+    pub warehouse_id: Uuid,
+    pub location_id: Uuid,
+    pub item_id: Uuid,
+}
+
+/// Items due on or before `day`, by the active bins holding them, skipping
+/// items already on an open or submitted count in that warehouse.
+pub(crate) async fn due_bin_items(
+    tx: &mut Transaction<'_, Postgres>,
+    tenant_id: &str,
+    warehouse_id: Option<Uuid>,
+    day: NaiveDate,
+) -> Result<Vec<DueBinItem>, sqlx::Error> {
+    sqlx::query_as::<_, DueBinItem>(
+        r#"
+        SELECT s.warehouse_id, oh.location_id, s.item_id
+        FROM inv_cycle_count_schedule s
+        JOIN item_on_hand oh
+          ON oh.tenant_id = s.tenant_id
+         AND oh.warehouse_id = s.warehouse_id
+         AND oh.item_id = s.item_id
+         AND oh.location_id IS NOT NULL
+         AND oh.quantity_on_hand > 0
+        JOIN locations loc ON loc.id = oh.location_id AND loc.is_active = TRUE
+        WHERE s.tenant_id = $1
+          AND ($2::UUID IS NULL OR s.warehouse_id = $2)
+          AND s.next_due_date <= $3
+          AND NOT EXISTS (
+              SELECT 1
+              FROM cycle_count_lines l
+              JOIN cycle_count_tasks t ON t.id = l.task_id
+              WHERE t.tenant_id = s.tenant_id
+                AND t.warehouse_id = s.warehouse_id
+                AND l.item_id = s.item_id
+                AND t.status IN ('open', 'submitted'))
+        ORDER BY s.warehouse_id, loc.code, s.item_id
+        "#,
+    )
+    .bind(tenant_id)
+    .bind(warehouse_id)
+    .bind(day)
+    .fetch_all(&mut **tx)
+    .await
+}
+
+/// Insert a scheduled partial task; None when the bin already has one that day.
+pub(crate) async fn insert_scheduled_task(
+    tx: &mut Transaction<'_, Postgres>,
+    tenant_id: &str,
+    warehouse_id: Uuid,
+    location_id: Uuid,
+    day: NaiveDate,
+) -> Result<Option<Uuid>, sqlx::Error> {
+    sqlx::query_scalar(
+        r#"
+        INSERT INTO cycle_count_tasks
+            (tenant_id, warehouse_id, location_id, scope, status, scheduled_for)
+        VALUES ($1, $2, $3, 'partial'::cycle_count_scope, 'open', $4)
+        ON CONFLICT (tenant_id, location_id, scheduled_for)
+            WHERE scheduled_for IS NOT NULL
+            DO NOTHING
+        RETURNING id
+        "#,
+    )
+    .bind(tenant_id)
+    .bind(warehouse_id)
+    .bind(location_id)
+    .bind(day)
+    .fetch_optional(&mut **tx)
+    .await
+}
+
+/// Record a count of the items: next due = count date + class interval.
+pub(crate) async fn mark_counted(
+    tx: &mut Transaction<'_, Postgres>,
+    tenant_id: &str,
+    warehouse_id: Uuid,
+    item_ids: &[Uuid],
+    counted_at: DateTime<Utc>,
+) -> Result<(), sqlx::Error> {
+    sqlx::query(
+        r#"
+        UPDATE inv_cycle_count_schedule
+        SET last_counted_at = $4,
+            next_due_date   = $4::DATE + interval_days,
+            updated_at      = NOW()
+        WHERE tenant_id = $1 AND warehouse_id = $2 AND item_id = ANY($3)
+        "#,
+    )
+    .bind(tenant_id)
+    .bind(warehouse_id)
+    .bind(item_ids)
+    .bind(counted_at)
+    .execute(&mut **tx)
+    .await?;
+    Ok(())
+}
+
+// ============================================================================
+// Accuracy
+// ============================================================================
+
+#[derive(sqlx::FromRow)]
+struct AccuracyRow {
+    abc_class: Option<String>,
+    location_id: Uuid,
+    lines_counted: i64,
+    hits: i64,
+    expected_value_minor: i64,
+    net_variance_value_minor: i64,
+    abs_variance_value_minor: i64,
+}
+
+/// Approved count lines in [`from`, `to`) aggregated by class and bin.
+/// Derived rates are left to the caller.
+pub(crate) async fn accuracy_by_class_location(
+    pool: &PgPool,
+    tenant_id: &str,
+    warehouse_id: Uuid,
+    from: DateTime<Utc>,
+    to: DateTime<Utc>,
+) -> Result<Vec<AccuracyBucket>, sqlx::Error> {
+    let rows = sqlx::query_as::<_, AccuracyRow>(
+        r#"
+        SELECT l.abc_class,
+               t.location_id,
+               COUNT(*)::BIGINT AS lines_counted,
+               COUNT(*) FILTER (WHERE COALESCE(l.counted_qty, l.expected_qty) = l.expected_qty)::BIGINT
+                   AS hits,
+               COALESCE(SUM(l.expected_qty * COALESCE(l.unit_cost_minor, 0)), 0)::BIGINT
+                   AS expected_value_minor,
+               COALESCE(SUM((COALESCE(l.counted_qty, l.expected_qty) - l.expected_qty)
+                            * COALESCE(l.unit_cost_minor, 0)), 0)::BIGINT
+                   AS net_variance_value_minor,
+               COALESCE(SUM(ABS(COALESCE(l.counted_qty, l.expected_qty) - l.expected_qty)
+                            * COALESCE(l.unit_cost_minor, 0)), 0)::BIGINT
+                   AS abs_variance_value_minor
+        FROM cycle_count_lines l
+        JOIN cycle_count_tasks t ON t.id = l.task_id
+        WHERE t.tenant_id = $1
+          AND t.warehouse_id = $2
+          AND t.status = 'approved'
+          AND t.approved_at >= $3
+          AND t.approved_at < $4
+        GROUP BY l.abc_class, t.location_id
+        ORDER BY l.abc_class NULLS LAST, t.location_id
+        "#,
+    )
+    .bind(tenant_id)
+    .bind(warehouse_id)
+    .bind(from)
+    .bind(to)
+    .fetch_all(pool)
+    .await?;
+    Ok(rows
+        .into_iter()
+        .map(|r| AccuracyBucket {
+            abc_class: r.abc_class,
+            location_id: Some(r.location_id),
+            lines_counted: r.lines_counted,
+            hits: r.hits,
+            expected_value_minor: r.expected_value_minor,
+            net_variance_value_minor: r.net_variance_value_minor,
+            abs_variance_value_minor: r.abs_variance_value_minor,
+            ..Default::default()
+        })
+        .collect())
+}
+
+/// Schedule rows of a warehouse, soonest due first.
+pub async fn list_schedule(
+    pool: &PgPool,
+    tenant_id: &str,
+    warehouse_id: Uuid,
+    due_on_or_before: Option<NaiveDate>,
+    limit: i64,
+    offset: i64,
+) -> Result<(Vec<CountScheduleEntry>, i64), sqlx::Error> {
+    let rows = sqlx::query_as::<_, CountScheduleEntry>(
+        r#"
+        SELECT warehouse_id, item_id, abc_class, interval_days, last_counted_at, next_due_date
+        FROM inv_cycle_count_schedule
+        WHERE tenant_id = $1 AND warehouse_id = $2
+          AND ($3::DATE IS NULL OR next_due_date <= $3)
+        ORDER BY next_due_date, item_id
+        LIMIT $4 OFFSET $5
+        "#,
+    )
+    .bind(tenant_id)
+    .bind(warehouse_id)
+    .bind(due_on_or_before)
+    .bind(limit)
+    .bind(offset)
+    .fetch_all(pool)
+    .await?;
+    let total: i64 = sqlx::query_scalar(
+        r#"
+        SELECT COUNT(*) FROM inv_cycle_count_schedule
+        WHERE tenant_id = $1 AND warehouse_id = $2
+          AND ($3::DATE IS NULL OR next_due_date <= $3)
+        "#,
+    )
+    .bind(tenant_id)
+    .bind(warehouse_id)
+    .bind(due_on_or_before)
+    .fetch_one(pool)
+    .await?;
+    Ok((rows, total))
+}
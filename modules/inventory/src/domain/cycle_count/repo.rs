@@ -1,4 +1,5 @@
-//! Cycle count repository — database operations for cycle count approval.
+//! Cycle count repository — database operations for cycle count tasks and
+//! approval.
 //!
 //! All transactional functions accept `&mut Transaction<'_, Postgres>` to
 //! participate in the Guard → Mutation → Outbox atomic unit orchestrated by
@@ -87,6 +88,54 @@ pub(crate) async fn store_idempotency_key(
     Ok(())
 }
 
+// ============================================================================
+// Task lines
+// ============================================================================
+
+/// Insert a count line for an item in a bin, snapshotting the expected
+/// quantity and average unit cost from `item_on_hand` and the item's ABC
+/// class (schedule row, else classification). Returns (line id, expected qty).
+pub(crate) async fn insert_line_snapshot(
+    tx: &mut Transaction<'_, Postgres>,
+    task_id: Uuid,
+    tenant_id: &str,
+    item_id: Uuid,
+    warehouse_id: Uuid,
+    location_id: Uuid,
+) -> Result<(Uuid, i64), sqlx::Error> {
+    sqlx::query_as::<_, (Uuid, i64)>(
+        r#"
+        INSERT INTO cycle_count_lines
+            (task_id, tenant_id, item_id, expected_qty, unit_cost_minor, abc_class)
+        SELECT $1, $2, $3,
+               COALESCE(oh.quantity_on_hand, 0),
+               CASE WHEN oh.quantity_on_hand > 0
+                    THEN oh.total_cost_minor / oh.quantity_on_hand END,
+               COALESCE(s.abc_class, (
+                   SELECT c.classification_code
+                   FROM item_classifications c
+                   WHERE c.tenant_id = $2 AND c.item_id = $3
+                     AND c.classification_system = 'abc'
+                   ORDER BY c.created_at DESC
+                   LIMIT 1))
+        FROM (SELECT 1) AS one
+        LEFT JOIN item_on_hand oh
+               ON oh.tenant_id = $2 AND oh.item_id = $3
+              AND oh.warehouse_id = $4 AND oh.location_id = $5
+        LEFT JOIN inv_cycle_count_schedule s
+               ON s.tenant_id = $2 AND s.warehouse_id = $4 AND s.item_id = $3
+        RETURNING id, expected_qty
+        "#,
+    )
+    .bind(task_id)
+    .bind(tenant_id)
+    .bind(item_id)
+    .bind(warehouse_id)
+    .bind(location_id)
+    .fetch_one(&mut **tx)
+    .await
+}
+
 // ============================================================================
 // Guard reads
 // ============================================================================
@@ -303,7 +352,7 @@ pub(crate) async fn update_task_approved(
     sqlx::query(
         r#"
         UPDATE cycle_count_tasks
-        SET status = 'approved', updated_at = $1
+        SET status = 'approved', updated_at = $1, approved_at = $1
         WHERE id = $2 AND tenant_id = $3
         "#,
     )
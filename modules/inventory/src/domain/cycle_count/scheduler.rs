@@ -0,0 +1,162 @@
+//! Cycle-count program scheduler.
+//!
+//! Design:
+//! - Runs on a configurable interval (INVENTORY_CYCLE_COUNT_SCHED_INTERVAL_SECS,
+//!   default 3600s). Each tick, for every active program:
+//!   - re-runs the ABC analysis when the last run is older than the program's
+//!     `reclassify_interval_days` (or there is none);
+//!   - generates today's count tasks.
+//! - Generating tasks for a day:
+//!   - schedules stocked (warehouse, item) pairs that have no schedule row yet,
+//!     spreading their first due dates over the class interval;
+//!   - takes items due on or before the day that are not already on an open or
+//!     submitted count, and creates one partial task per bin holding them
+//!     (`scheduled_for` = the day), with lines snapshotted like manual tasks.
+//! - Approving a task advances its items' next due date by the class interval,
+//!   so every item is counted at its class frequency.
+//! - Idempotency: at most one scheduled task per bin and day, and items on an
+//!   open count are skipped, so re-running a day creates nothing new.
+
+use chrono::{DateTime, Duration, NaiveDate, Utc};
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use super::abc_service::run_abc_analysis;
+use super::program::{GenerateCountTasksResult, ProgramError, RunAbcRequest};
+use super::{program_repo, repo};
+
+/// Result of a single scheduler tick.
+#[derive(Debug, Default)]
+pub struct TickResult {
+    pub programs: usize,
+    pub abc_runs: usize,
+    pub tasks_created: usize,
+    pub items_scheduled: usize,
+}
+
+/// Create the count tasks due on `day` for the tenant (one warehouse, or all).
+pub async fn generate_count_tasks(
+    pool: &PgPool,
+    tenant_id: &str,
+    warehouse_id: Option<Uuid>,
+    day: NaiveDate,
+) -> Result<GenerateCountTasksResult, ProgramError> {
+    let program = program_repo::find_program(pool, tenant_id)
+        .await?
+        .ok_or(ProgramError::ProgramNotFound)?;
+    if !program.is_active {
+        return Err(ProgramError::ProgramInactive);
+    }
+
+    let mut tx = pool.begin().await?;
+
+    program_repo::seed_schedule(&mut tx, &program, warehouse_id, day).await?;
+    let due = program_repo::due_bin_items(&mut tx, tenant_id, warehouse_id, day).await?;
+
+    let mut result = GenerateCountTasksResult {
+        scheduled_for: Some(day),
+        ..Default::default()
+    };
+    let mut current: Option<(Uuid, Uuid, Option<Uuid>)> = None;
+    for row in &due {
+        let task_id = match current {
+            Some((wh, loc, task_id)) if wh == row.warehouse_id && loc == row.location_id => task_id,
+            _ => {
+                let task_id = program_repo::insert_scheduled_task(
+                    &mut tx,
+                    tenant_id,
+                    row.warehouse_id,
+                    row.location_id,
+                    day,
+                )
+                .await?;
+                if let Some(id) = task_id {
+                    result.task_ids.push(id);
+                }
+                current = Some((row.warehouse_id, row.location_id, task_id));
+                task_id
+            }
+        };
+        // The bin already had a task for this day: its items wait for tomorrow.
+        let Some(task_id) = task_id else { continue };
+        repo::insert_line_snapshot(
+            &mut tx,
+            task_id,
+            tenant_id,
+            row.item_id,
+            row.warehouse_id,
+            row.location_id,
+        )
+        .await?;
+        result.items_scheduled += 1;
+    }
+    result.tasks_created = result.task_ids.len();
+
+    tx.commit().await?;
+    Ok(result)
+}
+
+/// One pass over all active programs at `now`.
+pub async fn run_tick(pool: &PgPool, now: DateTime<Utc>) -> Result<TickResult, ProgramError> {
+    let mut result = TickResult::default();
+    for program in program_repo::list_active_programs(pool).await? {
+        result.programs += 1;
+        let tenant_id = program.tenant_id.clone();
+
+        let last_run = program_repo::latest_run_as_of(pool, &tenant_id).await?;
+        let stale = last_run
+            .is_none_or(|t| now - t >= Duration::days(program.reclassify_interval_days as i64));
+        if stale {
+            let req = RunAbcRequest {
+                as_of: Some(now),
+                created_by: Some("cycle-count-scheduler".to_string()),
+            };
+            match run_abc_analysis(pool, &program, &req).await {
+                Ok(_) => result.abc_runs += 1,
+                Err(e) => {
+                    tracing::error!(tenant_id = %tenant_id, error = %e, "ABC analysis failed");
+                    continue;
+                }
+            }
+        }
+
+        match generate_count_tasks(pool, &tenant_id, None, now.date_naive()).await {
+            Ok(r) => {
+                result.tasks_created += r.tasks_created;
+                result.items_scheduled += r.items_scheduled;
+            }
+            Err(e) => {
+                tracing::error!(tenant_id = %tenant_id, error = %e, "Cycle count generation failed");
+            }
+        }
+    }
+    Ok(result)
+}
+
+/// Background loop driving [`run_tick`].
+pub async fn run_scheduler_task(pool: PgPool, interval_secs: u64) {
+    tracing::info!(
+        interval_secs = interval_secs,
+        "Inventory: starting cycle count scheduler"
+    );
+
+    let mut interval = tokio::time::interval(std::time::Duration::from_secs(interval_secs));
+    loop {
+        interval.tick().await;
+        match run_tick(&pool, Utc::now()).await {
+            Ok(r) if r.tasks_created > 0 || r.abc_runs > 0 => {
+                tracing::info!(
+                    programs = r.programs,
+                    abc_runs = r.abc_runs,
+                    tasks_created = r.tasks_created,
+                    items_scheduled = r.items_scheduled,
+                    "Cycle count scheduler: tasks generated"
+                );
+            }
+            Ok(_) => {}
+            Err(e) => {
+                tracing::error!(error = %e, "Cycle count scheduler: tick failed");
+            }
+        }
+    }
+}
@@ -17,6 +17,8 @@ use thiserror::Error;
 use utoipa::ToSchema;
 use uuid::Uuid;
 
+use super::repo;
+
 // ============================================================================
 // Types
 // ============================================================================
@@ -154,40 +156,17 @@ pub async fn create_cycle_count_task(
         TaskScope::Partial => req.item_ids.clone(),
     };
 
-    // Insert lines with snapshotted expected_qty
+    // Insert lines with snapshotted expected_qty (plus unit cost and ABC class)
     let mut lines: Vec<TaskLine> = Vec::with_capacity(item_ids.len());
     for item_id in &item_ids {
-        let expected_qty: i64 = sqlx::query_scalar(
-            r#"
-            SELECT COALESCE(quantity_on_hand, 0)
-            FROM item_on_hand
-            WHERE tenant_id   = $1
-              AND item_id     = $2
-              AND warehouse_id = $3
-              AND location_id  = $4
-            "#,
-        )
-        .bind(&req.tenant_id)
-        .bind(item_id)
-        .bind(req.warehouse_id)
-        .bind(req.location_id)
-        .fetch_optional(&mut *tx)
-        .await?
-        .unwrap_or(0);
-
-        let line_id = sqlx::query_scalar::<_, Uuid>(
-            r#"
-            INSERT INTO cycle_count_lines
-                (task_id, tenant_id, item_id, expected_qty)
-            VALUES ($1, $2, $3, $4)
-            RETURNING id
-            "#,
+        let (line_id, expected_qty) = repo::insert_line_snapshot(
+            &mut tx,
+            task_id,
+            &req.tenant_id,
+            *item_id,
+            req.warehouse_id,
+            req.location_id,
         )
-        .bind(task_id)
-        .bind(&req.tenant_id)
-        .bind(item_id)
-        .bind(expected_qty)
-        .fetch_one(&mut *tx)
         .await?;
 
         lines.push(TaskLine {
@@ -4,12 +4,14 @@
 //! inline `json!` error construction.  Status codes and error codes match the
 //! pre-migration handler behaviour exactly — no semantic changes.
 
+use label_render::RenderError;
 use platform_http_contracts::ApiError;
 
 use super::adjust_service::AdjustError;
 use super::barcode_resolver::BarcodeError;
 use super::classifications::ClassificationError;
 use super::cycle_count::approve_service::ApproveError;
+use super::cycle_count::program::ProgramError;
 use super::cycle_count::submit_service::SubmitError;
 use super::cycle_count::task_service::TaskError;
 use super::expiry::ExpiryError;
@@ -20,10 +22,13 @@ use super::guards::GuardError;
 use super::history::change_history::ChangeHistoryError;
 use super::issue_service::IssueError;
 use super::items::ItemError;
+use super::label_printing::models::LabelPrintingError;
 use super::labels::LabelError;
 use super::locations::LocationError;
 use super::lots_serials::issue::LotSerialError;
 use super::make_buy::MakeBuyError;
+use super::picking::models::PickError;
+use super::putaway::models::PutawayError;
 use super::receipt_service::ReceiptError;
 use super::reorder::models::ReorderPolicyError;
 use super::reservation_service::ReservationError;
@@ -32,6 +37,7 @@ use super::status::transfer_service::StatusTransferError;
 use super::transfer_service::TransferError;
 use super::uom::convert::ConvertError;
 use super::uom::models::UomError;
+use super::valuation::landed_cost::models::LandedCostError;
 use super::valuation::run_service::RunError;
 use super::valuation::snapshot_service::SnapshotError;
 
@@ -121,6 +127,43 @@ impl From<LabelError> for ApiError {
     }
 }
 
+// ── LabelPrintingError ────────────────────────────────────────────────────
+
+impl From<LabelPrintingError> for ApiError {
+    fn from(err: LabelPrintingError) -> Self {
+        match err {
+            LabelPrintingError::TemplateNotFound | LabelPrintingError::LabelNotFound => {
+                ApiError::not_found(err.to_string())
+            }
+            LabelPrintingError::DuplicateTemplateName(_)
+            | LabelPrintingError::DuplicateDefault(_)
+            | LabelPrintingError::ConflictingIdempotencyKey => ApiError::conflict(err.to_string()),
+            LabelPrintingError::SsccNotConfigured => {
+                ApiError::new(422, "sscc_not_configured", err.to_string())
+            }
+            LabelPrintingError::SsccExhausted(_) => {
+                ApiError::new(422, "sscc_exhausted", err.to_string())
+            }
+            LabelPrintingError::Render(RenderError::Pdf(e)) => {
+                tracing::error!(error = %e, "label PDF rendering failed");
+                ApiError::internal("PDF rendering failed")
+            }
+            LabelPrintingError::Render(e) => {
+                ApiError::new(422, "label_render_error", e.to_string())
+            }
+            LabelPrintingError::Validation(msg) => ApiError::new(422, "validation_error", msg),
+            LabelPrintingError::Serialization(e) => {
+                tracing::error!(error = %e, "label printing serialization error");
+                ApiError::internal("Serialization error")
+            }
+            LabelPrintingError::Database(e) => {
+                tracing::error!(error = %e, "label printing database error");
+                ApiError::internal("Database error")
+            }
+        }
+    }
+}
+
 // ── RevisionError ─────────────────────────────────────────────────────────
 
 impl From<RevisionError> for ApiError {
@@ -325,9 +368,12 @@ impl From<IssueError> for ApiError {
                 ApiError::new(422, "validation_error", err.to_string())
             }
             IssueError::LotNotFound(_) => ApiError::not_found(err.to_string()),
+            IssueError::LotQuarantined(_) => ApiError::new(422, "lot_quarantined", err.to_string()),
             IssueError::SerialNotAvailable(_) => {
                 ApiError::new(422, "serial_not_available", err.to_string())
             }
+            IssueError::ReservationNotFound(_) => ApiError::not_found(err.to_string()),
+            IssueError::ReservationSettled(_) => ApiError::conflict(err.to_string()),
             IssueError::Serialization(e) => {
                 tracing::error!(error = %e, "serialization error in issue");
                 ApiError::internal("Serialization error")
@@ -647,3 +693,117 @@ impl From<ApproveError> for ApiError {
         }
     }
 }
+
+// ── ProgramError (cycle-count program) ────────────────────────────────────
+
+impl From<ProgramError> for ApiError {
+    fn from(err: ProgramError) -> Self {
+        match err {
+            ProgramError::Validation(msg) => ApiError::new(422, "validation_error", msg),
+            ProgramError::ProgramNotFound | ProgramError::RunNotFound => {
+                ApiError::not_found(err.to_string())
+            }
+            ProgramError::ProgramInactive => {
+                ApiError::new(422, "program_inactive", err.to_string())
+            }
+            ProgramError::Classification(e) => e.into(),
+            ProgramError::Database(e) => {
+                tracing::error!(error = %e, "database error in cycle-count program");
+                ApiError::internal("Database error")
+            }
+        }
+    }
+}
+
+// ── PickError ─────────────────────────────────────────────────────────────
+
+impl From<PickError> for ApiError {
+    fn from(err: PickError) -> Self {
+        match err {
+            PickError::Guard(e) => e.into(),
+            PickError::Issue(e) => e.into(),
+            PickError::Barcode(e) => e.into(),
+            PickError::Validation(msg) => ApiError::new(422, "validation_error", msg),
+            PickError::TaskNotFound | PickError::WaveNotFound => {
+                ApiError::not_found(err.to_string())
+            }
+            PickError::TaskNotOpen
+            | PickError::ReservationNotActive
+            | PickError::ReservationInUse
+            | PickError::SourceLineTasked(_)
+            | PickError::ConflictingIdempotencyKey => ApiError::conflict(err.to_string()),
+            PickError::NoOpenTasks => ApiError::new(422, "no_open_tasks", err.to_string()),
+            PickError::InsufficientAvailable { .. } => {
+                ApiError::new(422, "insufficient_stock", err.to_string())
+            }
+            PickError::LocationNotFound(_) => {
+                ApiError::new(422, "location_not_found", err.to_string())
+            }
+            PickError::ScanMismatch(_) => ApiError::new(422, "scan_mismatch", err.to_string()),
+            PickError::Serialization(e) => {
+                tracing::error!(error = %e, "serialization error in picking");
+                ApiError::internal("Serialization error")
+            }
+            PickError::Database(e) => {
+                tracing::error!(error = %e, "database error in picking");
+                ApiError::internal("Database error")
+            }
+        }
+    }
+}
+
+// ── PutawayError ──────────────────────────────────────────────────────────
+
+impl From<PutawayError> for ApiError {
+    fn from(err: PutawayError) -> Self {
+        match err {
+            PutawayError::Guard(e) => e.into(),
+            PutawayError::Validation(msg) => ApiError::new(422, "validation_error", msg),
+            PutawayError::RuleNotFound | PutawayError::TaskNotFound => {
+                ApiError::not_found(err.to_string())
+            }
+            PutawayError::TaskNotOpen | PutawayError::ConflictingIdempotencyKey => {
+                ApiError::conflict(err.to_string())
+            }
+            PutawayError::LocationNotFound(_) => {
+                ApiError::new(422, "location_not_found", err.to_string())
+            }
+            PutawayError::InsufficientAtDock { .. } => {
+                ApiError::new(422, "insufficient_stock", err.to_string())
+            }
+            PutawayError::Serialization(e) => {
+                tracing::error!(error = %e, "serialization error in putaway");
+                ApiError::internal("Serialization error")
+            }
+            PutawayError::Database(e) => {
+                tracing::error!(error = %e, "database error in putaway");
+                ApiError::internal("Database error")
+            }
+        }
+    }
+}
+
+// ── LandedCostError ───────────────────────────────────────────────────────
+
+impl From<LandedCostError> for ApiError {
+    fn from(err: LandedCostError) -> Self {
+        match err {
+            LandedCostError::Guard(e) => e.into(),
+            LandedCostError::Validation(msg) => ApiError::new(422, "validation_error", msg),
+            LandedCostError::ReceiptNotFound(_) => {
+                ApiError::new(422, "receipt_not_found", err.to_string())
+            }
+            LandedCostError::VoucherNotFound => ApiError::not_found(err.to_string()),
+            LandedCostError::BillLineAlreadyAllocated(_)
+            | LandedCostError::ConflictingIdempotencyKey => ApiError::conflict(err.to_string()),
+            LandedCostError::Serialization(e) => {
+                tracing::error!(error = %e, "serialization error in landed cost");
+                ApiError::internal("Serialization error")
+            }
+            LandedCostError::Database(e) => {
+                tracing::error!(error = %e, "database error in landed cost");
+                ApiError::internal("Database error")
+            }
+        }
+    }
+}
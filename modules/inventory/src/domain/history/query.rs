@@ -13,6 +13,7 @@
 //! No writes are performed; the function is safe to call concurrently.
 
 use chrono::{DateTime, Utc};
+use security::ScopeSql;
 use serde::Serialize;
 use sqlx::PgPool;
 use utoipa::ToSchema;
@@ -133,3 +134,46 @@ pub async fn query_movement_history(
         }
     }
 }
+
+/// [`query_movement_history`] restricted to the caller's data scope. Build
+/// `scope` with `ScopeFilter::to_sql(4)`, mapping `location` to `warehouse_id`.
+pub async fn query_movement_history_scoped(
+    pool: &PgPool,
+    tenant_id: &str,
+    item_id: Uuid,
+    location_id: Option<Uuid>,
+    scope: &ScopeSql,
+) -> Result<Vec<MovementEntry>, sqlx::Error> {
+    let sql = format!(
+        r#"
+        SELECT id              AS ledger_id,
+               entry_id,
+               warehouse_id,
+               location_id,
+               entry_type::TEXT,
+               quantity,
+               unit_cost_minor,
+               currency,
+               source_event_id,
+               source_event_type,
+               reference_type,
+               reference_id,
+               posted_at
+        FROM inventory_ledger
+        WHERE tenant_id = $1
+          AND item_id   = $2
+          AND ($3::uuid IS NULL OR location_id = $3)
+          AND {}
+        ORDER BY posted_at ASC, id ASC
+        "#,
+        scope.clause
+    );
+    let mut query = sqlx::query_as::<_, MovementEntry>(&sql)
+        .bind(tenant_id)
+        .bind(item_id)
+        .bind(location_id);
+    for values in &scope.binds {
+        query = query.bind(values);
+    }
+    query.fetch_all(pool).await
+}
@@ -2,7 +2,7 @@
 
 use chrono::{Duration, Utc};
 use event_bus::TracingContext;
-use sqlx::PgPool;
+use sqlx::{PgPool, Postgres, Transaction};
 use uuid::Uuid;
 
 use crate::{
@@ -46,6 +46,60 @@ pub async fn process_issue(
         return Ok(replay);
     }
 
+    let correlation_id = req
+        .correlation_id
+        .clone()
+        .unwrap_or_else(|| Uuid::new_v4().to_string());
+
+    let mut tx = pool.begin().await?;
+
+    let result = issue_in_tx(pool, &mut tx, req, &correlation_id, tracing_ctx).await?;
+
+    // --- Store idempotency key (expires in 7 days) ---
+    let response_json = serde_json::to_string(&result)?;
+    store_idempotency_key(
+        &mut tx,
+        &req.tenant_id,
+        &req.idempotency_key,
+        &request_hash,
+        &response_json,
+        201,
+        result.issued_at + Duration::days(7),
+    )
+    .await?;
+
+    tx.commit().await?;
+
+    // Best-effort low-stock signal evaluation (errors do not fail the issue).
+    let _ = evaluator::evaluate_low_stock(
+        pool,
+        &req.tenant_id,
+        req.item_id,
+        req.warehouse_id,
+        req.location_id,
+        &correlation_id,
+        req.causation_id.clone(),
+    )
+    .await;
+
+    Ok((result, false))
+}
+
+/// Post a stock issue inside a caller-owned transaction.
+///
+/// Performs the guard, FIFO and mutation steps of [`process_issue`] and enqueues
+/// the `inventory.item_issued` outbox event, but neither checks nor stores the
+/// idempotency key and does not commit. Used by flows that must post the issue
+/// atomically with their own writes (e.g. pick confirmation).
+pub(crate) async fn issue_in_tx(
+    pool: &PgPool,
+    tx: &mut Transaction<'_, Postgres>,
+    req: &IssueRequest,
+    correlation_id: &str,
+    tracing_ctx: Option<&TracingContext>,
+) -> Result<IssueResult, IssueError> {
+    validate_request(req)?;
+
     // --- Guard: item must exist and be active ---
     let item = guard_item_active(pool, req.item_id, &req.tenant_id).await?;
 
@@ -97,12 +151,12 @@ pub async fn process_issue(
 
     let event_id = Uuid::new_v4();
     let issued_at = Utc::now();
-    let correlation_id = req
-        .correlation_id
-        .clone()
-        .unwrap_or_else(|| Uuid::new_v4().to_string());
 
-    let mut tx = pool.begin().await?;
+    // --- Lock the reservation being consumed; its hold counts as available ---
+    let reserved_for_issue = match req.reservation_id {
+        Some(reservation_id) => lock_reservation(tx, req, reservation_id).await?,
+        None => 0,
+    };
 
     // --- Lock FIFO layers and compute consumed layers ---
     // Branching by tracking mode determines which layers are locked and how
@@ -113,19 +167,22 @@ pub async fn process_issue(
         TrackingMode::Serial => {
             let codes = req.serial_codes.as_deref().unwrap_or(&[]);
             let locked =
-                ls_issue::validate_and_lock_serials(&mut tx, &req.tenant_id, req.item_id, codes)
-                    .await?;
+                ls_issue::validate_and_lock_serials(tx, &req.tenant_id, req.item_id, codes).await?;
             let serial_ids: Vec<Uuid> = locked.iter().map(|s| s.serial_id).collect();
             let consumed_layers = build_consumed_from_serials(&locked);
 
             let (wh_remaining, wh_cost) =
-                fetch_warehouse_totals(&mut tx, &req.tenant_id, req.item_id, req.warehouse_id)
-                    .await?;
+                fetch_warehouse_totals(tx, &req.tenant_id, req.item_id, req.warehouse_id).await?;
 
             (consumed_layers, wh_remaining, wh_cost, serial_ids)
         }
         TrackingMode::Lot => {
             let lid = lot_id.expect("lot_id must be Some for Lot-tracked path");
+            if ls_issue::lock_lot_quarantined(tx, lid).await? {
+                return Err(IssueError::LotQuarantined(
+                    req.lot_code.clone().unwrap_or_default(),
+                ));
+            }
             let layer_rows = sqlx::query_as::<_, LayerRow>(
                 r#"
                     SELECT id, quantity_remaining, unit_cost_minor
@@ -143,7 +200,7 @@ pub async fn process_issue(
             .bind(req.item_id)
             .bind(req.warehouse_id)
             .bind(lid)
-            .fetch_all(&mut *tx)
+            .fetch_all(&mut **tx)
             .await?;
 
             if layer_rows.is_empty() {
@@ -173,11 +230,11 @@ pub async fn process_issue(
             .bind(&req.tenant_id)
             .bind(req.item_id)
             .bind(req.warehouse_id)
-            .fetch_optional(&mut *tx)
+            .fetch_optional(&mut **tx)
             .await?
             .unwrap_or(0i64);
 
-            let net_available = lot_sum - quantity_reserved;
+            let net_available = lot_sum - quantity_reserved + reserved_for_issue;
             if net_available < quantity {
                 return Err(IssueError::InsufficientQuantity {
                     requested: quantity,
@@ -188,8 +245,7 @@ pub async fn process_issue(
             let consumed_layers = fifo::consume_fifo(&available_layers, quantity)?;
 
             let (wh_remaining, wh_cost) =
-                fetch_warehouse_totals(&mut tx, &req.tenant_id, req.item_id, req.warehouse_id)
-                    .await?;
+                fetch_warehouse_totals(tx, &req.tenant_id, req.item_id, req.warehouse_id).await?;
 
             (consumed_layers, wh_remaining, wh_cost, vec![])
         }
@@ -210,7 +266,7 @@ pub async fn process_issue(
             .bind(&req.tenant_id)
             .bind(req.item_id)
             .bind(req.warehouse_id)
-            .fetch_all(&mut *tx)
+            .fetch_all(&mut **tx)
             .await?;
 
             let available_layers: Vec<AvailableLayer> = layer_rows
@@ -244,7 +300,7 @@ pub async fn process_issue(
                 .bind(req.item_id)
                 .bind(req.warehouse_id)
                 .bind(loc_id)
-                .fetch_optional(&mut *tx)
+                .fetch_optional(&mut **tx)
                 .await?
                 .unwrap_or(0i64)
             } else {
@@ -259,11 +315,11 @@ pub async fn process_issue(
                 .bind(&req.tenant_id)
                 .bind(req.item_id)
                 .bind(req.warehouse_id)
-                .fetch_optional(&mut *tx)
+                .fetch_optional(&mut **tx)
                 .await?
                 .unwrap_or(0i64);
 
-                sum_remaining - quantity_reserved
+                sum_remaining - quantity_reserved + reserved_for_issue
             };
 
             if net_available < quantity {
@@ -305,20 +361,20 @@ pub async fn process_issue(
     .bind(&req.source_type)
     .bind(&req.source_id)
     .bind(issued_at)
-    .fetch_one(&mut *tx)
+    .fetch_one(&mut **tx)
     .await?;
 
     let ledger_id = ledger_row.id;
     let issue_line_id = ledger_row.entry_id;
 
     // --- Step 2: Insert layer_consumptions + update layer quantity_remaining ---
-    write_layer_consumptions(&mut tx, &consumed, ledger_id, issued_at)
+    write_layer_consumptions(tx, &consumed, ledger_id, issued_at)
         .await
         .map_err(IssueError::Database)?;
 
     // --- Step 2b: Mark serial instances as issued (after layer writes) ---
     if !serial_ids_to_mark.is_empty() {
-        ls_issue::mark_serials_issued(&mut tx, &serial_ids_to_mark)
+        ls_issue::mark_serials_issued(tx, &serial_ids_to_mark)
             .await
             .map_err(IssueError::Database)?;
     }
@@ -326,7 +382,7 @@ pub async fn process_issue(
     // --- Step 3: Update on-hand projection ---
     if let Some(loc_id) = req.location_id {
         on_hand::decrement_for_issue(
-            &mut tx,
+            tx,
             &req.tenant_id,
             req.item_id,
             req.warehouse_id,
@@ -339,7 +395,7 @@ pub async fn process_issue(
         .map_err(IssueError::Database)?;
 
         on_hand::decrement_available_bucket(
-            &mut tx,
+            tx,
             &req.tenant_id,
             req.item_id,
             req.warehouse_id,
@@ -349,7 +405,7 @@ pub async fn process_issue(
         .map_err(IssueError::Database)?;
     } else {
         on_hand::upsert_after_issue(
-            &mut tx,
+            tx,
             &req.tenant_id,
             req.item_id,
             req.warehouse_id,
@@ -362,7 +418,7 @@ pub async fn process_issue(
         .map_err(IssueError::Database)?;
 
         on_hand::set_available_bucket(
-            &mut tx,
+            tx,
             &req.tenant_id,
             req.item_id,
             req.warehouse_id,
@@ -372,6 +428,11 @@ pub async fn process_issue(
         .map_err(IssueError::Database)?;
     }
 
+    // --- Step 3b: Settle the consumed reservation ---
+    if let Some(reservation_id) = req.reservation_id {
+        settle_reservation(tx, req, reservation_id, reserved_for_issue, issued_at).await?;
+    }
+
     // --- Step 4: Build and enqueue outbox event ---
     let source_ref = SourceRef {
         source_module: req.source_module.clone(),
@@ -399,7 +460,7 @@ pub async fn process_issue(
     let envelope = build_item_issued_envelope(
         event_id,
         req.tenant_id.clone(),
-        correlation_id.clone(),
+        correlation_id.to_string(),
         req.causation_id.clone(),
         payload,
     )
@@ -420,13 +481,13 @@ pub async fn process_issue(
     .bind(req.item_id.to_string())
     .bind(&req.tenant_id)
     .bind(&envelope_json)
-    .bind(&correlation_id)
+    .bind(correlation_id)
     .bind(&req.causation_id)
-    .execute(&mut *tx)
+    .execute(&mut **tx)
     .await?;
 
     // --- Step 5: Build result ---
-    let result = IssueResult {
+    Ok(IssueResult {
         issue_line_id,
         ledger_entry_id: ledger_id,
         event_id,
@@ -440,42 +501,101 @@ pub async fn process_issue(
         consumed_layers: consumed,
         source_ref,
         issued_at,
-    };
+    })
+}
 
-    // --- Step 6: Store idempotency key (expires in 7 days) ---
-    let response_json = serde_json::to_string(&result)?;
-    store_idempotency_key(
-        &mut tx,
-        &req.tenant_id,
-        &req.idempotency_key,
-        &request_hash,
-        &response_json,
-        201,
-        issued_at + Duration::days(7),
+// ============================================================================
+// Helpers
+// ============================================================================
+
+/// Lock an active reservation for this item/warehouse and return its held
+/// quantity. Concurrent issues against the same reservation serialize here;
+/// the loser sees the settling row and fails with `ReservationSettled`.
+async fn lock_reservation(
+    tx: &mut Transaction<'_, Postgres>,
+    req: &IssueRequest,
+    reservation_id: Uuid,
+) -> Result<i64, IssueError> {
+    let quantity: i64 = sqlx::query_scalar(
+        r#"
+        SELECT quantity
+        FROM inventory_reservations
+        WHERE id = $1 AND tenant_id = $2 AND item_id = $3 AND warehouse_id = $4
+          AND reverses_reservation_id IS NULL
+        FOR UPDATE
+        "#,
     )
-    .await?;
+    .bind(reservation_id)
+    .bind(&req.tenant_id)
+    .bind(req.item_id)
+    .bind(req.warehouse_id)
+    .fetch_optional(&mut **tx)
+    .await?
+    .ok_or(IssueError::ReservationNotFound(reservation_id))?;
 
-    tx.commit().await?;
+    let settled: bool = sqlx::query_scalar(
+        "SELECT EXISTS(SELECT 1 FROM inventory_reservations WHERE reverses_reservation_id = $1 AND tenant_id = $2)",
+    )
+    .bind(reservation_id)
+    .bind(&req.tenant_id)
+    .fetch_one(&mut **tx)
+    .await?;
+    if settled {
+        return Err(IssueError::ReservationSettled(reservation_id));
+    }
+    Ok(quantity)
+}
 
-    // Best-effort low-stock signal evaluation (errors do not fail the issue).
-    let _ = evaluator::evaluate_low_stock(
-        pool,
-        &req.tenant_id,
-        req.item_id,
-        req.warehouse_id,
-        req.location_id,
-        &correlation_id,
-        req.causation_id.clone(),
+/// Close out a reservation consumed by an issue: compensating `fulfilled`
+/// row for the full hold and the warehouse `quantity_reserved` reduced by it.
+/// On-hand was already reduced by the issue itself.
+async fn settle_reservation(
+    tx: &mut Transaction<'_, Postgres>,
+    req: &IssueRequest,
+    reservation_id: Uuid,
+    reserved: i64,
+    issued_at: chrono::DateTime<Utc>,
+) -> Result<(), IssueError> {
+    sqlx::query(
+        r#"
+        INSERT INTO inventory_reservations
+            (tenant_id, item_id, warehouse_id, quantity, status,
+             reverses_reservation_id, reference_type, reference_id, fulfilled_at)
+        VALUES
+            ($1, $2, $3, $4, 'fulfilled', $5, $6, $7, $8)
+        "#,
     )
-    .await;
+    .bind(&req.tenant_id)
+    .bind(req.item_id)
+    .bind(req.warehouse_id)
+    .bind(reserved)
+    .bind(reservation_id)
+    .bind(&req.source_type)
+    .bind(&req.source_id)
+    .bind(issued_at)
+    .execute(&mut **tx)
+    .await?;
 
-    Ok((result, false))
+    sqlx::query(
+        r#"
+        UPDATE item_on_hand
+        SET quantity_reserved = GREATEST(0, quantity_reserved - $1),
+            projected_at      = NOW()
+        WHERE tenant_id    = $2
+          AND item_id      = $3
+          AND warehouse_id = $4
+          AND location_id IS NULL
+        "#,
+    )
+    .bind(reserved)
+    .bind(&req.tenant_id)
+    .bind(req.item_id)
+    .bind(req.warehouse_id)
+    .execute(&mut **tx)
+    .await?;
+    Ok(())
 }
 
-// ============================================================================
-// Helpers
-// ============================================================================
-
 pub(super) fn validate_request(req: &IssueRequest) -> Result<(), IssueError> {
     if req.idempotency_key.trim().is_empty() {
         return Err(IssueError::Guard(GuardError::Validation(
@@ -51,6 +51,12 @@ pub struct IssueRequest {
     /// Quantity is derived from this list; the `quantity` field is ignored.
     #[serde(default)]
     pub serial_codes: Option<Vec<String>>,
+    /// Active reservation this issue consumes. Its held quantity counts as
+    /// available to this issue, and the hold is settled (status `fulfilled`)
+    /// in the same transaction, so the units are never released to other
+    /// demand in between.
+    #[serde(default)]
+    pub reservation_id: Option<Uuid>,
 }
 
 /// Result returned on successful or replayed issue
@@ -104,11 +110,20 @@ pub enum IssueError {
     #[error("Lot '{0}' not found for this item/tenant")]
     LotNotFound(String),
 
+    #[error("Lot '{0}' is quarantined")]
+    LotQuarantined(String),
+
     #[error("serial_codes is required (non-empty) for serial-tracked items")]
     SerialRequired,
 
     #[error("Serial '{0}' is not available (not found or not on_hand)")]
     SerialNotAvailable(String),
+
+    #[error("Reservation {0} not found for this item/warehouse")]
+    ReservationNotFound(Uuid),
+
+    #[error("Reservation {0} already released or fulfilled")]
+    ReservationSettled(Uuid),
 }
 
 impl From<LotSerialError> for IssueError {
@@ -170,6 +185,7 @@ mod tests {
             uom_id: None,
             lot_code: None,
             serial_codes: None,
+            reservation_id: None,
         }
     }
 
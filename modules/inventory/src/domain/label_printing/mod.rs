@@ -0,0 +1,5 @@
+pub mod models;
+pub mod render_service;
+pub mod repo;
+pub mod sscc_service;
+pub mod template_service;
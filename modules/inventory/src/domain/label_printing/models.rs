@@ -0,0 +1,195 @@
+//! Label template, rendering and SSCC types.
+
+use chrono::{DateTime, Utc};
+use label_render::{LabelElement, LabelLayout, OutputFormat, RenderError};
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+/// Label types a template can be the default for.
+pub const TEMPLATE_LABEL_TYPES: &[&str] = &["item_label", "lot_label", "sscc_label"];
+
+pub const MAX_SSCC_PER_REQUEST: i32 = 1000;
+
+// ============================================================================
+// Records
+// ============================================================================
+
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow, ToSchema)]
+pub struct LabelTemplate {
+    pub id: Uuid,
+    pub tenant_id: String,
+    pub name: String,
+    /// item_label | lot_label | sscc_label; None = general purpose
+    pub label_type: Option<String>,
+    /// Used for labels of `label_type` rendered without an explicit template
+    pub is_default: bool,
+    pub dpi: i32,
+    pub width_dots: i32,
+    pub height_dots: i32,
+    #[sqlx(json)]
+    pub elements: Vec<LabelElement>,
+    pub is_active: bool,
+    pub updated_by: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl LabelTemplate {
+    pub fn layout(&self) -> LabelLayout {
+        LabelLayout {
+            dpi: self.dpi as u32,
+            width_dots: self.width_dots as u32,
+            height_dots: self.height_dots as u32,
+            elements: self.elements.clone(),
+        }
+    }
+}
+
+/// Tenant GS1 identity used to build SSCC-18 codes.
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow, ToSchema)]
+pub struct SsccConfig {
+    pub tenant_id: String,
+    /// 7–10 digit GS1 company prefix
+    pub gs1_company_prefix: String,
+    /// Leading digit (0–9) chosen by the company to widen its serial range
+    pub extension_digit: i16,
+    pub updated_by: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow, ToSchema)]
+pub struct SsccSerial {
+    pub id: Uuid,
+    pub tenant_id: String,
+    /// 18-digit SSCC including check digit
+    pub sscc: String,
+    pub gs1_company_prefix: String,
+    pub extension_digit: i16,
+    pub serial_reference: i64,
+    pub reference_type: Option<String>,
+    pub reference_id: Option<String>,
+    pub allocated_by: Option<String>,
+    pub allocated_at: DateTime<Utc>,
+}
+
+// ============================================================================
+// Requests / results
+// ============================================================================
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct CreateLabelTemplateRequest {
+    pub name: String,
+    pub label_type: Option<String>,
+    #[serde(default)]
+    pub is_default: bool,
+    #[serde(flatten)]
+    pub layout: LabelLayout,
+    pub updated_by: Option<String>,
+}
+
+/// Full replacement of a template.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct UpdateLabelTemplateRequest {
+    pub name: String,
+    pub label_type: Option<String>,
+    #[serde(default)]
+    pub is_default: bool,
+    #[serde(default = "default_true")]
+    pub is_active: bool,
+    #[serde(flatten)]
+    pub layout: LabelLayout,
+    pub updated_by: Option<String>,
+}
+
+fn default_true() -> bool {
+    true
+}
+
+/// Render a stored template with caller-supplied data.
+#[derive(Debug, Clone, Deserialize, ToSchema)]
+pub struct RenderTemplateRequest {
+    #[serde(default)]
+    pub format: OutputFormat,
+    /// Values for the template's `{{field}}` placeholders
+    #[serde(default)]
+    pub payload: serde_json::Value,
+}
+
+/// A printable label artifact.
+#[derive(Debug, Clone)]
+pub struct RenderedLabel {
+    pub content_type: &'static str,
+    pub filename: String,
+    pub bytes: Vec<u8>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct UpsertSsccConfigRequest {
+    pub gs1_company_prefix: String,
+    pub extension_digit: i16,
+    pub updated_by: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct AllocateSsccRequest {
+    /// Number of codes to allocate (1–1000)
+    #[serde(default = "default_count")]
+    pub count: i32,
+    /// What the cartons belong to, e.g. `shipment`
+    pub reference_type: Option<String>,
+    pub reference_id: Option<String>,
+    pub allocated_by: Option<String>,
+    pub idempotency_key: String,
+}
+
+fn default_count() -> i32 {
+    1
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct AllocateSsccResult {
+    pub serials: Vec<SsccSerial>,
+}
+
+// ============================================================================
+// Errors
+// ============================================================================
+
+#[derive(Debug, Error)]
+pub enum LabelPrintingError {
+    #[error("Label template not found")]
+    TemplateNotFound,
+
+    #[error("Label not found")]
+    LabelNotFound,
+
+    #[error("Label template name '{0}' already exists")]
+    DuplicateTemplateName(String),
+
+    #[error("An active default template already exists for label type '{0}'")]
+    DuplicateDefault(String),
+
+    #[error("SSCC allocation is not configured for this tenant")]
+    SsccNotConfigured,
+
+    #[error("SSCC serial range exhausted for company prefix {0}")]
+    SsccExhausted(String),
+
+    #[error("{0}")]
+    Render(#[from] RenderError),
+
+    #[error("Validation error: {0}")]
+    Validation(String),
+
+    #[error("Idempotency key conflict: same key used with a different request")]
+    ConflictingIdempotencyKey,
+
+    #[error("Serialization error: {0}")]
+    Serialization(#[from] serde_json::Error),
+
+    #[error("Database error: {0}")]
+    Database(#[from] sqlx::Error),
+}
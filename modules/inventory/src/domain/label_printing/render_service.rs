@@ -0,0 +1,205 @@
+//! Label rendering to ZPL II / PDF.
+//!
+//! A stored label renders with, in order of preference: the template named
+//! by the caller, the tenant's active default template for the label's type,
+//! or a built-in 4×2 inch layout for its barcode format. The label payload
+//! (plus `label_id`) fills the template placeholders.
+//!
+//! Stored templates can also be rendered with any payload, which is how
+//! other modules print their own labels through inventory.
+
+use serde_json::{json, Value};
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use super::models::{LabelPrintingError, LabelTemplate, RenderTemplateRequest, RenderedLabel};
+use super::repo;
+use crate::domain::labels::Label;
+use crate::domain::labels_repo;
+use label_render::{LabelLayout, OutputFormat, Symbology};
+
+/// Render a generated label.
+pub async fn render_label(
+    pool: &PgPool,
+    tenant_id: &str,
+    label_id: Uuid,
+    template_id: Option<Uuid>,
+    format: OutputFormat,
+) -> Result<RenderedLabel, LabelPrintingError> {
+    let label = labels_repo::get_label(pool, tenant_id, label_id)
+        .await?
+        .ok_or(LabelPrintingError::LabelNotFound)?;
+
+    let layout = match template_id {
+        Some(id) => active_template(pool, tenant_id, id).await?.layout(),
+        None => match repo::find_default_template(pool, tenant_id, &label.label_type).await? {
+            Some(t) => t.layout(),
+            None => builtin_layout(&label)?,
+        },
+    };
+
+    let payload = label_payload(&label);
+    let title = format!("Label {}", label.id);
+    Ok(RenderedLabel {
+        content_type: format.content_type(),
+        filename: format!("label-{}.{}", label.id, format.as_str()),
+        bytes: label_render::render(&layout, &payload, format, &title)?,
+    })
+}
+
+/// Render a stored template with a caller-supplied payload.
+pub async fn render_template(
+    pool: &PgPool,
+    tenant_id: &str,
+    template_id: Uuid,
+    req: &RenderTemplateRequest,
+) -> Result<RenderedLabel, LabelPrintingError> {
+    let template = active_template(pool, tenant_id, template_id).await?;
+    Ok(RenderedLabel {
+        content_type: req.format.content_type(),
+        filename: format!("label-template-{}.{}", template.id, req.format.as_str()),
+        bytes: label_render::render(&template.layout(), &req.payload, req.format, &template.name)?,
+    })
+}
+
+async fn active_template(
+    pool: &PgPool,
+    tenant_id: &str,
+    template_id: Uuid,
+) -> Result<LabelTemplate, LabelPrintingError> {
+    repo::find_template(pool, tenant_id, template_id)
+        .await?
+        .filter(|t| t.is_active)
+        .ok_or(LabelPrintingError::TemplateNotFound)
+}
+
+fn label_payload(label: &Label) -> Value {
+    let mut payload = label.payload.clone();
+    if let Value::Object(ref mut p) = payload {
+        p.insert("label_id".to_string(), json!(label.id));
+        p.entry("barcode_format")
+            .or_insert_with(|| json!(label.barcode_format));
+    }
+    payload
+}
+
+/// Built-in 4×2 inch, 203 dpi layout: SKU, name, revision/UoM, optional lot
+/// and the barcode in the label's format.
+///
+/// Text symbologies encode `barcode_value`. GS1 symbologies encode
+/// `(01) gtin` followed by `(10) lot_code`, `(17) expiry_date` and
+/// `(21) serial_number` when the payload has them.
+fn builtin_layout(label: &Label) -> Result<LabelLayout, LabelPrintingError> {
+    let symbology = Symbology::parse(&label.barcode_format).ok_or_else(|| {
+        LabelPrintingError::Validation(format!(
+            "unsupported barcode_format '{}'",
+            label.barcode_format
+        ))
+    })?;
+
+    let mut barcode = json!({
+        "type": "barcode",
+        "x": 30,
+        "y": 170,
+        "symbology": symbology,
+        "height": 150,
+        "module_width": if symbology.is_matrix() { 6 } else { 2 },
+    });
+    if symbology.is_gs1() {
+        let mut gs1 = vec![json!({"ai": "01", "value": "{{gtin}}"})];
+        for (ai, field) in [
+            ("10", "lot_code"),
+            ("17", "expiry_date"),
+            ("21", "serial_number"),
+        ] {
+            if label.payload.get(field).is_some() {
+                gs1.push(json!({"ai": ai, "value": format!("{{{{{}}}}}", field)}));
+            }
+        }
+        barcode["gs1"] = json!(gs1);
+    } else {
+        barcode["value"] = json!("{{barcode_value}}");
+    }
+
+    Ok(serde_json::from_value(json!({
+        "dpi": 203,
+        "width_dots": 812,
+        "height_dots": 406,
+        "elements": [
+            {"type": "text", "x": 30, "y": 25, "font_height": 45, "value": "{{item_sku}}"},
+            {"type": "text", "x": 30, "y": 80, "font_height": 30, "value": "{{item_name}}"},
+            {"type": "text", "x": 30, "y": 120, "font_height": 24,
+             "value": "Rev {{revision_number}}  {{uom}}"},
+            {"type": "text", "x": 480, "y": 120, "font_height": 24,
+             "value": "Lot {{lot_code}}", "optional": true},
+            barcode,
+        ]
+    }))?)
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+
+    fn label(barcode_format: &str, payload: Value) -> Label {
+        Label {
+            id: Uuid::new_v4(),
+            tenant_id: "t1".to_string(),
+            item_id: Uuid::new_v4(),
+            revision_id: Uuid::new_v4(),
+            label_type: "lot_label".to_string(),
+            barcode_format: barcode_format.to_string(),
+            payload,
+            idempotency_key: None,
+            actor_id: None,
+            created_at: Utc::now(),
+        }
+    }
+
+    fn base_payload() -> Value {
+        json!({
+            "barcode_value": "SKU-001-R3",
+            "item_sku": "SKU-001",
+            "item_name": "Widget Pro",
+            "uom": "ea",
+            "revision_number": 3,
+        })
+    }
+
+    #[test]
+    fn builtin_code128_layout_renders_zpl() {
+        let l = label("code128", base_payload());
+        let zpl =
+            label_render::render_zpl(&builtin_layout(&l).unwrap(), &label_payload(&l)).unwrap();
+        assert!(zpl.contains("^FDSKU-001^FS"));
+        assert!(zpl.contains("^FDRev 3  ea^FS"));
+        assert!(zpl.contains("^BCN,150,Y,N,N,A^FH\\^FDSKU-001-R3^FS"));
+        assert!(!zpl.contains("Lot "), "lot line is optional");
+    }
+
+    #[test]
+    fn builtin_gs1_layout_adds_present_identifiers() {
+        let mut p = base_payload();
+        p["gtin"] = json!("09521234543213");
+        p["lot_code"] = json!("LOT-7");
+        p["expiry_date"] = json!("2027-03-31");
+        let l = label("gs1_128", p);
+        let zpl =
+            label_render::render_zpl(&builtin_layout(&l).unwrap(), &label_payload(&l)).unwrap();
+        assert!(zpl.contains("^FD(01)09521234543213(10)LOT-7(17)270331^FS"));
+        assert!(zpl.contains("^FDLot LOT-7^FS"));
+    }
+
+    #[test]
+    fn gs1_layout_without_gtin_reports_the_missing_field() {
+        let l = label("gs1_datamatrix", base_payload());
+        let err =
+            label_render::render_zpl(&builtin_layout(&l).unwrap(), &label_payload(&l)).unwrap_err();
+        assert_eq!(err, label_render::RenderError::MissingField("gtin".into()));
+    }
+}
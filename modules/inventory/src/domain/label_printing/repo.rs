@@ -0,0 +1,249 @@
+//! Label printing repository — label templates, SSCC configuration and
+//! allocated SSCC serials.
+
+use sqlx::{types::Json, PgPool, Postgres, Transaction};
+use uuid::Uuid;
+
+use super::models::{
+    CreateLabelTemplateRequest, LabelTemplate, SsccConfig, SsccSerial, UpdateLabelTemplateRequest,
+    UpsertSsccConfigRequest,
+};
+
+const TEMPLATE_COLUMNS: &str = "id, tenant_id, name, label_type, is_default, dpi, width_dots, \
+     height_dots, elements, is_active, updated_by, created_at, updated_at";
+
+const SSCC_CONFIG_COLUMNS: &str =
+    "tenant_id, gs1_company_prefix, extension_digit, updated_by, created_at, updated_at";
+
+const SSCC_SERIAL_COLUMNS: &str = "id, tenant_id, sscc, gs1_company_prefix, extension_digit, \
+     serial_reference, reference_type, reference_id, allocated_by, allocated_at";
+
+/// Insert values for an allocated SSCC.
+pub(crate) struct NewSsccSerial<'a> {
+    pub tenant_id: &'a str,
+    pub sscc: &'a str,
+    pub gs1_company_prefix: &'a str,
+    pub extension_digit: i16,
+    pub serial_reference: i64,
+    pub reference_type: Option<&'a str>,
+    pub reference_id: Option<&'a str>,
+    pub allocated_by: Option<&'a str>,
+}
+
+// ============================================================================
+// Templates
+// ============================================================================
+
+pub async fn insert_template(
+    pool: &PgPool,
+    tenant_id: &str,
+    req: &CreateLabelTemplateRequest,
+) -> Result<LabelTemplate, sqlx::Error> {
+    sqlx::query_as::<_, LabelTemplate>(&format!(
+        "INSERT INTO inv_label_templates
+            (tenant_id, name, label_type, is_default, dpi, width_dots, height_dots,
+             elements, updated_by)
+         VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
+         RETURNING {TEMPLATE_COLUMNS}"
+    ))
+    .bind(tenant_id)
+    .bind(req.name.trim())
+    .bind(req.label_type.as_deref())
+    .bind(req.is_default)
+    .bind(req.layout.dpi as i32)
+    .bind(req.layout.width_dots as i32)
+    .bind(req.layout.height_dots as i32)
+    .bind(Json(&req.layout.elements))
+    .bind(req.updated_by.as_deref())
+    .fetch_one(pool)
+    .await
+}
+
+pub async fn update_template(
+    pool: &PgPool,
+    tenant_id: &str,
+    template_id: Uuid,
+    req: &UpdateLabelTemplateRequest,
+) -> Result<Option<LabelTemplate>, sqlx::Error> {
+    sqlx::query_as::<_, LabelTemplate>(&format!(
+        "UPDATE inv_label_templates
+         SET name        = $3,
+             label_type  = $4,
+             is_default  = $5,
+             is_active   = $6,
+             dpi         = $7,
+             width_dots  = $8,
+             height_dots = $9,
+             elements    = $10,
+             updated_by  = $11,
+             updated_at  = NOW()
+         WHERE id = $1 AND tenant_id = $2
+         RETURNING {TEMPLATE_COLUMNS}"
+    ))
+    .bind(template_id)
+    .bind(tenant_id)
+    .bind(req.name.trim())
+    .bind(req.label_type.as_deref())
+    .bind(req.is_default)
+    .bind(req.is_active)
+    .bind(req.layout.dpi as i32)
+    .bind(req.layout.width_dots as i32)
+    .bind(req.layout.height_dots as i32)
+    .bind(Json(&req.layout.elements))
+    .bind(req.updated_by.as_deref())
+    .fetch_optional(pool)
+    .await
+}
+
+pub async fn find_template(
+    pool: &PgPool,
+    tenant_id: &str,
+    template_id: Uuid,
+) -> Result<Option<LabelTemplate>, sqlx::Error> {
+    sqlx::query_as::<_, LabelTemplate>(&format!(
+        "SELECT {TEMPLATE_COLUMNS} FROM inv_label_templates WHERE id = $1 AND tenant_id = $2"
+    ))
+    .bind(template_id)
+    .bind(tenant_id)
+    .fetch_optional(pool)
+    .await
+}
+
+/// The active default template for a label type, if any.
+pub async fn find_default_template(
+    pool: &PgPool,
+    tenant_id: &str,
+    label_type: &str,
+) -> Result<Option<LabelTemplate>, sqlx::Error> {
+    sqlx::query_as::<_, LabelTemplate>(&format!(
+        "SELECT {TEMPLATE_COLUMNS} FROM inv_label_templates
+         WHERE tenant_id = $1 AND label_type = $2 AND is_default = TRUE AND is_active = TRUE"
+    ))
+    .bind(tenant_id)
+    .bind(label_type)
+    .fetch_optional(pool)
+    .await
+}
+
+pub async fn list_templates(
+    pool: &PgPool,
+    tenant_id: &str,
+    label_type: Option<&str>,
+    include_inactive: bool,
+) -> Result<Vec<LabelTemplate>, sqlx::Error> {
+    sqlx::query_as::<_, LabelTemplate>(&format!(
+        "SELECT {TEMPLATE_COLUMNS} FROM inv_label_templates
+         WHERE tenant_id = $1
+           AND ($2::TEXT IS NULL OR label_type = $2)
+           AND ($3 OR is_active = TRUE)
+         ORDER BY name"
+    ))
+    .bind(tenant_id)
+    .bind(label_type)
+    .bind(include_inactive)
+    .fetch_all(pool)
+    .await
+}
+
+// ============================================================================
+// SSCC
+// ============================================================================
+
+pub async fn find_sscc_config(
+    pool: &PgPool,
+    tenant_id: &str,
+) -> Result<Option<SsccConfig>, sqlx::Error> {
+    sqlx::query_as::<_, SsccConfig>(&format!(
+        "SELECT {SSCC_CONFIG_COLUMNS} FROM inv_sscc_configs WHERE tenant_id = $1"
+    ))
+    .bind(tenant_id)
+    .fetch_optional(pool)
+    .await
+}
+
+pub async fn upsert_sscc_config(
+    pool: &PgPool,
+    tenant_id: &str,
+    req: &UpsertSsccConfigRequest,
+) -> Result<SsccConfig, sqlx::Error> {
+    sqlx::query_as::<_, SsccConfig>(&format!(
+        "INSERT INTO inv_sscc_configs (tenant_id, gs1_company_prefix, extension_digit, updated_by)
+         VALUES ($1, $2, $3, $4)
+         ON CONFLICT (tenant_id) DO UPDATE
+            SET gs1_company_prefix = EXCLUDED.gs1_company_prefix,
+                extension_digit    = EXCLUDED.extension_digit,
+                updated_by         = EXCLUDED.updated_by,
+                updated_at         = NOW()
+         RETURNING {SSCC_CONFIG_COLUMNS}"
+    ))
+    .bind(tenant_id)
+    .bind(&req.gs1_company_prefix)
+    .bind(req.extension_digit)
+    .bind(req.updated_by.as_deref())
+    .fetch_one(pool)
+    .await
+}
+
+/// Advance the serial counter of a (prefix, extension) by `count` and return
+/// the first reserved serial reference. The row lock serializes concurrent
+/// allocations until the transaction ends.
+pub(crate) async fn reserve_serials(
+    tx: &mut Transaction<'_, Postgres>,
+    tenant_id: &str,
+    gs1_company_prefix: &str,
+    extension_digit: i16,
+    count: i64,
+) -> Result<i64, sqlx::Error> {
+    let next: i64 = sqlx::query_scalar(
+        "INSERT INTO inv_sscc_counters (tenant_id, gs1_company_prefix, extension_digit, next_serial)
+         VALUES ($1, $2, $3, $4)
+         ON CONFLICT (tenant_id, gs1_company_prefix, extension_digit) DO UPDATE
+            SET next_serial = inv_sscc_counters.next_serial + EXCLUDED.next_serial,
+                updated_at  = NOW()
+         RETURNING next_serial",
+    )
+    .bind(tenant_id)
+    .bind(gs1_company_prefix)
+    .bind(extension_digit)
+    .bind(count)
+    .fetch_one(&mut **tx)
+    .await?;
+    Ok(next - count)
+}
+
+pub(crate) async fn insert_sscc_serial(
+    tx: &mut Transaction<'_, Postgres>,
+    s: &NewSsccSerial<'_>,
+) -> Result<SsccSerial, sqlx::Error> {
+    sqlx::query_as::<_, SsccSerial>(&format!(
+        "INSERT INTO inv_sscc_serials
+            (tenant_id, sscc, gs1_company_prefix, extension_digit, serial_reference,
+             reference_type, reference_id, allocated_by)
+         VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+         RETURNING {SSCC_SERIAL_COLUMNS}"
+    ))
+    .bind(s.tenant_id)
+    .bind(s.sscc)
+    .bind(s.gs1_company_prefix)
+    .bind(s.extension_digit)
+    .bind(s.serial_reference)
+    .bind(s.reference_type)
+    .bind(s.reference_id)
+    .bind(s.allocated_by)
+    .fetch_one(&mut **tx)
+    .await
+}
+
+pub async fn find_sscc_serial(
+    pool: &PgPool,
+    tenant_id: &str,
+    sscc: &str,
+) -> Result<Option<SsccSerial>, sqlx::Error> {
+    sqlx::query_as::<_, SsccSerial>(&format!(
+        "SELECT {SSCC_SERIAL_COLUMNS} FROM inv_sscc_serials WHERE tenant_id = $1 AND sscc = $2"
+    ))
+    .bind(tenant_id)
+    .bind(sscc)
+    .fetch_optional(pool)
+    .await
+}
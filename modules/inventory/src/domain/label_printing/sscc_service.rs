@@ -0,0 +1,162 @@
+//! SSCC-18 allocation for shipping cartons and pallets.
+//!
+//! Each code is `extension digit + company prefix + serial reference + check
+//! digit`. Serial references come from a per-(prefix, extension) counter
+//! advanced inside the allocation transaction, so concurrent allocations
+//! never hand out the same code and a reconfigured tenant never reuses one.
+
+use chrono::{Duration, Utc};
+use label_render::gs1;
+use sqlx::PgPool;
+
+use super::models::{
+    AllocateSsccRequest, AllocateSsccResult, LabelPrintingError, SsccConfig,
+    UpsertSsccConfigRequest, MAX_SSCC_PER_REQUEST,
+};
+use super::repo::{self, NewSsccSerial};
+use crate::domain::labels_repo;
+
+pub async fn upsert_config(
+    pool: &PgPool,
+    tenant_id: &str,
+    req: &UpsertSsccConfigRequest,
+) -> Result<SsccConfig, LabelPrintingError> {
+    validate_config(req)?;
+    Ok(repo::upsert_sscc_config(pool, tenant_id, req).await?)
+}
+
+fn validate_config(req: &UpsertSsccConfigRequest) -> Result<(), LabelPrintingError> {
+    let prefix = &req.gs1_company_prefix;
+    if !(7..=10).contains(&prefix.len()) || !prefix.bytes().all(|b| b.is_ascii_digit()) {
+        return Err(LabelPrintingError::Validation(
+            "gs1_company_prefix must be 7-10 digits".to_string(),
+        ));
+    }
+    if !(0..=9).contains(&req.extension_digit) {
+        return Err(LabelPrintingError::Validation(
+            "extension_digit must be 0-9".to_string(),
+        ));
+    }
+    Ok(())
+}
+
+/// Allocate `count` consecutive SSCCs.
+///
+/// Pattern: Guard → Mutation (single transaction). Returns
+/// `(result, is_replay)`.
+pub async fn allocate_sscc(
+    pool: &PgPool,
+    tenant_id: &str,
+    req: &AllocateSsccRequest,
+) -> Result<(AllocateSsccResult, bool), LabelPrintingError> {
+    // --- Guard: validate inputs ---
+    if req.idempotency_key.trim().is_empty() {
+        return Err(LabelPrintingError::Validation(
+            "idempotency_key must not be empty".to_string(),
+        ));
+    }
+    if !(1..=MAX_SSCC_PER_REQUEST).contains(&req.count) {
+        return Err(LabelPrintingError::Validation(format!(
+            "count must be between 1 and {}",
+            MAX_SSCC_PER_REQUEST
+        )));
+    }
+
+    // --- Guard: idempotency check ---
+    let request_hash = serde_json::to_string(req)?;
+    if let Some(record) =
+        labels_repo::find_idempotency_key(pool, tenant_id, &req.idempotency_key).await?
+    {
+        if record.request_hash != request_hash {
+            return Err(LabelPrintingError::ConflictingIdempotencyKey);
+        }
+        let result: AllocateSsccResult = serde_json::from_str(&record.response_body)?;
+        return Ok((result, true));
+    }
+
+    let config = repo::find_sscc_config(pool, tenant_id)
+        .await?
+        .ok_or(LabelPrintingError::SsccNotConfigured)?;
+    let prefix = config.gs1_company_prefix.as_str();
+    let capacity = gs1::sscc_serial_capacity(prefix.len());
+
+    // --- Mutation ---
+    let mut tx = pool.begin().await?;
+
+    let first = repo::reserve_serials(
+        &mut tx,
+        tenant_id,
+        prefix,
+        config.extension_digit,
+        req.count as i64,
+    )
+    .await?;
+    if (first + req.count as i64) as u64 > capacity {
+        return Err(LabelPrintingError::SsccExhausted(prefix.to_string()));
+    }
+
+    let mut serials = Vec::with_capacity(req.count as usize);
+    for serial_reference in first..first + req.count as i64 {
+        let sscc = gs1::sscc(
+            config.extension_digit as u8,
+            prefix,
+            serial_reference as u64,
+        )?;
+        let row = repo::insert_sscc_serial(
+            &mut tx,
+            &NewSsccSerial {
+                tenant_id,
+                sscc: &sscc,
+                gs1_company_prefix: prefix,
+                extension_digit: config.extension_digit,
+                serial_reference,
+                reference_type: req.reference_type.as_deref(),
+                reference_id: req.reference_id.as_deref(),
+                allocated_by: req.allocated_by.as_deref(),
+            },
+        )
+        .await?;
+        serials.push(row);
+    }
+    let result = AllocateSsccResult { serials };
+
+    let response_json = serde_json::to_string(&result)?;
+    labels_repo::store_idempotency_key(
+        &mut tx,
+        tenant_id,
+        &req.idempotency_key,
+        &request_hash,
+        &response_json,
+        Utc::now() + Duration::days(7),
+    )
+    .await?;
+
+    tx.commit().await?;
+    Ok((result, false))
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config(prefix: &str, ext: i16) -> UpsertSsccConfigRequest {
+        UpsertSsccConfigRequest {
+            gs1_company_prefix: prefix.to_string(),
+            extension_digit: ext,
+            updated_by: None,
+        }
+    }
+
+    #[test]
+    fn config_requires_gs1_prefix_and_single_extension_digit() {
+        assert!(validate_config(&config("7610425", 3)).is_ok());
+        assert!(validate_config(&config("0614141999", 0)).is_ok());
+        assert!(validate_config(&config("123456", 0)).is_err());
+        assert!(validate_config(&config("76104AB", 0)).is_err());
+        assert!(validate_config(&config("7610425", 10)).is_err());
+    }
+}
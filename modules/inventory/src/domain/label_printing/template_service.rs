@@ -0,0 +1,136 @@
+//! Label template management.
+//!
+//! The layout is validated by `label-render` before it is stored, so a saved
+//! template can only fail to render because of the payload it is given.
+
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use super::models::{
+    CreateLabelTemplateRequest, LabelPrintingError, LabelTemplate, UpdateLabelTemplateRequest,
+    TEMPLATE_LABEL_TYPES,
+};
+use super::repo;
+use label_render::LabelLayout;
+
+pub async fn create_template(
+    pool: &PgPool,
+    tenant_id: &str,
+    req: &CreateLabelTemplateRequest,
+) -> Result<LabelTemplate, LabelPrintingError> {
+    validate(
+        &req.name,
+        req.label_type.as_deref(),
+        req.is_default,
+        &req.layout,
+    )?;
+    repo::insert_template(pool, tenant_id, req)
+        .await
+        .map_err(|e| unique_violation(e, &req.name, req.label_type.as_deref()))
+}
+
+pub async fn update_template(
+    pool: &PgPool,
+    tenant_id: &str,
+    template_id: Uuid,
+    req: &UpdateLabelTemplateRequest,
+) -> Result<LabelTemplate, LabelPrintingError> {
+    validate(
+        &req.name,
+        req.label_type.as_deref(),
+        req.is_default,
+        &req.layout,
+    )?;
+    repo::update_template(pool, tenant_id, template_id, req)
+        .await
+        .map_err(|e| unique_violation(e, &req.name, req.label_type.as_deref()))?
+        .ok_or(LabelPrintingError::TemplateNotFound)
+}
+
+fn validate(
+    name: &str,
+    label_type: Option<&str>,
+    is_default: bool,
+    layout: &LabelLayout,
+) -> Result<(), LabelPrintingError> {
+    if name.trim().is_empty() {
+        return Err(LabelPrintingError::Validation(
+            "name must not be empty".to_string(),
+        ));
+    }
+    if let Some(t) = label_type {
+        if !TEMPLATE_LABEL_TYPES.contains(&t) {
+            return Err(LabelPrintingError::Validation(format!(
+                "label_type must be one of: {}",
+                TEMPLATE_LABEL_TYPES.join(", ")
+            )));
+        }
+    }
+    if is_default && label_type.is_none() {
+        return Err(LabelPrintingError::Validation(
+            "a default template needs a label_type".to_string(),
+        ));
+    }
+    layout.validate()?;
+    Ok(())
+}
+
+fn unique_violation(e: sqlx::Error, name: &str, label_type: Option<&str>) -> LabelPrintingError {
+    if let sqlx::Error::Database(ref dbe) = e {
+        if dbe.code().as_deref() == Some("23505") {
+            return match dbe.constraint() {
+                Some("idx_inv_label_templates_default") => {
+                    LabelPrintingError::DuplicateDefault(label_type.unwrap_or_default().to_string())
+                }
+                _ => LabelPrintingError::DuplicateTemplateName(name.trim().to_string()),
+            };
+        }
+    }
+    LabelPrintingError::Database(e)
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn layout() -> LabelLayout {
+        serde_json::from_value(json!({
+            "width_dots": 812,
+            "height_dots": 406,
+            "elements": [{"type": "text", "x": 10, "y": 10, "value": "{{item_sku}}"}]
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn default_template_needs_a_known_label_type() {
+        assert!(validate("Item 4x2", Some("item_label"), true, &layout()).is_ok());
+        assert!(matches!(
+            validate("Item 4x2", None, true, &layout()),
+            Err(LabelPrintingError::Validation(_))
+        ));
+        assert!(matches!(
+            validate("Item 4x2", Some("pallet"), false, &layout()),
+            Err(LabelPrintingError::Validation(_))
+        ));
+        assert!(matches!(
+            validate(" ", None, false, &layout()),
+            Err(LabelPrintingError::Validation(_))
+        ));
+    }
+
+    #[test]
+    fn invalid_layout_is_rejected_before_storage() {
+        let mut l = layout();
+        l.dpi = 50;
+        assert!(matches!(
+            validate("Item", None, false, &l),
+            Err(LabelPrintingError::Render(_))
+        ));
+    }
+}
@@ -11,6 +11,7 @@
 //! - Tenant-scoped: labels cannot leak across tenants
 
 use chrono::{Duration, Utc};
+use label_render::{Gs1Element, Gs1ElementString};
 use serde::{Deserialize, Serialize};
 use sqlx::PgPool;
 use thiserror::Error;
@@ -99,7 +100,16 @@ fn require_non_empty(value: &str, field: &str) -> Result<(), LabelError> {
 }
 
 const VALID_LABEL_TYPES: &[&str] = &["item_label", "lot_label"];
-const VALID_BARCODE_FORMATS: &[&str] = &["code128", "code39", "qr", "datamatrix", "ean13"];
+const VALID_BARCODE_FORMATS: &[&str] = &[
+    "code128",
+    "code39",
+    "qr",
+    "datamatrix",
+    "ean13",
+    "gs1_128",
+    "gs1_datamatrix",
+];
+const GS1_BARCODE_FORMATS: &[&str] = &["gs1_128", "gs1_datamatrix"];
 
 fn validate_request(req: &GenerateLabelRequest) -> Result<(), LabelError> {
     require_non_empty(&req.tenant_id, "tenant_id")?;
@@ -121,6 +131,26 @@ fn validate_request(req: &GenerateLabelRequest) -> Result<(), LabelError> {
         )));
     }
 
+    // GS1 symbols are built around the item's GTIN (AI 01)
+    if GS1_BARCODE_FORMATS.contains(&req.barcode_format.as_str()) {
+        let gtin = req
+            .extra
+            .as_ref()
+            .and_then(|e| e.get("gtin"))
+            .and_then(|g| g.as_str())
+            .ok_or_else(|| {
+                LabelError::Validation(format!(
+                    "extra.gtin is required for barcode_format {}",
+                    req.barcode_format
+                ))
+            })?;
+        Gs1ElementString::new(&[Gs1Element {
+            ai: "01".to_string(),
+            value: gtin.to_string(),
+        }])
+        .map_err(|e| LabelError::Validation(format!("extra.gtin: {}", e)))?;
+    }
+
     Ok(())
 }
 
@@ -424,6 +454,23 @@ mod tests {
         assert!(validate_request(&r).is_ok());
     }
 
+    #[test]
+    fn gs1_formats_require_valid_gtin() {
+        let mut r = valid_request();
+        r.barcode_format = "gs1_128".to_string();
+        assert!(matches!(
+            validate_request(&r),
+            Err(LabelError::Validation(_))
+        ));
+        r.extra = Some(serde_json::json!({"gtin": "09521234543214"}));
+        assert!(matches!(
+            validate_request(&r),
+            Err(LabelError::Validation(_))
+        ));
+        r.extra = Some(serde_json::json!({"gtin": "09521234543213"}));
+        assert!(validate_request(&r).is_ok());
+    }
+
     #[test]
     fn deterministic_payload_generation() {
         let revision = ItemRevision {
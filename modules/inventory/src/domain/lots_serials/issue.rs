@@ -6,7 +6,8 @@
 //!
 //! ## Lot-tracked issues
 //! `find_lot_id` resolves a lot_code to its UUID so the FIFO layer lock query
-//! can filter to only the layers belonging to that lot.
+//! can filter to only the layers belonging to that lot. `lock_lot_quarantined`
+//! refuses lots quarantined by quality inspection.
 //!
 //! ## Serial-tracked issues
 //! `validate_and_lock_serials` checks every serial_code is `on_hand` and locks
@@ -72,6 +73,20 @@ pub async fn find_lot_id(
     .await
 }
 
+/// Share-lock a lot for the issue transaction and report whether it is
+/// quarantined. The lock serialises the issue with a concurrent quarantine.
+pub async fn lock_lot_quarantined(
+    tx: &mut Transaction<'_, Postgres>,
+    lot_id: Uuid,
+) -> Result<bool, sqlx::Error> {
+    sqlx::query_scalar::<_, bool>(
+        "SELECT quarantined_at IS NOT NULL FROM inventory_lots WHERE id = $1 FOR SHARE",
+    )
+    .bind(lot_id)
+    .fetch_one(&mut **tx)
+    .await
+}
+
 // ============================================================================
 // Serial helpers
 // ============================================================================
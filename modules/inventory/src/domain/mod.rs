@@ -16,12 +16,15 @@ pub mod history;
 pub mod issue_service;
 pub mod items;
 pub mod items_repo;
+pub mod label_printing;
 pub mod labels;
 pub mod labels_repo;
 pub mod locations;
 pub mod lots_serials;
 pub mod make_buy;
+pub mod picking;
 pub mod projections;
+pub mod putaway;
 pub mod receipt_repo;
 pub mod receipt_service;
 pub mod reorder;
@@ -0,0 +1,623 @@
+//! Pick confirmation.
+//!
+//! A picker confirms a task by posting what was taken from where: each pick
+//! names a location (by id or scanned label) and carries the raw item, lot or
+//! serial barcodes scanned at the bin. Scans are resolved through the tenant's
+//! barcode rules and must match the task item and its tracking mode.
+//!
+//! Confirmation is atomic: the task's reservation is released and one stock
+//! issue is posted per pick in the same transaction, so reserved stock turns
+//! into issued stock without ever being available to other demand.
+//!
+//! A pick below the requested quantity closes the task as `short`. The
+//! remainder is either re-reserved onto a follow-up task in the same wave or
+//! recorded as backordered for the demand owner.
+//!
+//! Pattern: Guard → Release → Issue → Close → Outbox (all in one transaction).
+
+use std::collections::HashSet;
+
+use chrono::{Duration, Utc};
+use event_bus::TracingContext;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use super::models::{
+    ConfirmPickRequest, ConfirmPickResult, PickError, PickLineInput, PickSourceType, PickTask,
+    ShortAction, SHORT_RESOLUTION_BACKORDERED, SHORT_RESOLUTION_RE_RESERVED, TASK_STATUS_OPEN,
+    TASK_STATUS_PICKED, TASK_STATUS_SHORT,
+};
+use super::repo::{self, NewPickTask};
+use super::task_service::{map_task_unique, reserve_in_tx};
+use crate::domain::barcode_resolver::{
+    self, ResolveRequest, ENTITY_TYPE_ITEM, ENTITY_TYPE_LOT, ENTITY_TYPE_SERIAL,
+};
+use crate::domain::barcode_resolver_repo;
+use crate::domain::guards::{guard_item_active, guard_quantity_positive};
+use crate::domain::issue_service::{service::issue_in_tx, IssueRequest};
+use crate::domain::items::TrackingMode;
+use crate::domain::lots_serials::issue as ls_issue;
+use crate::domain::reorder::evaluator;
+use crate::domain::reservation_repo;
+use crate::domain::reservation_service::EVENT_TYPE_RESERVATION_RELEASED;
+use crate::events::{
+    build_pick_confirmed_envelope, PickConfirmedPayload, EVENT_TYPE_PICK_CONFIRMED,
+};
+
+/// A scan resolved and checked against the task item.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum ScannedEntity {
+    Item,
+    Lot(String),
+    Serial(String),
+}
+
+/// A pick whose location and scans have been verified.
+struct VerifiedPick {
+    location_id: Option<Uuid>,
+    lot_code: Option<String>,
+    serial_codes: Option<Vec<String>>,
+    quantity: i64,
+}
+
+/// Confirm a pick task.
+///
+/// Returns `(ConfirmPickResult, is_replay)`.
+/// - `is_replay = false`: task confirmed; HTTP 201.
+/// - `is_replay = true`:  idempotency key matched; HTTP 200.
+pub async fn confirm_pick(
+    pool: &PgPool,
+    req: &ConfirmPickRequest,
+    tracing_ctx: Option<&TracingContext>,
+) -> Result<(ConfirmPickResult, bool), PickError> {
+    validate_request(req)?;
+
+    let request_hash = serde_json::to_string(req)?;
+
+    if let Some(record) =
+        reservation_repo::find_idempotency_key(pool, &req.tenant_id, &req.idempotency_key).await?
+    {
+        if record.request_hash != request_hash {
+            return Err(PickError::ConflictingIdempotencyKey);
+        }
+        let result: ConfirmPickResult = serde_json::from_str(&record.response_body)?;
+        return Ok((result, true));
+    }
+
+    // --- Guards (read-only) ---
+    let task = repo::find_task(pool, &req.tenant_id, req.task_id)
+        .await?
+        .ok_or(PickError::TaskNotFound)?;
+    if task.status != TASK_STATUS_OPEN {
+        return Err(PickError::TaskNotOpen);
+    }
+    let item = guard_item_active(pool, task.item_id, &req.tenant_id).await?;
+
+    let correlation_id = req
+        .correlation_id
+        .clone()
+        .unwrap_or_else(|| Uuid::new_v4().to_string());
+
+    let mut picks = Vec::with_capacity(req.picks.len());
+    for pick in &req.picks {
+        let location_id = resolve_location(pool, &task, pick).await?;
+        let scanned = resolve_scans(pool, req, &task, pick, &correlation_id).await?;
+        let (lot_code, serial_codes) = verify_scans(item.tracking_mode, pick.quantity, &scanned)?;
+        picks.push(VerifiedPick {
+            location_id,
+            lot_code,
+            serial_codes,
+            quantity: pick.quantity,
+        });
+    }
+
+    let quantity_picked: i64 = picks.iter().map(|p| p.quantity).sum();
+    if quantity_picked > task.quantity_requested {
+        return Err(PickError::Validation(format!(
+            "picked quantity {} exceeds the requested {}",
+            quantity_picked, task.quantity_requested
+        )));
+    }
+    let remainder = task.quantity_requested - quantity_picked;
+
+    let reservation =
+        reservation_repo::find_active_reservation(pool, task.reservation_id, &req.tenant_id)
+            .await?
+            .ok_or(PickError::ReservationNotActive)?;
+    if reservation_repo::is_already_compensated(pool, reservation.id).await? {
+        return Err(PickError::ReservationNotActive);
+    }
+
+    let source_type = PickSourceType::parse(&task.source_type).ok_or_else(|| {
+        PickError::Validation(format!("unknown task source_type '{}'", task.source_type))
+    })?;
+    let confirmed_at = Utc::now();
+
+    let mut tx = pool.begin().await?;
+
+    // Step 1: Lock the task; a concurrent confirmation may have closed it.
+    let task = repo::lock_task(&mut tx, &req.tenant_id, req.task_id)
+        .await?
+        .ok_or(PickError::TaskNotFound)?;
+    if task.status != TASK_STATUS_OPEN {
+        return Err(PickError::TaskNotOpen);
+    }
+
+    // Step 2: Release the task's reservation so the issues below can draw on it.
+    let release_id =
+        reservation_repo::insert_compensating_reservation(&mut tx, &reservation, confirmed_at)
+            .await?;
+    reservation_repo::decrement_reserved(&mut tx, &reservation).await?;
+
+    let release_payload = serde_json::json!({
+        "release_id":     release_id,
+        "reservation_id": reservation.id,
+        "tenant_id":      reservation.tenant_id,
+        "item_id":        reservation.item_id,
+        "warehouse_id":   reservation.warehouse_id,
+        "quantity":       reservation.quantity,
+        "released_at":    confirmed_at,
+    });
+    reservation_repo::insert_outbox_event(
+        &mut tx,
+        Uuid::new_v4(),
+        EVENT_TYPE_RESERVATION_RELEASED,
+        &reservation.id.to_string(),
+        &req.tenant_id,
+        &release_payload.to_string(),
+        &correlation_id,
+        req.causation_id.as_deref(),
+    )
+    .await?;
+
+    // Step 3: One stock issue per pick.
+    let mut confirmations = Vec::with_capacity(picks.len());
+    for (idx, pick) in picks.iter().enumerate() {
+        let issue_req = IssueRequest {
+            tenant_id: req.tenant_id.clone(),
+            item_id: task.item_id,
+            warehouse_id: task.warehouse_id,
+            location_id: pick.location_id,
+            quantity: pick.quantity,
+            currency: task.currency.clone(),
+            source_module: source_type.source_module().to_string(),
+            source_type: task.source_type.clone(),
+            source_id: task.source_id.clone(),
+            source_line_id: task.source_line_id.clone(),
+            idempotency_key: format!("{}-{}", req.idempotency_key, idx + 1),
+            correlation_id: Some(correlation_id.clone()),
+            causation_id: req.causation_id.clone(),
+            uom_id: None,
+            lot_code: pick.lot_code.clone(),
+            serial_codes: pick.serial_codes.clone(),
+            reservation_id: None,
+        };
+        let issued = issue_in_tx(pool, &mut tx, &issue_req, &correlation_id, tracing_ctx).await?;
+
+        let confirmation = repo::insert_confirmation(
+            &mut tx,
+            &req.tenant_id,
+            task.id,
+            pick.location_id,
+            pick.lot_code.as_deref(),
+            pick.serial_codes.as_deref(),
+            pick.quantity,
+            issued.issue_line_id,
+            confirmed_at,
+        )
+        .await?;
+        confirmations.push(confirmation);
+    }
+
+    // Step 4: Resolve any shortfall.
+    let mut follow_up_task: Option<PickTask> = None;
+    let mut quantity_backordered = 0;
+    let mut short_resolution: Option<&str> = None;
+    if remainder > 0 {
+        let re_reserved = match req.short_action {
+            ShortAction::ReReserve => match reserve_in_tx(
+                &mut tx,
+                &req.tenant_id,
+                task.item_id,
+                task.warehouse_id,
+                remainder,
+                &task.source_type,
+                &task.source_id,
+                &correlation_id,
+                req.causation_id.as_deref(),
+            )
+            .await
+            {
+                Ok(id) => Some(id),
+                Err(PickError::InsufficientAvailable { .. }) => None,
+                Err(e) => return Err(e),
+            },
+            ShortAction::Backorder => None,
+        };
+
+        match re_reserved {
+            Some(reservation_id) => {
+                let follow_up = repo::insert_task(
+                    &mut tx,
+                    &NewPickTask {
+                        tenant_id: &req.tenant_id,
+                        warehouse_id: task.warehouse_id,
+                        wave_id: task.wave_id,
+                        item_id: task.item_id,
+                        source_type: &task.source_type,
+                        source_id: &task.source_id,
+                        source_line_id: task.source_line_id.as_deref(),
+                        currency: &task.currency,
+                        quantity_requested: remainder,
+                        reservation_id,
+                        parent_task_id: Some(task.id),
+                    },
+                )
+                .await
+                .map_err(|e| map_task_unique(e, None))?;
+                follow_up_task = Some(follow_up);
+                short_resolution = Some(SHORT_RESOLUTION_RE_RESERVED);
+            }
+            None => {
+                quantity_backordered = remainder;
+                short_resolution = Some(SHORT_RESOLUTION_BACKORDERED);
+            }
+        }
+    }
+
+    // Step 5: Close the task and, when it was the last open one, its wave.
+    let status = if remainder > 0 {
+        TASK_STATUS_SHORT
+    } else {
+        TASK_STATUS_PICKED
+    };
+    let task = repo::close_task(
+        &mut tx,
+        task.id,
+        status,
+        quantity_picked,
+        quantity_backordered,
+        short_resolution,
+        req.confirmed_by.as_deref(),
+        confirmed_at,
+    )
+    .await?;
+    if let Some(wave_id) = task.wave_id {
+        repo::complete_wave_if_done(&mut tx, wave_id, confirmed_at).await?;
+    }
+
+    // Step 6: Outbox event.
+    let event_id = Uuid::new_v4();
+    let payload = PickConfirmedPayload {
+        task_id: task.id,
+        tenant_id: req.tenant_id.clone(),
+        warehouse_id: task.warehouse_id,
+        wave_id: task.wave_id,
+        item_id: task.item_id,
+        source_type: task.source_type.clone(),
+        source_id: task.source_id.clone(),
+        source_line_id: task.source_line_id.clone(),
+        quantity_requested: task.quantity_requested,
+        quantity_picked,
+        quantity_backordered,
+        status: task.status.clone(),
+        short_resolution: task.short_resolution.clone(),
+        follow_up_task_id: follow_up_task.as_ref().map(|t| t.id),
+        reservation_id: reservation.id,
+        issue_line_ids: confirmations.iter().map(|c| c.issue_line_id).collect(),
+        confirmed_by: req.confirmed_by.clone(),
+        confirmed_at,
+    };
+    let envelope = build_pick_confirmed_envelope(
+        event_id,
+        req.tenant_id.clone(),
+        correlation_id.clone(),
+        req.causation_id.clone(),
+        payload,
+    );
+    let envelope_json = serde_json::to_string(&envelope)?;
+    repo::insert_outbox_event(
+        &mut tx,
+        event_id,
+        EVENT_TYPE_PICK_CONFIRMED,
+        &task.id.to_string(),
+        &req.tenant_id,
+        &envelope_json,
+        &correlation_id,
+        req.causation_id.as_deref(),
+    )
+    .await?;
+
+    let result = ConfirmPickResult {
+        task,
+        confirmations,
+        release_id,
+        follow_up_task,
+        event_id,
+    };
+
+    // Step 7: Store idempotency key (expires in 7 days).
+    let response_json = serde_json::to_string(&result)?;
+    reservation_repo::store_idempotency_key(
+        &mut tx,
+        &req.tenant_id,
+        &req.idempotency_key,
+        &request_hash,
+        &response_json,
+        201,
+        confirmed_at + Duration::days(7),
+    )
+    .await?;
+
+    tx.commit().await?;
+
+    // Best-effort low-stock signal evaluation per picked location.
+    let locations: HashSet<Option<Uuid>> = picks.iter().map(|p| p.location_id).collect();
+    for location_id in locations {
+        let _ = evaluator::evaluate_low_stock(
+            pool,
+            &req.tenant_id,
+            result.task.item_id,
+            result.task.warehouse_id,
+            location_id,
+            &correlation_id,
+            req.causation_id.clone(),
+        )
+        .await;
+    }
+
+    Ok((result, false))
+}
+
+// ============================================================================
+// Scan verification
+// ============================================================================
+
+/// Resolve the location of a pick from its id and/or scanned label.
+async fn resolve_location(
+    pool: &PgPool,
+    task: &PickTask,
+    pick: &PickLineInput,
+) -> Result<Option<Uuid>, PickError> {
+    let scanned = match pick.location_barcode.as_deref() {
+        Some(code) => Some(
+            repo::find_location_by_code(pool, &task.tenant_id, task.warehouse_id, code.trim())
+                .await?
+                .ok_or_else(|| PickError::LocationNotFound(code.to_string()))?,
+        ),
+        None => None,
+    };
+
+    match (pick.location_id, scanned) {
+        (Some(id), Some(scanned_id)) if id != scanned_id => Err(PickError::ScanMismatch(format!(
+            "scanned location does not match location {}",
+            id
+        ))),
+        (Some(id), _) => {
+            if repo::location_is_active(pool, &task.tenant_id, task.warehouse_id, id).await? {
+                Ok(Some(id))
+            } else {
+                Err(PickError::LocationNotFound(id.to_string()))
+            }
+        }
+        (None, scanned) => Ok(scanned),
+    }
+}
+
+/// Resolve raw scans through the barcode rules and check each against the task item.
+async fn resolve_scans(
+    pool: &PgPool,
+    req: &ConfirmPickRequest,
+    task: &PickTask,
+    pick: &PickLineInput,
+    correlation_id: &str,
+) -> Result<Vec<ScannedEntity>, PickError> {
+    let mut out = Vec::with_capacity(pick.scans.len());
+    for raw in &pick.scans {
+        let resolved = barcode_resolver::resolve(
+            pool,
+            &ResolveRequest {
+                tenant_id: req.tenant_id.clone(),
+                barcode_raw: raw.clone(),
+                resolved_by: req.confirmed_by.clone(),
+                correlation_id: Some(correlation_id.to_string()),
+                causation_id: req.causation_id.clone(),
+            },
+        )
+        .await?;
+
+        if !resolved.resolved {
+            return Err(PickError::ScanMismatch(format!(
+                "barcode '{}' did not resolve{}",
+                raw,
+                resolved.error.map(|e| format!(": {e}")).unwrap_or_default()
+            )));
+        }
+        let entity_ref = resolved.entity_ref.unwrap_or_default();
+
+        let entity = match resolved.entity_type.as_deref() {
+            Some(ENTITY_TYPE_ITEM) if resolved.entity_id == Some(task.item_id) => {
+                ScannedEntity::Item
+            }
+            Some(ENTITY_TYPE_LOT)
+                if ls_issue::find_lot_id(pool, &req.tenant_id, task.item_id, &entity_ref)
+                    .await?
+                    .is_some() =>
+            {
+                ScannedEntity::Lot(entity_ref)
+            }
+            Some(ENTITY_TYPE_SERIAL)
+                if barcode_resolver_repo::find_serial_by_code(
+                    pool,
+                    &req.tenant_id,
+                    &entity_ref,
+                )
+                .await?
+                .is_some_and(|s| s.item_id == task.item_id) =>
+            {
+                ScannedEntity::Serial(entity_ref)
+            }
+            _ => {
+                return Err(PickError::ScanMismatch(format!(
+                    "barcode '{}' does not identify the task item",
+                    raw
+                )))
+            }
+        };
+        out.push(entity);
+    }
+    Ok(out)
+}
+
+/// Check the scans of one pick against the item's tracking mode.
+///
+/// Returns the lot code (lot-tracked) or serial codes (serial-tracked) to issue.
+fn verify_scans(
+    tracking_mode: TrackingMode,
+    quantity: i64,
+    scanned: &[ScannedEntity],
+) -> Result<(Option<String>, Option<Vec<String>>), PickError> {
+    match tracking_mode {
+        TrackingMode::None => {
+            if scanned.is_empty() {
+                return Err(PickError::ScanMismatch(
+                    "at least one item scan is required per pick".to_string(),
+                ));
+            }
+            Ok((None, None))
+        }
+        TrackingMode::Lot => {
+            let lots: HashSet<&str> = scanned
+                .iter()
+                .filter_map(|s| match s {
+                    ScannedEntity::Lot(code) => Some(code.as_str()),
+                    _ => None,
+                })
+                .collect();
+            match lots.len() {
+                1 => Ok((lots.into_iter().next().map(str::to_string), None)),
+                0 => Err(PickError::ScanMismatch(
+                    "lot-tracked item requires a lot scan per pick".to_string(),
+                )),
+                _ => Err(PickError::ScanMismatch(
+                    "a pick may only cover one lot".to_string(),
+                )),
+            }
+        }
+        TrackingMode::Serial => {
+            let serials: Vec<String> = scanned
+                .iter()
+                .filter_map(|s| match s {
+                    ScannedEntity::Serial(code) => Some(code.clone()),
+                    _ => None,
+                })
+                .collect();
+            let unique: HashSet<&String> = serials.iter().collect();
+            if unique.len() != serials.len() {
+                return Err(PickError::ScanMismatch(
+                    "serial scanned more than once".to_string(),
+                ));
+            }
+            if serials.len() as i64 != quantity {
+                return Err(PickError::ScanMismatch(format!(
+                    "serial-tracked pick of {} requires {} serial scans, got {}",
+                    quantity,
+                    quantity,
+                    serials.len()
+                )));
+            }
+            Ok((None, Some(serials)))
+        }
+    }
+}
+
+fn validate_request(req: &ConfirmPickRequest) -> Result<(), PickError> {
+    if req.idempotency_key.trim().is_empty() {
+        return Err(PickError::Validation(
+            "idempotency_key is required".to_string(),
+        ));
+    }
+    if req.tenant_id.trim().is_empty() {
+        return Err(PickError::Validation("tenant_id is required".to_string()));
+    }
+    for pick in &req.picks {
+        guard_quantity_positive(pick.quantity)?;
+    }
+    Ok(())
+}
+
+// ============================================================================
+// Unit tests (stateless verification only)
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn untracked_pick_requires_a_scan() {
+        assert!(matches!(
+            verify_scans(TrackingMode::None, 2, &[]),
+            Err(PickError::ScanMismatch(_))
+        ));
+        assert_eq!(
+            verify_scans(TrackingMode::None, 2, &[ScannedEntity::Item]).unwrap(),
+            (None, None)
+        );
+    }
+
+    #[test]
+    fn lot_pick_takes_exactly_one_lot() {
+        let one = [ScannedEntity::Item, ScannedEntity::Lot("L1".to_string())];
+        assert_eq!(
+            verify_scans(TrackingMode::Lot, 3, &one).unwrap(),
+            (Some("L1".to_string()), None)
+        );
+
+        let two = [
+            ScannedEntity::Lot("L1".to_string()),
+            ScannedEntity::Lot("L2".to_string()),
+        ];
+        assert!(verify_scans(TrackingMode::Lot, 3, &two).is_err());
+        assert!(verify_scans(TrackingMode::Lot, 3, &[ScannedEntity::Item]).is_err());
+    }
+
+    #[test]
+    fn serial_pick_needs_one_unique_scan_per_unit() {
+        let scans = [
+            ScannedEntity::Serial("S1".to_string()),
+            ScannedEntity::Serial("S2".to_string()),
+        ];
+        assert_eq!(
+            verify_scans(TrackingMode::Serial, 2, &scans).unwrap(),
+            (None, Some(vec!["S1".to_string(), "S2".to_string()]))
+        );
+        assert!(verify_scans(TrackingMode::Serial, 3, &scans).is_err());
+
+        let dup = [
+            ScannedEntity::Serial("S1".to_string()),
+            ScannedEntity::Serial("S1".to_string()),
+        ];
+        assert!(verify_scans(TrackingMode::Serial, 2, &dup).is_err());
+    }
+
+    #[test]
+    fn rejects_non_positive_pick_quantity() {
+        let req = ConfirmPickRequest {
+            tenant_id: "t".to_string(),
+            task_id: Uuid::new_v4(),
+            picks: vec![PickLineInput {
+                quantity: 0,
+                location_id: None,
+                location_barcode: None,
+                scans: vec![],
+            }],
+            short_action: ShortAction::Backorder,
+            confirmed_by: None,
+            idempotency_key: "k".to_string(),
+            correlation_id: None,
+            causation_id: None,
+        };
+        assert!(matches!(validate_request(&req), Err(PickError::Guard(_))));
+    }
+}
@@ -0,0 +1,6 @@
+pub mod confirm_service;
+pub mod models;
+pub mod repo;
+pub mod suggest;
+pub mod task_service;
+pub mod wave_service;
@@ -0,0 +1,347 @@
+//! Pick task, wave and confirmation types.
+
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::domain::{
+    barcode_resolver::BarcodeError, guards::GuardError, issue_service::IssueError,
+};
+
+// ============================================================================
+// Enums
+// ============================================================================
+
+/// Demand document a pick task was generated from.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum PickSourceType {
+    /// Sales-order release line
+    SalesOrder,
+    /// Production work-order component demand
+    Production,
+}
+
+impl PickSourceType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PickSourceType::SalesOrder => "sales_order",
+            PickSourceType::Production => "production",
+        }
+    }
+
+    /// Module recorded as `source_module` on the issue posted by a confirmed pick.
+    pub fn source_module(&self) -> &'static str {
+        match self {
+            PickSourceType::SalesOrder => "sales_orders",
+            PickSourceType::Production => "production",
+        }
+    }
+
+    pub fn parse(s: &str) -> Option<Self> {
+        match s {
+            "sales_order" => Some(PickSourceType::SalesOrder),
+            "production" => Some(PickSourceType::Production),
+            _ => None,
+        }
+    }
+}
+
+/// What to do with the unpicked remainder of a short pick.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ShortAction {
+    /// Reserve the remainder again and open a follow-up pick task for it.
+    /// Falls back to a backorder when the remainder is not available.
+    ReReserve,
+    /// Record the remainder as backordered on the task.
+    #[default]
+    Backorder,
+}
+
+pub const TASK_STATUS_OPEN: &str = "open";
+pub const TASK_STATUS_PICKED: &str = "picked";
+pub const TASK_STATUS_SHORT: &str = "short";
+
+pub const WAVE_STATUS_RELEASED: &str = "released";
+pub const WAVE_STATUS_COMPLETED: &str = "completed";
+
+pub const SHORT_RESOLUTION_RE_RESERVED: &str = "re_reserved";
+pub const SHORT_RESOLUTION_BACKORDERED: &str = "backordered";
+
+// ============================================================================
+// Records
+// ============================================================================
+
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow, ToSchema)]
+pub struct PickTask {
+    pub id: Uuid,
+    pub tenant_id: String,
+    pub warehouse_id: Uuid,
+    pub wave_id: Option<Uuid>,
+    pub item_id: Uuid,
+    /// sales_order | production
+    pub source_type: String,
+    pub source_id: String,
+    pub source_line_id: Option<String>,
+    pub currency: String,
+    pub quantity_requested: i64,
+    pub quantity_picked: i64,
+    pub quantity_backordered: i64,
+    /// Reservation held for `quantity_requested` until the task is confirmed
+    pub reservation_id: Uuid,
+    /// Set on follow-up tasks created by a re-reserved short pick
+    pub parent_task_id: Option<Uuid>,
+    /// open | picked | short
+    pub status: String,
+    /// re_reserved | backordered (short tasks only)
+    pub short_resolution: Option<String>,
+    pub confirmed_by: Option<String>,
+    pub confirmed_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// One scanned pick posted against a task (one stock issue).
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow, ToSchema)]
+pub struct PickConfirmation {
+    pub id: Uuid,
+    pub task_id: Uuid,
+    pub location_id: Option<Uuid>,
+    pub lot_code: Option<String>,
+    pub serial_codes: Option<Vec<String>>,
+    pub quantity: i64,
+    /// `issue_line_id` of the posted stock issue
+    pub issue_line_id: Uuid,
+    pub confirmed_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow, ToSchema)]
+pub struct PickWave {
+    pub id: Uuid,
+    pub tenant_id: String,
+    pub warehouse_id: Uuid,
+    pub wave_number: String,
+    /// released | completed
+    pub status: String,
+    pub created_by: Option<String>,
+    pub released_at: DateTime<Utc>,
+    pub completed_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct PickTaskDetail {
+    pub task: PickTask,
+    pub confirmations: Vec<PickConfirmation>,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct PickWaveDetail {
+    pub wave: PickWave,
+    pub tasks: Vec<PickTask>,
+}
+
+// ============================================================================
+// Task generation
+// ============================================================================
+
+/// Input for POST /api/inventory/pick-tasks
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct CreatePickTasksRequest {
+    pub tenant_id: String,
+    pub warehouse_id: Uuid,
+    pub source_type: PickSourceType,
+    /// Sales order / release id or work order id
+    pub source_id: String,
+    /// Currency recorded on the issues posted by confirmed picks
+    pub currency: String,
+    pub lines: Vec<PickDemandLine>,
+    /// Caller-supplied idempotency key (required; scoped per tenant)
+    pub idempotency_key: String,
+    pub correlation_id: Option<String>,
+    pub causation_id: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct PickDemandLine {
+    pub item_id: Uuid,
+    /// Units to pick (base UoM, must be > 0)
+    pub quantity: i64,
+    pub source_line_id: Option<String>,
+    /// Existing active reservation to pick against. Must match item, warehouse
+    /// and quantity. When absent, the quantity is reserved on task creation.
+    #[serde(default)]
+    pub reservation_id: Option<Uuid>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct CreatePickTasksResult {
+    pub tasks: Vec<PickTask>,
+}
+
+// ============================================================================
+// Waves and pick lists
+// ============================================================================
+
+/// Input for POST /api/inventory/pick-waves
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct CreatePickWaveRequest {
+    pub tenant_id: String,
+    pub warehouse_id: Uuid,
+    /// Explicit open tasks to group. When empty, open unwaved tasks of the
+    /// warehouse are selected oldest-first.
+    #[serde(default)]
+    pub task_ids: Vec<Uuid>,
+    /// Restrict automatic selection to one demand type
+    #[serde(default)]
+    pub source_type: Option<PickSourceType>,
+    /// Cap for automatic selection (default 50, max 500)
+    #[serde(default)]
+    pub max_tasks: Option<i64>,
+    #[serde(default)]
+    pub created_by: Option<String>,
+}
+
+/// Location-directed pick list for a wave, in walk order (location code).
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct PickList {
+    pub wave_id: Uuid,
+    pub warehouse_id: Uuid,
+    /// Date used to exclude expired lots
+    pub as_of: NaiveDate,
+    pub lines: Vec<PickListLine>,
+    /// Open task quantities no pickable stock could be suggested for
+    pub shortages: Vec<PickShortage>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct PickListLine {
+    pub task_id: Uuid,
+    pub item_id: Uuid,
+    /// None = warehouse-level (unlocated) stock
+    pub location_id: Option<Uuid>,
+    pub location_code: Option<String>,
+    pub lot_code: Option<String>,
+    pub expires_on: Option<NaiveDate>,
+    pub quantity: i64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct PickShortage {
+    pub task_id: Uuid,
+    pub item_id: Uuid,
+    pub quantity: i64,
+}
+
+// ============================================================================
+// Confirmation
+// ============================================================================
+
+/// Pick confirmation command (built from the HTTP body + path task id).
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ConfirmPickRequest {
+    pub tenant_id: String,
+    pub task_id: Uuid,
+    /// Scanned picks; empty confirms a full short pick
+    pub picks: Vec<PickLineInput>,
+    pub short_action: ShortAction,
+    pub confirmed_by: Option<String>,
+    /// Caller-supplied idempotency key (required; scoped per tenant)
+    pub idempotency_key: String,
+    pub correlation_id: Option<String>,
+    pub causation_id: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct PickLineInput {
+    /// Units picked (base UoM). Must equal the number of serial scans for
+    /// serial-tracked items.
+    pub quantity: i64,
+    /// Location picked from
+    #[serde(default)]
+    pub location_id: Option<Uuid>,
+    /// Scanned location label (location code); must agree with `location_id` when both are set
+    #[serde(default)]
+    pub location_barcode: Option<String>,
+    /// Raw item / lot / serial barcodes, resolved through the barcode rules
+    #[serde(default)]
+    pub scans: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct ConfirmPickResult {
+    pub task: PickTask,
+    pub confirmations: Vec<PickConfirmation>,
+    /// Compensating reservation row written for the task's reservation
+    pub release_id: Uuid,
+    /// Follow-up task holding the re-reserved remainder of a short pick
+    pub follow_up_task: Option<PickTask>,
+    /// UUID of the inventory.pick_confirmed outbox event
+    pub event_id: Uuid,
+}
+
+// ============================================================================
+// Errors
+// ============================================================================
+
+#[derive(Debug, Error)]
+pub enum PickError {
+    #[error("Guard failed: {0}")]
+    Guard(#[from] GuardError),
+
+    #[error("Validation error: {0}")]
+    Validation(String),
+
+    #[error("Pick task not found")]
+    TaskNotFound,
+
+    #[error("Pick wave not found")]
+    WaveNotFound,
+
+    #[error("Pick task is not open")]
+    TaskNotOpen,
+
+    #[error("No open pick tasks to group into a wave")]
+    NoOpenTasks,
+
+    #[error("Reservation not found or no longer active")]
+    ReservationNotActive,
+
+    #[error("Reservation is already backing another pick task")]
+    ReservationInUse,
+
+    #[error("Source line '{0}' already has a pick task")]
+    SourceLineTasked(String),
+
+    #[error("Insufficient available stock for item {item_id}: requested {requested}, available {available}")]
+    InsufficientAvailable {
+        item_id: Uuid,
+        requested: i64,
+        available: i64,
+    },
+
+    #[error("Location '{0}' not found or inactive in this warehouse")]
+    LocationNotFound(String),
+
+    #[error("Scan mismatch: {0}")]
+    ScanMismatch(String),
+
+    #[error("Issue failed: {0}")]
+    Issue(#[from] IssueError),
+
+    #[error("Barcode resolution failed: {0}")]
+    Barcode(#[from] BarcodeError),
+
+    #[error("Idempotency key conflict: same key used with a different request body")]
+    ConflictingIdempotencyKey,
+
+    #[error("Serialization error: {0}")]
+    Serialization(#[from] serde_json::Error),
+
+    #[error("Database error: {0}")]
+    Database(#[from] sqlx::Error),
+}
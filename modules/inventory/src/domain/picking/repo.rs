@@ -0,0 +1,603 @@
+//! Pick repository — database operations for pick tasks, waves and confirmations.
+//!
+//! Transactional functions accept `&mut Transaction<'_, Postgres>` so they
+//! participate in the atomic units established by the picking services.
+
+use std::collections::HashMap;
+
+use chrono::{DateTime, Utc};
+use sqlx::{PgPool, Postgres, Transaction};
+use uuid::Uuid;
+
+use super::models::{PickConfirmation, PickTask, PickWave, TASK_STATUS_OPEN};
+use super::suggest::StockCandidate;
+
+const TASK_COLUMNS: &str = "id, tenant_id, warehouse_id, wave_id, item_id, source_type, \
+     source_id, source_line_id, currency, quantity_requested, quantity_picked, \
+     quantity_backordered, reservation_id, parent_task_id, status, short_resolution, \
+     confirmed_by, confirmed_at, created_at, updated_at";
+
+const WAVE_COLUMNS: &str = "id, tenant_id, warehouse_id, wave_number, status, created_by, \
+     released_at, completed_at, created_at, updated_at";
+
+const CONFIRMATION_COLUMNS: &str =
+    "id, task_id, location_id, lot_code, serial_codes, quantity, issue_line_id, confirmed_at";
+
+/// Insert values for a new pick task.
+pub(crate) struct NewPickTask<'a> {
+    pub tenant_id: &'a str,
+    pub warehouse_id: Uuid,
+    pub wave_id: Option<Uuid>,
+    pub item_id: Uuid,
+    pub source_type: &'a str,
+    pub source_id: &'a str,
+    pub source_line_id: Option<&'a str>,
+    pub currency: &'a str,
+    pub quantity_requested: i64,
+    pub reservation_id: Uuid,
+    pub parent_task_id: Option<Uuid>,
+}
+
+/// List filter for pick tasks (warehouse is always required).
+pub struct PickTaskFilter<'a> {
+    pub warehouse_id: Uuid,
+    pub status: Option<&'a str>,
+    pub source_type: Option<&'a str>,
+    pub source_id: Option<&'a str>,
+    pub wave_id: Option<Uuid>,
+}
+
+// ============================================================================
+// Tasks
+// ============================================================================
+
+pub async fn find_task(
+    pool: &PgPool,
+    tenant_id: &str,
+    task_id: Uuid,
+) -> Result<Option<PickTask>, sqlx::Error> {
+    sqlx::query_as::<_, PickTask>(&format!(
+        "SELECT {TASK_COLUMNS} FROM inv_pick_tasks WHERE id = $1 AND tenant_id = $2"
+    ))
+    .bind(task_id)
+    .bind(tenant_id)
+    .fetch_optional(pool)
+    .await
+}
+
+/// Whether a demand source line already has its original (non follow-up) task.
+pub(crate) async fn source_line_has_task(
+    pool: &PgPool,
+    tenant_id: &str,
+    source_type: &str,
+    source_id: &str,
+    source_line_id: &str,
+) -> Result<bool, sqlx::Error> {
+    sqlx::query_scalar::<_, bool>(
+        r#"
+        SELECT EXISTS (
+            SELECT 1 FROM inv_pick_tasks
+            WHERE tenant_id = $1 AND source_type = $2 AND source_id = $3
+              AND source_line_id = $4 AND parent_task_id IS NULL
+        )
+        "#,
+    )
+    .bind(tenant_id)
+    .bind(source_type)
+    .bind(source_id)
+    .bind(source_line_id)
+    .fetch_one(pool)
+    .await
+}
+
+/// Lock a task row for the confirmation transaction.
+pub(crate) async fn lock_task(
+    tx: &mut Transaction<'_, Postgres>,
+    tenant_id: &str,
+    task_id: Uuid,
+) -> Result<Option<PickTask>, sqlx::Error> {
+    sqlx::query_as::<_, PickTask>(&format!(
+        "SELECT {TASK_COLUMNS} FROM inv_pick_tasks WHERE id = $1 AND tenant_id = $2 FOR UPDATE"
+    ))
+    .bind(task_id)
+    .bind(tenant_id)
+    .fetch_optional(&mut **tx)
+    .await
+}
+
+pub async fn list_tasks(
+    pool: &PgPool,
+    tenant_id: &str,
+    filter: &PickTaskFilter<'_>,
+    limit: i64,
+    offset: i64,
+) -> Result<(Vec<PickTask>, i64), sqlx::Error> {
+    let where_clause = "tenant_id = $1 AND warehouse_id = $2
+          AND ($3::TEXT IS NULL OR status = $3)
+          AND ($4::TEXT IS NULL OR source_type = $4)
+          AND ($5::TEXT IS NULL OR source_id = $5)
+          AND ($6::UUID IS NULL OR wave_id = $6)";
+
+    let total: i64 = sqlx::query_scalar(&format!(
+        "SELECT COUNT(*) FROM inv_pick_tasks WHERE {where_clause}"
+    ))
+    .bind(tenant_id)
+    .bind(filter.warehouse_id)
+    .bind(filter.status)
+    .bind(filter.source_type)
+    .bind(filter.source_id)
+    .bind(filter.wave_id)
+    .fetch_one(pool)
+    .await?;
+
+    let rows = sqlx::query_as::<_, PickTask>(&format!(
+        "SELECT {TASK_COLUMNS} FROM inv_pick_tasks WHERE {where_clause}
+         ORDER BY created_at, id LIMIT $7 OFFSET $8"
+    ))
+    .bind(tenant_id)
+    .bind(filter.warehouse_id)
+    .bind(filter.status)
+    .bind(filter.source_type)
+    .bind(filter.source_id)
+    .bind(filter.wave_id)
+    .bind(limit)
+    .bind(offset)
+    .fetch_all(pool)
+    .await?;
+
+    Ok((rows, total))
+}
+
+pub(crate) async fn insert_task(
+    tx: &mut Transaction<'_, Postgres>,
+    t: &NewPickTask<'_>,
+) -> Result<PickTask, sqlx::Error> {
+    sqlx::query_as::<_, PickTask>(&format!(
+        "INSERT INTO inv_pick_tasks
+            (tenant_id, warehouse_id, wave_id, item_id, source_type, source_id,
+             source_line_id, currency, quantity_requested, reservation_id, parent_task_id)
+         VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11)
+         RETURNING {TASK_COLUMNS}"
+    ))
+    .bind(t.tenant_id)
+    .bind(t.warehouse_id)
+    .bind(t.wave_id)
+    .bind(t.item_id)
+    .bind(t.source_type)
+    .bind(t.source_id)
+    .bind(t.source_line_id)
+    .bind(t.currency)
+    .bind(t.quantity_requested)
+    .bind(t.reservation_id)
+    .bind(t.parent_task_id)
+    .fetch_one(&mut **tx)
+    .await
+}
+
+/// Record the outcome of a confirmation on the task row.
+#[allow(clippy::too_many_arguments)]
+pub(crate) async fn close_task(
+    tx: &mut Transaction<'_, Postgres>,
+    task_id: Uuid,
+    status: &str,
+    quantity_picked: i64,
+    quantity_backordered: i64,
+    short_resolution: Option<&str>,
+    confirmed_by: Option<&str>,
+    confirmed_at: DateTime<Utc>,
+) -> Result<PickTask, sqlx::Error> {
+    sqlx::query_as::<_, PickTask>(&format!(
+        "UPDATE inv_pick_tasks
+         SET status = $2, quantity_picked = $3, quantity_backordered = $4,
+             short_resolution = $5, confirmed_by = $6, confirmed_at = $7, updated_at = $7
+         WHERE id = $1
+         RETURNING {TASK_COLUMNS}"
+    ))
+    .bind(task_id)
+    .bind(status)
+    .bind(quantity_picked)
+    .bind(quantity_backordered)
+    .bind(short_resolution)
+    .bind(confirmed_by)
+    .bind(confirmed_at)
+    .fetch_one(&mut **tx)
+    .await
+}
+
+// ============================================================================
+// Confirmations
+// ============================================================================
+
+#[allow(clippy::too_many_arguments)]
+pub(crate) async fn insert_confirmation(
+    tx: &mut Transaction<'_, Postgres>,
+    tenant_id: &str,
+    task_id: Uuid,
+    location_id: Option<Uuid>,
+    lot_code: Option<&str>,
+    serial_codes: Option<&[String]>,
+    quantity: i64,
+    issue_line_id: Uuid,
+    confirmed_at: DateTime<Utc>,
+) -> Result<PickConfirmation, sqlx::Error> {
+    sqlx::query_as::<_, PickConfirmation>(&format!(
+        "INSERT INTO inv_pick_confirmations
+            (tenant_id, task_id, location_id, lot_code, serial_codes, quantity,
+             issue_line_id, confirmed_at)
+         VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+         RETURNING {CONFIRMATION_COLUMNS}"
+    ))
+    .bind(tenant_id)
+    .bind(task_id)
+    .bind(location_id)
+    .bind(lot_code)
+    .bind(serial_codes)
+    .bind(quantity)
+    .bind(issue_line_id)
+    .bind(confirmed_at)
+    .fetch_one(&mut **tx)
+    .await
+}
+
+pub async fn list_confirmations(
+    pool: &PgPool,
+    task_id: Uuid,
+) -> Result<Vec<PickConfirmation>, sqlx::Error> {
+    sqlx::query_as::<_, PickConfirmation>(&format!(
+        "SELECT {CONFIRMATION_COLUMNS} FROM inv_pick_confirmations
+         WHERE task_id = $1 ORDER BY confirmed_at, id"
+    ))
+    .bind(task_id)
+    .fetch_all(pool)
+    .await
+}
+
+// ============================================================================
+// Waves
+// ============================================================================
+
+pub async fn find_wave(
+    pool: &PgPool,
+    tenant_id: &str,
+    wave_id: Uuid,
+) -> Result<Option<PickWave>, sqlx::Error> {
+    sqlx::query_as::<_, PickWave>(&format!(
+        "SELECT {WAVE_COLUMNS} FROM inv_pick_waves WHERE id = $1 AND tenant_id = $2"
+    ))
+    .bind(wave_id)
+    .bind(tenant_id)
+    .fetch_optional(pool)
+    .await
+}
+
+pub async fn tasks_for_wave(
+    pool: &PgPool,
+    wave_id: Uuid,
+    open_only: bool,
+) -> Result<Vec<PickTask>, sqlx::Error> {
+    sqlx::query_as::<_, PickTask>(&format!(
+        "SELECT {TASK_COLUMNS} FROM inv_pick_tasks
+         WHERE wave_id = $1 AND (NOT $2 OR status = '{TASK_STATUS_OPEN}')
+         ORDER BY created_at, id"
+    ))
+    .bind(wave_id)
+    .bind(open_only)
+    .fetch_all(pool)
+    .await
+}
+
+/// Lock explicitly requested tasks for wave assignment.
+pub(crate) async fn lock_tasks_by_ids(
+    tx: &mut Transaction<'_, Postgres>,
+    tenant_id: &str,
+    task_ids: &[Uuid],
+) -> Result<Vec<PickTask>, sqlx::Error> {
+    sqlx::query_as::<_, PickTask>(&format!(
+        "SELECT {TASK_COLUMNS} FROM inv_pick_tasks
+         WHERE tenant_id = $1 AND id = ANY($2)
+         ORDER BY created_at, id
+         FOR UPDATE"
+    ))
+    .bind(tenant_id)
+    .bind(task_ids)
+    .fetch_all(&mut **tx)
+    .await
+}
+
+/// Lock the oldest open, unwaved tasks of a warehouse. Rows locked by a
+/// concurrent wave build are skipped rather than waited on.
+pub(crate) async fn lock_unwaved_open_tasks(
+    tx: &mut Transaction<'_, Postgres>,
+    tenant_id: &str,
+    warehouse_id: Uuid,
+    source_type: Option<&str>,
+    limit: i64,
+) -> Result<Vec<PickTask>, sqlx::Error> {
+    sqlx::query_as::<_, PickTask>(&format!(
+        "SELECT {TASK_COLUMNS} FROM inv_pick_tasks
+         WHERE tenant_id = $1 AND warehouse_id = $2
+           AND status = '{TASK_STATUS_OPEN}' AND wave_id IS NULL
+           AND ($3::TEXT IS NULL OR source_type = $3)
+         ORDER BY created_at, id
+         LIMIT $4
+         FOR UPDATE SKIP LOCKED"
+    ))
+    .bind(tenant_id)
+    .bind(warehouse_id)
+    .bind(source_type)
+    .bind(limit)
+    .fetch_all(&mut **tx)
+    .await
+}
+
+/// Next per-tenant wave sequence number. Serialized by a transaction-scoped
+/// advisory lock so concurrent wave builds never collide on `wave_number`.
+pub(crate) async fn next_wave_seq(
+    tx: &mut Transaction<'_, Postgres>,
+    tenant_id: &str,
+) -> Result<i64, sqlx::Error> {
+    sqlx::query("SELECT pg_advisory_xact_lock(hashtext($1))")
+        .bind(format!("inv_pick_waves:{tenant_id}"))
+        .execute(&mut **tx)
+        .await?;
+    let count: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM inv_pick_waves WHERE tenant_id = $1")
+        .bind(tenant_id)
+        .fetch_one(&mut **tx)
+        .await?;
+    Ok(count + 1)
+}
+
+pub(crate) async fn insert_wave(
+    tx: &mut Transaction<'_, Postgres>,
+    tenant_id: &str,
+    warehouse_id: Uuid,
+    wave_number: &str,
+    created_by: Option<&str>,
+) -> Result<PickWave, sqlx::Error> {
+    sqlx::query_as::<_, PickWave>(&format!(
+        "INSERT INTO inv_pick_waves (tenant_id, warehouse_id, wave_number, created_by)
+         VALUES ($1, $2, $3, $4)
+         RETURNING {WAVE_COLUMNS}"
+    ))
+    .bind(tenant_id)
+    .bind(warehouse_id)
+    .bind(wave_number)
+    .bind(created_by)
+    .fetch_one(&mut **tx)
+    .await
+}
+
+pub(crate) async fn assign_tasks_to_wave(
+    tx: &mut Transaction<'_, Postgres>,
+    wave_id: Uuid,
+    task_ids: &[Uuid],
+) -> Result<Vec<PickTask>, sqlx::Error> {
+    sqlx::query_as::<_, PickTask>(&format!(
+        "UPDATE inv_pick_tasks SET wave_id = $1, updated_at = NOW()
+         WHERE id = ANY($2)
+         RETURNING {TASK_COLUMNS}"
+    ))
+    .bind(wave_id)
+    .bind(task_ids)
+    .fetch_all(&mut **tx)
+    .await
+}
+
+/// Mark the wave completed when none of its tasks remain open.
+/// Returns true when the wave transitioned.
+pub(crate) async fn complete_wave_if_done(
+    tx: &mut Transaction<'_, Postgres>,
+    wave_id: Uuid,
+    at: DateTime<Utc>,
+) -> Result<bool, sqlx::Error> {
+    let result = sqlx::query(&format!(
+        "UPDATE inv_pick_waves SET status = 'completed', completed_at = $2, updated_at = $2
+         WHERE id = $1 AND status = 'released'
+           AND NOT EXISTS (
+               SELECT 1 FROM inv_pick_tasks
+               WHERE wave_id = $1 AND status = '{TASK_STATUS_OPEN}'
+           )"
+    ))
+    .bind(wave_id)
+    .bind(at)
+    .execute(&mut **tx)
+    .await?;
+    Ok(result.rows_affected() > 0)
+}
+
+// ============================================================================
+// Suggestion inputs
+// ============================================================================
+
+/// Open FIFO layers of an item in a warehouse with receipt location and lot expiry.
+///
+/// The layer location is the bin its completed putaway moved it to, otherwise
+/// the location of the receipt ledger entry that created it; layers in an
+/// inactive location or of a quarantined lot are excluded.
+pub async fn stock_candidates(
+    pool: &PgPool,
+    tenant_id: &str,
+    item_id: Uuid,
+    warehouse_id: Uuid,
+) -> Result<Vec<StockCandidate>, sqlx::Error> {
+    sqlx::query_as::<_, StockCandidate>(
+        r#"
+        SELECT COALESCE(pt.to_location_id, l.location_id) AS location_id,
+               loc.code        AS location_code,
+               lot.lot_code,
+               lot.expires_on,
+               layer.received_at,
+               layer.ledger_entry_id,
+               layer.quantity_remaining AS quantity
+        FROM inventory_layers layer
+        JOIN inventory_ledger l ON l.id = layer.ledger_entry_id
+        LEFT JOIN inv_putaway_tasks pt
+               ON pt.layer_id = layer.id AND pt.status = 'completed'
+        LEFT JOIN locations loc ON loc.id = COALESCE(pt.to_location_id, l.location_id)
+        LEFT JOIN inventory_lots lot ON lot.id = layer.lot_id
+        WHERE layer.tenant_id = $1
+          AND layer.item_id = $2
+          AND layer.warehouse_id = $3
+          AND layer.quantity_remaining > 0
+          AND lot.quarantined_at IS NULL
+          AND (COALESCE(pt.to_location_id, l.location_id) IS NULL OR loc.is_active = TRUE)
+        "#,
+    )
+    .bind(tenant_id)
+    .bind(item_id)
+    .bind(warehouse_id)
+    .fetch_all(pool)
+    .await
+}
+
+/// Lock the open layers of an item in a warehouse and return what can still
+/// be reserved: remaining layer quantity (quarantined lots excluded) minus
+/// warehouse reservations.
+///
+/// Unlike `reservation_repo::lock_available_stock`, this counts stock received
+/// into locations, which is never projected onto the null-location row.
+pub(crate) async fn lock_reservable_quantity(
+    tx: &mut Transaction<'_, Postgres>,
+    tenant_id: &str,
+    item_id: Uuid,
+    warehouse_id: Uuid,
+) -> Result<i64, sqlx::Error> {
+    let remaining: Vec<i64> = sqlx::query_scalar(
+        r#"
+        SELECT quantity_remaining
+        FROM inventory_layers layer
+        WHERE tenant_id = $1 AND item_id = $2 AND warehouse_id = $3
+          AND quantity_remaining > 0
+          AND NOT EXISTS (
+              SELECT 1 FROM inventory_lots lot
+              WHERE lot.id = layer.lot_id AND lot.quarantined_at IS NOT NULL
+          )
+        FOR UPDATE
+        "#,
+    )
+    .bind(tenant_id)
+    .bind(item_id)
+    .bind(warehouse_id)
+    .fetch_all(&mut **tx)
+    .await?;
+
+    let reserved: i64 = sqlx::query_scalar(
+        r#"
+        SELECT COALESCE(quantity_reserved, 0)
+        FROM item_on_hand
+        WHERE tenant_id = $1 AND item_id = $2 AND warehouse_id = $3
+          AND location_id IS NULL
+        "#,
+    )
+    .bind(tenant_id)
+    .bind(item_id)
+    .bind(warehouse_id)
+    .fetch_optional(&mut **tx)
+    .await?
+    .unwrap_or(0);
+
+    Ok(remaining.iter().sum::<i64>() - reserved)
+}
+
+/// Available quantity per location for an item in a warehouse.
+pub async fn location_caps(
+    pool: &PgPool,
+    tenant_id: &str,
+    item_id: Uuid,
+    warehouse_id: Uuid,
+) -> Result<HashMap<Uuid, i64>, sqlx::Error> {
+    let rows: Vec<(Uuid, i64)> = sqlx::query_as(
+        r#"
+        SELECT location_id, available_status_on_hand
+        FROM item_on_hand
+        WHERE tenant_id = $1 AND item_id = $2 AND warehouse_id = $3
+          AND location_id IS NOT NULL
+        "#,
+    )
+    .bind(tenant_id)
+    .bind(item_id)
+    .bind(warehouse_id)
+    .fetch_all(pool)
+    .await?;
+    Ok(rows.into_iter().collect())
+}
+
+// ============================================================================
+// Locations
+// ============================================================================
+
+/// Resolve an active location of the warehouse by its code (scanned label).
+pub async fn find_location_by_code(
+    pool: &PgPool,
+    tenant_id: &str,
+    warehouse_id: Uuid,
+    code: &str,
+) -> Result<Option<Uuid>, sqlx::Error> {
+    sqlx::query_scalar(
+        r#"
+        SELECT id FROM locations
+        WHERE tenant_id = $1 AND warehouse_id = $2 AND code = $3 AND is_active = TRUE
+        "#,
+    )
+    .bind(tenant_id)
+    .bind(warehouse_id)
+    .bind(code)
+    .fetch_optional(pool)
+    .await
+}
+
+pub async fn location_is_active(
+    pool: &PgPool,
+    tenant_id: &str,
+    warehouse_id: Uuid,
+    location_id: Uuid,
+) -> Result<bool, sqlx::Error> {
+    sqlx::query_scalar(
+        r#"
+        SELECT EXISTS(
+            SELECT 1 FROM locations
+            WHERE id = $1 AND tenant_id = $2 AND warehouse_id = $3 AND is_active = TRUE
+        )
+        "#,
+    )
+    .bind(location_id)
+    .bind(tenant_id)
+    .bind(warehouse_id)
+    .fetch_one(pool)
+    .await
+}
+
+// ============================================================================
+// Outbox
+// ============================================================================
+
+#[allow(clippy::too_many_arguments)]
+pub(crate) async fn insert_outbox_event(
+    tx: &mut Transaction<'_, Postgres>,
+    event_id: Uuid,
+    event_type: &str,
+    aggregate_id: &str,
+    tenant_id: &str,
+    envelope_json: &str,
+    correlation_id: &str,
+    causation_id: Option<&str>,
+) -> Result<(), sqlx::Error> {
+    sqlx::query(
+        r#"
+        INSERT INTO inv_outbox
+            (event_id, event_type, aggregate_type, aggregate_id, tenant_id,
+             payload, correlation_id, causation_id, schema_version)
+        VALUES ($1, $2, 'inventory_pick_task', $3, $4, $5::JSONB, $6, $7, '1.0.0')
+        "#,
+    )
+    .bind(event_id)
+    .bind(event_type)
+    .bind(aggregate_id)
+    .bind(tenant_id)
+    .bind(envelope_json)
+    .bind(correlation_id)
+    .bind(causation_id)
+    .execute(&mut **tx)
+    .await?;
+    Ok(())
+}
@@ -0,0 +1,242 @@
+//! Pick location suggestion (pure, no I/O).
+//!
+//! Candidates are the open FIFO layers of an item in one warehouse, carrying
+//! the location of their receipt and their lot's expiry. Ordering:
+//!   1. FEFO — earliest `expires_on` first; lots without expiry after dated lots
+//!   2. FIFO — oldest `received_at`, tie-break by ledger_entry_id
+//!
+//! Lots whose `expires_on` is before `as_of` are never suggested. Located
+//! candidates are capped by what the location's on-hand row still holds, so
+//! stock moved out of a bin after receipt is not suggested from it.
+
+use std::collections::HashMap;
+
+use chrono::{DateTime, NaiveDate, Utc};
+use uuid::Uuid;
+
+/// One open layer as seen by the suggester.
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct StockCandidate {
+    pub location_id: Option<Uuid>,
+    pub location_code: Option<String>,
+    pub lot_code: Option<String>,
+    pub expires_on: Option<NaiveDate>,
+    pub received_at: DateTime<Utc>,
+    pub ledger_entry_id: i64,
+    pub quantity: i64,
+}
+
+/// A suggested pick of `quantity` from one location/lot.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Allocation {
+    pub location_id: Option<Uuid>,
+    pub location_code: Option<String>,
+    pub lot_code: Option<String>,
+    pub expires_on: Option<NaiveDate>,
+    pub quantity: i64,
+}
+
+/// Pickable stock of one item, consumed as tasks are allocated.
+pub struct StockPool {
+    candidates: Vec<StockCandidate>,
+    location_caps: HashMap<Uuid, i64>,
+}
+
+impl StockPool {
+    /// Build a pool from raw candidates and per-location available quantities.
+    pub fn new(
+        candidates: Vec<StockCandidate>,
+        location_caps: HashMap<Uuid, i64>,
+        as_of: NaiveDate,
+    ) -> Self {
+        let mut candidates: Vec<StockCandidate> = candidates
+            .into_iter()
+            .filter(|c| c.quantity > 0 && c.expires_on.is_none_or(|d| d >= as_of))
+            .collect();
+        candidates.sort_by(|a, b| {
+            a.expires_on
+                .is_none()
+                .cmp(&b.expires_on.is_none())
+                .then(a.expires_on.cmp(&b.expires_on))
+                .then(a.received_at.cmp(&b.received_at))
+                .then(a.ledger_entry_id.cmp(&b.ledger_entry_id))
+        });
+        Self {
+            candidates,
+            location_caps,
+        }
+    }
+
+    /// Allocate up to `quantity` units. Returns the allocations in pick order
+    /// and the quantity that could not be allocated.
+    pub fn allocate(&mut self, quantity: i64) -> (Vec<Allocation>, i64) {
+        let mut remaining = quantity;
+        let mut out: Vec<Allocation> = Vec::new();
+
+        for c in self.candidates.iter_mut() {
+            if remaining == 0 {
+                break;
+            }
+            let cap = match c.location_id {
+                Some(loc) => *self.location_caps.get(&loc).unwrap_or(&0),
+                None => i64::MAX,
+            };
+            let take = remaining.min(c.quantity).min(cap);
+            if take <= 0 {
+                continue;
+            }
+
+            c.quantity -= take;
+            if let Some(loc) = c.location_id {
+                if let Some(left) = self.location_caps.get_mut(&loc) {
+                    *left -= take;
+                }
+            }
+            remaining -= take;
+
+            match out.last_mut() {
+                Some(last) if last.location_id == c.location_id && last.lot_code == c.lot_code => {
+                    last.quantity += take;
+                }
+                _ => out.push(Allocation {
+                    location_id: c.location_id,
+                    location_code: c.location_code.clone(),
+                    lot_code: c.lot_code.clone(),
+                    expires_on: c.expires_on,
+                    quantity: take,
+                }),
+            }
+        }
+
+        (out, remaining)
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn day(d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2026, 5, d).unwrap()
+    }
+
+    fn cand(
+        loc: Option<Uuid>,
+        lot: Option<&str>,
+        expires: Option<NaiveDate>,
+        received_day: u32,
+        entry: i64,
+        qty: i64,
+    ) -> StockCandidate {
+        StockCandidate {
+            location_id: loc,
+            location_code: loc.map(|_| format!("BIN-{entry}")),
+            lot_code: lot.map(str::to_string),
+            expires_on: expires,
+            received_at: Utc
+                .with_ymd_and_hms(2026, 4, received_day, 0, 0, 0)
+                .unwrap(),
+            ledger_entry_id: entry,
+            quantity: qty,
+        }
+    }
+
+    #[test]
+    fn fefo_orders_by_expiry_before_receipt_date() {
+        let mut pool = StockPool::new(
+            vec![
+                cand(None, Some("OLD-LATE"), Some(day(30)), 1, 1, 10),
+                cand(None, Some("NEW-SOON"), Some(day(10)), 20, 2, 10),
+                cand(None, Some("NO-EXPIRY"), None, 1, 3, 10),
+            ],
+            HashMap::new(),
+            day(1),
+        );
+        let (allocs, short) = pool.allocate(25);
+        assert_eq!(short, 0);
+        let lots: Vec<_> = allocs.iter().map(|a| a.lot_code.clone().unwrap()).collect();
+        assert_eq!(lots, vec!["NEW-SOON", "OLD-LATE", "NO-EXPIRY"]);
+        assert_eq!(allocs[2].quantity, 5);
+    }
+
+    #[test]
+    fn fifo_applies_when_no_expiry() {
+        let mut pool = StockPool::new(
+            vec![
+                cand(None, None, None, 5, 7, 4),
+                cand(None, None, None, 2, 9, 4),
+            ],
+            HashMap::new(),
+            day(1),
+        );
+        let (allocs, _) = pool.allocate(3);
+        assert_eq!(allocs.len(), 1);
+        assert_eq!(allocs[0].quantity, 3);
+        // Remaining stock of the older layer is consumed before the newer one.
+        let (allocs, _) = pool.allocate(2);
+        assert_eq!(allocs.len(), 1, "same location/lot allocations merge");
+        assert_eq!(allocs[0].quantity, 2);
+    }
+
+    #[test]
+    fn expired_lots_are_not_suggested() {
+        let mut pool = StockPool::new(
+            vec![cand(None, Some("EXPIRED"), Some(day(4)), 1, 1, 10)],
+            HashMap::new(),
+            day(5),
+        );
+        let (allocs, short) = pool.allocate(3);
+        assert!(allocs.is_empty());
+        assert_eq!(short, 3);
+    }
+
+    #[test]
+    fn lot_expiring_on_as_of_date_is_still_pickable() {
+        let mut pool = StockPool::new(
+            vec![cand(None, Some("TODAY"), Some(day(5)), 1, 1, 10)],
+            HashMap::new(),
+            day(5),
+        );
+        assert_eq!(pool.allocate(3).1, 0);
+    }
+
+    #[test]
+    fn location_cap_limits_and_is_shared_across_layers() {
+        let bin = Uuid::new_v4();
+        let mut caps = HashMap::new();
+        caps.insert(bin, 6);
+        let mut pool = StockPool::new(
+            vec![
+                cand(Some(bin), None, None, 1, 1, 5),
+                cand(Some(bin), None, None, 2, 2, 5),
+                cand(None, None, None, 3, 3, 5),
+            ],
+            caps,
+            day(1),
+        );
+        let (allocs, short) = pool.allocate(9);
+        assert_eq!(short, 0);
+        assert_eq!(allocs.len(), 2);
+        assert_eq!(allocs[0].location_id, Some(bin));
+        assert_eq!(allocs[0].quantity, 6);
+        assert_eq!(allocs[1].location_id, None);
+        assert_eq!(allocs[1].quantity, 3);
+    }
+
+    #[test]
+    fn located_layer_without_on_hand_row_is_skipped() {
+        let mut pool = StockPool::new(
+            vec![cand(Some(Uuid::new_v4()), None, None, 1, 1, 5)],
+            HashMap::new(),
+            day(1),
+        );
+        let (allocs, short) = pool.allocate(2);
+        assert!(allocs.is_empty());
+        assert_eq!(short, 2);
+    }
+}
@@ -0,0 +1,458 @@
+//! Pick task generation from released demand.
+//!
+//! One task is created per demand line (sales-order release line or production
+//! component line). Every task is backed by its own warehouse-level reservation
+//! for the requested quantity: either an existing active reservation supplied
+//! by the caller, or one created here in the same transaction.
+//!
+//! Pattern: Guard → Reserve → Insert tasks → Outbox (all in one transaction).
+//!
+//! Event consumers create tasks one line at a time through
+//! [`create_pick_task_for_line`], which is idempotent on the source line.
+
+use chrono::{Duration, Utc};
+use sqlx::{PgPool, Postgres, Transaction};
+use uuid::Uuid;
+
+use super::models::{
+    CreatePickTasksRequest, CreatePickTasksResult, PickError, PickSourceType, PickTask,
+};
+use super::repo::{self, NewPickTask};
+use crate::domain::guards::{guard_item_active, guard_quantity_positive};
+use crate::domain::reservation_repo;
+use crate::domain::reservation_service::EVENT_TYPE_ITEM_RESERVED;
+
+/// Create pick tasks for a demand document.
+///
+/// Returns `(CreatePickTasksResult, is_replay)`.
+/// - `is_replay = false`: tasks created; HTTP 201.
+/// - `is_replay = true`:  idempotency key matched; HTTP 200.
+pub async fn create_pick_tasks(
+    pool: &PgPool,
+    req: &CreatePickTasksRequest,
+) -> Result<(CreatePickTasksResult, bool), PickError> {
+    validate_request(req)?;
+
+    let request_hash = serde_json::to_string(req)?;
+
+    if let Some(record) =
+        reservation_repo::find_idempotency_key(pool, &req.tenant_id, &req.idempotency_key).await?
+    {
+        if record.request_hash != request_hash {
+            return Err(PickError::ConflictingIdempotencyKey);
+        }
+        let result: CreatePickTasksResult = serde_json::from_str(&record.response_body)?;
+        return Ok((result, true));
+    }
+
+    // Guards (read-only): items active, supplied reservations usable.
+    for line in &req.lines {
+        guard_item_active(pool, line.item_id, &req.tenant_id).await?;
+
+        if let Some(reservation_id) = line.reservation_id {
+            let reservation =
+                reservation_repo::find_active_reservation(pool, reservation_id, &req.tenant_id)
+                    .await?
+                    .ok_or(PickError::ReservationNotActive)?;
+            if reservation_repo::is_already_compensated(pool, reservation.id).await? {
+                return Err(PickError::ReservationNotActive);
+            }
+            if reservation.item_id != line.item_id
+                || reservation.warehouse_id != req.warehouse_id
+                || reservation.quantity != line.quantity
+            {
+                return Err(PickError::Validation(format!(
+                    "reservation {} does not match item, warehouse and quantity of its line",
+                    reservation_id
+                )));
+            }
+        }
+    }
+
+    let correlation_id = req
+        .correlation_id
+        .clone()
+        .unwrap_or_else(|| Uuid::new_v4().to_string());
+    let now = Utc::now();
+
+    let mut tx = pool.begin().await?;
+
+    let mut tasks = Vec::with_capacity(req.lines.len());
+    for line in &req.lines {
+        let reservation_id = match line.reservation_id {
+            Some(id) => id,
+            None => {
+                reserve_in_tx(
+                    &mut tx,
+                    &req.tenant_id,
+                    line.item_id,
+                    req.warehouse_id,
+                    line.quantity,
+                    req.source_type.as_str(),
+                    &req.source_id,
+                    &correlation_id,
+                    req.causation_id.as_deref(),
+                )
+                .await?
+            }
+        };
+
+        let task = repo::insert_task(
+            &mut tx,
+            &NewPickTask {
+                tenant_id: &req.tenant_id,
+                warehouse_id: req.warehouse_id,
+                wave_id: None,
+                item_id: line.item_id,
+                source_type: req.source_type.as_str(),
+                source_id: &req.source_id,
+                source_line_id: line.source_line_id.as_deref(),
+                currency: &req.currency,
+                quantity_requested: line.quantity,
+                reservation_id,
+                parent_task_id: None,
+            },
+        )
+        .await
+        .map_err(|e| map_task_unique(e, line.source_line_id.as_deref()))?;
+        tasks.push(task);
+    }
+
+    let result = CreatePickTasksResult { tasks };
+
+    let response_json = serde_json::to_string(&result)?;
+    reservation_repo::store_idempotency_key(
+        &mut tx,
+        &req.tenant_id,
+        &req.idempotency_key,
+        &request_hash,
+        &response_json,
+        201,
+        now + Duration::days(7),
+    )
+    .await?;
+
+    tx.commit().await?;
+
+    Ok((result, false))
+}
+
+const SOURCE_LINE_UNIQUE: &str = "inv_pick_tasks_source_line_unique";
+
+/// One demand line delivered by an event consumer.
+#[derive(Debug, Clone)]
+pub struct DemandLine<'a> {
+    pub tenant_id: &'a str,
+    pub warehouse_id: Uuid,
+    pub source_type: PickSourceType,
+    pub source_id: &'a str,
+    pub source_line_id: &'a str,
+    pub item_id: Uuid,
+    pub quantity: i64,
+    pub currency: &'a str,
+    pub correlation_id: &'a str,
+    pub causation_id: Option<&'a str>,
+}
+
+/// Outcome of [`create_pick_task_for_line`].
+#[derive(Debug)]
+pub enum DemandLineOutcome {
+    Created(PickTask),
+    /// The source line already had its task; nothing was written.
+    AlreadyTasked,
+}
+
+/// Create the pick task for one demand line, reserving its quantity.
+///
+/// Idempotent on `(source_type, source_id, source_line_id)`: a line that
+/// already has its original task yields `AlreadyTasked`, both when found up
+/// front and when a concurrent delivery commits first and the insert trips
+/// `inv_pick_tasks_source_line_unique` (the reservation is rolled back).
+pub async fn create_pick_task_for_line(
+    pool: &PgPool,
+    line: &DemandLine<'_>,
+) -> Result<DemandLineOutcome, PickError> {
+    if line.source_line_id.trim().is_empty() {
+        return Err(PickError::Validation(
+            "source_line_id is required".to_string(),
+        ));
+    }
+    guard_quantity_positive(line.quantity)?;
+
+    let source_type = line.source_type.as_str();
+    if repo::source_line_has_task(
+        pool,
+        line.tenant_id,
+        source_type,
+        line.source_id,
+        line.source_line_id,
+    )
+    .await?
+    {
+        return Ok(DemandLineOutcome::AlreadyTasked);
+    }
+
+    guard_item_active(pool, line.item_id, line.tenant_id).await?;
+
+    let mut tx = pool.begin().await?;
+
+    let reservation_id = reserve_in_tx(
+        &mut tx,
+        line.tenant_id,
+        line.item_id,
+        line.warehouse_id,
+        line.quantity,
+        source_type,
+        line.source_id,
+        line.correlation_id,
+        line.causation_id,
+    )
+    .await?;
+
+    let inserted = repo::insert_task(
+        &mut tx,
+        &NewPickTask {
+            tenant_id: line.tenant_id,
+            warehouse_id: line.warehouse_id,
+            wave_id: None,
+            item_id: line.item_id,
+            source_type,
+            source_id: line.source_id,
+            source_line_id: Some(line.source_line_id),
+            currency: line.currency,
+            quantity_requested: line.quantity,
+            reservation_id,
+            parent_task_id: None,
+        },
+    )
+    .await;
+
+    match inserted.map_err(|e| map_task_unique(e, Some(line.source_line_id))) {
+        Ok(task) => {
+            tx.commit().await?;
+            Ok(DemandLineOutcome::Created(task))
+        }
+        Err(PickError::SourceLineTasked(_)) => {
+            tx.rollback().await?;
+            Ok(DemandLineOutcome::AlreadyTasked)
+        }
+        Err(e) => Err(e),
+    }
+}
+
+/// Reserve `quantity` of an item for a pick task inside the caller's transaction.
+///
+/// Same effect as `reservation_service::process_reserve` (reservation row,
+/// quantity_reserved increment, `inventory.item_reserved` outbox event) without
+/// the idempotency record. Availability is checked against all open layers of
+/// the warehouse, so stock held in locations can be reserved for picking.
+/// Returns the reservation id.
+#[allow(clippy::too_many_arguments)]
+pub(super) async fn reserve_in_tx(
+    tx: &mut Transaction<'_, Postgres>,
+    tenant_id: &str,
+    item_id: Uuid,
+    warehouse_id: Uuid,
+    quantity: i64,
+    reference_type: &str,
+    reference_id: &str,
+    correlation_id: &str,
+    causation_id: Option<&str>,
+) -> Result<Uuid, PickError> {
+    let available = repo::lock_reservable_quantity(tx, tenant_id, item_id, warehouse_id).await?;
+    if available < quantity {
+        return Err(PickError::InsufficientAvailable {
+            item_id,
+            requested: quantity,
+            available,
+        });
+    }
+
+    let reserved_at = Utc::now();
+    let reservation_id = reservation_repo::insert_reservation(
+        tx,
+        tenant_id,
+        item_id,
+        warehouse_id,
+        quantity,
+        Some(reference_type),
+        Some(reference_id),
+        reserved_at,
+        None,
+    )
+    .await?;
+
+    reservation_repo::increment_reserved(tx, tenant_id, item_id, warehouse_id, quantity).await?;
+
+    let payload = serde_json::json!({
+        "reservation_id": reservation_id,
+        "tenant_id":      tenant_id,
+        "item_id":        item_id,
+        "warehouse_id":   warehouse_id,
+        "quantity":       quantity,
+        "reference_type": reference_type,
+        "reference_id":   reference_id,
+        "reserved_at":    reserved_at,
+    });
+
+    reservation_repo::insert_outbox_event(
+        tx,
+        Uuid::new_v4(),
+        EVENT_TYPE_ITEM_RESERVED,
+        &reservation_id.to_string(),
+        tenant_id,
+        &payload.to_string(),
+        correlation_id,
+        causation_id,
+    )
+    .await?;
+
+    Ok(reservation_id)
+}
+
+/// Map the pick task unique violations: one task per reservation
+/// (`ReservationInUse`) and one original task per source line
+/// (`SourceLineTasked`).
+pub(super) fn map_task_unique(e: sqlx::Error, source_line_id: Option<&str>) -> PickError {
+    if let sqlx::Error::Database(ref dbe) = e {
+        match dbe.constraint() {
+            Some("inv_pick_tasks_reservation_unique") => return PickError::ReservationInUse,
+            Some(SOURCE_LINE_UNIQUE) => {
+                return PickError::SourceLineTasked(source_line_id.unwrap_or_default().to_string())
+            }
+            _ => {}
+        }
+    }
+    PickError::Database(e)
+}
+
+// ============================================================================
+// Validation
+// ============================================================================
+
+fn validate_request(req: &CreatePickTasksRequest) -> Result<(), PickError> {
+    if req.idempotency_key.trim().is_empty() {
+        return Err(PickError::Validation(
+            "idempotency_key is required".to_string(),
+        ));
+    }
+    if req.tenant_id.trim().is_empty() {
+        return Err(PickError::Validation("tenant_id is required".to_string()));
+    }
+    if req.source_id.trim().is_empty() {
+        return Err(PickError::Validation("source_id is required".to_string()));
+    }
+    if req.currency.trim().is_empty() {
+        return Err(PickError::Validation("currency is required".to_string()));
+    }
+    if req.lines.is_empty() {
+        return Err(PickError::Validation(
+            "at least one demand line is required".to_string(),
+        ));
+    }
+    let mut seen = std::collections::HashSet::new();
+    let mut source_lines = std::collections::HashSet::new();
+    for line in &req.lines {
+        guard_quantity_positive(line.quantity)?;
+        if let Some(source_line_id) = line.source_line_id.as_deref() {
+            if !source_lines.insert(source_line_id) {
+                return Err(PickError::Validation(format!(
+                    "source line '{}' appears on more than one line",
+                    source_line_id
+                )));
+            }
+        }
+        if let Some(id) = line.reservation_id {
+            if !seen.insert(id) {
+                return Err(PickError::Validation(format!(
+                    "reservation {} is referenced by more than one line",
+                    id
+                )));
+            }
+        }
+    }
+    Ok(())
+}
+
+// ============================================================================
+// Unit tests (stateless validation only)
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::picking::models::PickDemandLine;
+
+    fn valid_req() -> CreatePickTasksRequest {
+        CreatePickTasksRequest {
+            tenant_id: "tenant-1".to_string(),
+            warehouse_id: Uuid::new_v4(),
+            source_type: PickSourceType::SalesOrder,
+            source_id: "SO-001".to_string(),
+            currency: "usd".to_string(),
+            lines: vec![PickDemandLine {
+                item_id: Uuid::new_v4(),
+                quantity: 4,
+                source_line_id: Some("1".to_string()),
+                reservation_id: None,
+            }],
+            idempotency_key: "pick-001".to_string(),
+            correlation_id: None,
+            causation_id: None,
+        }
+    }
+
+    #[test]
+    fn accepts_valid_request() {
+        assert!(validate_request(&valid_req()).is_ok());
+    }
+
+    #[test]
+    fn rejects_empty_lines() {
+        let mut r = valid_req();
+        r.lines.clear();
+        assert!(matches!(
+            validate_request(&r),
+            Err(PickError::Validation(_))
+        ));
+    }
+
+    #[test]
+    fn rejects_non_positive_quantity() {
+        let mut r = valid_req();
+        r.lines[0].quantity = 0;
+        assert!(matches!(validate_request(&r), Err(PickError::Guard(_))));
+    }
+
+    #[test]
+    fn rejects_reservation_shared_by_two_lines() {
+        let mut r = valid_req();
+        let reservation_id = Some(Uuid::new_v4());
+        r.lines[0].reservation_id = reservation_id;
+        r.lines.push(PickDemandLine {
+            item_id: r.lines[0].item_id,
+            quantity: 1,
+            source_line_id: None,
+            reservation_id,
+        });
+        assert!(matches!(
+            validate_request(&r),
+            Err(PickError::Validation(_))
+        ));
+    }
+
+    #[test]
+    fn rejects_source_line_repeated_within_request() {
+        let mut r = valid_req();
+        r.lines.push(PickDemandLine {
+            item_id: Uuid::new_v4(),
+            quantity: 1,
+            source_line_id: Some("1".to_string()),
+            reservation_id: None,
+        });
+        assert!(matches!(
+            validate_request(&r),
+            Err(PickError::Validation(_))
+        ));
+    }
+}
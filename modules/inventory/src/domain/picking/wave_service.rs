@@ -0,0 +1,264 @@
+//! Pick waves and location-directed pick lists.
+//!
+//! A wave groups open pick tasks of one warehouse so they are worked as a
+//! single batch. Waves are released on creation and complete automatically
+//! when their last open task is confirmed.
+//!
+//! Pick lists are computed on read: open tasks of the wave are allocated,
+//! oldest first, against pickable stock (see `suggest`), and the resulting
+//! lines are ordered by location code to give a single walk through the bins.
+
+use std::collections::{hash_map::Entry, HashMap, HashSet};
+
+use chrono::NaiveDate;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use super::models::{
+    CreatePickWaveRequest, PickError, PickList, PickListLine, PickShortage, PickWaveDetail,
+    TASK_STATUS_OPEN,
+};
+use super::repo;
+use super::suggest::StockPool;
+
+const DEFAULT_MAX_TASKS: i64 = 50;
+const MAX_TASKS_LIMIT: i64 = 500;
+
+/// Create and release a wave from explicit or automatically selected tasks.
+pub async fn create_pick_wave(
+    pool: &PgPool,
+    req: &CreatePickWaveRequest,
+) -> Result<PickWaveDetail, PickError> {
+    validate_request(req)?;
+
+    let mut tx = pool.begin().await?;
+
+    let candidates = if req.task_ids.is_empty() {
+        let limit = req.max_tasks.unwrap_or(DEFAULT_MAX_TASKS);
+        repo::lock_unwaved_open_tasks(
+            &mut tx,
+            &req.tenant_id,
+            req.warehouse_id,
+            req.source_type.map(|s| s.as_str()),
+            limit,
+        )
+        .await?
+    } else {
+        let locked = repo::lock_tasks_by_ids(&mut tx, &req.tenant_id, &req.task_ids).await?;
+        if locked.len() != req.task_ids.len() {
+            return Err(PickError::TaskNotFound);
+        }
+        if let Some(t) = locked.iter().find(|t| {
+            t.warehouse_id != req.warehouse_id
+                || t.status != TASK_STATUS_OPEN
+                || t.wave_id.is_some()
+        }) {
+            return Err(PickError::Validation(format!(
+                "task {} is not an open, unwaved task of this warehouse",
+                t.id
+            )));
+        }
+        locked
+    };
+
+    if candidates.is_empty() {
+        return Err(PickError::NoOpenTasks);
+    }
+
+    let seq = repo::next_wave_seq(&mut tx, &req.tenant_id).await?;
+    let wave = repo::insert_wave(
+        &mut tx,
+        &req.tenant_id,
+        req.warehouse_id,
+        &format!("WAVE-{:06}", seq),
+        req.created_by.as_deref(),
+    )
+    .await?;
+
+    let task_ids: Vec<Uuid> = candidates.iter().map(|t| t.id).collect();
+    let mut tasks = repo::assign_tasks_to_wave(&mut tx, wave.id, &task_ids).await?;
+    tasks.sort_by(|a, b| a.created_at.cmp(&b.created_at).then(a.id.cmp(&b.id)));
+
+    tx.commit().await?;
+
+    Ok(PickWaveDetail { wave, tasks })
+}
+
+/// Fetch a wave with all of its tasks.
+pub async fn get_pick_wave(
+    pool: &PgPool,
+    tenant_id: &str,
+    wave_id: Uuid,
+) -> Result<PickWaveDetail, PickError> {
+    let wave = repo::find_wave(pool, tenant_id, wave_id)
+        .await?
+        .ok_or(PickError::WaveNotFound)?;
+    let tasks = repo::tasks_for_wave(pool, wave.id, false).await?;
+    Ok(PickWaveDetail { wave, tasks })
+}
+
+/// Build the location-directed pick list for the open tasks of a wave.
+pub async fn build_pick_list(
+    pool: &PgPool,
+    tenant_id: &str,
+    wave_id: Uuid,
+    as_of: NaiveDate,
+) -> Result<PickList, PickError> {
+    let wave = repo::find_wave(pool, tenant_id, wave_id)
+        .await?
+        .ok_or(PickError::WaveNotFound)?;
+    let tasks = repo::tasks_for_wave(pool, wave.id, true).await?;
+
+    let mut pools: HashMap<Uuid, StockPool> = HashMap::new();
+    let mut lines: Vec<PickListLine> = Vec::new();
+    let mut shortages: Vec<PickShortage> = Vec::new();
+
+    for task in &tasks {
+        let stock = match pools.entry(task.item_id) {
+            Entry::Occupied(e) => e.into_mut(),
+            Entry::Vacant(e) => {
+                let candidates =
+                    repo::stock_candidates(pool, tenant_id, task.item_id, wave.warehouse_id)
+                        .await?;
+                let caps =
+                    repo::location_caps(pool, tenant_id, task.item_id, wave.warehouse_id).await?;
+                e.insert(StockPool::new(candidates, caps, as_of))
+            }
+        };
+
+        let (allocations, short) = stock.allocate(task.quantity_requested);
+        lines.extend(allocations.into_iter().map(|a| PickListLine {
+            task_id: task.id,
+            item_id: task.item_id,
+            location_id: a.location_id,
+            location_code: a.location_code,
+            lot_code: a.lot_code,
+            expires_on: a.expires_on,
+            quantity: a.quantity,
+        }));
+        if short > 0 {
+            shortages.push(PickShortage {
+                task_id: task.id,
+                item_id: task.item_id,
+                quantity: short,
+            });
+        }
+    }
+
+    sort_walk_order(&mut lines);
+
+    Ok(PickList {
+        wave_id: wave.id,
+        warehouse_id: wave.warehouse_id,
+        as_of,
+        lines,
+        shortages,
+    })
+}
+
+/// Order lines by location code (unlocated stock last), then item; the sort is
+/// stable so task order is kept within a bin.
+fn sort_walk_order(lines: &mut [PickListLine]) {
+    lines.sort_by(|a, b| {
+        a.location_code
+            .is_none()
+            .cmp(&b.location_code.is_none())
+            .then(a.location_code.cmp(&b.location_code))
+            .then(a.item_id.cmp(&b.item_id))
+    });
+}
+
+fn validate_request(req: &CreatePickWaveRequest) -> Result<(), PickError> {
+    if req.tenant_id.trim().is_empty() {
+        return Err(PickError::Validation("tenant_id is required".to_string()));
+    }
+    if let Some(max) = req.max_tasks {
+        if !(1..=MAX_TASKS_LIMIT).contains(&max) {
+            return Err(PickError::Validation(format!(
+                "max_tasks must be between 1 and {}",
+                MAX_TASKS_LIMIT
+            )));
+        }
+    }
+    let unique: HashSet<_> = req.task_ids.iter().collect();
+    if unique.len() != req.task_ids.len() {
+        return Err(PickError::Validation("task_ids must be unique".to_string()));
+    }
+    if req.task_ids.len() as i64 > MAX_TASKS_LIMIT {
+        return Err(PickError::Validation(format!(
+            "a wave holds at most {} tasks",
+            MAX_TASKS_LIMIT
+        )));
+    }
+    Ok(())
+}
+
+// ============================================================================
+// Unit tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn line(code: Option<&str>, item: Uuid) -> PickListLine {
+        PickListLine {
+            task_id: Uuid::new_v4(),
+            item_id: item,
+            location_id: code.map(|_| Uuid::new_v4()),
+            location_code: code.map(str::to_string),
+            lot_code: None,
+            expires_on: None,
+            quantity: 1,
+        }
+    }
+
+    #[test]
+    fn walk_order_sorts_by_location_with_unlocated_last() {
+        let item = Uuid::new_v4();
+        let mut lines = vec![
+            line(None, item),
+            line(Some("B-02"), item),
+            line(Some("A-10"), item),
+        ];
+        sort_walk_order(&mut lines);
+        let codes: Vec<_> = lines.iter().map(|l| l.location_code.clone()).collect();
+        assert_eq!(
+            codes,
+            vec![Some("A-10".to_string()), Some("B-02".to_string()), None]
+        );
+    }
+
+    #[test]
+    fn rejects_out_of_range_max_tasks() {
+        let req = CreatePickWaveRequest {
+            tenant_id: "t".to_string(),
+            warehouse_id: Uuid::new_v4(),
+            task_ids: vec![],
+            source_type: None,
+            max_tasks: Some(0),
+            created_by: None,
+        };
+        assert!(matches!(
+            validate_request(&req),
+            Err(PickError::Validation(_))
+        ));
+    }
+
+    #[test]
+    fn rejects_duplicate_task_ids() {
+        let id = Uuid::new_v4();
+        let req = CreatePickWaveRequest {
+            tenant_id: "t".to_string(),
+            warehouse_id: Uuid::new_v4(),
+            task_ids: vec![id, id],
+            source_type: None,
+            max_tasks: None,
+            created_by: None,
+        };
+        assert!(matches!(
+            validate_request(&req),
+            Err(PickError::Validation(_))
+        ));
+    }
+}
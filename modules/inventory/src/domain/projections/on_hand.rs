@@ -333,3 +333,42 @@ pub async fn decrement_available_bucket(
 
     Ok(())
 }
+
+// ============================================================================
+// Revaluation
+// ============================================================================
+
+/// Add a cost delta to one `item_on_hand` row without changing quantities.
+///
+/// Used when a layer is revalued (landed cost). `location_id = None` targets
+/// the null-location row. A missing row is left alone: the next issue on the
+/// null-location path recomputes `total_cost_minor` from the layers.
+pub async fn add_cost(
+    tx: &mut Transaction<'_, Postgres>,
+    tenant_id: &str,
+    item_id: Uuid,
+    warehouse_id: Uuid,
+    location_id: Option<Uuid>,
+    cost_delta: i64,
+) -> Result<(), sqlx::Error> {
+    sqlx::query(
+        r#"
+        UPDATE item_on_hand
+        SET total_cost_minor = total_cost_minor + $5,
+            projected_at     = NOW()
+        WHERE tenant_id    = $1
+          AND item_id      = $2
+          AND warehouse_id = $3
+          AND location_id IS NOT DISTINCT FROM $4
+        "#,
+    )
+    .bind(tenant_id)
+    .bind(item_id)
+    .bind(warehouse_id)
+    .bind(location_id)
+    .bind(cost_delta)
+    .execute(&mut **tx)
+    .await?;
+
+    Ok(())
+}
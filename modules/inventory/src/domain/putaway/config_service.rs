@@ -0,0 +1,183 @@
+//! Putaway configuration: warehouse dock, bin profiles and rules.
+//!
+//! Locations referenced by the configuration must be active and belong to
+//! the configured warehouse; rules must carry the fields their strategy
+//! needs (the table CHECKs are the last line of defence, not the first).
+
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use super::models::{
+    BinProfile, CreatePutawayRuleRequest, PutawayConfig, PutawayError, PutawayRule,
+    PutawayStrategy, UpdatePutawayRuleRequest, UpsertBinProfileRequest, UpsertPutawayConfigRequest,
+};
+use super::repo;
+use crate::domain::guards::guard_item_active;
+
+/// Enable (or update) directed putaway for a warehouse.
+pub async fn upsert_config(
+    pool: &PgPool,
+    tenant_id: &str,
+    warehouse_id: Uuid,
+    req: &UpsertPutawayConfigRequest,
+) -> Result<PutawayConfig, PutawayError> {
+    active_location_in(pool, tenant_id, warehouse_id, req.dock_location_id).await?;
+    Ok(repo::upsert_config(pool, tenant_id, warehouse_id, req).await?)
+}
+
+/// Set the putaway attributes of an active location.
+pub async fn upsert_bin_profile(
+    pool: &PgPool,
+    tenant_id: &str,
+    location_id: Uuid,
+    req: &UpsertBinProfileRequest,
+) -> Result<BinProfile, PutawayError> {
+    if req.capacity_units.is_some_and(|c| c <= 0) {
+        return Err(PutawayError::Validation(
+            "capacity_units must be > 0 when set".to_string(),
+        ));
+    }
+    if req.zone.as_deref().is_some_and(|z| z.trim().is_empty()) {
+        return Err(PutawayError::Validation(
+            "zone must not be blank when set".to_string(),
+        ));
+    }
+    let location = repo::find_location(pool, tenant_id, location_id)
+        .await?
+        .filter(|l| l.is_active)
+        .ok_or_else(|| PutawayError::LocationNotFound(location_id.to_string()))?;
+    Ok(repo::upsert_bin_profile(pool, &location, req).await?)
+}
+
+pub async fn create_rule(
+    pool: &PgPool,
+    tenant_id: &str,
+    req: &CreatePutawayRuleRequest,
+) -> Result<PutawayRule, PutawayError> {
+    validate_rule(req)?;
+    if let Some(item_id) = req.item_id {
+        guard_item_active(pool, item_id, tenant_id).await?;
+    }
+    if let Some(location_id) = req.location_id {
+        active_location_in(pool, tenant_id, req.warehouse_id, location_id).await?;
+    }
+    Ok(repo::insert_rule(pool, tenant_id, req).await?)
+}
+
+pub async fn update_rule(
+    pool: &PgPool,
+    tenant_id: &str,
+    rule_id: Uuid,
+    req: &UpdatePutawayRuleRequest,
+) -> Result<PutawayRule, PutawayError> {
+    repo::update_rule(pool, tenant_id, rule_id, req)
+        .await?
+        .ok_or(PutawayError::RuleNotFound)
+}
+
+async fn active_location_in(
+    pool: &PgPool,
+    tenant_id: &str,
+    warehouse_id: Uuid,
+    location_id: Uuid,
+) -> Result<(), PutawayError> {
+    match repo::find_location(pool, tenant_id, location_id).await? {
+        Some(l) if l.is_active && l.warehouse_id == warehouse_id => Ok(()),
+        _ => Err(PutawayError::LocationNotFound(location_id.to_string())),
+    }
+}
+
+/// Check that a rule carries exactly what its strategy uses.
+fn validate_rule(req: &CreatePutawayRuleRequest) -> Result<(), PutawayError> {
+    let missing = |field: &str| {
+        PutawayError::Validation(format!(
+            "{} is required for strategy {}",
+            field,
+            req.strategy.as_str()
+        ))
+    };
+    let blank = |v: &Option<String>| v.as_deref().is_none_or(|s| s.trim().is_empty());
+
+    match req.strategy {
+        PutawayStrategy::FixedBin => {
+            if req.item_id.is_none() {
+                return Err(missing("item_id"));
+            }
+            if req.location_id.is_none() {
+                return Err(missing("location_id"));
+            }
+        }
+        PutawayStrategy::ZoneByClassification => {
+            if blank(&req.classification_system) {
+                return Err(missing("classification_system"));
+            }
+            if blank(&req.classification_code) {
+                return Err(missing("classification_code"));
+            }
+            if blank(&req.zone) {
+                return Err(missing("zone"));
+            }
+        }
+        PutawayStrategy::NearestEmptyBin | PutawayStrategy::ConsolidateLot => {}
+    }
+    Ok(())
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn req(strategy: PutawayStrategy) -> CreatePutawayRuleRequest {
+        CreatePutawayRuleRequest {
+            warehouse_id: Uuid::new_v4(),
+            strategy,
+            priority: 100,
+            item_id: None,
+            location_id: None,
+            classification_system: None,
+            classification_code: None,
+            zone: None,
+            updated_by: None,
+        }
+    }
+
+    #[test]
+    fn fixed_bin_requires_item_and_location() {
+        let mut r = req(PutawayStrategy::FixedBin);
+        assert!(matches!(
+            validate_rule(&r),
+            Err(PutawayError::Validation(_))
+        ));
+        r.item_id = Some(Uuid::new_v4());
+        assert!(matches!(
+            validate_rule(&r),
+            Err(PutawayError::Validation(_))
+        ));
+        r.location_id = Some(Uuid::new_v4());
+        assert!(validate_rule(&r).is_ok());
+    }
+
+    #[test]
+    fn zone_by_classification_requires_class_and_zone() {
+        let mut r = req(PutawayStrategy::ZoneByClassification);
+        r.classification_system = Some("storage".to_string());
+        r.classification_code = Some("frozen".to_string());
+        r.zone = Some("  ".to_string());
+        assert!(matches!(
+            validate_rule(&r),
+            Err(PutawayError::Validation(_))
+        ));
+        r.zone = Some("cold".to_string());
+        assert!(validate_rule(&r).is_ok());
+    }
+
+    #[test]
+    fn open_strategies_need_no_fields() {
+        assert!(validate_rule(&req(PutawayStrategy::NearestEmptyBin)).is_ok());
+        assert!(validate_rule(&req(PutawayStrategy::ConsolidateLot)).is_ok());
+    }
+}
@@ -0,0 +1,288 @@
+//! Putaway confirmation.
+//!
+//! The operator confirms a putaway task by naming the bin the stock went to
+//! (by id or scanned label); without either, the suggested bin is assumed.
+//! Any active bin of the warehouse other than the dock is accepted, so the
+//! operator can override the suggestion.
+//!
+//! Confirmation moves the task quantity from the dock to the bin with a
+//! transfer_out / transfer_in ledger pair at the layer's unit cost and
+//! updates both location rows of the on-hand projection. Warehouse-level
+//! totals and status buckets do not change.
+//!
+//! Pattern: Guard → Lock → Move → Complete → Outbox (all in one transaction).
+
+use chrono::{Duration, Utc};
+use event_bus::TracingContext;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use super::models::{
+    ConfirmPutawayRequest, ConfirmPutawayResult, PutawayError, PutawayTask, PUTAWAY_STATUS_OPEN,
+};
+use super::repo;
+use crate::domain::guards::GuardError;
+use crate::domain::picking::repo as picking_repo;
+use crate::domain::projections::on_hand;
+use crate::domain::reorder::evaluator;
+use crate::domain::reservation_repo;
+use crate::events::{
+    build_putaway_completed_envelope, PutawayCompletedPayload, EVENT_TYPE_PUTAWAY_COMPLETED,
+};
+
+/// Confirm a putaway task.
+///
+/// Returns `(ConfirmPutawayResult, is_replay)`.
+/// - `is_replay = false`: stock moved to the bin; HTTP 201.
+/// - `is_replay = true`:  idempotency key matched; HTTP 200.
+pub async fn confirm_putaway(
+    pool: &PgPool,
+    req: &ConfirmPutawayRequest,
+    tracing_ctx: Option<&TracingContext>,
+) -> Result<(ConfirmPutawayResult, bool), PutawayError> {
+    if req.idempotency_key.trim().is_empty() {
+        return Err(PutawayError::Guard(GuardError::Validation(
+            "idempotency_key is required".to_string(),
+        )));
+    }
+
+    let request_hash = serde_json::to_string(req)?;
+
+    if let Some(record) =
+        reservation_repo::find_idempotency_key(pool, &req.tenant_id, &req.idempotency_key).await?
+    {
+        if record.request_hash != request_hash {
+            return Err(PutawayError::ConflictingIdempotencyKey);
+        }
+        let result: ConfirmPutawayResult = serde_json::from_str(&record.response_body)?;
+        return Ok((result, true));
+    }
+
+    // --- Guards (read-only) ---
+    let task = repo::find_task(pool, &req.tenant_id, req.task_id)
+        .await?
+        .ok_or(PutawayError::TaskNotFound)?;
+    if task.status != PUTAWAY_STATUS_OPEN {
+        return Err(PutawayError::TaskNotOpen);
+    }
+    let to_location_id = resolve_target(pool, req, &task).await?;
+
+    let correlation_id = req
+        .correlation_id
+        .clone()
+        .unwrap_or_else(|| Uuid::new_v4().to_string());
+    let confirmed_at = Utc::now();
+
+    let mut tx = pool.begin().await?;
+
+    // Step 1: Lock the task and the dock row; the stock must still be on the dock.
+    let task = repo::lock_task(&mut tx, &req.tenant_id, req.task_id)
+        .await?
+        .ok_or(PutawayError::TaskNotFound)?;
+    if task.status != PUTAWAY_STATUS_OPEN {
+        return Err(PutawayError::TaskNotOpen);
+    }
+    let available = repo::lock_location_available(
+        &mut tx,
+        &req.tenant_id,
+        task.item_id,
+        task.warehouse_id,
+        task.from_location_id,
+    )
+    .await?;
+    if available < task.quantity {
+        return Err(PutawayError::InsufficientAtDock {
+            required: task.quantity,
+            available,
+        });
+    }
+    let (unit_cost_minor, currency) = repo::layer_cost(&mut tx, task.layer_id).await?;
+
+    // Step 2: Ledger pair — out of the dock, into the bin.
+    let event_id = Uuid::new_v4();
+    let out_ledger_id = repo::insert_move_ledger(
+        &mut tx,
+        &req.tenant_id,
+        task.item_id,
+        task.warehouse_id,
+        task.from_location_id,
+        "transfer_out",
+        -task.quantity,
+        unit_cost_minor,
+        &currency,
+        Uuid::new_v4(),
+        EVENT_TYPE_PUTAWAY_COMPLETED,
+        task.id,
+        confirmed_at,
+    )
+    .await?;
+    let in_ledger_id = repo::insert_move_ledger(
+        &mut tx,
+        &req.tenant_id,
+        task.item_id,
+        task.warehouse_id,
+        to_location_id,
+        "transfer_in",
+        task.quantity,
+        unit_cost_minor,
+        &currency,
+        event_id,
+        EVENT_TYPE_PUTAWAY_COMPLETED,
+        task.id,
+        confirmed_at,
+    )
+    .await?;
+
+    // Step 3: On-hand projection — location rows only.
+    on_hand::decrement_for_issue(
+        &mut tx,
+        &req.tenant_id,
+        task.item_id,
+        task.warehouse_id,
+        task.from_location_id,
+        task.quantity,
+        task.quantity * unit_cost_minor,
+        out_ledger_id,
+    )
+    .await?;
+    on_hand::upsert_after_receipt(
+        &mut tx,
+        &req.tenant_id,
+        task.item_id,
+        task.warehouse_id,
+        Some(to_location_id),
+        task.quantity,
+        unit_cost_minor,
+        &currency,
+        in_ledger_id,
+    )
+    .await?;
+
+    // Step 4: Complete the task.
+    let task = repo::complete_task(
+        &mut tx,
+        task.id,
+        to_location_id,
+        out_ledger_id,
+        in_ledger_id,
+        req.confirmed_by.as_deref(),
+        confirmed_at,
+    )
+    .await?;
+
+    // Step 5: Outbox event.
+    let payload = PutawayCompletedPayload {
+        task_id: task.id,
+        tenant_id: req.tenant_id.clone(),
+        warehouse_id: task.warehouse_id,
+        item_id: task.item_id,
+        lot_id: task.lot_id,
+        receipt_ledger_entry_id: task.receipt_ledger_entry_id,
+        quantity: task.quantity,
+        from_location_id: task.from_location_id,
+        suggested_location_id: task.suggested_location_id,
+        to_location_id,
+        strategy: task.strategy.clone(),
+        transfer_out_ledger_id: out_ledger_id,
+        transfer_in_ledger_id: in_ledger_id,
+        confirmed_by: req.confirmed_by.clone(),
+        confirmed_at,
+    };
+    let default_ctx = TracingContext::default();
+    let ctx = tracing_ctx.unwrap_or(&default_ctx);
+    let envelope = build_putaway_completed_envelope(
+        event_id,
+        req.tenant_id.clone(),
+        correlation_id.clone(),
+        req.causation_id.clone(),
+        payload,
+    )
+    .with_tracing_context(ctx);
+    let envelope_json = serde_json::to_string(&envelope)?;
+    repo::insert_outbox_event(
+        &mut tx,
+        event_id,
+        EVENT_TYPE_PUTAWAY_COMPLETED,
+        &task.id.to_string(),
+        &req.tenant_id,
+        &envelope_json,
+        &correlation_id,
+        req.causation_id.as_deref(),
+    )
+    .await?;
+
+    let result = ConfirmPutawayResult { task, event_id };
+
+    // Step 6: Store idempotency key (expires in 7 days).
+    let response_json = serde_json::to_string(&result)?;
+    reservation_repo::store_idempotency_key(
+        &mut tx,
+        &req.tenant_id,
+        &req.idempotency_key,
+        &request_hash,
+        &response_json,
+        201,
+        confirmed_at + Duration::days(7),
+    )
+    .await?;
+
+    tx.commit().await?;
+
+    // Best-effort low-stock evaluation for the two locations touched.
+    for location_id in [result.task.from_location_id, to_location_id] {
+        let _ = evaluator::evaluate_low_stock(
+            pool,
+            &req.tenant_id,
+            result.task.item_id,
+            result.task.warehouse_id,
+            Some(location_id),
+            &correlation_id,
+            req.causation_id.clone(),
+        )
+        .await;
+    }
+
+    Ok((result, false))
+}
+
+/// Resolve the bin from the request (id and/or scanned label), falling back
+/// to the task's suggestion.
+async fn resolve_target(
+    pool: &PgPool,
+    req: &ConfirmPutawayRequest,
+    task: &PutawayTask,
+) -> Result<Uuid, PutawayError> {
+    let scanned = match req.location_barcode.as_deref() {
+        Some(code) => Some(
+            picking_repo::find_location_by_code(pool, &req.tenant_id, task.warehouse_id, code)
+                .await?
+                .ok_or_else(|| PutawayError::LocationNotFound(code.to_string()))?,
+        ),
+        None => None,
+    };
+    let target = match (req.to_location_id, scanned) {
+        (Some(id), Some(scanned)) if id != scanned => {
+            return Err(PutawayError::Validation(
+                "location_barcode does not match to_location_id".to_string(),
+            ));
+        }
+        (Some(id), _) => id,
+        (None, Some(scanned)) => scanned,
+        (None, None) => task.suggested_location_id.ok_or_else(|| {
+            PutawayError::Validation(
+                "to_location_id or location_barcode is required: the task has no suggested bin"
+                    .to_string(),
+            )
+        })?,
+    };
+
+    if target == task.from_location_id {
+        return Err(PutawayError::Validation(
+            "target bin must differ from the receiving dock".to_string(),
+        ));
+    }
+    if !picking_repo::location_is_active(pool, &req.tenant_id, task.warehouse_id, target).await? {
+        return Err(PutawayError::LocationNotFound(target.to_string()));
+    }
+    Ok(target)
+}
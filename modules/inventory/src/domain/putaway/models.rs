@@ -0,0 +1,258 @@
+//! Directed putaway configuration, rule and task types.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::domain::guards::GuardError;
+
+// ============================================================================
+// Enums
+// ============================================================================
+
+/// How a putaway rule picks the target bin for received stock.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum PutawayStrategy {
+    /// The item's home bin (`item_id` → `location_id`)
+    FixedBin,
+    /// Nearest bin with room in the zone mapped to one of the item's classifications
+    ZoneByClassification,
+    /// Nearest bin holding nothing, optionally restricted to `zone`
+    NearestEmptyBin,
+    /// Nearest bin already holding the received lot (or the item, when not lot-tracked)
+    ConsolidateLot,
+}
+
+impl PutawayStrategy {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PutawayStrategy::FixedBin => "fixed_bin",
+            PutawayStrategy::ZoneByClassification => "zone_by_classification",
+            PutawayStrategy::NearestEmptyBin => "nearest_empty_bin",
+            PutawayStrategy::ConsolidateLot => "consolidate_lot",
+        }
+    }
+
+    pub fn parse(s: &str) -> Option<Self> {
+        match s {
+            "fixed_bin" => Some(PutawayStrategy::FixedBin),
+            "zone_by_classification" => Some(PutawayStrategy::ZoneByClassification),
+            "nearest_empty_bin" => Some(PutawayStrategy::NearestEmptyBin),
+            "consolidate_lot" => Some(PutawayStrategy::ConsolidateLot),
+            _ => None,
+        }
+    }
+}
+
+pub const PUTAWAY_STATUS_OPEN: &str = "open";
+pub const PUTAWAY_STATUS_COMPLETED: &str = "completed";
+
+// ============================================================================
+// Records
+// ============================================================================
+
+/// Per-warehouse putaway settings. Present and active = directed putaway on.
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow, ToSchema)]
+pub struct PutawayConfig {
+    pub tenant_id: String,
+    pub warehouse_id: Uuid,
+    /// Receiving dock: default location of receipts and source of putaway tasks
+    pub dock_location_id: Uuid,
+    pub is_active: bool,
+    pub updated_by: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// Putaway attributes of a storage location.
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow, ToSchema)]
+pub struct BinProfile {
+    pub location_id: Uuid,
+    pub tenant_id: String,
+    pub warehouse_id: Uuid,
+    pub zone: Option<String>,
+    /// Units the bin holds across all items; None = unlimited
+    pub capacity_units: Option<i64>,
+    /// Walking order from the dock; lower = nearer
+    pub travel_sequence: i32,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow, ToSchema)]
+pub struct PutawayRule {
+    pub id: Uuid,
+    pub tenant_id: String,
+    pub warehouse_id: Uuid,
+    /// fixed_bin | zone_by_classification | nearest_empty_bin | consolidate_lot
+    pub strategy: String,
+    /// Rules are evaluated lowest priority first
+    pub priority: i32,
+    pub item_id: Option<Uuid>,
+    pub location_id: Option<Uuid>,
+    pub classification_system: Option<String>,
+    pub classification_code: Option<String>,
+    pub zone: Option<String>,
+    pub is_active: bool,
+    pub updated_by: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow, ToSchema)]
+pub struct PutawayTask {
+    pub id: Uuid,
+    pub tenant_id: String,
+    pub warehouse_id: Uuid,
+    pub item_id: Uuid,
+    pub lot_id: Option<Uuid>,
+    pub receipt_ledger_entry_id: i64,
+    /// FIFO layer created by the receipt; moved as a whole on confirmation
+    pub layer_id: Uuid,
+    /// Receipt origin: purchase | production | return
+    pub source_type: String,
+    /// Receiving dock the stock sits on until confirmed
+    pub from_location_id: Uuid,
+    /// Bin chosen by the rules; None when no rule matched
+    pub suggested_location_id: Option<Uuid>,
+    pub rule_id: Option<Uuid>,
+    pub strategy: Option<String>,
+    pub quantity: i64,
+    /// open | completed
+    pub status: String,
+    /// Bin the stock was actually put away to
+    pub to_location_id: Option<Uuid>,
+    pub transfer_out_ledger_id: Option<i64>,
+    pub transfer_in_ledger_id: Option<i64>,
+    pub confirmed_by: Option<String>,
+    pub confirmed_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+// ============================================================================
+// Configuration requests
+// ============================================================================
+
+/// Input for PUT /api/inventory/warehouses/{warehouse_id}/putaway-config
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct UpsertPutawayConfigRequest {
+    pub dock_location_id: Uuid,
+    #[serde(default = "default_true")]
+    pub is_active: bool,
+    pub updated_by: Option<String>,
+}
+
+/// Input for PUT /api/inventory/locations/{location_id}/putaway-profile
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct UpsertBinProfileRequest {
+    pub zone: Option<String>,
+    /// Units the bin holds across all items (must be > 0); absent = unlimited
+    pub capacity_units: Option<i64>,
+    #[serde(default)]
+    pub travel_sequence: i32,
+}
+
+/// Input for POST /api/inventory/putaway-rules
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct CreatePutawayRuleRequest {
+    pub warehouse_id: Uuid,
+    pub strategy: PutawayStrategy,
+    #[serde(default = "default_priority")]
+    pub priority: i32,
+    /// fixed_bin: the item
+    pub item_id: Option<Uuid>,
+    /// fixed_bin: its home bin
+    pub location_id: Option<Uuid>,
+    /// zone_by_classification: classification matched against the item
+    pub classification_system: Option<String>,
+    pub classification_code: Option<String>,
+    /// zone_by_classification: target zone (required);
+    /// nearest_empty_bin: restrict to this zone (optional)
+    pub zone: Option<String>,
+    pub updated_by: Option<String>,
+}
+
+/// Input for PUT /api/inventory/putaway-rules/{id}
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct UpdatePutawayRuleRequest {
+    pub priority: Option<i32>,
+    pub is_active: Option<bool>,
+    pub updated_by: Option<String>,
+}
+
+fn default_true() -> bool {
+    true
+}
+
+fn default_priority() -> i32 {
+    100
+}
+
+// ============================================================================
+// Confirmation
+// ============================================================================
+
+/// Putaway confirmation command (built from the HTTP body + path task id).
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ConfirmPutawayRequest {
+    pub tenant_id: String,
+    pub task_id: Uuid,
+    /// Bin put away to; defaults to the suggested bin
+    pub to_location_id: Option<Uuid>,
+    /// Scanned bin label (location code); must agree with `to_location_id` when both are set
+    pub location_barcode: Option<String>,
+    pub confirmed_by: Option<String>,
+    /// Caller-supplied idempotency key (required; scoped per tenant)
+    pub idempotency_key: String,
+    pub correlation_id: Option<String>,
+    pub causation_id: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct ConfirmPutawayResult {
+    pub task: PutawayTask,
+    /// UUID of the inventory.putaway_completed outbox event
+    pub event_id: Uuid,
+}
+
+// ============================================================================
+// Errors
+// ============================================================================
+
+#[derive(Debug, Error)]
+pub enum PutawayError {
+    #[error("Guard failed: {0}")]
+    Guard(#[from] GuardError),
+
+    #[error("Validation error: {0}")]
+    Validation(String),
+
+    #[error("Putaway rule not found")]
+    RuleNotFound,
+
+    #[error("Putaway task not found")]
+    TaskNotFound,
+
+    #[error("Putaway task is not open")]
+    TaskNotOpen,
+
+    #[error("Location '{0}' not found or inactive in this warehouse")]
+    LocationNotFound(String),
+
+    #[error(
+        "Insufficient stock on the receiving dock: required {required}, available {available}"
+    )]
+    InsufficientAtDock { required: i64, available: i64 },
+
+    #[error("Idempotency key conflict: same key used with a different request body")]
+    ConflictingIdempotencyKey,
+
+    #[error("Serialization error: {0}")]
+    Serialization(#[from] serde_json::Error),
+
+    #[error("Database error: {0}")]
+    Database(#[from] sqlx::Error),
+}
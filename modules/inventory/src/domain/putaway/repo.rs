@@ -0,0 +1,580 @@
+//! Putaway repository — database operations for putaway configuration, rules
+//! and tasks.
+//!
+//! Transactional functions accept `&mut Transaction<'_, Postgres>` so they
+//! participate in the receipt and putaway confirmation atomic units.
+
+use std::collections::HashSet;
+
+use chrono::{DateTime, Utc};
+use sqlx::{PgPool, Postgres, Transaction};
+use uuid::Uuid;
+
+use super::models::{
+    BinProfile, CreatePutawayRuleRequest, PutawayConfig, PutawayRule, PutawayTask,
+    UpdatePutawayRuleRequest, UpsertBinProfileRequest, UpsertPutawayConfigRequest,
+    PUTAWAY_STATUS_COMPLETED, PUTAWAY_STATUS_OPEN,
+};
+use super::suggest::BinCandidate;
+use crate::domain::locations::Location;
+
+const CONFIG_COLUMNS: &str =
+    "tenant_id, warehouse_id, dock_location_id, is_active, updated_by, created_at, updated_at";
+
+const PROFILE_COLUMNS: &str =
+    "location_id, tenant_id, warehouse_id, zone, capacity_units, travel_sequence, updated_at";
+
+const RULE_COLUMNS: &str = "id, tenant_id, warehouse_id, strategy, priority, item_id, \
+     location_id, classification_system, classification_code, zone, is_active, updated_by, \
+     created_at, updated_at";
+
+const TASK_COLUMNS: &str = "id, tenant_id, warehouse_id, item_id, lot_id, \
+     receipt_ledger_entry_id, layer_id, source_type, from_location_id, suggested_location_id, \
+     rule_id, strategy, quantity, status, to_location_id, transfer_out_ledger_id, \
+     transfer_in_ledger_id, confirmed_by, confirmed_at, created_at, updated_at";
+
+/// Insert values for a new putaway task.
+pub(crate) struct NewPutawayTask<'a> {
+    pub tenant_id: &'a str,
+    pub warehouse_id: Uuid,
+    pub item_id: Uuid,
+    pub lot_id: Option<Uuid>,
+    pub receipt_ledger_entry_id: i64,
+    pub layer_id: Uuid,
+    pub source_type: &'a str,
+    pub from_location_id: Uuid,
+    pub suggested_location_id: Option<Uuid>,
+    pub rule_id: Option<Uuid>,
+    pub strategy: Option<&'a str>,
+    pub quantity: i64,
+}
+
+/// List filter for putaway tasks (warehouse is always required).
+pub struct PutawayTaskFilter<'a> {
+    pub warehouse_id: Uuid,
+    pub status: Option<&'a str>,
+    pub item_id: Option<Uuid>,
+}
+
+// ============================================================================
+// Configuration
+// ============================================================================
+
+pub async fn find_config(
+    pool: &PgPool,
+    tenant_id: &str,
+    warehouse_id: Uuid,
+) -> Result<Option<PutawayConfig>, sqlx::Error> {
+    sqlx::query_as::<_, PutawayConfig>(&format!(
+        "SELECT {CONFIG_COLUMNS} FROM inv_putaway_configs
+         WHERE tenant_id = $1 AND warehouse_id = $2"
+    ))
+    .bind(tenant_id)
+    .bind(warehouse_id)
+    .fetch_optional(pool)
+    .await
+}
+
+pub async fn upsert_config(
+    pool: &PgPool,
+    tenant_id: &str,
+    warehouse_id: Uuid,
+    req: &UpsertPutawayConfigRequest,
+) -> Result<PutawayConfig, sqlx::Error> {
+    sqlx::query_as::<_, PutawayConfig>(&format!(
+        "INSERT INTO inv_putaway_configs
+            (tenant_id, warehouse_id, dock_location_id, is_active, updated_by)
+         VALUES ($1, $2, $3, $4, $5)
+         ON CONFLICT (tenant_id, warehouse_id) DO UPDATE
+            SET dock_location_id = EXCLUDED.dock_location_id,
+                is_active        = EXCLUDED.is_active,
+                updated_by       = EXCLUDED.updated_by,
+                updated_at       = NOW()
+         RETURNING {CONFIG_COLUMNS}"
+    ))
+    .bind(tenant_id)
+    .bind(warehouse_id)
+    .bind(req.dock_location_id)
+    .bind(req.is_active)
+    .bind(req.updated_by.as_deref())
+    .fetch_one(pool)
+    .await
+}
+
+pub async fn find_location(
+    pool: &PgPool,
+    tenant_id: &str,
+    location_id: Uuid,
+) -> Result<Option<Location>, sqlx::Error> {
+    sqlx::query_as::<_, Location>("SELECT * FROM locations WHERE id = $1 AND tenant_id = $2")
+        .bind(location_id)
+        .bind(tenant_id)
+        .fetch_optional(pool)
+        .await
+}
+
+pub async fn upsert_bin_profile(
+    pool: &PgPool,
+    location: &Location,
+    req: &UpsertBinProfileRequest,
+) -> Result<BinProfile, sqlx::Error> {
+    sqlx::query_as::<_, BinProfile>(&format!(
+        "INSERT INTO inv_putaway_bin_profiles
+            (location_id, tenant_id, warehouse_id, zone, capacity_units, travel_sequence)
+         VALUES ($1, $2, $3, $4, $5, $6)
+         ON CONFLICT (location_id) DO UPDATE
+            SET zone            = EXCLUDED.zone,
+                capacity_units  = EXCLUDED.capacity_units,
+                travel_sequence = EXCLUDED.travel_sequence,
+                updated_at      = NOW()
+         RETURNING {PROFILE_COLUMNS}"
+    ))
+    .bind(location.id)
+    .bind(&location.tenant_id)
+    .bind(location.warehouse_id)
+    .bind(req.zone.as_deref())
+    .bind(req.capacity_units)
+    .bind(req.travel_sequence)
+    .fetch_one(pool)
+    .await
+}
+
+// ============================================================================
+// Rules
+// ============================================================================
+
+pub async fn insert_rule(
+    pool: &PgPool,
+    tenant_id: &str,
+    req: &CreatePutawayRuleRequest,
+) -> Result<PutawayRule, sqlx::Error> {
+    sqlx::query_as::<_, PutawayRule>(&format!(
+        "INSERT INTO inv_putaway_rules
+            (tenant_id, warehouse_id, strategy, priority, item_id, location_id,
+             classification_system, classification_code, zone, updated_by)
+         VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)
+         RETURNING {RULE_COLUMNS}"
+    ))
+    .bind(tenant_id)
+    .bind(req.warehouse_id)
+    .bind(req.strategy.as_str())
+    .bind(req.priority)
+    .bind(req.item_id)
+    .bind(req.location_id)
+    .bind(req.classification_system.as_deref())
+    .bind(req.classification_code.as_deref())
+    .bind(req.zone.as_deref())
+    .bind(req.updated_by.as_deref())
+    .fetch_one(pool)
+    .await
+}
+
+pub async fn find_rule(
+    pool: &PgPool,
+    tenant_id: &str,
+    rule_id: Uuid,
+) -> Result<Option<PutawayRule>, sqlx::Error> {
+    sqlx::query_as::<_, PutawayRule>(&format!(
+        "SELECT {RULE_COLUMNS} FROM inv_putaway_rules WHERE id = $1 AND tenant_id = $2"
+    ))
+    .bind(rule_id)
+    .bind(tenant_id)
+    .fetch_optional(pool)
+    .await
+}
+
+pub async fn update_rule(
+    pool: &PgPool,
+    tenant_id: &str,
+    rule_id: Uuid,
+    req: &UpdatePutawayRuleRequest,
+) -> Result<Option<PutawayRule>, sqlx::Error> {
+    sqlx::query_as::<_, PutawayRule>(&format!(
+        "UPDATE inv_putaway_rules
+         SET priority   = COALESCE($3, priority),
+             is_active  = COALESCE($4, is_active),
+             updated_by = COALESCE($5, updated_by),
+             updated_at = NOW()
+         WHERE id = $1 AND tenant_id = $2
+         RETURNING {RULE_COLUMNS}"
+    ))
+    .bind(rule_id)
+    .bind(tenant_id)
+    .bind(req.priority)
+    .bind(req.is_active)
+    .bind(req.updated_by.as_deref())
+    .fetch_optional(pool)
+    .await
+}
+
+/// Rules of a warehouse in evaluation order.
+pub async fn list_rules(
+    pool: &PgPool,
+    tenant_id: &str,
+    warehouse_id: Uuid,
+    active_only: bool,
+) -> Result<Vec<PutawayRule>, sqlx::Error> {
+    sqlx::query_as::<_, PutawayRule>(&format!(
+        "SELECT {RULE_COLUMNS} FROM inv_putaway_rules
+         WHERE tenant_id = $1 AND warehouse_id = $2 AND (NOT $3 OR is_active = TRUE)
+         ORDER BY priority, created_at, id"
+    ))
+    .bind(tenant_id)
+    .bind(warehouse_id)
+    .bind(active_only)
+    .fetch_all(pool)
+    .await
+}
+
+// ============================================================================
+// Suggestion inputs (read inside the receipt transaction)
+// ============================================================================
+
+pub(crate) async fn active_rules_tx(
+    tx: &mut Transaction<'_, Postgres>,
+    tenant_id: &str,
+    warehouse_id: Uuid,
+) -> Result<Vec<PutawayRule>, sqlx::Error> {
+    sqlx::query_as::<_, PutawayRule>(&format!(
+        "SELECT {RULE_COLUMNS} FROM inv_putaway_rules
+         WHERE tenant_id = $1 AND warehouse_id = $2 AND is_active = TRUE
+         ORDER BY priority, created_at, id"
+    ))
+    .bind(tenant_id)
+    .bind(warehouse_id)
+    .fetch_all(&mut **tx)
+    .await
+}
+
+/// Active locations of the warehouse except the dock, with their putaway
+/// profile, current occupancy and inbound open putaway quantity.
+pub(crate) async fn bin_candidates(
+    tx: &mut Transaction<'_, Postgres>,
+    tenant_id: &str,
+    warehouse_id: Uuid,
+    item_id: Uuid,
+    dock_location_id: Uuid,
+) -> Result<Vec<BinCandidate>, sqlx::Error> {
+    sqlx::query_as::<_, BinCandidate>(&format!(
+        r#"
+        SELECT loc.id   AS location_id,
+               loc.code AS location_code,
+               p.zone,
+               p.capacity_units,
+               COALESCE(p.travel_sequence, 0) AS travel_sequence,
+               COALESCE((SELECT SUM(oh.quantity_on_hand) FROM item_on_hand oh
+                         WHERE oh.tenant_id = $1 AND oh.location_id = loc.id), 0)::BIGINT
+                   AS on_hand_units,
+               COALESCE((SELECT SUM(oh.quantity_on_hand) FROM item_on_hand oh
+                         WHERE oh.tenant_id = $1 AND oh.location_id = loc.id
+                           AND oh.item_id = $3), 0)::BIGINT
+                   AS item_units,
+               COALESCE((SELECT SUM(t.quantity) FROM inv_putaway_tasks t
+                         WHERE t.suggested_location_id = loc.id
+                           AND t.status = '{PUTAWAY_STATUS_OPEN}'), 0)::BIGINT
+                   AS inbound_units
+        FROM locations loc
+        LEFT JOIN inv_putaway_bin_profiles p ON p.location_id = loc.id
+        WHERE loc.tenant_id = $1 AND loc.warehouse_id = $2
+          AND loc.is_active = TRUE AND loc.id <> $4
+        "#
+    ))
+    .bind(tenant_id)
+    .bind(warehouse_id)
+    .bind(item_id)
+    .bind(dock_location_id)
+    .fetch_all(&mut **tx)
+    .await
+}
+
+pub(crate) async fn item_classifications(
+    tx: &mut Transaction<'_, Postgres>,
+    tenant_id: &str,
+    item_id: Uuid,
+) -> Result<Vec<(String, String)>, sqlx::Error> {
+    sqlx::query_as(
+        r#"
+        SELECT DISTINCT classification_system, classification_code
+        FROM item_classifications
+        WHERE tenant_id = $1 AND item_id = $2
+        "#,
+    )
+    .bind(tenant_id)
+    .bind(item_id)
+    .fetch_all(&mut **tx)
+    .await
+}
+
+/// Locations currently holding open layers of a lot. A layer sits where its
+/// completed putaway moved it, otherwise where it was received.
+pub(crate) async fn lot_locations(
+    tx: &mut Transaction<'_, Postgres>,
+    tenant_id: &str,
+    warehouse_id: Uuid,
+    lot_id: Uuid,
+) -> Result<HashSet<Uuid>, sqlx::Error> {
+    let rows: Vec<Uuid> = sqlx::query_scalar(&format!(
+        r#"
+        SELECT DISTINCT COALESCE(pt.to_location_id, l.location_id)
+        FROM inventory_layers layer
+        JOIN inventory_ledger l ON l.id = layer.ledger_entry_id
+        LEFT JOIN inv_putaway_tasks pt
+               ON pt.layer_id = layer.id AND pt.status = '{PUTAWAY_STATUS_COMPLETED}'
+        WHERE layer.tenant_id = $1 AND layer.warehouse_id = $2 AND layer.lot_id = $3
+          AND layer.quantity_remaining > 0
+          AND COALESCE(pt.to_location_id, l.location_id) IS NOT NULL
+        "#
+    ))
+    .bind(tenant_id)
+    .bind(warehouse_id)
+    .bind(lot_id)
+    .fetch_all(&mut **tx)
+    .await?;
+    Ok(rows.into_iter().collect())
+}
+
+// ============================================================================
+// Tasks
+// ============================================================================
+
+pub(crate) async fn insert_task(
+    tx: &mut Transaction<'_, Postgres>,
+    t: &NewPutawayTask<'_>,
+) -> Result<PutawayTask, sqlx::Error> {
+    sqlx::query_as::<_, PutawayTask>(&format!(
+        "INSERT INTO inv_putaway_tasks
+            (tenant_id, warehouse_id, item_id, lot_id, receipt_ledger_entry_id, layer_id,
+             source_type, from_location_id, suggested_location_id, rule_id, strategy, quantity)
+         VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12)
+         RETURNING {TASK_COLUMNS}"
+    ))
+    .bind(t.tenant_id)
+    .bind(t.warehouse_id)
+    .bind(t.item_id)
+    .bind(t.lot_id)
+    .bind(t.receipt_ledger_entry_id)
+    .bind(t.layer_id)
+    .bind(t.source_type)
+    .bind(t.from_location_id)
+    .bind(t.suggested_location_id)
+    .bind(t.rule_id)
+    .bind(t.strategy)
+    .bind(t.quantity)
+    .fetch_one(&mut **tx)
+    .await
+}
+
+pub async fn find_task(
+    pool: &PgPool,
+    tenant_id: &str,
+    task_id: Uuid,
+) -> Result<Option<PutawayTask>, sqlx::Error> {
+    sqlx::query_as::<_, PutawayTask>(&format!(
+        "SELECT {TASK_COLUMNS} FROM inv_putaway_tasks WHERE id = $1 AND tenant_id = $2"
+    ))
+    .bind(task_id)
+    .bind(tenant_id)
+    .fetch_optional(pool)
+    .await
+}
+
+/// Lock a task row for the confirmation transaction.
+pub(crate) async fn lock_task(
+    tx: &mut Transaction<'_, Postgres>,
+    tenant_id: &str,
+    task_id: Uuid,
+) -> Result<Option<PutawayTask>, sqlx::Error> {
+    sqlx::query_as::<_, PutawayTask>(&format!(
+        "SELECT {TASK_COLUMNS} FROM inv_putaway_tasks
+         WHERE id = $1 AND tenant_id = $2 FOR UPDATE"
+    ))
+    .bind(task_id)
+    .bind(tenant_id)
+    .fetch_optional(&mut **tx)
+    .await
+}
+
+pub async fn list_tasks(
+    pool: &PgPool,
+    tenant_id: &str,
+    filter: &PutawayTaskFilter<'_>,
+    limit: i64,
+    offset: i64,
+) -> Result<(Vec<PutawayTask>, i64), sqlx::Error> {
+    let where_clause = "tenant_id = $1 AND warehouse_id = $2
+          AND ($3::TEXT IS NULL OR status = $3)
+          AND ($4::UUID IS NULL OR item_id = $4)";
+
+    let total: i64 = sqlx::query_scalar(&format!(
+        "SELECT COUNT(*) FROM inv_putaway_tasks WHERE {where_clause}"
+    ))
+    .bind(tenant_id)
+    .bind(filter.warehouse_id)
+    .bind(filter.status)
+    .bind(filter.item_id)
+    .fetch_one(pool)
+    .await?;
+
+    let rows = sqlx::query_as::<_, PutawayTask>(&format!(
+        "SELECT {TASK_COLUMNS} FROM inv_putaway_tasks WHERE {where_clause}
+         ORDER BY created_at, id LIMIT $5 OFFSET $6"
+    ))
+    .bind(tenant_id)
+    .bind(filter.warehouse_id)
+    .bind(filter.status)
+    .bind(filter.item_id)
+    .bind(limit)
+    .bind(offset)
+    .fetch_all(pool)
+    .await?;
+
+    Ok((rows, total))
+}
+
+#[allow(clippy::too_many_arguments)]
+pub(crate) async fn complete_task(
+    tx: &mut Transaction<'_, Postgres>,
+    task_id: Uuid,
+    to_location_id: Uuid,
+    transfer_out_ledger_id: i64,
+    transfer_in_ledger_id: i64,
+    confirmed_by: Option<&str>,
+    confirmed_at: DateTime<Utc>,
+) -> Result<PutawayTask, sqlx::Error> {
+    sqlx::query_as::<_, PutawayTask>(&format!(
+        "UPDATE inv_putaway_tasks
+         SET status = '{PUTAWAY_STATUS_COMPLETED}', to_location_id = $2,
+             transfer_out_ledger_id = $3, transfer_in_ledger_id = $4,
+             confirmed_by = $5, confirmed_at = $6, updated_at = $6
+         WHERE id = $1
+         RETURNING {TASK_COLUMNS}"
+    ))
+    .bind(task_id)
+    .bind(to_location_id)
+    .bind(transfer_out_ledger_id)
+    .bind(transfer_in_ledger_id)
+    .bind(confirmed_by)
+    .bind(confirmed_at)
+    .fetch_one(&mut **tx)
+    .await
+}
+
+// ============================================================================
+// Stock movement
+// ============================================================================
+
+/// Lock the dock's on-hand row for the item and return its available quantity.
+pub(crate) async fn lock_location_available(
+    tx: &mut Transaction<'_, Postgres>,
+    tenant_id: &str,
+    item_id: Uuid,
+    warehouse_id: Uuid,
+    location_id: Uuid,
+) -> Result<i64, sqlx::Error> {
+    let available: Option<i64> = sqlx::query_scalar(
+        r#"
+        SELECT available_status_on_hand
+        FROM item_on_hand
+        WHERE tenant_id = $1 AND item_id = $2 AND warehouse_id = $3 AND location_id = $4
+        FOR UPDATE
+        "#,
+    )
+    .bind(tenant_id)
+    .bind(item_id)
+    .bind(warehouse_id)
+    .bind(location_id)
+    .fetch_optional(&mut **tx)
+    .await?;
+    Ok(available.unwrap_or(0))
+}
+
+/// Unit cost and currency of the layer being put away.
+pub(crate) async fn layer_cost(
+    tx: &mut Transaction<'_, Postgres>,
+    layer_id: Uuid,
+) -> Result<(i64, String), sqlx::Error> {
+    sqlx::query_as("SELECT unit_cost_minor, currency FROM inventory_layers WHERE id = $1")
+        .bind(layer_id)
+        .fetch_one(&mut **tx)
+        .await
+}
+
+/// Insert one leg ('transfer_out' at the dock, 'transfer_in' at the bin) of an
+/// intra-warehouse putaway move. Returns the ledger id.
+#[allow(clippy::too_many_arguments)]
+pub(crate) async fn insert_move_ledger(
+    tx: &mut Transaction<'_, Postgres>,
+    tenant_id: &str,
+    item_id: Uuid,
+    warehouse_id: Uuid,
+    location_id: Uuid,
+    entry_type: &str,
+    quantity: i64,
+    unit_cost_minor: i64,
+    currency: &str,
+    source_event_id: Uuid,
+    event_type: &str,
+    task_id: Uuid,
+    posted_at: DateTime<Utc>,
+) -> Result<i64, sqlx::Error> {
+    sqlx::query_scalar(
+        r#"
+        INSERT INTO inventory_ledger
+            (tenant_id, item_id, warehouse_id, location_id, entry_type, quantity,
+             unit_cost_minor, currency, source_event_id, source_event_type,
+             reference_type, reference_id, posted_at)
+        VALUES
+            ($1, $2, $3, $4, $5::inv_entry_type, $6, $7, $8, $9, $10, 'putaway_task', $11, $12)
+        RETURNING id
+        "#,
+    )
+    .bind(tenant_id)
+    .bind(item_id)
+    .bind(warehouse_id)
+    .bind(location_id)
+    .bind(entry_type)
+    .bind(quantity)
+    .bind(unit_cost_minor)
+    .bind(currency)
+    .bind(source_event_id)
+    .bind(event_type)
+    .bind(task_id.to_string())
+    .bind(posted_at)
+    .fetch_one(&mut **tx)
+    .await
+}
+
+// ============================================================================
+// Outbox
+// ============================================================================
+
+#[allow(clippy::too_many_arguments)]
+pub(crate) async fn insert_outbox_event(
+    tx: &mut Transaction<'_, Postgres>,
+    event_id: Uuid,
+    event_type: &str,
+    aggregate_id: &str,
+    tenant_id: &str,
+    envelope_json: &str,
+    correlation_id: &str,
+    causation_id: Option<&str>,
+) -> Result<(), sqlx::Error> {
+    sqlx::query(
+        r#"
+        INSERT INTO inv_outbox
+            (event_id, event_type, aggregate_type, aggregate_id, tenant_id,
+             payload, correlation_id, causation_id, schema_version)
+        VALUES ($1, $2, 'inventory_putaway_task', $3, $4, $5::JSONB, $6, $7, '1.0.0')
+        "#,
+    )
+    .bind(event_id)
+    .bind(event_type)
+    .bind(aggregate_id)
+    .bind(tenant_id)
+    .bind(envelope_json)
+    .bind(correlation_id)
+    .bind(causation_id)
+    .execute(&mut **tx)
+    .await?;
+    Ok(())
+}
@@ -0,0 +1,306 @@
+//! Putaway bin suggestion (pure, no I/O).
+//!
+//! Active rules of the warehouse are evaluated lowest `priority` first; the
+//! first rule that yields a bin wins. Within a rule, bins are ranked by
+//! `travel_sequence` then location code. A bin only qualifies when its free
+//! capacity covers the whole receipt, where free capacity is
+//!
+//!   capacity − units on hand (all items) − units of open putaway tasks targeting it
+//!
+//! and bins without a capacity are unlimited. The receiving dock is never a
+//! candidate. No match leaves the task without a suggestion; the operator
+//! then chooses the bin on confirmation.
+
+use std::collections::HashSet;
+
+use uuid::Uuid;
+
+use super::models::{PutawayRule, PutawayStrategy};
+
+/// One active location of the warehouse as seen by the suggester.
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct BinCandidate {
+    pub location_id: Uuid,
+    pub location_code: String,
+    pub zone: Option<String>,
+    pub capacity_units: Option<i64>,
+    pub travel_sequence: i32,
+    /// Units on hand at the bin across all items
+    pub on_hand_units: i64,
+    /// Units of the received item on hand at the bin
+    pub item_units: i64,
+    /// Units of open putaway tasks suggested to the bin
+    pub inbound_units: i64,
+}
+
+impl BinCandidate {
+    fn fits(&self, quantity: i64) -> bool {
+        match self.capacity_units {
+            Some(cap) => cap - self.on_hand_units - self.inbound_units >= quantity,
+            None => true,
+        }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.on_hand_units == 0 && self.inbound_units == 0
+    }
+}
+
+/// What is being put away.
+pub struct PutawayContext<'a> {
+    pub item_id: Uuid,
+    pub quantity: i64,
+    /// `(classification_system, classification_code)` pairs assigned to the item
+    pub classifications: &'a [(String, String)],
+    /// Lot-tracked receipts: bins currently holding the received lot.
+    /// None for untracked items, which consolidate on `item_units` instead.
+    pub lot_locations: Option<&'a HashSet<Uuid>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Suggestion {
+    pub location_id: Uuid,
+    pub rule_id: Uuid,
+    pub strategy: PutawayStrategy,
+}
+
+/// Suggest a target bin for a receipt, or None when no rule yields one.
+pub fn suggest_bin(
+    rules: &[PutawayRule],
+    bins: &[BinCandidate],
+    ctx: &PutawayContext<'_>,
+) -> Option<Suggestion> {
+    let mut ordered: Vec<&PutawayRule> = rules.iter().filter(|r| r.is_active).collect();
+    ordered.sort_by(|a, b| {
+        a.priority
+            .cmp(&b.priority)
+            .then(a.created_at.cmp(&b.created_at))
+    });
+
+    let mut ranked: Vec<&BinCandidate> = bins.iter().filter(|b| b.fits(ctx.quantity)).collect();
+    ranked.sort_by(|a, b| {
+        a.travel_sequence
+            .cmp(&b.travel_sequence)
+            .then(a.location_code.cmp(&b.location_code))
+    });
+
+    ordered.into_iter().find_map(|rule| {
+        let strategy = PutawayStrategy::parse(&rule.strategy)?;
+        let bin = match strategy {
+            PutawayStrategy::FixedBin => {
+                if rule.item_id != Some(ctx.item_id) {
+                    return None;
+                }
+                ranked
+                    .iter()
+                    .find(|b| Some(b.location_id) == rule.location_id)
+            }
+            PutawayStrategy::ZoneByClassification => {
+                let matches = ctx.classifications.iter().any(|(system, code)| {
+                    rule.classification_system.as_deref() == Some(system.as_str())
+                        && rule.classification_code.as_deref() == Some(code.as_str())
+                });
+                if !matches {
+                    return None;
+                }
+                ranked
+                    .iter()
+                    .find(|b| b.zone.is_some() && b.zone == rule.zone)
+            }
+            PutawayStrategy::NearestEmptyBin => ranked
+                .iter()
+                .find(|b| b.is_empty() && (rule.zone.is_none() || b.zone == rule.zone)),
+            PutawayStrategy::ConsolidateLot => ranked.iter().find(|b| match ctx.lot_locations {
+                Some(locations) => locations.contains(&b.location_id),
+                None => b.item_units > 0,
+            }),
+        }?;
+        Some(Suggestion {
+            location_id: bin.location_id,
+            rule_id: rule.id,
+            strategy,
+        })
+    })
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+
+    fn bin(
+        code: &str,
+        zone: Option<&str>,
+        cap: Option<i64>,
+        seq: i32,
+        on_hand: i64,
+    ) -> BinCandidate {
+        BinCandidate {
+            location_id: Uuid::new_v4(),
+            location_code: code.to_string(),
+            zone: zone.map(str::to_string),
+            capacity_units: cap,
+            travel_sequence: seq,
+            on_hand_units: on_hand,
+            item_units: 0,
+            inbound_units: 0,
+        }
+    }
+
+    fn rule(strategy: PutawayStrategy, priority: i32) -> PutawayRule {
+        PutawayRule {
+            id: Uuid::new_v4(),
+            tenant_id: "t".to_string(),
+            warehouse_id: Uuid::new_v4(),
+            strategy: strategy.as_str().to_string(),
+            priority,
+            item_id: None,
+            location_id: None,
+            classification_system: None,
+            classification_code: None,
+            zone: None,
+            is_active: true,
+            updated_by: None,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+        }
+    }
+
+    fn ctx(item_id: Uuid, quantity: i64) -> PutawayContext<'static> {
+        PutawayContext {
+            item_id,
+            quantity,
+            classifications: &[],
+            lot_locations: None,
+        }
+    }
+
+    #[test]
+    fn fixed_bin_wins_by_priority() {
+        let item = Uuid::new_v4();
+        let home = bin("A-01", None, None, 9, 5);
+        let empty = bin("A-02", None, None, 1, 0);
+        let mut fixed = rule(PutawayStrategy::FixedBin, 10);
+        fixed.item_id = Some(item);
+        fixed.location_id = Some(home.location_id);
+        let nearest = rule(PutawayStrategy::NearestEmptyBin, 20);
+
+        let s = suggest_bin(
+            &[nearest, fixed.clone()],
+            &[home.clone(), empty],
+            &ctx(item, 3),
+        )
+        .unwrap();
+        assert_eq!(s.location_id, home.location_id);
+        assert_eq!(s.rule_id, fixed.id);
+        assert_eq!(s.strategy, PutawayStrategy::FixedBin);
+    }
+
+    #[test]
+    fn fixed_bin_without_room_falls_through() {
+        let item = Uuid::new_v4();
+        let home = bin("A-01", None, Some(10), 1, 8);
+        let empty = bin("A-02", None, None, 2, 0);
+        let mut fixed = rule(PutawayStrategy::FixedBin, 10);
+        fixed.item_id = Some(item);
+        fixed.location_id = Some(home.location_id);
+        let nearest = rule(PutawayStrategy::NearestEmptyBin, 20);
+
+        let s = suggest_bin(&[fixed, nearest], &[home, empty.clone()], &ctx(item, 3)).unwrap();
+        assert_eq!(s.location_id, empty.location_id);
+        assert_eq!(s.strategy, PutawayStrategy::NearestEmptyBin);
+    }
+
+    #[test]
+    fn zone_by_classification_matches_item_class() {
+        let item = Uuid::new_v4();
+        let cold_far = bin("C-09", Some("cold"), None, 9, 4);
+        let cold_near = bin("C-01", Some("cold"), None, 1, 4);
+        let dry = bin("D-01", Some("dry"), None, 0, 0);
+        let mut r = rule(PutawayStrategy::ZoneByClassification, 10);
+        r.classification_system = Some("storage".to_string());
+        r.classification_code = Some("frozen".to_string());
+        r.zone = Some("cold".to_string());
+
+        let classes = vec![("storage".to_string(), "frozen".to_string())];
+        let c = PutawayContext {
+            classifications: &classes,
+            ..ctx(item, 1)
+        };
+        let s = suggest_bin(
+            &[r.clone()],
+            &[cold_far, dry.clone(), cold_near.clone()],
+            &c,
+        )
+        .unwrap();
+        assert_eq!(s.location_id, cold_near.location_id);
+
+        // Unclassified item: rule does not apply
+        assert!(suggest_bin(&[r], &[dry], &ctx(item, 1)).is_none());
+    }
+
+    #[test]
+    fn nearest_empty_bin_counts_inbound_and_capacity() {
+        let item = Uuid::new_v4();
+        let mut reserved = bin("B-01", None, None, 1, 0);
+        reserved.inbound_units = 4;
+        let small = bin("B-02", None, Some(2), 2, 0);
+        let fits = bin("B-03", None, Some(5), 3, 0);
+
+        let s = suggest_bin(
+            &[rule(PutawayStrategy::NearestEmptyBin, 1)],
+            &[reserved, small, fits.clone()],
+            &ctx(item, 5),
+        )
+        .unwrap();
+        assert_eq!(s.location_id, fits.location_id);
+    }
+
+    #[test]
+    fn consolidate_lot_prefers_bins_holding_the_lot() {
+        let item = Uuid::new_v4();
+        let other = bin("L-01", None, None, 1, 3);
+        let with_lot = bin("L-05", None, None, 5, 3);
+        let locations: HashSet<Uuid> = [with_lot.location_id].into_iter().collect();
+        let c = PutawayContext {
+            lot_locations: Some(&locations),
+            ..ctx(item, 2)
+        };
+        let s = suggest_bin(
+            &[rule(PutawayStrategy::ConsolidateLot, 1)],
+            &[other, with_lot.clone()],
+            &c,
+        )
+        .unwrap();
+        assert_eq!(s.location_id, with_lot.location_id);
+    }
+
+    #[test]
+    fn consolidate_untracked_uses_item_units_and_none_when_no_match() {
+        let item = Uuid::new_v4();
+        let mut holding = bin("U-02", None, None, 2, 6);
+        holding.item_units = 6;
+        let empty = bin("U-01", None, None, 1, 0);
+        let r = rule(PutawayStrategy::ConsolidateLot, 1);
+
+        let s = suggest_bin(
+            std::slice::from_ref(&r),
+            &[empty.clone(), holding.clone()],
+            &ctx(item, 1),
+        );
+        assert_eq!(s.unwrap().location_id, holding.location_id);
+        assert!(suggest_bin(&[r], &[empty], &ctx(item, 1)).is_none());
+    }
+
+    #[test]
+    fn inactive_rules_are_ignored() {
+        let item = Uuid::new_v4();
+        let mut r = rule(PutawayStrategy::NearestEmptyBin, 1);
+        r.is_active = false;
+        assert!(suggest_bin(&[r], &[bin("E-01", None, None, 0, 0)], &ctx(item, 1)).is_none());
+    }
+}
@@ -0,0 +1,83 @@
+//! Putaway task creation.
+//!
+//! Called by `receipt_service::process_receipt` inside the receipt
+//! transaction whenever stock lands on a warehouse's receiving dock, so every
+//! receipt channel (HTTP, batch, production FG, shipping-receiving inbound)
+//! gets a task without extra wiring. The target bin is suggested from the
+//! warehouse's active rules at receipt time; tasks already open count against
+//! their suggested bin's capacity.
+
+use sqlx::{Postgres, Transaction};
+use uuid::Uuid;
+
+use super::models::PutawayTask;
+use super::repo::{self, NewPutawayTask};
+use super::suggest::{suggest_bin, PutawayContext};
+
+/// The receipt a putaway task is created for.
+pub(crate) struct ReceivedStock<'a> {
+    pub tenant_id: &'a str,
+    pub warehouse_id: Uuid,
+    pub item_id: Uuid,
+    pub lot_id: Option<Uuid>,
+    pub ledger_entry_id: i64,
+    pub layer_id: Uuid,
+    pub source_type: &'a str,
+    pub dock_location_id: Uuid,
+    pub quantity: i64,
+}
+
+/// Create the putaway task for a receipt on the dock, with its suggested bin.
+pub(crate) async fn create_task_in_tx(
+    tx: &mut Transaction<'_, Postgres>,
+    stock: &ReceivedStock<'_>,
+) -> Result<PutawayTask, sqlx::Error> {
+    let rules = repo::active_rules_tx(tx, stock.tenant_id, stock.warehouse_id).await?;
+
+    let suggestion = if rules.is_empty() {
+        None
+    } else {
+        let bins = repo::bin_candidates(
+            tx,
+            stock.tenant_id,
+            stock.warehouse_id,
+            stock.item_id,
+            stock.dock_location_id,
+        )
+        .await?;
+        let classifications =
+            repo::item_classifications(tx, stock.tenant_id, stock.item_id).await?;
+        let lot_locations = match stock.lot_id {
+            Some(lot_id) => {
+                Some(repo::lot_locations(tx, stock.tenant_id, stock.warehouse_id, lot_id).await?)
+            }
+            None => None,
+        };
+        let ctx = PutawayContext {
+            item_id: stock.item_id,
+            quantity: stock.quantity,
+            classifications: &classifications,
+            lot_locations: lot_locations.as_ref(),
+        };
+        suggest_bin(&rules, &bins, &ctx)
+    };
+
+    repo::insert_task(
+        tx,
+        &NewPutawayTask {
+            tenant_id: stock.tenant_id,
+            warehouse_id: stock.warehouse_id,
+            item_id: stock.item_id,
+            lot_id: stock.lot_id,
+            receipt_ledger_entry_id: stock.ledger_entry_id,
+            layer_id: stock.layer_id,
+            source_type: stock.source_type,
+            from_location_id: stock.dock_location_id,
+            suggested_location_id: suggestion.as_ref().map(|s| s.location_id),
+            rule_id: suggestion.as_ref().map(|s| s.rule_id),
+            strategy: suggestion.as_ref().map(|s| s.strategy.as_str()),
+            quantity: stock.quantity,
+        },
+    )
+    .await
+}
@@ -4,6 +4,9 @@
 //! - Ledger row + FIFO layer + outbox event created in a single transaction
 //! - Idempotency key prevents double-processing on retry
 //! - Guards run before any mutation
+//! - Warehouses with directed putaway: receipts without a location land on
+//!   the receiving dock, and dock receipts get a putaway task in the same
+//!   transaction
 //!
 //! Pattern: Guard → Mutation → Outbox (all in one transaction)
 
@@ -25,6 +28,7 @@ use crate::{
         items::TrackingMode,
         lots_serials::receipt::{insert_serial_instances, upsert_lot},
         projections::on_hand,
+        putaway::{repo as putaway_repo, task_service as putaway_tasks},
         receipt_repo,
         reorder::evaluator,
     },
@@ -51,7 +55,8 @@ pub struct ReceiptRequest {
     pub item_id: Uuid,
     pub warehouse_id: Uuid,
     /// Optional storage location within the warehouse (bin, shelf, zone).
-    /// When absent, the receipt is location-agnostic — existing behavior.
+    /// When absent, the receipt lands on the receiving dock of warehouses with
+    /// directed putaway, and is location-agnostic otherwise.
     #[serde(default)]
     pub location_id: Option<Uuid>,
     /// Quantity received (must be > 0)
@@ -115,6 +120,9 @@ pub struct ReceiptResult {
     /// Empty for non-serial items.
     #[serde(default)]
     pub serial_instance_ids: Vec<Uuid>,
+    /// Putaway task created for a receipt on the receiving dock.
+    #[serde(default)]
+    pub putaway_task_id: Option<Uuid>,
 }
 
 #[derive(Debug, Error)]
@@ -195,6 +203,13 @@ pub async fn process_receipt(
     // --- Tracking requirements guard (requires item.tracking_mode from DB) ---
     validate_tracking_requirements(item.tracking_mode, req)?;
 
+    // --- Directed putaway: default the location to the receiving dock ---
+    let dock_location_id = putaway_repo::find_config(pool, &req.tenant_id, req.warehouse_id)
+        .await?
+        .filter(|c| c.is_active)
+        .map(|c| c.dock_location_id);
+    let location_id = req.location_id.or(dock_location_id);
+
     // --- Atomic transaction: ledger + lot/serial + FIFO layer + outbox + idempotency key ---
     let event_id = Uuid::new_v4();
     let received_at = Utc::now();
@@ -223,7 +238,7 @@ pub async fn process_receipt(
         &req.tenant_id,
         req.item_id,
         req.warehouse_id,
-        req.location_id,
+        location_id,
         quantity,
         req.unit_cost_minor,
         &req.currency,
@@ -310,7 +325,7 @@ pub async fn process_receipt(
         &req.tenant_id,
         req.item_id,
         req.warehouse_id,
-        req.location_id,
+        location_id,
         quantity,
         req.unit_cost_minor,
         &req.currency,
@@ -328,6 +343,29 @@ pub async fn process_receipt(
     )
     .await?;
 
+    // Step 4c: Putaway task for stock landing on the receiving dock
+    let putaway_task_id = match dock_location_id {
+        Some(dock) if location_id == Some(dock) => {
+            let task = putaway_tasks::create_task_in_tx(
+                &mut tx,
+                &putaway_tasks::ReceivedStock {
+                    tenant_id: &req.tenant_id,
+                    warehouse_id: req.warehouse_id,
+                    item_id: req.item_id,
+                    lot_id,
+                    ledger_entry_id,
+                    layer_id,
+                    source_type: &req.source_type,
+                    dock_location_id: dock,
+                    quantity,
+                },
+            )
+            .await?;
+            Some(task.id)
+        }
+        _ => None,
+    };
+
     // Step 5: Build event envelope and enqueue in outbox
     let payload = ItemReceivedPayload {
         receipt_line_id,
@@ -416,7 +454,7 @@ pub async fn process_receipt(
         tenant_id: req.tenant_id.clone(),
         item_id: req.item_id,
         warehouse_id: req.warehouse_id,
-        location_id: req.location_id,
+        location_id,
         quantity,
         unit_cost_minor: req.unit_cost_minor,
         currency: req.currency.clone(),
@@ -424,6 +462,7 @@ pub async fn process_receipt(
         received_at,
         lot_id,
         serial_instance_ids,
+        putaway_task_id,
     };
 
     // Step 7: Store idempotency key with response (expires in 7 days)
@@ -449,7 +488,7 @@ pub async fn process_receipt(
         &req.tenant_id,
         req.item_id,
         req.warehouse_id,
-        req.location_id,
+        location_id,
         &correlation_id,
         req.causation_id.clone(),
     )
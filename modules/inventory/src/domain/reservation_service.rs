@@ -24,8 +24,8 @@ use crate::domain::guards::{guard_item_active, guard_quantity_positive, GuardErr
 use crate::domain::reservation_repo;
 
 // Internal event type strings — not shared with external consumers yet.
-const EVENT_TYPE_ITEM_RESERVED: &str = "inventory.item_reserved";
-const EVENT_TYPE_RESERVATION_RELEASED: &str = "inventory.reservation_released";
+pub(crate) const EVENT_TYPE_ITEM_RESERVED: &str = "inventory.item_reserved";
+pub(crate) const EVENT_TYPE_RESERVATION_RELEASED: &str = "inventory.reservation_released";
 
 // ============================================================================
 // Types: Reserve
@@ -0,0 +1,168 @@
+//! Landed cost allocation and layer revaluation (pure, no I/O).
+//!
+//! A voucher total is spread over its receipts in proportion to the basis
+//! value of each receipt (quantity, weight, value or volume). Amounts are
+//! floored and the leftover minor units go to the largest fractional
+//! remainders, ties to the earlier receipt, so the shares always sum to
+//! the voucher total.
+//!
+//! Each receipt's share then revalues its layer:
+//!
+//!   on-hand share = allocated × quantity_remaining / quantity_received
+//!   uplift        = ⌊on-hand share / quantity_remaining⌋ per unit
+//!   capitalized   = uplift × quantity_remaining
+//!   variance      = allocated − capitalized
+//!
+//! The variance is the share of units already consumed plus the per-unit
+//! rounding remainder, and is expensed to COGS. Capitalized value is exactly
+//! the increase of the layer's remaining value, so inventory and GL agree.
+
+/// Split `amount_minor` over `bases` (all > 0) by largest remainder.
+pub fn allocate_amount(amount_minor: i64, bases: &[f64]) -> Vec<i64> {
+    let total: f64 = bases.iter().sum();
+    if bases.is_empty() || total <= 0.0 {
+        return vec![0; bases.len()];
+    }
+
+    let exact: Vec<f64> = bases
+        .iter()
+        .map(|b| amount_minor as f64 * b / total)
+        .collect();
+    let mut shares: Vec<i64> = exact.iter().map(|e| e.floor() as i64).collect();
+    let mut leftover = amount_minor - shares.iter().sum::<i64>();
+
+    let mut order: Vec<usize> = (0..bases.len()).collect();
+    order.sort_by(|&a, &b| {
+        let ra = exact[a] - exact[a].floor();
+        let rb = exact[b] - exact[b].floor();
+        rb.total_cmp(&ra).then(a.cmp(&b))
+    });
+    for i in order.into_iter().cycle() {
+        if leftover == 0 {
+            break;
+        }
+        shares[i] += 1;
+        leftover -= 1;
+    }
+    shares
+}
+
+/// Layer quantities and cost at the time of revaluation.
+#[derive(Debug, Clone, Copy)]
+pub struct LayerState {
+    pub quantity_received: i64,
+    pub quantity_remaining: i64,
+    pub unit_cost_minor: i64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Revaluation {
+    pub allocated_minor: i64,
+    pub capitalized_minor: i64,
+    pub variance_minor: i64,
+    pub unit_cost_after_minor: i64,
+}
+
+/// Revalue one layer by its allocated landed cost.
+pub fn revalue_layer(layer: LayerState, allocated_minor: i64) -> Revaluation {
+    if layer.quantity_remaining <= 0 || layer.quantity_received <= 0 {
+        return Revaluation {
+            allocated_minor,
+            capitalized_minor: 0,
+            variance_minor: allocated_minor,
+            unit_cost_after_minor: layer.unit_cost_minor,
+        };
+    }
+
+    let on_hand_share = (allocated_minor as i128 * layer.quantity_remaining as i128
+        / layer.quantity_received as i128) as i64;
+    let uplift = on_hand_share / layer.quantity_remaining;
+    let capitalized_minor = uplift * layer.quantity_remaining;
+
+    Revaluation {
+        allocated_minor,
+        capitalized_minor,
+        variance_minor: allocated_minor - capitalized_minor,
+        unit_cost_after_minor: layer.unit_cost_minor + uplift,
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn allocation_sums_to_total_with_largest_remainder() {
+        // 100 over 1:1:1 → 33.33 each; the first receipt takes the extra unit.
+        assert_eq!(allocate_amount(100, &[1.0, 1.0, 1.0]), vec![34, 33, 33]);
+        // 1000 over 3:7 → exact split.
+        assert_eq!(allocate_amount(1_000, &[3.0, 7.0]), vec![300, 700]);
+        // 10 over 0.5 kg / 1.25 kg / 3 kg
+        let shares = allocate_amount(10, &[0.5, 1.25, 3.0]);
+        assert_eq!(shares.iter().sum::<i64>(), 10);
+        assert_eq!(shares, vec![1, 3, 6]);
+    }
+
+    #[test]
+    fn allocation_without_basis_is_zero() {
+        assert_eq!(allocate_amount(50, &[]), Vec::<i64>::new());
+        assert_eq!(allocate_amount(50, &[0.0, 0.0]), vec![0, 0]);
+    }
+
+    #[test]
+    fn untouched_layer_capitalizes_everything() {
+        let layer = LayerState {
+            quantity_received: 10,
+            quantity_remaining: 10,
+            unit_cost_minor: 400,
+        };
+        let r = revalue_layer(layer, 250);
+        assert_eq!(r.capitalized_minor, 250);
+        assert_eq!(r.variance_minor, 0);
+        assert_eq!(r.unit_cost_after_minor, 425);
+    }
+
+    #[test]
+    fn consumed_share_goes_to_variance() {
+        // 6 of 10 consumed: 40% stays on hand.
+        let layer = LayerState {
+            quantity_received: 10,
+            quantity_remaining: 4,
+            unit_cost_minor: 400,
+        };
+        let r = revalue_layer(layer, 1_000);
+        assert_eq!(r.capitalized_minor, 400);
+        assert_eq!(r.variance_minor, 600);
+        assert_eq!(r.unit_cost_after_minor, 500);
+    }
+
+    #[test]
+    fn per_unit_rounding_remainder_goes_to_variance() {
+        let layer = LayerState {
+            quantity_received: 3,
+            quantity_remaining: 3,
+            unit_cost_minor: 100,
+        };
+        let r = revalue_layer(layer, 100);
+        assert_eq!(r.unit_cost_after_minor, 133);
+        assert_eq!(r.capitalized_minor, 99);
+        assert_eq!(r.variance_minor, 1);
+    }
+
+    #[test]
+    fn exhausted_layer_is_all_variance() {
+        let layer = LayerState {
+            quantity_received: 5,
+            quantity_remaining: 0,
+            unit_cost_minor: 700,
+        };
+        let r = revalue_layer(layer, 90);
+        assert_eq!(r.capitalized_minor, 0);
+        assert_eq!(r.variance_minor, 90);
+        assert_eq!(r.unit_cost_after_minor, 700);
+    }
+}
@@ -0,0 +1,4 @@
+pub mod allocate;
+pub mod models;
+pub mod repo;
+pub mod service;
@@ -0,0 +1,198 @@
+//! Landed cost voucher types.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::domain::guards::GuardError;
+
+// ============================================================================
+// Enums
+// ============================================================================
+
+/// How the voucher total is spread over its receipts.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum AllocationBasis {
+    /// Received quantity of each receipt
+    Quantity,
+    /// Caller-supplied `weight` of each receipt
+    Weight,
+    /// Extended receipt value (quantity × PO unit cost)
+    Value,
+    /// Caller-supplied `volume` of each receipt
+    Volume,
+}
+
+impl AllocationBasis {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AllocationBasis::Quantity => "quantity",
+            AllocationBasis::Weight => "weight",
+            AllocationBasis::Value => "value",
+            AllocationBasis::Volume => "volume",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum LandedCostType {
+    Freight,
+    Duty,
+    Brokerage,
+    Insurance,
+    Other,
+}
+
+impl LandedCostType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            LandedCostType::Freight => "freight",
+            LandedCostType::Duty => "duty",
+            LandedCostType::Brokerage => "brokerage",
+            LandedCostType::Insurance => "insurance",
+            LandedCostType::Other => "other",
+        }
+    }
+}
+
+// ============================================================================
+// Records
+// ============================================================================
+
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow, ToSchema)]
+pub struct LandedCostVoucher {
+    pub id: Uuid,
+    pub tenant_id: String,
+    /// quantity | weight | value | volume
+    pub allocation_basis: String,
+    pub currency: String,
+    /// Sum of the charges
+    pub total_amount_minor: i64,
+    /// Added to the remaining value of the receipt layers
+    pub capitalized_minor: i64,
+    /// Expensed to COGS (consumed units and rounding)
+    pub variance_minor: i64,
+    pub description: Option<String>,
+    pub created_by: Option<String>,
+    /// UUID of the inventory.landed_cost_allocated outbox event
+    pub event_id: Uuid,
+    pub created_at: DateTime<Utc>,
+}
+
+/// An AP bill line carried by a voucher.
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow, ToSchema)]
+pub struct LandedCostCharge {
+    pub id: Uuid,
+    pub voucher_id: Uuid,
+    pub ap_bill_id: Uuid,
+    pub ap_bill_line_id: Uuid,
+    /// freight | duty | brokerage | insurance | other
+    pub cost_type: String,
+    pub amount_minor: i64,
+    /// GL account the AP bill line was posted to; credited on capitalization
+    pub clearing_account_ref: String,
+}
+
+/// A voucher's share on one receipt layer.
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow, ToSchema)]
+pub struct LandedCostAllocation {
+    pub id: Uuid,
+    pub voucher_id: Uuid,
+    pub receipt_line_id: Uuid,
+    pub layer_id: Uuid,
+    pub item_id: Uuid,
+    pub warehouse_id: Uuid,
+    pub basis_value: f64,
+    pub quantity_received: i64,
+    /// Units still in the layer when the voucher was posted
+    pub quantity_remaining: i64,
+    pub allocated_minor: i64,
+    pub capitalized_minor: i64,
+    pub variance_minor: i64,
+    pub unit_cost_before_minor: i64,
+    pub unit_cost_after_minor: i64,
+}
+
+/// Voucher with its charges and allocations.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct LandedCostVoucherDetail {
+    #[serde(flatten)]
+    pub voucher: LandedCostVoucher,
+    pub charges: Vec<LandedCostCharge>,
+    pub allocations: Vec<LandedCostAllocation>,
+}
+
+// ============================================================================
+// Requests
+// ============================================================================
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct LandedCostChargeInput {
+    pub ap_bill_id: Uuid,
+    pub ap_bill_line_id: Uuid,
+    pub cost_type: LandedCostType,
+    /// Bill line amount in minor currency units (must be > 0)
+    pub amount_minor: i64,
+    pub clearing_account_ref: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct LandedCostReceiptInput {
+    /// `receipt_line_id` returned by the receipt
+    pub receipt_line_id: Uuid,
+    /// Required (> 0) when allocating by weight
+    pub weight: Option<f64>,
+    /// Required (> 0) when allocating by volume
+    pub volume: Option<f64>,
+}
+
+/// Input for POST /api/inventory/landed-cost-vouchers
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct CreateLandedCostVoucherRequest {
+    pub tenant_id: String,
+    pub allocation_basis: AllocationBasis,
+    /// Must match the currency of every receipt layer
+    pub currency: String,
+    pub description: Option<String>,
+    pub charges: Vec<LandedCostChargeInput>,
+    pub receipts: Vec<LandedCostReceiptInput>,
+    pub created_by: Option<String>,
+    pub idempotency_key: String,
+    pub correlation_id: Option<String>,
+    pub causation_id: Option<String>,
+}
+
+// ============================================================================
+// Errors
+// ============================================================================
+
+#[derive(Debug, Error)]
+pub enum LandedCostError {
+    #[error("Guard failed: {0}")]
+    Guard(#[from] GuardError),
+
+    #[error("Validation error: {0}")]
+    Validation(String),
+
+    #[error("Receipt {0} not found")]
+    ReceiptNotFound(Uuid),
+
+    #[error("AP bill line {0} is already allocated by another landed cost voucher")]
+    BillLineAlreadyAllocated(Uuid),
+
+    #[error("Landed cost voucher not found")]
+    VoucherNotFound,
+
+    #[error("Idempotency key conflict: same key used with a different request body")]
+    ConflictingIdempotencyKey,
+
+    #[error("Serialization error: {0}")]
+    Serialization(#[from] serde_json::Error),
+
+    #[error("Database error: {0}")]
+    Database(#[from] sqlx::Error),
+}
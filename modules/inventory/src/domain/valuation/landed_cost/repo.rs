@@ -0,0 +1,330 @@
+//! Landed cost voucher repository.
+
+use sqlx::{PgPool, Postgres, Transaction};
+use uuid::Uuid;
+
+use super::models::{
+    LandedCostAllocation, LandedCostCharge, LandedCostChargeInput, LandedCostVoucher,
+};
+
+/// A receipt and its FIFO layer, locked for revaluation.
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub(crate) struct ReceiptLayerRow {
+    pub receipt_line_id: Uuid,
+    pub layer_id: Uuid,
+    pub item_id: Uuid,
+    pub warehouse_id: Uuid,
+    pub quantity_received: i64,
+    pub quantity_remaining: i64,
+    pub unit_cost_minor: i64,
+    pub currency: String,
+    /// PO unit cost as received (the layer cost may already carry landed cost)
+    pub receipt_unit_cost_minor: i64,
+    /// Where the layer's stock sits: its putaway bin, else the receipt location
+    pub location_id: Option<Uuid>,
+}
+
+/// Lock the layers of the given receipts (`inventory_ledger.entry_id` of
+/// 'received' rows), in layer id order.
+pub(crate) async fn lock_receipt_layers(
+    tx: &mut Transaction<'_, Postgres>,
+    tenant_id: &str,
+    receipt_line_ids: &[Uuid],
+) -> Result<Vec<ReceiptLayerRow>, sqlx::Error> {
+    sqlx::query_as::<_, ReceiptLayerRow>(
+        r#"
+        SELECT le.entry_id AS receipt_line_id,
+               l.id AS layer_id,
+               l.item_id,
+               l.warehouse_id,
+               l.quantity_received,
+               l.quantity_remaining,
+               l.unit_cost_minor,
+               l.currency,
+               le.unit_cost_minor AS receipt_unit_cost_minor,
+               COALESCE(pt.to_location_id, le.location_id) AS location_id
+        FROM inventory_ledger le
+        JOIN inventory_layers l ON l.ledger_entry_id = le.id
+        LEFT JOIN inv_putaway_tasks pt
+               ON pt.layer_id = l.id AND pt.status = 'completed'
+        WHERE le.tenant_id = $1
+          AND le.entry_type = 'received'
+          AND le.entry_id = ANY($2)
+        ORDER BY l.id
+        FOR UPDATE OF l
+        "#,
+    )
+    .bind(tenant_id)
+    .bind(receipt_line_ids)
+    .fetch_all(&mut **tx)
+    .await
+}
+
+/// Warehouses of the given receipts (for data-scope checks).
+pub async fn receipt_warehouses(
+    pool: &PgPool,
+    tenant_id: &str,
+    receipt_line_ids: &[Uuid],
+) -> Result<Vec<Uuid>, sqlx::Error> {
+    sqlx::query_scalar(
+        r#"
+        SELECT DISTINCT warehouse_id
+        FROM inventory_ledger
+        WHERE tenant_id = $1 AND entry_type = 'received' AND entry_id = ANY($2)
+        "#,
+    )
+    .bind(tenant_id)
+    .bind(receipt_line_ids)
+    .fetch_all(pool)
+    .await
+}
+
+/// Bill lines of the list that some voucher already carries.
+pub(crate) async fn allocated_bill_lines(
+    tx: &mut Transaction<'_, Postgres>,
+    tenant_id: &str,
+    ap_bill_line_ids: &[Uuid],
+) -> Result<Vec<Uuid>, sqlx::Error> {
+    sqlx::query_scalar(
+        r#"
+        SELECT ap_bill_line_id
+        FROM inv_landed_cost_charges
+        WHERE tenant_id = $1 AND ap_bill_line_id = ANY($2)
+        "#,
+    )
+    .bind(tenant_id)
+    .bind(ap_bill_line_ids)
+    .fetch_all(&mut **tx)
+    .await
+}
+
+pub(crate) async fn insert_voucher(
+    tx: &mut Transaction<'_, Postgres>,
+    v: &LandedCostVoucher,
+) -> Result<LandedCostVoucher, sqlx::Error> {
+    sqlx::query_as::<_, LandedCostVoucher>(
+        r#"
+        INSERT INTO inv_landed_cost_vouchers
+            (id, tenant_id, allocation_basis, currency, total_amount_minor,
+             capitalized_minor, variance_minor, description, created_by, event_id, created_at)
+        VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11)
+        RETURNING *
+        "#,
+    )
+    .bind(v.id)
+    .bind(&v.tenant_id)
+    .bind(&v.allocation_basis)
+    .bind(&v.currency)
+    .bind(v.total_amount_minor)
+    .bind(v.capitalized_minor)
+    .bind(v.variance_minor)
+    .bind(&v.description)
+    .bind(&v.created_by)
+    .bind(v.event_id)
+    .bind(v.created_at)
+    .fetch_one(&mut **tx)
+    .await
+}
+
+pub(crate) async fn insert_charge(
+    tx: &mut Transaction<'_, Postgres>,
+    tenant_id: &str,
+    voucher_id: Uuid,
+    c: &LandedCostChargeInput,
+) -> Result<LandedCostCharge, sqlx::Error> {
+    sqlx::query_as::<_, LandedCostCharge>(
+        r#"
+        INSERT INTO inv_landed_cost_charges
+            (voucher_id, tenant_id, ap_bill_id, ap_bill_line_id, cost_type,
+             amount_minor, clearing_account_ref)
+        VALUES ($1, $2, $3, $4, $5, $6, $7)
+        RETURNING id, voucher_id, ap_bill_id, ap_bill_line_id, cost_type,
+                  amount_minor, clearing_account_ref
+        "#,
+    )
+    .bind(voucher_id)
+    .bind(tenant_id)
+    .bind(c.ap_bill_id)
+    .bind(c.ap_bill_line_id)
+    .bind(c.cost_type.as_str())
+    .bind(c.amount_minor)
+    .bind(c.clearing_account_ref.trim())
+    .fetch_one(&mut **tx)
+    .await
+}
+
+pub(crate) async fn insert_allocation(
+    tx: &mut Transaction<'_, Postgres>,
+    tenant_id: &str,
+    a: &LandedCostAllocation,
+) -> Result<LandedCostAllocation, sqlx::Error> {
+    sqlx::query_as::<_, LandedCostAllocation>(
+        r#"
+        INSERT INTO inv_landed_cost_allocations
+            (id, voucher_id, tenant_id, receipt_line_id, layer_id, item_id, warehouse_id,
+             basis_value, quantity_received, quantity_remaining, allocated_minor,
+             capitalized_minor, variance_minor, unit_cost_before_minor, unit_cost_after_minor)
+        VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15)
+        RETURNING id, voucher_id, receipt_line_id, layer_id, item_id, warehouse_id,
+                  basis_value, quantity_received, quantity_remaining, allocated_minor,
+                  capitalized_minor, variance_minor, unit_cost_before_minor,
+                  unit_cost_after_minor
+        "#,
+    )
+    .bind(a.id)
+    .bind(a.voucher_id)
+    .bind(tenant_id)
+    .bind(a.receipt_line_id)
+    .bind(a.layer_id)
+    .bind(a.item_id)
+    .bind(a.warehouse_id)
+    .bind(a.basis_value)
+    .bind(a.quantity_received)
+    .bind(a.quantity_remaining)
+    .bind(a.allocated_minor)
+    .bind(a.capitalized_minor)
+    .bind(a.variance_minor)
+    .bind(a.unit_cost_before_minor)
+    .bind(a.unit_cost_after_minor)
+    .fetch_one(&mut **tx)
+    .await
+}
+
+/// Set the unit cost of a layer; later consumptions carry the new cost.
+pub(crate) async fn set_layer_unit_cost(
+    tx: &mut Transaction<'_, Postgres>,
+    layer_id: Uuid,
+    unit_cost_minor: i64,
+) -> Result<(), sqlx::Error> {
+    sqlx::query("UPDATE inventory_layers SET unit_cost_minor = $2 WHERE id = $1")
+        .bind(layer_id)
+        .bind(unit_cost_minor)
+        .execute(&mut **tx)
+        .await?;
+    Ok(())
+}
+
+// ============================================================================
+// Reads
+// ============================================================================
+
+pub async fn find_voucher(
+    pool: &PgPool,
+    tenant_id: &str,
+    voucher_id: Uuid,
+) -> Result<Option<LandedCostVoucher>, sqlx::Error> {
+    sqlx::query_as::<_, LandedCostVoucher>(
+        "SELECT * FROM inv_landed_cost_vouchers WHERE tenant_id = $1 AND id = $2",
+    )
+    .bind(tenant_id)
+    .bind(voucher_id)
+    .fetch_optional(pool)
+    .await
+}
+
+pub async fn list_charges(
+    pool: &PgPool,
+    tenant_id: &str,
+    voucher_id: Uuid,
+) -> Result<Vec<LandedCostCharge>, sqlx::Error> {
+    sqlx::query_as::<_, LandedCostCharge>(
+        r#"
+        SELECT id, voucher_id, ap_bill_id, ap_bill_line_id, cost_type,
+               amount_minor, clearing_account_ref
+        FROM inv_landed_cost_charges
+        WHERE tenant_id = $1 AND voucher_id = $2
+        ORDER BY created_at, id
+        "#,
+    )
+    .bind(tenant_id)
+    .bind(voucher_id)
+    .fetch_all(pool)
+    .await
+}
+
+pub async fn list_allocations(
+    pool: &PgPool,
+    tenant_id: &str,
+    voucher_id: Uuid,
+) -> Result<Vec<LandedCostAllocation>, sqlx::Error> {
+    sqlx::query_as::<_, LandedCostAllocation>(
+        r#"
+        SELECT id, voucher_id, receipt_line_id, layer_id, item_id, warehouse_id,
+               basis_value, quantity_received, quantity_remaining, allocated_minor,
+               capitalized_minor, variance_minor, unit_cost_before_minor,
+               unit_cost_after_minor
+        FROM inv_landed_cost_allocations
+        WHERE tenant_id = $1 AND voucher_id = $2
+        ORDER BY layer_id
+        "#,
+    )
+    .bind(tenant_id)
+    .bind(voucher_id)
+    .fetch_all(pool)
+    .await
+}
+
+/// Vouchers newest first, optionally only those carrying lines of one AP bill.
+pub async fn list_vouchers(
+    pool: &PgPool,
+    tenant_id: &str,
+    ap_bill_id: Option<Uuid>,
+    limit: i64,
+    offset: i64,
+) -> Result<(Vec<LandedCostVoucher>, i64), sqlx::Error> {
+    let filter = r#"
+        WHERE v.tenant_id = $1
+          AND ($2::UUID IS NULL OR EXISTS (
+                SELECT 1 FROM inv_landed_cost_charges c
+                WHERE c.voucher_id = v.id AND c.ap_bill_id = $2))
+    "#;
+    let rows = sqlx::query_as::<_, LandedCostVoucher>(&format!(
+        "SELECT v.* FROM inv_landed_cost_vouchers v {} ORDER BY v.created_at DESC, v.id LIMIT $3 OFFSET $4",
+        filter
+    ))
+    .bind(tenant_id)
+    .bind(ap_bill_id)
+    .bind(limit)
+    .bind(offset)
+    .fetch_all(pool)
+    .await?;
+    let total: i64 = sqlx::query_scalar(&format!(
+        "SELECT COUNT(*) FROM inv_landed_cost_vouchers v {}",
+        filter
+    ))
+    .bind(tenant_id)
+    .bind(ap_bill_id)
+    .fetch_one(pool)
+    .await?;
+    Ok((rows, total))
+}
+
+pub(crate) async fn insert_outbox_event(
+    tx: &mut Transaction<'_, Postgres>,
+    event_id: Uuid,
+    event_type: &str,
+    aggregate_id: &str,
+    tenant_id: &str,
+    envelope_json: &str,
+    correlation_id: &str,
+    causation_id: Option<&str>,
+) -> Result<(), sqlx::Error> {
+    sqlx::query(
+        r#"
+        INSERT INTO inv_outbox
+            (event_id, event_type, aggregate_type, aggregate_id, tenant_id,
+             payload, correlation_id, causation_id, schema_version)
+        VALUES ($1, $2, 'inventory_landed_cost_voucher', $3, $4, $5::JSONB, $6, $7, '1.0.0')
+        "#,
+    )
+    .bind(event_id)
+    .bind(event_type)
+    .bind(aggregate_id)
+    .bind(tenant_id)
+    .bind(envelope_json)
+    .bind(correlation_id)
+    .bind(causation_id)
+    .execute(&mut **tx)
+    .await?;
+    Ok(())
+}
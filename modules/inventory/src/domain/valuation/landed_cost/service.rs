@@ -0,0 +1,454 @@
+//! Landed cost voucher posting.
+//!
+//! A voucher links AP bill lines (freight, duty, brokerage, …) to one or
+//! more receipts and capitalizes them onto the receipts' FIFO layers. The
+//! layers are shared by every valuation method, so FIFO, LIFO and WAC runs
+//! all see the raised unit cost; consumptions already posted keep their
+//! cost and the share of consumed units goes to COGS variance instead.
+//! See [`super::allocate`] for the arithmetic.
+//!
+//! Pattern: Guard → Lock layers → Revalue → Outbox (all in one transaction).
+
+use chrono::{Duration, Utc};
+use event_bus::TracingContext;
+use sqlx::PgPool;
+use std::collections::{HashMap, HashSet};
+use uuid::Uuid;
+
+use super::allocate::{allocate_amount, revalue_layer, LayerState};
+use super::models::{
+    AllocationBasis, CreateLandedCostVoucherRequest, LandedCostAllocation, LandedCostError,
+    LandedCostVoucher, LandedCostVoucherDetail,
+};
+use super::repo::{self, ReceiptLayerRow};
+use crate::domain::guards::GuardError;
+use crate::domain::projections::on_hand;
+use crate::domain::reservation_repo;
+use crate::events::{
+    build_landed_cost_allocated_envelope, LandedCostAllocatedPayload, LandedCostAllocationLine,
+    LandedCostChargeLine, EVENT_TYPE_LANDED_COST_ALLOCATED,
+};
+
+/// Post a landed cost voucher.
+///
+/// Returns `(LandedCostVoucherDetail, is_replay)`.
+/// - `is_replay = false`: voucher posted, layers revalued; HTTP 201.
+/// - `is_replay = true`:  idempotency key matched; HTTP 200.
+pub async fn create_voucher(
+    pool: &PgPool,
+    req: &CreateLandedCostVoucherRequest,
+    tracing_ctx: Option<&TracingContext>,
+) -> Result<(LandedCostVoucherDetail, bool), LandedCostError> {
+    validate_request(req)?;
+
+    let request_hash = serde_json::to_string(req)?;
+    if let Some(record) =
+        reservation_repo::find_idempotency_key(pool, &req.tenant_id, &req.idempotency_key).await?
+    {
+        if record.request_hash != request_hash {
+            return Err(LandedCostError::ConflictingIdempotencyKey);
+        }
+        let result: LandedCostVoucherDetail = serde_json::from_str(&record.response_body)?;
+        return Ok((result, true));
+    }
+
+    let voucher_id = Uuid::new_v4();
+    let event_id = Uuid::new_v4();
+    let created_at = Utc::now();
+    let correlation_id = req
+        .correlation_id
+        .clone()
+        .unwrap_or_else(|| Uuid::new_v4().to_string());
+
+    let mut tx = pool.begin().await?;
+
+    // Step 1: Lock the receipt layers.
+    let receipt_ids: Vec<Uuid> = req.receipts.iter().map(|r| r.receipt_line_id).collect();
+    let rows = repo::lock_receipt_layers(&mut tx, &req.tenant_id, &receipt_ids).await?;
+    let by_receipt: HashMap<Uuid, &ReceiptLayerRow> =
+        rows.iter().map(|r| (r.receipt_line_id, r)).collect();
+    let mut layers: Vec<&ReceiptLayerRow> = Vec::with_capacity(req.receipts.len());
+    for id in &receipt_ids {
+        let row = by_receipt
+            .get(id)
+            .copied()
+            .ok_or(LandedCostError::ReceiptNotFound(*id))?;
+        if !row.currency.eq_ignore_ascii_case(&req.currency) {
+            return Err(LandedCostError::Validation(format!(
+                "receipt {} is in {}, voucher currency is {}",
+                id, row.currency, req.currency
+            )));
+        }
+        layers.push(row);
+    }
+
+    // Step 2: A bill line is capitalized once.
+    let bill_line_ids: Vec<Uuid> = req.charges.iter().map(|c| c.ap_bill_line_id).collect();
+    if let Some(&taken) = repo::allocated_bill_lines(&mut tx, &req.tenant_id, &bill_line_ids)
+        .await?
+        .first()
+    {
+        return Err(LandedCostError::BillLineAlreadyAllocated(taken));
+    }
+
+    // Step 3: Allocate the total over the receipts and revalue each layer.
+    let bases: Vec<f64> = req
+        .receipts
+        .iter()
+        .zip(&layers)
+        .map(|(input, layer)| match req.allocation_basis {
+            AllocationBasis::Quantity => layer.quantity_received as f64,
+            AllocationBasis::Value => {
+                (layer.quantity_received * layer.receipt_unit_cost_minor) as f64
+            }
+            AllocationBasis::Weight => input.weight.unwrap_or_default(),
+            AllocationBasis::Volume => input.volume.unwrap_or_default(),
+        })
+        .collect();
+    if bases.iter().any(|b| *b <= 0.0) {
+        return Err(LandedCostError::Validation(format!(
+            "every receipt needs a positive {} to allocate by",
+            req.allocation_basis.as_str()
+        )));
+    }
+    let total_amount_minor: i64 = req.charges.iter().map(|c| c.amount_minor).sum();
+    let shares = allocate_amount(total_amount_minor, &bases);
+
+    let mut allocations: Vec<LandedCostAllocation> = Vec::with_capacity(layers.len());
+    for ((layer, basis_value), allocated) in layers.iter().zip(&bases).zip(shares) {
+        let r = revalue_layer(
+            LayerState {
+                quantity_received: layer.quantity_received,
+                quantity_remaining: layer.quantity_remaining,
+                unit_cost_minor: layer.unit_cost_minor,
+            },
+            allocated,
+        );
+        if r.capitalized_minor > 0 {
+            repo::set_layer_unit_cost(&mut tx, layer.layer_id, r.unit_cost_after_minor).await?;
+            on_hand::add_cost(
+                &mut tx,
+                &req.tenant_id,
+                layer.item_id,
+                layer.warehouse_id,
+                layer.location_id,
+                r.capitalized_minor,
+            )
+            .await?;
+        }
+        allocations.push(LandedCostAllocation {
+            id: Uuid::new_v4(),
+            voucher_id,
+            receipt_line_id: layer.receipt_line_id,
+            layer_id: layer.layer_id,
+            item_id: layer.item_id,
+            warehouse_id: layer.warehouse_id,
+            basis_value: *basis_value,
+            quantity_received: layer.quantity_received,
+            quantity_remaining: layer.quantity_remaining,
+            allocated_minor: r.allocated_minor,
+            capitalized_minor: r.capitalized_minor,
+            variance_minor: r.variance_minor,
+            unit_cost_before_minor: layer.unit_cost_minor,
+            unit_cost_after_minor: r.unit_cost_after_minor,
+        });
+    }
+    let capitalized_minor: i64 = allocations.iter().map(|a| a.capitalized_minor).sum();
+
+    // Step 4: Persist the voucher, its charges and allocations.
+    let voucher = repo::insert_voucher(
+        &mut tx,
+        &LandedCostVoucher {
+            id: voucher_id,
+            tenant_id: req.tenant_id.clone(),
+            allocation_basis: req.allocation_basis.as_str().to_string(),
+            currency: req.currency.to_lowercase(),
+            total_amount_minor,
+            capitalized_minor,
+            variance_minor: total_amount_minor - capitalized_minor,
+            description: req.description.clone(),
+            created_by: req.created_by.clone(),
+            event_id,
+            created_at,
+        },
+    )
+    .await?;
+    let mut charges = Vec::with_capacity(req.charges.len());
+    for input in &req.charges {
+        let charge = repo::insert_charge(&mut tx, &req.tenant_id, voucher_id, input)
+            .await
+            .map_err(|e| match &e {
+                sqlx::Error::Database(db)
+                    if db.constraint() == Some("landed_cost_bill_line_once") =>
+                {
+                    LandedCostError::BillLineAlreadyAllocated(input.ap_bill_line_id)
+                }
+                _ => LandedCostError::Database(e),
+            })?;
+        charges.push(charge);
+    }
+    let mut stored = Vec::with_capacity(allocations.len());
+    for a in &allocations {
+        stored.push(repo::insert_allocation(&mut tx, &req.tenant_id, a).await?);
+    }
+
+    // Step 5: Outbox event (GL capitalization).
+    let payload = LandedCostAllocatedPayload {
+        voucher_id,
+        tenant_id: req.tenant_id.clone(),
+        allocation_basis: voucher.allocation_basis.clone(),
+        currency: voucher.currency.clone(),
+        total_amount_minor,
+        capitalized_minor,
+        variance_minor: voucher.variance_minor,
+        charges: charges
+            .iter()
+            .map(|c| LandedCostChargeLine {
+                ap_bill_id: c.ap_bill_id,
+                ap_bill_line_id: c.ap_bill_line_id,
+                cost_type: c.cost_type.clone(),
+                amount_minor: c.amount_minor,
+                clearing_account_ref: c.clearing_account_ref.clone(),
+            })
+            .collect(),
+        allocations: stored
+            .iter()
+            .map(|a| LandedCostAllocationLine {
+                receipt_line_id: a.receipt_line_id,
+                layer_id: a.layer_id,
+                item_id: a.item_id,
+                warehouse_id: a.warehouse_id,
+                allocated_minor: a.allocated_minor,
+                capitalized_minor: a.capitalized_minor,
+                variance_minor: a.variance_minor,
+                unit_cost_before_minor: a.unit_cost_before_minor,
+                unit_cost_after_minor: a.unit_cost_after_minor,
+            })
+            .collect(),
+        allocated_at: created_at,
+    };
+    let default_ctx = TracingContext::default();
+    let ctx = tracing_ctx.unwrap_or(&default_ctx);
+    let envelope = build_landed_cost_allocated_envelope(
+        event_id,
+        req.tenant_id.clone(),
+        correlation_id.clone(),
+        req.causation_id.clone(),
+        payload,
+    )
+    .with_tracing_context(ctx);
+    let envelope_json = serde_json::to_string(&envelope)?;
+    repo::insert_outbox_event(
+        &mut tx,
+        event_id,
+        EVENT_TYPE_LANDED_COST_ALLOCATED,
+        &voucher_id.to_string(),
+        &req.tenant_id,
+        &envelope_json,
+        &correlation_id,
+        req.causation_id.as_deref(),
+    )
+    .await?;
+
+    let result = LandedCostVoucherDetail {
+        voucher,
+        charges,
+        allocations: stored,
+    };
+
+    // Step 6: Store idempotency key (expires in 7 days).
+    let response_json = serde_json::to_string(&result)?;
+    reservation_repo::store_idempotency_key(
+        &mut tx,
+        &req.tenant_id,
+        &req.idempotency_key,
+        &request_hash,
+        &response_json,
+        201,
+        created_at + Duration::days(7),
+    )
+    .await?;
+
+    tx.commit().await?;
+
+    Ok((result, false))
+}
+
+/// Voucher header with its charges and allocations.
+pub async fn get_voucher(
+    pool: &PgPool,
+    tenant_id: &str,
+    voucher_id: Uuid,
+) -> Result<LandedCostVoucherDetail, LandedCostError> {
+    let voucher = repo::find_voucher(pool, tenant_id, voucher_id)
+        .await?
+        .ok_or(LandedCostError::VoucherNotFound)?;
+    let charges = repo::list_charges(pool, tenant_id, voucher_id).await?;
+    let allocations = repo::list_allocations(pool, tenant_id, voucher_id).await?;
+    Ok(LandedCostVoucherDetail {
+        voucher,
+        charges,
+        allocations,
+    })
+}
+
+fn validate_request(req: &CreateLandedCostVoucherRequest) -> Result<(), LandedCostError> {
+    let invalid = |msg: &str| LandedCostError::Validation(msg.to_string());
+
+    if req.tenant_id.trim().is_empty() {
+        return Err(LandedCostError::Guard(GuardError::Validation(
+            "tenant_id is required".to_string(),
+        )));
+    }
+    if req.idempotency_key.trim().is_empty() {
+        return Err(LandedCostError::Guard(GuardError::Validation(
+            "idempotency_key is required".to_string(),
+        )));
+    }
+    if req.currency.trim().is_empty() {
+        return Err(invalid("currency is required"));
+    }
+    if req.charges.is_empty() {
+        return Err(invalid("at least one charge is required"));
+    }
+    if req.receipts.is_empty() {
+        return Err(invalid("at least one receipt is required"));
+    }
+
+    let mut bill_lines = HashSet::new();
+    for c in &req.charges {
+        if c.amount_minor <= 0 {
+            return Err(invalid("charge amount_minor must be > 0"));
+        }
+        if c.clearing_account_ref.trim().is_empty() {
+            return Err(invalid("charge clearing_account_ref is required"));
+        }
+        if !bill_lines.insert(c.ap_bill_line_id) {
+            return Err(LandedCostError::Validation(format!(
+                "AP bill line {} appears more than once",
+                c.ap_bill_line_id
+            )));
+        }
+    }
+    if req
+        .charges
+        .iter()
+        .try_fold(0_i64, |acc, c| acc.checked_add(c.amount_minor))
+        .is_none()
+    {
+        return Err(invalid("charge total overflows"));
+    }
+
+    let mut receipts = HashSet::new();
+    for r in &req.receipts {
+        if !receipts.insert(r.receipt_line_id) {
+            return Err(LandedCostError::Validation(format!(
+                "receipt {} appears more than once",
+                r.receipt_line_id
+            )));
+        }
+        let basis = match req.allocation_basis {
+            AllocationBasis::Weight => Some(("weight", r.weight)),
+            AllocationBasis::Volume => Some(("volume", r.volume)),
+            AllocationBasis::Quantity | AllocationBasis::Value => None,
+        };
+        if let Some((field, value)) = basis {
+            if !value.is_some_and(|v| v.is_finite() && v > 0.0) {
+                return Err(LandedCostError::Validation(format!(
+                    "{} > 0 is required on every receipt when allocating by {}",
+                    field, field
+                )));
+            }
+        }
+    }
+    Ok(())
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::valuation::landed_cost::models::{
+        LandedCostChargeInput, LandedCostReceiptInput, LandedCostType,
+    };
+
+    fn req(basis: AllocationBasis) -> CreateLandedCostVoucherRequest {
+        CreateLandedCostVoucherRequest {
+            tenant_id: "t1".to_string(),
+            allocation_basis: basis,
+            currency: "usd".to_string(),
+            description: None,
+            charges: vec![LandedCostChargeInput {
+                ap_bill_id: Uuid::new_v4(),
+                ap_bill_line_id: Uuid::new_v4(),
+                cost_type: LandedCostType::Freight,
+                amount_minor: 10_000,
+                clearing_account_ref: "FREIGHT_IN".to_string(),
+            }],
+            receipts: vec![LandedCostReceiptInput {
+                receipt_line_id: Uuid::new_v4(),
+                weight: None,
+                volume: None,
+            }],
+            created_by: None,
+            idempotency_key: "lc-1".to_string(),
+            correlation_id: None,
+            causation_id: None,
+        }
+    }
+
+    #[test]
+    fn quantity_and_value_need_no_receipt_measures() {
+        assert!(validate_request(&req(AllocationBasis::Quantity)).is_ok());
+        assert!(validate_request(&req(AllocationBasis::Value)).is_ok());
+    }
+
+    #[test]
+    fn weight_basis_requires_positive_weight() {
+        let mut r = req(AllocationBasis::Weight);
+        assert!(matches!(
+            validate_request(&r),
+            Err(LandedCostError::Validation(_))
+        ));
+        r.receipts[0].weight = Some(0.0);
+        assert!(validate_request(&r).is_err());
+        r.receipts[0].weight = Some(12.5);
+        assert!(validate_request(&r).is_ok());
+    }
+
+    #[test]
+    fn duplicate_bill_lines_and_receipts_are_rejected() {
+        let mut r = req(AllocationBasis::Quantity);
+        let line = r.charges[0].ap_bill_line_id;
+        r.charges.push(LandedCostChargeInput {
+            ap_bill_id: Uuid::new_v4(),
+            ap_bill_line_id: line,
+            cost_type: LandedCostType::Duty,
+            amount_minor: 5,
+            clearing_account_ref: "DUTY".to_string(),
+        });
+        assert!(validate_request(&r).is_err());
+
+        let mut r = req(AllocationBasis::Quantity);
+        let receipt = r.receipts[0].receipt_line_id;
+        r.receipts.push(LandedCostReceiptInput {
+            receipt_line_id: receipt,
+            weight: None,
+            volume: None,
+        });
+        assert!(validate_request(&r).is_err());
+    }
+
+    #[test]
+    fn non_positive_charges_are_rejected() {
+        let mut r = req(AllocationBasis::Quantity);
+        r.charges[0].amount_minor = 0;
+        assert!(validate_request(&r).is_err());
+        let mut r = req(AllocationBasis::Quantity);
+        r.charges[0].clearing_account_ref = " ".to_string();
+        assert!(validate_request(&r).is_err());
+    }
+}
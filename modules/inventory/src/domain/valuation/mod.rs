@@ -1,3 +1,4 @@
+pub mod landed_cost;
 pub mod methods;
 pub mod models;
 pub mod queries;
@@ -3,6 +3,7 @@
 //! All queries enforce tenant scoping via `tenant_id` in WHERE clauses.
 //! These are read-only; no state is mutated.
 
+use security::ScopeSql;
 use sqlx::PgPool;
 use uuid::Uuid;
 
@@ -23,40 +24,49 @@ pub async fn list_snapshots(
     limit: i64,
     offset: i64,
 ) -> Result<Vec<ValuationSnapshot>, sqlx::Error> {
-    if let Some(wh) = warehouse_id {
-        sqlx::query_as::<_, ValuationSnapshot>(
-            r#"
-            SELECT id, tenant_id, warehouse_id, location_id, as_of,
-                   total_value_minor, currency, created_at
-            FROM inventory_valuation_snapshots
-            WHERE tenant_id = $1 AND warehouse_id = $2
-            ORDER BY as_of DESC, created_at DESC
-            LIMIT $3 OFFSET $4
-            "#,
-        )
-        .bind(tenant_id)
-        .bind(wh)
-        .bind(limit)
-        .bind(offset)
-        .fetch_all(pool)
-        .await
-    } else {
-        sqlx::query_as::<_, ValuationSnapshot>(
-            r#"
-            SELECT id, tenant_id, warehouse_id, location_id, as_of,
-                   total_value_minor, currency, created_at
-            FROM inventory_valuation_snapshots
-            WHERE tenant_id = $1
-            ORDER BY as_of DESC, created_at DESC
-            LIMIT $2 OFFSET $3
-            "#,
-        )
+    list_snapshots_scoped(
+        pool,
+        tenant_id,
+        warehouse_id,
+        limit,
+        offset,
+        &ScopeSql::unrestricted(),
+    )
+    .await
+}
+
+/// [`list_snapshots`] restricted to the caller's data scope. Build `scope`
+/// with `ScopeFilter::to_sql(5)`, mapping `location` to `warehouse_id`.
+pub async fn list_snapshots_scoped(
+    pool: &PgPool,
+    tenant_id: &str,
+    warehouse_id: Option<Uuid>,
+    limit: i64,
+    offset: i64,
+    scope: &ScopeSql,
+) -> Result<Vec<ValuationSnapshot>, sqlx::Error> {
+    let sql = format!(
+        r#"
+        SELECT id, tenant_id, warehouse_id, location_id, as_of,
+               total_value_minor, currency, created_at
+        FROM inventory_valuation_snapshots
+        WHERE tenant_id = $1
+          AND ($2::uuid IS NULL OR warehouse_id = $2)
+          AND {}
+        ORDER BY as_of DESC, created_at DESC
+        LIMIT $3 OFFSET $4
+        "#,
+        scope.clause
+    );
+    let mut query = sqlx::query_as::<_, ValuationSnapshot>(&sql)
         .bind(tenant_id)
+        .bind(warehouse_id)
         .bind(limit)
-        .bind(offset)
-        .fetch_all(pool)
-        .await
+        .bind(offset);
+    for values in &scope.binds {
+        query = query.bind(values);
     }
+    query.fetch_all(pool).await
 }
 
 // ============================================================================
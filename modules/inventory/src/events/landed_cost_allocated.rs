@@ -0,0 +1,128 @@
+//! Event contract: inventory.landed_cost_allocated
+//!
+//! Emitted when a landed-cost voucher is posted: AP bill line charges were
+//! allocated to receipt layers and the layers revalued in the same
+//! transaction. GL capitalizes `capitalized_minor` to inventory, expenses
+//! `variance_minor` to COGS and credits each charge's clearing account.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::events::create_inventory_envelope;
+use event_bus::EventEnvelope;
+
+use super::{INVENTORY_EVENT_SCHEMA_VERSION, MUTATION_CLASS_DATA_MUTATION};
+
+pub const EVENT_TYPE_LANDED_COST_ALLOCATED: &str = "inventory.landed_cost_allocated";
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LandedCostChargeLine {
+    pub ap_bill_id: Uuid,
+    pub ap_bill_line_id: Uuid,
+    pub cost_type: String,
+    pub amount_minor: i64,
+    /// GL account to credit (where the AP bill line was posted)
+    pub clearing_account_ref: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LandedCostAllocationLine {
+    pub receipt_line_id: Uuid,
+    pub layer_id: Uuid,
+    pub item_id: Uuid,
+    pub warehouse_id: Uuid,
+    pub allocated_minor: i64,
+    pub capitalized_minor: i64,
+    pub variance_minor: i64,
+    pub unit_cost_before_minor: i64,
+    pub unit_cost_after_minor: i64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LandedCostAllocatedPayload {
+    pub voucher_id: Uuid,
+    pub tenant_id: String,
+    /// quantity | weight | value | volume
+    pub allocation_basis: String,
+    pub currency: String,
+    pub total_amount_minor: i64,
+    /// Debit to inventory
+    pub capitalized_minor: i64,
+    /// Debit to COGS (consumed units and rounding)
+    pub variance_minor: i64,
+    pub charges: Vec<LandedCostChargeLine>,
+    pub allocations: Vec<LandedCostAllocationLine>,
+    pub allocated_at: DateTime<Utc>,
+}
+
+pub fn build_landed_cost_allocated_envelope(
+    event_id: Uuid,
+    tenant_id: String,
+    correlation_id: String,
+    causation_id: Option<String>,
+    payload: LandedCostAllocatedPayload,
+) -> EventEnvelope<LandedCostAllocatedPayload> {
+    create_inventory_envelope(
+        event_id,
+        tenant_id,
+        EVENT_TYPE_LANDED_COST_ALLOCATED.to_string(),
+        correlation_id,
+        causation_id,
+        MUTATION_CLASS_DATA_MUTATION.to_string(),
+        payload,
+    )
+    .with_schema_version(INVENTORY_EVENT_SCHEMA_VERSION.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn landed_cost_allocated_envelope_has_correct_metadata() {
+        let payload = LandedCostAllocatedPayload {
+            voucher_id: Uuid::new_v4(),
+            tenant_id: "tenant-1".to_string(),
+            allocation_basis: "quantity".to_string(),
+            currency: "usd".to_string(),
+            total_amount_minor: 1_000,
+            capitalized_minor: 400,
+            variance_minor: 600,
+            charges: vec![LandedCostChargeLine {
+                ap_bill_id: Uuid::new_v4(),
+                ap_bill_line_id: Uuid::new_v4(),
+                cost_type: "freight".to_string(),
+                amount_minor: 1_000,
+                clearing_account_ref: "FREIGHT_IN".to_string(),
+            }],
+            allocations: vec![LandedCostAllocationLine {
+                receipt_line_id: Uuid::new_v4(),
+                layer_id: Uuid::new_v4(),
+                item_id: Uuid::new_v4(),
+                warehouse_id: Uuid::new_v4(),
+                allocated_minor: 1_000,
+                capitalized_minor: 400,
+                variance_minor: 600,
+                unit_cost_before_minor: 400,
+                unit_cost_after_minor: 500,
+            }],
+            allocated_at: Utc::now(),
+        };
+        let envelope = build_landed_cost_allocated_envelope(
+            Uuid::new_v4(),
+            "tenant-1".to_string(),
+            "corr-landed-1".to_string(),
+            None,
+            payload,
+        );
+        assert_eq!(envelope.event_type, EVENT_TYPE_LANDED_COST_ALLOCATED);
+        assert_eq!(envelope.source_module, "inventory");
+        assert!(envelope.replay_safe);
+    }
+
+    #[test]
+    fn event_type_has_no_v1_suffix() {
+        assert!(!EVENT_TYPE_LANDED_COST_ALLOCATED.ends_with(".v1"));
+    }
+}
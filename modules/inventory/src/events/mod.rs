@@ -54,10 +54,13 @@ pub mod expiry_alert;
 pub mod expiry_set;
 pub mod item_change_recorded;
 pub mod label_generated;
+pub mod landed_cost_allocated;
 pub mod lot_merged;
 pub mod lot_split;
 pub mod low_stock_triggered;
 pub mod make_buy_changed;
+pub mod pick_confirmed;
+pub mod putaway_completed;
 pub mod revision_activated;
 pub mod revision_created;
 pub mod revision_policy_updated;
@@ -162,6 +165,19 @@ pub use barcode_resolved::{
     build_barcode_resolved_envelope, BarcodeResolvedPayload, EVENT_TYPE_BARCODE_RESOLVED,
 };
 
+pub use pick_confirmed::{
+    build_pick_confirmed_envelope, PickConfirmedPayload, EVENT_TYPE_PICK_CONFIRMED,
+};
+
+pub use putaway_completed::{
+    build_putaway_completed_envelope, PutawayCompletedPayload, EVENT_TYPE_PUTAWAY_COMPLETED,
+};
+
+pub use landed_cost_allocated::{
+    build_landed_cost_allocated_envelope, LandedCostAllocatedPayload, LandedCostAllocationLine,
+    LandedCostChargeLine, EVENT_TYPE_LANDED_COST_ALLOCATED,
+};
+
 // ============================================================================
 // Envelope builder helper
 // ============================================================================
@@ -0,0 +1,109 @@
+//! Event contract: inventory.pick_confirmed
+//!
+//! Emitted when a pick task is confirmed. By then the task's reservation has
+//! been released and one `inventory.item_issued` event has been enqueued per
+//! scanned pick, all in the same transaction. Short picks carry the
+//! resolution so the demand owner (sales orders, production) can follow up
+//! on backordered quantities.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::events::create_inventory_envelope;
+use event_bus::EventEnvelope;
+
+use super::{INVENTORY_EVENT_SCHEMA_VERSION, MUTATION_CLASS_DATA_MUTATION};
+
+pub const EVENT_TYPE_PICK_CONFIRMED: &str = "inventory.pick_confirmed";
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PickConfirmedPayload {
+    pub task_id: Uuid,
+    pub tenant_id: String,
+    pub warehouse_id: Uuid,
+    pub wave_id: Option<Uuid>,
+    pub item_id: Uuid,
+    /// sales_order | production
+    pub source_type: String,
+    pub source_id: String,
+    pub source_line_id: Option<String>,
+    pub quantity_requested: i64,
+    pub quantity_picked: i64,
+    pub quantity_backordered: i64,
+    /// picked | short
+    pub status: String,
+    /// re_reserved | backordered (short picks only)
+    pub short_resolution: Option<String>,
+    /// Follow-up task holding the re-reserved remainder
+    pub follow_up_task_id: Option<Uuid>,
+    /// Reservation released by the confirmation
+    pub reservation_id: Uuid,
+    /// One issue per scanned pick
+    pub issue_line_ids: Vec<Uuid>,
+    pub confirmed_by: Option<String>,
+    pub confirmed_at: DateTime<Utc>,
+}
+
+pub fn build_pick_confirmed_envelope(
+    event_id: Uuid,
+    tenant_id: String,
+    correlation_id: String,
+    causation_id: Option<String>,
+    payload: PickConfirmedPayload,
+) -> EventEnvelope<PickConfirmedPayload> {
+    create_inventory_envelope(
+        event_id,
+        tenant_id,
+        EVENT_TYPE_PICK_CONFIRMED.to_string(),
+        correlation_id,
+        causation_id,
+        MUTATION_CLASS_DATA_MUTATION.to_string(),
+        payload,
+    )
+    .with_schema_version(INVENTORY_EVENT_SCHEMA_VERSION.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pick_confirmed_envelope_has_correct_metadata() {
+        let payload = PickConfirmedPayload {
+            task_id: Uuid::new_v4(),
+            tenant_id: "tenant-1".to_string(),
+            warehouse_id: Uuid::new_v4(),
+            wave_id: None,
+            item_id: Uuid::new_v4(),
+            source_type: "sales_order".to_string(),
+            source_id: "SO-1".to_string(),
+            source_line_id: Some("1".to_string()),
+            quantity_requested: 10,
+            quantity_picked: 7,
+            quantity_backordered: 3,
+            status: "short".to_string(),
+            short_resolution: Some("backordered".to_string()),
+            follow_up_task_id: None,
+            reservation_id: Uuid::new_v4(),
+            issue_line_ids: vec![Uuid::new_v4()],
+            confirmed_by: Some("picker-1".to_string()),
+            confirmed_at: Utc::now(),
+        };
+        let envelope = build_pick_confirmed_envelope(
+            Uuid::new_v4(),
+            "tenant-1".to_string(),
+            "corr-pick-1".to_string(),
+            None,
+            payload,
+        );
+        assert_eq!(envelope.event_type, EVENT_TYPE_PICK_CONFIRMED);
+        assert_eq!(envelope.source_module, "inventory");
+        assert!(envelope.replay_safe);
+    }
+
+    #[test]
+    fn event_type_has_no_v1_suffix() {
+        assert!(!EVENT_TYPE_PICK_CONFIRMED.ends_with(".v1"));
+    }
+}
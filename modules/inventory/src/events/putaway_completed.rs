@@ -0,0 +1,102 @@
+//! Event contract: inventory.putaway_completed
+//!
+//! Emitted when a putaway task is confirmed: the received stock has moved
+//! from the receiving dock to its storage bin through a transfer_out /
+//! transfer_in ledger pair inside the warehouse. Warehouse totals and
+//! valuation are unchanged; only the location of the stock differs.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::events::create_inventory_envelope;
+use event_bus::EventEnvelope;
+
+use super::{INVENTORY_EVENT_SCHEMA_VERSION, MUTATION_CLASS_DATA_MUTATION};
+
+pub const EVENT_TYPE_PUTAWAY_COMPLETED: &str = "inventory.putaway_completed";
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PutawayCompletedPayload {
+    pub task_id: Uuid,
+    pub tenant_id: String,
+    pub warehouse_id: Uuid,
+    pub item_id: Uuid,
+    pub lot_id: Option<Uuid>,
+    /// Receipt ledger entry the task was created for
+    pub receipt_ledger_entry_id: i64,
+    pub quantity: i64,
+    /// Receiving dock
+    pub from_location_id: Uuid,
+    /// Bin suggested by the putaway rules, if any
+    pub suggested_location_id: Option<Uuid>,
+    /// Bin the stock was put away to
+    pub to_location_id: Uuid,
+    /// Rule strategy behind the suggestion
+    pub strategy: Option<String>,
+    pub transfer_out_ledger_id: i64,
+    pub transfer_in_ledger_id: i64,
+    pub confirmed_by: Option<String>,
+    pub confirmed_at: DateTime<Utc>,
+}
+
+pub fn build_putaway_completed_envelope(
+    event_id: Uuid,
+    tenant_id: String,
+    correlation_id: String,
+    causation_id: Option<String>,
+    payload: PutawayCompletedPayload,
+) -> EventEnvelope<PutawayCompletedPayload> {
+    create_inventory_envelope(
+        event_id,
+        tenant_id,
+        EVENT_TYPE_PUTAWAY_COMPLETED.to_string(),
+        correlation_id,
+        causation_id,
+        MUTATION_CLASS_DATA_MUTATION.to_string(),
+        payload,
+    )
+    .with_schema_version(INVENTORY_EVENT_SCHEMA_VERSION.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn putaway_completed_envelope_has_correct_metadata() {
+        let bin = Uuid::new_v4();
+        let payload = PutawayCompletedPayload {
+            task_id: Uuid::new_v4(),
+            tenant_id: "tenant-1".to_string(),
+            warehouse_id: Uuid::new_v4(),
+            item_id: Uuid::new_v4(),
+            lot_id: None,
+            receipt_ledger_entry_id: 42,
+            quantity: 12,
+            from_location_id: Uuid::new_v4(),
+            suggested_location_id: Some(bin),
+            to_location_id: bin,
+            strategy: Some("fixed_bin".to_string()),
+            transfer_out_ledger_id: 43,
+            transfer_in_ledger_id: 44,
+            confirmed_by: Some("operator-1".to_string()),
+            confirmed_at: Utc::now(),
+        };
+        let envelope = build_putaway_completed_envelope(
+            Uuid::new_v4(),
+            "tenant-1".to_string(),
+            "corr-putaway-1".to_string(),
+            None,
+            payload,
+        );
+        assert_eq!(envelope.event_type, EVENT_TYPE_PUTAWAY_COMPLETED);
+        assert_eq!(envelope.source_module, "inventory");
+        assert!(envelope.replay_safe);
+    }
+
+    #[test]
+    fn event_type_has_no_v1_suffix() {
+        assert!(!EVENT_TYPE_PUTAWAY_COMPLETED.ends_with(".v1"));
+    }
+}
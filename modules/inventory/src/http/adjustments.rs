@@ -3,7 +3,8 @@
 //! Endpoint:
 //!   POST /api/inventory/adjustments — create a compensating ledger entry
 //!
-//! Tenant identity derived from JWT `VerifiedClaims`.
+//! Tenant identity derived from JWT `VerifiedClaims`; the warehouse must be
+//! inside the caller's data scope.
 //!
 //! Idempotency:
 //!   Callers MUST supply `idempotency_key` in the request body.
@@ -13,10 +14,10 @@
 use axum::{extract::State, http::StatusCode, response::IntoResponse, Extension, Json};
 use event_bus::TracingContext;
 use platform_http_contracts::ApiError;
-use security::VerifiedClaims;
+use security::{permissions, VerifiedClaims};
 use std::sync::Arc;
 
-use super::tenant::with_request_id;
+use super::tenant::{check_warehouse_scope, with_request_id};
 use crate::{
     domain::adjust_service::{process_adjustment, AdjustRequest, AdjustResult},
     AppState,
@@ -51,6 +52,10 @@ pub async fn post_adjustment(
         Ok(id) => id,
         Err(e) => return with_request_id(e, &tracing_ctx).into_response(),
     };
+    if let Err(e) = check_warehouse_scope(&claims, permissions::INVENTORY_MUTATE, req.warehouse_id)
+    {
+        return with_request_id(e, &tracing_ctx).into_response();
+    }
     req.tenant_id = tenant_id;
     match process_adjustment(&state.pool, &req, tracing_ctx_val.as_ref()).await {
         Ok((result, is_replay)) => {
@@ -0,0 +1,317 @@
+//! Cycle-count program HTTP handlers.
+//!
+//! Endpoints:
+//!   PUT  /api/inventory/cycle-count-program           — configure ABC cutoffs and count frequencies
+//!   GET  /api/inventory/cycle-count-program           — current program
+//!   POST /api/inventory/cycle-count-program/generate  — generate a warehouse's due count tasks now
+//!   GET  /api/inventory/cycle-count-schedule          — next due date per (warehouse, item)
+//!   POST /api/inventory/abc-runs                      — run the ABC analysis now
+//!   GET  /api/inventory/abc-runs/{id}                 — run with per-item ranking
+//!   GET  /api/inventory/cycle-count-accuracy          — hit rate and value variance KPIs
+//!
+//! Tenant identity derived from JWT `VerifiedClaims`. The program and ABC
+//! classes are tenant-wide; schedule, generation and KPIs are per warehouse
+//! and require it to be inside the caller's data scope.
+
+use axum::{
+    extract::{Path, Query, State},
+    http::StatusCode,
+    response::IntoResponse,
+    Extension, Json,
+};
+use chrono::{DateTime, NaiveDate, Utc};
+use event_bus::TracingContext;
+use platform_http_contracts::{ApiError, PaginatedResponse};
+use security::{permissions, VerifiedClaims};
+use serde::Deserialize;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use super::tenant::{check_warehouse_scope, with_request_id};
+use crate::{
+    domain::cycle_count::{
+        abc_service::{get_abc_run, run_abc_analysis},
+        accuracy::accuracy_report,
+        program::{
+            get_program, upsert_program, AbcRunDetail, AccuracyReport, CountScheduleEntry,
+            CycleCountProgram, GenerateCountTasksRequest, GenerateCountTasksResult, ProgramError,
+            RunAbcRequest, UpsertCycleCountProgramRequest,
+        },
+        program_repo,
+        scheduler::generate_count_tasks,
+    },
+    AppState,
+};
+use platform_sdk::extract_tenant;
+
+#[derive(Debug, Deserialize)]
+pub struct ListScheduleQuery {
+    pub warehouse_id: Uuid,
+    /// Only rows due on or before this date
+    pub due_on_or_before: Option<NaiveDate>,
+    #[serde(default = "default_page")]
+    pub page: i64,
+    #[serde(default = "default_page_size")]
+    pub page_size: i64,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct AccuracyQuery {
+    pub warehouse_id: Uuid,
+    /// Approved at or after (inclusive)
+    pub from: DateTime<Utc>,
+    /// Approved before (exclusive)
+    pub to: DateTime<Utc>,
+}
+
+fn default_page() -> i64 {
+    1
+}
+fn default_page_size() -> i64 {
+    50
+}
+
+fn program_error(
+    err: ProgramError,
+    tracing_ctx: &Option<Extension<TracingContext>>,
+) -> axum::response::Response {
+    let api_err: ApiError = err.into();
+    with_request_id(api_err, tracing_ctx).into_response()
+}
+
+// ============================================================================
+// Program
+// ============================================================================
+
+#[utoipa::path(
+    put,
+    path = "/api/inventory/cycle-count-program",
+    tag = "Cycle Counts",
+    request_body = UpsertCycleCountProgramRequest,
+    responses(
+        (status = 200, description = "Program saved", body = CycleCountProgram),
+        (status = 422, description = "Invalid cutoffs or frequencies", body = ApiError),
+    ),
+    security(("bearer" = [])),
+)]
+pub async fn put_cycle_count_program(
+    State(state): State<Arc<AppState>>,
+    claims: Option<Extension<VerifiedClaims>>,
+    tracing_ctx: Option<Extension<TracingContext>>,
+    Json(req): Json<UpsertCycleCountProgramRequest>,
+) -> impl IntoResponse {
+    let tenant_id = match extract_tenant(&claims) {
+        Ok(id) => id,
+        Err(e) => return with_request_id(e, &tracing_ctx).into_response(),
+    };
+    match upsert_program(&state.pool, &tenant_id, &req).await {
+        Ok(program) => (StatusCode::OK, Json(program)).into_response(),
+        Err(err) => program_error(err, &tracing_ctx),
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/inventory/cycle-count-program",
+    tag = "Cycle Counts",
+    responses(
+        (status = 200, description = "Current program", body = CycleCountProgram),
+        (status = 404, description = "No program configured", body = ApiError),
+    ),
+    security(("bearer" = [])),
+)]
+pub async fn get_cycle_count_program(
+    State(state): State<Arc<AppState>>,
+    claims: Option<Extension<VerifiedClaims>>,
+    tracing_ctx: Option<Extension<TracingContext>>,
+) -> impl IntoResponse {
+    let tenant_id = match extract_tenant(&claims) {
+        Ok(id) => id,
+        Err(e) => return with_request_id(e, &tracing_ctx).into_response(),
+    };
+    match get_program(&state.pool, &tenant_id).await {
+        Ok(program) => (StatusCode::OK, Json(program)).into_response(),
+        Err(err) => program_error(err, &tracing_ctx),
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/inventory/cycle-count-program/generate",
+    tag = "Cycle Counts",
+    request_body = GenerateCountTasksRequest,
+    responses(
+        (status = 201, description = "Due count tasks generated", body = GenerateCountTasksResult),
+        (status = 404, description = "No program configured", body = ApiError),
+        (status = 422, description = "Program inactive", body = ApiError),
+    ),
+    security(("bearer" = [])),
+)]
+pub async fn post_generate_count_tasks(
+    State(state): State<Arc<AppState>>,
+    claims: Option<Extension<VerifiedClaims>>,
+    tracing_ctx: Option<Extension<TracingContext>>,
+    Json(req): Json<GenerateCountTasksRequest>,
+) -> impl IntoResponse {
+    let tenant_id = match extract_tenant(&claims) {
+        Ok(id) => id,
+        Err(e) => return with_request_id(e, &tracing_ctx).into_response(),
+    };
+    if let Err(e) = check_warehouse_scope(&claims, permissions::INVENTORY_MUTATE, req.warehouse_id)
+    {
+        return with_request_id(e, &tracing_ctx).into_response();
+    }
+    let day = req.scheduled_for.unwrap_or_else(|| Utc::now().date_naive());
+    match generate_count_tasks(&state.pool, &tenant_id, Some(req.warehouse_id), day).await {
+        Ok(result) => (StatusCode::CREATED, Json(result)).into_response(),
+        Err(err) => program_error(err, &tracing_ctx),
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/inventory/cycle-count-schedule",
+    tag = "Cycle Counts",
+    params(
+        ("warehouse_id" = Uuid, Query, description = "Warehouse ID"),
+        ("due_on_or_before" = Option<NaiveDate>, Query, description = "Only rows due by this date"),
+        ("page" = Option<i64>, Query, description = "Page (default 1)"),
+        ("page_size" = Option<i64>, Query, description = "Page size (default 50, max 200)"),
+    ),
+    responses(
+        (status = 200, description = "Count schedule, soonest due first", body = PaginatedResponse<CountScheduleEntry>),
+    ),
+    security(("bearer" = [])),
+)]
+pub async fn list_cycle_count_schedule(
+    State(state): State<Arc<AppState>>,
+    claims: Option<Extension<VerifiedClaims>>,
+    Query(q): Query<ListScheduleQuery>,
+    tracing_ctx: Option<Extension<TracingContext>>,
+) -> impl IntoResponse {
+    let tenant_id = match extract_tenant(&claims) {
+        Ok(id) => id,
+        Err(e) => return with_request_id(e, &tracing_ctx).into_response(),
+    };
+    if let Err(e) = check_warehouse_scope(&claims, permissions::INVENTORY_READ, q.warehouse_id) {
+        return with_request_id(e, &tracing_ctx).into_response();
+    }
+    let page_size = q.page_size.clamp(1, 200);
+    let page = q.page.max(1);
+    match program_repo::list_schedule(
+        &state.pool,
+        &tenant_id,
+        q.warehouse_id,
+        q.due_on_or_before,
+        page_size,
+        (page - 1) * page_size,
+    )
+    .await
+    {
+        Ok((rows, total)) => {
+            let resp = PaginatedResponse::new(rows, page, page_size, total);
+            (StatusCode::OK, Json(resp)).into_response()
+        }
+        Err(e) => program_error(e.into(), &tracing_ctx),
+    }
+}
+
+// ============================================================================
+// ABC runs
+// ============================================================================
+
+#[utoipa::path(
+    post,
+    path = "/api/inventory/abc-runs",
+    tag = "Cycle Counts",
+    request_body = RunAbcRequest,
+    responses(
+        (status = 201, description = "Items classified", body = AbcRunDetail),
+        (status = 404, description = "No program configured", body = ApiError),
+    ),
+    security(("bearer" = [])),
+)]
+pub async fn post_abc_run(
+    State(state): State<Arc<AppState>>,
+    claims: Option<Extension<VerifiedClaims>>,
+    tracing_ctx: Option<Extension<TracingContext>>,
+    Json(req): Json<RunAbcRequest>,
+) -> impl IntoResponse {
+    let tenant_id = match extract_tenant(&claims) {
+        Ok(id) => id,
+        Err(e) => return with_request_id(e, &tracing_ctx).into_response(),
+    };
+    let program = match get_program(&state.pool, &tenant_id).await {
+        Ok(p) => p,
+        Err(err) => return program_error(err, &tracing_ctx),
+    };
+    match run_abc_analysis(&state.pool, &program, &req).await {
+        Ok(run) => (StatusCode::CREATED, Json(run)).into_response(),
+        Err(err) => program_error(err, &tracing_ctx),
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/inventory/abc-runs/{run_id}",
+    tag = "Cycle Counts",
+    params(("run_id" = Uuid, Path, description = "ABC run ID")),
+    responses(
+        (status = 200, description = "Run with items ranked by usage value", body = AbcRunDetail),
+        (status = 404, description = "Run not found", body = ApiError),
+    ),
+    security(("bearer" = [])),
+)]
+pub async fn get_abc_run_handler(
+    Path(run_id): Path<Uuid>,
+    State(state): State<Arc<AppState>>,
+    claims: Option<Extension<VerifiedClaims>>,
+    tracing_ctx: Option<Extension<TracingContext>>,
+) -> impl IntoResponse {
+    let tenant_id = match extract_tenant(&claims) {
+        Ok(id) => id,
+        Err(e) => return with_request_id(e, &tracing_ctx).into_response(),
+    };
+    match get_abc_run(&state.pool, &tenant_id, run_id).await {
+        Ok(run) => (StatusCode::OK, Json(run)).into_response(),
+        Err(err) => program_error(err, &tracing_ctx),
+    }
+}
+
+// ============================================================================
+// Accuracy
+// ============================================================================
+
+#[utoipa::path(
+    get,
+    path = "/api/inventory/cycle-count-accuracy",
+    tag = "Cycle Counts",
+    params(
+        ("warehouse_id" = Uuid, Query, description = "Warehouse ID"),
+        ("from" = DateTime<Utc>, Query, description = "Approved at or after"),
+        ("to" = DateTime<Utc>, Query, description = "Approved before"),
+    ),
+    responses(
+        (status = 200, description = "Accuracy by class and bin, by class, overall", body = AccuracyReport),
+        (status = 422, description = "Invalid period", body = ApiError),
+    ),
+    security(("bearer" = [])),
+)]
+pub async fn get_cycle_count_accuracy(
+    State(state): State<Arc<AppState>>,
+    claims: Option<Extension<VerifiedClaims>>,
+    Query(q): Query<AccuracyQuery>,
+    tracing_ctx: Option<Extension<TracingContext>>,
+) -> impl IntoResponse {
+    let tenant_id = match extract_tenant(&claims) {
+        Ok(id) => id,
+        Err(e) => return with_request_id(e, &tracing_ctx).into_response(),
+    };
+    if let Err(e) = check_warehouse_scope(&claims, permissions::INVENTORY_READ, q.warehouse_id) {
+        return with_request_id(e, &tracing_ctx).into_response();
+    }
+    match accuracy_report(&state.pool, &tenant_id, q.warehouse_id, q.from, q.to).await {
+        Ok(report) => (StatusCode::OK, Json(report)).into_response(),
+        Err(err) => program_error(err, &tracing_ctx),
+    }
+}
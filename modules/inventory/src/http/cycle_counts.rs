@@ -4,6 +4,8 @@
 //!   POST /api/inventory/cycle-count-tasks              — create a task + snapshot lines
 //!   POST /api/inventory/cycle-count-tasks/{id}/submit  — submit counted quantities
 //!   POST /api/inventory/cycle-count-tasks/{id}/approve — approve and apply adjustments
+//!
+//! The task's warehouse must be inside the caller's data scope.
 
 use axum::{
     extract::{Path, State},
@@ -13,15 +15,16 @@ use axum::{
 };
 use event_bus::TracingContext;
 use platform_http_contracts::ApiError;
-use security::VerifiedClaims;
+use security::{permissions, VerifiedClaims};
 use serde::Deserialize;
 use std::sync::Arc;
 use uuid::Uuid;
 
-use super::tenant::with_request_id;
+use super::tenant::{check_warehouse_scope, with_request_id};
 use crate::{
     domain::cycle_count::{
         approve_service::{approve_cycle_count, ApproveRequest},
+        repo::fetch_task,
         submit_service::{submit_cycle_count, SubmitLineInput, SubmitRequest},
         task_service::{create_cycle_count_task, CreateTaskRequest, CreateTaskResult},
     },
@@ -29,6 +32,27 @@ use crate::{
 };
 use platform_sdk::extract_tenant;
 
+/// Deny acting on a count task in a warehouse outside the caller's scope.
+///
+/// Unknown ids pass through so the service answers 404 as before.
+async fn check_task_scope(
+    state: &AppState,
+    claims: &Option<Extension<VerifiedClaims>>,
+    tenant_id: &str,
+    task_id: Uuid,
+) -> Result<(), ApiError> {
+    let task = fetch_task(&state.pool, task_id, tenant_id)
+        .await
+        .map_err(|e| {
+            tracing::error!(error = %e, task_id = %task_id, "database error loading cycle count task");
+            ApiError::internal("Database error")
+        })?;
+    match task {
+        Some(t) => check_warehouse_scope(claims, permissions::INVENTORY_MUTATE, t.warehouse_id),
+        None => Ok(()),
+    }
+}
+
 // ============================================================================
 // Handlers
 // ============================================================================
@@ -53,6 +77,10 @@ pub async fn post_cycle_count_task(
         Ok(id) => id,
         Err(e) => return with_request_id(e, &tracing_ctx).into_response(),
     };
+    if let Err(e) = check_warehouse_scope(&claims, permissions::INVENTORY_MUTATE, req.warehouse_id)
+    {
+        return with_request_id(e, &tracing_ctx).into_response();
+    }
     req.tenant_id = tenant_id;
     match create_cycle_count_task(&state.pool, &req).await {
         Ok(result) => (StatusCode::CREATED, Json(result)).into_response(),
@@ -100,6 +128,9 @@ pub async fn post_cycle_count_submit(
         Ok(id) => id,
         Err(e) => return with_request_id(e, &tracing_ctx).into_response(),
     };
+    if let Err(e) = check_task_scope(&state, &claims, &tenant_id, task_id).await {
+        return with_request_id(e, &tracing_ctx).into_response();
+    }
     let req = SubmitRequest {
         task_id,
         tenant_id,
@@ -153,6 +184,9 @@ pub async fn post_cycle_count_approve(
         Ok(id) => id,
         Err(e) => return with_request_id(e, &tracing_ctx).into_response(),
     };
+    if let Err(e) = check_task_scope(&state, &claims, &tenant_id, task_id).await {
+        return with_request_id(e, &tracing_ctx).into_response();
+    }
     let req = ApproveRequest {
         task_id,
         tenant_id,
@@ -1,4 +1,6 @@
 //! Movement history HTTP handler.
+//!
+//! Movements are limited to warehouses inside the caller's data scope.
 
 use axum::{
     extract::{Path, Query, State},
@@ -8,14 +10,14 @@ use axum::{
 };
 use event_bus::TracingContext;
 use platform_http_contracts::ApiError;
-use security::VerifiedClaims;
+use security::{permissions, ScopeFilter, VerifiedClaims, SCOPE_LOCATION};
 use serde::Deserialize;
 use serde_json::json;
 use std::sync::Arc;
 use uuid::Uuid;
 
 use super::tenant::with_request_id;
-use crate::{domain::history::query::query_movement_history, AppState};
+use crate::{domain::history::query::query_movement_history_scoped, AppState};
 use platform_sdk::extract_tenant;
 
 #[derive(Debug, Deserialize)]
@@ -47,7 +49,21 @@ pub async fn get_movement_history(
         Ok(id) => id,
         Err(e) => return with_request_id(e, &tracing_ctx).into_response(),
     };
-    match query_movement_history(&state.pool, &tenant_id, item_id, q.location_id).await {
+    let scope = match &claims {
+        Some(Extension(c)) => ScopeFilter::new(c, permissions::INVENTORY_READ)
+            .column(SCOPE_LOCATION, "warehouse_id")
+            .to_sql(4),
+        None => {
+            return with_request_id(
+                ApiError::unauthorized("Missing or invalid authentication"),
+                &tracing_ctx,
+            )
+            .into_response()
+        }
+    };
+    match query_movement_history_scoped(&state.pool, &tenant_id, item_id, q.location_id, &scope)
+        .await
+    {
         Ok(movements) => (
             StatusCode::OK,
             Json(json!({
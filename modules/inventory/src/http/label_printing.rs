@@ -0,0 +1,430 @@
+//! Label template, rendering and SSCC HTTP handlers.
+//!
+//! Endpoints:
+//!   POST /api/inventory/label-templates                         — create a template
+//!   GET  /api/inventory/label-templates                         — list templates
+//!   GET  /api/inventory/label-templates/{template_id}           — one template
+//!   PUT  /api/inventory/label-templates/{template_id}           — replace a template
+//!   POST /api/inventory/label-templates/{template_id}/render    — render with a payload
+//!   GET  /api/inventory/labels/{label_id}/render                — render a generated label
+//!   PUT  /api/inventory/sscc-config                             — set GS1 company prefix
+//!   GET  /api/inventory/sscc-config                             — current SSCC config
+//!   POST /api/inventory/sscc/allocate                           — allocate SSCC codes
+//!   GET  /api/inventory/sscc/{sscc}                             — look up an allocated SSCC
+//!
+//! Rendered labels are returned as the raw artifact (`application/zpl` or
+//! `application/pdf`), not JSON.
+
+use axum::{
+    body::Body,
+    extract::{Path, Query, State},
+    http::{header, StatusCode},
+    response::{IntoResponse, Response},
+    Extension, Json,
+};
+use event_bus::TracingContext;
+use label_render::OutputFormat;
+use platform_http_contracts::ApiError;
+use security::VerifiedClaims;
+use serde::Deserialize;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use super::tenant::with_request_id;
+use crate::{
+    domain::label_printing::{
+        models::{
+            AllocateSsccRequest, AllocateSsccResult, CreateLabelTemplateRequest,
+            LabelPrintingError, LabelTemplate, RenderTemplateRequest, RenderedLabel, SsccConfig,
+            SsccSerial, UpdateLabelTemplateRequest, UpsertSsccConfigRequest,
+        },
+        render_service, repo, sscc_service, template_service,
+    },
+    AppState,
+};
+use platform_sdk::extract_tenant;
+
+// ============================================================================
+// Query params
+// ============================================================================
+
+#[derive(Debug, Deserialize)]
+pub struct ListLabelTemplatesQuery {
+    pub label_type: Option<String>,
+    /// Include deactivated templates (default false)
+    #[serde(default)]
+    pub include_inactive: bool,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct RenderLabelQuery {
+    /// zpl (default) | pdf
+    #[serde(default)]
+    pub format: OutputFormat,
+    /// Template to use instead of the label type's default
+    pub template_id: Option<Uuid>,
+}
+
+fn artifact_response(rendered: RenderedLabel) -> Response {
+    Response::builder()
+        .status(StatusCode::OK)
+        .header(header::CONTENT_TYPE, rendered.content_type)
+        .header(
+            header::CONTENT_DISPOSITION,
+            format!("attachment; filename=\"{}\"", rendered.filename),
+        )
+        .body(Body::from(rendered.bytes))
+        .expect("label response headers are valid")
+}
+
+// ============================================================================
+// Templates
+// ============================================================================
+
+#[utoipa::path(
+    post,
+    path = "/api/inventory/label-templates",
+    tag = "Labels",
+    request_body = CreateLabelTemplateRequest,
+    responses(
+        (status = 201, description = "Template created", body = LabelTemplate),
+        (status = 409, description = "Duplicate name or default", body = ApiError),
+        (status = 422, description = "Invalid layout", body = ApiError),
+    ),
+    security(("bearer" = [])),
+)]
+pub async fn post_label_template(
+    State(state): State<Arc<AppState>>,
+    claims: Option<Extension<VerifiedClaims>>,
+    tracing_ctx: Option<Extension<TracingContext>>,
+    Json(req): Json<CreateLabelTemplateRequest>,
+) -> impl IntoResponse {
+    let tenant_id = match extract_tenant(&claims) {
+        Ok(id) => id,
+        Err(e) => return with_request_id(e, &tracing_ctx).into_response(),
+    };
+    match template_service::create_template(&state.pool, &tenant_id, &req).await {
+        Ok(template) => (StatusCode::CREATED, Json(template)).into_response(),
+        Err(err) => {
+            let api_err: ApiError = err.into();
+            with_request_id(api_err, &tracing_ctx).into_response()
+        }
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/inventory/label-templates",
+    tag = "Labels",
+    params(
+        ("label_type" = Option<String>, Query, description = "Filter by label type"),
+        ("include_inactive" = Option<bool>, Query, description = "Include deactivated templates"),
+    ),
+    responses(
+        (status = 200, description = "Label templates by name", body = Vec<LabelTemplate>),
+    ),
+    security(("bearer" = [])),
+)]
+pub async fn list_label_templates(
+    State(state): State<Arc<AppState>>,
+    claims: Option<Extension<VerifiedClaims>>,
+    Query(q): Query<ListLabelTemplatesQuery>,
+    tracing_ctx: Option<Extension<TracingContext>>,
+) -> impl IntoResponse {
+    let tenant_id = match extract_tenant(&claims) {
+        Ok(id) => id,
+        Err(e) => return with_request_id(e, &tracing_ctx).into_response(),
+    };
+    match repo::list_templates(
+        &state.pool,
+        &tenant_id,
+        q.label_type.as_deref(),
+        q.include_inactive,
+    )
+    .await
+    {
+        Ok(templates) => (StatusCode::OK, Json(templates)).into_response(),
+        Err(e) => {
+            let api_err: ApiError = LabelPrintingError::from(e).into();
+            with_request_id(api_err, &tracing_ctx).into_response()
+        }
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/inventory/label-templates/{template_id}",
+    tag = "Labels",
+    params(("template_id" = Uuid, Path, description = "Template ID")),
+    responses(
+        (status = 200, description = "Label template", body = LabelTemplate),
+        (status = 404, description = "Not found", body = ApiError),
+    ),
+    security(("bearer" = [])),
+)]
+pub async fn get_label_template(
+    Path(template_id): Path<Uuid>,
+    State(state): State<Arc<AppState>>,
+    claims: Option<Extension<VerifiedClaims>>,
+    tracing_ctx: Option<Extension<TracingContext>>,
+) -> impl IntoResponse {
+    let tenant_id = match extract_tenant(&claims) {
+        Ok(id) => id,
+        Err(e) => return with_request_id(e, &tracing_ctx).into_response(),
+    };
+    match repo::find_template(&state.pool, &tenant_id, template_id).await {
+        Ok(Some(template)) => (StatusCode::OK, Json(template)).into_response(),
+        Ok(None) => {
+            let api_err: ApiError = LabelPrintingError::TemplateNotFound.into();
+            with_request_id(api_err, &tracing_ctx).into_response()
+        }
+        Err(e) => {
+            let api_err: ApiError = LabelPrintingError::from(e).into();
+            with_request_id(api_err, &tracing_ctx).into_response()
+        }
+    }
+}
+
+#[utoipa::path(
+    put,
+    path = "/api/inventory/label-templates/{template_id}",
+    tag = "Labels",
+    params(("template_id" = Uuid, Path, description = "Template ID")),
+    request_body = UpdateLabelTemplateRequest,
+    responses(
+        (status = 200, description = "Template replaced", body = LabelTemplate),
+        (status = 404, description = "Not found", body = ApiError),
+        (status = 409, description = "Duplicate name or default", body = ApiError),
+        (status = 422, description = "Invalid layout", body = ApiError),
+    ),
+    security(("bearer" = [])),
+)]
+pub async fn put_label_template(
+    Path(template_id): Path<Uuid>,
+    State(state): State<Arc<AppState>>,
+    claims: Option<Extension<VerifiedClaims>>,
+    tracing_ctx: Option<Extension<TracingContext>>,
+    Json(req): Json<UpdateLabelTemplateRequest>,
+) -> impl IntoResponse {
+    let tenant_id = match extract_tenant(&claims) {
+        Ok(id) => id,
+        Err(e) => return with_request_id(e, &tracing_ctx).into_response(),
+    };
+    match template_service::update_template(&state.pool, &tenant_id, template_id, &req).await {
+        Ok(template) => (StatusCode::OK, Json(template)).into_response(),
+        Err(err) => {
+            let api_err: ApiError = err.into();
+            with_request_id(api_err, &tracing_ctx).into_response()
+        }
+    }
+}
+
+// ============================================================================
+// Rendering
+// ============================================================================
+
+#[utoipa::path(
+    post,
+    path = "/api/inventory/label-templates/{template_id}/render",
+    tag = "Labels",
+    params(("template_id" = Uuid, Path, description = "Template ID")),
+    request_body = RenderTemplateRequest,
+    responses(
+        (status = 200, description = "Rendered label in the requested format",
+            content(("application/zpl"), ("application/pdf"))),
+        (status = 404, description = "Template not found or inactive", body = ApiError),
+        (status = 422, description = "Payload missing a field or not encodable", body = ApiError),
+    ),
+    security(("bearer" = [])),
+)]
+pub async fn post_render_label_template(
+    Path(template_id): Path<Uuid>,
+    State(state): State<Arc<AppState>>,
+    claims: Option<Extension<VerifiedClaims>>,
+    tracing_ctx: Option<Extension<TracingContext>>,
+    Json(req): Json<RenderTemplateRequest>,
+) -> impl IntoResponse {
+    let tenant_id = match extract_tenant(&claims) {
+        Ok(id) => id,
+        Err(e) => return with_request_id(e, &tracing_ctx).into_response(),
+    };
+    match render_service::render_template(&state.pool, &tenant_id, template_id, &req).await {
+        Ok(rendered) => artifact_response(rendered),
+        Err(err) => {
+            let api_err: ApiError = err.into();
+            with_request_id(api_err, &tracing_ctx).into_response()
+        }
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/inventory/labels/{label_id}/render",
+    tag = "Labels",
+    params(
+        ("label_id" = Uuid, Path, description = "Label ID"),
+        ("format" = Option<String>, Query, description = "zpl (default) | pdf"),
+        ("template_id" = Option<Uuid>, Query, description = "Template overriding the label type default"),
+    ),
+    responses(
+        (status = 200, description = "Rendered label in the requested format",
+            content(("application/zpl"), ("application/pdf"))),
+        (status = 404, description = "Label or template not found", body = ApiError),
+        (status = 422, description = "Payload missing a field or not encodable", body = ApiError),
+    ),
+    security(("bearer" = [])),
+)]
+pub async fn get_render_label(
+    Path(label_id): Path<Uuid>,
+    State(state): State<Arc<AppState>>,
+    claims: Option<Extension<VerifiedClaims>>,
+    Query(q): Query<RenderLabelQuery>,
+    tracing_ctx: Option<Extension<TracingContext>>,
+) -> impl IntoResponse {
+    let tenant_id = match extract_tenant(&claims) {
+        Ok(id) => id,
+        Err(e) => return with_request_id(e, &tracing_ctx).into_response(),
+    };
+    match render_service::render_label(&state.pool, &tenant_id, label_id, q.template_id, q.format)
+        .await
+    {
+        Ok(rendered) => artifact_response(rendered),
+        Err(err) => {
+            let api_err: ApiError = err.into();
+            with_request_id(api_err, &tracing_ctx).into_response()
+        }
+    }
+}
+
+// ============================================================================
+// SSCC
+// ============================================================================
+
+#[utoipa::path(
+    put,
+    path = "/api/inventory/sscc-config",
+    tag = "Labels",
+    request_body = UpsertSsccConfigRequest,
+    responses(
+        (status = 200, description = "SSCC configuration saved", body = SsccConfig),
+        (status = 422, description = "Invalid company prefix or extension digit", body = ApiError),
+    ),
+    security(("bearer" = [])),
+)]
+pub async fn put_sscc_config(
+    State(state): State<Arc<AppState>>,
+    claims: Option<Extension<VerifiedClaims>>,
+    tracing_ctx: Option<Extension<TracingContext>>,
+    Json(req): Json<UpsertSsccConfigRequest>,
+) -> impl IntoResponse {
+    let tenant_id = match extract_tenant(&claims) {
+        Ok(id) => id,
+        Err(e) => return with_request_id(e, &tracing_ctx).into_response(),
+    };
+    match sscc_service::upsert_config(&state.pool, &tenant_id, &req).await {
+        Ok(config) => (StatusCode::OK, Json(config)).into_response(),
+        Err(err) => {
+            let api_err: ApiError = err.into();
+            with_request_id(api_err, &tracing_ctx).into_response()
+        }
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/inventory/sscc-config",
+    tag = "Labels",
+    responses(
+        (status = 200, description = "SSCC configuration", body = SsccConfig),
+        (status = 404, description = "SSCC allocation not configured", body = ApiError),
+    ),
+    security(("bearer" = [])),
+)]
+pub async fn get_sscc_config(
+    State(state): State<Arc<AppState>>,
+    claims: Option<Extension<VerifiedClaims>>,
+    tracing_ctx: Option<Extension<TracingContext>>,
+) -> impl IntoResponse {
+    let tenant_id = match extract_tenant(&claims) {
+        Ok(id) => id,
+        Err(e) => return with_request_id(e, &tracing_ctx).into_response(),
+    };
+    match repo::find_sscc_config(&state.pool, &tenant_id).await {
+        Ok(Some(config)) => (StatusCode::OK, Json(config)).into_response(),
+        Ok(None) => with_request_id(
+            ApiError::not_found("SSCC allocation is not configured for this tenant"),
+            &tracing_ctx,
+        )
+        .into_response(),
+        Err(e) => {
+            let api_err: ApiError = LabelPrintingError::from(e).into();
+            with_request_id(api_err, &tracing_ctx).into_response()
+        }
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/inventory/sscc/allocate",
+    tag = "Labels",
+    request_body = AllocateSsccRequest,
+    responses(
+        (status = 201, description = "SSCCs allocated", body = AllocateSsccResult),
+        (status = 200, description = "Idempotency replay", body = AllocateSsccResult),
+        (status = 409, description = "Idempotency key conflict", body = ApiError),
+        (status = 422, description = "Not configured or serial range exhausted", body = ApiError),
+    ),
+    security(("bearer" = [])),
+)]
+pub async fn post_allocate_sscc(
+    State(state): State<Arc<AppState>>,
+    claims: Option<Extension<VerifiedClaims>>,
+    tracing_ctx: Option<Extension<TracingContext>>,
+    Json(req): Json<AllocateSsccRequest>,
+) -> impl IntoResponse {
+    let tenant_id = match extract_tenant(&claims) {
+        Ok(id) => id,
+        Err(e) => return with_request_id(e, &tracing_ctx).into_response(),
+    };
+    match sscc_service::allocate_sscc(&state.pool, &tenant_id, &req).await {
+        Ok((result, false)) => (StatusCode::CREATED, Json(result)).into_response(),
+        Ok((result, true)) => (StatusCode::OK, Json(result)).into_response(),
+        Err(err) => {
+            let api_err: ApiError = err.into();
+            with_request_id(api_err, &tracing_ctx).into_response()
+        }
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/inventory/sscc/{sscc}",
+    tag = "Labels",
+    params(("sscc" = String, Path, description = "18-digit SSCC")),
+    responses(
+        (status = 200, description = "Allocated SSCC", body = SsccSerial),
+        (status = 404, description = "Not allocated by this tenant", body = ApiError),
+    ),
+    security(("bearer" = [])),
+)]
+pub async fn get_sscc(
+    Path(sscc): Path<String>,
+    State(state): State<Arc<AppState>>,
+    claims: Option<Extension<VerifiedClaims>>,
+    tracing_ctx: Option<Extension<TracingContext>>,
+) -> impl IntoResponse {
+    let tenant_id = match extract_tenant(&claims) {
+        Ok(id) => id,
+        Err(e) => return with_request_id(e, &tracing_ctx).into_response(),
+    };
+    match repo::find_sscc_serial(&state.pool, &tenant_id, &sscc).await {
+        Ok(Some(serial)) => (StatusCode::OK, Json(serial)).into_response(),
+        Ok(None) => {
+            with_request_id(ApiError::not_found("SSCC not found"), &tracing_ctx).into_response()
+        }
+        Err(e) => {
+            let api_err: ApiError = LabelPrintingError::from(e).into();
+            with_request_id(api_err, &tracing_ctx).into_response()
+        }
+    }
+}
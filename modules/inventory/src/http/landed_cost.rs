@@ -0,0 +1,188 @@
+//! Landed cost voucher HTTP handlers.
+//!
+//! Endpoints:
+//!   POST /api/inventory/landed-cost-vouchers       — allocate AP charges to receipts
+//!   GET  /api/inventory/landed-cost-vouchers       — list vouchers (newest first)
+//!   GET  /api/inventory/landed-cost-vouchers/{id}  — voucher + charges + allocations
+//!
+//! Tenant identity derived from JWT `VerifiedClaims`. Posting and reading a
+//! voucher require every receipt warehouse to be inside the caller's data
+//! scope; the list returns headers only.
+
+use axum::{
+    extract::{Path, Query, State},
+    http::StatusCode,
+    response::IntoResponse,
+    Extension, Json,
+};
+use event_bus::TracingContext;
+use platform_http_contracts::{ApiError, PaginatedResponse};
+use security::{permissions, VerifiedClaims};
+use serde::Deserialize;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use super::tenant::{check_warehouse_scope, with_request_id};
+use crate::{
+    domain::valuation::landed_cost::{
+        models::{
+            CreateLandedCostVoucherRequest, LandedCostError, LandedCostVoucher,
+            LandedCostVoucherDetail,
+        },
+        repo,
+        service::{create_voucher, get_voucher},
+    },
+    AppState,
+};
+use platform_sdk::extract_tenant;
+
+#[derive(Debug, Deserialize)]
+pub struct ListLandedCostVouchersQuery {
+    /// Only vouchers carrying lines of this AP bill
+    pub ap_bill_id: Option<Uuid>,
+    #[serde(default = "default_page")]
+    pub page: i64,
+    #[serde(default = "default_page_size")]
+    pub page_size: i64,
+}
+
+fn default_page() -> i64 {
+    1
+}
+fn default_page_size() -> i64 {
+    50
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/inventory/landed-cost-vouchers",
+    tag = "Valuation",
+    request_body = CreateLandedCostVoucherRequest,
+    responses(
+        (status = 201, description = "Voucher posted, layers revalued", body = LandedCostVoucherDetail),
+        (status = 200, description = "Idempotency replay", body = LandedCostVoucherDetail),
+        (status = 409, description = "Bill line already allocated or idempotency key conflict", body = ApiError),
+        (status = 422, description = "Invalid voucher or unknown receipt", body = ApiError),
+    ),
+    security(("bearer" = [])),
+)]
+pub async fn post_landed_cost_voucher(
+    State(state): State<Arc<AppState>>,
+    claims: Option<Extension<VerifiedClaims>>,
+    tracing_ctx: Option<Extension<TracingContext>>,
+    Json(mut req): Json<CreateLandedCostVoucherRequest>,
+) -> impl IntoResponse {
+    let tracing_ctx_val = tracing_ctx.as_ref().map(|Extension(c)| c.clone());
+    let tenant_id = match extract_tenant(&claims) {
+        Ok(id) => id,
+        Err(e) => return with_request_id(e, &tracing_ctx).into_response(),
+    };
+    let receipt_ids: Vec<Uuid> = req.receipts.iter().map(|r| r.receipt_line_id).collect();
+    match repo::receipt_warehouses(&state.pool, &tenant_id, &receipt_ids).await {
+        Ok(warehouses) => {
+            for wh in warehouses {
+                if let Err(e) = check_warehouse_scope(&claims, permissions::INVENTORY_MUTATE, wh) {
+                    return with_request_id(e, &tracing_ctx).into_response();
+                }
+            }
+        }
+        Err(e) => {
+            let api_err: ApiError = LandedCostError::from(e).into();
+            return with_request_id(api_err, &tracing_ctx).into_response();
+        }
+    }
+    req.tenant_id = tenant_id;
+    match create_voucher(&state.pool, &req, tracing_ctx_val.as_ref()).await {
+        Ok((result, false)) => (StatusCode::CREATED, Json(result)).into_response(),
+        Ok((result, true)) => (StatusCode::OK, Json(result)).into_response(),
+        Err(err) => {
+            let api_err: ApiError = err.into();
+            with_request_id(api_err, &tracing_ctx).into_response()
+        }
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/inventory/landed-cost-vouchers",
+    tag = "Valuation",
+    params(
+        ("ap_bill_id" = Option<Uuid>, Query, description = "Filter by AP bill"),
+        ("page" = Option<i64>, Query, description = "Page (default 1)"),
+        ("page_size" = Option<i64>, Query, description = "Page size (default 50, max 200)"),
+    ),
+    responses(
+        (status = 200, description = "Paginated voucher list", body = PaginatedResponse<LandedCostVoucher>),
+    ),
+    security(("bearer" = [])),
+)]
+pub async fn list_landed_cost_vouchers(
+    State(state): State<Arc<AppState>>,
+    claims: Option<Extension<VerifiedClaims>>,
+    Query(q): Query<ListLandedCostVouchersQuery>,
+    tracing_ctx: Option<Extension<TracingContext>>,
+) -> impl IntoResponse {
+    let tenant_id = match extract_tenant(&claims) {
+        Ok(id) => id,
+        Err(e) => return with_request_id(e, &tracing_ctx).into_response(),
+    };
+    let page_size = q.page_size.clamp(1, 200);
+    let page = q.page.max(1);
+    match repo::list_vouchers(
+        &state.pool,
+        &tenant_id,
+        q.ap_bill_id,
+        page_size,
+        (page - 1) * page_size,
+    )
+    .await
+    {
+        Ok((vouchers, total)) => {
+            let resp = PaginatedResponse::new(vouchers, page, page_size, total);
+            (StatusCode::OK, Json(resp)).into_response()
+        }
+        Err(e) => {
+            let api_err: ApiError = LandedCostError::from(e).into();
+            with_request_id(api_err, &tracing_ctx).into_response()
+        }
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/inventory/landed-cost-vouchers/{voucher_id}",
+    tag = "Valuation",
+    params(("voucher_id" = Uuid, Path, description = "Landed cost voucher ID")),
+    responses(
+        (status = 200, description = "Voucher with charges and allocations", body = LandedCostVoucherDetail),
+        (status = 404, description = "Voucher not found", body = ApiError),
+    ),
+    security(("bearer" = [])),
+)]
+pub async fn get_landed_cost_voucher(
+    Path(voucher_id): Path<Uuid>,
+    State(state): State<Arc<AppState>>,
+    claims: Option<Extension<VerifiedClaims>>,
+    tracing_ctx: Option<Extension<TracingContext>>,
+) -> impl IntoResponse {
+    let tenant_id = match extract_tenant(&claims) {
+        Ok(id) => id,
+        Err(e) => return with_request_id(e, &tracing_ctx).into_response(),
+    };
+    match get_voucher(&state.pool, &tenant_id, voucher_id).await {
+        Ok(detail) => {
+            for a in &detail.allocations {
+                if let Err(e) =
+                    check_warehouse_scope(&claims, permissions::INVENTORY_READ, a.warehouse_id)
+                {
+                    return with_request_id(e, &tracing_ctx).into_response();
+                }
+            }
+            (StatusCode::OK, Json(detail)).into_response()
+        }
+        Err(err) => {
+            let api_err: ApiError = err.into();
+            with_request_id(api_err, &tracing_ctx).into_response()
+        }
+    }
+}
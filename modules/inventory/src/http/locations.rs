@@ -7,7 +7,8 @@
 //!   POST  /api/inventory/locations/:id/deactivate   — soft-delete location
 //!   GET   /api/inventory/warehouses/:wid/locations  — list by warehouse
 //!
-//! Tenant identity derived from JWT `VerifiedClaims`.
+//! Tenant identity derived from JWT `VerifiedClaims`; the location's warehouse
+//! must be inside the caller's data scope.
 
 use axum::{
     extract::{Path, Query, State},
@@ -17,18 +18,34 @@ use axum::{
 };
 use event_bus::TracingContext;
 use platform_http_contracts::{ApiError, PaginatedResponse};
-use security::VerifiedClaims;
+use security::{permissions, VerifiedClaims};
 use serde::Deserialize;
 use std::sync::Arc;
 use uuid::Uuid;
 
-use super::tenant::with_request_id;
+use super::tenant::{check_warehouse_scope, with_request_id};
 use crate::{
     domain::locations::{CreateLocationRequest, Location, LocationRepo, UpdateLocationRequest},
     AppState,
 };
 use platform_sdk::extract_tenant;
 
+/// Deny `perm` on a location whose warehouse is outside the caller's scope.
+///
+/// Unknown ids pass through so the repository answers 404 as before.
+async fn check_location_scope(
+    state: &AppState,
+    claims: &Option<Extension<VerifiedClaims>>,
+    perm: &str,
+    tenant_id: &str,
+    location_id: Uuid,
+) -> Result<(), ApiError> {
+    match LocationRepo::find_by_id(&state.pool, location_id, tenant_id).await? {
+        Some(loc) => check_warehouse_scope(claims, perm, loc.warehouse_id),
+        None => Ok(()),
+    }
+}
+
 // ============================================================================
 // Query params
 // ============================================================================
@@ -74,6 +91,10 @@ pub async fn create_location(
         Ok(id) => id,
         Err(e) => return with_request_id(e, &tracing_ctx).into_response(),
     };
+    if let Err(e) = check_warehouse_scope(&claims, permissions::INVENTORY_MUTATE, req.warehouse_id)
+    {
+        return with_request_id(e, &tracing_ctx).into_response();
+    }
     req.tenant_id = tenant_id;
     match LocationRepo::create(&state.pool, &req).await {
         Ok(loc) => (StatusCode::CREATED, Json(loc)).into_response(),
@@ -106,7 +127,14 @@ pub async fn get_location(
         Err(e) => return with_request_id(e, &tracing_ctx).into_response(),
     };
     match LocationRepo::find_by_id(&state.pool, id, &tenant_id).await {
-        Ok(Some(loc)) => (StatusCode::OK, Json(loc)).into_response(),
+        Ok(Some(loc)) => {
+            if let Err(e) =
+                check_warehouse_scope(&claims, permissions::INVENTORY_READ, loc.warehouse_id)
+            {
+                return with_request_id(e, &tracing_ctx).into_response();
+            }
+            (StatusCode::OK, Json(loc)).into_response()
+        }
         Ok(None) => {
             with_request_id(ApiError::not_found("Location not found"), &tracing_ctx).into_response()
         }
@@ -140,6 +168,17 @@ pub async fn update_location(
         Ok(id) => id,
         Err(e) => return with_request_id(e, &tracing_ctx).into_response(),
     };
+    if let Err(e) = check_location_scope(
+        &state,
+        &claims,
+        permissions::INVENTORY_MUTATE,
+        &tenant_id,
+        id,
+    )
+    .await
+    {
+        return with_request_id(e, &tracing_ctx).into_response();
+    }
     req.tenant_id = tenant_id;
     match LocationRepo::update(&state.pool, id, &req).await {
         Ok(loc) => (StatusCode::OK, Json(loc)).into_response(),
@@ -171,6 +210,17 @@ pub async fn deactivate_location(
         Ok(id) => id,
         Err(e) => return with_request_id(e, &tracing_ctx).into_response(),
     };
+    if let Err(e) = check_location_scope(
+        &state,
+        &claims,
+        permissions::INVENTORY_MUTATE,
+        &tenant_id,
+        id,
+    )
+    .await
+    {
+        return with_request_id(e, &tracing_ctx).into_response();
+    }
     match LocationRepo::deactivate(&state.pool, id, &tenant_id).await {
         Ok(loc) => (StatusCode::OK, Json(loc)).into_response(),
         Err(e) => {
@@ -201,6 +251,9 @@ pub async fn list_locations(
         Ok(id) => id,
         Err(e) => return with_request_id(e, &tracing_ctx).into_response(),
     };
+    if let Err(e) = check_warehouse_scope(&claims, permissions::INVENTORY_READ, warehouse_id) {
+        return with_request_id(e, &tracing_ctx).into_response();
+    }
     match LocationRepo::list_for_warehouse(&state.pool, &tenant_id, warehouse_id).await {
         Ok(all_locs) => {
             let total = all_locs.len() as i64;
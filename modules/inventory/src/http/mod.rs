@@ -3,6 +3,7 @@ pub mod admin;
 pub mod admin_types;
 pub mod barcode;
 pub mod batch_receipts;
+pub mod cycle_count_program;
 pub mod cycle_counts;
 pub mod expiry;
 pub mod genealogy;
@@ -11,10 +12,14 @@ pub mod history;
 pub mod imports;
 pub mod issues;
 pub mod items;
+pub mod label_printing;
 pub mod labels;
+pub mod landed_cost;
 pub mod locations;
 pub mod lots;
 pub mod make_buy;
+pub mod picking;
+pub mod putaway;
 pub mod receipts;
 pub mod reorder;
 pub mod reservations;
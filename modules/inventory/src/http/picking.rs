@@ -0,0 +1,420 @@
+//! Pick task and wave HTTP handlers.
+//!
+//! Endpoints:
+//!   POST /api/inventory/pick-tasks                       — create tasks for released demand
+//!   GET  /api/inventory/pick-tasks                       — list tasks of a warehouse
+//!   GET  /api/inventory/pick-tasks/{task_id}             — task with its confirmations
+//!   POST /api/inventory/pick-tasks/{task_id}/confirm     — scan-confirm picks, issue stock
+//!   POST /api/inventory/pick-waves                       — group open tasks into a wave
+//!   GET  /api/inventory/pick-waves/{wave_id}             — wave with its tasks
+//!   GET  /api/inventory/pick-waves/{wave_id}/pick-list   — location-directed pick list
+//!
+//! Tenant identity derived from JWT `VerifiedClaims`; every endpoint is
+//! limited to warehouses inside the caller's data scope.
+
+use axum::{
+    extract::{Path, Query, State},
+    http::StatusCode,
+    response::IntoResponse,
+    Extension, Json,
+};
+use chrono::{NaiveDate, Utc};
+use event_bus::TracingContext;
+use platform_http_contracts::{ApiError, PaginatedResponse};
+use security::{permissions, VerifiedClaims};
+use serde::Deserialize;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use super::tenant::{check_warehouse_scope, with_request_id};
+use crate::{
+    domain::picking::{
+        confirm_service::confirm_pick,
+        models::{
+            ConfirmPickRequest, ConfirmPickResult, CreatePickTasksRequest, CreatePickTasksResult,
+            CreatePickWaveRequest, PickError, PickLineInput, PickList, PickSourceType, PickTask,
+            PickTaskDetail, PickWaveDetail, ShortAction,
+        },
+        repo::{self, PickTaskFilter},
+        task_service::create_pick_tasks,
+        wave_service::{build_pick_list, create_pick_wave, get_pick_wave},
+    },
+    AppState,
+};
+use platform_sdk::extract_tenant;
+
+// ============================================================================
+// Query params / bodies
+// ============================================================================
+
+#[derive(Debug, Deserialize)]
+pub struct ListPickTasksQuery {
+    pub warehouse_id: Uuid,
+    pub status: Option<String>,
+    pub source_type: Option<PickSourceType>,
+    pub source_id: Option<String>,
+    pub wave_id: Option<Uuid>,
+    #[serde(default = "default_page")]
+    pub page: i64,
+    #[serde(default = "default_page_size")]
+    pub page_size: i64,
+}
+
+fn default_page() -> i64 {
+    1
+}
+fn default_page_size() -> i64 {
+    50
+}
+
+#[derive(Debug, Deserialize)]
+pub struct PickListQuery {
+    /// Date used to exclude expired lots (defaults to today, UTC)
+    pub as_of: Option<NaiveDate>,
+}
+
+#[derive(Debug, Deserialize, utoipa::ToSchema)]
+pub struct ConfirmPickBody {
+    pub idempotency_key: String,
+    /// Scanned picks; empty confirms a full short pick
+    #[serde(default)]
+    pub picks: Vec<PickLineInput>,
+    /// Handling of an unpicked remainder (default: backorder)
+    #[serde(default)]
+    pub short_action: ShortAction,
+    pub confirmed_by: Option<String>,
+    pub correlation_id: Option<String>,
+    pub causation_id: Option<String>,
+}
+
+// ============================================================================
+// Pick tasks
+// ============================================================================
+
+#[utoipa::path(
+    post,
+    path = "/api/inventory/pick-tasks",
+    tag = "Picking",
+    request_body = CreatePickTasksRequest,
+    responses(
+        (status = 201, description = "Pick tasks created and reserved", body = CreatePickTasksResult),
+        (status = 200, description = "Idempotency replay", body = CreatePickTasksResult),
+        (status = 409, description = "Reservation unusable or idempotency key conflict", body = ApiError),
+        (status = 422, description = "Validation failure or insufficient available stock", body = ApiError),
+    ),
+    security(("bearer" = [])),
+)]
+pub async fn post_pick_tasks(
+    State(state): State<Arc<AppState>>,
+    claims: Option<Extension<VerifiedClaims>>,
+    tracing_ctx: Option<Extension<TracingContext>>,
+    Json(mut req): Json<CreatePickTasksRequest>,
+) -> impl IntoResponse {
+    let tenant_id = match extract_tenant(&claims) {
+        Ok(id) => id,
+        Err(e) => return with_request_id(e, &tracing_ctx).into_response(),
+    };
+    if let Err(e) = check_warehouse_scope(&claims, permissions::INVENTORY_MUTATE, req.warehouse_id)
+    {
+        return with_request_id(e, &tracing_ctx).into_response();
+    }
+    req.tenant_id = tenant_id;
+    match create_pick_tasks(&state.pool, &req).await {
+        Ok((result, false)) => (StatusCode::CREATED, Json(result)).into_response(),
+        Ok((result, true)) => (StatusCode::OK, Json(result)).into_response(),
+        Err(err) => {
+            let api_err: ApiError = err.into();
+            with_request_id(api_err, &tracing_ctx).into_response()
+        }
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/inventory/pick-tasks",
+    tag = "Picking",
+    params(
+        ("warehouse_id" = Uuid, Query, description = "Warehouse ID"),
+        ("status" = Option<String>, Query, description = "open | picked | short"),
+        ("source_type" = Option<PickSourceType>, Query, description = "Demand type"),
+        ("source_id" = Option<String>, Query, description = "Demand document id"),
+        ("wave_id" = Option<Uuid>, Query, description = "Wave ID"),
+    ),
+    responses(
+        (status = 200, description = "Paginated pick tasks", body = PaginatedResponse<PickTask>),
+    ),
+    security(("bearer" = [])),
+)]
+pub async fn list_pick_tasks(
+    State(state): State<Arc<AppState>>,
+    claims: Option<Extension<VerifiedClaims>>,
+    Query(q): Query<ListPickTasksQuery>,
+    tracing_ctx: Option<Extension<TracingContext>>,
+) -> impl IntoResponse {
+    let tenant_id = match extract_tenant(&claims) {
+        Ok(id) => id,
+        Err(e) => return with_request_id(e, &tracing_ctx).into_response(),
+    };
+    if let Err(e) = check_warehouse_scope(&claims, permissions::INVENTORY_READ, q.warehouse_id) {
+        return with_request_id(e, &tracing_ctx).into_response();
+    }
+    let page_size = q.page_size.clamp(1, 200);
+    let page = q.page.max(1);
+    let filter = PickTaskFilter {
+        warehouse_id: q.warehouse_id,
+        status: q.status.as_deref(),
+        source_type: q.source_type.map(|s| s.as_str()),
+        source_id: q.source_id.as_deref(),
+        wave_id: q.wave_id,
+    };
+    match repo::list_tasks(
+        &state.pool,
+        &tenant_id,
+        &filter,
+        page_size,
+        (page - 1) * page_size,
+    )
+    .await
+    {
+        Ok((tasks, total)) => {
+            let resp = PaginatedResponse::new(tasks, page, page_size, total);
+            (StatusCode::OK, Json(resp)).into_response()
+        }
+        Err(e) => {
+            let api_err: ApiError = PickError::from(e).into();
+            with_request_id(api_err, &tracing_ctx).into_response()
+        }
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/inventory/pick-tasks/{task_id}",
+    tag = "Picking",
+    params(("task_id" = Uuid, Path, description = "Pick task ID")),
+    responses(
+        (status = 200, description = "Pick task with confirmations", body = PickTaskDetail),
+        (status = 404, description = "Pick task not found", body = ApiError),
+    ),
+    security(("bearer" = [])),
+)]
+pub async fn get_pick_task(
+    Path(task_id): Path<Uuid>,
+    State(state): State<Arc<AppState>>,
+    claims: Option<Extension<VerifiedClaims>>,
+    tracing_ctx: Option<Extension<TracingContext>>,
+) -> impl IntoResponse {
+    let tenant_id = match extract_tenant(&claims) {
+        Ok(id) => id,
+        Err(e) => return with_request_id(e, &tracing_ctx).into_response(),
+    };
+    let result = async {
+        let task = repo::find_task(&state.pool, &tenant_id, task_id)
+            .await?
+            .ok_or(PickError::TaskNotFound)?;
+        let confirmations = repo::list_confirmations(&state.pool, task.id).await?;
+        Ok::<_, PickError>(PickTaskDetail {
+            task,
+            confirmations,
+        })
+    }
+    .await;
+    match result {
+        Ok(detail) => {
+            if let Err(e) = check_warehouse_scope(
+                &claims,
+                permissions::INVENTORY_READ,
+                detail.task.warehouse_id,
+            ) {
+                return with_request_id(e, &tracing_ctx).into_response();
+            }
+            (StatusCode::OK, Json(detail)).into_response()
+        }
+        Err(err) => {
+            let api_err: ApiError = err.into();
+            with_request_id(api_err, &tracing_ctx).into_response()
+        }
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/inventory/pick-tasks/{task_id}/confirm",
+    tag = "Picking",
+    params(("task_id" = Uuid, Path, description = "Pick task ID")),
+    request_body = ConfirmPickBody,
+    responses(
+        (status = 201, description = "Picks confirmed, reservation released and stock issued", body = ConfirmPickResult),
+        (status = 200, description = "Idempotency replay", body = ConfirmPickResult),
+        (status = 404, description = "Pick task not found", body = ApiError),
+        (status = 409, description = "Task not open, reservation inactive or idempotency key conflict", body = ApiError),
+        (status = 422, description = "Scan mismatch, unknown location or insufficient stock", body = ApiError),
+    ),
+    security(("bearer" = [])),
+)]
+pub async fn post_pick_confirm(
+    Path(task_id): Path<Uuid>,
+    State(state): State<Arc<AppState>>,
+    claims: Option<Extension<VerifiedClaims>>,
+    tracing_ctx: Option<Extension<TracingContext>>,
+    Json(body): Json<ConfirmPickBody>,
+) -> impl IntoResponse {
+    let tracing_ctx_val = tracing_ctx.as_ref().map(|Extension(c)| c.clone());
+    let tenant_id = match extract_tenant(&claims) {
+        Ok(id) => id,
+        Err(e) => return with_request_id(e, &tracing_ctx).into_response(),
+    };
+    match repo::find_task(&state.pool, &tenant_id, task_id).await {
+        Ok(Some(task)) => {
+            if let Err(e) =
+                check_warehouse_scope(&claims, permissions::INVENTORY_MUTATE, task.warehouse_id)
+            {
+                return with_request_id(e, &tracing_ctx).into_response();
+            }
+        }
+        Ok(None) => {}
+        Err(e) => {
+            let api_err: ApiError = PickError::from(e).into();
+            return with_request_id(api_err, &tracing_ctx).into_response();
+        }
+    }
+    let req = ConfirmPickRequest {
+        tenant_id,
+        task_id,
+        picks: body.picks,
+        short_action: body.short_action,
+        confirmed_by: body.confirmed_by,
+        idempotency_key: body.idempotency_key,
+        correlation_id: body.correlation_id,
+        causation_id: body.causation_id,
+    };
+    match confirm_pick(&state.pool, &req, tracing_ctx_val.as_ref()).await {
+        Ok((result, false)) => (StatusCode::CREATED, Json(result)).into_response(),
+        Ok((result, true)) => (StatusCode::OK, Json(result)).into_response(),
+        Err(err) => {
+            let api_err: ApiError = err.into();
+            with_request_id(api_err, &tracing_ctx).into_response()
+        }
+    }
+}
+
+// ============================================================================
+// Pick waves
+// ============================================================================
+
+#[utoipa::path(
+    post,
+    path = "/api/inventory/pick-waves",
+    tag = "Picking",
+    request_body = CreatePickWaveRequest,
+    responses(
+        (status = 201, description = "Wave created and released", body = PickWaveDetail),
+        (status = 404, description = "Pick task not found", body = ApiError),
+        (status = 422, description = "No eligible open tasks", body = ApiError),
+    ),
+    security(("bearer" = [])),
+)]
+pub async fn post_pick_wave(
+    State(state): State<Arc<AppState>>,
+    claims: Option<Extension<VerifiedClaims>>,
+    tracing_ctx: Option<Extension<TracingContext>>,
+    Json(mut req): Json<CreatePickWaveRequest>,
+) -> impl IntoResponse {
+    let tenant_id = match extract_tenant(&claims) {
+        Ok(id) => id,
+        Err(e) => return with_request_id(e, &tracing_ctx).into_response(),
+    };
+    if let Err(e) = check_warehouse_scope(&claims, permissions::INVENTORY_MUTATE, req.warehouse_id)
+    {
+        return with_request_id(e, &tracing_ctx).into_response();
+    }
+    req.tenant_id = tenant_id;
+    match create_pick_wave(&state.pool, &req).await {
+        Ok(detail) => (StatusCode::CREATED, Json(detail)).into_response(),
+        Err(err) => {
+            let api_err: ApiError = err.into();
+            with_request_id(api_err, &tracing_ctx).into_response()
+        }
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/inventory/pick-waves/{wave_id}",
+    tag = "Picking",
+    params(("wave_id" = Uuid, Path, description = "Pick wave ID")),
+    responses(
+        (status = 200, description = "Wave with its tasks", body = PickWaveDetail),
+        (status = 404, description = "Pick wave not found", body = ApiError),
+    ),
+    security(("bearer" = [])),
+)]
+pub async fn get_pick_wave_handler(
+    Path(wave_id): Path<Uuid>,
+    State(state): State<Arc<AppState>>,
+    claims: Option<Extension<VerifiedClaims>>,
+    tracing_ctx: Option<Extension<TracingContext>>,
+) -> impl IntoResponse {
+    let tenant_id = match extract_tenant(&claims) {
+        Ok(id) => id,
+        Err(e) => return with_request_id(e, &tracing_ctx).into_response(),
+    };
+    match get_pick_wave(&state.pool, &tenant_id, wave_id).await {
+        Ok(detail) => {
+            if let Err(e) = check_warehouse_scope(
+                &claims,
+                permissions::INVENTORY_READ,
+                detail.wave.warehouse_id,
+            ) {
+                return with_request_id(e, &tracing_ctx).into_response();
+            }
+            (StatusCode::OK, Json(detail)).into_response()
+        }
+        Err(err) => {
+            let api_err: ApiError = err.into();
+            with_request_id(api_err, &tracing_ctx).into_response()
+        }
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/inventory/pick-waves/{wave_id}/pick-list",
+    tag = "Picking",
+    params(
+        ("wave_id" = Uuid, Path, description = "Pick wave ID"),
+        ("as_of" = Option<NaiveDate>, Query, description = "Expiry cut-off date (default today)"),
+    ),
+    responses(
+        (status = 200, description = "Pick list in walk order", body = PickList),
+        (status = 404, description = "Pick wave not found", body = ApiError),
+    ),
+    security(("bearer" = [])),
+)]
+pub async fn get_pick_list(
+    Path(wave_id): Path<Uuid>,
+    State(state): State<Arc<AppState>>,
+    claims: Option<Extension<VerifiedClaims>>,
+    Query(q): Query<PickListQuery>,
+    tracing_ctx: Option<Extension<TracingContext>>,
+) -> impl IntoResponse {
+    let tenant_id = match extract_tenant(&claims) {
+        Ok(id) => id,
+        Err(e) => return with_request_id(e, &tracing_ctx).into_response(),
+    };
+    let as_of = q.as_of.unwrap_or_else(|| Utc::now().date_naive());
+    match build_pick_list(&state.pool, &tenant_id, wave_id, as_of).await {
+        Ok(list) => {
+            if let Err(e) =
+                check_warehouse_scope(&claims, permissions::INVENTORY_READ, list.warehouse_id)
+            {
+                return with_request_id(e, &tracing_ctx).into_response();
+            }
+            (StatusCode::OK, Json(list)).into_response()
+        }
+        Err(err) => {
+            let api_err: ApiError = err.into();
+            with_request_id(api_err, &tracing_ctx).into_response()
+        }
+    }
+}
@@ -0,0 +1,491 @@
+//! Directed putaway HTTP handlers.
+//!
+//! Endpoints:
+//!   PUT  /api/inventory/warehouses/{warehouse_id}/putaway-config  — set receiving dock
+//!   GET  /api/inventory/warehouses/{warehouse_id}/putaway-config  — current config
+//!   PUT  /api/inventory/locations/{location_id}/putaway-profile   — bin zone/capacity/sequence
+//!   POST /api/inventory/putaway-rules                             — create a rule
+//!   GET  /api/inventory/putaway-rules                             — rules of a warehouse
+//!   PUT  /api/inventory/putaway-rules/{rule_id}                   — reprioritize / (de)activate
+//!   GET  /api/inventory/putaway-tasks                             — list tasks of a warehouse
+//!   GET  /api/inventory/putaway-tasks/{task_id}                   — one task
+//!   POST /api/inventory/putaway-tasks/{task_id}/confirm           — move stock dock → bin
+//!
+//! Tenant identity derived from JWT `VerifiedClaims`; every endpoint is
+//! limited to warehouses inside the caller's data scope.
+
+use axum::{
+    extract::{Path, Query, State},
+    http::StatusCode,
+    response::IntoResponse,
+    Extension, Json,
+};
+use event_bus::TracingContext;
+use platform_http_contracts::{ApiError, PaginatedResponse};
+use security::{permissions, VerifiedClaims};
+use serde::Deserialize;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use super::tenant::{check_warehouse_scope, with_request_id};
+use crate::{
+    domain::putaway::{
+        config_service,
+        confirm_service::confirm_putaway,
+        models::{
+            BinProfile, ConfirmPutawayRequest, ConfirmPutawayResult, CreatePutawayRuleRequest,
+            PutawayConfig, PutawayError, PutawayRule, PutawayTask, UpdatePutawayRuleRequest,
+            UpsertBinProfileRequest, UpsertPutawayConfigRequest,
+        },
+        repo::{self, PutawayTaskFilter},
+    },
+    AppState,
+};
+use platform_sdk::extract_tenant;
+
+// ============================================================================
+// Query params / bodies
+// ============================================================================
+
+#[derive(Debug, Deserialize)]
+pub struct ListPutawayRulesQuery {
+    pub warehouse_id: Uuid,
+    /// Include deactivated rules (default false)
+    #[serde(default)]
+    pub include_inactive: bool,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ListPutawayTasksQuery {
+    pub warehouse_id: Uuid,
+    pub status: Option<String>,
+    pub item_id: Option<Uuid>,
+    #[serde(default = "default_page")]
+    pub page: i64,
+    #[serde(default = "default_page_size")]
+    pub page_size: i64,
+}
+
+fn default_page() -> i64 {
+    1
+}
+fn default_page_size() -> i64 {
+    50
+}
+
+#[derive(Debug, Deserialize, utoipa::ToSchema)]
+pub struct ConfirmPutawayBody {
+    pub idempotency_key: String,
+    /// Bin put away to; defaults to the suggested bin
+    pub to_location_id: Option<Uuid>,
+    /// Scanned bin label (location code)
+    pub location_barcode: Option<String>,
+    pub confirmed_by: Option<String>,
+    pub correlation_id: Option<String>,
+    pub causation_id: Option<String>,
+}
+
+// ============================================================================
+// Configuration
+// ============================================================================
+
+#[utoipa::path(
+    put,
+    path = "/api/inventory/warehouses/{warehouse_id}/putaway-config",
+    tag = "Putaway",
+    params(("warehouse_id" = Uuid, Path, description = "Warehouse ID")),
+    request_body = UpsertPutawayConfigRequest,
+    responses(
+        (status = 200, description = "Putaway configuration saved", body = PutawayConfig),
+        (status = 422, description = "Dock location not found in the warehouse", body = ApiError),
+    ),
+    security(("bearer" = [])),
+)]
+pub async fn put_putaway_config(
+    Path(warehouse_id): Path<Uuid>,
+    State(state): State<Arc<AppState>>,
+    claims: Option<Extension<VerifiedClaims>>,
+    tracing_ctx: Option<Extension<TracingContext>>,
+    Json(req): Json<UpsertPutawayConfigRequest>,
+) -> impl IntoResponse {
+    let tenant_id = match extract_tenant(&claims) {
+        Ok(id) => id,
+        Err(e) => return with_request_id(e, &tracing_ctx).into_response(),
+    };
+    if let Err(e) = check_warehouse_scope(&claims, permissions::INVENTORY_MUTATE, warehouse_id) {
+        return with_request_id(e, &tracing_ctx).into_response();
+    }
+    match config_service::upsert_config(&state.pool, &tenant_id, warehouse_id, &req).await {
+        Ok(config) => (StatusCode::OK, Json(config)).into_response(),
+        Err(err) => {
+            let api_err: ApiError = err.into();
+            with_request_id(api_err, &tracing_ctx).into_response()
+        }
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/inventory/warehouses/{warehouse_id}/putaway-config",
+    tag = "Putaway",
+    params(("warehouse_id" = Uuid, Path, description = "Warehouse ID")),
+    responses(
+        (status = 200, description = "Putaway configuration", body = PutawayConfig),
+        (status = 404, description = "Directed putaway not configured", body = ApiError),
+    ),
+    security(("bearer" = [])),
+)]
+pub async fn get_putaway_config(
+    Path(warehouse_id): Path<Uuid>,
+    State(state): State<Arc<AppState>>,
+    claims: Option<Extension<VerifiedClaims>>,
+    tracing_ctx: Option<Extension<TracingContext>>,
+) -> impl IntoResponse {
+    let tenant_id = match extract_tenant(&claims) {
+        Ok(id) => id,
+        Err(e) => return with_request_id(e, &tracing_ctx).into_response(),
+    };
+    if let Err(e) = check_warehouse_scope(&claims, permissions::INVENTORY_READ, warehouse_id) {
+        return with_request_id(e, &tracing_ctx).into_response();
+    }
+    match repo::find_config(&state.pool, &tenant_id, warehouse_id).await {
+        Ok(Some(config)) => (StatusCode::OK, Json(config)).into_response(),
+        Ok(None) => with_request_id(
+            ApiError::not_found("Directed putaway is not configured for this warehouse"),
+            &tracing_ctx,
+        )
+        .into_response(),
+        Err(e) => {
+            let api_err: ApiError = PutawayError::from(e).into();
+            with_request_id(api_err, &tracing_ctx).into_response()
+        }
+    }
+}
+
+#[utoipa::path(
+    put,
+    path = "/api/inventory/locations/{location_id}/putaway-profile",
+    tag = "Putaway",
+    params(("location_id" = Uuid, Path, description = "Location ID")),
+    request_body = UpsertBinProfileRequest,
+    responses(
+        (status = 200, description = "Bin profile saved", body = BinProfile),
+        (status = 422, description = "Validation failure or unknown location", body = ApiError),
+    ),
+    security(("bearer" = [])),
+)]
+pub async fn put_bin_profile(
+    Path(location_id): Path<Uuid>,
+    State(state): State<Arc<AppState>>,
+    claims: Option<Extension<VerifiedClaims>>,
+    tracing_ctx: Option<Extension<TracingContext>>,
+    Json(req): Json<UpsertBinProfileRequest>,
+) -> impl IntoResponse {
+    let tenant_id = match extract_tenant(&claims) {
+        Ok(id) => id,
+        Err(e) => return with_request_id(e, &tracing_ctx).into_response(),
+    };
+    match repo::find_location(&state.pool, &tenant_id, location_id).await {
+        Ok(Some(location)) => {
+            if let Err(e) = check_warehouse_scope(
+                &claims,
+                permissions::INVENTORY_MUTATE,
+                location.warehouse_id,
+            ) {
+                return with_request_id(e, &tracing_ctx).into_response();
+            }
+        }
+        Ok(None) => {}
+        Err(e) => {
+            let api_err: ApiError = PutawayError::from(e).into();
+            return with_request_id(api_err, &tracing_ctx).into_response();
+        }
+    }
+    match config_service::upsert_bin_profile(&state.pool, &tenant_id, location_id, &req).await {
+        Ok(profile) => (StatusCode::OK, Json(profile)).into_response(),
+        Err(err) => {
+            let api_err: ApiError = err.into();
+            with_request_id(api_err, &tracing_ctx).into_response()
+        }
+    }
+}
+
+// ============================================================================
+// Rules
+// ============================================================================
+
+#[utoipa::path(
+    post,
+    path = "/api/inventory/putaway-rules",
+    tag = "Putaway",
+    request_body = CreatePutawayRuleRequest,
+    responses(
+        (status = 201, description = "Putaway rule created", body = PutawayRule),
+        (status = 404, description = "Item not found", body = ApiError),
+        (status = 422, description = "Missing strategy fields or unknown location", body = ApiError),
+    ),
+    security(("bearer" = [])),
+)]
+pub async fn post_putaway_rule(
+    State(state): State<Arc<AppState>>,
+    claims: Option<Extension<VerifiedClaims>>,
+    tracing_ctx: Option<Extension<TracingContext>>,
+    Json(req): Json<CreatePutawayRuleRequest>,
+) -> impl IntoResponse {
+    let tenant_id = match extract_tenant(&claims) {
+        Ok(id) => id,
+        Err(e) => return with_request_id(e, &tracing_ctx).into_response(),
+    };
+    if let Err(e) = check_warehouse_scope(&claims, permissions::INVENTORY_MUTATE, req.warehouse_id)
+    {
+        return with_request_id(e, &tracing_ctx).into_response();
+    }
+    match config_service::create_rule(&state.pool, &tenant_id, &req).await {
+        Ok(rule) => (StatusCode::CREATED, Json(rule)).into_response(),
+        Err(err) => {
+            let api_err: ApiError = err.into();
+            with_request_id(api_err, &tracing_ctx).into_response()
+        }
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/inventory/putaway-rules",
+    tag = "Putaway",
+    params(
+        ("warehouse_id" = Uuid, Query, description = "Warehouse ID"),
+        ("include_inactive" = Option<bool>, Query, description = "Include deactivated rules"),
+    ),
+    responses(
+        (status = 200, description = "Rules in evaluation order", body = Vec<PutawayRule>),
+    ),
+    security(("bearer" = [])),
+)]
+pub async fn list_putaway_rules(
+    State(state): State<Arc<AppState>>,
+    claims: Option<Extension<VerifiedClaims>>,
+    Query(q): Query<ListPutawayRulesQuery>,
+    tracing_ctx: Option<Extension<TracingContext>>,
+) -> impl IntoResponse {
+    let tenant_id = match extract_tenant(&claims) {
+        Ok(id) => id,
+        Err(e) => return with_request_id(e, &tracing_ctx).into_response(),
+    };
+    if let Err(e) = check_warehouse_scope(&claims, permissions::INVENTORY_READ, q.warehouse_id) {
+        return with_request_id(e, &tracing_ctx).into_response();
+    }
+    match repo::list_rules(&state.pool, &tenant_id, q.warehouse_id, !q.include_inactive).await {
+        Ok(rules) => (StatusCode::OK, Json(rules)).into_response(),
+        Err(e) => {
+            let api_err: ApiError = PutawayError::from(e).into();
+            with_request_id(api_err, &tracing_ctx).into_response()
+        }
+    }
+}
+
+#[utoipa::path(
+    put,
+    path = "/api/inventory/putaway-rules/{rule_id}",
+    tag = "Putaway",
+    params(("rule_id" = Uuid, Path, description = "Putaway rule ID")),
+    request_body = UpdatePutawayRuleRequest,
+    responses(
+        (status = 200, description = "Putaway rule updated", body = PutawayRule),
+        (status = 404, description = "Putaway rule not found", body = ApiError),
+    ),
+    security(("bearer" = [])),
+)]
+pub async fn put_putaway_rule(
+    Path(rule_id): Path<Uuid>,
+    State(state): State<Arc<AppState>>,
+    claims: Option<Extension<VerifiedClaims>>,
+    tracing_ctx: Option<Extension<TracingContext>>,
+    Json(req): Json<UpdatePutawayRuleRequest>,
+) -> impl IntoResponse {
+    let tenant_id = match extract_tenant(&claims) {
+        Ok(id) => id,
+        Err(e) => return with_request_id(e, &tracing_ctx).into_response(),
+    };
+    match repo::find_rule(&state.pool, &tenant_id, rule_id).await {
+        Ok(Some(rule)) => {
+            if let Err(e) =
+                check_warehouse_scope(&claims, permissions::INVENTORY_MUTATE, rule.warehouse_id)
+            {
+                return with_request_id(e, &tracing_ctx).into_response();
+            }
+        }
+        Ok(None) => {}
+        Err(e) => {
+            let api_err: ApiError = PutawayError::from(e).into();
+            return with_request_id(api_err, &tracing_ctx).into_response();
+        }
+    }
+    match config_service::update_rule(&state.pool, &tenant_id, rule_id, &req).await {
+        Ok(rule) => (StatusCode::OK, Json(rule)).into_response(),
+        Err(err) => {
+            let api_err: ApiError = err.into();
+            with_request_id(api_err, &tracing_ctx).into_response()
+        }
+    }
+}
+
+// ============================================================================
+// Tasks
+// ============================================================================
+
+#[utoipa::path(
+    get,
+    path = "/api/inventory/putaway-tasks",
+    tag = "Putaway",
+    params(
+        ("warehouse_id" = Uuid, Query, description = "Warehouse ID"),
+        ("status" = Option<String>, Query, description = "open | completed"),
+        ("item_id" = Option<Uuid>, Query, description = "Item ID"),
+    ),
+    responses(
+        (status = 200, description = "Paginated putaway tasks", body = PaginatedResponse<PutawayTask>),
+    ),
+    security(("bearer" = [])),
+)]
+pub async fn list_putaway_tasks(
+    State(state): State<Arc<AppState>>,
+    claims: Option<Extension<VerifiedClaims>>,
+    Query(q): Query<ListPutawayTasksQuery>,
+    tracing_ctx: Option<Extension<TracingContext>>,
+) -> impl IntoResponse {
+    let tenant_id = match extract_tenant(&claims) {
+        Ok(id) => id,
+        Err(e) => return with_request_id(e, &tracing_ctx).into_response(),
+    };
+    if let Err(e) = check_warehouse_scope(&claims, permissions::INVENTORY_READ, q.warehouse_id) {
+        return with_request_id(e, &tracing_ctx).into_response();
+    }
+    let page_size = q.page_size.clamp(1, 200);
+    let page = q.page.max(1);
+    let filter = PutawayTaskFilter {
+        warehouse_id: q.warehouse_id,
+        status: q.status.as_deref(),
+        item_id: q.item_id,
+    };
+    match repo::list_tasks(
+        &state.pool,
+        &tenant_id,
+        &filter,
+        page_size,
+        (page - 1) * page_size,
+    )
+    .await
+    {
+        Ok((tasks, total)) => {
+            let resp = PaginatedResponse::new(tasks, page, page_size, total);
+            (StatusCode::OK, Json(resp)).into_response()
+        }
+        Err(e) => {
+            let api_err: ApiError = PutawayError::from(e).into();
+            with_request_id(api_err, &tracing_ctx).into_response()
+        }
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/inventory/putaway-tasks/{task_id}",
+    tag = "Putaway",
+    params(("task_id" = Uuid, Path, description = "Putaway task ID")),
+    responses(
+        (status = 200, description = "Putaway task", body = PutawayTask),
+        (status = 404, description = "Putaway task not found", body = ApiError),
+    ),
+    security(("bearer" = [])),
+)]
+pub async fn get_putaway_task(
+    Path(task_id): Path<Uuid>,
+    State(state): State<Arc<AppState>>,
+    claims: Option<Extension<VerifiedClaims>>,
+    tracing_ctx: Option<Extension<TracingContext>>,
+) -> impl IntoResponse {
+    let tenant_id = match extract_tenant(&claims) {
+        Ok(id) => id,
+        Err(e) => return with_request_id(e, &tracing_ctx).into_response(),
+    };
+    let result = repo::find_task(&state.pool, &tenant_id, task_id)
+        .await
+        .map_err(PutawayError::from)
+        .and_then(|t| t.ok_or(PutawayError::TaskNotFound));
+    match result {
+        Ok(task) => {
+            if let Err(e) =
+                check_warehouse_scope(&claims, permissions::INVENTORY_READ, task.warehouse_id)
+            {
+                return with_request_id(e, &tracing_ctx).into_response();
+            }
+            (StatusCode::OK, Json(task)).into_response()
+        }
+        Err(err) => {
+            let api_err: ApiError = err.into();
+            with_request_id(api_err, &tracing_ctx).into_response()
+        }
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/inventory/putaway-tasks/{task_id}/confirm",
+    tag = "Putaway",
+    params(("task_id" = Uuid, Path, description = "Putaway task ID")),
+    request_body = ConfirmPutawayBody,
+    responses(
+        (status = 201, description = "Stock moved from the dock to the bin", body = ConfirmPutawayResult),
+        (status = 200, description = "Idempotency replay", body = ConfirmPutawayResult),
+        (status = 404, description = "Putaway task not found", body = ApiError),
+        (status = 409, description = "Task not open or idempotency key conflict", body = ApiError),
+        (status = 422, description = "Unknown bin, no target or stock no longer on the dock", body = ApiError),
+    ),
+    security(("bearer" = [])),
+)]
+pub async fn post_putaway_confirm(
+    Path(task_id): Path<Uuid>,
+    State(state): State<Arc<AppState>>,
+    claims: Option<Extension<VerifiedClaims>>,
+    tracing_ctx: Option<Extension<TracingContext>>,
+    Json(body): Json<ConfirmPutawayBody>,
+) -> impl IntoResponse {
+    let tracing_ctx_val = tracing_ctx.as_ref().map(|Extension(c)| c.clone());
+    let tenant_id = match extract_tenant(&claims) {
+        Ok(id) => id,
+        Err(e) => return with_request_id(e, &tracing_ctx).into_response(),
+    };
+    match repo::find_task(&state.pool, &tenant_id, task_id).await {
+        Ok(Some(task)) => {
+            if let Err(e) =
+                check_warehouse_scope(&claims, permissions::INVENTORY_MUTATE, task.warehouse_id)
+            {
+                return with_request_id(e, &tracing_ctx).into_response();
+            }
+        }
+        Ok(None) => {}
+        Err(e) => {
+            let api_err: ApiError = PutawayError::from(e).into();
+            return with_request_id(api_err, &tracing_ctx).into_response();
+        }
+    }
+    let req = ConfirmPutawayRequest {
+        tenant_id,
+        task_id,
+        to_location_id: body.to_location_id,
+        location_barcode: body.location_barcode,
+        confirmed_by: body.confirmed_by,
+        idempotency_key: body.idempotency_key,
+        correlation_id: body.correlation_id,
+        causation_id: body.causation_id,
+    };
+    match confirm_putaway(&state.pool, &req, tracing_ctx_val.as_ref()).await {
+        Ok((result, false)) => (StatusCode::CREATED, Json(result)).into_response(),
+        Ok((result, true)) => (StatusCode::OK, Json(result)).into_response(),
+        Err(err) => {
+            let api_err: ApiError = err.into();
+            with_request_id(api_err, &tracing_ctx).into_response()
+        }
+    }
+}
@@ -5,6 +5,8 @@
 //!   POST /api/inventory/reservations/release          — compensating release
 //!   POST /api/inventory/reservations/{id}/fulfill     — fulfill reservation
 //!
+//! The reservation's warehouse must be inside the caller's data scope.
+//!
 //! Idempotency:
 //!   Callers MUST supply `idempotency_key` in the request body.
 //!   Duplicate keys with the same body return 200 OK with the stored result.
@@ -18,15 +20,16 @@ use axum::{
 };
 use event_bus::TracingContext;
 use platform_http_contracts::ApiError;
-use security::VerifiedClaims;
+use security::{permissions, VerifiedClaims};
 use serde_json::json;
 use std::sync::Arc;
 use uuid::Uuid;
 
-use super::tenant::with_request_id;
+use super::tenant::{check_warehouse_scope, with_request_id};
 use crate::{
     domain::{
         fulfill_service::{process_fulfill, FulfillRequest, FulfillResult},
+        reservation_repo::find_active_reservation,
         reservation_service::{
             process_release, process_reserve, ReleaseRequest, ReleaseResult, ReserveRequest,
             ReserveResult,
@@ -36,6 +39,27 @@ use crate::{
 };
 use platform_sdk::extract_tenant;
 
+/// Deny mutating a reservation held in a warehouse outside the caller's scope.
+///
+/// Unknown ids pass through so the service answers 404 as before.
+async fn check_reservation_scope(
+    state: &AppState,
+    claims: &Option<Extension<VerifiedClaims>>,
+    tenant_id: &str,
+    reservation_id: Uuid,
+) -> Result<(), ApiError> {
+    let reservation = find_active_reservation(&state.pool, reservation_id, tenant_id)
+        .await
+        .map_err(|e| {
+            tracing::error!(error = %e, reservation_id = %reservation_id, "database error loading reservation");
+            ApiError::internal("Database error")
+        })?;
+    match reservation {
+        Some(r) => check_warehouse_scope(claims, permissions::INVENTORY_MUTATE, r.warehouse_id),
+        None => Ok(()),
+    }
+}
+
 #[utoipa::path(
     post,
     path = "/api/inventory/reservations/reserve",
@@ -59,6 +83,10 @@ pub async fn post_reserve(
         Ok(id) => id,
         Err(e) => return with_request_id(e, &tracing_ctx).into_response(),
     };
+    if let Err(e) = check_warehouse_scope(&claims, permissions::INVENTORY_MUTATE, req.warehouse_id)
+    {
+        return with_request_id(e, &tracing_ctx).into_response();
+    }
     req.tenant_id = tenant_id;
     match process_reserve(&state.pool, &req).await {
         Ok((result, is_replay)) => {
@@ -98,6 +126,9 @@ pub async fn post_release(
         Ok(id) => id,
         Err(e) => return with_request_id(e, &tracing_ctx).into_response(),
     };
+    if let Err(e) = check_reservation_scope(&state, &claims, &tenant_id, req.reservation_id).await {
+        return with_request_id(e, &tracing_ctx).into_response();
+    }
     req.tenant_id = tenant_id;
     match process_release(&state.pool, &req).await {
         Ok((result, _)) => (StatusCode::OK, Json(json!(result))).into_response(),
@@ -132,6 +163,9 @@ pub async fn post_fulfill(
         Ok(id) => id,
         Err(e) => return with_request_id(e, &tracing_ctx).into_response(),
     };
+    if let Err(e) = check_reservation_scope(&state, &claims, &tenant_id, reservation_id).await {
+        return with_request_id(e, &tracing_ctx).into_response();
+    }
     req.tenant_id = tenant_id;
     req.reservation_id = reservation_id;
     match process_fulfill(&state.pool, &req).await {
@@ -4,6 +4,8 @@
 //!   POST /api/inventory/status-transfers
 //!     — move quantity between status buckets (available/quarantine/damaged)
 //!
+//! The warehouse must be inside the caller's data scope.
+//!
 //! Idempotency:
 //!   Callers MUST supply `idempotency_key` in the request body.
 //!   Duplicate keys with the same body return 200 OK with the stored result.
@@ -12,11 +14,11 @@
 use axum::{extract::State, http::StatusCode, response::IntoResponse, Extension, Json};
 use event_bus::TracingContext;
 use platform_http_contracts::ApiError;
-use security::VerifiedClaims;
+use security::{permissions, VerifiedClaims};
 use serde_json::json;
 use std::sync::Arc;
 
-use super::tenant::with_request_id;
+use super::tenant::{check_warehouse_scope, with_request_id};
 use crate::{
     domain::status::transfer_service::{
         process_status_transfer, StatusTransferRequest, StatusTransferResult,
@@ -48,6 +50,10 @@ pub async fn post_status_transfer(
         Ok(id) => id,
         Err(e) => return with_request_id(e, &tracing_ctx).into_response(),
     };
+    if let Err(e) = check_warehouse_scope(&claims, permissions::INVENTORY_MUTATE, req.warehouse_id)
+    {
+        return with_request_id(e, &tracing_ctx).into_response();
+    }
     req.tenant_id = tenant_id;
     match process_status_transfer(&state.pool, &req).await {
         Ok((result, false)) => (StatusCode::CREATED, Json(json!(result))).into_response(),
@@ -1,6 +1,8 @@
 use axum::Extension;
 use event_bus::TracingContext;
 use platform_http_contracts::ApiError;
+use security::{check_scope, VerifiedClaims, SCOPE_LOCATION};
+use uuid::Uuid;
 
 /// Enrich an `ApiError` with the `request_id` from `TracingContext`.
 pub fn with_request_id(err: ApiError, ctx: &Option<Extension<TracingContext>>) -> ApiError {
@@ -15,3 +17,19 @@ pub fn with_request_id(err: ApiError, ctx: &Option<Extension<TracingContext>>) -
         None => err,
     }
 }
+
+/// Deny `perm` on a warehouse outside the caller's location scope.
+///
+/// Warehouses are the `location` attribute of inventory data scopes.
+pub fn check_warehouse_scope(
+    claims: &Option<Extension<VerifiedClaims>>,
+    perm: &str,
+    warehouse_id: Uuid,
+) -> Result<(), ApiError> {
+    let Some(Extension(claims)) = claims else {
+        return Err(ApiError::unauthorized("Missing or invalid authentication"));
+    };
+    let warehouse = warehouse_id.to_string();
+    check_scope(claims, perm, &[(SCOPE_LOCATION, &warehouse)])
+        .map_err(|_| ApiError::forbidden("Warehouse is outside your data scope"))
+}
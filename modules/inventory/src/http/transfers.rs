@@ -3,7 +3,8 @@
 //! Endpoint:
 //!   POST /api/inventory/transfers — move stock between warehouses (paired ledger entries)
 //!
-//! Tenant identity derived from JWT `VerifiedClaims`.
+//! Tenant identity derived from JWT `VerifiedClaims`; both warehouses must be
+//! inside the caller's data scope.
 //!
 //! Idempotency:
 //!   Callers MUST supply `idempotency_key` in the request body.
@@ -13,10 +14,10 @@
 use axum::{extract::State, http::StatusCode, response::IntoResponse, Extension, Json};
 use event_bus::TracingContext;
 use platform_http_contracts::ApiError;
-use security::VerifiedClaims;
+use security::{permissions, VerifiedClaims};
 use std::sync::Arc;
 
-use super::tenant::with_request_id;
+use super::tenant::{check_warehouse_scope, with_request_id};
 use crate::{
     domain::transfer_service::{process_transfer, TransferRequest, TransferResult},
     AppState,
@@ -51,6 +52,18 @@ pub async fn post_transfer(
         Ok(id) => id,
         Err(e) => return with_request_id(e, &tracing_ctx).into_response(),
     };
+    if let Err(e) = check_warehouse_scope(
+        &claims,
+        permissions::INVENTORY_MUTATE,
+        req.from_warehouse_id,
+    ) {
+        return with_request_id(e, &tracing_ctx).into_response();
+    }
+    if let Err(e) =
+        check_warehouse_scope(&claims, permissions::INVENTORY_MUTATE, req.to_warehouse_id)
+    {
+        return with_request_id(e, &tracing_ctx).into_response();
+    }
     req.tenant_id = tenant_id;
     match process_transfer(&state.pool, &req, tracing_ctx_val.as_ref()).await {
         Ok((result, is_replay)) => {
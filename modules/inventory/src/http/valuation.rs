@@ -10,7 +10,8 @@
 //!   GET /api/inventory/valuation-snapshots/{id}
 //!     — snapshot header + per-item lines
 //!
-//! Tenant derived from JWT VerifiedClaims.
+//! Tenant derived from JWT VerifiedClaims. Warehouse access honours the
+//! caller's `location` data scope.
 
 use axum::{
     extract::{Path, Query, State},
@@ -20,17 +21,17 @@ use axum::{
 };
 use event_bus::TracingContext;
 use platform_http_contracts::{ApiError, PaginatedResponse};
-use security::VerifiedClaims;
+use security::{permissions, ScopeFilter, VerifiedClaims, SCOPE_LOCATION};
 use serde::Deserialize;
 use serde_json::json;
 use std::sync::Arc;
 use uuid::Uuid;
 
-use super::tenant::with_request_id;
+use super::tenant::{check_warehouse_scope, with_request_id};
 use crate::{
     domain::valuation::{
         models::ValuationSnapshot,
-        queries::{get_snapshot, get_snapshot_lines, list_snapshots},
+        queries::{get_snapshot, get_snapshot_lines, list_snapshots_scoped},
         snapshot_service::CreateSnapshotRequest,
     },
     AppState,
@@ -83,6 +84,10 @@ pub async fn post_valuation_snapshot(
         Ok(id) => id,
         Err(e) => return with_request_id(e, &tracing_ctx).into_response(),
     };
+    if let Err(e) = check_warehouse_scope(&claims, permissions::INVENTORY_MUTATE, req.warehouse_id)
+    {
+        return with_request_id(e, &tracing_ctx).into_response();
+    }
     req.tenant_id = tenant_id;
     match crate::domain::valuation::snapshot_service::create_valuation_snapshot(&state.pool, &req)
         .await
@@ -125,7 +130,34 @@ pub async fn list_valuation_snapshots(
     let page = q.page.max(1);
     let offset = (page - 1) * page_size;
 
-    match list_snapshots(&state.pool, &tenant_id, q.warehouse_id, page_size, offset).await {
+    if let Some(wh) = q.warehouse_id {
+        if let Err(e) = check_warehouse_scope(&claims, permissions::INVENTORY_READ, wh) {
+            return with_request_id(e, &tracing_ctx).into_response();
+        }
+    }
+    let scope = match &claims {
+        Some(Extension(c)) => ScopeFilter::new(c, permissions::INVENTORY_READ)
+            .column(SCOPE_LOCATION, "warehouse_id")
+            .to_sql(5),
+        None => {
+            return with_request_id(
+                ApiError::unauthorized("Missing or invalid authentication"),
+                &tracing_ctx,
+            )
+            .into_response()
+        }
+    };
+
+    match list_snapshots_scoped(
+        &state.pool,
+        &tenant_id,
+        q.warehouse_id,
+        page_size,
+        offset,
+        &scope,
+    )
+    .await
+    {
         Ok(snapshots) => {
             let count = snapshots.len() as i64;
             // Use count as total_items since the query returns a windowed result;
@@ -180,6 +212,12 @@ pub async fn get_valuation_snapshot(
         }
     };
 
+    if let Err(e) =
+        check_warehouse_scope(&claims, permissions::INVENTORY_READ, snapshot.warehouse_id)
+    {
+        return with_request_id(e, &tracing_ctx).into_response();
+    }
+
     let lines = match get_snapshot_lines(&state.pool, id).await {
         Ok(l) => l,
         Err(e) => {
@@ -13,11 +13,22 @@ use inventory_rs::http::batch_receipts::{
     BatchReceiptItemResult, BatchReceiptRequest, BatchReceiptResponse,
 };
 use inventory_rs::http::cycle_counts::{ApproveBody, SubmitBody};
+use inventory_rs::http::picking::ConfirmPickBody;
+use inventory_rs::http::putaway::ConfirmPutawayBody;
 use inventory_rs::{
-    consumers::{component_issue_consumer, fg_receipt_consumer},
+    consumers::{
+        component_issue_consumer, fg_receipt_consumer, pick_demand_consumer, quality_lot_consumer,
+    },
     domain::{
         adjust_service::{AdjustRequest, AdjustResult},
         cycle_count::{
+            abc::AbcClass,
+            program::{
+                AbcRun, AbcRunDetail, AbcRunLine, AccuracyBucket, AccuracyReport,
+                CountScheduleEntry, CycleCountProgram, GenerateCountTasksRequest,
+                GenerateCountTasksResult, RunAbcRequest, UpsertCycleCountProgramRequest,
+            },
+            scheduler::run_scheduler_task,
             submit_service::SubmitLineInput,
             task_service::{CreateTaskRequest, CreateTaskResult, TaskLine, TaskScope},
         },
@@ -30,10 +41,26 @@ use inventory_rs::{
         history::query::MovementEntry,
         issue_service::{IssueRequest, IssueResult},
         items::{CreateItemRequest, Item, TrackingMode, UpdateItemRequest},
+        label_printing::models::{
+            AllocateSsccRequest, AllocateSsccResult, CreateLabelTemplateRequest, LabelTemplate,
+            RenderTemplateRequest, SsccConfig, SsccSerial, UpdateLabelTemplateRequest,
+            UpsertSsccConfigRequest,
+        },
         labels::{GenerateLabelRequest, Label},
         locations::{CreateLocationRequest, Location, UpdateLocationRequest},
         lots_serials::models::InventoryLot,
         make_buy::SetMakeBuyRequest,
+        picking::models::{
+            ConfirmPickResult, CreatePickTasksRequest, CreatePickTasksResult,
+            CreatePickWaveRequest, PickConfirmation, PickDemandLine, PickLineInput, PickList,
+            PickListLine, PickShortage, PickSourceType, PickTask, PickTaskDetail, PickWave,
+            PickWaveDetail, ShortAction,
+        },
+        putaway::models::{
+            BinProfile, ConfirmPutawayResult, CreatePutawayRuleRequest, PutawayConfig, PutawayRule,
+            PutawayStrategy, PutawayTask, UpdatePutawayRuleRequest, UpsertBinProfileRequest,
+            UpsertPutawayConfigRequest,
+        },
         receipt_service::{ReceiptRequest, ReceiptResult},
         reorder::models::{CreateReorderPolicyRequest, ReorderPolicy, UpdateReorderPolicyRequest},
         reservation_service::{ReleaseRequest, ReleaseResult, ReserveRequest, ReserveResult},
@@ -45,6 +72,11 @@ use inventory_rs::{
         transfer_service::{TransferRequest, TransferResult},
         uom::models::{CreateConversionRequest, CreateUomRequest, ItemUomConversion, Uom},
         valuation::{
+            landed_cost::models::{
+                AllocationBasis, CreateLandedCostVoucherRequest, LandedCostAllocation,
+                LandedCostCharge, LandedCostChargeInput, LandedCostReceiptInput, LandedCostType,
+                LandedCostVoucher, LandedCostVoucherDetail,
+            },
             models::{ValuationLine, ValuationSnapshot},
             snapshot_service::CreateSnapshotRequest,
         },
@@ -70,12 +102,34 @@ use inventory_rs::{
             post_resolve_barcode, post_resolve_barcode_batch, post_test_barcode_rule,
             put_update_barcode_rule,
         },
+        cycle_count_program::{
+            get_abc_run_handler, get_cycle_count_accuracy, get_cycle_count_program,
+            list_cycle_count_schedule, post_abc_run, post_generate_count_tasks,
+            put_cycle_count_program,
+        },
         cycle_counts::{post_cycle_count_approve, post_cycle_count_submit, post_cycle_count_task},
         expiry::{post_expiry_alert_scan, put_lot_expiry},
         genealogy::{get_lot_children, get_lot_parents, post_lot_merge, post_lot_split},
         history::get_movement_history,
+        label_printing::{
+            get_label_template, get_render_label, get_sscc, get_sscc_config, list_label_templates,
+            post_allocate_sscc, post_label_template, post_render_label_template,
+            put_label_template, put_sscc_config,
+        },
         labels::{get_label_by_id, get_list_labels, post_generate_label},
+        landed_cost::{
+            get_landed_cost_voucher, list_landed_cost_vouchers, post_landed_cost_voucher,
+        },
         lots::get_lots_for_item,
+        picking::{
+            get_pick_list, get_pick_task, get_pick_wave_handler, list_pick_tasks,
+            post_pick_confirm, post_pick_tasks, post_pick_wave,
+        },
+        putaway::{
+            get_putaway_config, get_putaway_task, list_putaway_rules, list_putaway_tasks,
+            post_putaway_confirm, post_putaway_rule, put_bin_profile, put_putaway_config,
+            put_putaway_rule,
+        },
         reorder::{
             get_reorder_policy, list_reorder_policies, post_reorder_policy, put_reorder_policy,
         },
@@ -137,6 +191,13 @@ use security::{permissions, RequirePermissionsLayer};
         inventory_rs::http::cycle_counts::post_cycle_count_task,
         inventory_rs::http::cycle_counts::post_cycle_count_submit,
         inventory_rs::http::cycle_counts::post_cycle_count_approve,
+        inventory_rs::http::cycle_count_program::put_cycle_count_program,
+        inventory_rs::http::cycle_count_program::get_cycle_count_program,
+        inventory_rs::http::cycle_count_program::post_generate_count_tasks,
+        inventory_rs::http::cycle_count_program::list_cycle_count_schedule,
+        inventory_rs::http::cycle_count_program::post_abc_run,
+        inventory_rs::http::cycle_count_program::get_abc_run_handler,
+        inventory_rs::http::cycle_count_program::get_cycle_count_accuracy,
         inventory_rs::http::reorder::post_reorder_policy,
         inventory_rs::http::reorder::get_reorder_policy,
         inventory_rs::http::reorder::put_reorder_policy,
@@ -144,6 +205,9 @@ use security::{permissions, RequirePermissionsLayer};
         inventory_rs::http::valuation::post_valuation_snapshot,
         inventory_rs::http::valuation::list_valuation_snapshots,
         inventory_rs::http::valuation::get_valuation_snapshot,
+        inventory_rs::http::landed_cost::post_landed_cost_voucher,
+        inventory_rs::http::landed_cost::list_landed_cost_vouchers,
+        inventory_rs::http::landed_cost::get_landed_cost_voucher,
         inventory_rs::http::revisions::post_create_revision,
         inventory_rs::http::revisions::post_activate_revision,
         inventory_rs::http::revisions::put_revision_policy,
@@ -152,6 +216,16 @@ use security::{permissions, RequirePermissionsLayer};
         inventory_rs::http::labels::post_generate_label,
         inventory_rs::http::labels::get_list_labels,
         inventory_rs::http::labels::get_label_by_id,
+        inventory_rs::http::label_printing::post_label_template,
+        inventory_rs::http::label_printing::list_label_templates,
+        inventory_rs::http::label_printing::get_label_template,
+        inventory_rs::http::label_printing::put_label_template,
+        inventory_rs::http::label_printing::post_render_label_template,
+        inventory_rs::http::label_printing::get_render_label,
+        inventory_rs::http::label_printing::put_sscc_config,
+        inventory_rs::http::label_printing::get_sscc_config,
+        inventory_rs::http::label_printing::post_allocate_sscc,
+        inventory_rs::http::label_printing::get_sscc,
         inventory_rs::http::expiry::put_lot_expiry,
         inventory_rs::http::expiry::post_expiry_alert_scan,
         inventory_rs::http::genealogy::post_lot_split,
@@ -175,6 +249,22 @@ use security::{permissions, RequirePermissionsLayer};
         inventory_rs::http::barcode::post_resolve_barcode,
         inventory_rs::http::barcode::post_resolve_barcode_batch,
         inventory_rs::http::barcode::post_test_barcode_rule,
+        inventory_rs::http::picking::post_pick_tasks,
+        inventory_rs::http::picking::list_pick_tasks,
+        inventory_rs::http::picking::get_pick_task,
+        inventory_rs::http::picking::post_pick_confirm,
+        inventory_rs::http::picking::post_pick_wave,
+        inventory_rs::http::picking::get_pick_wave_handler,
+        inventory_rs::http::picking::get_pick_list,
+        inventory_rs::http::putaway::put_putaway_config,
+        inventory_rs::http::putaway::get_putaway_config,
+        inventory_rs::http::putaway::put_bin_profile,
+        inventory_rs::http::putaway::post_putaway_rule,
+        inventory_rs::http::putaway::list_putaway_rules,
+        inventory_rs::http::putaway::put_putaway_rule,
+        inventory_rs::http::putaway::list_putaway_tasks,
+        inventory_rs::http::putaway::get_putaway_task,
+        inventory_rs::http::putaway::post_putaway_confirm,
     ),
     components(schemas(
         Item, TrackingMode, CreateItemRequest, UpdateItemRequest, ListItemsQuery,
@@ -189,11 +279,23 @@ use security::{permissions, RequirePermissionsLayer};
         Location, CreateLocationRequest, UpdateLocationRequest,
         CreateTaskRequest, CreateTaskResult, TaskScope, TaskLine,
         SubmitBody, SubmitLineInput, ApproveBody,
+        CycleCountProgram, UpsertCycleCountProgramRequest, AbcClass, AbcRun, AbcRunLine,
+        AbcRunDetail, RunAbcRequest, CountScheduleEntry, GenerateCountTasksRequest,
+        GenerateCountTasksResult, AccuracyBucket, AccuracyReport,
         ReorderPolicy, CreateReorderPolicyRequest, UpdateReorderPolicyRequest,
         ValuationSnapshot, ValuationLine, CreateSnapshotRequest,
+        AllocationBasis, LandedCostType, LandedCostChargeInput, LandedCostReceiptInput,
+        CreateLandedCostVoucherRequest, LandedCostVoucher, LandedCostCharge,
+        LandedCostAllocation, LandedCostVoucherDetail,
         ItemRevision, CreateRevisionRequest, ActivateRevisionRequest,
         UpdateRevisionPolicyRequest,
         Label, GenerateLabelRequest,
+        LabelTemplate, CreateLabelTemplateRequest, UpdateLabelTemplateRequest,
+        RenderTemplateRequest, SsccConfig, SsccSerial, UpsertSsccConfigRequest,
+        AllocateSsccRequest, AllocateSsccResult,
+        label_render::LabelLayout, label_render::LabelElement, label_render::TextElement,
+        label_render::BarcodeElement, label_render::BoxElement, label_render::Symbology,
+        label_render::Gs1Element, label_render::OutputFormat,
         LotExpiryRecord, SetLotExpiryRequest, RunExpiryAlertScanRequest,
         RunExpiryAlertScanResult,
         LotSplitRequest, LotMergeRequest, SplitChild, MergeParent,
@@ -204,10 +306,19 @@ use security::{permissions, RequirePermissionsLayer};
         BatchReceiptRequest, BatchReceiptResponse, BatchReceiptItemResult,
         BarcodeFormatRule, CreateRuleRequest, UpdateRuleRequest,
         ResolveRequest, BatchResolveRequest, TestRuleRequest,
+        PickTask, PickConfirmation, PickWave, PickTaskDetail, PickWaveDetail,
+        PickSourceType, ShortAction, CreatePickTasksRequest, PickDemandLine,
+        CreatePickTasksResult, CreatePickWaveRequest, PickList, PickListLine, PickShortage,
+        ConfirmPickBody, PickLineInput, ConfirmPickResult,
+        PutawayConfig, BinProfile, PutawayRule, PutawayStrategy, PutawayTask,
+        UpsertPutawayConfigRequest, UpsertBinProfileRequest, CreatePutawayRuleRequest,
+        UpdatePutawayRuleRequest, ConfirmPutawayBody, ConfirmPutawayResult,
         ApiError, PaginatedResponse<Item>, PaginatedResponse<Location>,
         PaginatedResponse<Label>, PaginatedResponse<InventoryLot>,
         PaginatedResponse<ReorderPolicy>, PaginatedResponse<ItemRevision>,
-        PaginatedResponse<ValuationSnapshot>, PaginationMeta,
+        PaginatedResponse<ValuationSnapshot>, PaginatedResponse<PickTask>,
+        PaginatedResponse<PutawayTask>, PaginatedResponse<LandedCostVoucher>,
+        PaginatedResponse<CountScheduleEntry>, PaginationMeta,
     )),
     security(
         ("bearer" = [])
@@ -260,9 +371,29 @@ async fn main() {
             bus_health.mark_connected(0);
 
             // Start consumers using SDK's bus
-            component_issue_consumer::start_component_issue_consumer(bus.clone(), pool.clone())
-                .await;
+            // Production component demand is issued directly unless
+            // INVENTORY_COMPONENT_PICKING=true turns it into pick tasks.
+            let component_picking = std::env::var("INVENTORY_COMPONENT_PICKING")
+                .map(|v| v == "true")
+                .unwrap_or(false);
+            if component_picking {
+                pick_demand_consumer::start_component_pick_consumer(bus.clone(), pool.clone())
+                    .await;
+            } else {
+                component_issue_consumer::start_component_issue_consumer(bus.clone(), pool.clone())
+                    .await;
+            }
+            pick_demand_consumer::start_so_release_pick_consumer(bus.clone(), pool.clone()).await;
             fg_receipt_consumer::start_fg_receipt_consumer(bus.clone(), pool.clone()).await;
+            quality_lot_consumer::start_quality_lot_consumer(bus.clone(), pool.clone()).await;
+
+            // Cycle count program: ABC reclassification and due count tasks
+            let cycle_count_interval_secs =
+                std::env::var("INVENTORY_CYCLE_COUNT_SCHED_INTERVAL_SECS")
+                    .ok()
+                    .and_then(|v| v.parse::<u64>().ok())
+                    .unwrap_or(3600);
+            tokio::spawn(run_scheduler_task(pool.clone(), cycle_count_interval_secs));
 
             let admin_pool = pool.clone();
             let app_state = Arc::new(AppState {
@@ -342,6 +473,19 @@ async fn main() {
                     "/api/inventory/valuation-snapshots",
                     axum::routing::post(post_valuation_snapshot),
                 )
+                .route(
+                    "/api/inventory/landed-cost-vouchers",
+                    axum::routing::post(post_landed_cost_voucher),
+                )
+                .route(
+                    "/api/inventory/cycle-count-program",
+                    axum::routing::put(put_cycle_count_program),
+                )
+                .route(
+                    "/api/inventory/cycle-count-program/generate",
+                    axum::routing::post(post_generate_count_tasks),
+                )
+                .route("/api/inventory/abc-runs", axum::routing::post(post_abc_run))
                 .route(
                     "/api/inventory/items/{item_id}/revisions",
                     axum::routing::post(post_create_revision),
@@ -358,6 +502,22 @@ async fn main() {
                     "/api/inventory/items/{item_id}/labels",
                     axum::routing::post(post_generate_label),
                 )
+                .route(
+                    "/api/inventory/label-templates",
+                    axum::routing::post(post_label_template),
+                )
+                .route(
+                    "/api/inventory/label-templates/{template_id}",
+                    axum::routing::put(put_label_template),
+                )
+                .route(
+                    "/api/inventory/sscc-config",
+                    axum::routing::put(put_sscc_config),
+                )
+                .route(
+                    "/api/inventory/sscc/allocate",
+                    axum::routing::post(post_allocate_sscc),
+                )
                 .route(
                     "/api/inventory/lots/{lot_id}/expiry",
                     axum::routing::put(put_lot_expiry),
@@ -410,6 +570,38 @@ async fn main() {
                     "/api/inventory/barcode/rules/test",
                     axum::routing::post(post_test_barcode_rule),
                 )
+                .route(
+                    "/api/inventory/pick-tasks",
+                    axum::routing::post(post_pick_tasks),
+                )
+                .route(
+                    "/api/inventory/pick-tasks/{task_id}/confirm",
+                    axum::routing::post(post_pick_confirm),
+                )
+                .route(
+                    "/api/inventory/pick-waves",
+                    axum::routing::post(post_pick_wave),
+                )
+                .route(
+                    "/api/inventory/warehouses/{warehouse_id}/putaway-config",
+                    axum::routing::put(put_putaway_config),
+                )
+                .route(
+                    "/api/inventory/locations/{location_id}/putaway-profile",
+                    axum::routing::put(put_bin_profile),
+                )
+                .route(
+                    "/api/inventory/putaway-rules",
+                    axum::routing::post(post_putaway_rule),
+                )
+                .route(
+                    "/api/inventory/putaway-rules/{rule_id}",
+                    axum::routing::put(put_putaway_rule),
+                )
+                .route(
+                    "/api/inventory/putaway-tasks/{task_id}/confirm",
+                    axum::routing::post(post_putaway_confirm),
+                )
                 .route_layer(RequirePermissionsLayer::new(&[
                     permissions::INVENTORY_MUTATE,
                 ]))
@@ -459,6 +651,28 @@ async fn main() {
                     "/api/inventory/labels/{label_id}",
                     axum::routing::get(get_label_by_id),
                 )
+                .route(
+                    "/api/inventory/labels/{label_id}/render",
+                    axum::routing::get(get_render_label),
+                )
+                .route(
+                    "/api/inventory/label-templates",
+                    axum::routing::get(list_label_templates),
+                )
+                .route(
+                    "/api/inventory/label-templates/{template_id}",
+                    axum::routing::get(get_label_template),
+                )
+                // Rendering writes nothing; read permission is enough
+                .route(
+                    "/api/inventory/label-templates/{template_id}/render",
+                    axum::routing::post(post_render_label_template),
+                )
+                .route(
+                    "/api/inventory/sscc-config",
+                    axum::routing::get(get_sscc_config),
+                )
+                .route("/api/inventory/sscc/{sscc}", axum::routing::get(get_sscc))
                 .route(
                     "/api/inventory/reorder-policies/{id}",
                     axum::routing::get(get_reorder_policy),
@@ -475,6 +689,30 @@ async fn main() {
                     "/api/inventory/valuation-snapshots/{id}",
                     axum::routing::get(get_valuation_snapshot),
                 )
+                .route(
+                    "/api/inventory/landed-cost-vouchers",
+                    axum::routing::get(list_landed_cost_vouchers),
+                )
+                .route(
+                    "/api/inventory/landed-cost-vouchers/{voucher_id}",
+                    axum::routing::get(get_landed_cost_voucher),
+                )
+                .route(
+                    "/api/inventory/cycle-count-program",
+                    axum::routing::get(get_cycle_count_program),
+                )
+                .route(
+                    "/api/inventory/cycle-count-schedule",
+                    axum::routing::get(list_cycle_count_schedule),
+                )
+                .route(
+                    "/api/inventory/abc-runs/{run_id}",
+                    axum::routing::get(get_abc_run_handler),
+                )
+                .route(
+                    "/api/inventory/cycle-count-accuracy",
+                    axum::routing::get(get_cycle_count_accuracy),
+                )
                 .route(
                     "/api/inventory/lots/{lot_id}/children",
                     axum::routing::get(get_lot_children),
@@ -495,6 +733,38 @@ async fn main() {
                     "/api/inventory/barcode/rules",
                     axum::routing::get(get_list_barcode_rules),
                 )
+                .route(
+                    "/api/inventory/pick-tasks",
+                    axum::routing::get(list_pick_tasks),
+                )
+                .route(
+                    "/api/inventory/pick-tasks/{task_id}",
+                    axum::routing::get(get_pick_task),
+                )
+                .route(
+                    "/api/inventory/pick-waves/{wave_id}",
+                    axum::routing::get(get_pick_wave_handler),
+                )
+                .route(
+                    "/api/inventory/pick-waves/{wave_id}/pick-list",
+                    axum::routing::get(get_pick_list),
+                )
+                .route(
+                    "/api/inventory/warehouses/{warehouse_id}/putaway-config",
+                    axum::routing::get(get_putaway_config),
+                )
+                .route(
+                    "/api/inventory/putaway-rules",
+                    axum::routing::get(list_putaway_rules),
+                )
+                .route(
+                    "/api/inventory/putaway-tasks",
+                    axum::routing::get(list_putaway_tasks),
+                )
+                .route(
+                    "/api/inventory/putaway-tasks/{task_id}",
+                    axum::routing::get(get_putaway_task),
+                )
                 .route_layer(RequirePermissionsLayer::new(&[permissions::INVENTORY_READ]))
                 .with_state(app_state.clone());
 
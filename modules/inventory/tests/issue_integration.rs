@@ -12,11 +12,13 @@
 //! 6. Guard: zero quantity rejected (stateless)
 //! 7. consumed_layers carried in outbox event payload
 //! 8. source_ref carried in outbox event payload
+//! 9. Issue against a reservation consumes the hold atomically
 
 use inventory_rs::domain::{
     issue_service::{process_issue, IssueError, IssueRequest},
     items::{CreateItemRequest, ItemRepo, TrackingMode},
     receipt_service::{process_receipt, ReceiptRequest},
+    reservation_service::{process_reserve, ReserveRequest},
 };
 use serial_test::serial;
 use sqlx::postgres::PgPoolOptions;
@@ -113,6 +115,7 @@ fn make_issue_req(
         uom_id: None,
         lot_code: None,
         serial_codes: None,
+        reservation_id: None,
     }
 }
 
@@ -660,3 +663,129 @@ async fn issue_outbox_event_contains_consumed_layers_and_source_ref() {
 
     cleanup_tenant(&pool, &tenant_id).await;
 }
+
+// ============================================================================
+// Test 9: Issue against a reservation consumes the hold atomically
+// ============================================================================
+
+#[tokio::test]
+#[serial]
+async fn issue_against_reservation_consumes_the_hold() {
+    let pool = setup_db().await;
+    let tenant_id = format!("test-{}", Uuid::new_v4());
+    let warehouse_id = Uuid::new_v4();
+
+    let item = ItemRepo::create(&pool, &make_item_req(&tenant_id, "SKU-RSV-001"))
+        .await
+        .expect("create item");
+
+    process_receipt(
+        &pool,
+        &make_receipt_req(
+            &tenant_id,
+            item.id,
+            warehouse_id,
+            10,
+            1000,
+            &format!("rcv-{}", Uuid::new_v4()),
+        ),
+        None,
+    )
+    .await
+    .expect("receipt");
+
+    let (reservation, _) = process_reserve(
+        &pool,
+        &ReserveRequest {
+            tenant_id: tenant_id.clone(),
+            item_id: item.id,
+            warehouse_id,
+            quantity: 8,
+            reference_type: Some("maintenance_work_order".to_string()),
+            reference_id: Some("WO-1".to_string()),
+            expires_at: None,
+            idempotency_key: format!("rsv-{}", Uuid::new_v4()),
+            correlation_id: None,
+            causation_id: None,
+        },
+    )
+    .await
+    .expect("reserve");
+
+    // Without the reservation only the 2 unreserved units are available.
+    let err = process_issue(
+        &pool,
+        &make_issue_req(
+            &tenant_id,
+            item.id,
+            warehouse_id,
+            8,
+            &format!("iss-{}", Uuid::new_v4()),
+        ),
+        None,
+    )
+    .await
+    .expect_err("held units must not be issued to other demand");
+    assert!(matches!(
+        err,
+        IssueError::InsufficientQuantity { available: 2, .. }
+    ));
+
+    // Against the reservation, the held units are issued and the hold settled.
+    let mut req = make_issue_req(
+        &tenant_id,
+        item.id,
+        warehouse_id,
+        8,
+        &format!("iss-{}", Uuid::new_v4()),
+    );
+    req.reservation_id = Some(reservation.reservation_id);
+    let (result, _) = process_issue(&pool, &req, None)
+        .await
+        .expect("issue against reservation");
+    assert_eq!(result.quantity, 8);
+
+    let (on_hand, reserved): (i64, i64) = sqlx::query_as(
+        "SELECT quantity_on_hand, quantity_reserved FROM item_on_hand \
+         WHERE tenant_id = $1 AND item_id = $2 AND warehouse_id = $3 AND location_id IS NULL",
+    )
+    .bind(&tenant_id)
+    .bind(item.id)
+    .bind(warehouse_id)
+    .fetch_one(&pool)
+    .await
+    .expect("on-hand row");
+    assert_eq!(on_hand, 2);
+    assert_eq!(reserved, 0, "hold must be consumed by the issue");
+
+    let status: String = sqlx::query_scalar(
+        "SELECT status::TEXT FROM inventory_reservations WHERE reverses_reservation_id = $1",
+    )
+    .bind(reservation.reservation_id)
+    .fetch_one(&pool)
+    .await
+    .expect("settling row");
+    assert_eq!(status, "fulfilled");
+
+    // The reservation cannot be consumed twice.
+    req.idempotency_key = format!("iss-{}", Uuid::new_v4());
+    req.quantity = 1;
+    let err = process_issue(&pool, &req, None)
+        .await
+        .expect_err("settled reservation");
+    assert!(matches!(err, IssueError::ReservationSettled(id) if id == reservation.reservation_id));
+
+    req.idempotency_key = format!("iss-{}", Uuid::new_v4());
+    req.reservation_id = Some(Uuid::new_v4());
+    let err = process_issue(&pool, &req, None)
+        .await
+        .expect_err("unknown reservation");
+    assert!(matches!(err, IssueError::ReservationNotFound(_)));
+
+    sqlx::query("DELETE FROM inventory_reservations WHERE tenant_id = $1")
+        .bind(&tenant_id)
+        .execute(&pool)
+        .await
+        .ok();
+    cleanup_tenant(&pool, &tenant_id).await;
+}
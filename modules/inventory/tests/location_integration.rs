@@ -110,6 +110,7 @@ fn issue_req(
         uom_id: None,
         lot_code: None,
         serial_codes: None,
+        reservation_id: None,
     }
 }
 
@@ -303,6 +303,7 @@ async fn test_trace_lot_returns_receipt_and_issue_movements() {
             uom_id: None,
             lot_code: Some("TRACE-LOT".to_string()),
             serial_codes: None,
+            reservation_id: None,
         },
         None,
     )
@@ -375,6 +376,7 @@ async fn test_trace_serial_returns_receipt_and_issue_movements() {
             uom_id: None,
             lot_code: None,
             serial_codes: Some(vec!["SN-TRACE".to_string()]),
+            reservation_id: None,
         },
         None,
     )
@@ -126,6 +126,7 @@ fn issue(
         uom_id: None,
         lot_code: None,
         serial_codes: None,
+        reservation_id: None,
     }
 }
 
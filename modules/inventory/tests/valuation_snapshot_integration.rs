@@ -118,6 +118,7 @@ fn issue_req(
         lot_code: None,
         serial_codes: None,
         uom_id: None,
+        reservation_id: None,
     }
 }
 
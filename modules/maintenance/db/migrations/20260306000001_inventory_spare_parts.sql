@@ -0,0 +1,106 @@
+-- Inventory-integrated spare parts
+-- 1. work_order_parts gains inventory linkage: item/warehouse/lot/serial,
+--    reservation and issue references, and actual issued/returned cost.
+-- 2. work_order_part_returns records each return of unused parts to stock.
+-- 3. maintenance_plan_parts lists the spare parts a plan consumes per occurrence.
+-- 4. spare_part_policies holds per-item min/max stocking policies computed
+--    from planned maintenance demand and synced to the item's Inventory
+--    reorder policy.
+-- 5. Tenant config: whether parts are issued when work starts (default) or
+--    only at completion.
+
+-- ============================================================================
+-- 1. Work order parts — inventory linkage
+-- ============================================================================
+
+ALTER TABLE work_order_parts
+    ADD COLUMN item_id             UUID,
+    ADD COLUMN warehouse_id        UUID,
+    ADD COLUMN location_id         UUID,
+    ADD COLUMN lot_code            TEXT,
+    ADD COLUMN serial_codes        TEXT[],
+    ADD COLUMN stock_status        TEXT NOT NULL DEFAULT 'standalone'
+                                   CHECK (stock_status IN ('standalone', 'reserved', 'issued', 'released')),
+    ADD COLUMN reservation_id      UUID,
+    ADD COLUMN issued_quantity     INTEGER NOT NULL DEFAULT 0 CHECK (issued_quantity >= 0),
+    ADD COLUMN issued_cost_minor   BIGINT NOT NULL DEFAULT 0 CHECK (issued_cost_minor >= 0),
+    ADD COLUMN returned_quantity   INTEGER NOT NULL DEFAULT 0,
+    ADD COLUMN returned_cost_minor BIGINT NOT NULL DEFAULT 0,
+    ADD COLUMN issued_at           TIMESTAMP WITH TIME ZONE;
+
+ALTER TABLE work_order_parts
+    ADD CONSTRAINT work_order_parts_item_requires_warehouse
+        CHECK (item_id IS NULL OR warehouse_id IS NOT NULL),
+    ADD CONSTRAINT work_order_parts_returned_within_issued
+        CHECK (returned_quantity >= 0 AND returned_quantity <= issued_quantity),
+    ADD CONSTRAINT work_order_parts_returned_cost_within_issued
+        CHECK (returned_cost_minor >= 0 AND returned_cost_minor <= issued_cost_minor);
+
+CREATE INDEX idx_work_order_parts_item
+    ON work_order_parts(tenant_id, item_id, warehouse_id)
+    WHERE item_id IS NOT NULL;
+
+-- ============================================================================
+-- 2. Part returns
+-- ============================================================================
+
+CREATE TABLE work_order_part_returns (
+    id             UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    tenant_id      TEXT NOT NULL,
+    work_order_id  UUID NOT NULL REFERENCES work_orders(id),
+    part_id        UUID NOT NULL REFERENCES work_order_parts(id),
+    quantity       INTEGER NOT NULL CHECK (quantity > 0),
+    serial_codes   TEXT[],
+    cost_minor     BIGINT NOT NULL CHECK (cost_minor >= 0),
+    receipt_ref    UUID NOT NULL,
+    created_at     TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_work_order_part_returns_part ON work_order_part_returns(tenant_id, part_id);
+
+-- ============================================================================
+-- 3. Plan spare-part requirements
+-- ============================================================================
+
+CREATE TABLE maintenance_plan_parts (
+    id               UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    tenant_id        TEXT NOT NULL,
+    plan_id          UUID NOT NULL REFERENCES maintenance_plans(id),
+    item_id          UUID NOT NULL,
+    part_description TEXT NOT NULL,
+    quantity         INTEGER NOT NULL CHECK (quantity > 0),
+    created_at       TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+    CONSTRAINT maintenance_plan_parts_plan_item_unique UNIQUE (plan_id, item_id)
+);
+
+CREATE INDEX idx_maintenance_plan_parts_item ON maintenance_plan_parts(tenant_id, item_id);
+
+-- ============================================================================
+-- 4. Spare-part min/max policies
+-- ============================================================================
+
+CREATE TABLE spare_part_policies (
+    id                          UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    tenant_id                   TEXT NOT NULL,
+    item_id                     UUID NOT NULL,
+    lead_time_days              INTEGER NOT NULL CHECK (lead_time_days >= 0),
+    horizon_days                INTEGER NOT NULL CHECK (horizon_days > 0),
+    safety_stock                INTEGER NOT NULL DEFAULT 0 CHECK (safety_stock >= 0),
+    min_qty                     INTEGER,
+    max_qty                     INTEGER,
+    inventory_reorder_policy_id UUID,
+    last_computed_at            TIMESTAMP WITH TIME ZONE,
+    created_at                  TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+    updated_at                  TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+    CONSTRAINT spare_part_policies_item_unique UNIQUE (tenant_id, item_id),
+    CONSTRAINT spare_part_policies_horizon_covers_lead_time CHECK (horizon_days >= lead_time_days)
+);
+
+CREATE INDEX idx_spare_part_policies_tenant ON spare_part_policies(tenant_id);
+
+-- ============================================================================
+-- 5. Tenant config
+-- ============================================================================
+
+ALTER TABLE maintenance_tenant_config
+    ADD COLUMN issue_parts_on_start BOOLEAN NOT NULL DEFAULT TRUE;
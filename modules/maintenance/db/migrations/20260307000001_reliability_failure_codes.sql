@@ -0,0 +1,16 @@
+-- Reliability analytics
+-- 1. work_orders.failure_code: failure mode recorded when a corrective work
+--    order is completed. Downtime events already carry reason_code.
+-- 2. Indexes for window scans of completed corrective work orders and
+--    downtime-to-work-order links.
+
+ALTER TABLE work_orders
+    ADD COLUMN failure_code TEXT;
+
+CREATE INDEX idx_work_orders_tenant_corrective_completed
+    ON work_orders (tenant_id, completed_at)
+    WHERE wo_type = 'corrective' AND completed_at IS NOT NULL;
+
+CREATE INDEX idx_downtime_events_tenant_wo_ref
+    ON downtime_events (tenant_id, wo_ref)
+    WHERE wo_ref IS NOT NULL;
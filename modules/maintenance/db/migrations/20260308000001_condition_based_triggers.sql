@@ -0,0 +1,80 @@
+-- Condition-based maintenance triggers
+--
+-- meter_types.kind:
+--   counter — cumulative usage (odometer, engine hours); monotonic with rollover
+--   gauge   — condition measurement (temperature, vibration, pressure); any value
+-- maintenance_plans.schedule_type gains 'condition': plan is driven only by
+-- condition rules.
+-- maintenance_condition_rules: per-plan rules evaluated by the scheduler tick.
+-- work_order_trigger_readings: the readings that fired a condition rule,
+-- attached to the work order auto-created from the due event.
+
+ALTER TABLE meter_types
+    ADD COLUMN kind TEXT NOT NULL DEFAULT 'counter'
+        CHECK (kind IN ('counter', 'gauge'));
+
+-- Gauges may go negative (e.g. temperature); counters are still checked in
+-- application code.
+ALTER TABLE meter_readings
+    DROP CONSTRAINT meter_readings_value_non_negative;
+
+ALTER TABLE maintenance_plans
+    DROP CONSTRAINT maintenance_plans_schedule_type_check;
+ALTER TABLE maintenance_plans
+    ADD CONSTRAINT maintenance_plans_schedule_type_check
+        CHECK (schedule_type IN ('calendar', 'meter', 'both', 'condition'));
+
+-- ============================================================
+-- CONDITION RULES
+-- rule_type:
+--   threshold      — latest reading outside [lower_limit, upper_limit]
+--   rate_of_change — change per hour across window_hours outside the band
+--   moving_average — mean of the last window_size readings outside the band
+--   usage_forecast — projected date for next_due_meter (usage rate across
+--                    window_hours) within lead_days of today
+-- ============================================================
+
+CREATE TABLE maintenance_condition_rules (
+    id             UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    tenant_id      TEXT NOT NULL,
+    plan_id        UUID NOT NULL REFERENCES maintenance_plans(id),
+    meter_type_id  UUID NOT NULL REFERENCES meter_types(id),
+    name           TEXT NOT NULL,
+    rule_type      TEXT NOT NULL
+                   CHECK (rule_type IN ('threshold', 'rate_of_change', 'moving_average', 'usage_forecast')),
+    lower_limit    DOUBLE PRECISION,
+    upper_limit    DOUBLE PRECISION,
+    window_size    INTEGER CHECK (window_size IS NULL OR window_size >= 2),
+    window_hours   INTEGER CHECK (window_hours IS NULL OR window_hours > 0),
+    lead_days      INTEGER CHECK (lead_days IS NULL OR lead_days >= 0),
+    is_active      BOOLEAN NOT NULL DEFAULT TRUE,
+    created_at     TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+    updated_at     TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+
+    CONSTRAINT maintenance_condition_rules_band
+        CHECK (lower_limit IS NULL OR upper_limit IS NULL OR lower_limit < upper_limit)
+);
+
+CREATE INDEX idx_condition_rules_plan
+    ON maintenance_condition_rules (tenant_id, plan_id)
+    WHERE is_active;
+
+-- ============================================================
+-- WORK ORDER TRIGGER READINGS
+-- ============================================================
+
+CREATE TABLE work_order_trigger_readings (
+    id               UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    tenant_id        TEXT NOT NULL,
+    work_order_id    UUID NOT NULL REFERENCES work_orders(id),
+    rule_id          UUID NOT NULL REFERENCES maintenance_condition_rules(id),
+    meter_reading_id UUID NOT NULL REFERENCES meter_readings(id),
+    reading_value    BIGINT NOT NULL,
+    recorded_at      TIMESTAMP WITH TIME ZONE NOT NULL,
+    created_at       TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+
+    CONSTRAINT work_order_trigger_readings_unique UNIQUE (work_order_id, meter_reading_id)
+);
+
+CREATE INDEX idx_wo_trigger_readings_wo
+    ON work_order_trigger_readings (tenant_id, work_order_id);
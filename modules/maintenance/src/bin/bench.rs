@@ -17,6 +17,7 @@ use maintenance_rs::domain::{
     plans::{AssignPlanRequest, AssignmentRepo, CreatePlanRequest, PlanRepo},
     work_orders::{CreateWorkOrderRequest, TransitionRequest, WorkOrderRepo},
 };
+use maintenance_rs::InventoryIntegration;
 use sqlx::postgres::PgPoolOptions;
 use sqlx::PgPool;
 use std::time::{Duration, Instant};
@@ -180,7 +181,9 @@ async fn bench_wo_create(pool: &PgPool, tenant_id: &str, asset_id: Uuid, bench:
             downtime_minutes: None,
             closed_at: None,
             notes: None,
+            failure_code: None,
         },
+        &InventoryIntegration::deterministic(),
     )
     .await;
 }
@@ -220,7 +223,9 @@ async fn bench_wo_transition(
             downtime_minutes: None,
             closed_at: None,
             notes: None,
+            failure_code: None,
         },
+        &InventoryIntegration::deterministic(),
     )
     .await
     .expect("schedule");
@@ -237,7 +242,9 @@ async fn bench_wo_transition(
             downtime_minutes: None,
             closed_at: None,
             notes: None,
+            failure_code: None,
         },
+        &InventoryIntegration::deterministic(),
     )
     .await
     .expect("in_progress");
@@ -311,6 +318,7 @@ async fn main() {
             name: format!("bench-hours-{}", Uuid::new_v4()),
             unit_label: "hours".to_string(),
             rollover_value: None,
+            kind: None,
         },
     )
     .await
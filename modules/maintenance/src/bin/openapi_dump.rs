@@ -11,7 +11,7 @@ use utoipa::OpenApi;
         title = "Maintenance Service",
         version = "2.2.0",
         description = "Maintenance management: work orders, preventive plans, meters, calibration, \
-                        downtime tracking, and labor management.\n\n\
+                        downtime tracking, labor management, inventory-integrated spare parts, condition-based triggers, and reliability analytics.\n\n\
                         **Authentication:** Bearer JWT. Tenant derived from JWT claims.\n\
                         Permissions: MAINTENANCE_READ for queries, MAINTENANCE_MUTATE for writes."
     ),
@@ -24,6 +24,12 @@ use utoipa::OpenApi;
         // Calibration
         maintenance_rs::http::calibration_events::record_calibration_event,
         maintenance_rs::http::calibration_events::get_calibration_status,
+        // Condition Monitoring
+        maintenance_rs::http::condition_rules::create_condition_rule,
+        maintenance_rs::http::condition_rules::list_condition_rules,
+        maintenance_rs::http::condition_rules::deactivate_condition_rule,
+        maintenance_rs::http::condition_rules::get_assignment_forecast,
+        maintenance_rs::http::condition_rules::list_trigger_readings,
         // Downtime
         maintenance_rs::http::downtime::create_downtime,
         maintenance_rs::http::downtime::list_downtime,
@@ -45,6 +51,17 @@ use utoipa::OpenApi;
         maintenance_rs::http::plans::update_plan,
         maintenance_rs::http::plans::assign_plan,
         maintenance_rs::http::plans::list_assignments,
+        // Reliability
+        maintenance_rs::http::reliability::get_reliability,
+        maintenance_rs::http::reliability::get_failure_pareto,
+        maintenance_rs::http::reliability::get_reliability_trend,
+        maintenance_rs::http::reliability::publish_snapshot,
+        // Spare Parts
+        maintenance_rs::http::spare_parts::add_plan_part,
+        maintenance_rs::http::spare_parts::list_plan_parts,
+        maintenance_rs::http::spare_parts::upsert_policy,
+        maintenance_rs::http::spare_parts::list_policies,
+        maintenance_rs::http::spare_parts::recompute_policy,
         // Work Orders
         maintenance_rs::http::work_orders::create_work_order,
         maintenance_rs::http::work_orders::list_work_orders,
@@ -58,6 +75,8 @@ use utoipa::OpenApi;
         maintenance_rs::http::work_order_parts::add_part,
         maintenance_rs::http::work_order_parts::list_parts,
         maintenance_rs::http::work_order_parts::remove_part,
+        maintenance_rs::http::work_order_parts::return_part,
+        maintenance_rs::http::work_order_parts::list_part_returns,
     ),
     components(schemas(
         maintenance_rs::domain::assets::Asset,
@@ -67,6 +86,13 @@ use utoipa::OpenApi;
         maintenance_rs::domain::calibration_events::CalibrationStatus,
         maintenance_rs::domain::calibration_events::CalibrationStatusResponse,
         maintenance_rs::domain::calibration_events::RecordCalibrationRequest,
+        maintenance_rs::domain::condition::ConditionRuleType,
+        maintenance_rs::domain::condition::ConditionRule,
+        maintenance_rs::domain::condition::CreateConditionRuleRequest,
+        maintenance_rs::domain::condition::ReadingPoint,
+        maintenance_rs::domain::condition::ConditionTrigger,
+        maintenance_rs::domain::condition::UsageForecast,
+        maintenance_rs::domain::condition::WorkOrderTriggerReading,
         maintenance_rs::domain::downtime::DowntimeEvent,
         maintenance_rs::domain::downtime::CreateDowntimeRequest,
         maintenance_rs::domain::meters::MeterType,
@@ -78,6 +104,20 @@ use utoipa::OpenApi;
         maintenance_rs::domain::plans::CreatePlanRequest,
         maintenance_rs::domain::plans::UpdatePlanRequest,
         maintenance_rs::domain::plans::AssignPlanRequest,
+        maintenance_rs::domain::reliability::ReliabilityMetrics,
+        maintenance_rs::domain::reliability::ReliabilityGroupBy,
+        maintenance_rs::domain::reliability::ReliabilityGroup,
+        maintenance_rs::domain::reliability::ReliabilityReport,
+        maintenance_rs::domain::reliability::FailureParetoEntry,
+        maintenance_rs::domain::reliability::TrendBucket,
+        maintenance_rs::domain::reliability::ReliabilityTrendPoint,
+        maintenance_rs::domain::reliability::ReliabilitySnapshot,
+        maintenance_rs::domain::reliability::PublishSnapshotRequest,
+        maintenance_rs::domain::spare_parts::PlanPart,
+        maintenance_rs::domain::spare_parts::AddPlanPartRequest,
+        maintenance_rs::domain::spare_parts::SparePartPolicy,
+        maintenance_rs::domain::spare_parts::UpsertSparePartPolicyRequest,
+        maintenance_rs::domain::spare_parts::SparePartRecompute,
         maintenance_rs::domain::work_orders::WorkOrder,
         maintenance_rs::domain::work_orders::CreateWorkOrderRequest,
         maintenance_rs::domain::work_orders::TransitionRequest,
@@ -85,6 +125,9 @@ use utoipa::OpenApi;
         maintenance_rs::domain::work_orders::AddLaborRequest,
         maintenance_rs::domain::work_orders::WoPart,
         maintenance_rs::domain::work_orders::AddPartRequest,
+        maintenance_rs::domain::work_orders::StockStatus,
+        maintenance_rs::domain::work_orders::WoPartReturn,
+        maintenance_rs::domain::work_orders::ReturnPartRequest,
         platform_http_contracts::ApiError,
     )),
     security(("bearer" = [])),
@@ -0,0 +1,291 @@
+//! Pure condition-rule evaluation over meter readings.
+//!
+//! All functions take readings ordered oldest first and never touch the
+//! database, so the scheduler and the forecast endpoint share one definition.
+
+use chrono::{Duration, NaiveDate};
+
+use super::{ConditionRule, ConditionRuleType, ConditionTrigger, ReadingPoint};
+
+/// Projections further out than this are reported as "no projected date".
+const MAX_PROJECTION_DAYS: f64 = 36_500.0;
+
+/// True when `value` is below `lower` or above `upper` (missing bounds are open).
+pub fn out_of_band(value: f64, lower: Option<f64>, upper: Option<f64>) -> bool {
+    lower.is_some_and(|l| value < l) || upper.is_some_and(|u| value > u)
+}
+
+/// Readings inside `hours` before the latest reading (inclusive).
+pub fn window_in(readings: &[ReadingPoint], hours: i32) -> &[ReadingPoint] {
+    let Some(latest) = readings.last() else {
+        return readings;
+    };
+    let cutoff = latest.recorded_at - Duration::hours(hours as i64);
+    let start = readings
+        .iter()
+        .position(|r| r.recorded_at >= cutoff)
+        .unwrap_or(readings.len());
+    &readings[start..]
+}
+
+/// Change per hour between the first and last reading of `window`.
+pub fn rate_per_hour(window: &[ReadingPoint]) -> Option<f64> {
+    let (first, last) = (window.first()?, window.last()?);
+    let elapsed_hours = (last.recorded_at - first.recorded_at).num_seconds() as f64 / 3600.0;
+    if elapsed_hours <= 0.0 {
+        return None;
+    }
+    Some((last.reading_value - first.reading_value) as f64 / elapsed_hours)
+}
+
+/// Usage rate per day and the date `next_due_meter` will be reached.
+///
+/// The rate is None without positive usage across the window. A meter already
+/// at or past `next_due_meter` projects to the latest reading's date.
+pub fn project_due(
+    window: &[ReadingPoint],
+    next_due_meter: i64,
+) -> (Option<f64>, Option<NaiveDate>) {
+    let rate_per_day = rate_per_hour(window).map(|r| r * 24.0).filter(|r| *r > 0.0);
+    let Some(latest) = window.last() else {
+        return (None, None);
+    };
+    if latest.reading_value >= next_due_meter {
+        return (rate_per_day, Some(latest.recorded_at.date_naive()));
+    }
+    let projected = rate_per_day.and_then(|rate| {
+        let days = (next_due_meter - latest.reading_value) as f64 / rate;
+        if days > MAX_PROJECTION_DAYS {
+            return None;
+        }
+        let at = latest.recorded_at + Duration::seconds((days * 86_400.0).ceil() as i64);
+        Some(at.date_naive())
+    });
+    (rate_per_day, projected)
+}
+
+/// Evaluate one rule; returns the trigger when the rule fires.
+///
+/// `next_due_meter` is the assignment's meter due point (usage_forecast only).
+pub fn evaluate(
+    rule: &ConditionRule,
+    readings: &[ReadingPoint],
+    next_due_meter: Option<i64>,
+    today: NaiveDate,
+) -> Option<ConditionTrigger> {
+    let fire = |observed: f64, projected: Option<NaiveDate>, used: Vec<ReadingPoint>| {
+        Some(ConditionTrigger {
+            rule_id: rule.id,
+            rule_name: rule.name.clone(),
+            rule_type: rule.rule_type,
+            meter_type_id: rule.meter_type_id,
+            observed_value: observed,
+            lower_limit: rule.lower_limit,
+            upper_limit: rule.upper_limit,
+            projected_due_date: projected,
+            readings: used,
+        })
+    };
+    let ends = |w: &[ReadingPoint]| -> Vec<ReadingPoint> {
+        match (w.first(), w.last()) {
+            (Some(a), Some(b)) if a != b => vec![a.clone(), b.clone()],
+            (Some(a), _) => vec![a.clone()],
+            _ => Vec::new(),
+        }
+    };
+
+    match rule.rule_type {
+        ConditionRuleType::Threshold => {
+            let latest = readings.last()?;
+            let value = latest.reading_value as f64;
+            if !out_of_band(value, rule.lower_limit, rule.upper_limit) {
+                return None;
+            }
+            fire(value, None, vec![latest.clone()])
+        }
+        ConditionRuleType::MovingAverage => {
+            let n = usize::try_from(rule.window_size?).ok()?;
+            if n == 0 || readings.len() < n {
+                return None;
+            }
+            let tail = &readings[readings.len() - n..];
+            let avg = tail.iter().map(|r| r.reading_value as f64).sum::<f64>() / n as f64;
+            if !out_of_band(avg, rule.lower_limit, rule.upper_limit) {
+                return None;
+            }
+            fire(avg, None, tail.to_vec())
+        }
+        ConditionRuleType::RateOfChange => {
+            let window = window_in(readings, rule.window_hours?);
+            let rate = rate_per_hour(window)?;
+            if !out_of_band(rate, rule.lower_limit, rule.upper_limit) {
+                return None;
+            }
+            fire(rate, None, ends(window))
+        }
+        ConditionRuleType::UsageForecast => {
+            let lead = rule.lead_days?;
+            let window = window_in(readings, rule.window_hours?);
+            let (_, projected) = project_due(window, next_due_meter?);
+            let projected = projected?;
+            let days_until = (projected - today).num_days();
+            if days_until > lead as i64 {
+                return None;
+            }
+            fire(days_until as f64, Some(projected), ends(window))
+        }
+    }
+}
+
+// ============================================================================
+// Unit tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{DateTime, TimeZone, Utc};
+    use uuid::Uuid;
+
+    fn at(day: u32, hour: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2026, 5, day, hour, 0, 0)
+            .single()
+            .expect("valid date")
+    }
+
+    fn reading(value: i64, recorded_at: DateTime<Utc>) -> ReadingPoint {
+        ReadingPoint {
+            meter_reading_id: Uuid::new_v4(),
+            reading_value: value,
+            recorded_at,
+        }
+    }
+
+    fn rule(rule_type: ConditionRuleType) -> ConditionRule {
+        ConditionRule {
+            id: Uuid::new_v4(),
+            tenant_id: "t".into(),
+            plan_id: Uuid::new_v4(),
+            meter_type_id: Uuid::new_v4(),
+            name: "r".into(),
+            rule_type,
+            lower_limit: None,
+            upper_limit: None,
+            window_size: None,
+            window_hours: None,
+            lead_days: None,
+            is_active: true,
+            created_at: at(1, 0),
+            updated_at: at(1, 0),
+        }
+    }
+
+    #[test]
+    fn band_bounds_are_optional() {
+        assert!(out_of_band(91.0, None, Some(90.0)));
+        assert!(!out_of_band(90.0, None, Some(90.0)));
+        assert!(out_of_band(-1.0, Some(0.0), None));
+        assert!(!out_of_band(5.0, None, None));
+    }
+
+    #[test]
+    fn threshold_fires_on_latest_reading_only() {
+        let mut r = rule(ConditionRuleType::Threshold);
+        r.upper_limit = Some(80.0);
+        let today = at(2, 0).date_naive();
+
+        let hot_then_cool = vec![reading(95, at(1, 0)), reading(70, at(1, 1))];
+        assert!(evaluate(&r, &hot_then_cool, None, today).is_none());
+
+        let breach = vec![reading(70, at(1, 0)), reading(85, at(1, 1))];
+        let t = evaluate(&r, &breach, None, today).expect("fires");
+        assert_eq!(t.observed_value, 85.0);
+        assert_eq!(t.readings, vec![breach[1].clone()]);
+    }
+
+    #[test]
+    fn moving_average_needs_full_window() {
+        let mut r = rule(ConditionRuleType::MovingAverage);
+        r.window_size = Some(3);
+        r.upper_limit = Some(10.0);
+        let today = at(2, 0).date_naive();
+
+        let short = vec![reading(20, at(1, 0)), reading(20, at(1, 1))];
+        assert!(evaluate(&r, &short, None, today).is_none());
+
+        let spiky = vec![
+            reading(30, at(1, 0)),
+            reading(5, at(1, 1)),
+            reading(5, at(1, 2)),
+            reading(15, at(1, 3)),
+        ];
+        // Last three average to 8.33 — a single spike does not fire.
+        assert!(evaluate(&r, &spiky, None, today).is_none());
+
+        let sustained = vec![
+            reading(5, at(1, 0)),
+            reading(12, at(1, 1)),
+            reading(12, at(1, 2)),
+            reading(12, at(1, 3)),
+        ];
+        let t = evaluate(&r, &sustained, None, today).expect("fires");
+        assert_eq!(t.observed_value, 12.0);
+        assert_eq!(t.readings.len(), 3);
+    }
+
+    #[test]
+    fn rate_of_change_uses_window_before_latest() {
+        let mut r = rule(ConditionRuleType::RateOfChange);
+        r.window_hours = Some(2);
+        r.lower_limit = Some(-5.0);
+        let today = at(2, 0).date_naive();
+
+        // Pressure drops 20 over 2h inside the window; the older reading is ignored.
+        let readings = vec![
+            reading(500, at(1, 0)),
+            reading(100, at(1, 10)),
+            reading(90, at(1, 11)),
+            reading(80, at(1, 12)),
+        ];
+        let t = evaluate(&r, &readings, None, today).expect("fires");
+        assert_eq!(t.observed_value, -10.0);
+        assert_eq!(t.readings.len(), 2);
+        assert_eq!(t.readings[0].reading_value, 100);
+
+        r.lower_limit = Some(-15.0);
+        assert!(evaluate(&r, &readings, None, today).is_none());
+    }
+
+    #[test]
+    fn project_due_from_usage_rate() {
+        // 100 units/day; 1000 remaining → 10 days after the latest reading.
+        let window = vec![reading(1000, at(1, 0)), reading(1500, at(6, 0))];
+        let (rate, date) = project_due(&window, 2500);
+        assert_eq!(rate, Some(100.0));
+        assert_eq!(date, Some(at(16, 0).date_naive()));
+
+        let (_, past) = project_due(&window, 1200);
+        assert_eq!(past, Some(at(6, 0).date_naive()));
+
+        let flat = vec![reading(1000, at(1, 0)), reading(1000, at(6, 0))];
+        assert_eq!(project_due(&flat, 2500), (None, None));
+    }
+
+    #[test]
+    fn usage_forecast_fires_within_lead_days() {
+        let mut r = rule(ConditionRuleType::UsageForecast);
+        r.window_hours = Some(24 * 30);
+        r.lead_days = Some(7);
+        let readings = vec![reading(1000, at(1, 0)), reading(1500, at(6, 0))];
+
+        // Projected 2026-05-16; today 2026-05-06 is 10 days out.
+        assert!(evaluate(&r, &readings, Some(2500), at(6, 0).date_naive()).is_none());
+
+        let t = evaluate(&r, &readings, Some(2500), at(10, 0).date_naive()).expect("fires");
+        assert_eq!(t.observed_value, 6.0);
+        assert_eq!(t.projected_due_date, Some(at(16, 0).date_naive()));
+        assert_eq!(t.readings.len(), 2);
+
+        assert!(evaluate(&r, &readings, None, at(10, 0).date_naive()).is_none());
+    }
+}
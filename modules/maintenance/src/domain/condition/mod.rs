@@ -0,0 +1,198 @@
+//! Condition-based maintenance — meter rules that make a plan due.
+//!
+//! Invariants:
+//! - A rule belongs to one plan and watches one meter type on each assigned asset
+//! - threshold / rate_of_change / moving_average fire when their observed
+//!   value leaves the [lower_limit, upper_limit] band (either bound optional)
+//! - usage_forecast fires when the projected date of the assignment's
+//!   next_due_meter falls within lead_days; it needs a counter meter matching
+//!   the plan's meter_type_id
+//! - Only readings recorded after the assignment's last_completed_at count
+//! - Rules are evaluated by the scheduler tick and share the assignment's
+//!   due_notified_at guard with calendar / meter triggers
+//! - Every query filters by tenant_id for multi-tenant isolation
+
+pub mod evaluate;
+mod repo;
+
+pub use repo::{ConditionRuleRepo, ReadingScope, TriggerReadingRepo};
+
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use thiserror::Error;
+use uuid::Uuid;
+
+use super::work_orders::types::ParseEnumError;
+
+/// Lookback used for usage forecasts when the caller gives none (30 days).
+pub const DEFAULT_FORECAST_WINDOW_HOURS: i32 = 720;
+/// Upper bound on moving-average window size.
+pub const MAX_WINDOW_SIZE: i32 = 500;
+
+// ============================================================================
+// Domain models
+// ============================================================================
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, utoipa::ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ConditionRuleType {
+    Threshold,
+    RateOfChange,
+    MovingAverage,
+    UsageForecast,
+}
+
+impl ConditionRuleType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Threshold => "threshold",
+            Self::RateOfChange => "rate_of_change",
+            Self::MovingAverage => "moving_average",
+            Self::UsageForecast => "usage_forecast",
+        }
+    }
+
+    pub fn from_str_value(s: &str) -> Result<Self, ParseEnumError> {
+        match s {
+            "threshold" => Ok(Self::Threshold),
+            "rate_of_change" => Ok(Self::RateOfChange),
+            "moving_average" => Ok(Self::MovingAverage),
+            "usage_forecast" => Ok(Self::UsageForecast),
+            _ => Err(ParseEnumError {
+                type_name: "ConditionRuleType",
+                value: s.to_string(),
+            }),
+        }
+    }
+}
+
+impl fmt::Display for ConditionRuleType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl TryFrom<String> for ConditionRuleType {
+    type Error = String;
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        Self::from_str_value(&s).map_err(|e| e.to_string())
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow, utoipa::ToSchema)]
+pub struct ConditionRule {
+    pub id: Uuid,
+    pub tenant_id: String,
+    pub plan_id: Uuid,
+    pub meter_type_id: Uuid,
+    pub name: String,
+    #[sqlx(try_from = "String")]
+    pub rule_type: ConditionRuleType,
+    pub lower_limit: Option<f64>,
+    pub upper_limit: Option<f64>,
+    /// moving_average: number of most recent readings averaged.
+    pub window_size: Option<i32>,
+    /// rate_of_change / usage_forecast: lookback before the latest reading.
+    pub window_hours: Option<i32>,
+    /// usage_forecast: fire when the projected due date is this close.
+    pub lead_days: Option<i32>,
+    pub is_active: bool,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// A meter reading as seen by rule evaluation.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, sqlx::FromRow, utoipa::ToSchema)]
+pub struct ReadingPoint {
+    pub meter_reading_id: Uuid,
+    pub reading_value: i64,
+    pub recorded_at: DateTime<Utc>,
+}
+
+/// Why a condition rule fired, carried on `maintenance.plan.due`.
+#[derive(Debug, Clone, Serialize, utoipa::ToSchema)]
+pub struct ConditionTrigger {
+    pub rule_id: Uuid,
+    pub rule_name: String,
+    pub rule_type: ConditionRuleType,
+    pub meter_type_id: Uuid,
+    /// Latest reading, rate per hour, average or days until due (forecast).
+    pub observed_value: f64,
+    pub lower_limit: Option<f64>,
+    pub upper_limit: Option<f64>,
+    pub projected_due_date: Option<NaiveDate>,
+    pub readings: Vec<ReadingPoint>,
+}
+
+/// Projected due date for a meter-scheduled assignment from its usage rate.
+#[derive(Debug, Clone, Serialize, utoipa::ToSchema)]
+pub struct UsageForecast {
+    pub assignment_id: Uuid,
+    pub meter_type_id: Uuid,
+    pub window_hours: i32,
+    pub latest_reading: Option<i64>,
+    pub latest_recorded_at: Option<DateTime<Utc>>,
+    pub next_due_meter: Option<i64>,
+    pub next_due_date: Option<NaiveDate>,
+    /// None when fewer than two readings or no usage in the window.
+    pub usage_rate_per_day: Option<f64>,
+    pub projected_due_date: Option<NaiveDate>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow, utoipa::ToSchema)]
+pub struct WorkOrderTriggerReading {
+    pub id: Uuid,
+    pub tenant_id: String,
+    pub work_order_id: Uuid,
+    pub rule_id: Uuid,
+    pub meter_reading_id: Uuid,
+    pub reading_value: i64,
+    pub recorded_at: DateTime<Utc>,
+    pub created_at: DateTime<Utc>,
+}
+
+// ============================================================================
+// Request types
+// ============================================================================
+
+#[derive(Debug, Deserialize, utoipa::ToSchema)]
+pub struct CreateConditionRuleRequest {
+    pub tenant_id: String,
+    pub meter_type_id: Uuid,
+    pub name: String,
+    pub rule_type: String,
+    pub lower_limit: Option<f64>,
+    pub upper_limit: Option<f64>,
+    pub window_size: Option<i32>,
+    pub window_hours: Option<i32>,
+    pub lead_days: Option<i32>,
+}
+
+// ============================================================================
+// Errors
+// ============================================================================
+
+#[derive(Debug, Error)]
+pub enum ConditionError {
+    #[error("Plan not found")]
+    PlanNotFound,
+
+    #[error("Meter type not found")]
+    MeterTypeNotFound,
+
+    #[error("Condition rule not found")]
+    RuleNotFound,
+
+    #[error("Assignment not found")]
+    AssignmentNotFound,
+
+    #[error("Work order not found")]
+    WorkOrderNotFound,
+
+    #[error("Validation error: {0}")]
+    Validation(String),
+
+    #[error("Database error: {0}")]
+    Database(#[from] sqlx::Error),
+}
@@ -0,0 +1,440 @@
+//! Condition rule, reading and trigger-reading repositories.
+
+use chrono::{DateTime, NaiveDate, Utc};
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use super::evaluate::{project_due, window_in};
+use super::{
+    ConditionError, ConditionRule, ConditionRuleType, ConditionTrigger, CreateConditionRuleRequest,
+    ReadingPoint, UsageForecast, WorkOrderTriggerReading, DEFAULT_FORECAST_WINDOW_HOURS,
+    MAX_WINDOW_SIZE,
+};
+use crate::domain::meters::METER_KIND_COUNTER;
+
+/// Which readings a rule needs.
+#[derive(Debug, Clone, Copy)]
+pub enum ReadingScope {
+    /// The N most recent readings.
+    Latest(i64),
+    /// Readings within N hours before the most recent one.
+    Hours(i32),
+}
+
+impl ReadingScope {
+    pub fn for_rule(rule: &ConditionRule) -> Self {
+        match rule.rule_type {
+            ConditionRuleType::Threshold => Self::Latest(1),
+            ConditionRuleType::MovingAverage => Self::Latest(rule.window_size.unwrap_or(2) as i64),
+            ConditionRuleType::RateOfChange | ConditionRuleType::UsageForecast => {
+                Self::Hours(rule.window_hours.unwrap_or(DEFAULT_FORECAST_WINDOW_HOURS))
+            }
+        }
+    }
+}
+
+pub struct ConditionRuleRepo;
+
+impl ConditionRuleRepo {
+    pub async fn create(
+        pool: &PgPool,
+        plan_id: Uuid,
+        req: &CreateConditionRuleRequest,
+    ) -> Result<ConditionRule, ConditionError> {
+        if req.tenant_id.trim().is_empty() {
+            return Err(ConditionError::Validation("tenant_id is required".into()));
+        }
+        if req.name.trim().is_empty() {
+            return Err(ConditionError::Validation("name is required".into()));
+        }
+        let rule_type = ConditionRuleType::from_str_value(&req.rule_type)
+            .map_err(|e| ConditionError::Validation(e.to_string()))?;
+
+        if let (Some(l), Some(u)) = (req.lower_limit, req.upper_limit) {
+            if l >= u {
+                return Err(ConditionError::Validation(
+                    "lower_limit must be less than upper_limit".into(),
+                ));
+            }
+        }
+        if req
+            .window_size
+            .is_some_and(|n| !(2..=MAX_WINDOW_SIZE).contains(&n))
+        {
+            return Err(ConditionError::Validation(format!(
+                "window_size must be between 2 and {}",
+                MAX_WINDOW_SIZE
+            )));
+        }
+        if req.window_hours.is_some_and(|h| h <= 0) {
+            return Err(ConditionError::Validation(
+                "window_hours must be positive".into(),
+            ));
+        }
+        if req.lead_days.is_some_and(|d| d < 0) {
+            return Err(ConditionError::Validation(
+                "lead_days must not be negative".into(),
+            ));
+        }
+
+        let banded = req.lower_limit.is_some() || req.upper_limit.is_some();
+        match rule_type {
+            ConditionRuleType::Threshold
+            | ConditionRuleType::RateOfChange
+            | ConditionRuleType::MovingAverage => {
+                if !banded {
+                    return Err(ConditionError::Validation(format!(
+                        "{} rule requires lower_limit and/or upper_limit",
+                        rule_type
+                    )));
+                }
+                if req.lead_days.is_some() {
+                    return Err(ConditionError::Validation(
+                        "lead_days applies only to usage_forecast rules".into(),
+                    ));
+                }
+            }
+            ConditionRuleType::UsageForecast => {
+                if banded {
+                    return Err(ConditionError::Validation(
+                        "usage_forecast rule takes no limits".into(),
+                    ));
+                }
+                if req.lead_days.is_none() || req.window_hours.is_none() {
+                    return Err(ConditionError::Validation(
+                        "lead_days and window_hours required for usage_forecast rule".into(),
+                    ));
+                }
+            }
+        }
+        if rule_type == ConditionRuleType::MovingAverage && req.window_size.is_none() {
+            return Err(ConditionError::Validation(
+                "window_size required for moving_average rule".into(),
+            ));
+        }
+        if rule_type != ConditionRuleType::MovingAverage && req.window_size.is_some() {
+            return Err(ConditionError::Validation(
+                "window_size applies only to moving_average rules".into(),
+            ));
+        }
+        if rule_type == ConditionRuleType::RateOfChange && req.window_hours.is_none() {
+            return Err(ConditionError::Validation(
+                "window_hours required for rate_of_change rule".into(),
+            ));
+        }
+
+        let plan: Option<(Option<Uuid>, Option<i64>)> = sqlx::query_as(
+            "SELECT meter_type_id, meter_interval FROM maintenance_plans WHERE id = $1 AND tenant_id = $2",
+        )
+        .bind(plan_id)
+        .bind(&req.tenant_id)
+        .fetch_optional(pool)
+        .await?;
+        let (plan_meter_type, plan_meter_interval) = plan.ok_or(ConditionError::PlanNotFound)?;
+
+        let kind: Option<String> =
+            sqlx::query_scalar("SELECT kind FROM meter_types WHERE id = $1 AND tenant_id = $2")
+                .bind(req.meter_type_id)
+                .bind(&req.tenant_id)
+                .fetch_optional(pool)
+                .await?;
+        let kind = kind.ok_or(ConditionError::MeterTypeNotFound)?;
+
+        if rule_type == ConditionRuleType::UsageForecast {
+            if kind != METER_KIND_COUNTER {
+                return Err(ConditionError::Validation(
+                    "usage_forecast rule requires a counter meter".into(),
+                ));
+            }
+            if plan_meter_type != Some(req.meter_type_id) || plan_meter_interval.is_none() {
+                return Err(ConditionError::Validation(
+                    "usage_forecast rule requires a meter-scheduled plan on the same meter type"
+                        .into(),
+                ));
+            }
+        }
+
+        sqlx::query_as::<_, ConditionRule>(
+            r#"
+            INSERT INTO maintenance_condition_rules
+                (tenant_id, plan_id, meter_type_id, name, rule_type,
+                 lower_limit, upper_limit, window_size, window_hours, lead_days)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)
+            RETURNING *
+            "#,
+        )
+        .bind(&req.tenant_id)
+        .bind(plan_id)
+        .bind(req.meter_type_id)
+        .bind(req.name.trim())
+        .bind(rule_type.as_str())
+        .bind(req.lower_limit)
+        .bind(req.upper_limit)
+        .bind(req.window_size)
+        .bind(req.window_hours)
+        .bind(req.lead_days)
+        .fetch_one(pool)
+        .await
+        .map_err(ConditionError::Database)
+    }
+
+    pub async fn list(
+        pool: &PgPool,
+        plan_id: Uuid,
+        tenant_id: &str,
+    ) -> Result<Vec<ConditionRule>, ConditionError> {
+        let plan: Option<(Uuid,)> =
+            sqlx::query_as("SELECT id FROM maintenance_plans WHERE id = $1 AND tenant_id = $2")
+                .bind(plan_id)
+                .bind(tenant_id)
+                .fetch_optional(pool)
+                .await?;
+        if plan.is_none() {
+            return Err(ConditionError::PlanNotFound);
+        }
+
+        sqlx::query_as::<_, ConditionRule>(
+            r#"
+            SELECT * FROM maintenance_condition_rules
+            WHERE tenant_id = $1 AND plan_id = $2
+            ORDER BY created_at
+            "#,
+        )
+        .bind(tenant_id)
+        .bind(plan_id)
+        .fetch_all(pool)
+        .await
+        .map_err(ConditionError::Database)
+    }
+
+    /// Deactivate a rule. Rules are kept for trigger-reading history.
+    pub async fn deactivate(
+        pool: &PgPool,
+        plan_id: Uuid,
+        rule_id: Uuid,
+        tenant_id: &str,
+    ) -> Result<ConditionRule, ConditionError> {
+        sqlx::query_as::<_, ConditionRule>(
+            r#"
+            UPDATE maintenance_condition_rules
+            SET is_active = FALSE, updated_at = NOW()
+            WHERE id = $1 AND plan_id = $2 AND tenant_id = $3
+            RETURNING *
+            "#,
+        )
+        .bind(rule_id)
+        .bind(plan_id)
+        .bind(tenant_id)
+        .fetch_optional(pool)
+        .await?
+        .ok_or(ConditionError::RuleNotFound)
+    }
+
+    /// Active rules for the given plans (scheduler use, all tenants).
+    pub async fn active_for_plans(
+        pool: &PgPool,
+        plan_ids: &[Uuid],
+    ) -> Result<Vec<ConditionRule>, sqlx::Error> {
+        sqlx::query_as::<_, ConditionRule>(
+            r#"
+            SELECT * FROM maintenance_condition_rules
+            WHERE is_active AND plan_id = ANY($1)
+            ORDER BY plan_id, created_at
+            "#,
+        )
+        .bind(plan_ids)
+        .fetch_all(pool)
+        .await
+    }
+
+    /// Readings for one asset + meter type, oldest first, restricted to
+    /// those recorded after `since` when given.
+    pub async fn load_readings(
+        pool: &PgPool,
+        tenant_id: &str,
+        asset_id: Uuid,
+        meter_type_id: Uuid,
+        since: Option<DateTime<Utc>>,
+        scope: ReadingScope,
+    ) -> Result<Vec<ReadingPoint>, sqlx::Error> {
+        match scope {
+            ReadingScope::Latest(n) => {
+                let mut v = sqlx::query_as::<_, ReadingPoint>(
+                    r#"
+                    SELECT id AS meter_reading_id, reading_value, recorded_at
+                    FROM meter_readings
+                    WHERE tenant_id = $1 AND asset_id = $2 AND meter_type_id = $3
+                      AND ($4::TIMESTAMPTZ IS NULL OR recorded_at > $4)
+                    ORDER BY recorded_at DESC, created_at DESC
+                    LIMIT $5
+                    "#,
+                )
+                .bind(tenant_id)
+                .bind(asset_id)
+                .bind(meter_type_id)
+                .bind(since)
+                .bind(n)
+                .fetch_all(pool)
+                .await?;
+                v.reverse();
+                Ok(v)
+            }
+            ReadingScope::Hours(h) => {
+                sqlx::query_as::<_, ReadingPoint>(
+                    r#"
+                    WITH scoped AS (
+                        SELECT id, reading_value, recorded_at, created_at
+                        FROM meter_readings
+                        WHERE tenant_id = $1 AND asset_id = $2 AND meter_type_id = $3
+                          AND ($4::TIMESTAMPTZ IS NULL OR recorded_at > $4)
+                    )
+                    SELECT id AS meter_reading_id, reading_value, recorded_at
+                    FROM scoped
+                    WHERE recorded_at >= (SELECT MAX(recorded_at) FROM scoped)
+                                         - make_interval(hours => $5)
+                    ORDER BY recorded_at, created_at
+                    "#,
+                )
+                .bind(tenant_id)
+                .bind(asset_id)
+                .bind(meter_type_id)
+                .bind(since)
+                .bind(h)
+                .fetch_all(pool)
+                .await
+            }
+        }
+    }
+
+    /// Project when a meter-scheduled assignment reaches its next_due_meter.
+    pub async fn forecast(
+        pool: &PgPool,
+        tenant_id: &str,
+        assignment_id: Uuid,
+        window_hours: Option<i32>,
+    ) -> Result<UsageForecast, ConditionError> {
+        let window_hours = window_hours.unwrap_or(DEFAULT_FORECAST_WINDOW_HOURS);
+        if window_hours <= 0 {
+            return Err(ConditionError::Validation(
+                "window_hours must be positive".into(),
+            ));
+        }
+
+        #[derive(sqlx::FromRow)]
+        struct Row {
+            asset_id: Uuid,
+            last_completed_at: Option<DateTime<Utc>>,
+            next_due_date: Option<NaiveDate>,
+            next_due_meter: Option<i64>,
+            meter_type_id: Option<Uuid>,
+        }
+        let row = sqlx::query_as::<_, Row>(
+            r#"
+            SELECT a.asset_id, a.last_completed_at, a.next_due_date, a.next_due_meter,
+                   p.meter_type_id
+            FROM maintenance_plan_assignments a
+            JOIN maintenance_plans p ON p.id = a.plan_id AND p.tenant_id = a.tenant_id
+            WHERE a.id = $1 AND a.tenant_id = $2
+            "#,
+        )
+        .bind(assignment_id)
+        .bind(tenant_id)
+        .fetch_optional(pool)
+        .await?
+        .ok_or(ConditionError::AssignmentNotFound)?;
+
+        let meter_type_id = row.meter_type_id.ok_or_else(|| {
+            ConditionError::Validation("assignment's plan has no meter schedule".into())
+        })?;
+
+        let readings = Self::load_readings(
+            pool,
+            tenant_id,
+            row.asset_id,
+            meter_type_id,
+            row.last_completed_at,
+            ReadingScope::Hours(window_hours),
+        )
+        .await?;
+        let window = window_in(&readings, window_hours);
+        let (usage_rate_per_day, projected_due_date) = match row.next_due_meter {
+            Some(due) => project_due(window, due),
+            None => (None, None),
+        };
+        let latest = readings.last();
+
+        Ok(UsageForecast {
+            assignment_id,
+            meter_type_id,
+            window_hours,
+            latest_reading: latest.map(|r| r.reading_value),
+            latest_recorded_at: latest.map(|r| r.recorded_at),
+            next_due_meter: row.next_due_meter,
+            next_due_date: row.next_due_date,
+            usage_rate_per_day: usage_rate_per_day.map(|r| (r * 100.0).round() / 100.0),
+            projected_due_date,
+        })
+    }
+}
+
+pub struct TriggerReadingRepo;
+
+impl TriggerReadingRepo {
+    /// Attach the readings behind a condition trigger to a work order.
+    pub async fn attach_tx(
+        tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+        tenant_id: &str,
+        work_order_id: Uuid,
+        trigger: &ConditionTrigger,
+    ) -> Result<(), sqlx::Error> {
+        for r in &trigger.readings {
+            sqlx::query(
+                r#"
+                INSERT INTO work_order_trigger_readings
+                    (tenant_id, work_order_id, rule_id, meter_reading_id,
+                     reading_value, recorded_at)
+                VALUES ($1, $2, $3, $4, $5, $6)
+                ON CONFLICT (work_order_id, meter_reading_id) DO NOTHING
+                "#,
+            )
+            .bind(tenant_id)
+            .bind(work_order_id)
+            .bind(trigger.rule_id)
+            .bind(r.meter_reading_id)
+            .bind(r.reading_value)
+            .bind(r.recorded_at)
+            .execute(&mut **tx)
+            .await?;
+        }
+        Ok(())
+    }
+
+    pub async fn list(
+        pool: &PgPool,
+        tenant_id: &str,
+        work_order_id: Uuid,
+    ) -> Result<Vec<WorkOrderTriggerReading>, ConditionError> {
+        let wo: Option<(Uuid,)> =
+            sqlx::query_as("SELECT id FROM work_orders WHERE id = $1 AND tenant_id = $2")
+                .bind(work_order_id)
+                .bind(tenant_id)
+                .fetch_optional(pool)
+                .await?;
+        if wo.is_none() {
+            return Err(ConditionError::WorkOrderNotFound);
+        }
+
+        sqlx::query_as::<_, WorkOrderTriggerReading>(
+            r#"
+            SELECT * FROM work_order_trigger_readings
+            WHERE tenant_id = $1 AND work_order_id = $2
+            ORDER BY recorded_at
+            "#,
+        )
+        .bind(tenant_id)
+        .bind(work_order_id)
+        .fetch_all(pool)
+        .await
+        .map_err(ConditionError::Database)
+    }
+}
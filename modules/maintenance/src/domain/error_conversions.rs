@@ -9,9 +9,12 @@ use platform_http_contracts::ApiError;
 use super::assets::AssetError;
 use super::calibration::CalibrationError;
 use super::calibration_events::CalibrationEventError;
+use super::condition::ConditionError;
 use super::downtime::DowntimeError;
 use super::meters::MeterError;
 use super::plans::PlanError;
+use super::reliability::ReliabilityError;
+use super::spare_parts::SparePartError;
 use super::work_orders::{WoError, WoLaborError, WoPartError};
 
 // ── AssetError ───────────────────────────────────────────────────────────
@@ -47,6 +50,7 @@ impl From<WoError> for ApiError {
             WoError::Validation(msg) => ApiError::bad_request(msg),
             WoError::Transition(e) => ApiError::new(422, "invalid_transition", e.to_string()),
             WoError::Guard(e) => ApiError::new(422, "guard_failed", e.to_string()),
+            WoError::Parts(e) => ApiError::from(e),
             WoError::Database(e) => {
                 tracing::error!(error = %e, "work order database error");
                 ApiError::internal("Database error")
@@ -68,6 +72,10 @@ impl From<WoPartError> for ApiError {
                 format!("Cannot modify parts: work order status is {}", status),
             ),
             WoPartError::Validation(msg) => ApiError::bad_request(msg),
+            WoPartError::Inventory(msg) => {
+                tracing::error!(error = %msg, "inventory call failed for work order part");
+                ApiError::new(502, "inventory_error", "Inventory integration failed")
+            }
             WoPartError::Database(e) => {
                 tracing::error!(error = %e, "work order parts database error");
                 ApiError::internal("Database error")
@@ -205,3 +213,59 @@ impl From<CalibrationError> for ApiError {
         }
     }
 }
+
+// ── SparePartError ───────────────────────────────────────────────────────
+
+impl From<SparePartError> for ApiError {
+    fn from(err: SparePartError) -> Self {
+        match err {
+            SparePartError::PlanNotFound => ApiError::not_found("Plan not found"),
+            SparePartError::PolicyNotFound => ApiError::not_found("Spare part policy not found"),
+            SparePartError::DuplicatePlanPart => {
+                ApiError::conflict("Item already listed on this plan")
+            }
+            SparePartError::Validation(msg) => ApiError::bad_request(msg),
+            SparePartError::Inventory(msg) => {
+                tracing::error!(error = %msg, "inventory reorder policy sync failed");
+                ApiError::new(502, "inventory_error", "Inventory integration failed")
+            }
+            SparePartError::Database(e) => {
+                tracing::error!(error = %e, "spare part database error");
+                ApiError::internal("Database error")
+            }
+        }
+    }
+}
+
+// ── ReliabilityError ─────────────────────────────────────────────────────
+
+impl From<ReliabilityError> for ApiError {
+    fn from(err: ReliabilityError) -> Self {
+        match err {
+            ReliabilityError::Validation(msg) => ApiError::bad_request(msg),
+            ReliabilityError::Database(e) => {
+                tracing::error!(error = %e, "reliability database error");
+                ApiError::internal("Database error")
+            }
+        }
+    }
+}
+
+// ── ConditionError ───────────────────────────────────────────────────────
+
+impl From<ConditionError> for ApiError {
+    fn from(err: ConditionError) -> Self {
+        match err {
+            ConditionError::PlanNotFound => ApiError::not_found("Plan not found"),
+            ConditionError::MeterTypeNotFound => ApiError::not_found("Meter type not found"),
+            ConditionError::RuleNotFound => ApiError::not_found("Condition rule not found"),
+            ConditionError::AssignmentNotFound => ApiError::not_found("Assignment not found"),
+            ConditionError::WorkOrderNotFound => ApiError::not_found("Work order not found"),
+            ConditionError::Validation(msg) => ApiError::bad_request(msg),
+            ConditionError::Database(e) => {
+                tracing::error!(error = %e, "condition rule database error");
+                ApiError::internal("Database error")
+            }
+        }
+    }
+}
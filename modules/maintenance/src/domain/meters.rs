@@ -2,7 +2,10 @@
 //!
 //! Invariants:
 //! - meter_type name is unique per tenant
-//! - reading_value is monotonically increasing per (tenant, asset, meter_type)
+//! - meter kind is `counter` (cumulative usage) or `gauge` (condition
+//!   measurement such as temperature, vibration or pressure)
+//! - Counter reading_value is non-negative and monotonically increasing per
+//!   (tenant, asset, meter_type); gauge readings are unconstrained
 //! - Rollover exception: accepted when prev near rollover_value and new near zero
 //! - Validation against highest reading_value, not latest timestamp
 
@@ -25,6 +28,8 @@ pub struct MeterType {
     pub rollover_value: Option<i64>,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
+    /// `counter` or `gauge`.
+    pub kind: String,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow, utoipa::ToSchema)]
@@ -49,6 +54,8 @@ pub struct CreateMeterTypeRequest {
     pub name: String,
     pub unit_label: String,
     pub rollover_value: Option<i64>,
+    /// `counter` (default) or `gauge`.
+    pub kind: Option<String>,
 }
 
 #[derive(Debug, Deserialize, utoipa::ToSchema)]
@@ -67,6 +74,9 @@ pub struct ListReadingsQuery {
     pub offset: Option<i64>,
 }
 
+pub const METER_KIND_COUNTER: &str = "counter";
+pub const METER_KIND_GAUGE: &str = "gauge";
+
 // ============================================================================
 // Errors
 // ============================================================================
@@ -170,11 +180,22 @@ impl MeterTypeRepo {
                 ));
             }
         }
+        let kind = req.kind.as_deref().unwrap_or(METER_KIND_COUNTER);
+        if kind != METER_KIND_COUNTER && kind != METER_KIND_GAUGE {
+            return Err(MeterError::Validation(
+                "kind must be 'counter' or 'gauge'".into(),
+            ));
+        }
+        if kind == METER_KIND_GAUGE && req.rollover_value.is_some() {
+            return Err(MeterError::Validation(
+                "rollover_value applies only to counter meters".into(),
+            ));
+        }
 
         sqlx::query_as::<_, MeterType>(
             r#"
-            INSERT INTO meter_types (tenant_id, name, unit_label, rollover_value)
-            VALUES ($1, $2, $3, $4)
+            INSERT INTO meter_types (tenant_id, name, unit_label, rollover_value, kind)
+            VALUES ($1, $2, $3, $4, $5)
             RETURNING *
             "#,
         )
@@ -182,6 +203,7 @@ impl MeterTypeRepo {
         .bind(req.name.trim())
         .bind(req.unit_label.trim())
         .bind(req.rollover_value)
+        .bind(kind)
         .fetch_one(pool)
         .await
         .map_err(|e| {
@@ -216,9 +238,9 @@ impl MeterReadingRepo {
     ///
     /// 1. Verify asset exists for tenant
     /// 2. Verify meter type exists for tenant
-    /// 3. Fetch previous max reading_value
-    /// 4. Validate monotonicity (with rollover exception)
-    /// 5. Insert reading + enqueue outbox event in same transaction
+    /// 3. Counters: fetch previous max reading_value and validate
+    ///    monotonicity (with rollover exception); gauges skip this
+    /// 4. Insert reading + enqueue outbox event in same transaction
     pub async fn record(
         pool: &PgPool,
         asset_id: Uuid,
@@ -227,11 +249,6 @@ impl MeterReadingRepo {
         if req.tenant_id.trim().is_empty() {
             return Err(MeterError::Validation("tenant_id is required".into()));
         }
-        if req.reading_value < 0 {
-            return Err(MeterError::Validation(
-                "reading_value must be non-negative".into(),
-            ));
-        }
 
         let mut tx = pool.begin().await?;
 
@@ -257,20 +274,28 @@ impl MeterReadingRepo {
         .await?
         .ok_or(MeterError::MeterTypeNotFound)?;
 
-        // Fetch previous max reading_value for this (tenant, asset, meter_type)
-        let prev_max_val: Option<i64> = sqlx::query_scalar(
-            r#"
-            SELECT MAX(reading_value) FROM meter_readings
-            WHERE tenant_id = $1 AND asset_id = $2 AND meter_type_id = $3
-            "#,
-        )
-        .bind(&req.tenant_id)
-        .bind(asset_id)
-        .bind(req.meter_type_id)
-        .fetch_one(&mut *tx)
-        .await?;
+        if meter_type.kind != METER_KIND_GAUGE {
+            if req.reading_value < 0 {
+                return Err(MeterError::Validation(
+                    "reading_value must be non-negative".into(),
+                ));
+            }
 
-        validate_reading(req.reading_value, prev_max_val, meter_type.rollover_value)?;
+            // Fetch previous max reading_value for this (tenant, asset, meter_type)
+            let prev_max_val: Option<i64> = sqlx::query_scalar(
+                r#"
+                SELECT MAX(reading_value) FROM meter_readings
+                WHERE tenant_id = $1 AND asset_id = $2 AND meter_type_id = $3
+                "#,
+            )
+            .bind(&req.tenant_id)
+            .bind(asset_id)
+            .bind(req.meter_type_id)
+            .fetch_one(&mut *tx)
+            .await?;
+
+            validate_reading(req.reading_value, prev_max_val, meter_type.rollover_value)?;
+        }
 
         let recorded_at = req.recorded_at.unwrap_or_else(Utc::now);
         let reading_id = Uuid::new_v4();
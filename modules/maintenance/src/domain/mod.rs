@@ -1,11 +1,14 @@
 pub mod assets;
 pub mod calibration;
 pub mod calibration_events;
+pub mod condition;
 pub mod downtime;
 pub mod error_conversions;
 pub mod meters;
 pub mod overdue;
 pub mod plans;
+pub mod reliability;
 pub mod scheduler;
+pub mod spare_parts;
 pub mod tenant_config;
 pub mod work_orders;
@@ -6,6 +6,7 @@
 //!   - calendar: calendar_interval_days required
 //!   - meter: meter_type_id + meter_interval required
 //!   - both: all three required
+//!   - condition: no interval fields; due only via condition rules
 //! - next_due fields are deterministically computed by `recompute_due`
 //! - Every query filters by tenant_id for multi-tenant isolation
 
@@ -171,6 +172,7 @@ pub enum PlanError {
 /// - meter: next_due_meter = base_meter + meter_interval
 ///   (base_meter = last_meter_reading or latest_reading or 0)
 /// - both: compute both fields
+/// - condition: neither (condition rules decide when the plan is due)
 pub fn recompute_due(
     schedule_type: ScheduleType,
     calendar_interval_days: Option<i32>,
@@ -196,6 +198,7 @@ pub fn recompute_due(
         ScheduleType::Calendar => (compute_date(), None),
         ScheduleType::Meter => (None, compute_meter()),
         ScheduleType::Both => (compute_date(), compute_meter()),
+        ScheduleType::Condition => (None, None),
     }
 }
 
@@ -318,4 +321,19 @@ mod tests {
         );
         assert_eq!(meter, Some(55_000));
     }
+
+    #[test]
+    fn condition_recompute_has_no_due_fields() {
+        let (date, meter) = recompute_due(
+            ScheduleType::Condition,
+            Some(30),
+            Some(5000),
+            None,
+            None,
+            Some(10_000),
+            Utc::now(),
+        );
+        assert_eq!(date, None);
+        assert_eq!(meter, None);
+    }
 }
@@ -53,6 +53,13 @@ impl PlanRepo {
                     ));
                 }
             }
+            ScheduleType::Condition => {
+                if req.calendar_interval_days.is_some() || req.meter_interval.is_some() {
+                    return Err(PlanError::Validation(
+                        "condition schedule takes no calendar_interval_days or meter_interval; add condition rules instead".into(),
+                    ));
+                }
+            }
         }
 
         if let Some(days) = req.calendar_interval_days {
@@ -0,0 +1,376 @@
+//! Pure reliability computations over a time window.
+//!
+//! All functions take pre-loaded asset and failure records so they can be
+//! unit tested without a database.
+//!
+//! Definitions, per asset, for the window `[from, to)`:
+//! - operating time = the full window
+//! - downtime       = union of failure intervals clipped to the window
+//!   (an unrestored failure runs to the end of the window)
+//! - uptime         = operating time − downtime
+//! - failures       = failure records that start inside the window
+//! - MTBF           = uptime / failures
+//! - MTTR           = mean repair time of restored failures in the window
+//! - MTTF           = mean up interval preceding each failure (from the
+//!   window start or the previous restoration)
+//! - availability   = uptime / operating time
+//!
+//! Aggregates (asset class, failure code, tenant) sum the per-asset totals
+//! before dividing, so every asset is weighted by its operating time.
+
+use chrono::{DateTime, Duration, Months, Utc};
+use std::collections::BTreeMap;
+use uuid::Uuid;
+
+use super::{FailureParetoEntry, ReliabilityMetrics, TrendBucket};
+
+/// Asset in scope of a reliability query.
+#[derive(Debug, Clone)]
+pub struct AssetScope {
+    pub asset_id: Uuid,
+    pub asset_tag: String,
+    pub asset_type: String,
+}
+
+/// A failure of an asset: unplanned downtime or a completed corrective
+/// work order. `restored_at` is `None` while the asset is still down.
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct FailureRecord {
+    pub asset_id: Uuid,
+    pub failure_code: String,
+    pub failed_at: DateTime<Utc>,
+    pub restored_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Default)]
+struct Totals {
+    operating_secs: i64,
+    downtime_secs: i64,
+    failures: i64,
+    repairs: i64,
+    repair_secs: i64,
+    ttf_secs: i64,
+}
+
+fn hours(secs: i64) -> f64 {
+    secs as f64 / 3600.0
+}
+
+fn round_to(v: f64, dp: i32) -> f64 {
+    let f = 10f64.powi(dp);
+    (v * f).round() / f
+}
+
+impl Totals {
+    fn into_metrics(self) -> ReliabilityMetrics {
+        let uptime_secs = self.operating_secs - self.downtime_secs;
+        let per_failure = |secs: i64| {
+            (self.failures > 0).then(|| round_to(hours(secs) / self.failures as f64, 2))
+        };
+        ReliabilityMetrics {
+            failure_count: self.failures,
+            repair_count: self.repairs,
+            operating_hours: round_to(hours(self.operating_secs), 2),
+            downtime_hours: round_to(hours(self.downtime_secs), 2),
+            uptime_hours: round_to(hours(uptime_secs), 2),
+            mtbf_hours: per_failure(uptime_secs),
+            mttr_hours: (self.repairs > 0)
+                .then(|| round_to(hours(self.repair_secs) / self.repairs as f64, 2)),
+            mttf_hours: per_failure(self.ttf_secs),
+            availability: (self.operating_secs > 0)
+                .then(|| round_to(uptime_secs as f64 / self.operating_secs as f64, 4)),
+        }
+    }
+}
+
+/// Accumulate one asset's totals. `records` must belong to the asset.
+fn asset_totals(
+    from: DateTime<Utc>,
+    to: DateTime<Utc>,
+    records: &[&FailureRecord],
+    totals: &mut Totals,
+) {
+    totals.operating_secs += (to - from).num_seconds();
+
+    let mut sorted: Vec<&FailureRecord> = records.to_vec();
+    sorted.sort_by_key(|r| r.failed_at);
+
+    // Downtime: merge overlapping intervals clipped to the window
+    let mut cursor = from;
+    for r in &sorted {
+        let start = r.failed_at.max(cursor);
+        let end = r.restored_at.unwrap_or(to).min(to);
+        if end > start {
+            totals.downtime_secs += (end - start).num_seconds();
+            cursor = end;
+        }
+    }
+
+    // Failures, repairs and time-to-failure
+    let mut last_restored = from;
+    for r in &sorted {
+        let restored = r.restored_at.unwrap_or(to).min(to);
+        if r.failed_at >= from && r.failed_at < to {
+            totals.failures += 1;
+            totals.ttf_secs += (r.failed_at - last_restored).num_seconds().max(0);
+            if let Some(restored_at) = r.restored_at {
+                totals.repairs += 1;
+                totals.repair_secs += (restored_at - r.failed_at).num_seconds().max(0);
+            }
+        }
+        last_restored = last_restored.max(restored);
+    }
+}
+
+/// Compute metrics for a set of assets, counting only `records` that match
+/// `include`. Records for assets outside `assets` are ignored.
+pub fn compute(
+    from: DateTime<Utc>,
+    to: DateTime<Utc>,
+    assets: &[AssetScope],
+    records: &[FailureRecord],
+    include: impl Fn(&FailureRecord) -> bool,
+) -> ReliabilityMetrics {
+    let mut by_asset: BTreeMap<Uuid, Vec<&FailureRecord>> = BTreeMap::new();
+    for r in records.iter().filter(|r| include(r)) {
+        by_asset.entry(r.asset_id).or_default().push(r);
+    }
+
+    let mut totals = Totals::default();
+    for a in assets {
+        let recs = by_asset.get(&a.asset_id).map(Vec::as_slice).unwrap_or(&[]);
+        asset_totals(from, to, recs, &mut totals);
+    }
+    totals.into_metrics()
+}
+
+/// Failure codes ranked by failure count (then downtime), with each code's
+/// share of all failures and the running cumulative share.
+pub fn pareto(
+    from: DateTime<Utc>,
+    to: DateTime<Utc>,
+    assets: &[AssetScope],
+    records: &[FailureRecord],
+) -> Vec<FailureParetoEntry> {
+    let codes: std::collections::BTreeSet<&str> =
+        records.iter().map(|r| r.failure_code.as_str()).collect();
+
+    let mut entries: Vec<FailureParetoEntry> = codes
+        .into_iter()
+        .map(|code| {
+            let m = compute(from, to, assets, records, |r| r.failure_code == code);
+            FailureParetoEntry {
+                failure_code: code.to_string(),
+                failure_count: m.failure_count,
+                downtime_hours: m.downtime_hours,
+                share_pct: 0.0,
+                cumulative_pct: 0.0,
+            }
+        })
+        .filter(|e| e.failure_count > 0)
+        .collect();
+
+    entries.sort_by(|a, b| {
+        b.failure_count
+            .cmp(&a.failure_count)
+            .then(b.downtime_hours.total_cmp(&a.downtime_hours))
+            .then(a.failure_code.cmp(&b.failure_code))
+    });
+
+    let total: i64 = entries.iter().map(|e| e.failure_count).sum();
+    let mut running = 0;
+    for e in &mut entries {
+        running += e.failure_count;
+        e.share_pct = round_to(e.failure_count as f64 * 100.0 / total as f64, 2);
+        e.cumulative_pct = round_to(running as f64 * 100.0 / total as f64, 2);
+    }
+    entries
+}
+
+/// Split `[from, to)` into consecutive buckets; the last bucket is clipped
+/// to `to`.
+pub fn buckets(
+    from: DateTime<Utc>,
+    to: DateTime<Utc>,
+    bucket: TrendBucket,
+) -> Vec<(DateTime<Utc>, DateTime<Utc>)> {
+    let mut out = Vec::new();
+    let mut start = from;
+    let mut n: u32 = 0;
+    while start < to {
+        n += 1;
+        let next = match bucket {
+            TrendBucket::Day => from + Duration::days(n as i64),
+            TrendBucket::Week => from + Duration::weeks(n as i64),
+            // Offset from `from` so month-end starts don't drift
+            TrendBucket::Month => from.checked_add_months(Months::new(n)).unwrap_or(to),
+        };
+        let end = next.min(to);
+        out.push((start, end));
+        start = end;
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn t(day: u32, hour: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2026, 3, day, hour, 0, 0).unwrap()
+    }
+
+    fn asset(id: Uuid, ty: &str) -> AssetScope {
+        AssetScope {
+            asset_id: id,
+            asset_tag: id.to_string(),
+            asset_type: ty.into(),
+        }
+    }
+
+    fn failure(
+        asset_id: Uuid,
+        code: &str,
+        failed_at: DateTime<Utc>,
+        restored_at: Option<DateTime<Utc>>,
+    ) -> FailureRecord {
+        FailureRecord {
+            asset_id,
+            failure_code: code.into(),
+            failed_at,
+            restored_at,
+        }
+    }
+
+    #[test]
+    fn no_failures_is_fully_available() {
+        let a = Uuid::new_v4();
+        let m = compute(t(1, 0), t(11, 0), &[asset(a, "machinery")], &[], |_| true);
+        assert_eq!(m.operating_hours, 240.0);
+        assert_eq!(m.failure_count, 0);
+        assert_eq!(m.mtbf_hours, None);
+        assert_eq!(m.mttr_hours, None);
+        assert_eq!(m.availability, Some(1.0));
+    }
+
+    #[test]
+    fn mtbf_mttr_mttf_and_availability() {
+        let a = Uuid::new_v4();
+        // 100h window; failures at 10h (2h repair) and 60h (8h repair)
+        let from = t(1, 0);
+        let to = from + Duration::hours(100);
+        let recs = vec![
+            failure(
+                a,
+                "bearing",
+                from + Duration::hours(10),
+                Some(from + Duration::hours(12)),
+            ),
+            failure(
+                a,
+                "seal",
+                from + Duration::hours(60),
+                Some(from + Duration::hours(68)),
+            ),
+        ];
+        let m = compute(from, to, &[asset(a, "machinery")], &recs, |_| true);
+        assert_eq!(m.failure_count, 2);
+        assert_eq!(m.downtime_hours, 10.0);
+        assert_eq!(m.uptime_hours, 90.0);
+        assert_eq!(m.mtbf_hours, Some(45.0));
+        assert_eq!(m.mttr_hours, Some(5.0));
+        // Up intervals before each failure: 10h and 48h
+        assert_eq!(m.mttf_hours, Some(29.0));
+        assert_eq!(m.availability, Some(0.9));
+    }
+
+    #[test]
+    fn open_and_overlapping_downtime_is_clipped_and_merged() {
+        let a = Uuid::new_v4();
+        let from = t(1, 0);
+        let to = from + Duration::hours(10);
+        let recs = vec![
+            // Started before the window: downtime counted, failure not
+            failure(
+                a,
+                "x",
+                from - Duration::hours(5),
+                Some(from + Duration::hours(2)),
+            ),
+            // Overlaps the first interval by 1h
+            failure(
+                a,
+                "y",
+                from + Duration::hours(1),
+                Some(from + Duration::hours(3)),
+            ),
+            // Still down at window end
+            failure(a, "z", from + Duration::hours(8), None),
+        ];
+        let m = compute(from, to, &[asset(a, "vehicle")], &recs, |_| true);
+        assert_eq!(m.failure_count, 2);
+        assert_eq!(m.repair_count, 1);
+        assert_eq!(m.downtime_hours, 5.0);
+        assert_eq!(m.mttr_hours, Some(2.0));
+        assert_eq!(m.availability, Some(0.5));
+    }
+
+    #[test]
+    fn aggregates_weight_by_operating_time() {
+        let (a, b) = (Uuid::new_v4(), Uuid::new_v4());
+        let from = t(1, 0);
+        let to = from + Duration::hours(100);
+        let recs = vec![failure(
+            a,
+            "x",
+            from + Duration::hours(50),
+            Some(from + Duration::hours(70)),
+        )];
+        let m = compute(
+            from,
+            to,
+            &[asset(a, "vehicle"), asset(b, "vehicle")],
+            &recs,
+            |_| true,
+        );
+        assert_eq!(m.operating_hours, 200.0);
+        assert_eq!(m.mtbf_hours, Some(180.0));
+        assert_eq!(m.availability, Some(0.9));
+    }
+
+    #[test]
+    fn pareto_ranks_codes_with_cumulative_share() {
+        let a = Uuid::new_v4();
+        let from = t(1, 0);
+        let to = t(11, 0);
+        let recs = vec![
+            failure(a, "seal", t(2, 0), Some(t(2, 1))),
+            failure(a, "bearing", t(3, 0), Some(t(3, 4))),
+            failure(a, "bearing", t(4, 0), Some(t(4, 2))),
+            failure(a, "belt", t(5, 0), Some(t(5, 3))),
+        ];
+        let p = pareto(from, to, &[asset(a, "machinery")], &recs);
+        let codes: Vec<&str> = p.iter().map(|e| e.failure_code.as_str()).collect();
+        assert_eq!(codes, vec!["bearing", "belt", "seal"]);
+        assert_eq!(p[0].share_pct, 50.0);
+        assert_eq!(p[0].downtime_hours, 6.0);
+        assert_eq!(p[1].cumulative_pct, 75.0);
+        assert_eq!(p[2].cumulative_pct, 100.0);
+    }
+
+    #[test]
+    fn buckets_cover_window_and_clip_last() {
+        let b = buckets(t(1, 0), t(10, 12), TrendBucket::Week);
+        assert_eq!(b.len(), 2);
+        assert_eq!(b[0], (t(1, 0), t(8, 0)));
+        assert_eq!(b[1], (t(8, 0), t(10, 12)));
+
+        let from = Utc.with_ymd_and_hms(2026, 1, 31, 0, 0, 0).unwrap();
+        let to = Utc.with_ymd_and_hms(2026, 4, 30, 0, 0, 0).unwrap();
+        let m = buckets(from, to, TrendBucket::Month);
+        assert_eq!(m.len(), 3);
+        assert_eq!(m[0].1, Utc.with_ymd_and_hms(2026, 2, 28, 0, 0, 0).unwrap());
+        assert_eq!(m[1].1, Utc.with_ymd_and_hms(2026, 3, 31, 0, 0, 0).unwrap());
+    }
+}
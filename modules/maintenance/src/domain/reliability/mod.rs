@@ -0,0 +1,150 @@
+//! Reliability analytics — MTBF, MTTR, MTTF and availability.
+//!
+//! Invariants:
+//! - Metrics are computed on read from downtime events and work order
+//!   history; nothing is stored in the maintenance database
+//! - A failure is either unplanned downtime on an asset (impact other than
+//!   `none`, not linked to a preventive or inspection work order) or a
+//!   completed corrective work order with no linked downtime event
+//! - Snapshots publish computed metrics to the outbox for the reporting caches
+//! - Every query filters by tenant_id for multi-tenant isolation
+
+pub mod metrics;
+mod repo;
+
+pub use repo::ReliabilityRepo;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+use uuid::Uuid;
+
+/// Longest window accepted by reliability queries.
+pub const MAX_WINDOW_DAYS: i64 = 3660;
+
+/// Most trend buckets returned in one response.
+pub const MAX_TREND_BUCKETS: usize = 400;
+
+// ============================================================================
+// Domain models
+// ============================================================================
+
+/// Reliability figures for one scope over a window. Hours are rounded to
+/// two decimals, availability to four.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, utoipa::ToSchema)]
+pub struct ReliabilityMetrics {
+    pub failure_count: i64,
+    /// Failures in the window that have been restored.
+    pub repair_count: i64,
+    pub operating_hours: f64,
+    pub downtime_hours: f64,
+    pub uptime_hours: f64,
+    /// Mean time between failures; `null` when there were no failures.
+    pub mtbf_hours: Option<f64>,
+    /// Mean time to repair; `null` when no failure was restored.
+    pub mttr_hours: Option<f64>,
+    /// Mean time to failure; `null` when there were no failures.
+    pub mttf_hours: Option<f64>,
+    /// Uptime / operating time (0–1).
+    pub availability: Option<f64>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, utoipa::ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ReliabilityGroupBy {
+    Asset,
+    AssetType,
+    FailureCode,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, utoipa::ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum TrendBucket {
+    Day,
+    Week,
+    Month,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, utoipa::ToSchema)]
+pub struct ReliabilityGroup {
+    /// Asset id, asset type or failure code, depending on the grouping.
+    pub key: String,
+    /// Asset tag when grouped by asset.
+    pub label: Option<String>,
+    pub metrics: ReliabilityMetrics,
+}
+
+#[derive(Debug, Clone, Serialize, utoipa::ToSchema)]
+pub struct ReliabilityReport {
+    pub from: DateTime<Utc>,
+    pub to: DateTime<Utc>,
+    pub group_by: Option<ReliabilityGroupBy>,
+    pub overall: ReliabilityMetrics,
+    pub groups: Vec<ReliabilityGroup>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, utoipa::ToSchema)]
+pub struct FailureParetoEntry {
+    pub failure_code: String,
+    pub failure_count: i64,
+    pub downtime_hours: f64,
+    /// Share of all failures in the window (percent).
+    pub share_pct: f64,
+    /// Running share including this and all higher-ranked codes (percent).
+    pub cumulative_pct: f64,
+}
+
+#[derive(Debug, Clone, Serialize, utoipa::ToSchema)]
+pub struct ReliabilityTrendPoint {
+    pub bucket_start: DateTime<Utc>,
+    pub bucket_end: DateTime<Utc>,
+    pub metrics: ReliabilityMetrics,
+}
+
+/// Published reliability snapshot, as carried by the
+/// `maintenance.reliability.snapshot` event.
+#[derive(Debug, Clone, Serialize, utoipa::ToSchema)]
+pub struct ReliabilitySnapshot {
+    pub event_id: Uuid,
+    pub from: DateTime<Utc>,
+    pub to: DateTime<Utc>,
+    pub overall: ReliabilityMetrics,
+    pub assets: Vec<ReliabilityGroup>,
+    pub asset_types: Vec<ReliabilityGroup>,
+    pub pareto: Vec<FailureParetoEntry>,
+}
+
+// ============================================================================
+// Request types
+// ============================================================================
+
+/// Scope and window shared by all reliability queries.
+#[derive(Debug, Clone)]
+pub struct ReliabilityQuery {
+    pub tenant_id: String,
+    pub from: DateTime<Utc>,
+    pub to: DateTime<Utc>,
+    pub asset_id: Option<Uuid>,
+    pub asset_type: Option<String>,
+    pub failure_code: Option<String>,
+}
+
+#[derive(Debug, Deserialize, utoipa::ToSchema)]
+pub struct PublishSnapshotRequest {
+    pub tenant_id: String,
+    pub from: DateTime<Utc>,
+    pub to: DateTime<Utc>,
+}
+
+// ============================================================================
+// Errors
+// ============================================================================
+
+#[derive(Debug, Error)]
+pub enum ReliabilityError {
+    #[error("Validation error: {0}")]
+    Validation(String),
+
+    #[error("Database error: {0}")]
+    Database(#[from] sqlx::Error),
+}
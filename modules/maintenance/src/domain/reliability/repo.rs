@@ -0,0 +1,325 @@
+//! Reliability repository — loads assets and failure history and runs the
+//! pure computations in [`super::metrics`].
+
+use chrono::Duration;
+use sqlx::PgPool;
+use std::collections::BTreeSet;
+use uuid::Uuid;
+
+use super::metrics::{self, AssetScope, FailureRecord};
+use super::{
+    FailureParetoEntry, PublishSnapshotRequest, ReliabilityError, ReliabilityGroup,
+    ReliabilityGroupBy, ReliabilityQuery, ReliabilityReport, ReliabilitySnapshot,
+    ReliabilityTrendPoint, TrendBucket, MAX_TREND_BUCKETS, MAX_WINDOW_DAYS,
+};
+use crate::domain::work_orders::types::AssetType;
+use crate::events::{envelope, subjects};
+use crate::outbox;
+
+/// Failure code used when neither the downtime event nor the work order
+/// carries one.
+const UNSPECIFIED_FAILURE_CODE: &str = "unspecified";
+
+#[derive(sqlx::FromRow)]
+struct AssetRow {
+    id: Uuid,
+    asset_tag: String,
+    asset_type: String,
+}
+
+/// Loaded scope: assets plus failure records overlapping the window.
+struct Dataset {
+    assets: Vec<AssetScope>,
+    records: Vec<FailureRecord>,
+}
+
+pub struct ReliabilityRepo;
+
+impl ReliabilityRepo {
+    fn validate(q: &ReliabilityQuery) -> Result<(), ReliabilityError> {
+        if q.tenant_id.trim().is_empty() {
+            return Err(ReliabilityError::Validation("tenant_id is required".into()));
+        }
+        if q.to <= q.from {
+            return Err(ReliabilityError::Validation("to must be after from".into()));
+        }
+        if q.to - q.from > Duration::days(MAX_WINDOW_DAYS) {
+            return Err(ReliabilityError::Validation(format!(
+                "window must not exceed {} days",
+                MAX_WINDOW_DAYS
+            )));
+        }
+        if let Some(ref at) = q.asset_type {
+            AssetType::from_str_value(at)
+                .map_err(|e| ReliabilityError::Validation(e.to_string()))?;
+        }
+        Ok(())
+    }
+
+    async fn load(pool: &PgPool, q: &ReliabilityQuery) -> Result<Dataset, ReliabilityError> {
+        let assets: Vec<AssetScope> = sqlx::query_as::<_, AssetRow>(
+            r#"
+            SELECT id, asset_tag, asset_type FROM maintainable_assets
+            WHERE tenant_id = $1
+              AND ($2::uuid IS NULL OR id = $2)
+              AND ($3::text IS NULL OR asset_type = $3)
+            ORDER BY asset_tag
+            "#,
+        )
+        .bind(&q.tenant_id)
+        .bind(q.asset_id)
+        .bind(q.asset_type.as_deref())
+        .fetch_all(pool)
+        .await?
+        .into_iter()
+        .map(|r| AssetScope {
+            asset_id: r.id,
+            asset_tag: r.asset_tag,
+            asset_type: r.asset_type,
+        })
+        .collect();
+
+        if assets.is_empty() {
+            return Ok(Dataset {
+                assets,
+                records: Vec::new(),
+            });
+        }
+        let asset_ids: Vec<Uuid> = assets.iter().map(|a| a.asset_id).collect();
+
+        // Unplanned downtime (not linked to preventive/inspection work) plus
+        // completed corrective work orders that no downtime event references.
+        // Corrective work orders are down for downtime_minutes ending at
+        // completion.
+        let mut records = sqlx::query_as::<_, FailureRecord>(
+            r#"
+            SELECT d.asset_id AS asset_id,
+                   COALESCE(d.reason_code, w.failure_code, $5) AS failure_code,
+                   d.start_time AS failed_at,
+                   d.end_time AS restored_at
+            FROM downtime_events d
+            LEFT JOIN work_orders w
+                   ON w.tenant_id = d.tenant_id AND w.wo_number = d.wo_ref
+            WHERE d.tenant_id = $1
+              AND d.asset_id = ANY($2)
+              AND d.impact_classification <> 'none'
+              AND (w.id IS NULL OR w.wo_type = 'corrective')
+              AND d.start_time < $4
+              AND (d.end_time IS NULL OR d.end_time > $3)
+            UNION ALL
+            SELECT w.asset_id,
+                   COALESCE(w.failure_code, $5),
+                   w.completed_at - make_interval(mins => COALESCE(w.downtime_minutes, 0)),
+                   w.completed_at
+            FROM work_orders w
+            WHERE w.tenant_id = $1
+              AND w.asset_id = ANY($2)
+              AND w.wo_type = 'corrective'
+              AND w.status IN ('completed', 'closed')
+              AND w.completed_at IS NOT NULL
+              AND w.completed_at - make_interval(mins => COALESCE(w.downtime_minutes, 0)) < $4
+              AND w.completed_at >= $3
+              AND NOT EXISTS (
+                  SELECT 1 FROM downtime_events d
+                  WHERE d.tenant_id = w.tenant_id AND d.wo_ref = w.wo_number
+              )
+            "#,
+        )
+        .bind(&q.tenant_id)
+        .bind(&asset_ids)
+        .bind(q.from)
+        .bind(q.to)
+        .bind(UNSPECIFIED_FAILURE_CODE)
+        .fetch_all(pool)
+        .await?;
+
+        if let Some(ref code) = q.failure_code {
+            records.retain(|r| &r.failure_code == code);
+        }
+        Ok(Dataset { assets, records })
+    }
+
+    fn groups(
+        q: &ReliabilityQuery,
+        ds: &Dataset,
+        group_by: ReliabilityGroupBy,
+    ) -> Vec<ReliabilityGroup> {
+        match group_by {
+            ReliabilityGroupBy::Asset => ds
+                .assets
+                .iter()
+                .map(|a| ReliabilityGroup {
+                    key: a.asset_id.to_string(),
+                    label: Some(a.asset_tag.clone()),
+                    metrics: metrics::compute(
+                        q.from,
+                        q.to,
+                        std::slice::from_ref(a),
+                        &ds.records,
+                        |_| true,
+                    ),
+                })
+                .collect(),
+            ReliabilityGroupBy::AssetType => {
+                let types: BTreeSet<&str> =
+                    ds.assets.iter().map(|a| a.asset_type.as_str()).collect();
+                types
+                    .into_iter()
+                    .map(|ty| {
+                        let scoped: Vec<AssetScope> = ds
+                            .assets
+                            .iter()
+                            .filter(|a| a.asset_type == ty)
+                            .cloned()
+                            .collect();
+                        ReliabilityGroup {
+                            key: ty.to_string(),
+                            label: None,
+                            metrics: metrics::compute(q.from, q.to, &scoped, &ds.records, |_| true),
+                        }
+                    })
+                    .collect()
+            }
+            ReliabilityGroupBy::FailureCode => {
+                let codes: BTreeSet<&str> =
+                    ds.records.iter().map(|r| r.failure_code.as_str()).collect();
+                codes
+                    .into_iter()
+                    .map(|code| ReliabilityGroup {
+                        key: code.to_string(),
+                        label: None,
+                        metrics: metrics::compute(q.from, q.to, &ds.assets, &ds.records, |r| {
+                            r.failure_code == code
+                        }),
+                    })
+                    .filter(|g| g.metrics.failure_count > 0)
+                    .collect()
+            }
+        }
+    }
+
+    /// Metrics for the whole scope, optionally broken down by asset, asset
+    /// type or failure code.
+    pub async fn report(
+        pool: &PgPool,
+        q: &ReliabilityQuery,
+        group_by: Option<ReliabilityGroupBy>,
+    ) -> Result<ReliabilityReport, ReliabilityError> {
+        Self::validate(q)?;
+        let ds = Self::load(pool, q).await?;
+
+        let overall = metrics::compute(q.from, q.to, &ds.assets, &ds.records, |_| true);
+        let groups = group_by
+            .map(|g| Self::groups(q, &ds, g))
+            .unwrap_or_default();
+
+        Ok(ReliabilityReport {
+            from: q.from,
+            to: q.to,
+            group_by,
+            overall,
+            groups,
+        })
+    }
+
+    /// Failure causes ranked by frequency.
+    pub async fn pareto(
+        pool: &PgPool,
+        q: &ReliabilityQuery,
+    ) -> Result<Vec<FailureParetoEntry>, ReliabilityError> {
+        Self::validate(q)?;
+        let ds = Self::load(pool, q).await?;
+        Ok(metrics::pareto(q.from, q.to, &ds.assets, &ds.records))
+    }
+
+    /// Metrics per consecutive bucket of the window.
+    pub async fn trend(
+        pool: &PgPool,
+        q: &ReliabilityQuery,
+        bucket: TrendBucket,
+    ) -> Result<Vec<ReliabilityTrendPoint>, ReliabilityError> {
+        Self::validate(q)?;
+        let windows = metrics::buckets(q.from, q.to, bucket);
+        if windows.len() > MAX_TREND_BUCKETS {
+            return Err(ReliabilityError::Validation(format!(
+                "trend would return {} buckets; at most {} allowed",
+                windows.len(),
+                MAX_TREND_BUCKETS
+            )));
+        }
+        let ds = Self::load(pool, q).await?;
+
+        Ok(windows
+            .into_iter()
+            .map(|(start, end)| ReliabilityTrendPoint {
+                bucket_start: start,
+                bucket_end: end,
+                metrics: metrics::compute(start, end, &ds.assets, &ds.records, |_| true),
+            })
+            .collect())
+    }
+
+    /// Compute tenant-wide, per-asset and per-asset-type metrics plus the
+    /// failure Pareto for a window and publish them via the outbox.
+    pub async fn publish_snapshot(
+        pool: &PgPool,
+        req: &PublishSnapshotRequest,
+    ) -> Result<ReliabilitySnapshot, ReliabilityError> {
+        let q = ReliabilityQuery {
+            tenant_id: req.tenant_id.clone(),
+            from: req.from,
+            to: req.to,
+            asset_id: None,
+            asset_type: None,
+            failure_code: None,
+        };
+        Self::validate(&q)?;
+        let ds = Self::load(pool, &q).await?;
+
+        let snapshot = ReliabilitySnapshot {
+            event_id: Uuid::new_v4(),
+            from: q.from,
+            to: q.to,
+            overall: metrics::compute(q.from, q.to, &ds.assets, &ds.records, |_| true),
+            assets: Self::groups(&q, &ds, ReliabilityGroupBy::Asset),
+            asset_types: Self::groups(&q, &ds, ReliabilityGroupBy::AssetType),
+            pareto: metrics::pareto(q.from, q.to, &ds.assets, &ds.records),
+        };
+
+        let payload = serde_json::json!({
+            "tenant_id": &q.tenant_id,
+            "period_start": snapshot.from,
+            "period_end": snapshot.to,
+            "overall": &snapshot.overall,
+            "assets": &snapshot.assets,
+            "asset_types": &snapshot.asset_types,
+            "pareto": &snapshot.pareto,
+        });
+        let env = envelope::create_envelope(
+            snapshot.event_id,
+            q.tenant_id.clone(),
+            subjects::RELIABILITY_SNAPSHOT.to_string(),
+            payload,
+        );
+        let env_json = envelope::validate_envelope(&env)
+            .map_err(|e| ReliabilityError::Validation(format!("envelope: {}", e)))?;
+
+        let mut tx = pool.begin().await?;
+        outbox::enqueue_event_tx(
+            &mut tx,
+            snapshot.event_id,
+            subjects::RELIABILITY_SNAPSHOT,
+            "reliability_snapshot",
+            &format!(
+                "{}:{}",
+                snapshot.from.to_rfc3339(),
+                snapshot.to.to_rfc3339()
+            ),
+            &env_json,
+        )
+        .await?;
+        tx.commit().await?;
+
+        Ok(snapshot)
+    }
+}
@@ -11,18 +11,30 @@
 //!   - approvals_required=true → WO starts as awaiting_approval; else scheduled.
 //! - Idempotency: due_notified_at prevents re-emission until reset (after WO completion).
 //! - FOR UPDATE SKIP LOCKED prevents concurrent scheduler instances from double-processing.
+//! - A second pass evaluates condition rules (threshold, rate of change, moving
+//!   average, usage forecast) for assignments not yet notified. A firing rule
+//!   emits the same event with `trigger_type` condition|forecast and the
+//!   triggering readings, which are also attached to any auto-created WO.
 
-use chrono::{NaiveDate, Utc};
+use chrono::{DateTime, NaiveDate, Utc};
 use serde::Serialize;
 use sqlx::PgPool;
+use std::collections::HashMap;
 use uuid::Uuid;
 
+use super::condition::{
+    evaluate, ConditionRuleRepo, ConditionRuleType, ConditionTrigger, ReadingScope,
+    TriggerReadingRepo,
+};
+
 /// Result of a single scheduler tick.
 #[derive(Debug, Default)]
 pub struct TickResult {
     pub evaluated: usize,
     pub events_emitted: usize,
     pub work_orders_created: usize,
+    /// Due events raised by condition rules (included in events_emitted).
+    pub condition_triggers: usize,
 }
 
 /// A due assignment row returned by the finder query.
@@ -34,6 +46,7 @@ struct DueAssignment {
     asset_id: Uuid,
     next_due_date: Option<NaiveDate>,
     next_due_meter: Option<i64>,
+    last_completed_at: Option<DateTime<Utc>>,
     // Joined from maintenance_plans
     plan_name: String,
     plan_priority: String,
@@ -54,6 +67,8 @@ struct PlanDueEvent {
     next_due_meter: Option<i64>,
     plan_name: String,
     plan_priority: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    condition: Option<ConditionTrigger>,
 }
 
 /// Determine which trigger fired for a due assignment.
@@ -88,6 +103,7 @@ pub async fn evaluate_due(pool: &PgPool) -> Result<TickResult, sqlx::Error> {
             a.asset_id,
             a.next_due_date,
             a.next_due_meter,
+            a.last_completed_at,
             p.name AS plan_name,
             p.priority AS plan_priority,
             p.schedule_type,
@@ -152,128 +168,249 @@ pub async fn evaluate_due(pool: &PgPool) -> Result<TickResult, sqlx::Error> {
 
         let trigger = classify_trigger(&assignment.schedule_type, date_due, meter_due);
 
-        let event = PlanDueEvent {
-            assignment_id: assignment.id,
-            plan_id: assignment.plan_id,
-            asset_id: assignment.asset_id,
-            tenant_id: assignment.tenant_id.clone(),
-            trigger_type: trigger.to_string(),
-            next_due_date: assignment.next_due_date,
-            next_due_meter: assignment.next_due_meter,
-            plan_name: assignment.plan_name.clone(),
-            plan_priority: assignment.plan_priority.clone(),
-        };
+        if let Some(wo_created) = emit_due(pool, assignment, trigger, None).await? {
+            result.events_emitted += 1;
+            if wo_created {
+                result.work_orders_created += 1;
+            }
+        }
+    }
 
-        // Atomic: enqueue event + mark notified in same transaction
-        let mut tx = pool.begin().await?;
+    evaluate_conditions(pool, today, &mut result).await?;
 
-        // Lock this specific assignment row to prevent concurrent processing
-        let locked: Option<(Uuid,)> = sqlx::query_as(
-            r#"
-            SELECT id FROM maintenance_plan_assignments
-            WHERE id = $1 AND due_notified_at IS NULL
-            FOR UPDATE SKIP LOCKED
-            "#,
-        )
-        .bind(assignment.id)
-        .fetch_optional(&mut *tx)
-        .await?;
+    Ok(result)
+}
 
-        if locked.is_none() {
-            // Another scheduler instance already processed this
-            tx.rollback().await?;
-            continue;
-        }
+/// Emit `maintenance.plan.due` for one assignment and auto-create a WO when
+/// the tenant enables it, atomically with setting due_notified_at.
+///
+/// Returns None when another scheduler instance already handled the
+/// assignment, otherwise whether a work order was created.
+async fn emit_due(
+    pool: &PgPool,
+    assignment: &DueAssignment,
+    trigger: &str,
+    condition: Option<&ConditionTrigger>,
+) -> Result<Option<bool>, sqlx::Error> {
+    let event = PlanDueEvent {
+        assignment_id: assignment.id,
+        plan_id: assignment.plan_id,
+        asset_id: assignment.asset_id,
+        tenant_id: assignment.tenant_id.clone(),
+        trigger_type: trigger.to_string(),
+        next_due_date: assignment.next_due_date,
+        next_due_meter: assignment.next_due_meter,
+        plan_name: assignment.plan_name.clone(),
+        plan_priority: assignment.plan_priority.clone(),
+        condition: condition.cloned(),
+    };
+
+    // Atomic: enqueue event + mark notified in same transaction
+    let mut tx = pool.begin().await?;
+
+    // Lock this specific assignment row to prevent concurrent processing
+    let locked: Option<(Uuid,)> = sqlx::query_as(
+        r#"
+        SELECT id FROM maintenance_plan_assignments
+        WHERE id = $1 AND due_notified_at IS NULL
+        FOR UPDATE SKIP LOCKED
+        "#,
+    )
+    .bind(assignment.id)
+    .fetch_optional(&mut *tx)
+    .await?;
 
-        let sched_event_id = Uuid::new_v4();
-        let env = crate::events::envelope::create_envelope(
-            sched_event_id,
-            assignment.tenant_id.clone(),
-            crate::events::subjects::PLAN_DUE.to_string(),
-            &event,
-        );
-        let env_json = crate::events::envelope::validate_envelope(&env).map_err(|e| {
-            sqlx::Error::Encode(Box::new(std::io::Error::new(
-                std::io::ErrorKind::InvalidData,
-                format!("Envelope validation: {}", e),
-            )))
-        })?;
-        crate::outbox::enqueue_event_tx(
-            &mut tx,
-            sched_event_id,
-            crate::events::subjects::PLAN_DUE,
-            "plan_assignment",
-            &assignment.id.to_string(),
-            &env_json,
-        )
-        .await?;
-
-        sqlx::query(
-            r#"
-            UPDATE maintenance_plan_assignments
-            SET due_notified_at = $2, updated_at = $2
-            WHERE id = $1
-            "#,
-        )
-        .bind(assignment.id)
-        .bind(Utc::now())
-        .execute(&mut *tx)
-        .await?;
+    if locked.is_none() {
+        // Another scheduler instance already processed this
+        tx.rollback().await?;
+        return Ok(None);
+    }
 
-        // Auto-create work order if tenant config enables it
-        let config = super::tenant_config::TenantConfigRepo::get_or_default_tx(
+    let sched_event_id = Uuid::new_v4();
+    let env = crate::events::envelope::create_envelope(
+        sched_event_id,
+        assignment.tenant_id.clone(),
+        crate::events::subjects::PLAN_DUE.to_string(),
+        &event,
+    );
+    let env_json = crate::events::envelope::validate_envelope(&env).map_err(|e| {
+        sqlx::Error::Encode(Box::new(std::io::Error::new(
+            std::io::ErrorKind::InvalidData,
+            format!("Envelope validation: {}", e),
+        )))
+    })?;
+    crate::outbox::enqueue_event_tx(
+        &mut tx,
+        sched_event_id,
+        crate::events::subjects::PLAN_DUE,
+        "plan_assignment",
+        &assignment.id.to_string(),
+        &env_json,
+    )
+    .await?;
+
+    sqlx::query(
+        r#"
+        UPDATE maintenance_plan_assignments
+        SET due_notified_at = $2, updated_at = $2
+        WHERE id = $1
+        "#,
+    )
+    .bind(assignment.id)
+    .bind(Utc::now())
+    .execute(&mut *tx)
+    .await?;
+
+    // Auto-create work order if tenant config enables it
+    let config =
+        super::tenant_config::TenantConfigRepo::get_or_default_tx(&mut tx, &assignment.tenant_id)
+            .await?;
+
+    let mut wo_created = false;
+    if config.auto_create_on_due {
+        let initial_status = if config.approvals_required {
+            "awaiting_approval"
+        } else {
+            "scheduled"
+        };
+
+        let title = format!("[Auto] {}", assignment.plan_name);
+        let wo = super::work_orders::WorkOrderRepo::create_from_due_tx(
             &mut tx,
             &assignment.tenant_id,
+            assignment.asset_id,
+            assignment.id,
+            &title,
+            &assignment.plan_priority,
+            initial_status,
+            assignment.task_checklist.as_ref(),
         )
-        .await?;
-
-        if config.auto_create_on_due {
-            let initial_status = if config.approvals_required {
-                "awaiting_approval"
-            } else {
-                "scheduled"
-            };
+        .await
+        .map_err(|e| match e {
+            super::work_orders::WoError::Database(db_err) => db_err,
+            other => sqlx::Error::Protocol(other.to_string()),
+        })?;
 
-            let title = format!("[Auto] {}", assignment.plan_name);
-            super::work_orders::WorkOrderRepo::create_from_due_tx(
-                &mut tx,
-                &assignment.tenant_id,
-                assignment.asset_id,
-                assignment.id,
-                &title,
-                &assignment.plan_priority,
-                initial_status,
-                assignment.task_checklist.as_ref(),
-            )
-            .await
-            .map_err(|e| match e {
-                super::work_orders::WoError::Database(db_err) => db_err,
-                other => sqlx::Error::Protocol(other.to_string()),
-            })?;
-
-            result.work_orders_created += 1;
-
-            tracing::info!(
-                assignment_id = %assignment.id,
-                initial_status = initial_status,
-                "auto-created work order from due plan"
-            );
+        if let Some(c) = condition {
+            TriggerReadingRepo::attach_tx(&mut tx, &assignment.tenant_id, wo.id, c).await?;
         }
-
-        tx.commit().await?;
-        result.events_emitted += 1;
+        wo_created = true;
 
         tracing::info!(
             assignment_id = %assignment.id,
-            plan_id = %assignment.plan_id,
-            asset_id = %assignment.asset_id,
-            tenant_id = %assignment.tenant_id,
-            trigger = trigger,
-            "maintenance.plan.due event emitted"
+            initial_status = initial_status,
+            "auto-created work order from due plan"
         );
     }
 
-    Ok(result)
+    tx.commit().await?;
+
+    tracing::info!(
+        assignment_id = %assignment.id,
+        plan_id = %assignment.plan_id,
+        asset_id = %assignment.asset_id,
+        tenant_id = %assignment.tenant_id,
+        trigger = trigger,
+        "maintenance.plan.due event emitted"
+    );
+
+    Ok(Some(wo_created))
+}
+
+/// Condition pass: evaluate active rules for assignments not yet notified.
+///
+/// Rules are tried in creation order; the first that fires notifies the
+/// assignment and the remaining rules are skipped until it is re-armed.
+async fn evaluate_conditions(
+    pool: &PgPool,
+    today: NaiveDate,
+    result: &mut TickResult,
+) -> Result<(), sqlx::Error> {
+    let candidates = sqlx::query_as::<_, DueAssignment>(
+        r#"
+        SELECT
+            a.id,
+            a.tenant_id,
+            a.plan_id,
+            a.asset_id,
+            a.next_due_date,
+            a.next_due_meter,
+            a.last_completed_at,
+            p.name AS plan_name,
+            p.priority AS plan_priority,
+            p.schedule_type,
+            p.meter_type_id,
+            p.task_checklist
+        FROM maintenance_plan_assignments a
+        JOIN maintenance_plans p ON a.plan_id = p.id AND p.tenant_id = a.tenant_id
+        WHERE a.state = 'active'
+          AND a.due_notified_at IS NULL
+          AND p.is_active = true
+          AND EXISTS (
+              SELECT 1 FROM maintenance_condition_rules r
+              WHERE r.plan_id = p.id AND r.tenant_id = p.tenant_id AND r.is_active
+          )
+        ORDER BY a.tenant_id, a.created_at
+        "#,
+    )
+    .fetch_all(pool)
+    .await?;
+
+    if candidates.is_empty() {
+        return Ok(());
+    }
+    result.evaluated += candidates.len();
+
+    let mut plan_ids: Vec<Uuid> = candidates.iter().map(|c| c.plan_id).collect();
+    plan_ids.sort();
+    plan_ids.dedup();
+    let mut rules_by_plan: HashMap<Uuid, Vec<_>> = HashMap::new();
+    for rule in ConditionRuleRepo::active_for_plans(pool, &plan_ids).await? {
+        rules_by_plan.entry(rule.plan_id).or_default().push(rule);
+    }
+
+    for assignment in &candidates {
+        let Some(rules) = rules_by_plan.get(&assignment.plan_id) else {
+            continue;
+        };
+        for rule in rules {
+            let readings = ConditionRuleRepo::load_readings(
+                pool,
+                &assignment.tenant_id,
+                assignment.asset_id,
+                rule.meter_type_id,
+                assignment.last_completed_at,
+                ReadingScope::for_rule(rule),
+            )
+            .await?;
+            let Some(hit) = evaluate::evaluate(rule, &readings, assignment.next_due_meter, today)
+            else {
+                continue;
+            };
+
+            let trigger = match rule.rule_type {
+                ConditionRuleType::UsageForecast => "forecast",
+                _ => "condition",
+            };
+            if let Some(wo_created) = emit_due(pool, assignment, trigger, Some(&hit)).await? {
+                result.events_emitted += 1;
+                result.condition_triggers += 1;
+                if wo_created {
+                    result.work_orders_created += 1;
+                }
+                tracing::info!(
+                    assignment_id = %assignment.id,
+                    rule_id = %rule.id,
+                    rule_type = %rule.rule_type,
+                    observed_value = hit.observed_value,
+                    "condition rule fired"
+                );
+            }
+            break;
+        }
+    }
+
+    Ok(())
 }
 
 /// Background scheduler loop — calls evaluate_due on each tick.
@@ -297,6 +434,7 @@ pub async fn run_scheduler_task(pool: PgPool, interval_secs: u64) {
                     evaluated = result.evaluated,
                     emitted = result.events_emitted,
                     wos_created = result.work_orders_created,
+                    conditions = result.condition_triggers,
                     "Maintenance scheduler: due events emitted"
                 );
             }
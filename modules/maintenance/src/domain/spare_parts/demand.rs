@@ -0,0 +1,97 @@
+//! Planned spare-part demand — pure functions.
+//!
+//! Calendar plans recur every `calendar_interval_days` from the assignment's
+//! `next_due_date`. An overdue date counts as due today. Meter-only plans
+//! have no date to project and contribute no planned demand.
+
+use chrono::{Duration, NaiveDate};
+
+/// Number of plan occurrences due on or before `today + window_days`.
+///
+/// `skip_first` drops the `next_due_date` occurrence — used when an open
+/// work order already carries the part for it.
+pub fn occurrences_within(
+    next_due: NaiveDate,
+    interval_days: i32,
+    today: NaiveDate,
+    window_days: i32,
+    skip_first: bool,
+) -> i64 {
+    if interval_days <= 0 || window_days < 0 {
+        return 0;
+    }
+    let window_end = today + Duration::days(window_days as i64);
+    if next_due > window_end {
+        return 0;
+    }
+    let span = (window_end - next_due).num_days();
+    let total = span / interval_days as i64 + 1;
+    if skip_first {
+        total - 1
+    } else {
+        total
+    }
+}
+
+/// Min/max levels from safety stock and demand inside the lead time and horizon.
+///
+/// min = safety + lead-time demand; max = safety + horizon demand, never
+/// below min.
+pub fn min_max(safety_stock: i64, lead_time_demand: i64, horizon_demand: i64) -> (i64, i64) {
+    let min = safety_stock + lead_time_demand;
+    let max = (safety_stock + horizon_demand).max(min);
+    (min, max)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn d(y: i32, m: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, day).expect("valid date")
+    }
+
+    #[test]
+    fn counts_occurrences_inside_window() {
+        // Due 2026-03-10, every 30 days; window to 2026-05-30 → 03-10, 04-09, 05-09.
+        assert_eq!(
+            occurrences_within(d(2026, 3, 10), 30, d(2026, 3, 1), 90, false),
+            3
+        );
+    }
+
+    #[test]
+    fn next_due_beyond_window_is_zero() {
+        assert_eq!(
+            occurrences_within(d(2026, 6, 1), 30, d(2026, 3, 1), 14, false),
+            0
+        );
+    }
+
+    #[test]
+    fn overdue_occurrence_counts() {
+        assert_eq!(
+            occurrences_within(d(2026, 2, 20), 30, d(2026, 3, 1), 0, false),
+            1
+        );
+    }
+
+    #[test]
+    fn skip_first_drops_covered_occurrence() {
+        assert_eq!(
+            occurrences_within(d(2026, 3, 10), 30, d(2026, 3, 1), 90, true),
+            2
+        );
+        assert_eq!(
+            occurrences_within(d(2026, 3, 10), 30, d(2026, 3, 1), 14, true),
+            0
+        );
+    }
+
+    #[test]
+    fn max_never_below_min() {
+        assert_eq!(min_max(2, 4, 10), (6, 12));
+        assert_eq!(min_max(2, 4, 4), (6, 6));
+        assert_eq!(min_max(0, 0, 0), (0, 0));
+    }
+}
@@ -0,0 +1,119 @@
+//! Spare parts — plan part requirements and min/max stocking policies.
+//!
+//! Invariants:
+//! - A plan lists each Inventory item at most once (quantity per occurrence)
+//! - One policy per tenant + item; the policy mirrors a single Inventory
+//!   reorder policy (reorder_point = min, max_qty = max)
+//! - min/max are only written by `recompute`, from demand in open work
+//!   orders plus calendar plan occurrences inside the lead time / horizon
+//! - Every query filters by tenant_id for multi-tenant isolation
+
+pub mod demand;
+mod repo;
+
+pub use repo::{PlanPartsRepo, SparePartPolicyRepo};
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+use uuid::Uuid;
+
+use crate::integrations::inventory_client::InventoryError;
+
+// ============================================================================
+// Domain models
+// ============================================================================
+
+/// Spare part consumed by each occurrence of a maintenance plan.
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow, utoipa::ToSchema)]
+pub struct PlanPart {
+    pub id: Uuid,
+    pub tenant_id: String,
+    pub plan_id: Uuid,
+    pub item_id: Uuid,
+    pub part_description: String,
+    pub quantity: i32,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow, utoipa::ToSchema)]
+pub struct SparePartPolicy {
+    pub id: Uuid,
+    pub tenant_id: String,
+    pub item_id: Uuid,
+    /// Replenishment lead time; demand inside it drives the min.
+    pub lead_time_days: i32,
+    /// Planning horizon; demand inside it drives the max.
+    pub horizon_days: i32,
+    pub safety_stock: i32,
+    pub min_qty: Option<i32>,
+    pub max_qty: Option<i32>,
+    pub inventory_reorder_policy_id: Option<Uuid>,
+    pub last_computed_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// Result of a policy recompute: the updated policy and the demand behind it.
+#[derive(Debug, Clone, Serialize, utoipa::ToSchema)]
+pub struct SparePartRecompute {
+    pub policy: SparePartPolicy,
+    /// Unissued quantity on open work orders.
+    pub open_work_order_demand: i64,
+    /// Planned quantity due within the lead time (excludes open work orders).
+    pub planned_lead_time_demand: i64,
+    /// Planned quantity due within the horizon (excludes open work orders).
+    pub planned_horizon_demand: i64,
+}
+
+// ============================================================================
+// Request types
+// ============================================================================
+
+#[derive(Debug, Deserialize, utoipa::ToSchema)]
+pub struct AddPlanPartRequest {
+    pub tenant_id: String,
+    pub item_id: Uuid,
+    pub part_description: String,
+    pub quantity: i32,
+}
+
+#[derive(Debug, Deserialize, utoipa::ToSchema)]
+pub struct UpsertSparePartPolicyRequest {
+    pub tenant_id: String,
+    pub item_id: Uuid,
+    pub lead_time_days: i32,
+    pub horizon_days: i32,
+    pub safety_stock: Option<i32>,
+}
+
+// ============================================================================
+// Errors
+// ============================================================================
+
+#[derive(Debug, Error)]
+pub enum SparePartError {
+    #[error("Plan not found")]
+    PlanNotFound,
+
+    #[error("Spare part policy not found")]
+    PolicyNotFound,
+
+    #[error("Item already listed on this plan")]
+    DuplicatePlanPart,
+
+    #[error("Validation error: {0}")]
+    Validation(String),
+
+    #[error("Inventory integration error: {0}")]
+    Inventory(String),
+
+    #[error("Database error: {0}")]
+    Database(#[from] sqlx::Error),
+}
+
+impl From<InventoryError> for SparePartError {
+    fn from(e: InventoryError) -> Self {
+        Self::Inventory(e.to_string())
+    }
+}
@@ -0,0 +1,315 @@
+//! Plan part and spare-part policy repositories — database access layer.
+
+use chrono::{NaiveDate, Utc};
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use super::demand::{min_max, occurrences_within};
+use super::{
+    AddPlanPartRequest, PlanPart, SparePartError, SparePartPolicy, SparePartRecompute,
+    UpsertSparePartPolicyRequest,
+};
+use crate::integrations::inventory_client::{InventoryIntegration, ReorderLevels};
+
+pub struct PlanPartsRepo;
+
+impl PlanPartsRepo {
+    /// Add a spare-part requirement to a plan.
+    pub async fn add(
+        pool: &PgPool,
+        plan_id: Uuid,
+        req: &AddPlanPartRequest,
+    ) -> Result<PlanPart, SparePartError> {
+        if req.tenant_id.trim().is_empty() {
+            return Err(SparePartError::Validation("tenant_id is required".into()));
+        }
+        if req.part_description.trim().is_empty() {
+            return Err(SparePartError::Validation(
+                "part_description is required".into(),
+            ));
+        }
+        if req.quantity <= 0 {
+            return Err(SparePartError::Validation(
+                "quantity must be greater than 0".into(),
+            ));
+        }
+        Self::ensure_plan(pool, plan_id, &req.tenant_id).await?;
+
+        sqlx::query_as::<_, PlanPart>(
+            r#"
+            INSERT INTO maintenance_plan_parts
+                (tenant_id, plan_id, item_id, part_description, quantity)
+            VALUES ($1, $2, $3, $4, $5)
+            RETURNING *
+            "#,
+        )
+        .bind(&req.tenant_id)
+        .bind(plan_id)
+        .bind(req.item_id)
+        .bind(req.part_description.trim())
+        .bind(req.quantity)
+        .fetch_one(pool)
+        .await
+        .map_err(|e| match &e {
+            sqlx::Error::Database(db)
+                if db.constraint() == Some("maintenance_plan_parts_plan_item_unique") =>
+            {
+                SparePartError::DuplicatePlanPart
+            }
+            _ => SparePartError::Database(e),
+        })
+    }
+
+    /// List spare-part requirements for a plan.
+    pub async fn list(
+        pool: &PgPool,
+        plan_id: Uuid,
+        tenant_id: &str,
+    ) -> Result<Vec<PlanPart>, SparePartError> {
+        Self::ensure_plan(pool, plan_id, tenant_id).await?;
+
+        let parts = sqlx::query_as::<_, PlanPart>(
+            r#"
+            SELECT * FROM maintenance_plan_parts
+            WHERE plan_id = $1 AND tenant_id = $2
+            ORDER BY created_at ASC
+            "#,
+        )
+        .bind(plan_id)
+        .bind(tenant_id)
+        .fetch_all(pool)
+        .await?;
+
+        Ok(parts)
+    }
+
+    async fn ensure_plan(
+        pool: &PgPool,
+        plan_id: Uuid,
+        tenant_id: &str,
+    ) -> Result<(), SparePartError> {
+        let exists: Option<(Uuid,)> =
+            sqlx::query_as("SELECT id FROM maintenance_plans WHERE id = $1 AND tenant_id = $2")
+                .bind(plan_id)
+                .bind(tenant_id)
+                .fetch_optional(pool)
+                .await?;
+        exists.map(|_| ()).ok_or(SparePartError::PlanNotFound)
+    }
+}
+
+/// Calendar plan assignment that consumes the policy's item.
+#[derive(sqlx::FromRow)]
+struct PlannedUse {
+    next_due_date: NaiveDate,
+    calendar_interval_days: i32,
+    quantity: i32,
+    covered_by_open_wo: bool,
+}
+
+pub struct SparePartPolicyRepo;
+
+impl SparePartPolicyRepo {
+    /// Create or update the stocking parameters for an item.
+    /// Computed min/max are left untouched until the next recompute.
+    pub async fn upsert(
+        pool: &PgPool,
+        req: &UpsertSparePartPolicyRequest,
+    ) -> Result<SparePartPolicy, SparePartError> {
+        if req.tenant_id.trim().is_empty() {
+            return Err(SparePartError::Validation("tenant_id is required".into()));
+        }
+        if req.lead_time_days < 0 {
+            return Err(SparePartError::Validation(
+                "lead_time_days must be >= 0".into(),
+            ));
+        }
+        if req.horizon_days <= 0 || req.horizon_days < req.lead_time_days {
+            return Err(SparePartError::Validation(
+                "horizon_days must be > 0 and >= lead_time_days".into(),
+            ));
+        }
+        let safety_stock = req.safety_stock.unwrap_or(0);
+        if safety_stock < 0 {
+            return Err(SparePartError::Validation(
+                "safety_stock must be >= 0".into(),
+            ));
+        }
+
+        let policy = sqlx::query_as::<_, SparePartPolicy>(
+            r#"
+            INSERT INTO spare_part_policies
+                (tenant_id, item_id, lead_time_days, horizon_days, safety_stock)
+            VALUES ($1, $2, $3, $4, $5)
+            ON CONFLICT (tenant_id, item_id) DO UPDATE SET
+                lead_time_days = $3,
+                horizon_days   = $4,
+                safety_stock   = $5,
+                updated_at     = NOW()
+            RETURNING *
+            "#,
+        )
+        .bind(&req.tenant_id)
+        .bind(req.item_id)
+        .bind(req.lead_time_days)
+        .bind(req.horizon_days)
+        .bind(safety_stock)
+        .fetch_one(pool)
+        .await?;
+
+        Ok(policy)
+    }
+
+    pub async fn list(
+        pool: &PgPool,
+        tenant_id: &str,
+    ) -> Result<Vec<SparePartPolicy>, SparePartError> {
+        let policies = sqlx::query_as::<_, SparePartPolicy>(
+            "SELECT * FROM spare_part_policies WHERE tenant_id = $1 ORDER BY created_at ASC",
+        )
+        .bind(tenant_id)
+        .fetch_all(pool)
+        .await?;
+        Ok(policies)
+    }
+
+    /// Recompute min/max from planned maintenance demand as of `today` and
+    /// push the levels to the item's Inventory reorder policy.
+    ///
+    /// Demand:
+    /// - open work orders: unissued quantity on reserved part lines, counted
+    ///   in full against both the lead time and the horizon
+    /// - active calendar plan assignments: plan part quantity per occurrence
+    ///   due inside each window, skipping the occurrence an open work order
+    ///   already carries the item for
+    pub async fn recompute(
+        pool: &PgPool,
+        inventory: &InventoryIntegration,
+        tenant_id: &str,
+        policy_id: Uuid,
+        today: NaiveDate,
+    ) -> Result<SparePartRecompute, SparePartError> {
+        let mut tx = pool.begin().await?;
+
+        let policy = sqlx::query_as::<_, SparePartPolicy>(
+            "SELECT * FROM spare_part_policies WHERE id = $1 AND tenant_id = $2 FOR UPDATE",
+        )
+        .bind(policy_id)
+        .bind(tenant_id)
+        .fetch_optional(&mut *tx)
+        .await?
+        .ok_or(SparePartError::PolicyNotFound)?;
+
+        let (open_wo_demand,): (i64,) = sqlx::query_as(
+            r#"
+            SELECT COALESCE(SUM(p.quantity), 0)::bigint
+            FROM work_order_parts p
+            JOIN work_orders w ON w.id = p.work_order_id
+            WHERE p.tenant_id = $1 AND p.item_id = $2
+              AND p.stock_status = 'reserved'
+              AND w.status NOT IN ('completed', 'closed', 'cancelled')
+            "#,
+        )
+        .bind(tenant_id)
+        .bind(policy.item_id)
+        .fetch_one(&mut *tx)
+        .await?;
+
+        let planned = sqlx::query_as::<_, PlannedUse>(
+            r#"
+            SELECT a.next_due_date,
+                   p.calendar_interval_days,
+                   pp.quantity,
+                   EXISTS (
+                       SELECT 1 FROM work_orders w
+                       JOIN work_order_parts wp ON wp.work_order_id = w.id
+                       WHERE w.plan_assignment_id = a.id
+                         AND w.status NOT IN ('completed', 'closed', 'cancelled')
+                         AND wp.item_id = pp.item_id
+                   ) AS covered_by_open_wo
+            FROM maintenance_plan_parts pp
+            JOIN maintenance_plans p ON p.id = pp.plan_id
+            JOIN maintenance_plan_assignments a ON a.plan_id = p.id
+            WHERE pp.tenant_id = $1 AND pp.item_id = $2
+              AND p.is_active AND a.state = 'active'
+              AND a.next_due_date IS NOT NULL
+              AND p.calendar_interval_days IS NOT NULL
+            "#,
+        )
+        .bind(tenant_id)
+        .bind(policy.item_id)
+        .fetch_all(&mut *tx)
+        .await?;
+
+        let planned_within = |window_days: i32| -> i64 {
+            planned
+                .iter()
+                .map(|u| {
+                    u.quantity as i64
+                        * occurrences_within(
+                            u.next_due_date,
+                            u.calendar_interval_days,
+                            today,
+                            window_days,
+                            u.covered_by_open_wo,
+                        )
+                })
+                .sum()
+        };
+        let planned_lead_time_demand = planned_within(policy.lead_time_days);
+        let planned_horizon_demand = planned_within(policy.horizon_days);
+
+        let (min_qty, max_qty) = min_max(
+            policy.safety_stock as i64,
+            open_wo_demand + planned_lead_time_demand,
+            open_wo_demand + planned_horizon_demand,
+        );
+        let to_i32 = |v: i64| {
+            i32::try_from(v).map_err(|_| {
+                SparePartError::Validation(format!("computed level {} out of range", v))
+            })
+        };
+        let (min_i32, max_i32) = (to_i32(min_qty)?, to_i32(max_qty)?);
+
+        let reorder_policy_id = inventory
+            .upsert_reorder_policy(
+                tenant_id,
+                policy.inventory_reorder_policy_id,
+                &ReorderLevels {
+                    item_id: policy.item_id,
+                    reorder_point: min_qty,
+                    safety_stock: policy.safety_stock as i64,
+                    max_qty,
+                },
+            )
+            .await?;
+
+        let policy = sqlx::query_as::<_, SparePartPolicy>(
+            r#"
+            UPDATE spare_part_policies SET
+                min_qty                     = $2,
+                max_qty                     = $3,
+                inventory_reorder_policy_id = $4,
+                last_computed_at            = $5,
+                updated_at                  = NOW()
+            WHERE id = $1
+            RETURNING *
+            "#,
+        )
+        .bind(policy.id)
+        .bind(min_i32)
+        .bind(max_i32)
+        .bind(reorder_policy_id)
+        .bind(Utc::now())
+        .fetch_one(&mut *tx)
+        .await?;
+
+        tx.commit().await?;
+        Ok(SparePartRecompute {
+            policy,
+            open_work_order_demand: open_wo_demand,
+            planned_lead_time_demand,
+            planned_horizon_demand,
+        })
+    }
+}
@@ -3,8 +3,10 @@
 //! Controls per-tenant behavior:
 //! - `auto_create_on_due`: When a plan assignment becomes due, automatically create a work order.
 //! - `approvals_required`: Auto-created work orders start as `awaiting_approval` instead of `scheduled`.
+//! - `issue_parts_on_start`: Reserved inventory parts are issued when a work order starts;
+//!   when false they are issued at completion.
 //!
-//! If no row exists for a tenant, defaults apply (both false).
+//! If no row exists for a tenant, defaults apply (flags false, parts issued on start).
 
 use serde::{Deserialize, Serialize};
 use sqlx::PgPool;
@@ -15,6 +17,7 @@ pub struct TenantConfig {
     pub tenant_id: String,
     pub auto_create_on_due: bool,
     pub approvals_required: bool,
+    pub issue_parts_on_start: bool,
 }
 
 impl TenantConfig {
@@ -24,6 +27,7 @@ impl TenantConfig {
             tenant_id: tenant_id.to_string(),
             auto_create_on_due: false,
             approvals_required: false,
+            issue_parts_on_start: true,
         }
     }
 }
@@ -37,7 +41,7 @@ impl TenantConfigRepo {
         tenant_id: &str,
     ) -> Result<TenantConfig, sqlx::Error> {
         let row = sqlx::query_as::<_, TenantConfig>(
-            "SELECT tenant_id, auto_create_on_due, approvals_required FROM maintenance_tenant_config WHERE tenant_id = $1",
+            "SELECT tenant_id, auto_create_on_due, approvals_required, issue_parts_on_start FROM maintenance_tenant_config WHERE tenant_id = $1",
         )
         .bind(tenant_id)
         .fetch_optional(pool)
@@ -52,7 +56,7 @@ impl TenantConfigRepo {
         tenant_id: &str,
     ) -> Result<TenantConfig, sqlx::Error> {
         let row = sqlx::query_as::<_, TenantConfig>(
-            "SELECT tenant_id, auto_create_on_due, approvals_required FROM maintenance_tenant_config WHERE tenant_id = $1",
+            "SELECT tenant_id, auto_create_on_due, approvals_required, issue_parts_on_start FROM maintenance_tenant_config WHERE tenant_id = $1",
         )
         .bind(tenant_id)
         .fetch_optional(&mut **tx)
@@ -76,7 +80,7 @@ impl TenantConfigRepo {
                 auto_create_on_due = $2,
                 approvals_required = $3,
                 updated_at = NOW()
-            RETURNING tenant_id, auto_create_on_due, approvals_required
+            RETURNING tenant_id, auto_create_on_due, approvals_required, issue_parts_on_start
             "#,
         )
         .bind(tenant_id)
@@ -85,4 +89,26 @@ impl TenantConfigRepo {
         .fetch_one(pool)
         .await
     }
+
+    /// Set whether reserved parts are issued on WO start (true) or at completion (false).
+    pub async fn set_issue_parts_on_start(
+        pool: &PgPool,
+        tenant_id: &str,
+        issue_parts_on_start: bool,
+    ) -> Result<TenantConfig, sqlx::Error> {
+        sqlx::query_as::<_, TenantConfig>(
+            r#"
+            INSERT INTO maintenance_tenant_config (tenant_id, issue_parts_on_start)
+            VALUES ($1, $2)
+            ON CONFLICT (tenant_id) DO UPDATE SET
+                issue_parts_on_start = $2,
+                updated_at = NOW()
+            RETURNING tenant_id, auto_create_on_due, approvals_required, issue_parts_on_start
+            "#,
+        )
+        .bind(tenant_id)
+        .bind(issue_parts_on_start)
+        .fetch_one(pool)
+        .await
+    }
 }
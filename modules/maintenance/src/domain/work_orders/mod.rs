@@ -9,7 +9,9 @@ pub use guards::{
     run_guards, validate_close_fields, validate_completion_fields, GuardError, TransitionContext,
 };
 pub use labor::{AddLaborRequest, WoLabor, WoLaborError, WoLaborRepo};
-pub use parts::{AddPartRequest, WoPart, WoPartError, WoPartsRepo};
+pub use parts::{
+    AddPartRequest, ReturnPartRequest, StockStatus, WoPart, WoPartError, WoPartReturn, WoPartsRepo,
+};
 pub use service::{
     CreateWorkOrderRequest, ListWorkOrdersQuery, TransitionRequest, WoError, WorkOrder,
     WorkOrderRepo,
@@ -1,18 +1,81 @@
-//! Work order parts — add/list/remove parts on a work order.
+//! Work order parts — add/list/remove/return parts on a work order.
+//!
+//! Two kinds of part lines:
+//! - standalone: description + planned cost only (no `item_id`)
+//! - stocked: linked to an Inventory item in a warehouse. Planning the part
+//!   reserves stock; starting (tenant default) or completing the WO issues
+//!   it at actual FIFO cost, with lot/serial where tracked; unused quantity
+//!   can be returned to stock while the WO is open.
 //!
 //! Lifecycle guard: parts cannot be modified when the WO status is
 //! completed, closed, or cancelled.
 
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
-use sqlx::PgPool;
+use sqlx::{PgPool, Postgres, Transaction};
+use std::fmt;
 use thiserror::Error;
 use uuid::Uuid;
 
-use super::types::WoStatus;
+use super::types::{ParseEnumError, WoStatus};
+use crate::domain::tenant_config::TenantConfigRepo;
+use crate::integrations::inventory_client::{
+    InventoryError, InventoryIntegration, PartIssue, PartReturn,
+};
 
 // ── Domain model ──────────────────────────────────────────────
 
+/// Where a part line stands against Inventory.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, utoipa::ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum StockStatus {
+    /// Not linked to an Inventory item.
+    Standalone,
+    /// Stock held by an Inventory reservation.
+    Reserved,
+    /// Stock issued to the work order.
+    Issued,
+    /// Reservation released without issue (work order cancelled).
+    Released,
+}
+
+impl StockStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Standalone => "standalone",
+            Self::Reserved => "reserved",
+            Self::Issued => "issued",
+            Self::Released => "released",
+        }
+    }
+
+    pub fn from_str_value(s: &str) -> Result<Self, ParseEnumError> {
+        match s {
+            "standalone" => Ok(Self::Standalone),
+            "reserved" => Ok(Self::Reserved),
+            "issued" => Ok(Self::Issued),
+            "released" => Ok(Self::Released),
+            _ => Err(ParseEnumError {
+                type_name: "StockStatus",
+                value: s.to_string(),
+            }),
+        }
+    }
+}
+
+impl fmt::Display for StockStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl TryFrom<String> for StockStatus {
+    type Error = String;
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        Self::from_str_value(&s).map_err(|e| e.to_string())
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow, utoipa::ToSchema)]
 pub struct WoPart {
     pub id: Uuid,
@@ -24,6 +87,45 @@ pub struct WoPart {
     pub unit_cost_minor: i64,
     pub currency: String,
     pub inventory_issue_ref: Option<Uuid>,
+    pub item_id: Option<Uuid>,
+    pub warehouse_id: Option<Uuid>,
+    pub location_id: Option<Uuid>,
+    pub lot_code: Option<String>,
+    pub serial_codes: Option<Vec<String>>,
+    #[sqlx(try_from = "String")]
+    pub stock_status: StockStatus,
+    pub reservation_id: Option<Uuid>,
+    pub issued_quantity: i32,
+    /// Actual FIFO cost of the issued quantity.
+    pub issued_cost_minor: i64,
+    pub returned_quantity: i32,
+    pub returned_cost_minor: i64,
+    pub issued_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl WoPart {
+    /// Cost this line contributes to the work order: actual issued cost net
+    /// of returns for stocked parts, planned cost for standalone parts.
+    pub fn cost_minor(&self) -> i64 {
+        match self.stock_status {
+            StockStatus::Standalone => self.quantity as i64 * self.unit_cost_minor,
+            _ => self.issued_cost_minor - self.returned_cost_minor,
+        }
+    }
+}
+
+/// One return of unused stock from a work order part.
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow, utoipa::ToSchema)]
+pub struct WoPartReturn {
+    pub id: Uuid,
+    pub tenant_id: String,
+    pub work_order_id: Uuid,
+    pub part_id: Uuid,
+    pub quantity: i32,
+    pub serial_codes: Option<Vec<String>>,
+    pub cost_minor: i64,
+    pub receipt_ref: Uuid,
     pub created_at: DateTime<Utc>,
 }
 
@@ -38,6 +140,23 @@ pub struct AddPartRequest {
     pub unit_cost_minor: i64,
     pub currency: Option<String>,
     pub inventory_issue_ref: Option<Uuid>,
+    /// Inventory item to reserve and issue. Omit for a standalone part.
+    pub item_id: Option<Uuid>,
+    /// Required with `item_id`.
+    pub warehouse_id: Option<Uuid>,
+    pub location_id: Option<Uuid>,
+    /// Lot to issue from (lot-tracked items).
+    pub lot_code: Option<String>,
+    /// Serials to issue (serial-tracked items); count must equal `quantity`.
+    pub serial_codes: Option<Vec<String>>,
+}
+
+#[derive(Debug, Deserialize, utoipa::ToSchema)]
+pub struct ReturnPartRequest {
+    pub tenant_id: String,
+    pub quantity: i32,
+    /// Serials going back to stock (serial-tracked parts); count must equal `quantity`.
+    pub serial_codes: Option<Vec<String>>,
 }
 
 // ── Errors ────────────────────────────────────────────────────
@@ -56,15 +175,28 @@ pub enum WoPartError {
     #[error("Validation error: {0}")]
     Validation(String),
 
+    #[error("Inventory integration error: {0}")]
+    Inventory(String),
+
     #[error("Database error: {0}")]
     Database(#[from] sqlx::Error),
 }
 
+impl From<InventoryError> for WoPartError {
+    fn from(e: InventoryError) -> Self {
+        Self::Inventory(e.to_string())
+    }
+}
+
 // ── Lifecycle guard ───────────────────────────────────────────
 
 const IMMUTABLE_STATUSES: &[WoStatus] =
     &[WoStatus::Completed, WoStatus::Closed, WoStatus::Cancelled];
 
+/// Statuses in which work is underway — parts added now are issued at once
+/// when the tenant issues on start.
+const WORKING_STATUSES: &[WoStatus] = &[WoStatus::InProgress, WoStatus::OnHold];
+
 fn check_modifiable(status: WoStatus) -> Result<(), WoPartError> {
     if IMMUTABLE_STATUSES.contains(&status) {
         return Err(WoPartError::WoImmutable(status));
@@ -72,6 +204,72 @@ fn check_modifiable(status: WoStatus) -> Result<(), WoPartError> {
     Ok(())
 }
 
+fn check_serial_count(serials: Option<&[String]>, quantity: i32) -> Result<(), WoPartError> {
+    if let Some(codes) = serials {
+        if codes.len() != quantity as usize {
+            return Err(WoPartError::Validation(format!(
+                "serial_codes count ({}) must equal quantity ({})",
+                codes.len(),
+                quantity
+            )));
+        }
+    }
+    Ok(())
+}
+
+fn validate_add(req: &AddPartRequest) -> Result<(), WoPartError> {
+    if req.tenant_id.trim().is_empty() {
+        return Err(WoPartError::Validation("tenant_id is required".into()));
+    }
+    if req.part_description.trim().is_empty() {
+        return Err(WoPartError::Validation(
+            "part_description is required".into(),
+        ));
+    }
+    if req.quantity <= 0 {
+        return Err(WoPartError::Validation(
+            "quantity must be greater than 0".into(),
+        ));
+    }
+    if req.unit_cost_minor < 0 {
+        return Err(WoPartError::Validation(
+            "unit_cost_minor must be >= 0".into(),
+        ));
+    }
+    match req.item_id {
+        Some(_) => {
+            if req.warehouse_id.is_none() {
+                return Err(WoPartError::Validation(
+                    "warehouse_id is required with item_id".into(),
+                ));
+            }
+            if req.inventory_issue_ref.is_some() {
+                return Err(WoPartError::Validation(
+                    "inventory_issue_ref is set by the issue for stocked parts".into(),
+                ));
+            }
+            if req.lot_code.is_some() && req.serial_codes.is_some() {
+                return Err(WoPartError::Validation(
+                    "a part is either lot- or serial-tracked, not both".into(),
+                ));
+            }
+            check_serial_count(req.serial_codes.as_deref(), req.quantity)
+        }
+        None => {
+            if req.warehouse_id.is_some()
+                || req.location_id.is_some()
+                || req.lot_code.is_some()
+                || req.serial_codes.is_some()
+            {
+                return Err(WoPartError::Validation(
+                    "warehouse, location, lot and serials require item_id".into(),
+                ));
+            }
+            Ok(())
+        }
+    }
+}
+
 // ── Repository ────────────────────────────────────────────────
 
 pub struct WoPartsRepo;
@@ -98,32 +296,66 @@ impl WoPartsRepo {
         }
     }
 
-    /// Add a part to a work order (standalone mode — no inventory integration).
+    /// Fetch the WO status inside a transaction, locking the WO row so part
+    /// changes serialize with status transitions.
+    async fn wo_status_for_update(
+        tx: &mut Transaction<'_, Postgres>,
+        wo_id: Uuid,
+        tenant_id: &str,
+    ) -> Result<WoStatus, WoPartError> {
+        let row: Option<(String,)> = sqlx::query_as(
+            "SELECT status FROM work_orders WHERE id = $1 AND tenant_id = $2 FOR UPDATE",
+        )
+        .bind(wo_id)
+        .bind(tenant_id)
+        .fetch_optional(&mut **tx)
+        .await?;
+
+        match row {
+            Some((s,)) => {
+                WoStatus::from_str_value(&s).map_err(|e| WoPartError::Validation(e.to_string()))
+            }
+            None => Err(WoPartError::WoNotFound),
+        }
+    }
+
+    async fn part_for_update(
+        tx: &mut Transaction<'_, Postgres>,
+        wo_id: Uuid,
+        part_id: Uuid,
+        tenant_id: &str,
+    ) -> Result<WoPart, WoPartError> {
+        sqlx::query_as::<_, WoPart>(
+            r#"
+            SELECT * FROM work_order_parts
+            WHERE id = $1 AND work_order_id = $2 AND tenant_id = $3
+            FOR UPDATE
+            "#,
+        )
+        .bind(part_id)
+        .bind(wo_id)
+        .bind(tenant_id)
+        .fetch_optional(&mut **tx)
+        .await?
+        .ok_or(WoPartError::PartNotFound)
+    }
+
+    /// Add a part to a work order.
+    ///
+    /// Stocked parts (`item_id` set) are reserved in Inventory, or issued
+    /// immediately when work is already underway and the tenant issues on
+    /// start. The Inventory call runs inside the transaction: if it fails,
+    /// no part row is written.
     pub async fn add(
         pool: &PgPool,
         wo_id: Uuid,
         req: &AddPartRequest,
+        inventory: &InventoryIntegration,
     ) -> Result<WoPart, WoPartError> {
-        if req.tenant_id.trim().is_empty() {
-            return Err(WoPartError::Validation("tenant_id is required".into()));
-        }
-        if req.part_description.trim().is_empty() {
-            return Err(WoPartError::Validation(
-                "part_description is required".into(),
-            ));
-        }
-        if req.quantity <= 0 {
-            return Err(WoPartError::Validation(
-                "quantity must be greater than 0".into(),
-            ));
-        }
-        if req.unit_cost_minor < 0 {
-            return Err(WoPartError::Validation(
-                "unit_cost_minor must be >= 0".into(),
-            ));
-        }
+        validate_add(req)?;
 
-        let status = Self::wo_status(pool, wo_id, &req.tenant_id).await?;
+        let mut tx = pool.begin().await?;
+        let status = Self::wo_status_for_update(&mut tx, wo_id, &req.tenant_id).await?;
         check_modifiable(status)?;
 
         let currency = req.currency.as_deref().unwrap_or("USD");
@@ -132,8 +364,9 @@ impl WoPartsRepo {
             r#"
             INSERT INTO work_order_parts
                 (tenant_id, work_order_id, part_description, part_ref,
-                 quantity, unit_cost_minor, currency, inventory_issue_ref)
-            VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+                 quantity, unit_cost_minor, currency, inventory_issue_ref,
+                 item_id, warehouse_id, location_id, lot_code, serial_codes)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13)
             RETURNING *
             "#,
         )
@@ -145,9 +378,48 @@ impl WoPartsRepo {
         .bind(req.unit_cost_minor)
         .bind(currency)
         .bind(req.inventory_issue_ref)
-        .fetch_one(pool)
+        .bind(req.item_id)
+        .bind(req.warehouse_id)
+        .bind(req.location_id)
+        .bind(req.lot_code.as_deref())
+        .bind(req.serial_codes.as_deref())
+        .fetch_one(&mut *tx)
         .await?;
 
+        let part = match (part.item_id, part.warehouse_id) {
+            (Some(item_id), Some(warehouse_id)) => {
+                let config = TenantConfigRepo::get_or_default_tx(&mut tx, &req.tenant_id).await?;
+                if config.issue_parts_on_start && WORKING_STATUSES.contains(&status) {
+                    Self::issue_tx(&mut tx, inventory, &part).await?
+                } else {
+                    let reservation_id = inventory
+                        .reserve(
+                            &req.tenant_id,
+                            wo_id,
+                            part.id,
+                            item_id,
+                            warehouse_id,
+                            part.quantity as i64,
+                        )
+                        .await?;
+                    sqlx::query_as::<_, WoPart>(
+                        r#"
+                        UPDATE work_order_parts
+                        SET stock_status = 'reserved', reservation_id = $2
+                        WHERE id = $1
+                        RETURNING *
+                        "#,
+                    )
+                    .bind(part.id)
+                    .bind(reservation_id)
+                    .fetch_one(&mut *tx)
+                    .await?
+                }
+            }
+            _ => part,
+        };
+
+        tx.commit().await?;
         Ok(part)
     }
 
@@ -180,20 +452,42 @@ impl WoPartsRepo {
     }
 
     /// Remove a part from a work order.
+    ///
+    /// A reserved part releases its reservation. Issued parts cannot be
+    /// removed — unused stock goes back through [`Self::return_part`].
     pub async fn remove(
         pool: &PgPool,
         wo_id: Uuid,
         part_id: Uuid,
         tenant_id: &str,
+        inventory: &InventoryIntegration,
     ) -> Result<(), WoPartError> {
         if tenant_id.trim().is_empty() {
             return Err(WoPartError::Validation("tenant_id is required".into()));
         }
 
-        let status = Self::wo_status(pool, wo_id, tenant_id).await?;
+        let mut tx = pool.begin().await?;
+        let status = Self::wo_status_for_update(&mut tx, wo_id, tenant_id).await?;
         check_modifiable(status)?;
 
-        let result = sqlx::query(
+        let part = Self::part_for_update(&mut tx, wo_id, part_id, tenant_id).await?;
+        match part.stock_status {
+            StockStatus::Issued => {
+                return Err(WoPartError::Validation(
+                    "issued parts cannot be removed; return unused quantity instead".into(),
+                ));
+            }
+            StockStatus::Reserved => {
+                if let Some(reservation_id) = part.reservation_id {
+                    inventory
+                        .release(tenant_id, wo_id, part.id, reservation_id)
+                        .await?;
+                }
+            }
+            StockStatus::Standalone | StockStatus::Released => {}
+        }
+
+        sqlx::query(
             r#"
             DELETE FROM work_order_parts
             WHERE id = $1 AND work_order_id = $2 AND tenant_id = $3
@@ -202,13 +496,359 @@ impl WoPartsRepo {
         .bind(part_id)
         .bind(wo_id)
         .bind(tenant_id)
-        .execute(pool)
+        .execute(&mut *tx)
         .await?;
 
-        if result.rows_affected() == 0 {
-            return Err(WoPartError::PartNotFound);
+        tx.commit().await?;
+        Ok(())
+    }
+
+    /// Return unused quantity of an issued part to stock.
+    ///
+    /// The returned quantity is valued at the part's average issued cost;
+    /// the last unit returned takes the remainder so a full return nets the
+    /// line to zero.
+    pub async fn return_part(
+        pool: &PgPool,
+        wo_id: Uuid,
+        part_id: Uuid,
+        req: &ReturnPartRequest,
+        inventory: &InventoryIntegration,
+    ) -> Result<WoPartReturn, WoPartError> {
+        if req.tenant_id.trim().is_empty() {
+            return Err(WoPartError::Validation("tenant_id is required".into()));
+        }
+        if req.quantity <= 0 {
+            return Err(WoPartError::Validation(
+                "quantity must be greater than 0".into(),
+            ));
+        }
+        check_serial_count(req.serial_codes.as_deref(), req.quantity)?;
+
+        let mut tx = pool.begin().await?;
+        let status = Self::wo_status_for_update(&mut tx, wo_id, &req.tenant_id).await?;
+        check_modifiable(status)?;
+
+        let part = Self::part_for_update(&mut tx, wo_id, part_id, &req.tenant_id).await?;
+        let (Some(item_id), Some(warehouse_id)) = (part.item_id, part.warehouse_id) else {
+            return Err(WoPartError::Validation(
+                "only stocked parts can be returned".into(),
+            ));
+        };
+        if part.stock_status != StockStatus::Issued {
+            return Err(WoPartError::Validation(format!(
+                "part has not been issued (stock_status {})",
+                part.stock_status
+            )));
+        }
+        let outstanding = part.issued_quantity - part.returned_quantity;
+        if req.quantity > outstanding {
+            return Err(WoPartError::Validation(format!(
+                "cannot return {} — only {} issued and not yet returned",
+                req.quantity, outstanding
+            )));
+        }
+        if let Some(codes) = &req.serial_codes {
+            let issued = part.serial_codes.as_deref().unwrap_or_default();
+            if let Some(unknown) = codes.iter().find(|c| !issued.contains(c)) {
+                return Err(WoPartError::Validation(format!(
+                    "serial '{}' was not issued to this part",
+                    unknown
+                )));
+            }
+        } else if part.serial_codes.is_some() {
+            return Err(WoPartError::Validation(
+                "serial_codes are required to return a serial-tracked part".into(),
+            ));
+        }
+
+        let cost_minor = return_cost(&part, req.quantity);
+        let unit_cost_minor = cost_minor / req.quantity as i64;
+        let return_id = Uuid::new_v4();
+        let receipt_ref = inventory
+            .receive_return(
+                &req.tenant_id,
+                wo_id,
+                return_id,
+                &PartReturn {
+                    item_id,
+                    warehouse_id,
+                    location_id: part.location_id,
+                    lot_code: part.lot_code.as_deref(),
+                    serial_codes: req.serial_codes.as_deref(),
+                    quantity: req.quantity as i64,
+                    unit_cost_minor,
+                    currency: &part.currency,
+                },
+            )
+            .await?;
+
+        let ret = sqlx::query_as::<_, WoPartReturn>(
+            r#"
+            INSERT INTO work_order_part_returns
+                (id, tenant_id, work_order_id, part_id, quantity, serial_codes,
+                 cost_minor, receipt_ref)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+            RETURNING *
+            "#,
+        )
+        .bind(return_id)
+        .bind(&req.tenant_id)
+        .bind(wo_id)
+        .bind(part_id)
+        .bind(req.quantity)
+        .bind(req.serial_codes.as_deref())
+        .bind(cost_minor)
+        .bind(receipt_ref)
+        .fetch_one(&mut *tx)
+        .await?;
+
+        sqlx::query(
+            r#"
+            UPDATE work_order_parts
+            SET returned_quantity   = returned_quantity + $2,
+                returned_cost_minor = returned_cost_minor + $3
+            WHERE id = $1
+            "#,
+        )
+        .bind(part_id)
+        .bind(req.quantity)
+        .bind(cost_minor)
+        .execute(&mut *tx)
+        .await?;
+
+        tx.commit().await?;
+        Ok(ret)
+    }
+
+    /// List returns recorded against a work order.
+    pub async fn list_returns(
+        pool: &PgPool,
+        wo_id: Uuid,
+        tenant_id: &str,
+    ) -> Result<Vec<WoPartReturn>, WoPartError> {
+        let _status = Self::wo_status(pool, wo_id, tenant_id).await?;
+
+        let returns = sqlx::query_as::<_, WoPartReturn>(
+            r#"
+            SELECT * FROM work_order_part_returns
+            WHERE work_order_id = $1 AND tenant_id = $2
+            ORDER BY created_at ASC
+            "#,
+        )
+        .bind(wo_id)
+        .bind(tenant_id)
+        .fetch_all(pool)
+        .await?;
+
+        Ok(returns)
+    }
+
+    // ── Stock movements driven by WO transitions ──────────────
+
+    /// Issue every reserved part on a work order (WO start / completion).
+    /// Runs inside the caller's transition transaction.
+    pub async fn issue_reserved_tx(
+        tx: &mut Transaction<'_, Postgres>,
+        inventory: &InventoryIntegration,
+        wo_id: Uuid,
+        tenant_id: &str,
+    ) -> Result<Vec<WoPart>, WoPartError> {
+        let reserved = Self::reserved_parts_for_update(tx, wo_id, tenant_id).await?;
+        let mut issued = Vec::with_capacity(reserved.len());
+        for part in &reserved {
+            issued.push(Self::issue_tx(tx, inventory, part).await?);
         }
+        Ok(issued)
+    }
 
+    /// Release every open reservation on a work order (WO cancelled).
+    pub async fn release_reserved_tx(
+        tx: &mut Transaction<'_, Postgres>,
+        inventory: &InventoryIntegration,
+        wo_id: Uuid,
+        tenant_id: &str,
+    ) -> Result<(), WoPartError> {
+        for part in Self::reserved_parts_for_update(tx, wo_id, tenant_id).await? {
+            if let Some(reservation_id) = part.reservation_id {
+                inventory
+                    .release(tenant_id, wo_id, part.id, reservation_id)
+                    .await?;
+            }
+            sqlx::query(
+                "UPDATE work_order_parts SET stock_status = 'released', reservation_id = NULL WHERE id = $1",
+            )
+                .bind(part.id)
+                .execute(&mut **tx)
+                .await?;
+        }
         Ok(())
     }
+
+    async fn reserved_parts_for_update(
+        tx: &mut Transaction<'_, Postgres>,
+        wo_id: Uuid,
+        tenant_id: &str,
+    ) -> Result<Vec<WoPart>, WoPartError> {
+        let parts = sqlx::query_as::<_, WoPart>(
+            r#"
+            SELECT * FROM work_order_parts
+            WHERE work_order_id = $1 AND tenant_id = $2 AND stock_status = 'reserved'
+            ORDER BY created_at ASC
+            FOR UPDATE
+            "#,
+        )
+        .bind(wo_id)
+        .bind(tenant_id)
+        .fetch_all(&mut **tx)
+        .await?;
+        Ok(parts)
+    }
+
+    /// Issue a part against its reservation and record the actual cost.
+    async fn issue_tx(
+        tx: &mut Transaction<'_, Postgres>,
+        inventory: &InventoryIntegration,
+        part: &WoPart,
+    ) -> Result<WoPart, WoPartError> {
+        let (Some(item_id), Some(warehouse_id)) = (part.item_id, part.warehouse_id) else {
+            return Err(WoPartError::Validation(
+                "only stocked parts can be issued".into(),
+            ));
+        };
+
+        let issued = inventory
+            .issue(
+                &part.tenant_id,
+                part.work_order_id,
+                part.id,
+                &PartIssue {
+                    item_id,
+                    warehouse_id,
+                    location_id: part.location_id,
+                    lot_code: part.lot_code.as_deref(),
+                    serial_codes: part.serial_codes.as_deref(),
+                    quantity: part.quantity as i64,
+                    currency: &part.currency,
+                    planned_unit_cost_minor: part.unit_cost_minor,
+                    // The issue settles the hold atomically; if it fails the
+                    // reservation stays in place.
+                    reservation_id: part.reservation_id,
+                },
+            )
+            .await?;
+
+        let part = sqlx::query_as::<_, WoPart>(
+            r#"
+            UPDATE work_order_parts SET
+                stock_status        = 'issued',
+                reservation_id      = NULL,
+                inventory_issue_ref = $2,
+                issued_quantity     = quantity,
+                issued_cost_minor   = $3,
+                issued_at           = NOW()
+            WHERE id = $1
+            RETURNING *
+            "#,
+        )
+        .bind(part.id)
+        .bind(issued.issue_line_id)
+        .bind(issued.total_cost_minor)
+        .fetch_one(&mut **tx)
+        .await?;
+
+        Ok(part)
+    }
+}
+
+/// Cost credited for returning `quantity` units of an issued part: average
+/// issued unit cost, with the final return taking whatever cost remains.
+fn return_cost(part: &WoPart, quantity: i32) -> i64 {
+    if part.returned_quantity + quantity == part.issued_quantity {
+        return part.issued_cost_minor - part.returned_cost_minor;
+    }
+    part.issued_cost_minor * quantity as i64 / part.issued_quantity as i64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn issued_part(quantity: i32, cost: i64) -> WoPart {
+        WoPart {
+            id: Uuid::new_v4(),
+            tenant_id: "t1".into(),
+            work_order_id: Uuid::new_v4(),
+            part_description: "Bearing".into(),
+            part_ref: None,
+            quantity,
+            unit_cost_minor: 0,
+            currency: "USD".into(),
+            inventory_issue_ref: None,
+            item_id: Some(Uuid::new_v4()),
+            warehouse_id: Some(Uuid::new_v4()),
+            location_id: None,
+            lot_code: None,
+            serial_codes: None,
+            stock_status: StockStatus::Issued,
+            reservation_id: None,
+            issued_quantity: quantity,
+            issued_cost_minor: cost,
+            returned_quantity: 0,
+            returned_cost_minor: 0,
+            issued_at: None,
+            created_at: Utc::now(),
+        }
+    }
+
+    #[test]
+    fn partial_returns_net_to_zero_on_full_return() {
+        let mut part = issued_part(3, 1000);
+        let first = return_cost(&part, 1);
+        assert_eq!(first, 333);
+        part.returned_quantity = 1;
+        part.returned_cost_minor = first;
+        let rest = return_cost(&part, 2);
+        assert_eq!(rest, 667);
+        part.returned_cost_minor += rest;
+        assert_eq!(part.cost_minor(), 0);
+    }
+
+    #[test]
+    fn standalone_cost_uses_planned_unit_cost() {
+        let mut part = issued_part(2, 999);
+        part.stock_status = StockStatus::Standalone;
+        part.unit_cost_minor = 450;
+        assert_eq!(part.cost_minor(), 900);
+    }
+
+    #[test]
+    fn stocked_part_requires_warehouse() {
+        let req = AddPartRequest {
+            tenant_id: "t1".into(),
+            part_description: "Filter".into(),
+            part_ref: None,
+            quantity: 1,
+            unit_cost_minor: 100,
+            currency: None,
+            inventory_issue_ref: None,
+            item_id: Some(Uuid::new_v4()),
+            warehouse_id: None,
+            location_id: None,
+            lot_code: None,
+            serial_codes: None,
+        };
+        assert!(matches!(
+            validate_add(&req),
+            Err(WoPartError::Validation(_))
+        ));
+    }
+
+    #[test]
+    fn serial_count_must_match_quantity() {
+        let serials = vec!["SN-1".to_string()];
+        assert!(check_serial_count(Some(&serials), 2).is_err());
+        assert!(check_serial_count(Some(&serials), 1).is_ok());
+        assert!(check_serial_count(None, 5).is_ok());
+    }
 }
@@ -12,6 +12,7 @@ use thiserror::Error;
 use uuid::Uuid;
 
 use super::super::guards::GuardError;
+use super::super::parts::WoPartError;
 use super::super::state_machine::TransitionError;
 use super::super::types::{Priority, WoStatus, WoType};
 use crate::events::{envelope, subjects};
@@ -44,6 +45,8 @@ pub struct WorkOrder {
     pub notes: Option<String>,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
+    /// Failure mode recorded on completion of a corrective work order.
+    pub failure_code: Option<String>,
 }
 
 // ── Request / query types ─────────────────────────────────────
@@ -71,6 +74,8 @@ pub struct TransitionRequest {
     pub downtime_minutes: Option<i32>,
     pub closed_at: Option<DateTime<Utc>>,
     pub notes: Option<String>,
+    /// Failure mode; accepted only when completing a corrective work order.
+    pub failure_code: Option<String>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -104,6 +109,9 @@ pub enum WoError {
     #[error("Guard error: {0}")]
     Guard(#[from] GuardError),
 
+    #[error("Parts error: {0}")]
+    Parts(#[from] WoPartError),
+
     #[error("Database error: {0}")]
     Database(#[from] sqlx::Error),
 }
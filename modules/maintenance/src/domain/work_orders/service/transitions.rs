@@ -4,15 +4,21 @@
 //! - Every status transition is validated by state_machine + guards
 //! - Completed events include GL cost payload for downstream journal entries
 //! - Every mutation writes its event to the outbox atomically in the same tx
+//! - Inventory-linked parts are issued on start (or completion) and released
+//!   on cancellation inside the transition tx; an Inventory failure rolls back
+//!   the transition
 
 use sqlx::PgPool;
 use uuid::Uuid;
 
 use super::super::guards::{run_guards, TransitionContext};
+use super::super::parts::WoPartsRepo;
 use super::super::state_machine::validate_transition;
-use super::super::types::WoStatus;
+use super::super::types::{WoStatus, WoType};
 use super::core::{TransitionRequest, WoError, WorkOrder, WorkOrderRepo};
+use crate::domain::tenant_config::TenantConfigRepo;
 use crate::events::{envelope, subjects};
+use crate::integrations::InventoryIntegration;
 use crate::outbox;
 
 // ── Cost payload for GL integration ───────────────────────────
@@ -29,7 +35,8 @@ struct CostPayload {
 impl WorkOrderRepo {
     /// Compute cost totals for a completed work order within the same tx.
     ///
-    /// Parts total: SUM(quantity * unit_cost_minor)
+    /// Parts total: standalone parts at SUM(quantity * unit_cost_minor);
+    /// inventory-linked parts at actual issued cost net of returns
     /// Labor total: SUM(ROUND(hours_decimal * rate_minor))
     /// Currency: taken from first cost entry, or "USD" if no entries.
     /// fixed_asset_ref: from the linked maintainable_asset.
@@ -43,7 +50,10 @@ impl WorkOrderRepo {
         // SUM of BIGINT returns NUMERIC; cast to BIGINT for Rust i64.
         let parts_row: (i64, Option<String>) = sqlx::query_as(
             r#"
-            SELECT COALESCE(SUM(quantity::bigint * unit_cost_minor), 0)::bigint,
+            SELECT COALESCE(SUM(CASE WHEN item_id IS NULL
+                                     THEN quantity::bigint * unit_cost_minor
+                                     ELSE issued_cost_minor - returned_cost_minor
+                                END), 0)::bigint,
                    MIN(currency)
             FROM work_order_parts
             WHERE work_order_id = $1 AND tenant_id = $2
@@ -97,6 +107,7 @@ impl WorkOrderRepo {
         pool: &PgPool,
         wo_id: Uuid,
         req: &TransitionRequest,
+        inventory: &InventoryIntegration,
     ) -> Result<WorkOrder, WoError> {
         if req.tenant_id.trim().is_empty() {
             return Err(WoError::Validation("tenant_id is required".into()));
@@ -127,6 +138,39 @@ impl WorkOrderRepo {
         };
         run_guards(target, &ctx)?;
 
+        // ── Guard: failure code (corrective completions only) ──
+        let failure_code = match req.failure_code.as_deref().map(str::trim) {
+            None => None,
+            Some("") => return Err(WoError::Validation("failure_code must not be empty".into())),
+            Some(code) => {
+                if target != WoStatus::Completed || current.wo_type != WoType::Corrective {
+                    return Err(WoError::Validation(
+                        "failure_code is only recorded when completing a corrective work order"
+                            .into(),
+                    ));
+                }
+                Some(code)
+            }
+        };
+
+        // ── Spare parts: issue or release inventory-linked parts ──
+        match target {
+            WoStatus::InProgress => {
+                let config = TenantConfigRepo::get_or_default_tx(&mut tx, &req.tenant_id).await?;
+                if config.issue_parts_on_start {
+                    WoPartsRepo::issue_reserved_tx(&mut tx, inventory, wo_id, &req.tenant_id)
+                        .await?;
+                }
+            }
+            WoStatus::Completed => {
+                WoPartsRepo::issue_reserved_tx(&mut tx, inventory, wo_id, &req.tenant_id).await?;
+            }
+            WoStatus::Cancelled => {
+                WoPartsRepo::release_reserved_tx(&mut tx, inventory, wo_id, &req.tenant_id).await?;
+            }
+            _ => {}
+        }
+
         // ── Mutation ──
         let wo = sqlx::query_as::<_, WorkOrder>(
             r#"
@@ -138,6 +182,7 @@ impl WorkOrderRepo {
                 downtime_minutes = COALESCE($5, downtime_minutes),
                 closed_at        = COALESCE($6, closed_at),
                 notes            = COALESCE($7, notes),
+                failure_code     = COALESCE($8, failure_code),
                 updated_at       = NOW()
             WHERE id = $1 AND tenant_id = $2
             RETURNING *
@@ -150,6 +195,7 @@ impl WorkOrderRepo {
         .bind(req.downtime_minutes)
         .bind(req.closed_at)
         .bind(req.notes.as_deref())
+        .bind(failure_code)
         .fetch_one(&mut *tx)
         .await?;
 
@@ -175,6 +221,7 @@ impl WorkOrderRepo {
                 "total_labor_minor": cost.total_labor_minor,
                 "currency": cost.currency,
                 "fixed_asset_ref": cost.fixed_asset_ref,
+                "failure_code": wo.failure_code,
             })
         } else {
             serde_json::json!({
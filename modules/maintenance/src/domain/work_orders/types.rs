@@ -238,6 +238,8 @@ pub enum ScheduleType {
     Calendar,
     Meter,
     Both,
+    /// Driven only by the plan's condition rules.
+    Condition,
 }
 
 impl ScheduleType {
@@ -246,6 +248,7 @@ impl ScheduleType {
             Self::Calendar => "calendar",
             Self::Meter => "meter",
             Self::Both => "both",
+            Self::Condition => "condition",
         }
     }
 
@@ -254,6 +257,7 @@ impl ScheduleType {
             "calendar" => Ok(Self::Calendar),
             "meter" => Ok(Self::Meter),
             "both" => Ok(Self::Both),
+            "condition" => Ok(Self::Condition),
             _ => Err(ParseEnumError {
                 type_name: "ScheduleType",
                 value: s.to_string(),
@@ -35,6 +35,9 @@ pub const CALIBRATION_STATUS_CHANGED: &str = "maintenance.calibration.status_cha
 // ── Out-of-service lifecycle ───────────────────────────────
 pub const OUT_OF_SERVICE_CHANGED: &str = "maintenance.asset.out_of_service_changed";
 
+// ── Reliability analytics ──────────────────────────────────
+pub const RELIABILITY_SNAPSHOT: &str = "maintenance.reliability.snapshot";
+
 /// All stable NATS subjects exported by the maintenance module.
 ///
 /// Used in tests to assert exhaustive coverage and in documentation.
@@ -56,6 +59,7 @@ pub const ALL_SUBJECTS: &[&str] = &[
     CALIBRATION_EVENT_RECORDED,
     CALIBRATION_STATUS_CHANGED,
     OUT_OF_SERVICE_CHANGED,
+    RELIABILITY_SNAPSHOT,
 ];
 
 #[cfg(test)]
@@ -83,6 +87,6 @@ mod tests {
 
     #[test]
     fn all_subjects_count() {
-        assert_eq!(ALL_SUBJECTS.len(), 17);
+        assert_eq!(ALL_SUBJECTS.len(), 18);
     }
 }
@@ -0,0 +1,193 @@
+use super::tenant::with_request_id;
+use crate::domain::condition::{
+    ConditionRule, ConditionRuleRepo, CreateConditionRuleRequest, TriggerReadingRepo,
+    UsageForecast, WorkOrderTriggerReading,
+};
+use crate::AppState;
+use axum::{
+    extract::{Path, Query, State},
+    http::StatusCode,
+    response::IntoResponse,
+    Extension, Json,
+};
+use event_bus::TracingContext;
+use platform_http_contracts::{ApiError, PaginatedResponse};
+use platform_sdk::extract_tenant;
+use security::VerifiedClaims;
+use serde::Deserialize;
+use std::sync::Arc;
+use uuid::Uuid;
+
+#[derive(Debug, Deserialize, utoipa::IntoParams)]
+pub struct ForecastParams {
+    /// Usage-rate lookback before the latest reading (default 720 hours).
+    pub window_hours: Option<i32>,
+}
+
+#[utoipa::path(
+    post, path = "/api/maintenance/plans/{plan_id}/condition-rules", tag = "Condition Monitoring",
+    params(("plan_id" = Uuid, Path, description = "Plan ID")),
+    request_body = CreateConditionRuleRequest,
+    responses(
+        (status = 201, description = "Condition rule created", body = ConditionRule),
+        (status = 400, body = ApiError),
+        (status = 404, body = ApiError),
+    ),
+    security(("bearer" = [])),
+)]
+pub async fn create_condition_rule(
+    State(state): State<Arc<AppState>>,
+    Path(plan_id): Path<Uuid>,
+    claims: Option<Extension<VerifiedClaims>>,
+    tracing_ctx: Option<Extension<TracingContext>>,
+    Json(mut req): Json<CreateConditionRuleRequest>,
+) -> impl IntoResponse {
+    let tenant_id = match extract_tenant(&claims) {
+        Ok(t) => t,
+        Err(e) => return with_request_id(e, &tracing_ctx).into_response(),
+    };
+    req.tenant_id = tenant_id;
+    match ConditionRuleRepo::create(&state.pool, plan_id, &req).await {
+        Ok(r) => (StatusCode::CREATED, Json(r)).into_response(),
+        Err(e) => {
+            let a = ApiError::from(e);
+            with_request_id(a, &tracing_ctx).into_response()
+        }
+    }
+}
+
+#[utoipa::path(
+    get, path = "/api/maintenance/plans/{plan_id}/condition-rules", tag = "Condition Monitoring",
+    params(("plan_id" = Uuid, Path, description = "Plan ID")),
+    responses(
+        (status = 200, description = "Condition rules on the plan", body = PaginatedResponse<ConditionRule>),
+        (status = 404, body = ApiError),
+    ),
+    security(("bearer" = [])),
+)]
+pub async fn list_condition_rules(
+    State(state): State<Arc<AppState>>,
+    Path(plan_id): Path<Uuid>,
+    claims: Option<Extension<VerifiedClaims>>,
+    tracing_ctx: Option<Extension<TracingContext>>,
+) -> impl IntoResponse {
+    let tenant_id = match extract_tenant(&claims) {
+        Ok(t) => t,
+        Err(e) => return with_request_id(e, &tracing_ctx).into_response(),
+    };
+    match ConditionRuleRepo::list(&state.pool, plan_id, &tenant_id).await {
+        Ok(v) => {
+            let t = v.len() as i64;
+            (
+                StatusCode::OK,
+                Json(PaginatedResponse::new(v, 1, t.max(1), t)),
+            )
+                .into_response()
+        }
+        Err(e) => {
+            let a = ApiError::from(e);
+            with_request_id(a, &tracing_ctx).into_response()
+        }
+    }
+}
+
+#[utoipa::path(
+    delete, path = "/api/maintenance/plans/{plan_id}/condition-rules/{rule_id}", tag = "Condition Monitoring",
+    params(
+        ("plan_id" = Uuid, Path, description = "Plan ID"),
+        ("rule_id" = Uuid, Path, description = "Condition rule ID"),
+    ),
+    responses(
+        (status = 200, description = "Condition rule deactivated", body = ConditionRule),
+        (status = 404, body = ApiError),
+    ),
+    security(("bearer" = [])),
+)]
+pub async fn deactivate_condition_rule(
+    State(state): State<Arc<AppState>>,
+    Path((plan_id, rule_id)): Path<(Uuid, Uuid)>,
+    claims: Option<Extension<VerifiedClaims>>,
+    tracing_ctx: Option<Extension<TracingContext>>,
+) -> impl IntoResponse {
+    let tenant_id = match extract_tenant(&claims) {
+        Ok(t) => t,
+        Err(e) => return with_request_id(e, &tracing_ctx).into_response(),
+    };
+    match ConditionRuleRepo::deactivate(&state.pool, plan_id, rule_id, &tenant_id).await {
+        Ok(r) => (StatusCode::OK, Json(r)).into_response(),
+        Err(e) => {
+            let a = ApiError::from(e);
+            with_request_id(a, &tracing_ctx).into_response()
+        }
+    }
+}
+
+#[utoipa::path(
+    get, path = "/api/maintenance/assignments/{assignment_id}/forecast", tag = "Condition Monitoring",
+    params(
+        ("assignment_id" = Uuid, Path, description = "Plan assignment ID"),
+        ForecastParams,
+    ),
+    responses(
+        (status = 200, description = "Usage rate and projected meter due date", body = UsageForecast),
+        (status = 400, body = ApiError),
+        (status = 404, body = ApiError),
+    ),
+    security(("bearer" = [])),
+)]
+pub async fn get_assignment_forecast(
+    State(state): State<Arc<AppState>>,
+    Path(assignment_id): Path<Uuid>,
+    claims: Option<Extension<VerifiedClaims>>,
+    Query(params): Query<ForecastParams>,
+    tracing_ctx: Option<Extension<TracingContext>>,
+) -> impl IntoResponse {
+    let tenant_id = match extract_tenant(&claims) {
+        Ok(t) => t,
+        Err(e) => return with_request_id(e, &tracing_ctx).into_response(),
+    };
+    match ConditionRuleRepo::forecast(&state.pool, &tenant_id, assignment_id, params.window_hours)
+        .await
+    {
+        Ok(f) => (StatusCode::OK, Json(f)).into_response(),
+        Err(e) => {
+            let a = ApiError::from(e);
+            with_request_id(a, &tracing_ctx).into_response()
+        }
+    }
+}
+
+#[utoipa::path(
+    get, path = "/api/maintenance/work-orders/{wo_id}/trigger-readings", tag = "Condition Monitoring",
+    params(("wo_id" = Uuid, Path, description = "Work order ID")),
+    responses(
+        (status = 200, description = "Meter readings that triggered the work order", body = PaginatedResponse<WorkOrderTriggerReading>),
+        (status = 404, body = ApiError),
+    ),
+    security(("bearer" = [])),
+)]
+pub async fn list_trigger_readings(
+    State(state): State<Arc<AppState>>,
+    Path(wo_id): Path<Uuid>,
+    claims: Option<Extension<VerifiedClaims>>,
+    tracing_ctx: Option<Extension<TracingContext>>,
+) -> impl IntoResponse {
+    let tenant_id = match extract_tenant(&claims) {
+        Ok(t) => t,
+        Err(e) => return with_request_id(e, &tracing_ctx).into_response(),
+    };
+    match TriggerReadingRepo::list(&state.pool, &tenant_id, wo_id).await {
+        Ok(v) => {
+            let t = v.len() as i64;
+            (
+                StatusCode::OK,
+                Json(PaginatedResponse::new(v, 1, t.max(1), t)),
+            )
+                .into_response()
+        }
+        Err(e) => {
+            let a = ApiError::from(e);
+            with_request_id(a, &tracing_ctx).into_response()
+        }
+    }
+}
@@ -1,9 +1,12 @@
 pub mod assets;
 pub mod calibration_events;
+pub mod condition_rules;
 pub mod downtime;
 pub mod health;
 pub mod meters;
 pub mod plans;
+pub mod reliability;
+pub mod spare_parts;
 pub mod tenant;
 pub mod work_order_labor;
 pub mod work_order_parts;
@@ -0,0 +1,202 @@
+use super::tenant::with_request_id;
+use crate::domain::reliability::{
+    FailureParetoEntry, PublishSnapshotRequest, ReliabilityGroupBy, ReliabilityQuery,
+    ReliabilityRepo, ReliabilityReport, ReliabilitySnapshot, ReliabilityTrendPoint, TrendBucket,
+};
+use crate::AppState;
+use axum::{
+    extract::{Query, State},
+    http::StatusCode,
+    response::IntoResponse,
+    Extension, Json,
+};
+use chrono::{DateTime, Utc};
+use event_bus::TracingContext;
+use platform_http_contracts::{ApiError, PaginatedResponse};
+use platform_sdk::extract_tenant;
+use security::VerifiedClaims;
+use serde::Deserialize;
+use std::sync::Arc;
+use uuid::Uuid;
+
+#[derive(Debug, Deserialize, utoipa::IntoParams)]
+pub struct ReliabilityParams {
+    pub from: DateTime<Utc>,
+    pub to: DateTime<Utc>,
+    pub asset_id: Option<Uuid>,
+    pub asset_type: Option<String>,
+    pub failure_code: Option<String>,
+    /// Break the overall figures down by asset, asset_type or failure_code.
+    pub group_by: Option<ReliabilityGroupBy>,
+}
+
+#[derive(Debug, Deserialize, utoipa::IntoParams)]
+pub struct ParetoParams {
+    pub from: DateTime<Utc>,
+    pub to: DateTime<Utc>,
+    pub asset_id: Option<Uuid>,
+    pub asset_type: Option<String>,
+}
+
+#[derive(Debug, Deserialize, utoipa::IntoParams)]
+pub struct TrendParams {
+    pub from: DateTime<Utc>,
+    pub to: DateTime<Utc>,
+    pub asset_id: Option<Uuid>,
+    pub asset_type: Option<String>,
+    pub failure_code: Option<String>,
+    /// Bucket size: day, week or month (default month).
+    pub bucket: Option<TrendBucket>,
+}
+
+#[utoipa::path(
+    get, path = "/api/maintenance/reliability", tag = "Reliability",
+    params(ReliabilityParams),
+    responses(
+        (status = 200, description = "MTBF, MTTR, MTTF and availability for the window", body = ReliabilityReport),
+        (status = 400, body = ApiError),
+    ),
+    security(("bearer" = [])),
+)]
+pub async fn get_reliability(
+    State(state): State<Arc<AppState>>,
+    claims: Option<Extension<VerifiedClaims>>,
+    Query(params): Query<ReliabilityParams>,
+    tracing_ctx: Option<Extension<TracingContext>>,
+) -> impl IntoResponse {
+    let tenant_id = match extract_tenant(&claims) {
+        Ok(t) => t,
+        Err(e) => return with_request_id(e, &tracing_ctx).into_response(),
+    };
+    let q = ReliabilityQuery {
+        tenant_id,
+        from: params.from,
+        to: params.to,
+        asset_id: params.asset_id,
+        asset_type: params.asset_type,
+        failure_code: params.failure_code,
+    };
+    match ReliabilityRepo::report(&state.pool, &q, params.group_by).await {
+        Ok(r) => (StatusCode::OK, Json(r)).into_response(),
+        Err(e) => {
+            let a = ApiError::from(e);
+            with_request_id(a, &tracing_ctx).into_response()
+        }
+    }
+}
+
+#[utoipa::path(
+    get, path = "/api/maintenance/reliability/pareto", tag = "Reliability",
+    params(ParetoParams),
+    responses(
+        (status = 200, description = "Failure codes ranked by failure count", body = PaginatedResponse<FailureParetoEntry>),
+        (status = 400, body = ApiError),
+    ),
+    security(("bearer" = [])),
+)]
+pub async fn get_failure_pareto(
+    State(state): State<Arc<AppState>>,
+    claims: Option<Extension<VerifiedClaims>>,
+    Query(params): Query<ParetoParams>,
+    tracing_ctx: Option<Extension<TracingContext>>,
+) -> impl IntoResponse {
+    let tenant_id = match extract_tenant(&claims) {
+        Ok(t) => t,
+        Err(e) => return with_request_id(e, &tracing_ctx).into_response(),
+    };
+    let q = ReliabilityQuery {
+        tenant_id,
+        from: params.from,
+        to: params.to,
+        asset_id: params.asset_id,
+        asset_type: params.asset_type,
+        failure_code: None,
+    };
+    match ReliabilityRepo::pareto(&state.pool, &q).await {
+        Ok(v) => {
+            let t = v.len() as i64;
+            (
+                StatusCode::OK,
+                Json(PaginatedResponse::new(v, 1, t.max(1), t)),
+            )
+                .into_response()
+        }
+        Err(e) => {
+            let a = ApiError::from(e);
+            with_request_id(a, &tracing_ctx).into_response()
+        }
+    }
+}
+
+#[utoipa::path(
+    get, path = "/api/maintenance/reliability/trend", tag = "Reliability",
+    params(TrendParams),
+    responses(
+        (status = 200, description = "Reliability metrics per bucket", body = PaginatedResponse<ReliabilityTrendPoint>),
+        (status = 400, body = ApiError),
+    ),
+    security(("bearer" = [])),
+)]
+pub async fn get_reliability_trend(
+    State(state): State<Arc<AppState>>,
+    claims: Option<Extension<VerifiedClaims>>,
+    Query(params): Query<TrendParams>,
+    tracing_ctx: Option<Extension<TracingContext>>,
+) -> impl IntoResponse {
+    let tenant_id = match extract_tenant(&claims) {
+        Ok(t) => t,
+        Err(e) => return with_request_id(e, &tracing_ctx).into_response(),
+    };
+    let q = ReliabilityQuery {
+        tenant_id,
+        from: params.from,
+        to: params.to,
+        asset_id: params.asset_id,
+        asset_type: params.asset_type,
+        failure_code: params.failure_code,
+    };
+    let bucket = params.bucket.unwrap_or(TrendBucket::Month);
+    match ReliabilityRepo::trend(&state.pool, &q, bucket).await {
+        Ok(v) => {
+            let t = v.len() as i64;
+            (
+                StatusCode::OK,
+                Json(PaginatedResponse::new(v, 1, t.max(1), t)),
+            )
+                .into_response()
+        }
+        Err(e) => {
+            let a = ApiError::from(e);
+            with_request_id(a, &tracing_ctx).into_response()
+        }
+    }
+}
+
+#[utoipa::path(
+    post, path = "/api/maintenance/reliability/snapshots", tag = "Reliability",
+    request_body = PublishSnapshotRequest,
+    responses(
+        (status = 201, description = "Snapshot computed and published for reporting", body = ReliabilitySnapshot),
+        (status = 400, body = ApiError),
+    ),
+    security(("bearer" = [])),
+)]
+pub async fn publish_snapshot(
+    State(state): State<Arc<AppState>>,
+    claims: Option<Extension<VerifiedClaims>>,
+    tracing_ctx: Option<Extension<TracingContext>>,
+    Json(mut req): Json<PublishSnapshotRequest>,
+) -> impl IntoResponse {
+    let tenant_id = match extract_tenant(&claims) {
+        Ok(t) => t,
+        Err(e) => return with_request_id(e, &tracing_ctx).into_response(),
+    };
+    req.tenant_id = tenant_id;
+    match ReliabilityRepo::publish_snapshot(&state.pool, &req).await {
+        Ok(s) => (StatusCode::CREATED, Json(s)).into_response(),
+        Err(e) => {
+            let a = ApiError::from(e);
+            with_request_id(a, &tracing_ctx).into_response()
+        }
+    }
+}
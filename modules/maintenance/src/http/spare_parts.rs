@@ -0,0 +1,186 @@
+use super::tenant::with_request_id;
+use crate::domain::spare_parts::{
+    AddPlanPartRequest, PlanPart, PlanPartsRepo, SparePartPolicy, SparePartPolicyRepo,
+    SparePartRecompute, UpsertSparePartPolicyRequest,
+};
+use crate::AppState;
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::IntoResponse,
+    Extension, Json,
+};
+use chrono::Utc;
+use event_bus::TracingContext;
+use platform_http_contracts::{ApiError, PaginatedResponse};
+use platform_sdk::extract_tenant;
+use security::VerifiedClaims;
+use std::sync::Arc;
+use uuid::Uuid;
+
+#[utoipa::path(
+    post, path = "/api/maintenance/plans/{plan_id}/parts", tag = "Spare Parts",
+    params(("plan_id" = Uuid, Path, description = "Plan ID")),
+    request_body = AddPlanPartRequest,
+    responses(
+        (status = 201, description = "Spare part added to plan", body = PlanPart),
+        (status = 400, body = ApiError),
+        (status = 404, body = ApiError),
+        (status = 409, body = ApiError),
+    ),
+    security(("bearer" = [])),
+)]
+pub async fn add_plan_part(
+    State(state): State<Arc<AppState>>,
+    Path(plan_id): Path<Uuid>,
+    claims: Option<Extension<VerifiedClaims>>,
+    tracing_ctx: Option<Extension<TracingContext>>,
+    Json(mut req): Json<AddPlanPartRequest>,
+) -> impl IntoResponse {
+    let tenant_id = match extract_tenant(&claims) {
+        Ok(t) => t,
+        Err(e) => return with_request_id(e, &tracing_ctx).into_response(),
+    };
+    req.tenant_id = tenant_id;
+    match PlanPartsRepo::add(&state.pool, plan_id, &req).await {
+        Ok(p) => (StatusCode::CREATED, Json(p)).into_response(),
+        Err(e) => {
+            let a = ApiError::from(e);
+            with_request_id(a, &tracing_ctx).into_response()
+        }
+    }
+}
+
+#[utoipa::path(
+    get, path = "/api/maintenance/plans/{plan_id}/parts", tag = "Spare Parts",
+    params(("plan_id" = Uuid, Path, description = "Plan ID")),
+    responses(
+        (status = 200, description = "Spare parts required per plan occurrence", body = PaginatedResponse<PlanPart>),
+        (status = 404, body = ApiError),
+    ),
+    security(("bearer" = [])),
+)]
+pub async fn list_plan_parts(
+    State(state): State<Arc<AppState>>,
+    Path(plan_id): Path<Uuid>,
+    claims: Option<Extension<VerifiedClaims>>,
+    tracing_ctx: Option<Extension<TracingContext>>,
+) -> impl IntoResponse {
+    let tenant_id = match extract_tenant(&claims) {
+        Ok(t) => t,
+        Err(e) => return with_request_id(e, &tracing_ctx).into_response(),
+    };
+    match PlanPartsRepo::list(&state.pool, plan_id, &tenant_id).await {
+        Ok(v) => {
+            let t = v.len() as i64;
+            (
+                StatusCode::OK,
+                Json(PaginatedResponse::new(v, 1, t.max(1), t)),
+            )
+                .into_response()
+        }
+        Err(e) => {
+            let a = ApiError::from(e);
+            with_request_id(a, &tracing_ctx).into_response()
+        }
+    }
+}
+
+#[utoipa::path(
+    post, path = "/api/maintenance/spare-part-policies", tag = "Spare Parts",
+    request_body = UpsertSparePartPolicyRequest,
+    responses(
+        (status = 200, description = "Spare part policy created or updated", body = SparePartPolicy),
+        (status = 400, body = ApiError),
+    ),
+    security(("bearer" = [])),
+)]
+pub async fn upsert_policy(
+    State(state): State<Arc<AppState>>,
+    claims: Option<Extension<VerifiedClaims>>,
+    tracing_ctx: Option<Extension<TracingContext>>,
+    Json(mut req): Json<UpsertSparePartPolicyRequest>,
+) -> impl IntoResponse {
+    let tenant_id = match extract_tenant(&claims) {
+        Ok(t) => t,
+        Err(e) => return with_request_id(e, &tracing_ctx).into_response(),
+    };
+    req.tenant_id = tenant_id;
+    match SparePartPolicyRepo::upsert(&state.pool, &req).await {
+        Ok(p) => (StatusCode::OK, Json(p)).into_response(),
+        Err(e) => {
+            let a = ApiError::from(e);
+            with_request_id(a, &tracing_ctx).into_response()
+        }
+    }
+}
+
+#[utoipa::path(
+    get, path = "/api/maintenance/spare-part-policies", tag = "Spare Parts",
+    responses(
+        (status = 200, description = "Spare part policies", body = PaginatedResponse<SparePartPolicy>),
+    ),
+    security(("bearer" = [])),
+)]
+pub async fn list_policies(
+    State(state): State<Arc<AppState>>,
+    claims: Option<Extension<VerifiedClaims>>,
+    tracing_ctx: Option<Extension<TracingContext>>,
+) -> impl IntoResponse {
+    let tenant_id = match extract_tenant(&claims) {
+        Ok(t) => t,
+        Err(e) => return with_request_id(e, &tracing_ctx).into_response(),
+    };
+    match SparePartPolicyRepo::list(&state.pool, &tenant_id).await {
+        Ok(v) => {
+            let t = v.len() as i64;
+            (
+                StatusCode::OK,
+                Json(PaginatedResponse::new(v, 1, t.max(1), t)),
+            )
+                .into_response()
+        }
+        Err(e) => {
+            let a = ApiError::from(e);
+            with_request_id(a, &tracing_ctx).into_response()
+        }
+    }
+}
+
+#[utoipa::path(
+    post, path = "/api/maintenance/spare-part-policies/{policy_id}/recompute", tag = "Spare Parts",
+    params(("policy_id" = Uuid, Path, description = "Spare part policy ID")),
+    responses(
+        (status = 200, description = "Min/max recomputed and synced to Inventory", body = SparePartRecompute),
+        (status = 404, body = ApiError),
+        (status = 502, description = "Inventory reorder policy sync failed", body = ApiError),
+    ),
+    security(("bearer" = [])),
+)]
+pub async fn recompute_policy(
+    State(state): State<Arc<AppState>>,
+    Path(policy_id): Path<Uuid>,
+    claims: Option<Extension<VerifiedClaims>>,
+    tracing_ctx: Option<Extension<TracingContext>>,
+) -> impl IntoResponse {
+    let tenant_id = match extract_tenant(&claims) {
+        Ok(t) => t,
+        Err(e) => return with_request_id(e, &tracing_ctx).into_response(),
+    };
+    let today = Utc::now().date_naive();
+    match SparePartPolicyRepo::recompute(
+        &state.pool,
+        &state.inventory,
+        &tenant_id,
+        policy_id,
+        today,
+    )
+    .await
+    {
+        Ok(r) => (StatusCode::OK, Json(r)).into_response(),
+        Err(e) => {
+            let a = ApiError::from(e);
+            with_request_id(a, &tracing_ctx).into_response()
+        }
+    }
+}
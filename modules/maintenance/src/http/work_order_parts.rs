@@ -1,5 +1,7 @@
 use super::tenant::with_request_id;
-use crate::domain::work_orders::{AddPartRequest, WoPart, WoPartsRepo};
+use crate::domain::work_orders::{
+    AddPartRequest, ReturnPartRequest, WoPart, WoPartReturn, WoPartsRepo,
+};
 use crate::AppState;
 use axum::{
     extract::{Path, State},
@@ -21,6 +23,7 @@ use uuid::Uuid;
     responses(
         (status = 201, description = "Part added to work order", body = WoPart),
         (status = 400, body = ApiError),
+        (status = 502, description = "Inventory reservation or issue failed", body = ApiError),
     ),
     security(("bearer" = [])),
 )]
@@ -36,7 +39,7 @@ pub async fn add_part(
         Err(e) => return with_request_id(e, &tracing_ctx).into_response(),
     };
     req.tenant_id = tenant_id;
-    match WoPartsRepo::add(&state.pool, wo_id, &req).await {
+    match WoPartsRepo::add(&state.pool, wo_id, &req, &state.inventory).await {
         Ok(p) => (StatusCode::CREATED, Json(p)).into_response(),
         Err(e) => {
             let a = ApiError::from(e);
@@ -102,7 +105,7 @@ pub async fn remove_part(
         Ok(t) => t,
         Err(e) => return with_request_id(e, &tracing_ctx).into_response(),
     };
-    match WoPartsRepo::remove(&state.pool, wo_id, part_id, &tenant_id).await {
+    match WoPartsRepo::remove(&state.pool, wo_id, part_id, &tenant_id, &state.inventory).await {
         Ok(()) => StatusCode::NO_CONTENT.into_response(),
         Err(e) => {
             let a = ApiError::from(e);
@@ -110,3 +113,74 @@ pub async fn remove_part(
         }
     }
 }
+
+#[utoipa::path(
+    post, path = "/api/maintenance/work-orders/{wo_id}/parts/{part_id}/return", tag = "Work Order Parts",
+    params(
+        ("wo_id" = Uuid, Path, description = "Work order ID"),
+        ("part_id" = Uuid, Path, description = "Part entry ID"),
+    ),
+    request_body = ReturnPartRequest,
+    responses(
+        (status = 201, description = "Unused quantity returned to stock", body = WoPartReturn),
+        (status = 400, body = ApiError),
+        (status = 404, body = ApiError),
+        (status = 502, description = "Inventory receipt failed", body = ApiError),
+    ),
+    security(("bearer" = [])),
+)]
+pub async fn return_part(
+    State(state): State<Arc<AppState>>,
+    Path((wo_id, part_id)): Path<(Uuid, Uuid)>,
+    claims: Option<Extension<VerifiedClaims>>,
+    tracing_ctx: Option<Extension<TracingContext>>,
+    Json(mut req): Json<ReturnPartRequest>,
+) -> impl IntoResponse {
+    let tenant_id = match extract_tenant(&claims) {
+        Ok(t) => t,
+        Err(e) => return with_request_id(e, &tracing_ctx).into_response(),
+    };
+    req.tenant_id = tenant_id;
+    match WoPartsRepo::return_part(&state.pool, wo_id, part_id, &req, &state.inventory).await {
+        Ok(r) => (StatusCode::CREATED, Json(r)).into_response(),
+        Err(e) => {
+            let a = ApiError::from(e);
+            with_request_id(a, &tracing_ctx).into_response()
+        }
+    }
+}
+
+#[utoipa::path(
+    get, path = "/api/maintenance/work-orders/{wo_id}/part-returns", tag = "Work Order Parts",
+    params(("wo_id" = Uuid, Path, description = "Work order ID")),
+    responses(
+        (status = 200, description = "Part returns for work order", body = PaginatedResponse<WoPartReturn>),
+        (status = 404, body = ApiError),
+    ),
+    security(("bearer" = [])),
+)]
+pub async fn list_part_returns(
+    State(state): State<Arc<AppState>>,
+    Path(wo_id): Path<Uuid>,
+    claims: Option<Extension<VerifiedClaims>>,
+    tracing_ctx: Option<Extension<TracingContext>>,
+) -> impl IntoResponse {
+    let tenant_id = match extract_tenant(&claims) {
+        Ok(t) => t,
+        Err(e) => return with_request_id(e, &tracing_ctx).into_response(),
+    };
+    match WoPartsRepo::list_returns(&state.pool, wo_id, &tenant_id).await {
+        Ok(v) => {
+            let t = v.len() as i64;
+            (
+                StatusCode::OK,
+                Json(PaginatedResponse::new(v, 1, t.max(1), t)),
+            )
+                .into_response()
+        }
+        Err(e) => {
+            let a = ApiError::from(e);
+            with_request_id(a, &tracing_ctx).into_response()
+        }
+    }
+}
@@ -155,7 +155,7 @@ pub async fn transition_work_order(
         Err(e) => return with_request_id(e, &tracing_ctx).into_response(),
     };
     req.tenant_id = tenant_id;
-    match WorkOrderRepo::transition(&state.pool, id, &req).await {
+    match WorkOrderRepo::transition(&state.pool, id, &req, &state.inventory).await {
         Ok(wo) => (StatusCode::OK, Json(wo)).into_response(),
         Err(e) => {
             let a = ApiError::from(e);
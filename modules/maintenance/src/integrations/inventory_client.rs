@@ -0,0 +1,442 @@
+//! Integration client for the Inventory module.
+//!
+//! Stock movements for work-order spare parts:
+//! - Part planned on a WO → reservation (hold) in the part's warehouse
+//! - WO started / completed → reservation released, then an issue with
+//!   source_type `maintenance` (FIFO cost, lot/serial where tracked)
+//! - Unused parts returned → receipt with source_type `return`
+//! - Spare-part min/max policy recomputed → Inventory reorder policy upsert
+//!
+//! Idempotency key: `mnt:{tenant_id}:{work_order_id}:{line_id}:{action}`,
+//! so a retried transition replays the same inventory movement.
+//!
+//! Two modes (same pattern as shipping-receiving → Inventory):
+//! - `Platform` / `Http` — calls the Inventory module via typed
+//!   `platform-client-inventory`
+//! - `Deterministic` — derives stable UUIDs from idempotency keys and costs
+//!   issues at the part's planned unit cost; no external calls
+
+use platform_client_inventory::{
+    CreateReorderPolicyRequest, IssueRequest, IssuesClient, ReceiptRequest, ReceiptsClient,
+    ReleaseRequest, ReorderPoliciesClient, ReservationsClient, ReserveRequest,
+    UpdateReorderPolicyRequest,
+};
+use platform_sdk::{ClientError, PlatformClient, VerifiedClaims};
+use thiserror::Error;
+use uuid::Uuid;
+
+const SOURCE_MODULE: &str = "maintenance";
+const SOURCE_TYPE_MAINTENANCE: &str = "maintenance";
+const SOURCE_TYPE_RETURN: &str = "return";
+
+// ── Errors ───────────────────────────────────────────────────
+
+#[derive(Debug, Error)]
+pub enum InventoryError {
+    #[error("Inventory client error: {0}")]
+    Client(Box<ClientError>),
+
+    #[error("Tenant id '{0}' is not a valid UUID for inventory service claims")]
+    InvalidTenant(String),
+}
+
+impl From<ClientError> for InventoryError {
+    fn from(e: ClientError) -> Self {
+        Self::Client(Box::new(e))
+    }
+}
+
+// ── Movement inputs / outputs ────────────────────────────────
+
+/// Stock to issue for one work-order part line.
+#[derive(Debug, Clone)]
+pub struct PartIssue<'a> {
+    pub item_id: Uuid,
+    pub warehouse_id: Uuid,
+    pub location_id: Option<Uuid>,
+    pub lot_code: Option<&'a str>,
+    pub serial_codes: Option<&'a [String]>,
+    pub quantity: i64,
+    pub currency: &'a str,
+    /// Planned unit cost — used to cost the issue in deterministic mode only.
+    pub planned_unit_cost_minor: i64,
+    /// Reservation the issue consumes, so the held units are never released
+    /// to other demand before the issue lands.
+    pub reservation_id: Option<Uuid>,
+}
+
+/// Result of an inventory issue: the ledger reference and actual FIFO cost.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct IssuedStock {
+    pub issue_line_id: Uuid,
+    pub total_cost_minor: i64,
+}
+
+/// Unused stock going back to the shelf.
+#[derive(Debug, Clone)]
+pub struct PartReturn<'a> {
+    pub item_id: Uuid,
+    pub warehouse_id: Uuid,
+    pub location_id: Option<Uuid>,
+    pub lot_code: Option<&'a str>,
+    pub serial_codes: Option<&'a [String]>,
+    pub quantity: i64,
+    pub unit_cost_minor: i64,
+    pub currency: &'a str,
+}
+
+/// Min/max levels pushed to the Inventory reorder policy for an item.
+#[derive(Debug, Clone, Copy)]
+pub struct ReorderLevels {
+    pub item_id: Uuid,
+    pub reorder_point: i64,
+    pub safety_stock: i64,
+    pub max_qty: i64,
+}
+
+// ── Client ───────────────────────────────────────────────────
+
+/// Inventory integration client.
+#[derive(Debug, Clone)]
+pub struct InventoryIntegration {
+    mode: Mode,
+}
+
+#[derive(Debug, Clone)]
+enum Mode {
+    /// SDK-wired platform client with service token.
+    Platform {
+        client: PlatformClient,
+    },
+    Http {
+        base_url: String,
+        token: String,
+    },
+    Deterministic,
+}
+
+impl platform_sdk::PlatformService for InventoryIntegration {
+    const SERVICE_NAME: &'static str = "inventory";
+    fn from_platform_client(client: PlatformClient) -> Self {
+        Self {
+            mode: Mode::Platform { client },
+        }
+    }
+}
+
+impl InventoryIntegration {
+    /// Create an HTTP-backed client pointing at the Inventory module.
+    pub fn http(base_url: &str, token: &str) -> Self {
+        Self {
+            mode: Mode::Http {
+                base_url: base_url.trim_end_matches('/').to_string(),
+                token: token.to_string(),
+            },
+        }
+    }
+
+    /// Create a deterministic client that derives IDs from idempotency keys.
+    /// No external calls are made — IDs are stable UUID v5 hashes.
+    pub fn deterministic() -> Self {
+        Self {
+            mode: Mode::Deterministic,
+        }
+    }
+
+    /// Platform client and service claims for the remote modes; `None` in
+    /// deterministic mode.
+    fn remote(
+        &self,
+        tenant_id: &str,
+    ) -> Result<Option<(PlatformClient, VerifiedClaims)>, InventoryError> {
+        let client = match &self.mode {
+            Mode::Platform { client } => client.clone(),
+            Mode::Http { base_url, token } => {
+                PlatformClient::new(base_url.clone()).with_bearer_token(token.clone())
+            }
+            Mode::Deterministic => return Ok(None),
+        };
+        let claims = PlatformClient::service_claims_from_str(tenant_id)
+            .map_err(|_| InventoryError::InvalidTenant(tenant_id.to_string()))?;
+        Ok(Some((client, claims)))
+    }
+
+    /// Hold stock for a planned part. Returns the reservation id.
+    pub async fn reserve(
+        &self,
+        tenant_id: &str,
+        work_order_id: Uuid,
+        part_id: Uuid,
+        item_id: Uuid,
+        warehouse_id: Uuid,
+        quantity: i64,
+    ) -> Result<Uuid, InventoryError> {
+        let idem_key = make_idempotency_key(tenant_id, work_order_id, part_id, "reserve");
+        let Some((client, claims)) = self.remote(tenant_id)? else {
+            return Ok(derive_id(&idem_key));
+        };
+        let body = ReserveRequest {
+            tenant_id: tenant_id.to_string(),
+            item_id,
+            warehouse_id,
+            quantity,
+            idempotency_key: idem_key,
+            reference_type: Some("maintenance_work_order".to_string()),
+            reference_id: Some(work_order_id.to_string()),
+            expires_at: None,
+            causation_id: None,
+            correlation_id: None,
+        };
+        let result = ReservationsClient::new(client)
+            .post_reserve(&claims, &body)
+            .await?;
+        Ok(result.reservation_id)
+    }
+
+    /// Release a part's reservation (part removed or WO cancelled).
+    pub async fn release(
+        &self,
+        tenant_id: &str,
+        work_order_id: Uuid,
+        part_id: Uuid,
+        reservation_id: Uuid,
+    ) -> Result<(), InventoryError> {
+        let idem_key = make_idempotency_key(tenant_id, work_order_id, part_id, "release");
+        let Some((client, claims)) = self.remote(tenant_id)? else {
+            return Ok(());
+        };
+        let body = ReleaseRequest {
+            tenant_id: tenant_id.to_string(),
+            reservation_id,
+            idempotency_key: idem_key,
+            causation_id: None,
+            correlation_id: None,
+        };
+        ReservationsClient::new(client)
+            .post_release(&claims, &body)
+            .await?;
+        Ok(())
+    }
+
+    /// Issue stock for a part line. Returns the issue reference and the
+    /// actual FIFO cost of the consumed layers.
+    pub async fn issue(
+        &self,
+        tenant_id: &str,
+        work_order_id: Uuid,
+        part_id: Uuid,
+        line: &PartIssue<'_>,
+    ) -> Result<IssuedStock, InventoryError> {
+        let idem_key = make_idempotency_key(tenant_id, work_order_id, part_id, "issue");
+        let Some((client, claims)) = self.remote(tenant_id)? else {
+            return Ok(IssuedStock {
+                issue_line_id: derive_id(&idem_key),
+                total_cost_minor: line.quantity * line.planned_unit_cost_minor,
+            });
+        };
+        let body = IssueRequest {
+            tenant_id: tenant_id.to_string(),
+            item_id: line.item_id,
+            warehouse_id: line.warehouse_id,
+            location_id: line.location_id,
+            quantity: line.quantity,
+            currency: line.currency.to_string(),
+            lot_code: line.lot_code.map(str::to_string),
+            serial_codes: line.serial_codes.map(|codes| serde_json::json!(codes)),
+            source_module: SOURCE_MODULE.to_string(),
+            source_type: SOURCE_TYPE_MAINTENANCE.to_string(),
+            source_id: work_order_id.to_string(),
+            source_line_id: Some(part_id.to_string()),
+            idempotency_key: idem_key,
+            causation_id: None,
+            correlation_id: None,
+            uom_id: None,
+            reservation_id: line.reservation_id,
+        };
+        let result = IssuesClient::new(client).post_issue(&claims, &body).await?;
+        Ok(IssuedStock {
+            issue_line_id: result.issue_line_id,
+            total_cost_minor: result.total_cost_minor,
+        })
+    }
+
+    /// Receive unused parts back into stock. Returns the receipt line id.
+    pub async fn receive_return(
+        &self,
+        tenant_id: &str,
+        work_order_id: Uuid,
+        return_id: Uuid,
+        line: &PartReturn<'_>,
+    ) -> Result<Uuid, InventoryError> {
+        let idem_key = make_idempotency_key(tenant_id, work_order_id, return_id, "return");
+        let Some((client, claims)) = self.remote(tenant_id)? else {
+            return Ok(derive_id(&idem_key));
+        };
+        let body = ReceiptRequest {
+            tenant_id: tenant_id.to_string(),
+            item_id: line.item_id,
+            warehouse_id: line.warehouse_id,
+            location_id: line.location_id,
+            quantity: line.quantity,
+            unit_cost_minor: line.unit_cost_minor,
+            currency: line.currency.to_string(),
+            lot_code: line.lot_code.map(str::to_string),
+            serial_codes: line.serial_codes.map(|codes| serde_json::json!(codes)),
+            source_type: Some(SOURCE_TYPE_RETURN.to_string()),
+            idempotency_key: idem_key,
+            purchase_order_id: None,
+            causation_id: None,
+            correlation_id: None,
+            uom_id: None,
+        };
+        let result = ReceiptsClient::new(client)
+            .post_receipt(&claims, &body)
+            .await?;
+        Ok(result.receipt_line_id)
+    }
+
+    /// Create or update the Inventory reorder policy for a spare part.
+    /// Returns the reorder policy id.
+    pub async fn upsert_reorder_policy(
+        &self,
+        tenant_id: &str,
+        existing_policy_id: Option<Uuid>,
+        levels: &ReorderLevels,
+    ) -> Result<Uuid, InventoryError> {
+        let Some((client, claims)) = self.remote(tenant_id)? else {
+            return Ok(existing_policy_id.unwrap_or_else(|| {
+                derive_id(&format!(
+                    "mnt:{}:{}:reorder_policy",
+                    tenant_id, levels.item_id
+                ))
+            }));
+        };
+        let policies = ReorderPoliciesClient::new(client);
+        let policy = match existing_policy_id {
+            Some(id) => {
+                let body = UpdateReorderPolicyRequest {
+                    tenant_id: tenant_id.to_string(),
+                    reorder_point: Some(levels.reorder_point),
+                    safety_stock: Some(levels.safety_stock),
+                    max_qty: Some(levels.max_qty),
+                    notes: Some(REORDER_POLICY_NOTE.to_string()),
+                    updated_by: Some(SOURCE_MODULE.to_string()),
+                };
+                policies.put_reorder_policy(&claims, id, &body).await?
+            }
+            None => {
+                let body = CreateReorderPolicyRequest {
+                    tenant_id: tenant_id.to_string(),
+                    item_id: levels.item_id,
+                    location_id: None,
+                    reorder_point: levels.reorder_point,
+                    safety_stock: levels.safety_stock,
+                    max_qty: Some(levels.max_qty),
+                    notes: Some(REORDER_POLICY_NOTE.to_string()),
+                    created_by: Some(SOURCE_MODULE.to_string()),
+                };
+                policies.post_reorder_policy(&claims, &body).await?
+            }
+        };
+        Ok(policy.id)
+    }
+}
+
+const REORDER_POLICY_NOTE: &str = "Managed by maintenance spare-part policy";
+
+// ── Idempotency key ──────────────────────────────────────────
+
+/// Build a deterministic idempotency key from business identifiers.
+///
+/// Format: `mnt:{tenant_id}:{work_order_id}:{line_id}:{action}` where action
+/// is "reserve", "release", "issue" or "return".
+pub fn make_idempotency_key(
+    tenant_id: &str,
+    work_order_id: Uuid,
+    line_id: Uuid,
+    action: &str,
+) -> String {
+    format!("mnt:{}:{}:{}:{}", tenant_id, work_order_id, line_id, action)
+}
+
+/// Derive a deterministic UUID from an idempotency key using UUID v5.
+fn derive_id(idempotency_key: &str) -> Uuid {
+    Uuid::new_v5(&Uuid::NAMESPACE_OID, idempotency_key.as_bytes())
+}
+
+// ── Tests ────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn issue_line(quantity: i64) -> PartIssue<'static> {
+        PartIssue {
+            item_id: Uuid::new_v4(),
+            warehouse_id: Uuid::new_v4(),
+            location_id: None,
+            lot_code: None,
+            serial_codes: None,
+            quantity,
+            currency: "USD",
+            planned_unit_cost_minor: 1250,
+            reservation_id: None,
+        }
+    }
+
+    #[test]
+    fn idempotency_key_differs_by_action() {
+        let wo = Uuid::new_v4();
+        let part = Uuid::new_v4();
+        assert_ne!(
+            make_idempotency_key("t1", wo, part, "reserve"),
+            make_idempotency_key("t1", wo, part, "issue")
+        );
+    }
+
+    #[tokio::test]
+    async fn deterministic_issue_is_stable_and_costed_at_plan() -> Result<(), InventoryError> {
+        let client = InventoryIntegration::deterministic();
+        let wo = Uuid::new_v4();
+        let part = Uuid::new_v4();
+        let line = issue_line(4);
+
+        let a = client.issue("t1", wo, part, &line).await?;
+        let b = client.issue("t1", wo, part, &line).await?;
+        assert_eq!(a, b, "same inputs must produce the same issue");
+        assert_eq!(a.total_cost_minor, 5000);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn reservation_and_issue_ids_differ_for_same_part() -> Result<(), InventoryError> {
+        let client = InventoryIntegration::deterministic();
+        let wo = Uuid::new_v4();
+        let part = Uuid::new_v4();
+        let line = issue_line(1);
+
+        let reservation = client
+            .reserve("t1", wo, part, line.item_id, line.warehouse_id, 1)
+            .await?;
+        let issued = client.issue("t1", wo, part, &line).await?;
+        assert_ne!(reservation, issued.issue_line_id);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn remote_mode_rejects_non_uuid_tenant() {
+        let client = InventoryIntegration::http("http://localhost:1", "token");
+        let err = client
+            .reserve(
+                "not-a-uuid",
+                Uuid::new_v4(),
+                Uuid::new_v4(),
+                Uuid::new_v4(),
+                Uuid::new_v4(),
+                1,
+            )
+            .await
+            .unwrap_err();
+        assert!(matches!(err, InventoryError::InvalidTenant(_)));
+    }
+}
@@ -0,0 +1,3 @@
+pub mod inventory_client;
+
+pub use inventory_client::InventoryIntegration;
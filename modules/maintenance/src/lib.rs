@@ -7,13 +7,16 @@ pub mod consumers;
 pub mod domain;
 pub mod events;
 pub mod http;
+pub mod integrations;
 pub mod metrics;
 pub mod outbox;
 
 pub use config::Config;
+pub use integrations::InventoryIntegration;
 
 /// Application state shared across HTTP handlers and background tasks.
 pub struct AppState {
     pub pool: sqlx::PgPool,
     pub metrics: std::sync::Arc<metrics::MaintenanceMetrics>,
+    pub inventory: InventoryIntegration,
 }
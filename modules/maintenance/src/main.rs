@@ -22,7 +22,7 @@ use platform_sdk::{ConsumerError, EventEnvelope, ModuleBuilder, ModuleContext};
         title = "Maintenance Service",
         version = "2.2.0",
         description = "Maintenance management: work orders, preventive plans, meters, calibration, \
-                        downtime tracking, and labor management.\n\n\
+                        downtime tracking, labor management, inventory-integrated spare parts, condition-based triggers, and reliability analytics.\n\n\
                         **Authentication:** Bearer JWT. Tenant derived from JWT claims.\n\
                         Permissions: MAINTENANCE_READ for queries, MAINTENANCE_MUTATE for writes."
     ),
@@ -35,6 +35,12 @@ use platform_sdk::{ConsumerError, EventEnvelope, ModuleBuilder, ModuleContext};
         // Calibration
         http::calibration_events::record_calibration_event,
         http::calibration_events::get_calibration_status,
+        // Condition Monitoring
+        http::condition_rules::create_condition_rule,
+        http::condition_rules::list_condition_rules,
+        http::condition_rules::deactivate_condition_rule,
+        http::condition_rules::get_assignment_forecast,
+        http::condition_rules::list_trigger_readings,
         // Downtime
         http::downtime::create_downtime,
         http::downtime::list_downtime,
@@ -56,6 +62,17 @@ use platform_sdk::{ConsumerError, EventEnvelope, ModuleBuilder, ModuleContext};
         http::plans::update_plan,
         http::plans::assign_plan,
         http::plans::list_assignments,
+        // Reliability
+        http::reliability::get_reliability,
+        http::reliability::get_failure_pareto,
+        http::reliability::get_reliability_trend,
+        http::reliability::publish_snapshot,
+        // Spare Parts
+        http::spare_parts::add_plan_part,
+        http::spare_parts::list_plan_parts,
+        http::spare_parts::upsert_policy,
+        http::spare_parts::list_policies,
+        http::spare_parts::recompute_policy,
         // Work Orders
         http::work_orders::create_work_order,
         http::work_orders::list_work_orders,
@@ -69,6 +86,8 @@ use platform_sdk::{ConsumerError, EventEnvelope, ModuleBuilder, ModuleContext};
         http::work_order_parts::add_part,
         http::work_order_parts::list_parts,
         http::work_order_parts::remove_part,
+        http::work_order_parts::return_part,
+        http::work_order_parts::list_part_returns,
     ),
     components(schemas(
         maintenance_rs::domain::assets::Asset,
@@ -78,6 +97,13 @@ use platform_sdk::{ConsumerError, EventEnvelope, ModuleBuilder, ModuleContext};
         maintenance_rs::domain::calibration_events::CalibrationStatus,
         maintenance_rs::domain::calibration_events::CalibrationStatusResponse,
         maintenance_rs::domain::calibration_events::RecordCalibrationRequest,
+        maintenance_rs::domain::condition::ConditionRuleType,
+        maintenance_rs::domain::condition::ConditionRule,
+        maintenance_rs::domain::condition::CreateConditionRuleRequest,
+        maintenance_rs::domain::condition::ReadingPoint,
+        maintenance_rs::domain::condition::ConditionTrigger,
+        maintenance_rs::domain::condition::UsageForecast,
+        maintenance_rs::domain::condition::WorkOrderTriggerReading,
         maintenance_rs::domain::downtime::DowntimeEvent,
         maintenance_rs::domain::downtime::CreateDowntimeRequest,
         maintenance_rs::domain::meters::MeterType,
@@ -89,6 +115,20 @@ use platform_sdk::{ConsumerError, EventEnvelope, ModuleBuilder, ModuleContext};
         maintenance_rs::domain::plans::CreatePlanRequest,
         maintenance_rs::domain::plans::UpdatePlanRequest,
         maintenance_rs::domain::plans::AssignPlanRequest,
+        maintenance_rs::domain::reliability::ReliabilityMetrics,
+        maintenance_rs::domain::reliability::ReliabilityGroupBy,
+        maintenance_rs::domain::reliability::ReliabilityGroup,
+        maintenance_rs::domain::reliability::ReliabilityReport,
+        maintenance_rs::domain::reliability::FailureParetoEntry,
+        maintenance_rs::domain::reliability::TrendBucket,
+        maintenance_rs::domain::reliability::ReliabilityTrendPoint,
+        maintenance_rs::domain::reliability::ReliabilitySnapshot,
+        maintenance_rs::domain::reliability::PublishSnapshotRequest,
+        maintenance_rs::domain::spare_parts::PlanPart,
+        maintenance_rs::domain::spare_parts::AddPlanPartRequest,
+        maintenance_rs::domain::spare_parts::SparePartPolicy,
+        maintenance_rs::domain::spare_parts::UpsertSparePartPolicyRequest,
+        maintenance_rs::domain::spare_parts::SparePartRecompute,
         maintenance_rs::domain::work_orders::WorkOrder,
         maintenance_rs::domain::work_orders::CreateWorkOrderRequest,
         maintenance_rs::domain::work_orders::TransitionRequest,
@@ -96,6 +136,9 @@ use platform_sdk::{ConsumerError, EventEnvelope, ModuleBuilder, ModuleContext};
         maintenance_rs::domain::work_orders::AddLaborRequest,
         maintenance_rs::domain::work_orders::WoPart,
         maintenance_rs::domain::work_orders::AddPartRequest,
+        maintenance_rs::domain::work_orders::StockStatus,
+        maintenance_rs::domain::work_orders::WoPartReturn,
+        maintenance_rs::domain::work_orders::ReturnPartRequest,
         platform_http_contracts::ApiError,
     )),
     security(("bearer" = [])),
@@ -162,9 +205,12 @@ async fn main() {
                 .await;
             });
 
+            let inventory = ctx.platform_client::<maintenance_rs::InventoryIntegration>();
+
             let app_state = Arc::new(AppState {
                 pool: pool.clone(),
                 metrics: app_metrics,
+                inventory,
             });
 
             Router::new()
@@ -197,6 +243,38 @@ async fn main() {
                             "/api/maintenance/assignments",
                             get(http::plans::list_assignments),
                         )
+                        .route(
+                            "/api/maintenance/plans/{plan_id}/parts",
+                            get(http::spare_parts::list_plan_parts),
+                        )
+                        .route(
+                            "/api/maintenance/spare-part-policies",
+                            get(http::spare_parts::list_policies),
+                        )
+                        .route(
+                            "/api/maintenance/plans/{plan_id}/condition-rules",
+                            get(http::condition_rules::list_condition_rules),
+                        )
+                        .route(
+                            "/api/maintenance/assignments/{assignment_id}/forecast",
+                            get(http::condition_rules::get_assignment_forecast),
+                        )
+                        .route(
+                            "/api/maintenance/work-orders/{wo_id}/trigger-readings",
+                            get(http::condition_rules::list_trigger_readings),
+                        )
+                        .route(
+                            "/api/maintenance/reliability",
+                            get(http::reliability::get_reliability),
+                        )
+                        .route(
+                            "/api/maintenance/reliability/pareto",
+                            get(http::reliability::get_failure_pareto),
+                        )
+                        .route(
+                            "/api/maintenance/reliability/trend",
+                            get(http::reliability::get_reliability_trend),
+                        )
                         .route(
                             "/api/maintenance/work-orders",
                             get(http::work_orders::list_work_orders),
@@ -209,6 +287,10 @@ async fn main() {
                             "/api/maintenance/work-orders/{wo_id}/parts",
                             get(http::work_order_parts::list_parts),
                         )
+                        .route(
+                            "/api/maintenance/work-orders/{wo_id}/part-returns",
+                            get(http::work_order_parts::list_part_returns),
+                        )
                         .route(
                             "/api/maintenance/work-orders/{wo_id}/labor",
                             get(http::work_order_labor::list_labor),
@@ -258,6 +340,30 @@ async fn main() {
                             "/api/maintenance/plans/{plan_id}/assign",
                             post(http::plans::assign_plan),
                         )
+                        .route(
+                            "/api/maintenance/plans/{plan_id}/parts",
+                            post(http::spare_parts::add_plan_part),
+                        )
+                        .route(
+                            "/api/maintenance/spare-part-policies",
+                            post(http::spare_parts::upsert_policy),
+                        )
+                        .route(
+                            "/api/maintenance/spare-part-policies/{policy_id}/recompute",
+                            post(http::spare_parts::recompute_policy),
+                        )
+                        .route(
+                            "/api/maintenance/plans/{plan_id}/condition-rules",
+                            post(http::condition_rules::create_condition_rule),
+                        )
+                        .route(
+                            "/api/maintenance/plans/{plan_id}/condition-rules/{rule_id}",
+                            axum::routing::delete(http::condition_rules::deactivate_condition_rule),
+                        )
+                        .route(
+                            "/api/maintenance/reliability/snapshots",
+                            post(http::reliability::publish_snapshot),
+                        )
                         .route(
                             "/api/maintenance/work-orders",
                             post(http::work_orders::create_work_order),
@@ -274,6 +380,10 @@ async fn main() {
                             "/api/maintenance/work-orders/{wo_id}/parts/{part_id}",
                             axum::routing::delete(http::work_order_parts::remove_part),
                         )
+                        .route(
+                            "/api/maintenance/work-orders/{wo_id}/parts/{part_id}/return",
+                            post(http::work_order_parts::return_part),
+                        )
                         .route(
                             "/api/maintenance/work-orders/{wo_id}/labor",
                             post(http::work_order_labor::add_labor),
@@ -332,6 +332,7 @@ async fn test_create_and_list_meter_types() {
             name: "Odometer".into(),
             unit_label: "miles".into(),
             rollover_value: Some(999_999),
+            kind: None,
         },
     )
     .await
@@ -347,6 +348,7 @@ async fn test_create_and_list_meter_types() {
             name: "Engine Hours".into(),
             unit_label: "hours".into(),
             rollover_value: None,
+            kind: None,
         },
     )
     .await
@@ -373,6 +375,7 @@ async fn test_duplicate_meter_type_rejected() {
             name: "Odometer".into(),
             unit_label: "miles".into(),
             rollover_value: None,
+            kind: None,
         },
     )
     .await
@@ -385,6 +388,7 @@ async fn test_duplicate_meter_type_rejected() {
             name: "Odometer".into(),
             unit_label: "km".into(),
             rollover_value: None,
+            kind: None,
         },
     )
     .await
@@ -438,6 +442,7 @@ async fn test_meter_readings_monotonic_increasing() {
             name: "Odometer".into(),
             unit_label: "miles".into(),
             rollover_value: None,
+            kind: None,
         },
     )
     .await
@@ -529,6 +534,7 @@ async fn test_meter_reading_decrease_rejected() {
             name: "Hours".into(),
             unit_label: "hours".into(),
             rollover_value: None,
+            kind: None,
         },
     )
     .await
@@ -613,6 +619,7 @@ async fn test_meter_reading_rollover_accepted() {
             name: "Odometer".into(),
             unit_label: "miles".into(),
             rollover_value: Some(1_000_000),
+            kind: None,
         },
     )
     .await
@@ -689,6 +696,7 @@ async fn test_meter_reading_invalid_rollover_rejected() {
             name: "Odometer".into(),
             unit_label: "miles".into(),
             rollover_value: Some(1_000_000),
+            kind: None,
         },
     )
     .await
@@ -771,6 +779,7 @@ async fn test_list_readings_for_asset() {
             name: "Cycles".into(),
             unit_label: "cycles".into(),
             rollover_value: None,
+            kind: None,
         },
     )
     .await
@@ -847,6 +856,7 @@ async fn test_tenant_isolation_meter_readings() {
             name: "Odometer".into(),
             unit_label: "miles".into(),
             rollover_value: None,
+            kind: None,
         },
     )
     .await
@@ -16,6 +16,7 @@ use maintenance_rs::domain::work_orders::{
     CreateWorkOrderRequest, TransitionRequest, WorkOrderRepo,
 };
 use maintenance_rs::events::subjects;
+use maintenance_rs::InventoryIntegration;
 use serial_test::serial;
 use sqlx::postgres::PgPoolOptions;
 use uuid::Uuid;
@@ -175,7 +176,9 @@ async fn test_wo_transition_subject_and_envelope() {
             downtime_minutes: None,
             closed_at: None,
             notes: None,
+            failure_code: None,
         },
+        &InventoryIntegration::deterministic(),
     )
     .await
     .unwrap();
@@ -207,6 +210,7 @@ async fn test_meter_reading_subject_and_envelope() {
             name: format!("Odometer-{}", Uuid::new_v4().simple()),
             unit_label: "km".into(),
             rollover_value: None,
+            kind: None,
         },
     )
     .await
@@ -255,8 +259,8 @@ fn all_subjects_are_stable_and_prefixed() {
             subject
         );
     }
-    // 17 stable subjects (14 original + 3 added in bd-20rpu)
-    assert_eq!(subjects::ALL_SUBJECTS.len(), 17);
+    // 18 stable subjects (14 original + 3 added in bd-20rpu + reliability snapshot)
+    assert_eq!(subjects::ALL_SUBJECTS.len(), 18);
 }
 
 // ============================================================================
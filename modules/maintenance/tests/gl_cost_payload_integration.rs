@@ -10,6 +10,7 @@ use maintenance_rs::domain::work_orders::{
     AddLaborRequest, AddPartRequest, CreateWorkOrderRequest, TransitionRequest, WoLaborRepo,
     WoPartsRepo, WorkOrderRepo,
 };
+use maintenance_rs::InventoryIntegration;
 use serial_test::serial;
 use sqlx::postgres::PgPoolOptions;
 use uuid::Uuid;
@@ -100,7 +101,9 @@ async fn create_in_progress_wo(pool: &sqlx::PgPool, tid: &str, asset_id: Uuid) -
                 downtime_minutes: None,
                 closed_at: None,
                 notes: None,
+                failure_code: None,
             },
+            &InventoryIntegration::deterministic(),
         )
         .await
         .unwrap();
@@ -154,7 +157,13 @@ async fn test_completed_event_has_cost_totals() {
             unit_cost_minor: 1000,
             currency: Some("USD".into()),
             inventory_issue_ref: None,
+            item_id: None,
+            warehouse_id: None,
+            location_id: None,
+            lot_code: None,
+            serial_codes: None,
         },
+        &InventoryIntegration::deterministic(),
     )
     .await
     .unwrap();
@@ -170,7 +179,13 @@ async fn test_completed_event_has_cost_totals() {
             unit_cost_minor: 500,
             currency: Some("USD".into()),
             inventory_issue_ref: None,
+            item_id: None,
+            warehouse_id: None,
+            location_id: None,
+            lot_code: None,
+            serial_codes: None,
         },
+        &InventoryIntegration::deterministic(),
     )
     .await
     .unwrap();
@@ -203,7 +218,9 @@ async fn test_completed_event_has_cost_totals() {
             downtime_minutes: Some(60),
             closed_at: None,
             notes: None,
+            failure_code: None,
         },
+        &InventoryIntegration::deterministic(),
     )
     .await
     .unwrap();
@@ -244,7 +261,9 @@ async fn test_completed_event_zero_cost_when_no_parts_labor() {
             downtime_minutes: Some(0),
             closed_at: None,
             notes: None,
+            failure_code: None,
         },
+        &InventoryIntegration::deterministic(),
     )
     .await
     .unwrap();
@@ -299,7 +318,9 @@ async fn test_non_completed_transition_has_no_cost_fields() {
             downtime_minutes: None,
             closed_at: None,
             notes: None,
+            failure_code: None,
         },
+        &InventoryIntegration::deterministic(),
     )
     .await
     .unwrap();
@@ -13,6 +13,7 @@ use maintenance_rs::domain::overdue::evaluate_overdue;
 use maintenance_rs::domain::work_orders::{
     CreateWorkOrderRequest, TransitionRequest, WorkOrderRepo,
 };
+use maintenance_rs::InventoryIntegration;
 use serial_test::serial;
 use sqlx::postgres::PgPoolOptions;
 use uuid::Uuid;
@@ -99,7 +100,9 @@ async fn mk_scheduled_overdue_wo(
             downtime_minutes: None,
             closed_at: None,
             notes: None,
+            failure_code: None,
         },
+        &InventoryIntegration::deterministic(),
     )
     .await
     .unwrap();
@@ -253,7 +256,9 @@ async fn test_wo_without_scheduled_date_skipped() {
             downtime_minutes: None,
             closed_at: None,
             notes: None,
+            failure_code: None,
         },
+        &InventoryIntegration::deterministic(),
     )
     .await
     .unwrap();
@@ -16,6 +16,7 @@ use maintenance_rs::domain::work_orders::{
     AddLaborRequest, AddPartRequest, CreateWorkOrderRequest, TransitionRequest, WoLaborError,
     WoLaborRepo, WoPartError, WoPartsRepo, WorkOrderRepo,
 };
+use maintenance_rs::InventoryIntegration;
 use serial_test::serial;
 use sqlx::postgres::PgPoolOptions;
 use uuid::Uuid;
@@ -106,7 +107,9 @@ async fn transition_simple(pool: &sqlx::PgPool, wo_id: Uuid, tid: &str, status:
             downtime_minutes: None,
             closed_at: None,
             notes: None,
+            failure_code: None,
         },
+        &InventoryIntegration::deterministic(),
     )
     .await
     .unwrap();
@@ -121,6 +124,11 @@ fn base_part_req(tid: &str) -> AddPartRequest {
         unit_cost_minor: 1500,
         currency: None,
         inventory_issue_ref: None,
+        item_id: None,
+        warehouse_id: None,
+        location_id: None,
+        lot_code: None,
+        serial_codes: None,
     }
 }
 
@@ -147,9 +155,14 @@ async fn test_add_part_to_in_progress_wo() {
     let asset_id = create_test_asset(&pool, &tid, "PART-001").await;
     let wo_id = create_in_progress_wo(&pool, &tid, asset_id).await;
 
-    let part = WoPartsRepo::add(&pool, wo_id, &base_part_req(&tid))
-        .await
-        .unwrap();
+    let part = WoPartsRepo::add(
+        &pool,
+        wo_id,
+        &base_part_req(&tid),
+        &InventoryIntegration::deterministic(),
+    )
+    .await
+    .unwrap();
 
     assert_eq!(part.tenant_id, tid);
     assert_eq!(part.work_order_id, wo_id);
@@ -173,14 +186,21 @@ async fn test_list_parts() {
     let asset_id = create_test_asset(&pool, &tid, "PART-002").await;
     let wo_id = create_in_progress_wo(&pool, &tid, asset_id).await;
 
-    WoPartsRepo::add(&pool, wo_id, &base_part_req(&tid))
-        .await
-        .unwrap();
+    WoPartsRepo::add(
+        &pool,
+        wo_id,
+        &base_part_req(&tid),
+        &InventoryIntegration::deterministic(),
+    )
+    .await
+    .unwrap();
 
     let mut req2 = base_part_req(&tid);
     req2.part_description = "Air filter".into();
     req2.quantity = 1;
-    WoPartsRepo::add(&pool, wo_id, &req2).await.unwrap();
+    WoPartsRepo::add(&pool, wo_id, &req2, &InventoryIntegration::deterministic())
+        .await
+        .unwrap();
 
     let parts = WoPartsRepo::list(&pool, wo_id, &tid).await.unwrap();
     assert_eq!(parts.len(), 2);
@@ -200,13 +220,24 @@ async fn test_remove_part() {
     let asset_id = create_test_asset(&pool, &tid, "PART-003").await;
     let wo_id = create_in_progress_wo(&pool, &tid, asset_id).await;
 
-    let part = WoPartsRepo::add(&pool, wo_id, &base_part_req(&tid))
-        .await
-        .unwrap();
+    let part = WoPartsRepo::add(
+        &pool,
+        wo_id,
+        &base_part_req(&tid),
+        &InventoryIntegration::deterministic(),
+    )
+    .await
+    .unwrap();
 
-    WoPartsRepo::remove(&pool, wo_id, part.id, &tid)
-        .await
-        .unwrap();
+    WoPartsRepo::remove(
+        &pool,
+        wo_id,
+        part.id,
+        &tid,
+        &InventoryIntegration::deterministic(),
+    )
+    .await
+    .unwrap();
 
     let parts = WoPartsRepo::list(&pool, wo_id, &tid).await.unwrap();
     assert!(parts.is_empty());
@@ -312,14 +343,21 @@ async fn test_cannot_add_part_after_completed() {
             downtime_minutes: Some(30),
             closed_at: None,
             notes: None,
+            failure_code: None,
         },
+        &InventoryIntegration::deterministic(),
     )
     .await
     .unwrap();
 
-    let err = WoPartsRepo::add(&pool, wo_id, &base_part_req(&tid))
-        .await
-        .unwrap_err();
+    let err = WoPartsRepo::add(
+        &pool,
+        wo_id,
+        &base_part_req(&tid),
+        &InventoryIntegration::deterministic(),
+    )
+    .await
+    .unwrap_err();
     assert!(
         matches!(err, WoPartError::WoImmutable(_)),
         "expected WoImmutable, got: {:?}",
@@ -351,7 +389,9 @@ async fn test_cannot_add_labor_after_closed() {
             downtime_minutes: Some(30),
             closed_at: None,
             notes: None,
+            failure_code: None,
         },
+        &InventoryIntegration::deterministic(),
     )
     .await
     .unwrap();
@@ -366,7 +406,9 @@ async fn test_cannot_add_labor_after_closed() {
             downtime_minutes: None,
             closed_at: Some(now),
             notes: None,
+            failure_code: None,
         },
+        &InventoryIntegration::deterministic(),
     )
     .await
     .unwrap();
@@ -394,9 +436,14 @@ async fn test_cannot_remove_part_after_cancelled() {
     let wo_id = create_in_progress_wo(&pool, &tid, asset_id).await;
 
     // Add part while in_progress
-    let part = WoPartsRepo::add(&pool, wo_id, &base_part_req(&tid))
-        .await
-        .unwrap();
+    let part = WoPartsRepo::add(
+        &pool,
+        wo_id,
+        &base_part_req(&tid),
+        &InventoryIntegration::deterministic(),
+    )
+    .await
+    .unwrap();
 
     // in_progress → cancelled
     WorkOrderRepo::transition(
@@ -409,14 +456,22 @@ async fn test_cannot_remove_part_after_cancelled() {
             downtime_minutes: None,
             closed_at: None,
             notes: None,
+            failure_code: None,
         },
+        &InventoryIntegration::deterministic(),
     )
     .await
     .unwrap();
 
-    let err = WoPartsRepo::remove(&pool, wo_id, part.id, &tid)
-        .await
-        .unwrap_err();
+    let err = WoPartsRepo::remove(
+        &pool,
+        wo_id,
+        part.id,
+        &tid,
+        &InventoryIntegration::deterministic(),
+    )
+    .await
+    .unwrap_err();
     assert!(
         matches!(err, WoPartError::WoImmutable(_)),
         "expected WoImmutable, got: {:?}",
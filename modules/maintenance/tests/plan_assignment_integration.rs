@@ -76,6 +76,7 @@ async fn create_test_meter(pool: &sqlx::PgPool, tid: &str, name: &str) -> Uuid {
             name: name.to_string(),
             unit_label: "miles".into(),
             rollover_value: None,
+            kind: None,
         },
     )
     .await
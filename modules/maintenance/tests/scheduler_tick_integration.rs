@@ -74,6 +74,7 @@ async fn mk_meter(pool: &sqlx::PgPool, tid: &str, name: &str) -> Uuid {
             name: name.into(),
             unit_label: "mi".into(),
             rollover_value: None,
+            kind: None,
         },
     )
     .await
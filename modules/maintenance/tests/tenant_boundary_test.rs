@@ -15,6 +15,7 @@ use maintenance_rs::domain::assets::{AssetRepo, CreateAssetRequest};
 use maintenance_rs::domain::work_orders::{
     AddPartRequest, CreateWorkOrderRequest, ListWorkOrdersQuery, WoPartsRepo, WorkOrderRepo,
 };
+use maintenance_rs::InventoryIntegration;
 use serial_test::serial;
 use sqlx::postgres::PgPoolOptions;
 use uuid::Uuid;
@@ -314,7 +315,13 @@ async fn parts_respect_tenant_boundary() {
             unit_cost_minor: 1500,
             currency: None,
             inventory_issue_ref: None,
+            item_id: None,
+            warehouse_id: None,
+            location_id: None,
+            lot_code: None,
+            serial_codes: None,
         },
+        &InventoryIntegration::deterministic(),
     )
     .await
     .unwrap();
@@ -330,7 +337,13 @@ async fn parts_respect_tenant_boundary() {
             unit_cost_minor: 2000,
             currency: None,
             inventory_issue_ref: None,
+            item_id: None,
+            warehouse_id: None,
+            location_id: None,
+            lot_code: None,
+            serial_codes: None,
         },
+        &InventoryIntegration::deterministic(),
     )
     .await
     .unwrap();
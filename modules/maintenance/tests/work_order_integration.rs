@@ -10,6 +10,7 @@ use maintenance_rs::domain::assets::{AssetRepo, CreateAssetRequest};
 use maintenance_rs::domain::work_orders::{
     CreateWorkOrderRequest, ListWorkOrdersQuery, TransitionRequest, WoError, WorkOrderRepo,
 };
+use maintenance_rs::InventoryIntegration;
 use serial_test::serial;
 use sqlx::postgres::PgPoolOptions;
 use uuid::Uuid;
@@ -166,7 +167,9 @@ async fn test_list_with_status_filter() {
             downtime_minutes: None,
             closed_at: None,
             notes: None,
+            failure_code: None,
         },
+        &InventoryIntegration::deterministic(),
     )
     .await
     .unwrap();
@@ -251,7 +254,9 @@ async fn test_tenant_isolation() {
             downtime_minutes: None,
             closed_at: None,
             notes: None,
+            failure_code: None,
         },
+        &InventoryIntegration::deterministic(),
     )
     .await
     .unwrap_err();
@@ -12,6 +12,7 @@ use maintenance_rs::domain::assets::{AssetRepo, CreateAssetRequest};
 use maintenance_rs::domain::work_orders::{
     CreateWorkOrderRequest, TransitionRequest, WoError, WorkOrderRepo,
 };
+use maintenance_rs::InventoryIntegration;
 use serial_test::serial;
 use sqlx::postgres::PgPoolOptions;
 use uuid::Uuid;
@@ -92,7 +93,9 @@ async fn transition_simple(pool: &sqlx::PgPool, wo_id: Uuid, tid: &str, status:
             downtime_minutes: None,
             closed_at: None,
             notes: None,
+            failure_code: None,
         },
+        &InventoryIntegration::deterministic(),
     )
     .await
     .unwrap();
@@ -124,7 +127,9 @@ async fn test_full_transition_chain() {
             downtime_minutes: None,
             closed_at: None,
             notes: None,
+            failure_code: None,
         },
+        &InventoryIntegration::deterministic(),
     )
     .await
     .unwrap();
@@ -141,7 +146,9 @@ async fn test_full_transition_chain() {
             downtime_minutes: None,
             closed_at: None,
             notes: None,
+            failure_code: None,
         },
+        &InventoryIntegration::deterministic(),
     )
     .await
     .unwrap();
@@ -160,7 +167,9 @@ async fn test_full_transition_chain() {
             downtime_minutes: Some(45),
             closed_at: None,
             notes: Some("Work done".into()),
+            failure_code: None,
         },
+        &InventoryIntegration::deterministic(),
     )
     .await
     .unwrap();
@@ -179,7 +188,9 @@ async fn test_full_transition_chain() {
             downtime_minutes: None,
             closed_at: Some(now),
             notes: None,
+            failure_code: None,
         },
+        &InventoryIntegration::deterministic(),
     )
     .await
     .unwrap();
@@ -221,7 +232,9 @@ async fn test_invalid_transition_rejected() {
             downtime_minutes: None,
             closed_at: None,
             notes: None,
+            failure_code: None,
         },
+        &InventoryIntegration::deterministic(),
     )
     .await
     .unwrap_err();
@@ -261,7 +274,9 @@ async fn test_completion_requires_guard_fields() {
             downtime_minutes: None,
             closed_at: None,
             notes: None,
+            failure_code: None,
         },
+        &InventoryIntegration::deterministic(),
     )
     .await
     .unwrap_err();
@@ -302,7 +317,9 @@ async fn test_close_requires_closed_at() {
             downtime_minutes: Some(10),
             closed_at: None,
             notes: None,
+            failure_code: None,
         },
+        &InventoryIntegration::deterministic(),
     )
     .await
     .unwrap();
@@ -317,7 +334,9 @@ async fn test_close_requires_closed_at() {
             downtime_minutes: None,
             closed_at: None,
             notes: None,
+            failure_code: None,
         },
+        &InventoryIntegration::deterministic(),
     )
     .await
     .unwrap_err();
@@ -354,7 +373,9 @@ async fn test_cancel_from_draft() {
             downtime_minutes: None,
             closed_at: None,
             notes: Some("No longer needed".into()),
+            failure_code: None,
         },
+        &InventoryIntegration::deterministic(),
     )
     .await
     .unwrap();
@@ -397,7 +418,9 @@ async fn test_cannot_transition_from_terminal() {
             downtime_minutes: None,
             closed_at: None,
             notes: None,
+            failure_code: None,
         },
+        &InventoryIntegration::deterministic(),
     )
     .await
     .unwrap();
@@ -412,7 +435,9 @@ async fn test_cannot_transition_from_terminal() {
             downtime_minutes: None,
             closed_at: None,
             notes: None,
+            failure_code: None,
         },
+        &InventoryIntegration::deterministic(),
     )
     .await
     .unwrap_err();
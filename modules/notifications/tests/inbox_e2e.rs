@@ -477,6 +477,9 @@ async fn list_my_inbox_uses_authenticated_user() {
         app_id: None,
         roles: vec![],
         perms: vec!["notifications.read".to_string()],
+        scopes: Default::default(),
+        amr: Vec::new(),
+        auth_time: None,
         actor_type: ActorType::User,
         issued_at: Utc::now(),
         expires_at: Utc::now() + chrono::Duration::hours(1),
@@ -52,6 +52,9 @@ fn admin_claims(tenant_id: Uuid) -> VerifiedClaims {
         app_id: None,
         roles: vec!["tenant_admin".to_string()],
         perms: vec!["party.mutate".to_string(), "party.read".to_string()],
+        scopes: Default::default(),
+        amr: Vec::new(),
+        auth_time: None,
         actor_type: ActorType::User,
         issued_at: Utc::now(),
         expires_at: Utc::now() + chrono::Duration::minutes(15),
@@ -67,6 +70,9 @@ fn unprivileged_claims(tenant_id: Uuid) -> VerifiedClaims {
         app_id: None,
         roles: vec!["viewer".to_string()],
         perms: vec!["party.read".to_string()],
+        scopes: Default::default(),
+        amr: Vec::new(),
+        auth_time: None,
         actor_type: ActorType::User,
         issued_at: Utc::now(),
         expires_at: Utc::now() + chrono::Duration::minutes(15),
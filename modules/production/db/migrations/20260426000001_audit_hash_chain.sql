@@ -0,0 +1,66 @@
+-- Tamper-evident audit log: per-tenant hash chain and signed checkpoints.
+--
+-- Every row written through AuditWriter is appended to its tenant's chain:
+--   chain_seq   1, 2, 3, ... per tenant_id, no gaps
+--   prev_hash   record_hash of the previous row in the chain (genesis: 64 zeros)
+--   record_hash SHA-256 over the row's content, chain_seq and prev_hash
+--
+-- Deleting a row leaves a sequence gap; editing one changes its recomputed
+-- hash; rewriting the tail is caught by audit_chain_heads and by the
+-- HMAC-signed rows in audit_checkpoints. Rows written before this migration
+-- (or by raw SQL) have NULL chain columns and are reported as unchained.
+-- Rows without a tenant chain under tenant_id = ''.
+
+ALTER TABLE audit_events
+    ADD COLUMN IF NOT EXISTS tenant_id   TEXT NOT NULL DEFAULT '',
+    ADD COLUMN IF NOT EXISTS chain_seq   BIGINT,
+    ADD COLUMN IF NOT EXISTS prev_hash   VARCHAR(64),
+    ADD COLUMN IF NOT EXISTS record_hash VARCHAR(64);
+
+CREATE UNIQUE INDEX IF NOT EXISTS audit_events_chain
+    ON audit_events(tenant_id, chain_seq)
+    WHERE chain_seq IS NOT NULL;
+
+CREATE INDEX IF NOT EXISTS audit_events_tenant_occurred
+    ON audit_events(tenant_id, occurred_at);
+
+-- Current end of each tenant's chain. The writer locks this row FOR UPDATE,
+-- which serialises appends per tenant.
+CREATE TABLE IF NOT EXISTS audit_chain_heads (
+    tenant_id  TEXT PRIMARY KEY,
+    last_seq   BIGINT NOT NULL DEFAULT 0,
+    last_hash  VARCHAR(64) NOT NULL
+        DEFAULT '0000000000000000000000000000000000000000000000000000000000000000',
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+
+-- Signed statements of "tenant X's chain had head H at seq N".
+CREATE TABLE IF NOT EXISTS audit_checkpoints (
+    checkpoint_id UUID PRIMARY KEY,
+    tenant_id     TEXT NOT NULL,
+    chain_seq     BIGINT NOT NULL,
+    head_hash     VARCHAR(64) NOT NULL,
+    key_id        VARCHAR(100) NOT NULL,
+    signature     VARCHAR(64) NOT NULL,
+    created_at    TIMESTAMPTZ NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS audit_checkpoints_tenant
+    ON audit_checkpoints(tenant_id, chain_seq DESC);
+
+CREATE OR REPLACE TRIGGER enforce_append_only_checkpoint_update
+    BEFORE UPDATE ON audit_checkpoints
+    FOR EACH ROW
+    EXECUTE FUNCTION prevent_audit_modification();
+
+CREATE OR REPLACE TRIGGER enforce_append_only_checkpoint_delete
+    BEFORE DELETE ON audit_checkpoints
+    FOR EACH ROW
+    EXECUTE FUNCTION prevent_audit_modification();
+
+COMMENT ON COLUMN audit_events.tenant_id IS 'Tenant whose hash chain this row belongs to ('''' = platform)';
+COMMENT ON COLUMN audit_events.chain_seq IS 'Position in the tenant hash chain, starting at 1';
+COMMENT ON COLUMN audit_events.prev_hash IS 'record_hash of the previous row in the tenant chain';
+COMMENT ON COLUMN audit_events.record_hash IS 'SHA-256 over row content, chain_seq and prev_hash';
+COMMENT ON TABLE audit_chain_heads IS 'Last sequence and hash of each tenant audit chain';
+COMMENT ON TABLE audit_checkpoints IS 'HMAC-signed audit chain heads for tamper evidence';
@@ -165,7 +165,8 @@ pub async fn request_fg_receipt(
         MutationClass::Create,
         "WorkOrder".to_string(),
         work_order_id.to_string(),
-    );
+    )
+    .with_tenant(req.tenant_id.as_str());
     AuditWriter::write_in_tx(&mut tx, audit_req)
         .await
         .map_err(|e| match e {
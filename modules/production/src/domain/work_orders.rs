@@ -380,7 +380,8 @@ impl WorkOrderRepo {
             MutationClass::Create,
             "WorkOrder".to_string(),
             wo.work_order_id.to_string(),
-        );
+        )
+        .with_tenant(req.tenant_id.as_str());
         AuditWriter::write_in_tx(&mut tx, audit_req)
             .await
             .map_err(|e| match e {
@@ -464,7 +465,8 @@ impl WorkOrderRepo {
             MutationClass::StateTransition,
             "WorkOrder".to_string(),
             work_order_id.to_string(),
-        );
+        )
+        .with_tenant(tenant_id);
         AuditWriter::write_in_tx(&mut tx, audit_req)
             .await
             .map_err(|e| match e {
@@ -597,7 +599,8 @@ impl WorkOrderRepo {
             MutationClass::StateTransition,
             "WorkOrder".to_string(),
             work_order_id.to_string(),
-        );
+        )
+        .with_tenant(tenant_id);
         AuditWriter::write_in_tx(&mut tx, audit_req)
             .await
             .map_err(|e| match e {
@@ -100,6 +100,9 @@ async fn inject_claims(req: AxumRequest, next: Next) -> Response {
                     "production.read".to_string(),
                     "production.mutate".to_string(),
                 ],
+                scopes: Default::default(),
+                amr: Vec::new(),
+                auth_time: None,
                 actor_type: ActorType::User,
                 issued_at: chrono::Utc::now(),
                 expires_at: chrono::Utc::now() + Duration::hours(1),
@@ -91,6 +91,9 @@ fn make_test_claims(tenant_id: &str) -> VerifiedClaims {
             "production.read".to_string(),
             "production.mutate".to_string(),
         ],
+        scopes: Default::default(),
+        amr: Vec::new(),
+        auth_time: None,
         actor_type: ActorType::User,
         issued_at: chrono::Utc::now(),
         expires_at: chrono::Utc::now() + Duration::hours(1),
@@ -663,6 +666,9 @@ async fn inject_production_claims(req: AxumRequest, next: Next) -> Response {
                     "production.read".to_string(),
                     "production.mutate".to_string(),
                 ],
+                scopes: Default::default(),
+                amr: Vec::new(),
+                auth_time: None,
                 actor_type: ActorType::User,
                 issued_at: chrono::Utc::now(),
                 expires_at: chrono::Utc::now() + Duration::hours(1),
@@ -0,0 +1,66 @@
+-- AQL sampling (ANSI/ASQ Z1.4 / ISO 2859-1) and measurement-driven lot acceptance
+--
+-- Adds: inspection level + AQL on inspection_plans,
+--       resolved sampling plan + lot/supplier anchors on inspections,
+--       per-characteristic sample measurements,
+--       per supplier/part switching state (normal/tightened/reduced/discontinued).
+
+ALTER TABLE inspection_plans
+    ADD COLUMN inspection_level TEXT
+        CHECK (inspection_level IN ('S-1', 'S-2', 'S-3', 'S-4', 'I', 'II', 'III')),
+    ADD COLUMN aql DOUBLE PRECISION CHECK (aql > 0);
+
+-- Resolved Z1.4 plan the lot was inspected under; NULL until sampling is applied.
+ALTER TABLE inspections
+    ADD COLUMN supplier_id       UUID,
+    ADD COLUMN lot_size          INTEGER CHECK (lot_size > 0),
+    ADD COLUMN code_letter       TEXT,
+    ADD COLUMN sampling_severity TEXT
+        CHECK (sampling_severity IN ('normal', 'tightened', 'reduced')),
+    ADD COLUMN sample_size       INTEGER CHECK (sample_size > 0),
+    ADD COLUMN acceptance_number INTEGER CHECK (acceptance_number >= 0),
+    ADD COLUMN rejection_number  INTEGER CHECK (rejection_number > 0),
+    ADD COLUMN defects_found     INTEGER CHECK (defects_found >= 0);
+
+CREATE INDEX idx_inspections_supplier_part ON inspections(tenant_id, supplier_id, part_id);
+
+-- One row per (characteristic, sample unit). conforming is evaluated against the
+-- plan characteristic's tolerance at record time, or supplied for attribute checks.
+CREATE TABLE inspection_measurements (
+    id              UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    tenant_id       TEXT NOT NULL,
+    inspection_id   UUID NOT NULL REFERENCES inspections(id),
+    characteristic  TEXT NOT NULL,
+    sample_index    INTEGER NOT NULL CHECK (sample_index >= 1),
+    value           DOUBLE PRECISION,
+    conforming      BOOLEAN NOT NULL,
+    recorded_by     UUID,
+    recorded_at     TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+
+    CONSTRAINT inspection_measurements_unique UNIQUE (inspection_id, characteristic, sample_index)
+);
+
+CREATE INDEX idx_inspection_measurements_tenant ON inspection_measurements(tenant_id);
+CREATE INDEX idx_inspection_measurements_inspection ON inspection_measurements(inspection_id);
+
+-- Z1.4 switching state per supplier/part lot stream.
+-- supplier_id is the nil UUID for lots without a supplier (in-process / final).
+CREATE TABLE sampling_states (
+    id                   UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    tenant_id            TEXT NOT NULL,
+    supplier_id          UUID NOT NULL,
+    part_id              UUID NOT NULL,
+    severity             TEXT NOT NULL DEFAULT 'normal'
+                         CHECK (severity IN ('normal', 'tightened', 'reduced', 'discontinued')),
+    switching_score      INTEGER NOT NULL DEFAULT 0,
+    consecutive_accepted INTEGER NOT NULL DEFAULT 0,
+    tightened_rejections INTEGER NOT NULL DEFAULT 0,
+    recent_outcomes      JSONB NOT NULL DEFAULT '[]'::JSONB,
+    lots_evaluated       INTEGER NOT NULL DEFAULT 0,
+    created_at           TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at           TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+
+    CONSTRAINT sampling_states_tenant_supplier_part_unique UNIQUE (tenant_id, supplier_id, part_id)
+);
+
+CREATE INDEX idx_sampling_states_tenant ON sampling_states(tenant_id);
@@ -0,0 +1,62 @@
+-- Statistical process control on in-process inspection measurements
+--
+-- Adds: workcenter anchor on inspections,
+--       chart definitions per part characteristic (optionally per workcenter),
+--       recorded run-rule violations (one row per chart/inspection/rule/section).
+
+ALTER TABLE inspections
+    ADD COLUMN workcenter_id UUID;
+
+CREATE INDEX idx_inspections_spc ON inspections(tenant_id, part_id, workcenter_id)
+    WHERE inspection_type = 'in_process';
+
+-- Specification limits are copied from the plan characteristic at creation so
+-- capability stays stable across plan revisions.
+CREATE TABLE spc_charts (
+    id                 UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    tenant_id          TEXT NOT NULL,
+    part_id            UUID NOT NULL,
+    plan_id            UUID NOT NULL REFERENCES inspection_plans(id),
+    characteristic     TEXT NOT NULL,
+    -- NULL charts every workcenter together.
+    workcenter_id      UUID,
+    chart_type         TEXT NOT NULL
+                       CHECK (chart_type IN ('xbar_r', 'xbar_s', 'i_mr', 'p', 'np')),
+    subgroup_size      INTEGER CHECK (subgroup_size >= 2),
+    rule_set           TEXT NOT NULL DEFAULT 'western_electric'
+                       CHECK (rule_set IN ('western_electric', 'nelson')),
+    baseline_subgroups INTEGER CHECK (baseline_subgroups >= 2),
+    nominal            DOUBLE PRECISION,
+    lsl                DOUBLE PRECISION,
+    usl                DOUBLE PRECISION,
+    hold_on_violation  BOOLEAN NOT NULL DEFAULT TRUE,
+    active             BOOLEAN NOT NULL DEFAULT TRUE,
+    created_at         TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at         TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE UNIQUE INDEX spc_charts_unique ON spc_charts(
+    tenant_id, part_id, characteristic,
+    COALESCE(workcenter_id, '00000000-0000-0000-0000-000000000000'::UUID),
+    chart_type
+);
+CREATE INDEX idx_spc_charts_tenant_part ON spc_charts(tenant_id, part_id);
+
+CREATE TABLE spc_violations (
+    id             UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    tenant_id      TEXT NOT NULL,
+    chart_id       UUID NOT NULL REFERENCES spc_charts(id),
+    inspection_id  UUID NOT NULL REFERENCES inspections(id),
+    wo_id          UUID,
+    rule           TEXT NOT NULL,
+    section        TEXT NOT NULL CHECK (section IN ('location', 'dispersion')),
+    value          DOUBLE PRECISION NOT NULL,
+    description    TEXT NOT NULL,
+    hold_requested BOOLEAN NOT NULL DEFAULT FALSE,
+    detected_at    TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+
+    CONSTRAINT spc_violations_unique UNIQUE (chart_id, inspection_id, rule, section)
+);
+
+CREATE INDEX idx_spc_violations_chart ON spc_violations(chart_id, detected_at);
+CREATE INDEX idx_spc_violations_wo ON spc_violations(tenant_id, wo_id);
@@ -113,6 +113,7 @@ pub async fn process_operation_completed(
     let req = CreateInProcessInspectionRequest {
         wo_id: payload.work_order_id,
         op_instance_id: payload.operation_id,
+        workcenter_id: None,
         plan_id: None,
         lot_id: None,
         part_id: None,
@@ -0,0 +1,623 @@
+//! ANSI/ASQ Z1.4 (ISO 2859-1) single sampling by attributes.
+//!
+//! Pure table lookups and switching-rule bookkeeping — no I/O.
+//!
+//! - Table I: lot size + inspection level → sample size code letter
+//! - Table II-A/B/C: code letter + AQL + severity → sample size, Ac, Re
+//! - Switching rules (§8.3): normal ↔ tightened ↔ discontinued, normal ↔ reduced
+//!   using the Z1.4-2008 switching score.
+//!
+//! The master tables are diagonal: moving one code letter down has the same
+//! effect on Ac/Re as moving one AQL column right. Cells are therefore derived
+//! from `row + column` rather than stored as a 16×26 grid. Arrows follow the
+//! standard: "use the first sampling plan below (↓) / above (↑) the arrow",
+//! and the sample size changes with the row the arrow lands on.
+
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+/// Preferred AQL values (percent nonconforming / nonconformities per 100 units).
+pub const AQL_VALUES: [f64; 26] = [
+    0.010, 0.015, 0.025, 0.040, 0.065, 0.10, 0.15, 0.25, 0.40, 0.65, 1.0, 1.5, 2.5, 4.0, 6.5, 10.0,
+    15.0, 25.0, 40.0, 65.0, 100.0, 150.0, 250.0, 400.0, 650.0, 1000.0,
+];
+
+/// Code letters in table order (I and O are not used by the standard).
+const CODE_LETTERS: [char; 16] = [
+    'A', 'B', 'C', 'D', 'E', 'F', 'G', 'H', 'J', 'K', 'L', 'M', 'N', 'P', 'Q', 'R',
+];
+
+/// Normal and tightened sample sizes per code letter.
+const SAMPLE_SIZES: [i32; 16] = [
+    2, 3, 5, 8, 13, 20, 32, 50, 80, 125, 200, 315, 500, 800, 1250, 2000,
+];
+
+/// Table II-B carries one extra row below R, reachable only via its ↓ arrow.
+const TIGHTENED_EXTRA_SAMPLE_SIZE: i32 = 3150;
+
+/// Upper lot-size bound per Table I row (last row is open-ended).
+const LOT_SIZE_BOUNDS: [i64; 15] = [
+    8,
+    15,
+    25,
+    50,
+    90,
+    150,
+    280,
+    500,
+    1200,
+    3200,
+    10_000,
+    35_000,
+    150_000,
+    500_000,
+    i64::MAX,
+];
+
+/// Table I — code letter index per lot-size row, columns S-1, S-2, S-3, S-4, I, II, III.
+const CODE_LETTER_TABLE: [[u8; 7]; 15] = [
+    [0, 0, 0, 0, 0, 0, 1],
+    [0, 0, 0, 0, 0, 1, 2],
+    [0, 0, 1, 1, 1, 2, 3],
+    [0, 1, 1, 2, 2, 3, 4],
+    [1, 1, 2, 2, 2, 4, 5],
+    [1, 1, 2, 3, 3, 5, 6],
+    [1, 2, 3, 4, 4, 6, 7],
+    [1, 2, 3, 4, 5, 7, 8],
+    [2, 2, 4, 5, 6, 8, 9],
+    [2, 3, 4, 6, 7, 9, 10],
+    [2, 3, 5, 6, 8, 10, 11],
+    [2, 3, 5, 7, 9, 11, 12],
+    [3, 4, 6, 8, 10, 12, 13],
+    [3, 4, 6, 8, 11, 13, 14],
+    [3, 4, 7, 9, 12, 14, 15],
+];
+
+/// Ac values along the normal diagonal from the first 1/2 cell onwards (Re = Ac + 1).
+const NORMAL_AC: [i32; 10] = [1, 2, 3, 5, 7, 10, 14, 21, 30, 44];
+/// Ac values along the tightened diagonal from the first 1/2 cell onwards.
+const TIGHTENED_AC: [i32; 9] = [1, 2, 3, 5, 8, 12, 18, 27, 41];
+
+/// Lots not accepted within the last five under normal inspection that
+/// trigger tightened inspection.
+const NORMAL_TO_TIGHTENED_REJECTS: usize = 2;
+const NORMAL_WINDOW: usize = 5;
+/// Consecutive accepted lots under tightened inspection to return to normal.
+const TIGHTENED_TO_NORMAL_ACCEPTS: i32 = 5;
+/// Cumulative lots not accepted under tightened inspection that discontinue acceptance inspection.
+const TIGHTENED_DISCONTINUE_REJECTS: i32 = 5;
+/// Switching score at which normal inspection moves to reduced.
+const REDUCED_SWITCHING_SCORE: i32 = 30;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub enum InspectionLevel {
+    #[serde(rename = "S-1")]
+    S1,
+    #[serde(rename = "S-2")]
+    S2,
+    #[serde(rename = "S-3")]
+    S3,
+    #[serde(rename = "S-4")]
+    S4,
+    I,
+    II,
+    III,
+}
+
+impl InspectionLevel {
+    pub fn parse(s: &str) -> Option<Self> {
+        match s {
+            "S-1" => Some(Self::S1),
+            "S-2" => Some(Self::S2),
+            "S-3" => Some(Self::S3),
+            "S-4" => Some(Self::S4),
+            "I" => Some(Self::I),
+            "II" => Some(Self::II),
+            "III" => Some(Self::III),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::S1 => "S-1",
+            Self::S2 => "S-2",
+            Self::S3 => "S-3",
+            Self::S4 => "S-4",
+            Self::I => "I",
+            Self::II => "II",
+            Self::III => "III",
+        }
+    }
+
+    fn column(&self) -> usize {
+        match self {
+            Self::S1 => 0,
+            Self::S2 => 1,
+            Self::S3 => 2,
+            Self::S4 => 3,
+            Self::I => 4,
+            Self::II => 5,
+            Self::III => 6,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum Severity {
+    Normal,
+    Tightened,
+    Reduced,
+    /// Acceptance inspection suspended after repeated rejections on tightened.
+    Discontinued,
+}
+
+impl Severity {
+    pub fn parse(s: &str) -> Option<Self> {
+        match s {
+            "normal" => Some(Self::Normal),
+            "tightened" => Some(Self::Tightened),
+            "reduced" => Some(Self::Reduced),
+            "discontinued" => Some(Self::Discontinued),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Normal => "normal",
+            Self::Tightened => "tightened",
+            Self::Reduced => "reduced",
+            Self::Discontinued => "discontinued",
+        }
+    }
+}
+
+/// A resolved single sampling plan.
+#[derive(Debug, Clone, PartialEq, Serialize, ToSchema)]
+pub struct SamplingPlan {
+    pub code_letter: String,
+    pub severity: Severity,
+    pub sample_size: i32,
+    pub acceptance_number: i32,
+    pub rejection_number: i32,
+    /// True when the sample size met or exceeded the lot size and the whole
+    /// lot must be inspected.
+    pub full_inspection: bool,
+}
+
+/// Position of `aql` in [`AQL_VALUES`], if it is a preferred value.
+pub fn aql_index(aql: f64) -> Option<usize> {
+    AQL_VALUES.iter().position(|v| ((v - aql) / v).abs() < 1e-9)
+}
+
+/// Table I lookup. Lot sizes below 2 use the first row.
+pub fn code_letter_index(lot_size: i64, level: InspectionLevel) -> usize {
+    let row = LOT_SIZE_BOUNDS
+        .iter()
+        .position(|&upper| lot_size <= upper)
+        .unwrap_or(LOT_SIZE_BOUNDS.len() - 1);
+    CODE_LETTER_TABLE[row][level.column()] as usize
+}
+
+pub fn code_letter(lot_size: i64, level: InspectionLevel) -> char {
+    CODE_LETTERS[code_letter_index(lot_size, level)]
+}
+
+enum Cell {
+    Plan(i32, i32),
+    Up,
+    Down,
+}
+
+/// Table II-A cell at (row, column).
+fn normal_cell(row: usize, col: usize) -> Cell {
+    let d = row + col;
+    match d {
+        0..=13 => Cell::Down,
+        14 => Cell::Plan(0, 1),
+        15 => Cell::Up,
+        16 => Cell::Down,
+        17..=24 => {
+            let ac = NORMAL_AC[d - 17];
+            Cell::Plan(ac, ac + 1)
+        }
+        // Ac 30 and 44 appear only for code letters A–D.
+        25 | 26 if row <= 3 => {
+            let ac = NORMAL_AC[d - 17];
+            Cell::Plan(ac, ac + 1)
+        }
+        _ => Cell::Up,
+    }
+}
+
+/// Table II-B cell at (row, column); row 16 is the extra 3150 row.
+fn tightened_cell(row: usize, col: usize) -> Cell {
+    let d = row + col;
+    match d {
+        0..=15 => Cell::Down,
+        16 => Cell::Plan(0, 1),
+        17 => Cell::Down,
+        18..=24 => {
+            let ac = TIGHTENED_AC[d - 18];
+            Cell::Plan(ac, ac + 1)
+        }
+        // Ac 27 and 41 appear only for code letters A–D.
+        25 | 26 if row <= 3 => {
+            let ac = TIGHTENED_AC[d - 18];
+            Cell::Plan(ac, ac + 1)
+        }
+        _ => Cell::Up,
+    }
+}
+
+/// Follow arrows from (row, col) until a plan cell is reached. Once an arrow
+/// sets the direction it is kept (the standard's "first plan below/above");
+/// running off either end of the table reverses it.
+fn resolve(
+    cell: fn(usize, usize) -> Cell,
+    rows: usize,
+    start: usize,
+    col: usize,
+) -> (usize, i32, i32) {
+    let mut row = start;
+    let mut going_down: Option<bool> = None;
+    // Bounded: at most one full sweep in each direction.
+    for _ in 0..(rows * 2 + 2) {
+        let down = match (cell(row, col), going_down) {
+            (Cell::Plan(ac, re), _) => return (row, ac, re),
+            (_, Some(dir)) => dir,
+            (Cell::Down, None) => true,
+            (Cell::Up, None) => false,
+        };
+        going_down = Some(down);
+        if down {
+            if row + 1 < rows {
+                row += 1;
+            } else {
+                going_down = Some(false);
+                row -= 1;
+            }
+        } else if row > 0 {
+            row -= 1;
+        } else {
+            going_down = Some(true);
+            row += 1;
+        }
+    }
+    unreachable!("every AQL column of the Z1.4 master tables contains a plan")
+}
+
+/// Resolve the Z1.4 single sampling plan for a lot.
+pub fn sampling_plan(
+    lot_size: i64,
+    level: InspectionLevel,
+    aql: f64,
+    severity: Severity,
+) -> Result<SamplingPlan, String> {
+    if lot_size < 1 {
+        return Err("lot_size must be >= 1".into());
+    }
+    let col = aql_index(aql).ok_or_else(|| format!("AQL {} is not a Z1.4 preferred value", aql))?;
+    let letter = code_letter_index(lot_size, level);
+
+    let (sample_size, ac, re) = match severity {
+        Severity::Normal => {
+            let (row, ac, re) = resolve(normal_cell, SAMPLE_SIZES.len(), letter, col);
+            (SAMPLE_SIZES[row], ac, re)
+        }
+        Severity::Tightened => {
+            let (row, ac, re) = resolve(tightened_cell, SAMPLE_SIZES.len() + 1, letter, col);
+            let n = SAMPLE_SIZES
+                .get(row)
+                .copied()
+                .unwrap_or(TIGHTENED_EXTRA_SAMPLE_SIZE);
+            (n, ac, re)
+        }
+        // Z1.4-2008 Table II-C: reduced sample sizes are the normal sample
+        // sizes two code letters earlier (floor 2), with Re = Ac + 1.
+        Severity::Reduced => {
+            let (row, ac, re) = resolve(
+                normal_cell,
+                SAMPLE_SIZES.len(),
+                letter.saturating_sub(2),
+                col,
+            );
+            (SAMPLE_SIZES[row], ac, re)
+        }
+        Severity::Discontinued => {
+            return Err("Acceptance inspection is discontinued for this supplier/part".into())
+        }
+    };
+
+    let full_inspection = i64::from(sample_size) >= lot_size;
+    Ok(SamplingPlan {
+        code_letter: CODE_LETTERS[letter].to_string(),
+        severity,
+        sample_size: if full_inspection {
+            lot_size as i32
+        } else {
+            sample_size
+        },
+        acceptance_number: ac,
+        rejection_number: re,
+        full_inspection,
+    })
+}
+
+/// Whether `defectives` would still have been accepted under normal
+/// inspection at the next tighter AQL — the Z1.4-2008 switching score test
+/// for plans with Ac >= 2.
+pub fn accepted_one_step_tighter(
+    lot_size: i64,
+    level: InspectionLevel,
+    aql: f64,
+    defectives: i32,
+) -> bool {
+    match aql_index(aql) {
+        Some(col) if col > 0 => {
+            sampling_plan(lot_size, level, AQL_VALUES[col - 1], Severity::Normal)
+                .map(|p| defectives <= p.acceptance_number)
+                .unwrap_or(false)
+        }
+        _ => false,
+    }
+}
+
+/// Switching-rule state for one supplier/part stream of lots.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SwitchState {
+    pub severity: Severity,
+    pub switching_score: i32,
+    pub consecutive_accepted: i32,
+    pub tightened_rejections: i32,
+    /// Outcomes (true = accepted) of up to the last five lots under normal inspection.
+    pub recent_outcomes: Vec<bool>,
+}
+
+impl Default for SwitchState {
+    fn default() -> Self {
+        Self {
+            severity: Severity::Normal,
+            switching_score: 0,
+            consecutive_accepted: 0,
+            tightened_rejections: 0,
+            recent_outcomes: Vec::new(),
+        }
+    }
+}
+
+impl SwitchState {
+    fn switch_to(&mut self, severity: Severity) {
+        *self = Self {
+            severity,
+            ..Self::default()
+        };
+    }
+
+    /// Record one lot decision and apply the switching rules.
+    ///
+    /// `acceptance_number` is the Ac of the plan the lot was inspected under;
+    /// `accepted_tighter` is [`accepted_one_step_tighter`] for that lot.
+    /// Returns the new severity if a switch occurred.
+    pub fn record_lot(
+        &mut self,
+        accepted: bool,
+        acceptance_number: i32,
+        accepted_tighter: bool,
+    ) -> Option<Severity> {
+        let before = self.severity;
+        match self.severity {
+            Severity::Normal => {
+                self.recent_outcomes.push(accepted);
+                if self.recent_outcomes.len() > NORMAL_WINDOW {
+                    self.recent_outcomes.remove(0);
+                }
+                let rejects = self.recent_outcomes.iter().filter(|a| !**a).count();
+                if rejects >= NORMAL_TO_TIGHTENED_REJECTS {
+                    self.switch_to(Severity::Tightened);
+                } else {
+                    let qualifies = if acceptance_number <= 1 {
+                        accepted
+                    } else {
+                        accepted && accepted_tighter
+                    };
+                    if !qualifies {
+                        self.switching_score = 0;
+                    } else if acceptance_number <= 1 {
+                        self.switching_score += 2;
+                    } else {
+                        self.switching_score += 3;
+                    }
+                    if self.switching_score >= REDUCED_SWITCHING_SCORE {
+                        self.switch_to(Severity::Reduced);
+                    }
+                }
+            }
+            Severity::Tightened => {
+                if accepted {
+                    self.consecutive_accepted += 1;
+                    if self.consecutive_accepted >= TIGHTENED_TO_NORMAL_ACCEPTS {
+                        self.switch_to(Severity::Normal);
+                    }
+                } else {
+                    self.consecutive_accepted = 0;
+                    self.tightened_rejections += 1;
+                    if self.tightened_rejections >= TIGHTENED_DISCONTINUE_REJECTS {
+                        self.switch_to(Severity::Discontinued);
+                    }
+                }
+            }
+            Severity::Reduced => {
+                if !accepted {
+                    self.switch_to(Severity::Normal);
+                }
+            }
+            Severity::Discontinued => {}
+        }
+        (self.severity != before).then_some(self.severity)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn plan(lot: i64, level: InspectionLevel, aql: f64, sev: Severity) -> (i32, i32, i32) {
+        let p = sampling_plan(lot, level, aql, sev).expect("plan");
+        (p.sample_size, p.acceptance_number, p.rejection_number)
+    }
+
+    #[test]
+    fn code_letters_follow_table_i() {
+        assert_eq!(code_letter(5, InspectionLevel::II), 'A');
+        assert_eq!(code_letter(100, InspectionLevel::II), 'F');
+        assert_eq!(code_letter(500, InspectionLevel::II), 'H');
+        assert_eq!(code_letter(1000, InspectionLevel::II), 'J');
+        assert_eq!(code_letter(1000, InspectionLevel::I), 'G');
+        assert_eq!(code_letter(1000, InspectionLevel::III), 'K');
+        assert_eq!(code_letter(1000, InspectionLevel::S4), 'F');
+        assert_eq!(code_letter(1_000_000, InspectionLevel::II), 'Q');
+        assert_eq!(code_letter(1_000_000, InspectionLevel::III), 'R');
+    }
+
+    #[test]
+    fn normal_plans_match_table_ii_a() {
+        use InspectionLevel::II;
+        assert_eq!(plan(1000, II, 2.5, Severity::Normal), (80, 5, 6));
+        assert_eq!(plan(1000, II, 0.65, Severity::Normal), (80, 1, 2));
+        assert_eq!(plan(1000, II, 4.0, Severity::Normal), (80, 7, 8));
+        assert_eq!(plan(500, II, 1.0, Severity::Normal), (50, 1, 2));
+        assert_eq!(plan(500, II, 2.5, Severity::Normal), (50, 3, 4));
+        assert_eq!(plan(100, II, 4.0, Severity::Normal), (20, 2, 3));
+        assert_eq!(plan(5000, II, 0.010, Severity::Normal), (1250, 0, 1));
+    }
+
+    #[test]
+    fn arrows_change_the_sample_size() {
+        use InspectionLevel::II;
+        // F at 1.0 is ↑ → E (13) with 0/1.
+        assert_eq!(plan(100, II, 1.0, Severity::Normal), (13, 0, 1));
+        // J at 0.40 is ↓ → K (125) with 1/2.
+        assert_eq!(plan(1000, II, 0.40, Severity::Normal), (125, 1, 2));
+        // J at 0.10 is ↓ → K (125) with 0/1.
+        assert_eq!(plan(1000, II, 0.10, Severity::Normal), (125, 0, 1));
+    }
+
+    #[test]
+    fn tightened_and_reduced_plans() {
+        use InspectionLevel::II;
+        assert_eq!(plan(1000, II, 2.5, Severity::Tightened), (80, 3, 4));
+        assert_eq!(plan(1000, II, 1.0, Severity::Tightened), (80, 1, 2));
+        assert_eq!(plan(1000, II, 6.5, Severity::Tightened), (80, 8, 9));
+        assert_eq!(plan(1000, II, 2.5, Severity::Reduced), (32, 2, 3));
+    }
+
+    #[test]
+    fn sample_size_capped_at_lot_size() {
+        let p = sampling_plan(10, InspectionLevel::III, 0.10, Severity::Normal).unwrap();
+        assert!(p.full_inspection);
+        assert_eq!(p.sample_size, 10);
+        assert_eq!(p.acceptance_number, 0);
+    }
+
+    #[test]
+    fn rejects_non_preferred_aql_and_discontinued() {
+        assert!(sampling_plan(1000, InspectionLevel::II, 3.0, Severity::Normal).is_err());
+        assert!(sampling_plan(1000, InspectionLevel::II, 2.5, Severity::Discontinued).is_err());
+        assert!(sampling_plan(0, InspectionLevel::II, 2.5, Severity::Normal).is_err());
+    }
+
+    #[test]
+    fn every_cell_resolves() {
+        for lot in [
+            2_i64, 10, 20, 40, 80, 120, 200, 400, 1000, 3000, 9000, 30_000, 100_000, 400_000,
+            600_000,
+        ] {
+            for level in [
+                InspectionLevel::S1,
+                InspectionLevel::S4,
+                InspectionLevel::I,
+                InspectionLevel::II,
+                InspectionLevel::III,
+            ] {
+                for aql in AQL_VALUES {
+                    for sev in [Severity::Normal, Severity::Tightened, Severity::Reduced] {
+                        let p = sampling_plan(lot, level, aql, sev).unwrap();
+                        assert_eq!(p.rejection_number, p.acceptance_number + 1);
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn two_rejects_in_five_switches_to_tightened() {
+        let mut s = SwitchState::default();
+        assert_eq!(s.record_lot(false, 1, false), None);
+        assert_eq!(s.record_lot(true, 1, false), None);
+        assert_eq!(s.record_lot(false, 1, false), Some(Severity::Tightened));
+        assert_eq!(s.switching_score, 0);
+    }
+
+    #[test]
+    fn five_accepts_on_tightened_returns_to_normal() {
+        let mut s = SwitchState {
+            severity: Severity::Tightened,
+            ..SwitchState::default()
+        };
+        for _ in 0..4 {
+            assert_eq!(s.record_lot(true, 3, false), None);
+        }
+        assert_eq!(s.record_lot(true, 3, false), Some(Severity::Normal));
+    }
+
+    #[test]
+    fn five_rejects_on_tightened_discontinues() {
+        let mut s = SwitchState {
+            severity: Severity::Tightened,
+            ..SwitchState::default()
+        };
+        for i in 0..5 {
+            let switched = s.record_lot(false, 3, false);
+            // An intervening accept does not reset the cumulative count.
+            s.record_lot(true, 3, false);
+            if i == 4 {
+                assert_eq!(switched, Some(Severity::Discontinued));
+            }
+        }
+        assert_eq!(s.severity, Severity::Discontinued);
+    }
+
+    #[test]
+    fn switching_score_reaches_reduced() {
+        let mut s = SwitchState::default();
+        // Ac <= 1: +2 per accepted lot → 15 lots.
+        for _ in 0..14 {
+            assert_eq!(s.record_lot(true, 1, false), None);
+        }
+        assert_eq!(s.record_lot(true, 1, false), Some(Severity::Reduced));
+
+        let mut s = SwitchState::default();
+        // Ac >= 2 and accepted at the tighter AQL: +3 per lot → 10 lots.
+        for _ in 0..9 {
+            s.record_lot(true, 5, true);
+        }
+        assert_eq!(s.switching_score, 27);
+        // Accepted, but not at the tighter AQL: score resets.
+        s.record_lot(true, 5, false);
+        assert_eq!(s.switching_score, 0);
+        assert_eq!(s.severity, Severity::Normal);
+    }
+
+    #[test]
+    fn reject_on_reduced_returns_to_normal() {
+        let mut s = SwitchState {
+            severity: Severity::Reduced,
+            ..SwitchState::default()
+        };
+        assert_eq!(s.record_lot(true, 2, false), None);
+        assert_eq!(s.record_lot(false, 2, false), Some(Severity::Normal));
+    }
+}
@@ -50,8 +50,8 @@ pub async fn insert_in_process_inspection(
         INSERT INTO inspections
             (tenant_id, plan_id, lot_id, inspector_id, inspection_type,
              result, notes, wo_id, op_instance_id, part_id, part_revision,
-             inspected_at)
-        VALUES ($1, $2, $3, $4, 'in_process', $5, $6, $7, $8, $9, $10,
+             workcenter_id, inspected_at)
+        VALUES ($1, $2, $3, $4, 'in_process', $5, $6, $7, $8, $9, $10, $11,
                 CASE WHEN $5 != 'pending' THEN NOW() ELSE NULL END)
         RETURNING *
         "#,
@@ -66,6 +66,7 @@ pub async fn insert_in_process_inspection(
     .bind(req.op_instance_id)
     .bind(req.part_id)
     .bind(&req.part_revision)
+    .bind(req.workcenter_id)
     .fetch_one(&mut **tx)
     .await?;
     Ok(inspection)
@@ -1,8 +1,14 @@
+pub mod aql;
 pub mod error_conversions;
 pub mod inspection_repo;
 pub mod inspection_service;
 pub mod models;
 pub mod outbox;
 pub mod plan_service;
+pub mod sampling_repo;
+pub mod sampling_service;
 pub mod service;
+pub mod spc;
+pub mod spc_repo;
+pub mod spc_service;
 pub mod wc_client;
@@ -15,6 +15,10 @@ pub struct InspectionPlan {
     pub characteristics: serde_json::Value,
     pub sampling_method: String,
     pub sample_size: Option<i32>,
+    /// Z1.4 inspection level (`S-1`..`S-4`, `I`, `II`, `III`) for `aql` sampling.
+    pub inspection_level: Option<String>,
+    /// Acceptance quality limit for `aql` sampling.
+    pub aql: Option<f64>,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
@@ -36,6 +40,15 @@ pub struct Inspection {
     pub disposition: String,
     pub wo_id: Option<Uuid>,
     pub op_instance_id: Option<Uuid>,
+    pub supplier_id: Option<Uuid>,
+    pub lot_size: Option<i32>,
+    pub code_letter: Option<String>,
+    pub sampling_severity: Option<String>,
+    pub sample_size: Option<i32>,
+    pub acceptance_number: Option<i32>,
+    pub rejection_number: Option<i32>,
+    pub defects_found: Option<i32>,
+    pub workcenter_id: Option<Uuid>,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
@@ -60,6 +73,12 @@ pub struct CreateInspectionPlanRequest {
     pub characteristics: Vec<Characteristic>,
     pub sampling_method: Option<String>,
     pub sample_size: Option<i32>,
+    /// Required when `sampling_method` is `aql`.
+    #[serde(default)]
+    pub inspection_level: Option<String>,
+    /// Required when `sampling_method` is `aql`; must be a Z1.4 preferred value.
+    #[serde(default)]
+    pub aql: Option<f64>,
 }
 
 #[derive(Debug, Deserialize, ToSchema)]
@@ -78,6 +97,8 @@ pub struct CreateReceivingInspectionRequest {
 pub struct CreateInProcessInspectionRequest {
     pub wo_id: Uuid,
     pub op_instance_id: Uuid,
+    /// Workcenter the operation ran on; SPC charts can be scoped to it.
+    pub workcenter_id: Option<Uuid>,
     pub plan_id: Option<Uuid>,
     pub lot_id: Option<Uuid>,
     pub part_id: Option<Uuid>,
@@ -104,3 +125,171 @@ pub struct DispositionTransitionRequest {
     pub inspector_id: Option<Uuid>,
     pub reason: Option<String>,
 }
+
+// ============================================================================
+// AQL sampling
+// ============================================================================
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct ApplySamplingRequest {
+    pub lot_size: i32,
+    /// Supplier whose switching history drives the severity; omit for in-house lots.
+    pub supplier_id: Option<Uuid>,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct MeasurementInput {
+    /// Name of a characteristic on the inspection's plan.
+    pub characteristic: String,
+    /// 1-based sample unit number, at most the inspection's sample size.
+    pub sample_index: i32,
+    pub value: Option<f64>,
+    /// Required when the characteristic has no tolerance limits or no value is given.
+    pub conforming: Option<bool>,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct RecordMeasurementsRequest {
+    pub inspector_id: Option<Uuid>,
+    pub measurements: Vec<MeasurementInput>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow, ToSchema)]
+pub struct InspectionMeasurement {
+    pub id: Uuid,
+    pub tenant_id: String,
+    pub inspection_id: Uuid,
+    pub characteristic: String,
+    pub sample_index: i32,
+    pub value: Option<f64>,
+    pub conforming: bool,
+    pub recorded_by: Option<Uuid>,
+    pub recorded_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct EvaluateInspectionRequest {
+    pub inspector_id: Option<Uuid>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow, ToSchema)]
+pub struct SamplingState {
+    pub id: Uuid,
+    pub tenant_id: String,
+    pub supplier_id: Uuid,
+    pub part_id: Uuid,
+    pub severity: String,
+    pub switching_score: i32,
+    pub consecutive_accepted: i32,
+    pub tightened_rejections: i32,
+    pub recent_outcomes: serde_json::Value,
+    pub lots_evaluated: i32,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct ReinstateSamplingRequest {
+    pub supplier_id: Option<Uuid>,
+    pub part_id: Uuid,
+    pub reason: Option<String>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct LotEvaluation {
+    pub inspection: Inspection,
+    /// `accepted` or `rejected`.
+    pub decision: String,
+    pub units_inspected: i32,
+    pub defective_units: i32,
+    pub severity_before: String,
+    pub severity_after: String,
+}
+
+// ============================================================================
+// Statistical process control
+// ============================================================================
+
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow, ToSchema)]
+pub struct SpcChart {
+    pub id: Uuid,
+    pub tenant_id: String,
+    pub part_id: Uuid,
+    pub plan_id: Uuid,
+    pub characteristic: String,
+    /// `None` charts in-process inspections from every workcenter together.
+    pub workcenter_id: Option<Uuid>,
+    /// `xbar_r`, `xbar_s`, `i_mr`, `p` or `np`.
+    pub chart_type: String,
+    pub subgroup_size: Option<i32>,
+    /// `western_electric` or `nelson`.
+    pub rule_set: String,
+    /// Control limits are fixed from this many leading subgroups when set.
+    pub baseline_subgroups: Option<i32>,
+    pub nominal: Option<f64>,
+    pub lsl: Option<f64>,
+    pub usl: Option<f64>,
+    pub hold_on_violation: bool,
+    pub active: bool,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct CreateSpcChartRequest {
+    /// Plan holding the characteristic; its part and tolerance limits are used.
+    pub plan_id: Uuid,
+    pub characteristic: String,
+    pub workcenter_id: Option<Uuid>,
+    pub chart_type: String,
+    /// Required for `xbar_r` (2..=10), `xbar_s` and `np`.
+    pub subgroup_size: Option<i32>,
+    /// Defaults to `western_electric`.
+    pub rule_set: Option<String>,
+    pub baseline_subgroups: Option<i32>,
+    /// Defaults to true: violations on a work order raise a shop-floor hold.
+    pub hold_on_violation: Option<bool>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow, ToSchema)]
+pub struct SpcViolation {
+    pub id: Uuid,
+    pub tenant_id: String,
+    pub chart_id: Uuid,
+    pub inspection_id: Uuid,
+    pub wo_id: Option<Uuid>,
+    pub rule: String,
+    /// `location` (X-bar, X, p, np) or `dispersion` (R, S, MR).
+    pub section: String,
+    pub value: f64,
+    pub description: String,
+    pub hold_requested: bool,
+    pub detected_at: DateTime<Utc>,
+}
+
+/// One plotted subgroup (or individual) with the inspection it came from.
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct SpcChartPoint {
+    pub index: usize,
+    pub inspection_id: Uuid,
+    pub wo_id: Option<Uuid>,
+    pub recorded_at: DateTime<Utc>,
+    pub value: f64,
+    pub ucl: f64,
+    pub lcl: f64,
+    pub dispersion: Option<f64>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct SpcChartData {
+    pub chart: SpcChart,
+    /// `None` until the chart has enough data to compute limits.
+    pub location: Option<crate::domain::spc::ControlLimits>,
+    pub dispersion: Option<crate::domain::spc::ControlLimits>,
+    pub sigma_within: Option<f64>,
+    pub points: Vec<SpcChartPoint>,
+    /// Rule violations among the returned points; `index` refers to `points`.
+    pub violations: Vec<crate::domain::spc::RuleViolation>,
+    /// Variables charts with at least one specification limit only.
+    pub capability: Option<crate::domain::spc::Capability>,
+}
@@ -1,6 +1,7 @@
 use sqlx::PgPool;
 use uuid::Uuid;
 
+use crate::domain::aql;
 use crate::domain::models::*;
 use crate::domain::outbox::enqueue_event;
 use crate::domain::service::QiError;
@@ -22,6 +23,7 @@ pub async fn create_inspection_plan(
 
     let revision = req.revision.as_deref().unwrap_or("A");
     let sampling = req.sampling_method.as_deref().unwrap_or("full");
+    validate_aql_parameters(req.inspection_level.as_deref(), req.aql)?;
     let chars_json = serde_json::to_value(&req.characteristics)?;
 
     let mut tx = pool.begin().await?;
@@ -30,8 +32,8 @@ pub async fn create_inspection_plan(
         r#"
         INSERT INTO inspection_plans
             (tenant_id, part_id, plan_name, revision, characteristics,
-             sampling_method, sample_size)
-        VALUES ($1, $2, $3, $4, $5, $6, $7)
+             sampling_method, sample_size, inspection_level, aql)
+        VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
         RETURNING *
         "#,
     )
@@ -42,6 +44,8 @@ pub async fn create_inspection_plan(
     .bind(&chars_json)
     .bind(sampling)
     .bind(req.sample_size)
+    .bind(&req.inspection_level)
+    .bind(req.aql)
     .fetch_one(&mut *tx)
     .await?;
 
@@ -68,6 +72,26 @@ pub async fn create_inspection_plan(
     Ok(plan)
 }
 
+fn validate_aql_parameters(level: Option<&str>, aql_value: Option<f64>) -> Result<(), QiError> {
+    if let Some(l) = level {
+        if aql::InspectionLevel::parse(l).is_none() {
+            return Err(QiError::Validation(format!(
+                "Invalid inspection_level '{}', expected one of: S-1, S-2, S-3, S-4, I, II, III",
+                l
+            )));
+        }
+    }
+    if let Some(v) = aql_value {
+        if aql::aql_index(v).is_none() {
+            return Err(QiError::Validation(format!(
+                "aql {} is not a Z1.4 preferred value",
+                v
+            )));
+        }
+    }
+    Ok(())
+}
+
 pub async fn get_inspection_plan(
     pool: &PgPool,
     tenant_id: &str,
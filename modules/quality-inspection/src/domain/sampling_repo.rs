@@ -0,0 +1,237 @@
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::domain::aql::{SamplingPlan, SwitchState};
+use crate::domain::models::*;
+use crate::domain::service::QiError;
+
+// ============================================================================
+// Inspections (sampling + evaluation columns)
+// ============================================================================
+
+pub async fn lock_inspection(
+    tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+    tenant_id: &str,
+    inspection_id: Uuid,
+) -> Result<Option<Inspection>, QiError> {
+    let row = sqlx::query_as::<_, Inspection>(
+        "SELECT * FROM inspections WHERE id = $1 AND tenant_id = $2 FOR UPDATE",
+    )
+    .bind(inspection_id)
+    .bind(tenant_id)
+    .fetch_optional(&mut **tx)
+    .await?;
+    Ok(row)
+}
+
+pub async fn set_sampling_plan(
+    pool: &PgPool,
+    tenant_id: &str,
+    inspection_id: Uuid,
+    supplier_id: Option<Uuid>,
+    lot_size: i32,
+    plan: &SamplingPlan,
+) -> Result<Inspection, QiError> {
+    let updated = sqlx::query_as::<_, Inspection>(
+        r#"
+        UPDATE inspections
+        SET supplier_id = $1, lot_size = $2, code_letter = $3, sampling_severity = $4,
+            sample_size = $5, acceptance_number = $6, rejection_number = $7,
+            updated_at = NOW()
+        WHERE id = $8 AND tenant_id = $9
+        RETURNING *
+        "#,
+    )
+    .bind(supplier_id)
+    .bind(lot_size)
+    .bind(&plan.code_letter)
+    .bind(plan.severity.as_str())
+    .bind(plan.sample_size)
+    .bind(plan.acceptance_number)
+    .bind(plan.rejection_number)
+    .bind(inspection_id)
+    .bind(tenant_id)
+    .fetch_one(pool)
+    .await?;
+    Ok(updated)
+}
+
+pub async fn record_evaluation(
+    tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+    tenant_id: &str,
+    inspection_id: Uuid,
+    result_val: &str,
+    disposition: &str,
+    defects_found: i32,
+    inspector_id: Uuid,
+) -> Result<Inspection, QiError> {
+    let updated = sqlx::query_as::<_, Inspection>(
+        r#"
+        UPDATE inspections
+        SET result = $1, disposition = $2, defects_found = $3,
+            inspector_id = COALESCE(inspector_id, $4),
+            inspected_at = COALESCE(inspected_at, NOW()),
+            updated_at = NOW()
+        WHERE id = $5 AND tenant_id = $6
+        RETURNING *
+        "#,
+    )
+    .bind(result_val)
+    .bind(disposition)
+    .bind(defects_found)
+    .bind(inspector_id)
+    .bind(inspection_id)
+    .bind(tenant_id)
+    .fetch_one(&mut **tx)
+    .await?;
+    Ok(updated)
+}
+
+// ============================================================================
+// Measurements
+// ============================================================================
+
+pub async fn upsert_measurement(
+    tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+    tenant_id: &str,
+    inspection_id: Uuid,
+    characteristic: &str,
+    sample_index: i32,
+    value: Option<f64>,
+    conforming: bool,
+    recorded_by: Option<Uuid>,
+) -> Result<InspectionMeasurement, QiError> {
+    let row = sqlx::query_as::<_, InspectionMeasurement>(
+        r#"
+        INSERT INTO inspection_measurements
+            (tenant_id, inspection_id, characteristic, sample_index, value,
+             conforming, recorded_by)
+        VALUES ($1, $2, $3, $4, $5, $6, $7)
+        ON CONFLICT (inspection_id, characteristic, sample_index) DO UPDATE
+        SET value = EXCLUDED.value, conforming = EXCLUDED.conforming,
+            recorded_by = EXCLUDED.recorded_by, recorded_at = NOW()
+        RETURNING *
+        "#,
+    )
+    .bind(tenant_id)
+    .bind(inspection_id)
+    .bind(characteristic)
+    .bind(sample_index)
+    .bind(value)
+    .bind(conforming)
+    .bind(recorded_by)
+    .fetch_one(&mut **tx)
+    .await?;
+    Ok(row)
+}
+
+pub async fn list_measurements<'e, E>(
+    executor: E,
+    tenant_id: &str,
+    inspection_id: Uuid,
+) -> Result<Vec<InspectionMeasurement>, QiError>
+where
+    E: sqlx::PgExecutor<'e>,
+{
+    let rows = sqlx::query_as::<_, InspectionMeasurement>(
+        r#"
+        SELECT * FROM inspection_measurements
+        WHERE tenant_id = $1 AND inspection_id = $2
+        ORDER BY sample_index, characteristic
+        "#,
+    )
+    .bind(tenant_id)
+    .bind(inspection_id)
+    .fetch_all(executor)
+    .await?;
+    Ok(rows)
+}
+
+// ============================================================================
+// Switching state
+// ============================================================================
+
+pub async fn get_state(
+    pool: &PgPool,
+    tenant_id: &str,
+    supplier_id: Uuid,
+    part_id: Uuid,
+) -> Result<Option<SamplingState>, QiError> {
+    let row = sqlx::query_as::<_, SamplingState>(
+        r#"
+        SELECT * FROM sampling_states
+        WHERE tenant_id = $1 AND supplier_id = $2 AND part_id = $3
+        "#,
+    )
+    .bind(tenant_id)
+    .bind(supplier_id)
+    .bind(part_id)
+    .fetch_optional(pool)
+    .await?;
+    Ok(row)
+}
+
+/// Fetch the state row for update, creating it at normal severity on first use.
+pub async fn lock_or_create_state(
+    tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+    tenant_id: &str,
+    supplier_id: Uuid,
+    part_id: Uuid,
+) -> Result<SamplingState, QiError> {
+    sqlx::query(
+        r#"
+        INSERT INTO sampling_states (tenant_id, supplier_id, part_id)
+        VALUES ($1, $2, $3)
+        ON CONFLICT (tenant_id, supplier_id, part_id) DO NOTHING
+        "#,
+    )
+    .bind(tenant_id)
+    .bind(supplier_id)
+    .bind(part_id)
+    .execute(&mut **tx)
+    .await?;
+
+    let row = sqlx::query_as::<_, SamplingState>(
+        r#"
+        SELECT * FROM sampling_states
+        WHERE tenant_id = $1 AND supplier_id = $2 AND part_id = $3
+        FOR UPDATE
+        "#,
+    )
+    .bind(tenant_id)
+    .bind(supplier_id)
+    .bind(part_id)
+    .fetch_one(&mut **tx)
+    .await?;
+    Ok(row)
+}
+
+pub async fn save_state(
+    tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+    state_id: Uuid,
+    state: &SwitchState,
+    lot_evaluated: bool,
+) -> Result<SamplingState, QiError> {
+    let outcomes = serde_json::to_value(&state.recent_outcomes)?;
+    let row = sqlx::query_as::<_, SamplingState>(
+        r#"
+        UPDATE sampling_states
+        SET severity = $1, switching_score = $2, consecutive_accepted = $3,
+            tightened_rejections = $4, recent_outcomes = $5,
+            lots_evaluated = lots_evaluated + CASE WHEN $6 THEN 1 ELSE 0 END,
+            updated_at = NOW()
+        WHERE id = $7
+        RETURNING *
+        "#,
+    )
+    .bind(state.severity.as_str())
+    .bind(state.switching_score)
+    .bind(state.consecutive_accepted)
+    .bind(state.tightened_rejections)
+    .bind(&outcomes)
+    .bind(lot_evaluated)
+    .bind(state_id)
+    .fetch_one(&mut **tx)
+    .await?;
+    Ok(row)
+}
@@ -0,0 +1,501 @@
+//! AQL sampling: resolve a Z1.4 plan for an inspection lot, record sample
+//! measurements against plan tolerances, and auto-disposition the lot.
+//!
+//! Severity (normal / tightened / reduced) comes from the switching state of the
+//! supplier/part lot stream and is advanced in the same transaction as the
+//! disposition. Lots without a supplier share the nil-UUID stream for the part.
+
+use platform_sdk::PlatformClient;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::domain::aql::{self, InspectionLevel, SamplingPlan, Severity, SwitchState};
+use crate::domain::models::*;
+use crate::domain::outbox::enqueue_event;
+use crate::domain::sampling_repo as repo;
+use crate::domain::service::{get_inspection, get_inspection_plan, QiError};
+use crate::domain::spc_service;
+use crate::events::{self, LotDispositionedPayload, QualityInspectionEventType};
+
+fn aql_parameters(plan: &InspectionPlan) -> Result<(InspectionLevel, f64), QiError> {
+    if plan.sampling_method != "aql" {
+        return Err(QiError::Validation(format!(
+            "Plan sampling_method is '{}', expected 'aql'",
+            plan.sampling_method
+        )));
+    }
+    let level = plan
+        .inspection_level
+        .as_deref()
+        .and_then(InspectionLevel::parse)
+        .ok_or_else(|| QiError::Validation("Plan has no inspection_level configured".into()))?;
+    let aql_value = plan
+        .aql
+        .ok_or_else(|| QiError::Validation("Plan has no aql configured".into()))?;
+    Ok((level, aql_value))
+}
+
+fn switch_state(row: &SamplingState) -> SwitchState {
+    SwitchState {
+        severity: Severity::parse(&row.severity).unwrap_or(Severity::Normal),
+        switching_score: row.switching_score,
+        consecutive_accepted: row.consecutive_accepted,
+        tightened_rejections: row.tightened_rejections,
+        recent_outcomes: serde_json::from_value(row.recent_outcomes.clone()).unwrap_or_default(),
+    }
+}
+
+async fn current_severity(
+    pool: &PgPool,
+    tenant_id: &str,
+    supplier_id: Option<Uuid>,
+    part_id: Uuid,
+) -> Result<Severity, QiError> {
+    let state = repo::get_state(pool, tenant_id, supplier_id.unwrap_or_default(), part_id).await?;
+    Ok(state
+        .as_ref()
+        .map(|s| switch_state(s).severity)
+        .unwrap_or(Severity::Normal))
+}
+
+fn resolve_plan(
+    lot_size: i32,
+    level: InspectionLevel,
+    aql_value: f64,
+    severity: Severity,
+) -> Result<SamplingPlan, QiError> {
+    aql::sampling_plan(i64::from(lot_size), level, aql_value, severity).map_err(QiError::Validation)
+}
+
+/// Whether a measurement conforms: tolerance limits decide when a value is
+/// given and the characteristic has at least one limit; otherwise the caller's
+/// attribute judgement is required.
+fn evaluate_conformance(
+    characteristic: &Characteristic,
+    value: Option<f64>,
+    conforming: Option<bool>,
+) -> Result<bool, QiError> {
+    let has_limits =
+        characteristic.tolerance_low.is_some() || characteristic.tolerance_high.is_some();
+    match value {
+        Some(v) if has_limits => Ok(characteristic.tolerance_low.is_none_or(|lo| v >= lo)
+            && characteristic.tolerance_high.is_none_or(|hi| v <= hi)),
+        _ => conforming.ok_or_else(|| {
+            QiError::Validation(format!(
+                "conforming is required for characteristic '{}' (no value or tolerance limits)",
+                characteristic.name
+            ))
+        }),
+    }
+}
+
+// ============================================================================
+// Sampling plan
+// ============================================================================
+
+/// Compute the plan a lot of `lot_size` would be inspected under today.
+pub async fn preview_sampling_plan(
+    pool: &PgPool,
+    tenant_id: &str,
+    plan_id: Uuid,
+    lot_size: i32,
+    supplier_id: Option<Uuid>,
+) -> Result<SamplingPlan, QiError> {
+    let plan = get_inspection_plan(pool, tenant_id, plan_id).await?;
+    let (level, aql_value) = aql_parameters(&plan)?;
+    let severity = current_severity(pool, tenant_id, supplier_id, plan.part_id).await?;
+    resolve_plan(lot_size, level, aql_value, severity)
+}
+
+/// Resolve and pin the Z1.4 plan on a pending inspection.
+pub async fn apply_sampling(
+    pool: &PgPool,
+    tenant_id: &str,
+    inspection_id: Uuid,
+    req: &ApplySamplingRequest,
+) -> Result<Inspection, QiError> {
+    if req.lot_size < 1 {
+        return Err(QiError::Validation("lot_size must be >= 1".into()));
+    }
+    let inspection = get_inspection(pool, tenant_id, inspection_id).await?;
+    if inspection.result != "pending" {
+        return Err(QiError::Validation(format!(
+            "Inspection result is '{}'; sampling can only be applied while pending",
+            inspection.result
+        )));
+    }
+    let plan_id = inspection
+        .plan_id
+        .ok_or_else(|| QiError::Validation("Inspection has no plan_id".into()))?;
+    let plan = get_inspection_plan(pool, tenant_id, plan_id).await?;
+    let (level, aql_value) = aql_parameters(&plan)?;
+    let part_id = inspection.part_id.unwrap_or(plan.part_id);
+
+    let severity = current_severity(pool, tenant_id, req.supplier_id, part_id).await?;
+    let sampling = resolve_plan(req.lot_size, level, aql_value, severity)?;
+
+    repo::set_sampling_plan(
+        pool,
+        tenant_id,
+        inspection_id,
+        req.supplier_id,
+        req.lot_size,
+        &sampling,
+    )
+    .await
+}
+
+// ============================================================================
+// Measurements
+// ============================================================================
+
+/// Record sample measurements. On in-process inspections this also runs the
+/// SPC charts the measured characteristics feed.
+pub async fn record_measurements(
+    pool: &PgPool,
+    tenant_id: &str,
+    inspection_id: Uuid,
+    req: &RecordMeasurementsRequest,
+    correlation_id: &str,
+    causation_id: Option<&str>,
+) -> Result<Vec<InspectionMeasurement>, QiError> {
+    if req.measurements.is_empty() {
+        return Err(QiError::Validation("measurements must not be empty".into()));
+    }
+    let inspection = get_inspection(pool, tenant_id, inspection_id).await?;
+    if inspection.result != "pending" {
+        return Err(QiError::Validation(format!(
+            "Inspection result is '{}'; measurements can only be recorded while pending",
+            inspection.result
+        )));
+    }
+    let plan_id = inspection
+        .plan_id
+        .ok_or_else(|| QiError::Validation("Inspection has no plan_id".into()))?;
+    let plan = get_inspection_plan(pool, tenant_id, plan_id).await?;
+    let characteristics: Vec<Characteristic> = serde_json::from_value(plan.characteristics)?;
+
+    let mut tx = pool.begin().await?;
+    let mut recorded = Vec::with_capacity(req.measurements.len());
+    for m in &req.measurements {
+        let ch = characteristics
+            .iter()
+            .find(|c| c.name == m.characteristic)
+            .ok_or_else(|| {
+                QiError::Validation(format!(
+                    "Characteristic '{}' is not on plan {}",
+                    m.characteristic, plan_id
+                ))
+            })?;
+        if m.sample_index < 1 || inspection.sample_size.is_some_and(|n| m.sample_index > n) {
+            return Err(QiError::Validation(format!(
+                "sample_index {} is outside the sample (1..={})",
+                m.sample_index,
+                inspection.sample_size.unwrap_or(i32::MAX)
+            )));
+        }
+        let conforming = evaluate_conformance(ch, m.value, m.conforming)?;
+        recorded.push(
+            repo::upsert_measurement(
+                &mut tx,
+                tenant_id,
+                inspection_id,
+                &m.characteristic,
+                m.sample_index,
+                m.value,
+                conforming,
+                req.inspector_id,
+            )
+            .await?,
+        );
+    }
+
+    if inspection.inspection_type == "in_process" {
+        let mut measured: Vec<String> = req
+            .measurements
+            .iter()
+            .map(|m| m.characteristic.clone())
+            .collect();
+        measured.sort();
+        measured.dedup();
+        spc_service::check_in_process_spc(
+            &mut tx,
+            tenant_id,
+            &inspection,
+            inspection.part_id.unwrap_or(plan.part_id),
+            &measured,
+            correlation_id,
+            causation_id,
+        )
+        .await?;
+    }
+
+    tx.commit().await?;
+    Ok(recorded)
+}
+
+pub async fn list_measurements(
+    pool: &PgPool,
+    tenant_id: &str,
+    inspection_id: Uuid,
+) -> Result<Vec<InspectionMeasurement>, QiError> {
+    get_inspection(pool, tenant_id, inspection_id).await?;
+    repo::list_measurements(pool, tenant_id, inspection_id).await
+}
+
+// ============================================================================
+// Evaluation + auto-disposition
+// ============================================================================
+
+/// Count (units fully measured, defective units). A unit is defective if any
+/// of its measurements is nonconforming.
+fn tally_units(measurements: &[InspectionMeasurement], characteristic_count: usize) -> (i32, i32) {
+    let mut units: std::collections::BTreeMap<i32, (usize, bool)> = Default::default();
+    for m in measurements {
+        let entry = units.entry(m.sample_index).or_insert((0, false));
+        entry.0 += 1;
+        entry.1 |= !m.conforming;
+    }
+    let complete = units
+        .values()
+        .filter(|(count, _)| *count >= characteristic_count)
+        .count() as i32;
+    let defective = units.values().filter(|(_, bad)| *bad).count() as i32;
+    (complete, defective)
+}
+
+/// Compare the recorded sample against Ac/Re, set result and disposition,
+/// and advance the supplier/part switching state.
+///
+/// Rejects as soon as defective units reach Re; acceptance requires the full
+/// sample to be measured. Requires an authorized inspector, like manual
+/// disposition.
+pub async fn evaluate_inspection(
+    pool: &PgPool,
+    wc_client: &PlatformClient,
+    tenant_id: &str,
+    inspection_id: Uuid,
+    inspector_id: Option<Uuid>,
+    correlation_id: &str,
+    causation_id: Option<&str>,
+) -> Result<LotEvaluation, QiError> {
+    let iid = inspector_id.ok_or_else(|| {
+        QiError::Validation("inspector_id is required for disposition actions".into())
+    })?;
+    crate::domain::wc_client::verify_inspector_authorized(wc_client, tenant_id, iid).await?;
+
+    let mut tx = pool.begin().await?;
+
+    let inspection = repo::lock_inspection(&mut tx, tenant_id, inspection_id)
+        .await?
+        .ok_or_else(|| QiError::NotFound("Inspection not found".into()))?;
+    if !matches!(inspection.disposition.as_str(), "pending" | "held") {
+        return Err(QiError::Validation(format!(
+            "Inspection disposition is already '{}'",
+            inspection.disposition
+        )));
+    }
+    let (Some(lot_size), Some(sample_size), Some(ac), Some(re)) = (
+        inspection.lot_size,
+        inspection.sample_size,
+        inspection.acceptance_number,
+        inspection.rejection_number,
+    ) else {
+        return Err(QiError::Validation(
+            "Sampling plan has not been applied to this inspection".into(),
+        ));
+    };
+    let plan_id = inspection
+        .plan_id
+        .ok_or_else(|| QiError::Validation("Inspection has no plan_id".into()))?;
+    let plan = get_inspection_plan(pool, tenant_id, plan_id).await?;
+    let (level, aql_value) = aql_parameters(&plan)?;
+    let characteristics: Vec<Characteristic> = serde_json::from_value(plan.characteristics)?;
+    if characteristics.is_empty() {
+        return Err(QiError::Validation("Plan has no characteristics".into()));
+    }
+
+    let measurements = repo::list_measurements(&mut *tx, tenant_id, inspection_id).await?;
+    let (units_inspected, defective_units) = tally_units(&measurements, characteristics.len());
+
+    let accepted = if defective_units >= re {
+        false
+    } else if units_inspected < sample_size {
+        return Err(QiError::Validation(format!(
+            "{} of {} sample units fully measured; record the remaining units before evaluating",
+            units_inspected, sample_size
+        )));
+    } else {
+        defective_units <= ac
+    };
+    let (result_val, target, event_type) = if accepted {
+        (
+            "pass",
+            "accepted",
+            QualityInspectionEventType::InspectionAccepted,
+        )
+    } else {
+        (
+            "fail",
+            "rejected",
+            QualityInspectionEventType::InspectionRejected,
+        )
+    };
+
+    // Switching rules
+    let part_id = inspection.part_id.unwrap_or(plan.part_id);
+    let state_row = repo::lock_or_create_state(
+        &mut tx,
+        tenant_id,
+        inspection.supplier_id.unwrap_or_default(),
+        part_id,
+    )
+    .await?;
+    let mut state = switch_state(&state_row);
+    let severity_before = state.severity;
+    let normal_ac = resolve_plan(lot_size, level, aql_value, Severity::Normal)?.acceptance_number;
+    let accepted_tighter =
+        aql::accepted_one_step_tighter(i64::from(lot_size), level, aql_value, defective_units);
+    state.record_lot(accepted, normal_ac, accepted_tighter);
+    repo::save_state(&mut tx, state_row.id, &state, true).await?;
+
+    let updated = repo::record_evaluation(
+        &mut tx,
+        tenant_id,
+        inspection_id,
+        result_val,
+        target,
+        defective_units,
+        iid,
+    )
+    .await?;
+
+    let reason = format!(
+        "AQL {} auto-disposition: {} defective in sample of {} (Ac={}, Re={}, {} inspection)",
+        aql_value,
+        defective_units,
+        sample_size,
+        ac,
+        re,
+        inspection.sampling_severity.as_deref().unwrap_or("normal"),
+    );
+    enqueue_event(
+        &mut tx,
+        tenant_id,
+        event_type,
+        "inspection",
+        &inspection_id.to_string(),
+        &events::build_disposition_transition_envelope(
+            event_type,
+            inspection_id,
+            tenant_id.to_string(),
+            inspection.disposition.clone(),
+            target.to_string(),
+            Some(iid),
+            Some(reason),
+            correlation_id.to_string(),
+            causation_id.map(String::from),
+        ),
+        correlation_id,
+        causation_id,
+    )
+    .await?;
+
+    if let Some(lot_id) = inspection.lot_id {
+        enqueue_event(
+            &mut tx,
+            tenant_id,
+            QualityInspectionEventType::LotDispositioned,
+            "inspection",
+            &inspection_id.to_string(),
+            &events::build_lot_dispositioned_envelope(
+                LotDispositionedPayload {
+                    inspection_id,
+                    tenant_id: tenant_id.to_string(),
+                    lot_id,
+                    part_id: Some(part_id),
+                    supplier_id: inspection.supplier_id,
+                    decision: target.to_string(),
+                    lot_size,
+                    sample_size,
+                    acceptance_number: ac,
+                    rejection_number: re,
+                    defective_units,
+                    severity: inspection
+                        .sampling_severity
+                        .clone()
+                        .unwrap_or_else(|| "normal".to_string()),
+                },
+                correlation_id.to_string(),
+                causation_id.map(String::from),
+            ),
+            correlation_id,
+            causation_id,
+        )
+        .await?;
+    }
+
+    tx.commit().await?;
+
+    Ok(LotEvaluation {
+        inspection: updated,
+        decision: target.to_string(),
+        units_inspected,
+        defective_units,
+        severity_before: severity_before.as_str().to_string(),
+        severity_after: state.severity.as_str().to_string(),
+    })
+}
+
+// ============================================================================
+// Switching state
+// ============================================================================
+
+pub async fn get_sampling_state(
+    pool: &PgPool,
+    tenant_id: &str,
+    supplier_id: Option<Uuid>,
+    part_id: Uuid,
+) -> Result<SamplingState, QiError> {
+    repo::get_state(pool, tenant_id, supplier_id.unwrap_or_default(), part_id)
+        .await?
+        .ok_or_else(|| QiError::NotFound("No sampling history for supplier/part".into()))
+}
+
+/// Resume acceptance inspection after discontinuation. Per Z1.4 §8.4 it
+/// restarts on tightened inspection once the supplier has corrected the cause.
+pub async fn reinstate_sampling(
+    pool: &PgPool,
+    tenant_id: &str,
+    req: &ReinstateSamplingRequest,
+) -> Result<SamplingState, QiError> {
+    let mut tx = pool.begin().await?;
+    let row = repo::lock_or_create_state(
+        &mut tx,
+        tenant_id,
+        req.supplier_id.unwrap_or_default(),
+        req.part_id,
+    )
+    .await?;
+    if row.severity != Severity::Discontinued.as_str() {
+        return Err(QiError::Validation(format!(
+            "Sampling severity is '{}', expected 'discontinued'",
+            row.severity
+        )));
+    }
+    let state = SwitchState {
+        severity: Severity::Tightened,
+        ..SwitchState::default()
+    };
+    let saved = repo::save_state(&mut tx, row.id, &state, false).await?;
+    tx.commit().await?;
+
+    tracing::info!(
+        tenant_id = %tenant_id,
+        part_id = %req.part_id,
+        supplier_id = ?req.supplier_id,
+        reason = ?req.reason,
+        "AQL sampling reinstated on tightened inspection"
+    );
+    Ok(saved)
+}
@@ -24,3 +24,5 @@ pub enum QiError {
 // Re-export from sub-modules so callers using `service::foo()` keep working.
 pub use crate::domain::inspection_service::*;
 pub use crate::domain::plan_service::*;
+pub use crate::domain::sampling_service::*;
+pub use crate::domain::spc_service::*;
@@ -0,0 +1,891 @@
+//! Statistical process control for in-process measurements.
+//!
+//! Pure computation — no I/O. Callers pass subgroups in time order.
+//!
+//! - Variables charts: X-bar/R (n = 2..10), X-bar/S (n >= 2), individuals /
+//!   moving range (I-MR)
+//! - Attributes charts: p (fraction nonconforming, variable n) and np (count
+//!   nonconforming, constant n)
+//! - Run rules: Western Electric (4 rules) and Nelson (8 rules), evaluated on
+//!   the location chart in sigma units; the dispersion chart is checked against
+//!   its own limits.
+//! - Capability: Cp/Cpk from within-subgroup sigma (R̄/d2, S̄/c4, MR̄/d2) and
+//!   Pp/Ppk from the overall sample standard deviation.
+//!
+//! Control limits come from the first `baseline` points (phase I) when given,
+//! otherwise from every point supplied.
+
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+/// d2 for subgroup sizes 2..=10 (expected range / sigma).
+const D2: [f64; 9] = [
+    1.128, 1.693, 2.059, 2.326, 2.534, 2.704, 2.847, 2.970, 3.078,
+];
+/// A2 for subgroup sizes 2..=10.
+const A2: [f64; 9] = [
+    1.880, 1.023, 0.729, 0.577, 0.483, 0.419, 0.373, 0.337, 0.308,
+];
+/// D3 for subgroup sizes 2..=10.
+const D3: [f64; 9] = [0.0, 0.0, 0.0, 0.0, 0.0, 0.076, 0.136, 0.184, 0.223];
+/// D4 for subgroup sizes 2..=10.
+const D4: [f64; 9] = [
+    3.267, 2.574, 2.282, 2.114, 2.004, 1.924, 1.864, 1.816, 1.777,
+];
+
+const MAX_RANGE_SUBGROUP: usize = 10;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ChartType {
+    XbarR,
+    XbarS,
+    IMr,
+    P,
+    Np,
+}
+
+impl ChartType {
+    pub fn parse(s: &str) -> Option<Self> {
+        match s {
+            "xbar_r" => Some(Self::XbarR),
+            "xbar_s" => Some(Self::XbarS),
+            "i_mr" => Some(Self::IMr),
+            "p" => Some(Self::P),
+            "np" => Some(Self::Np),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::XbarR => "xbar_r",
+            Self::XbarS => "xbar_s",
+            Self::IMr => "i_mr",
+            Self::P => "p",
+            Self::Np => "np",
+        }
+    }
+
+    /// Variables charts plot measured values; attributes charts plot counts of
+    /// nonconforming units.
+    pub fn is_variables(&self) -> bool {
+        matches!(self, Self::XbarR | Self::XbarS | Self::IMr)
+    }
+
+    /// Whether the chart needs a fixed subgroup size.
+    pub fn requires_subgroup_size(&self) -> bool {
+        matches!(self, Self::XbarR | Self::XbarS | Self::Np)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum RuleSet {
+    WesternElectric,
+    Nelson,
+}
+
+impl RuleSet {
+    pub fn parse(s: &str) -> Option<Self> {
+        match s {
+            "western_electric" => Some(Self::WesternElectric),
+            "nelson" => Some(Self::Nelson),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::WesternElectric => "western_electric",
+            Self::Nelson => "nelson",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, ToSchema)]
+pub struct ControlLimits {
+    pub center_line: f64,
+    pub ucl: f64,
+    pub lcl: f64,
+}
+
+/// One plotted point. Limits are per point because p-chart limits vary with
+/// the subgroup size; for every other chart they equal the chart limits.
+#[derive(Debug, Clone, PartialEq, Serialize, ToSchema)]
+pub struct ChartPoint {
+    pub value: f64,
+    pub ucl: f64,
+    pub lcl: f64,
+    /// R, S or moving range for the point; `None` for attributes charts and
+    /// the first individual.
+    pub dispersion: Option<f64>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, ToSchema)]
+pub struct ChartComputation {
+    pub chart_type: ChartType,
+    /// X-bar, X, p or np chart limits. For p charts the limits are shown at
+    /// the average subgroup size.
+    pub location: ControlLimits,
+    /// R, S or MR chart limits; `None` for attributes charts.
+    pub dispersion: Option<ControlLimits>,
+    /// Within-subgroup sigma estimate for variables charts.
+    pub sigma_within: Option<f64>,
+    pub points: Vec<ChartPoint>,
+}
+
+/// Attributes subgroup: nonconforming units out of units inspected.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AttributeSample {
+    pub nonconforming: i64,
+    pub inspected: i64,
+}
+
+fn mean(values: &[f64]) -> f64 {
+    values.iter().sum::<f64>() / values.len() as f64
+}
+
+fn range(values: &[f64]) -> f64 {
+    let max = values.iter().cloned().fold(f64::NEG_INFINITY, f64::max);
+    let min = values.iter().cloned().fold(f64::INFINITY, f64::min);
+    max - min
+}
+
+/// Sample standard deviation (n - 1 denominator).
+fn std_dev(values: &[f64]) -> f64 {
+    if values.len() < 2 {
+        return 0.0;
+    }
+    let m = mean(values);
+    let ss: f64 = values.iter().map(|v| (v - m).powi(2)).sum();
+    (ss / (values.len() - 1) as f64).sqrt()
+}
+
+/// c4 bias correction for the sample standard deviation, computed exactly
+/// from Γ(n/2) / Γ((n-1)/2) via its recurrence.
+pub fn c4(n: usize) -> f64 {
+    let mut ratio = 1.0 / std::f64::consts::PI.sqrt(); // n = 2
+    for k in 2..n {
+        ratio = (k as f64 - 1.0) / (2.0 * ratio);
+    }
+    (2.0 / (n as f64 - 1.0)).sqrt() * ratio
+}
+
+fn baseline_len(baseline: Option<usize>, total: usize) -> usize {
+    match baseline {
+        Some(b) if b >= 2 && b < total => b,
+        _ => total,
+    }
+}
+
+fn uniform_subgroup_size(subgroups: &[Vec<f64>]) -> Result<usize, String> {
+    let n = subgroups
+        .first()
+        .map(Vec::len)
+        .ok_or_else(|| "at least one subgroup is required".to_string())?;
+    if subgroups.iter().any(|s| s.len() != n) {
+        return Err("all subgroups must have the same size".into());
+    }
+    Ok(n)
+}
+
+fn flat_points(
+    values: &[f64],
+    dispersion: &[Option<f64>],
+    limits: ControlLimits,
+) -> Vec<ChartPoint> {
+    values
+        .iter()
+        .zip(dispersion)
+        .map(|(v, d)| ChartPoint {
+            value: *v,
+            ucl: limits.ucl,
+            lcl: limits.lcl,
+            dispersion: *d,
+        })
+        .collect()
+}
+
+/// X-bar and range chart for subgroups of 2..=10.
+pub fn xbar_r(subgroups: &[Vec<f64>], baseline: Option<usize>) -> Result<ChartComputation, String> {
+    let n = uniform_subgroup_size(subgroups)?;
+    if !(2..=MAX_RANGE_SUBGROUP).contains(&n) {
+        return Err(format!(
+            "X-bar/R charts need subgroups of 2..={} (got {}); use X-bar/S",
+            MAX_RANGE_SUBGROUP, n
+        ));
+    }
+    let means: Vec<f64> = subgroups.iter().map(|s| mean(s)).collect();
+    let ranges: Vec<f64> = subgroups.iter().map(|s| range(s)).collect();
+    let k = baseline_len(baseline, subgroups.len());
+    let grand_mean = mean(&means[..k]);
+    let r_bar = mean(&ranges[..k]);
+    let i = n - 2;
+
+    let location = ControlLimits {
+        center_line: grand_mean,
+        ucl: grand_mean + A2[i] * r_bar,
+        lcl: grand_mean - A2[i] * r_bar,
+    };
+    let dispersion = ControlLimits {
+        center_line: r_bar,
+        ucl: D4[i] * r_bar,
+        lcl: D3[i] * r_bar,
+    };
+    let disp: Vec<Option<f64>> = ranges.into_iter().map(Some).collect();
+    Ok(ChartComputation {
+        chart_type: ChartType::XbarR,
+        location,
+        dispersion: Some(dispersion),
+        sigma_within: Some(r_bar / D2[i]),
+        points: flat_points(&means, &disp, location),
+    })
+}
+
+/// X-bar and standard deviation chart for subgroups of 2 or more.
+pub fn xbar_s(subgroups: &[Vec<f64>], baseline: Option<usize>) -> Result<ChartComputation, String> {
+    let n = uniform_subgroup_size(subgroups)?;
+    if n < 2 {
+        return Err("X-bar/S charts need subgroups of at least 2".into());
+    }
+    let means: Vec<f64> = subgroups.iter().map(|s| mean(s)).collect();
+    let sds: Vec<f64> = subgroups.iter().map(|s| std_dev(s)).collect();
+    let k = baseline_len(baseline, subgroups.len());
+    let grand_mean = mean(&means[..k]);
+    let s_bar = mean(&sds[..k]);
+
+    let c4 = c4(n);
+    let a3 = 3.0 / (c4 * (n as f64).sqrt());
+    let spread = 3.0 * (1.0 - c4 * c4).sqrt() / c4;
+    let location = ControlLimits {
+        center_line: grand_mean,
+        ucl: grand_mean + a3 * s_bar,
+        lcl: grand_mean - a3 * s_bar,
+    };
+    let dispersion = ControlLimits {
+        center_line: s_bar,
+        ucl: (1.0 + spread) * s_bar,
+        lcl: (1.0 - spread).max(0.0) * s_bar,
+    };
+    let disp: Vec<Option<f64>> = sds.into_iter().map(Some).collect();
+    Ok(ChartComputation {
+        chart_type: ChartType::XbarS,
+        location,
+        dispersion: Some(dispersion),
+        sigma_within: Some(s_bar / c4),
+        points: flat_points(&means, &disp, location),
+    })
+}
+
+/// Individuals and moving range (span 2) chart.
+pub fn individuals(values: &[f64], baseline: Option<usize>) -> Result<ChartComputation, String> {
+    if values.len() < 2 {
+        return Err("I-MR charts need at least 2 individual values".into());
+    }
+    let moving: Vec<Option<f64>> = std::iter::once(None)
+        .chain(values.windows(2).map(|w| Some((w[1] - w[0]).abs())))
+        .collect();
+    let k = baseline_len(baseline, values.len());
+    let x_bar = mean(&values[..k]);
+    let mr: Vec<f64> = moving[1..k].iter().flatten().cloned().collect();
+    let mr_bar = mean(&mr);
+    let sigma = mr_bar / D2[0];
+
+    let location = ControlLimits {
+        center_line: x_bar,
+        ucl: x_bar + 3.0 * sigma,
+        lcl: x_bar - 3.0 * sigma,
+    };
+    let dispersion = ControlLimits {
+        center_line: mr_bar,
+        ucl: D4[0] * mr_bar,
+        lcl: 0.0,
+    };
+    Ok(ChartComputation {
+        chart_type: ChartType::IMr,
+        location,
+        dispersion: Some(dispersion),
+        sigma_within: Some(sigma),
+        points: flat_points(values, &moving, location),
+    })
+}
+
+fn validate_attribute_samples(samples: &[AttributeSample]) -> Result<(), String> {
+    if samples.is_empty() {
+        return Err("at least one subgroup is required".into());
+    }
+    if samples
+        .iter()
+        .any(|s| s.inspected < 1 || s.nonconforming < 0 || s.nonconforming > s.inspected)
+    {
+        return Err(
+            "each subgroup needs 0 <= nonconforming <= inspected and inspected >= 1".into(),
+        );
+    }
+    Ok(())
+}
+
+/// Fraction-nonconforming chart; limits vary with each subgroup's size.
+pub fn p_chart(
+    samples: &[AttributeSample],
+    baseline: Option<usize>,
+) -> Result<ChartComputation, String> {
+    validate_attribute_samples(samples)?;
+    let k = baseline_len(baseline, samples.len());
+    let defects: i64 = samples[..k].iter().map(|s| s.nonconforming).sum();
+    let inspected: i64 = samples[..k].iter().map(|s| s.inspected).sum();
+    let p_bar = defects as f64 / inspected as f64;
+    let limits_at = |n: f64| {
+        let sigma = (p_bar * (1.0 - p_bar) / n).sqrt();
+        (
+            (p_bar + 3.0 * sigma).min(1.0),
+            (p_bar - 3.0 * sigma).max(0.0),
+        )
+    };
+
+    let points = samples
+        .iter()
+        .map(|s| {
+            let (ucl, lcl) = limits_at(s.inspected as f64);
+            ChartPoint {
+                value: s.nonconforming as f64 / s.inspected as f64,
+                ucl,
+                lcl,
+                dispersion: None,
+            }
+        })
+        .collect();
+    let avg_n = inspected as f64 / k as f64;
+    let (ucl, lcl) = limits_at(avg_n);
+    Ok(ChartComputation {
+        chart_type: ChartType::P,
+        location: ControlLimits {
+            center_line: p_bar,
+            ucl,
+            lcl,
+        },
+        dispersion: None,
+        sigma_within: None,
+        points,
+    })
+}
+
+/// Number-nonconforming chart for a constant subgroup size.
+pub fn np_chart(
+    samples: &[AttributeSample],
+    baseline: Option<usize>,
+) -> Result<ChartComputation, String> {
+    validate_attribute_samples(samples)?;
+    let n = samples[0].inspected;
+    if samples.iter().any(|s| s.inspected != n) {
+        return Err("np charts need a constant subgroup size; use a p chart".into());
+    }
+    let k = baseline_len(baseline, samples.len());
+    let defects: i64 = samples[..k].iter().map(|s| s.nonconforming).sum();
+    let p_bar = defects as f64 / (n * k as i64) as f64;
+    let center = n as f64 * p_bar;
+    let sigma = (center * (1.0 - p_bar)).sqrt();
+    let location = ControlLimits {
+        center_line: center,
+        ucl: (center + 3.0 * sigma).min(n as f64),
+        lcl: (center - 3.0 * sigma).max(0.0),
+    };
+    let values: Vec<f64> = samples.iter().map(|s| s.nonconforming as f64).collect();
+    Ok(ChartComputation {
+        chart_type: ChartType::Np,
+        location,
+        dispersion: None,
+        sigma_within: None,
+        points: flat_points(&values, &vec![None; values.len()], location),
+    })
+}
+
+// ============================================================================
+// Run rules
+// ============================================================================
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ChartSection {
+    Location,
+    Dispersion,
+}
+
+impl ChartSection {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Location => "location",
+            Self::Dispersion => "dispersion",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, ToSchema)]
+pub struct RuleViolation {
+    /// `we1`..`we4` or `nelson1`..`nelson8`.
+    pub rule: String,
+    pub section: ChartSection,
+    /// Index of the point that completes the pattern.
+    pub index: usize,
+    pub value: f64,
+    pub description: String,
+}
+
+/// Distance from the center line in sigma units, using the point's own limits.
+fn z_score(point: &ChartPoint, center: f64) -> f64 {
+    let sigma = (point.ucl - center) / 3.0;
+    if sigma > 0.0 {
+        (point.value - center) / sigma
+    } else if point.value > center {
+        f64::INFINITY
+    } else if point.value < center {
+        f64::NEG_INFINITY
+    } else {
+        0.0
+    }
+}
+
+/// `count` of the last `window` points beyond `sigma` on the same side as the
+/// newest point, which must itself be beyond.
+fn k_of_m_beyond(z: &[f64], i: usize, window: usize, count: usize, sigma: f64) -> bool {
+    if i + 1 < window {
+        return false;
+    }
+    let side = z[i].signum();
+    if z[i].abs() <= sigma {
+        return false;
+    }
+    z[i + 1 - window..=i]
+        .iter()
+        .filter(|v| v.signum() == side && v.abs() > sigma)
+        .count()
+        >= count
+}
+
+fn run_same_side(z: &[f64], i: usize, len: usize) -> bool {
+    if i + 1 < len || z[i] == 0.0 {
+        return false;
+    }
+    let side = z[i].signum();
+    z[i + 1 - len..=i]
+        .iter()
+        .all(|v| *v != 0.0 && v.signum() == side)
+}
+
+fn trend(values: &[f64], i: usize, len: usize) -> bool {
+    if i + 1 < len {
+        return false;
+    }
+    let w = &values[i + 1 - len..=i];
+    w.windows(2).all(|p| p[1] > p[0]) || w.windows(2).all(|p| p[1] < p[0])
+}
+
+fn alternating(values: &[f64], i: usize, len: usize) -> bool {
+    if i + 1 < len {
+        return false;
+    }
+    let diffs: Vec<f64> = values[i + 1 - len..=i]
+        .windows(2)
+        .map(|p| p[1] - p[0])
+        .collect();
+    diffs.iter().all(|d| *d != 0.0) && diffs.windows(2).all(|d| d[0].signum() != d[1].signum())
+}
+
+fn rule_definitions(rule_set: RuleSet) -> Vec<(&'static str, &'static str)> {
+    match rule_set {
+        RuleSet::WesternElectric => vec![
+            ("we1", "1 point beyond 3 sigma"),
+            ("we2", "2 of 3 points beyond 2 sigma on the same side"),
+            ("we3", "4 of 5 points beyond 1 sigma on the same side"),
+            (
+                "we4",
+                "8 points in a row on the same side of the center line",
+            ),
+        ],
+        RuleSet::Nelson => vec![
+            ("nelson1", "1 point beyond 3 sigma"),
+            (
+                "nelson2",
+                "9 points in a row on the same side of the center line",
+            ),
+            (
+                "nelson3",
+                "6 points in a row steadily increasing or decreasing",
+            ),
+            ("nelson4", "14 points in a row alternating up and down"),
+            ("nelson5", "2 of 3 points beyond 2 sigma on the same side"),
+            ("nelson6", "4 of 5 points beyond 1 sigma on the same side"),
+            (
+                "nelson7",
+                "15 points in a row within 1 sigma of the center line",
+            ),
+            ("nelson8", "8 points in a row beyond 1 sigma on either side"),
+        ],
+    }
+}
+
+fn rule_fires(rule: &str, points: &[ChartPoint], values: &[f64], z: &[f64], i: usize) -> bool {
+    match rule {
+        "we1" | "nelson1" => points[i].value > points[i].ucl || points[i].value < points[i].lcl,
+        "we2" | "nelson5" => k_of_m_beyond(z, i, 3, 2, 2.0),
+        "we3" | "nelson6" => k_of_m_beyond(z, i, 5, 4, 1.0),
+        "we4" => run_same_side(z, i, 8),
+        "nelson2" => run_same_side(z, i, 9),
+        "nelson3" => trend(values, i, 6),
+        "nelson4" => alternating(values, i, 14),
+        "nelson7" => i + 1 >= 15 && z[i + 1 - 15..=i].iter().all(|v| v.abs() < 1.0),
+        "nelson8" => {
+            i + 1 >= 8 && {
+                let w = &z[i + 1 - 8..=i];
+                w.iter().all(|v| v.abs() > 1.0)
+                    && w.iter().any(|v| *v > 0.0)
+                    && w.iter().any(|v| *v < 0.0)
+            }
+        }
+        _ => false,
+    }
+}
+
+/// Evaluate the rule set on the location chart and the 3-sigma limits on the
+/// dispersion chart. Every point that completes a pattern is reported.
+pub fn detect_violations(chart: &ChartComputation, rule_set: RuleSet) -> Vec<RuleViolation> {
+    let center = chart.location.center_line;
+    let values: Vec<f64> = chart.points.iter().map(|p| p.value).collect();
+    let z: Vec<f64> = chart.points.iter().map(|p| z_score(p, center)).collect();
+    let rules = rule_definitions(rule_set);
+    let mut violations = Vec::new();
+
+    for i in 0..chart.points.len() {
+        for (rule, description) in &rules {
+            if rule_fires(rule, &chart.points, &values, &z, i) {
+                violations.push(RuleViolation {
+                    rule: rule.to_string(),
+                    section: ChartSection::Location,
+                    index: i,
+                    value: chart.points[i].value,
+                    description: description.to_string(),
+                });
+            }
+        }
+        if let (Some(limits), Some(d)) = (chart.dispersion, chart.points[i].dispersion) {
+            if d > limits.ucl || d < limits.lcl {
+                violations.push(RuleViolation {
+                    rule: rules[0].0.to_string(),
+                    section: ChartSection::Dispersion,
+                    index: i,
+                    value: d,
+                    description: "dispersion beyond control limits".to_string(),
+                });
+            }
+        }
+    }
+    violations
+}
+
+// ============================================================================
+// Capability
+// ============================================================================
+
+#[derive(Debug, Clone, PartialEq, Serialize, ToSchema)]
+pub struct Capability {
+    pub lsl: Option<f64>,
+    pub usl: Option<f64>,
+    pub mean: f64,
+    pub sigma_within: f64,
+    pub sigma_overall: f64,
+    pub observations: usize,
+    /// Needs both specification limits.
+    pub cp: Option<f64>,
+    pub cpu: Option<f64>,
+    pub cpl: Option<f64>,
+    /// Worst of Cpu and Cpl; one-sided when only one limit is set.
+    pub cpk: Option<f64>,
+    pub pp: Option<f64>,
+    pub ppk: Option<f64>,
+}
+
+fn indices(mean: f64, sigma: f64, lsl: Option<f64>, usl: Option<f64>) -> [Option<f64>; 4] {
+    if sigma <= 0.0 {
+        return [None; 4];
+    }
+    let p = match (lsl, usl) {
+        (Some(l), Some(u)) => Some((u - l) / (6.0 * sigma)),
+        _ => None,
+    };
+    let upper = usl.map(|u| (u - mean) / (3.0 * sigma));
+    let lower = lsl.map(|l| (mean - l) / (3.0 * sigma));
+    let k = match (upper, lower) {
+        (Some(u), Some(l)) => Some(u.min(l)),
+        (u, l) => u.or(l),
+    };
+    [p, upper, lower, k]
+}
+
+/// Process capability against specification limits. Returns `None` without
+/// any limit or with fewer than 2 observations.
+pub fn capability(
+    values: &[f64],
+    sigma_within: f64,
+    lsl: Option<f64>,
+    usl: Option<f64>,
+) -> Option<Capability> {
+    if values.len() < 2 || (lsl.is_none() && usl.is_none()) {
+        return None;
+    }
+    let m = mean(values);
+    let sigma_overall = std_dev(values);
+    let [cp, cpu, cpl, cpk] = indices(m, sigma_within, lsl, usl);
+    let [pp, _, _, ppk] = indices(m, sigma_overall, lsl, usl);
+    Some(Capability {
+        lsl,
+        usl,
+        mean: m,
+        sigma_within,
+        sigma_overall,
+        observations: values.len(),
+        cp,
+        cpu,
+        cpl,
+        cpk,
+        pp,
+        ppk,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn close(a: f64, b: f64) -> bool {
+        (a - b).abs() < 1e-3
+    }
+
+    fn individuals_chart(values: &[f64]) -> ChartComputation {
+        // Center 10, sigma = MR̄/d2 ≈ 0.89: limits fixed by a baseline
+        // alternating 9.5 / 10.5.
+        let mut all: Vec<f64> = (0..20)
+            .map(|i| if i % 2 == 0 { 9.5 } else { 10.5 })
+            .collect();
+        all.extend_from_slice(values);
+        let mut chart = individuals(&all, Some(20)).expect("chart");
+        chart.points.drain(..20);
+        chart
+    }
+
+    fn rules(chart: &ChartComputation, set: RuleSet) -> Vec<(String, usize)> {
+        detect_violations(chart, set)
+            .into_iter()
+            .filter(|v| v.section == ChartSection::Location)
+            .map(|v| (v.rule, v.index))
+            .collect()
+    }
+
+    #[test]
+    fn c4_matches_published_constants() {
+        assert!(close(c4(2), 0.7979));
+        assert!(close(c4(5), 0.9400));
+        assert!(close(c4(10), 0.9727));
+        assert!(close(c4(25), 0.9896));
+    }
+
+    #[test]
+    fn xbar_r_limits() {
+        let subgroups = vec![
+            vec![10.0, 10.2, 9.8, 10.1, 9.9],
+            vec![10.1, 10.3, 9.9, 10.0, 10.2],
+            vec![9.9, 10.0, 9.8, 10.1, 10.0],
+        ];
+        let chart = xbar_r(&subgroups, None).expect("chart");
+        let r_bar = (0.4 + 0.4 + 0.3) / 3.0;
+        let grand = (10.0 + 10.1 + 9.96) / 3.0;
+        assert!(close(chart.location.center_line, grand));
+        assert!(close(chart.location.ucl, grand + 0.577 * r_bar));
+        assert!(close(chart.dispersion.unwrap().ucl, 2.114 * r_bar));
+        assert!(close(chart.dispersion.unwrap().lcl, 0.0));
+        assert!(close(chart.sigma_within.unwrap(), r_bar / 2.326));
+        assert_eq!(chart.points.len(), 3);
+
+        assert!(xbar_r(&[vec![1.0; 12]], None).is_err());
+        assert!(xbar_r(&[vec![1.0, 2.0], vec![1.0]], None).is_err());
+    }
+
+    #[test]
+    fn xbar_s_uses_c4_constants() {
+        let subgroups = vec![vec![1.0, 2.0, 3.0, 4.0, 5.0], vec![2.0, 3.0, 4.0, 5.0, 6.0]];
+        let chart = xbar_s(&subgroups, None).expect("chart");
+        let s_bar = std_dev(&[1.0, 2.0, 3.0, 4.0, 5.0]);
+        // A3 = 1.427, B4 = 2.089, B3 = 0 for n = 5
+        assert!(close(
+            chart.location.ucl - chart.location.center_line,
+            1.427 * s_bar
+        ));
+        assert!(close(chart.dispersion.unwrap().ucl, 2.089 * s_bar));
+        assert!(close(chart.dispersion.unwrap().lcl, 0.0));
+    }
+
+    #[test]
+    fn individuals_use_moving_range() {
+        let chart = individuals(&[10.0, 12.0, 11.0, 13.0], None).expect("chart");
+        let mr_bar = (2.0 + 1.0 + 2.0) / 3.0;
+        assert!(close(chart.location.center_line, 11.5));
+        assert!(close(chart.location.ucl, 11.5 + 2.66 * mr_bar));
+        assert!(close(chart.dispersion.unwrap().ucl, 3.267 * mr_bar));
+        assert_eq!(chart.points[0].dispersion, None);
+        assert_eq!(chart.points[1].dispersion, Some(2.0));
+    }
+
+    #[test]
+    fn baseline_fixes_limits() {
+        let values = [10.0, 10.5, 9.5, 10.0, 20.0];
+        let all = individuals(&values, None).unwrap();
+        let phase1 = individuals(&values, Some(4)).unwrap();
+        assert!(close(phase1.location.center_line, 10.0));
+        assert!(phase1.location.center_line < all.location.center_line);
+        assert_eq!(phase1.points.len(), 5);
+    }
+
+    #[test]
+    fn p_chart_limits_vary_with_n() {
+        let samples = [
+            AttributeSample {
+                nonconforming: 5,
+                inspected: 100,
+            },
+            AttributeSample {
+                nonconforming: 10,
+                inspected: 200,
+            },
+            AttributeSample {
+                nonconforming: 3,
+                inspected: 50,
+            },
+        ];
+        let chart = p_chart(&samples, None).expect("chart");
+        let p_bar = 18.0 / 350.0;
+        assert!(close(chart.location.center_line, p_bar));
+        let ucl_100 = p_bar + 3.0 * (p_bar * (1.0 - p_bar) / 100.0).sqrt();
+        assert!(close(chart.points[0].ucl, ucl_100));
+        assert!(chart.points[2].ucl > chart.points[1].ucl);
+        assert!(close(chart.points[0].lcl, 0.0));
+    }
+
+    #[test]
+    fn np_chart_requires_constant_n() {
+        let samples = [
+            AttributeSample {
+                nonconforming: 2,
+                inspected: 50,
+            },
+            AttributeSample {
+                nonconforming: 4,
+                inspected: 50,
+            },
+        ];
+        let chart = np_chart(&samples, None).expect("chart");
+        assert!(close(chart.location.center_line, 3.0));
+        let sigma = (3.0_f64 * (1.0 - 0.06)).sqrt();
+        assert!(close(chart.location.ucl, 3.0 + 3.0 * sigma));
+
+        let mixed = [
+            AttributeSample {
+                nonconforming: 2,
+                inspected: 50,
+            },
+            AttributeSample {
+                nonconforming: 4,
+                inspected: 40,
+            },
+        ];
+        assert!(np_chart(&mixed, None).is_err());
+    }
+
+    #[test]
+    fn rule_one_flags_point_beyond_limits() {
+        let chart = individuals_chart(&[10.0, 13.5, 10.0]);
+        assert_eq!(
+            rules(&chart, RuleSet::WesternElectric),
+            vec![("we1".into(), 1)]
+        );
+    }
+
+    #[test]
+    fn western_electric_zone_rules() {
+        // 2 of 3 beyond 2 sigma (upper side)
+        let chart = individuals_chart(&[12.5, 10.0, 12.5]);
+        assert!(rules(&chart, RuleSet::WesternElectric).contains(&("we2".into(), 2)));
+
+        // 4 of 5 beyond 1 sigma (lower side)
+        let chart = individuals_chart(&[8.5, 8.5, 10.2, 8.5, 8.5]);
+        assert!(rules(&chart, RuleSet::WesternElectric).contains(&("we3".into(), 4)));
+
+        // 8 in a row above center
+        let chart = individuals_chart(&[10.2; 8]);
+        let found = rules(&chart, RuleSet::WesternElectric);
+        assert!(found.contains(&("we4".into(), 7)));
+        assert!(!found.iter().any(|(r, i)| r == "we4" && *i < 7));
+    }
+
+    #[test]
+    fn nelson_rules() {
+        // Nelson 2 needs 9 in a row, not 8
+        let chart = individuals_chart(&[10.2; 8]);
+        assert!(!rules(&chart, RuleSet::Nelson)
+            .iter()
+            .any(|(r, _)| r == "nelson2"));
+        let chart = individuals_chart(&[10.2; 9]);
+        assert!(rules(&chart, RuleSet::Nelson).contains(&("nelson2".into(), 8)));
+
+        // 6 increasing
+        let chart = individuals_chart(&[9.0, 9.2, 9.4, 9.6, 9.8, 10.0]);
+        assert!(rules(&chart, RuleSet::Nelson).contains(&("nelson3".into(), 5)));
+
+        // 14 alternating
+        let alt: Vec<f64> = (0..14)
+            .map(|i| if i % 2 == 0 { 9.8 } else { 10.2 })
+            .collect();
+        let chart = individuals_chart(&alt);
+        assert!(rules(&chart, RuleSet::Nelson).contains(&("nelson4".into(), 13)));
+
+        // 15 hugging the center line
+        let hug: Vec<f64> = (0..15)
+            .map(|i| if i % 3 == 0 { 10.1 } else { 9.9 })
+            .collect();
+        let chart = individuals_chart(&hug);
+        assert!(rules(&chart, RuleSet::Nelson).contains(&("nelson7".into(), 14)));
+
+        // 8 avoiding the center zone on both sides
+        let mix = [11.5, 8.5, 11.5, 8.5, 8.6, 11.4, 8.5, 11.5];
+        let chart = individuals_chart(&mix);
+        assert!(rules(&chart, RuleSet::Nelson).contains(&("nelson8".into(), 7)));
+    }
+
+    #[test]
+    fn dispersion_chart_is_checked() {
+        let mut subgroups = vec![vec![10.0, 10.1, 9.9]; 10];
+        subgroups.push(vec![8.0, 12.0, 10.0]);
+        let chart = xbar_r(&subgroups, Some(10)).expect("chart");
+        let found = detect_violations(&chart, RuleSet::WesternElectric);
+        assert!(found
+            .iter()
+            .any(|v| v.section == ChartSection::Dispersion && v.index == 10));
+    }
+
+    #[test]
+    fn capability_indices() {
+        let values = [9.9, 10.0, 10.1, 10.0, 9.95, 10.05];
+        let cap = capability(&values, 0.05, Some(9.7), Some(10.3)).expect("capability");
+        assert!(close(cap.cp.unwrap(), 0.6 / 0.3));
+        assert!(close(cap.cpk.unwrap(), 2.0));
+        assert!(cap.pp.unwrap() < cap.cp.unwrap() + 1.0);
+
+        // One-sided: only USL
+        let cap = capability(&values, 0.05, None, Some(10.15)).expect("capability");
+        assert_eq!(cap.cp, None);
+        assert_eq!(cap.cpl, None);
+        assert!(close(cap.cpk.unwrap(), 0.15 / 0.15));
+
+        assert!(capability(&values, 0.05, None, None).is_none());
+        assert!(capability(&[10.0], 0.05, Some(9.0), Some(11.0)).is_none());
+    }
+}
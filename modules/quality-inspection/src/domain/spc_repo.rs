@@ -0,0 +1,232 @@
+use chrono::{DateTime, Utc};
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::domain::models::*;
+use crate::domain::service::QiError;
+
+/// A measurement joined with the in-process inspection it belongs to.
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct ChartMeasurementRow {
+    pub inspection_id: Uuid,
+    pub wo_id: Option<Uuid>,
+    pub subgroup_at: DateTime<Utc>,
+    pub sample_index: i32,
+    pub value: Option<f64>,
+    pub conforming: bool,
+}
+
+// ============================================================================
+// Charts
+// ============================================================================
+
+pub async fn insert_chart(
+    pool: &PgPool,
+    tenant_id: &str,
+    part_id: Uuid,
+    req: &CreateSpcChartRequest,
+    rule_set: &str,
+    characteristic: &Characteristic,
+) -> Result<SpcChart, QiError> {
+    let inserted = sqlx::query_as::<_, SpcChart>(
+        r#"
+        INSERT INTO spc_charts
+            (tenant_id, part_id, plan_id, characteristic, workcenter_id, chart_type,
+             subgroup_size, rule_set, baseline_subgroups, nominal, lsl, usl,
+             hold_on_violation)
+        VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13)
+        RETURNING *
+        "#,
+    )
+    .bind(tenant_id)
+    .bind(part_id)
+    .bind(req.plan_id)
+    .bind(&characteristic.name)
+    .bind(req.workcenter_id)
+    .bind(&req.chart_type)
+    .bind(req.subgroup_size)
+    .bind(rule_set)
+    .bind(req.baseline_subgroups)
+    .bind(characteristic.nominal)
+    .bind(characteristic.tolerance_low)
+    .bind(characteristic.tolerance_high)
+    .bind(req.hold_on_violation.unwrap_or(true))
+    .fetch_one(pool)
+    .await;
+
+    match inserted {
+        Ok(chart) => Ok(chart),
+        Err(sqlx::Error::Database(db)) if db.is_unique_violation() => {
+            Err(QiError::Validation(format!(
+                "A {} chart for '{}' already exists for this part and workcenter",
+                req.chart_type, characteristic.name
+            )))
+        }
+        Err(e) => Err(e.into()),
+    }
+}
+
+pub async fn get_chart(
+    pool: &PgPool,
+    tenant_id: &str,
+    chart_id: Uuid,
+) -> Result<Option<SpcChart>, QiError> {
+    let row =
+        sqlx::query_as::<_, SpcChart>("SELECT * FROM spc_charts WHERE id = $1 AND tenant_id = $2")
+            .bind(chart_id)
+            .bind(tenant_id)
+            .fetch_optional(pool)
+            .await?;
+    Ok(row)
+}
+
+pub async fn list_charts(
+    pool: &PgPool,
+    tenant_id: &str,
+    part_id: Option<Uuid>,
+    workcenter_id: Option<Uuid>,
+) -> Result<Vec<SpcChart>, QiError> {
+    let rows = sqlx::query_as::<_, SpcChart>(
+        r#"
+        SELECT * FROM spc_charts
+        WHERE tenant_id = $1
+          AND ($2::UUID IS NULL OR part_id = $2)
+          AND ($3::UUID IS NULL OR workcenter_id = $3)
+        ORDER BY created_at
+        "#,
+    )
+    .bind(tenant_id)
+    .bind(part_id)
+    .bind(workcenter_id)
+    .fetch_all(pool)
+    .await?;
+    Ok(rows)
+}
+
+/// Active charts that an in-process inspection's measurements feed.
+pub async fn active_charts_for(
+    tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+    tenant_id: &str,
+    part_id: Uuid,
+    characteristics: &[String],
+    workcenter_id: Option<Uuid>,
+) -> Result<Vec<SpcChart>, QiError> {
+    let rows = sqlx::query_as::<_, SpcChart>(
+        r#"
+        SELECT * FROM spc_charts
+        WHERE tenant_id = $1 AND part_id = $2 AND active
+          AND characteristic = ANY($3)
+          AND (workcenter_id IS NULL OR workcenter_id = $4)
+        ORDER BY created_at
+        "#,
+    )
+    .bind(tenant_id)
+    .bind(part_id)
+    .bind(characteristics)
+    .bind(workcenter_id)
+    .fetch_all(&mut **tx)
+    .await?;
+    Ok(rows)
+}
+
+/// Every in-process measurement the chart plots, oldest subgroup first.
+pub async fn chart_measurements<'e, E>(
+    executor: E,
+    chart: &SpcChart,
+    to: Option<DateTime<Utc>>,
+) -> Result<Vec<ChartMeasurementRow>, QiError>
+where
+    E: sqlx::PgExecutor<'e>,
+{
+    let rows = sqlx::query_as::<_, ChartMeasurementRow>(
+        r#"
+        SELECT i.id AS inspection_id, i.wo_id, i.created_at AS subgroup_at,
+               m.sample_index, m.value, m.conforming
+        FROM inspection_measurements m
+        JOIN inspections i ON i.id = m.inspection_id
+        LEFT JOIN inspection_plans p ON p.id = i.plan_id
+        WHERE m.tenant_id = $1 AND i.tenant_id = $1
+          AND i.inspection_type = 'in_process'
+          AND COALESCE(i.part_id, p.part_id) = $2
+          AND m.characteristic = $3
+          AND ($4::UUID IS NULL OR i.workcenter_id = $4)
+          AND ($5::TIMESTAMPTZ IS NULL OR i.created_at <= $5)
+        ORDER BY i.created_at, i.id, m.sample_index
+        "#,
+    )
+    .bind(&chart.tenant_id)
+    .bind(chart.part_id)
+    .bind(&chart.characteristic)
+    .bind(chart.workcenter_id)
+    .bind(to)
+    .fetch_all(executor)
+    .await?;
+    Ok(rows)
+}
+
+// ============================================================================
+// Violations
+// ============================================================================
+
+/// Record a violation; `None` when it was already recorded for this
+/// chart/inspection/rule/section.
+pub async fn insert_violation(
+    tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+    chart: &SpcChart,
+    inspection_id: Uuid,
+    wo_id: Option<Uuid>,
+    rule: &str,
+    section: &str,
+    value: f64,
+    description: &str,
+    hold_requested: bool,
+) -> Result<Option<SpcViolation>, QiError> {
+    let row = sqlx::query_as::<_, SpcViolation>(
+        r#"
+        INSERT INTO spc_violations
+            (tenant_id, chart_id, inspection_id, wo_id, rule, section, value,
+             description, hold_requested)
+        VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
+        ON CONFLICT (chart_id, inspection_id, rule, section) DO NOTHING
+        RETURNING *
+        "#,
+    )
+    .bind(&chart.tenant_id)
+    .bind(chart.id)
+    .bind(inspection_id)
+    .bind(wo_id)
+    .bind(rule)
+    .bind(section)
+    .bind(value)
+    .bind(description)
+    .bind(hold_requested)
+    .fetch_optional(&mut **tx)
+    .await?;
+    Ok(row)
+}
+
+pub async fn list_violations(
+    pool: &PgPool,
+    tenant_id: &str,
+    chart_id: Option<Uuid>,
+    wo_id: Option<Uuid>,
+    limit: i64,
+) -> Result<Vec<SpcViolation>, QiError> {
+    let rows = sqlx::query_as::<_, SpcViolation>(
+        r#"
+        SELECT * FROM spc_violations
+        WHERE tenant_id = $1
+          AND ($2::UUID IS NULL OR chart_id = $2)
+          AND ($3::UUID IS NULL OR wo_id = $3)
+        ORDER BY detected_at DESC
+        LIMIT $4
+        "#,
+    )
+    .bind(tenant_id)
+    .bind(chart_id)
+    .bind(wo_id)
+    .bind(limit)
+    .fetch_all(pool)
+    .await?;
+    Ok(rows)
+}
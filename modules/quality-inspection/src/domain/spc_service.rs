@@ -0,0 +1,443 @@
+//! Statistical process control over in-process inspection measurements.
+//!
+//! Charts are defined per plan characteristic, optionally scoped to a
+//! workcenter. Each in-process inspection contributes one subgroup (X-bar/R,
+//! X-bar/S, p, np) or one point per sample unit (I-MR).
+//!
+//! Recording measurements on an in-process inspection re-runs the run rules of
+//! every chart it feeds, in the same transaction. New violations are stored
+//! and announced on `quality_inspection.spc_violation_detected`, which
+//! shop-floor-gates turns into a quality hold on the work order.
+
+use chrono::{DateTime, Utc};
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::domain::models::*;
+use crate::domain::outbox::enqueue_event;
+use crate::domain::service::{get_inspection_plan, QiError};
+use crate::domain::spc::{self, AttributeSample, Capability, ChartComputation, ChartType, RuleSet};
+use crate::domain::spc_repo::{self as repo, ChartMeasurementRow};
+use crate::events::{self, QualityInspectionEventType, SpcViolationDetectedPayload};
+
+const DEFAULT_POINT_LIMIT: i64 = 100;
+const MAX_POINT_LIMIT: i64 = 1000;
+
+struct PointSource {
+    inspection_id: Uuid,
+    wo_id: Option<Uuid>,
+    recorded_at: DateTime<Utc>,
+    /// Measured values behind the point (variables charts only).
+    values: Vec<f64>,
+}
+
+struct AssembledChart {
+    computation: ChartComputation,
+    sources: Vec<PointSource>,
+}
+
+fn chart_kind(chart: &SpcChart) -> ChartType {
+    // The table CHECK constraint only admits known chart types.
+    ChartType::parse(&chart.chart_type).unwrap_or(ChartType::IMr)
+}
+
+fn chart_rule_set(chart: &SpcChart) -> RuleSet {
+    RuleSet::parse(&chart.rule_set).unwrap_or(RuleSet::WesternElectric)
+}
+
+fn source(row: &ChartMeasurementRow, values: Vec<f64>) -> PointSource {
+    PointSource {
+        inspection_id: row.inspection_id,
+        wo_id: row.wo_id,
+        recorded_at: row.subgroup_at,
+        values,
+    }
+}
+
+/// Group measurement rows into the chart's points and compute its limits.
+/// `None` until there is enough data. Inspections with fewer than
+/// `subgroup_size` measurements are left off fixed-size charts.
+fn assemble(chart: &SpcChart, rows: &[ChartMeasurementRow]) -> Option<AssembledChart> {
+    let kind = chart_kind(chart);
+    let n = chart.subgroup_size.unwrap_or(0).max(0) as usize;
+    let baseline = chart.baseline_subgroups.map(|b| b.max(0) as usize);
+    let subgroups = rows.chunk_by(|a, b| a.inspection_id == b.inspection_id);
+    let mut sources = Vec::new();
+
+    let computation = match kind {
+        ChartType::XbarR | ChartType::XbarS => {
+            let mut values_by_subgroup = Vec::new();
+            for group in subgroups {
+                let values: Vec<f64> = group.iter().filter_map(|r| r.value).take(n).collect();
+                if values.len() < n {
+                    continue;
+                }
+                sources.push(source(&group[0], values.clone()));
+                values_by_subgroup.push(values);
+            }
+            if values_by_subgroup.is_empty() {
+                return None;
+            }
+            if kind == ChartType::XbarR {
+                spc::xbar_r(&values_by_subgroup, baseline)
+            } else {
+                spc::xbar_s(&values_by_subgroup, baseline)
+            }
+        }
+        ChartType::IMr => {
+            for row in rows {
+                if let Some(v) = row.value {
+                    sources.push(source(row, vec![v]));
+                }
+            }
+            let values: Vec<f64> = sources.iter().map(|s| s.values[0]).collect();
+            spc::individuals(&values, baseline)
+        }
+        ChartType::P | ChartType::Np => {
+            let mut samples = Vec::new();
+            for group in subgroups {
+                let units = if kind == ChartType::Np {
+                    if group.len() < n {
+                        continue;
+                    }
+                    &group[..n]
+                } else {
+                    group
+                };
+                samples.push(AttributeSample {
+                    nonconforming: units.iter().filter(|r| !r.conforming).count() as i64,
+                    inspected: units.len() as i64,
+                });
+                sources.push(source(&group[0], Vec::new()));
+            }
+            if samples.is_empty() {
+                return None;
+            }
+            if kind == ChartType::P {
+                spc::p_chart(&samples, baseline)
+            } else {
+                spc::np_chart(&samples, baseline)
+            }
+        }
+    };
+
+    computation.ok().map(|computation| AssembledChart {
+        computation,
+        sources,
+    })
+}
+
+fn validate_subgroup_size(kind: ChartType, size: Option<i32>) -> Result<(), QiError> {
+    let allowed = match kind {
+        ChartType::XbarR => Some(2..=10),
+        ChartType::XbarS => Some(2..=25),
+        ChartType::Np => Some(2..=10_000),
+        ChartType::IMr | ChartType::P => None,
+    };
+    match (allowed, size) {
+        (None, None) => Ok(()),
+        (None, Some(_)) => Err(QiError::Validation(
+            "subgroup_size applies only to xbar_r, xbar_s and np charts".into(),
+        )),
+        (Some(range), Some(n)) if range.contains(&n) => Ok(()),
+        (Some(range), _) => Err(QiError::Validation(format!(
+            "{} charts need subgroup_size in {}..={}",
+            kind.as_str(),
+            range.start(),
+            range.end()
+        ))),
+    }
+}
+
+// ============================================================================
+// Charts
+// ============================================================================
+
+pub async fn create_spc_chart(
+    pool: &PgPool,
+    tenant_id: &str,
+    req: &CreateSpcChartRequest,
+) -> Result<SpcChart, QiError> {
+    let kind = ChartType::parse(&req.chart_type).ok_or_else(|| {
+        QiError::Validation(format!(
+            "Invalid chart_type '{}': expected xbar_r, xbar_s, i_mr, p or np",
+            req.chart_type
+        ))
+    })?;
+    let rule_set = match req.rule_set.as_deref() {
+        None => RuleSet::WesternElectric,
+        Some(s) => RuleSet::parse(s).ok_or_else(|| {
+            QiError::Validation(format!(
+                "Invalid rule_set '{}': expected western_electric or nelson",
+                s
+            ))
+        })?,
+    };
+    validate_subgroup_size(kind, req.subgroup_size)?;
+    if req.baseline_subgroups.is_some_and(|b| b < 2) {
+        return Err(QiError::Validation(
+            "baseline_subgroups must be at least 2".into(),
+        ));
+    }
+
+    let plan = get_inspection_plan(pool, tenant_id, req.plan_id).await?;
+    let characteristics: Vec<Characteristic> = serde_json::from_value(plan.characteristics)?;
+    let characteristic = characteristics
+        .iter()
+        .find(|c| c.name == req.characteristic)
+        .ok_or_else(|| {
+            QiError::Validation(format!(
+                "Characteristic '{}' is not on plan {}",
+                req.characteristic, req.plan_id
+            ))
+        })?;
+
+    repo::insert_chart(
+        pool,
+        tenant_id,
+        plan.part_id,
+        req,
+        rule_set.as_str(),
+        characteristic,
+    )
+    .await
+}
+
+pub async fn get_spc_chart(
+    pool: &PgPool,
+    tenant_id: &str,
+    chart_id: Uuid,
+) -> Result<SpcChart, QiError> {
+    repo::get_chart(pool, tenant_id, chart_id)
+        .await?
+        .ok_or_else(|| QiError::NotFound("SPC chart not found".into()))
+}
+
+pub async fn list_spc_charts(
+    pool: &PgPool,
+    tenant_id: &str,
+    part_id: Option<Uuid>,
+    workcenter_id: Option<Uuid>,
+) -> Result<Vec<SpcChart>, QiError> {
+    repo::list_charts(pool, tenant_id, part_id, workcenter_id).await
+}
+
+/// Chart points, limits, rule violations and capability for dashboards.
+///
+/// Limits and rules are computed over the full history up to `to`, so a
+/// narrow window still shows phase-I limits; only the returned points are cut
+/// to the `from` bound and the latest `limit` points.
+pub async fn get_spc_chart_data(
+    pool: &PgPool,
+    tenant_id: &str,
+    chart_id: Uuid,
+    from: Option<DateTime<Utc>>,
+    to: Option<DateTime<Utc>>,
+    limit: Option<i64>,
+) -> Result<SpcChartData, QiError> {
+    let chart = get_spc_chart(pool, tenant_id, chart_id).await?;
+    let limit = limit
+        .unwrap_or(DEFAULT_POINT_LIMIT)
+        .clamp(1, MAX_POINT_LIMIT) as usize;
+    let rows = repo::chart_measurements(pool, &chart, to).await?;
+
+    let Some(assembled) = assemble(&chart, &rows) else {
+        return Ok(SpcChartData {
+            chart,
+            location: None,
+            dispersion: None,
+            sigma_within: None,
+            points: Vec::new(),
+            violations: Vec::new(),
+            capability: None,
+        });
+    };
+    let computation = &assembled.computation;
+    let total = computation.points.len();
+    let start = assembled
+        .sources
+        .iter()
+        .position(|s| from.is_none_or(|f| s.recorded_at >= f))
+        .unwrap_or(total)
+        .max(total.saturating_sub(limit));
+
+    let points = (start..total)
+        .map(|i| {
+            let p = &computation.points[i];
+            let s = &assembled.sources[i];
+            SpcChartPoint {
+                index: i - start,
+                inspection_id: s.inspection_id,
+                wo_id: s.wo_id,
+                recorded_at: s.recorded_at,
+                value: p.value,
+                ucl: p.ucl,
+                lcl: p.lcl,
+                dispersion: p.dispersion,
+            }
+        })
+        .collect();
+    let violations = spc::detect_violations(computation, chart_rule_set(&chart))
+        .into_iter()
+        .filter(|v| v.index >= start)
+        .map(|mut v| {
+            v.index -= start;
+            v
+        })
+        .collect();
+    let capability = match (chart_kind(&chart).is_variables(), computation.sigma_within) {
+        (true, Some(sigma)) => {
+            let values: Vec<f64> = assembled.sources[start..]
+                .iter()
+                .flat_map(|s| s.values.iter().copied())
+                .collect();
+            spc::capability(&values, sigma, chart.lsl, chart.usl)
+        }
+        _ => None,
+    };
+
+    Ok(SpcChartData {
+        location: Some(computation.location),
+        dispersion: computation.dispersion,
+        sigma_within: computation.sigma_within,
+        points,
+        violations,
+        capability,
+        chart,
+    })
+}
+
+/// Cp/Cpk and Pp/Ppk for a variables chart over the same window as
+/// [`get_spc_chart_data`].
+pub async fn get_spc_capability(
+    pool: &PgPool,
+    tenant_id: &str,
+    chart_id: Uuid,
+    from: Option<DateTime<Utc>>,
+    to: Option<DateTime<Utc>>,
+    limit: Option<i64>,
+) -> Result<Capability, QiError> {
+    let data = get_spc_chart_data(pool, tenant_id, chart_id, from, to, limit).await?;
+    data.capability.ok_or_else(|| {
+        QiError::Validation(
+            "Capability needs a variables chart with a specification limit and at least 2 measurements"
+                .into(),
+        )
+    })
+}
+
+// ============================================================================
+// Violations
+// ============================================================================
+
+pub async fn list_spc_violations(
+    pool: &PgPool,
+    tenant_id: &str,
+    chart_id: Option<Uuid>,
+    wo_id: Option<Uuid>,
+    limit: Option<i64>,
+) -> Result<Vec<SpcViolation>, QiError> {
+    let limit = limit
+        .unwrap_or(DEFAULT_POINT_LIMIT)
+        .clamp(1, MAX_POINT_LIMIT);
+    repo::list_violations(pool, tenant_id, chart_id, wo_id, limit).await
+}
+
+/// Run the rules of every active chart fed by an in-process inspection and
+/// record violations on the inspection's own points.
+///
+/// Called inside the measurement transaction. One event is emitted per chart
+/// with new violations; already-recorded violations are not re-announced.
+pub async fn check_in_process_spc(
+    tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+    tenant_id: &str,
+    inspection: &Inspection,
+    part_id: Uuid,
+    characteristics: &[String],
+    correlation_id: &str,
+    causation_id: Option<&str>,
+) -> Result<Vec<SpcViolation>, QiError> {
+    let charts = repo::active_charts_for(
+        tx,
+        tenant_id,
+        part_id,
+        characteristics,
+        inspection.workcenter_id,
+    )
+    .await?;
+
+    let mut recorded = Vec::new();
+    for chart in charts {
+        let rows = repo::chart_measurements(&mut **tx, &chart, None).await?;
+        let Some(assembled) = assemble(&chart, &rows) else {
+            continue;
+        };
+        let hold_requested = chart.hold_on_violation && inspection.wo_id.is_some();
+
+        let mut new_rules: Vec<String> = Vec::new();
+        for v in spc::detect_violations(&assembled.computation, chart_rule_set(&chart)) {
+            if assembled.sources[v.index].inspection_id != inspection.id {
+                continue;
+            }
+            let inserted = repo::insert_violation(
+                tx,
+                &chart,
+                inspection.id,
+                inspection.wo_id,
+                &v.rule,
+                v.section.as_str(),
+                v.value,
+                &v.description,
+                hold_requested,
+            )
+            .await?;
+            if let Some(row) = inserted {
+                if !new_rules.contains(&row.rule) {
+                    new_rules.push(row.rule.clone());
+                }
+                recorded.push(row);
+            }
+        }
+        if new_rules.is_empty() {
+            continue;
+        }
+
+        tracing::warn!(
+            tenant_id = %tenant_id,
+            chart_id = %chart.id,
+            inspection_id = %inspection.id,
+            wo_id = ?inspection.wo_id,
+            rules = ?new_rules,
+            hold_requested,
+            "SPC run rule violation detected"
+        );
+        enqueue_event(
+            tx,
+            tenant_id,
+            QualityInspectionEventType::SpcViolationDetected,
+            "spc_chart",
+            &chart.id.to_string(),
+            &events::build_spc_violation_detected_envelope(
+                SpcViolationDetectedPayload {
+                    chart_id: chart.id,
+                    tenant_id: tenant_id.to_string(),
+                    inspection_id: inspection.id,
+                    wo_id: inspection.wo_id,
+                    op_instance_id: inspection.op_instance_id,
+                    workcenter_id: inspection.workcenter_id,
+                    part_id,
+                    characteristic: chart.characteristic.clone(),
+                    chart_type: chart.chart_type.clone(),
+                    rules: new_rules,
+                    hold_requested,
+                },
+                correlation_id.to_string(),
+                causation_id.map(String::from),
+            ),
+            correlation_id,
+            causation_id,
+        )
+        .await?;
+    }
+    Ok(recorded)
+}
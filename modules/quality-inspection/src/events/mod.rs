@@ -13,6 +13,8 @@ pub enum QualityInspectionEventType {
     InspectionReleased,
     InspectionAccepted,
     InspectionRejected,
+    LotDispositioned,
+    SpcViolationDetected,
 }
 
 impl QualityInspectionEventType {
@@ -25,6 +27,8 @@ impl QualityInspectionEventType {
             Self::InspectionReleased => "quality_inspection.released",
             Self::InspectionAccepted => "quality_inspection.accepted",
             Self::InspectionRejected => "quality_inspection.rejected",
+            Self::LotDispositioned => "quality_inspection.lot_dispositioned",
+            Self::SpcViolationDetected => "quality_inspection.spc_violation_detected",
         }
     }
 }
@@ -64,6 +68,44 @@ pub struct DispositionTransitionPayload {
     pub reason: Option<String>,
 }
 
+/// Emitted when AQL evaluation accepts or rejects an inspection linked to an
+/// inventory lot. Inventory consumes it to quarantine rejected lots.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct LotDispositionedPayload {
+    pub inspection_id: Uuid,
+    pub tenant_id: String,
+    pub lot_id: Uuid,
+    pub part_id: Option<Uuid>,
+    pub supplier_id: Option<Uuid>,
+    /// `accepted` or `rejected`.
+    pub decision: String,
+    pub lot_size: i32,
+    pub sample_size: i32,
+    pub acceptance_number: i32,
+    pub rejection_number: i32,
+    pub defective_units: i32,
+    pub severity: String,
+}
+
+/// Emitted when an in-process inspection's measurements break a control chart
+/// run rule. Shop-floor-gates places a quality hold on the work order when
+/// `hold_requested` is set.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SpcViolationDetectedPayload {
+    pub chart_id: Uuid,
+    pub tenant_id: String,
+    pub inspection_id: Uuid,
+    pub wo_id: Option<Uuid>,
+    pub op_instance_id: Option<Uuid>,
+    pub workcenter_id: Option<Uuid>,
+    pub part_id: Uuid,
+    pub characteristic: String,
+    pub chart_type: String,
+    /// Rule ids broken by this inspection's points, e.g. `we1`, `nelson3`.
+    pub rules: Vec<String>,
+    pub hold_requested: bool,
+}
+
 // ============================================================================
 // Envelope builders
 // ============================================================================
@@ -178,3 +220,37 @@ pub fn build_disposition_transition_envelope(
         },
     )
 }
+
+pub fn build_lot_dispositioned_envelope(
+    payload: LotDispositionedPayload,
+    correlation_id: String,
+    causation_id: Option<String>,
+) -> event_bus::EventEnvelope<LotDispositionedPayload> {
+    create_qi_envelope(
+        Uuid::new_v4(),
+        payload.tenant_id.clone(),
+        QualityInspectionEventType::LotDispositioned
+            .as_str()
+            .to_string(),
+        correlation_id,
+        causation_id,
+        payload,
+    )
+}
+
+pub fn build_spc_violation_detected_envelope(
+    payload: SpcViolationDetectedPayload,
+    correlation_id: String,
+    causation_id: Option<String>,
+) -> event_bus::EventEnvelope<SpcViolationDetectedPayload> {
+    create_qi_envelope(
+        Uuid::new_v4(),
+        payload.tenant_id.clone(),
+        QualityInspectionEventType::SpcViolationDetected
+            .as_str()
+            .to_string(),
+        correlation_id,
+        causation_id,
+        payload,
+    )
+}
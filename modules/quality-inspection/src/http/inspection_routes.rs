@@ -20,7 +20,7 @@ use platform_sdk::extract_tenant;
 
 /// Clone the WC client with the inbound request's bearer token attached,
 /// so downstream WC calls are authenticated as the original caller.
-fn wc_with_bearer(state: &AppState, headers: &HeaderMap) -> platform_sdk::PlatformClient {
+pub(super) fn wc_with_bearer(state: &AppState, headers: &HeaderMap) -> platform_sdk::PlatformClient {
     if let Some(auth) = headers.get("authorization").and_then(|v| v.to_str().ok()) {
         if let Some(token) = auth.strip_prefix("Bearer ") {
             return state.wc_client.clone().with_bearer_token(token.to_string());
@@ -29,7 +29,7 @@ fn wc_with_bearer(state: &AppState, headers: &HeaderMap) -> platform_sdk::Platfo
     state.wc_client.clone()
 }
 
-fn correlation_id() -> String {
+pub(super) fn correlation_id() -> String {
     Uuid::new_v4().to_string()
 }
 
@@ -1,4 +1,6 @@
 pub mod inspection_routes;
+pub mod sampling_routes;
+pub mod spc_routes;
 pub mod tenant;
 
 use axum::Json;
@@ -28,6 +30,19 @@ use utoipa::OpenApi;
         inspection_routes::get_inspections_by_receipt,
         inspection_routes::get_inspections_by_wo,
         inspection_routes::get_inspections_by_lot,
+        sampling_routes::get_sampling_plan,
+        sampling_routes::post_apply_sampling,
+        sampling_routes::post_measurements,
+        sampling_routes::get_measurements,
+        sampling_routes::post_evaluate_inspection,
+        sampling_routes::get_sampling_state,
+        sampling_routes::post_reinstate_sampling,
+        spc_routes::post_spc_chart,
+        spc_routes::get_spc_charts,
+        spc_routes::get_spc_chart,
+        spc_routes::get_spc_chart_data,
+        spc_routes::get_spc_capability,
+        spc_routes::get_spc_violations,
     ),
     components(schemas(
         crate::domain::models::InspectionPlan,
@@ -38,6 +53,27 @@ use utoipa::OpenApi;
         crate::domain::models::CreateInProcessInspectionRequest,
         crate::domain::models::CreateFinalInspectionRequest,
         crate::domain::models::DispositionTransitionRequest,
+        crate::domain::models::ApplySamplingRequest,
+        crate::domain::models::MeasurementInput,
+        crate::domain::models::RecordMeasurementsRequest,
+        crate::domain::models::InspectionMeasurement,
+        crate::domain::models::EvaluateInspectionRequest,
+        crate::domain::models::LotEvaluation,
+        crate::domain::models::SamplingState,
+        crate::domain::models::ReinstateSamplingRequest,
+        crate::domain::aql::SamplingPlan,
+        crate::domain::aql::Severity,
+        crate::domain::models::SpcChart,
+        crate::domain::models::CreateSpcChartRequest,
+        crate::domain::models::SpcViolation,
+        crate::domain::models::SpcChartPoint,
+        crate::domain::models::SpcChartData,
+        crate::domain::spc::ChartType,
+        crate::domain::spc::RuleSet,
+        crate::domain::spc::ControlLimits,
+        crate::domain::spc::ChartSection,
+        crate::domain::spc::RuleViolation,
+        crate::domain::spc::Capability,
         platform_http_contracts::ApiError,
         platform_http_contracts::PaginatedResponse<crate::domain::models::Inspection>,
         platform_http_contracts::PaginatedResponse<crate::domain::models::InspectionPlan>,
@@ -0,0 +1,296 @@
+use axum::{
+    extract::{Path, Query, State},
+    http::{HeaderMap, StatusCode},
+    response::IntoResponse,
+    Extension, Json,
+};
+use event_bus::TracingContext;
+use platform_http_contracts::ApiError;
+use security::VerifiedClaims;
+use serde::Deserialize;
+use std::sync::Arc;
+use utoipa::IntoParams;
+use uuid::Uuid;
+
+use super::inspection_routes::{correlation_id, wc_with_bearer};
+use super::tenant::with_request_id;
+use crate::domain::aql::SamplingPlan;
+use crate::domain::models::*;
+use crate::domain::service;
+use crate::AppState;
+use platform_sdk::extract_tenant;
+
+// ============================================================================
+// Sampling plan
+// ============================================================================
+
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct SamplingPlanQuery {
+    pub lot_size: i32,
+    pub supplier_id: Option<Uuid>,
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/quality-inspection/plans/{plan_id}/sampling-plan",
+    tag = "AQL Sampling",
+    params(("plan_id" = Uuid, Path, description = "Plan ID"), SamplingPlanQuery),
+    responses(
+        (status = 200, description = "Z1.4 sampling plan at the supplier/part's current severity", body = SamplingPlan),
+        (status = 401, description = "Unauthorized"),
+        (status = 404, description = "Not found", body = ApiError),
+        (status = 422, description = "Validation error", body = ApiError),
+        (status = 500, description = "Internal error", body = ApiError),
+    ),
+    security(("bearer" = ["QUALITY_INSPECTION_READ"]))
+)]
+pub async fn get_sampling_plan(
+    State(state): State<Arc<AppState>>,
+    claims: Option<Extension<VerifiedClaims>>,
+    tracing_ctx: Option<Extension<TracingContext>>,
+    Path(plan_id): Path<Uuid>,
+    Query(q): Query<SamplingPlanQuery>,
+) -> impl IntoResponse {
+    let tenant_id = match extract_tenant(&claims) {
+        Ok(id) => id,
+        Err(e) => return with_request_id(e, &tracing_ctx).into_response(),
+    };
+    match service::preview_sampling_plan(
+        &state.pool,
+        &tenant_id,
+        plan_id,
+        q.lot_size,
+        q.supplier_id,
+    )
+    .await
+    {
+        Ok(plan) => Json(plan).into_response(),
+        Err(e) => with_request_id(ApiError::from(e), &tracing_ctx).into_response(),
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/quality-inspection/inspections/{inspection_id}/sampling",
+    tag = "AQL Sampling",
+    params(("inspection_id" = Uuid, Path, description = "Inspection ID")),
+    request_body = ApplySamplingRequest,
+    responses(
+        (status = 200, description = "Sampling plan applied", body = Inspection),
+        (status = 401, description = "Unauthorized"),
+        (status = 404, description = "Not found", body = ApiError),
+        (status = 422, description = "Validation error", body = ApiError),
+        (status = 500, description = "Internal error", body = ApiError),
+    ),
+    security(("bearer" = ["QUALITY_INSPECTION_MUTATE"]))
+)]
+pub async fn post_apply_sampling(
+    State(state): State<Arc<AppState>>,
+    claims: Option<Extension<VerifiedClaims>>,
+    tracing_ctx: Option<Extension<TracingContext>>,
+    Path(inspection_id): Path<Uuid>,
+    Json(req): Json<ApplySamplingRequest>,
+) -> impl IntoResponse {
+    let tenant_id = match extract_tenant(&claims) {
+        Ok(id) => id,
+        Err(e) => return with_request_id(e, &tracing_ctx).into_response(),
+    };
+    match service::apply_sampling(&state.pool, &tenant_id, inspection_id, &req).await {
+        Ok(i) => Json(i).into_response(),
+        Err(e) => with_request_id(ApiError::from(e), &tracing_ctx).into_response(),
+    }
+}
+
+// ============================================================================
+// Measurements
+// ============================================================================
+
+#[utoipa::path(
+    post,
+    path = "/api/quality-inspection/inspections/{inspection_id}/measurements",
+    tag = "AQL Sampling",
+    params(("inspection_id" = Uuid, Path, description = "Inspection ID")),
+    request_body = RecordMeasurementsRequest,
+    responses(
+        (status = 201, description = "Measurements recorded", body = Vec<InspectionMeasurement>),
+        (status = 401, description = "Unauthorized"),
+        (status = 404, description = "Not found", body = ApiError),
+        (status = 422, description = "Validation error", body = ApiError),
+        (status = 500, description = "Internal error", body = ApiError),
+    ),
+    security(("bearer" = ["QUALITY_INSPECTION_MUTATE"]))
+)]
+pub async fn post_measurements(
+    State(state): State<Arc<AppState>>,
+    claims: Option<Extension<VerifiedClaims>>,
+    tracing_ctx: Option<Extension<TracingContext>>,
+    Path(inspection_id): Path<Uuid>,
+    Json(req): Json<RecordMeasurementsRequest>,
+) -> impl IntoResponse {
+    let tenant_id = match extract_tenant(&claims) {
+        Ok(id) => id,
+        Err(e) => return with_request_id(e, &tracing_ctx).into_response(),
+    };
+    match service::record_measurements(
+        &state.pool,
+        &tenant_id,
+        inspection_id,
+        &req,
+        &correlation_id(),
+        None,
+    )
+    .await
+    {
+        Ok(rows) => (StatusCode::CREATED, Json(rows)).into_response(),
+        Err(e) => with_request_id(ApiError::from(e), &tracing_ctx).into_response(),
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/quality-inspection/inspections/{inspection_id}/measurements",
+    tag = "AQL Sampling",
+    params(("inspection_id" = Uuid, Path, description = "Inspection ID")),
+    responses(
+        (status = 200, description = "Recorded measurements", body = Vec<InspectionMeasurement>),
+        (status = 401, description = "Unauthorized"),
+        (status = 404, description = "Not found", body = ApiError),
+        (status = 500, description = "Internal error", body = ApiError),
+    ),
+    security(("bearer" = ["QUALITY_INSPECTION_READ"]))
+)]
+pub async fn get_measurements(
+    State(state): State<Arc<AppState>>,
+    claims: Option<Extension<VerifiedClaims>>,
+    tracing_ctx: Option<Extension<TracingContext>>,
+    Path(inspection_id): Path<Uuid>,
+) -> impl IntoResponse {
+    let tenant_id = match extract_tenant(&claims) {
+        Ok(id) => id,
+        Err(e) => return with_request_id(e, &tracing_ctx).into_response(),
+    };
+    match service::list_measurements(&state.pool, &tenant_id, inspection_id).await {
+        Ok(rows) => Json(rows).into_response(),
+        Err(e) => with_request_id(ApiError::from(e), &tracing_ctx).into_response(),
+    }
+}
+
+// ============================================================================
+// Evaluation
+// ============================================================================
+
+#[utoipa::path(
+    post,
+    path = "/api/quality-inspection/inspections/{inspection_id}/evaluate",
+    tag = "AQL Sampling",
+    params(("inspection_id" = Uuid, Path, description = "Inspection ID")),
+    request_body = EvaluateInspectionRequest,
+    responses(
+        (status = 200, description = "Lot accepted or rejected against Ac/Re", body = LotEvaluation),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Inspector not authorized", body = ApiError),
+        (status = 404, description = "Not found", body = ApiError),
+        (status = 422, description = "Validation error", body = ApiError),
+        (status = 500, description = "Internal error", body = ApiError),
+        (status = 503, description = "Workforce-competence unavailable", body = ApiError),
+    ),
+    security(("bearer" = ["QUALITY_INSPECTION_MUTATE"]))
+)]
+pub async fn post_evaluate_inspection(
+    State(state): State<Arc<AppState>>,
+    claims: Option<Extension<VerifiedClaims>>,
+    tracing_ctx: Option<Extension<TracingContext>>,
+    headers: HeaderMap,
+    Path(inspection_id): Path<Uuid>,
+    Json(req): Json<EvaluateInspectionRequest>,
+) -> impl IntoResponse {
+    let tenant_id = match extract_tenant(&claims) {
+        Ok(id) => id,
+        Err(e) => return with_request_id(e, &tracing_ctx).into_response(),
+    };
+    let wc = wc_with_bearer(&state, &headers);
+    match service::evaluate_inspection(
+        &state.pool,
+        &wc,
+        &tenant_id,
+        inspection_id,
+        req.inspector_id,
+        &correlation_id(),
+        None,
+    )
+    .await
+    {
+        Ok(eval) => Json(eval).into_response(),
+        Err(e) => with_request_id(ApiError::from(e), &tracing_ctx).into_response(),
+    }
+}
+
+// ============================================================================
+// Switching state
+// ============================================================================
+
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct SamplingStateQuery {
+    pub part_id: Uuid,
+    pub supplier_id: Option<Uuid>,
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/quality-inspection/sampling-states",
+    tag = "AQL Sampling",
+    params(SamplingStateQuery),
+    responses(
+        (status = 200, description = "Switching state for the supplier/part", body = SamplingState),
+        (status = 401, description = "Unauthorized"),
+        (status = 404, description = "No lots evaluated yet", body = ApiError),
+        (status = 500, description = "Internal error", body = ApiError),
+    ),
+    security(("bearer" = ["QUALITY_INSPECTION_READ"]))
+)]
+pub async fn get_sampling_state(
+    State(state): State<Arc<AppState>>,
+    claims: Option<Extension<VerifiedClaims>>,
+    tracing_ctx: Option<Extension<TracingContext>>,
+    Query(q): Query<SamplingStateQuery>,
+) -> impl IntoResponse {
+    let tenant_id = match extract_tenant(&claims) {
+        Ok(id) => id,
+        Err(e) => return with_request_id(e, &tracing_ctx).into_response(),
+    };
+    match service::get_sampling_state(&state.pool, &tenant_id, q.supplier_id, q.part_id).await {
+        Ok(s) => Json(s).into_response(),
+        Err(e) => with_request_id(ApiError::from(e), &tracing_ctx).into_response(),
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/quality-inspection/sampling-states/reinstate",
+    tag = "AQL Sampling",
+    request_body = ReinstateSamplingRequest,
+    responses(
+        (status = 200, description = "Sampling resumed on tightened inspection", body = SamplingState),
+        (status = 401, description = "Unauthorized"),
+        (status = 422, description = "Validation error", body = ApiError),
+        (status = 500, description = "Internal error", body = ApiError),
+    ),
+    security(("bearer" = ["QUALITY_INSPECTION_MUTATE"]))
+)]
+pub async fn post_reinstate_sampling(
+    State(state): State<Arc<AppState>>,
+    claims: Option<Extension<VerifiedClaims>>,
+    tracing_ctx: Option<Extension<TracingContext>>,
+    Json(req): Json<ReinstateSamplingRequest>,
+) -> impl IntoResponse {
+    let tenant_id = match extract_tenant(&claims) {
+        Ok(id) => id,
+        Err(e) => return with_request_id(e, &tracing_ctx).into_response(),
+    };
+    match service::reinstate_sampling(&state.pool, &tenant_id, &req).await {
+        Ok(s) => Json(s).into_response(),
+        Err(e) => with_request_id(ApiError::from(e), &tracing_ctx).into_response(),
+    }
+}
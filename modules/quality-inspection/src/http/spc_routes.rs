@@ -0,0 +1,241 @@
+use axum::{
+    extract::{Path, Query, State},
+    http::StatusCode,
+    response::IntoResponse,
+    Extension, Json,
+};
+use chrono::{DateTime, Utc};
+use event_bus::TracingContext;
+use platform_http_contracts::ApiError;
+use security::VerifiedClaims;
+use serde::Deserialize;
+use std::sync::Arc;
+use utoipa::IntoParams;
+use uuid::Uuid;
+
+use super::tenant::with_request_id;
+use crate::domain::models::*;
+use crate::domain::service;
+use crate::domain::spc::Capability;
+use crate::AppState;
+use platform_sdk::extract_tenant;
+
+// ============================================================================
+// Chart definitions
+// ============================================================================
+
+#[utoipa::path(
+    post,
+    path = "/api/quality-inspection/spc/charts",
+    tag = "SPC",
+    request_body = CreateSpcChartRequest,
+    responses(
+        (status = 201, description = "Control chart defined", body = SpcChart),
+        (status = 401, description = "Unauthorized"),
+        (status = 404, description = "Plan not found", body = ApiError),
+        (status = 422, description = "Validation error", body = ApiError),
+        (status = 500, description = "Internal error", body = ApiError),
+    ),
+    security(("bearer" = ["QUALITY_INSPECTION_MUTATE"]))
+)]
+pub async fn post_spc_chart(
+    State(state): State<Arc<AppState>>,
+    claims: Option<Extension<VerifiedClaims>>,
+    tracing_ctx: Option<Extension<TracingContext>>,
+    Json(req): Json<CreateSpcChartRequest>,
+) -> impl IntoResponse {
+    let tenant_id = match extract_tenant(&claims) {
+        Ok(id) => id,
+        Err(e) => return with_request_id(e, &tracing_ctx).into_response(),
+    };
+    match service::create_spc_chart(&state.pool, &tenant_id, &req).await {
+        Ok(chart) => (StatusCode::CREATED, Json(chart)).into_response(),
+        Err(e) => with_request_id(ApiError::from(e), &tracing_ctx).into_response(),
+    }
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct SpcChartsQuery {
+    pub part_id: Option<Uuid>,
+    pub workcenter_id: Option<Uuid>,
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/quality-inspection/spc/charts",
+    tag = "SPC",
+    params(SpcChartsQuery),
+    responses(
+        (status = 200, description = "Control charts", body = Vec<SpcChart>),
+        (status = 401, description = "Unauthorized"),
+        (status = 500, description = "Internal error", body = ApiError),
+    ),
+    security(("bearer" = ["QUALITY_INSPECTION_READ"]))
+)]
+pub async fn get_spc_charts(
+    State(state): State<Arc<AppState>>,
+    claims: Option<Extension<VerifiedClaims>>,
+    tracing_ctx: Option<Extension<TracingContext>>,
+    Query(q): Query<SpcChartsQuery>,
+) -> impl IntoResponse {
+    let tenant_id = match extract_tenant(&claims) {
+        Ok(id) => id,
+        Err(e) => return with_request_id(e, &tracing_ctx).into_response(),
+    };
+    match service::list_spc_charts(&state.pool, &tenant_id, q.part_id, q.workcenter_id).await {
+        Ok(rows) => Json(rows).into_response(),
+        Err(e) => with_request_id(ApiError::from(e), &tracing_ctx).into_response(),
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/quality-inspection/spc/charts/{chart_id}",
+    tag = "SPC",
+    params(("chart_id" = Uuid, Path, description = "Chart ID")),
+    responses(
+        (status = 200, description = "Control chart definition", body = SpcChart),
+        (status = 401, description = "Unauthorized"),
+        (status = 404, description = "Not found", body = ApiError),
+        (status = 500, description = "Internal error", body = ApiError),
+    ),
+    security(("bearer" = ["QUALITY_INSPECTION_READ"]))
+)]
+pub async fn get_spc_chart(
+    State(state): State<Arc<AppState>>,
+    claims: Option<Extension<VerifiedClaims>>,
+    tracing_ctx: Option<Extension<TracingContext>>,
+    Path(chart_id): Path<Uuid>,
+) -> impl IntoResponse {
+    let tenant_id = match extract_tenant(&claims) {
+        Ok(id) => id,
+        Err(e) => return with_request_id(e, &tracing_ctx).into_response(),
+    };
+    match service::get_spc_chart(&state.pool, &tenant_id, chart_id).await {
+        Ok(chart) => Json(chart).into_response(),
+        Err(e) => with_request_id(ApiError::from(e), &tracing_ctx).into_response(),
+    }
+}
+
+// ============================================================================
+// Chart data + capability
+// ============================================================================
+
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct SpcWindowQuery {
+    /// Only return points from inspections created at or after this time.
+    pub from: Option<DateTime<Utc>>,
+    /// Ignore inspections created after this time.
+    pub to: Option<DateTime<Utc>>,
+    /// Latest points to return (default 100, max 1000).
+    pub limit: Option<i64>,
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/quality-inspection/spc/charts/{chart_id}/data",
+    tag = "SPC",
+    params(("chart_id" = Uuid, Path, description = "Chart ID"), SpcWindowQuery),
+    responses(
+        (status = 200, description = "Points, control limits, rule violations and capability", body = SpcChartData),
+        (status = 401, description = "Unauthorized"),
+        (status = 404, description = "Not found", body = ApiError),
+        (status = 500, description = "Internal error", body = ApiError),
+    ),
+    security(("bearer" = ["QUALITY_INSPECTION_READ"]))
+)]
+pub async fn get_spc_chart_data(
+    State(state): State<Arc<AppState>>,
+    claims: Option<Extension<VerifiedClaims>>,
+    tracing_ctx: Option<Extension<TracingContext>>,
+    Path(chart_id): Path<Uuid>,
+    Query(q): Query<SpcWindowQuery>,
+) -> impl IntoResponse {
+    let tenant_id = match extract_tenant(&claims) {
+        Ok(id) => id,
+        Err(e) => return with_request_id(e, &tracing_ctx).into_response(),
+    };
+    match service::get_spc_chart_data(&state.pool, &tenant_id, chart_id, q.from, q.to, q.limit)
+        .await
+    {
+        Ok(data) => Json(data).into_response(),
+        Err(e) => with_request_id(ApiError::from(e), &tracing_ctx).into_response(),
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/quality-inspection/spc/charts/{chart_id}/capability",
+    tag = "SPC",
+    params(("chart_id" = Uuid, Path, description = "Chart ID"), SpcWindowQuery),
+    responses(
+        (status = 200, description = "Cp, Cpk, Pp and Ppk against the characteristic's tolerance", body = Capability),
+        (status = 401, description = "Unauthorized"),
+        (status = 404, description = "Not found", body = ApiError),
+        (status = 422, description = "Not a variables chart, no specification limits, or too little data", body = ApiError),
+        (status = 500, description = "Internal error", body = ApiError),
+    ),
+    security(("bearer" = ["QUALITY_INSPECTION_READ"]))
+)]
+pub async fn get_spc_capability(
+    State(state): State<Arc<AppState>>,
+    claims: Option<Extension<VerifiedClaims>>,
+    tracing_ctx: Option<Extension<TracingContext>>,
+    Path(chart_id): Path<Uuid>,
+    Query(q): Query<SpcWindowQuery>,
+) -> impl IntoResponse {
+    let tenant_id = match extract_tenant(&claims) {
+        Ok(id) => id,
+        Err(e) => return with_request_id(e, &tracing_ctx).into_response(),
+    };
+    match service::get_spc_capability(&state.pool, &tenant_id, chart_id, q.from, q.to, q.limit)
+        .await
+    {
+        Ok(cap) => Json(cap).into_response(),
+        Err(e) => with_request_id(ApiError::from(e), &tracing_ctx).into_response(),
+    }
+}
+
+// ============================================================================
+// Violations
+// ============================================================================
+
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct SpcViolationsQuery {
+    pub chart_id: Option<Uuid>,
+    pub wo_id: Option<Uuid>,
+    /// Most recent violations to return (default 100, max 1000).
+    pub limit: Option<i64>,
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/quality-inspection/spc/violations",
+    tag = "SPC",
+    params(SpcViolationsQuery),
+    responses(
+        (status = 200, description = "Recorded run rule violations, newest first", body = Vec<SpcViolation>),
+        (status = 401, description = "Unauthorized"),
+        (status = 500, description = "Internal error", body = ApiError),
+    ),
+    security(("bearer" = ["QUALITY_INSPECTION_READ"]))
+)]
+pub async fn get_spc_violations(
+    State(state): State<Arc<AppState>>,
+    claims: Option<Extension<VerifiedClaims>>,
+    tracing_ctx: Option<Extension<TracingContext>>,
+    Query(q): Query<SpcViolationsQuery>,
+) -> impl IntoResponse {
+    let tenant_id = match extract_tenant(&claims) {
+        Ok(id) => id,
+        Err(e) => return with_request_id(e, &tracing_ctx).into_response(),
+    };
+    match service::list_spc_violations(&state.pool, &tenant_id, q.chart_id, q.wo_id, q.limit).await
+    {
+        Ok(rows) => Json(rows).into_response(),
+        Err(e) => with_request_id(ApiError::from(e), &tracing_ctx).into_response(),
+    }
+}
@@ -14,6 +14,14 @@ use quality_inspection_rs::{
             post_receiving_inspection, post_reject_inspection, post_release_inspection,
         },
         openapi_json,
+        sampling_routes::{
+            get_measurements, get_sampling_plan, get_sampling_state, post_apply_sampling,
+            post_evaluate_inspection, post_measurements, post_reinstate_sampling,
+        },
+        spc_routes::{
+            get_spc_capability, get_spc_chart, get_spc_chart_data, get_spc_charts,
+            get_spc_violations, post_spc_chart,
+        },
     },
     metrics::QualityInspectionMetrics,
     AppState,
@@ -87,6 +95,26 @@ async fn main() {
                     "/api/quality-inspection/inspections/{inspection_id}/reject",
                     axum::routing::post(post_reject_inspection),
                 )
+                .route(
+                    "/api/quality-inspection/inspections/{inspection_id}/sampling",
+                    axum::routing::post(post_apply_sampling),
+                )
+                .route(
+                    "/api/quality-inspection/inspections/{inspection_id}/measurements",
+                    axum::routing::post(post_measurements),
+                )
+                .route(
+                    "/api/quality-inspection/inspections/{inspection_id}/evaluate",
+                    axum::routing::post(post_evaluate_inspection),
+                )
+                .route(
+                    "/api/quality-inspection/sampling-states/reinstate",
+                    axum::routing::post(post_reinstate_sampling),
+                )
+                .route(
+                    "/api/quality-inspection/spc/charts",
+                    axum::routing::post(post_spc_chart),
+                )
                 .route_layer(RequirePermissionsLayer::new(&[
                     permissions::QUALITY_INSPECTION_MUTATE,
                 ]))
@@ -121,6 +149,38 @@ async fn main() {
                     "/api/quality-inspection/inspections/by-lot",
                     axum::routing::get(get_inspections_by_lot),
                 )
+                .route(
+                    "/api/quality-inspection/plans/{plan_id}/sampling-plan",
+                    axum::routing::get(get_sampling_plan),
+                )
+                .route(
+                    "/api/quality-inspection/inspections/{inspection_id}/measurements",
+                    axum::routing::get(get_measurements),
+                )
+                .route(
+                    "/api/quality-inspection/sampling-states",
+                    axum::routing::get(get_sampling_state),
+                )
+                .route(
+                    "/api/quality-inspection/spc/charts",
+                    axum::routing::get(get_spc_charts),
+                )
+                .route(
+                    "/api/quality-inspection/spc/charts/{chart_id}",
+                    axum::routing::get(get_spc_chart),
+                )
+                .route(
+                    "/api/quality-inspection/spc/charts/{chart_id}/data",
+                    axum::routing::get(get_spc_chart_data),
+                )
+                .route(
+                    "/api/quality-inspection/spc/charts/{chart_id}/capability",
+                    axum::routing::get(get_spc_capability),
+                )
+                .route(
+                    "/api/quality-inspection/spc/violations",
+                    axum::routing::get(get_spc_violations),
+                )
                 .route_layer(RequirePermissionsLayer::new(&[
                     permissions::QUALITY_INSPECTION_READ,
                 ]))
@@ -175,6 +175,7 @@ async fn create_in_process_inspection_linked_to_wo_and_op() {
         &CreateInProcessInspectionRequest {
             wo_id,
             op_instance_id,
+            workcenter_id: None,
             plan_id: None,
             lot_id: None,
             part_id: Some(part_id),
@@ -262,6 +263,7 @@ async fn query_inspections_by_wo() {
         &CreateInProcessInspectionRequest {
             wo_id,
             op_instance_id: Uuid::new_v4(),
+            workcenter_id: None,
             plan_id: None,
             lot_id: None,
             part_id: Some(part_id),
@@ -395,6 +397,7 @@ async fn query_by_part_rev_includes_all_types() {
         &CreateInProcessInspectionRequest {
             wo_id: Uuid::new_v4(),
             op_instance_id: Uuid::new_v4(),
+            workcenter_id: None,
             plan_id: None,
             lot_id: None,
             part_id: Some(part_id),
@@ -453,6 +456,7 @@ async fn events_emitted_for_in_process_and_final() {
         &CreateInProcessInspectionRequest {
             wo_id: Uuid::new_v4(),
             op_instance_id: Uuid::new_v4(),
+            workcenter_id: None,
             plan_id: None,
             lot_id: None,
             part_id: None,
@@ -550,6 +554,7 @@ async fn disposition_works_on_in_process_inspection() {
         &CreateInProcessInspectionRequest {
             wo_id: Uuid::new_v4(),
             op_instance_id: Uuid::new_v4(),
+            workcenter_id: None,
             plan_id: None,
             lot_id: None,
             part_id: None,
@@ -196,6 +196,8 @@ async fn create_inspection_plan_with_characteristics() {
             ],
             sampling_method: Some("aql".to_string()),
             sample_size: Some(13),
+            inspection_level: None,
+            aql: None,
         },
         &corr,
         None,
@@ -239,6 +241,8 @@ async fn activate_inspection_plan() {
             characteristics: vec![],
             sampling_method: None,
             sample_size: None,
+            inspection_level: None,
+            aql: None,
         },
         &corr,
         None,
@@ -395,6 +399,8 @@ async fn events_emitted_to_outbox() {
             characteristics: vec![],
             sampling_method: None,
             sample_size: None,
+            inspection_level: None,
+            aql: None,
         },
         &corr,
         None,
@@ -913,6 +919,8 @@ async fn tenant_isolation() {
             characteristics: vec![],
             sampling_method: None,
             sample_size: None,
+            inspection_level: None,
+            aql: None,
         },
         &corr,
         None,
@@ -0,0 +1,75 @@
+-- Maintenance reliability caches
+-- Ingested from maintenance.reliability.snapshot.
+--
+-- Design:
+--   rpt_asset_reliability_cache: MTBF / MTTR / MTTF / availability per scope
+--     (tenant-wide, asset, asset_type) per snapshot window
+--   rpt_failure_pareto_cache: failure codes ranked by count per window
+--   A snapshot replaces every row for its (tenant_id, period_start, period_end),
+--   so replays and re-published windows converge to the latest figures.
+
+-- ============================================================
+-- ASSET RELIABILITY CACHE
+-- ============================================================
+-- Grain: (tenant_id, period_start, period_end, scope_type, scope_key)
+-- scope_type: 'tenant' (scope_key = 'all') | 'asset' (asset id) | 'asset_type'
+
+CREATE TABLE rpt_asset_reliability_cache (
+    id              BIGSERIAL        PRIMARY KEY,
+    tenant_id       TEXT             NOT NULL,
+    period_start    TIMESTAMPTZ      NOT NULL,
+    period_end      TIMESTAMPTZ      NOT NULL,
+    scope_type      TEXT             NOT NULL
+                    CHECK (scope_type IN ('tenant', 'asset', 'asset_type')),
+    scope_key       TEXT             NOT NULL,
+    scope_label     TEXT,
+    failure_count   BIGINT           NOT NULL CHECK (failure_count >= 0),
+    repair_count    BIGINT           NOT NULL CHECK (repair_count >= 0),
+    operating_hours DOUBLE PRECISION NOT NULL,
+    downtime_hours  DOUBLE PRECISION NOT NULL,
+    uptime_hours    DOUBLE PRECISION NOT NULL,
+    mtbf_hours      DOUBLE PRECISION,
+    mttr_hours      DOUBLE PRECISION,
+    mttf_hours      DOUBLE PRECISION,
+    availability    DOUBLE PRECISION,
+    computed_at     TIMESTAMPTZ      NOT NULL DEFAULT NOW(),
+
+    CONSTRAINT rpt_asset_reliability_cache_unique
+        UNIQUE (tenant_id, period_start, period_end, scope_type, scope_key),
+    CONSTRAINT rpt_asset_reliability_cache_period
+        CHECK (period_end > period_start)
+);
+
+CREATE INDEX idx_rpt_asset_reliability_tenant_period
+    ON rpt_asset_reliability_cache (tenant_id, period_end DESC);
+
+COMMENT ON TABLE rpt_asset_reliability_cache IS
+    'Maintenance reliability metrics per snapshot window. Grain: (tenant_id, period_start, period_end, scope_type, scope_key).';
+
+-- ============================================================
+-- FAILURE PARETO CACHE
+-- ============================================================
+-- Grain: (tenant_id, period_start, period_end, failure_code)
+
+CREATE TABLE rpt_failure_pareto_cache (
+    id              BIGSERIAL        PRIMARY KEY,
+    tenant_id       TEXT             NOT NULL,
+    period_start    TIMESTAMPTZ      NOT NULL,
+    period_end      TIMESTAMPTZ      NOT NULL,
+    failure_code    TEXT             NOT NULL,
+    rank            INT              NOT NULL CHECK (rank >= 1),
+    failure_count   BIGINT           NOT NULL CHECK (failure_count >= 0),
+    downtime_hours  DOUBLE PRECISION NOT NULL,
+    share_pct       DOUBLE PRECISION NOT NULL,
+    cumulative_pct  DOUBLE PRECISION NOT NULL,
+    computed_at     TIMESTAMPTZ      NOT NULL DEFAULT NOW(),
+
+    CONSTRAINT rpt_failure_pareto_cache_unique
+        UNIQUE (tenant_id, period_start, period_end, failure_code)
+);
+
+CREATE INDEX idx_rpt_failure_pareto_tenant_period
+    ON rpt_failure_pareto_cache (tenant_id, period_end DESC, rank);
+
+COMMENT ON TABLE rpt_failure_pareto_cache IS
+    'Maintenance failure-cause Pareto per snapshot window. Grain: (tenant_id, period_start, period_end, failure_code).';
@@ -8,6 +8,7 @@ use utoipa::OpenApi;
 use platform_http_contracts::{ApiError, PaginatedResponse, PaginationMeta};
 use reporting::domain::{
     aging::{ap_aging, ar_aging},
+    asset_reliability::{AssetReliabilityReport, FailureParetoRow, ReliabilityRow},
     forecast::types::{AtRiskItem, CashForecastResponse, CurrencyForecast, ForecastHorizon},
     jobs::snapshot_runner::SnapshotRunResult,
     kpis::KpiSnapshot,
@@ -42,6 +43,7 @@ use reporting::http::{
         reporting::http::aging::get_ar_aging,
         reporting::http::aging::get_ap_aging,
         reporting::http::kpis::get_kpis,
+        reporting::http::asset_reliability::get_asset_reliability,
         reporting::http::forecast::get_forecast,
         reporting::http::admin::rebuild,
         reporting::http::admin::projection_status,
@@ -59,6 +61,7 @@ use reporting::http::{
         ArAgingResponse,
         ap_aging::ApAgingReport, ap_aging::VendorAgingRow, ap_aging::CurrencySummary,
         KpiSnapshot,
+        AssetReliabilityReport, ReliabilityRow, FailureParetoRow,
         CashForecastResponse, CurrencyForecast, ForecastHorizon, AtRiskItem,
         SnapshotRunResult,
         RebuildRequest,
@@ -0,0 +1,126 @@
+//! Maintenance asset reliability read model.
+//!
+//! Reads pre-computed snapshots from:
+//!   - `rpt_asset_reliability_cache` → MTBF / MTTR / MTTF / availability per scope
+//!   - `rpt_failure_pareto_cache`    → failure causes ranked by count
+//!
+//! Without an explicit window the most recent ingested window is returned.
+//! A tenant with no snapshots yields an empty report, not an error.
+
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use sqlx::PgPool;
+
+// ── Output types ──────────────────────────────────────────────────────────────
+
+/// Reliability metrics for one scope of a snapshot window.
+#[derive(Debug, Serialize, sqlx::FromRow, utoipa::ToSchema)]
+pub struct ReliabilityRow {
+    /// `tenant`, `asset` or `asset_type`.
+    pub scope_type: String,
+    /// `all` for the tenant row, otherwise the asset id or asset type.
+    pub scope_key: String,
+    pub scope_label: Option<String>,
+    pub failure_count: i64,
+    pub repair_count: i64,
+    pub operating_hours: f64,
+    pub downtime_hours: f64,
+    pub uptime_hours: f64,
+    pub mtbf_hours: Option<f64>,
+    pub mttr_hours: Option<f64>,
+    pub mttf_hours: Option<f64>,
+    pub availability: Option<f64>,
+}
+
+#[derive(Debug, Serialize, sqlx::FromRow, utoipa::ToSchema)]
+pub struct FailureParetoRow {
+    pub rank: i32,
+    pub failure_code: String,
+    pub failure_count: i64,
+    pub downtime_hours: f64,
+    pub share_pct: f64,
+    pub cumulative_pct: f64,
+}
+
+#[derive(Debug, Serialize, utoipa::ToSchema)]
+pub struct AssetReliabilityReport {
+    pub period_start: Option<DateTime<Utc>>,
+    pub period_end: Option<DateTime<Utc>>,
+    pub rows: Vec<ReliabilityRow>,
+    pub pareto: Vec<FailureParetoRow>,
+}
+
+// ── Query functions ───────────────────────────────────────────────────────────
+
+/// Load the cached reliability snapshot for a window, or the latest window
+/// when `window` is `None`.
+pub async fn load_report(
+    pool: &PgPool,
+    tenant_id: &str,
+    window: Option<(DateTime<Utc>, DateTime<Utc>)>,
+) -> Result<AssetReliabilityReport, anyhow::Error> {
+    let window = match window {
+        Some(w) => Some(w),
+        None => sqlx::query_as::<_, (DateTime<Utc>, DateTime<Utc>)>(
+            r#"
+            SELECT period_start, period_end FROM rpt_asset_reliability_cache
+            WHERE tenant_id = $1
+            ORDER BY period_end DESC, period_start DESC
+            LIMIT 1
+            "#,
+        )
+        .bind(tenant_id)
+        .fetch_optional(pool)
+        .await
+        .map_err(|e| anyhow::anyhow!("Latest reliability window query failed: {}", e))?,
+    };
+
+    let Some((start, end)) = window else {
+        return Ok(AssetReliabilityReport {
+            period_start: None,
+            period_end: None,
+            rows: Vec::new(),
+            pareto: Vec::new(),
+        });
+    };
+
+    let rows = sqlx::query_as::<_, ReliabilityRow>(
+        r#"
+        SELECT scope_type, scope_key, scope_label, failure_count, repair_count,
+               operating_hours, downtime_hours, uptime_hours,
+               mtbf_hours, mttr_hours, mttf_hours, availability
+        FROM rpt_asset_reliability_cache
+        WHERE tenant_id = $1 AND period_start = $2 AND period_end = $3
+        ORDER BY CASE scope_type WHEN 'tenant' THEN 0 WHEN 'asset_type' THEN 1 ELSE 2 END,
+                 scope_key
+        "#,
+    )
+    .bind(tenant_id)
+    .bind(start)
+    .bind(end)
+    .fetch_all(pool)
+    .await
+    .map_err(|e| anyhow::anyhow!("Reliability cache query failed: {}", e))?;
+
+    let pareto = sqlx::query_as::<_, FailureParetoRow>(
+        r#"
+        SELECT rank, failure_code, failure_count, downtime_hours, share_pct, cumulative_pct
+        FROM rpt_failure_pareto_cache
+        WHERE tenant_id = $1 AND period_start = $2 AND period_end = $3
+        ORDER BY rank
+        "#,
+    )
+    .bind(tenant_id)
+    .bind(start)
+    .bind(end)
+    .fetch_all(pool)
+    .await
+    .map_err(|e| anyhow::anyhow!("Failure pareto cache query failed: {}", e))?;
+
+    Ok(AssetReliabilityReport {
+        period_start: Some(start),
+        period_end: Some(end),
+        rows,
+        pareto,
+    })
+}
@@ -1,4 +1,5 @@
 pub mod aging;
+pub mod asset_reliability;
 pub mod dashboards;
 pub mod exports;
 pub mod forecast;
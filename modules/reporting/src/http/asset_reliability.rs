@@ -0,0 +1,86 @@
+//! HTTP handler for cached maintenance reliability metrics.
+//!
+//! Endpoint:
+//!   GET /api/reporting/asset-reliability?period_start=...&period_end=...
+//!
+//! Returns the reliability snapshot ingested from maintenance for the given
+//! window, or the most recent window when none is given.
+
+use axum::{
+    extract::{Query, State},
+    response::IntoResponse,
+    Extension, Json,
+};
+use chrono::{DateTime, Utc};
+use event_bus::TracingContext;
+use platform_http_contracts::ApiError;
+use security::VerifiedClaims;
+use serde::Deserialize;
+use std::sync::Arc;
+
+use crate::domain::asset_reliability::{load_report, AssetReliabilityReport};
+
+use super::tenant::with_request_id;
+use platform_sdk::extract_tenant;
+
+// ── Query params ─────────────────────────────────────────────────────────────
+
+#[derive(Debug, Deserialize, utoipa::IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct AssetReliabilityParams {
+    /// Window start; must be given together with `period_end`.
+    pub period_start: Option<DateTime<Utc>>,
+    /// Window end; must be given together with `period_start`.
+    pub period_end: Option<DateTime<Utc>>,
+}
+
+// ── Handler ──────────────────────────────────────────────────────────────────
+
+/// GET /api/reporting/asset-reliability — cached MTBF / MTTR / availability.
+#[utoipa::path(
+    get,
+    path = "/api/reporting/asset-reliability",
+    tag = "Maintenance",
+    params(AssetReliabilityParams),
+    responses(
+        (status = 200, description = "Reliability snapshot", body = AssetReliabilityReport),
+        (status = 400, description = "Incomplete window", body = ApiError),
+        (status = 401, description = "Unauthorized", body = ApiError),
+        (status = 500, description = "Internal error", body = ApiError),
+    ),
+    security(("bearer" = ["REPORTING_READ"]))
+)]
+pub async fn get_asset_reliability(
+    State(state): State<Arc<crate::AppState>>,
+    claims: Option<Extension<VerifiedClaims>>,
+    tracing_ctx: Option<Extension<TracingContext>>,
+    Query(params): Query<AssetReliabilityParams>,
+) -> impl IntoResponse {
+    let tenant_id = match extract_tenant(&claims) {
+        Ok(id) => id,
+        Err(e) => return with_request_id(e, &tracing_ctx).into_response(),
+    };
+
+    let window = match (params.period_start, params.period_end) {
+        (Some(start), Some(end)) => Some((start, end)),
+        (None, None) => None,
+        _ => {
+            let api_err =
+                ApiError::bad_request("period_start and period_end must be given together");
+            return with_request_id(api_err, &tracing_ctx).into_response();
+        }
+    };
+
+    match load_report(&state.pool, &tenant_id, window).await {
+        Ok(report) => Json(report).into_response(),
+        Err(e) => {
+            tracing::error!(
+                tenant_id = %tenant_id,
+                error = %e,
+                "Asset reliability query failed"
+            );
+            let api_err = ApiError::internal("Asset reliability query failed");
+            with_request_id(api_err, &tracing_ctx).into_response()
+        }
+    }
+}
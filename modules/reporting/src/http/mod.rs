@@ -1,5 +1,6 @@
 pub mod admin;
 pub mod aging;
+pub mod asset_reliability;
 pub mod cashflow;
 pub mod forecast;
 pub mod kpis;
@@ -29,6 +29,7 @@ pub mod ar;
 pub mod checkpoints;
 pub mod gl;
 pub mod inventory;
+pub mod maintenance;
 pub mod metrics;
 pub mod payments;
 pub mod runner;
@@ -14,6 +14,7 @@ use platform_http_contracts::ApiError;
 use platform_http_contracts::{PaginatedResponse, PaginationMeta};
 use reporting::domain::{
     aging::{ap_aging, ar_aging},
+    asset_reliability::{AssetReliabilityReport, FailureParetoRow, ReliabilityRow},
     forecast::types::{AtRiskItem, CashForecastResponse, CurrencyForecast, ForecastHorizon},
     jobs::snapshot_runner::SnapshotRunResult,
     kpis::KpiSnapshot,
@@ -46,6 +47,7 @@ use reporting::http::{
         reporting::http::aging::get_ar_aging,
         reporting::http::aging::get_ap_aging,
         reporting::http::kpis::get_kpis,
+        reporting::http::asset_reliability::get_asset_reliability,
         reporting::http::forecast::get_forecast,
         reporting::http::admin::rebuild,
         reporting::http::admin::projection_status,
@@ -63,6 +65,7 @@ use reporting::http::{
         ArAgingResponse,
         ap_aging::ApAgingReport, ap_aging::VendorAgingRow, ap_aging::CurrencySummary,
         KpiSnapshot,
+        AssetReliabilityReport, ReliabilityRow, FailureParetoRow,
         CashForecastResponse, CurrencyForecast, ForecastHorizon, AtRiskItem,
         SnapshotRunResult,
         RebuildRequest,
@@ -135,6 +138,10 @@ async fn main() {
                 .route("/api/reporting/ar-aging", get(http::aging::get_ar_aging))
                 .route("/api/reporting/ap-aging", get(http::aging::get_ap_aging))
                 .route("/api/reporting/kpis", get(http::kpis::get_kpis))
+                .route(
+                    "/api/reporting/asset-reliability",
+                    get(http::asset_reliability::get_asset_reliability),
+                )
                 .route("/api/reporting/forecast", get(http::forecast::get_forecast))
                 .route_layer(RequirePermissionsLayer::new(&[permissions::REPORTING_READ]))
                 .with_state(app_state);
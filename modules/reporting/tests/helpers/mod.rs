@@ -58,6 +58,9 @@ async fn inject_claims(req: Request, next: Next) -> Response {
                 app_id: None,
                 roles: vec!["admin".to_string()],
                 perms: vec![],
+                scopes: Default::default(),
+                amr: Vec::new(),
+                auth_time: None,
                 actor_type: ActorType::User,
                 issued_at: chrono::Utc::now(),
                 expires_at: chrono::Utc::now() + chrono::Duration::hours(1),
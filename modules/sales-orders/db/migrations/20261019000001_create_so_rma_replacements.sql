@@ -0,0 +1,14 @@
+-- One replacement order per RMA authorization
+--
+-- Shipping-receiving requests a no-charge replacement order when an RMA is
+-- resolved by replacement. The event may be redelivered (or delivered to two
+-- consumers at once); the primary key makes the order creation claim atomic,
+-- so an RMA never gets a second replacement order.
+
+CREATE TABLE so_rma_replacements (
+    tenant_id            TEXT NOT NULL,
+    rma_authorization_id UUID NOT NULL,
+    sales_order_id       UUID NOT NULL REFERENCES sales_orders(id) ON DELETE CASCADE,
+    created_at           TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (tenant_id, rma_authorization_id)
+);
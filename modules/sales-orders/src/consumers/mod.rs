@@ -1,2 +1,3 @@
 pub mod invoice_issued;
+pub mod rma_replacement;
 pub mod shipment_shipped;
@@ -0,0 +1,161 @@
+//! Consumer for shipping_receiving.rma.replacement_requested events.
+//! Creates a no-charge draft replacement order for the returned quantities.
+//! Lines referencing the original order copy its item, description, UoM and
+//! warehouse; the order carries `rma:<rma_number>` as its external reference.
+//! Redelivery is a no-op: the order is created at most once per RMA
+//! authorization (see `service::create_rma_replacement_order`).
+
+use event_bus::EventBus;
+use futures::StreamExt;
+use serde::Deserialize;
+use sqlx::PgPool;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::domain::orders::{
+    repo, service, CreateOrderLineRequest, CreateOrderRequest, OrderError, SalesOrder,
+};
+
+const SUBJECT: &str = "shipping_receiving.rma.replacement_requested";
+#[allow(dead_code)]
+const QUEUE_GROUP: &str = "sales-orders-rma-replacement";
+
+// ── Incoming payload structs (mirror shipping_receiving.rma.replacement_requested) ──
+
+#[derive(Debug, Deserialize)]
+pub struct RmaReplacementLine {
+    pub sku: String,
+    pub qty: i64,
+    #[serde(default)]
+    pub so_line_id: Option<Uuid>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct RmaReplacementRequestedPayload {
+    pub tenant_id: String,
+    pub rma_authorization_id: Uuid,
+    pub rma_number: String,
+    pub customer_id: Uuid,
+    #[serde(default)]
+    pub original_sales_order_id: Option<Uuid>,
+    pub currency: String,
+    pub lines: Vec<RmaReplacementLine>,
+}
+
+#[derive(Deserialize)]
+struct EventEnvelopeShim<T> {
+    pub payload: T,
+}
+
+// ── Consumer ──────────────────────────────────────────────────────────────────
+
+pub fn start_rma_replacement_consumer(bus: Arc<dyn EventBus>, pool: PgPool) {
+    tokio::spawn(async move {
+        consume(bus, pool).await;
+    });
+}
+
+async fn consume(bus: Arc<dyn EventBus>, pool: PgPool) {
+    let mut stream = match bus.subscribe(SUBJECT).await {
+        Ok(s) => s,
+        Err(e) => {
+            tracing::error!("SO: failed to subscribe to {}: {}", SUBJECT, e);
+            return;
+        }
+    };
+
+    while let Some(msg) = stream.next().await {
+        if let Err(e) = process_message(&msg, &pool).await {
+            tracing::error!("SO: rma.replacement_requested processing error: {}", e);
+        }
+    }
+}
+
+async fn process_message(
+    msg: &event_bus::BusMessage,
+    pool: &PgPool,
+) -> Result<(), Box<dyn std::error::Error>> {
+    // SR publishes the raw payload; accept an enveloped one as well.
+    let payload = match serde_json::from_slice::<EventEnvelopeShim<RmaReplacementRequestedPayload>>(
+        &msg.payload,
+    ) {
+        Ok(shim) => shim.payload,
+        Err(_) => serde_json::from_slice::<RmaReplacementRequestedPayload>(&msg.payload)?,
+    };
+    handle_replacement_requested(pool, &payload).await?;
+    Ok(())
+}
+
+/// Create (or return the existing) draft replacement order for an RMA.
+pub async fn handle_replacement_requested(
+    pool: &PgPool,
+    payload: &RmaReplacementRequestedPayload,
+) -> Result<SalesOrder, OrderError> {
+    let tenant_id = payload.tenant_id.as_str();
+    let external_ref = format!("rma:{}", payload.rma_number);
+
+    let order = service::create_rma_replacement_order(
+        pool,
+        tenant_id,
+        "shipping-receiving-rma",
+        payload.rma_authorization_id,
+        CreateOrderRequest {
+            customer_id: Some(payload.customer_id),
+            party_id: None,
+            currency: payload.currency.to_uppercase(),
+            order_date: None,
+            required_date: None,
+            promised_date: None,
+            external_quote_ref: Some(external_ref),
+            notes: Some(format!("Replacement for RMA {}", payload.rma_number)),
+        },
+    )
+    .await?;
+
+    // Lines already on the order came from an earlier, partially applied delivery.
+    let existing = repo::fetch_lines_for_order(pool, order.id, tenant_id).await?;
+    let original = match payload.original_sales_order_id {
+        Some(so_id) => repo::fetch_lines_for_order(pool, so_id, tenant_id).await?,
+        None => Vec::new(),
+    };
+
+    for line in &payload.lines {
+        if existing
+            .iter()
+            .any(|l| l.part_number.as_deref() == Some(line.sku.as_str()))
+        {
+            continue;
+        }
+        let source = line
+            .so_line_id
+            .and_then(|id| original.iter().find(|l| l.id == id));
+        service::add_line(
+            pool,
+            tenant_id,
+            order.id,
+            CreateOrderLineRequest {
+                item_id: source.and_then(|l| l.item_id),
+                part_number: Some(line.sku.clone()),
+                description: source
+                    .map(|l| l.description.clone())
+                    .unwrap_or_else(|| line.sku.clone()),
+                uom: source.map(|l| l.uom.clone()),
+                quantity: line.qty as f64,
+                unit_price_cents: 0,
+                required_date: None,
+                promised_date: None,
+                warehouse_id: source.and_then(|l| l.warehouse_id),
+                notes: Some(format!("RMA {} replacement", payload.rma_number)),
+            },
+        )
+        .await?;
+    }
+
+    tracing::info!(
+        order_id = %order.id,
+        rma_number = %payload.rma_number,
+        lines = payload.lines.len(),
+        "SO: replacement order created for RMA"
+    );
+    Ok(order)
+}
@@ -30,6 +30,30 @@ pub async fn fetch_order_for_mutation<'e>(
     .await
 }
 
+/// The replacement order already created for an RMA authorization, if any.
+pub async fn fetch_rma_replacement_order<'e>(
+    executor: impl PgExecutor<'e>,
+    tenant_id: &str,
+    rma_authorization_id: Uuid,
+) -> Result<Option<SalesOrder>, sqlx::Error> {
+    sqlx::query_as::<_, SalesOrder>(
+        r#"
+        SELECT o.id, o.tenant_id, o.order_number, o.status, o.customer_id, o.party_id,
+               o.currency, o.subtotal_cents, o.tax_cents, o.total_cents,
+               o.order_date, o.required_date, o.promised_date, o.external_quote_ref,
+               o.blanket_order_id, o.blanket_release_id, o.notes, o.created_by,
+               o.created_at, o.updated_at
+        FROM so_rma_replacements r
+        JOIN sales_orders o ON o.id = r.sales_order_id
+        WHERE r.tenant_id = $1 AND r.rma_authorization_id = $2
+        "#,
+    )
+    .bind(tenant_id)
+    .bind(rma_authorization_id)
+    .fetch_optional(executor)
+    .await
+}
+
 // ── Query reads ───────────────────────────────────────────────────────────────
 
 pub async fn fetch_lines_for_order<'e>(
@@ -174,6 +198,29 @@ pub async fn insert_order<'e>(
     .await
 }
 
+/// Record `sales_order_id` as the replacement order of an RMA authorization.
+/// Returns false (and writes nothing) when the RMA already has one.
+pub async fn insert_rma_replacement<'e>(
+    executor: impl PgExecutor<'e>,
+    tenant_id: &str,
+    rma_authorization_id: Uuid,
+    sales_order_id: Uuid,
+) -> Result<bool, sqlx::Error> {
+    let result = sqlx::query(
+        r#"
+        INSERT INTO so_rma_replacements (tenant_id, rma_authorization_id, sales_order_id)
+        VALUES ($1, $2, $3)
+        ON CONFLICT (tenant_id, rma_authorization_id) DO NOTHING
+        "#,
+    )
+    .bind(tenant_id)
+    .bind(rma_authorization_id)
+    .bind(sales_order_id)
+    .execute(executor)
+    .await?;
+    Ok(result.rows_affected() == 1)
+}
+
 pub async fn update_order_header<'e>(
     executor: impl PgExecutor<'e>,
     id: Uuid,
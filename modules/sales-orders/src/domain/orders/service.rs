@@ -47,6 +47,59 @@ pub async fn create_order(
     Ok(order)
 }
 
+/// Create the draft replacement order for an RMA authorization, or return the
+/// one already created for it.
+///
+/// The order and its `so_rma_replacements` claim commit together; a delivery
+/// that loses the race rolls its order back and returns the winner's.
+pub async fn create_rma_replacement_order(
+    pool: &PgPool,
+    tenant_id: &str,
+    created_by: &str,
+    rma_authorization_id: Uuid,
+    req: CreateOrderRequest,
+) -> Result<SalesOrder, OrderError> {
+    if let Some(existing) =
+        repo::fetch_rma_replacement_order(pool, tenant_id, rma_authorization_id).await?
+    {
+        return Ok(existing);
+    }
+
+    let order_date = req
+        .order_date
+        .unwrap_or_else(|| chrono::Utc::now().date_naive());
+
+    let mut tx = pool.begin().await?;
+    let order = repo::insert_order(
+        &mut *tx,
+        Uuid::new_v4(),
+        tenant_id,
+        &generate_order_number(),
+        req.customer_id,
+        req.party_id,
+        &req.currency,
+        order_date,
+        req.required_date,
+        req.promised_date,
+        req.external_quote_ref.as_deref(),
+        None,
+        None,
+        req.notes.as_deref(),
+        created_by,
+    )
+    .await?;
+
+    if !repo::insert_rma_replacement(&mut *tx, tenant_id, rma_authorization_id, order.id).await? {
+        tx.rollback().await?;
+        return repo::fetch_rma_replacement_order(pool, tenant_id, rma_authorization_id)
+            .await?
+            .ok_or(OrderError::Database(sqlx::Error::RowNotFound));
+    }
+
+    tx.commit().await?;
+    Ok(order)
+}
+
 pub async fn get_order_with_lines(
     pool: &PgPool,
     tenant_id: &str,
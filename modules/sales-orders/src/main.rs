@@ -28,6 +28,10 @@ async fn main() {
                     bus.clone(),
                     ctx.pool().clone(),
                 );
+                consumers::rma_replacement::start_rma_replacement_consumer(
+                    bus.clone(),
+                    ctx.pool().clone(),
+                );
                 consumers::invoice_issued::start_invoice_issued_consumer(bus, ctx.pool().clone());
                 tracing::info!("SO: event consumers started");
             }
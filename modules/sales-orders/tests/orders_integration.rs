@@ -625,3 +625,85 @@ async fn invoice_issued_closes_order_when_all_lines_invoiced() {
         .expect("get order");
     assert_eq!(final_order.order.status, "closed");
 }
+
+// ============================================================================
+// RMA replacement orders
+// ============================================================================
+
+#[tokio::test]
+#[serial]
+async fn test_rma_replacement_creates_no_charge_draft_once() {
+    use sales_orders_rs::consumers::rma_replacement::{
+        handle_replacement_requested, RmaReplacementLine, RmaReplacementRequestedPayload,
+    };
+
+    let pool = setup_db().await;
+    let tenant = unique_tenant();
+
+    let original = service::create_order(&pool, &tenant, "test-user", base_order_req())
+        .await
+        .expect("create original");
+    let original_line = service::add_line(
+        &pool,
+        &tenant,
+        original.id,
+        CreateOrderLineRequest {
+            part_number: Some("PART-A100".to_string()),
+            ..line_req(3.0, 1000)
+        },
+    )
+    .await
+    .expect("add original line");
+
+    let payload = RmaReplacementRequestedPayload {
+        tenant_id: tenant.clone(),
+        rma_authorization_id: Uuid::new_v4(),
+        rma_number: "RMA-REP-1".to_string(),
+        customer_id: original.customer_id.expect("customer"),
+        original_sales_order_id: Some(original.id),
+        currency: "usd".to_string(),
+        lines: vec![
+            RmaReplacementLine {
+                sku: "PART-A100".to_string(),
+                qty: 2,
+                so_line_id: Some(original_line.id),
+            },
+            RmaReplacementLine {
+                sku: "PART-B200".to_string(),
+                qty: 1,
+                so_line_id: None,
+            },
+        ],
+    };
+
+    let replacement = handle_replacement_requested(&pool, &payload)
+        .await
+        .expect("create replacement");
+    let redelivered = handle_replacement_requested(&pool, &payload)
+        .await
+        .expect("redelivery must not error");
+    assert_eq!(replacement.id, redelivered.id, "redelivery reuses the order");
+
+    let detail = service::get_order_with_lines(&pool, &tenant, replacement.id)
+        .await
+        .expect("get replacement");
+    assert_eq!(detail.order.status, "draft");
+    assert_eq!(detail.order.currency, "USD");
+    assert_eq!(detail.order.external_quote_ref.as_deref(), Some("rma:RMA-REP-1"));
+    assert_eq!(detail.order.total_cents, 0);
+    assert_eq!(detail.lines.len(), 2);
+    assert_eq!(detail.lines[0].part_number.as_deref(), Some("PART-A100"));
+    assert_eq!(detail.lines[0].description, original_line.description);
+    assert_eq!(detail.lines[0].quantity, 2.0);
+    assert_eq!(detail.lines[0].unit_price_cents, 0);
+
+    // Dedupe is keyed on the RMA authorization, not the (non-unique) external ref.
+    let other_rma = RmaReplacementRequestedPayload {
+        rma_authorization_id: Uuid::new_v4(),
+        ..payload
+    };
+    let other = handle_replacement_requested(&pool, &other_rma)
+        .await
+        .expect("replacement for another RMA");
+    assert_ne!(other.id, replacement.id);
+}
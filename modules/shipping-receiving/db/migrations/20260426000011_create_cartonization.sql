@@ -0,0 +1,102 @@
+-- Shipping-Receiving: Cartonization and packing
+--
+-- sr_carton_types:          tenant carton master data (inner dimensions, tare, max gross weight)
+-- sr_sku_pack_profiles:     per-SKU unit dimensions, weight and packing constraints
+-- sr_shipment_cartons:      cartons of an outbound shipment (planned or added at the pack station)
+-- sr_shipment_carton_lines: planned and scanned quantity per shipment line per carton
+--
+-- Carton status: open → closed. Contents can only change while open.
+-- Dimensions are inches, weights are pounds (same units as carrier PackageInfo).
+
+-- ─── sr_carton_types ─────────────────────────────────────────────────────────
+
+CREATE TABLE sr_carton_types (
+    id              UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    tenant_id       UUID NOT NULL,
+    code            TEXT NOT NULL,
+    description     TEXT,
+    length_in       DOUBLE PRECISION NOT NULL,
+    width_in        DOUBLE PRECISION NOT NULL,
+    height_in       DOUBLE PRECISION NOT NULL,
+    tare_weight_lbs DOUBLE PRECISION NOT NULL DEFAULT 0,
+    max_weight_lbs  DOUBLE PRECISION NOT NULL,
+    is_active       BOOLEAN NOT NULL DEFAULT TRUE,
+    created_at      TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at      TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+
+    CONSTRAINT uq_sr_carton_types_code UNIQUE (tenant_id, code),
+    CONSTRAINT ck_sr_carton_types_dims
+        CHECK (length_in > 0 AND width_in > 0 AND height_in > 0),
+    CONSTRAINT ck_sr_carton_types_weight
+        CHECK (tare_weight_lbs >= 0 AND max_weight_lbs > tare_weight_lbs)
+);
+
+-- ─── sr_sku_pack_profiles ────────────────────────────────────────────────────
+
+CREATE TABLE sr_sku_pack_profiles (
+    tenant_id    UUID NOT NULL,
+    sku          TEXT NOT NULL,
+    length_in    DOUBLE PRECISION NOT NULL,
+    width_in     DOUBLE PRECISION NOT NULL,
+    height_in    DOUBLE PRECISION NOT NULL,
+    weight_lbs   DOUBLE PRECISION NOT NULL,
+    -- every unit gets its own carton
+    ship_alone   BOOLEAN NOT NULL DEFAULT FALSE,
+    -- height must stay vertical; only the footprint may rotate
+    this_side_up BOOLEAN NOT NULL DEFAULT FALSE,
+    -- SKUs only share a carton with SKUs of the same group (NULL = ungrouped)
+    pack_group   TEXT,
+    updated_at   TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+
+    PRIMARY KEY (tenant_id, sku),
+    CONSTRAINT ck_sr_sku_pack_profiles_dims
+        CHECK (length_in > 0 AND width_in > 0 AND height_in > 0),
+    CONSTRAINT ck_sr_sku_pack_profiles_weight CHECK (weight_lbs >= 0)
+);
+
+-- ─── sr_shipment_cartons ─────────────────────────────────────────────────────
+
+CREATE TABLE sr_shipment_cartons (
+    id               UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    tenant_id        UUID NOT NULL,
+    shipment_id      UUID NOT NULL REFERENCES shipments(id),
+    carton_number    INTEGER NOT NULL,
+    carton_type_id   UUID NOT NULL REFERENCES sr_carton_types(id),
+    status           TEXT NOT NULL DEFAULT 'open',
+    -- true when created by the packing algorithm, false when added at the pack station
+    planned          BOOLEAN NOT NULL,
+    gross_weight_lbs DOUBLE PRECISION,
+    tracking_number  TEXT,
+    closed_at        TIMESTAMPTZ,
+    closed_by        UUID,
+    created_at       TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at       TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+
+    CONSTRAINT uq_sr_shipment_cartons_number UNIQUE (shipment_id, carton_number),
+    CONSTRAINT ck_sr_shipment_cartons_status CHECK (status IN ('open', 'closed')),
+    CONSTRAINT ck_sr_shipment_cartons_closed
+        CHECK (status = 'open' OR (closed_at IS NOT NULL AND gross_weight_lbs IS NOT NULL))
+);
+
+CREATE INDEX idx_sr_shipment_cartons_shipment
+    ON sr_shipment_cartons (tenant_id, shipment_id);
+
+-- ─── sr_shipment_carton_lines ────────────────────────────────────────────────
+
+CREATE TABLE sr_shipment_carton_lines (
+    id               UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    tenant_id        UUID NOT NULL,
+    carton_id        UUID NOT NULL REFERENCES sr_shipment_cartons(id) ON DELETE CASCADE,
+    shipment_line_id UUID NOT NULL REFERENCES shipment_lines(id),
+    sku              TEXT NOT NULL,
+    qty_planned      BIGINT NOT NULL DEFAULT 0,
+    qty_packed       BIGINT NOT NULL DEFAULT 0,
+    updated_at       TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+
+    CONSTRAINT uq_sr_shipment_carton_lines UNIQUE (carton_id, shipment_line_id),
+    CONSTRAINT ck_sr_carton_line_qty_planned CHECK (qty_planned >= 0),
+    CONSTRAINT ck_sr_carton_line_qty_packed  CHECK (qty_packed  >= 0)
+);
+
+CREATE INDEX idx_sr_shipment_carton_lines_line
+    ON sr_shipment_carton_lines (tenant_id, shipment_line_id);
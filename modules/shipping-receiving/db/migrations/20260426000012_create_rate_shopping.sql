@@ -0,0 +1,72 @@
+-- Shipping-Receiving: Multi-carrier rate shopping
+--
+-- sr_rate_shop_rules:      per-tenant carrier set and selection rules (one row per tenant)
+-- sr_rate_shop_exclusions: carriers excluded for a destination (NULL fields match anything)
+-- sr_rate_shop_decisions:  every rate-shop run on a shipment — the selected quote,
+--                          the rule that picked it, the rationale and all normalized quotes
+--
+-- Weights are pounds; charges are minor currency units (same as carrier RateQuote).
+
+-- ─── sr_rate_shop_rules ──────────────────────────────────────────────────────
+
+CREATE TABLE sr_rate_shop_rules (
+    tenant_id               UUID PRIMARY KEY,
+    -- carrier codes to quote (must be registered providers)
+    carrier_codes           TEXT[] NOT NULL,
+    -- preferred carrier wins when within preferred_tolerance_pct of the cheapest quote
+    preferred_carrier_code  TEXT,
+    preferred_tolerance_pct DOUBLE PRECISION NOT NULL DEFAULT 0,
+    -- at or above this weight only LTL carriers are eligible, below it only parcel (NULL = no split)
+    ltl_min_weight_lbs      DOUBLE PRECISION,
+    -- quotes in other currencies are not comparable and are ineligible
+    currency                TEXT NOT NULL DEFAULT 'USD',
+    -- per-carrier quote timeout
+    carrier_timeout_ms      INTEGER NOT NULL DEFAULT 5000,
+    updated_at              TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+
+    CONSTRAINT ck_sr_rate_shop_rules_carriers CHECK (cardinality(carrier_codes) > 0),
+    CONSTRAINT ck_sr_rate_shop_rules_tolerance CHECK (preferred_tolerance_pct >= 0),
+    CONSTRAINT ck_sr_rate_shop_rules_ltl CHECK (ltl_min_weight_lbs IS NULL OR ltl_min_weight_lbs > 0),
+    CONSTRAINT ck_sr_rate_shop_rules_timeout CHECK (carrier_timeout_ms > 0)
+);
+
+-- ─── sr_rate_shop_exclusions ─────────────────────────────────────────────────
+
+CREATE TABLE sr_rate_shop_exclusions (
+    id              UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    tenant_id       UUID NOT NULL,
+    carrier_code    TEXT NOT NULL,
+    dest_country    TEXT,
+    dest_state      TEXT,
+    dest_zip_prefix TEXT,
+    reason          TEXT,
+    created_at      TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_sr_rate_shop_exclusions_tenant ON sr_rate_shop_exclusions (tenant_id);
+
+-- ─── sr_rate_shop_decisions ──────────────────────────────────────────────────
+
+CREATE TABLE sr_rate_shop_decisions (
+    id                 UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    tenant_id          UUID NOT NULL,
+    shipment_id        UUID NOT NULL REFERENCES shipments(id),
+    carrier_code       TEXT NOT NULL,
+    service_level      TEXT NOT NULL,
+    total_charge_minor BIGINT NOT NULL,
+    currency           TEXT NOT NULL,
+    transit_days       INTEGER,
+    guaranteed         BOOLEAN NOT NULL,
+    -- cheapest | cheapest_within_promise | preferred_carrier
+    rule_applied       TEXT NOT NULL,
+    rationale          TEXT NOT NULL,
+    -- all normalized quotes with eligibility, plus carrier failures
+    quotes             JSONB NOT NULL,
+    weight_lbs         DOUBLE PRECISION NOT NULL,
+    max_transit_days   INTEGER,
+    decided_by         UUID,
+    created_at         TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_sr_rate_shop_decisions_shipment
+    ON sr_rate_shop_decisions (tenant_id, shipment_id, created_at DESC);
@@ -0,0 +1,123 @@
+-- Shipping-Receiving: RMA authorization and financial resolution
+--
+-- An RMA is authorized before goods come back, linked to the original sales
+-- order and AR invoice. Receipts are recorded against the authorization and,
+-- once every receipt reaches a terminal disposition, the RMA is resolved:
+--   credit | refund → AR credit note for the returned value less restocking fee
+--   replacement     → replacement sales order for the returned quantities
+--   repair          → no financial document
+-- Inventory adjustments follow each receipt's disposition.
+--
+-- Header state machine:
+--   authorized → received → resolved
+--   authorized → cancelled
+
+-- ─── rma_restocking_fee_rules ──────────────────────────────────────────────────
+
+CREATE TABLE rma_restocking_fee_rules (
+    tenant_id           UUID PRIMARY KEY,
+    -- Fee as basis points of the returned line value (1500 = 15%)
+    fee_bps             INTEGER NOT NULL DEFAULT 0,
+    -- Floor applied to the total fee when any fee is charged
+    min_fee_minor       BIGINT NOT NULL DEFAULT 0,
+    -- Return reasons that never carry a fee (e.g. defective, wrong_item)
+    waived_reason_codes TEXT[] NOT NULL DEFAULT '{}',
+    -- Charge the fee on scrapped lines as well as restocked lines
+    apply_to_scrap      BOOLEAN NOT NULL DEFAULT FALSE,
+    created_at          TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at          TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+
+    CONSTRAINT ck_rma_fee_bps CHECK (fee_bps BETWEEN 0 AND 10000),
+    CONSTRAINT ck_rma_min_fee CHECK (min_fee_minor >= 0)
+);
+
+-- ─── rma_authorizations ────────────────────────────────────────────────────────
+
+CREATE TABLE rma_authorizations (
+    id                     UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    tenant_id              UUID NOT NULL,
+    rma_number             TEXT NOT NULL,
+    customer_id            UUID NOT NULL,
+    sales_order_id         UUID,
+    ar_invoice_id          INTEGER,
+    resolution_type        TEXT NOT NULL,
+    reason_code            TEXT NOT NULL,
+    status                 TEXT NOT NULL DEFAULT 'authorized',
+    currency               TEXT NOT NULL DEFAULT 'usd',
+    -- Restock destination; without it restocked lines are not inventory-tracked
+    warehouse_id           UUID,
+    notes                  TEXT,
+    gross_credit_minor     BIGINT,
+    restocking_fee_minor   BIGINT,
+    net_credit_minor       BIGINT,
+    credit_note_id         UUID,
+    replacement_request_id UUID,
+    authorized_by          UUID,
+    resolved_by            UUID,
+    resolved_at            TIMESTAMPTZ,
+    cancelled_at           TIMESTAMPTZ,
+    created_at             TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at             TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+
+    CONSTRAINT uq_rma_authorizations_number UNIQUE (tenant_id, rma_number),
+    CONSTRAINT ck_rma_resolution_type
+        CHECK (resolution_type IN ('credit', 'refund', 'replacement', 'repair')),
+    CONSTRAINT ck_rma_authorization_status
+        CHECK (status IN ('authorized', 'received', 'resolved', 'cancelled'))
+);
+
+CREATE INDEX idx_rma_authorizations_tenant_status
+    ON rma_authorizations (tenant_id, status);
+
+CREATE INDEX idx_rma_authorizations_tenant_customer
+    ON rma_authorizations (tenant_id, customer_id);
+
+-- ─── rma_authorization_lines ───────────────────────────────────────────────────
+
+CREATE TABLE rma_authorization_lines (
+    id                   UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    tenant_id            UUID NOT NULL,
+    rma_authorization_id UUID NOT NULL REFERENCES rma_authorizations(id),
+    sku                  TEXT NOT NULL,
+    qty_authorized       BIGINT NOT NULL,
+    unit_price_minor     BIGINT NOT NULL DEFAULT 0,
+    so_line_id           UUID,
+    created_at           TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+
+    CONSTRAINT uq_rma_authorization_lines_sku UNIQUE (rma_authorization_id, sku),
+    CONSTRAINT ck_rma_line_qty CHECK (qty_authorized > 0),
+    CONSTRAINT ck_rma_line_price CHECK (unit_price_minor >= 0)
+);
+
+CREATE INDEX idx_rma_authorization_lines_tenant_auth
+    ON rma_authorization_lines (tenant_id, rma_authorization_id);
+
+-- ─── rma_receipts → authorization ──────────────────────────────────────────────
+
+ALTER TABLE rma_receipts
+    ADD COLUMN rma_authorization_id UUID REFERENCES rma_authorizations(id);
+
+CREATE INDEX idx_rma_receipts_authorization
+    ON rma_receipts (rma_authorization_id)
+    WHERE rma_authorization_id IS NOT NULL;
+
+-- ─── rma_inventory_adjustments ─────────────────────────────────────────────────
+
+CREATE TABLE rma_inventory_adjustments (
+    id                   UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    tenant_id            UUID NOT NULL,
+    rma_authorization_id UUID NOT NULL REFERENCES rma_authorizations(id),
+    rma_receipt_item_id  UUID NOT NULL REFERENCES rma_receipt_items(id),
+    sku                  TEXT NOT NULL,
+    qty                  BIGINT NOT NULL,
+    adjustment_type      TEXT NOT NULL,
+    -- Inventory receipt line for restocked items; NULL when not inventory-tracked
+    inventory_ref_id     UUID,
+    created_at           TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+
+    CONSTRAINT uq_rma_inventory_adjustments_item UNIQUE (rma_receipt_item_id),
+    CONSTRAINT ck_rma_adjustment_type CHECK (adjustment_type IN ('restock', 'scrap'))
+);
+
+CREATE INDEX idx_rma_inventory_adjustments_tenant_auth
+    ON rma_inventory_adjustments (tenant_id, rma_authorization_id);
@@ -0,0 +1,472 @@
+//! Repository for carton types, SKU pack profiles and shipment cartons.
+//!
+//! Pure data access — no business logic. All queries filter by tenant_id.
+
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::domain::cartonization::{
+    CartonLine, CartonType, CartonTypeRequest, ShipmentCarton, SkuPackProfile,
+    SkuPackProfileRequest,
+};
+
+pub struct CartonizationRepo;
+
+impl CartonizationRepo {
+    // ── Carton types ─────────────────────────────────────────
+
+    pub async fn insert_carton_type(
+        pool: &PgPool,
+        tenant_id: Uuid,
+        req: &CartonTypeRequest,
+    ) -> Result<CartonType, sqlx::Error> {
+        sqlx::query_as::<_, CartonType>(
+            r#"
+            INSERT INTO sr_carton_types
+                (tenant_id, code, description, length_in, width_in, height_in,
+                 tare_weight_lbs, max_weight_lbs, is_active)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
+            RETURNING *
+            "#,
+        )
+        .bind(tenant_id)
+        .bind(req.code.trim())
+        .bind(&req.description)
+        .bind(req.length_in)
+        .bind(req.width_in)
+        .bind(req.height_in)
+        .bind(req.tare_weight_lbs)
+        .bind(req.max_weight_lbs)
+        .bind(req.is_active)
+        .fetch_one(pool)
+        .await
+    }
+
+    pub async fn update_carton_type(
+        pool: &PgPool,
+        id: Uuid,
+        tenant_id: Uuid,
+        req: &CartonTypeRequest,
+    ) -> Result<Option<CartonType>, sqlx::Error> {
+        sqlx::query_as::<_, CartonType>(
+            r#"
+            UPDATE sr_carton_types
+            SET code = $3, description = $4, length_in = $5, width_in = $6,
+                height_in = $7, tare_weight_lbs = $8, max_weight_lbs = $9,
+                is_active = $10, updated_at = NOW()
+            WHERE id = $1 AND tenant_id = $2
+            RETURNING *
+            "#,
+        )
+        .bind(id)
+        .bind(tenant_id)
+        .bind(req.code.trim())
+        .bind(&req.description)
+        .bind(req.length_in)
+        .bind(req.width_in)
+        .bind(req.height_in)
+        .bind(req.tare_weight_lbs)
+        .bind(req.max_weight_lbs)
+        .bind(req.is_active)
+        .fetch_optional(pool)
+        .await
+    }
+
+    pub async fn list_carton_types(
+        pool: &PgPool,
+        tenant_id: Uuid,
+        include_inactive: bool,
+    ) -> Result<Vec<CartonType>, sqlx::Error> {
+        sqlx::query_as::<_, CartonType>(
+            r#"
+            SELECT * FROM sr_carton_types
+            WHERE tenant_id = $1 AND (is_active OR $2)
+            ORDER BY length_in * width_in * height_in, code
+            "#,
+        )
+        .bind(tenant_id)
+        .bind(include_inactive)
+        .fetch_all(pool)
+        .await
+    }
+
+    pub async fn find_active_carton_type_by_code(
+        pool: &PgPool,
+        tenant_id: Uuid,
+        code: &str,
+    ) -> Result<Option<CartonType>, sqlx::Error> {
+        sqlx::query_as::<_, CartonType>(
+            "SELECT * FROM sr_carton_types WHERE tenant_id = $1 AND code = $2 AND is_active",
+        )
+        .bind(tenant_id)
+        .bind(code)
+        .fetch_optional(pool)
+        .await
+    }
+
+    // ── SKU pack profiles ────────────────────────────────────
+
+    pub async fn upsert_sku_profile(
+        pool: &PgPool,
+        tenant_id: Uuid,
+        sku: &str,
+        req: &SkuPackProfileRequest,
+    ) -> Result<SkuPackProfile, sqlx::Error> {
+        sqlx::query_as::<_, SkuPackProfile>(
+            r#"
+            INSERT INTO sr_sku_pack_profiles
+                (tenant_id, sku, length_in, width_in, height_in, weight_lbs,
+                 ship_alone, this_side_up, pack_group)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
+            ON CONFLICT (tenant_id, sku) DO UPDATE
+            SET length_in = EXCLUDED.length_in, width_in = EXCLUDED.width_in,
+                height_in = EXCLUDED.height_in, weight_lbs = EXCLUDED.weight_lbs,
+                ship_alone = EXCLUDED.ship_alone, this_side_up = EXCLUDED.this_side_up,
+                pack_group = EXCLUDED.pack_group, updated_at = NOW()
+            RETURNING *
+            "#,
+        )
+        .bind(tenant_id)
+        .bind(sku)
+        .bind(req.length_in)
+        .bind(req.width_in)
+        .bind(req.height_in)
+        .bind(req.weight_lbs)
+        .bind(req.ship_alone)
+        .bind(req.this_side_up)
+        .bind(&req.pack_group)
+        .fetch_one(pool)
+        .await
+    }
+
+    pub async fn get_sku_profile(
+        pool: &PgPool,
+        tenant_id: Uuid,
+        sku: &str,
+    ) -> Result<Option<SkuPackProfile>, sqlx::Error> {
+        sqlx::query_as::<_, SkuPackProfile>(
+            "SELECT * FROM sr_sku_pack_profiles WHERE tenant_id = $1 AND sku = $2",
+        )
+        .bind(tenant_id)
+        .bind(sku)
+        .fetch_optional(pool)
+        .await
+    }
+
+    pub async fn get_sku_profiles(
+        pool: &PgPool,
+        tenant_id: Uuid,
+        skus: &[String],
+    ) -> Result<Vec<SkuPackProfile>, sqlx::Error> {
+        sqlx::query_as::<_, SkuPackProfile>(
+            "SELECT * FROM sr_sku_pack_profiles WHERE tenant_id = $1 AND sku = ANY($2)",
+        )
+        .bind(tenant_id)
+        .bind(skus)
+        .fetch_all(pool)
+        .await
+    }
+
+    // ── Shipment cartons ─────────────────────────────────────
+
+    /// Remove every carton of a shipment (lines cascade).
+    pub async fn delete_cartons_tx(
+        tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+        shipment_id: Uuid,
+        tenant_id: Uuid,
+    ) -> Result<u64, sqlx::Error> {
+        sqlx::query("DELETE FROM sr_shipment_cartons WHERE shipment_id = $1 AND tenant_id = $2")
+            .bind(shipment_id)
+            .bind(tenant_id)
+            .execute(&mut **tx)
+            .await
+            .map(|r| r.rows_affected())
+    }
+
+    /// Insert a carton numbered after the shipment's current last carton.
+    pub async fn insert_carton_tx(
+        tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+        tenant_id: Uuid,
+        shipment_id: Uuid,
+        carton_type_id: Uuid,
+        planned: bool,
+    ) -> Result<ShipmentCarton, sqlx::Error> {
+        sqlx::query_as::<_, ShipmentCarton>(
+            r#"
+            INSERT INTO sr_shipment_cartons
+                (tenant_id, shipment_id, carton_number, carton_type_id, planned)
+            SELECT $1, $2, COALESCE(MAX(carton_number), 0) + 1, $3, $4
+            FROM sr_shipment_cartons
+            WHERE shipment_id = $2
+            RETURNING *
+            "#,
+        )
+        .bind(tenant_id)
+        .bind(shipment_id)
+        .bind(carton_type_id)
+        .bind(planned)
+        .fetch_one(&mut **tx)
+        .await
+    }
+
+    pub async fn insert_planned_line_tx(
+        tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+        tenant_id: Uuid,
+        carton_id: Uuid,
+        shipment_line_id: Uuid,
+        sku: &str,
+        qty_planned: i64,
+    ) -> Result<(), sqlx::Error> {
+        sqlx::query(
+            r#"
+            INSERT INTO sr_shipment_carton_lines
+                (tenant_id, carton_id, shipment_line_id, sku, qty_planned)
+            VALUES ($1, $2, $3, $4, $5)
+            "#,
+        )
+        .bind(tenant_id)
+        .bind(carton_id)
+        .bind(shipment_line_id)
+        .bind(sku)
+        .bind(qty_planned)
+        .execute(&mut **tx)
+        .await?;
+        Ok(())
+    }
+
+    /// Add `qty` (may be negative) to a carton line's packed quantity,
+    /// creating an unplanned line if needed. Returns the new packed quantity.
+    pub async fn add_packed_qty_tx(
+        tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+        tenant_id: Uuid,
+        carton_id: Uuid,
+        shipment_line_id: Uuid,
+        sku: &str,
+        qty: i64,
+    ) -> Result<i64, sqlx::Error> {
+        sqlx::query_scalar(
+            r#"
+            INSERT INTO sr_shipment_carton_lines
+                (tenant_id, carton_id, shipment_line_id, sku, qty_packed)
+            VALUES ($1, $2, $3, $4, $5)
+            ON CONFLICT (carton_id, shipment_line_id) DO UPDATE
+            SET qty_packed = sr_shipment_carton_lines.qty_packed + EXCLUDED.qty_packed,
+                updated_at = NOW()
+            RETURNING qty_packed
+            "#,
+        )
+        .bind(tenant_id)
+        .bind(carton_id)
+        .bind(shipment_line_id)
+        .bind(sku)
+        .bind(qty)
+        .fetch_one(&mut **tx)
+        .await
+    }
+
+    pub async fn get_carton_for_update_tx(
+        tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+        carton_id: Uuid,
+        shipment_id: Uuid,
+        tenant_id: Uuid,
+    ) -> Result<Option<ShipmentCarton>, sqlx::Error> {
+        sqlx::query_as::<_, ShipmentCarton>(
+            r#"
+            SELECT * FROM sr_shipment_cartons
+            WHERE id = $1 AND shipment_id = $2 AND tenant_id = $3
+            FOR UPDATE
+            "#,
+        )
+        .bind(carton_id)
+        .bind(shipment_id)
+        .bind(tenant_id)
+        .fetch_optional(&mut **tx)
+        .await
+    }
+
+    pub async fn close_carton_tx(
+        tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+        carton_id: Uuid,
+        tenant_id: Uuid,
+        gross_weight_lbs: f64,
+        closed_by: Option<Uuid>,
+    ) -> Result<ShipmentCarton, sqlx::Error> {
+        sqlx::query_as::<_, ShipmentCarton>(
+            r#"
+            UPDATE sr_shipment_cartons
+            SET status = 'closed', gross_weight_lbs = $3, closed_at = NOW(),
+                closed_by = $4, updated_at = NOW()
+            WHERE id = $1 AND tenant_id = $2
+            RETURNING *
+            "#,
+        )
+        .bind(carton_id)
+        .bind(tenant_id)
+        .bind(gross_weight_lbs)
+        .bind(closed_by)
+        .fetch_one(&mut **tx)
+        .await
+    }
+
+    pub async fn set_tracking_number_tx(
+        tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+        carton_id: Uuid,
+        tenant_id: Uuid,
+        tracking_number: &str,
+    ) -> Result<(), sqlx::Error> {
+        sqlx::query(
+            r#"
+            UPDATE sr_shipment_cartons
+            SET tracking_number = $3, updated_at = NOW()
+            WHERE id = $1 AND tenant_id = $2
+            "#,
+        )
+        .bind(carton_id)
+        .bind(tenant_id)
+        .bind(tracking_number)
+        .execute(&mut **tx)
+        .await?;
+        Ok(())
+    }
+
+    pub async fn count_open_cartons_tx(
+        tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+        shipment_id: Uuid,
+        tenant_id: Uuid,
+    ) -> Result<i64, sqlx::Error> {
+        sqlx::query_scalar(
+            r#"
+            SELECT COUNT(*) FROM sr_shipment_cartons
+            WHERE shipment_id = $1 AND tenant_id = $2 AND status = 'open'
+            "#,
+        )
+        .bind(shipment_id)
+        .bind(tenant_id)
+        .fetch_one(&mut **tx)
+        .await
+    }
+
+    /// True once anything has been scanned or a carton closed.
+    pub async fn packing_started_tx(
+        tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+        shipment_id: Uuid,
+        tenant_id: Uuid,
+    ) -> Result<bool, sqlx::Error> {
+        sqlx::query_scalar(
+            r#"
+            SELECT EXISTS (
+                SELECT 1 FROM sr_shipment_cartons c
+                LEFT JOIN sr_shipment_carton_lines l ON l.carton_id = c.id
+                WHERE c.shipment_id = $1 AND c.tenant_id = $2
+                  AND (c.status = 'closed' OR l.qty_packed > 0)
+            )
+            "#,
+        )
+        .bind(shipment_id)
+        .bind(tenant_id)
+        .fetch_one(&mut **tx)
+        .await
+    }
+
+    /// Packed quantity per shipment line across all cartons of the shipment.
+    pub async fn packed_qty_by_line_tx(
+        tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+        shipment_id: Uuid,
+        tenant_id: Uuid,
+    ) -> Result<Vec<(Uuid, i64)>, sqlx::Error> {
+        sqlx::query_as(
+            r#"
+            SELECT l.shipment_line_id, SUM(l.qty_packed)::BIGINT
+            FROM sr_shipment_carton_lines l
+            JOIN sr_shipment_cartons c ON c.id = l.carton_id
+            WHERE c.shipment_id = $1 AND c.tenant_id = $2
+            GROUP BY l.shipment_line_id
+            "#,
+        )
+        .bind(shipment_id)
+        .bind(tenant_id)
+        .fetch_all(&mut **tx)
+        .await
+    }
+
+    // ── Reads ────────────────────────────────────────────────
+
+    pub async fn get_carton_lines_tx(
+        tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+        carton_id: Uuid,
+        tenant_id: Uuid,
+    ) -> Result<Vec<CartonLine>, sqlx::Error> {
+        sqlx::query_as::<_, CartonLine>(
+            r#"
+            SELECT l.id, l.carton_id, l.shipment_line_id, l.sku,
+                   l.qty_planned, l.qty_packed, p.weight_lbs AS unit_weight_lbs
+            FROM sr_shipment_carton_lines l
+            LEFT JOIN sr_sku_pack_profiles p
+                   ON p.tenant_id = l.tenant_id AND p.sku = l.sku
+            WHERE l.carton_id = $1 AND l.tenant_id = $2
+            "#,
+        )
+        .bind(carton_id)
+        .bind(tenant_id)
+        .fetch_all(&mut **tx)
+        .await
+    }
+
+    pub async fn list_cartons(
+        pool: &PgPool,
+        shipment_id: Uuid,
+        tenant_id: Uuid,
+    ) -> Result<Vec<(ShipmentCarton, CartonType)>, sqlx::Error> {
+        let cartons = sqlx::query_as::<_, ShipmentCarton>(
+            r#"
+            SELECT * FROM sr_shipment_cartons
+            WHERE shipment_id = $1 AND tenant_id = $2
+            ORDER BY carton_number
+            "#,
+        )
+        .bind(shipment_id)
+        .bind(tenant_id)
+        .fetch_all(pool)
+        .await?;
+
+        let type_ids: Vec<Uuid> = cartons.iter().map(|c| c.carton_type_id).collect();
+        let types = sqlx::query_as::<_, CartonType>(
+            "SELECT * FROM sr_carton_types WHERE tenant_id = $1 AND id = ANY($2)",
+        )
+        .bind(tenant_id)
+        .bind(&type_ids)
+        .fetch_all(pool)
+        .await?;
+
+        Ok(cartons
+            .into_iter()
+            .filter_map(|c| {
+                let t = types.iter().find(|t| t.id == c.carton_type_id)?.clone();
+                Some((c, t))
+            })
+            .collect())
+    }
+
+    pub async fn list_carton_lines(
+        pool: &PgPool,
+        shipment_id: Uuid,
+        tenant_id: Uuid,
+    ) -> Result<Vec<CartonLine>, sqlx::Error> {
+        sqlx::query_as::<_, CartonLine>(
+            r#"
+            SELECT l.id, l.carton_id, l.shipment_line_id, l.sku,
+                   l.qty_planned, l.qty_packed, p.weight_lbs AS unit_weight_lbs
+            FROM sr_shipment_carton_lines l
+            JOIN sr_shipment_cartons c ON c.id = l.carton_id
+            LEFT JOIN sr_sku_pack_profiles p
+                   ON p.tenant_id = l.tenant_id AND p.sku = l.sku
+            WHERE c.shipment_id = $1 AND c.tenant_id = $2
+            ORDER BY c.carton_number, l.sku, l.shipment_line_id
+            "#,
+        )
+        .bind(shipment_id)
+        .bind(tenant_id)
+        .fetch_all(pool)
+        .await
+    }
+}
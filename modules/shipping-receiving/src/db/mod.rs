@@ -1,6 +1,9 @@
 pub mod carrier_request_repo;
+pub mod cartonization_repo;
 pub mod inspection_routing_repo;
 pub mod repository;
+pub mod rate_shop_repo;
+pub mod rma_authorization_repo;
 pub mod rma_repo;
 pub mod shipping_doc_repo;
 
@@ -0,0 +1,187 @@
+//! Repository for rate-shop rules, destination exclusions and decisions.
+//!
+//! Pure data access — no business logic. All queries filter by tenant_id.
+
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::domain::rate_shop::{
+    RateShopDecision, RateShopExclusion, RateShopExclusionRequest, RateShopRules,
+    RateShopRulesRequest,
+};
+
+/// Column values of a new decision row.
+pub struct NewDecision<'a> {
+    pub carrier_code: &'a str,
+    pub service_level: &'a str,
+    pub total_charge_minor: i64,
+    pub currency: &'a str,
+    pub transit_days: Option<i32>,
+    pub guaranteed: bool,
+    pub rule_applied: &'a str,
+    pub rationale: &'a str,
+    pub quotes: &'a serde_json::Value,
+    pub weight_lbs: f64,
+    pub max_transit_days: Option<i32>,
+    pub decided_by: Option<Uuid>,
+}
+
+pub struct RateShopRepo;
+
+impl RateShopRepo {
+    // ── Rules ────────────────────────────────────────────────
+
+    pub async fn upsert_rules(
+        pool: &PgPool,
+        tenant_id: Uuid,
+        req: &RateShopRulesRequest,
+    ) -> Result<RateShopRules, sqlx::Error> {
+        sqlx::query_as::<_, RateShopRules>(
+            r#"
+            INSERT INTO sr_rate_shop_rules
+                (tenant_id, carrier_codes, preferred_carrier_code, preferred_tolerance_pct,
+                 ltl_min_weight_lbs, currency, carrier_timeout_ms)
+            VALUES ($1, $2, $3, $4, $5, $6, $7)
+            ON CONFLICT (tenant_id) DO UPDATE
+            SET carrier_codes = EXCLUDED.carrier_codes,
+                preferred_carrier_code = EXCLUDED.preferred_carrier_code,
+                preferred_tolerance_pct = EXCLUDED.preferred_tolerance_pct,
+                ltl_min_weight_lbs = EXCLUDED.ltl_min_weight_lbs,
+                currency = EXCLUDED.currency,
+                carrier_timeout_ms = EXCLUDED.carrier_timeout_ms,
+                updated_at = NOW()
+            RETURNING *
+            "#,
+        )
+        .bind(tenant_id)
+        .bind(&req.carrier_codes)
+        .bind(&req.preferred_carrier_code)
+        .bind(req.preferred_tolerance_pct)
+        .bind(req.ltl_min_weight_lbs)
+        .bind(&req.currency)
+        .bind(req.carrier_timeout_ms)
+        .fetch_one(pool)
+        .await
+    }
+
+    pub async fn get_rules(
+        pool: &PgPool,
+        tenant_id: Uuid,
+    ) -> Result<Option<RateShopRules>, sqlx::Error> {
+        sqlx::query_as::<_, RateShopRules>("SELECT * FROM sr_rate_shop_rules WHERE tenant_id = $1")
+            .bind(tenant_id)
+            .fetch_optional(pool)
+            .await
+    }
+
+    // ── Exclusions ───────────────────────────────────────────
+
+    pub async fn insert_exclusion(
+        pool: &PgPool,
+        tenant_id: Uuid,
+        req: &RateShopExclusionRequest,
+    ) -> Result<RateShopExclusion, sqlx::Error> {
+        sqlx::query_as::<_, RateShopExclusion>(
+            r#"
+            INSERT INTO sr_rate_shop_exclusions
+                (tenant_id, carrier_code, dest_country, dest_state, dest_zip_prefix, reason)
+            VALUES ($1, $2, $3, $4, $5, $6)
+            RETURNING *
+            "#,
+        )
+        .bind(tenant_id)
+        .bind(&req.carrier_code)
+        .bind(&req.dest_country)
+        .bind(&req.dest_state)
+        .bind(&req.dest_zip_prefix)
+        .bind(&req.reason)
+        .fetch_one(pool)
+        .await
+    }
+
+    /// Returns false if no exclusion matched.
+    pub async fn delete_exclusion(
+        pool: &PgPool,
+        id: Uuid,
+        tenant_id: Uuid,
+    ) -> Result<bool, sqlx::Error> {
+        let result =
+            sqlx::query("DELETE FROM sr_rate_shop_exclusions WHERE id = $1 AND tenant_id = $2")
+                .bind(id)
+                .bind(tenant_id)
+                .execute(pool)
+                .await?;
+        Ok(result.rows_affected() > 0)
+    }
+
+    pub async fn list_exclusions(
+        pool: &PgPool,
+        tenant_id: Uuid,
+    ) -> Result<Vec<RateShopExclusion>, sqlx::Error> {
+        sqlx::query_as::<_, RateShopExclusion>(
+            r#"
+            SELECT * FROM sr_rate_shop_exclusions
+            WHERE tenant_id = $1
+            ORDER BY carrier_code, created_at
+            "#,
+        )
+        .bind(tenant_id)
+        .fetch_all(pool)
+        .await
+    }
+
+    // ── Decisions ────────────────────────────────────────────
+
+    pub async fn insert_decision_tx(
+        tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+        tenant_id: Uuid,
+        shipment_id: Uuid,
+        d: &NewDecision<'_>,
+    ) -> Result<RateShopDecision, sqlx::Error> {
+        sqlx::query_as::<_, RateShopDecision>(
+            r#"
+            INSERT INTO sr_rate_shop_decisions
+                (tenant_id, shipment_id, carrier_code, service_level, total_charge_minor,
+                 currency, transit_days, guaranteed, rule_applied, rationale, quotes,
+                 weight_lbs, max_transit_days, decided_by)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14)
+            RETURNING *
+            "#,
+        )
+        .bind(tenant_id)
+        .bind(shipment_id)
+        .bind(d.carrier_code)
+        .bind(d.service_level)
+        .bind(d.total_charge_minor)
+        .bind(d.currency)
+        .bind(d.transit_days)
+        .bind(d.guaranteed)
+        .bind(d.rule_applied)
+        .bind(d.rationale)
+        .bind(d.quotes)
+        .bind(d.weight_lbs)
+        .bind(d.max_transit_days)
+        .bind(d.decided_by)
+        .fetch_one(&mut **tx)
+        .await
+    }
+
+    /// Decisions for a shipment, newest first.
+    pub async fn list_decisions(
+        pool: &PgPool,
+        shipment_id: Uuid,
+        tenant_id: Uuid,
+    ) -> Result<Vec<RateShopDecision>, sqlx::Error> {
+        sqlx::query_as::<_, RateShopDecision>(
+            r#"
+            SELECT * FROM sr_rate_shop_decisions
+            WHERE shipment_id = $1 AND tenant_id = $2
+            ORDER BY created_at DESC
+            "#,
+        )
+        .bind(shipment_id)
+        .bind(tenant_id)
+        .fetch_all(pool)
+        .await
+    }
+}
@@ -0,0 +1,407 @@
+//! Repository for RMA authorizations, restocking fee rules and inventory adjustments.
+//!
+//! Pure data access — no business logic. All queries filter by tenant_id.
+
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::domain::rma::resolution::ResolutionAmounts;
+use crate::domain::rma::{
+    RestockingFeeRules, RestockingFeeRulesRequest, RmaAuthorization, RmaAuthorizationLine,
+    RmaInventoryAdjustment, RmaReceipt,
+};
+
+/// Column values of a new authorization header.
+pub struct NewAuthorization<'a> {
+    pub rma_number: &'a str,
+    pub customer_id: Uuid,
+    pub sales_order_id: Option<Uuid>,
+    pub ar_invoice_id: Option<i32>,
+    pub resolution_type: &'a str,
+    pub reason_code: &'a str,
+    pub currency: &'a str,
+    pub warehouse_id: Option<Uuid>,
+    pub notes: Option<&'a str>,
+    pub authorized_by: Option<Uuid>,
+}
+
+/// A received item on an authorization with its receipt's disposition.
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct ReceivedItemRow {
+    pub item_id: Uuid,
+    pub rma_receipt_id: Uuid,
+    pub sku: String,
+    pub qty: i64,
+    pub disposition_status: String,
+}
+
+pub struct RmaAuthorizationRepo;
+
+impl RmaAuthorizationRepo {
+    // ── Restocking fee rules ─────────────────────────────────
+
+    pub async fn upsert_fee_rules(
+        pool: &PgPool,
+        tenant_id: Uuid,
+        req: &RestockingFeeRulesRequest,
+    ) -> Result<RestockingFeeRules, sqlx::Error> {
+        sqlx::query_as::<_, RestockingFeeRules>(
+            r#"
+            INSERT INTO rma_restocking_fee_rules
+                (tenant_id, fee_bps, min_fee_minor, waived_reason_codes, apply_to_scrap)
+            VALUES ($1, $2, $3, $4, $5)
+            ON CONFLICT (tenant_id) DO UPDATE
+            SET fee_bps = EXCLUDED.fee_bps,
+                min_fee_minor = EXCLUDED.min_fee_minor,
+                waived_reason_codes = EXCLUDED.waived_reason_codes,
+                apply_to_scrap = EXCLUDED.apply_to_scrap,
+                updated_at = NOW()
+            RETURNING *
+            "#,
+        )
+        .bind(tenant_id)
+        .bind(req.fee_bps)
+        .bind(req.min_fee_minor)
+        .bind(&req.waived_reason_codes)
+        .bind(req.apply_to_scrap)
+        .fetch_one(pool)
+        .await
+    }
+
+    pub async fn get_fee_rules(
+        pool: &PgPool,
+        tenant_id: Uuid,
+    ) -> Result<Option<RestockingFeeRules>, sqlx::Error> {
+        sqlx::query_as::<_, RestockingFeeRules>(
+            "SELECT * FROM rma_restocking_fee_rules WHERE tenant_id = $1",
+        )
+        .bind(tenant_id)
+        .fetch_optional(pool)
+        .await
+    }
+
+    // ── Authorizations ───────────────────────────────────────
+
+    pub async fn number_exists_tx(
+        tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+        tenant_id: Uuid,
+        rma_number: &str,
+    ) -> Result<bool, sqlx::Error> {
+        let row: (bool,) = sqlx::query_as(
+            "SELECT EXISTS(SELECT 1 FROM rma_authorizations WHERE tenant_id = $1 AND rma_number = $2)",
+        )
+        .bind(tenant_id)
+        .bind(rma_number)
+        .fetch_one(&mut **tx)
+        .await?;
+        Ok(row.0)
+    }
+
+    pub async fn insert_authorization_tx(
+        tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+        tenant_id: Uuid,
+        a: &NewAuthorization<'_>,
+    ) -> Result<RmaAuthorization, sqlx::Error> {
+        sqlx::query_as::<_, RmaAuthorization>(
+            r#"
+            INSERT INTO rma_authorizations
+                (tenant_id, rma_number, customer_id, sales_order_id, ar_invoice_id,
+                 resolution_type, reason_code, currency, warehouse_id, notes, authorized_by)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11)
+            RETURNING *
+            "#,
+        )
+        .bind(tenant_id)
+        .bind(a.rma_number)
+        .bind(a.customer_id)
+        .bind(a.sales_order_id)
+        .bind(a.ar_invoice_id)
+        .bind(a.resolution_type)
+        .bind(a.reason_code)
+        .bind(a.currency)
+        .bind(a.warehouse_id)
+        .bind(a.notes)
+        .bind(a.authorized_by)
+        .fetch_one(&mut **tx)
+        .await
+    }
+
+    pub async fn insert_line_tx(
+        tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+        tenant_id: Uuid,
+        rma_authorization_id: Uuid,
+        sku: &str,
+        qty_authorized: i64,
+        unit_price_minor: i64,
+        so_line_id: Option<Uuid>,
+    ) -> Result<RmaAuthorizationLine, sqlx::Error> {
+        sqlx::query_as::<_, RmaAuthorizationLine>(
+            r#"
+            INSERT INTO rma_authorization_lines
+                (tenant_id, rma_authorization_id, sku, qty_authorized, unit_price_minor, so_line_id)
+            VALUES ($1, $2, $3, $4, $5, $6)
+            RETURNING *
+            "#,
+        )
+        .bind(tenant_id)
+        .bind(rma_authorization_id)
+        .bind(sku)
+        .bind(qty_authorized)
+        .bind(unit_price_minor)
+        .bind(so_line_id)
+        .fetch_one(&mut **tx)
+        .await
+    }
+
+    pub async fn get_authorization(
+        pool: &PgPool,
+        id: Uuid,
+        tenant_id: Uuid,
+    ) -> Result<Option<RmaAuthorization>, sqlx::Error> {
+        sqlx::query_as::<_, RmaAuthorization>(
+            "SELECT * FROM rma_authorizations WHERE id = $1 AND tenant_id = $2",
+        )
+        .bind(id)
+        .bind(tenant_id)
+        .fetch_optional(pool)
+        .await
+    }
+
+    pub async fn get_for_update_tx(
+        tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+        id: Uuid,
+        tenant_id: Uuid,
+    ) -> Result<Option<RmaAuthorization>, sqlx::Error> {
+        sqlx::query_as::<_, RmaAuthorization>(
+            "SELECT * FROM rma_authorizations WHERE id = $1 AND tenant_id = $2 FOR UPDATE",
+        )
+        .bind(id)
+        .bind(tenant_id)
+        .fetch_optional(&mut **tx)
+        .await
+    }
+
+    pub async fn get_lines(
+        pool: &PgPool,
+        rma_authorization_id: Uuid,
+        tenant_id: Uuid,
+    ) -> Result<Vec<RmaAuthorizationLine>, sqlx::Error> {
+        sqlx::query_as::<_, RmaAuthorizationLine>(
+            r#"
+            SELECT * FROM rma_authorization_lines
+            WHERE rma_authorization_id = $1 AND tenant_id = $2
+            ORDER BY created_at, sku
+            "#,
+        )
+        .bind(rma_authorization_id)
+        .bind(tenant_id)
+        .fetch_all(pool)
+        .await
+    }
+
+    pub async fn get_lines_tx(
+        tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+        rma_authorization_id: Uuid,
+        tenant_id: Uuid,
+    ) -> Result<Vec<RmaAuthorizationLine>, sqlx::Error> {
+        sqlx::query_as::<_, RmaAuthorizationLine>(
+            r#"
+            SELECT * FROM rma_authorization_lines
+            WHERE rma_authorization_id = $1 AND tenant_id = $2
+            ORDER BY created_at, sku
+            "#,
+        )
+        .bind(rma_authorization_id)
+        .bind(tenant_id)
+        .fetch_all(&mut **tx)
+        .await
+    }
+
+    pub async fn update_status_tx(
+        tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+        id: Uuid,
+        tenant_id: Uuid,
+        status: &str,
+    ) -> Result<RmaAuthorization, sqlx::Error> {
+        sqlx::query_as::<_, RmaAuthorization>(
+            r#"
+            UPDATE rma_authorizations
+            SET status = $3, updated_at = NOW()
+            WHERE id = $1 AND tenant_id = $2
+            RETURNING *
+            "#,
+        )
+        .bind(id)
+        .bind(tenant_id)
+        .bind(status)
+        .fetch_one(&mut **tx)
+        .await
+    }
+
+    pub async fn cancel_tx(
+        tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+        id: Uuid,
+        tenant_id: Uuid,
+    ) -> Result<RmaAuthorization, sqlx::Error> {
+        sqlx::query_as::<_, RmaAuthorization>(
+            r#"
+            UPDATE rma_authorizations
+            SET status = 'cancelled', cancelled_at = NOW(), updated_at = NOW()
+            WHERE id = $1 AND tenant_id = $2
+            RETURNING *
+            "#,
+        )
+        .bind(id)
+        .bind(tenant_id)
+        .fetch_one(&mut **tx)
+        .await
+    }
+
+    pub async fn resolve_tx(
+        tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+        id: Uuid,
+        tenant_id: Uuid,
+        amounts: &ResolutionAmounts,
+        credit_note_id: Option<Uuid>,
+        replacement_request_id: Option<Uuid>,
+        resolved_by: Option<Uuid>,
+    ) -> Result<RmaAuthorization, sqlx::Error> {
+        sqlx::query_as::<_, RmaAuthorization>(
+            r#"
+            UPDATE rma_authorizations
+            SET status = 'resolved',
+                gross_credit_minor = $3,
+                restocking_fee_minor = $4,
+                net_credit_minor = $5,
+                credit_note_id = $6,
+                replacement_request_id = $7,
+                resolved_by = $8,
+                resolved_at = NOW(),
+                updated_at = NOW()
+            WHERE id = $1 AND tenant_id = $2
+            RETURNING *
+            "#,
+        )
+        .bind(id)
+        .bind(tenant_id)
+        .bind(amounts.gross_credit_minor)
+        .bind(amounts.restocking_fee_minor)
+        .bind(amounts.net_credit_minor)
+        .bind(credit_note_id)
+        .bind(replacement_request_id)
+        .bind(resolved_by)
+        .fetch_one(&mut **tx)
+        .await
+    }
+
+    // ── Linked receipts ──────────────────────────────────────
+
+    pub async fn list_receipts(
+        pool: &PgPool,
+        rma_authorization_id: Uuid,
+        tenant_id: Uuid,
+    ) -> Result<Vec<RmaReceipt>, sqlx::Error> {
+        sqlx::query_as::<_, RmaReceipt>(
+            r#"
+            SELECT * FROM rma_receipts
+            WHERE rma_authorization_id = $1 AND tenant_id = $2
+            ORDER BY created_at
+            "#,
+        )
+        .bind(rma_authorization_id)
+        .bind(tenant_id)
+        .fetch_all(pool)
+        .await
+    }
+
+    /// Quantity already received per SKU across all linked receipts.
+    pub async fn received_qty_by_sku_tx(
+        tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+        rma_authorization_id: Uuid,
+        tenant_id: Uuid,
+    ) -> Result<Vec<(String, i64)>, sqlx::Error> {
+        sqlx::query_as::<_, (String, i64)>(
+            r#"
+            SELECT i.sku, SUM(i.qty)::BIGINT
+            FROM rma_receipt_items i
+            JOIN rma_receipts r ON r.id = i.rma_receipt_id
+            WHERE r.rma_authorization_id = $1 AND r.tenant_id = $2
+            GROUP BY i.sku
+            "#,
+        )
+        .bind(rma_authorization_id)
+        .bind(tenant_id)
+        .fetch_all(&mut **tx)
+        .await
+    }
+
+    /// Received items with their receipt's disposition, locking the receipts.
+    pub async fn received_items_tx(
+        tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+        rma_authorization_id: Uuid,
+        tenant_id: Uuid,
+    ) -> Result<Vec<ReceivedItemRow>, sqlx::Error> {
+        sqlx::query_as::<_, ReceivedItemRow>(
+            r#"
+            SELECT i.id AS item_id, i.rma_receipt_id, i.sku, i.qty, r.disposition_status
+            FROM rma_receipt_items i
+            JOIN rma_receipts r ON r.id = i.rma_receipt_id
+            WHERE r.rma_authorization_id = $1 AND r.tenant_id = $2
+            ORDER BY r.created_at, i.created_at
+            FOR UPDATE OF r
+            "#,
+        )
+        .bind(rma_authorization_id)
+        .bind(tenant_id)
+        .fetch_all(&mut **tx)
+        .await
+    }
+
+    // ── Inventory adjustments ────────────────────────────────
+
+    pub async fn insert_adjustment_tx(
+        tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+        tenant_id: Uuid,
+        rma_authorization_id: Uuid,
+        item: &ReceivedItemRow,
+        adjustment_type: &str,
+        inventory_ref_id: Option<Uuid>,
+    ) -> Result<RmaInventoryAdjustment, sqlx::Error> {
+        sqlx::query_as::<_, RmaInventoryAdjustment>(
+            r#"
+            INSERT INTO rma_inventory_adjustments
+                (tenant_id, rma_authorization_id, rma_receipt_item_id, sku, qty,
+                 adjustment_type, inventory_ref_id)
+            VALUES ($1, $2, $3, $4, $5, $6, $7)
+            RETURNING *
+            "#,
+        )
+        .bind(tenant_id)
+        .bind(rma_authorization_id)
+        .bind(item.item_id)
+        .bind(&item.sku)
+        .bind(item.qty)
+        .bind(adjustment_type)
+        .bind(inventory_ref_id)
+        .fetch_one(&mut **tx)
+        .await
+    }
+
+    pub async fn list_adjustments(
+        pool: &PgPool,
+        rma_authorization_id: Uuid,
+        tenant_id: Uuid,
+    ) -> Result<Vec<RmaInventoryAdjustment>, sqlx::Error> {
+        sqlx::query_as::<_, RmaInventoryAdjustment>(
+            r#"
+            SELECT * FROM rma_inventory_adjustments
+            WHERE rma_authorization_id = $1 AND tenant_id = $2
+            ORDER BY created_at
+            "#,
+        )
+        .bind(rma_authorization_id)
+        .bind(tenant_id)
+        .fetch_all(pool)
+        .await
+    }
+}
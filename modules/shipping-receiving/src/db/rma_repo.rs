@@ -19,11 +19,13 @@ impl RmaRepo {
         customer_id: Uuid,
         condition_notes: Option<&str>,
         idempotency_key: Option<&str>,
+        rma_authorization_id: Option<Uuid>,
     ) -> Result<RmaReceipt, sqlx::Error> {
         sqlx::query_as::<_, RmaReceipt>(
             r#"
-            INSERT INTO rma_receipts (tenant_id, rma_id, customer_id, condition_notes, idempotency_key)
-            VALUES ($1, $2, $3, $4, $5)
+            INSERT INTO rma_receipts
+                (tenant_id, rma_id, customer_id, condition_notes, idempotency_key, rma_authorization_id)
+            VALUES ($1, $2, $3, $4, $5, $6)
             RETURNING *
             "#,
         )
@@ -32,6 +34,7 @@ impl RmaRepo {
         .bind(customer_id)
         .bind(condition_notes)
         .bind(idempotency_key)
+        .bind(rma_authorization_id)
         .fetch_one(&mut **tx)
         .await
     }
@@ -222,7 +222,7 @@ async fn handle_message(
     .await
 }
 
-async fn fetch_credentials_or_empty(
+pub(crate) async fn fetch_credentials_or_empty(
     http_client: &Client,
     app_id: &str,
     connector_type: &str,
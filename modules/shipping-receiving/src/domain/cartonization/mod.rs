@@ -0,0 +1,11 @@
+pub mod packer;
+pub mod service;
+pub mod types;
+
+pub use packer::{PackError, VOLUME_FILL_FACTOR};
+pub use service::{CartonizationError, CartonizationService};
+pub use types::{
+    AddCartonRequest, CartonContents, CartonLine, CartonStatus, CartonType, CartonTypeRequest,
+    CartonizeRequest, CloseCartonRequest, PackingList, ScanCartonItemRequest, ShipmentCarton,
+    SkuPackProfile, SkuPackProfileRequest, UnpackedLine,
+};
@@ -0,0 +1,476 @@
+//! Cartonization algorithm — pure, no I/O.
+//!
+//! First-fit decreasing over unit volume with per-carton weight and volume
+//! limits. New cartons are opened with the type that has the most room for
+//! the SKU being placed; a final downsize pass moves each carton's contents
+//! into the smallest carton type that still holds them.
+//!
+//! Rules:
+//! - A unit fits a carton type if its dimensions fit in some orientation
+//!   (footprint rotation only for `this_side_up`) and its weight plus the
+//!   carton's tare is within the carton's max weight
+//! - Content volume may fill at most `VOLUME_FILL_FACTOR` of the carton,
+//!   except a carton holding a single unit (dimension fit is enough)
+//! - `ship_alone` units each get their own carton
+//! - SKUs only share a carton with SKUs of the same `pack_group`
+//!
+//! The output is deterministic for the same input.
+
+use thiserror::Error;
+use uuid::Uuid;
+
+/// Share of a carton's inner volume usable by content (void fill, dunnage,
+/// imperfect stacking).
+pub const VOLUME_FILL_FACTOR: f64 = 0.85;
+
+#[derive(Debug, Clone)]
+pub struct CartonSpec {
+    pub carton_type_id: Uuid,
+    pub code: String,
+    pub length_in: f64,
+    pub width_in: f64,
+    pub height_in: f64,
+    pub tare_weight_lbs: f64,
+    pub max_weight_lbs: f64,
+}
+
+impl CartonSpec {
+    fn volume(&self) -> f64 {
+        self.length_in * self.width_in * self.height_in
+    }
+
+    fn usable_volume(&self) -> f64 {
+        self.volume() * VOLUME_FILL_FACTOR
+    }
+}
+
+/// A shipment line to pack, with its SKU's packing profile.
+#[derive(Debug, Clone)]
+pub struct PackItem {
+    pub shipment_line_id: Uuid,
+    pub sku: String,
+    pub qty: i64,
+    pub length_in: f64,
+    pub width_in: f64,
+    pub height_in: f64,
+    pub weight_lbs: f64,
+    pub ship_alone: bool,
+    pub this_side_up: bool,
+    pub pack_group: Option<String>,
+}
+
+impl PackItem {
+    fn volume(&self) -> f64 {
+        self.length_in * self.width_in * self.height_in
+    }
+
+    fn fits_dimensions(&self, c: &CartonSpec) -> bool {
+        if self.this_side_up {
+            self.height_in <= c.height_in
+                && fits_sorted(
+                    &mut [self.length_in, self.width_in],
+                    &mut [c.length_in, c.width_in],
+                )
+        } else {
+            fits_sorted(
+                &mut [self.length_in, self.width_in, self.height_in],
+                &mut [c.length_in, c.width_in, c.height_in],
+            )
+        }
+    }
+
+    fn fits(&self, c: &CartonSpec) -> bool {
+        self.fits_dimensions(c) && c.tare_weight_lbs + self.weight_lbs <= c.max_weight_lbs
+    }
+}
+
+fn fits_sorted(item: &mut [f64], carton: &mut [f64]) -> bool {
+    item.sort_by(f64::total_cmp);
+    carton.sort_by(f64::total_cmp);
+    item.iter().zip(carton.iter()).all(|(i, c)| i <= c)
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct PlannedCartonLine {
+    pub shipment_line_id: Uuid,
+    pub sku: String,
+    pub qty: i64,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct PlannedCarton {
+    pub carton_type_id: Uuid,
+    pub code: String,
+    pub lines: Vec<PlannedCartonLine>,
+    /// Content weight excluding tare.
+    pub content_weight_lbs: f64,
+    pub content_volume_in3: f64,
+}
+
+#[derive(Debug, Error, PartialEq)]
+pub enum PackError {
+    #[error("no active carton types")]
+    NoCartonTypes,
+
+    #[error("SKU '{sku}' does not fit any active carton type")]
+    DoesNotFit { sku: String },
+
+    #[error("SKU '{sku}': {reason}")]
+    InvalidItem { sku: String, reason: String },
+}
+
+struct OpenCarton {
+    spec: usize,
+    group: Option<String>,
+    sealed: bool,
+    /// (item index, qty)
+    contents: Vec<(usize, i64)>,
+    units: i64,
+    weight: f64,
+    volume: f64,
+}
+
+impl OpenCarton {
+    fn new(spec: usize, item: &PackItem) -> Self {
+        Self {
+            spec,
+            group: item.pack_group.clone(),
+            sealed: item.ship_alone,
+            contents: Vec::new(),
+            units: 0,
+            weight: 0.0,
+            volume: 0.0,
+        }
+    }
+
+    fn add(&mut self, idx: usize, item: &PackItem, qty: i64) {
+        match self.contents.iter_mut().find(|(i, _)| *i == idx) {
+            Some((_, q)) => *q += qty,
+            None => self.contents.push((idx, qty)),
+        }
+        self.units += qty;
+        self.weight += item.weight_lbs * qty as f64;
+        self.volume += item.volume() * qty as f64;
+    }
+}
+
+/// How many more units of `item` a carton of type `spec` can take, given its
+/// current content.
+fn capacity_for(item: &PackItem, spec: &CartonSpec, units: i64, weight: f64, volume: f64) -> i64 {
+    if !item.fits(spec) {
+        return 0;
+    }
+    let by_weight = if item.weight_lbs > 0.0 {
+        ((spec.max_weight_lbs - spec.tare_weight_lbs - weight) / item.weight_lbs).floor() as i64
+    } else {
+        i64::MAX
+    };
+    let mut by_volume = ((spec.usable_volume() - volume) / item.volume()).floor() as i64;
+    if units == 0 {
+        by_volume = by_volume.max(1);
+    }
+    by_weight.min(by_volume).max(0)
+}
+
+fn validate(item: &PackItem) -> Result<(), PackError> {
+    let invalid = |reason: &str| PackError::InvalidItem {
+        sku: item.sku.clone(),
+        reason: reason.to_string(),
+    };
+    if item.qty <= 0 {
+        return Err(invalid("quantity must be > 0"));
+    }
+    if !(item.length_in > 0.0 && item.width_in > 0.0 && item.height_in > 0.0) {
+        return Err(invalid("dimensions must be > 0"));
+    }
+    if item.weight_lbs.is_nan() || item.weight_lbs < 0.0 {
+        return Err(invalid("weight must be >= 0"));
+    }
+    Ok(())
+}
+
+/// Assign `items` to cartons chosen from `specs`.
+pub fn cartonize(
+    items: &[PackItem],
+    specs: &[CartonSpec],
+) -> Result<Vec<PlannedCarton>, PackError> {
+    if specs.is_empty() {
+        return Err(PackError::NoCartonTypes);
+    }
+    for item in items {
+        validate(item)?;
+        if !specs.iter().any(|s| item.fits(s)) {
+            return Err(PackError::DoesNotFit {
+                sku: item.sku.clone(),
+            });
+        }
+    }
+
+    // Carton types smallest first
+    let mut by_size: Vec<usize> = (0..specs.len()).collect();
+    by_size.sort_by(|&a, &b| {
+        specs[a]
+            .volume()
+            .total_cmp(&specs[b].volume())
+            .then_with(|| specs[a].code.cmp(&specs[b].code))
+    });
+
+    // Largest units first
+    let mut order: Vec<usize> = (0..items.len()).collect();
+    order.sort_by(|&a, &b| {
+        items[b]
+            .volume()
+            .total_cmp(&items[a].volume())
+            .then_with(|| items[a].sku.cmp(&items[b].sku))
+            .then_with(|| items[a].shipment_line_id.cmp(&items[b].shipment_line_id))
+    });
+
+    let mut cartons: Vec<OpenCarton> = Vec::new();
+    for idx in order {
+        let item = &items[idx];
+        let mut remaining = item.qty;
+
+        if item.ship_alone {
+            let spec = *by_size
+                .iter()
+                .find(|&&s| item.fits(&specs[s]))
+                .expect("fit checked above");
+            for _ in 0..remaining {
+                let mut c = OpenCarton::new(spec, item);
+                c.add(idx, item, 1);
+                cartons.push(c);
+            }
+            continue;
+        }
+
+        // First fit into cartons already opened
+        for c in cartons.iter_mut() {
+            if remaining == 0 {
+                break;
+            }
+            if c.sealed || c.group != item.pack_group {
+                continue;
+            }
+            let n = capacity_for(item, &specs[c.spec], c.units, c.weight, c.volume).min(remaining);
+            if n > 0 {
+                c.add(idx, item, n);
+                remaining -= n;
+            }
+        }
+
+        // Open new cartons of the type with the most room for this SKU; the
+        // downsize pass right-sizes them once everything is placed
+        while remaining > 0 {
+            let spec = by_size
+                .iter()
+                .copied()
+                .max_by_key(|&s| capacity_for(item, &specs[s], 0, 0.0, 0.0))
+                .expect("specs not empty");
+            let n = capacity_for(item, &specs[spec], 0, 0.0, 0.0).min(remaining);
+            let mut c = OpenCarton::new(spec, item);
+            c.add(idx, item, n);
+            cartons.push(c);
+            remaining -= n;
+        }
+    }
+
+    // Downsize: smallest type that still holds each carton's contents
+    for c in cartons.iter_mut() {
+        if let Some(&spec) = by_size.iter().find(|&&s| holds(c, items, &specs[s])) {
+            c.spec = spec;
+        }
+    }
+
+    Ok(cartons
+        .into_iter()
+        .map(|c| {
+            let spec = &specs[c.spec];
+            PlannedCarton {
+                carton_type_id: spec.carton_type_id,
+                code: spec.code.clone(),
+                lines: c
+                    .contents
+                    .iter()
+                    .map(|&(i, qty)| PlannedCartonLine {
+                        shipment_line_id: items[i].shipment_line_id,
+                        sku: items[i].sku.clone(),
+                        qty,
+                    })
+                    .collect(),
+                content_weight_lbs: c.weight,
+                content_volume_in3: c.volume,
+            }
+        })
+        .collect())
+}
+
+fn holds(c: &OpenCarton, items: &[PackItem], spec: &CartonSpec) -> bool {
+    c.contents.iter().all(|&(i, _)| items[i].fits(spec))
+        && spec.tare_weight_lbs + c.weight <= spec.max_weight_lbs
+        && (c.units == 1 || c.volume <= spec.usable_volume())
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn spec(code: &str, l: f64, w: f64, h: f64, max: f64) -> CartonSpec {
+        CartonSpec {
+            carton_type_id: Uuid::new_v4(),
+            code: code.to_string(),
+            length_in: l,
+            width_in: w,
+            height_in: h,
+            tare_weight_lbs: 0.5,
+            max_weight_lbs: max,
+        }
+    }
+
+    fn item(sku: &str, qty: i64, dims: (f64, f64, f64), weight: f64) -> PackItem {
+        PackItem {
+            shipment_line_id: Uuid::new_v4(),
+            sku: sku.to_string(),
+            qty,
+            length_in: dims.0,
+            width_in: dims.1,
+            height_in: dims.2,
+            weight_lbs: weight,
+            ship_alone: false,
+            this_side_up: false,
+            pack_group: None,
+        }
+    }
+
+    fn boxes() -> Vec<CartonSpec> {
+        vec![
+            spec("LARGE", 24.0, 18.0, 18.0, 50.0),
+            spec("SMALL", 10.0, 8.0, 6.0, 20.0),
+            spec("MEDIUM", 16.0, 12.0, 10.0, 40.0),
+        ]
+    }
+
+    fn total_qty(plan: &[PlannedCarton], sku: &str) -> i64 {
+        plan.iter()
+            .flat_map(|c| &c.lines)
+            .filter(|l| l.sku == sku)
+            .map(|l| l.qty)
+            .sum()
+    }
+
+    #[test]
+    fn small_order_goes_into_the_smallest_carton() {
+        let plan = cartonize(&[item("A", 4, (4.0, 3.0, 2.0), 0.5)], &boxes()).unwrap();
+        assert_eq!(plan.len(), 1);
+        assert_eq!(plan[0].code, "SMALL");
+        assert_eq!(plan[0].lines[0].qty, 4);
+    }
+
+    #[test]
+    fn weight_limit_splits_cartons() {
+        // 30 × 2 lbs = 60 lbs; LARGE takes 49.5 lbs of content → 24 units
+        let plan = cartonize(&[item("HEAVY", 30, (3.0, 3.0, 3.0), 2.0)], &boxes()).unwrap();
+        assert_eq!(total_qty(&plan, "HEAVY"), 30);
+        for c in &plan {
+            let s = boxes().into_iter().find(|s| s.code == c.code).unwrap();
+            assert!(s.tare_weight_lbs + c.content_weight_lbs <= s.max_weight_lbs);
+        }
+        assert_eq!(plan.len(), 2);
+    }
+
+    #[test]
+    fn volume_fill_factor_is_respected_and_rest_downsized() {
+        // 60 units of 120 in³ = 7200 in³; LARGE usable = 6609.6 in³ → 55 units
+        let plan = cartonize(&[item("BULKY", 60, (6.0, 5.0, 4.0), 0.1)], &boxes()).unwrap();
+        assert_eq!(plan.len(), 2);
+        assert_eq!(plan[0].code, "LARGE");
+        assert_eq!(plan[0].lines[0].qty, 55);
+        assert_eq!(
+            plan[1].code, "MEDIUM",
+            "600 in³ exceeds SMALL's usable volume"
+        );
+        assert_eq!(plan[1].lines[0].qty, 5);
+    }
+
+    #[test]
+    fn single_unit_only_needs_a_dimension_fit() {
+        let plan = cartonize(&[item("SNUG", 1, (10.0, 8.0, 6.0), 1.0)], &boxes()).unwrap();
+        assert_eq!(plan[0].code, "SMALL");
+    }
+
+    #[test]
+    fn orientation_may_rotate_unless_this_side_up() {
+        // 4" tall box lying on its side: 9 × 4 × 7 fits SMALL (10 × 8 × 6) rotated
+        let mut tall = item("TALL", 1, (9.0, 4.0, 7.0), 1.0);
+        assert_eq!(
+            cartonize(&[tall.clone()], &boxes()).unwrap()[0].code,
+            "SMALL"
+        );
+        tall.this_side_up = true;
+        assert_eq!(cartonize(&[tall], &boxes()).unwrap()[0].code, "MEDIUM");
+    }
+
+    #[test]
+    fn ship_alone_units_get_their_own_cartons() {
+        let mut fragile = item("GLASS", 3, (4.0, 4.0, 4.0), 1.0);
+        fragile.ship_alone = true;
+        let plan = cartonize(&[fragile, item("A", 2, (2.0, 2.0, 2.0), 0.1)], &boxes()).unwrap();
+        assert_eq!(plan.len(), 4);
+        assert_eq!(plan.iter().filter(|c| c.lines[0].sku == "GLASS").count(), 3);
+        assert!(plan.iter().all(|c| c.lines.len() == 1));
+    }
+
+    #[test]
+    fn pack_groups_are_segregated() {
+        let mut acid = item("ACID", 1, (3.0, 3.0, 3.0), 1.0);
+        acid.pack_group = Some("hazmat-8".to_string());
+        let plan = cartonize(&[acid, item("A", 1, (3.0, 3.0, 3.0), 1.0)], &boxes()).unwrap();
+        assert_eq!(plan.len(), 2);
+
+        let mut acid2 = item("ACID2", 1, (3.0, 3.0, 3.0), 1.0);
+        acid2.pack_group = Some("hazmat-8".to_string());
+        let mut acid = item("ACID", 1, (3.0, 3.0, 3.0), 1.0);
+        acid.pack_group = Some("hazmat-8".to_string());
+        assert_eq!(cartonize(&[acid, acid2], &boxes()).unwrap().len(), 1);
+    }
+
+    #[test]
+    fn mixed_lines_share_cartons() {
+        let plan = cartonize(
+            &[
+                item("A", 2, (8.0, 6.0, 4.0), 1.0),
+                item("B", 10, (2.0, 2.0, 2.0), 0.2),
+            ],
+            &boxes(),
+        )
+        .unwrap();
+        assert_eq!(plan.len(), 1);
+        assert_eq!(plan[0].lines.len(), 2);
+        assert_eq!(plan[0].lines[0].sku, "A", "larger units are placed first");
+    }
+
+    #[test]
+    fn oversize_or_invalid_items_are_rejected() {
+        assert_eq!(
+            cartonize(&[item("BEAM", 1, (30.0, 2.0, 2.0), 1.0)], &boxes()),
+            Err(PackError::DoesNotFit { sku: "BEAM".into() })
+        );
+        assert_eq!(
+            cartonize(&[item("ANVIL", 1, (5.0, 5.0, 5.0), 60.0)], &boxes()),
+            Err(PackError::DoesNotFit {
+                sku: "ANVIL".into()
+            })
+        );
+        assert!(matches!(
+            cartonize(&[item("FLAT", 1, (5.0, 0.0, 5.0), 1.0)], &boxes()),
+            Err(PackError::InvalidItem { .. })
+        ));
+        assert_eq!(
+            cartonize(&[item("A", 1, (1.0, 1.0, 1.0), 1.0)], &[]),
+            Err(PackError::NoCartonTypes)
+        );
+    }
+}
@@ -0,0 +1,678 @@
+//! Cartonization and pack-station service — Guard → Mutation → Outbox.
+//!
+//! Invariants:
+//! - Only outbound shipments in draft, confirmed or picking can be packed
+//! - Cartonization replaces the shipment's cartons and is refused once
+//!   anything has been scanned or a carton closed
+//! - Scans are serialized per shipment (shipment row lock); the packed
+//!   quantity of a line across all cartons never exceeds qty_expected
+//! - Closed cartons are immutable; a shipment cannot move to packed while a
+//!   carton is open (enforced in ShipmentService::transition)
+//! - All queries filter by tenant_id
+
+use std::collections::HashMap;
+
+use sqlx::PgPool;
+use thiserror::Error;
+use uuid::Uuid;
+
+use super::packer::{self, CartonSpec, PackError, PackItem};
+use super::types::{
+    CartonContents, CartonStatus, CartonType, CartonTypeRequest, CartonizeRequest,
+    CloseCartonRequest, PackingList, ScanCartonItemRequest, SkuPackProfile, SkuPackProfileRequest,
+    UnpackedLine,
+};
+use crate::db::cartonization_repo::CartonizationRepo;
+use crate::db::repository::ShipmentRepository;
+use crate::domain::carrier_providers::PackageInfo;
+use crate::domain::shipments::types::{Direction, OutboundStatus};
+use crate::domain::shipments::Shipment;
+use crate::http::shipments::ShipmentLineRow;
+use crate::outbox;
+
+// ── Errors ────────────────────────────────────────────────────
+
+#[derive(Debug, Error)]
+pub enum CartonizationError {
+    #[error("Shipment not found")]
+    ShipmentNotFound,
+
+    #[error("Carton not found")]
+    CartonNotFound,
+
+    #[error("Carton type not found")]
+    CartonTypeNotFound,
+
+    #[error("Carton type code '{0}' already exists")]
+    DuplicateCartonType(String),
+
+    #[error("Cartonization is only valid for outbound shipments")]
+    NotOutbound,
+
+    #[error("Shipment cannot be packed in status {current}")]
+    NotPackable { current: String },
+
+    #[error("SKU '{0}' has no pack profile")]
+    MissingPackProfile(String),
+
+    #[error("Packing has started — cartons can no longer be re-planned")]
+    PackingStarted,
+
+    #[error("Carton is closed")]
+    CartonClosed,
+
+    #[error("Carton has no packed units")]
+    EmptyCarton,
+
+    #[error("SKU '{0}' is not on this shipment")]
+    SkuNotOnShipment(String),
+
+    #[error("SKU '{sku}': only {remaining} unit(s) left to pack")]
+    OverPack { sku: String, remaining: i64 },
+
+    #[error("SKU '{sku}': only {packed} unit(s) packed in this carton")]
+    UnderPack { sku: String, packed: i64 },
+
+    #[error("{0} carton(s) still open")]
+    CartonsOpen(i64),
+
+    #[error(transparent)]
+    Pack(#[from] PackError),
+
+    #[error("Validation error: {0}")]
+    Validation(String),
+
+    #[error("Database error: {0}")]
+    Database(#[from] sqlx::Error),
+}
+
+// ── Event type constants ─────────────────────────────────────
+
+pub mod subjects {
+    pub const SHIPMENT_CARTONIZED: &str = "sr.shipment.cartonized";
+    pub const CARTON_CLOSED: &str = "sr.carton.closed";
+}
+
+// ── Service ──────────────────────────────────────────────────
+
+pub struct CartonizationService;
+
+impl CartonizationService {
+    // ── Master data ──────────────────────────────────────────
+
+    pub async fn create_carton_type(
+        pool: &PgPool,
+        tenant_id: Uuid,
+        req: &CartonTypeRequest,
+    ) -> Result<CartonType, CartonizationError> {
+        validate_carton_type(req)?;
+        CartonizationRepo::insert_carton_type(pool, tenant_id, req)
+            .await
+            .map_err(|e| duplicate_code(e, &req.code))
+    }
+
+    pub async fn update_carton_type(
+        pool: &PgPool,
+        id: Uuid,
+        tenant_id: Uuid,
+        req: &CartonTypeRequest,
+    ) -> Result<CartonType, CartonizationError> {
+        validate_carton_type(req)?;
+        CartonizationRepo::update_carton_type(pool, id, tenant_id, req)
+            .await
+            .map_err(|e| duplicate_code(e, &req.code))?
+            .ok_or(CartonizationError::CartonTypeNotFound)
+    }
+
+    pub async fn list_carton_types(
+        pool: &PgPool,
+        tenant_id: Uuid,
+        include_inactive: bool,
+    ) -> Result<Vec<CartonType>, CartonizationError> {
+        Ok(CartonizationRepo::list_carton_types(pool, tenant_id, include_inactive).await?)
+    }
+
+    pub async fn upsert_sku_profile(
+        pool: &PgPool,
+        tenant_id: Uuid,
+        sku: &str,
+        req: &SkuPackProfileRequest,
+    ) -> Result<SkuPackProfile, CartonizationError> {
+        if sku.trim().is_empty() {
+            return Err(CartonizationError::Validation(
+                "sku must not be empty".to_string(),
+            ));
+        }
+        if !(req.length_in > 0.0 && req.width_in > 0.0 && req.height_in > 0.0) {
+            return Err(CartonizationError::Validation(
+                "dimensions must be > 0".to_string(),
+            ));
+        }
+        if req.weight_lbs.is_nan() || req.weight_lbs < 0.0 {
+            return Err(CartonizationError::Validation(
+                "weight_lbs must be >= 0".to_string(),
+            ));
+        }
+        Ok(CartonizationRepo::upsert_sku_profile(pool, tenant_id, sku, req).await?)
+    }
+
+    pub async fn get_sku_profile(
+        pool: &PgPool,
+        tenant_id: Uuid,
+        sku: &str,
+    ) -> Result<Option<SkuPackProfile>, CartonizationError> {
+        Ok(CartonizationRepo::get_sku_profile(pool, tenant_id, sku).await?)
+    }
+
+    // ── Cartonization ────────────────────────────────────────
+
+    /// Plan the shipment's cartons from its lines, replacing any earlier plan.
+    ///
+    /// Guard → Mutation → Outbox, all within a single transaction.
+    pub async fn cartonize(
+        pool: &PgPool,
+        shipment_id: Uuid,
+        tenant_id: Uuid,
+        req: &CartonizeRequest,
+    ) -> Result<PackingList, CartonizationError> {
+        let shipment = ShipmentRepository::get_shipment(pool, shipment_id, tenant_id)
+            .await?
+            .ok_or(CartonizationError::ShipmentNotFound)?;
+        check_packable(&shipment)?;
+
+        // ── Inputs: lines, pack profiles, carton types ──
+        let lines =
+            ShipmentRepository::get_lines_for_shipment(pool, shipment_id, tenant_id).await?;
+        let mut items = Vec::new();
+        let mut skus = Vec::new();
+        for line in lines.iter().filter(|l| l.qty_expected > 0) {
+            let sku = line.sku.clone().ok_or_else(|| {
+                CartonizationError::Validation(format!("line {} has no sku", line.id))
+            })?;
+            skus.push(sku);
+        }
+        let profiles: HashMap<String, SkuPackProfile> =
+            CartonizationRepo::get_sku_profiles(pool, tenant_id, &skus)
+                .await?
+                .into_iter()
+                .map(|p| (p.sku.clone(), p))
+                .collect();
+        for line in lines.iter().filter(|l| l.qty_expected > 0) {
+            let sku = line.sku.clone().unwrap_or_default();
+            let p = profiles
+                .get(&sku)
+                .ok_or_else(|| CartonizationError::MissingPackProfile(sku.clone()))?;
+            items.push(PackItem {
+                shipment_line_id: line.id,
+                sku,
+                qty: line.qty_expected,
+                length_in: p.length_in,
+                width_in: p.width_in,
+                height_in: p.height_in,
+                weight_lbs: p.weight_lbs,
+                ship_alone: p.ship_alone,
+                this_side_up: p.this_side_up,
+                pack_group: p.pack_group.clone(),
+            });
+        }
+        if items.is_empty() {
+            return Err(CartonizationError::Validation(
+                "shipment has no lines to pack".to_string(),
+            ));
+        }
+
+        let mut types = CartonizationRepo::list_carton_types(pool, tenant_id, false).await?;
+        if let Some(codes) = &req.carton_type_codes {
+            if let Some(unknown) = codes.iter().find(|c| !types.iter().any(|t| &t.code == *c)) {
+                return Err(CartonizationError::Validation(format!(
+                    "unknown or inactive carton type '{unknown}'"
+                )));
+            }
+            types.retain(|t| codes.contains(&t.code));
+        }
+        let specs: Vec<CartonSpec> = types
+            .iter()
+            .map(|t| CartonSpec {
+                carton_type_id: t.id,
+                code: t.code.clone(),
+                length_in: t.length_in,
+                width_in: t.width_in,
+                height_in: t.height_in,
+                tare_weight_lbs: t.tare_weight_lbs,
+                max_weight_lbs: t.max_weight_lbs,
+            })
+            .collect();
+
+        let plan = packer::cartonize(&items, &specs)?;
+
+        let mut tx = pool.begin().await?;
+
+        // ── Guard: shipment state, nothing packed yet ──
+        lock_packable_shipment(&mut tx, shipment_id, tenant_id).await?;
+        if CartonizationRepo::packing_started_tx(&mut tx, shipment_id, tenant_id).await? {
+            return Err(CartonizationError::PackingStarted);
+        }
+
+        // ── Mutation: replace cartons ──
+        CartonizationRepo::delete_cartons_tx(&mut tx, shipment_id, tenant_id).await?;
+        for planned in &plan {
+            let carton = CartonizationRepo::insert_carton_tx(
+                &mut tx,
+                tenant_id,
+                shipment_id,
+                planned.carton_type_id,
+                true,
+            )
+            .await?;
+            for line in &planned.lines {
+                CartonizationRepo::insert_planned_line_tx(
+                    &mut tx,
+                    tenant_id,
+                    carton.id,
+                    line.shipment_line_id,
+                    &line.sku,
+                    line.qty,
+                )
+                .await?;
+            }
+        }
+
+        // ── Outbox ──
+        let event_payload = serde_json::json!({
+            "shipment_id": shipment_id,
+            "tenant_id": tenant_id,
+            "carton_count": plan.len(),
+            "cartons": plan.iter().enumerate().map(|(i, c)| serde_json::json!({
+                "carton_number": i + 1,
+                "carton_type_code": c.code,
+                "content_weight_lbs": c.content_weight_lbs,
+            })).collect::<Vec<_>>(),
+        });
+        outbox::enqueue_event_tx(
+            &mut tx,
+            Uuid::new_v4(),
+            subjects::SHIPMENT_CARTONIZED,
+            "shipment",
+            &shipment_id.to_string(),
+            &tenant_id.to_string(),
+            &event_payload,
+        )
+        .await?;
+
+        tx.commit().await?;
+        Self::packing_list(pool, shipment_id, tenant_id).await
+    }
+
+    // ── Pack station ─────────────────────────────────────────
+
+    /// Open an extra carton at the pack station.
+    pub async fn add_carton(
+        pool: &PgPool,
+        shipment_id: Uuid,
+        tenant_id: Uuid,
+        carton_type_code: &str,
+    ) -> Result<CartonContents, CartonizationError> {
+        let carton_type =
+            CartonizationRepo::find_active_carton_type_by_code(pool, tenant_id, carton_type_code)
+                .await?
+                .ok_or(CartonizationError::CartonTypeNotFound)?;
+
+        let mut tx = pool.begin().await?;
+        lock_packable_shipment(&mut tx, shipment_id, tenant_id).await?;
+        let carton = CartonizationRepo::insert_carton_tx(
+            &mut tx,
+            tenant_id,
+            shipment_id,
+            carton_type.id,
+            false,
+        )
+        .await?;
+        tx.commit().await?;
+
+        Self::carton_contents(pool, shipment_id, tenant_id, carton.id).await
+    }
+
+    /// Record a scanned SKU into an open carton.
+    ///
+    /// The scan goes to the shipment line planned into this carton for the
+    /// SKU, otherwise to the first line of the SKU with quantity left to pack.
+    /// A negative `qty` removes units from the carton.
+    pub async fn scan(
+        pool: &PgPool,
+        shipment_id: Uuid,
+        carton_id: Uuid,
+        tenant_id: Uuid,
+        req: &ScanCartonItemRequest,
+    ) -> Result<CartonContents, CartonizationError> {
+        if req.qty == 0 {
+            return Err(CartonizationError::Validation(
+                "qty must not be 0".to_string(),
+            ));
+        }
+        let sku = req.sku.trim();
+        let lines =
+            ShipmentRepository::get_lines_for_shipment(pool, shipment_id, tenant_id).await?;
+        let sku_lines: Vec<&ShipmentLineRow> = lines
+            .iter()
+            .filter(|l| l.sku.as_deref() == Some(sku))
+            .collect();
+        if sku_lines.is_empty() {
+            return Err(CartonizationError::SkuNotOnShipment(sku.to_string()));
+        }
+
+        let mut tx = pool.begin().await?;
+
+        // ── Guard: shipment and carton state ──
+        lock_packable_shipment(&mut tx, shipment_id, tenant_id).await?;
+        let carton =
+            CartonizationRepo::get_carton_for_update_tx(&mut tx, carton_id, shipment_id, tenant_id)
+                .await?
+                .ok_or(CartonizationError::CartonNotFound)?;
+        if carton.status != CartonStatus::Open.as_str() {
+            return Err(CartonizationError::CartonClosed);
+        }
+        let carton_lines =
+            CartonizationRepo::get_carton_lines_tx(&mut tx, carton_id, tenant_id).await?;
+        let in_carton = |line_id: Uuid| {
+            carton_lines
+                .iter()
+                .find(|cl| cl.shipment_line_id == line_id)
+        };
+
+        // ── Guard: pick the shipment line ──
+        let line_id = if req.qty > 0 {
+            let packed: HashMap<Uuid, i64> =
+                CartonizationRepo::packed_qty_by_line_tx(&mut tx, shipment_id, tenant_id)
+                    .await?
+                    .into_iter()
+                    .collect();
+            let remaining =
+                |l: &ShipmentLineRow| l.qty_expected - packed.get(&l.id).copied().unwrap_or(0);
+            // Lines planned into this carton first, then shipment line order
+            let mut candidates = sku_lines.clone();
+            candidates.sort_by_key(|l| in_carton(l.id).is_none_or(|cl| cl.qty_planned == 0));
+            match candidates.iter().find(|l| remaining(l) >= req.qty) {
+                Some(l) => l.id,
+                None => {
+                    return Err(CartonizationError::OverPack {
+                        sku: sku.to_string(),
+                        remaining: candidates.iter().map(|l| remaining(l)).max().unwrap_or(0),
+                    })
+                }
+            }
+        } else {
+            let in_this: Vec<_> = sku_lines.iter().filter_map(|l| in_carton(l.id)).collect();
+            match in_this.iter().find(|cl| cl.qty_packed >= -req.qty) {
+                Some(cl) => cl.shipment_line_id,
+                None => {
+                    return Err(CartonizationError::UnderPack {
+                        sku: sku.to_string(),
+                        packed: in_this.iter().map(|cl| cl.qty_packed).sum(),
+                    })
+                }
+            }
+        };
+
+        // ── Mutation ──
+        CartonizationRepo::add_packed_qty_tx(&mut tx, tenant_id, carton_id, line_id, sku, req.qty)
+            .await?;
+        tx.commit().await?;
+
+        Self::carton_contents(pool, shipment_id, tenant_id, carton_id).await
+    }
+
+    /// Close a carton with its scale weight (or the estimated weight).
+    ///
+    /// Guard → Mutation → Outbox, all within a single transaction.
+    pub async fn close_carton(
+        pool: &PgPool,
+        shipment_id: Uuid,
+        carton_id: Uuid,
+        tenant_id: Uuid,
+        closed_by: Option<Uuid>,
+        req: &CloseCartonRequest,
+    ) -> Result<CartonContents, CartonizationError> {
+        if let Some(w) = req.gross_weight_lbs {
+            if w.is_nan() || w <= 0.0 {
+                return Err(CartonizationError::Validation(
+                    "gross_weight_lbs must be > 0".to_string(),
+                ));
+            }
+        }
+        let before = Self::carton_contents(pool, shipment_id, tenant_id, carton_id).await?;
+
+        let mut tx = pool.begin().await?;
+
+        // ── Guard ──
+        lock_packable_shipment(&mut tx, shipment_id, tenant_id).await?;
+        let carton =
+            CartonizationRepo::get_carton_for_update_tx(&mut tx, carton_id, shipment_id, tenant_id)
+                .await?
+                .ok_or(CartonizationError::CartonNotFound)?;
+        if carton.status != CartonStatus::Open.as_str() {
+            return Err(CartonizationError::CartonClosed);
+        }
+        if before.lines.iter().all(|l| l.qty_packed == 0) {
+            return Err(CartonizationError::EmptyCarton);
+        }
+        let gross_weight = req.gross_weight_lbs.unwrap_or(before.estimated_weight_lbs);
+
+        // ── Mutation ──
+        CartonizationRepo::close_carton_tx(&mut tx, carton_id, tenant_id, gross_weight, closed_by)
+            .await?;
+
+        // ── Outbox ──
+        let event_payload = serde_json::json!({
+            "shipment_id": shipment_id,
+            "carton_id": carton_id,
+            "tenant_id": tenant_id,
+            "carton_number": carton.carton_number,
+            "carton_type_code": before.carton_type_code,
+            "gross_weight_lbs": gross_weight,
+            "lines": before.lines.iter().filter(|l| l.qty_packed > 0).map(|l| serde_json::json!({
+                "shipment_line_id": l.shipment_line_id,
+                "sku": l.sku,
+                "qty_packed": l.qty_packed,
+            })).collect::<Vec<_>>(),
+        });
+        outbox::enqueue_event_tx(
+            &mut tx,
+            Uuid::new_v4(),
+            subjects::CARTON_CLOSED,
+            "shipment_carton",
+            &carton_id.to_string(),
+            &tenant_id.to_string(),
+            &event_payload,
+        )
+        .await?;
+
+        tx.commit().await?;
+        Self::carton_contents(pool, shipment_id, tenant_id, carton_id).await
+    }
+
+    // ── Reads ────────────────────────────────────────────────
+
+    /// Per-carton content lists plus the quantity still unpacked per line.
+    pub async fn packing_list(
+        pool: &PgPool,
+        shipment_id: Uuid,
+        tenant_id: Uuid,
+    ) -> Result<PackingList, CartonizationError> {
+        ShipmentRepository::get_shipment(pool, shipment_id, tenant_id)
+            .await?
+            .ok_or(CartonizationError::ShipmentNotFound)?;
+        let cartons = load_contents(pool, shipment_id, tenant_id).await?;
+        let lines =
+            ShipmentRepository::get_lines_for_shipment(pool, shipment_id, tenant_id).await?;
+
+        let mut packed: HashMap<Uuid, i64> = HashMap::new();
+        for l in cartons.iter().flat_map(|c| &c.lines) {
+            *packed.entry(l.shipment_line_id).or_default() += l.qty_packed;
+        }
+        let unpacked = lines
+            .into_iter()
+            .filter(|l| packed.get(&l.id).copied().unwrap_or(0) < l.qty_expected)
+            .map(|l| UnpackedLine {
+                shipment_line_id: l.id,
+                qty_packed: packed.get(&l.id).copied().unwrap_or(0),
+                sku: l.sku,
+                qty_expected: l.qty_expected,
+            })
+            .collect();
+
+        Ok(PackingList {
+            shipment_id,
+            cartons,
+            unpacked,
+        })
+    }
+
+    async fn carton_contents(
+        pool: &PgPool,
+        shipment_id: Uuid,
+        tenant_id: Uuid,
+        carton_id: Uuid,
+    ) -> Result<CartonContents, CartonizationError> {
+        load_contents(pool, shipment_id, tenant_id)
+            .await?
+            .into_iter()
+            .find(|c| c.carton.id == carton_id)
+            .ok_or(CartonizationError::CartonNotFound)
+    }
+
+    /// Carton content lists for a shipment, empty if it was never cartonized.
+    pub async fn cartons(
+        pool: &PgPool,
+        shipment_id: Uuid,
+        tenant_id: Uuid,
+    ) -> Result<Vec<CartonContents>, sqlx::Error> {
+        load_contents(pool, shipment_id, tenant_id).await
+    }
+
+    /// Carrier packages for a fully packed shipment, one per closed carton in
+    /// carton-number order, with the carton ids in the same order.
+    pub async fn packages_for_shipment(
+        pool: &PgPool,
+        shipment_id: Uuid,
+        tenant_id: Uuid,
+    ) -> Result<Vec<(Uuid, PackageInfo)>, CartonizationError> {
+        let cartons = load_contents(pool, shipment_id, tenant_id).await?;
+        if cartons.is_empty() {
+            return Err(CartonizationError::Validation(
+                "shipment has no cartons".to_string(),
+            ));
+        }
+        let open = cartons
+            .iter()
+            .filter(|c| c.carton.status == CartonStatus::Open.as_str())
+            .count() as i64;
+        if open > 0 {
+            return Err(CartonizationError::CartonsOpen(open));
+        }
+        Ok(cartons
+            .into_iter()
+            .map(|c| {
+                (
+                    c.carton.id,
+                    PackageInfo {
+                        weight_lbs: c.carton.gross_weight_lbs.unwrap_or(c.estimated_weight_lbs),
+                        length_in: c.length_in,
+                        width_in: c.width_in,
+                        height_in: c.height_in,
+                        declared_value_cents: None,
+                    },
+                )
+            })
+            .collect())
+    }
+}
+
+// ── Helpers ──────────────────────────────────────────────────
+
+fn validate_carton_type(req: &CartonTypeRequest) -> Result<(), CartonizationError> {
+    if req.code.trim().is_empty() {
+        return Err(CartonizationError::Validation(
+            "code must not be empty".to_string(),
+        ));
+    }
+    if !(req.length_in > 0.0 && req.width_in > 0.0 && req.height_in > 0.0) {
+        return Err(CartonizationError::Validation(
+            "dimensions must be > 0".to_string(),
+        ));
+    }
+    if !(req.tare_weight_lbs >= 0.0 && req.max_weight_lbs > req.tare_weight_lbs) {
+        return Err(CartonizationError::Validation(
+            "max_weight_lbs must exceed tare_weight_lbs (>= 0)".to_string(),
+        ));
+    }
+    Ok(())
+}
+
+fn duplicate_code(e: sqlx::Error, code: &str) -> CartonizationError {
+    if let sqlx::Error::Database(ref dbe) = e {
+        if dbe.constraint() == Some("uq_sr_carton_types_code") {
+            return CartonizationError::DuplicateCartonType(code.trim().to_string());
+        }
+    }
+    CartonizationError::Database(e)
+}
+
+fn check_packable(shipment: &Shipment) -> Result<(), CartonizationError> {
+    if shipment.direction != Direction::Outbound {
+        return Err(CartonizationError::NotOutbound);
+    }
+    let packable = matches!(
+        OutboundStatus::from_str_value(&shipment.status),
+        Ok(OutboundStatus::Draft | OutboundStatus::Confirmed | OutboundStatus::Picking)
+    );
+    if !packable {
+        return Err(CartonizationError::NotPackable {
+            current: shipment.status.clone(),
+        });
+    }
+    Ok(())
+}
+
+/// Lock the shipment row and check it is an outbound shipment still being
+/// prepared (draft, confirmed or picking).
+async fn lock_packable_shipment(
+    tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+    shipment_id: Uuid,
+    tenant_id: Uuid,
+) -> Result<Shipment, CartonizationError> {
+    let shipment = ShipmentRepository::get_shipment_for_update(tx, shipment_id, tenant_id)
+        .await?
+        .ok_or(CartonizationError::ShipmentNotFound)?;
+    check_packable(&shipment)?;
+    Ok(shipment)
+}
+
+async fn load_contents(
+    pool: &PgPool,
+    shipment_id: Uuid,
+    tenant_id: Uuid,
+) -> Result<Vec<CartonContents>, sqlx::Error> {
+    let cartons = CartonizationRepo::list_cartons(pool, shipment_id, tenant_id).await?;
+    let mut lines = CartonizationRepo::list_carton_lines(pool, shipment_id, tenant_id).await?;
+    Ok(cartons
+        .into_iter()
+        .map(|(carton, carton_type)| {
+            let (mine, rest): (Vec<_>, Vec<_>) =
+                lines.drain(..).partition(|l| l.carton_id == carton.id);
+            lines = rest;
+            let content: f64 = mine
+                .iter()
+                .map(|l| l.unit_weight_lbs.unwrap_or(0.0) * l.qty_packed as f64)
+                .sum();
+            CartonContents {
+                carton,
+                carton_type_code: carton_type.code,
+                length_in: carton_type.length_in,
+                width_in: carton_type.width_in,
+                height_in: carton_type.height_in,
+                estimated_weight_lbs: carton_type.tare_weight_lbs + content,
+                lines: mine,
+            }
+        })
+        .collect())
+}
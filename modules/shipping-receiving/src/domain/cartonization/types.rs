@@ -0,0 +1,209 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use super::super::shipments::types::ParseEnumError;
+
+// ── Master data ───────────────────────────────────────────────
+
+/// Carton type master data. Dimensions are inner, in inches; weights in pounds.
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow, ToSchema)]
+pub struct CartonType {
+    pub id: Uuid,
+    pub tenant_id: Uuid,
+    pub code: String,
+    pub description: Option<String>,
+    pub length_in: f64,
+    pub width_in: f64,
+    pub height_in: f64,
+    pub tare_weight_lbs: f64,
+    pub max_weight_lbs: f64,
+    pub is_active: bool,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// Per-SKU unit dimensions, weight and packing constraints.
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow, ToSchema)]
+pub struct SkuPackProfile {
+    pub tenant_id: Uuid,
+    pub sku: String,
+    pub length_in: f64,
+    pub width_in: f64,
+    pub height_in: f64,
+    pub weight_lbs: f64,
+    pub ship_alone: bool,
+    pub this_side_up: bool,
+    pub pack_group: Option<String>,
+    pub updated_at: DateTime<Utc>,
+}
+
+// ── Shipment cartons ──────────────────────────────────────────
+
+/// Carton status.
+///
+/// ```text
+/// open → closed
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CartonStatus {
+    Open,
+    Closed,
+}
+
+impl CartonStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Open => "open",
+            Self::Closed => "closed",
+        }
+    }
+
+    pub fn from_str_value(s: &str) -> Result<Self, ParseEnumError> {
+        match s {
+            "open" => Ok(Self::Open),
+            "closed" => Ok(Self::Closed),
+            _ => Err(ParseEnumError {
+                type_name: "CartonStatus",
+                value: s.to_string(),
+            }),
+        }
+    }
+}
+
+impl fmt::Display for CartonStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow, ToSchema)]
+pub struct ShipmentCarton {
+    pub id: Uuid,
+    pub tenant_id: Uuid,
+    pub shipment_id: Uuid,
+    pub carton_number: i32,
+    pub carton_type_id: Uuid,
+    pub status: String,
+    /// True when created by cartonization, false when added at the pack station.
+    pub planned: bool,
+    pub gross_weight_lbs: Option<f64>,
+    pub tracking_number: Option<String>,
+    pub closed_at: Option<DateTime<Utc>>,
+    pub closed_by: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow, ToSchema)]
+pub struct CartonLine {
+    pub id: Uuid,
+    pub carton_id: Uuid,
+    pub shipment_line_id: Uuid,
+    pub sku: String,
+    pub qty_planned: i64,
+    pub qty_packed: i64,
+    /// From the SKU's pack profile; None if the SKU has none.
+    pub unit_weight_lbs: Option<f64>,
+}
+
+/// One carton with its type and content list — a packing slip entry and
+/// the source of one carrier package.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct CartonContents {
+    #[serde(flatten)]
+    pub carton: ShipmentCarton,
+    pub carton_type_code: String,
+    pub length_in: f64,
+    pub width_in: f64,
+    pub height_in: f64,
+    /// Tare plus packed content weight (SKUs without a profile count as 0).
+    pub estimated_weight_lbs: f64,
+    pub lines: Vec<CartonLine>,
+}
+
+/// Shipment line quantity not yet packed into any carton.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct UnpackedLine {
+    pub shipment_line_id: Uuid,
+    pub sku: Option<String>,
+    pub qty_expected: i64,
+    pub qty_packed: i64,
+}
+
+/// Per-carton content lists for a shipment.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct PackingList {
+    pub shipment_id: Uuid,
+    pub cartons: Vec<CartonContents>,
+    pub unpacked: Vec<UnpackedLine>,
+}
+
+// ── Request types ─────────────────────────────────────────────
+
+/// Create or replace a carton type.
+#[derive(Debug, Clone, Deserialize, ToSchema)]
+pub struct CartonTypeRequest {
+    pub code: String,
+    pub description: Option<String>,
+    pub length_in: f64,
+    pub width_in: f64,
+    pub height_in: f64,
+    #[serde(default)]
+    pub tare_weight_lbs: f64,
+    pub max_weight_lbs: f64,
+    #[serde(default = "default_true")]
+    pub is_active: bool,
+}
+
+fn default_true() -> bool {
+    true
+}
+
+#[derive(Debug, Clone, Deserialize, ToSchema)]
+pub struct SkuPackProfileRequest {
+    pub length_in: f64,
+    pub width_in: f64,
+    pub height_in: f64,
+    pub weight_lbs: f64,
+    /// Every unit gets its own carton.
+    #[serde(default)]
+    pub ship_alone: bool,
+    /// Height stays vertical; only the footprint may rotate.
+    #[serde(default)]
+    pub this_side_up: bool,
+    /// SKUs only share a carton with SKUs of the same group.
+    pub pack_group: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize, ToSchema)]
+pub struct CartonizeRequest {
+    /// Restrict the plan to these carton type codes (default: all active types).
+    pub carton_type_codes: Option<Vec<String>>,
+}
+
+#[derive(Debug, Clone, Deserialize, ToSchema)]
+pub struct AddCartonRequest {
+    pub carton_type_code: String,
+}
+
+#[derive(Debug, Clone, Deserialize, ToSchema)]
+pub struct ScanCartonItemRequest {
+    pub sku: String,
+    /// Units scanned (default 1). Negative removes units after a mis-scan.
+    #[serde(default = "default_scan_qty")]
+    pub qty: i64,
+}
+
+fn default_scan_qty() -> i64 {
+    1
+}
+
+#[derive(Debug, Clone, Default, Deserialize, ToSchema)]
+pub struct CloseCartonRequest {
+    /// Scale weight. Defaults to the estimated weight.
+    pub gross_weight_lbs: Option<f64>,
+}
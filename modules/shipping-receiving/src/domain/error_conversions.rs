@@ -1,7 +1,10 @@
 use platform_http_contracts::ApiError;
 
+use super::cartonization::{CartonizationError, PackError};
 use super::inspection_routing::RoutingError;
 use super::outbound_ship::OutboundShipError;
+use super::rate_shop::RateShopError;
+use super::rma::{RmaAuthorizationError, RmaError};
 use super::shipments::ShipmentError;
 
 impl From<ShipmentError> for ApiError {
@@ -88,3 +91,144 @@ impl From<RoutingError> for ApiError {
         }
     }
 }
+
+impl From<CartonizationError> for ApiError {
+    fn from(err: CartonizationError) -> Self {
+        match err {
+            CartonizationError::ShipmentNotFound => ApiError::not_found("Shipment not found"),
+            CartonizationError::CartonNotFound => ApiError::not_found("Carton not found"),
+            CartonizationError::CartonTypeNotFound => ApiError::not_found("Carton type not found"),
+            CartonizationError::DuplicateCartonType(code) => {
+                ApiError::conflict(format!("Carton type code '{code}' already exists"))
+            }
+            CartonizationError::NotOutbound => ApiError::new(
+                400,
+                "validation_error",
+                "Cartonization is only valid for outbound shipments",
+            ),
+            CartonizationError::NotPackable { ref current } => ApiError::new(
+                400,
+                "invalid_state",
+                format!("Shipment cannot be packed in status {current}"),
+            ),
+            CartonizationError::PackingStarted => {
+                ApiError::conflict("Packing has started — cartons can no longer be re-planned")
+            }
+            CartonizationError::CartonClosed => ApiError::conflict("Carton is closed"),
+            CartonizationError::CartonsOpen(open) => {
+                ApiError::conflict(format!("{open} carton(s) still open"))
+            }
+            CartonizationError::MissingPackProfile(sku) => ApiError::new(
+                422,
+                "missing_pack_profile",
+                format!("SKU '{sku}' has no pack profile"),
+            ),
+            CartonizationError::Pack(PackError::DoesNotFit { sku }) => ApiError::new(
+                422,
+                "does_not_fit",
+                format!("SKU '{sku}' does not fit any active carton type"),
+            ),
+            CartonizationError::Pack(e) => ApiError::new(422, "validation_error", e.to_string()),
+            e @ (CartonizationError::EmptyCarton
+            | CartonizationError::SkuNotOnShipment(_)
+            | CartonizationError::OverPack { .. }
+            | CartonizationError::UnderPack { .. }) => {
+                ApiError::new(422, "pack_scan_rejected", e.to_string())
+            }
+            CartonizationError::Validation(msg) => ApiError::new(422, "validation_error", msg),
+            CartonizationError::Database(e) => {
+                tracing::error!(error = %e, "database error in cartonization");
+                ApiError::internal("Internal server error")
+            }
+        }
+    }
+}
+
+impl From<RateShopError> for ApiError {
+    fn from(err: RateShopError) -> Self {
+        match err {
+            RateShopError::ShipmentNotFound => ApiError::not_found("Shipment not found"),
+            RateShopError::ExclusionNotFound => ApiError::not_found("Exclusion not found"),
+            RateShopError::RulesNotConfigured => ApiError::new(
+                422,
+                "rate_shop_not_configured",
+                "Rate shopping is not configured for this tenant",
+            ),
+            RateShopError::NotOutbound => ApiError::new(
+                400,
+                "validation_error",
+                "Rate shopping is only valid for outbound shipments",
+            ),
+            RateShopError::NotShoppable { ref current } => ApiError::new(
+                400,
+                "invalid_state",
+                format!("Shipment cannot be rate shopped in status {current}"),
+            ),
+            RateShopError::UnknownCarrier(code) => ApiError::new(
+                422,
+                "validation_error",
+                format!("Unknown carrier_code: {code}"),
+            ),
+            RateShopError::NoEligibleQuote(summary) => ApiError::new(
+                422,
+                "no_eligible_quote",
+                format!("No eligible quote: {summary}"),
+            ),
+            RateShopError::Validation(msg) => ApiError::new(422, "validation_error", msg),
+            RateShopError::Database(e) => {
+                tracing::error!(error = %e, "database error in rate shopping");
+                ApiError::internal("Internal server error")
+            }
+        }
+    }
+}
+
+impl From<RmaError> for ApiError {
+    fn from(err: RmaError) -> Self {
+        match err {
+            RmaError::NotFound => ApiError::not_found("RMA receipt not found"),
+            RmaError::AuthorizationNotFound => ApiError::not_found("RMA authorization not found"),
+            RmaError::Validation(msg) => ApiError::new(422, "validation_error", msg),
+            RmaError::Transition(t) => ApiError::new(400, "invalid_transition", t.to_string()),
+            RmaError::NoItems => ApiError::new(
+                422,
+                "validation_error",
+                "RMA must have at least one item",
+            ),
+            RmaError::Database(e) => {
+                tracing::error!(error = %e, "database error in RMA receiving");
+                ApiError::internal("Internal server error")
+            }
+        }
+    }
+}
+
+impl From<RmaAuthorizationError> for ApiError {
+    fn from(err: RmaAuthorizationError) -> Self {
+        match err {
+            RmaAuthorizationError::NotFound => ApiError::not_found("RMA authorization not found"),
+            RmaAuthorizationError::DuplicateNumber(number) => {
+                ApiError::conflict(format!("RMA number '{number}' already exists"))
+            }
+            RmaAuthorizationError::InvalidStatus { action, ref current } => ApiError::new(
+                400,
+                "invalid_state",
+                format!("RMA authorization cannot be {action} in status {current}"),
+            ),
+            RmaAuthorizationError::ReceiptsPending(msg) => ApiError::new(
+                422,
+                "rma_receipts_pending",
+                format!("RMA receipts pending: {msg}"),
+            ),
+            RmaAuthorizationError::Validation(msg) => ApiError::new(422, "validation_error", msg),
+            RmaAuthorizationError::InventoryIntegration(msg) => {
+                tracing::error!(error = %msg, "inventory integration error");
+                ApiError::new(502, "inventory_error", "Inventory integration failed")
+            }
+            RmaAuthorizationError::Database(e) => {
+                tracing::error!(error = %e, "database error in RMA authorization");
+                ApiError::internal("Internal server error")
+            }
+        }
+    }
+}
@@ -1,8 +1,10 @@
 pub mod carrier_providers;
+pub mod cartonization;
 pub mod carrier_requests;
 mod error_conversions;
 pub mod inspection_routing;
 pub mod outbound_ship;
+pub mod rate_shop;
 pub mod rma;
 pub mod shipments;
 pub mod shipping_docs;
@@ -0,0 +1,10 @@
+pub mod selection;
+pub mod service;
+pub mod types;
+
+pub use selection::{CarrierFailure, CarrierMode, NormalizedQuote, SelectionRule};
+pub use service::{RateShopError, RateShopService};
+pub use types::{
+    RateShopAddress, RateShopConfig, RateShopDecision, RateShopExclusion, RateShopExclusionRequest,
+    RateShopRequest, RateShopRules, RateShopRulesRequest,
+};
@@ -0,0 +1,504 @@
+//! Rate-shop quote normalization and selection — pure, no I/O.
+//!
+//! Carrier quotes are normalized (transport mode, guaranteed delivery) and
+//! checked against the tenant's rules. Rejection reasons, first match wins:
+//! - carrier excluded for the destination
+//! - wrong transport mode for the shipment weight (LTL threshold)
+//! - currency differs from the rules currency
+//! - no positive charge returned
+//! - transit days unknown or beyond the delivery promise
+//! - not guaranteed when a guaranteed service is required
+//!
+//! Among eligible quotes the cheapest wins (ties: faster, then carrier code).
+//! The preferred carrier wins instead when its best eligible quote is within
+//! `preferred_tolerance_pct` of the cheapest.
+
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use super::types::{RateShopAddress, RateShopExclusion, RateShopRules};
+use crate::domain::carrier_providers::RateQuote;
+
+/// Transport mode of a carrier.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum CarrierMode {
+    Parcel,
+    Ltl,
+}
+
+impl CarrierMode {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Parcel => "parcel",
+            Self::Ltl => "ltl",
+        }
+    }
+}
+
+/// Rule that picked the selected quote.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SelectionRule {
+    Cheapest,
+    CheapestWithinPromise,
+    PreferredCarrier,
+}
+
+impl SelectionRule {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Cheapest => "cheapest",
+            Self::CheapestWithinPromise => "cheapest_within_promise",
+            Self::PreferredCarrier => "preferred_carrier",
+        }
+    }
+}
+
+/// A carrier quote in comparable form, with its eligibility verdict.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct NormalizedQuote {
+    pub carrier_code: String,
+    pub mode: CarrierMode,
+    pub service_level: String,
+    pub total_charge_minor: i64,
+    pub currency: String,
+    pub transit_days: Option<i32>,
+    pub guaranteed: bool,
+    pub eligible: bool,
+    /// Why the quote was not eligible.
+    pub rejection: Option<String>,
+}
+
+/// A carrier that returned no quotes (error or timeout).
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct CarrierFailure {
+    pub carrier_code: String,
+    pub error: String,
+}
+
+/// Shipment facts the rules are evaluated against.
+#[derive(Debug, Clone)]
+pub struct ShipmentFacts<'a> {
+    pub weight_lbs: f64,
+    pub destination: &'a RateShopAddress,
+    pub max_transit_days: Option<i32>,
+    pub require_guaranteed: bool,
+}
+
+#[derive(Debug, Clone)]
+pub struct Selection {
+    pub quote: NormalizedQuote,
+    pub rule: SelectionRule,
+    pub rationale: String,
+}
+
+/// LTL carriers; every other registered carrier (including the stub) is parcel.
+pub fn carrier_mode(carrier_code: &str) -> CarrierMode {
+    match carrier_code {
+        "rl" | "xpo" | "odfl" | "saia" => CarrierMode::Ltl,
+        _ => CarrierMode::Parcel,
+    }
+}
+
+/// Whether a carrier service carries a delivery guarantee.
+///
+/// Carriers do not flag this uniformly, so it is derived from the service
+/// name: any "guaranteed" service, UPS air services, FedEx express services
+/// and USPS Priority Mail Express.
+pub fn is_guaranteed(carrier_code: &str, service_level: &str) -> bool {
+    let s = service_level.to_ascii_lowercase();
+    if s.contains("guaranteed") {
+        return true;
+    }
+    let keywords: &[&str] = match carrier_code {
+        "ups" => &[
+            "next day air",
+            "2nd day air",
+            "3 day select",
+            "worldwide express",
+        ],
+        "fedex" => &["overnight", "2day", "2 day", "express saver"],
+        "usps" => &["priority mail express"],
+        _ => &[],
+    };
+    keywords.iter().any(|k| s.contains(k))
+}
+
+/// Normalize carrier quotes and mark each eligible or rejected.
+pub fn evaluate(
+    quotes: Vec<RateQuote>,
+    rules: &RateShopRules,
+    exclusions: &[RateShopExclusion],
+    facts: &ShipmentFacts<'_>,
+) -> Vec<NormalizedQuote> {
+    quotes
+        .into_iter()
+        .map(|q| {
+            let mode = carrier_mode(&q.carrier_code);
+            let guaranteed = is_guaranteed(&q.carrier_code, &q.service_level);
+            let mut n = NormalizedQuote {
+                carrier_code: q.carrier_code,
+                mode,
+                service_level: q.service_level,
+                total_charge_minor: q.total_charge_minor,
+                currency: q.currency,
+                transit_days: q.estimated_days,
+                guaranteed,
+                eligible: true,
+                rejection: None,
+            };
+            n.rejection = rejection(&n, rules, exclusions, facts);
+            n.eligible = n.rejection.is_none();
+            n
+        })
+        .collect()
+}
+
+fn rejection(
+    q: &NormalizedQuote,
+    rules: &RateShopRules,
+    exclusions: &[RateShopExclusion],
+    facts: &ShipmentFacts<'_>,
+) -> Option<String> {
+    if let Some(ex) = exclusions
+        .iter()
+        .find(|ex| ex.carrier_code == q.carrier_code && excludes(ex, facts.destination))
+    {
+        return Some(match &ex.reason {
+            Some(r) => format!("excluded for destination: {r}"),
+            None => "excluded for destination".to_string(),
+        });
+    }
+    if let Some(threshold) = rules.ltl_min_weight_lbs {
+        let want = if facts.weight_lbs >= threshold {
+            CarrierMode::Ltl
+        } else {
+            CarrierMode::Parcel
+        };
+        if q.mode != want {
+            return Some(format!(
+                "{} not eligible at {} lbs (LTL threshold {} lbs)",
+                q.mode.as_str(),
+                facts.weight_lbs,
+                threshold
+            ));
+        }
+    }
+    if !q.currency.eq_ignore_ascii_case(&rules.currency) {
+        return Some(format!(
+            "currency {} differs from {}",
+            q.currency, rules.currency
+        ));
+    }
+    if q.total_charge_minor <= 0 {
+        return Some("no charge returned".to_string());
+    }
+    if let Some(promise) = facts.max_transit_days {
+        match q.transit_days {
+            None => return Some("transit days unknown".to_string()),
+            Some(d) if d > promise => {
+                return Some(format!("{d} transit days exceeds promise of {promise}"))
+            }
+            Some(_) => {}
+        }
+    }
+    if facts.require_guaranteed && !q.guaranteed {
+        return Some("not a guaranteed service".to_string());
+    }
+    None
+}
+
+/// True if the exclusion covers the destination; NULL fields match anything.
+fn excludes(ex: &RateShopExclusion, dest: &RateShopAddress) -> bool {
+    let eq = |want: &Option<String>, have: Option<&str>| match want {
+        None => true,
+        Some(w) => have.is_some_and(|h| h.trim().eq_ignore_ascii_case(w.trim())),
+    };
+    let zip_ok = match &ex.dest_zip_prefix {
+        None => true,
+        Some(prefix) => dest.zip.trim().starts_with(prefix.trim()),
+    };
+    eq(&ex.dest_country, Some(&dest.country)) && eq(&ex.dest_state, dest.state.as_deref()) && zip_ok
+}
+
+/// Pick the winning quote among the eligible ones.
+///
+/// Returns `None` when no quote is eligible.
+pub fn select(
+    quotes: &[NormalizedQuote],
+    rules: &RateShopRules,
+    facts: &ShipmentFacts<'_>,
+) -> Option<Selection> {
+    let mut eligible: Vec<&NormalizedQuote> = quotes.iter().filter(|q| q.eligible).collect();
+    eligible.sort_by(|a, b| {
+        a.total_charge_minor
+            .cmp(&b.total_charge_minor)
+            .then(
+                a.transit_days
+                    .unwrap_or(i32::MAX)
+                    .cmp(&b.transit_days.unwrap_or(i32::MAX)),
+            )
+            .then(a.carrier_code.cmp(&b.carrier_code))
+            .then(a.service_level.cmp(&b.service_level))
+    });
+    let cheapest = *eligible.first()?;
+    let summary = format!("{} of {} quote(s) eligible", eligible.len(), quotes.len());
+
+    if let Some(preferred) = rules.preferred_carrier_code.as_deref() {
+        if preferred != cheapest.carrier_code {
+            let limit =
+                cheapest.total_charge_minor as f64 * (1.0 + rules.preferred_tolerance_pct / 100.0);
+            if let Some(p) = eligible
+                .iter()
+                .find(|q| q.carrier_code == preferred && q.total_charge_minor as f64 <= limit)
+            {
+                return Some(Selection {
+                    rationale: format!(
+                        "{}: preferred carrier within {}% of cheapest {}; {summary}",
+                        describe(p),
+                        rules.preferred_tolerance_pct,
+                        describe(cheapest)
+                    ),
+                    quote: (*p).clone(),
+                    rule: SelectionRule::PreferredCarrier,
+                });
+            }
+        }
+    }
+
+    let (rule, why) = match facts.max_transit_days {
+        Some(p) => (
+            SelectionRule::CheapestWithinPromise,
+            format!("cheapest within {p}-day promise"),
+        ),
+        None => (SelectionRule::Cheapest, "cheapest".to_string()),
+    };
+    Some(Selection {
+        rationale: format!("{}: {why}; {summary}", describe(cheapest)),
+        quote: cheapest.clone(),
+        rule,
+    })
+}
+
+fn describe(q: &NormalizedQuote) -> String {
+    let days = match q.transit_days {
+        Some(d) => format!("{d} day(s)"),
+        None => "transit unknown".to_string(),
+    };
+    format!(
+        "{} {} at {}.{:02} {}, {days}",
+        q.carrier_code,
+        q.service_level,
+        q.total_charge_minor / 100,
+        q.total_charge_minor % 100,
+        q.currency
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+    use uuid::Uuid;
+
+    fn rules() -> RateShopRules {
+        RateShopRules {
+            tenant_id: Uuid::new_v4(),
+            carrier_codes: vec!["ups".into(), "fedex".into(), "rl".into()],
+            preferred_carrier_code: None,
+            preferred_tolerance_pct: 0.0,
+            ltl_min_weight_lbs: None,
+            currency: "USD".to_string(),
+            carrier_timeout_ms: 5000,
+            updated_at: Utc::now(),
+        }
+    }
+
+    fn dest() -> RateShopAddress {
+        RateShopAddress {
+            zip: "99501".to_string(),
+            city: Some("Anchorage".to_string()),
+            state: Some("AK".to_string()),
+            country: "US".to_string(),
+        }
+    }
+
+    fn quote(carrier: &str, service: &str, charge: i64, days: Option<i32>) -> RateQuote {
+        RateQuote {
+            service_level: service.to_string(),
+            carrier_code: carrier.to_string(),
+            total_charge_minor: charge,
+            currency: "USD".to_string(),
+            estimated_days: days,
+        }
+    }
+
+    fn facts(d: &RateShopAddress, weight: f64, promise: Option<i32>) -> ShipmentFacts<'_> {
+        ShipmentFacts {
+            weight_lbs: weight,
+            destination: d,
+            max_transit_days: promise,
+            require_guaranteed: false,
+        }
+    }
+
+    fn sample() -> Vec<RateQuote> {
+        vec![
+            quote("ups", "UPS Ground", 1800, Some(5)),
+            quote("fedex", "FedEx Ground", 1700, Some(6)),
+            quote("fedex", "FedEx 2Day", 4200, Some(2)),
+            quote("rl", "LTL Standard", 9500, Some(4)),
+        ]
+    }
+
+    #[test]
+    fn cheapest_wins_without_rules() {
+        let d = dest();
+        let f = facts(&d, 20.0, None);
+        let r = rules();
+        let quotes = evaluate(sample(), &r, &[], &f);
+        let s = select(&quotes, &r, &f).unwrap();
+        assert_eq!(s.quote.carrier_code, "fedex");
+        assert_eq!(s.quote.service_level, "FedEx Ground");
+        assert_eq!(s.rule, SelectionRule::Cheapest);
+        assert!(s.rationale.contains("17.00 USD"), "{}", s.rationale);
+    }
+
+    #[test]
+    fn delivery_promise_filters_slow_quotes() {
+        let d = dest();
+        let f = facts(&d, 20.0, Some(5));
+        let r = rules();
+        let quotes = evaluate(sample(), &r, &[], &f);
+        let s = select(&quotes, &r, &f).unwrap();
+        assert_eq!(s.quote.carrier_code, "ups");
+        assert_eq!(s.rule, SelectionRule::CheapestWithinPromise);
+        let slow = quotes
+            .iter()
+            .find(|q| q.service_level == "FedEx Ground")
+            .unwrap();
+        assert!(!slow.eligible);
+        assert!(slow
+            .rejection
+            .as_deref()
+            .unwrap()
+            .contains("exceeds promise"));
+    }
+
+    #[test]
+    fn preferred_carrier_wins_within_tolerance() {
+        let d = dest();
+        let f = facts(&d, 20.0, None);
+        let mut r = rules();
+        r.preferred_carrier_code = Some("ups".to_string());
+        r.preferred_tolerance_pct = 10.0;
+        let quotes = evaluate(sample(), &r, &[], &f);
+        let s = select(&quotes, &r, &f).unwrap();
+        assert_eq!(s.quote.carrier_code, "ups");
+        assert_eq!(s.rule, SelectionRule::PreferredCarrier);
+
+        // 1800 > 1700 × 1.05 — cheapest wins
+        r.preferred_tolerance_pct = 5.0;
+        let s = select(&quotes, &r, &f).unwrap();
+        assert_eq!(s.quote.carrier_code, "fedex");
+        assert_eq!(s.rule, SelectionRule::Cheapest);
+    }
+
+    #[test]
+    fn ltl_threshold_splits_parcel_and_ltl() {
+        let d = dest();
+        let mut r = rules();
+        r.ltl_min_weight_lbs = Some(150.0);
+
+        let light = facts(&d, 20.0, None);
+        let quotes = evaluate(sample(), &r, &[], &light);
+        assert!(quotes
+            .iter()
+            .filter(|q| q.mode == CarrierMode::Ltl)
+            .all(|q| !q.eligible));
+        assert_eq!(
+            select(&quotes, &r, &light).unwrap().quote.mode,
+            CarrierMode::Parcel
+        );
+
+        let heavy = facts(&d, 400.0, None);
+        let quotes = evaluate(sample(), &r, &[], &heavy);
+        let s = select(&quotes, &r, &heavy).unwrap();
+        assert_eq!(s.quote.carrier_code, "rl");
+        assert_eq!(s.quote.mode, CarrierMode::Ltl);
+    }
+
+    #[test]
+    fn destination_exclusions_apply() {
+        let d = dest();
+        let f = facts(&d, 20.0, None);
+        let r = rules();
+        let ex = RateShopExclusion {
+            id: Uuid::new_v4(),
+            tenant_id: r.tenant_id,
+            carrier_code: "fedex".to_string(),
+            dest_country: Some("us".to_string()),
+            dest_state: Some("AK".to_string()),
+            dest_zip_prefix: None,
+            reason: Some("no ground service".to_string()),
+            created_at: Utc::now(),
+        };
+        let quotes = evaluate(sample(), &r, std::slice::from_ref(&ex), &f);
+        let s = select(&quotes, &r, &f).unwrap();
+        assert_eq!(s.quote.carrier_code, "ups");
+        let fx = quotes.iter().find(|q| q.carrier_code == "fedex").unwrap();
+        assert_eq!(
+            fx.rejection.as_deref(),
+            Some("excluded for destination: no ground service")
+        );
+
+        // A zip prefix that does not match leaves the carrier eligible
+        let ex = RateShopExclusion {
+            dest_state: None,
+            dest_zip_prefix: Some("100".to_string()),
+            ..ex
+        };
+        let quotes = evaluate(sample(), &r, &[ex], &f);
+        assert_eq!(select(&quotes, &r, &f).unwrap().quote.carrier_code, "fedex");
+    }
+
+    #[test]
+    fn guaranteed_and_currency_rules() {
+        let d = dest();
+        let mut f = facts(&d, 20.0, None);
+        f.require_guaranteed = true;
+        let r = rules();
+        let mut input = sample();
+        input.push(RateQuote {
+            currency: "CAD".to_string(),
+            ..quote("ups", "UPS Next Day Air", 100, Some(1))
+        });
+        let quotes = evaluate(input, &r, &[], &f);
+        let s = select(&quotes, &r, &f).unwrap();
+        assert_eq!(s.quote.service_level, "FedEx 2Day");
+        assert!(s.quote.guaranteed);
+        let cad = quotes.iter().find(|q| q.currency == "CAD").unwrap();
+        assert!(cad.rejection.as_deref().unwrap().contains("currency"));
+    }
+
+    #[test]
+    fn no_eligible_quote_returns_none() {
+        let d = dest();
+        let f = facts(&d, 20.0, Some(1));
+        let r = rules();
+        let quotes = evaluate(sample(), &r, &[], &f);
+        assert!(select(&quotes, &r, &f).is_none());
+        assert!(select(&[], &r, &f).is_none());
+    }
+
+    #[test]
+    fn guaranteed_detection() {
+        assert!(is_guaranteed("ups", "UPS Next Day Air Saver"));
+        assert!(!is_guaranteed("ups", "UPS Ground"));
+        assert!(is_guaranteed("fedex", "FedEx Priority Overnight"));
+        assert!(is_guaranteed("usps", "Priority Mail Express 1-Day™"));
+        assert!(!is_guaranteed("usps", "Priority Mail 2-Day™"));
+        assert!(is_guaranteed("xpo", "Guaranteed by 5PM"));
+        assert!(!is_guaranteed("stub", "express"));
+    }
+}
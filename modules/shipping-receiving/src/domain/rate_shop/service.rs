@@ -0,0 +1,559 @@
+//! Rate-shop service — quotes all configured carriers and records the choice.
+//!
+//! Invariants:
+//! - Only outbound shipments that have not shipped can be rate shopped
+//! - Carriers are quoted in parallel; each carrier call (credentials and
+//!   rates) is bounded by the tenant's `carrier_timeout_ms`. A failed or
+//!   timed-out carrier never fails the rate shop, it is recorded instead
+//! - Every successful rate shop writes a decision row (selected quote, rule,
+//!   rationale, all quotes) and its event to the outbox atomically
+//! - All queries filter by tenant_id
+
+use std::time::Duration;
+
+use futures::future::{join_all, BoxFuture};
+use reqwest::Client;
+use sqlx::PgPool;
+use thiserror::Error;
+use uuid::Uuid;
+
+use super::selection::{self, CarrierFailure, NormalizedQuote, ShipmentFacts};
+use super::types::{
+    RateShopConfig, RateShopDecision, RateShopExclusion, RateShopExclusionRequest, RateShopRequest,
+    RateShopRules, RateShopRulesRequest,
+};
+use crate::db::rate_shop_repo::{NewDecision, RateShopRepo};
+use crate::db::repository::ShipmentRepository;
+use crate::domain::carrier_providers::dispatch::fetch_credentials_or_empty;
+use crate::domain::carrier_providers::{get_provider, CarrierProviderError, RateQuote};
+use crate::domain::cartonization::CartonizationService;
+use crate::domain::shipments::types::{Direction, OutboundStatus};
+use crate::outbox;
+
+// ── Errors ────────────────────────────────────────────────────
+
+#[derive(Debug, Error)]
+pub enum RateShopError {
+    #[error("Shipment not found")]
+    ShipmentNotFound,
+
+    #[error("Exclusion not found")]
+    ExclusionNotFound,
+
+    #[error("Rate shopping is not configured for this tenant")]
+    RulesNotConfigured,
+
+    #[error("Rate shopping is only valid for outbound shipments")]
+    NotOutbound,
+
+    #[error("Shipment cannot be rate shopped in status {current}")]
+    NotShoppable { current: String },
+
+    #[error("Unknown carrier_code: {0}")]
+    UnknownCarrier(String),
+
+    #[error("No eligible quote: {0}")]
+    NoEligibleQuote(String),
+
+    #[error("Validation error: {0}")]
+    Validation(String),
+
+    #[error("Database error: {0}")]
+    Database(#[from] sqlx::Error),
+}
+
+// ── Event type constants ─────────────────────────────────────
+
+pub mod subjects {
+    pub const SHIPMENT_RATE_SHOPPED: &str = "sr.shipment.rate_shopped";
+}
+
+// ── Service ──────────────────────────────────────────────────
+
+pub struct RateShopService;
+
+impl RateShopService {
+    // ── Configuration ────────────────────────────────────────
+
+    pub async fn put_rules(
+        pool: &PgPool,
+        tenant_id: Uuid,
+        req: &RateShopRulesRequest,
+    ) -> Result<RateShopRules, RateShopError> {
+        let req = validate_rules(req)?;
+        Ok(RateShopRepo::upsert_rules(pool, tenant_id, &req).await?)
+    }
+
+    /// Rules and exclusions; `None` if the tenant has no rules.
+    pub async fn get_config(
+        pool: &PgPool,
+        tenant_id: Uuid,
+    ) -> Result<Option<RateShopConfig>, RateShopError> {
+        let Some(rules) = RateShopRepo::get_rules(pool, tenant_id).await? else {
+            return Ok(None);
+        };
+        let exclusions = RateShopRepo::list_exclusions(pool, tenant_id).await?;
+        Ok(Some(RateShopConfig { rules, exclusions }))
+    }
+
+    pub async fn add_exclusion(
+        pool: &PgPool,
+        tenant_id: Uuid,
+        req: &RateShopExclusionRequest,
+    ) -> Result<RateShopExclusion, RateShopError> {
+        let code = req.carrier_code.trim();
+        if get_provider(code).is_none() {
+            return Err(RateShopError::UnknownCarrier(code.to_string()));
+        }
+        let trimmed = |v: &Option<String>| {
+            v.as_deref()
+                .map(str::trim)
+                .filter(|s| !s.is_empty())
+                .map(String::from)
+        };
+        let req = RateShopExclusionRequest {
+            carrier_code: code.to_string(),
+            dest_country: trimmed(&req.dest_country),
+            dest_state: trimmed(&req.dest_state),
+            dest_zip_prefix: trimmed(&req.dest_zip_prefix),
+            reason: req.reason.clone(),
+        };
+        Ok(RateShopRepo::insert_exclusion(pool, tenant_id, &req).await?)
+    }
+
+    pub async fn delete_exclusion(
+        pool: &PgPool,
+        id: Uuid,
+        tenant_id: Uuid,
+    ) -> Result<(), RateShopError> {
+        if RateShopRepo::delete_exclusion(pool, id, tenant_id).await? {
+            Ok(())
+        } else {
+            Err(RateShopError::ExclusionNotFound)
+        }
+    }
+
+    // ── Rate shop ────────────────────────────────────────────
+
+    /// Quote every configured carrier, apply the tenant rules and record the
+    /// winning quote with its rationale on the shipment.
+    ///
+    /// Guard → Quote → Mutation → Outbox. Carrier calls happen outside the
+    /// transaction.
+    pub async fn rate_shop(
+        pool: &PgPool,
+        shipment_id: Uuid,
+        tenant_id: Uuid,
+        decided_by: Option<Uuid>,
+        req: &RateShopRequest,
+    ) -> Result<RateShopDecision, RateShopError> {
+        // ── Guard: shipment, rules, request ──
+        let shipment = ShipmentRepository::get_shipment(pool, shipment_id, tenant_id)
+            .await?
+            .ok_or(RateShopError::ShipmentNotFound)?;
+        if shipment.direction != Direction::Outbound {
+            return Err(RateShopError::NotOutbound);
+        }
+        let shoppable = matches!(
+            OutboundStatus::from_str_value(&shipment.status),
+            Ok(OutboundStatus::Draft
+                | OutboundStatus::Confirmed
+                | OutboundStatus::Picking
+                | OutboundStatus::Packed)
+        );
+        if !shoppable {
+            return Err(RateShopError::NotShoppable {
+                current: shipment.status,
+            });
+        }
+        let rules = RateShopRepo::get_rules(pool, tenant_id)
+            .await?
+            .ok_or(RateShopError::RulesNotConfigured)?;
+        let exclusions = RateShopRepo::list_exclusions(pool, tenant_id).await?;
+        if let Some(d) = req.max_transit_days {
+            if d < 1 {
+                return Err(RateShopError::Validation(
+                    "max_transit_days must be >= 1".to_string(),
+                ));
+            }
+        }
+
+        // Weight and dimensions from the request or the shipment's cartons
+        let cartons = CartonizationService::cartons(pool, shipment_id, tenant_id).await?;
+        let weight_lbs = match req.weight_lbs {
+            Some(w) if w.is_nan() || w <= 0.0 => {
+                return Err(RateShopError::Validation(
+                    "weight_lbs must be > 0".to_string(),
+                ))
+            }
+            Some(w) => w,
+            None if cartons.is_empty() => {
+                return Err(RateShopError::Validation(
+                    "weight_lbs is required when the shipment has no cartons".to_string(),
+                ))
+            }
+            None => cartons
+                .iter()
+                .map(|c| c.carton.gross_weight_lbs.unwrap_or(c.estimated_weight_lbs))
+                .sum(),
+        };
+
+        let mut payload = serde_json::json!({
+            "origin_zip": req.origin.zip,
+            "origin_city": req.origin.city,
+            "origin_state": req.origin.state,
+            "origin_country": req.origin.country,
+            "dest_zip": req.destination.zip,
+            "dest_city": req.destination.city,
+            "dest_state": req.destination.state,
+            "dest_country": req.destination.country,
+            "weight_lbs": weight_lbs,
+            "pieces": cartons.len().max(1),
+        });
+        if let Some(class) = &req.freight_class {
+            payload["freight_class"] = serde_json::json!(class);
+        }
+        // Parcel rating uses one package; the largest carton stands in for it
+        if let Some(c) = cartons.iter().max_by(|a, b| {
+            (a.length_in * a.width_in * a.height_in)
+                .total_cmp(&(b.length_in * b.width_in * b.height_in))
+        }) {
+            payload["length_in"] = serde_json::json!(c.length_in);
+            payload["width_in"] = serde_json::json!(c.width_in);
+            payload["height_in"] = serde_json::json!(c.height_in);
+        }
+
+        // ── Quote all carriers in parallel ──
+        let http_client = Client::new();
+        let tenant_str = tenant_id.to_string();
+        let calls: Vec<(
+            String,
+            BoxFuture<'_, Result<Vec<RateQuote>, CarrierProviderError>>,
+        )> = rules
+            .carrier_codes
+            .iter()
+            .map(|code| {
+                let (client, tenant, payload) = (&http_client, &tenant_str, &payload);
+                let fut: BoxFuture<'_, _> = Box::pin(async move {
+                    let provider = get_provider(code).ok_or_else(|| {
+                        CarrierProviderError::InvalidRequest(format!(
+                            "unknown carrier_code: {code}"
+                        ))
+                    })?;
+                    let config = fetch_credentials_or_empty(client, tenant, code).await;
+                    provider.get_rates(payload, &config).await
+                });
+                (code.clone(), fut)
+            })
+            .collect();
+        let timeout = Duration::from_millis(rules.carrier_timeout_ms.max(1) as u64);
+        let (quotes, failures) = collect_quotes(calls, timeout).await;
+
+        // ── Rules ──
+        let facts = ShipmentFacts {
+            weight_lbs,
+            destination: &req.destination,
+            max_transit_days: req.max_transit_days,
+            require_guaranteed: req.require_guaranteed,
+        };
+        let normalized = selection::evaluate(quotes, &rules, &exclusions, &facts);
+        let Some(chosen) = selection::select(&normalized, &rules, &facts) else {
+            return Err(RateShopError::NoEligibleQuote(no_quote_summary(
+                &normalized,
+                &failures,
+            )));
+        };
+        let mut rationale = chosen.rationale;
+        if !failures.is_empty() {
+            let failed: Vec<&str> = failures.iter().map(|f| f.carrier_code.as_str()).collect();
+            rationale.push_str(&format!("; no quotes from {}", failed.join(", ")));
+        }
+        let quotes_json = serde_json::json!({
+            "quotes": normalized,
+            "failures": failures,
+        });
+
+        // ── Mutation ──
+        let mut tx = pool.begin().await?;
+        let decision = RateShopRepo::insert_decision_tx(
+            &mut tx,
+            tenant_id,
+            shipment_id,
+            &NewDecision {
+                carrier_code: &chosen.quote.carrier_code,
+                service_level: &chosen.quote.service_level,
+                total_charge_minor: chosen.quote.total_charge_minor,
+                currency: &chosen.quote.currency,
+                transit_days: chosen.quote.transit_days,
+                guaranteed: chosen.quote.guaranteed,
+                rule_applied: chosen.rule.as_str(),
+                rationale: &rationale,
+                quotes: &quotes_json,
+                weight_lbs,
+                max_transit_days: req.max_transit_days,
+                decided_by,
+            },
+        )
+        .await?;
+
+        // ── Outbox ──
+        let event_payload = serde_json::json!({
+            "decision_id": decision.id,
+            "tenant_id": tenant_id,
+            "shipment_id": shipment_id,
+            "carrier_code": decision.carrier_code,
+            "service_level": decision.service_level,
+            "total_charge_minor": decision.total_charge_minor,
+            "currency": decision.currency,
+            "transit_days": decision.transit_days,
+            "rule_applied": decision.rule_applied,
+            "rationale": decision.rationale,
+        });
+        outbox::enqueue_event_tx(
+            &mut tx,
+            Uuid::new_v4(),
+            subjects::SHIPMENT_RATE_SHOPPED,
+            "shipment",
+            &shipment_id.to_string(),
+            &tenant_id.to_string(),
+            &event_payload,
+        )
+        .await?;
+
+        tx.commit().await?;
+        Ok(decision)
+    }
+
+    /// Rate-shop decisions for a shipment, newest first.
+    pub async fn list_decisions(
+        pool: &PgPool,
+        shipment_id: Uuid,
+        tenant_id: Uuid,
+    ) -> Result<Vec<RateShopDecision>, RateShopError> {
+        ShipmentRepository::get_shipment(pool, shipment_id, tenant_id)
+            .await?
+            .ok_or(RateShopError::ShipmentNotFound)?;
+        Ok(RateShopRepo::list_decisions(pool, shipment_id, tenant_id).await?)
+    }
+}
+
+// ── Helpers ──────────────────────────────────────────────────
+
+/// Run carrier quote calls concurrently, each bounded by `timeout`.
+///
+/// Quotes are returned in call order; errors and timeouts become failures.
+pub(crate) async fn collect_quotes(
+    calls: Vec<(
+        String,
+        BoxFuture<'_, Result<Vec<RateQuote>, CarrierProviderError>>,
+    )>,
+    timeout: Duration,
+) -> (Vec<RateQuote>, Vec<CarrierFailure>) {
+    let results = join_all(
+        calls
+            .into_iter()
+            .map(|(code, fut)| async move { (code, tokio::time::timeout(timeout, fut).await) }),
+    )
+    .await;
+
+    let mut quotes = Vec::new();
+    let mut failures = Vec::new();
+    for (code, result) in results {
+        match result {
+            Ok(Ok(q)) if q.is_empty() => failures.push(CarrierFailure {
+                carrier_code: code,
+                error: "no quotes returned".to_string(),
+            }),
+            Ok(Ok(q)) => quotes.extend(q),
+            Ok(Err(e)) => failures.push(CarrierFailure {
+                carrier_code: code,
+                error: e.to_string(),
+            }),
+            Err(_) => failures.push(CarrierFailure {
+                carrier_code: code,
+                error: format!("timed out after {} ms", timeout.as_millis()),
+            }),
+        }
+    }
+    (quotes, failures)
+}
+
+fn no_quote_summary(quotes: &[NormalizedQuote], failures: &[CarrierFailure]) -> String {
+    let mut parts: Vec<String> = quotes
+        .iter()
+        .map(|q| {
+            format!(
+                "{} {}: {}",
+                q.carrier_code,
+                q.service_level,
+                q.rejection.as_deref().unwrap_or("eligible")
+            )
+        })
+        .collect();
+    parts.extend(
+        failures
+            .iter()
+            .map(|f| format!("{}: {}", f.carrier_code, f.error)),
+    );
+    if parts.is_empty() {
+        "no carriers returned quotes".to_string()
+    } else {
+        parts.join("; ")
+    }
+}
+
+fn validate_rules(req: &RateShopRulesRequest) -> Result<RateShopRulesRequest, RateShopError> {
+    let mut codes: Vec<String> = Vec::new();
+    for code in &req.carrier_codes {
+        let code = code.trim().to_string();
+        if get_provider(&code).is_none() {
+            return Err(RateShopError::UnknownCarrier(code));
+        }
+        if !codes.contains(&code) {
+            codes.push(code);
+        }
+    }
+    if codes.is_empty() {
+        return Err(RateShopError::Validation(
+            "carrier_codes must not be empty".to_string(),
+        ));
+    }
+    let preferred = req
+        .preferred_carrier_code
+        .as_deref()
+        .map(str::trim)
+        .filter(|s| !s.is_empty())
+        .map(String::from);
+    if let Some(p) = &preferred {
+        if !codes.contains(p) {
+            return Err(RateShopError::Validation(format!(
+                "preferred_carrier_code {p} is not in carrier_codes"
+            )));
+        }
+    }
+    if req.preferred_tolerance_pct.is_nan() || req.preferred_tolerance_pct < 0.0 {
+        return Err(RateShopError::Validation(
+            "preferred_tolerance_pct must be >= 0".to_string(),
+        ));
+    }
+    if let Some(w) = req.ltl_min_weight_lbs {
+        if w.is_nan() || w <= 0.0 {
+            return Err(RateShopError::Validation(
+                "ltl_min_weight_lbs must be > 0".to_string(),
+            ));
+        }
+    }
+    let currency = req.currency.trim().to_ascii_uppercase();
+    if currency.len() != 3 || !currency.chars().all(|c| c.is_ascii_alphabetic()) {
+        return Err(RateShopError::Validation(
+            "currency must be an ISO 4217 code".to_string(),
+        ));
+    }
+    if !(1..=60_000).contains(&req.carrier_timeout_ms) {
+        return Err(RateShopError::Validation(
+            "carrier_timeout_ms must be between 1 and 60000".to_string(),
+        ));
+    }
+    Ok(RateShopRulesRequest {
+        carrier_codes: codes,
+        preferred_carrier_code: preferred,
+        preferred_tolerance_pct: req.preferred_tolerance_pct,
+        ltl_min_weight_lbs: req.ltl_min_weight_lbs,
+        currency,
+        carrier_timeout_ms: req.carrier_timeout_ms,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn quote(carrier: &str, charge: i64) -> RateQuote {
+        RateQuote {
+            service_level: "ground".to_string(),
+            carrier_code: carrier.to_string(),
+            total_charge_minor: charge,
+            currency: "USD".to_string(),
+            estimated_days: Some(3),
+        }
+    }
+
+    #[tokio::test]
+    async fn slow_and_failing_carriers_become_failures() {
+        let calls: Vec<(
+            String,
+            BoxFuture<'_, Result<Vec<RateQuote>, CarrierProviderError>>,
+        )> = vec![
+            (
+                "fast".to_string(),
+                Box::pin(async { Ok(vec![quote("fast", 1000)]) }),
+            ),
+            (
+                "slow".to_string(),
+                Box::pin(async {
+                    tokio::time::sleep(Duration::from_secs(5)).await;
+                    Ok(vec![quote("slow", 500)])
+                }),
+            ),
+            (
+                "broken".to_string(),
+                Box::pin(async { Err(CarrierProviderError::ApiError("503".to_string())) }),
+            ),
+            ("empty".to_string(), Box::pin(async { Ok(vec![]) })),
+        ];
+        let started = std::time::Instant::now();
+        let (quotes, failures) = collect_quotes(calls, Duration::from_millis(50)).await;
+        assert!(
+            started.elapsed() < Duration::from_secs(2),
+            "calls must run concurrently"
+        );
+
+        assert_eq!(quotes.len(), 1);
+        assert_eq!(quotes[0].carrier_code, "fast");
+        let failed: Vec<&str> = failures.iter().map(|f| f.carrier_code.as_str()).collect();
+        assert_eq!(failed, vec!["slow", "broken", "empty"]);
+        assert!(failures[0].error.contains("timed out"));
+    }
+
+    #[test]
+    fn rules_validation_normalizes_and_rejects() {
+        let req = RateShopRulesRequest {
+            carrier_codes: vec![" stub ".to_string(), "ups".to_string(), "stub".to_string()],
+            preferred_carrier_code: Some("ups".to_string()),
+            preferred_tolerance_pct: 5.0,
+            ltl_min_weight_lbs: Some(150.0),
+            currency: "usd".to_string(),
+            carrier_timeout_ms: 3000,
+        };
+        let ok = validate_rules(&req).unwrap();
+        assert_eq!(ok.carrier_codes, vec!["stub", "ups"]);
+        assert_eq!(ok.currency, "USD");
+
+        let bad = RateShopRulesRequest {
+            carrier_codes: vec!["dhl".to_string()],
+            ..req.clone()
+        };
+        assert!(matches!(
+            validate_rules(&bad),
+            Err(RateShopError::UnknownCarrier(_))
+        ));
+
+        let bad = RateShopRulesRequest {
+            preferred_carrier_code: Some("fedex".to_string()),
+            ..req.clone()
+        };
+        assert!(matches!(
+            validate_rules(&bad),
+            Err(RateShopError::Validation(_))
+        ));
+
+        let bad = RateShopRulesRequest {
+            carrier_timeout_ms: 0,
+            ..req
+        };
+        assert!(matches!(
+            validate_rules(&bad),
+            Err(RateShopError::Validation(_))
+        ));
+    }
+}
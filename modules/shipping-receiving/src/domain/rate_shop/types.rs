@@ -0,0 +1,127 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+// ── Tenant rules ──────────────────────────────────────────────
+
+/// Per-tenant carrier set and selection rules.
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow, ToSchema)]
+pub struct RateShopRules {
+    pub tenant_id: Uuid,
+    /// Carriers quoted on every rate shop.
+    pub carrier_codes: Vec<String>,
+    pub preferred_carrier_code: Option<String>,
+    /// The preferred carrier wins when within this percentage of the cheapest quote.
+    pub preferred_tolerance_pct: f64,
+    /// At or above this weight only LTL carriers are eligible, below it only parcel.
+    pub ltl_min_weight_lbs: Option<f64>,
+    pub currency: String,
+    pub carrier_timeout_ms: i32,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// Carrier excluded for a destination. Unset fields match any destination.
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow, ToSchema)]
+pub struct RateShopExclusion {
+    pub id: Uuid,
+    pub tenant_id: Uuid,
+    pub carrier_code: String,
+    pub dest_country: Option<String>,
+    pub dest_state: Option<String>,
+    pub dest_zip_prefix: Option<String>,
+    pub reason: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+/// Rules plus destination exclusions.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct RateShopConfig {
+    pub rules: RateShopRules,
+    pub exclusions: Vec<RateShopExclusion>,
+}
+
+// ── Decisions ─────────────────────────────────────────────────
+
+/// Outcome of one rate shop on a shipment.
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow, ToSchema)]
+pub struct RateShopDecision {
+    pub id: Uuid,
+    pub tenant_id: Uuid,
+    pub shipment_id: Uuid,
+    pub carrier_code: String,
+    pub service_level: String,
+    pub total_charge_minor: i64,
+    pub currency: String,
+    pub transit_days: Option<i32>,
+    pub guaranteed: bool,
+    /// cheapest | cheapest_within_promise | preferred_carrier
+    pub rule_applied: String,
+    pub rationale: String,
+    /// `{ "quotes": [NormalizedQuote], "failures": [CarrierFailure] }`
+    pub quotes: serde_json::Value,
+    pub weight_lbs: f64,
+    pub max_transit_days: Option<i32>,
+    pub decided_by: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+}
+
+// ── Request types ─────────────────────────────────────────────
+
+#[derive(Debug, Clone, Deserialize, ToSchema)]
+pub struct RateShopRulesRequest {
+    pub carrier_codes: Vec<String>,
+    pub preferred_carrier_code: Option<String>,
+    #[serde(default)]
+    pub preferred_tolerance_pct: f64,
+    pub ltl_min_weight_lbs: Option<f64>,
+    #[serde(default = "default_currency")]
+    pub currency: String,
+    #[serde(default = "default_timeout_ms")]
+    pub carrier_timeout_ms: i32,
+}
+
+fn default_currency() -> String {
+    "USD".to_string()
+}
+
+fn default_timeout_ms() -> i32 {
+    5000
+}
+
+#[derive(Debug, Clone, Deserialize, ToSchema)]
+pub struct RateShopExclusionRequest {
+    pub carrier_code: String,
+    pub dest_country: Option<String>,
+    pub dest_state: Option<String>,
+    pub dest_zip_prefix: Option<String>,
+    pub reason: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct RateShopAddress {
+    pub zip: String,
+    pub city: Option<String>,
+    pub state: Option<String>,
+    #[serde(default = "default_country")]
+    pub country: String,
+}
+
+fn default_country() -> String {
+    "US".to_string()
+}
+
+#[derive(Debug, Clone, Deserialize, ToSchema)]
+pub struct RateShopRequest {
+    pub origin: RateShopAddress,
+    pub destination: RateShopAddress,
+    /// Shipment weight. Defaults to the total weight of the shipment's cartons.
+    pub weight_lbs: Option<f64>,
+    /// NMFC freight class for LTL quotes.
+    pub freight_class: Option<String>,
+    /// Delivery promise: quotes slower than this are ineligible.
+    pub max_transit_days: Option<i32>,
+    /// Only guaranteed services are eligible.
+    #[serde(default)]
+    pub require_guaranteed: bool,
+}
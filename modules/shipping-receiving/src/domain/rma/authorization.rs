@@ -0,0 +1,758 @@
+//! RMA authorization service — authorize before receipt, resolve financially.
+//!
+//! Invariants:
+//! - An RMA is authorized against a customer, optionally the original sales
+//!   order and AR invoice; credit and refund resolutions require the invoice
+//! - Receipts are checked against the authorized SKUs and quantities
+//! - Resolution waits until every linked receipt reaches a terminal
+//!   disposition, then atomically records the amounts, the inventory
+//!   adjustments and the credit / replacement / status events in the outbox
+//! - All queries filter by tenant_id
+
+use std::collections::{BTreeMap, HashMap};
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::PgPool;
+use thiserror::Error;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use super::resolution::{self, ResolutionAmounts, ReturnedLine};
+use super::service::{ReceiveRmaRequest, RmaError, RmaReceipt};
+use super::types::{AuthorizationStatus, DispositionStatus, ResolutionType};
+use crate::db::rma_authorization_repo::{NewAuthorization, RmaAuthorizationRepo};
+use crate::events::{
+    RmaCreditRequestedPayload, RmaInventoryAdjustedPayload, RmaInventoryAdjustmentLine,
+    RmaReplacementLine, RmaReplacementRequestedPayload, EVENT_TYPE_RMA_CREDIT_REQUESTED,
+    EVENT_TYPE_RMA_INVENTORY_ADJUSTED, EVENT_TYPE_RMA_REPLACEMENT_REQUESTED,
+};
+use crate::integrations::inventory_client::InventoryIntegration;
+use crate::outbox;
+
+// ── Domain model ──────────────────────────────────────────────
+
+/// Per-tenant restocking fee rules.
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow, ToSchema)]
+pub struct RestockingFeeRules {
+    pub tenant_id: Uuid,
+    /// Fee as basis points of the returned line value (1500 = 15%).
+    pub fee_bps: i32,
+    /// Floor on the total fee whenever a fee is charged.
+    pub min_fee_minor: i64,
+    /// Return reasons that never carry a fee.
+    pub waived_reason_codes: Vec<String>,
+    /// Charge the fee on scrapped lines as well as restocked lines.
+    pub apply_to_scrap: bool,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow, ToSchema)]
+pub struct RmaAuthorization {
+    pub id: Uuid,
+    pub tenant_id: Uuid,
+    pub rma_number: String,
+    pub customer_id: Uuid,
+    pub sales_order_id: Option<Uuid>,
+    pub ar_invoice_id: Option<i32>,
+    /// credit | refund | replacement | repair
+    pub resolution_type: String,
+    pub reason_code: String,
+    /// authorized | received | resolved | cancelled
+    pub status: String,
+    pub currency: String,
+    pub warehouse_id: Option<Uuid>,
+    pub notes: Option<String>,
+    pub gross_credit_minor: Option<i64>,
+    pub restocking_fee_minor: Option<i64>,
+    pub net_credit_minor: Option<i64>,
+    pub credit_note_id: Option<Uuid>,
+    pub replacement_request_id: Option<Uuid>,
+    pub authorized_by: Option<Uuid>,
+    pub resolved_by: Option<Uuid>,
+    pub resolved_at: Option<DateTime<Utc>>,
+    pub cancelled_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow, ToSchema)]
+pub struct RmaAuthorizationLine {
+    pub id: Uuid,
+    pub tenant_id: Uuid,
+    pub rma_authorization_id: Uuid,
+    pub sku: String,
+    pub qty_authorized: i64,
+    pub unit_price_minor: i64,
+    pub so_line_id: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+}
+
+/// Stock movement recorded for one received item at resolution.
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow, ToSchema)]
+pub struct RmaInventoryAdjustment {
+    pub id: Uuid,
+    pub tenant_id: Uuid,
+    pub rma_authorization_id: Uuid,
+    pub rma_receipt_item_id: Uuid,
+    pub sku: String,
+    pub qty: i64,
+    /// restock | scrap
+    pub adjustment_type: String,
+    pub inventory_ref_id: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+}
+
+/// Authorization header with lines, linked receipts and adjustments.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct RmaAuthorizationDetail {
+    #[serde(flatten)]
+    pub authorization: RmaAuthorization,
+    pub lines: Vec<RmaAuthorizationLine>,
+    pub receipts: Vec<RmaReceipt>,
+    pub adjustments: Vec<RmaInventoryAdjustment>,
+}
+
+/// Outcome of resolving an RMA.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct RmaResolution {
+    pub authorization: RmaAuthorization,
+    pub amounts: ResolutionAmounts,
+    pub adjustments: Vec<RmaInventoryAdjustment>,
+}
+
+// ── Request types ─────────────────────────────────────────────
+
+#[derive(Debug, Clone, Deserialize, ToSchema)]
+pub struct RestockingFeeRulesRequest {
+    pub fee_bps: i32,
+    #[serde(default)]
+    pub min_fee_minor: i64,
+    #[serde(default)]
+    pub waived_reason_codes: Vec<String>,
+    #[serde(default)]
+    pub apply_to_scrap: bool,
+}
+
+#[derive(Debug, Clone, Deserialize, ToSchema)]
+pub struct CreateRmaAuthorizationRequest {
+    pub rma_number: String,
+    pub customer_id: Uuid,
+    pub sales_order_id: Option<Uuid>,
+    /// Required for credit and refund resolutions.
+    pub ar_invoice_id: Option<i32>,
+    /// credit | refund | replacement | repair
+    pub resolution_type: String,
+    pub reason_code: String,
+    #[serde(default = "default_currency")]
+    pub currency: String,
+    pub warehouse_id: Option<Uuid>,
+    pub notes: Option<String>,
+    pub lines: Vec<RmaAuthorizationLineInput>,
+}
+
+#[derive(Debug, Clone, Deserialize, ToSchema)]
+pub struct RmaAuthorizationLineInput {
+    pub sku: String,
+    pub qty_authorized: i64,
+    #[serde(default)]
+    pub unit_price_minor: i64,
+    pub so_line_id: Option<Uuid>,
+}
+
+fn default_currency() -> String {
+    "usd".to_string()
+}
+
+// ── Errors ────────────────────────────────────────────────────
+
+#[derive(Debug, Error)]
+pub enum RmaAuthorizationError {
+    #[error("RMA authorization not found")]
+    NotFound,
+
+    #[error("RMA number '{0}' already exists")]
+    DuplicateNumber(String),
+
+    #[error("RMA authorization cannot be {action} in status {current}")]
+    InvalidStatus {
+        action: &'static str,
+        current: String,
+    },
+
+    #[error("RMA receipts pending: {0}")]
+    ReceiptsPending(String),
+
+    #[error("Validation error: {0}")]
+    Validation(String),
+
+    #[error("Inventory integration error: {0}")]
+    InventoryIntegration(String),
+
+    #[error("Database error: {0}")]
+    Database(#[from] sqlx::Error),
+}
+
+// ── Event type constants ─────────────────────────────────────
+
+pub mod subjects {
+    pub const RMA_AUTHORIZED: &str = "sr.rma.authorized";
+    pub const RMA_STATUS_CHANGED: &str = "sr.rma.status_changed";
+}
+
+// ── Service ──────────────────────────────────────────────────
+
+pub struct RmaAuthorizationService;
+
+impl RmaAuthorizationService {
+    // ── Restocking fee rules ─────────────────────────────────
+
+    pub async fn put_fee_rules(
+        pool: &PgPool,
+        tenant_id: Uuid,
+        req: &RestockingFeeRulesRequest,
+    ) -> Result<RestockingFeeRules, RmaAuthorizationError> {
+        if !(0..=10_000).contains(&req.fee_bps) {
+            return Err(RmaAuthorizationError::Validation(
+                "fee_bps must be between 0 and 10000".to_string(),
+            ));
+        }
+        if req.min_fee_minor < 0 {
+            return Err(RmaAuthorizationError::Validation(
+                "min_fee_minor must be >= 0".to_string(),
+            ));
+        }
+        let req = RestockingFeeRulesRequest {
+            waived_reason_codes: req
+                .waived_reason_codes
+                .iter()
+                .map(|c| c.trim().to_lowercase())
+                .filter(|c| !c.is_empty())
+                .collect(),
+            ..req.clone()
+        };
+        Ok(RmaAuthorizationRepo::upsert_fee_rules(pool, tenant_id, &req).await?)
+    }
+
+    pub async fn get_fee_rules(
+        pool: &PgPool,
+        tenant_id: Uuid,
+    ) -> Result<Option<RestockingFeeRules>, RmaAuthorizationError> {
+        Ok(RmaAuthorizationRepo::get_fee_rules(pool, tenant_id).await?)
+    }
+
+    // ── Authorization ────────────────────────────────────────
+
+    /// Authorize a return before the goods arrive.
+    pub async fn authorize(
+        pool: &PgPool,
+        tenant_id: Uuid,
+        authorized_by: Option<Uuid>,
+        req: &CreateRmaAuthorizationRequest,
+    ) -> Result<RmaAuthorizationDetail, RmaAuthorizationError> {
+        let rma_number = req.rma_number.trim();
+        if rma_number.is_empty() {
+            return Err(RmaAuthorizationError::Validation(
+                "rma_number is required".to_string(),
+            ));
+        }
+        let resolution = ResolutionType::from_str_value(&req.resolution_type)
+            .map_err(|e| RmaAuthorizationError::Validation(e.to_string()))?;
+        let reason_code = req.reason_code.trim().to_lowercase();
+        if reason_code.is_empty() {
+            return Err(RmaAuthorizationError::Validation(
+                "reason_code is required".to_string(),
+            ));
+        }
+        if resolution.issues_credit() && req.ar_invoice_id.is_none() {
+            return Err(RmaAuthorizationError::Validation(format!(
+                "ar_invoice_id is required for {resolution} resolutions"
+            )));
+        }
+        if req.lines.is_empty() {
+            return Err(RmaAuthorizationError::Validation(
+                "RMA authorization must have at least one line".to_string(),
+            ));
+        }
+        let mut seen = std::collections::HashSet::new();
+        for (i, line) in req.lines.iter().enumerate() {
+            if line.qty_authorized <= 0 {
+                return Err(RmaAuthorizationError::Validation(format!(
+                    "lines[{i}] qty_authorized must be > 0, got {}",
+                    line.qty_authorized
+                )));
+            }
+            if line.unit_price_minor < 0 {
+                return Err(RmaAuthorizationError::Validation(format!(
+                    "lines[{i}] unit_price_minor must be >= 0"
+                )));
+            }
+            if !seen.insert(line.sku.trim()) {
+                return Err(RmaAuthorizationError::Validation(format!(
+                    "lines[{i}] duplicates SKU '{}'",
+                    line.sku.trim()
+                )));
+            }
+        }
+
+        let mut tx = pool.begin().await?;
+
+        if RmaAuthorizationRepo::number_exists_tx(&mut tx, tenant_id, rma_number).await? {
+            return Err(RmaAuthorizationError::DuplicateNumber(
+                rma_number.to_string(),
+            ));
+        }
+
+        let authorization = RmaAuthorizationRepo::insert_authorization_tx(
+            &mut tx,
+            tenant_id,
+            &NewAuthorization {
+                rma_number,
+                customer_id: req.customer_id,
+                sales_order_id: req.sales_order_id,
+                ar_invoice_id: req.ar_invoice_id,
+                resolution_type: resolution.as_str(),
+                reason_code: &reason_code,
+                currency: &req.currency.trim().to_lowercase(),
+                warehouse_id: req.warehouse_id,
+                notes: req.notes.as_deref(),
+                authorized_by,
+            },
+        )
+        .await?;
+
+        let mut lines = Vec::with_capacity(req.lines.len());
+        for line in &req.lines {
+            lines.push(
+                RmaAuthorizationRepo::insert_line_tx(
+                    &mut tx,
+                    tenant_id,
+                    authorization.id,
+                    line.sku.trim(),
+                    line.qty_authorized,
+                    line.unit_price_minor,
+                    line.so_line_id,
+                )
+                .await?,
+            );
+        }
+
+        let event_payload = serde_json::json!({
+            "rma_authorization_id": authorization.id,
+            "tenant_id": tenant_id,
+            "rma_number": &authorization.rma_number,
+            "customer_id": authorization.customer_id,
+            "sales_order_id": authorization.sales_order_id,
+            "ar_invoice_id": authorization.ar_invoice_id,
+            "resolution_type": &authorization.resolution_type,
+            "reason_code": &authorization.reason_code,
+            "line_count": lines.len(),
+        });
+        outbox::enqueue_event_tx(
+            &mut tx,
+            Uuid::new_v4(),
+            subjects::RMA_AUTHORIZED,
+            "rma_authorization",
+            &authorization.id.to_string(),
+            &tenant_id.to_string(),
+            &event_payload,
+        )
+        .await?;
+
+        tx.commit().await?;
+        Ok(RmaAuthorizationDetail {
+            authorization,
+            lines,
+            receipts: Vec::new(),
+            adjustments: Vec::new(),
+        })
+    }
+
+    pub async fn get(
+        pool: &PgPool,
+        id: Uuid,
+        tenant_id: Uuid,
+    ) -> Result<Option<RmaAuthorizationDetail>, RmaAuthorizationError> {
+        let Some(authorization) =
+            RmaAuthorizationRepo::get_authorization(pool, id, tenant_id).await?
+        else {
+            return Ok(None);
+        };
+        let lines = RmaAuthorizationRepo::get_lines(pool, id, tenant_id).await?;
+        let receipts = RmaAuthorizationRepo::list_receipts(pool, id, tenant_id).await?;
+        let adjustments = RmaAuthorizationRepo::list_adjustments(pool, id, tenant_id).await?;
+        Ok(Some(RmaAuthorizationDetail {
+            authorization,
+            lines,
+            receipts,
+            adjustments,
+        }))
+    }
+
+    /// Cancel an authorization before any goods were received.
+    pub async fn cancel(
+        pool: &PgPool,
+        id: Uuid,
+        tenant_id: Uuid,
+    ) -> Result<RmaAuthorization, RmaAuthorizationError> {
+        let mut tx = pool.begin().await?;
+        let current = RmaAuthorizationRepo::get_for_update_tx(&mut tx, id, tenant_id)
+            .await?
+            .ok_or(RmaAuthorizationError::NotFound)?;
+        if current.status != AuthorizationStatus::Authorized.as_str() {
+            return Err(RmaAuthorizationError::InvalidStatus {
+                action: "cancelled",
+                current: current.status,
+            });
+        }
+
+        let updated = RmaAuthorizationRepo::cancel_tx(&mut tx, id, tenant_id).await?;
+        enqueue_status_changed(
+            &mut tx,
+            &updated,
+            AuthorizationStatus::Authorized,
+            AuthorizationStatus::Cancelled,
+        )
+        .await?;
+
+        tx.commit().await?;
+        Ok(updated)
+    }
+
+    /// Resolve a received RMA once every receipt has a final disposition.
+    ///
+    /// Restocked items are received into the authorization's warehouse through
+    /// the Inventory integration (skipped when no warehouse is set); scrapped
+    /// items are recorded as scrap adjustments. Credit and refund resolutions
+    /// request an AR credit note for the net credit; replacement resolutions
+    /// request a replacement sales order for the received quantities.
+    pub async fn resolve(
+        pool: &PgPool,
+        inventory: &InventoryIntegration,
+        id: Uuid,
+        tenant_id: Uuid,
+        resolved_by: Option<Uuid>,
+    ) -> Result<RmaResolution, RmaAuthorizationError> {
+        let rules = RmaAuthorizationRepo::get_fee_rules(pool, tenant_id).await?;
+
+        let mut tx = pool.begin().await?;
+
+        // ── Guard: header must be received ──
+        let current = RmaAuthorizationRepo::get_for_update_tx(&mut tx, id, tenant_id)
+            .await?
+            .ok_or(RmaAuthorizationError::NotFound)?;
+        if current.status != AuthorizationStatus::Received.as_str() {
+            return Err(RmaAuthorizationError::InvalidStatus {
+                action: "resolved",
+                current: current.status,
+            });
+        }
+        let resolution = ResolutionType::from_str_value(&current.resolution_type)
+            .map_err(|e| RmaAuthorizationError::Validation(e.to_string()))?;
+
+        // ── Guard: every receipt dispositioned ──
+        let items = RmaAuthorizationRepo::received_items_tx(&mut tx, id, tenant_id).await?;
+        let mut returned = Vec::with_capacity(items.len());
+        let lines = RmaAuthorizationRepo::get_lines_tx(&mut tx, id, tenant_id).await?;
+        let line_by_sku: HashMap<&str, &RmaAuthorizationLine> =
+            lines.iter().map(|l| (l.sku.as_str(), l)).collect();
+        for item in &items {
+            let disposition = DispositionStatus::from_str_value(&item.disposition_status)
+                .map_err(|e| RmaAuthorizationError::Validation(e.to_string()))?;
+            if !disposition.is_terminal() {
+                return Err(RmaAuthorizationError::ReceiptsPending(format!(
+                    "receipt {} is in disposition '{disposition}'",
+                    item.rma_receipt_id
+                )));
+            }
+            returned.push(ReturnedLine {
+                sku: item.sku.clone(),
+                qty: item.qty,
+                unit_price_minor: line_by_sku
+                    .get(item.sku.as_str())
+                    .map(|l| l.unit_price_minor)
+                    .unwrap_or(0),
+                disposition,
+            });
+        }
+        if returned.is_empty() {
+            return Err(RmaAuthorizationError::ReceiptsPending(
+                "no goods received".to_string(),
+            ));
+        }
+
+        let amounts = resolution::compute_resolution(
+            resolution,
+            &current.reason_code,
+            rules.as_ref(),
+            &returned,
+        );
+
+        // ── Inventory adjustments matching the disposition ──
+        let mut adjustments = Vec::with_capacity(items.len());
+        for (item, line) in items.iter().zip(&returned) {
+            let (adjustment_type, inventory_ref_id) = match line.disposition {
+                DispositionStatus::ReturnToStock => {
+                    let ref_id = match current.warehouse_id {
+                        Some(warehouse_id) => Some(
+                            inventory
+                                .create_receipt(
+                                    tenant_id,
+                                    item.rma_receipt_id,
+                                    item.item_id,
+                                    warehouse_id,
+                                    item.qty,
+                                    &current.currency,
+                                )
+                                .await
+                                .map_err(|e| {
+                                    RmaAuthorizationError::InventoryIntegration(e.to_string())
+                                })?,
+                        ),
+                        None => None,
+                    };
+                    ("restock", ref_id)
+                }
+                _ => ("scrap", None),
+            };
+            adjustments.push(
+                RmaAuthorizationRepo::insert_adjustment_tx(
+                    &mut tx,
+                    tenant_id,
+                    id,
+                    item,
+                    adjustment_type,
+                    inventory_ref_id,
+                )
+                .await?,
+            );
+        }
+
+        let now = Utc::now();
+        let tenant_str = tenant_id.to_string();
+
+        outbox::enqueue_event_tx(
+            &mut tx,
+            Uuid::new_v4(),
+            EVENT_TYPE_RMA_INVENTORY_ADJUSTED,
+            "rma_authorization",
+            &id.to_string(),
+            &tenant_str,
+            &RmaInventoryAdjustedPayload {
+                tenant_id: tenant_str.clone(),
+                rma_authorization_id: id,
+                rma_number: current.rma_number.clone(),
+                warehouse_id: current.warehouse_id,
+                lines: adjustments
+                    .iter()
+                    .map(|a| RmaInventoryAdjustmentLine {
+                        rma_receipt_item_id: a.rma_receipt_item_id,
+                        sku: a.sku.clone(),
+                        qty: a.qty,
+                        adjustment_type: a.adjustment_type.clone(),
+                        inventory_ref_id: a.inventory_ref_id,
+                    })
+                    .collect(),
+                adjusted_at: now,
+            },
+        )
+        .await?;
+
+        // ── Financial documents ──
+        let mut credit_note_id = None;
+        let mut replacement_request_id = None;
+        match resolution {
+            ResolutionType::Credit | ResolutionType::Refund if amounts.net_credit_minor > 0 => {
+                let ar_invoice_id = current.ar_invoice_id.ok_or_else(|| {
+                    RmaAuthorizationError::Validation(
+                        "ar_invoice_id is required for credit resolutions".to_string(),
+                    )
+                })?;
+                let note_id = Uuid::new_v5(
+                    &Uuid::NAMESPACE_OID,
+                    format!("rma-credit:{tenant_id}:{id}").as_bytes(),
+                );
+                outbox::enqueue_event_tx(
+                    &mut tx,
+                    Uuid::new_v4(),
+                    EVENT_TYPE_RMA_CREDIT_REQUESTED,
+                    "rma_authorization",
+                    &id.to_string(),
+                    &tenant_str,
+                    &RmaCreditRequestedPayload {
+                        tenant_id: tenant_str.clone(),
+                        rma_authorization_id: id,
+                        rma_number: current.rma_number.clone(),
+                        customer_id: current.customer_id,
+                        ar_invoice_id,
+                        sales_order_id: current.sales_order_id,
+                        credit_note_id: note_id,
+                        gross_credit_minor: amounts.gross_credit_minor,
+                        restocking_fee_minor: amounts.restocking_fee_minor,
+                        amount_minor: amounts.net_credit_minor,
+                        currency: current.currency.clone(),
+                        reason_code: current.reason_code.clone(),
+                        refund: resolution == ResolutionType::Refund,
+                        resolved_at: now,
+                    },
+                )
+                .await?;
+                credit_note_id = Some(note_id);
+            }
+            ResolutionType::Replacement => {
+                let mut qty_by_sku: BTreeMap<&str, i64> = BTreeMap::new();
+                for line in &returned {
+                    *qty_by_sku.entry(line.sku.as_str()).or_insert(0) += line.qty;
+                }
+                let request_id = Uuid::new_v4();
+                outbox::enqueue_event_tx(
+                    &mut tx,
+                    request_id,
+                    EVENT_TYPE_RMA_REPLACEMENT_REQUESTED,
+                    "rma_authorization",
+                    &id.to_string(),
+                    &tenant_str,
+                    &RmaReplacementRequestedPayload {
+                        tenant_id: tenant_str.clone(),
+                        rma_authorization_id: id,
+                        rma_number: current.rma_number.clone(),
+                        replacement_request_id: request_id,
+                        customer_id: current.customer_id,
+                        original_sales_order_id: current.sales_order_id,
+                        currency: current.currency.clone(),
+                        lines: qty_by_sku
+                            .into_iter()
+                            .map(|(sku, qty)| RmaReplacementLine {
+                                sku: sku.to_string(),
+                                qty,
+                                so_line_id: line_by_sku.get(sku).and_then(|l| l.so_line_id),
+                            })
+                            .collect(),
+                        resolved_at: now,
+                    },
+                )
+                .await?;
+                replacement_request_id = Some(request_id);
+            }
+            _ => {}
+        }
+
+        // ── Mutation: resolve header ──
+        let authorization = RmaAuthorizationRepo::resolve_tx(
+            &mut tx,
+            id,
+            tenant_id,
+            &amounts,
+            credit_note_id,
+            replacement_request_id,
+            resolved_by,
+        )
+        .await?;
+        enqueue_status_changed(
+            &mut tx,
+            &authorization,
+            AuthorizationStatus::Received,
+            AuthorizationStatus::Resolved,
+        )
+        .await?;
+
+        tx.commit().await?;
+        Ok(RmaResolution {
+            authorization,
+            amounts,
+            adjustments,
+        })
+    }
+}
+
+/// Check a receipt against its authorization and link it, moving the header
+/// to `received` on the first receipt. Runs inside the receipt transaction.
+pub(crate) async fn attach_receipt_tx(
+    tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+    tenant_id: Uuid,
+    rma_authorization_id: Uuid,
+    req: &ReceiveRmaRequest,
+) -> Result<(), RmaError> {
+    let current = RmaAuthorizationRepo::get_for_update_tx(tx, rma_authorization_id, tenant_id)
+        .await?
+        .ok_or(RmaError::AuthorizationNotFound)?;
+    let status = AuthorizationStatus::from_str_value(&current.status)
+        .map_err(|e| RmaError::Validation(e.to_string()))?;
+    if !status.accepts_receipts() {
+        return Err(RmaError::Validation(format!(
+            "RMA authorization {} is {status} and cannot receive goods",
+            current.rma_number
+        )));
+    }
+    if current.customer_id != req.customer_id {
+        return Err(RmaError::Validation(
+            "customer_id does not match the RMA authorization".to_string(),
+        ));
+    }
+    if current.rma_number != req.rma_id {
+        return Err(RmaError::Validation(format!(
+            "rma_id '{}' does not match authorization {}",
+            req.rma_id, current.rma_number
+        )));
+    }
+
+    let authorized: HashMap<String, i64> =
+        RmaAuthorizationRepo::get_lines_tx(tx, rma_authorization_id, tenant_id)
+            .await?
+            .into_iter()
+            .map(|l| (l.sku, l.qty_authorized))
+            .collect();
+    let already: HashMap<String, i64> =
+        RmaAuthorizationRepo::received_qty_by_sku_tx(tx, rma_authorization_id, tenant_id)
+            .await?
+            .into_iter()
+            .collect();
+    let incoming: Vec<(String, i64)> = req.items.iter().map(|i| (i.sku.clone(), i.qty)).collect();
+    resolution::check_receipt_quantities(&authorized, &already, &incoming)
+        .map_err(RmaError::Validation)?;
+
+    if status == AuthorizationStatus::Authorized {
+        let updated = RmaAuthorizationRepo::update_status_tx(
+            tx,
+            rma_authorization_id,
+            tenant_id,
+            AuthorizationStatus::Received.as_str(),
+        )
+        .await?;
+        enqueue_status_changed(
+            tx,
+            &updated,
+            AuthorizationStatus::Authorized,
+            AuthorizationStatus::Received,
+        )
+        .await?;
+    }
+    Ok(())
+}
+
+async fn enqueue_status_changed(
+    tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+    authorization: &RmaAuthorization,
+    from: AuthorizationStatus,
+    to: AuthorizationStatus,
+) -> Result<(), sqlx::Error> {
+    let event_payload = serde_json::json!({
+        "rma_authorization_id": authorization.id,
+        "tenant_id": authorization.tenant_id,
+        "rma_number": &authorization.rma_number,
+        "from_status": from.as_str(),
+        "to_status": to.as_str(),
+        "net_credit_minor": authorization.net_credit_minor,
+    });
+    outbox::enqueue_event_tx(
+        tx,
+        Uuid::new_v4(),
+        subjects::RMA_STATUS_CHANGED,
+        "rma_authorization",
+        &authorization.id.to_string(),
+        &authorization.tenant_id.to_string(),
+        &event_payload,
+    )
+    .await
+}
@@ -1,9 +1,17 @@
+pub mod authorization;
+pub mod resolution;
 pub mod service;
 pub mod state_machine;
 pub mod types;
 
+pub use authorization::{
+    CreateRmaAuthorizationRequest, RestockingFeeRules, RestockingFeeRulesRequest,
+    RmaAuthorization, RmaAuthorizationDetail, RmaAuthorizationError, RmaAuthorizationLine,
+    RmaAuthorizationLineInput, RmaAuthorizationService, RmaInventoryAdjustment, RmaResolution,
+};
+pub use resolution::{ResolutionAmounts, ResolutionLine};
 pub use service::{
     DispositionRequest, ReceiveRmaRequest, RmaError, RmaItemInput, RmaReceipt, RmaReceiptItem,
     RmaService,
 };
-pub use types::DispositionStatus;
+pub use types::{AuthorizationStatus, DispositionStatus, ResolutionType};
@@ -0,0 +1,290 @@
+//! Pure RMA resolution math — receipt quantity checks and credit / restocking
+//! fee calculation. No I/O; the authorization service feeds it rows.
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use super::authorization::RestockingFeeRules;
+use super::types::{DispositionStatus, ResolutionType};
+
+/// A returned quantity of one SKU with its final disposition.
+#[derive(Debug, Clone)]
+pub struct ReturnedLine {
+    pub sku: String,
+    pub qty: i64,
+    pub unit_price_minor: i64,
+    pub disposition: DispositionStatus,
+}
+
+/// Credit and fee for one returned line.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub struct ResolutionLine {
+    pub sku: String,
+    pub qty: i64,
+    pub unit_price_minor: i64,
+    pub disposition: String,
+    pub value_minor: i64,
+    pub fee_minor: i64,
+}
+
+/// Totals for a resolution. `net = gross - fee`, never negative.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub struct ResolutionAmounts {
+    pub gross_credit_minor: i64,
+    pub restocking_fee_minor: i64,
+    pub net_credit_minor: i64,
+    pub lines: Vec<ResolutionLine>,
+}
+
+/// Check an incoming receipt against the authorized quantities.
+///
+/// `already_received` holds the quantities recorded by earlier receipts on the
+/// same authorization. Every SKU must be authorized and the cumulative
+/// quantity may not exceed `qty_authorized`.
+pub fn check_receipt_quantities(
+    authorized: &HashMap<String, i64>,
+    already_received: &HashMap<String, i64>,
+    incoming: &[(String, i64)],
+) -> Result<(), String> {
+    let mut totals = already_received.clone();
+    for (sku, qty) in incoming {
+        let Some(&limit) = authorized.get(sku) else {
+            return Err(format!("SKU '{sku}' is not on the RMA authorization"));
+        };
+        let total = totals.entry(sku.clone()).or_insert(0);
+        *total += qty;
+        if *total > limit {
+            return Err(format!(
+                "SKU '{sku}' received quantity {total} exceeds authorized {limit}"
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// Whether the restocking fee applies to a line with this disposition.
+fn fee_applies(
+    rules: &RestockingFeeRules,
+    reason_code: &str,
+    disposition: DispositionStatus,
+) -> bool {
+    if rules
+        .waived_reason_codes
+        .iter()
+        .any(|c| c.eq_ignore_ascii_case(reason_code))
+    {
+        return false;
+    }
+    match disposition {
+        DispositionStatus::ReturnToStock => true,
+        DispositionStatus::Scrap => rules.apply_to_scrap,
+        _ => false,
+    }
+}
+
+/// Compute credit and restocking fee for a resolution.
+///
+/// Only credit and refund resolutions carry money: each line is credited at
+/// `qty * unit_price_minor`, less `fee_bps` of that value (rounded half up)
+/// on lines the fee applies to. When any line is charged, the total fee is
+/// raised to `min_fee_minor` (topped up on the first charged line), and no
+/// line's fee exceeds its value. Replacement and repair resolutions report
+/// line values but no credit or fee.
+pub fn compute_resolution(
+    resolution: ResolutionType,
+    reason_code: &str,
+    rules: Option<&RestockingFeeRules>,
+    returned: &[ReturnedLine],
+) -> ResolutionAmounts {
+    let credits = resolution.issues_credit();
+    let mut first_charged: Option<usize> = None;
+
+    let mut lines: Vec<ResolutionLine> = returned
+        .iter()
+        .enumerate()
+        .map(|(i, r)| {
+            let value_minor = r.qty * r.unit_price_minor;
+            let fee_minor = match rules {
+                Some(rules) if credits && fee_applies(rules, reason_code, r.disposition) => {
+                    first_charged.get_or_insert(i);
+                    (value_minor * i64::from(rules.fee_bps) + 5_000) / 10_000
+                }
+                _ => 0,
+            };
+            ResolutionLine {
+                sku: r.sku.clone(),
+                qty: r.qty,
+                unit_price_minor: r.unit_price_minor,
+                disposition: r.disposition.as_str().to_string(),
+                value_minor,
+                fee_minor,
+            }
+        })
+        .collect();
+
+    if !credits {
+        return ResolutionAmounts {
+            gross_credit_minor: 0,
+            restocking_fee_minor: 0,
+            net_credit_minor: 0,
+            lines,
+        };
+    }
+
+    if let Some(i) = first_charged {
+        let min_fee = rules.map(|r| r.min_fee_minor).unwrap_or(0);
+        let fee: i64 = lines.iter().map(|l| l.fee_minor).sum();
+        if fee < min_fee {
+            lines[i].fee_minor += min_fee - fee;
+        }
+    }
+    for line in &mut lines {
+        line.fee_minor = line.fee_minor.min(line.value_minor);
+    }
+
+    let gross: i64 = lines.iter().map(|l| l.value_minor).sum();
+    let fee: i64 = lines.iter().map(|l| l.fee_minor).sum();
+
+    ResolutionAmounts {
+        gross_credit_minor: gross,
+        restocking_fee_minor: fee,
+        net_credit_minor: gross - fee,
+        lines,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+    use uuid::Uuid;
+
+    fn rules(fee_bps: i32, min_fee_minor: i64, apply_to_scrap: bool) -> RestockingFeeRules {
+        RestockingFeeRules {
+            tenant_id: Uuid::new_v4(),
+            fee_bps,
+            min_fee_minor,
+            waived_reason_codes: vec!["defective".to_string()],
+            apply_to_scrap,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+        }
+    }
+
+    fn line(sku: &str, qty: i64, price: i64, disposition: DispositionStatus) -> ReturnedLine {
+        ReturnedLine {
+            sku: sku.to_string(),
+            qty,
+            unit_price_minor: price,
+            disposition,
+        }
+    }
+
+    #[test]
+    fn credit_without_rules_is_full_value() {
+        let out = compute_resolution(
+            ResolutionType::Credit,
+            "unwanted",
+            None,
+            &[line("A", 2, 1000, DispositionStatus::ReturnToStock)],
+        );
+        assert_eq!(out.gross_credit_minor, 2000);
+        assert_eq!(out.restocking_fee_minor, 0);
+        assert_eq!(out.net_credit_minor, 2000);
+    }
+
+    #[test]
+    fn fee_charged_on_restocked_lines_only() {
+        let r = rules(1500, 0, false);
+        let out = compute_resolution(
+            ResolutionType::Credit,
+            "unwanted",
+            Some(&r),
+            &[
+                line("A", 2, 1000, DispositionStatus::ReturnToStock),
+                line("B", 1, 3000, DispositionStatus::Scrap),
+            ],
+        );
+        assert_eq!(out.gross_credit_minor, 5000);
+        assert_eq!(out.restocking_fee_minor, 300);
+        assert_eq!(out.net_credit_minor, 4700);
+        assert_eq!(out.lines[1].fee_minor, 0);
+    }
+
+    #[test]
+    fn scrap_charged_when_configured() {
+        let r = rules(1000, 0, true);
+        let out = compute_resolution(
+            ResolutionType::Refund,
+            "unwanted",
+            Some(&r),
+            &[line("B", 1, 3000, DispositionStatus::Scrap)],
+        );
+        assert_eq!(out.restocking_fee_minor, 300);
+    }
+
+    #[test]
+    fn waived_reason_has_no_fee() {
+        let r = rules(1500, 500, true);
+        let out = compute_resolution(
+            ResolutionType::Credit,
+            "DEFECTIVE",
+            Some(&r),
+            &[line("A", 2, 1000, DispositionStatus::ReturnToStock)],
+        );
+        assert_eq!(out.restocking_fee_minor, 0);
+        assert_eq!(out.net_credit_minor, 2000);
+    }
+
+    #[test]
+    fn minimum_fee_applies_and_is_capped_at_gross() {
+        let r = rules(100, 500, false);
+        let out = compute_resolution(
+            ResolutionType::Credit,
+            "unwanted",
+            Some(&r),
+            &[line("A", 1, 2000, DispositionStatus::ReturnToStock)],
+        );
+        assert_eq!(out.restocking_fee_minor, 500);
+        assert_eq!(out.lines[0].fee_minor, 500);
+        assert_eq!(out.net_credit_minor, 1500);
+
+        let out = compute_resolution(
+            ResolutionType::Credit,
+            "unwanted",
+            Some(&r),
+            &[line("A", 1, 300, DispositionStatus::ReturnToStock)],
+        );
+        assert_eq!(out.restocking_fee_minor, 300);
+        assert_eq!(out.net_credit_minor, 0);
+    }
+
+    #[test]
+    fn replacement_carries_no_money() {
+        let r = rules(1500, 500, true);
+        let out = compute_resolution(
+            ResolutionType::Replacement,
+            "unwanted",
+            Some(&r),
+            &[line("A", 2, 1000, DispositionStatus::ReturnToStock)],
+        );
+        assert_eq!(out.gross_credit_minor, 0);
+        assert_eq!(out.restocking_fee_minor, 0);
+        assert_eq!(out.lines[0].value_minor, 2000);
+    }
+
+    #[test]
+    fn receipt_quantities_checked_cumulatively() {
+        let authorized = HashMap::from([("A".to_string(), 3)]);
+        let already = HashMap::from([("A".to_string(), 2)]);
+        assert!(check_receipt_quantities(&authorized, &already, &[("A".to_string(), 1)]).is_ok());
+        let err =
+            check_receipt_quantities(&authorized, &already, &[("A".to_string(), 2)]).unwrap_err();
+        assert!(err.contains("authorized 3"), "{err}");
+        let err = check_receipt_quantities(&authorized, &HashMap::new(), &[("Z".to_string(), 1)])
+            .unwrap_err();
+        assert!(err.contains("not on the RMA"), "{err}");
+    }
+}
@@ -10,8 +10,10 @@ use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use sqlx::PgPool;
 use thiserror::Error;
+use utoipa::ToSchema;
 use uuid::Uuid;
 
+use super::authorization;
 use super::state_machine::{validate_disposition, DispositionTransitionError};
 use super::types::DispositionStatus;
 use crate::db::rma_repo::RmaRepo;
@@ -19,7 +21,7 @@ use crate::outbox;
 
 // ── Domain model ──────────────────────────────────────────────
 
-#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow, ToSchema)]
 pub struct RmaReceipt {
     pub id: Uuid,
     pub tenant_id: Uuid,
@@ -30,9 +32,10 @@ pub struct RmaReceipt {
     pub idempotency_key: Option<String>,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
+    pub rma_authorization_id: Option<Uuid>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow, ToSchema)]
 pub struct RmaReceiptItem {
     pub id: Uuid,
     pub tenant_id: Uuid,
@@ -45,23 +48,26 @@ pub struct RmaReceiptItem {
 
 // ── Request types ─────────────────────────────────────────────
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, ToSchema)]
 pub struct ReceiveRmaRequest {
     pub rma_id: String,
     pub customer_id: Uuid,
     pub condition_notes: Option<String>,
     pub items: Vec<RmaItemInput>,
     pub idempotency_key: Option<String>,
+    /// Authorization the goods are returned against; checked and linked on receipt.
+    #[serde(default)]
+    pub rma_authorization_id: Option<Uuid>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, ToSchema)]
 pub struct RmaItemInput {
     pub sku: String,
     pub qty: i64,
     pub condition_notes: Option<String>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, ToSchema)]
 pub struct DispositionRequest {
     pub disposition_status: String,
 }
@@ -73,6 +79,9 @@ pub enum RmaError {
     #[error("RMA receipt not found")]
     NotFound,
 
+    #[error("RMA authorization not found")]
+    AuthorizationNotFound,
+
     #[error("Validation error: {0}")]
     Validation(String),
 
@@ -132,6 +141,11 @@ impl RmaService {
             }
         }
 
+        // ── Guard: authorized SKUs and quantities ──
+        if let Some(auth_id) = req.rma_authorization_id {
+            authorization::attach_receipt_tx(&mut tx, tenant_id, auth_id, req).await?;
+        }
+
         // ── Mutation: insert receipt ──
         let receipt = RmaRepo::insert_receipt_tx(
             &mut tx,
@@ -140,6 +154,7 @@ impl RmaService {
             req.customer_id,
             req.condition_notes.as_deref(),
             req.idempotency_key.as_deref(),
+            req.rma_authorization_id,
         )
         .await?;
 
@@ -161,6 +176,7 @@ impl RmaService {
             "rma_receipt_id": receipt.id,
             "tenant_id": tenant_id,
             "rma_id": &req.rma_id,
+            "rma_authorization_id": req.rma_authorization_id,
             "customer_id": req.customer_id,
             "disposition_status": "received",
             "item_count": req.items.len(),
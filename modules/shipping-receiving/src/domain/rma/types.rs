@@ -69,3 +69,98 @@ impl TryFrom<String> for DispositionStatus {
         Self::from_str_value(&s).map_err(|e| e.to_string())
     }
 }
+
+/// How an RMA is settled once its goods are back and dispositioned.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ResolutionType {
+    Credit,
+    Refund,
+    Replacement,
+    Repair,
+}
+
+impl ResolutionType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Credit => "credit",
+            Self::Refund => "refund",
+            Self::Replacement => "replacement",
+            Self::Repair => "repair",
+        }
+    }
+
+    pub fn from_str_value(s: &str) -> Result<Self, ParseEnumError> {
+        match s {
+            "credit" => Ok(Self::Credit),
+            "refund" => Ok(Self::Refund),
+            "replacement" => Ok(Self::Replacement),
+            "repair" => Ok(Self::Repair),
+            _ => Err(ParseEnumError {
+                type_name: "ResolutionType",
+                value: s.to_string(),
+            }),
+        }
+    }
+
+    /// Credit and refund resolutions produce an AR credit note.
+    pub fn issues_credit(&self) -> bool {
+        matches!(self, Self::Credit | Self::Refund)
+    }
+}
+
+impl fmt::Display for ResolutionType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// RMA authorization header status.
+///
+/// ```text
+/// authorized → received → resolved
+/// authorized → cancelled
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AuthorizationStatus {
+    Authorized,
+    Received,
+    Resolved,
+    Cancelled,
+}
+
+impl AuthorizationStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Authorized => "authorized",
+            Self::Received => "received",
+            Self::Resolved => "resolved",
+            Self::Cancelled => "cancelled",
+        }
+    }
+
+    pub fn from_str_value(s: &str) -> Result<Self, ParseEnumError> {
+        match s {
+            "authorized" => Ok(Self::Authorized),
+            "received" => Ok(Self::Received),
+            "resolved" => Ok(Self::Resolved),
+            "cancelled" => Ok(Self::Cancelled),
+            _ => Err(ParseEnumError {
+                type_name: "AuthorizationStatus",
+                value: s.to_string(),
+            }),
+        }
+    }
+
+    /// Receipts can still be recorded against the authorization.
+    pub fn accepts_receipts(&self) -> bool {
+        matches!(self, Self::Authorized | Self::Received)
+    }
+}
+
+impl fmt::Display for AuthorizationStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
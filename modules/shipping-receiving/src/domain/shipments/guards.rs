@@ -56,6 +56,9 @@ pub enum GuardError {
 
     #[error("outbound ship: shipment has already been shipped (shipped_at is set)")]
     AlreadyShipped,
+
+    #[error("outbound packed: {open} carton(s) still open")]
+    CartonsOpen { open: i64 },
 }
 
 /// Context for inbound transition guards.
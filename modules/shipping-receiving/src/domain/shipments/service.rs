@@ -21,6 +21,7 @@ use super::guards::{
 };
 use super::state_machine::{validate_inbound, validate_outbound, TransitionError};
 use super::types::{Direction, InboundStatus, OutboundStatus};
+use crate::db::cartonization_repo::CartonizationRepo;
 use crate::db::repository::ShipmentRepository;
 use crate::events::{
     InboundClosedLine, InboundClosedPayload, OutboundDeliveredPayload, OutboundShippedLine,
@@ -166,6 +167,16 @@ impl ShipmentService {
                 };
                 run_outbound_guards(to, &ctx)?;
 
+                // Packing: every carton must be closed before the shipment is packed
+                if to == OutboundStatus::Packed {
+                    let open =
+                        CartonizationRepo::count_open_cartons_tx(&mut tx, shipment_id, tenant_id)
+                            .await?;
+                    if open > 0 {
+                        return Err(GuardError::CartonsOpen { open }.into());
+                    }
+                }
+
                 match to {
                     OutboundStatus::Shipped => EVENT_TYPE_OUTBOUND_SHIPPED,
                     OutboundStatus::Delivered => EVENT_TYPE_OUTBOUND_DELIVERED,
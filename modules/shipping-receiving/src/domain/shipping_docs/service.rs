@@ -15,6 +15,7 @@ use uuid::Uuid;
 use super::state_machine::{validate_doc_status, DocStatusTransitionError};
 use super::types::{DocRequestStatus, DocType};
 use crate::db::shipping_doc_repo::ShippingDocRepo;
+use crate::domain::cartonization::CartonizationService;
 use crate::outbox;
 
 // ── Domain model ──────────────────────────────────────────────
@@ -86,9 +87,16 @@ impl ShippingDocService {
         req: &CreateDocRequest,
     ) -> Result<ShippingDocRequest, ShippingDocError> {
         // ── Guard: validate doc_type ──
-        DocType::from_str_value(&req.doc_type)
+        let doc_type = DocType::from_str_value(&req.doc_type)
             .map_err(|e| ShippingDocError::Validation(e.to_string()))?;
 
+        // Packing slips carry the per-carton content lists once cartonized
+        let cartons = if doc_type == DocType::PackingSlip {
+            CartonizationService::cartons(pool, req.shipment_id, tenant_id).await?
+        } else {
+            Vec::new()
+        };
+
         let mut tx = pool.begin().await?;
 
         // ── Idempotency check ──
@@ -113,13 +121,16 @@ impl ShippingDocService {
         .await?;
 
         // ── Outbox ──
-        let event_payload = serde_json::json!({
+        let mut event_payload = serde_json::json!({
             "doc_request_id": doc_req.id,
             "tenant_id": tenant_id,
             "shipment_id": req.shipment_id,
             "doc_type": &req.doc_type,
             "status": "requested",
         });
+        if !cartons.is_empty() {
+            event_payload["cartons"] = serde_json::json!(cartons);
+        }
 
         let event_id = Uuid::new_v4();
         outbox::enqueue_event_tx(
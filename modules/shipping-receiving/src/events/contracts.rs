@@ -330,6 +330,9 @@ pub fn build_receipt_routed_envelope(
 /// Shipping cost event contract (schema_version 1)
 pub mod shipping_cost;
 
+/// RMA resolution event contracts (schema_version 1)
+pub mod rma;
+
 #[cfg(test)]
 #[path = "contracts_tests.rs"]
 mod tests;
@@ -0,0 +1,175 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::events::{
+    create_shipping_receiving_envelope, MUTATION_CLASS_DATA_MUTATION,
+    SHIPPING_RECEIVING_EVENT_SCHEMA_VERSION,
+};
+use event_bus::EventEnvelope;
+
+// ============================================================================
+// Event Type Constants
+// ============================================================================
+
+/// A credit or refund RMA was resolved; AR issues the credit note.
+pub const EVENT_TYPE_RMA_CREDIT_REQUESTED: &str = "shipping_receiving.rma.credit_requested";
+
+/// A replacement RMA was resolved; Sales Orders creates the replacement order.
+pub const EVENT_TYPE_RMA_REPLACEMENT_REQUESTED: &str =
+    "shipping_receiving.rma.replacement_requested";
+
+/// Returned goods were restocked or scrapped according to their disposition.
+pub const EVENT_TYPE_RMA_INVENTORY_ADJUSTED: &str = "shipping_receiving.rma.inventory_adjusted";
+
+// ============================================================================
+// Payload: shipping_receiving.rma.credit_requested
+// ============================================================================
+
+/// Payload for shipping_receiving.rma.credit_requested (schema_version 1)
+///
+/// Emitted once per resolved credit/refund RMA with a positive net credit.
+/// AR issues a credit note against `ar_invoice_id` using `credit_note_id` as
+/// its idempotency anchor, so redelivery never issues a second note.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RmaCreditRequestedPayload {
+    pub tenant_id: String,
+    pub rma_authorization_id: Uuid,
+    pub rma_number: String,
+    pub customer_id: Uuid,
+    /// AR invoice (ar_invoices.id) the goods were billed on.
+    pub ar_invoice_id: i32,
+    pub sales_order_id: Option<Uuid>,
+    /// Deterministic from the authorization — stable across redelivery.
+    pub credit_note_id: Uuid,
+    /// Value of the returned goods before the restocking fee.
+    pub gross_credit_minor: i64,
+    pub restocking_fee_minor: i64,
+    /// Credit note amount: gross less restocking fee.
+    pub amount_minor: i64,
+    /// ISO 4217 currency code (lowercase, e.g. "usd").
+    pub currency: String,
+    pub reason_code: String,
+    /// True when the customer is to be refunded rather than left with a balance credit.
+    pub refund: bool,
+    pub resolved_at: DateTime<Utc>,
+}
+
+// ============================================================================
+// Payload: shipping_receiving.rma.replacement_requested
+// ============================================================================
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RmaReplacementLine {
+    pub sku: String,
+    pub qty: i64,
+    /// Line on the original sales order, when known.
+    pub so_line_id: Option<Uuid>,
+}
+
+/// Payload for shipping_receiving.rma.replacement_requested (schema_version 1)
+///
+/// One replacement line per returned SKU for the quantity actually received.
+/// `replacement_request_id` is the idempotency anchor for the new order.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RmaReplacementRequestedPayload {
+    pub tenant_id: String,
+    pub rma_authorization_id: Uuid,
+    pub rma_number: String,
+    pub replacement_request_id: Uuid,
+    pub customer_id: Uuid,
+    pub original_sales_order_id: Option<Uuid>,
+    pub currency: String,
+    pub lines: Vec<RmaReplacementLine>,
+    pub resolved_at: DateTime<Utc>,
+}
+
+// ============================================================================
+// Payload: shipping_receiving.rma.inventory_adjusted
+// ============================================================================
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RmaInventoryAdjustmentLine {
+    pub rma_receipt_item_id: Uuid,
+    pub sku: String,
+    pub qty: i64,
+    /// "restock" | "scrap"
+    pub adjustment_type: String,
+    /// Inventory receipt line for restocked items; None when not inventory-tracked.
+    pub inventory_ref_id: Option<Uuid>,
+}
+
+/// Payload for shipping_receiving.rma.inventory_adjusted (schema_version 1)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RmaInventoryAdjustedPayload {
+    pub tenant_id: String,
+    pub rma_authorization_id: Uuid,
+    pub rma_number: String,
+    pub warehouse_id: Option<Uuid>,
+    pub lines: Vec<RmaInventoryAdjustmentLine>,
+    pub adjusted_at: DateTime<Utc>,
+}
+
+// ============================================================================
+// Envelope builders
+// ============================================================================
+
+/// Build an EventEnvelope for shipping_receiving.rma.credit_requested.
+pub fn build_rma_credit_requested_envelope(
+    event_id: Uuid,
+    tenant_id: String,
+    correlation_id: String,
+    causation_id: Option<String>,
+    payload: RmaCreditRequestedPayload,
+) -> EventEnvelope<RmaCreditRequestedPayload> {
+    create_shipping_receiving_envelope(
+        event_id,
+        tenant_id,
+        EVENT_TYPE_RMA_CREDIT_REQUESTED.to_string(),
+        correlation_id,
+        causation_id,
+        MUTATION_CLASS_DATA_MUTATION.to_string(),
+        payload,
+    )
+    .with_schema_version(SHIPPING_RECEIVING_EVENT_SCHEMA_VERSION.to_string())
+}
+
+/// Build an EventEnvelope for shipping_receiving.rma.replacement_requested.
+pub fn build_rma_replacement_requested_envelope(
+    event_id: Uuid,
+    tenant_id: String,
+    correlation_id: String,
+    causation_id: Option<String>,
+    payload: RmaReplacementRequestedPayload,
+) -> EventEnvelope<RmaReplacementRequestedPayload> {
+    create_shipping_receiving_envelope(
+        event_id,
+        tenant_id,
+        EVENT_TYPE_RMA_REPLACEMENT_REQUESTED.to_string(),
+        correlation_id,
+        causation_id,
+        MUTATION_CLASS_DATA_MUTATION.to_string(),
+        payload,
+    )
+    .with_schema_version(SHIPPING_RECEIVING_EVENT_SCHEMA_VERSION.to_string())
+}
+
+/// Build an EventEnvelope for shipping_receiving.rma.inventory_adjusted.
+pub fn build_rma_inventory_adjusted_envelope(
+    event_id: Uuid,
+    tenant_id: String,
+    correlation_id: String,
+    causation_id: Option<String>,
+    payload: RmaInventoryAdjustedPayload,
+) -> EventEnvelope<RmaInventoryAdjustedPayload> {
+    create_shipping_receiving_envelope(
+        event_id,
+        tenant_id,
+        EVENT_TYPE_RMA_INVENTORY_ADJUSTED.to_string(),
+        correlation_id,
+        causation_id,
+        MUTATION_CLASS_DATA_MUTATION.to_string(),
+        payload,
+    )
+    .with_schema_version(SHIPPING_RECEIVING_EVENT_SCHEMA_VERSION.to_string())
+}
@@ -13,6 +13,9 @@
 //! | shipping_receiving.outbound_shipped           | DATA_MUTATION  | inventory (issue)  |
 //! | shipping_receiving.outbound_delivered         | DATA_MUTATION  | projections        |
 //! | shipping_receiving.shipping_cost.incurred     | DATA_MUTATION  | ap, ar             |
+//! | shipping_receiving.rma.credit_requested       | DATA_MUTATION  | ar (credit note)   |
+//! | shipping_receiving.rma.replacement_requested  | DATA_MUTATION  | sales-orders       |
+//! | shipping_receiving.rma.inventory_adjusted     | DATA_MUTATION  | projections        |
 
 pub mod contracts;
 
@@ -47,6 +50,14 @@ pub use contracts::shipping_cost::{
     EVENT_TYPE_SHIPPING_COST_INCURRED,
 };
 
+pub use contracts::rma::{
+    build_rma_credit_requested_envelope, build_rma_inventory_adjusted_envelope,
+    build_rma_replacement_requested_envelope, RmaCreditRequestedPayload,
+    RmaInventoryAdjustedPayload, RmaInventoryAdjustmentLine, RmaReplacementLine,
+    RmaReplacementRequestedPayload, EVENT_TYPE_RMA_CREDIT_REQUESTED,
+    EVENT_TYPE_RMA_INVENTORY_ADJUSTED, EVENT_TYPE_RMA_REPLACEMENT_REQUESTED,
+};
+
 // ============================================================================
 // Envelope builder helper
 // ============================================================================
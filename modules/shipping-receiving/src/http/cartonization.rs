@@ -0,0 +1,366 @@
+//! HTTP handlers for cartonization and the pack station.
+//!
+//! Carton types and SKU pack profiles are master data. Cartonization plans a
+//! shipment's cartons; the pack station adds cartons, records scans and
+//! closes cartons. Closed cartons feed multi-package labels and packing slips.
+
+use axum::{
+    extract::{Path, Query, State},
+    http::StatusCode,
+    response::IntoResponse,
+    Extension, Json,
+};
+use event_bus::TracingContext;
+use platform_http_contracts::ApiError;
+use platform_sdk::extract_tenant;
+use security::VerifiedClaims;
+use serde::Deserialize;
+use std::sync::Arc;
+use utoipa::IntoParams;
+use uuid::Uuid;
+
+use crate::domain::cartonization::{
+    AddCartonRequest, CartonTypeRequest, CartonizationService, CartonizeRequest,
+    CloseCartonRequest, ScanCartonItemRequest, SkuPackProfileRequest,
+};
+use crate::AppState;
+
+use super::shipments::types::with_request_id;
+
+fn tenant(claims: &Option<Extension<VerifiedClaims>>) -> Result<Uuid, ApiError> {
+    extract_tenant(claims).and_then(|id| {
+        id.parse()
+            .map_err(|_| ApiError::bad_request("malformed tenant_id"))
+    })
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct ListCartonTypesQuery {
+    /// Include deactivated carton types (default false).
+    #[serde(default)]
+    pub include_inactive: bool,
+}
+
+// ── Carton types ─────────────────────────────────────────────
+
+#[utoipa::path(
+    post,
+    path = "/api/shipping-receiving/carton-types",
+    tag = "Cartonization",
+    request_body = CartonTypeRequest,
+    responses(
+        (status = 201, description = "Carton type created", body = crate::domain::cartonization::CartonType),
+        (status = 409, description = "Duplicate code", body = ApiError),
+        (status = 422, description = "Validation error", body = ApiError),
+    ),
+    security(("bearer" = [])),
+)]
+pub async fn create_carton_type(
+    State(state): State<Arc<AppState>>,
+    claims: Option<Extension<VerifiedClaims>>,
+    tracing_ctx: Option<Extension<TracingContext>>,
+    Json(req): Json<CartonTypeRequest>,
+) -> impl IntoResponse {
+    let tenant_id = match tenant(&claims) {
+        Ok(id) => id,
+        Err(e) => return with_request_id(e, &tracing_ctx).into_response(),
+    };
+    match CartonizationService::create_carton_type(&state.pool, tenant_id, &req).await {
+        Ok(t) => (StatusCode::CREATED, Json(t)).into_response(),
+        Err(e) => with_request_id(ApiError::from(e), &tracing_ctx).into_response(),
+    }
+}
+
+#[utoipa::path(
+    put,
+    path = "/api/shipping-receiving/carton-types/{id}",
+    tag = "Cartonization",
+    params(("id" = Uuid, Path, description = "Carton type ID")),
+    request_body = CartonTypeRequest,
+    responses(
+        (status = 200, description = "Carton type updated", body = crate::domain::cartonization::CartonType),
+        (status = 404, description = "Not found", body = ApiError),
+        (status = 409, description = "Duplicate code", body = ApiError),
+    ),
+    security(("bearer" = [])),
+)]
+pub async fn update_carton_type(
+    State(state): State<Arc<AppState>>,
+    claims: Option<Extension<VerifiedClaims>>,
+    tracing_ctx: Option<Extension<TracingContext>>,
+    Path(id): Path<Uuid>,
+    Json(req): Json<CartonTypeRequest>,
+) -> impl IntoResponse {
+    let tenant_id = match tenant(&claims) {
+        Ok(id) => id,
+        Err(e) => return with_request_id(e, &tracing_ctx).into_response(),
+    };
+    match CartonizationService::update_carton_type(&state.pool, id, tenant_id, &req).await {
+        Ok(t) => Json(t).into_response(),
+        Err(e) => with_request_id(ApiError::from(e), &tracing_ctx).into_response(),
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/shipping-receiving/carton-types",
+    tag = "Cartonization",
+    params(ListCartonTypesQuery),
+    responses(
+        (status = 200, description = "Carton types, smallest first", body = Vec<crate::domain::cartonization::CartonType>),
+    ),
+    security(("bearer" = [])),
+)]
+pub async fn list_carton_types(
+    State(state): State<Arc<AppState>>,
+    claims: Option<Extension<VerifiedClaims>>,
+    tracing_ctx: Option<Extension<TracingContext>>,
+    Query(q): Query<ListCartonTypesQuery>,
+) -> impl IntoResponse {
+    let tenant_id = match tenant(&claims) {
+        Ok(id) => id,
+        Err(e) => return with_request_id(e, &tracing_ctx).into_response(),
+    };
+    match CartonizationService::list_carton_types(&state.pool, tenant_id, q.include_inactive).await
+    {
+        Ok(types) => Json(types).into_response(),
+        Err(e) => with_request_id(ApiError::from(e), &tracing_ctx).into_response(),
+    }
+}
+
+// ── SKU pack profiles ────────────────────────────────────────
+
+#[utoipa::path(
+    put,
+    path = "/api/shipping-receiving/sku-pack-profiles/{sku}",
+    tag = "Cartonization",
+    params(("sku" = String, Path, description = "SKU")),
+    request_body = SkuPackProfileRequest,
+    responses(
+        (status = 200, description = "Pack profile saved", body = crate::domain::cartonization::SkuPackProfile),
+        (status = 422, description = "Validation error", body = ApiError),
+    ),
+    security(("bearer" = [])),
+)]
+pub async fn put_sku_pack_profile(
+    State(state): State<Arc<AppState>>,
+    claims: Option<Extension<VerifiedClaims>>,
+    tracing_ctx: Option<Extension<TracingContext>>,
+    Path(sku): Path<String>,
+    Json(req): Json<SkuPackProfileRequest>,
+) -> impl IntoResponse {
+    let tenant_id = match tenant(&claims) {
+        Ok(id) => id,
+        Err(e) => return with_request_id(e, &tracing_ctx).into_response(),
+    };
+    match CartonizationService::upsert_sku_profile(&state.pool, tenant_id, &sku, &req).await {
+        Ok(p) => Json(p).into_response(),
+        Err(e) => with_request_id(ApiError::from(e), &tracing_ctx).into_response(),
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/shipping-receiving/sku-pack-profiles/{sku}",
+    tag = "Cartonization",
+    params(("sku" = String, Path, description = "SKU")),
+    responses(
+        (status = 200, description = "Pack profile", body = crate::domain::cartonization::SkuPackProfile),
+        (status = 404, description = "Not found", body = ApiError),
+    ),
+    security(("bearer" = [])),
+)]
+pub async fn get_sku_pack_profile(
+    State(state): State<Arc<AppState>>,
+    claims: Option<Extension<VerifiedClaims>>,
+    tracing_ctx: Option<Extension<TracingContext>>,
+    Path(sku): Path<String>,
+) -> impl IntoResponse {
+    let tenant_id = match tenant(&claims) {
+        Ok(id) => id,
+        Err(e) => return with_request_id(e, &tracing_ctx).into_response(),
+    };
+    match CartonizationService::get_sku_profile(&state.pool, tenant_id, &sku).await {
+        Ok(Some(p)) => Json(p).into_response(),
+        Ok(None) => with_request_id(ApiError::not_found("Pack profile not found"), &tracing_ctx)
+            .into_response(),
+        Err(e) => with_request_id(ApiError::from(e), &tracing_ctx).into_response(),
+    }
+}
+
+// ── Shipment cartons ─────────────────────────────────────────
+
+#[utoipa::path(
+    post,
+    path = "/api/shipping-receiving/shipments/{id}/cartonize",
+    tag = "Cartonization",
+    params(("id" = Uuid, Path, description = "Shipment ID")),
+    request_body(content = CartonizeRequest, description = "Optional carton type restriction"),
+    responses(
+        (status = 200, description = "Planned cartons", body = crate::domain::cartonization::PackingList),
+        (status = 404, description = "Shipment not found", body = ApiError),
+        (status = 409, description = "Packing already started", body = ApiError),
+        (status = 422, description = "Missing pack profile or SKU does not fit", body = ApiError),
+    ),
+    security(("bearer" = [])),
+)]
+pub async fn cartonize_shipment(
+    State(state): State<Arc<AppState>>,
+    claims: Option<Extension<VerifiedClaims>>,
+    tracing_ctx: Option<Extension<TracingContext>>,
+    Path(shipment_id): Path<Uuid>,
+    body: Option<Json<CartonizeRequest>>,
+) -> impl IntoResponse {
+    let tenant_id = match tenant(&claims) {
+        Ok(id) => id,
+        Err(e) => return with_request_id(e, &tracing_ctx).into_response(),
+    };
+    let req = body.map(|Json(r)| r).unwrap_or_default();
+    match CartonizationService::cartonize(&state.pool, shipment_id, tenant_id, &req).await {
+        Ok(list) => Json(list).into_response(),
+        Err(e) => with_request_id(ApiError::from(e), &tracing_ctx).into_response(),
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/shipping-receiving/shipments/{id}/cartons",
+    tag = "Cartonization",
+    params(("id" = Uuid, Path, description = "Shipment ID")),
+    responses(
+        (status = 200, description = "Per-carton content lists", body = crate::domain::cartonization::PackingList),
+        (status = 404, description = "Shipment not found", body = ApiError),
+    ),
+    security(("bearer" = [])),
+)]
+pub async fn get_packing_list(
+    State(state): State<Arc<AppState>>,
+    claims: Option<Extension<VerifiedClaims>>,
+    tracing_ctx: Option<Extension<TracingContext>>,
+    Path(shipment_id): Path<Uuid>,
+) -> impl IntoResponse {
+    let tenant_id = match tenant(&claims) {
+        Ok(id) => id,
+        Err(e) => return with_request_id(e, &tracing_ctx).into_response(),
+    };
+    match CartonizationService::packing_list(&state.pool, shipment_id, tenant_id).await {
+        Ok(list) => Json(list).into_response(),
+        Err(e) => with_request_id(ApiError::from(e), &tracing_ctx).into_response(),
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/shipping-receiving/shipments/{id}/cartons",
+    tag = "Cartonization",
+    params(("id" = Uuid, Path, description = "Shipment ID")),
+    request_body = AddCartonRequest,
+    responses(
+        (status = 201, description = "Carton opened", body = crate::domain::cartonization::CartonContents),
+        (status = 404, description = "Shipment or carton type not found", body = ApiError),
+    ),
+    security(("bearer" = [])),
+)]
+pub async fn add_carton(
+    State(state): State<Arc<AppState>>,
+    claims: Option<Extension<VerifiedClaims>>,
+    tracing_ctx: Option<Extension<TracingContext>>,
+    Path(shipment_id): Path<Uuid>,
+    Json(req): Json<AddCartonRequest>,
+) -> impl IntoResponse {
+    let tenant_id = match tenant(&claims) {
+        Ok(id) => id,
+        Err(e) => return with_request_id(e, &tracing_ctx).into_response(),
+    };
+    match CartonizationService::add_carton(
+        &state.pool,
+        shipment_id,
+        tenant_id,
+        &req.carton_type_code,
+    )
+    .await
+    {
+        Ok(c) => (StatusCode::CREATED, Json(c)).into_response(),
+        Err(e) => with_request_id(ApiError::from(e), &tracing_ctx).into_response(),
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/shipping-receiving/shipments/{id}/cartons/{carton_id}/scan",
+    tag = "Cartonization",
+    params(
+        ("id" = Uuid, Path, description = "Shipment ID"),
+        ("carton_id" = Uuid, Path, description = "Carton ID"),
+    ),
+    request_body = ScanCartonItemRequest,
+    responses(
+        (status = 200, description = "Carton contents after the scan", body = crate::domain::cartonization::CartonContents),
+        (status = 404, description = "Shipment or carton not found", body = ApiError),
+        (status = 409, description = "Carton is closed", body = ApiError),
+        (status = 422, description = "SKU not on shipment or over-pack", body = ApiError),
+    ),
+    security(("bearer" = [])),
+)]
+pub async fn scan_carton_item(
+    State(state): State<Arc<AppState>>,
+    claims: Option<Extension<VerifiedClaims>>,
+    tracing_ctx: Option<Extension<TracingContext>>,
+    Path((shipment_id, carton_id)): Path<(Uuid, Uuid)>,
+    Json(req): Json<ScanCartonItemRequest>,
+) -> impl IntoResponse {
+    let tenant_id = match tenant(&claims) {
+        Ok(id) => id,
+        Err(e) => return with_request_id(e, &tracing_ctx).into_response(),
+    };
+    match CartonizationService::scan(&state.pool, shipment_id, carton_id, tenant_id, &req).await {
+        Ok(c) => Json(c).into_response(),
+        Err(e) => with_request_id(ApiError::from(e), &tracing_ctx).into_response(),
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/shipping-receiving/shipments/{id}/cartons/{carton_id}/close",
+    tag = "Cartonization",
+    params(
+        ("id" = Uuid, Path, description = "Shipment ID"),
+        ("carton_id" = Uuid, Path, description = "Carton ID"),
+    ),
+    request_body(content = CloseCartonRequest, description = "Optional scale weight"),
+    responses(
+        (status = 200, description = "Carton closed", body = crate::domain::cartonization::CartonContents),
+        (status = 404, description = "Shipment or carton not found", body = ApiError),
+        (status = 409, description = "Carton already closed", body = ApiError),
+        (status = 422, description = "Carton is empty", body = ApiError),
+    ),
+    security(("bearer" = [])),
+)]
+pub async fn close_carton(
+    State(state): State<Arc<AppState>>,
+    claims: Option<Extension<VerifiedClaims>>,
+    tracing_ctx: Option<Extension<TracingContext>>,
+    Path((shipment_id, carton_id)): Path<(Uuid, Uuid)>,
+    body: Option<Json<CloseCartonRequest>>,
+) -> impl IntoResponse {
+    let tenant_id = match tenant(&claims) {
+        Ok(id) => id,
+        Err(e) => return with_request_id(e, &tracing_ctx).into_response(),
+    };
+    let req = body.map(|Json(r)| r).unwrap_or_default();
+    let closed_by = claims.as_ref().map(|Extension(c)| c.user_id);
+    match CartonizationService::close_carton(
+        &state.pool,
+        shipment_id,
+        carton_id,
+        tenant_id,
+        closed_by,
+        &req,
+    )
+    .await
+    {
+        Ok(c) => Json(c).into_response(),
+        Err(e) => with_request_id(ApiError::from(e), &tracing_ctx).into_response(),
+    }
+}
@@ -1,10 +1,13 @@
 pub mod admin_types;
 pub mod carrier_webhooks;
+pub mod cartonization;
 pub mod health;
 pub mod inbound_tracking;
 pub mod inspection_routing;
 pub mod label_reprint;
 pub mod multi_package;
+pub mod rate_shop;
 pub mod refs;
 pub mod return_labels;
+pub mod rma;
 pub mod shipments;
@@ -6,6 +6,10 @@
 //!
 //! Invariant: one logical shipment = one billing event. Children share the master's
 //! tracking number for AR/AP purposes. This endpoint enforces that.
+//!
+//! With `shipment_id`, packages are the shipment's closed cartons (one package
+//! per carton, in carton-number order) and each carton gets its child tracking
+//! number.
 
 use axum::{
     extract::State,
@@ -23,9 +27,11 @@ use serde::{Deserialize, Serialize};
 use std::sync::Arc;
 use uuid::Uuid;
 
+use crate::db::cartonization_repo::CartonizationRepo;
 use crate::domain::carrier_providers::{
     credentials, get_provider, MultiPackageLabelRequest, MultiPackageLabelResponse, PackageInfo,
 };
+use crate::domain::cartonization::CartonizationService;
 use crate::events::contracts::shipping_cost::{
     build_shipping_cost_incurred_envelope, ShippingCostIncurredPayload,
     EVENT_TYPE_SHIPPING_COST_INCURRED,
@@ -33,6 +39,8 @@ use crate::events::contracts::shipping_cost::{
 use crate::outbox;
 use crate::AppState;
 
+use super::shipments::types::with_request_id;
+
 // ── Request / Response types ──────────────────────────────────
 
 /// HTTP request body for multi-package label creation.
@@ -40,8 +48,11 @@ use crate::AppState;
 pub struct MultiPackageRequest {
     /// Carrier code: ups | fedex | rl | xpo | odfl | saia
     pub carrier_code: String,
-    /// One entry per physical package.
+    /// One entry per physical package. Leave empty when `shipment_id` is given.
+    #[serde(default)]
     pub packages: Vec<PackageInfoDto>,
+    /// Packed outbound shipment whose closed cartons become the packages.
+    pub shipment_id: Option<Uuid>,
     /// Origin address fields: name, address, city, state, zip
     pub origin: serde_json::Value,
     /// Destination address fields: name, address, city, state, zip
@@ -103,7 +114,39 @@ pub async fn create_multi_package_shipment(
         Err(e) => return (StatusCode::UNAUTHORIZED, Json(serde_json::json!({"error": e.to_string()}))).into_response(),
     };
 
-    if req.packages.is_empty() {
+    // Packages come from the request or from the shipment's closed cartons.
+    let (packages, carton_ids): (Vec<PackageInfo>, Vec<Uuid>) = match req.shipment_id {
+        Some(_) if !req.packages.is_empty() => {
+            return (
+                StatusCode::BAD_REQUEST,
+                Json(serde_json::json!({"error": "give either packages or shipment_id, not both"})),
+            )
+                .into_response()
+        }
+        Some(shipment_id) => {
+            match CartonizationService::packages_for_shipment(&state.pool, shipment_id, tenant_id)
+                .await
+            {
+                Ok(pkgs) => pkgs.into_iter().map(|(id, p)| (p, id)).unzip(),
+                Err(e) => return with_request_id(ApiError::from(e), &tracing_ctx).into_response(),
+            }
+        }
+        None => (
+            req.packages
+                .iter()
+                .map(|p| PackageInfo {
+                    weight_lbs: p.weight_lbs,
+                    length_in: p.length_in,
+                    width_in: p.width_in,
+                    height_in: p.height_in,
+                    declared_value_cents: p.declared_value_cents,
+                })
+                .collect(),
+            Vec::new(),
+        ),
+    };
+
+    if packages.is_empty() {
         return (
             StatusCode::BAD_REQUEST,
             Json(serde_json::json!({"error": "packages must not be empty"})),
@@ -163,18 +206,9 @@ pub async fn create_multi_package_shipment(
         }
     };
 
+    let package_count = packages.len();
     let label_req = MultiPackageLabelRequest {
-        packages: req
-            .packages
-            .iter()
-            .map(|p| PackageInfo {
-                weight_lbs: p.weight_lbs,
-                length_in: p.length_in,
-                width_in: p.width_in,
-                height_in: p.height_in,
-                declared_value_cents: p.declared_value_cents,
-            })
-            .collect(),
+        packages,
         origin: req.origin.clone(),
         destination: req.destination.clone(),
         service_level: req.service_level.clone(),
@@ -193,7 +227,6 @@ pub async fn create_multi_package_shipment(
             }
         };
 
-    let package_count = req.packages.len();
     let correlation_id = tracing_ctx
         .as_ref()
         .and_then(|Extension(c)| c.trace_id.clone())
@@ -264,6 +297,22 @@ pub async fn create_multi_package_shipment(
                     .into_response()
             }
         };
+        if let Some(carton_id) = carton_ids.get(child.package_index) {
+            if let Err(e) = CartonizationRepo::set_tracking_number_tx(
+                &mut tx,
+                *carton_id,
+                tenant_id,
+                &child.tracking_number,
+            )
+            .await
+            {
+                return (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    Json(serde_json::json!({"error": format!("db error updating carton: {e}")})),
+                )
+                    .into_response();
+            }
+        }
         children_out.push(ChildShipmentDto {
             shipment_id: child_id,
             tracking_number: child.tracking_number.clone(),
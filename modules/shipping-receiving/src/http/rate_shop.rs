@@ -0,0 +1,205 @@
+//! HTTP handlers for multi-carrier rate shopping.
+//!
+//! Tenants configure which carriers to quote and the selection rules; a rate
+//! shop on an outbound shipment quotes them all, picks one and records the
+//! decision with its rationale.
+
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::IntoResponse,
+    Extension, Json,
+};
+use event_bus::TracingContext;
+use platform_http_contracts::ApiError;
+use platform_sdk::extract_tenant;
+use security::VerifiedClaims;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::domain::rate_shop::{
+    RateShopExclusionRequest, RateShopRequest, RateShopRulesRequest, RateShopService,
+};
+use crate::AppState;
+
+use super::shipments::types::with_request_id;
+
+fn tenant(claims: &Option<Extension<VerifiedClaims>>) -> Result<Uuid, ApiError> {
+    extract_tenant(claims).and_then(|id| {
+        id.parse()
+            .map_err(|_| ApiError::bad_request("malformed tenant_id"))
+    })
+}
+
+// ── Configuration ────────────────────────────────────────────
+
+#[utoipa::path(
+    put,
+    path = "/api/shipping-receiving/rate-shop/rules",
+    tag = "Rate Shopping",
+    request_body = RateShopRulesRequest,
+    responses(
+        (status = 200, description = "Rules saved", body = crate::domain::rate_shop::RateShopRules),
+        (status = 422, description = "Unknown carrier or invalid rule", body = ApiError),
+    ),
+    security(("bearer" = [])),
+)]
+pub async fn put_rules(
+    State(state): State<Arc<AppState>>,
+    claims: Option<Extension<VerifiedClaims>>,
+    tracing_ctx: Option<Extension<TracingContext>>,
+    Json(req): Json<RateShopRulesRequest>,
+) -> impl IntoResponse {
+    let tenant_id = match tenant(&claims) {
+        Ok(id) => id,
+        Err(e) => return with_request_id(e, &tracing_ctx).into_response(),
+    };
+    match RateShopService::put_rules(&state.pool, tenant_id, &req).await {
+        Ok(rules) => Json(rules).into_response(),
+        Err(e) => with_request_id(ApiError::from(e), &tracing_ctx).into_response(),
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/shipping-receiving/rate-shop/rules",
+    tag = "Rate Shopping",
+    responses(
+        (status = 200, description = "Rules and destination exclusions", body = crate::domain::rate_shop::RateShopConfig),
+        (status = 404, description = "Rate shopping not configured", body = ApiError),
+    ),
+    security(("bearer" = [])),
+)]
+pub async fn get_rules(
+    State(state): State<Arc<AppState>>,
+    claims: Option<Extension<VerifiedClaims>>,
+    tracing_ctx: Option<Extension<TracingContext>>,
+) -> impl IntoResponse {
+    let tenant_id = match tenant(&claims) {
+        Ok(id) => id,
+        Err(e) => return with_request_id(e, &tracing_ctx).into_response(),
+    };
+    match RateShopService::get_config(&state.pool, tenant_id).await {
+        Ok(Some(config)) => Json(config).into_response(),
+        Ok(None) => with_request_id(
+            ApiError::not_found("Rate shopping not configured"),
+            &tracing_ctx,
+        )
+        .into_response(),
+        Err(e) => with_request_id(ApiError::from(e), &tracing_ctx).into_response(),
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/shipping-receiving/rate-shop/exclusions",
+    tag = "Rate Shopping",
+    request_body = RateShopExclusionRequest,
+    responses(
+        (status = 201, description = "Exclusion created", body = crate::domain::rate_shop::RateShopExclusion),
+        (status = 422, description = "Unknown carrier", body = ApiError),
+    ),
+    security(("bearer" = [])),
+)]
+pub async fn add_exclusion(
+    State(state): State<Arc<AppState>>,
+    claims: Option<Extension<VerifiedClaims>>,
+    tracing_ctx: Option<Extension<TracingContext>>,
+    Json(req): Json<RateShopExclusionRequest>,
+) -> impl IntoResponse {
+    let tenant_id = match tenant(&claims) {
+        Ok(id) => id,
+        Err(e) => return with_request_id(e, &tracing_ctx).into_response(),
+    };
+    match RateShopService::add_exclusion(&state.pool, tenant_id, &req).await {
+        Ok(ex) => (StatusCode::CREATED, Json(ex)).into_response(),
+        Err(e) => with_request_id(ApiError::from(e), &tracing_ctx).into_response(),
+    }
+}
+
+#[utoipa::path(
+    delete,
+    path = "/api/shipping-receiving/rate-shop/exclusions/{id}",
+    tag = "Rate Shopping",
+    params(("id" = Uuid, Path, description = "Exclusion ID")),
+    responses(
+        (status = 204, description = "Exclusion removed"),
+        (status = 404, description = "Not found", body = ApiError),
+    ),
+    security(("bearer" = [])),
+)]
+pub async fn delete_exclusion(
+    State(state): State<Arc<AppState>>,
+    claims: Option<Extension<VerifiedClaims>>,
+    tracing_ctx: Option<Extension<TracingContext>>,
+    Path(id): Path<Uuid>,
+) -> impl IntoResponse {
+    let tenant_id = match tenant(&claims) {
+        Ok(id) => id,
+        Err(e) => return with_request_id(e, &tracing_ctx).into_response(),
+    };
+    match RateShopService::delete_exclusion(&state.pool, id, tenant_id).await {
+        Ok(()) => StatusCode::NO_CONTENT.into_response(),
+        Err(e) => with_request_id(ApiError::from(e), &tracing_ctx).into_response(),
+    }
+}
+
+// ── Rate shop ────────────────────────────────────────────────
+
+#[utoipa::path(
+    post,
+    path = "/api/shipping-receiving/shipments/{id}/rate-shop",
+    tag = "Rate Shopping",
+    params(("id" = Uuid, Path, description = "Shipment ID")),
+    request_body = RateShopRequest,
+    responses(
+        (status = 201, description = "Decision recorded", body = crate::domain::rate_shop::RateShopDecision),
+        (status = 404, description = "Shipment not found", body = ApiError),
+        (status = 422, description = "Not configured or no eligible quote", body = ApiError),
+    ),
+    security(("bearer" = [])),
+)]
+pub async fn rate_shop_shipment(
+    State(state): State<Arc<AppState>>,
+    claims: Option<Extension<VerifiedClaims>>,
+    tracing_ctx: Option<Extension<TracingContext>>,
+    Path(shipment_id): Path<Uuid>,
+    Json(req): Json<RateShopRequest>,
+) -> impl IntoResponse {
+    let tenant_id = match tenant(&claims) {
+        Ok(id) => id,
+        Err(e) => return with_request_id(e, &tracing_ctx).into_response(),
+    };
+    let decided_by = claims.as_ref().map(|Extension(c)| c.user_id);
+    match RateShopService::rate_shop(&state.pool, shipment_id, tenant_id, decided_by, &req).await {
+        Ok(decision) => (StatusCode::CREATED, Json(decision)).into_response(),
+        Err(e) => with_request_id(ApiError::from(e), &tracing_ctx).into_response(),
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/shipping-receiving/shipments/{id}/rate-shop",
+    tag = "Rate Shopping",
+    params(("id" = Uuid, Path, description = "Shipment ID")),
+    responses(
+        (status = 200, description = "Rate-shop decisions, newest first", body = Vec<crate::domain::rate_shop::RateShopDecision>),
+        (status = 404, description = "Shipment not found", body = ApiError),
+    ),
+    security(("bearer" = [])),
+)]
+pub async fn list_decisions(
+    State(state): State<Arc<AppState>>,
+    claims: Option<Extension<VerifiedClaims>>,
+    tracing_ctx: Option<Extension<TracingContext>>,
+    Path(shipment_id): Path<Uuid>,
+) -> impl IntoResponse {
+    let tenant_id = match tenant(&claims) {
+        Ok(id) => id,
+        Err(e) => return with_request_id(e, &tracing_ctx).into_response(),
+    };
+    match RateShopService::list_decisions(&state.pool, shipment_id, tenant_id).await {
+        Ok(decisions) => Json(decisions).into_response(),
+        Err(e) => with_request_id(ApiError::from(e), &tracing_ctx).into_response(),
+    }
+}
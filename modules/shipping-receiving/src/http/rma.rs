@@ -0,0 +1,281 @@
+//! HTTP handlers for RMA authorization, receiving and financial resolution.
+//!
+//! An RMA is authorized against the original sales order and AR invoice,
+//! goods are received and dispositioned against it, and resolution issues the
+//! credit, replacement and inventory adjustments.
+
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::IntoResponse,
+    Extension, Json,
+};
+use event_bus::TracingContext;
+use platform_http_contracts::ApiError;
+use platform_sdk::extract_tenant;
+use security::VerifiedClaims;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::domain::rma::{
+    CreateRmaAuthorizationRequest, DispositionRequest, ReceiveRmaRequest,
+    RestockingFeeRulesRequest, RmaAuthorizationService, RmaService,
+};
+use crate::AppState;
+
+use super::shipments::types::with_request_id;
+
+fn tenant(claims: &Option<Extension<VerifiedClaims>>) -> Result<Uuid, ApiError> {
+    extract_tenant(claims).and_then(|id| {
+        id.parse()
+            .map_err(|_| ApiError::bad_request("malformed tenant_id"))
+    })
+}
+
+// ── Restocking fee rules ─────────────────────────────────────
+
+#[utoipa::path(
+    put,
+    path = "/api/shipping-receiving/rma/restocking-fee-rules",
+    tag = "RMA",
+    request_body = RestockingFeeRulesRequest,
+    responses(
+        (status = 200, description = "Rules saved", body = crate::domain::rma::RestockingFeeRules),
+        (status = 422, description = "Invalid rule", body = ApiError),
+    ),
+    security(("bearer" = [])),
+)]
+pub async fn put_fee_rules(
+    State(state): State<Arc<AppState>>,
+    claims: Option<Extension<VerifiedClaims>>,
+    tracing_ctx: Option<Extension<TracingContext>>,
+    Json(req): Json<RestockingFeeRulesRequest>,
+) -> impl IntoResponse {
+    let tenant_id = match tenant(&claims) {
+        Ok(id) => id,
+        Err(e) => return with_request_id(e, &tracing_ctx).into_response(),
+    };
+    match RmaAuthorizationService::put_fee_rules(&state.pool, tenant_id, &req).await {
+        Ok(rules) => Json(rules).into_response(),
+        Err(e) => with_request_id(ApiError::from(e), &tracing_ctx).into_response(),
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/shipping-receiving/rma/restocking-fee-rules",
+    tag = "RMA",
+    responses(
+        (status = 200, description = "Restocking fee rules", body = crate::domain::rma::RestockingFeeRules),
+        (status = 404, description = "No restocking fee configured", body = ApiError),
+    ),
+    security(("bearer" = [])),
+)]
+pub async fn get_fee_rules(
+    State(state): State<Arc<AppState>>,
+    claims: Option<Extension<VerifiedClaims>>,
+    tracing_ctx: Option<Extension<TracingContext>>,
+) -> impl IntoResponse {
+    let tenant_id = match tenant(&claims) {
+        Ok(id) => id,
+        Err(e) => return with_request_id(e, &tracing_ctx).into_response(),
+    };
+    match RmaAuthorizationService::get_fee_rules(&state.pool, tenant_id).await {
+        Ok(Some(rules)) => Json(rules).into_response(),
+        Ok(None) => with_request_id(
+            ApiError::not_found("No restocking fee configured"),
+            &tracing_ctx,
+        )
+        .into_response(),
+        Err(e) => with_request_id(ApiError::from(e), &tracing_ctx).into_response(),
+    }
+}
+
+// ── Authorizations ───────────────────────────────────────────
+
+#[utoipa::path(
+    post,
+    path = "/api/shipping-receiving/rma/authorizations",
+    tag = "RMA",
+    request_body = CreateRmaAuthorizationRequest,
+    responses(
+        (status = 201, description = "RMA authorized", body = crate::domain::rma::RmaAuthorizationDetail),
+        (status = 409, description = "RMA number already exists", body = ApiError),
+        (status = 422, description = "Validation error", body = ApiError),
+    ),
+    security(("bearer" = [])),
+)]
+pub async fn create_authorization(
+    State(state): State<Arc<AppState>>,
+    claims: Option<Extension<VerifiedClaims>>,
+    tracing_ctx: Option<Extension<TracingContext>>,
+    Json(req): Json<CreateRmaAuthorizationRequest>,
+) -> impl IntoResponse {
+    let tenant_id = match tenant(&claims) {
+        Ok(id) => id,
+        Err(e) => return with_request_id(e, &tracing_ctx).into_response(),
+    };
+    let authorized_by = claims.as_ref().map(|Extension(c)| c.user_id);
+    match RmaAuthorizationService::authorize(&state.pool, tenant_id, authorized_by, &req).await {
+        Ok(detail) => (StatusCode::CREATED, Json(detail)).into_response(),
+        Err(e) => with_request_id(ApiError::from(e), &tracing_ctx).into_response(),
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/shipping-receiving/rma/authorizations/{id}",
+    tag = "RMA",
+    params(("id" = Uuid, Path, description = "RMA authorization ID")),
+    responses(
+        (status = 200, description = "Authorization with lines, receipts and adjustments", body = crate::domain::rma::RmaAuthorizationDetail),
+        (status = 404, description = "Not found", body = ApiError),
+    ),
+    security(("bearer" = [])),
+)]
+pub async fn get_authorization(
+    State(state): State<Arc<AppState>>,
+    claims: Option<Extension<VerifiedClaims>>,
+    tracing_ctx: Option<Extension<TracingContext>>,
+    Path(id): Path<Uuid>,
+) -> impl IntoResponse {
+    let tenant_id = match tenant(&claims) {
+        Ok(id) => id,
+        Err(e) => return with_request_id(e, &tracing_ctx).into_response(),
+    };
+    match RmaAuthorizationService::get(&state.pool, id, tenant_id).await {
+        Ok(Some(detail)) => Json(detail).into_response(),
+        Ok(None) => with_request_id(
+            ApiError::not_found("RMA authorization not found"),
+            &tracing_ctx,
+        )
+        .into_response(),
+        Err(e) => with_request_id(ApiError::from(e), &tracing_ctx).into_response(),
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/shipping-receiving/rma/authorizations/{id}/cancel",
+    tag = "RMA",
+    params(("id" = Uuid, Path, description = "RMA authorization ID")),
+    responses(
+        (status = 200, description = "Authorization cancelled", body = crate::domain::rma::RmaAuthorization),
+        (status = 400, description = "Goods already received", body = ApiError),
+        (status = 404, description = "Not found", body = ApiError),
+    ),
+    security(("bearer" = [])),
+)]
+pub async fn cancel_authorization(
+    State(state): State<Arc<AppState>>,
+    claims: Option<Extension<VerifiedClaims>>,
+    tracing_ctx: Option<Extension<TracingContext>>,
+    Path(id): Path<Uuid>,
+) -> impl IntoResponse {
+    let tenant_id = match tenant(&claims) {
+        Ok(id) => id,
+        Err(e) => return with_request_id(e, &tracing_ctx).into_response(),
+    };
+    match RmaAuthorizationService::cancel(&state.pool, id, tenant_id).await {
+        Ok(authorization) => Json(authorization).into_response(),
+        Err(e) => with_request_id(ApiError::from(e), &tracing_ctx).into_response(),
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/shipping-receiving/rma/authorizations/{id}/resolve",
+    tag = "RMA",
+    params(("id" = Uuid, Path, description = "RMA authorization ID")),
+    responses(
+        (status = 200, description = "RMA resolved", body = crate::domain::rma::RmaResolution),
+        (status = 400, description = "Not in received status", body = ApiError),
+        (status = 404, description = "Not found", body = ApiError),
+        (status = 422, description = "Receipts awaiting final disposition", body = ApiError),
+    ),
+    security(("bearer" = [])),
+)]
+pub async fn resolve_authorization(
+    State(state): State<Arc<AppState>>,
+    claims: Option<Extension<VerifiedClaims>>,
+    tracing_ctx: Option<Extension<TracingContext>>,
+    Path(id): Path<Uuid>,
+) -> impl IntoResponse {
+    let tenant_id = match tenant(&claims) {
+        Ok(id) => id,
+        Err(e) => return with_request_id(e, &tracing_ctx).into_response(),
+    };
+    let resolved_by = claims.as_ref().map(|Extension(c)| c.user_id);
+    match RmaAuthorizationService::resolve(
+        &state.pool,
+        &state.inventory,
+        id,
+        tenant_id,
+        resolved_by,
+    )
+    .await
+    {
+        Ok(resolution) => Json(resolution).into_response(),
+        Err(e) => with_request_id(ApiError::from(e), &tracing_ctx).into_response(),
+    }
+}
+
+// ── Receipts ─────────────────────────────────────────────────
+
+#[utoipa::path(
+    post,
+    path = "/api/shipping-receiving/rma/receipts",
+    tag = "RMA",
+    request_body = ReceiveRmaRequest,
+    responses(
+        (status = 201, description = "Goods received", body = crate::domain::rma::RmaReceipt),
+        (status = 404, description = "RMA authorization not found", body = ApiError),
+        (status = 422, description = "Not authorized or validation error", body = ApiError),
+    ),
+    security(("bearer" = [])),
+)]
+pub async fn receive_rma(
+    State(state): State<Arc<AppState>>,
+    claims: Option<Extension<VerifiedClaims>>,
+    tracing_ctx: Option<Extension<TracingContext>>,
+    Json(req): Json<ReceiveRmaRequest>,
+) -> impl IntoResponse {
+    let tenant_id = match tenant(&claims) {
+        Ok(id) => id,
+        Err(e) => return with_request_id(e, &tracing_ctx).into_response(),
+    };
+    match RmaService::receive(&state.pool, tenant_id, &req).await {
+        Ok(receipt) => (StatusCode::CREATED, Json(receipt)).into_response(),
+        Err(e) => with_request_id(ApiError::from(e), &tracing_ctx).into_response(),
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/shipping-receiving/rma/receipts/{id}/disposition",
+    tag = "RMA",
+    params(("id" = Uuid, Path, description = "RMA receipt ID")),
+    request_body = DispositionRequest,
+    responses(
+        (status = 200, description = "Disposition changed", body = crate::domain::rma::RmaReceipt),
+        (status = 400, description = "Transition not allowed", body = ApiError),
+        (status = 404, description = "Not found", body = ApiError),
+    ),
+    security(("bearer" = [])),
+)]
+pub async fn transition_disposition(
+    State(state): State<Arc<AppState>>,
+    claims: Option<Extension<VerifiedClaims>>,
+    tracing_ctx: Option<Extension<TracingContext>>,
+    Path(id): Path<Uuid>,
+    Json(req): Json<DispositionRequest>,
+) -> impl IntoResponse {
+    let tenant_id = match tenant(&claims) {
+        Ok(id) => id,
+        Err(e) => return with_request_id(e, &tracing_ctx).into_response(),
+    };
+    match RmaService::transition_disposition(&state.pool, id, tenant_id, &req).await {
+        Ok(receipt) => Json(receipt).into_response(),
+        Err(e) => with_request_id(ApiError::from(e), &tracing_ctx).into_response(),
+    }
+}
@@ -183,6 +183,7 @@ impl InventoryIntegration {
                     lot_code: None,
                     serial_codes: None,
                     uom_id: None,
+                    reservation_id: None,
                 };
                 let result = issues.post_issue(&claims, &body).await?;
                 Ok(result.issue_line_id)
@@ -210,6 +211,7 @@ impl InventoryIntegration {
                     lot_code: None,
                     serial_codes: None,
                     uom_id: None,
+                    reservation_id: None,
                 };
                 let result = issues.post_issue(&claims, &body).await?;
                 Ok(result.issue_line_id)
@@ -13,7 +13,24 @@ use shipping_receiving_rs::{
             deployment_environment, dispatch::start_carrier_dispatch_consumer,
             warn_if_stub_provider_enabled,
         },
+        cartonization::{
+            AddCartonRequest, CartonContents, CartonLine, CartonType, CartonTypeRequest,
+            CartonizeRequest, CloseCartonRequest, PackingList, ScanCartonItemRequest,
+            ShipmentCarton, SkuPackProfile, SkuPackProfileRequest, UnpackedLine,
+        },
         inspection_routing::RouteLineRequest,
+        rate_shop::{
+            CarrierFailure, CarrierMode, NormalizedQuote, RateShopAddress, RateShopConfig,
+            RateShopDecision, RateShopExclusion, RateShopExclusionRequest, RateShopRequest,
+            RateShopRules, RateShopRulesRequest,
+        },
+        rma::{
+            CreateRmaAuthorizationRequest, DispositionRequest, ReceiveRmaRequest,
+            ResolutionAmounts, ResolutionLine, RestockingFeeRules, RestockingFeeRulesRequest,
+            RmaAuthorization, RmaAuthorizationDetail, RmaAuthorizationLine,
+            RmaAuthorizationLineInput, RmaInventoryAdjustment, RmaItemInput, RmaReceipt,
+            RmaResolution,
+        },
         shipments::{Direction, Shipment},
         tracking::odfl_poller::start_odfl_poll_task,
     },
@@ -52,6 +69,30 @@ use shipping_receiving_rs::{
         shipping_receiving_rs::http::shipments::ship_outbound,
         shipping_receiving_rs::http::inspection_routing::route_line,
         shipping_receiving_rs::http::inspection_routing::list_routings,
+        shipping_receiving_rs::http::cartonization::create_carton_type,
+        shipping_receiving_rs::http::cartonization::update_carton_type,
+        shipping_receiving_rs::http::cartonization::list_carton_types,
+        shipping_receiving_rs::http::cartonization::put_sku_pack_profile,
+        shipping_receiving_rs::http::cartonization::get_sku_pack_profile,
+        shipping_receiving_rs::http::cartonization::cartonize_shipment,
+        shipping_receiving_rs::http::cartonization::get_packing_list,
+        shipping_receiving_rs::http::cartonization::add_carton,
+        shipping_receiving_rs::http::cartonization::scan_carton_item,
+        shipping_receiving_rs::http::cartonization::close_carton,
+        shipping_receiving_rs::http::rate_shop::put_rules,
+        shipping_receiving_rs::http::rate_shop::get_rules,
+        shipping_receiving_rs::http::rate_shop::add_exclusion,
+        shipping_receiving_rs::http::rate_shop::delete_exclusion,
+        shipping_receiving_rs::http::rate_shop::rate_shop_shipment,
+        shipping_receiving_rs::http::rate_shop::list_decisions,
+        shipping_receiving_rs::http::rma::put_fee_rules,
+        shipping_receiving_rs::http::rma::get_fee_rules,
+        shipping_receiving_rs::http::rma::create_authorization,
+        shipping_receiving_rs::http::rma::get_authorization,
+        shipping_receiving_rs::http::rma::cancel_authorization,
+        shipping_receiving_rs::http::rma::resolve_authorization,
+        shipping_receiving_rs::http::rma::receive_rma,
+        shipping_receiving_rs::http::rma::transition_disposition,
         shipping_receiving_rs::http::refs::shipments_by_po,
         shipping_receiving_rs::http::refs::lines_by_po_line,
         shipping_receiving_rs::http::refs::shipments_by_source_ref,
@@ -62,6 +103,16 @@ use shipping_receiving_rs::{
         AddLineRequest, ReceiveLineRequest, ShipLineQtyRequest,
         ShipmentLineRow, ShipOutboundRequest,
         RouteLineRequest, InspectionRoutingRow,
+        CartonType, CartonTypeRequest, SkuPackProfile, SkuPackProfileRequest,
+        CartonizeRequest, AddCartonRequest, ScanCartonItemRequest, CloseCartonRequest,
+        ShipmentCarton, CartonLine, CartonContents, UnpackedLine, PackingList,
+        RateShopRules, RateShopRulesRequest, RateShopExclusion, RateShopExclusionRequest,
+        RateShopConfig, RateShopAddress, RateShopRequest, RateShopDecision,
+        NormalizedQuote, CarrierFailure, CarrierMode,
+        RestockingFeeRules, RestockingFeeRulesRequest, CreateRmaAuthorizationRequest,
+        RmaAuthorizationLineInput, RmaAuthorization, RmaAuthorizationLine, RmaAuthorizationDetail,
+        RmaInventoryAdjustment, RmaResolution, ResolutionAmounts, ResolutionLine,
+        ReceiveRmaRequest, RmaItemInput, DispositionRequest, RmaReceipt,
         ApiError, PaginatedResponse<Shipment>, PaginatedResponse<InspectionRoutingRow>, PaginationMeta,
     )),
     security(("bearer" = [])),
@@ -1,5 +1,5 @@
 use axum::{
-    routing::{get, patch, post},
+    routing::{delete, get, patch, post, put},
     Router,
 };
 use security::{permissions, RequirePermissionsLayer};
@@ -35,6 +35,34 @@ pub fn build_router() -> Router<Arc<AppState>> {
             "/api/shipping-receiving/shipments/{id}/routings",
             get(http::inspection_routing::list_routings),
         )
+        .route(
+            "/api/shipping-receiving/shipments/{id}/cartons",
+            get(http::cartonization::get_packing_list),
+        )
+        .route(
+            "/api/shipping-receiving/carton-types",
+            get(http::cartonization::list_carton_types),
+        )
+        .route(
+            "/api/shipping-receiving/sku-pack-profiles/{sku}",
+            get(http::cartonization::get_sku_pack_profile),
+        )
+        .route(
+            "/api/shipping-receiving/shipments/{id}/rate-shop",
+            get(http::rate_shop::list_decisions),
+        )
+        .route(
+            "/api/shipping-receiving/rate-shop/rules",
+            get(http::rate_shop::get_rules),
+        )
+        .route(
+            "/api/shipping-receiving/rma/restocking-fee-rules",
+            get(http::rma::get_fee_rules),
+        )
+        .route(
+            "/api/shipping-receiving/rma/authorizations/{id}",
+            get(http::rma::get_authorization),
+        )
         .route(
             "/api/shipping-receiving/po/{po_id}/shipments",
             get(http::refs::shipments_by_po),
@@ -151,4 +179,72 @@ pub fn build_mutation_router() -> Router<Arc<AppState>> {
             "/api/shipping-receiving/inbound-shipments/{id}/expected-tracking",
             post(http::inbound_tracking::set_expected_tracking),
         )
+        .route(
+            "/api/shipping-receiving/carton-types",
+            post(http::cartonization::create_carton_type),
+        )
+        .route(
+            "/api/shipping-receiving/carton-types/{id}",
+            put(http::cartonization::update_carton_type),
+        )
+        .route(
+            "/api/shipping-receiving/sku-pack-profiles/{sku}",
+            put(http::cartonization::put_sku_pack_profile),
+        )
+        .route(
+            "/api/shipping-receiving/shipments/{id}/cartonize",
+            post(http::cartonization::cartonize_shipment),
+        )
+        .route(
+            "/api/shipping-receiving/shipments/{id}/cartons",
+            post(http::cartonization::add_carton),
+        )
+        .route(
+            "/api/shipping-receiving/shipments/{id}/cartons/{carton_id}/scan",
+            post(http::cartonization::scan_carton_item),
+        )
+        .route(
+            "/api/shipping-receiving/shipments/{id}/cartons/{carton_id}/close",
+            post(http::cartonization::close_carton),
+        )
+        .route(
+            "/api/shipping-receiving/rate-shop/rules",
+            put(http::rate_shop::put_rules),
+        )
+        .route(
+            "/api/shipping-receiving/rate-shop/exclusions",
+            post(http::rate_shop::add_exclusion),
+        )
+        .route(
+            "/api/shipping-receiving/rate-shop/exclusions/{id}",
+            delete(http::rate_shop::delete_exclusion),
+        )
+        .route(
+            "/api/shipping-receiving/shipments/{id}/rate-shop",
+            post(http::rate_shop::rate_shop_shipment),
+        )
+        .route(
+            "/api/shipping-receiving/rma/restocking-fee-rules",
+            put(http::rma::put_fee_rules),
+        )
+        .route(
+            "/api/shipping-receiving/rma/authorizations",
+            post(http::rma::create_authorization),
+        )
+        .route(
+            "/api/shipping-receiving/rma/authorizations/{id}/cancel",
+            post(http::rma::cancel_authorization),
+        )
+        .route(
+            "/api/shipping-receiving/rma/authorizations/{id}/resolve",
+            post(http::rma::resolve_authorization),
+        )
+        .route(
+            "/api/shipping-receiving/rma/receipts",
+            post(http::rma::receive_rma),
+        )
+        .route(
+            "/api/shipping-receiving/rma/receipts/{id}/disposition",
+            post(http::rma::transition_disposition),
+        )
 }
@@ -54,6 +54,7 @@ fn make_receive_request(
             },
         ],
         idempotency_key: idem_key.map(|s| s.to_string()),
+        rma_authorization_id: None,
     }
 }
 
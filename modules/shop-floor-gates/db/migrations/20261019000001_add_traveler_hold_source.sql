@@ -0,0 +1,21 @@
+-- Source of system-placed holds
+--
+-- Holds placed by event consumers record what placed them so the database,
+-- not a reason-text match, keeps them idempotent:
+--   source_key      — kind of automatic hold (e.g. 'spc'); at most one active
+--                     hold per work order and key
+--   source_event_id — triggering event; an event places at most one hold,
+--                     even when redelivered after the hold was released
+-- Both are NULL for holds placed by users.
+
+ALTER TABLE traveler_holds
+    ADD COLUMN source_key TEXT,
+    ADD COLUMN source_event_id UUID;
+
+CREATE UNIQUE INDEX idx_traveler_holds_active_source
+    ON traveler_holds (tenant_id, work_order_id, source_key)
+    WHERE status = 'active' AND source_key IS NOT NULL;
+
+CREATE UNIQUE INDEX idx_traveler_holds_source_event
+    ON traveler_holds (tenant_id, source_event_id)
+    WHERE source_event_id IS NOT NULL;
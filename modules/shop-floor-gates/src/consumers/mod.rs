@@ -1,2 +1,3 @@
 pub mod operation_completed;
+pub mod spc_violation;
 pub mod work_order_closed;
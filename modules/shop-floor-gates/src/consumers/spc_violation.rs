@@ -0,0 +1,124 @@
+//! Places a quality hold on the work order when quality-inspection reports an
+//! SPC run rule violation on one of its in-process inspections.
+//!
+//! At most one active SPC hold per work order, and at most one hold per event:
+//! further violations while the work order is already held, and redelivered
+//! events, are logged only. Both rules are unique indexes on the hold's
+//! source columns (see `holds::service::place_sourced_hold`).
+
+use event_bus::{EventBus, EventEnvelope};
+use futures::StreamExt;
+use platform_http_contracts::ApiError;
+use serde::Deserialize;
+use sqlx::PgPool;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::domain::holds::{
+    service::{self as holds_service, SYSTEM_ACTOR},
+    HoldSource, PlaceHoldRequest, TravelerHold,
+};
+
+const SUBJECT: &str = "quality_inspection.spc_violation_detected";
+const REASON_PREFIX: &str = "SPC rule violation";
+const HOLD_SOURCE_SPC: &str = "spc";
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct SpcViolationDetectedPayload {
+    pub tenant_id: String,
+    pub chart_id: Uuid,
+    pub inspection_id: Uuid,
+    pub wo_id: Option<Uuid>,
+    pub characteristic: String,
+    pub chart_type: String,
+    pub rules: Vec<String>,
+    pub hold_requested: bool,
+}
+
+pub fn start_spc_violation_consumer(bus: Arc<dyn EventBus>, pool: PgPool) {
+    tokio::spawn(async move {
+        consume(bus, pool).await;
+    });
+}
+
+async fn consume(bus: Arc<dyn EventBus>, pool: PgPool) {
+    let mut stream = match bus.subscribe(SUBJECT).await {
+        Ok(s) => s,
+        Err(e) => {
+            tracing::error!("SFG: failed to subscribe to {}: {}", SUBJECT, e);
+            return;
+        }
+    };
+
+    while let Some(msg) = stream.next().await {
+        if let Err(e) = process_message(&msg, &pool).await {
+            tracing::error!("SFG: spc_violation_detected processing error: {}", e);
+        }
+    }
+}
+
+async fn process_message(
+    msg: &event_bus::BusMessage,
+    pool: &PgPool,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let envelope: EventEnvelope<SpcViolationDetectedPayload> =
+        serde_json::from_slice(&msg.payload)?;
+    process_spc_violation(pool, envelope.event_id, &envelope.payload).await?;
+    Ok(())
+}
+
+/// Place the hold. Returns `None` when no hold was requested, the inspection
+/// has no work order, the work order already has an active SPC hold, or this
+/// event already placed one.
+pub async fn process_spc_violation(
+    pool: &PgPool,
+    event_id: Uuid,
+    payload: &SpcViolationDetectedPayload,
+) -> Result<Option<TravelerHold>, ApiError> {
+    let Some(work_order_id) = payload.wo_id.filter(|_| payload.hold_requested) else {
+        return Ok(None);
+    };
+
+    let hold = holds_service::place_sourced_hold(
+        pool,
+        &payload.tenant_id,
+        SYSTEM_ACTOR,
+        PlaceHoldRequest {
+            hold_type: "quality".to_string(),
+            scope: "work_order".to_string(),
+            work_order_id,
+            operation_id: None,
+            reason: format!(
+                "{} on '{}' ({} chart): {} — inspection {}",
+                REASON_PREFIX,
+                payload.characteristic,
+                payload.chart_type,
+                payload.rules.join(", "),
+                payload.inspection_id
+            ),
+            release_authority: Some("quality".to_string()),
+        },
+        &HoldSource {
+            key: HOLD_SOURCE_SPC,
+            event_id,
+        },
+    )
+    .await?;
+    let Some(hold) = hold else {
+        tracing::info!(
+            work_order_id = %work_order_id,
+            chart_id = %payload.chart_id,
+            rules = ?payload.rules,
+            "SFG: work order already on SPC hold — violation logged only"
+        );
+        return Ok(None);
+    };
+
+    tracing::info!(
+        work_order_id = %work_order_id,
+        hold_id = %hold.id,
+        chart_id = %payload.chart_id,
+        "SFG: SPC violation hold placed"
+    );
+    Ok(Some(hold))
+}
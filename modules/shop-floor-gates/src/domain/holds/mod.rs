@@ -39,6 +39,15 @@ pub struct PlaceHoldRequest {
     pub release_authority: Option<String>,
 }
 
+/// What placed a system hold; see `service::place_sourced_hold`.
+#[derive(Debug, Clone, Copy)]
+pub struct HoldSource<'a> {
+    /// Kind of automatic hold, e.g. `spc`. One active hold per work order and key.
+    pub key: &'a str,
+    /// Triggering event. An event places at most one hold.
+    pub event_id: Uuid,
+}
+
 #[derive(Debug, Deserialize)]
 pub struct ReleaseHoldRequest {
     pub release_notes: Option<String>,
@@ -7,7 +7,8 @@ use crate::outbox::enqueue_event_tx;
 use platform_http_contracts::ApiError;
 
 use super::{
-    repo, CancelHoldRequest, ListHoldsQuery, PlaceHoldRequest, ReleaseHoldRequest, TravelerHold,
+    repo, CancelHoldRequest, HoldSource, ListHoldsQuery, PlaceHoldRequest, ReleaseHoldRequest,
+    TravelerHold,
 };
 
 const VALID_HOLD_TYPES: &[&str] = &["quality", "engineering", "material", "customer", "other"];
@@ -34,6 +35,33 @@ pub async fn place_hold(
     placed_by: Uuid,
     req: PlaceHoldRequest,
 ) -> Result<TravelerHold, ApiError> {
+    place(pool, tenant_id, placed_by, req, None)
+        .await?
+        .ok_or_else(|| ApiError::internal("hold was not placed"))
+}
+
+/// Place a hold on behalf of an event consumer.
+///
+/// Returns `None` when the work order already has an active hold for
+/// `source.key`, or `source.event_id` already placed a hold; both are enforced
+/// by unique indexes, so concurrent or redelivered events cannot double-hold.
+pub async fn place_sourced_hold(
+    pool: &PgPool,
+    tenant_id: &str,
+    placed_by: Uuid,
+    req: PlaceHoldRequest,
+    source: &HoldSource<'_>,
+) -> Result<Option<TravelerHold>, ApiError> {
+    place(pool, tenant_id, placed_by, req, Some(source)).await
+}
+
+async fn place(
+    pool: &PgPool,
+    tenant_id: &str,
+    placed_by: Uuid,
+    req: PlaceHoldRequest,
+    source: Option<&HoldSource<'_>>,
+) -> Result<Option<TravelerHold>, ApiError> {
     if !VALID_HOLD_TYPES.contains(&req.hold_type.as_str()) {
         return Err(ApiError::bad_request(format!(
             "Invalid hold_type: {}",
@@ -88,11 +116,12 @@ pub async fn place_hold(
         .await
         .map_err(|e| ApiError::internal(e.to_string()))?;
 
-    sqlx::query(
+    let inserted = sqlx::query(
         r#"INSERT INTO traveler_holds
            (id, tenant_id, hold_number, hold_type, scope, work_order_id, operation_id,
-            reason, status, release_authority, placed_by, placed_at, created_at, updated_at)
-           VALUES ($1,$2,$3,$4,$5,$6,$7,$8,$9,$10,$11,$12,$13,$14)"#,
+            reason, status, release_authority, placed_by, placed_at, created_at, updated_at,
+            source_key, source_event_id)
+           VALUES ($1,$2,$3,$4,$5,$6,$7,$8,$9,$10,$11,$12,$13,$14,$15,$16)"#,
     )
     .bind(hold.id)
     .bind(&hold.tenant_id)
@@ -108,9 +137,20 @@ pub async fn place_hold(
     .bind(hold.placed_at)
     .bind(hold.created_at)
     .bind(hold.updated_at)
+    .bind(source.map(|s| s.key))
+    .bind(source.map(|s| s.event_id))
     .execute(&mut *tx)
-    .await
-    .map_err(|e| ApiError::internal(e.to_string()))?;
+    .await;
+    match inserted {
+        Ok(_) => {}
+        Err(e) if source.is_some() && is_source_conflict(&e) => {
+            tx.rollback()
+                .await
+                .map_err(|e| ApiError::internal(e.to_string()))?;
+            return Ok(None);
+        }
+        Err(e) => return Err(ApiError::internal(e.to_string())),
+    }
 
     let payload = serde_json::to_value(HoldPlacedPayload {
         tenant_id: hold.tenant_id.clone(),
@@ -139,7 +179,18 @@ pub async fn place_hold(
     tx.commit()
         .await
         .map_err(|e| ApiError::internal(e.to_string()))?;
-    Ok(hold)
+    Ok(Some(hold))
+}
+
+fn is_source_conflict(e: &sqlx::Error) -> bool {
+    matches!(
+        e,
+        sqlx::Error::Database(dbe)
+            if matches!(
+                dbe.constraint(),
+                Some("idx_traveler_holds_active_source" | "idx_traveler_holds_source_event")
+            )
+    )
 }
 
 pub async fn release_hold(
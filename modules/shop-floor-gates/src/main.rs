@@ -29,9 +29,10 @@ async fn main() {
                     ctx.pool().clone(),
                 );
                 consumers::operation_completed::start_operation_completed_consumer(
-                    bus,
+                    bus.clone(),
                     ctx.pool().clone(),
                 );
+                consumers::spc_violation::start_spc_violation_consumer(bus, ctx.pool().clone());
                 tracing::info!("SFG: event consumers started");
             }
 
@@ -0,0 +1,80 @@
+-- Timekeeping: Pay Rule Sets (overtime, double-time, shift differential, meal breaks)
+--
+-- A pay rule set classifies approved minutes into regular / overtime /
+-- double-time buckets and computes shift-differential premium minutes.
+-- Rule sets apply per employee group: `employee_group` matches
+-- tk_employees.department. A rule set with employee_group = NULL is the
+-- tenant default, used for employees whose department has no rule set.
+--
+-- Employees with no applicable rule set are classified as all-regular.
+-- All thresholds are integer minutes; multipliers are basis points
+-- (15000 = 1.5x) so labor cost math stays in integers.
+
+-- ============================================================
+-- PAY RULE SETS
+-- ============================================================
+
+CREATE TABLE tk_pay_rule_sets (
+    id                       UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    app_id                   VARCHAR(50) NOT NULL,
+    name                     VARCHAR(100) NOT NULL,
+    -- Matches tk_employees.department; NULL = tenant default rule set
+    employee_group           VARCHAR(255),
+    -- Daily thresholds: minutes beyond daily_ot are OT, beyond daily_dt are DT
+    daily_ot_after_minutes   INT CHECK (daily_ot_after_minutes > 0),
+    daily_dt_after_minutes   INT CHECK (daily_dt_after_minutes > 0),
+    -- Weekly threshold: regular minutes beyond this in a work week become OT
+    weekly_ot_after_minutes  INT CHECK (weekly_ot_after_minutes > 0),
+    -- ISO weekday the work week starts on (1 = Monday … 7 = Sunday)
+    week_start_day           SMALLINT NOT NULL DEFAULT 1
+                             CHECK (week_start_day BETWEEN 1 AND 7),
+    -- ISO weekdays on which all worked minutes are double-time (e.g. {7} = Sundays)
+    double_time_weekdays     SMALLINT[] NOT NULL DEFAULT '{}',
+    ot_multiplier_bp         INT NOT NULL DEFAULT 15000 CHECK (ot_multiplier_bp >= 10000),
+    dt_multiplier_bp         INT NOT NULL DEFAULT 20000 CHECK (dt_multiplier_bp >= 10000),
+    -- Meal break auto-deduction: days worked beyond the threshold lose meal_break_minutes
+    meal_break_after_minutes INT CHECK (meal_break_after_minutes > 0),
+    meal_break_minutes       INT NOT NULL DEFAULT 0 CHECK (meal_break_minutes >= 0),
+    -- IANA zone used to place clock sessions into shift-differential windows
+    timezone                 VARCHAR(64) NOT NULL DEFAULT 'UTC',
+    is_active                BOOLEAN NOT NULL DEFAULT TRUE,
+    created_at               TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at               TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+
+    CONSTRAINT tk_pay_rule_sets_app_name_unique UNIQUE (app_id, name),
+    CONSTRAINT tk_pay_rule_sets_dt_after_ot
+        CHECK (daily_dt_after_minutes IS NULL
+               OR daily_ot_after_minutes IS NULL
+               OR daily_dt_after_minutes >= daily_ot_after_minutes)
+);
+
+-- At most one active rule set per employee group (and one active default)
+CREATE UNIQUE INDEX tk_pay_rule_sets_one_active_per_group
+    ON tk_pay_rule_sets (app_id, COALESCE(employee_group, ''))
+    WHERE is_active = TRUE;
+
+CREATE INDEX tk_pay_rule_sets_app_id
+    ON tk_pay_rule_sets (app_id);
+
+-- ============================================================
+-- SHIFT DIFFERENTIALS (premium windows within a rule set)
+-- ============================================================
+--
+-- Clock-session minutes that fall inside [start_time, end_time) in the rule
+-- set's timezone earn premium_minor_per_hour on top of base pay. A window
+-- whose end_time <= start_time wraps past midnight (e.g. 22:00 → 06:00).
+
+CREATE TABLE tk_pay_rule_shift_differentials (
+    id                      UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    rule_set_id             UUID NOT NULL REFERENCES tk_pay_rule_sets(id) ON DELETE CASCADE,
+    name                    VARCHAR(100) NOT NULL,
+    start_time              TIME NOT NULL,
+    end_time                TIME NOT NULL,
+    premium_minor_per_hour  BIGINT NOT NULL CHECK (premium_minor_per_hour > 0),
+    created_at              TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+
+    CONSTRAINT tk_pay_rule_shift_diff_name_unique UNIQUE (rule_set_id, name)
+);
+
+CREATE INDEX tk_pay_rule_shift_differentials_rule_set
+    ON tk_pay_rule_shift_differentials (rule_set_id);
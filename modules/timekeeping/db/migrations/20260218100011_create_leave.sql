@@ -0,0 +1,172 @@
+-- Timekeeping: Paid Time Off — leave types, accrual policies, holiday
+-- calendars, leave requests and the per-employee balance ledger.
+--
+-- Balances are never stored directly. tk_leave_ledger is append-only:
+-- accruals, front-loaded grants, carryover forfeits and approved usage are
+-- signed minute rows with an effective_date, so a balance "as of" any date
+-- is SUM(minutes) WHERE effective_date <= as_of.
+--
+-- Leave requests reuse tk_approval_status and the timesheet approval state
+-- machine: submitted → approved / rejected, submitted → draft (recall).
+-- Policies and holiday calendars apply per employee group (matched against
+-- tk_employees.department); a NULL group is the tenant default.
+
+-- ============================================================
+-- LEAVE TYPES
+-- ============================================================
+
+CREATE TYPE tk_leave_category AS ENUM ('vacation', 'sick', 'holiday', 'other');
+
+CREATE TABLE tk_leave_types (
+    id              UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    app_id          VARCHAR(50) NOT NULL,
+    code            VARCHAR(50) NOT NULL,
+    name            VARCHAR(255) NOT NULL,
+    category        tk_leave_category NOT NULL,
+    -- Balance-tracked types require sufficient balance before approval
+    tracks_balance  BOOLEAN NOT NULL DEFAULT TRUE,
+    is_paid         BOOLEAN NOT NULL DEFAULT TRUE,
+    active          BOOLEAN NOT NULL DEFAULT TRUE,
+    created_at      TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+
+    CONSTRAINT tk_leave_types_app_code_unique UNIQUE (app_id, code)
+);
+
+CREATE INDEX tk_leave_types_app_id ON tk_leave_types(app_id);
+
+-- ============================================================
+-- ACCRUAL POLICIES
+-- ============================================================
+
+CREATE TYPE tk_accrual_method AS ENUM ('per_hour_worked', 'per_pay_period', 'front_loaded');
+
+CREATE TABLE tk_leave_accrual_policies (
+    id                    UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    app_id                VARCHAR(50) NOT NULL,
+    leave_type_id         UUID NOT NULL REFERENCES tk_leave_types(id) ON DELETE RESTRICT,
+    name                  VARCHAR(100) NOT NULL,
+    employee_group        VARCHAR(255),           -- NULL = tenant default
+    method                tk_accrual_method NOT NULL,
+    -- per_hour_worked: accrual_minutes per per_worked_minutes of approved time
+    -- per_pay_period:  accrual_minutes per accrual run
+    -- front_loaded:    accrual_minutes granted once per calendar year
+    accrual_minutes       INT NOT NULL CHECK (accrual_minutes > 0),
+    per_worked_minutes    INT CHECK (per_worked_minutes > 0),
+    cap_minutes           INT CHECK (cap_minutes > 0),          -- NULL = no cap
+    carryover_max_minutes INT CHECK (carryover_max_minutes >= 0), -- NULL = unlimited
+    active                BOOLEAN NOT NULL DEFAULT TRUE,
+    created_at            TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+
+    CONSTRAINT tk_leave_policies_app_name_unique UNIQUE (app_id, name),
+    CONSTRAINT tk_leave_policies_per_hour_requires_basis
+        CHECK (method <> 'per_hour_worked' OR per_worked_minutes IS NOT NULL)
+);
+
+CREATE UNIQUE INDEX tk_leave_policies_one_active_per_group
+    ON tk_leave_accrual_policies (app_id, leave_type_id, COALESCE(employee_group, ''))
+    WHERE active = TRUE;
+
+-- ============================================================
+-- HOLIDAY CALENDARS
+-- ============================================================
+
+CREATE TABLE tk_holiday_calendars (
+    id              UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    app_id          VARCHAR(50) NOT NULL,
+    name            VARCHAR(100) NOT NULL,
+    employee_group  VARCHAR(255),                 -- NULL = tenant default
+    -- Leave type used when holiday hours are paid out in the payroll export
+    leave_type_id   UUID REFERENCES tk_leave_types(id) ON DELETE RESTRICT,
+    active          BOOLEAN NOT NULL DEFAULT TRUE,
+    created_at      TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+
+    CONSTRAINT tk_holiday_calendars_app_name_unique UNIQUE (app_id, name)
+);
+
+CREATE UNIQUE INDEX tk_holiday_calendars_one_active_per_group
+    ON tk_holiday_calendars (app_id, COALESCE(employee_group, ''))
+    WHERE active = TRUE;
+
+CREATE TABLE tk_holidays (
+    id            UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    calendar_id   UUID NOT NULL REFERENCES tk_holiday_calendars(id) ON DELETE CASCADE,
+    holiday_date  DATE NOT NULL,
+    name          VARCHAR(255) NOT NULL,
+    paid_minutes  INT NOT NULL DEFAULT 480 CHECK (paid_minutes >= 0),
+
+    CONSTRAINT tk_holidays_calendar_date_unique UNIQUE (calendar_id, holiday_date)
+);
+
+-- ============================================================
+-- LEAVE REQUESTS
+-- ============================================================
+
+CREATE TABLE tk_leave_requests (
+    id              UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    app_id          VARCHAR(50) NOT NULL,
+    employee_id     UUID NOT NULL REFERENCES tk_employees(id) ON DELETE RESTRICT,
+    leave_type_id   UUID NOT NULL REFERENCES tk_leave_types(id) ON DELETE RESTRICT,
+    start_date      DATE NOT NULL,
+    end_date        DATE NOT NULL,
+    total_minutes   INT NOT NULL CHECK (total_minutes > 0),
+    reason          TEXT,
+    status          tk_approval_status NOT NULL DEFAULT 'submitted',
+    submitted_at    TIMESTAMPTZ,
+    reviewed_at     TIMESTAMPTZ,
+    reviewer_id     UUID,
+    reviewer_notes  TEXT,
+    created_at      TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at      TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+
+    CONSTRAINT tk_leave_requests_dates CHECK (end_date >= start_date)
+);
+
+CREATE INDEX tk_leave_requests_employee
+    ON tk_leave_requests(app_id, employee_id, start_date);
+CREATE INDEX tk_leave_requests_status
+    ON tk_leave_requests(app_id, status);
+
+-- Per-day breakdown fixed at submission (weekends and holidays excluded)
+CREATE TABLE tk_leave_request_days (
+    request_id  UUID NOT NULL REFERENCES tk_leave_requests(id) ON DELETE CASCADE,
+    leave_date  DATE NOT NULL,
+    minutes     INT NOT NULL CHECK (minutes > 0),
+
+    PRIMARY KEY (request_id, leave_date)
+);
+
+CREATE TABLE tk_leave_request_actions (
+    id          BIGSERIAL PRIMARY KEY,
+    request_id  UUID NOT NULL REFERENCES tk_leave_requests(id) ON DELETE CASCADE,
+    action      VARCHAR(50) NOT NULL,      -- 'submit', 'approve', 'reject', 'recall'
+    actor_id    UUID NOT NULL,
+    notes       TEXT,
+    created_at  TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX tk_leave_request_actions_request
+    ON tk_leave_request_actions(request_id);
+
+-- ============================================================
+-- BALANCE LEDGER (append-only)
+-- ============================================================
+
+CREATE TABLE tk_leave_ledger (
+    id              BIGSERIAL PRIMARY KEY,
+    app_id          VARCHAR(50) NOT NULL,
+    employee_id     UUID NOT NULL REFERENCES tk_employees(id) ON DELETE RESTRICT,
+    leave_type_id   UUID NOT NULL REFERENCES tk_leave_types(id) ON DELETE RESTRICT,
+    entry_kind      VARCHAR(20) NOT NULL
+                    CHECK (entry_kind IN ('accrual', 'front_load', 'forfeit', 'usage', 'adjustment')),
+    minutes         INT NOT NULL,              -- signed: usage/forfeit are negative
+    effective_date  DATE NOT NULL,
+    -- Idempotency: accrual period, year, or leave request id
+    source_ref      VARCHAR(100) NOT NULL,
+    created_at      TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+
+    CONSTRAINT tk_leave_ledger_source_unique
+        UNIQUE (app_id, employee_id, leave_type_id, entry_kind, source_ref)
+);
+
+CREATE INDEX tk_leave_ledger_balance
+    ON tk_leave_ledger(app_id, employee_id, leave_type_id, effective_date);
@@ -22,6 +22,20 @@ pub enum ApprovalStatus {
     Rejected,
 }
 
+impl ApprovalStatus {
+    /// Guard for review transitions (approve / reject / recall): only a
+    /// submitted request may move on. Shared by timesheet and leave approvals.
+    pub fn ensure_submitted(&self, to: &str) -> Result<(), ApprovalError> {
+        if *self != ApprovalStatus::Submitted {
+            return Err(ApprovalError::InvalidTransition {
+                from: format!("{:?}", self),
+                to: to.into(),
+            });
+        }
+        Ok(())
+    }
+}
+
 // ============================================================================
 // Domain models
 // ============================================================================
@@ -127,6 +141,23 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn only_submitted_may_be_reviewed() {
+        assert!(ApprovalStatus::Submitted
+            .ensure_submitted("approved")
+            .is_ok());
+        for status in [
+            ApprovalStatus::Draft,
+            ApprovalStatus::Approved,
+            ApprovalStatus::Rejected,
+        ] {
+            assert!(matches!(
+                status.ensure_submitted("approved"),
+                Err(ApprovalError::InvalidTransition { .. })
+            ));
+        }
+    }
+
     #[test]
     fn submit_request_deserialize() -> Result<(), Box<dyn std::error::Error>> {
         let json = r#"{
@@ -137,12 +137,7 @@ pub async fn approve(
     let mut tx = pool.begin().await?;
 
     let current = repo::fetch_for_update(&mut *tx, &req.app_id, req.approval_id).await?;
-    if current.status != ApprovalStatus::Submitted {
-        return Err(ApprovalError::InvalidTransition {
-            from: format!("{:?}", current.status),
-            to: "approved".into(),
-        });
-    }
+    current.status.ensure_submitted("approved")?;
 
     let approval = repo::update_to_approved(
         &mut *tx,
@@ -198,12 +193,7 @@ pub async fn reject(
     let mut tx = pool.begin().await?;
 
     let current = repo::fetch_for_update(&mut *tx, &req.app_id, req.approval_id).await?;
-    if current.status != ApprovalStatus::Submitted {
-        return Err(ApprovalError::InvalidTransition {
-            from: format!("{:?}", current.status),
-            to: "rejected".into(),
-        });
-    }
+    current.status.ensure_submitted("rejected")?;
 
     let approval = repo::update_to_rejected(
         &mut *tx,
@@ -259,12 +249,7 @@ pub async fn recall(
     let mut tx = pool.begin().await?;
 
     let current = repo::fetch_for_update(&mut *tx, &req.app_id, req.approval_id).await?;
-    if current.status != ApprovalStatus::Submitted {
-        return Err(ApprovalError::InvalidTransition {
-            from: format!("{:?}", current.status),
-            to: "draft".into(),
-        });
-    }
+    current.status.ensure_submitted("draft")?;
 
     let approval = repo::update_to_recalled(&mut *tx, req.approval_id, now).await?;
 
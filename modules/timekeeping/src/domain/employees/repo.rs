@@ -1,5 +1,6 @@
 //! Employee repository — CRUD operations against tk_employees.
 
+use security::ScopeSql;
 use sqlx::PgPool;
 use uuid::Uuid;
 
@@ -134,30 +135,31 @@ impl EmployeeRepo {
         app_id: &str,
         active_only: bool,
     ) -> Result<Vec<Employee>, EmployeeError> {
-        if active_only {
-            sqlx::query_as::<_, Employee>(
-                r#"
-                SELECT * FROM tk_employees
-                WHERE app_id = $1 AND active = TRUE
-                ORDER BY last_name, first_name
-                "#,
-            )
-            .bind(app_id)
-            .fetch_all(pool)
-            .await
-            .map_err(EmployeeError::Database)
-        } else {
-            sqlx::query_as::<_, Employee>(
-                r#"
-                SELECT * FROM tk_employees
-                WHERE app_id = $1
-                ORDER BY last_name, first_name
-                "#,
-            )
+        Self::list_scoped(pool, app_id, active_only, &ScopeSql::unrestricted()).await
+    }
+
+    /// [`Self::list`] restricted to the caller's data scope. Build `scope`
+    /// with `ScopeFilter::to_sql(3)`, mapping `department` to `department`.
+    pub async fn list_scoped(
+        pool: &PgPool,
+        app_id: &str,
+        active_only: bool,
+        scope: &ScopeSql,
+    ) -> Result<Vec<Employee>, EmployeeError> {
+        let sql = format!(
+            r#"
+            SELECT * FROM tk_employees
+            WHERE app_id = $1 AND ($2 = FALSE OR active = TRUE) AND {}
+            ORDER BY last_name, first_name
+            "#,
+            scope.clause
+        );
+        let mut query = sqlx::query_as::<_, Employee>(&sql)
             .bind(app_id)
-            .fetch_all(pool)
-            .await
-            .map_err(EmployeeError::Database)
+            .bind(active_only);
+        for values in &scope.binds {
+            query = query.bind(values);
         }
+        query.fetch_all(pool).await.map_err(EmployeeError::Database)
     }
 }
@@ -2,11 +2,14 @@
 //!
 //! Rows are sorted by (work_date, employee_id, entry_id) for stable output.
 //! The same input always produces the same CSV byte-for-byte.
+//! Pay bucket columns carry the pay rule classification for each entry.
+//! `leave_type` is empty for worked time and holds the leave code for leave rows.
 
 use super::models::ExportEntry;
 
 const CSV_HEADER: &str = "entry_id,employee_id,employee_name,project_id,\
-project_name,task_id,work_date,minutes,hours,description";
+project_name,task_id,work_date,minutes,hours,description,regular_minutes,\
+overtime_minutes,double_time_minutes,premium_minutes,premium_minor,meal_break_minutes,leave_type";
 
 /// Generate a deterministic CSV string from sorted export entries.
 pub fn generate(entries: &[ExportEntry]) -> String {
@@ -35,6 +38,19 @@ pub fn generate(entries: &[ExportEntry]) -> String {
         buf.push_str(&hours);
         buf.push(',');
         push_csv_field(&mut buf, e.description.as_deref().unwrap_or(""));
+        for n in [
+            e.pay.regular_minutes as i64,
+            e.pay.overtime_minutes as i64,
+            e.pay.double_time_minutes as i64,
+            e.pay.premium_minutes as i64,
+            e.pay.premium_minor,
+            e.pay.meal_break_deducted_minutes as i64,
+        ] {
+            buf.push(',');
+            buf.push_str(&n.to_string());
+        }
+        buf.push(',');
+        push_csv_field(&mut buf, e.leave_type.as_deref().unwrap_or(""));
         buf.push('\n');
     }
 
@@ -58,6 +74,7 @@ fn push_csv_field(buf: &mut String, val: &str) {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::domain::pay_rules::models::PayBuckets;
     use chrono::NaiveDate;
     use uuid::Uuid;
 
@@ -73,6 +90,12 @@ mod tests {
                 work_date: NaiveDate::from_ymd_opt(2026, 2, 1).unwrap(),
                 minutes: 480,
                 description: Some("Development work".into()),
+                pay: PayBuckets {
+                    regular_minutes: 450,
+                    meal_break_deducted_minutes: 30,
+                    ..Default::default()
+                },
+                leave_type: None,
             },
             ExportEntry {
                 entry_id: Uuid::parse_str("00000000-0000-0000-0000-000000000002").unwrap(),
@@ -84,6 +107,8 @@ mod tests {
                 work_date: NaiveDate::from_ymd_opt(2026, 2, 2).unwrap(),
                 minutes: 60,
                 description: Some("Meeting, with \"quotes\"".into()),
+                pay: PayBuckets::default(),
+                leave_type: None,
             },
         ]
     }
@@ -115,6 +140,24 @@ mod tests {
         assert!(csv.contains("\"Meeting, with \"\"quotes\"\"\""));
     }
 
+    #[test]
+    fn csv_includes_pay_buckets() {
+        let csv = generate(&sample_entries());
+        let header_cols = csv.lines().next().unwrap().split(',').count();
+        let row = csv.lines().nth(1).unwrap();
+        assert_eq!(row.split(',').count(), header_cols);
+        assert!(row.ends_with(",450,0,0,0,0,30,"));
+    }
+
+    #[test]
+    fn csv_leave_row_carries_leave_type() {
+        let mut entries = sample_entries();
+        entries[1].leave_type = Some("VAC".into());
+        let csv = generate(&entries);
+        assert!(csv.lines().next().unwrap().ends_with(",leave_type"));
+        assert!(csv.lines().nth(2).unwrap().ends_with(",0,0,0,0,0,0,VAC"));
+    }
+
     #[test]
     fn csv_empty_entries() {
         let csv = generate(&[]);
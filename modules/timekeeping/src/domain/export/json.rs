@@ -2,11 +2,14 @@
 //!
 //! Produces a structured JSON object with metadata + line items.
 //! Keys are sorted for deterministic serialization.
+//! Each line item carries its pay buckets; totals are summed per bucket.
+//! Leave rows carry `leave_type` and are totalled in `total_leave_minutes`.
 
 use chrono::NaiveDate;
 use serde_json::{json, Value};
 
 use super::models::ExportEntry;
+use crate::domain::pay_rules::models::PayBuckets;
 
 /// Generate a deterministic JSON export payload.
 pub fn generate(
@@ -18,6 +21,14 @@ pub fn generate(
 ) -> Value {
     let total_minutes: i64 = entries.iter().map(|e| e.minutes as i64).sum();
     let total_hours = format!("{:.2}", total_minutes as f64 / 60.0);
+    let mut totals = PayBuckets::default();
+    let mut total_leave_minutes: i64 = 0;
+    for e in entries {
+        totals.add(&e.pay);
+        if e.leave_type.is_some() {
+            total_leave_minutes += e.minutes as i64;
+        }
+    }
 
     let line_items: Vec<Value> = entries
         .iter()
@@ -33,6 +44,13 @@ pub fn generate(
                 "minutes": e.minutes,
                 "hours": format!("{:.2}", e.minutes as f64 / 60.0),
                 "description": e.description,
+                "regular_minutes": e.pay.regular_minutes,
+                "overtime_minutes": e.pay.overtime_minutes,
+                "double_time_minutes": e.pay.double_time_minutes,
+                "premium_minutes": e.pay.premium_minutes,
+                "premium_minor": e.pay.premium_minor,
+                "meal_break_minutes": e.pay.meal_break_deducted_minutes,
+                "leave_type": e.leave_type,
             })
         })
         .collect();
@@ -45,6 +63,13 @@ pub fn generate(
         "record_count": entries.len(),
         "total_minutes": total_minutes,
         "total_hours": total_hours,
+        "total_regular_minutes": totals.regular_minutes,
+        "total_overtime_minutes": totals.overtime_minutes,
+        "total_double_time_minutes": totals.double_time_minutes,
+        "total_premium_minutes": totals.premium_minutes,
+        "total_premium_minor": totals.premium_minor,
+        "total_meal_break_minutes": totals.meal_break_deducted_minutes,
+        "total_leave_minutes": total_leave_minutes,
         "line_items": line_items,
     })
 }
@@ -65,6 +90,12 @@ mod tests {
             work_date: NaiveDate::from_ymd_opt(2026, 2, 1).unwrap(),
             minutes: 480,
             description: Some("Dev".into()),
+            pay: PayBuckets {
+                regular_minutes: 420,
+                overtime_minutes: 60,
+                ..Default::default()
+            },
+            leave_type: None,
         }]
     }
 
@@ -82,6 +113,9 @@ mod tests {
         assert_eq!(payload["record_count"], 1);
         assert_eq!(payload["total_minutes"], 480);
         assert!(payload["line_items"].is_array());
+        assert_eq!(payload["total_regular_minutes"], 420);
+        assert_eq!(payload["total_overtime_minutes"], 60);
+        assert_eq!(payload["line_items"][0]["overtime_minutes"], 60);
     }
 
     #[test]
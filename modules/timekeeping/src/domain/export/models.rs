@@ -9,6 +9,9 @@ use thiserror::Error;
 use utoipa::ToSchema;
 use uuid::Uuid;
 
+use crate::domain::leave::models::{LeaveError, LeaveExportLine};
+use crate::domain::pay_rules::models::{PayBuckets, PayRuleError};
+
 // ============================================================================
 // Export status enum (mirrors tk_export_status in DB)
 // ============================================================================
@@ -44,6 +47,10 @@ pub struct ExportRun {
 }
 
 /// Flattened entry row used for export generation.
+///
+/// `pay` is filled in by the pay rule engine after the row is loaded.
+/// Paid leave days are exported as rows with `leave_type` set; their minutes
+/// are not classified into pay buckets.
 #[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
 pub struct ExportEntry {
     pub entry_id: Uuid,
@@ -55,6 +62,11 @@ pub struct ExportEntry {
     pub work_date: NaiveDate,
     pub minutes: i32,
     pub description: Option<String>,
+    #[sqlx(skip)]
+    #[serde(flatten)]
+    pub pay: PayBuckets,
+    #[sqlx(skip)]
+    pub leave_type: Option<String>,
 }
 
 /// Generated export artifacts returned to the caller.
@@ -100,7 +112,7 @@ pub enum ExportError {
     #[error("Validation error: {0}")]
     Validation(String),
 
-    #[error("No approved entries found for this period")]
+    #[error("No approved entries or leave found for this period")]
     NoApprovedEntries,
 
     #[error("Idempotent replay: export with same content already exists")]
@@ -110,6 +122,42 @@ pub enum ExportError {
     Database(#[from] sqlx::Error),
 }
 
+impl From<PayRuleError> for ExportError {
+    fn from(err: PayRuleError) -> Self {
+        match err {
+            PayRuleError::Database(e) => ExportError::Database(e),
+            other => ExportError::Validation(other.to_string()),
+        }
+    }
+}
+
+impl From<LeaveError> for ExportError {
+    fn from(err: LeaveError) -> Self {
+        match err {
+            LeaveError::Database(e) => ExportError::Database(e),
+            other => ExportError::Validation(other.to_string()),
+        }
+    }
+}
+
+impl From<LeaveExportLine> for ExportEntry {
+    fn from(line: LeaveExportLine) -> Self {
+        ExportEntry {
+            entry_id: line.source_id,
+            employee_id: line.employee_id,
+            employee_name: line.employee_name,
+            project_id: None,
+            project_name: None,
+            task_id: None,
+            work_date: line.leave_date,
+            minutes: line.minutes,
+            description: Some(line.description),
+            pay: PayBuckets::default(),
+            leave_type: Some(line.leave_type_code),
+        }
+    }
+}
+
 // ============================================================================
 // Tests
 // ============================================================================
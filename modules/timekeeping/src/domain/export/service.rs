@@ -2,6 +2,9 @@
 //!
 //! Creates export runs from approved timesheet entries.
 //! Generates deterministic CSV + JSON artifacts and stores a content hash.
+//! Each entry is classified by the pay rule engine before generation, so the
+//! artifacts carry regular / OT / DT / premium buckets.
+//! Approved leave days and calendar holidays are appended as leave rows.
 //! Re-running the same export with unchanged data yields the same hash.
 
 use chrono::Utc;
@@ -11,6 +14,8 @@ use uuid::Uuid;
 
 use super::repo;
 use super::{csv, json, models::*};
+use crate::domain::leave::service as leave;
+use crate::domain::pay_rules::{engine::EntryMinutes, service as pay_rules};
 use crate::events;
 
 const EVT_EXPORT_COMPLETED: &str = "export_run.completed";
@@ -46,12 +51,41 @@ pub async fn create_export_run(
     let now = Utc::now();
 
     // Fetch approved entries for the period (deterministic order)
-    let entries =
+    let mut entries =
         repo::fetch_approved_entries(pool, &req.app_id, req.period_start, req.period_end).await?;
 
+    // Classify into pay buckets (regular / OT / DT / premium)
+    let inputs: Vec<EntryMinutes> = entries
+        .iter()
+        .map(|e| EntryMinutes {
+            entry_id: e.entry_id,
+            employee_id: e.employee_id,
+            work_date: e.work_date,
+            minutes: e.minutes,
+        })
+        .collect();
+    let buckets =
+        pay_rules::classify_entries(pool, &req.app_id, req.period_start, req.period_end, &inputs)
+            .await?;
+    for e in &mut entries {
+        if let Some(b) = buckets.get(&e.entry_id) {
+            e.pay = *b;
+        }
+    }
+
+    // Paid leave (approved requests + holidays); not part of OT classification
+    entries.extend(
+        leave::export_lines(pool, &req.app_id, req.period_start, req.period_end)
+            .await?
+            .into_iter()
+            .map(ExportEntry::from),
+    );
     if entries.is_empty() {
         return Err(ExportError::NoApprovedEntries);
     }
+    entries.sort_by(|a, b| {
+        (a.work_date, a.employee_id, a.entry_id).cmp(&(b.work_date, b.employee_id, b.entry_id))
+    });
 
     // Generate deterministic artifacts
     let csv_content = csv::generate(&entries);
@@ -4,14 +4,19 @@ use chrono::NaiveDate;
 use sqlx::PgPool;
 use uuid::Uuid;
 
-/// Row returned when querying approved time with employee rates.
+/// One approved entry joined with the employee's rate.
+///
+/// Rows are per entry (not pre-aggregated) so the pay rule engine can
+/// classify each entry before the service groups by (employee, project).
 #[derive(Debug, Clone, sqlx::FromRow)]
 pub struct LaborCostRow {
+    pub entry_id: Uuid,
     pub employee_id: Uuid,
     pub employee_name: String,
     pub project_id: Option<Uuid>,
     pub project_name: Option<String>,
-    pub total_minutes: i64,
+    pub work_date: NaiveDate,
+    pub minutes: i32,
     pub hourly_rate_minor: i64,
     pub currency: String,
 }
@@ -25,11 +30,13 @@ pub async fn fetch_labor_cost_rows(
     sqlx::query_as::<_, LaborCostRow>(
         r#"
         SELECT
+            e.entry_id,
             e.employee_id,
             COALESCE(emp.first_name || ' ' || emp.last_name, 'Unknown') AS employee_name,
             e.project_id,
             p.name AS project_name,
-            SUM(e.minutes)::BIGINT AS total_minutes,
+            e.work_date,
+            e.minutes,
             emp.hourly_rate_minor,
             emp.currency
         FROM tk_timesheet_entries e
@@ -48,10 +55,7 @@ pub async fn fetch_labor_cost_rows(
           AND e.work_date <= $3
           AND e.is_current = TRUE
           AND e.entry_type != 'void'
-        GROUP BY e.employee_id, emp.first_name, emp.last_name,
-                 e.project_id, p.name,
-                 emp.hourly_rate_minor, emp.currency
-        ORDER BY e.employee_id, e.project_id
+        ORDER BY e.employee_id, e.project_id, e.work_date, e.entry_id
         "#,
     )
     .bind(app_id)
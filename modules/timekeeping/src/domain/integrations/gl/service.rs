@@ -8,8 +8,11 @@
 //! CR  ACCRUED_LABOR   total_cost  ← accrued labor liability
 //! ```
 //!
-//! Cost is computed as `(minutes / 60.0) * hourly_rate_minor / 100.0`
-//! using the employee's configured `hourly_rate_minor`.
+//! Entries are first classified by the employee's pay rule set into
+//! regular / OT / DT / premium buckets. Cost is the base hourly rate applied
+//! to each bucket at the rule set's multipliers, plus shift-differential
+//! premium (see `PayBuckets::cost_minor`). Without a rule set all minutes
+//! are regular and cost is `(minutes * hourly_rate_minor) / 60`, truncated.
 //!
 //! ## Exactly-once Semantics
 //!
@@ -18,6 +21,8 @@
 //! the same period produces the same posting_id, which the GL consumer's
 //! `processed_events` table uses as the idempotency key.
 
+use std::collections::BTreeMap;
+
 use chrono::NaiveDate;
 use serde::{Deserialize, Serialize};
 use sqlx::PgPool;
@@ -26,6 +31,11 @@ use uuid::Uuid;
 
 use super::repo;
 pub use super::repo::LaborCostRow;
+use crate::domain::pay_rules::{
+    engine::EntryMinutes,
+    models::{PayBuckets, PayRuleError, PayRuleSet},
+    service as pay_rules,
+};
 use crate::events;
 
 // ============================================================================
@@ -35,6 +45,9 @@ use crate::events;
 /// Payload emitted to the outbox for GL consumption.
 ///
 /// The GL consumer deserializes this to build a `GlPostingRequestV1`.
+/// `total_minutes` is paid minutes (after meal-break deduction); the bucket
+/// fields break it down and `total_cost_minor` already includes OT/DT
+/// multipliers and shift premium.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct LaborCostPostingPayload {
     pub posting_id: Uuid,
@@ -50,6 +63,16 @@ pub struct LaborCostPostingPayload {
     pub currency: String,
     pub total_cost_minor: i64,
     pub posting_date: String,
+    #[serde(default)]
+    pub regular_minutes: i32,
+    #[serde(default)]
+    pub overtime_minutes: i32,
+    #[serde(default)]
+    pub double_time_minutes: i32,
+    #[serde(default)]
+    pub premium_minutes: i32,
+    #[serde(default)]
+    pub premium_minor: i64,
 }
 
 // ============================================================================
@@ -68,6 +91,15 @@ pub enum GlIntegrationError {
     Database(#[from] sqlx::Error),
 }
 
+impl From<PayRuleError> for GlIntegrationError {
+    fn from(err: PayRuleError) -> Self {
+        match err {
+            PayRuleError::Database(e) => GlIntegrationError::Database(e),
+            other => GlIntegrationError::Validation(other.to_string()),
+        }
+    }
+}
+
 // ============================================================================
 // Service
 // ============================================================================
@@ -81,9 +113,10 @@ const LABOR_COST_NS: Uuid = Uuid::from_bytes([
 
 /// Generate GL labor cost postings for approved time in a period.
 ///
-/// Queries approved entries joined with employee rates, groups by
-/// (employee, project), and enqueues a posting event per group into
-/// the outbox. Returns the list of posting payloads emitted.
+/// Queries approved entries joined with employee rates, classifies them
+/// through the pay rule engine, groups by (employee, project), and enqueues
+/// a posting event per group into the outbox. Returns the list of posting
+/// payloads emitted.
 pub async fn generate_labor_cost_postings(
     pool: &PgPool,
     app_id: &str,
@@ -101,19 +134,51 @@ pub async fn generate_labor_cost_postings(
         ));
     }
 
-    // Fetch approved entries grouped by employee + project, joined with rates
+    // Fetch approved entries joined with rates
     let rows = repo::fetch_labor_cost_rows(pool, app_id, period_start, period_end).await?;
     if rows.is_empty() {
         return Err(GlIntegrationError::NoEligibleEntries);
     }
 
+    // Classify into pay buckets and resolve multipliers per employee
+    let inputs: Vec<EntryMinutes> = rows
+        .iter()
+        .map(|r| EntryMinutes {
+            entry_id: r.entry_id,
+            employee_id: r.employee_id,
+            work_date: r.work_date,
+            minutes: r.minutes,
+        })
+        .collect();
+    let buckets =
+        pay_rules::classify_entries(pool, app_id, period_start, period_end, &inputs).await?;
+    let employee_ids: Vec<Uuid> = rows.iter().map(|r| r.employee_id).collect();
+    let rule_sets = pay_rules::resolve_employee_rule_sets(pool, app_id, &employee_ids).await?;
+
+    // Group by (employee, project)
+    let mut groups: BTreeMap<(Uuid, Option<Uuid>), (&LaborCostRow, PayBuckets)> = BTreeMap::new();
+    for row in &rows {
+        let entry_buckets = buckets.get(&row.entry_id).copied().unwrap_or(PayBuckets {
+            regular_minutes: row.minutes,
+            ..Default::default()
+        });
+        groups
+            .entry((row.employee_id, row.project_id))
+            .or_insert((row, PayBuckets::default()))
+            .1
+            .add(&entry_buckets);
+    }
+
     let posting_date = period_end.format("%Y-%m-%d").to_string();
-    let mut payloads = Vec::with_capacity(rows.len());
+    let mut payloads = Vec::with_capacity(groups.len());
     let mut tx = pool.begin().await?;
 
-    for row in &rows {
-        // Cost = (minutes / 60) * hourly_rate_minor (in minor units)
-        let total_cost_minor = (row.total_minutes * row.hourly_rate_minor) / 60;
+    for (row, totals) in groups.values() {
+        let total_cost_minor = accrued_cost_minor(
+            rule_sets.get(&row.employee_id),
+            totals,
+            row.hourly_rate_minor,
+        );
 
         // Deterministic posting ID: hash of (app_id, employee_id, project_id, period)
         let id_seed = format!(
@@ -135,11 +200,16 @@ pub async fn generate_labor_cost_postings(
             project_name: row.project_name.clone(),
             period_start,
             period_end,
-            total_minutes: row.total_minutes as i32,
+            total_minutes: totals.paid_minutes(),
             hourly_rate_minor: row.hourly_rate_minor,
             currency: row.currency.clone(),
             total_cost_minor,
             posting_date: posting_date.clone(),
+            regular_minutes: totals.regular_minutes,
+            overtime_minutes: totals.overtime_minutes,
+            double_time_minutes: totals.double_time_minutes,
+            premium_minutes: totals.premium_minutes,
+            premium_minor: totals.premium_minor,
         };
 
         events::enqueue_event_tx(
@@ -159,6 +229,23 @@ pub async fn generate_labor_cost_postings(
     Ok(payloads)
 }
 
+/// Accrued cost of a group's buckets.
+///
+/// With a rule set, buckets are priced by `PayBuckets::cost_minor`. Without
+/// one every minute is regular and cost keeps the original truncating
+/// `(minutes * hourly_rate_minor) / 60`, so accruals for tenants without pay
+/// rules are unchanged.
+fn accrued_cost_minor(
+    rules: Option<&PayRuleSet>,
+    totals: &PayBuckets,
+    hourly_rate_minor: i64,
+) -> i64 {
+    match rules {
+        Some(r) => totals.cost_minor(hourly_rate_minor, r.ot_multiplier_bp, r.dt_multiplier_bp),
+        None => (totals.paid_minutes() as i64 * hourly_rate_minor) / 60,
+    }
+}
+
 // ============================================================================
 // Tests
 // ============================================================================
@@ -200,10 +287,27 @@ mod tests {
             currency: "USD".into(),
             total_cost_minor: 200000,
             posting_date: "2026-02-07".into(),
+            regular_minutes: 2400,
+            overtime_minutes: 0,
+            double_time_minutes: 0,
+            premium_minutes: 0,
+            premium_minor: 0,
         };
         let json = serde_json::to_string(&payload).expect("test");
         let back: LaborCostPostingPayload = serde_json::from_str(&json).expect("test");
         assert_eq!(back.app_id, "acme");
         assert_eq!(back.total_cost_minor, 200000);
+        assert_eq!(back.regular_minutes, 2400);
+    }
+
+    #[test]
+    fn cost_without_rule_set_truncates_like_baseline() {
+        let totals = PayBuckets {
+            regular_minutes: 7,
+            ..Default::default()
+        };
+        // 7 * 1000 / 60 = 116.67: truncated without a rule set
+        assert_eq!(accrued_cost_minor(None, &totals, 1000), 116);
+        assert_eq!(totals.cost_minor(1000, 15000, 20000), 117);
     }
 }
@@ -0,0 +1,159 @@
+//! Accrual run — posts accruals, front-loaded grants and carryover forfeits
+//! to the leave ledger for one pay period.
+//!
+//! Runs are idempotent per (employee, leave type, kind, source_ref):
+//! - accrual:    source_ref = "<period_start>:<period_end>", effective period_end
+//! - front_load: source_ref = "<year>", effective Jan 1 (or period_start in
+//!   the first year a policy applies)
+//! - forfeit:    source_ref = "<year>", effective Jan 1, posted before that
+//!   year's grant so the carryover limit applies to the prior-year balance
+//!
+//! Caps are applied against the balance as of the posting date.
+
+use std::collections::{BTreeSet, HashMap};
+
+use chrono::{Datelike, NaiveDate};
+use sqlx::{PgConnection, PgPool};
+use uuid::Uuid;
+
+use super::calc;
+use super::models::*;
+use super::repo;
+
+pub async fn run_accruals(
+    pool: &PgPool,
+    req: &RunAccrualsRequest,
+) -> Result<AccrualRunResult, LeaveError> {
+    req.validate()?;
+
+    let app_id = req.app_id.as_str();
+    let (start, end) = (req.period_start, req.period_end);
+    let period_ref = format!("{}:{}", start, end);
+    let mut posted = Vec::new();
+
+    let mut tx = pool.begin().await?;
+
+    let policies = repo::list_active_policies(&mut tx, app_id).await?;
+    let leave_type_ids: BTreeSet<Uuid> = policies.iter().map(|p| p.leave_type_id).collect();
+    let employees = repo::list_active_employees(&mut tx, app_id).await?;
+    let worked: HashMap<Uuid, i64> = repo::sum_approved_minutes(&mut tx, app_id, start, end)
+        .await?
+        .into_iter()
+        .collect();
+
+    for (employee_id, group, _) in &employees {
+        for type_id in &leave_type_ids {
+            let Some(policy) = calc::resolve_for_group(
+                policies.iter().filter(|p| p.leave_type_id == *type_id),
+                group.as_deref(),
+                |p| p.employee_group.as_deref(),
+            ) else {
+                continue;
+            };
+            let mut post = Posting {
+                conn: &mut tx,
+                app_id,
+                employee_id: *employee_id,
+                policy,
+                posted: &mut posted,
+            };
+
+            for jan1 in calc::year_starts(start, end) {
+                let Some(prior) = jan1.pred_opt() else {
+                    continue;
+                };
+                let balance = post.balance(prior).await?;
+                let forfeit = calc::carryover_forfeit(balance, policy.carryover_max_minutes);
+                if forfeit > 0 {
+                    post.post("forfeit", -forfeit, jan1, &jan1.year().to_string())
+                        .await?;
+                }
+            }
+
+            match policy.method {
+                AccrualMethod::FrontLoaded => {
+                    for year in start.year()..=end.year() {
+                        let Some(jan1) = NaiveDate::from_ymd_opt(year, 1, 1) else {
+                            continue;
+                        };
+                        let effective = jan1.max(start);
+                        let balance = post.balance(effective).await?;
+                        let amount = calc::capped(
+                            balance,
+                            policy.accrual_minutes as i64,
+                            policy.cap_minutes,
+                        );
+                        // Posted even when capped to zero: marks the year as granted
+                        post.post("front_load", amount, effective, &year.to_string())
+                            .await?;
+                    }
+                }
+                AccrualMethod::PerHourWorked | AccrualMethod::PerPayPeriod => {
+                    let earned =
+                        calc::period_accrual(policy, worked.get(employee_id).copied().unwrap_or(0));
+                    let balance = post.balance(end).await?;
+                    let amount = calc::capped(balance, earned, policy.cap_minutes);
+                    if amount > 0 {
+                        post.post("accrual", amount, end, &period_ref).await?;
+                    }
+                }
+            }
+        }
+    }
+
+    tx.commit().await?;
+
+    Ok(AccrualRunResult {
+        period_start: start,
+        period_end: end,
+        posted,
+    })
+}
+
+/// Ledger writer for one employee under one policy.
+struct Posting<'a> {
+    conn: &'a mut PgConnection,
+    app_id: &'a str,
+    employee_id: Uuid,
+    policy: &'a AccrualPolicy,
+    posted: &'a mut Vec<LedgerEntry>,
+}
+
+impl Posting<'_> {
+    async fn balance(&mut self, as_of: NaiveDate) -> Result<i64, LeaveError> {
+        repo::balance_as_of(
+            self.conn,
+            self.app_id,
+            self.employee_id,
+            self.policy.leave_type_id,
+            as_of,
+        )
+        .await
+    }
+
+    async fn post(
+        &mut self,
+        kind: &str,
+        minutes: i64,
+        effective_date: NaiveDate,
+        source_ref: &str,
+    ) -> Result<(), LeaveError> {
+        let minutes = i32::try_from(minutes)
+            .map_err(|_| LeaveError::Validation("ledger amount out of range".into()))?;
+        if let Some(entry) = repo::insert_ledger_entry(
+            self.conn,
+            self.app_id,
+            self.employee_id,
+            self.policy.leave_type_id,
+            kind,
+            minutes,
+            effective_date,
+            source_ref,
+        )
+        .await?
+        {
+            self.posted.push(entry);
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,176 @@
+//! Pure leave calculations — working-day expansion, accrual amounts, caps
+//! and year-end carryover. No I/O; the service feeds balances in and posts
+//! the results to the ledger.
+
+use std::collections::HashSet;
+
+use chrono::{Datelike, NaiveDate, Weekday};
+
+use super::models::{AccrualMethod, AccrualPolicy, LeaveDay};
+
+/// Expand a date range into paid leave days, skipping weekends and holidays.
+pub fn working_days(
+    start: NaiveDate,
+    end: NaiveDate,
+    holidays: &HashSet<NaiveDate>,
+    minutes_per_day: i32,
+) -> Vec<LeaveDay> {
+    start
+        .iter_days()
+        .take_while(|d| *d <= end)
+        .filter(|d| !matches!(d.weekday(), Weekday::Sat | Weekday::Sun))
+        .filter(|d| !holidays.contains(d))
+        .map(|leave_date| LeaveDay {
+            leave_date,
+            minutes: minutes_per_day,
+        })
+        .collect()
+}
+
+/// Minutes earned for one accrual period before the cap is applied.
+///
+/// Front-loaded policies earn nothing per period; their annual grant is
+/// posted separately (see [`year_starts`]).
+pub fn period_accrual(policy: &AccrualPolicy, worked_minutes: i64) -> i64 {
+    match policy.method {
+        AccrualMethod::PerHourWorked => {
+            let basis = policy.per_worked_minutes.unwrap_or(60).max(1) as i64;
+            worked_minutes.max(0) * policy.accrual_minutes as i64 / basis
+        }
+        AccrualMethod::PerPayPeriod => policy.accrual_minutes as i64,
+        AccrualMethod::FrontLoaded => 0,
+    }
+}
+
+/// Limit an accrual so the balance never exceeds the cap. Never negative.
+pub fn capped(balance: i64, amount: i64, cap: Option<i32>) -> i64 {
+    match cap {
+        Some(cap) => amount.min(cap as i64 - balance).max(0),
+        None => amount.max(0),
+    }
+}
+
+/// Minutes forfeited at year end when the balance exceeds the carryover limit.
+pub fn carryover_forfeit(balance: i64, carryover_max: Option<i32>) -> i64 {
+    match carryover_max {
+        Some(max) => (balance - max as i64).max(0),
+        None => 0,
+    }
+}
+
+/// January 1st of every year that starts inside `[start, end]`.
+pub fn year_starts(start: NaiveDate, end: NaiveDate) -> Vec<NaiveDate> {
+    (start.year()..=end.year())
+        .filter_map(|y| NaiveDate::from_ymd_opt(y, 1, 1))
+        .filter(|d| *d >= start && *d <= end)
+        .collect()
+}
+
+/// Pick the record for an employee group, falling back to the tenant default
+/// (NULL group). Shared by accrual policies and holiday calendars.
+pub fn resolve_for_group<'a, T>(
+    candidates: impl IntoIterator<Item = &'a T>,
+    group: Option<&str>,
+    group_of: impl Fn(&T) -> Option<&str>,
+) -> Option<&'a T> {
+    let mut default = None;
+    for c in candidates {
+        match group_of(c) {
+            Some(g) if Some(g) == group => return Some(c),
+            None if default.is_none() => default = Some(c),
+            _ => {}
+        }
+    }
+    default
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+    use uuid::Uuid;
+
+    fn d(m: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2026, m, day).unwrap()
+    }
+
+    fn policy(method: AccrualMethod, accrual: i32, per_worked: Option<i32>) -> AccrualPolicy {
+        AccrualPolicy {
+            id: Uuid::nil(),
+            app_id: "acme".into(),
+            leave_type_id: Uuid::nil(),
+            name: "p".into(),
+            employee_group: None,
+            method,
+            accrual_minutes: accrual,
+            per_worked_minutes: per_worked,
+            cap_minutes: None,
+            carryover_max_minutes: None,
+            active: true,
+            created_at: Utc::now(),
+        }
+    }
+
+    #[test]
+    fn working_days_skip_weekends_and_holidays() {
+        // Fri 2026-03-06 .. Tue 2026-03-10, Monday is a holiday
+        let holidays: HashSet<NaiveDate> = [d(3, 9)].into_iter().collect();
+        let days = working_days(d(3, 6), d(3, 10), &holidays, 480);
+        let dates: Vec<NaiveDate> = days.iter().map(|x| x.leave_date).collect();
+        assert_eq!(dates, vec![d(3, 6), d(3, 10)]);
+        assert!(days.iter().all(|x| x.minutes == 480));
+    }
+
+    #[test]
+    fn per_hour_worked_accrues_pro_rata() {
+        // 1 hour per 30 hours worked; 80 hours → 160 minutes
+        let p = policy(AccrualMethod::PerHourWorked, 60, Some(1800));
+        assert_eq!(period_accrual(&p, 80 * 60), 160);
+        assert_eq!(period_accrual(&p, 0), 0);
+    }
+
+    #[test]
+    fn per_pay_period_and_front_loaded() {
+        let p = policy(AccrualMethod::PerPayPeriod, 240, None);
+        assert_eq!(period_accrual(&p, 0), 240);
+        let f = policy(AccrualMethod::FrontLoaded, 4800, None);
+        assert_eq!(period_accrual(&f, 10_000), 0);
+    }
+
+    #[test]
+    fn cap_limits_accrual() {
+        assert_eq!(capped(4700, 240, Some(4800)), 100);
+        assert_eq!(capped(4800, 240, Some(4800)), 0);
+        assert_eq!(capped(5000, 240, Some(4800)), 0);
+        assert_eq!(capped(5000, 240, None), 240);
+    }
+
+    #[test]
+    fn carryover_forfeits_excess_only() {
+        assert_eq!(carryover_forfeit(3000, Some(2400)), 600);
+        assert_eq!(carryover_forfeit(1000, Some(2400)), 0);
+        assert_eq!(carryover_forfeit(3000, None), 0);
+        assert_eq!(carryover_forfeit(3000, Some(0)), 3000);
+    }
+
+    #[test]
+    fn year_starts_within_range() {
+        let start = NaiveDate::from_ymd_opt(2025, 12, 20).unwrap();
+        let end = NaiveDate::from_ymd_opt(2026, 1, 2).unwrap();
+        assert_eq!(year_starts(start, end), vec![d(1, 1)]);
+        assert!(year_starts(d(3, 1), d(3, 14)).is_empty());
+    }
+
+    #[test]
+    fn group_resolution_prefers_exact_match() {
+        let items = [(Some("Ops"), 1), (None, 2), (Some("Sales"), 3)];
+        let pick = |g| resolve_for_group(items.iter(), g, |i| i.0).map(|i| i.1);
+        assert_eq!(pick(Some("Sales")), Some(3));
+        assert_eq!(pick(Some("Finance")), Some(2));
+        assert_eq!(pick(None), Some(2));
+    }
+}
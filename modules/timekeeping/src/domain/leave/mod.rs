@@ -0,0 +1,6 @@
+pub mod accrual;
+pub mod calc;
+pub mod models;
+pub mod repo;
+pub mod requests;
+pub mod service;
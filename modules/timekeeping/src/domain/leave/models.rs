@@ -0,0 +1,489 @@
+//! Leave (PTO) domain models and request types.
+//!
+//! Invariants:
+//! - Balances are derived from the append-only ledger; never stored directly
+//! - One active accrual policy per (app_id, leave_type, employee_group) and
+//!   one active holiday calendar per (app_id, employee_group); NULL = default
+//! - Leave requests follow the timesheet approval state machine
+//!   (`ApprovalStatus`): submitted → approved / rejected, submitted → draft
+//! - Approval of a balance-tracked request posts a negative `usage` ledger row
+
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::domain::approvals::models::{ApprovalError, ApprovalStatus};
+
+// ============================================================================
+// Enums (mirror tk_leave_category / tk_accrual_method in DB)
+// ============================================================================
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type, ToSchema)]
+#[sqlx(type_name = "tk_leave_category", rename_all = "lowercase")]
+#[serde(rename_all = "lowercase")]
+pub enum LeaveCategory {
+    Vacation,
+    Sick,
+    Holiday,
+    Other,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type, ToSchema)]
+#[sqlx(type_name = "tk_accrual_method", rename_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum AccrualMethod {
+    /// `accrual_minutes` for every `per_worked_minutes` of approved time
+    PerHourWorked,
+    /// `accrual_minutes` each accrual run (one run per pay period)
+    PerPayPeriod,
+    /// `accrual_minutes` granted once per calendar year
+    FrontLoaded,
+}
+
+// ============================================================================
+// Domain models
+// ============================================================================
+
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow, ToSchema)]
+pub struct LeaveType {
+    pub id: Uuid,
+    pub app_id: String,
+    pub code: String,
+    pub name: String,
+    pub category: LeaveCategory,
+    pub tracks_balance: bool,
+    pub is_paid: bool,
+    pub active: bool,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow, ToSchema)]
+pub struct AccrualPolicy {
+    pub id: Uuid,
+    pub app_id: String,
+    pub leave_type_id: Uuid,
+    pub name: String,
+    pub employee_group: Option<String>,
+    pub method: AccrualMethod,
+    pub accrual_minutes: i32,
+    pub per_worked_minutes: Option<i32>,
+    pub cap_minutes: Option<i32>,
+    pub carryover_max_minutes: Option<i32>,
+    pub active: bool,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow, ToSchema)]
+pub struct HolidayCalendar {
+    pub id: Uuid,
+    pub app_id: String,
+    pub name: String,
+    pub employee_group: Option<String>,
+    pub leave_type_id: Option<Uuid>,
+    pub active: bool,
+    pub created_at: DateTime<Utc>,
+    #[sqlx(skip)]
+    pub holidays: Vec<Holiday>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow, ToSchema)]
+pub struct Holiday {
+    pub id: Uuid,
+    pub calendar_id: Uuid,
+    pub holiday_date: NaiveDate,
+    pub name: String,
+    pub paid_minutes: i32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow, ToSchema)]
+pub struct LeaveRequest {
+    pub id: Uuid,
+    pub app_id: String,
+    pub employee_id: Uuid,
+    pub leave_type_id: Uuid,
+    pub start_date: NaiveDate,
+    pub end_date: NaiveDate,
+    pub total_minutes: i32,
+    pub reason: Option<String>,
+    pub status: ApprovalStatus,
+    pub submitted_at: Option<DateTime<Utc>>,
+    pub reviewed_at: Option<DateTime<Utc>>,
+    pub reviewer_id: Option<Uuid>,
+    pub reviewer_notes: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    #[sqlx(skip)]
+    pub days: Vec<LeaveDay>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, sqlx::FromRow, ToSchema)]
+pub struct LeaveDay {
+    pub leave_date: NaiveDate,
+    pub minutes: i32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow, ToSchema)]
+pub struct LedgerEntry {
+    pub id: i64,
+    pub app_id: String,
+    pub employee_id: Uuid,
+    pub leave_type_id: Uuid,
+    /// accrual | front_load | forfeit | usage | adjustment
+    pub entry_kind: String,
+    /// Signed minutes: usage and forfeit rows are negative
+    pub minutes: i32,
+    pub effective_date: NaiveDate,
+    pub source_ref: String,
+    pub created_at: DateTime<Utc>,
+}
+
+/// Balance of one leave type for one employee as of a date.
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow, ToSchema)]
+pub struct LeaveBalance {
+    pub employee_id: Uuid,
+    pub leave_type_id: Uuid,
+    pub leave_type_code: String,
+    pub as_of: NaiveDate,
+    pub balance_minutes: i64,
+}
+
+/// One paid leave day for the payroll export (approved request or holiday).
+#[derive(Debug, Clone, PartialEq, Eq, sqlx::FromRow)]
+pub struct LeaveExportLine {
+    /// Leave request id, or holiday id for calendar holidays
+    pub source_id: Uuid,
+    pub employee_id: Uuid,
+    pub employee_name: String,
+    pub leave_date: NaiveDate,
+    pub minutes: i32,
+    pub leave_type_code: String,
+    pub description: String,
+}
+
+/// Ledger rows posted by one accrual run.
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct AccrualRunResult {
+    pub period_start: NaiveDate,
+    pub period_end: NaiveDate,
+    pub posted: Vec<LedgerEntry>,
+}
+
+// ============================================================================
+// Request types
+// ============================================================================
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct CreateLeaveTypeRequest {
+    pub app_id: String,
+    pub code: String,
+    pub name: String,
+    pub category: LeaveCategory,
+    /// Defaults to false for holidays, true otherwise
+    pub tracks_balance: Option<bool>,
+    pub is_paid: Option<bool>,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct CreateAccrualPolicyRequest {
+    pub app_id: String,
+    pub leave_type_id: Uuid,
+    pub name: String,
+    pub employee_group: Option<String>,
+    pub method: AccrualMethod,
+    pub accrual_minutes: i32,
+    pub per_worked_minutes: Option<i32>,
+    pub cap_minutes: Option<i32>,
+    pub carryover_max_minutes: Option<i32>,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct CreateHolidayCalendarRequest {
+    pub app_id: String,
+    pub name: String,
+    pub employee_group: Option<String>,
+    pub leave_type_id: Option<Uuid>,
+    #[serde(default)]
+    pub holidays: Vec<CreateHolidayRequest>,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct CreateHolidayRequest {
+    pub holiday_date: NaiveDate,
+    pub name: String,
+    /// Defaults to 480 (one 8-hour day)
+    pub paid_minutes: Option<i32>,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct SubmitLeaveRequest {
+    pub app_id: String,
+    pub employee_id: Uuid,
+    pub leave_type_id: Uuid,
+    pub start_date: NaiveDate,
+    pub end_date: NaiveDate,
+    /// Minutes per working day; defaults to 480
+    pub minutes_per_day: Option<i32>,
+    pub reason: Option<String>,
+    pub actor_id: Uuid,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct ReviewLeaveRequest {
+    pub app_id: String,
+    pub request_id: Uuid,
+    pub actor_id: Uuid,
+    pub notes: Option<String>,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct RunAccrualsRequest {
+    pub app_id: String,
+    pub period_start: NaiveDate,
+    pub period_end: NaiveDate,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct BalanceQuery {
+    pub employee_id: Uuid,
+    pub as_of: Option<NaiveDate>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct LedgerQuery {
+    pub employee_id: Uuid,
+    pub leave_type_id: Option<Uuid>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct LeaveRequestsQuery {
+    pub employee_id: Option<Uuid>,
+    pub status: Option<ApprovalStatus>,
+}
+
+// ============================================================================
+// Validation
+// ============================================================================
+
+impl CreateLeaveTypeRequest {
+    pub fn validate(&self) -> Result<(), LeaveError> {
+        not_blank(&self.app_id, "app_id")?;
+        not_blank(&self.code, "code")?;
+        not_blank(&self.name, "name")?;
+        Ok(())
+    }
+}
+
+impl CreateAccrualPolicyRequest {
+    pub fn validate(&self) -> Result<(), LeaveError> {
+        not_blank(&self.app_id, "app_id")?;
+        not_blank(&self.name, "name")?;
+        if let Some(ref group) = self.employee_group {
+            not_blank(group, "employee_group")?;
+        }
+        if self.accrual_minutes <= 0 {
+            return Err(LeaveError::Validation("accrual_minutes must be > 0".into()));
+        }
+        match (self.method, self.per_worked_minutes) {
+            (AccrualMethod::PerHourWorked, None) => {
+                return Err(LeaveError::Validation(
+                    "per_worked_minutes is required for per_hour_worked policies".into(),
+                ))
+            }
+            (_, Some(v)) if v <= 0 => {
+                return Err(LeaveError::Validation(
+                    "per_worked_minutes must be > 0".into(),
+                ))
+            }
+            _ => {}
+        }
+        if matches!(self.cap_minutes, Some(v) if v <= 0) {
+            return Err(LeaveError::Validation("cap_minutes must be > 0".into()));
+        }
+        if matches!(self.carryover_max_minutes, Some(v) if v < 0) {
+            return Err(LeaveError::Validation(
+                "carryover_max_minutes must be >= 0".into(),
+            ));
+        }
+        Ok(())
+    }
+}
+
+impl CreateHolidayCalendarRequest {
+    pub fn validate(&self) -> Result<(), LeaveError> {
+        not_blank(&self.app_id, "app_id")?;
+        not_blank(&self.name, "name")?;
+        if let Some(ref group) = self.employee_group {
+            not_blank(group, "employee_group")?;
+        }
+        for h in &self.holidays {
+            h.validate()?;
+        }
+        Ok(())
+    }
+}
+
+impl CreateHolidayRequest {
+    pub fn validate(&self) -> Result<(), LeaveError> {
+        not_blank(&self.name, "holiday name")?;
+        if matches!(self.paid_minutes, Some(v) if !(0..=1440).contains(&v)) {
+            return Err(LeaveError::Validation(
+                "paid_minutes must be between 0 and 1440".into(),
+            ));
+        }
+        Ok(())
+    }
+}
+
+impl SubmitLeaveRequest {
+    pub fn validate(&self) -> Result<(), LeaveError> {
+        not_blank(&self.app_id, "app_id")?;
+        if self.end_date < self.start_date {
+            return Err(LeaveError::Validation(
+                "end_date must be >= start_date".into(),
+            ));
+        }
+        if (self.end_date - self.start_date).num_days() > 366 {
+            return Err(LeaveError::Validation(
+                "leave requests may span at most one year".into(),
+            ));
+        }
+        if matches!(self.minutes_per_day, Some(v) if !(1..=1440).contains(&v)) {
+            return Err(LeaveError::Validation(
+                "minutes_per_day must be between 1 and 1440".into(),
+            ));
+        }
+        Ok(())
+    }
+}
+
+impl RunAccrualsRequest {
+    pub fn validate(&self) -> Result<(), LeaveError> {
+        not_blank(&self.app_id, "app_id")?;
+        if self.period_end < self.period_start {
+            return Err(LeaveError::Validation(
+                "period_end must be >= period_start".into(),
+            ));
+        }
+        Ok(())
+    }
+}
+
+fn not_blank(value: &str, field: &str) -> Result<(), LeaveError> {
+    if value.trim().is_empty() {
+        return Err(LeaveError::Validation(format!(
+            "{} must not be empty",
+            field
+        )));
+    }
+    Ok(())
+}
+
+// ============================================================================
+// Errors
+// ============================================================================
+
+#[derive(Debug, Error)]
+pub enum LeaveError {
+    #[error("Leave record not found")]
+    NotFound,
+
+    #[error("Validation error: {0}")]
+    Validation(String),
+
+    #[error("Duplicate: {0}")]
+    Duplicate(String),
+
+    #[error("Insufficient leave balance: requested {requested} minutes, available {available}")]
+    InsufficientBalance { requested: i64, available: i64 },
+
+    #[error("Invalid status transition from {from} to {to}")]
+    InvalidTransition { from: String, to: String },
+
+    #[error("Database error: {0}")]
+    Database(#[from] sqlx::Error),
+}
+
+impl From<ApprovalError> for LeaveError {
+    fn from(err: ApprovalError) -> Self {
+        match err {
+            ApprovalError::InvalidTransition { from, to } => {
+                LeaveError::InvalidTransition { from, to }
+            }
+            ApprovalError::NotFound => LeaveError::NotFound,
+            ApprovalError::Database(e) => LeaveError::Database(e),
+            other => LeaveError::Validation(other.to_string()),
+        }
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn policy_req(method: AccrualMethod, per_worked: Option<i32>) -> CreateAccrualPolicyRequest {
+        CreateAccrualPolicyRequest {
+            app_id: "acme".into(),
+            leave_type_id: Uuid::nil(),
+            name: "Standard".into(),
+            employee_group: None,
+            method,
+            accrual_minutes: 60,
+            per_worked_minutes: per_worked,
+            cap_minutes: Some(4800),
+            carryover_max_minutes: Some(2400),
+        }
+    }
+
+    #[test]
+    fn accrual_method_serde_snake_case() -> Result<(), Box<dyn std::error::Error>> {
+        let json = serde_json::to_string(&AccrualMethod::PerHourWorked)?;
+        assert_eq!(json, "\"per_hour_worked\"");
+        let back: AccrualMethod = serde_json::from_str("\"front_loaded\"")?;
+        assert_eq!(back, AccrualMethod::FrontLoaded);
+        Ok(())
+    }
+
+    #[test]
+    fn per_hour_policy_requires_basis() {
+        assert!(policy_req(AccrualMethod::PerHourWorked, None)
+            .validate()
+            .is_err());
+        assert!(policy_req(AccrualMethod::PerHourWorked, Some(1800))
+            .validate()
+            .is_ok());
+        assert!(policy_req(AccrualMethod::PerPayPeriod, None)
+            .validate()
+            .is_ok());
+    }
+
+    #[test]
+    fn submit_rejects_inverted_dates() {
+        let req = SubmitLeaveRequest {
+            app_id: "acme".into(),
+            employee_id: Uuid::nil(),
+            leave_type_id: Uuid::nil(),
+            start_date: NaiveDate::from_ymd_opt(2026, 3, 5).unwrap(),
+            end_date: NaiveDate::from_ymd_opt(2026, 3, 4).unwrap(),
+            minutes_per_day: None,
+            reason: None,
+            actor_id: Uuid::nil(),
+        };
+        assert!(matches!(req.validate(), Err(LeaveError::Validation(_))));
+    }
+
+    #[test]
+    fn approval_transition_error_maps_through() {
+        let err: LeaveError = ApprovalError::InvalidTransition {
+            from: "Approved".into(),
+            to: "rejected".into(),
+        }
+        .into();
+        assert!(matches!(err, LeaveError::InvalidTransition { .. }));
+    }
+}
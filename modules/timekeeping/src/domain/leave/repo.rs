@@ -0,0 +1,662 @@
+//! Leave repository — SQL layer for leave types, accrual policies, holiday
+//! calendars, leave requests and the balance ledger.
+
+use chrono::{DateTime, NaiveDate, Utc};
+use sqlx::{PgConnection, PgPool};
+use uuid::Uuid;
+
+use super::models::*;
+use crate::domain::approvals::models::ApprovalStatus;
+
+const LEAVE_TYPE_COLS: &str = r#"
+    id, app_id, code, name, category, tracks_balance, is_paid, active, created_at
+"#;
+
+const POLICY_COLS: &str = r#"
+    id, app_id, leave_type_id, name, employee_group, method, accrual_minutes,
+    per_worked_minutes, cap_minutes, carryover_max_minutes, active, created_at
+"#;
+
+const CALENDAR_COLS: &str = r#"
+    id, app_id, name, employee_group, leave_type_id, active, created_at
+"#;
+
+const REQUEST_COLS: &str = r#"
+    id, app_id, employee_id, leave_type_id, start_date, end_date,
+    total_minutes, reason, status, submitted_at, reviewed_at, reviewer_id,
+    reviewer_notes, created_at, updated_at
+"#;
+
+const LEDGER_COLS: &str = r#"
+    id, app_id, employee_id, leave_type_id, entry_kind, minutes,
+    effective_date, source_ref, created_at
+"#;
+
+fn map_unique(e: sqlx::Error, constraint: &str, msg: impl FnOnce() -> String) -> LeaveError {
+    match e {
+        sqlx::Error::Database(ref db) if db.constraint() == Some(constraint) => {
+            LeaveError::Duplicate(msg())
+        }
+        other => LeaveError::Database(other),
+    }
+}
+
+// ============================================================================
+// Leave types
+// ============================================================================
+
+pub async fn insert_leave_type(
+    pool: &PgPool,
+    req: &CreateLeaveTypeRequest,
+) -> Result<LeaveType, LeaveError> {
+    let tracks_balance = req
+        .tracks_balance
+        .unwrap_or(req.category != LeaveCategory::Holiday);
+    let sql = format!(
+        r#"INSERT INTO tk_leave_types (app_id, code, name, category, tracks_balance, is_paid)
+        VALUES ($1, $2, $3, $4, $5, $6)
+        RETURNING {}"#,
+        LEAVE_TYPE_COLS
+    );
+    sqlx::query_as::<_, LeaveType>(&sql)
+        .bind(&req.app_id)
+        .bind(req.code.trim())
+        .bind(req.name.trim())
+        .bind(req.category)
+        .bind(tracks_balance)
+        .bind(req.is_paid.unwrap_or(true))
+        .fetch_one(pool)
+        .await
+        .map_err(|e| {
+            map_unique(e, "tk_leave_types_app_code_unique", || {
+                format!("leave type '{}' already exists", req.code.trim())
+            })
+        })
+}
+
+pub async fn fetch_leave_type(
+    conn: &mut PgConnection,
+    app_id: &str,
+    id: Uuid,
+) -> Result<Option<LeaveType>, LeaveError> {
+    let sql = format!(
+        "SELECT {} FROM tk_leave_types WHERE app_id = $1 AND id = $2",
+        LEAVE_TYPE_COLS
+    );
+    Ok(sqlx::query_as::<_, LeaveType>(&sql)
+        .bind(app_id)
+        .bind(id)
+        .fetch_optional(conn)
+        .await?)
+}
+
+pub async fn list_leave_types(pool: &PgPool, app_id: &str) -> Result<Vec<LeaveType>, LeaveError> {
+    let sql = format!(
+        "SELECT {} FROM tk_leave_types WHERE app_id = $1 AND active = TRUE ORDER BY code",
+        LEAVE_TYPE_COLS
+    );
+    Ok(sqlx::query_as::<_, LeaveType>(&sql)
+        .bind(app_id)
+        .fetch_all(pool)
+        .await?)
+}
+
+// ============================================================================
+// Accrual policies
+// ============================================================================
+
+pub async fn insert_policy(
+    pool: &PgPool,
+    req: &CreateAccrualPolicyRequest,
+) -> Result<AccrualPolicy, LeaveError> {
+    let sql = format!(
+        r#"INSERT INTO tk_leave_accrual_policies
+            (app_id, leave_type_id, name, employee_group, method, accrual_minutes,
+             per_worked_minutes, cap_minutes, carryover_max_minutes)
+        VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
+        RETURNING {}"#,
+        POLICY_COLS
+    );
+    sqlx::query_as::<_, AccrualPolicy>(&sql)
+        .bind(&req.app_id)
+        .bind(req.leave_type_id)
+        .bind(req.name.trim())
+        .bind(req.employee_group.as_deref().map(str::trim))
+        .bind(req.method)
+        .bind(req.accrual_minutes)
+        .bind(req.per_worked_minutes)
+        .bind(req.cap_minutes)
+        .bind(req.carryover_max_minutes)
+        .fetch_one(pool)
+        .await
+        .map_err(|e| match e {
+            sqlx::Error::Database(ref db)
+                if db.constraint() == Some("tk_leave_policies_one_active_per_group") =>
+            {
+                LeaveError::Duplicate(
+                    "an active accrual policy already exists for this leave type and group".into(),
+                )
+            }
+            other => map_unique(other, "tk_leave_policies_app_name_unique", || {
+                format!("accrual policy '{}' already exists", req.name.trim())
+            }),
+        })
+}
+
+pub async fn list_active_policies(
+    conn: &mut PgConnection,
+    app_id: &str,
+) -> Result<Vec<AccrualPolicy>, LeaveError> {
+    let sql = format!(
+        "SELECT {} FROM tk_leave_accrual_policies \
+         WHERE app_id = $1 AND active = TRUE ORDER BY leave_type_id, name",
+        POLICY_COLS
+    );
+    Ok(sqlx::query_as::<_, AccrualPolicy>(&sql)
+        .bind(app_id)
+        .fetch_all(conn)
+        .await?)
+}
+
+// ============================================================================
+// Holiday calendars
+// ============================================================================
+
+pub async fn insert_calendar(
+    conn: &mut PgConnection,
+    req: &CreateHolidayCalendarRequest,
+) -> Result<HolidayCalendar, LeaveError> {
+    let sql = format!(
+        r#"INSERT INTO tk_holiday_calendars (app_id, name, employee_group, leave_type_id)
+        VALUES ($1, $2, $3, $4)
+        RETURNING {}"#,
+        CALENDAR_COLS
+    );
+    sqlx::query_as::<_, HolidayCalendar>(&sql)
+        .bind(&req.app_id)
+        .bind(req.name.trim())
+        .bind(req.employee_group.as_deref().map(str::trim))
+        .bind(req.leave_type_id)
+        .fetch_one(conn)
+        .await
+        .map_err(|e| match e {
+            sqlx::Error::Database(ref db)
+                if db.constraint() == Some("tk_holiday_calendars_one_active_per_group") =>
+            {
+                LeaveError::Duplicate(
+                    "an active holiday calendar already exists for this group".into(),
+                )
+            }
+            other => map_unique(other, "tk_holiday_calendars_app_name_unique", || {
+                format!("holiday calendar '{}' already exists", req.name.trim())
+            }),
+        })
+}
+
+pub async fn insert_holiday(
+    conn: &mut PgConnection,
+    calendar_id: Uuid,
+    req: &CreateHolidayRequest,
+) -> Result<Holiday, LeaveError> {
+    sqlx::query_as::<_, Holiday>(
+        r#"
+        INSERT INTO tk_holidays (calendar_id, holiday_date, name, paid_minutes)
+        VALUES ($1, $2, $3, $4)
+        RETURNING id, calendar_id, holiday_date, name, paid_minutes
+        "#,
+    )
+    .bind(calendar_id)
+    .bind(req.holiday_date)
+    .bind(req.name.trim())
+    .bind(req.paid_minutes.unwrap_or(480))
+    .fetch_one(conn)
+    .await
+    .map_err(|e| {
+        map_unique(e, "tk_holidays_calendar_date_unique", || {
+            format!("holiday on {} already exists", req.holiday_date)
+        })
+    })
+}
+
+pub async fn fetch_calendar(
+    conn: &mut PgConnection,
+    app_id: &str,
+    id: Uuid,
+) -> Result<Option<HolidayCalendar>, LeaveError> {
+    let sql = format!(
+        "SELECT {} FROM tk_holiday_calendars WHERE app_id = $1 AND id = $2",
+        CALENDAR_COLS
+    );
+    Ok(sqlx::query_as::<_, HolidayCalendar>(&sql)
+        .bind(app_id)
+        .bind(id)
+        .fetch_optional(conn)
+        .await?)
+}
+
+pub async fn list_active_calendars(
+    conn: &mut PgConnection,
+    app_id: &str,
+) -> Result<Vec<HolidayCalendar>, LeaveError> {
+    let sql = format!(
+        "SELECT {} FROM tk_holiday_calendars WHERE app_id = $1 AND active = TRUE ORDER BY name",
+        CALENDAR_COLS
+    );
+    Ok(sqlx::query_as::<_, HolidayCalendar>(&sql)
+        .bind(app_id)
+        .fetch_all(conn)
+        .await?)
+}
+
+/// Holidays for the given calendars, optionally bounded to a date range.
+pub async fn list_holidays(
+    conn: &mut PgConnection,
+    calendar_ids: &[Uuid],
+    from: Option<NaiveDate>,
+    to: Option<NaiveDate>,
+) -> Result<Vec<Holiday>, LeaveError> {
+    Ok(sqlx::query_as::<_, Holiday>(
+        r#"
+        SELECT id, calendar_id, holiday_date, name, paid_minutes
+        FROM tk_holidays
+        WHERE calendar_id = ANY($1)
+          AND ($2::DATE IS NULL OR holiday_date >= $2)
+          AND ($3::DATE IS NULL OR holiday_date <= $3)
+        ORDER BY calendar_id, holiday_date
+        "#,
+    )
+    .bind(calendar_ids)
+    .bind(from)
+    .bind(to)
+    .fetch_all(conn)
+    .await?)
+}
+
+// ============================================================================
+// Employees / worked time
+// ============================================================================
+
+/// (id, department, display name) for active employees of the app.
+pub async fn list_active_employees(
+    conn: &mut PgConnection,
+    app_id: &str,
+) -> Result<Vec<(Uuid, Option<String>, String)>, LeaveError> {
+    Ok(sqlx::query_as(
+        "SELECT id, department, first_name || ' ' || last_name \
+         FROM tk_employees WHERE app_id = $1 AND active = TRUE ORDER BY id",
+    )
+    .bind(app_id)
+    .fetch_all(conn)
+    .await?)
+}
+
+/// Department of an employee; `None` if the employee does not exist.
+pub async fn fetch_employee_group(
+    conn: &mut PgConnection,
+    app_id: &str,
+    employee_id: Uuid,
+) -> Result<Option<Option<String>>, LeaveError> {
+    let row: Option<(Option<String>,)> =
+        sqlx::query_as("SELECT department FROM tk_employees WHERE app_id = $1 AND id = $2")
+            .bind(app_id)
+            .bind(employee_id)
+            .fetch_optional(conn)
+            .await?;
+    Ok(row.map(|r| r.0))
+}
+
+/// Approved timesheet minutes per employee within the period.
+pub async fn sum_approved_minutes(
+    conn: &mut PgConnection,
+    app_id: &str,
+    from: NaiveDate,
+    to: NaiveDate,
+) -> Result<Vec<(Uuid, i64)>, LeaveError> {
+    Ok(sqlx::query_as(
+        r#"
+        SELECT e.employee_id, COALESCE(SUM(e.minutes), 0)::BIGINT
+        FROM tk_timesheet_entries e
+        JOIN tk_approval_requests ar
+            ON ar.app_id = e.app_id
+            AND ar.employee_id = e.employee_id
+            AND ar.period_start <= e.work_date
+            AND ar.period_end >= e.work_date
+            AND ar.status = 'approved'
+        WHERE e.app_id = $1
+          AND e.work_date >= $2
+          AND e.work_date <= $3
+          AND e.is_current = TRUE
+          AND e.entry_type != 'void'
+        GROUP BY e.employee_id
+        "#,
+    )
+    .bind(app_id)
+    .bind(from)
+    .bind(to)
+    .fetch_all(conn)
+    .await?)
+}
+
+// ============================================================================
+// Leave requests
+// ============================================================================
+
+#[allow(clippy::too_many_arguments)]
+pub async fn insert_request(
+    conn: &mut PgConnection,
+    app_id: &str,
+    employee_id: Uuid,
+    leave_type_id: Uuid,
+    start_date: NaiveDate,
+    end_date: NaiveDate,
+    total_minutes: i32,
+    reason: Option<&str>,
+    now: DateTime<Utc>,
+) -> Result<LeaveRequest, LeaveError> {
+    let sql = format!(
+        r#"INSERT INTO tk_leave_requests
+            (app_id, employee_id, leave_type_id, start_date, end_date,
+             total_minutes, reason, status, submitted_at)
+        VALUES ($1, $2, $3, $4, $5, $6, $7, 'submitted', $8)
+        RETURNING {}"#,
+        REQUEST_COLS
+    );
+    Ok(sqlx::query_as::<_, LeaveRequest>(&sql)
+        .bind(app_id)
+        .bind(employee_id)
+        .bind(leave_type_id)
+        .bind(start_date)
+        .bind(end_date)
+        .bind(total_minutes)
+        .bind(reason)
+        .bind(now)
+        .fetch_one(conn)
+        .await?)
+}
+
+pub async fn insert_request_days(
+    conn: &mut PgConnection,
+    request_id: Uuid,
+    days: &[LeaveDay],
+) -> Result<(), LeaveError> {
+    let dates: Vec<NaiveDate> = days.iter().map(|d| d.leave_date).collect();
+    let minutes: Vec<i32> = days.iter().map(|d| d.minutes).collect();
+    sqlx::query(
+        "INSERT INTO tk_leave_request_days (request_id, leave_date, minutes) \
+         SELECT $1, d, m FROM UNNEST($2::DATE[], $3::INT[]) AS t(d, m)",
+    )
+    .bind(request_id)
+    .bind(&dates)
+    .bind(&minutes)
+    .execute(conn)
+    .await?;
+    Ok(())
+}
+
+pub async fn fetch_request(
+    pool: &PgPool,
+    app_id: &str,
+    id: Uuid,
+) -> Result<Option<LeaveRequest>, LeaveError> {
+    let sql = format!(
+        "SELECT {} FROM tk_leave_requests WHERE app_id = $1 AND id = $2",
+        REQUEST_COLS
+    );
+    Ok(sqlx::query_as::<_, LeaveRequest>(&sql)
+        .bind(app_id)
+        .bind(id)
+        .fetch_optional(pool)
+        .await?)
+}
+
+pub async fn fetch_request_for_update(
+    conn: &mut PgConnection,
+    app_id: &str,
+    id: Uuid,
+) -> Result<LeaveRequest, LeaveError> {
+    let sql = format!(
+        "SELECT {} FROM tk_leave_requests WHERE app_id = $1 AND id = $2 FOR UPDATE",
+        REQUEST_COLS
+    );
+    sqlx::query_as::<_, LeaveRequest>(&sql)
+        .bind(app_id)
+        .bind(id)
+        .fetch_optional(conn)
+        .await?
+        .ok_or(LeaveError::NotFound)
+}
+
+pub async fn list_requests(
+    pool: &PgPool,
+    app_id: &str,
+    employee_id: Option<Uuid>,
+    status: Option<&ApprovalStatus>,
+) -> Result<Vec<LeaveRequest>, LeaveError> {
+    let sql = format!(
+        "SELECT {} FROM tk_leave_requests \
+         WHERE app_id = $1 \
+           AND ($2::UUID IS NULL OR employee_id = $2) \
+           AND ($3::tk_approval_status IS NULL OR status = $3) \
+         ORDER BY start_date, created_at",
+        REQUEST_COLS
+    );
+    Ok(sqlx::query_as::<_, LeaveRequest>(&sql)
+        .bind(app_id)
+        .bind(employee_id)
+        .bind(status)
+        .fetch_all(pool)
+        .await?)
+}
+
+pub async fn list_request_days(
+    pool: &PgPool,
+    request_id: Uuid,
+) -> Result<Vec<LeaveDay>, LeaveError> {
+    Ok(sqlx::query_as::<_, LeaveDay>(
+        "SELECT leave_date, minutes FROM tk_leave_request_days \
+         WHERE request_id = $1 ORDER BY leave_date",
+    )
+    .bind(request_id)
+    .fetch_all(pool)
+    .await?)
+}
+
+/// Move a request to a reviewed status (approved / rejected) or back to draft.
+pub async fn update_status(
+    conn: &mut PgConnection,
+    id: Uuid,
+    status: ApprovalStatus,
+    reviewer_id: Option<Uuid>,
+    notes: Option<&str>,
+    now: DateTime<Utc>,
+) -> Result<LeaveRequest, LeaveError> {
+    let sql = format!(
+        r#"UPDATE tk_leave_requests
+        SET status = $2,
+            reviewed_at = CASE WHEN $3::UUID IS NULL THEN reviewed_at ELSE $5 END,
+            reviewer_id = COALESCE($3, reviewer_id),
+            reviewer_notes = CASE WHEN $3::UUID IS NULL THEN reviewer_notes ELSE $4 END,
+            updated_at = $5
+        WHERE id = $1
+        RETURNING {}"#,
+        REQUEST_COLS
+    );
+    Ok(sqlx::query_as::<_, LeaveRequest>(&sql)
+        .bind(id)
+        .bind(status)
+        .bind(reviewer_id)
+        .bind(notes)
+        .bind(now)
+        .fetch_one(conn)
+        .await?)
+}
+
+pub async fn record_action(
+    conn: &mut PgConnection,
+    request_id: Uuid,
+    action: &str,
+    actor_id: Uuid,
+    notes: Option<&str>,
+) -> Result<(), LeaveError> {
+    sqlx::query(
+        "INSERT INTO tk_leave_request_actions (request_id, action, actor_id, notes) \
+         VALUES ($1, $2, $3, $4)",
+    )
+    .bind(request_id)
+    .bind(action)
+    .bind(actor_id)
+    .bind(notes)
+    .execute(conn)
+    .await?;
+    Ok(())
+}
+
+/// Approved leave days within the period, one row per request day.
+pub async fn fetch_approved_leave_days(
+    pool: &PgPool,
+    app_id: &str,
+    from: NaiveDate,
+    to: NaiveDate,
+) -> Result<Vec<LeaveExportLine>, LeaveError> {
+    Ok(sqlx::query_as::<_, LeaveExportLine>(
+        r#"
+        SELECT
+            r.id AS source_id,
+            r.employee_id,
+            COALESCE(emp.first_name || ' ' || emp.last_name, 'Unknown') AS employee_name,
+            d.leave_date,
+            d.minutes,
+            lt.code AS leave_type_code,
+            lt.name AS description
+        FROM tk_leave_request_days d
+        JOIN tk_leave_requests r ON r.id = d.request_id
+        JOIN tk_leave_types lt ON lt.id = r.leave_type_id
+        LEFT JOIN tk_employees emp ON emp.id = r.employee_id
+        WHERE r.app_id = $1
+          AND r.status = 'approved'
+          AND lt.is_paid = TRUE
+          AND d.leave_date >= $2
+          AND d.leave_date <= $3
+        ORDER BY d.leave_date, r.employee_id, r.id
+        "#,
+    )
+    .bind(app_id)
+    .bind(from)
+    .bind(to)
+    .fetch_all(pool)
+    .await?)
+}
+
+// ============================================================================
+// Ledger
+// ============================================================================
+
+/// Append a ledger row. Returns `None` if the (kind, source_ref) was already
+/// posted for this employee and leave type — reruns are no-ops.
+#[allow(clippy::too_many_arguments)]
+pub async fn insert_ledger_entry(
+    conn: &mut PgConnection,
+    app_id: &str,
+    employee_id: Uuid,
+    leave_type_id: Uuid,
+    entry_kind: &str,
+    minutes: i32,
+    effective_date: NaiveDate,
+    source_ref: &str,
+) -> Result<Option<LedgerEntry>, LeaveError> {
+    let sql = format!(
+        r#"INSERT INTO tk_leave_ledger
+            (app_id, employee_id, leave_type_id, entry_kind, minutes, effective_date, source_ref)
+        VALUES ($1, $2, $3, $4, $5, $6, $7)
+        ON CONFLICT ON CONSTRAINT tk_leave_ledger_source_unique DO NOTHING
+        RETURNING {}"#,
+        LEDGER_COLS
+    );
+    Ok(sqlx::query_as::<_, LedgerEntry>(&sql)
+        .bind(app_id)
+        .bind(employee_id)
+        .bind(leave_type_id)
+        .bind(entry_kind)
+        .bind(minutes)
+        .bind(effective_date)
+        .bind(source_ref)
+        .fetch_optional(conn)
+        .await?)
+}
+
+pub async fn balance_as_of(
+    conn: &mut PgConnection,
+    app_id: &str,
+    employee_id: Uuid,
+    leave_type_id: Uuid,
+    as_of: NaiveDate,
+) -> Result<i64, LeaveError> {
+    let row: (i64,) = sqlx::query_as(
+        "SELECT COALESCE(SUM(minutes), 0)::BIGINT FROM tk_leave_ledger \
+         WHERE app_id = $1 AND employee_id = $2 AND leave_type_id = $3 \
+           AND effective_date <= $4",
+    )
+    .bind(app_id)
+    .bind(employee_id)
+    .bind(leave_type_id)
+    .bind(as_of)
+    .fetch_one(conn)
+    .await?;
+    Ok(row.0)
+}
+
+/// Balances for every active balance-tracked leave type as of a date.
+pub async fn balances_as_of(
+    pool: &PgPool,
+    app_id: &str,
+    employee_id: Uuid,
+    as_of: NaiveDate,
+) -> Result<Vec<LeaveBalance>, LeaveError> {
+    Ok(sqlx::query_as::<_, LeaveBalance>(
+        r#"
+        SELECT
+            $2::UUID AS employee_id,
+            lt.id AS leave_type_id,
+            lt.code AS leave_type_code,
+            $3::DATE AS as_of,
+            COALESCE(SUM(l.minutes), 0)::BIGINT AS balance_minutes
+        FROM tk_leave_types lt
+        LEFT JOIN tk_leave_ledger l
+            ON l.leave_type_id = lt.id
+            AND l.app_id = lt.app_id
+            AND l.employee_id = $2
+            AND l.effective_date <= $3
+        WHERE lt.app_id = $1
+          AND lt.active = TRUE
+          AND lt.tracks_balance = TRUE
+        GROUP BY lt.id, lt.code
+        ORDER BY lt.code
+        "#,
+    )
+    .bind(app_id)
+    .bind(employee_id)
+    .bind(as_of)
+    .fetch_all(pool)
+    .await?)
+}
+
+pub async fn list_ledger(
+    pool: &PgPool,
+    app_id: &str,
+    employee_id: Uuid,
+    leave_type_id: Option<Uuid>,
+) -> Result<Vec<LedgerEntry>, LeaveError> {
+    let sql = format!(
+        "SELECT {} FROM tk_leave_ledger \
+         WHERE app_id = $1 AND employee_id = $2 \
+           AND ($3::UUID IS NULL OR leave_type_id = $3) \
+         ORDER BY effective_date, id",
+        LEDGER_COLS
+    );
+    Ok(sqlx::query_as::<_, LedgerEntry>(&sql)
+        .bind(app_id)
+        .bind(employee_id)
+        .bind(leave_type_id)
+        .fetch_all(pool)
+        .await?)
+}
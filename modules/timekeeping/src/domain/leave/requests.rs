@@ -0,0 +1,337 @@
+//! Leave request workflow — Guard→Mutation→Outbox atomicity.
+//!
+//! Reuses the timesheet approval state machine (`ApprovalStatus`):
+//! submit creates the request as submitted; approve / reject / recall are
+//! only valid from submitted. Approval posts a `usage` row to the ledger for
+//! balance-tracked leave types, re-checking the balance under the row lock.
+
+use chrono::Utc;
+use sqlx::{PgConnection, PgPool};
+use uuid::Uuid;
+
+use super::models::*;
+use super::{calc, repo, service};
+use crate::domain::approvals::models::ApprovalStatus;
+use crate::events;
+
+const EVT_LEAVE_SUBMITTED: &str = "leave_request.submitted";
+const EVT_LEAVE_APPROVED: &str = "leave_request.approved";
+const EVT_LEAVE_REJECTED: &str = "leave_request.rejected";
+const EVT_LEAVE_RECALLED: &str = "leave_request.recalled";
+
+const DEFAULT_MINUTES_PER_DAY: i32 = 480;
+
+// -- Reads ------------------------------------------------------------------
+
+pub async fn get_request(
+    pool: &PgPool,
+    app_id: &str,
+    id: Uuid,
+) -> Result<LeaveRequest, LeaveError> {
+    let mut request = repo::fetch_request(pool, app_id, id)
+        .await?
+        .ok_or(LeaveError::NotFound)?;
+    request.days = repo::list_request_days(pool, id).await?;
+    Ok(request)
+}
+
+pub async fn list_requests(
+    pool: &PgPool,
+    app_id: &str,
+    employee_id: Option<Uuid>,
+    status: Option<&ApprovalStatus>,
+) -> Result<Vec<LeaveRequest>, LeaveError> {
+    repo::list_requests(pool, app_id, employee_id, status).await
+}
+
+// -- Submit -----------------------------------------------------------------
+
+pub async fn submit(pool: &PgPool, req: &SubmitLeaveRequest) -> Result<LeaveRequest, LeaveError> {
+    req.validate()?;
+
+    let now = Utc::now();
+    let event_id = Uuid::new_v4();
+    let mut tx = pool.begin().await?;
+
+    let group = repo::fetch_employee_group(&mut tx, &req.app_id, req.employee_id)
+        .await?
+        .ok_or(LeaveError::NotFound)?;
+    let leave_type = repo::fetch_leave_type(&mut tx, &req.app_id, req.leave_type_id)
+        .await?
+        .filter(|t| t.active)
+        .ok_or(LeaveError::NotFound)?;
+
+    let holidays = service::holidays_for_group(
+        &mut tx,
+        &req.app_id,
+        group.as_deref(),
+        req.start_date,
+        req.end_date,
+    )
+    .await?;
+    let days = calc::working_days(
+        req.start_date,
+        req.end_date,
+        &holidays,
+        req.minutes_per_day.unwrap_or(DEFAULT_MINUTES_PER_DAY),
+    );
+    if days.is_empty() {
+        return Err(LeaveError::Validation(
+            "requested range contains no working days".into(),
+        ));
+    }
+    let total_minutes: i32 = days.iter().map(|d| d.minutes).sum();
+
+    if leave_type.tracks_balance {
+        ensure_balance(
+            &mut tx,
+            &req.app_id,
+            req.employee_id,
+            &leave_type,
+            req,
+            total_minutes,
+        )
+        .await?;
+    }
+
+    let mut request = repo::insert_request(
+        &mut tx,
+        &req.app_id,
+        req.employee_id,
+        req.leave_type_id,
+        req.start_date,
+        req.end_date,
+        total_minutes,
+        req.reason.as_deref(),
+        now,
+    )
+    .await?;
+    repo::insert_request_days(&mut tx, request.id, &days).await?;
+    repo::record_action(&mut tx, request.id, "submit", req.actor_id, None).await?;
+
+    let payload = serde_json::json!({
+        "request_id": request.id,
+        "app_id": req.app_id,
+        "employee_id": req.employee_id,
+        "leave_type": leave_type.code,
+        "start_date": req.start_date,
+        "end_date": req.end_date,
+        "total_minutes": total_minutes,
+    });
+    events::enqueue_event_tx(
+        &mut tx,
+        event_id,
+        EVT_LEAVE_SUBMITTED,
+        "leave_request",
+        &request.id.to_string(),
+        &payload,
+    )
+    .await?;
+
+    tx.commit().await?;
+    request.days = days;
+    Ok(request)
+}
+
+// -- Approve (submitted → approved, posts usage) ----------------------------
+
+pub async fn approve(pool: &PgPool, req: &ReviewLeaveRequest) -> Result<LeaveRequest, LeaveError> {
+    validate_review(req)?;
+
+    let now = Utc::now();
+    let event_id = Uuid::new_v4();
+    let mut tx = pool.begin().await?;
+
+    let current = repo::fetch_request_for_update(&mut tx, &req.app_id, req.request_id).await?;
+    current.status.ensure_submitted("approved")?;
+
+    let leave_type = repo::fetch_leave_type(&mut tx, &req.app_id, current.leave_type_id)
+        .await?
+        .ok_or(LeaveError::NotFound)?;
+    if leave_type.tracks_balance {
+        let available = repo::balance_as_of(
+            &mut tx,
+            &req.app_id,
+            current.employee_id,
+            current.leave_type_id,
+            current.start_date,
+        )
+        .await?;
+        if available < current.total_minutes as i64 {
+            return Err(LeaveError::InsufficientBalance {
+                requested: current.total_minutes as i64,
+                available,
+            });
+        }
+        repo::insert_ledger_entry(
+            &mut tx,
+            &req.app_id,
+            current.employee_id,
+            current.leave_type_id,
+            "usage",
+            -current.total_minutes,
+            current.start_date,
+            &current.id.to_string(),
+        )
+        .await?;
+    }
+
+    let request = repo::update_status(
+        &mut tx,
+        req.request_id,
+        ApprovalStatus::Approved,
+        Some(req.actor_id),
+        req.notes.as_deref(),
+        now,
+    )
+    .await?;
+    repo::record_action(
+        &mut tx,
+        request.id,
+        "approve",
+        req.actor_id,
+        req.notes.as_deref(),
+    )
+    .await?;
+
+    let payload = serde_json::json!({
+        "request_id": request.id,
+        "app_id": request.app_id,
+        "employee_id": request.employee_id,
+        "leave_type": leave_type.code,
+        "start_date": request.start_date,
+        "end_date": request.end_date,
+        "total_minutes": request.total_minutes,
+        "reviewer_id": req.actor_id,
+    });
+    events::enqueue_event_tx(
+        &mut tx,
+        event_id,
+        EVT_LEAVE_APPROVED,
+        "leave_request",
+        &request.id.to_string(),
+        &payload,
+    )
+    .await?;
+
+    tx.commit().await?;
+    Ok(request)
+}
+
+// -- Reject (submitted → rejected) ------------------------------------------
+
+pub async fn reject(pool: &PgPool, req: &ReviewLeaveRequest) -> Result<LeaveRequest, LeaveError> {
+    validate_review(req)?;
+    transition(
+        pool,
+        req,
+        ApprovalStatus::Rejected,
+        "rejected",
+        "reject",
+        EVT_LEAVE_REJECTED,
+    )
+    .await
+}
+
+// -- Recall (submitted → draft) ---------------------------------------------
+
+pub async fn recall(pool: &PgPool, req: &ReviewLeaveRequest) -> Result<LeaveRequest, LeaveError> {
+    validate_review(req)?;
+    transition(
+        pool,
+        req,
+        ApprovalStatus::Draft,
+        "draft",
+        "recall",
+        EVT_LEAVE_RECALLED,
+    )
+    .await
+}
+
+// -- Internal helpers --------------------------------------------------------
+
+/// Reject / recall: no ledger effect, only status, audit and outbox.
+async fn transition(
+    pool: &PgPool,
+    req: &ReviewLeaveRequest,
+    to: ApprovalStatus,
+    to_label: &str,
+    action: &str,
+    event_type: &str,
+) -> Result<LeaveRequest, LeaveError> {
+    let now = Utc::now();
+    let event_id = Uuid::new_v4();
+    let mut tx = pool.begin().await?;
+
+    let current = repo::fetch_request_for_update(&mut tx, &req.app_id, req.request_id).await?;
+    current.status.ensure_submitted(to_label)?;
+
+    // Recall is the employee pulling the request back, not a review
+    let reviewer = (to != ApprovalStatus::Draft).then_some(req.actor_id);
+    let request = repo::update_status(
+        &mut tx,
+        req.request_id,
+        to,
+        reviewer,
+        req.notes.as_deref(),
+        now,
+    )
+    .await?;
+    repo::record_action(
+        &mut tx,
+        request.id,
+        action,
+        req.actor_id,
+        req.notes.as_deref(),
+    )
+    .await?;
+
+    let payload = serde_json::json!({
+        "request_id": request.id,
+        "app_id": request.app_id,
+        "employee_id": request.employee_id,
+        "start_date": request.start_date,
+        "end_date": request.end_date,
+        "actor_id": req.actor_id,
+        "notes": req.notes,
+    });
+    events::enqueue_event_tx(
+        &mut tx,
+        event_id,
+        event_type,
+        "leave_request",
+        &request.id.to_string(),
+        &payload,
+    )
+    .await?;
+
+    tx.commit().await?;
+    Ok(request)
+}
+
+/// Balance as of the first leave day must cover the request.
+async fn ensure_balance(
+    conn: &mut PgConnection,
+    app_id: &str,
+    employee_id: Uuid,
+    leave_type: &LeaveType,
+    req: &SubmitLeaveRequest,
+    total_minutes: i32,
+) -> Result<(), LeaveError> {
+    let available =
+        repo::balance_as_of(conn, app_id, employee_id, leave_type.id, req.start_date).await?;
+    if available < total_minutes as i64 {
+        return Err(LeaveError::InsufficientBalance {
+            requested: total_minutes as i64,
+            available,
+        });
+    }
+    Ok(())
+}
+
+fn validate_review(req: &ReviewLeaveRequest) -> Result<(), LeaveError> {
+    if req.app_id.trim().is_empty() {
+        return Err(LeaveError::Validation("app_id must not be empty".into()));
+    }
+    Ok(())
+}
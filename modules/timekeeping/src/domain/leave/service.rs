@@ -0,0 +1,224 @@
+//! Leave service — leave types, accrual policies, holiday calendars, balance
+//! queries and the payroll export feed.
+//!
+//! Request workflow lives in `requests`, accrual posting in `accrual`.
+
+use std::collections::{HashMap, HashSet};
+
+use chrono::NaiveDate;
+use sqlx::{PgConnection, PgPool};
+use uuid::Uuid;
+
+use super::calc;
+use super::models::*;
+use super::repo;
+
+// ============================================================================
+// Leave types
+// ============================================================================
+
+pub async fn create_leave_type(
+    pool: &PgPool,
+    req: &CreateLeaveTypeRequest,
+) -> Result<LeaveType, LeaveError> {
+    req.validate()?;
+    repo::insert_leave_type(pool, req).await
+}
+
+pub async fn list_leave_types(pool: &PgPool, app_id: &str) -> Result<Vec<LeaveType>, LeaveError> {
+    repo::list_leave_types(pool, app_id).await
+}
+
+// ============================================================================
+// Accrual policies
+// ============================================================================
+
+pub async fn create_policy(
+    pool: &PgPool,
+    req: &CreateAccrualPolicyRequest,
+) -> Result<AccrualPolicy, LeaveError> {
+    req.validate()?;
+    let mut conn = pool.acquire().await?;
+    let leave_type = repo::fetch_leave_type(&mut conn, &req.app_id, req.leave_type_id)
+        .await?
+        .ok_or(LeaveError::NotFound)?;
+    if !leave_type.tracks_balance {
+        return Err(LeaveError::Validation(format!(
+            "leave type '{}' does not track a balance",
+            leave_type.code
+        )));
+    }
+    repo::insert_policy(pool, req).await
+}
+
+pub async fn list_policies(pool: &PgPool, app_id: &str) -> Result<Vec<AccrualPolicy>, LeaveError> {
+    let mut conn = pool.acquire().await?;
+    repo::list_active_policies(&mut conn, app_id).await
+}
+
+// ============================================================================
+// Holiday calendars
+// ============================================================================
+
+/// Create a holiday calendar with its holidays atomically.
+pub async fn create_calendar(
+    pool: &PgPool,
+    req: &CreateHolidayCalendarRequest,
+) -> Result<HolidayCalendar, LeaveError> {
+    req.validate()?;
+    let mut tx = pool.begin().await?;
+    if let Some(type_id) = req.leave_type_id {
+        repo::fetch_leave_type(&mut tx, &req.app_id, type_id)
+            .await?
+            .ok_or(LeaveError::NotFound)?;
+    }
+    let mut calendar = repo::insert_calendar(&mut tx, req).await?;
+    for h in &req.holidays {
+        let created = repo::insert_holiday(&mut tx, calendar.id, h).await?;
+        calendar.holidays.push(created);
+    }
+    tx.commit().await?;
+    calendar.holidays.sort_by_key(|h| h.holiday_date);
+    Ok(calendar)
+}
+
+pub async fn add_holiday(
+    pool: &PgPool,
+    app_id: &str,
+    calendar_id: Uuid,
+    req: &CreateHolidayRequest,
+) -> Result<Holiday, LeaveError> {
+    req.validate()?;
+    let mut tx = pool.begin().await?;
+    repo::fetch_calendar(&mut tx, app_id, calendar_id)
+        .await?
+        .ok_or(LeaveError::NotFound)?;
+    let holiday = repo::insert_holiday(&mut tx, calendar_id, req).await?;
+    tx.commit().await?;
+    Ok(holiday)
+}
+
+/// List active calendars with their holidays attached.
+pub async fn list_calendars(
+    pool: &PgPool,
+    app_id: &str,
+) -> Result<Vec<HolidayCalendar>, LeaveError> {
+    let mut conn = pool.acquire().await?;
+    let mut calendars = repo::list_active_calendars(&mut conn, app_id).await?;
+    let ids: Vec<Uuid> = calendars.iter().map(|c| c.id).collect();
+    let mut by_calendar: HashMap<Uuid, Vec<Holiday>> = HashMap::new();
+    for h in repo::list_holidays(&mut conn, &ids, None, None).await? {
+        by_calendar.entry(h.calendar_id).or_default().push(h);
+    }
+    for c in &mut calendars {
+        c.holidays = by_calendar.remove(&c.id).unwrap_or_default();
+    }
+    Ok(calendars)
+}
+
+/// Holiday dates that apply to an employee group within a range.
+pub(crate) async fn holidays_for_group(
+    conn: &mut PgConnection,
+    app_id: &str,
+    group: Option<&str>,
+    from: NaiveDate,
+    to: NaiveDate,
+) -> Result<HashSet<NaiveDate>, LeaveError> {
+    let calendars = repo::list_active_calendars(conn, app_id).await?;
+    let Some(calendar) =
+        calc::resolve_for_group(&calendars, group, |c| c.employee_group.as_deref())
+    else {
+        return Ok(HashSet::new());
+    };
+    Ok(
+        repo::list_holidays(conn, &[calendar.id], Some(from), Some(to))
+            .await?
+            .into_iter()
+            .map(|h| h.holiday_date)
+            .collect(),
+    )
+}
+
+// ============================================================================
+// Balances
+// ============================================================================
+
+/// Balance per balance-tracked leave type as of a date (inclusive).
+pub async fn balances(
+    pool: &PgPool,
+    app_id: &str,
+    employee_id: Uuid,
+    as_of: NaiveDate,
+) -> Result<Vec<LeaveBalance>, LeaveError> {
+    repo::balances_as_of(pool, app_id, employee_id, as_of).await
+}
+
+pub async fn ledger(
+    pool: &PgPool,
+    app_id: &str,
+    employee_id: Uuid,
+    leave_type_id: Option<Uuid>,
+) -> Result<Vec<LedgerEntry>, LeaveError> {
+    repo::list_ledger(pool, app_id, employee_id, leave_type_id).await
+}
+
+// ============================================================================
+// Payroll export feed
+// ============================================================================
+
+/// Paid leave lines for the payroll export: approved leave request days plus
+/// calendar holidays for every active employee, in (date, employee, source)
+/// order.
+pub async fn export_lines(
+    pool: &PgPool,
+    app_id: &str,
+    from: NaiveDate,
+    to: NaiveDate,
+) -> Result<Vec<LeaveExportLine>, LeaveError> {
+    let mut lines = repo::fetch_approved_leave_days(pool, app_id, from, to).await?;
+
+    let mut conn = pool.acquire().await?;
+    let calendars = repo::list_active_calendars(&mut conn, app_id).await?;
+    if !calendars.is_empty() {
+        let ids: Vec<Uuid> = calendars.iter().map(|c| c.id).collect();
+        let mut holidays: HashMap<Uuid, Vec<Holiday>> = HashMap::new();
+        for h in repo::list_holidays(&mut conn, &ids, Some(from), Some(to)).await? {
+            if h.paid_minutes > 0 {
+                holidays.entry(h.calendar_id).or_default().push(h);
+            }
+        }
+        let type_codes: HashMap<Uuid, String> = repo::list_leave_types(pool, app_id)
+            .await?
+            .into_iter()
+            .map(|t| (t.id, t.code))
+            .collect();
+
+        for (employee_id, group, name) in repo::list_active_employees(&mut conn, app_id).await? {
+            let Some(calendar) = calc::resolve_for_group(&calendars, group.as_deref(), |c| {
+                c.employee_group.as_deref()
+            }) else {
+                continue;
+            };
+            let code = calendar
+                .leave_type_id
+                .and_then(|id| type_codes.get(&id).cloned())
+                .unwrap_or_else(|| "HOLIDAY".to_string());
+            for h in holidays.get(&calendar.id).into_iter().flatten() {
+                lines.push(LeaveExportLine {
+                    source_id: h.id,
+                    employee_id,
+                    employee_name: name.clone(),
+                    leave_date: h.holiday_date,
+                    minutes: h.paid_minutes,
+                    leave_type_code: code.clone(),
+                    description: h.name.clone(),
+                });
+            }
+        }
+    }
+
+    lines.sort_by(|a, b| {
+        (a.leave_date, a.employee_id, a.source_id).cmp(&(b.leave_date, b.employee_id, b.source_id))
+    });
+    Ok(lines)
+}
@@ -6,4 +6,6 @@ pub mod employees;
 pub mod entries;
 pub mod export;
 pub mod integrations;
+pub mod leave;
+pub mod pay_rules;
 pub mod projects;
@@ -0,0 +1,377 @@
+//! Pay rule engine — pure classification of approved minutes into pay buckets.
+//!
+//! ## Order of evaluation (per employee, per work date)
+//! 1. Meal break: if the day's recorded minutes exceed `meal_break_after_minutes`,
+//!    `meal_break_minutes` are deducted, taken from the day's last entries first.
+//! 2. Double-time weekday: every paid minute on a listed weekday is DT.
+//! 3. Daily thresholds: minutes past `daily_ot_after_minutes` are OT, minutes
+//!    past `daily_dt_after_minutes` are DT.
+//! 4. Weekly threshold: regular minutes past `weekly_ot_after_minutes` within
+//!    the work week (starting on `week_start_day`) are reclassified as OT.
+//!
+//! Weekly accumulation only sees the entries passed in, so callers must pass
+//! whole work weeks; `service::classify_entries` widens a period to the work
+//! weeks it overlaps before classifying.
+//!
+//! Shift-differential premium is computed from clock sessions, attributed to
+//! the session's local start date, capped at that day's paid minutes and
+//! spread across the day's entries in order.
+
+use std::collections::BTreeMap;
+
+use chrono::{Datelike, Duration, NaiveDate, NaiveDateTime};
+use uuid::Uuid;
+
+use super::models::{PayBuckets, PayRuleSet, ShiftDifferential};
+
+/// Minutes of one approved entry, the unit of classification.
+#[derive(Debug, Clone)]
+pub struct EntryMinutes {
+    pub entry_id: Uuid,
+    pub employee_id: Uuid,
+    pub work_date: NaiveDate,
+    pub minutes: i32,
+}
+
+/// A closed clock session expressed in the rule set's local time.
+#[derive(Debug, Clone, Copy)]
+pub struct SessionSpan {
+    pub start: NaiveDateTime,
+    pub end: NaiveDateTime,
+}
+
+/// Premium minutes and priced premium (minor units) earned on a work date.
+pub type DailyPremiums = BTreeMap<NaiveDate, (i32, i64)>;
+
+/// First day of the work week containing `date`.
+pub fn week_start(date: NaiveDate, week_start_day: i16) -> NaiveDate {
+    let day = date.weekday().number_from_monday() as i64;
+    let back = (day - week_start_day as i64).rem_euclid(7);
+    date - Duration::days(back)
+}
+
+/// Length of `[start, end)` intersected with `[lo, hi)`, never negative.
+fn segment(start: i32, end: i32, lo: i32, hi: i32) -> i32 {
+    (end.min(hi) - start.max(lo)).max(0)
+}
+
+/// Compute shift-differential premiums per local work date.
+pub fn shift_premiums(diffs: &[ShiftDifferential], sessions: &[SessionSpan]) -> DailyPremiums {
+    let mut out = DailyPremiums::new();
+    for s in sessions.iter().filter(|s| s.end > s.start) {
+        let mut minutes = 0i32;
+        let mut minor = 0i64;
+        for d in diffs {
+            let mut overlap = 0i64;
+            // A window anchored on the previous day may wrap into this session.
+            let mut day = s.start.date() - Duration::days(1);
+            while day <= s.end.date() {
+                let w_start = day.and_time(d.start_time);
+                let w_end = if d.end_time > d.start_time {
+                    day.and_time(d.end_time)
+                } else {
+                    (day + Duration::days(1)).and_time(d.end_time)
+                };
+                let lo = w_start.max(s.start);
+                let hi = w_end.min(s.end);
+                if hi > lo {
+                    overlap += (hi - lo).num_minutes();
+                }
+                day += Duration::days(1);
+            }
+            minutes += overlap as i32;
+            minor += (overlap * d.premium_minor_per_hour + 30) / 60;
+        }
+        if minutes > 0 {
+            let slot = out.entry(s.start.date()).or_insert((0, 0));
+            slot.0 += minutes;
+            slot.1 += minor;
+        }
+    }
+    out
+}
+
+/// Classify one employee's entries into pay buckets.
+///
+/// With no rule set every recorded minute is regular and no premium applies.
+/// Results are returned in (work_date, entry_id) order.
+pub fn classify(
+    rules: Option<&PayRuleSet>,
+    entries: &[EntryMinutes],
+    premiums: &DailyPremiums,
+) -> Vec<(Uuid, PayBuckets)> {
+    let mut sorted: Vec<&EntryMinutes> = entries.iter().collect();
+    sorted.sort_by_key(|e| (e.work_date, e.entry_id));
+
+    let Some(rules) = rules else {
+        return sorted
+            .iter()
+            .map(|e| {
+                (
+                    e.entry_id,
+                    PayBuckets {
+                        regular_minutes: e.minutes,
+                        ..Default::default()
+                    },
+                )
+            })
+            .collect();
+    };
+
+    let ot_at = rules.daily_ot_after_minutes.unwrap_or(i32::MAX);
+    let dt_at = rules.daily_dt_after_minutes.unwrap_or(i32::MAX).max(ot_at);
+
+    let mut out = Vec::with_capacity(sorted.len());
+    let mut current_week: Option<NaiveDate> = None;
+    let mut weekly_regular = 0i32;
+
+    for day in sorted.chunk_by(|a, b| a.work_date == b.work_date) {
+        let date = day[0].work_date;
+        let week = week_start(date, rules.week_start_day);
+        if current_week != Some(week) {
+            current_week = Some(week);
+            weekly_regular = 0;
+        }
+
+        // 1. Meal break deduction, taken from the last entries of the day
+        let day_total: i32 = day.iter().map(|e| e.minutes).sum();
+        let mut deduction = match rules.meal_break_after_minutes {
+            Some(after) if day_total > after => rules.meal_break_minutes.min(day_total),
+            _ => 0,
+        };
+        let mut paid: Vec<i32> = day.iter().map(|e| e.minutes).collect();
+        let mut deducted = vec![0; day.len()];
+        for i in (0..day.len()).rev() {
+            let take = deduction.min(paid[i]);
+            paid[i] -= take;
+            deducted[i] = take;
+            deduction -= take;
+        }
+
+        // Premium for the day, capped at paid minutes
+        let day_paid: i32 = paid.iter().sum();
+        let (raw_prem_minutes, raw_prem_minor) = premiums.get(&date).copied().unwrap_or((0, 0));
+        let prem_minutes = raw_prem_minutes.min(day_paid);
+        let prem_minor = if raw_prem_minutes > 0 {
+            raw_prem_minor * prem_minutes as i64 / raw_prem_minutes as i64
+        } else {
+            0
+        };
+        let mut prem_minutes_left = prem_minutes;
+        let mut prem_minor_left = prem_minor;
+
+        let dt_day = rules
+            .double_time_weekdays
+            .contains(&(date.weekday().number_from_monday() as i16));
+
+        let mut day_worked = 0i32;
+        for (i, e) in day.iter().enumerate() {
+            let mut b = PayBuckets {
+                meal_break_deducted_minutes: deducted[i],
+                ..Default::default()
+            };
+
+            if dt_day {
+                b.double_time_minutes = paid[i];
+            } else {
+                // 2–3. Daily thresholds on the running day total
+                let start = day_worked;
+                let end = day_worked + paid[i];
+                let mut regular = segment(start, end, 0, ot_at);
+                b.overtime_minutes = segment(start, end, ot_at, dt_at);
+                b.double_time_minutes = segment(start, end, dt_at, i32::MAX);
+
+                // 4. Weekly threshold on remaining regular minutes
+                if let Some(weekly) = rules.weekly_ot_after_minutes {
+                    let room = (weekly - weekly_regular).max(0);
+                    let moved = (regular - room).max(0);
+                    regular -= moved;
+                    b.overtime_minutes += moved;
+                }
+                weekly_regular += regular;
+                b.regular_minutes = regular;
+                day_worked = end;
+            }
+
+            // Premium spread across the day's entries in order
+            let p = prem_minutes_left.min(paid[i]);
+            if p > 0 {
+                prem_minutes_left -= p;
+                let minor = if prem_minutes_left == 0 {
+                    prem_minor_left
+                } else {
+                    prem_minor * p as i64 / prem_minutes as i64
+                };
+                prem_minor_left -= minor;
+                b.premium_minutes = p;
+                b.premium_minor = minor;
+            }
+
+            out.push((e.entry_id, b));
+        }
+    }
+
+    out
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{NaiveTime, Utc};
+
+    fn rules() -> PayRuleSet {
+        PayRuleSet {
+            id: Uuid::nil(),
+            app_id: "acme".into(),
+            name: "Plant".into(),
+            employee_group: None,
+            daily_ot_after_minutes: Some(480),
+            daily_dt_after_minutes: Some(720),
+            weekly_ot_after_minutes: Some(2400),
+            week_start_day: 1,
+            double_time_weekdays: vec![7],
+            ot_multiplier_bp: 15000,
+            dt_multiplier_bp: 20000,
+            meal_break_after_minutes: None,
+            meal_break_minutes: 0,
+            timezone: "UTC".into(),
+            is_active: true,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+            shift_differentials: vec![],
+        }
+    }
+
+    fn date(d: u32) -> NaiveDate {
+        // March 2026: the 2nd is a Monday, the 8th a Sunday
+        NaiveDate::from_ymd_opt(2026, 3, d).unwrap()
+    }
+
+    fn entry(n: u128, d: u32, minutes: i32) -> EntryMinutes {
+        EntryMinutes {
+            entry_id: Uuid::from_u128(n),
+            employee_id: Uuid::nil(),
+            work_date: date(d),
+            minutes,
+        }
+    }
+
+    fn total(results: &[(Uuid, PayBuckets)]) -> PayBuckets {
+        let mut t = PayBuckets::default();
+        for (_, b) in results {
+            t.add(b);
+        }
+        t
+    }
+
+    #[test]
+    fn no_rules_is_all_regular() {
+        let r = classify(None, &[entry(1, 2, 700)], &DailyPremiums::new());
+        assert_eq!(r[0].1.regular_minutes, 700);
+        assert_eq!(r[0].1.overtime_minutes, 0);
+    }
+
+    #[test]
+    fn daily_ot_and_dt_split_across_entries() {
+        let rules = rules();
+        let r = classify(
+            Some(&rules),
+            &[entry(1, 2, 420), entry(2, 2, 360)],
+            &DailyPremiums::new(),
+        );
+        // 420 regular; second entry: 60 regular, 240 OT, 60 DT
+        assert_eq!(r[0].1.regular_minutes, 420);
+        assert_eq!(r[1].1.regular_minutes, 60);
+        assert_eq!(r[1].1.overtime_minutes, 240);
+        assert_eq!(r[1].1.double_time_minutes, 60);
+    }
+
+    #[test]
+    fn weekly_ot_after_forty_hours() {
+        let rules = rules();
+        let entries: Vec<_> = (2..=7).map(|d| entry(d as u128, d, 480)).collect();
+        let t = total(&classify(Some(&rules), &entries, &DailyPremiums::new()));
+        assert_eq!(t.regular_minutes, 2400);
+        assert_eq!(t.overtime_minutes, 480);
+    }
+
+    #[test]
+    fn weekly_counter_resets_on_week_start() {
+        let rules = rules();
+        // Mon 2 .. Fri 6 = 40h, then Mon 9 starts a new week
+        let mut entries: Vec<_> = (2..=6).map(|d| entry(d as u128, d, 480)).collect();
+        entries.push(entry(9, 9, 480));
+        let t = total(&classify(Some(&rules), &entries, &DailyPremiums::new()));
+        assert_eq!(t.regular_minutes, 2880);
+        assert_eq!(t.overtime_minutes, 0);
+    }
+
+    #[test]
+    fn sunday_is_double_time() {
+        let rules = rules();
+        let r = classify(Some(&rules), &[entry(1, 8, 240)], &DailyPremiums::new());
+        assert_eq!(r[0].1.double_time_minutes, 240);
+        assert_eq!(r[0].1.regular_minutes, 0);
+    }
+
+    #[test]
+    fn meal_break_deducted_from_last_entry() {
+        let mut rules = rules();
+        rules.meal_break_after_minutes = Some(360);
+        rules.meal_break_minutes = 30;
+        let r = classify(
+            Some(&rules),
+            &[entry(1, 2, 300), entry(2, 2, 180)],
+            &DailyPremiums::new(),
+        );
+        assert_eq!(r[1].1.meal_break_deducted_minutes, 30);
+        assert_eq!(r[1].1.regular_minutes, 150);
+        let t = total(&r);
+        assert_eq!(t.paid_minutes() + t.meal_break_deducted_minutes, 480);
+    }
+
+    #[test]
+    fn night_window_wraps_midnight() {
+        let diffs = vec![ShiftDifferential {
+            id: Uuid::nil(),
+            rule_set_id: Uuid::nil(),
+            name: "Night".into(),
+            start_time: NaiveTime::from_hms_opt(22, 0, 0).unwrap(),
+            end_time: NaiveTime::from_hms_opt(6, 0, 0).unwrap(),
+            premium_minor_per_hour: 120,
+        }];
+        let sessions = [SessionSpan {
+            start: date(2).and_hms_opt(20, 0, 0).unwrap(),
+            end: date(3).and_hms_opt(4, 0, 0).unwrap(),
+        }];
+        let p = shift_premiums(&diffs, &sessions);
+        assert_eq!(p.get(&date(2)), Some(&(360, 720)));
+    }
+
+    #[test]
+    fn premium_capped_and_spread_over_entries() {
+        let rules = rules();
+        let mut premiums = DailyPremiums::new();
+        premiums.insert(date(2), (300, 600));
+        let r = classify(
+            Some(&rules),
+            &[entry(1, 2, 120), entry(2, 2, 120)],
+            &premiums,
+        );
+        let t = total(&r);
+        assert_eq!(t.premium_minutes, 240);
+        assert_eq!(t.premium_minor, 480);
+        assert_eq!(r[0].1.premium_minutes, 120);
+    }
+
+    #[test]
+    fn week_start_respects_configured_day() {
+        // Sunday-start week: Wed Mar 4 belongs to week starting Sun Mar 1
+        assert_eq!(week_start(date(4), 7), date(1));
+        assert_eq!(week_start(date(4), 1), date(2));
+    }
+}
@@ -0,0 +1,4 @@
+pub mod engine;
+pub mod models;
+pub mod repo;
+pub mod service;
@@ -0,0 +1,327 @@
+//! Pay rule set domain models and request types.
+//!
+//! A pay rule set classifies approved minutes into regular / overtime /
+//! double-time buckets and computes shift-differential premium minutes.
+//!
+//! Invariants:
+//! - One active rule set per (app_id, employee_group); NULL group = tenant default
+//! - daily_dt_after_minutes >= daily_ot_after_minutes when both are set
+//! - Multipliers are basis points (15000 = 1.5x) and never below 1.0x
+//! - Weekdays are ISO numbers (1 = Monday … 7 = Sunday)
+
+use chrono::{DateTime, NaiveTime, Utc};
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+// ============================================================================
+// Domain models
+// ============================================================================
+
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow, ToSchema)]
+pub struct PayRuleSet {
+    pub id: Uuid,
+    pub app_id: String,
+    pub name: String,
+    pub employee_group: Option<String>,
+    pub daily_ot_after_minutes: Option<i32>,
+    pub daily_dt_after_minutes: Option<i32>,
+    pub weekly_ot_after_minutes: Option<i32>,
+    pub week_start_day: i16,
+    pub double_time_weekdays: Vec<i16>,
+    pub ot_multiplier_bp: i32,
+    pub dt_multiplier_bp: i32,
+    pub meal_break_after_minutes: Option<i32>,
+    pub meal_break_minutes: i32,
+    pub timezone: String,
+    pub is_active: bool,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    #[sqlx(skip)]
+    pub shift_differentials: Vec<ShiftDifferential>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow, ToSchema)]
+pub struct ShiftDifferential {
+    pub id: Uuid,
+    pub rule_set_id: Uuid,
+    pub name: String,
+    pub start_time: NaiveTime,
+    pub end_time: NaiveTime,
+    pub premium_minor_per_hour: i64,
+}
+
+/// Minutes of a single approved entry split into pay buckets.
+///
+/// `regular + overtime + double_time + meal_break_deducted` always equals the
+/// entry's recorded minutes. Premium minutes are an add-on (shift differential)
+/// and overlap the other buckets rather than adding to them.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub struct PayBuckets {
+    pub regular_minutes: i32,
+    pub overtime_minutes: i32,
+    pub double_time_minutes: i32,
+    pub premium_minutes: i32,
+    /// Shift-differential premium in minor units (already priced).
+    pub premium_minor: i64,
+    pub meal_break_deducted_minutes: i32,
+}
+
+impl PayBuckets {
+    /// Paid minutes (regular + OT + DT), excluding meal-break deductions.
+    pub fn paid_minutes(&self) -> i32 {
+        self.regular_minutes + self.overtime_minutes + self.double_time_minutes
+    }
+
+    pub fn add(&mut self, other: &PayBuckets) {
+        self.regular_minutes += other.regular_minutes;
+        self.overtime_minutes += other.overtime_minutes;
+        self.double_time_minutes += other.double_time_minutes;
+        self.premium_minutes += other.premium_minutes;
+        self.premium_minor += other.premium_minor;
+        self.meal_break_deducted_minutes += other.meal_break_deducted_minutes;
+    }
+
+    /// Labor cost in minor units for the given base hourly rate.
+    ///
+    /// OT and DT minutes are priced at the rule set's multipliers; premium is
+    /// added on top. Rounds half-up once on the combined base amount.
+    pub fn cost_minor(
+        &self,
+        hourly_rate_minor: i64,
+        ot_multiplier_bp: i32,
+        dt_multiplier_bp: i32,
+    ) -> i64 {
+        let weighted_bp_minutes = self.regular_minutes as i64 * 10_000
+            + self.overtime_minutes as i64 * ot_multiplier_bp as i64
+            + self.double_time_minutes as i64 * dt_multiplier_bp as i64;
+        let denom = 60 * 10_000;
+        (weighted_bp_minutes * hourly_rate_minor + denom / 2) / denom + self.premium_minor
+    }
+}
+
+// ============================================================================
+// Request types
+// ============================================================================
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct CreatePayRuleSetRequest {
+    pub app_id: String,
+    pub name: String,
+    pub employee_group: Option<String>,
+    pub daily_ot_after_minutes: Option<i32>,
+    pub daily_dt_after_minutes: Option<i32>,
+    pub weekly_ot_after_minutes: Option<i32>,
+    pub week_start_day: Option<i16>,
+    #[serde(default)]
+    pub double_time_weekdays: Vec<i16>,
+    pub ot_multiplier_bp: Option<i32>,
+    pub dt_multiplier_bp: Option<i32>,
+    pub meal_break_after_minutes: Option<i32>,
+    pub meal_break_minutes: Option<i32>,
+    pub timezone: Option<String>,
+    #[serde(default)]
+    pub shift_differentials: Vec<CreateShiftDifferentialRequest>,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct CreateShiftDifferentialRequest {
+    pub name: String,
+    pub start_time: NaiveTime,
+    pub end_time: NaiveTime,
+    pub premium_minor_per_hour: i64,
+}
+
+// ============================================================================
+// Errors
+// ============================================================================
+
+#[derive(Debug, Error)]
+pub enum PayRuleError {
+    #[error("Pay rule set not found")]
+    NotFound,
+
+    #[error("An active pay rule set already exists for this employee group")]
+    DuplicateGroup,
+
+    #[error("Validation error: {0}")]
+    Validation(String),
+
+    #[error("Database error: {0}")]
+    Database(#[from] sqlx::Error),
+}
+
+// ============================================================================
+// Validation
+// ============================================================================
+
+fn positive(value: Option<i32>, field: &str) -> Result<(), PayRuleError> {
+    match value {
+        Some(v) if v <= 0 => Err(PayRuleError::Validation(format!(
+            "{} must be positive",
+            field
+        ))),
+        _ => Ok(()),
+    }
+}
+
+fn iso_weekday(day: i16, field: &str) -> Result<(), PayRuleError> {
+    if !(1..=7).contains(&day) {
+        return Err(PayRuleError::Validation(format!(
+            "{} must be an ISO weekday (1-7)",
+            field
+        )));
+    }
+    Ok(())
+}
+
+impl CreatePayRuleSetRequest {
+    pub fn validate(&self) -> Result<(), PayRuleError> {
+        if self.app_id.trim().is_empty() {
+            return Err(PayRuleError::Validation("app_id must not be empty".into()));
+        }
+        if self.name.trim().is_empty() {
+            return Err(PayRuleError::Validation("name must not be empty".into()));
+        }
+        if let Some(ref group) = self.employee_group {
+            if group.trim().is_empty() {
+                return Err(PayRuleError::Validation(
+                    "employee_group must not be empty when provided".into(),
+                ));
+            }
+        }
+        positive(self.daily_ot_after_minutes, "daily_ot_after_minutes")?;
+        positive(self.daily_dt_after_minutes, "daily_dt_after_minutes")?;
+        positive(self.weekly_ot_after_minutes, "weekly_ot_after_minutes")?;
+        positive(self.meal_break_after_minutes, "meal_break_after_minutes")?;
+        if let (Some(ot), Some(dt)) = (self.daily_ot_after_minutes, self.daily_dt_after_minutes) {
+            if dt < ot {
+                return Err(PayRuleError::Validation(
+                    "daily_dt_after_minutes must be >= daily_ot_after_minutes".into(),
+                ));
+            }
+        }
+        if let Some(day) = self.week_start_day {
+            iso_weekday(day, "week_start_day")?;
+        }
+        for day in &self.double_time_weekdays {
+            iso_weekday(*day, "double_time_weekdays")?;
+        }
+        for (bp, field) in [
+            (self.ot_multiplier_bp, "ot_multiplier_bp"),
+            (self.dt_multiplier_bp, "dt_multiplier_bp"),
+        ] {
+            if matches!(bp, Some(v) if v < 10_000) {
+                return Err(PayRuleError::Validation(format!(
+                    "{} must be >= 10000 (1.0x)",
+                    field
+                )));
+            }
+        }
+        if matches!(self.meal_break_minutes, Some(m) if m < 0) {
+            return Err(PayRuleError::Validation(
+                "meal_break_minutes must not be negative".into(),
+            ));
+        }
+        if matches!(self.timezone.as_deref(), Some(tz) if tz.trim().is_empty()) {
+            return Err(PayRuleError::Validation(
+                "timezone must not be empty when provided".into(),
+            ));
+        }
+        for diff in &self.shift_differentials {
+            if diff.name.trim().is_empty() {
+                return Err(PayRuleError::Validation(
+                    "shift differential name must not be empty".into(),
+                ));
+            }
+            if diff.start_time == diff.end_time {
+                return Err(PayRuleError::Validation(format!(
+                    "shift differential '{}' must have distinct start and end times",
+                    diff.name
+                )));
+            }
+            if diff.premium_minor_per_hour <= 0 {
+                return Err(PayRuleError::Validation(format!(
+                    "shift differential '{}' premium_minor_per_hour must be positive",
+                    diff.name
+                )));
+            }
+        }
+        Ok(())
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn valid_create() -> CreatePayRuleSetRequest {
+        CreatePayRuleSetRequest {
+            app_id: "acme".into(),
+            name: "Plant hourly".into(),
+            employee_group: Some("Production".into()),
+            daily_ot_after_minutes: Some(480),
+            daily_dt_after_minutes: Some(720),
+            weekly_ot_after_minutes: Some(2400),
+            week_start_day: Some(1),
+            double_time_weekdays: vec![7],
+            ot_multiplier_bp: Some(15000),
+            dt_multiplier_bp: Some(20000),
+            meal_break_after_minutes: Some(360),
+            meal_break_minutes: Some(30),
+            timezone: Some("America/Chicago".into()),
+            shift_differentials: vec![CreateShiftDifferentialRequest {
+                name: "Night".into(),
+                start_time: NaiveTime::from_hms_opt(22, 0, 0).unwrap(),
+                end_time: NaiveTime::from_hms_opt(6, 0, 0).unwrap(),
+                premium_minor_per_hour: 150,
+            }],
+        }
+    }
+
+    #[test]
+    fn create_valid() {
+        assert!(valid_create().validate().is_ok());
+    }
+
+    #[test]
+    fn create_dt_below_ot_rejected() {
+        let mut r = valid_create();
+        r.daily_dt_after_minutes = Some(400);
+        assert!(matches!(r.validate(), Err(PayRuleError::Validation(_))));
+    }
+
+    #[test]
+    fn create_bad_weekday_rejected() {
+        let mut r = valid_create();
+        r.double_time_weekdays = vec![0];
+        assert!(matches!(r.validate(), Err(PayRuleError::Validation(_))));
+    }
+
+    #[test]
+    fn create_multiplier_below_one_rejected() {
+        let mut r = valid_create();
+        r.ot_multiplier_bp = Some(9000);
+        assert!(matches!(r.validate(), Err(PayRuleError::Validation(_))));
+    }
+
+    #[test]
+    fn buckets_cost_applies_multipliers_and_premium() {
+        let b = PayBuckets {
+            regular_minutes: 480,
+            overtime_minutes: 60,
+            double_time_minutes: 30,
+            premium_minutes: 120,
+            premium_minor: 300,
+            meal_break_deducted_minutes: 30,
+        };
+        // 8h * 2000 + 1h * 2000 * 1.5 + 0.5h * 2000 * 2.0 + 300
+        assert_eq!(b.cost_minor(2000, 15000, 20000), 16000 + 3000 + 2000 + 300);
+        assert_eq!(b.paid_minutes(), 570);
+    }
+}
@@ -0,0 +1,264 @@
+//! Pay rule repository — SQL layer for tk_pay_rule_sets and
+//! tk_pay_rule_shift_differentials.
+
+use chrono::{NaiveDate, NaiveDateTime};
+use sqlx::{PgConnection, PgPool};
+use uuid::Uuid;
+
+use super::engine::EntryMinutes;
+use super::models::*;
+
+const RULE_SET_COLS: &str = r#"
+    id, app_id, name, employee_group, daily_ot_after_minutes,
+    daily_dt_after_minutes, weekly_ot_after_minutes, week_start_day,
+    double_time_weekdays, ot_multiplier_bp, dt_multiplier_bp,
+    meal_break_after_minutes, meal_break_minutes, timezone, is_active,
+    created_at, updated_at
+"#;
+
+// ============================================================================
+// Reads
+// ============================================================================
+
+pub async fn fetch_rule_set(
+    pool: &PgPool,
+    app_id: &str,
+    id: Uuid,
+) -> Result<Option<PayRuleSet>, PayRuleError> {
+    let sql = format!(
+        "SELECT {} FROM tk_pay_rule_sets WHERE app_id = $1 AND id = $2",
+        RULE_SET_COLS
+    );
+    Ok(sqlx::query_as::<_, PayRuleSet>(&sql)
+        .bind(app_id)
+        .bind(id)
+        .fetch_optional(pool)
+        .await?)
+}
+
+pub async fn list_active_rule_sets(
+    pool: &PgPool,
+    app_id: &str,
+) -> Result<Vec<PayRuleSet>, PayRuleError> {
+    let sql = format!(
+        "SELECT {} FROM tk_pay_rule_sets \
+         WHERE app_id = $1 AND is_active = TRUE \
+         ORDER BY name",
+        RULE_SET_COLS
+    );
+    Ok(sqlx::query_as::<_, PayRuleSet>(&sql)
+        .bind(app_id)
+        .fetch_all(pool)
+        .await?)
+}
+
+pub async fn list_shift_differentials(
+    pool: &PgPool,
+    rule_set_ids: &[Uuid],
+) -> Result<Vec<ShiftDifferential>, PayRuleError> {
+    Ok(sqlx::query_as::<_, ShiftDifferential>(
+        r#"
+        SELECT id, rule_set_id, name, start_time, end_time, premium_minor_per_hour
+        FROM tk_pay_rule_shift_differentials
+        WHERE rule_set_id = ANY($1)
+        ORDER BY rule_set_id, start_time, name
+        "#,
+    )
+    .bind(rule_set_ids)
+    .fetch_all(pool)
+    .await?)
+}
+
+/// Employee → department lookup for rule set resolution.
+pub async fn fetch_employee_groups(
+    pool: &PgPool,
+    app_id: &str,
+    employee_ids: &[Uuid],
+) -> Result<Vec<(Uuid, Option<String>)>, PayRuleError> {
+    Ok(
+        sqlx::query_as(
+            "SELECT id, department FROM tk_employees WHERE app_id = $1 AND id = ANY($2)",
+        )
+        .bind(app_id)
+        .bind(employee_ids)
+        .fetch_all(pool)
+        .await?,
+    )
+}
+
+/// Closed clock sessions starting in the period, converted to local time.
+///
+/// Returns (local clock-in, local clock-out) pairs.
+pub async fn fetch_local_sessions(
+    pool: &PgPool,
+    app_id: &str,
+    employee_id: Uuid,
+    timezone: &str,
+    from: NaiveDate,
+    to: NaiveDate,
+) -> Result<Vec<(NaiveDateTime, NaiveDateTime)>, PayRuleError> {
+    Ok(sqlx::query_as(
+        r#"
+        SELECT clock_in_at AT TIME ZONE $3, clock_out_at AT TIME ZONE $3
+        FROM tk_clock_sessions
+        WHERE app_id = $1
+          AND employee_id = $2
+          AND status = 'closed'
+          AND clock_out_at IS NOT NULL
+          AND (clock_in_at AT TIME ZONE $3)::DATE >= $4
+          AND (clock_in_at AT TIME ZONE $3)::DATE <= $5
+        ORDER BY clock_in_at
+        "#,
+    )
+    .bind(app_id)
+    .bind(employee_id)
+    .bind(timezone)
+    .bind(from)
+    .bind(to)
+    .fetch_all(pool)
+    .await?)
+}
+
+/// Approved, current entries for the given employees dated within `[from, to]`.
+pub async fn fetch_approved_entry_minutes(
+    pool: &PgPool,
+    app_id: &str,
+    employee_ids: &[Uuid],
+    from: NaiveDate,
+    to: NaiveDate,
+) -> Result<Vec<EntryMinutes>, PayRuleError> {
+    let rows: Vec<(Uuid, Uuid, NaiveDate, i32)> = sqlx::query_as(
+        r#"
+        SELECT e.entry_id, e.employee_id, e.work_date, e.minutes
+        FROM tk_timesheet_entries e
+        JOIN tk_approval_requests ar
+            ON ar.app_id = e.app_id
+            AND ar.employee_id = e.employee_id
+            AND ar.period_start <= e.work_date
+            AND ar.period_end >= e.work_date
+            AND ar.status = 'approved'
+        WHERE e.app_id = $1
+          AND e.employee_id = ANY($2)
+          AND e.work_date >= $3
+          AND e.work_date <= $4
+          AND e.is_current = TRUE
+          AND e.entry_type != 'void'
+        ORDER BY e.employee_id, e.work_date, e.entry_id
+        "#,
+    )
+    .bind(app_id)
+    .bind(employee_ids)
+    .bind(from)
+    .bind(to)
+    .fetch_all(pool)
+    .await?;
+    Ok(rows
+        .into_iter()
+        .map(|(entry_id, employee_id, work_date, minutes)| EntryMinutes {
+            entry_id,
+            employee_id,
+            work_date,
+            minutes,
+        })
+        .collect())
+}
+
+pub async fn is_known_timezone(pool: &PgPool, timezone: &str) -> Result<bool, PayRuleError> {
+    let row: Option<(String,)> =
+        sqlx::query_as("SELECT name FROM pg_timezone_names WHERE name = $1 LIMIT 1")
+            .bind(timezone)
+            .fetch_optional(pool)
+            .await?;
+    Ok(row.is_some())
+}
+
+// ============================================================================
+// Writes
+// ============================================================================
+
+pub async fn insert_rule_set(
+    conn: &mut PgConnection,
+    req: &CreatePayRuleSetRequest,
+) -> Result<PayRuleSet, PayRuleError> {
+    let sql = format!(
+        r#"INSERT INTO tk_pay_rule_sets
+            (app_id, name, employee_group, daily_ot_after_minutes,
+             daily_dt_after_minutes, weekly_ot_after_minutes, week_start_day,
+             double_time_weekdays, ot_multiplier_bp, dt_multiplier_bp,
+             meal_break_after_minutes, meal_break_minutes, timezone)
+        VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13)
+        RETURNING {}"#,
+        RULE_SET_COLS
+    );
+    sqlx::query_as::<_, PayRuleSet>(&sql)
+        .bind(&req.app_id)
+        .bind(req.name.trim())
+        .bind(req.employee_group.as_deref().map(str::trim))
+        .bind(req.daily_ot_after_minutes)
+        .bind(req.daily_dt_after_minutes)
+        .bind(req.weekly_ot_after_minutes)
+        .bind(req.week_start_day.unwrap_or(1))
+        .bind(&req.double_time_weekdays)
+        .bind(req.ot_multiplier_bp.unwrap_or(15000))
+        .bind(req.dt_multiplier_bp.unwrap_or(20000))
+        .bind(req.meal_break_after_minutes)
+        .bind(req.meal_break_minutes.unwrap_or(0))
+        .bind(req.timezone.as_deref().unwrap_or("UTC"))
+        .fetch_one(conn)
+        .await
+        .map_err(|e| match e {
+            sqlx::Error::Database(ref db)
+                if db.constraint() == Some("tk_pay_rule_sets_one_active_per_group") =>
+            {
+                PayRuleError::DuplicateGroup
+            }
+            sqlx::Error::Database(ref db)
+                if db.constraint() == Some("tk_pay_rule_sets_app_name_unique") =>
+            {
+                PayRuleError::Validation(format!(
+                    "pay rule set '{}' already exists",
+                    req.name.trim()
+                ))
+            }
+            other => PayRuleError::Database(other),
+        })
+}
+
+pub async fn insert_shift_differential(
+    conn: &mut PgConnection,
+    rule_set_id: Uuid,
+    req: &CreateShiftDifferentialRequest,
+) -> Result<ShiftDifferential, PayRuleError> {
+    Ok(sqlx::query_as::<_, ShiftDifferential>(
+        r#"
+        INSERT INTO tk_pay_rule_shift_differentials
+            (rule_set_id, name, start_time, end_time, premium_minor_per_hour)
+        VALUES ($1, $2, $3, $4, $5)
+        RETURNING id, rule_set_id, name, start_time, end_time, premium_minor_per_hour
+        "#,
+    )
+    .bind(rule_set_id)
+    .bind(req.name.trim())
+    .bind(req.start_time)
+    .bind(req.end_time)
+    .bind(req.premium_minor_per_hour)
+    .fetch_one(conn)
+    .await?)
+}
+
+/// Deactivate a rule set. Returns false if it does not exist for the tenant.
+pub async fn deactivate_rule_set(
+    pool: &PgPool,
+    app_id: &str,
+    id: Uuid,
+) -> Result<bool, PayRuleError> {
+    let result = sqlx::query(
+        "UPDATE tk_pay_rule_sets SET is_active = FALSE, updated_at = NOW() \
+         WHERE app_id = $1 AND id = $2",
+    )
+    .bind(app_id)
+    .bind(id)
+    .execute(pool)
+    .await?;
+    Ok(result.rows_affected() > 0)
+}
@@ -0,0 +1,219 @@
+//! Pay rule service — rule set CRUD + classification of approved entries.
+//!
+//! Classification resolves each employee's rule set by department
+//! (`employee_group`), falling back to the tenant default (NULL group),
+//! then runs the pure engine over that employee's entries. Exports and the
+//! GL labor cost accrual both classify through `classify_entries` so payroll
+//! files and accrued cost always agree.
+
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+use chrono::{Duration, NaiveDate};
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use super::engine::{self, EntryMinutes, SessionSpan};
+use super::models::*;
+use super::repo;
+
+// ============================================================================
+// Rule sets
+// ============================================================================
+
+/// Create a pay rule set with its shift differentials atomically.
+pub async fn create_rule_set(
+    pool: &PgPool,
+    req: &CreatePayRuleSetRequest,
+) -> Result<PayRuleSet, PayRuleError> {
+    req.validate()?;
+    if let Some(tz) = req.timezone.as_deref() {
+        if !repo::is_known_timezone(pool, tz).await? {
+            return Err(PayRuleError::Validation(format!(
+                "unknown timezone '{}'",
+                tz
+            )));
+        }
+    }
+
+    let mut tx = pool.begin().await?;
+    let mut rule_set = repo::insert_rule_set(&mut tx, req).await?;
+    for diff in &req.shift_differentials {
+        let created = repo::insert_shift_differential(&mut tx, rule_set.id, diff).await?;
+        rule_set.shift_differentials.push(created);
+    }
+    tx.commit().await?;
+
+    Ok(rule_set)
+}
+
+pub async fn get_rule_set(
+    pool: &PgPool,
+    app_id: &str,
+    id: Uuid,
+) -> Result<PayRuleSet, PayRuleError> {
+    let mut rule_set = repo::fetch_rule_set(pool, app_id, id)
+        .await?
+        .ok_or(PayRuleError::NotFound)?;
+    rule_set.shift_differentials = repo::list_shift_differentials(pool, &[id]).await?;
+    Ok(rule_set)
+}
+
+/// List active rule sets for an app, with shift differentials attached.
+pub async fn list_rule_sets(pool: &PgPool, app_id: &str) -> Result<Vec<PayRuleSet>, PayRuleError> {
+    let mut sets = repo::list_active_rule_sets(pool, app_id).await?;
+    let ids: Vec<Uuid> = sets.iter().map(|s| s.id).collect();
+    let mut diffs_by_set: HashMap<Uuid, Vec<ShiftDifferential>> = HashMap::new();
+    for d in repo::list_shift_differentials(pool, &ids).await? {
+        diffs_by_set.entry(d.rule_set_id).or_default().push(d);
+    }
+    for s in &mut sets {
+        s.shift_differentials = diffs_by_set.remove(&s.id).unwrap_or_default();
+    }
+    Ok(sets)
+}
+
+/// Deactivate a rule set. Idempotent.
+pub async fn deactivate_rule_set(
+    pool: &PgPool,
+    app_id: &str,
+    id: Uuid,
+) -> Result<(), PayRuleError> {
+    if !repo::deactivate_rule_set(pool, app_id, id).await? {
+        return Err(PayRuleError::NotFound);
+    }
+    Ok(())
+}
+
+// ============================================================================
+// Classification
+// ============================================================================
+
+/// Classify approved entries into pay buckets, keyed by entry_id.
+///
+/// `period_start`/`period_end` bound the clock sessions considered for shift
+/// differentials; they should match the range the entries were drawn from.
+///
+/// Weekly overtime accrues over whole work weeks, so approved entries on the
+/// days of the boundary weeks that fall outside the period are classified
+/// alongside the requested ones. Only the requested entries are returned.
+pub async fn classify_entries(
+    pool: &PgPool,
+    app_id: &str,
+    period_start: NaiveDate,
+    period_end: NaiveDate,
+    entries: &[EntryMinutes],
+) -> Result<HashMap<Uuid, PayBuckets>, PayRuleError> {
+    let mut by_employee: BTreeMap<Uuid, Vec<EntryMinutes>> = BTreeMap::new();
+    for e in entries {
+        by_employee
+            .entry(e.employee_id)
+            .or_default()
+            .push(e.clone());
+    }
+    if by_employee.is_empty() {
+        return Ok(HashMap::new());
+    }
+
+    let employee_ids: Vec<Uuid> = by_employee.keys().copied().collect();
+    let rule_sets = resolve_employee_rule_sets(pool, app_id, &employee_ids).await?;
+
+    // Pull in the rest of the work weeks the period starts and ends in
+    let requested: HashSet<Uuid> = entries.iter().map(|e| e.entry_id).collect();
+    let weekly_ids: Vec<Uuid> = employee_ids
+        .iter()
+        .filter(|id| {
+            rule_sets
+                .get(id)
+                .is_some_and(|r| r.weekly_ot_after_minutes.is_some())
+        })
+        .copied()
+        .collect();
+    if !weekly_ids.is_empty() {
+        let context = repo::fetch_approved_entry_minutes(
+            pool,
+            app_id,
+            &weekly_ids,
+            period_start - Duration::days(6),
+            period_end + Duration::days(6),
+        )
+        .await?;
+        for e in context {
+            let week_start_day = rule_sets[&e.employee_id].week_start_day;
+            let weeks_from = engine::week_start(period_start, week_start_day);
+            let weeks_to = engine::week_start(period_end, week_start_day) + Duration::days(6);
+            let in_period = e.work_date >= period_start && e.work_date <= period_end;
+            let in_weeks = e.work_date >= weeks_from && e.work_date <= weeks_to;
+            if in_weeks && !in_period && !requested.contains(&e.entry_id) {
+                by_employee.entry(e.employee_id).or_default().push(e);
+            }
+        }
+    }
+
+    let mut out = HashMap::with_capacity(entries.len());
+    for (employee_id, emp_entries) in &by_employee {
+        let rules = rule_sets.get(employee_id);
+
+        let premiums = match rules {
+            Some(r) if !r.shift_differentials.is_empty() => {
+                let sessions: Vec<SessionSpan> = repo::fetch_local_sessions(
+                    pool,
+                    app_id,
+                    *employee_id,
+                    &r.timezone,
+                    period_start,
+                    period_end,
+                )
+                .await?
+                .into_iter()
+                .map(|(start, end)| SessionSpan { start, end })
+                .collect();
+                engine::shift_premiums(&r.shift_differentials, &sessions)
+            }
+            _ => engine::DailyPremiums::new(),
+        };
+
+        out.extend(
+            engine::classify(rules, emp_entries, &premiums)
+                .into_iter()
+                .filter(|(entry_id, _)| requested.contains(entry_id)),
+        );
+    }
+
+    Ok(out)
+}
+
+/// Resolve the applicable rule set for each employee.
+///
+/// Employees with no matching group rule set and no tenant default are
+/// absent from the map (their time classifies as all-regular).
+pub async fn resolve_employee_rule_sets(
+    pool: &PgPool,
+    app_id: &str,
+    employee_ids: &[Uuid],
+) -> Result<HashMap<Uuid, PayRuleSet>, PayRuleError> {
+    let rule_sets = list_rule_sets(pool, app_id).await?;
+    if rule_sets.is_empty() {
+        return Ok(HashMap::new());
+    }
+    let groups = repo::fetch_employee_groups(pool, app_id, employee_ids).await?;
+    Ok(groups
+        .into_iter()
+        .filter_map(|(employee_id, group)| {
+            resolve_rule_set(&rule_sets, group.as_deref()).map(|r| (employee_id, r.clone()))
+        })
+        .collect())
+}
+
+/// Pick the rule set for an employee group, falling back to the default set.
+pub fn resolve_rule_set<'a>(
+    rule_sets: &'a [PayRuleSet],
+    employee_group: Option<&str>,
+) -> Option<&'a PayRuleSet> {
+    employee_group
+        .and_then(|g| {
+            rule_sets
+                .iter()
+                .find(|s| s.employee_group.as_deref() == Some(g))
+        })
+        .or_else(|| rule_sets.iter().find(|s| s.employee_group.is_none()))
+}
@@ -1,4 +1,7 @@
 //! Employee HTTP handlers — CRUD endpoints for the employee directory.
+//!
+//! Access honours the caller's `department` data scope on
+//! `timekeeping.read` / `timekeeping.mutate` grants.
 
 use axum::{
     extract::{Path, State},
@@ -6,7 +9,7 @@ use axum::{
     Extension, Json,
 };
 use platform_http_contracts::{ApiError, PaginatedResponse};
-use security::VerifiedClaims;
+use security::{check_scope, permissions, ScopeFilter, VerifiedClaims, SCOPE_DEPARTMENT};
 use std::sync::Arc;
 use uuid::Uuid;
 
@@ -35,6 +38,42 @@ fn map_employee_error(err: EmployeeError) -> ApiError {
     }
 }
 
+/// Deny `perm` on an employee in a department outside the caller's scope.
+fn check_department_scope(
+    claims: &Option<Extension<VerifiedClaims>>,
+    perm: &str,
+    department: Option<&str>,
+) -> Result<(), ApiError> {
+    let Some(Extension(claims)) = claims else {
+        return Err(ApiError::unauthorized("Missing or invalid authentication"));
+    };
+    let attrs: Vec<(&str, &str)> = department
+        .map(|d| (SCOPE_DEPARTMENT, d))
+        .into_iter()
+        .collect();
+    check_scope(claims, perm, &attrs)
+        .map_err(|_| ApiError::forbidden("Employee is outside your data scope"))
+}
+
+/// Load an employee and check the caller may mutate it.
+async fn find_mutable_employee(
+    state: &AppState,
+    claims: &Option<Extension<VerifiedClaims>>,
+    id: Uuid,
+) -> Result<Employee, ApiError> {
+    let app_id = extract_tenant(claims)?;
+    let emp = EmployeeRepo::find_by_id(&state.pool, id, &app_id)
+        .await
+        .map_err(map_employee_error)?
+        .ok_or_else(|| ApiError::not_found("Employee not found"))?;
+    check_department_scope(
+        claims,
+        permissions::TIMEKEEPING_MUTATE,
+        emp.department.as_deref(),
+    )?;
+    Ok(emp)
+}
+
 // ============================================================================
 // Handlers
 // ============================================================================
@@ -58,6 +97,11 @@ pub async fn create_employee(
     Json(mut req): Json<CreateEmployeeRequest>,
 ) -> Result<(StatusCode, Json<Employee>), ApiError> {
     let app_id = extract_tenant(&claims)?;
+    check_department_scope(
+        &claims,
+        permissions::TIMEKEEPING_MUTATE,
+        req.department.as_deref(),
+    )?;
     req.app_id = app_id;
     let emp = EmployeeRepo::create(&state.pool, &req)
         .await
@@ -87,6 +131,11 @@ pub async fn get_employee(
         .await
         .map_err(map_employee_error)?
         .ok_or_else(|| ApiError::not_found("Employee not found"))?;
+    check_department_scope(
+        &claims,
+        permissions::TIMEKEEPING_READ,
+        emp.department.as_deref(),
+    )?;
     Ok(Json(emp))
 }
 
@@ -105,7 +154,13 @@ pub async fn list_employees(
     claims: Option<Extension<VerifiedClaims>>,
 ) -> Result<Json<PaginatedResponse<Employee>>, ApiError> {
     let app_id = extract_tenant(&claims)?;
-    let employees = EmployeeRepo::list(&state.pool, &app_id, true)
+    let scope = match &claims {
+        Some(Extension(c)) => ScopeFilter::new(c, permissions::TIMEKEEPING_READ)
+            .column(SCOPE_DEPARTMENT, "department")
+            .to_sql(3),
+        None => return Err(ApiError::unauthorized("Missing or invalid authentication")),
+    };
+    let employees = EmployeeRepo::list_scoped(&state.pool, &app_id, true, &scope)
         .await
         .map_err(map_employee_error)?;
     let total = employees.len() as i64;
@@ -127,9 +182,18 @@ pub async fn list_employees(
 )]
 pub async fn update_employee(
     State(state): State<Arc<AppState>>,
+    claims: Option<Extension<VerifiedClaims>>,
     Path(id): Path<Uuid>,
     Json(req): Json<UpdateEmployeeRequest>,
 ) -> Result<Json<Employee>, ApiError> {
+    find_mutable_employee(&state, &claims, id).await?;
+    if req.department.is_some() {
+        check_department_scope(
+            &claims,
+            permissions::TIMEKEEPING_MUTATE,
+            req.department.as_deref(),
+        )?;
+    }
     let emp = EmployeeRepo::update(&state.pool, id, &req)
         .await
         .map_err(map_employee_error)?;
@@ -154,6 +218,7 @@ pub async fn deactivate_employee(
     Path(id): Path<Uuid>,
 ) -> Result<Json<Employee>, ApiError> {
     let app_id = extract_tenant(&claims)?;
+    find_mutable_employee(&state, &claims, id).await?;
     let emp = EmployeeRepo::deactivate(&state.pool, id, &app_id)
         .await
         .map_err(map_employee_error)?;
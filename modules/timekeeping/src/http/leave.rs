@@ -0,0 +1,483 @@
+//! Leave (PTO) HTTP handlers — leave types, accrual policies, holiday
+//! calendars, leave requests, accrual runs, balances and ledger.
+
+use axum::{
+    extract::{Path, Query, State},
+    http::StatusCode,
+    Extension, Json,
+};
+use chrono::Utc;
+use platform_http_contracts::{ApiError, PaginatedResponse};
+use security::VerifiedClaims;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::{
+    domain::leave::{
+        accrual,
+        models::{
+            AccrualPolicy, AccrualRunResult, BalanceQuery, CreateAccrualPolicyRequest,
+            CreateHolidayCalendarRequest, CreateHolidayRequest, CreateLeaveTypeRequest, Holiday,
+            HolidayCalendar, LeaveBalance, LeaveError, LeaveRequest, LeaveRequestsQuery, LeaveType,
+            LedgerEntry, LedgerQuery, ReviewLeaveRequest, RunAccrualsRequest, SubmitLeaveRequest,
+        },
+        requests, service,
+    },
+    AppState,
+};
+use platform_sdk::extract_tenant;
+
+// ============================================================================
+// Error mapping
+// ============================================================================
+
+fn map_leave_error(err: LeaveError) -> ApiError {
+    match err {
+        LeaveError::NotFound => ApiError::not_found("Leave record not found"),
+        LeaveError::Validation(msg) => ApiError::new(422, "validation_error", msg),
+        LeaveError::Duplicate(msg) => ApiError::conflict(msg),
+        LeaveError::InsufficientBalance {
+            requested,
+            available,
+        } => ApiError::new(
+            422,
+            "insufficient_balance",
+            format!(
+                "Requested {} minutes but only {} available",
+                requested, available
+            ),
+        ),
+        LeaveError::InvalidTransition { from, to } => {
+            ApiError::conflict(format!("Cannot transition from {} to {}", from, to))
+        }
+        LeaveError::Database(e) => ApiError::internal(e.to_string()),
+    }
+}
+
+// ============================================================================
+// Leave types
+// ============================================================================
+
+#[utoipa::path(
+    post,
+    path = "/api/timekeeping/leave/types",
+    request_body = CreateLeaveTypeRequest,
+    responses(
+        (status = 201, description = "Leave type created", body = LeaveType),
+        (status = 401, description = "Unauthorized", body = ApiError),
+        (status = 409, description = "Duplicate code", body = ApiError),
+        (status = 422, description = "Validation error", body = ApiError),
+    ),
+    security(("bearer" = [])),
+    tag = "Leave",
+)]
+pub async fn create_leave_type(
+    State(state): State<Arc<AppState>>,
+    claims: Option<Extension<VerifiedClaims>>,
+    Json(mut req): Json<CreateLeaveTypeRequest>,
+) -> Result<(StatusCode, Json<LeaveType>), ApiError> {
+    let app_id = extract_tenant(&claims)?;
+    req.app_id = app_id;
+    let leave_type = service::create_leave_type(&state.pool, &req)
+        .await
+        .map_err(map_leave_error)?;
+    Ok((StatusCode::CREATED, Json(leave_type)))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/timekeeping/leave/types",
+    responses(
+        (status = 200, description = "Active leave types", body = Vec<LeaveType>),
+        (status = 401, description = "Unauthorized", body = ApiError),
+    ),
+    security(("bearer" = [])),
+    tag = "Leave",
+)]
+pub async fn list_leave_types(
+    State(state): State<Arc<AppState>>,
+    claims: Option<Extension<VerifiedClaims>>,
+) -> Result<Json<PaginatedResponse<LeaveType>>, ApiError> {
+    let app_id = extract_tenant(&claims)?;
+    let types = service::list_leave_types(&state.pool, &app_id)
+        .await
+        .map_err(map_leave_error)?;
+    let total = types.len() as i64;
+    Ok(Json(PaginatedResponse::new(types, 1, total, total)))
+}
+
+// ============================================================================
+// Accrual policies
+// ============================================================================
+
+#[utoipa::path(
+    post,
+    path = "/api/timekeeping/leave/policies",
+    request_body = CreateAccrualPolicyRequest,
+    responses(
+        (status = 201, description = "Accrual policy created", body = AccrualPolicy),
+        (status = 401, description = "Unauthorized", body = ApiError),
+        (status = 404, description = "Leave type not found", body = ApiError),
+        (status = 409, description = "Active policy already exists for group", body = ApiError),
+        (status = 422, description = "Validation error", body = ApiError),
+    ),
+    security(("bearer" = [])),
+    tag = "Leave",
+)]
+pub async fn create_accrual_policy(
+    State(state): State<Arc<AppState>>,
+    claims: Option<Extension<VerifiedClaims>>,
+    Json(mut req): Json<CreateAccrualPolicyRequest>,
+) -> Result<(StatusCode, Json<AccrualPolicy>), ApiError> {
+    let app_id = extract_tenant(&claims)?;
+    req.app_id = app_id;
+    let policy = service::create_policy(&state.pool, &req)
+        .await
+        .map_err(map_leave_error)?;
+    Ok((StatusCode::CREATED, Json(policy)))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/timekeeping/leave/policies",
+    responses(
+        (status = 200, description = "Active accrual policies", body = Vec<AccrualPolicy>),
+        (status = 401, description = "Unauthorized", body = ApiError),
+    ),
+    security(("bearer" = [])),
+    tag = "Leave",
+)]
+pub async fn list_accrual_policies(
+    State(state): State<Arc<AppState>>,
+    claims: Option<Extension<VerifiedClaims>>,
+) -> Result<Json<PaginatedResponse<AccrualPolicy>>, ApiError> {
+    let app_id = extract_tenant(&claims)?;
+    let policies = service::list_policies(&state.pool, &app_id)
+        .await
+        .map_err(map_leave_error)?;
+    let total = policies.len() as i64;
+    Ok(Json(PaginatedResponse::new(policies, 1, total, total)))
+}
+
+// ============================================================================
+// Holiday calendars
+// ============================================================================
+
+#[utoipa::path(
+    post,
+    path = "/api/timekeeping/leave/holiday-calendars",
+    request_body = CreateHolidayCalendarRequest,
+    responses(
+        (status = 201, description = "Holiday calendar created", body = HolidayCalendar),
+        (status = 401, description = "Unauthorized", body = ApiError),
+        (status = 409, description = "Active calendar already exists for group", body = ApiError),
+        (status = 422, description = "Validation error", body = ApiError),
+    ),
+    security(("bearer" = [])),
+    tag = "Leave",
+)]
+pub async fn create_holiday_calendar(
+    State(state): State<Arc<AppState>>,
+    claims: Option<Extension<VerifiedClaims>>,
+    Json(mut req): Json<CreateHolidayCalendarRequest>,
+) -> Result<(StatusCode, Json<HolidayCalendar>), ApiError> {
+    let app_id = extract_tenant(&claims)?;
+    req.app_id = app_id;
+    let calendar = service::create_calendar(&state.pool, &req)
+        .await
+        .map_err(map_leave_error)?;
+    Ok((StatusCode::CREATED, Json(calendar)))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/timekeeping/leave/holiday-calendars",
+    responses(
+        (status = 200, description = "Active holiday calendars", body = Vec<HolidayCalendar>),
+        (status = 401, description = "Unauthorized", body = ApiError),
+    ),
+    security(("bearer" = [])),
+    tag = "Leave",
+)]
+pub async fn list_holiday_calendars(
+    State(state): State<Arc<AppState>>,
+    claims: Option<Extension<VerifiedClaims>>,
+) -> Result<Json<PaginatedResponse<HolidayCalendar>>, ApiError> {
+    let app_id = extract_tenant(&claims)?;
+    let calendars = service::list_calendars(&state.pool, &app_id)
+        .await
+        .map_err(map_leave_error)?;
+    let total = calendars.len() as i64;
+    Ok(Json(PaginatedResponse::new(calendars, 1, total, total)))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/timekeeping/leave/holiday-calendars/{id}/holidays",
+    params(("id" = Uuid, Path, description = "Holiday calendar UUID")),
+    request_body = CreateHolidayRequest,
+    responses(
+        (status = 201, description = "Holiday added", body = Holiday),
+        (status = 401, description = "Unauthorized", body = ApiError),
+        (status = 404, description = "Calendar not found", body = ApiError),
+        (status = 409, description = "Holiday already exists on date", body = ApiError),
+    ),
+    security(("bearer" = [])),
+    tag = "Leave",
+)]
+pub async fn add_holiday(
+    State(state): State<Arc<AppState>>,
+    claims: Option<Extension<VerifiedClaims>>,
+    Path(id): Path<Uuid>,
+    Json(req): Json<CreateHolidayRequest>,
+) -> Result<(StatusCode, Json<Holiday>), ApiError> {
+    let app_id = extract_tenant(&claims)?;
+    let holiday = service::add_holiday(&state.pool, &app_id, id, &req)
+        .await
+        .map_err(map_leave_error)?;
+    Ok((StatusCode::CREATED, Json(holiday)))
+}
+
+// ============================================================================
+// Leave requests
+// ============================================================================
+
+#[utoipa::path(
+    post,
+    path = "/api/timekeeping/leave/requests",
+    request_body = SubmitLeaveRequest,
+    responses(
+        (status = 201, description = "Leave request submitted", body = LeaveRequest),
+        (status = 401, description = "Unauthorized", body = ApiError),
+        (status = 404, description = "Employee or leave type not found", body = ApiError),
+        (status = 422, description = "Validation error or insufficient balance", body = ApiError),
+    ),
+    security(("bearer" = [])),
+    tag = "Leave",
+)]
+pub async fn submit_leave_request(
+    State(state): State<Arc<AppState>>,
+    claims: Option<Extension<VerifiedClaims>>,
+    Json(mut req): Json<SubmitLeaveRequest>,
+) -> Result<(StatusCode, Json<LeaveRequest>), ApiError> {
+    let app_id = extract_tenant(&claims)?;
+    req.app_id = app_id;
+    let request = requests::submit(&state.pool, &req)
+        .await
+        .map_err(map_leave_error)?;
+    Ok((StatusCode::CREATED, Json(request)))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/timekeeping/leave/requests/approve",
+    request_body = ReviewLeaveRequest,
+    responses(
+        (status = 200, description = "Leave request approved", body = LeaveRequest),
+        (status = 401, description = "Unauthorized", body = ApiError),
+        (status = 404, description = "Not found", body = ApiError),
+        (status = 409, description = "Invalid transition", body = ApiError),
+        (status = 422, description = "Insufficient balance", body = ApiError),
+    ),
+    security(("bearer" = [])),
+    tag = "Leave",
+)]
+pub async fn approve_leave_request(
+    State(state): State<Arc<AppState>>,
+    claims: Option<Extension<VerifiedClaims>>,
+    Json(mut req): Json<ReviewLeaveRequest>,
+) -> Result<Json<LeaveRequest>, ApiError> {
+    let app_id = extract_tenant(&claims)?;
+    req.app_id = app_id;
+    let request = requests::approve(&state.pool, &req)
+        .await
+        .map_err(map_leave_error)?;
+    Ok(Json(request))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/timekeeping/leave/requests/reject",
+    request_body = ReviewLeaveRequest,
+    responses(
+        (status = 200, description = "Leave request rejected", body = LeaveRequest),
+        (status = 401, description = "Unauthorized", body = ApiError),
+        (status = 404, description = "Not found", body = ApiError),
+        (status = 409, description = "Invalid transition", body = ApiError),
+    ),
+    security(("bearer" = [])),
+    tag = "Leave",
+)]
+pub async fn reject_leave_request(
+    State(state): State<Arc<AppState>>,
+    claims: Option<Extension<VerifiedClaims>>,
+    Json(mut req): Json<ReviewLeaveRequest>,
+) -> Result<Json<LeaveRequest>, ApiError> {
+    let app_id = extract_tenant(&claims)?;
+    req.app_id = app_id;
+    let request = requests::reject(&state.pool, &req)
+        .await
+        .map_err(map_leave_error)?;
+    Ok(Json(request))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/timekeeping/leave/requests/recall",
+    request_body = ReviewLeaveRequest,
+    responses(
+        (status = 200, description = "Leave request recalled", body = LeaveRequest),
+        (status = 401, description = "Unauthorized", body = ApiError),
+        (status = 404, description = "Not found", body = ApiError),
+        (status = 409, description = "Invalid transition", body = ApiError),
+    ),
+    security(("bearer" = [])),
+    tag = "Leave",
+)]
+pub async fn recall_leave_request(
+    State(state): State<Arc<AppState>>,
+    claims: Option<Extension<VerifiedClaims>>,
+    Json(mut req): Json<ReviewLeaveRequest>,
+) -> Result<Json<LeaveRequest>, ApiError> {
+    let app_id = extract_tenant(&claims)?;
+    req.app_id = app_id;
+    let request = requests::recall(&state.pool, &req)
+        .await
+        .map_err(map_leave_error)?;
+    Ok(Json(request))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/timekeeping/leave/requests",
+    params(
+        ("employee_id" = Option<Uuid>, Query, description = "Filter by employee"),
+        ("status" = Option<String>, Query, description = "Filter by status (draft, submitted, approved, rejected)"),
+    ),
+    responses(
+        (status = 200, description = "Leave requests", body = Vec<LeaveRequest>),
+        (status = 401, description = "Unauthorized", body = ApiError),
+    ),
+    security(("bearer" = [])),
+    tag = "Leave",
+)]
+pub async fn list_leave_requests(
+    State(state): State<Arc<AppState>>,
+    claims: Option<Extension<VerifiedClaims>>,
+    Query(q): Query<LeaveRequestsQuery>,
+) -> Result<Json<PaginatedResponse<LeaveRequest>>, ApiError> {
+    let app_id = extract_tenant(&claims)?;
+    let list = requests::list_requests(&state.pool, &app_id, q.employee_id, q.status.as_ref())
+        .await
+        .map_err(map_leave_error)?;
+    let total = list.len() as i64;
+    Ok(Json(PaginatedResponse::new(list, 1, total, total)))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/timekeeping/leave/requests/{id}",
+    params(("id" = Uuid, Path, description = "Leave request UUID")),
+    responses(
+        (status = 200, description = "Leave request with per-day breakdown", body = LeaveRequest),
+        (status = 401, description = "Unauthorized", body = ApiError),
+        (status = 404, description = "Not found", body = ApiError),
+    ),
+    security(("bearer" = [])),
+    tag = "Leave",
+)]
+pub async fn get_leave_request(
+    State(state): State<Arc<AppState>>,
+    claims: Option<Extension<VerifiedClaims>>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<LeaveRequest>, ApiError> {
+    let app_id = extract_tenant(&claims)?;
+    let request = requests::get_request(&state.pool, &app_id, id)
+        .await
+        .map_err(map_leave_error)?;
+    Ok(Json(request))
+}
+
+// ============================================================================
+// Accruals, balances, ledger
+// ============================================================================
+
+#[utoipa::path(
+    post,
+    path = "/api/timekeeping/leave/accruals/run",
+    request_body = RunAccrualsRequest,
+    responses(
+        (status = 200, description = "Ledger rows posted by the run", body = AccrualRunResult),
+        (status = 401, description = "Unauthorized", body = ApiError),
+        (status = 422, description = "Validation error", body = ApiError),
+    ),
+    security(("bearer" = [])),
+    tag = "Leave",
+)]
+pub async fn run_accruals(
+    State(state): State<Arc<AppState>>,
+    claims: Option<Extension<VerifiedClaims>>,
+    Json(mut req): Json<RunAccrualsRequest>,
+) -> Result<Json<AccrualRunResult>, ApiError> {
+    let app_id = extract_tenant(&claims)?;
+    req.app_id = app_id;
+    let result = accrual::run_accruals(&state.pool, &req)
+        .await
+        .map_err(map_leave_error)?;
+    Ok(Json(result))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/timekeeping/leave/balances",
+    params(
+        ("employee_id" = Uuid, Query, description = "Employee UUID"),
+        ("as_of" = Option<NaiveDate>, Query, description = "Balance date (inclusive); defaults to today"),
+    ),
+    responses(
+        (status = 200, description = "Balances per leave type", body = Vec<LeaveBalance>),
+        (status = 401, description = "Unauthorized", body = ApiError),
+    ),
+    security(("bearer" = [])),
+    tag = "Leave",
+)]
+pub async fn leave_balances(
+    State(state): State<Arc<AppState>>,
+    claims: Option<Extension<VerifiedClaims>>,
+    Query(q): Query<BalanceQuery>,
+) -> Result<Json<PaginatedResponse<LeaveBalance>>, ApiError> {
+    let app_id = extract_tenant(&claims)?;
+    let as_of = q.as_of.unwrap_or_else(|| Utc::now().date_naive());
+    let balances = service::balances(&state.pool, &app_id, q.employee_id, as_of)
+        .await
+        .map_err(map_leave_error)?;
+    let total = balances.len() as i64;
+    Ok(Json(PaginatedResponse::new(balances, 1, total, total)))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/timekeeping/leave/ledger",
+    params(
+        ("employee_id" = Uuid, Query, description = "Employee UUID"),
+        ("leave_type_id" = Option<Uuid>, Query, description = "Filter by leave type"),
+    ),
+    responses(
+        (status = 200, description = "Ledger rows in effective-date order", body = Vec<LedgerEntry>),
+        (status = 401, description = "Unauthorized", body = ApiError),
+    ),
+    security(("bearer" = [])),
+    tag = "Leave",
+)]
+pub async fn leave_ledger(
+    State(state): State<Arc<AppState>>,
+    claims: Option<Extension<VerifiedClaims>>,
+    Query(q): Query<LedgerQuery>,
+) -> Result<Json<PaginatedResponse<LedgerEntry>>, ApiError> {
+    let app_id = extract_tenant(&claims)?;
+    let rows = service::ledger(&state.pool, &app_id, q.employee_id, q.leave_type_id)
+        .await
+        .map_err(map_leave_error)?;
+    let total = rows.len() as i64;
+    Ok(Json(PaginatedResponse::new(rows, 1, total, total)))
+}
@@ -5,10 +5,12 @@ pub mod billing;
 pub mod employees;
 pub mod entries;
 pub mod export;
+pub mod leave;
+pub mod pay_rules;
 pub mod projects;
 
 use axum::{
-    routing::{get, post, put},
+    routing::{delete, get, post, put},
     Router,
 };
 use security::{permissions, RequirePermissionsLayer};
@@ -76,6 +78,28 @@ use crate::AppState;
         billing::create_rate,
         billing::list_rates,
         billing::create_billing_run,
+        // Pay rules
+        pay_rules::create_pay_rule_set,
+        pay_rules::get_pay_rule_set,
+        pay_rules::list_pay_rule_sets,
+        pay_rules::deactivate_pay_rule_set,
+        // Leave
+        leave::create_leave_type,
+        leave::list_leave_types,
+        leave::create_accrual_policy,
+        leave::list_accrual_policies,
+        leave::create_holiday_calendar,
+        leave::list_holiday_calendars,
+        leave::add_holiday,
+        leave::submit_leave_request,
+        leave::approve_leave_request,
+        leave::reject_leave_request,
+        leave::recall_leave_request,
+        leave::list_leave_requests,
+        leave::get_leave_request,
+        leave::run_accruals,
+        leave::leave_balances,
+        leave::leave_ledger,
     ),
     components(schemas(
         // Employees
@@ -121,6 +145,31 @@ use crate::AppState;
         crate::domain::billing::models::BillingRunResult,
         crate::domain::billing::models::CreateBillingRateRequest,
         crate::domain::billing::models::CreateBillingRunRequest,
+        // Pay rules
+        crate::domain::pay_rules::models::PayRuleSet,
+        crate::domain::pay_rules::models::ShiftDifferential,
+        crate::domain::pay_rules::models::PayBuckets,
+        crate::domain::pay_rules::models::CreatePayRuleSetRequest,
+        crate::domain::pay_rules::models::CreateShiftDifferentialRequest,
+        // Leave
+        crate::domain::leave::models::LeaveType,
+        crate::domain::leave::models::LeaveCategory,
+        crate::domain::leave::models::AccrualPolicy,
+        crate::domain::leave::models::AccrualMethod,
+        crate::domain::leave::models::HolidayCalendar,
+        crate::domain::leave::models::Holiday,
+        crate::domain::leave::models::LeaveRequest,
+        crate::domain::leave::models::LeaveDay,
+        crate::domain::leave::models::LedgerEntry,
+        crate::domain::leave::models::LeaveBalance,
+        crate::domain::leave::models::AccrualRunResult,
+        crate::domain::leave::models::CreateLeaveTypeRequest,
+        crate::domain::leave::models::CreateAccrualPolicyRequest,
+        crate::domain::leave::models::CreateHolidayCalendarRequest,
+        crate::domain::leave::models::CreateHolidayRequest,
+        crate::domain::leave::models::SubmitLeaveRequest,
+        crate::domain::leave::models::ReviewLeaveRequest,
+        crate::domain::leave::models::RunAccrualsRequest,
         // Platform
         platform_http_contracts::ApiError,
     )),
@@ -214,6 +263,52 @@ pub fn router(state: Arc<AppState>) -> Router {
             "/api/timekeeping/billing-runs",
             post(billing::create_billing_run),
         )
+        // Pay rule sets — write
+        .route(
+            "/api/timekeeping/pay-rule-sets",
+            post(pay_rules::create_pay_rule_set),
+        )
+        .route(
+            "/api/timekeeping/pay-rule-sets/{id}",
+            delete(pay_rules::deactivate_pay_rule_set),
+        )
+        // Leave — write
+        .route(
+            "/api/timekeeping/leave/types",
+            post(leave::create_leave_type),
+        )
+        .route(
+            "/api/timekeeping/leave/policies",
+            post(leave::create_accrual_policy),
+        )
+        .route(
+            "/api/timekeeping/leave/holiday-calendars",
+            post(leave::create_holiday_calendar),
+        )
+        .route(
+            "/api/timekeeping/leave/holiday-calendars/{id}/holidays",
+            post(leave::add_holiday),
+        )
+        .route(
+            "/api/timekeeping/leave/requests",
+            post(leave::submit_leave_request),
+        )
+        .route(
+            "/api/timekeeping/leave/requests/approve",
+            post(leave::approve_leave_request),
+        )
+        .route(
+            "/api/timekeeping/leave/requests/reject",
+            post(leave::reject_leave_request),
+        )
+        .route(
+            "/api/timekeeping/leave/requests/recall",
+            post(leave::recall_leave_request),
+        )
+        .route(
+            "/api/timekeeping/leave/accruals/run",
+            post(leave::run_accruals),
+        )
         .route_layer(RequirePermissionsLayer::new(&[
             permissions::TIMEKEEPING_MUTATE,
         ]))
@@ -280,6 +375,38 @@ pub fn router(state: Arc<AppState>) -> Router {
         .route("/api/timekeeping/exports/{id}", get(export::get_export))
         // Billing rates — read
         .route("/api/timekeeping/rates", get(billing::list_rates))
+        // Pay rule sets — read
+        .route(
+            "/api/timekeeping/pay-rule-sets",
+            get(pay_rules::list_pay_rule_sets),
+        )
+        .route(
+            "/api/timekeeping/pay-rule-sets/{id}",
+            get(pay_rules::get_pay_rule_set),
+        )
+        // Leave — read
+        .route("/api/timekeeping/leave/types", get(leave::list_leave_types))
+        .route(
+            "/api/timekeeping/leave/policies",
+            get(leave::list_accrual_policies),
+        )
+        .route(
+            "/api/timekeeping/leave/holiday-calendars",
+            get(leave::list_holiday_calendars),
+        )
+        .route(
+            "/api/timekeeping/leave/requests",
+            get(leave::list_leave_requests),
+        )
+        .route(
+            "/api/timekeeping/leave/requests/{id}",
+            get(leave::get_leave_request),
+        )
+        .route(
+            "/api/timekeeping/leave/balances",
+            get(leave::leave_balances),
+        )
+        .route("/api/timekeeping/leave/ledger", get(leave::leave_ledger))
         .route_layer(RequirePermissionsLayer::new(&[
             permissions::TIMEKEEPING_READ,
         ]))
@@ -330,6 +457,18 @@ mod tests {
             json.contains("/api/timekeeping/billing-runs"),
             "must contain billing-runs path"
         );
+        assert!(
+            json.contains("/api/timekeeping/pay-rule-sets"),
+            "must contain pay-rule-sets path"
+        );
+        assert!(
+            json.contains("/api/timekeeping/leave/requests"),
+            "must contain leave requests path"
+        );
+        assert!(
+            json.contains("/api/timekeeping/leave/balances"),
+            "must contain leave balances path"
+        );
         assert!(
             json.contains("/api/timekeeping/rollups/by-project"),
             "must contain rollups path"
@@ -0,0 +1,135 @@
+//! Pay rule set HTTP handlers — create, get, list, deactivate.
+
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    Extension, Json,
+};
+use platform_http_contracts::{ApiError, PaginatedResponse};
+use security::VerifiedClaims;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::{
+    domain::pay_rules::{
+        models::{CreatePayRuleSetRequest, PayRuleError, PayRuleSet},
+        service,
+    },
+    AppState,
+};
+use platform_sdk::extract_tenant;
+
+// ============================================================================
+// Error mapping
+// ============================================================================
+
+fn map_pay_rule_error(err: PayRuleError) -> ApiError {
+    match err {
+        PayRuleError::NotFound => ApiError::not_found("Pay rule set not found"),
+        PayRuleError::DuplicateGroup => {
+            ApiError::conflict("An active pay rule set already exists for this employee group")
+        }
+        PayRuleError::Validation(msg) => ApiError::new(422, "validation_error", msg),
+        PayRuleError::Database(e) => ApiError::internal(e.to_string()),
+    }
+}
+
+// ============================================================================
+// Handlers
+// ============================================================================
+
+#[utoipa::path(
+    post,
+    path = "/api/timekeeping/pay-rule-sets",
+    request_body = CreatePayRuleSetRequest,
+    responses(
+        (status = 201, description = "Pay rule set created", body = PayRuleSet),
+        (status = 401, description = "Unauthorized", body = ApiError),
+        (status = 409, description = "Active rule set already exists for group", body = ApiError),
+        (status = 422, description = "Validation error", body = ApiError),
+    ),
+    security(("bearer" = [])),
+    tag = "Pay Rules",
+)]
+pub async fn create_pay_rule_set(
+    State(state): State<Arc<AppState>>,
+    claims: Option<Extension<VerifiedClaims>>,
+    Json(mut req): Json<CreatePayRuleSetRequest>,
+) -> Result<(StatusCode, Json<PayRuleSet>), ApiError> {
+    let app_id = extract_tenant(&claims)?;
+    req.app_id = app_id;
+    let rule_set = service::create_rule_set(&state.pool, &req)
+        .await
+        .map_err(map_pay_rule_error)?;
+    Ok((StatusCode::CREATED, Json(rule_set)))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/timekeeping/pay-rule-sets/{id}",
+    params(("id" = Uuid, Path, description = "Pay rule set UUID")),
+    responses(
+        (status = 200, description = "Pay rule set found", body = PayRuleSet),
+        (status = 401, description = "Unauthorized", body = ApiError),
+        (status = 404, description = "Not found", body = ApiError),
+    ),
+    security(("bearer" = [])),
+    tag = "Pay Rules",
+)]
+pub async fn get_pay_rule_set(
+    State(state): State<Arc<AppState>>,
+    claims: Option<Extension<VerifiedClaims>>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<PayRuleSet>, ApiError> {
+    let app_id = extract_tenant(&claims)?;
+    let rule_set = service::get_rule_set(&state.pool, &app_id, id)
+        .await
+        .map_err(map_pay_rule_error)?;
+    Ok(Json(rule_set))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/timekeeping/pay-rule-sets",
+    responses(
+        (status = 200, description = "Active pay rule sets", body = Vec<PayRuleSet>),
+        (status = 401, description = "Unauthorized", body = ApiError),
+    ),
+    security(("bearer" = [])),
+    tag = "Pay Rules",
+)]
+pub async fn list_pay_rule_sets(
+    State(state): State<Arc<AppState>>,
+    claims: Option<Extension<VerifiedClaims>>,
+) -> Result<Json<PaginatedResponse<PayRuleSet>>, ApiError> {
+    let app_id = extract_tenant(&claims)?;
+    let sets = service::list_rule_sets(&state.pool, &app_id)
+        .await
+        .map_err(map_pay_rule_error)?;
+    let total = sets.len() as i64;
+    Ok(Json(PaginatedResponse::new(sets, 1, total, total)))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/api/timekeeping/pay-rule-sets/{id}",
+    params(("id" = Uuid, Path, description = "Pay rule set UUID")),
+    responses(
+        (status = 204, description = "Pay rule set deactivated"),
+        (status = 401, description = "Unauthorized", body = ApiError),
+        (status = 404, description = "Not found", body = ApiError),
+    ),
+    security(("bearer" = [])),
+    tag = "Pay Rules",
+)]
+pub async fn deactivate_pay_rule_set(
+    State(state): State<Arc<AppState>>,
+    claims: Option<Extension<VerifiedClaims>>,
+    Path(id): Path<Uuid>,
+) -> Result<StatusCode, ApiError> {
+    let app_id = extract_tenant(&claims)?;
+    service::deactivate_rule_set(&state.pool, &app_id, id)
+        .await
+        .map_err(map_pay_rule_error)?;
+    Ok(StatusCode::NO_CONTENT)
+}
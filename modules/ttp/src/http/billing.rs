@@ -269,6 +269,9 @@ mod tests {
             app_id: None,
             roles: vec!["admin".into()],
             perms: vec!["ttp.mutate".into()],
+            scopes: Default::default(),
+            amr: Vec::new(),
+            auth_time: None,
             actor_type: ActorType::User,
             issued_at: now,
             expires_at: now + chrono::Duration::minutes(15),
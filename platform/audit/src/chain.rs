@@ -0,0 +1,865 @@
+//! Per-tenant hash chain over `audit_events`
+//!
+//! Each row written through [`crate::writer::AuditWriter`] records its
+//! position (`chain_seq`), the previous row's hash (`prev_hash`) and a hash of
+//! its own content plus those two (`record_hash`). Deleting a row leaves a
+//! sequence gap, editing one changes its recomputed hash, and rewriting the
+//! tail disagrees with `audit_chain_heads` and with signed checkpoints.
+//!
+//! Checkpoints are HMAC-SHA256 signatures over a chain head, written
+//! periodically (e.g. `compliance-export checkpoint` from cron). The key is
+//! held outside the database, so a DBA can neither forge nor re-sign them.
+
+use chrono::{DateTime, SecondsFormat, Utc};
+use hmac::{Hmac, Mac};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Map, Value};
+use sha2::{Digest, Sha256};
+use sqlx::{PgPool, Postgres, Transaction};
+use thiserror::Error;
+use uuid::Uuid;
+
+use crate::schema::{AuditEvent, WriteAuditRequest};
+
+/// `prev_hash` of the first row in every chain.
+pub const GENESIS_HASH: &str = "0000000000000000000000000000000000000000000000000000000000000000";
+
+/// Chain for events that carry no tenant.
+pub const PLATFORM_CHAIN: &str = "";
+
+/// Environment variable holding the checkpoint signing key.
+pub const CHECKPOINT_KEY_ENV: &str = "AUDIT_CHECKPOINT_KEY";
+
+/// Environment variable naming the checkpoint key (for rotation).
+pub const CHECKPOINT_KEY_ID_ENV: &str = "AUDIT_CHECKPOINT_KEY_ID";
+
+/// Issues listed individually before the report only counts them.
+const MAX_ISSUES: usize = 1000;
+
+#[derive(Debug, Error)]
+pub enum ChainError {
+    #[error("Database error: {0}")]
+    Database(#[from] sqlx::Error),
+
+    #[error("Checkpoint key error: {0}")]
+    Key(String),
+}
+
+pub type Result<T> = std::result::Result<T, ChainError>;
+
+/// Tenant chain an event joins: the request's tenant, else a string
+/// `metadata.tenant_id`, else [`PLATFORM_CHAIN`].
+pub fn chain_tenant(request: &WriteAuditRequest) -> String {
+    if let Some(tenant) = &request.tenant_id {
+        return tenant.clone();
+    }
+    request
+        .metadata
+        .as_ref()
+        .and_then(|m| m.get("tenant_id"))
+        .and_then(Value::as_str)
+        .unwrap_or(PLATFORM_CHAIN)
+        .to_string()
+}
+
+/// Postgres stores microseconds; hash what will be read back.
+fn truncate_to_micros(at: DateTime<Utc>) -> DateTime<Utc> {
+    DateTime::from_timestamp_micros(at.timestamp_micros()).unwrap_or(at)
+}
+
+fn timestamp(at: &DateTime<Utc>) -> String {
+    at.to_rfc3339_opts(SecondsFormat::Micros, true)
+}
+
+/// Rebuild `value` with object keys sorted at every level, so the hash does
+/// not depend on JSONB key order.
+fn canonical(value: &Value) -> Value {
+    match value {
+        Value::Object(map) => {
+            let mut keys: Vec<&String> = map.keys().collect();
+            keys.sort();
+            let mut sorted = Map::new();
+            for key in keys {
+                sorted.insert(key.clone(), canonical(&map[key]));
+            }
+            Value::Object(sorted)
+        }
+        Value::Array(items) => Value::Array(items.iter().map(canonical).collect()),
+        other => other.clone(),
+    }
+}
+
+fn sha256_hex(bytes: &[u8]) -> String {
+    hex::encode(Sha256::digest(bytes))
+}
+
+/// Hash of a chained row: its content, `chain_seq` and `prev_hash`.
+pub fn record_hash(event: &AuditEvent, chain_seq: i64, prev_hash: &str) -> String {
+    let content = json!({
+        "v": 1,
+        "tenant_id": event.tenant_id,
+        "chain_seq": chain_seq,
+        "prev_hash": prev_hash,
+        "audit_id": event.audit_id,
+        "occurred_at": timestamp(&event.occurred_at),
+        "actor_id": event.actor_id,
+        "actor_type": event.actor_type,
+        "action": event.action,
+        "mutation_class": event.mutation_class,
+        "entity_type": event.entity_type,
+        "entity_id": event.entity_id,
+        "before_snapshot": event.before_snapshot,
+        "after_snapshot": event.after_snapshot,
+        "before_hash": event.before_hash,
+        "after_hash": event.after_hash,
+        "causation_id": event.causation_id,
+        "correlation_id": event.correlation_id,
+        "trace_id": event.trace_id,
+        "metadata": event.metadata,
+    });
+    sha256_hex(canonical(&content).to_string().as_bytes())
+}
+
+/// Append `request` to its tenant's chain inside `tx`.
+///
+/// Locks the tenant's `audit_chain_heads` row until `tx` ends, so appends
+/// for one tenant are serialised.
+pub async fn append_in_tx(
+    tx: &mut Transaction<'_, Postgres>,
+    request: WriteAuditRequest,
+) -> std::result::Result<AuditEvent, sqlx::Error> {
+    let tenant_id = chain_tenant(&request);
+
+    sqlx::query(
+        "INSERT INTO audit_chain_heads (tenant_id) VALUES ($1) ON CONFLICT (tenant_id) DO NOTHING",
+    )
+    .bind(&tenant_id)
+    .execute(&mut **tx)
+    .await?;
+
+    let (last_seq, last_hash): (i64, String) = sqlx::query_as(
+        "SELECT last_seq, last_hash FROM audit_chain_heads WHERE tenant_id = $1 FOR UPDATE",
+    )
+    .bind(&tenant_id)
+    .fetch_one(&mut **tx)
+    .await?;
+
+    let seq = last_seq + 1;
+    let mut event = AuditEvent {
+        audit_id: Uuid::new_v4(),
+        occurred_at: truncate_to_micros(Utc::now()),
+        actor_id: request.actor_id,
+        actor_type: request.actor_type,
+        action: request.action,
+        mutation_class: request.mutation_class,
+        entity_type: request.entity_type,
+        entity_id: request.entity_id,
+        before_snapshot: request.before_snapshot,
+        after_snapshot: request.after_snapshot,
+        before_hash: request.before_hash,
+        after_hash: request.after_hash,
+        causation_id: request.causation_id,
+        correlation_id: request.correlation_id,
+        trace_id: request.trace_id,
+        metadata: request.metadata,
+        tenant_id,
+        chain_seq: Some(seq),
+        prev_hash: Some(last_hash.clone()),
+        record_hash: None,
+    };
+    let hash = record_hash(&event, seq, &last_hash);
+
+    sqlx::query(
+        r#"
+        INSERT INTO audit_events (
+            audit_id, occurred_at,
+            actor_id, actor_type, action, mutation_class,
+            entity_type, entity_id,
+            before_snapshot, after_snapshot,
+            before_hash, after_hash,
+            causation_id, correlation_id, trace_id,
+            metadata,
+            tenant_id, chain_seq, prev_hash, record_hash
+        ) VALUES (
+            $1, $2, $3, $4, $5, $6, $7, $8, $9, $10,
+            $11, $12, $13, $14, $15, $16, $17, $18, $19, $20
+        )
+        "#,
+    )
+    .bind(event.audit_id)
+    .bind(event.occurred_at)
+    .bind(event.actor_id)
+    .bind(&event.actor_type)
+    .bind(&event.action)
+    .bind(event.mutation_class)
+    .bind(&event.entity_type)
+    .bind(&event.entity_id)
+    .bind(&event.before_snapshot)
+    .bind(&event.after_snapshot)
+    .bind(&event.before_hash)
+    .bind(&event.after_hash)
+    .bind(event.causation_id)
+    .bind(event.correlation_id)
+    .bind(&event.trace_id)
+    .bind(&event.metadata)
+    .bind(&event.tenant_id)
+    .bind(seq)
+    .bind(&last_hash)
+    .bind(&hash)
+    .execute(&mut **tx)
+    .await?;
+
+    sqlx::query(
+        r#"
+        UPDATE audit_chain_heads
+        SET last_seq = $2, last_hash = $3, updated_at = CURRENT_TIMESTAMP
+        WHERE tenant_id = $1
+        "#,
+    )
+    .bind(&event.tenant_id)
+    .bind(seq)
+    .bind(&hash)
+    .execute(&mut **tx)
+    .await?;
+
+    event.record_hash = Some(hash);
+    Ok(event)
+}
+
+// ============================================================================
+// Checkpoints
+// ============================================================================
+
+/// HMAC key used to sign and verify checkpoints.
+#[derive(Clone)]
+pub struct CheckpointKey {
+    pub key_id: String,
+    secret: Vec<u8>,
+}
+
+impl std::fmt::Debug for CheckpointKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CheckpointKey")
+            .field("key_id", &self.key_id)
+            .finish_non_exhaustive()
+    }
+}
+
+impl CheckpointKey {
+    pub fn new(key_id: impl Into<String>, secret: impl Into<Vec<u8>>) -> Self {
+        Self {
+            key_id: key_id.into(),
+            secret: secret.into(),
+        }
+    }
+
+    /// Key from [`CHECKPOINT_KEY_ENV`] and [`CHECKPOINT_KEY_ID_ENV`]
+    /// (default id `"default"`). `None` when the key variable is unset.
+    pub fn from_env() -> Result<Option<Self>> {
+        let Ok(secret) = std::env::var(CHECKPOINT_KEY_ENV) else {
+            return Ok(None);
+        };
+        if secret.len() < 32 {
+            return Err(ChainError::Key(format!(
+                "{} must be at least 32 bytes",
+                CHECKPOINT_KEY_ENV
+            )));
+        }
+        let key_id = std::env::var(CHECKPOINT_KEY_ID_ENV).unwrap_or_else(|_| "default".to_string());
+        Ok(Some(Self::new(key_id, secret.into_bytes())))
+    }
+
+    fn mac(&self, checkpoint: &Checkpoint) -> Hmac<Sha256> {
+        let mut mac =
+            Hmac::<Sha256>::new_from_slice(&self.secret).expect("HMAC accepts keys of any length");
+        mac.update(signing_payload(checkpoint).as_bytes());
+        mac
+    }
+
+    fn sign(&self, checkpoint: &Checkpoint) -> String {
+        hex::encode(self.mac(checkpoint).finalize().into_bytes())
+    }
+
+    /// Whether `checkpoint` was signed by this key.
+    pub fn verify(&self, checkpoint: &Checkpoint) -> bool {
+        if checkpoint.key_id != self.key_id {
+            return false;
+        }
+        let Ok(signature) = hex::decode(&checkpoint.signature) else {
+            return false;
+        };
+        self.mac(checkpoint).verify_slice(&signature).is_ok()
+    }
+}
+
+fn signing_payload(checkpoint: &Checkpoint) -> String {
+    format!(
+        "audit-checkpoint:v1|{}|{}|{}|{}|{}|{}",
+        checkpoint.checkpoint_id,
+        checkpoint.tenant_id,
+        checkpoint.chain_seq,
+        checkpoint.head_hash,
+        checkpoint.key_id,
+        timestamp(&checkpoint.created_at),
+    )
+}
+
+/// A signed statement that a tenant's chain had `head_hash` at `chain_seq`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, sqlx::FromRow)]
+pub struct Checkpoint {
+    pub checkpoint_id: Uuid,
+    pub tenant_id: String,
+    pub chain_seq: i64,
+    pub head_hash: String,
+    pub key_id: String,
+    pub signature: String,
+    pub created_at: DateTime<Utc>,
+}
+
+impl Checkpoint {
+    /// Sign a checkpoint for the given head.
+    pub fn sign(
+        key: &CheckpointKey,
+        tenant_id: &str,
+        chain_seq: i64,
+        head_hash: &str,
+        created_at: DateTime<Utc>,
+    ) -> Self {
+        let mut checkpoint = Self {
+            checkpoint_id: Uuid::new_v4(),
+            tenant_id: tenant_id.to_string(),
+            chain_seq,
+            head_hash: head_hash.to_string(),
+            key_id: key.key_id.clone(),
+            signature: String::new(),
+            created_at: truncate_to_micros(created_at),
+        };
+        checkpoint.signature = key.sign(&checkpoint);
+        checkpoint
+    }
+}
+
+/// Sign and store a checkpoint of `tenant_id`'s current head.
+///
+/// Returns `None` if the chain is empty or has not moved since the last
+/// checkpoint.
+pub async fn create_checkpoint(
+    pool: &PgPool,
+    tenant_id: &str,
+    key: &CheckpointKey,
+) -> Result<Option<Checkpoint>> {
+    let head: Option<(i64, String)> =
+        sqlx::query_as("SELECT last_seq, last_hash FROM audit_chain_heads WHERE tenant_id = $1")
+            .bind(tenant_id)
+            .fetch_optional(pool)
+            .await?;
+    let Some((seq, hash)) = head.filter(|(seq, _)| *seq > 0) else {
+        return Ok(None);
+    };
+
+    let latest = latest_checkpoint(pool, tenant_id).await?;
+    if latest.is_some_and(|c| c.chain_seq == seq && c.head_hash == hash) {
+        return Ok(None);
+    }
+
+    let checkpoint = Checkpoint::sign(key, tenant_id, seq, &hash, Utc::now());
+    sqlx::query(
+        r#"
+        INSERT INTO audit_checkpoints (
+            checkpoint_id, tenant_id, chain_seq, head_hash, key_id, signature, created_at
+        ) VALUES ($1, $2, $3, $4, $5, $6, $7)
+        "#,
+    )
+    .bind(checkpoint.checkpoint_id)
+    .bind(&checkpoint.tenant_id)
+    .bind(checkpoint.chain_seq)
+    .bind(&checkpoint.head_hash)
+    .bind(&checkpoint.key_id)
+    .bind(&checkpoint.signature)
+    .bind(checkpoint.created_at)
+    .execute(pool)
+    .await?;
+
+    tracing::info!(
+        tenant_id = %checkpoint.tenant_id,
+        chain_seq = checkpoint.chain_seq,
+        "Audit chain checkpoint written"
+    );
+    Ok(Some(checkpoint))
+}
+
+/// Checkpoint every tenant chain that moved since its last checkpoint.
+pub async fn checkpoint_all(pool: &PgPool, key: &CheckpointKey) -> Result<Vec<Checkpoint>> {
+    let tenants: Vec<String> =
+        sqlx::query_scalar("SELECT tenant_id FROM audit_chain_heads ORDER BY tenant_id")
+            .fetch_all(pool)
+            .await?;
+    let mut written = Vec::new();
+    for tenant_id in tenants {
+        if let Some(checkpoint) = create_checkpoint(pool, &tenant_id, key).await? {
+            written.push(checkpoint);
+        }
+    }
+    Ok(written)
+}
+
+pub async fn latest_checkpoint(pool: &PgPool, tenant_id: &str) -> Result<Option<Checkpoint>> {
+    let checkpoint = sqlx::query_as(
+        r#"
+        SELECT checkpoint_id, tenant_id, chain_seq, head_hash, key_id, signature, created_at
+        FROM audit_checkpoints
+        WHERE tenant_id = $1
+        ORDER BY chain_seq DESC, created_at DESC
+        LIMIT 1
+        "#,
+    )
+    .bind(tenant_id)
+    .fetch_optional(pool)
+    .await?;
+    Ok(checkpoint)
+}
+
+pub async fn list_checkpoints(pool: &PgPool, tenant_id: &str) -> Result<Vec<Checkpoint>> {
+    let checkpoints = sqlx::query_as(
+        r#"
+        SELECT checkpoint_id, tenant_id, chain_seq, head_hash, key_id, signature, created_at
+        FROM audit_checkpoints
+        WHERE tenant_id = $1
+        ORDER BY chain_seq ASC, created_at ASC
+        "#,
+    )
+    .bind(tenant_id)
+    .fetch_all(pool)
+    .await?;
+    Ok(checkpoints)
+}
+
+// ============================================================================
+// Verification
+// ============================================================================
+
+/// A problem found while walking a chain.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum ChainIssue {
+    /// Rows `expected_seq..found_seq` are missing (deleted).
+    Gap { expected_seq: i64, found_seq: i64 },
+    /// The row's content no longer matches its stored hash (edited).
+    Modified {
+        chain_seq: i64,
+        audit_id: Uuid,
+        stored_hash: Option<String>,
+        computed_hash: String,
+    },
+    /// `prev_hash` does not match the previous row's hash (re-hashed or
+    /// spliced).
+    BrokenLink {
+        chain_seq: i64,
+        audit_id: Uuid,
+        expected_prev_hash: String,
+        found_prev_hash: Option<String>,
+    },
+    /// The chain head is ahead of the last row (tail deleted), or disagrees
+    /// with it.
+    HeadMismatch {
+        head_seq: i64,
+        head_hash: String,
+        last_seq: i64,
+        last_hash: Option<String>,
+    },
+    /// A checkpoint's head no longer matches the row at its sequence.
+    CheckpointMismatch {
+        checkpoint_id: Uuid,
+        chain_seq: i64,
+        checkpoint_hash: String,
+        record_hash: Option<String>,
+    },
+    /// A checkpoint's signature does not verify with the supplied key.
+    BadSignature { checkpoint_id: Uuid, key_id: String },
+    /// Rows for this tenant written after chaining began without chain
+    /// columns (inserted around the writer).
+    Unchained { count: i64 },
+}
+
+/// Result of walking one tenant chain.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChainVerification {
+    pub tenant_id: String,
+    pub verified_at: DateTime<Utc>,
+    pub records: usize,
+    pub first_seq: Option<i64>,
+    pub last_seq: Option<i64>,
+    pub head_seq: Option<i64>,
+    pub head_hash: Option<String>,
+    /// Checkpoints whose head matched the chain (and, when a key was
+    /// supplied, whose signature verified).
+    pub checkpoints_verified: usize,
+    /// Whether checkpoint signatures were checked.
+    pub signatures_checked: bool,
+    pub latest_checkpoint: Option<Checkpoint>,
+    pub issues: Vec<ChainIssue>,
+    /// Issues found beyond the first `MAX_ISSUES`.
+    pub issues_truncated: usize,
+}
+
+impl ChainVerification {
+    /// No gaps, modifications, broken links, bad checkpoints or unchained
+    /// rows.
+    pub fn is_intact(&self) -> bool {
+        self.issues.is_empty() && self.issues_truncated == 0
+    }
+
+    fn push(&mut self, issue: ChainIssue) {
+        if self.issues.len() < MAX_ISSUES {
+            self.issues.push(issue);
+        } else {
+            self.issues_truncated += 1;
+        }
+    }
+}
+
+/// Walk `records` (chained rows of one tenant, ordered by `chain_seq`) and
+/// check them against the stored head and checkpoints.
+pub fn verify_records(
+    tenant_id: &str,
+    records: &[AuditEvent],
+    head: Option<(i64, String)>,
+    checkpoints: &[Checkpoint],
+    key: Option<&CheckpointKey>,
+) -> ChainVerification {
+    let mut report = ChainVerification {
+        tenant_id: tenant_id.to_string(),
+        verified_at: Utc::now(),
+        records: records.len(),
+        first_seq: records.first().and_then(|r| r.chain_seq),
+        last_seq: records.last().and_then(|r| r.chain_seq),
+        head_seq: head.as_ref().map(|(seq, _)| *seq),
+        head_hash: head.as_ref().map(|(_, hash)| hash.clone()),
+        checkpoints_verified: 0,
+        signatures_checked: key.is_some(),
+        latest_checkpoint: checkpoints.iter().max_by_key(|c| c.chain_seq).cloned(),
+        issues: Vec::new(),
+        issues_truncated: 0,
+    };
+
+    let mut expected_seq = 1;
+    let mut expected_prev = Some(GENESIS_HASH.to_string());
+    for record in records {
+        let Some(seq) = record.chain_seq else {
+            continue;
+        };
+        if seq != expected_seq {
+            report.push(ChainIssue::Gap {
+                expected_seq,
+                found_seq: seq,
+            });
+            // The predecessor is gone; the link cannot be checked.
+            expected_prev = None;
+        }
+        if let Some(expected) = &expected_prev {
+            if record.prev_hash.as_deref() != Some(expected.as_str()) {
+                report.push(ChainIssue::BrokenLink {
+                    chain_seq: seq,
+                    audit_id: record.audit_id,
+                    expected_prev_hash: expected.clone(),
+                    found_prev_hash: record.prev_hash.clone(),
+                });
+            }
+        }
+        let computed = record_hash(record, seq, record.prev_hash.as_deref().unwrap_or_default());
+        if record.record_hash.as_deref() != Some(computed.as_str()) {
+            report.push(ChainIssue::Modified {
+                chain_seq: seq,
+                audit_id: record.audit_id,
+                stored_hash: record.record_hash.clone(),
+                computed_hash: computed,
+            });
+        }
+        expected_seq = seq + 1;
+        expected_prev = record.record_hash.clone();
+    }
+
+    let last_seq = report.last_seq.unwrap_or(0);
+    let last_hash = records.last().and_then(|r| r.record_hash.clone());
+    if let Some((head_seq, head_hash)) = head {
+        let matches = head_seq == last_seq
+            && (head_seq == 0 && last_hash.is_none()
+                || last_hash.as_deref() == Some(head_hash.as_str()));
+        if !matches {
+            report.push(ChainIssue::HeadMismatch {
+                head_seq,
+                head_hash,
+                last_seq,
+                last_hash,
+            });
+        }
+    }
+
+    for checkpoint in checkpoints {
+        let record_hash = records
+            .iter()
+            .find(|r| r.chain_seq == Some(checkpoint.chain_seq))
+            .and_then(|r| r.record_hash.clone());
+        let mut ok = true;
+        if record_hash.as_deref() != Some(checkpoint.head_hash.as_str()) {
+            ok = false;
+            report.push(ChainIssue::CheckpointMismatch {
+                checkpoint_id: checkpoint.checkpoint_id,
+                chain_seq: checkpoint.chain_seq,
+                checkpoint_hash: checkpoint.head_hash.clone(),
+                record_hash,
+            });
+        }
+        if let Some(key) = key {
+            if !key.verify(checkpoint) {
+                ok = false;
+                report.push(ChainIssue::BadSignature {
+                    checkpoint_id: checkpoint.checkpoint_id,
+                    key_id: checkpoint.key_id.clone(),
+                });
+            }
+        }
+        if ok {
+            report.checkpoints_verified += 1;
+        }
+    }
+
+    report
+}
+
+/// Load and verify `tenant_id`'s chain.
+pub async fn verify_chain(
+    pool: &PgPool,
+    tenant_id: &str,
+    key: Option<&CheckpointKey>,
+) -> Result<ChainVerification> {
+    let records = sqlx::query_as::<_, AuditEvent>(
+        r#"
+        SELECT
+            audit_id, occurred_at,
+            actor_id, actor_type,
+            action, mutation_class,
+            entity_type, entity_id,
+            before_snapshot, after_snapshot,
+            before_hash, after_hash,
+            causation_id, correlation_id, trace_id,
+            metadata,
+            tenant_id, chain_seq, prev_hash, record_hash
+        FROM audit_events
+        WHERE tenant_id = $1 AND chain_seq IS NOT NULL
+        ORDER BY chain_seq ASC
+        "#,
+    )
+    .bind(tenant_id)
+    .fetch_all(pool)
+    .await?;
+
+    let head: Option<(i64, String)> =
+        sqlx::query_as("SELECT last_seq, last_hash FROM audit_chain_heads WHERE tenant_id = $1")
+            .bind(tenant_id)
+            .fetch_optional(pool)
+            .await?;
+
+    let checkpoints = list_checkpoints(pool, tenant_id).await?;
+    let mut report = verify_records(tenant_id, &records, head, &checkpoints, key);
+
+    if let Some(first) = records.first() {
+        let unchained: i64 = sqlx::query_scalar(
+            r#"
+            SELECT COUNT(*) FROM audit_events
+            WHERE tenant_id = $1 AND chain_seq IS NULL AND occurred_at >= $2
+            "#,
+        )
+        .bind(tenant_id)
+        .bind(first.occurred_at)
+        .fetch_one(pool)
+        .await?;
+        if unchained > 0 {
+            report.push(ChainIssue::Unchained { count: unchained });
+        }
+    }
+
+    Ok(report)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::schema::MutationClass;
+
+    fn chain(n: i64) -> Vec<AuditEvent> {
+        let mut prev = GENESIS_HASH.to_string();
+        (1..=n)
+            .map(|seq| {
+                let mut event = AuditEvent {
+                    audit_id: Uuid::from_u128(seq as u128),
+                    occurred_at: truncate_to_micros(Utc::now()),
+                    actor_id: Uuid::nil(),
+                    actor_type: "system".into(),
+                    action: "PostJournalEntry".into(),
+                    mutation_class: MutationClass::Create,
+                    entity_type: "JournalEntry".into(),
+                    entity_id: format!("je_{seq}"),
+                    before_snapshot: None,
+                    after_snapshot: Some(json!({"b": 2, "a": {"y": 1, "x": [3]}})),
+                    before_hash: None,
+                    after_hash: None,
+                    causation_id: None,
+                    correlation_id: None,
+                    trace_id: None,
+                    metadata: None,
+                    tenant_id: "t1".into(),
+                    chain_seq: Some(seq),
+                    prev_hash: Some(prev.clone()),
+                    record_hash: None,
+                };
+                let hash = record_hash(&event, seq, &prev);
+                event.record_hash = Some(hash.clone());
+                prev = hash;
+                event
+            })
+            .collect()
+    }
+
+    fn head(records: &[AuditEvent]) -> Option<(i64, String)> {
+        let last = records.last()?;
+        Some((last.chain_seq?, last.record_hash.clone()?))
+    }
+
+    fn key() -> CheckpointKey {
+        CheckpointKey::new("k1", b"0123456789abcdef0123456789abcdef".to_vec())
+    }
+
+    #[test]
+    fn intact_chain_verifies() {
+        let records = chain(5);
+        let cp = Checkpoint::sign(
+            &key(),
+            "t1",
+            3,
+            records[2].record_hash.as_ref().unwrap(),
+            Utc::now(),
+        );
+        let report = verify_records("t1", &records, head(&records), &[cp], Some(&key()));
+        assert!(report.is_intact(), "{:?}", report.issues);
+        assert_eq!(report.checkpoints_verified, 1);
+        assert_eq!(report.last_seq, Some(5));
+    }
+
+    #[test]
+    fn hash_ignores_json_key_order() {
+        let mut records = chain(1);
+        records[0].after_snapshot = Some(json!({"a": {"x": [3], "y": 1}, "b": 2}));
+        let report = verify_records("t1", &records, head(&records), &[], None);
+        assert!(report.is_intact(), "{:?}", report.issues);
+    }
+
+    #[test]
+    fn edited_row_is_reported_as_modified() {
+        let mut records = chain(3);
+        records[1].entity_id = "je_forged".into();
+        let report = verify_records("t1", &records, head(&records), &[], None);
+        assert!(matches!(
+            report.issues.as_slice(),
+            [ChainIssue::Modified { chain_seq: 2, .. }]
+        ));
+    }
+
+    #[test]
+    fn rehashed_row_breaks_the_next_link() {
+        let mut records = chain(3);
+        records[1].entity_id = "je_forged".into();
+        records[1].record_hash = Some(record_hash(
+            &records[1],
+            2,
+            records[1].prev_hash.as_deref().unwrap(),
+        ));
+        let report = verify_records("t1", &records, head(&records), &[], None);
+        assert!(matches!(
+            report.issues.as_slice(),
+            [ChainIssue::BrokenLink { chain_seq: 3, .. }]
+        ));
+    }
+
+    #[test]
+    fn deleted_rows_leave_gap_and_head_mismatch() {
+        let full = chain(5);
+        let head = head(&full);
+        let mut records = full.clone();
+        records.remove(1);
+        records.pop();
+        let report = verify_records("t1", &records, head, &[], None);
+        assert_eq!(
+            report.issues[0],
+            ChainIssue::Gap {
+                expected_seq: 2,
+                found_seq: 3
+            }
+        );
+        assert!(matches!(
+            report.issues[1],
+            ChainIssue::HeadMismatch {
+                head_seq: 5,
+                last_seq: 4,
+                ..
+            }
+        ));
+        assert_eq!(report.issues.len(), 2);
+    }
+
+    #[test]
+    fn forged_checkpoint_fails_signature() {
+        let records = chain(2);
+        let hash = records[1].record_hash.clone().unwrap();
+        let forger = CheckpointKey::new("k1", b"not-the-real-key-not-the-real-key".to_vec());
+        let forged = Checkpoint::sign(&forger, "t1", 2, &hash, Utc::now());
+        let report = verify_records("t1", &records, head(&records), &[forged], Some(&key()));
+        assert!(matches!(
+            report.issues.as_slice(),
+            [ChainIssue::BadSignature { .. }]
+        ));
+        assert_eq!(report.checkpoints_verified, 0);
+    }
+
+    #[test]
+    fn rewritten_tail_disagrees_with_checkpoint() {
+        let records = chain(3);
+        let cp = Checkpoint::sign(
+            &key(),
+            "t1",
+            3,
+            records[2].record_hash.as_ref().unwrap(),
+            Utc::now(),
+        );
+        let mut rewritten = records[..2].to_vec();
+        let mut replacement = records[2].clone();
+        replacement.entity_id = "je_other".into();
+        replacement.record_hash = Some(record_hash(
+            &replacement,
+            3,
+            replacement.prev_hash.as_deref().unwrap(),
+        ));
+        rewritten.push(replacement);
+        let report = verify_records("t1", &rewritten, head(&rewritten), &[cp], Some(&key()));
+        assert!(matches!(
+            report.issues.as_slice(),
+            [ChainIssue::CheckpointMismatch { chain_seq: 3, .. }]
+        ));
+    }
+
+    #[test]
+    fn chain_tenant_falls_back_to_metadata_then_platform() {
+        let request = WriteAuditRequest::new(
+            Uuid::nil(),
+            "system".into(),
+            "A".into(),
+            MutationClass::Create,
+            "E".into(),
+            "1".into(),
+        );
+        assert_eq!(chain_tenant(&request), PLATFORM_CHAIN);
+        let request = request.with_metadata(json!({"tenant_id": "t9"}));
+        assert_eq!(chain_tenant(&request), "t9");
+        assert_eq!(chain_tenant(&request.with_tenant("t1")), "t1");
+    }
+}
@@ -9,8 +9,14 @@
 //! - Every mutation in module outbox tables has exactly one audit record
 //! - Audit records are linked via causation_id to the originating event
 //! - No gaps or duplicates exist in the audit trail
+//!
+//! ## Tamper Evidence
+//!
+//! Rows are hash-chained per tenant and the chain head is periodically
+//! signed; see [`chain`] for verification.
 
 pub mod actor;
+pub mod chain;
 pub mod diff;
 pub mod outbox_bridge;
 pub mod policy;
@@ -217,29 +217,9 @@ pub async fn backfill_missing_audit_records(
             "module": module_name,
         }));
 
-        sqlx::query_scalar::<_, Uuid>(
-            r#"
-            INSERT INTO audit_events (
-                actor_id, actor_type, action, mutation_class,
-                entity_type, entity_id,
-                causation_id, correlation_id, trace_id,
-                metadata
-            ) VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)
-            RETURNING audit_id
-            "#,
-        )
-        .bind(request.actor_id)
-        .bind(&request.actor_type)
-        .bind(&request.action)
-        .bind(request.mutation_class)
-        .bind(&request.entity_type)
-        .bind(&request.entity_id)
-        .bind(request.causation_id)
-        .bind(request.correlation_id)
-        .bind(&request.trace_id)
-        .bind(&request.metadata)
-        .fetch_one(audit_pool)
-        .await?;
+        let mut tx = audit_pool.begin().await?;
+        crate::chain::append_in_tx(&mut tx, request).await?;
+        tx.commit().await?;
 
         written += 1;
     }
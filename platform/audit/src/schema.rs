@@ -48,6 +48,12 @@ pub struct AuditEvent {
 
     // Metadata
     pub metadata: Option<serde_json::Value>,
+
+    // Hash chain (NULL for rows written before chaining)
+    pub tenant_id: String,
+    pub chain_seq: Option<i64>,
+    pub prev_hash: Option<String>,
+    pub record_hash: Option<String>,
 }
 
 /// Request to write an audit event (before database insertion)
@@ -78,6 +84,11 @@ pub struct WriteAuditRequest {
 
     // Metadata
     pub metadata: Option<serde_json::Value>,
+
+    /// Tenant whose hash chain the event joins. Falls back to
+    /// `metadata.tenant_id`, then the platform chain (see [`crate::chain`]).
+    #[serde(default)]
+    pub tenant_id: Option<String>,
 }
 
 impl WriteAuditRequest {
@@ -105,6 +116,7 @@ impl WriteAuditRequest {
             correlation_id: None,
             trace_id: None,
             metadata: None,
+            tenant_id: None,
         }
     }
 
@@ -139,6 +151,12 @@ impl WriteAuditRequest {
         self
     }
 
+    /// Set the tenant whose hash chain this event joins
+    pub fn with_tenant(mut self, tenant_id: impl Into<String>) -> Self {
+        self.tenant_id = Some(tenant_id.into());
+        self
+    }
+
     /// Add metadata
     pub fn with_metadata(mut self, metadata: serde_json::Value) -> Self {
         self.metadata = Some(metadata);
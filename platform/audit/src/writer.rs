@@ -2,6 +2,7 @@
 //!
 //! Provides a single writer interface for modules to record audit events
 
+use crate::chain;
 use crate::schema::{AuditEvent, WriteAuditRequest};
 use sqlx::{PgPool, Postgres, Transaction};
 use thiserror::Error;
@@ -38,14 +39,17 @@ impl AuditWriter {
         entity_id = %request.entity_id
     ))]
     pub async fn write(&self, request: WriteAuditRequest) -> Result<Uuid> {
-        let audit_id = self.write_impl(&self.pool, request).await?;
+        let mut tx = self.pool.begin().await?;
+        let audit_id = Self::write_impl_tx(&mut tx, request).await?;
+        tx.commit().await?;
         tracing::debug!(audit_id = %audit_id, "Audit event written");
         Ok(audit_id)
     }
 
     /// Write an audit event within an existing transaction
     ///
-    /// Use this for transactional consistency with module mutations
+    /// Use this for transactional consistency with module mutations. The
+    /// tenant's chain head stays locked until `tx` commits or rolls back.
     #[tracing::instrument(skip(tx, request), fields(
         action = %request.action,
         entity_type = %request.entity_type,
@@ -60,84 +64,13 @@ impl AuditWriter {
         Ok(audit_id)
     }
 
-    /// Internal implementation for pool-based writes
-    async fn write_impl<'e, E>(&self, executor: E, request: WriteAuditRequest) -> Result<Uuid>
-    where
-        E: sqlx::Executor<'e, Database = Postgres>,
-    {
-        let audit_id = sqlx::query_scalar::<_, Uuid>(
-            r#"
-            INSERT INTO audit_events (
-                actor_id, actor_type, action, mutation_class,
-                entity_type, entity_id,
-                before_snapshot, after_snapshot,
-                before_hash, after_hash,
-                causation_id, correlation_id, trace_id,
-                metadata
-            ) VALUES (
-                $1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14
-            )
-            RETURNING audit_id
-            "#,
-        )
-        .bind(request.actor_id)
-        .bind(request.actor_type)
-        .bind(request.action)
-        .bind(request.mutation_class)
-        .bind(request.entity_type)
-        .bind(request.entity_id)
-        .bind(request.before_snapshot)
-        .bind(request.after_snapshot)
-        .bind(request.before_hash)
-        .bind(request.after_hash)
-        .bind(request.causation_id)
-        .bind(request.correlation_id)
-        .bind(request.trace_id)
-        .bind(request.metadata)
-        .fetch_one(executor)
-        .await?;
-
-        Ok(audit_id)
-    }
-
-    /// Internal implementation for transaction-based writes
+    /// Append to the tenant's hash chain (see [`crate::chain`])
     async fn write_impl_tx(
         tx: &mut Transaction<'_, Postgres>,
         request: WriteAuditRequest,
     ) -> Result<Uuid> {
-        let audit_id = sqlx::query_scalar::<_, Uuid>(
-            r#"
-            INSERT INTO audit_events (
-                actor_id, actor_type, action, mutation_class,
-                entity_type, entity_id,
-                before_snapshot, after_snapshot,
-                before_hash, after_hash,
-                causation_id, correlation_id, trace_id,
-                metadata
-            ) VALUES (
-                $1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14
-            )
-            RETURNING audit_id
-            "#,
-        )
-        .bind(request.actor_id)
-        .bind(request.actor_type)
-        .bind(request.action)
-        .bind(request.mutation_class)
-        .bind(request.entity_type)
-        .bind(request.entity_id)
-        .bind(request.before_snapshot)
-        .bind(request.after_snapshot)
-        .bind(request.before_hash)
-        .bind(request.after_hash)
-        .bind(request.causation_id)
-        .bind(request.correlation_id)
-        .bind(request.trace_id)
-        .bind(request.metadata)
-        .fetch_one(&mut **tx)
-        .await?;
-
-        Ok(audit_id)
+        let event = chain::append_in_tx(tx, request).await?;
+        Ok(event.audit_id)
     }
 
     /// Query audit events by entity
@@ -156,7 +89,8 @@ impl AuditWriter {
                 before_snapshot, after_snapshot,
                 before_hash, after_hash,
                 causation_id, correlation_id, trace_id,
-                metadata
+                metadata,
+                tenant_id, chain_seq, prev_hash, record_hash
             FROM audit_events
             WHERE entity_type = $1 AND entity_id = $2
             ORDER BY occurred_at DESC
@@ -182,7 +116,8 @@ impl AuditWriter {
                 before_snapshot, after_snapshot,
                 before_hash, after_hash,
                 causation_id, correlation_id, trace_id,
-                metadata
+                metadata,
+                tenant_id, chain_seq, prev_hash, record_hash
             FROM audit_events
             WHERE correlation_id = $1
             ORDER BY occurred_at ASC
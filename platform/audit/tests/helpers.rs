@@ -52,9 +52,16 @@ pub async fn run_audit_migrations(pool: &PgPool) {
         .await
         .expect("Failed to acquire audit migration advisory lock");
 
-    let migration_sql = include_str!("../db/migrations/20260216000001_create_audit_log.sql");
-
-    let result = sqlx::raw_sql(migration_sql).execute(pool).await;
+    let mut result = Ok(Default::default());
+    for migration_sql in [
+        include_str!("../db/migrations/20260216000001_create_audit_log.sql"),
+        include_str!("../db/migrations/20260426000001_audit_hash_chain.sql"),
+    ] {
+        result = sqlx::raw_sql(migration_sql).execute(pool).await;
+        if result.is_err() {
+            break;
+        }
+    }
 
     sqlx::query("SELECT pg_advisory_unlock($1)")
         .bind(AUDIT_MIGRATION_LOCK_KEY)
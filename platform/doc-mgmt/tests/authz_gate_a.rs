@@ -14,6 +14,9 @@ fn build_claims(perms: &[&str]) -> VerifiedClaims {
         app_id: None,
         roles: vec!["operator".to_string()],
         perms: perms.iter().map(|p| (*p).to_string()).collect(),
+        scopes: Default::default(),
+        amr: Vec::new(),
+        auth_time: None,
         actor_type: ActorType::User,
         issued_at: now,
         expires_at: now + Duration::minutes(15),
@@ -17,6 +17,8 @@
 //! 1. Constitutional envelope metadata is present and non-empty
 //! 2. Required fields are validated before persistence
 //! 3. Invalid envelopes are rejected at the boundary, not at publish time
+//! 4. The payload matches its `contracts/events` schema, when a
+//!    [`schema_registry`] is installed (see [`validate_and_serialize_envelope_with`])
 //!
 //! # Examples
 //!
@@ -40,6 +42,7 @@
 //! ```
 
 use crate::envelope::{validate_envelope_fields, EventEnvelope};
+use schema_registry::SchemaRegistry;
 use serde::Serialize;
 
 /// Validate an EventEnvelope and serialize it to JSON
@@ -61,6 +64,8 @@ use serde::Serialize;
 /// Returns an error if:
 /// - Required fields are missing or empty
 /// - Optional string fields are present but empty
+/// - The payload violates its contract schema and the installed
+///   [`schema_registry`] is in enforce mode
 /// - Serialization fails
 ///
 /// # Example
@@ -89,6 +94,15 @@ use serde::Serialize;
 /// ```
 pub fn validate_and_serialize_envelope<T: Serialize>(
     envelope: &EventEnvelope<T>,
+) -> Result<serde_json::Value, String> {
+    validate_and_serialize_envelope_with(envelope, schema_registry::global())
+}
+
+/// [`validate_and_serialize_envelope`] against an explicit schema registry
+/// instead of the process-wide one. `None` skips the schema check.
+pub fn validate_and_serialize_envelope_with<T: Serialize>(
+    envelope: &EventEnvelope<T>,
+    registry: Option<&SchemaRegistry>,
 ) -> Result<serde_json::Value, String> {
     // Serialize the envelope to JSON
     let payload = serde_json::to_value(envelope)
@@ -97,12 +111,24 @@ pub fn validate_and_serialize_envelope<T: Serialize>(
     // Validate envelope fields
     validate_envelope_fields(&payload)?;
 
+    // Validate the payload against its contract schema
+    if let Some(registry) = registry {
+        registry
+            .check(
+                &envelope.event_type,
+                &envelope.schema_version,
+                &payload["payload"],
+            )
+            .map_err(|v| v.to_string())?;
+    }
+
     Ok(payload)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use schema_registry::{test_support, ValidationMode};
 
     #[derive(Debug, serde::Serialize, serde::Deserialize)]
     struct TestPayload {
@@ -197,6 +223,35 @@ mod tests {
             .contains("mutation_class cannot be empty"));
     }
 
+    fn payment_envelope(amount_minor: serde_json::Value) -> EventEnvelope<serde_json::Value> {
+        EventEnvelope::new(
+            "tenant-123".to_string(),
+            "ap".to_string(),
+            test_support::PAYMENT_EXECUTED.to_string(),
+            test_support::payment_executed_payload(amount_minor),
+        )
+        .with_mutation_class(Some("SIDE_EFFECT".to_string()))
+    }
+
+    #[test]
+    fn test_schema_violation_rejected_in_enforce_mode() {
+        let registry = test_support::contracts_registry(ValidationMode::Enforce);
+        let envelope = payment_envelope(serde_json::json!("oops"));
+
+        let err = validate_and_serialize_envelope_with(&envelope, Some(&registry)).unwrap_err();
+        assert!(err.starts_with("schema_violation:"), "{err}");
+        assert!(err.contains("ap-payment-executed.v1.json"));
+    }
+
+    #[test]
+    fn test_schema_violation_allowed_in_warn_mode() {
+        let registry = test_support::contracts_registry(ValidationMode::Warn);
+        let envelope = payment_envelope(serde_json::json!("oops"));
+
+        assert!(validate_and_serialize_envelope_with(&envelope, Some(&registry)).is_ok());
+        assert!(validate_and_serialize_envelope_with(&envelope, None).is_ok());
+    }
+
     #[test]
     fn test_validate_and_serialize_envelope_accepts_none_optional_fields() {
         let envelope = EventEnvelope::new(
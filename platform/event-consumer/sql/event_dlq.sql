@@ -5,13 +5,20 @@
 CREATE TABLE IF NOT EXISTS event_dlq (
     event_id      UUID        PRIMARY KEY,
     subject       TEXT        NOT NULL,
-    failure_kind  TEXT        NOT NULL CHECK (failure_kind IN ('retryable', 'fatal', 'poison')),
+    failure_kind  TEXT        NOT NULL CHECK (failure_kind IN ('retryable', 'fatal', 'poison', 'schema_violation')),
     error_message TEXT        NOT NULL,
     payload       JSONB       NOT NULL,
     payload_hash  TEXT        NOT NULL DEFAULT '',
     created_at    TIMESTAMPTZ NOT NULL DEFAULT now()
 );
 
+-- Tables created before 'schema_violation' existed keep the old CHECK under
+-- CREATE TABLE IF NOT EXISTS; replace it so schema violations can be stored.
+ALTER TABLE event_dlq
+    DROP CONSTRAINT IF EXISTS event_dlq_failure_kind_check,
+    ADD CONSTRAINT event_dlq_failure_kind_check
+        CHECK (failure_kind IN ('retryable', 'fatal', 'poison', 'schema_violation'));
+
 -- Index for filtering by failure kind (e.g. list all retryable entries).
 CREATE INDEX IF NOT EXISTS idx_event_dlq_failure_kind
     ON event_dlq (failure_kind);
@@ -1,6 +1,6 @@
 //! Dead-letter queue (DLQ) persistence and failure classification.
 //!
-//! When an event cannot be processed, it is classified as one of three failure
+//! When an event cannot be processed, it is classified as one of four failure
 //! kinds and written to the `event_dlq` table for later investigation or replay.
 //!
 //! Payloads are **redacted** before persistence: sensitive keys (passwords,
@@ -52,6 +52,9 @@ pub enum FailureKind {
     /// Message is structurally unparseable or violates envelope invariants.
     /// Never retry.
     Poison,
+    /// Payload does not match its `contracts/events` schema — a producer
+    /// bug. Never retry; replay after the producer is fixed.
+    SchemaViolation,
 }
 
 impl FailureKind {
@@ -60,6 +63,7 @@ impl FailureKind {
             FailureKind::Retryable => "retryable",
             FailureKind::Fatal => "fatal",
             FailureKind::Poison => "poison",
+            FailureKind::SchemaViolation => "schema_violation",
         }
     }
 }
@@ -228,6 +232,7 @@ impl DlqRow {
         let kind = match self.failure_kind.as_str() {
             "retryable" => FailureKind::Retryable,
             "fatal" => FailureKind::Fatal,
+            "schema_violation" => FailureKind::SchemaViolation,
             _ => FailureKind::Poison,
         };
         DlqEntry {
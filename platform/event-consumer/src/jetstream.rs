@@ -16,7 +16,7 @@ use event_bus::consumer_retry::RetryConfig;
 use event_bus::EventEnvelope;
 use futures::StreamExt;
 use sqlx::PgPool;
-use tracing::{debug, info, warn};
+use tracing::{debug, error, info, warn};
 
 use crate::dlq::{classify_handler_error, write_dlq_entry, FailureKind};
 use crate::idempotency::{with_dedupe, DedupeError, DedupeOutcome};
@@ -211,19 +211,27 @@ impl JetStreamConsumer {
                 }
             };
 
-        // Validate envelope + subject BEFORE any routing or handler dispatch.
-        // Failures are non-retryable — go straight to DLQ.
+        // Validate envelope + subject + payload schema BEFORE any routing or
+        // handler dispatch. Failures are non-retryable — go straight to DLQ.
         if let Err(e) = validate_incoming(&envelope, &subject) {
             warn!(subject = %subject, error = %e, "Validation rejected incoming message");
-            let _ = write_dlq_entry(
+            if let Err(dlq_err) = write_dlq_entry(
                 &self.pool,
                 envelope.event_id,
                 &subject,
-                FailureKind::Poison,
+                e.failure_kind(),
                 &format!("validation: {e}"),
                 &serde_json::to_value(&envelope).unwrap_or_default(),
             )
-            .await;
+            .await
+            {
+                error!(
+                    event_id = %envelope.event_id,
+                    subject = %subject,
+                    error = %dlq_err,
+                    "Failed to write rejected message to DLQ"
+                );
+            }
             self.health.dlq.fetch_add(1, Ordering::Relaxed);
             let _ = message.ack().await;
             return;
@@ -9,12 +9,16 @@
 //! - [`RegistryBuilder`] — Builder for constructing a `HandlerRegistry`
 //! - [`EventRouter`] — Validates envelopes and dispatches through the registry
 //! - [`RouteOutcome`] — Result of routing (Handled, Skipped, DeadLettered, etc.)
+//! - [`Upcasters`] — Chained payload transforms from older schema versions,
+//!   applied by the router before dispatch
 //!
 //! # Persistence layer (requires sqlx + Postgres)
 //!
 //! - [`idempotency::with_dedupe`] — Execute handler exactly once per event_id
 //! - [`dlq::write_dlq_entry`] — Write failed events to the dead-letter queue
 //! - [`dlq::classify_handler_error`] — Map handler errors to DLQ failure kinds
+//! - [`replay::Replayer`] — Re-deliver filtered JetStream history to one
+//!   consumer's router, with dedupe and a dry-run mode
 //!
 //! ## Migration templates
 //!
@@ -27,7 +31,9 @@ pub mod dlq;
 pub mod idempotency;
 pub mod jetstream;
 pub mod registry;
+pub mod replay;
 pub mod router;
+pub mod upcast;
 pub mod validation;
 
 pub use context::HandlerContext;
@@ -39,5 +45,7 @@ pub use jetstream::{
     ConsumerConfig, ConsumerError, ConsumerHealth, HealthSnapshot, JetStreamConsumer,
 };
 pub use registry::{HandlerError, HandlerFn, HandlerRegistry, LookupResult, RegistryBuilder};
+pub use replay::{ReplayError, ReplayFilter, ReplayReport, Replayer};
 pub use router::{EventRouter, RouteOutcome};
-pub use validation::{validate_incoming, ValidationError};
+pub use upcast::{UpcastError, UpcastFn, Upcasters};
+pub use validation::{validate_incoming, validate_incoming_with, ValidationError};
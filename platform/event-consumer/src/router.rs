@@ -3,7 +3,8 @@ use event_bus::EventEnvelope;
 use tracing::{debug, warn};
 
 use crate::context::HandlerContext;
-use crate::registry::{HandlerError, HandlerRegistry, LookupResult};
+use crate::registry::{HandlerError, HandlerFn, HandlerRegistry, LookupResult};
+use crate::upcast::{UpcastError, Upcasters};
 
 /// Outcome of routing an event through the dispatcher.
 #[derive(Debug)]
@@ -12,7 +13,9 @@ pub enum RouteOutcome {
     Handled,
     /// Event type not registered — silently skipped (not for this consumer).
     Skipped,
-    /// Known event type but unknown schema version — should be dead-lettered.
+    /// Known event type but unknown schema version (and no upcaster path to
+    /// a known one), or an upcaster rejected the payload — should be
+    /// dead-lettered.
     DeadLettered(String),
     /// Envelope validation failed (e.g. empty tenant_id).
     Invalid(String),
@@ -34,24 +37,38 @@ impl RouteOutcome {
 
 /// Routes incoming events to registered handlers via the registry.
 ///
-/// Validates envelope fields, looks up the handler, builds
-/// [`HandlerContext`], and dispatches.
+/// Validates envelope fields, looks up the handler, upcasts older payloads
+/// when needed, builds [`HandlerContext`], and dispatches.
 pub struct EventRouter {
     registry: HandlerRegistry,
+    upcasters: Upcasters,
 }
 
 impl EventRouter {
     /// Create a new router backed by the given handler registry.
     pub fn new(registry: HandlerRegistry) -> Self {
-        Self { registry }
+        Self {
+            registry,
+            upcasters: Upcasters::new(),
+        }
+    }
+
+    /// Upcast payloads whose `schema_version` has no handler through
+    /// `upcasters` before dispatch.
+    pub fn with_upcasters(mut self, upcasters: Upcasters) -> Self {
+        self.upcasters = upcasters;
+        self
     }
 
     /// Route an event envelope to the appropriate handler.
     ///
     /// 1. Validates required envelope fields (tenant_id, event_type).
     /// 2. Looks up the handler by (event_type, schema_version).
-    /// 3. Builds a [`HandlerContext`] from envelope metadata.
-    /// 4. Dispatches to the handler and returns the outcome.
+    /// 3. If only other versions are registered, upcasts the payload along
+    ///    the registered chain to the first version with a handler.
+    /// 4. Builds a [`HandlerContext`] from envelope metadata; its
+    ///    `schema_version` is the version the payload was upcast to.
+    /// 5. Dispatches to the handler and returns the outcome.
     pub async fn route(
         &self,
         subject: &str,
@@ -80,52 +97,107 @@ impl EventRouter {
                 RouteOutcome::Skipped
             }
             LookupResult::UnknownVersion { known_versions } => {
-                warn!(
-                    subject = %subject,
-                    event_type = %envelope.event_type,
-                    schema_version = %envelope.schema_version,
-                    ?known_versions,
-                    "Unknown schema version, dead-lettering"
+                let upcast = self.upcasters.upcast_until(
+                    &envelope.event_type,
+                    &envelope.schema_version,
+                    envelope.payload.clone(),
+                    |v| known_versions.iter().any(|k| k == v),
                 );
-                RouteOutcome::DeadLettered(format!(
-                    "event_type={} schema_version={} not registered (known: {:?})",
-                    envelope.event_type, envelope.schema_version, known_versions
-                ))
-            }
-            LookupResult::Found(handler) => {
-                let ctx = HandlerContext {
-                    event_id: envelope.event_id,
-                    tenant_id: envelope.tenant_id.clone(),
-                    source_module: envelope.source_module.clone(),
-                    correlation_id: envelope.correlation_id.clone(),
-                    causation_id: envelope.causation_id.clone(),
-                    actor_id: envelope.actor_id,
-                    schema_version: envelope.schema_version.clone(),
-                    received_at: Utc::now(),
-                };
-
-                match handler(ctx, envelope.payload.clone()).await {
-                    Ok(()) => {
+                match upcast {
+                    Ok((version, payload)) => {
                         debug!(
                             subject = %subject,
                             event_type = %envelope.event_type,
-                            event_id = %envelope.event_id,
-                            "Handler completed successfully"
+                            from = %envelope.schema_version,
+                            to = %version,
+                            "Upcast payload"
                         );
-                        RouteOutcome::Handled
+                        match self.registry.lookup(&envelope.event_type, &version) {
+                            LookupResult::Found(handler) => {
+                                Self::dispatch(subject, envelope, handler, version, payload).await
+                            }
+                            _ => RouteOutcome::DeadLettered(format!(
+                                "event_type={} upcast to schema_version={} has no handler",
+                                envelope.event_type, version
+                            )),
+                        }
                     }
-                    Err(e) => {
+                    Err(UpcastError::NoPath { .. }) => {
                         warn!(
                             subject = %subject,
                             event_type = %envelope.event_type,
+                            schema_version = %envelope.schema_version,
+                            ?known_versions,
+                            "Unknown schema version, dead-lettering"
+                        );
+                        RouteOutcome::DeadLettered(format!(
+                            "event_type={} schema_version={} not registered (known: {:?})",
+                            envelope.event_type, envelope.schema_version, known_versions
+                        ))
+                    }
+                    Err(e) => {
+                        warn!(
+                            subject = %subject,
                             event_id = %envelope.event_id,
                             error = %e,
-                            "Handler returned error"
+                            "Upcast failed, dead-lettering"
                         );
-                        RouteOutcome::HandlerError(e)
+                        RouteOutcome::DeadLettered(e.to_string())
                     }
                 }
             }
+            LookupResult::Found(handler) => {
+                Self::dispatch(
+                    subject,
+                    envelope,
+                    handler,
+                    envelope.schema_version.clone(),
+                    envelope.payload.clone(),
+                )
+                .await
+            }
+        }
+    }
+
+    /// Build the [`HandlerContext`] and run `handler` on `payload`.
+    async fn dispatch(
+        subject: &str,
+        envelope: &EventEnvelope<serde_json::Value>,
+        handler: HandlerFn,
+        schema_version: String,
+        payload: serde_json::Value,
+    ) -> RouteOutcome {
+        let ctx = HandlerContext {
+            event_id: envelope.event_id,
+            tenant_id: envelope.tenant_id.clone(),
+            source_module: envelope.source_module.clone(),
+            correlation_id: envelope.correlation_id.clone(),
+            causation_id: envelope.causation_id.clone(),
+            actor_id: envelope.actor_id,
+            schema_version,
+            received_at: Utc::now(),
+        };
+
+        match handler(ctx, payload).await {
+            Ok(()) => {
+                debug!(
+                    subject = %subject,
+                    event_type = %envelope.event_type,
+                    event_id = %envelope.event_id,
+                    "Handler completed successfully"
+                );
+                RouteOutcome::Handled
+            }
+            Err(e) => {
+                warn!(
+                    subject = %subject,
+                    event_type = %envelope.event_type,
+                    event_id = %envelope.event_id,
+                    error = %e,
+                    "Handler returned error"
+                );
+                RouteOutcome::HandlerError(e)
+            }
         }
     }
 
@@ -139,6 +211,7 @@ impl EventRouter {
 mod tests {
     use super::*;
     use crate::registry::{HandlerError, RegistryBuilder};
+    use crate::upcast::Upcasters;
     use event_bus::EventEnvelope;
     use std::sync::{Arc, Mutex};
 
@@ -219,6 +292,114 @@ mod tests {
         assert!(matches!(outcome, RouteOutcome::DeadLettered(_)));
     }
 
+    fn amount_upcasters() -> Upcasters {
+        Upcasters::new()
+            .register("test.event", "1.0.0", "2.0.0", |mut p| {
+                let v = p["key"].take();
+                Ok(serde_json::json!({"renamed": v}))
+            })
+            .register("test.event", "2.0.0", "3.0.0", |mut p| {
+                p["added"] = serde_json::json!(true);
+                Ok(p)
+            })
+    }
+
+    #[tokio::test]
+    async fn route_upcasts_old_version_to_registered_handler() {
+        let captured = Arc::new(Mutex::new(None));
+        let captured_clone = captured.clone();
+
+        let registry = RegistryBuilder::new()
+            .register("test.event", "3.0.0", move |ctx, payload| {
+                let captured = captured_clone.clone();
+                async move {
+                    *captured.lock().expect("mutex poisoned") = Some((ctx.schema_version, payload));
+                    Ok(())
+                }
+            })
+            .build();
+
+        let router = EventRouter::new(registry).with_upcasters(amount_upcasters());
+        let envelope = make_envelope("test.event", "1.0.0");
+        let outcome = router.route("test.subject", &envelope).await;
+
+        assert!(outcome.is_handled());
+        let (version, payload) = captured
+            .lock()
+            .expect("mutex poisoned")
+            .take()
+            .expect("handler was not called");
+        assert_eq!(version, "3.0.0");
+        assert_eq!(
+            payload,
+            serde_json::json!({"renamed": "value", "added": true})
+        );
+    }
+
+    #[tokio::test]
+    async fn route_upcast_prefers_exact_handler() {
+        let calls = Arc::new(Mutex::new(Vec::new()));
+        let (c2, c3) = (calls.clone(), calls.clone());
+
+        let registry = RegistryBuilder::new()
+            .register("test.event", "2.0.0", move |ctx, _payload| {
+                let calls = c2.clone();
+                async move {
+                    calls
+                        .lock()
+                        .expect("mutex poisoned")
+                        .push(ctx.schema_version);
+                    Ok(())
+                }
+            })
+            .register("test.event", "3.0.0", move |ctx, _payload| {
+                let calls = c3.clone();
+                async move {
+                    calls
+                        .lock()
+                        .expect("mutex poisoned")
+                        .push(ctx.schema_version);
+                    Ok(())
+                }
+            })
+            .build();
+
+        let router = EventRouter::new(registry).with_upcasters(amount_upcasters());
+        router
+            .route("test.subject", &make_envelope("test.event", "1.0.0"))
+            .await;
+        router
+            .route("test.subject", &make_envelope("test.event", "3.0.0"))
+            .await;
+
+        assert_eq!(
+            *calls.lock().expect("mutex poisoned"),
+            vec!["2.0.0", "3.0.0"]
+        );
+    }
+
+    #[tokio::test]
+    async fn route_failed_upcast_deadlettered() {
+        let registry = RegistryBuilder::new()
+            .register("test.event", "2.0.0", |_ctx, _payload| async { Ok(()) })
+            .build();
+        let upcasters = Upcasters::new().register("test.event", "1.0.0", "2.0.0", |_| {
+            Err("cannot derive customer_id".to_string())
+        });
+
+        let router = EventRouter::new(registry).with_upcasters(upcasters);
+        let outcome = router
+            .route("test.subject", &make_envelope("test.event", "1.0.0"))
+            .await;
+
+        match outcome {
+            RouteOutcome::DeadLettered(reason) => {
+                assert!(reason.contains("cannot derive customer_id"), "{reason}")
+            }
+            other => panic!("Expected DeadLettered, got {:?}", other),
+        }
+    }
+
     #[tokio::test]
     async fn route_empty_tenant_invalid() {
         let registry = RegistryBuilder::new()
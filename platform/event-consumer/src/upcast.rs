@@ -0,0 +1,394 @@
+//! Payload upcasters — transform older `schema_version` payloads into the
+//! shape current handlers expect.
+//!
+//! An upcaster is a pure function registered for one `(event_type, from, to)`
+//! step. Steps chain: with `1.0.0 → 2.0.0` and `2.0.0 → 3.0.0` registered, a
+//! v1 payload reaches a v3 handler. [`EventRouter`](crate::EventRouter)
+//! applies the chain when no handler is registered for the incoming version,
+//! stopping at the first version that has one — so a consumer keeps a single
+//! handler per event type across contract bumps.
+//!
+//! ```rust,no_run
+//! use event_consumer::{EventRouter, RegistryBuilder, Upcasters};
+//!
+//! let upcasters = Upcasters::new()
+//!     .register("ar.invoice_opened", "1.0.0", "2.0.0", |mut payload| {
+//!         // v2 renamed amount_cents → amount_minor
+//!         let amount = payload["amount_cents"].take();
+//!         payload["amount_minor"] = amount;
+//!         Ok(payload)
+//!     });
+//!
+//! let registry = RegistryBuilder::new()
+//!     .register("ar.invoice_opened", "2.0.0", |_ctx, _payload| async { Ok(()) })
+//!     .build();
+//! let router = EventRouter::new(registry).with_upcasters(upcasters);
+//! ```
+
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+
+/// A single upcast step: payload at `from` → payload at `to`.
+///
+/// Returning `Err` dead-letters the event (the payload cannot be migrated).
+pub type UpcastFn =
+    Arc<dyn Fn(serde_json::Value) -> Result<serde_json::Value, String> + Send + Sync>;
+
+/// Why a payload could not be upcast.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum UpcastError {
+    /// No chain from the incoming version reaches an accepted version.
+    #[error("no upcaster path for event_type={event_type} from schema_version={from}")]
+    NoPath { event_type: String, from: String },
+
+    /// An upcaster in the chain rejected the payload.
+    #[error("upcast event_type={event_type} {from} → {to} failed: {reason}")]
+    Step {
+        event_type: String,
+        from: String,
+        to: String,
+        reason: String,
+    },
+}
+
+#[derive(Clone)]
+struct UpcastStep {
+    to: String,
+    upcast: UpcastFn,
+}
+
+/// Registered upcasters, keyed by `(event_type, from_version)`.
+///
+/// Each version has at most one outgoing step, so the chain from any version
+/// is a single path.
+#[derive(Clone, Default)]
+pub struct Upcasters {
+    steps: HashMap<(String, String), UpcastStep>,
+}
+
+impl Upcasters {
+    /// Create an empty set — no payloads are transformed.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register the upcaster from `from` to `to` for `event_type`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a step from `from` is already registered for this event
+    /// type, or if the new step would close a cycle. This catches wiring
+    /// bugs at startup rather than at runtime.
+    pub fn register<F>(
+        mut self,
+        event_type: impl Into<String>,
+        from: impl Into<String>,
+        to: impl Into<String>,
+        upcast: F,
+    ) -> Self
+    where
+        F: Fn(serde_json::Value) -> Result<serde_json::Value, String> + Send + Sync + 'static,
+    {
+        let event_type = event_type.into();
+        let from = from.into();
+        let to = to.into();
+
+        let key = (event_type.clone(), from.clone());
+        if self.steps.contains_key(&key) {
+            panic!(
+                "Duplicate upcaster registration: event_type={}, from={}",
+                event_type, from
+            );
+        }
+        if self.path(&event_type, &to).iter().any(|v| *v == from) || from == to {
+            panic!(
+                "Upcaster cycle: event_type={}, {} → {} leads back to {}",
+                event_type, from, to, from
+            );
+        }
+
+        self.steps.insert(
+            key,
+            UpcastStep {
+                to,
+                upcast: Arc::new(upcast),
+            },
+        );
+        self
+    }
+
+    /// Number of registered steps.
+    pub fn len(&self) -> usize {
+        self.steps.len()
+    }
+
+    /// Returns true if no upcasters are registered.
+    pub fn is_empty(&self) -> bool {
+        self.steps.is_empty()
+    }
+
+    /// Versions reachable from `version`, in chain order (excluding `version`).
+    pub fn path(&self, event_type: &str, version: &str) -> Vec<&str> {
+        let mut out = Vec::new();
+        let mut seen = HashSet::new();
+        let mut current = version.to_string();
+        while let Some(step) = self.steps.get(&(event_type.to_string(), current.clone())) {
+            if !seen.insert(step.to.as_str()) {
+                break;
+            }
+            out.push(step.to.as_str());
+            current = step.to.clone();
+        }
+        out
+    }
+
+    /// Apply steps from `version` until `accept` returns true for the
+    /// current version.
+    ///
+    /// Returns the version the payload now conforms to and the transformed
+    /// payload. If `accept(version)` already holds, the payload is returned
+    /// unchanged.
+    pub fn upcast_until(
+        &self,
+        event_type: &str,
+        version: &str,
+        payload: serde_json::Value,
+        accept: impl Fn(&str) -> bool,
+    ) -> Result<(String, serde_json::Value), UpcastError> {
+        let mut current = version.to_string();
+        let mut payload = payload;
+        let mut seen = HashSet::new();
+
+        while !accept(&current) {
+            let Some(step) = self.steps.get(&(event_type.to_string(), current.clone())) else {
+                return Err(UpcastError::NoPath {
+                    event_type: event_type.to_string(),
+                    from: version.to_string(),
+                });
+            };
+            if !seen.insert(current.clone()) {
+                return Err(UpcastError::NoPath {
+                    event_type: event_type.to_string(),
+                    from: version.to_string(),
+                });
+            }
+            payload = (step.upcast)(payload).map_err(|reason| UpcastError::Step {
+                event_type: event_type.to_string(),
+                from: current.clone(),
+                to: step.to.clone(),
+                reason,
+            })?;
+            current = step.to.clone();
+        }
+
+        Ok((current, payload))
+    }
+}
+
+/// Test helpers for consumers that register upcasters.
+pub mod testing {
+    use std::path::Path;
+
+    use schema_registry::SchemaRegistry;
+
+    use super::Upcasters;
+
+    /// Assert that every historical fixture in `contracts_dir/examples`
+    /// upcasts to the current contract version.
+    ///
+    /// For each `<name>.v<N>.example.json` whose event type has a newer
+    /// `<name>.v<M>.json` schema, the fixture's payload is run through
+    /// `upcasters` until it reaches major version `M` and must then validate
+    /// against that schema. Returns the number of fixtures checked; panics
+    /// listing every fixture that failed.
+    ///
+    /// ```rust,ignore
+    /// #[test]
+    /// fn historical_fixtures_upcast() {
+    ///     let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("../../contracts/events");
+    ///     event_consumer::upcast::testing::assert_fixtures_upcast(&my_upcasters(), &dir);
+    /// }
+    /// ```
+    pub fn assert_fixtures_upcast(upcasters: &Upcasters, contracts_dir: &Path) -> usize {
+        let registry = SchemaRegistry::load_dir(contracts_dir)
+            .unwrap_or_else(|e| panic!("contracts at {}: {e}", contracts_dir.display()));
+
+        let examples_dir = contracts_dir.join("examples");
+        let mut fixtures: Vec<_> = std::fs::read_dir(&examples_dir)
+            .unwrap_or_else(|e| panic!("{}: {e}", examples_dir.display()))
+            .filter_map(|e| e.ok())
+            .filter_map(|e| e.file_name().into_string().ok())
+            .collect();
+        fixtures.sort();
+
+        let mut checked = 0;
+        let mut failures = Vec::new();
+
+        for fixture in fixtures {
+            let Some(schema_file) = fixture.strip_suffix(".example.json") else {
+                continue;
+            };
+            let schema_file = format!("{schema_file}.json");
+            let Some(fixture_major) = schema_file
+                .strip_suffix(".json")
+                .and_then(|s| s.rsplit_once(".v"))
+                .and_then(|(_, v)| v.parse::<u64>().ok())
+            else {
+                continue;
+            };
+
+            let envelope: serde_json::Value =
+                match std::fs::read_to_string(examples_dir.join(&fixture))
+                    .map_err(|e| e.to_string())
+                    .and_then(|s| serde_json::from_str(&s).map_err(|e| e.to_string()))
+                {
+                    Ok(v) => v,
+                    Err(e) => {
+                        failures.push(format!("{fixture}: {e}"));
+                        continue;
+                    }
+                };
+            let version = envelope
+                .get("schema_version")
+                .and_then(|v| v.as_str())
+                .filter(|v| v.split('.').next() == Some(&fixture_major.to_string()))
+                .map(str::to_string)
+                .unwrap_or_else(|| format!("{fixture_major}.0.0"));
+            let payload = envelope.get("payload").cloned().unwrap_or_default();
+
+            for event_type in registry.event_types_in(&schema_file) {
+                let Some(current) = registry.latest_major(event_type) else {
+                    continue;
+                };
+                if current <= fixture_major {
+                    continue;
+                }
+                checked += 1;
+
+                let upcast = upcasters.upcast_until(event_type, &version, payload.clone(), |v| {
+                    v.split('.').next().and_then(|m| m.parse::<u64>().ok()) == Some(current)
+                });
+                match upcast {
+                    Ok((to, upcast_payload)) => {
+                        if let Err(v) = registry.validate_payload(event_type, &to, &upcast_payload)
+                        {
+                            failures
+                                .push(format!("{fixture} ({event_type} {version} → {to}): {v}"));
+                        }
+                    }
+                    Err(e) => failures.push(format!("{fixture}: {e}")),
+                }
+            }
+        }
+
+        assert!(
+            failures.is_empty(),
+            "{} fixture(s) do not upcast to the current contract:\n  {}",
+            failures.len(),
+            failures.join("\n  ")
+        );
+        checked
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn chain() -> Upcasters {
+        Upcasters::new()
+            .register("test.event", "1.0.0", "2.0.0", |mut p| {
+                let v = p["amount_cents"].take();
+                p["amount_minor"] = v;
+                p.as_object_mut().unwrap().remove("amount_cents");
+                Ok(p)
+            })
+            .register("test.event", "2.0.0", "3.0.0", |mut p| {
+                p["currency"] = json!("USD");
+                Ok(p)
+            })
+    }
+
+    #[test]
+    fn chains_to_accepted_version() {
+        let (version, payload) = chain()
+            .upcast_until("test.event", "1.0.0", json!({"amount_cents": 5}), |v| {
+                v == "3.0.0"
+            })
+            .unwrap();
+        assert_eq!(version, "3.0.0");
+        assert_eq!(payload, json!({"amount_minor": 5, "currency": "USD"}));
+    }
+
+    #[test]
+    fn stops_at_first_accepted_version() {
+        let (version, payload) = chain()
+            .upcast_until("test.event", "1.0.0", json!({"amount_cents": 5}), |v| {
+                v == "2.0.0" || v == "3.0.0"
+            })
+            .unwrap();
+        assert_eq!(version, "2.0.0");
+        assert_eq!(payload, json!({"amount_minor": 5}));
+    }
+
+    #[test]
+    fn accepted_version_is_unchanged() {
+        let input = json!({"amount_minor": 1});
+        let (version, payload) = chain()
+            .upcast_until("test.event", "3.0.0", input.clone(), |v| v == "3.0.0")
+            .unwrap();
+        assert_eq!(version, "3.0.0");
+        assert_eq!(payload, input);
+    }
+
+    #[test]
+    fn no_path_is_an_error() {
+        let err = chain()
+            .upcast_until("test.event", "1.0.0", json!({}), |v| v == "9.0.0")
+            .unwrap_err();
+        assert!(matches!(err, UpcastError::NoPath { .. }));
+
+        let err = chain()
+            .upcast_until("other.event", "1.0.0", json!({}), |v| v == "2.0.0")
+            .unwrap_err();
+        assert!(matches!(err, UpcastError::NoPath { .. }));
+    }
+
+    #[test]
+    fn failing_step_reports_versions() {
+        let upcasters = Upcasters::new().register("test.event", "1.0.0", "2.0.0", |_| {
+            Err("missing customer_id".to_string())
+        });
+        let err = upcasters
+            .upcast_until("test.event", "1.0.0", json!({}), |v| v == "2.0.0")
+            .unwrap_err();
+        assert_eq!(
+            err,
+            UpcastError::Step {
+                event_type: "test.event".into(),
+                from: "1.0.0".into(),
+                to: "2.0.0".into(),
+                reason: "missing customer_id".into(),
+            }
+        );
+    }
+
+    #[test]
+    fn path_lists_chain() {
+        assert_eq!(chain().path("test.event", "1.0.0"), vec!["2.0.0", "3.0.0"]);
+        assert!(chain().path("test.event", "3.0.0").is_empty());
+    }
+
+    #[test]
+    #[should_panic(expected = "Duplicate upcaster")]
+    fn duplicate_step_panics() {
+        let _ = chain().register("test.event", "1.0.0", "4.0.0", Ok);
+    }
+
+    #[test]
+    #[should_panic(expected = "Upcaster cycle")]
+    fn cycle_panics() {
+        let _ = chain().register("test.event", "3.0.0", "1.0.0", Ok);
+    }
+}
@@ -1,11 +1,15 @@
-//! Consumer-side envelope and subject validation.
+//! Consumer-side envelope, subject and payload-schema validation.
 //!
 //! [`validate_incoming`] runs before routing and handler dispatch,
-//! rejecting malformed envelopes and unsafe NATS subjects.
+//! rejecting malformed envelopes, unsafe NATS subjects, and payloads that
+//! violate their `contracts/events` schema.
 
 use event_bus::EventEnvelope;
+use schema_registry::{SchemaRegistry, SchemaViolation};
 
-/// Validation failure — always non-retryable (→ DLQ as Poison).
+use crate::dlq::FailureKind;
+
+/// Validation failure — always non-retryable (→ DLQ).
 #[derive(Debug, thiserror::Error)]
 pub enum ValidationError {
     #[error("invalid envelope: {0}")]
@@ -13,18 +17,52 @@ pub enum ValidationError {
 
     #[error("invalid subject: {0}")]
     Subject(String),
+
+    #[error("{0}")]
+    Schema(SchemaViolation),
+}
+
+impl ValidationError {
+    /// DLQ classification: schema violations are kept apart from poison
+    /// messages so producer contract bugs are easy to find.
+    pub fn failure_kind(&self) -> FailureKind {
+        match self {
+            ValidationError::Schema(_) => FailureKind::SchemaViolation,
+            _ => FailureKind::Poison,
+        }
+    }
 }
 
 /// Validate an incoming envelope and its delivery subject.
 ///
 /// Must be called BEFORE routing/handler dispatch. Failures are
-/// non-retryable and should be sent to the DLQ.
+/// non-retryable and should be sent to the DLQ. The payload is checked
+/// against the process-wide [`schema_registry`], if one is installed.
 pub fn validate_incoming(
     envelope: &EventEnvelope<serde_json::Value>,
     subject: &str,
+) -> Result<(), ValidationError> {
+    validate_incoming_with(envelope, subject, schema_registry::global())
+}
+
+/// [`validate_incoming`] against an explicit schema registry. `None` skips
+/// the schema check.
+pub fn validate_incoming_with(
+    envelope: &EventEnvelope<serde_json::Value>,
+    subject: &str,
+    registry: Option<&SchemaRegistry>,
 ) -> Result<(), ValidationError> {
     validate_envelope(envelope)?;
     validate_subject(subject)?;
+    if let Some(registry) = registry {
+        registry
+            .check(
+                &envelope.event_type,
+                &envelope.schema_version,
+                &envelope.payload,
+            )
+            .map_err(ValidationError::Schema)?;
+    }
     Ok(())
 }
 
@@ -93,6 +131,7 @@ fn is_semver(s: &str) -> bool {
 mod tests {
     use super::*;
     use event_bus::EventEnvelope;
+    use schema_registry::{test_support, ValidationMode};
 
     fn good_envelope() -> EventEnvelope<serde_json::Value> {
         EventEnvelope::new(
@@ -214,4 +253,41 @@ mod tests {
         let env = good_envelope();
         assert!(validate_incoming(&env, "inventory>item").is_err());
     }
+
+    // -- payload schema validation --
+
+    fn payment_envelope(amount_minor: serde_json::Value) -> EventEnvelope<serde_json::Value> {
+        EventEnvelope::new(
+            "tenant-1".to_string(),
+            "ap".to_string(),
+            test_support::PAYMENT_EXECUTED.to_string(),
+            test_support::payment_executed_payload(amount_minor),
+        )
+        .with_schema_version("1.0.0".to_string())
+    }
+
+    #[test]
+    fn schema_violation_rejected_when_enforced() {
+        let registry = test_support::contracts_registry(ValidationMode::Enforce);
+        let env = payment_envelope(serde_json::json!("oops"));
+        let err = validate_incoming_with(&env, "ap.payment_executed", Some(&registry)).unwrap_err();
+        assert!(matches!(err, ValidationError::Schema(_)));
+        assert_eq!(err.failure_kind(), FailureKind::SchemaViolation);
+        assert!(err.to_string().starts_with("schema_violation:"));
+    }
+
+    #[test]
+    fn schema_violation_passes_in_warn_mode() {
+        let registry = test_support::contracts_registry(ValidationMode::Warn);
+        let env = payment_envelope(serde_json::json!("oops"));
+        assert!(validate_incoming_with(&env, "ap.payment_executed", Some(&registry)).is_ok());
+    }
+
+    #[test]
+    fn envelope_errors_classified_as_poison() {
+        let mut env = good_envelope();
+        env.tenant_id = String::new();
+        let err = validate_incoming(&env, "inventory.item_issued").unwrap_err();
+        assert_eq!(err.failure_kind(), FailureKind::Poison);
+    }
 }
@@ -0,0 +1,97 @@
+//! Historical contract fixtures must upcast to the current contract version.
+
+use std::path::{Path, PathBuf};
+
+use event_consumer::upcast::testing::assert_fixtures_upcast;
+use event_consumer::Upcasters;
+use serde_json::json;
+
+fn write(path: &Path, value: serde_json::Value) {
+    std::fs::write(path, serde_json::to_vec_pretty(&value).unwrap()).unwrap();
+}
+
+fn envelope_schema(payload: serde_json::Value) -> serde_json::Value {
+    json!({
+        "$schema": "https://json-schema.org/draft/2020-12/schema",
+        "type": "object",
+        "properties": {
+            "event_type": { "const": "test.order_placed" },
+            "payload": payload
+        }
+    })
+}
+
+/// Contracts dir with a v1 and v2 schema for `test.order_placed` and a v1
+/// fixture. v2 renamed `total_cents` to `total_minor`.
+fn contracts_with_v2() -> tempfile::TempDir {
+    let dir = tempfile::tempdir().unwrap();
+    std::fs::create_dir(dir.path().join("examples")).unwrap();
+    write(&dir.path().join("catalog.json"), json!({ "subjects": [] }));
+    write(
+        &dir.path().join("test-order-placed.v1.json"),
+        envelope_schema(json!({
+            "type": "object",
+            "required": ["order_id", "total_cents"],
+            "properties": { "total_cents": { "type": "integer" } }
+        })),
+    );
+    write(
+        &dir.path().join("test-order-placed.v2.json"),
+        envelope_schema(json!({
+            "type": "object",
+            "required": ["order_id", "total_minor"],
+            "properties": {
+                "order_id": { "type": "string" },
+                "total_minor": { "type": "integer" }
+            },
+            "additionalProperties": false
+        })),
+    );
+    write(
+        &dir.path().join("examples/test-order-placed.v1.example.json"),
+        json!({
+            "event_type": "test.order_placed",
+            "schema_version": "1.0.0",
+            "payload": { "order_id": "o-1", "total_cents": 1250 }
+        }),
+    );
+    dir
+}
+
+fn rename_total() -> Upcasters {
+    Upcasters::new().register("test.order_placed", "1.0.0", "2.0.0", |p| {
+        Ok(json!({
+            "order_id": p["order_id"],
+            "total_minor": p["total_cents"]
+        }))
+    })
+}
+
+#[test]
+fn v1_fixture_upcasts_to_v2_schema() {
+    let dir = contracts_with_v2();
+    assert_eq!(assert_fixtures_upcast(&rename_total(), dir.path()), 1);
+}
+
+#[test]
+#[should_panic(expected = "no upcaster path")]
+fn missing_upcaster_fails() {
+    let dir = contracts_with_v2();
+    assert_fixtures_upcast(&Upcasters::new(), dir.path());
+}
+
+#[test]
+#[should_panic(expected = "do not upcast to the current contract")]
+fn upcaster_output_must_match_current_schema() {
+    let dir = contracts_with_v2();
+    let wrong = Upcasters::new().register("test.order_placed", "1.0.0", "2.0.0", Ok);
+    assert_fixtures_upcast(&wrong, dir.path());
+}
+
+#[test]
+fn repository_contracts_need_no_upcasters_yet() {
+    let dir = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("../../contracts/events");
+    // Every contract is still at v1 — nothing to upcast. This fails as soon
+    // as a v2 contract lands with v1 fixtures and no upcaster.
+    assert_fixtures_upcast(&Upcasters::new(), &dir);
+}
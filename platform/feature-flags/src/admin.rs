@@ -12,24 +12,51 @@
 //! | GET    | `/feature-flags/{flag_name}`                | Get a single flag                     |
 //! | PUT    | `/feature-flags/{flag_name}`                | Set enabled/disabled for a flag       |
 //! | DELETE | `/feature-flags/{flag_name}`                | Remove a flag row                     |
+//! | GET    | `/feature-flags/{flag_name}/definition`     | Full rows (variants, rules, rollout)  |
+//! | PUT    | `/feature-flags/{flag_name}/definition`     | Replace a row's full definition       |
+//! | POST   | `/feature-flags/{flag_name}/evaluate`       | Explain the result for a context      |
+//! | GET    | `/feature-flags/{flag_name}/evaluations`    | Recent evaluation audit rows          |
+//!
+//! With [`admin_router_with_bus`], every write publishes a
+//! [`FlagChangedEvent`](crate::FlagChangedEvent) so [`FlagEvaluator`](crate::FlagEvaluator)
+//! caches reload the flag immediately.
 
 use axum::{
     extract::{Path, State},
     http::StatusCode,
     response::IntoResponse,
-    routing::{delete, get, put},
+    routing::{delete, get, post, put},
     Json, Router,
 };
+use chrono::{DateTime, Utc};
+use event_bus::EventBus;
 use serde::{Deserialize, Serialize};
 use sqlx::PgPool;
+use std::sync::Arc;
 use uuid::Uuid;
 
-use crate::flags::{delete_flag, is_enabled, set_flag, FlagError};
+use crate::audit::recent_evaluations;
+use crate::evaluator::publish_flag_changed;
+use crate::flags::{
+    delete_flag, evaluate_flag, is_enabled, load_definition, set_flag, set_flag_definition,
+    FlagError,
+};
+use crate::rules::{BucketBy, EvalContext, FlagDefinition, TargetingRule, Variant};
 
 /// State threaded through admin handler functions.
 #[derive(Clone)]
 pub struct AdminState {
     pub pool: PgPool,
+    /// When set, writes publish a flag-changed event.
+    pub bus: Option<Arc<dyn EventBus>>,
+}
+
+impl AdminState {
+    async fn notify(&self, flag_name: &str, tenant_id: Option<Uuid>) {
+        if let Some(bus) = &self.bus {
+            publish_flag_changed(bus.as_ref(), flag_name, tenant_id).await;
+        }
+    }
 }
 
 /// Request body for `PUT /feature-flags/{flag_name}`.
@@ -56,12 +83,35 @@ pub struct FlagRow {
     pub enabled: bool,
 }
 
+/// Request body for `PUT /feature-flags/{flag_name}/definition`.
+#[derive(Debug, Deserialize)]
+pub struct SetDefinitionRequest {
+    /// `null` sets the global row; a UUID sets a per-tenant override.
+    pub tenant_id: Option<Uuid>,
+    pub enabled: bool,
+    #[serde(default)]
+    pub variants: Vec<Variant>,
+    #[serde(default)]
+    pub rules: Vec<TargetingRule>,
+    #[serde(default)]
+    pub rollout_percent: Option<u8>,
+    #[serde(default)]
+    pub bucket_by: BucketBy,
+    #[serde(default)]
+    pub active_from: Option<DateTime<Utc>>,
+    #[serde(default)]
+    pub active_until: Option<DateTime<Utc>>,
+}
+
 fn flag_error_response(e: FlagError) -> impl IntoResponse {
-    tracing::warn!(error = %e, "feature flag admin error");
-    (
-        StatusCode::INTERNAL_SERVER_ERROR,
-        Json(serde_json::json!({ "error": e.to_string() })),
-    )
+    let status = match e {
+        FlagError::Invalid(_) => StatusCode::BAD_REQUEST,
+        _ => {
+            tracing::warn!(error = %e, "feature flag admin error");
+            StatusCode::INTERNAL_SERVER_ERROR
+        }
+    };
+    (status, Json(serde_json::json!({ "error": e.to_string() })))
 }
 
 /// GET `/feature-flags` — list all flag rows.
@@ -109,15 +159,18 @@ async fn put_flag(
     Json(body): Json<SetFlagRequest>,
 ) -> impl IntoResponse {
     match set_flag(&state.pool, &flag_name, body.tenant_id, body.enabled).await {
-        Ok(()) => (
-            StatusCode::OK,
-            Json(FlagResponse {
-                flag_name,
-                tenant_id: body.tenant_id,
-                enabled: body.enabled,
-            }),
-        )
-            .into_response(),
+        Ok(()) => {
+            state.notify(&flag_name, body.tenant_id).await;
+            (
+                StatusCode::OK,
+                Json(FlagResponse {
+                    flag_name,
+                    tenant_id: body.tenant_id,
+                    enabled: body.enabled,
+                }),
+            )
+                .into_response()
+        }
         Err(e) => flag_error_response(e).into_response(),
     }
 }
@@ -133,7 +186,97 @@ async fn remove_flag(
         .and_then(|v| Uuid::parse_str(v).ok());
 
     match delete_flag(&state.pool, &flag_name, tenant_id).await {
-        Ok(()) => StatusCode::NO_CONTENT.into_response(),
+        Ok(()) => {
+            state.notify(&flag_name, tenant_id).await;
+            StatusCode::NO_CONTENT.into_response()
+        }
+        Err(e) => flag_error_response(e).into_response(),
+    }
+}
+
+/// GET `/feature-flags/{flag_name}/definition?tenant_id=<uuid>` — full rows.
+///
+/// Without `tenant_id` returns every row for the flag.
+async fn get_definition(
+    Path(flag_name): Path<String>,
+    axum::extract::Query(params): axum::extract::Query<std::collections::HashMap<String, String>>,
+    State(state): State<AdminState>,
+) -> impl IntoResponse {
+    let tenant_id = params
+        .get("tenant_id")
+        .and_then(|v| Uuid::parse_str(v).ok());
+
+    match load_definition(&state.pool, &flag_name).await {
+        Ok(rows) => {
+            let rows: Vec<FlagDefinition> = rows
+                .into_iter()
+                .filter(|d| tenant_id.is_none() || d.tenant_id == tenant_id)
+                .collect();
+            (StatusCode::OK, Json(rows)).into_response()
+        }
+        Err(e) => flag_error_response(e).into_response(),
+    }
+}
+
+/// PUT `/feature-flags/{flag_name}/definition` — replace a row's variants,
+/// rules, rollout and schedule.
+async fn put_definition(
+    Path(flag_name): Path<String>,
+    State(state): State<AdminState>,
+    Json(body): Json<SetDefinitionRequest>,
+) -> impl IntoResponse {
+    let def = FlagDefinition {
+        flag_name,
+        tenant_id: body.tenant_id,
+        enabled: body.enabled,
+        variants: body.variants,
+        rules: body.rules,
+        rollout_percent: body.rollout_percent,
+        bucket_by: body.bucket_by,
+        active_from: body.active_from,
+        active_until: body.active_until,
+        updated_at: None,
+    };
+    match set_flag_definition(&state.pool, &def).await {
+        Ok(()) => {
+            state.notify(&def.flag_name, def.tenant_id).await;
+            (StatusCode::OK, Json(def)).into_response()
+        }
+        Err(e) => flag_error_response(e).into_response(),
+    }
+}
+
+/// POST `/feature-flags/{flag_name}/evaluate` — evaluate for the posted
+/// context and return the variant and reason. Not recorded in the audit.
+async fn explain_flag(
+    Path(flag_name): Path<String>,
+    State(state): State<AdminState>,
+    Json(ctx): Json<EvalContext>,
+) -> impl IntoResponse {
+    match evaluate_flag(&state.pool, &flag_name, &ctx).await {
+        Ok(eval) => (StatusCode::OK, Json(eval)).into_response(),
+        Err(e) => flag_error_response(e).into_response(),
+    }
+}
+
+/// GET `/feature-flags/{flag_name}/evaluations?tenant_id=<uuid>&limit=<n>` —
+/// recent evaluation audit rows, newest first (default 50, max 500).
+async fn list_evaluations(
+    Path(flag_name): Path<String>,
+    axum::extract::Query(params): axum::extract::Query<std::collections::HashMap<String, String>>,
+    State(state): State<AdminState>,
+) -> impl IntoResponse {
+    let tenant_id = params
+        .get("tenant_id")
+        .and_then(|v| Uuid::parse_str(v).ok());
+    let limit = params
+        .get("limit")
+        .and_then(|v| v.parse::<i64>().ok())
+        .unwrap_or(50)
+        .clamp(1, 500);
+
+    match recent_evaluations(&state.pool, &flag_name, tenant_id, limit).await {
+        Ok(rows) => (StatusCode::OK, Json(rows)).into_response(),
         Err(e) => flag_error_response(e).into_response(),
     }
 }
@@ -147,10 +290,29 @@ async fn remove_flag(
 ///     .layer(require_platform_admin);
 /// ```
 pub fn admin_router(pool: PgPool) -> Router {
+    build_router(AdminState { pool, bus: None })
+}
+
+/// [`admin_router`] that publishes a flag-changed event after every write.
+pub fn admin_router_with_bus(pool: PgPool, bus: Arc<dyn EventBus>) -> Router {
+    build_router(AdminState {
+        pool,
+        bus: Some(bus),
+    })
+}
+
+fn build_router(state: AdminState) -> Router {
     Router::new()
         .route("/feature-flags", get(list_flags))
         .route("/feature-flags/{flag_name}", get(get_flag))
         .route("/feature-flags/{flag_name}", put(put_flag))
         .route("/feature-flags/{flag_name}", delete(remove_flag))
-        .with_state(AdminState { pool })
+        .route("/feature-flags/{flag_name}/definition", get(get_definition))
+        .route("/feature-flags/{flag_name}/definition", put(put_definition))
+        .route("/feature-flags/{flag_name}/evaluate", post(explain_flag))
+        .route(
+            "/feature-flags/{flag_name}/evaluations",
+            get(list_evaluations),
+        )
+        .with_state(state)
 }
@@ -0,0 +1,186 @@
+//! Evaluation audit: which variant a tenant or user got, and why.
+//!
+//! Writing every evaluation would add a row per request, so
+//! [`EvaluationAudit`] records only when the outcome for a
+//! `(flag, tenant, user)` differs from the last one this process recorded —
+//! first sight, a flag change, a schedule boundary or a rollout bump.
+//! Inserts run on a spawned task and never fail the evaluation.
+
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::flags::FlagError;
+use crate::rules::{EvalContext, EvalReason, Evaluation};
+
+/// Entries remembered before the in-process dedupe map is cleared.
+const MAX_TRACKED: usize = 50_000;
+
+type AuditKey = (String, Option<Uuid>, Option<Uuid>);
+
+#[derive(PartialEq)]
+struct Outcome {
+    enabled: bool,
+    variant: Option<String>,
+    reason: EvalReason,
+    flag_updated_at: Option<DateTime<Utc>>,
+}
+
+/// Records changed evaluation outcomes to `feature_flag_evaluations`.
+pub struct EvaluationAudit {
+    pool: PgPool,
+    last: Mutex<HashMap<AuditKey, Outcome>>,
+}
+
+impl EvaluationAudit {
+    pub fn new(pool: PgPool) -> Self {
+        Self {
+            pool,
+            last: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Whether `eval` differs from the last recorded outcome for this
+    /// subject; remembers it if so.
+    fn is_new(&self, ctx: &EvalContext, eval: &Evaluation) -> bool {
+        let key = (eval.flag_name.clone(), ctx.tenant_id, ctx.user_id);
+        let outcome = Outcome {
+            enabled: eval.enabled,
+            variant: eval.variant.clone(),
+            reason: eval.reason.clone(),
+            flag_updated_at: eval.flag_updated_at,
+        };
+        let mut last = self.last.lock().unwrap_or_else(|e| e.into_inner());
+        if last.get(&key) == Some(&outcome) {
+            return false;
+        }
+        if last.len() >= MAX_TRACKED {
+            last.clear();
+        }
+        last.insert(key, outcome);
+        true
+    }
+
+    /// Record `eval` if its outcome changed. Must be called inside a Tokio
+    /// runtime.
+    pub fn record(&self, ctx: &EvalContext, eval: &Evaluation) {
+        if !self.is_new(ctx, eval) {
+            return;
+        }
+        let pool = self.pool.clone();
+        let ctx = ctx.clone();
+        let eval = eval.clone();
+        tokio::spawn(async move {
+            if let Err(e) = insert_evaluation(&pool, &ctx, &eval).await {
+                tracing::warn!(
+                    flag = %eval.flag_name,
+                    error = %e,
+                    "feature flag evaluation audit insert failed"
+                );
+            }
+        });
+    }
+}
+
+async fn insert_evaluation(
+    pool: &PgPool,
+    ctx: &EvalContext,
+    eval: &Evaluation,
+) -> Result<(), sqlx::Error> {
+    sqlx::query(
+        r#"
+        INSERT INTO feature_flag_evaluations
+            (flag_name, tenant_id, user_id, enabled, variant, reason, source, flag_updated_at)
+        VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+        "#,
+    )
+    .bind(&eval.flag_name)
+    .bind(ctx.tenant_id)
+    .bind(ctx.user_id)
+    .bind(eval.enabled)
+    .bind(&eval.variant)
+    .bind(serde_json::to_value(&eval.reason).unwrap_or_default())
+    .bind(eval.source.as_str())
+    .bind(eval.flag_updated_at)
+    .execute(pool)
+    .await?;
+    Ok(())
+}
+
+/// One audit row.
+#[derive(Debug, Clone, Serialize, sqlx::FromRow)]
+pub struct EvaluationRecord {
+    pub flag_name: String,
+    pub tenant_id: Option<Uuid>,
+    pub user_id: Option<Uuid>,
+    pub enabled: bool,
+    pub variant: Option<String>,
+    pub reason: serde_json::Value,
+    pub source: String,
+    pub flag_updated_at: Option<DateTime<Utc>>,
+    pub evaluated_at: DateTime<Utc>,
+}
+
+/// Most recent audit rows for a flag, newest first, optionally for one
+/// tenant.
+pub async fn recent_evaluations(
+    pool: &PgPool,
+    flag: &str,
+    tenant_id: Option<Uuid>,
+    limit: i64,
+) -> Result<Vec<EvaluationRecord>, FlagError> {
+    let rows = sqlx::query_as(
+        r#"
+        SELECT flag_name, tenant_id, user_id, enabled, variant, reason, source,
+               flag_updated_at, evaluated_at
+        FROM feature_flag_evaluations
+        WHERE flag_name = $1 AND ($2::uuid IS NULL OR tenant_id = $2)
+        ORDER BY evaluated_at DESC
+        LIMIT $3
+        "#,
+    )
+    .bind(flag)
+    .bind(tenant_id)
+    .bind(limit)
+    .fetch_all(pool)
+    .await?;
+    Ok(rows)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rules::FlagSource;
+    use std::time::Duration;
+
+    fn eval(variant: &str) -> Evaluation {
+        Evaluation {
+            flag_name: "checkout".into(),
+            enabled: true,
+            variant: Some(variant.into()),
+            value: serde_json::Value::Bool(true),
+            reason: EvalReason::Rollout { bucket: Some(10) },
+            source: FlagSource::Global,
+            flag_updated_at: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn records_only_changed_outcomes() {
+        let pool = sqlx::postgres::PgPoolOptions::new()
+            .acquire_timeout(Duration::from_millis(200))
+            .connect_lazy("postgres://ff:ff@127.0.0.1:1/ff")
+            .unwrap();
+        let audit = EvaluationAudit::new(pool);
+        let ctx = EvalContext::tenant(Uuid::from_u128(1));
+
+        assert!(audit.is_new(&ctx, &eval("a")));
+        assert!(!audit.is_new(&ctx, &eval("a")));
+        assert!(audit.is_new(&ctx, &eval("b")));
+        assert!(audit.is_new(&EvalContext::tenant(Uuid::from_u128(2)), &eval("b")));
+    }
+}
@@ -0,0 +1,325 @@
+//! Cached flag evaluation.
+//!
+//! [`FlagEvaluator`] keeps every `feature_flags` row in memory and evaluates
+//! without a database round-trip. The cache is refreshed:
+//! - when a [`FlagChangedEvent`] arrives on [`FLAG_CHANGED_SUBJECT`]
+//!   (published by the admin router after each write), and
+//! - after `max_age` (default 60s) as a backstop for missed events.
+//!
+//! If a refresh fails the last good snapshot keeps serving.
+
+use std::collections::HashMap;
+use std::sync::{Arc, RwLock};
+use std::time::{Duration, Instant};
+
+use chrono::{DateTime, Utc};
+use event_bus::EventBus;
+use futures::StreamExt;
+use serde::{Deserialize, Serialize};
+use sqlx::PgPool;
+use tokio::sync::{watch, Mutex};
+use tokio::task::JoinHandle;
+use uuid::Uuid;
+
+use crate::audit::EvaluationAudit;
+use crate::flags::{load_definition, load_definitions, FlagError};
+use crate::rules::{evaluate, EvalContext, Evaluation, FlagDefinition, FlagSource};
+
+/// Subject for flag change notifications (bare JSON, not an envelope).
+pub const FLAG_CHANGED_SUBJECT: &str = "feature_flags.changed";
+
+const DEFAULT_MAX_AGE: Duration = Duration::from_secs(60);
+
+/// Published after a flag row is written or deleted.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FlagChangedEvent {
+    pub flag_name: String,
+    pub tenant_id: Option<Uuid>,
+    pub changed_at: DateTime<Utc>,
+}
+
+/// Publish a [`FlagChangedEvent`]. Failures are logged — caches still
+/// converge after `max_age`.
+pub async fn publish_flag_changed(bus: &dyn EventBus, flag_name: &str, tenant_id: Option<Uuid>) {
+    let event = FlagChangedEvent {
+        flag_name: flag_name.to_string(),
+        tenant_id,
+        changed_at: Utc::now(),
+    };
+    let payload = match serde_json::to_vec(&event) {
+        Ok(p) => p,
+        Err(e) => {
+            tracing::warn!(flag = flag_name, error = %e, "flag changed event: serialize failed");
+            return;
+        }
+    };
+    if let Err(e) = bus.publish(FLAG_CHANGED_SUBJECT, payload).await {
+        tracing::warn!(flag = flag_name, error = %e, "flag changed event: publish failed");
+    }
+}
+
+#[derive(Default)]
+struct FlagRows {
+    global: Option<FlagDefinition>,
+    tenants: HashMap<Uuid, FlagDefinition>,
+}
+
+#[derive(Default)]
+struct Snapshot {
+    flags: HashMap<String, FlagRows>,
+    loaded_at: Option<Instant>,
+}
+
+fn group(defs: Vec<FlagDefinition>) -> HashMap<String, FlagRows> {
+    let mut flags: HashMap<String, FlagRows> = HashMap::new();
+    for def in defs {
+        let rows = flags.entry(def.flag_name.clone()).or_default();
+        match def.tenant_id {
+            Some(tenant_id) => {
+                rows.tenants.insert(tenant_id, def);
+            }
+            None => rows.global = Some(def),
+        }
+    }
+    flags
+}
+
+/// In-process flag cache and evaluator. Share one per process via `Arc`.
+pub struct FlagEvaluator {
+    pool: PgPool,
+    snapshot: RwLock<Snapshot>,
+    max_age: Duration,
+    audit: Option<EvaluationAudit>,
+    reload: Mutex<()>,
+}
+
+impl FlagEvaluator {
+    /// Evaluator with a 60s backstop refresh and the evaluation audit on.
+    pub fn new(pool: PgPool) -> Self {
+        Self {
+            audit: Some(EvaluationAudit::new(pool.clone())),
+            pool,
+            snapshot: RwLock::new(Snapshot::default()),
+            max_age: DEFAULT_MAX_AGE,
+            reload: Mutex::new(()),
+        }
+    }
+
+    pub fn with_max_age(mut self, max_age: Duration) -> Self {
+        self.max_age = max_age;
+        self
+    }
+
+    pub fn without_audit(mut self) -> Self {
+        self.audit = None;
+        self
+    }
+
+    /// Replace the cache with `defs` (e.g. in tests, or after loading
+    /// definitions elsewhere).
+    pub fn load_snapshot(&self, defs: Vec<FlagDefinition>) {
+        let mut snapshot = self.snapshot.write().unwrap_or_else(|e| e.into_inner());
+        snapshot.flags = group(defs);
+        snapshot.loaded_at = Some(Instant::now());
+    }
+
+    /// Reload every flag from the database.
+    pub async fn refresh(&self) -> Result<(), FlagError> {
+        let _guard = self.reload.lock().await;
+        let defs = load_definitions(&self.pool).await?;
+        self.load_snapshot(defs);
+        Ok(())
+    }
+
+    /// Reload one flag's rows. Serialized with full reloads so a slower full
+    /// load cannot overwrite a newer flag change with rows it read earlier.
+    pub async fn refresh_flag(&self, flag: &str) -> Result<(), FlagError> {
+        let _guard = self.reload.lock().await;
+        let defs = load_definition(&self.pool, flag).await?;
+        let mut snapshot = self.snapshot.write().unwrap_or_else(|e| e.into_inner());
+        let mut grouped = group(defs);
+        match grouped.remove(flag) {
+            Some(rows) => snapshot.flags.insert(flag.to_string(), rows),
+            None => snapshot.flags.remove(flag),
+        };
+        Ok(())
+    }
+
+    fn is_stale(&self) -> bool {
+        let snapshot = self.snapshot.read().unwrap_or_else(|e| e.into_inner());
+        snapshot
+            .loaded_at
+            .is_none_or(|at| at.elapsed() >= self.max_age)
+    }
+
+    async fn ensure_fresh(&self) -> Result<(), FlagError> {
+        if !self.is_stale() {
+            return Ok(());
+        }
+        let _guard = self.reload.lock().await;
+        // Another caller may have reloaded while we waited.
+        if !self.is_stale() {
+            return Ok(());
+        }
+        match load_definitions(&self.pool).await {
+            Ok(defs) => {
+                self.load_snapshot(defs);
+                Ok(())
+            }
+            Err(e) => {
+                let mut snapshot = self.snapshot.write().unwrap_or_else(|e| e.into_inner());
+                match snapshot.loaded_at {
+                    // Keep serving the last snapshot; retry after another max_age.
+                    Some(_) => {
+                        tracing::warn!(error = %e, "feature flag refresh failed — serving cached flags");
+                        snapshot.loaded_at = Some(Instant::now());
+                        Ok(())
+                    }
+                    None => Err(e),
+                }
+            }
+        }
+    }
+
+    /// Evaluate from the cache as it is, without refreshing or auditing.
+    pub fn evaluate_cached(&self, flag: &str, ctx: &EvalContext) -> Evaluation {
+        let snapshot = self.snapshot.read().unwrap_or_else(|e| e.into_inner());
+        let Some(rows) = snapshot.flags.get(flag) else {
+            return Evaluation::not_found(flag);
+        };
+        let tenant_row = ctx.tenant_id.and_then(|t| rows.tenants.get(&t));
+        match (tenant_row, &rows.global) {
+            (Some(def), _) => evaluate(def, FlagSource::Tenant, ctx, Utc::now()),
+            (None, Some(def)) => evaluate(def, FlagSource::Global, ctx, Utc::now()),
+            (None, None) => Evaluation::not_found(flag),
+        }
+    }
+
+    /// Evaluate `flag` for `ctx`, refreshing a stale cache first and
+    /// recording the outcome in the evaluation audit.
+    pub async fn evaluate(&self, flag: &str, ctx: &EvalContext) -> Result<Evaluation, FlagError> {
+        self.ensure_fresh().await?;
+        let eval = self.evaluate_cached(flag, ctx);
+        if let Some(audit) = &self.audit {
+            audit.record(ctx, &eval);
+        }
+        Ok(eval)
+    }
+
+    pub async fn is_enabled(&self, flag: &str, ctx: &EvalContext) -> Result<bool, FlagError> {
+        Ok(self.evaluate(flag, ctx).await?.enabled)
+    }
+
+    /// Subscribe to [`FLAG_CHANGED_SUBJECT`] and reload the changed flag on
+    /// each event until `shutdown` signals `true`.
+    pub async fn listen(
+        self: Arc<Self>,
+        bus: &Arc<dyn EventBus>,
+        mut shutdown: watch::Receiver<bool>,
+    ) -> Result<JoinHandle<()>, FlagError> {
+        let mut stream = bus
+            .subscribe(FLAG_CHANGED_SUBJECT)
+            .await
+            .map_err(|e| FlagError::Bus(e.to_string()))?;
+
+        Ok(tokio::spawn(async move {
+            tracing::info!(
+                subject = FLAG_CHANGED_SUBJECT,
+                "feature flag cache listening"
+            );
+            loop {
+                tokio::select! {
+                    biased;
+                    _ = shutdown.changed() => {
+                        if *shutdown.borrow() {
+                            break;
+                        }
+                    }
+                    msg = stream.next() => {
+                        let Some(msg) = msg else { break; };
+                        let result = match serde_json::from_slice::<FlagChangedEvent>(&msg.payload) {
+                            Ok(event) => self.refresh_flag(&event.flag_name).await,
+                            Err(e) => {
+                                tracing::warn!(error = %e, "bad flag changed event — full reload");
+                                self.refresh().await
+                            }
+                        };
+                        if let Err(e) = result {
+                            tracing::warn!(error = %e, "feature flag cache reload failed");
+                        }
+                    }
+                }
+            }
+            tracing::info!("feature flag cache listener stopped");
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rules::{EvalReason, Variant};
+
+    fn evaluator() -> FlagEvaluator {
+        let pool = sqlx::postgres::PgPoolOptions::new()
+            .acquire_timeout(Duration::from_millis(200))
+            .connect_lazy("postgres://ff:ff@127.0.0.1:1/ff")
+            .unwrap();
+        FlagEvaluator::new(pool).without_audit()
+    }
+
+    #[tokio::test]
+    async fn tenant_row_overrides_global() {
+        let tenant = Uuid::from_u128(1);
+        let ev = evaluator();
+        ev.load_snapshot(vec![
+            FlagDefinition::boolean("checkout", None, true),
+            FlagDefinition::boolean("checkout", Some(tenant), false),
+        ]);
+
+        let own = ev.evaluate_cached("checkout", &EvalContext::tenant(tenant));
+        assert!(!own.enabled);
+        assert_eq!(own.source, FlagSource::Tenant);
+
+        let other = ev.evaluate_cached("checkout", &EvalContext::tenant(Uuid::from_u128(2)));
+        assert!(other.enabled);
+        assert_eq!(other.source, FlagSource::Global);
+
+        let missing = ev.evaluate_cached("unknown", &EvalContext::tenant(tenant));
+        assert_eq!(missing.reason, EvalReason::NotFound);
+    }
+
+    #[tokio::test]
+    async fn fresh_snapshot_evaluates_without_database() {
+        let ev = evaluator();
+        let mut def = FlagDefinition::boolean("theme", None, true);
+        def.variants = vec![Variant {
+            key: "dark".into(),
+            value: serde_json::json!("dark"),
+            weight: 1,
+        }];
+        ev.load_snapshot(vec![def]);
+
+        let eval = ev
+            .evaluate("theme", &EvalContext::tenant(Uuid::from_u128(3)))
+            .await
+            .unwrap();
+        assert_eq!(eval.variant.as_deref(), Some("dark"));
+    }
+
+    #[tokio::test]
+    async fn first_load_failure_is_an_error() {
+        let ev = evaluator();
+        assert!(ev.evaluate("theme", &EvalContext::default()).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn stale_snapshot_survives_refresh_failure() {
+        let ev = evaluator().with_max_age(Duration::ZERO);
+        ev.load_snapshot(vec![FlagDefinition::boolean("checkout", None, true)]);
+        assert!(ev
+            .is_enabled("checkout", &EvalContext::tenant(Uuid::from_u128(1)))
+            .await
+            .unwrap());
+    }
+}
@@ -5,15 +5,70 @@
 //! - **Per-tenant** (`tenant_id IS NOT NULL`): overrides the global value for that tenant.
 //!
 //! Lookup priority: per-tenant → global → `false` (absent row = disabled).
+//! The chosen row is then evaluated by [`crate::rules::evaluate`], so
+//! rollouts, targeting and schedules apply to direct lookups too.
 
+use chrono::{DateTime, Utc};
 use sqlx::PgPool;
 use uuid::Uuid;
 
+use crate::rules::{evaluate, BucketBy, EvalContext, Evaluation, FlagDefinition, FlagSource};
+
 /// Errors returned by flag operations.
 #[derive(Debug, thiserror::Error)]
 pub enum FlagError {
     #[error("database error: {0}")]
     Db(#[from] sqlx::Error),
+
+    #[error("invalid flag definition: {0}")]
+    Invalid(String),
+
+    #[error("event bus error: {0}")]
+    Bus(String),
+}
+
+const DEFINITION_COLUMNS: &str = "flag_name, tenant_id, enabled, variants, rules, \
+     rollout_percent, bucket_by, active_from, active_until, updated_at";
+
+#[derive(sqlx::FromRow)]
+struct DefinitionRow {
+    flag_name: String,
+    tenant_id: Option<Uuid>,
+    enabled: bool,
+    variants: serde_json::Value,
+    rules: serde_json::Value,
+    rollout_percent: Option<i16>,
+    bucket_by: String,
+    active_from: Option<DateTime<Utc>>,
+    active_until: Option<DateTime<Utc>>,
+    updated_at: Option<DateTime<Utc>>,
+}
+
+impl DefinitionRow {
+    /// Malformed JSON columns are logged and treated as empty so one bad
+    /// row cannot break every lookup.
+    fn into_definition(self) -> FlagDefinition {
+        let variants = serde_json::from_value(self.variants).unwrap_or_else(|e| {
+            tracing::warn!(flag = %self.flag_name, error = %e, "feature flag: bad variants JSON");
+            Vec::new()
+        });
+        let rules = serde_json::from_value(self.rules).unwrap_or_else(|e| {
+            tracing::warn!(flag = %self.flag_name, error = %e, "feature flag: bad rules JSON");
+            Vec::new()
+        });
+        FlagDefinition {
+            flag_name: self.flag_name,
+            tenant_id: self.tenant_id,
+            enabled: self.enabled,
+            variants,
+            rules,
+            rollout_percent: self.rollout_percent.map(|p| p.clamp(0, 100) as u8),
+            bucket_by: BucketBy::parse(&self.bucket_by).unwrap_or_default(),
+            active_from: self.active_from,
+            active_until: self.active_until,
+            updated_at: self.updated_at,
+        }
+    }
 }
 
 /// Returns whether a feature flag is enabled for the given tenant.
@@ -22,38 +77,155 @@ pub enum FlagError {
 /// 1. Per-tenant row (if `tenant_id` is `Some` and a row exists).
 /// 2. Global row (`tenant_id IS NULL`).
 /// 3. `false` when no row matches — absent flags are disabled by default.
+///
+/// Hits the database on every call; request paths should use
+/// [`crate::FlagEvaluator`], which caches definitions.
 pub async fn is_enabled(
     pool: &PgPool,
     flag: &str,
     tenant_id: Option<Uuid>,
 ) -> Result<bool, FlagError> {
-    // Per-tenant override takes precedence.
-    if let Some(tid) = tenant_id {
-        let row: Option<bool> = sqlx::query_scalar(
-            "SELECT enabled FROM feature_flags WHERE flag_name = $1 AND tenant_id = $2",
-        )
-        .bind(flag)
-        .bind(tid)
-        .fetch_optional(pool)
-        .await?;
+    let ctx = EvalContext {
+        tenant_id,
+        ..Default::default()
+    };
+    Ok(evaluate_flag(pool, flag, &ctx).await?.enabled)
+}
+
+/// Evaluate a flag for `ctx` straight from the database.
+pub async fn evaluate_flag(
+    pool: &PgPool,
+    flag: &str,
+    ctx: &EvalContext,
+) -> Result<Evaluation, FlagError> {
+    // Per-tenant override sorts first.
+    let row: Option<DefinitionRow> = sqlx::query_as(&format!(
+        "SELECT {DEFINITION_COLUMNS} FROM feature_flags \
+         WHERE flag_name = $1 AND (tenant_id = $2 OR tenant_id IS NULL) \
+         ORDER BY (tenant_id IS NOT NULL) DESC LIMIT 1"
+    ))
+    .bind(flag)
+    .bind(ctx.tenant_id)
+    .fetch_optional(pool)
+    .await?;
 
-        if let Some(enabled) = row {
-            return Ok(enabled);
+    Ok(match row {
+        Some(row) => {
+            let source = if row.tenant_id.is_some() {
+                FlagSource::Tenant
+            } else {
+                FlagSource::Global
+            };
+            evaluate(&row.into_definition(), source, ctx, Utc::now())
         }
-    }
+        None => Evaluation::not_found(flag),
+    })
+}
 
-    // Fall back to the global flag.
-    let global: Option<bool> = sqlx::query_scalar(
-        "SELECT enabled FROM feature_flags WHERE flag_name = $1 AND tenant_id IS NULL",
-    )
+/// Load every flag row (global and per-tenant).
+pub async fn load_definitions(pool: &PgPool) -> Result<Vec<FlagDefinition>, FlagError> {
+    let rows: Vec<DefinitionRow> = sqlx::query_as(&format!(
+        "SELECT {DEFINITION_COLUMNS} FROM feature_flags ORDER BY flag_name, tenant_id"
+    ))
+    .fetch_all(pool)
+    .await?;
+    Ok(rows
+        .into_iter()
+        .map(DefinitionRow::into_definition)
+        .collect())
+}
+
+/// Load the rows for one flag.
+pub async fn load_definition(pool: &PgPool, flag: &str) -> Result<Vec<FlagDefinition>, FlagError> {
+    let rows: Vec<DefinitionRow> = sqlx::query_as(&format!(
+        "SELECT {DEFINITION_COLUMNS} FROM feature_flags WHERE flag_name = $1 ORDER BY tenant_id"
+    ))
     .bind(flag)
-    .fetch_optional(pool)
+    .fetch_all(pool)
     .await?;
+    Ok(rows
+        .into_iter()
+        .map(DefinitionRow::into_definition)
+        .collect())
+}
 
-    Ok(global.unwrap_or(false))
+/// Check a definition before it is stored.
+pub fn validate_definition(def: &FlagDefinition) -> Result<(), FlagError> {
+    if def.flag_name.trim().is_empty() {
+        return Err(FlagError::Invalid("flag_name is empty".into()));
+    }
+    if def.rollout_percent.is_some_and(|p| p > 100) {
+        return Err(FlagError::Invalid("rollout_percent must be 0-100".into()));
+    }
+    if let (Some(from), Some(until)) = (def.active_from, def.active_until) {
+        if from >= until {
+            return Err(FlagError::Invalid(
+                "active_from must be before active_until".into(),
+            ));
+        }
+    }
+    let mut keys = std::collections::HashSet::new();
+    for v in &def.variants {
+        if !keys.insert(v.key.as_str()) {
+            return Err(FlagError::Invalid(format!("duplicate variant '{}'", v.key)));
+        }
+    }
+    for (i, rule) in def.rules.iter().enumerate() {
+        if let Some(key) = &rule.variant {
+            if !keys.contains(key.as_str()) {
+                return Err(FlagError::Invalid(format!(
+                    "rule {i} serves unknown variant '{key}'"
+                )));
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Insert or replace a full flag definition (variants, rules, rollout,
+/// schedule). `def.tenant_id` selects the global row or a tenant override.
+pub async fn set_flag_definition(pool: &PgPool, def: &FlagDefinition) -> Result<(), FlagError> {
+    validate_definition(def)?;
+    let conflict = if def.tenant_id.is_none() {
+        "ON CONFLICT (flag_name) WHERE tenant_id IS NULL"
+    } else {
+        "ON CONFLICT (flag_name, tenant_id) WHERE tenant_id IS NOT NULL"
+    };
+    sqlx::query(&format!(
+        r#"
+        INSERT INTO feature_flags
+            (flag_name, tenant_id, enabled, variants, rules, rollout_percent,
+             bucket_by, active_from, active_until)
+        VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
+        {conflict}
+        DO UPDATE SET enabled = EXCLUDED.enabled,
+                      variants = EXCLUDED.variants,
+                      rules = EXCLUDED.rules,
+                      rollout_percent = EXCLUDED.rollout_percent,
+                      bucket_by = EXCLUDED.bucket_by,
+                      active_from = EXCLUDED.active_from,
+                      active_until = EXCLUDED.active_until,
+                      updated_at = now()
+        "#
+    ))
+    .bind(&def.flag_name)
+    .bind(def.tenant_id)
+    .bind(def.enabled)
+    .bind(serde_json::to_value(&def.variants).unwrap_or_default())
+    .bind(serde_json::to_value(&def.rules).unwrap_or_default())
+    .bind(def.rollout_percent.map(i16::from))
+    .bind(def.bucket_by.as_str())
+    .bind(def.active_from)
+    .bind(def.active_until)
+    .execute(pool)
+    .await?;
+    Ok(())
 }
 
-/// Insert or update a feature flag.
+/// Insert or update a feature flag's `enabled` column.
+///
+/// Variants, rules, rollout and schedule are left as they are; use
+/// [`set_flag_definition`] to change those.
 ///
 /// Pass `tenant_id = None` to set the global default.
 /// Pass `tenant_id = Some(id)` to set a per-tenant override.
@@ -97,23 +269,38 @@ pub async fn set_flag(
 /// Returns all feature flags visible to the given tenant.
 ///
 /// For each flag name, returns the per-tenant row if one exists, otherwise
-/// the global default row (`tenant_id IS NULL`). Flags with no DB rows at
-/// all are absent from the map — callers should treat absence as disabled.
+/// the global default row (`tenant_id IS NULL`), evaluated for the tenant
+/// alone (claim targeting and user bucketing do not apply). Flags with no
+/// DB rows at all are absent from the map — callers should treat absence as
+/// disabled.
 pub async fn list_flags_for_tenant(
     pool: &PgPool,
     tenant_id: Uuid,
 ) -> Result<std::collections::HashMap<String, bool>, FlagError> {
-    let rows: Vec<(String, bool)> = sqlx::query_as(
-        "SELECT DISTINCT ON (flag_name) flag_name, enabled \
+    let rows: Vec<DefinitionRow> = sqlx::query_as(&format!(
+        "SELECT DISTINCT ON (flag_name) {DEFINITION_COLUMNS} \
          FROM feature_flags \
          WHERE tenant_id = $1 OR tenant_id IS NULL \
-         ORDER BY flag_name, (tenant_id IS NOT NULL) DESC",
-    )
+         ORDER BY flag_name, (tenant_id IS NOT NULL) DESC"
+    ))
     .bind(tenant_id)
     .fetch_all(pool)
     .await?;
 
-    Ok(rows.into_iter().collect())
+    let ctx = EvalContext::tenant(tenant_id);
+    let now = Utc::now();
+    Ok(rows
+        .into_iter()
+        .map(|row| {
+            let source = if row.tenant_id.is_some() {
+                FlagSource::Tenant
+            } else {
+                FlagSource::Global
+            };
+            let eval = evaluate(&row.into_definition(), source, &ctx, now);
+            (eval.flag_name, eval.enabled)
+        })
+        .collect())
 }
 
 /// Remove a feature flag row.
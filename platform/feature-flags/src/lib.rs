@@ -4,6 +4,11 @@
 //! tenant-registry database migrations).  Per-tenant rows override the global
 //! default for that flag; absent flags default to `false` (disabled).
 //!
+//! A row can also carry multivariate values, a percentage rollout bucketed
+//! by tenant or user, targeting rules on token claims and a schedule window
+//! — see [`rules`]. [`FlagEvaluator`] caches all rows in-process, reloads on
+//! [`FLAG_CHANGED_SUBJECT`], and records outcomes to the evaluation audit.
+//!
 //! # Quick start
 //!
 //! ```rust,ignore
@@ -17,6 +22,11 @@
 //!
 //! // Override for a specific tenant.
 //! set_flag(&pool, "composite_wo_create", Some(tenant_id), false).await?;
+//!
+//! // Cached evaluation with variants and targeting.
+//! let evaluator = Arc::new(FlagEvaluator::new(pool.clone()));
+//! let eval = evaluator.evaluate("checkout_layout", &ctx).await?;
+//! match eval.variant.as_deref() { Some("compact") => { /* ... */ } _ => {} }
 //! ```
 //!
 //! # Admin endpoint
@@ -31,7 +41,19 @@
 //! ```
 
 pub mod admin;
+pub mod audit;
+pub mod evaluator;
 pub mod flags;
+pub mod rules;
 
-pub use admin::admin_router;
-pub use flags::{delete_flag, is_enabled, list_flags_for_tenant, set_flag, FlagError};
+pub use admin::{admin_router, admin_router_with_bus};
+pub use audit::{recent_evaluations, EvaluationRecord};
+pub use evaluator::{publish_flag_changed, FlagChangedEvent, FlagEvaluator, FLAG_CHANGED_SUBJECT};
+pub use flags::{
+    delete_flag, evaluate_flag, is_enabled, list_flags_for_tenant, load_definitions, set_flag,
+    set_flag_definition, FlagError,
+};
+pub use rules::{
+    BucketBy, EvalContext, EvalReason, Evaluation, FlagDefinition, FlagSource, TargetingRule,
+    Variant,
+};
@@ -0,0 +1,553 @@
+//! Flag definitions and pure evaluation.
+//!
+//! A [`FlagDefinition`] is one `feature_flags` row. [`evaluate`] turns a
+//! definition plus an [`EvalContext`] into an [`Evaluation`], in this order:
+//!
+//! 1. `enabled = false` → off.
+//! 2. Outside `[active_from, active_until)` → off.
+//! 3. First matching targeting rule → its variant (skips the rollout).
+//! 4. `rollout_percent` → off unless the bucket falls inside the percentage.
+//! 5. Weighted variant pick, or `true` for a plain boolean flag.
+//!
+//! Buckets are a SHA-256 of the flag name and the tenant or user ID, so a
+//! subject stays in the same bucket across processes and restarts.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use sha2::{Digest, Sha256};
+use uuid::Uuid;
+
+/// Number of rollout buckets; `rollout_percent` 1 covers 100 of them.
+pub const BUCKETS: u32 = 10_000;
+
+/// Which ID is hashed for rollout and variant bucketing.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BucketBy {
+    #[default]
+    Tenant,
+    User,
+}
+
+impl BucketBy {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            BucketBy::Tenant => "tenant",
+            BucketBy::User => "user",
+        }
+    }
+
+    pub fn parse(s: &str) -> Option<Self> {
+        match s {
+            "tenant" => Some(BucketBy::Tenant),
+            "user" => Some(BucketBy::User),
+            _ => None,
+        }
+    }
+}
+
+/// One value a multivariate flag can serve.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Variant {
+    pub key: String,
+    #[serde(default = "default_variant_value")]
+    pub value: Value,
+    /// Relative weight in the rollout pick. Zero-weight variants are only
+    /// served through targeting rules.
+    #[serde(default)]
+    pub weight: u32,
+}
+
+fn default_variant_value() -> Value {
+    Value::Bool(true)
+}
+
+/// A targeting rule on token claims.
+///
+/// Every non-empty list must match (any element); an empty list ignores
+/// that claim.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TargetingRule {
+    #[serde(default)]
+    pub roles: Vec<String>,
+    #[serde(default)]
+    pub app_ids: Vec<Uuid>,
+    #[serde(default)]
+    pub actor_types: Vec<String>,
+    /// Variant to serve; `None` serves the plain enabled value.
+    #[serde(default)]
+    pub variant: Option<String>,
+    /// `false` turns the flag off for matching subjects.
+    #[serde(default = "default_true")]
+    pub enabled: bool,
+}
+
+fn default_true() -> bool {
+    true
+}
+
+impl Default for TargetingRule {
+    /// Matches everyone and serves the plain enabled value.
+    fn default() -> Self {
+        Self {
+            roles: Vec::new(),
+            app_ids: Vec::new(),
+            actor_types: Vec::new(),
+            variant: None,
+            enabled: true,
+        }
+    }
+}
+
+impl TargetingRule {
+    pub fn matches(&self, ctx: &EvalContext) -> bool {
+        (self.roles.is_empty() || self.roles.iter().any(|r| ctx.roles.contains(r)))
+            && (self.app_ids.is_empty() || ctx.app_id.is_some_and(|a| self.app_ids.contains(&a)))
+            && (self.actor_types.is_empty()
+                || ctx
+                    .actor_type
+                    .as_ref()
+                    .is_some_and(|a| self.actor_types.contains(a)))
+    }
+}
+
+/// One `feature_flags` row.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FlagDefinition {
+    pub flag_name: String,
+    /// `None` for the global row.
+    pub tenant_id: Option<Uuid>,
+    pub enabled: bool,
+    #[serde(default)]
+    pub variants: Vec<Variant>,
+    #[serde(default)]
+    pub rules: Vec<TargetingRule>,
+    /// 0-100. `None` means no percentage gate.
+    #[serde(default)]
+    pub rollout_percent: Option<u8>,
+    #[serde(default)]
+    pub bucket_by: BucketBy,
+    #[serde(default)]
+    pub active_from: Option<DateTime<Utc>>,
+    #[serde(default)]
+    pub active_until: Option<DateTime<Utc>>,
+    #[serde(default)]
+    pub updated_at: Option<DateTime<Utc>>,
+}
+
+impl FlagDefinition {
+    /// A plain on/off definition, as written by [`crate::set_flag`].
+    pub fn boolean(flag_name: impl Into<String>, tenant_id: Option<Uuid>, enabled: bool) -> Self {
+        Self {
+            flag_name: flag_name.into(),
+            tenant_id,
+            enabled,
+            variants: Vec::new(),
+            rules: Vec::new(),
+            rollout_percent: None,
+            bucket_by: BucketBy::Tenant,
+            active_from: None,
+            active_until: None,
+            updated_at: None,
+        }
+    }
+
+    fn variant(&self, key: &str) -> Option<&Variant> {
+        self.variants.iter().find(|v| v.key == key)
+    }
+}
+
+/// Who a flag is evaluated for — usually built from verified token claims.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EvalContext {
+    pub tenant_id: Option<Uuid>,
+    pub user_id: Option<Uuid>,
+    pub app_id: Option<Uuid>,
+    #[serde(default)]
+    pub roles: Vec<String>,
+    pub actor_type: Option<String>,
+}
+
+impl EvalContext {
+    pub fn tenant(tenant_id: Uuid) -> Self {
+        Self {
+            tenant_id: Some(tenant_id),
+            ..Default::default()
+        }
+    }
+
+    /// The ID hashed for `bucket_by`. User bucketing falls back to the
+    /// tenant when there is no user (e.g. background jobs).
+    fn bucket_key(&self, by: BucketBy) -> Option<Uuid> {
+        match by {
+            BucketBy::Tenant => self.tenant_id,
+            BucketBy::User => self.user_id.or(self.tenant_id),
+        }
+    }
+}
+
+/// Which row an evaluation used.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FlagSource {
+    Tenant,
+    Global,
+    Missing,
+}
+
+impl FlagSource {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            FlagSource::Tenant => "tenant",
+            FlagSource::Global => "global",
+            FlagSource::Missing => "missing",
+        }
+    }
+}
+
+/// Why an evaluation came out the way it did.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum EvalReason {
+    /// No row for the flag.
+    NotFound,
+    /// The row has `enabled = false`.
+    Disabled,
+    BeforeSchedule {
+        active_from: DateTime<Utc>,
+    },
+    AfterSchedule {
+        active_until: DateTime<Utc>,
+    },
+    /// Targeting rule `rule` (0-based) matched.
+    TargetingRule {
+        rule: usize,
+    },
+    /// The bucket is outside the rollout percentage.
+    OutsideRollout {
+        bucket: u32,
+        percent: u8,
+    },
+    /// Rollout percentage set but nothing to bucket by.
+    NoBucketKey,
+    /// Served by the rollout (`bucket` is `None` for a plain boolean flag
+    /// with no percentage gate).
+    Rollout {
+        bucket: Option<u32>,
+    },
+}
+
+/// The result of evaluating a flag for one subject.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Evaluation {
+    pub flag_name: String,
+    pub enabled: bool,
+    pub variant: Option<String>,
+    /// Variant value, or `true`/`false` for boolean flags.
+    pub value: Value,
+    pub reason: EvalReason,
+    pub source: FlagSource,
+    /// `updated_at` of the row used, to tie audit entries to a flag version.
+    pub flag_updated_at: Option<DateTime<Utc>>,
+}
+
+impl Evaluation {
+    fn off(
+        flag_name: &str,
+        reason: EvalReason,
+        def: Option<&FlagDefinition>,
+        source: FlagSource,
+    ) -> Self {
+        Self {
+            flag_name: flag_name.to_string(),
+            enabled: false,
+            variant: None,
+            value: Value::Bool(false),
+            reason,
+            source,
+            flag_updated_at: def.and_then(|d| d.updated_at),
+        }
+    }
+
+    /// Result for a flag with no row at all.
+    pub fn not_found(flag_name: &str) -> Self {
+        Self::off(flag_name, EvalReason::NotFound, None, FlagSource::Missing)
+    }
+}
+
+/// Deterministic bucket in `0..BUCKETS` for `key` under `flag_name`.
+///
+/// `salt` keeps the rollout gate and the variant pick independent.
+pub fn bucket(flag_name: &str, salt: &str, key: Uuid) -> u32 {
+    let digest = Sha256::new()
+        .chain_update(flag_name.as_bytes())
+        .chain_update(b":")
+        .chain_update(salt.as_bytes())
+        .chain_update(b":")
+        .chain_update(key.as_bytes())
+        .finalize();
+    u32::from_be_bytes([digest[0], digest[1], digest[2], digest[3]]) % BUCKETS
+}
+
+/// Evaluate `def` (the row chosen for the tenant) for `ctx` at `now`.
+pub fn evaluate(
+    def: &FlagDefinition,
+    source: FlagSource,
+    ctx: &EvalContext,
+    now: DateTime<Utc>,
+) -> Evaluation {
+    let name = def.flag_name.as_str();
+    if !def.enabled {
+        return Evaluation::off(name, EvalReason::Disabled, Some(def), source);
+    }
+    if let Some(active_from) = def.active_from.filter(|from| now < *from) {
+        return Evaluation::off(
+            name,
+            EvalReason::BeforeSchedule { active_from },
+            Some(def),
+            source,
+        );
+    }
+    if let Some(active_until) = def.active_until.filter(|until| now >= *until) {
+        return Evaluation::off(
+            name,
+            EvalReason::AfterSchedule { active_until },
+            Some(def),
+            source,
+        );
+    }
+
+    let on = |variant: Option<&Variant>, reason: EvalReason| Evaluation {
+        flag_name: name.to_string(),
+        enabled: true,
+        variant: variant.map(|v| v.key.clone()),
+        value: variant.map_or(Value::Bool(true), |v| v.value.clone()),
+        reason,
+        source,
+        flag_updated_at: def.updated_at,
+    };
+
+    if let Some((index, rule)) = def.rules.iter().enumerate().find(|(_, r)| r.matches(ctx)) {
+        let reason = EvalReason::TargetingRule { rule: index };
+        if !rule.enabled {
+            return Evaluation::off(name, reason, Some(def), source);
+        }
+        let variant = rule.variant.as_deref().and_then(|k| def.variant(k));
+        return on(variant, reason);
+    }
+
+    let key = ctx.bucket_key(def.bucket_by);
+    let mut rollout_bucket = None;
+    if let Some(percent) = def.rollout_percent {
+        let Some(key) = key else {
+            return Evaluation::off(name, EvalReason::NoBucketKey, Some(def), source);
+        };
+        let b = bucket(name, "rollout", key);
+        if b >= u32::from(percent.min(100)) * (BUCKETS / 100) {
+            return Evaluation::off(
+                name,
+                EvalReason::OutsideRollout { bucket: b, percent },
+                Some(def),
+                source,
+            );
+        }
+        rollout_bucket = Some(b);
+    }
+
+    on(
+        pick_variant(def, key),
+        EvalReason::Rollout {
+            bucket: rollout_bucket,
+        },
+    )
+}
+
+/// Weighted variant pick. With no weights (or no bucket key) the first
+/// variant is served; with no variants the flag is boolean.
+fn pick_variant(def: &FlagDefinition, key: Option<Uuid>) -> Option<&Variant> {
+    let total: u64 = def.variants.iter().map(|v| u64::from(v.weight)).sum();
+    let (Some(key), true) = (key, total > 0) else {
+        return def.variants.first();
+    };
+    let point = u64::from(bucket(&def.flag_name, "variant", key)) * total / u64::from(BUCKETS);
+    let mut acc = 0;
+    def.variants.iter().find(|v| {
+        acc += u64::from(v.weight);
+        point < acc
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn now() -> DateTime<Utc> {
+        "2026-04-15T12:00:00Z".parse().unwrap()
+    }
+
+    fn def() -> FlagDefinition {
+        FlagDefinition::boolean("new_checkout", None, true)
+    }
+
+    fn tenant_ctx(n: u128) -> EvalContext {
+        EvalContext::tenant(Uuid::from_u128(n))
+    }
+
+    #[test]
+    fn boolean_flag_matches_legacy_lookup() {
+        let on = evaluate(&def(), FlagSource::Global, &tenant_ctx(1), now());
+        assert!(on.enabled);
+        assert_eq!(on.value, json!(true));
+        assert_eq!(on.reason, EvalReason::Rollout { bucket: None });
+
+        let mut off = def();
+        off.enabled = false;
+        let off = evaluate(&off, FlagSource::Global, &tenant_ctx(1), now());
+        assert!(!off.enabled);
+        assert_eq!(off.reason, EvalReason::Disabled);
+    }
+
+    #[test]
+    fn schedule_window_is_half_open() {
+        let mut d = def();
+        d.active_from = Some("2026-04-15T12:00:00Z".parse().unwrap());
+        d.active_until = Some("2026-04-16T00:00:00Z".parse().unwrap());
+
+        assert!(evaluate(&d, FlagSource::Global, &tenant_ctx(1), now()).enabled);
+        let before = evaluate(
+            &d,
+            FlagSource::Global,
+            &tenant_ctx(1),
+            now() - chrono::Duration::seconds(1),
+        );
+        assert!(matches!(before.reason, EvalReason::BeforeSchedule { .. }));
+        let after = evaluate(
+            &d,
+            FlagSource::Global,
+            &tenant_ctx(1),
+            d.active_until.unwrap(),
+        );
+        assert!(matches!(after.reason, EvalReason::AfterSchedule { .. }));
+    }
+
+    #[test]
+    fn rollout_is_deterministic_and_close_to_percentage() {
+        let mut d = def();
+        d.rollout_percent = Some(25);
+
+        let first: Vec<bool> = (0..2000)
+            .map(|n| evaluate(&d, FlagSource::Global, &tenant_ctx(n), now()).enabled)
+            .collect();
+        let second: Vec<bool> = (0..2000)
+            .map(|n| evaluate(&d, FlagSource::Global, &tenant_ctx(n), now()).enabled)
+            .collect();
+        assert_eq!(first, second);
+
+        let on = first.iter().filter(|e| **e).count();
+        assert!((400..600).contains(&on), "25% of 2000 ≈ 500, got {on}");
+    }
+
+    #[test]
+    fn raising_rollout_keeps_existing_subjects() {
+        let mut d = def();
+        d.rollout_percent = Some(10);
+        let at_10: Vec<u128> = (0..1000)
+            .filter(|n| evaluate(&d, FlagSource::Global, &tenant_ctx(*n), now()).enabled)
+            .collect();
+        d.rollout_percent = Some(50);
+        assert!(at_10
+            .iter()
+            .all(|n| evaluate(&d, FlagSource::Global, &tenant_ctx(*n), now()).enabled));
+    }
+
+    #[test]
+    fn user_bucketing_falls_back_to_tenant() {
+        let mut d = def();
+        d.rollout_percent = Some(100);
+        d.bucket_by = BucketBy::User;
+        assert!(evaluate(&d, FlagSource::Global, &tenant_ctx(7), now()).enabled);
+
+        let none = evaluate(&d, FlagSource::Global, &EvalContext::default(), now());
+        assert_eq!(none.reason, EvalReason::NoBucketKey);
+    }
+
+    #[test]
+    fn variants_split_by_weight() {
+        let mut d = def();
+        d.variants = vec![
+            Variant {
+                key: "control".into(),
+                value: json!("a"),
+                weight: 1,
+            },
+            Variant {
+                key: "treatment".into(),
+                value: json!("b"),
+                weight: 3,
+            },
+        ];
+        let treatment = (0..2000)
+            .filter(|n| {
+                evaluate(&d, FlagSource::Global, &tenant_ctx(*n), now())
+                    .variant
+                    .as_deref()
+                    == Some("treatment")
+            })
+            .count();
+        assert!(
+            (1350..1650).contains(&treatment),
+            "75% of 2000 ≈ 1500, got {treatment}"
+        );
+    }
+
+    #[test]
+    fn targeting_rule_wins_over_rollout() {
+        let mut d = def();
+        d.rollout_percent = Some(0);
+        d.variants = vec![Variant {
+            key: "beta".into(),
+            value: json!({"limit": 5}),
+            weight: 0,
+        }];
+        d.rules = vec![TargetingRule {
+            roles: vec!["support".into()],
+            actor_types: vec!["user".into()],
+            variant: Some("beta".into()),
+            ..Default::default()
+        }];
+
+        let mut ctx = tenant_ctx(1);
+        ctx.roles = vec!["support".into()];
+        ctx.actor_type = Some("user".into());
+        let hit = evaluate(&d, FlagSource::Tenant, &ctx, now());
+        assert!(hit.enabled);
+        assert_eq!(hit.variant.as_deref(), Some("beta"));
+        assert_eq!(hit.value, json!({"limit": 5}));
+        assert_eq!(hit.reason, EvalReason::TargetingRule { rule: 0 });
+
+        ctx.actor_type = Some("service".into());
+        let miss = evaluate(&d, FlagSource::Tenant, &ctx, now());
+        assert!(matches!(
+            miss.reason,
+            EvalReason::OutsideRollout { percent: 0, .. }
+        ));
+    }
+
+    #[test]
+    fn disabling_rule_turns_flag_off() {
+        let app = Uuid::from_u128(99);
+        let mut d = def();
+        d.rules = vec![TargetingRule {
+            app_ids: vec![app],
+            enabled: false,
+            ..Default::default()
+        }];
+        let mut ctx = tenant_ctx(1);
+        ctx.app_id = Some(app);
+        let e = evaluate(&d, FlagSource::Global, &ctx, now());
+        assert!(!e.enabled);
+        assert_eq!(e.reason, EvalReason::TargetingRule { rule: 0 });
+    }
+}
@@ -7,7 +7,10 @@
 //! Run with:
 //!   cargo test -p feature-flags --test integration -- --nocapture
 
-use feature_flags::{delete_flag, is_enabled, list_flags_for_tenant, set_flag};
+use feature_flags::{
+    delete_flag, evaluate_flag, is_enabled, list_flags_for_tenant, set_flag, set_flag_definition,
+    EvalContext, EvalReason, FlagDefinition, FlagEvaluator, TargetingRule, Variant,
+};
 use sqlx::PgPool;
 use uuid::Uuid;
 
@@ -186,3 +189,93 @@ async fn list_flags_for_tenant_per_tenant_overrides_global() {
     delete_flag(&pool, &flag, None).await.unwrap();
     delete_flag(&pool, &flag, Some(tenant)).await.unwrap();
 }
+
+/// Full definition round-trips and targeting/variants apply on lookup.
+#[tokio::test]
+async fn flag_definition_variants_and_targeting() {
+    let pool = test_pool().await;
+    let tenant = Uuid::new_v4();
+    let flag = format!("test_variants_{}", Uuid::new_v4().simple());
+
+    let mut def = FlagDefinition::boolean(&flag, None, true);
+    def.variants = vec![
+        Variant {
+            key: "compact".into(),
+            value: serde_json::json!({"rows": 50}),
+            weight: 1,
+        },
+        Variant {
+            key: "beta".into(),
+            value: serde_json::json!({"rows": 200}),
+            weight: 0,
+        },
+    ];
+    def.rules = vec![TargetingRule {
+        roles: vec!["support".into()],
+        variant: Some("beta".into()),
+        ..Default::default()
+    }];
+    set_flag_definition(&pool, &def).await.unwrap();
+
+    let plain = evaluate_flag(&pool, &flag, &EvalContext::tenant(tenant))
+        .await
+        .unwrap();
+    assert_eq!(plain.variant.as_deref(), Some("compact"));
+
+    let support = EvalContext {
+        roles: vec!["support".into()],
+        ..EvalContext::tenant(tenant)
+    };
+    let targeted = evaluate_flag(&pool, &flag, &support).await.unwrap();
+    assert_eq!(targeted.variant.as_deref(), Some("beta"));
+    assert_eq!(targeted.reason, EvalReason::TargetingRule { rule: 0 });
+
+    // set_flag only flips `enabled`; variants survive.
+    set_flag(&pool, &flag, None, false).await.unwrap();
+    set_flag(&pool, &flag, None, true).await.unwrap();
+    let again = evaluate_flag(&pool, &flag, &support).await.unwrap();
+    assert_eq!(again.variant.as_deref(), Some("beta"));
+
+    // Cleanup
+    delete_flag(&pool, &flag, None).await.unwrap();
+}
+
+/// A 0% rollout disables the flag through the legacy boolean lookup too.
+#[tokio::test]
+async fn zero_percent_rollout_is_disabled() {
+    let pool = test_pool().await;
+    let tenant = Uuid::new_v4();
+    let flag = format!("test_rollout_{}", Uuid::new_v4().simple());
+
+    let mut def = FlagDefinition::boolean(&flag, None, true);
+    def.rollout_percent = Some(0);
+    set_flag_definition(&pool, &def).await.unwrap();
+
+    assert!(!is_enabled(&pool, &flag, Some(tenant)).await.unwrap());
+
+    def.rollout_percent = Some(100);
+    set_flag_definition(&pool, &def).await.unwrap();
+    assert!(is_enabled(&pool, &flag, Some(tenant)).await.unwrap());
+
+    // Cleanup
+    delete_flag(&pool, &flag, None).await.unwrap();
+}
+
+/// The cached evaluator sees rows after a refresh.
+#[tokio::test]
+async fn evaluator_refresh_picks_up_changes() {
+    let pool = test_pool().await;
+    let tenant = Uuid::new_v4();
+    let flag = format!("test_cache_{}", Uuid::new_v4().simple());
+    let evaluator = FlagEvaluator::new(pool.clone()).without_audit();
+    let ctx = EvalContext::tenant(tenant);
+
+    assert!(!evaluator.is_enabled(&flag, &ctx).await.unwrap());
+
+    set_flag(&pool, &flag, None, true).await.unwrap();
+    evaluator.refresh_flag(&flag).await.unwrap();
+    assert!(evaluator.is_enabled(&flag, &ctx).await.unwrap());
+
+    // Cleanup
+    delete_flag(&pool, &flag, None).await.unwrap();
+}
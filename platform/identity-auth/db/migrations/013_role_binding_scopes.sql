@@ -0,0 +1,13 @@
+-- Attribute-based data scoping on role grants.
+--
+-- A binding's scope restricts every permission the role confers to resources
+-- whose attributes match: {"business_unit": ["EAST"], "location": ["W1"]}.
+-- NULL = unscoped (tenant-wide). Scopes are embedded in access tokens per
+-- permission; see platform/security/src/scope.rs for evaluation rules.
+
+ALTER TABLE user_role_bindings
+    ADD COLUMN scope JSONB;
+
+ALTER TABLE user_role_bindings
+    ADD CONSTRAINT user_role_bindings_scope_is_object
+    CHECK (scope IS NULL OR jsonb_typeof(scope) = 'object');
@@ -0,0 +1,107 @@
+-- Multi-factor authentication: TOTP factors with recovery codes, WebAuthn
+-- (passkey) credentials, pending MFA challenges and per-tenant enforcement.
+--
+-- Refresh rows remember how their session authenticated (`amr`, RFC 8176) and
+-- when it last did so interactively (`auth_time`) so refreshed access tokens
+-- keep the MFA claims that sensitive endpoints check for.
+
+CREATE TABLE mfa_totp_factors (
+    tenant_id UUID NOT NULL,
+    user_id UUID NOT NULL,
+    -- AES-256-GCM under MFA_SECRET_KEY: 12-byte nonce || ciphertext+tag
+    secret_enc BYTEA NOT NULL,
+    -- NULL until the user proves possession with a first valid code
+    confirmed_at TIMESTAMPTZ,
+    -- Highest accepted time step; codes at or below it are replays
+    last_used_step BIGINT,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (tenant_id, user_id)
+);
+
+CREATE TABLE mfa_recovery_codes (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    tenant_id UUID NOT NULL,
+    user_id UUID NOT NULL,
+    code_hash TEXT NOT NULL,
+    used_at TIMESTAMPTZ,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_mfa_recovery_codes_unused
+    ON mfa_recovery_codes (tenant_id, user_id)
+    WHERE used_at IS NULL;
+
+CREATE TABLE mfa_webauthn_credentials (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    tenant_id UUID NOT NULL,
+    user_id UUID NOT NULL,
+    credential_id BYTEA NOT NULL,
+    -- SEC1 uncompressed P-256 point (ES256 is the only accepted algorithm)
+    public_key BYTEA NOT NULL,
+    sign_count BIGINT NOT NULL DEFAULT 0,
+    name TEXT NOT NULL DEFAULT '',
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    last_used_at TIMESTAMPTZ,
+    CONSTRAINT mfa_webauthn_credentials_unique UNIQUE (tenant_id, credential_id)
+);
+
+CREATE INDEX idx_mfa_webauthn_credentials_user
+    ON mfa_webauthn_credentials (tenant_id, user_id);
+
+-- Short-lived challenges. `login` and `step_up` are redeemed with an opaque
+-- mfa_token (stored hashed); `webauthn_register` by id.
+CREATE TABLE mfa_challenges (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    tenant_id UUID NOT NULL,
+    user_id UUID NOT NULL,
+    purpose TEXT NOT NULL,
+    token_hash TEXT,
+    webauthn_challenge BYTEA,
+    -- step_up only: the refresh row whose session is being elevated
+    refresh_token_id UUID,
+    refresh_session_id UUID,
+    attempts INT NOT NULL DEFAULT 0,
+    expires_at TIMESTAMPTZ NOT NULL,
+    consumed_at TIMESTAMPTZ,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    CONSTRAINT mfa_challenges_purpose_check
+        CHECK (purpose IN ('login', 'step_up', 'webauthn_register')),
+    CONSTRAINT mfa_challenges_token_hash_unique UNIQUE (token_hash)
+);
+
+CREATE INDEX idx_mfa_challenges_user
+    ON mfa_challenges (tenant_id, user_id, created_at);
+
+CREATE TABLE mfa_policies (
+    tenant_id UUID PRIMARY KEY,
+    -- off: MFA only for users who enrolled; all_users: everyone;
+    -- roles: holders of any active binding to one of role_ids
+    mode TEXT NOT NULL DEFAULT 'off',
+    role_ids UUID[] NOT NULL DEFAULT '{}',
+    updated_by UUID,
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    CONSTRAINT mfa_policies_mode_check CHECK (mode IN ('off', 'all_users', 'roles'))
+);
+
+ALTER TABLE refresh_tokens
+    ADD COLUMN amr TEXT[] NOT NULL DEFAULT '{pwd}',
+    ADD COLUMN auth_time TIMESTAMPTZ;
+
+ALTER TABLE refresh_sessions
+    ADD COLUMN amr TEXT[] NOT NULL DEFAULT '{pwd}',
+    ADD COLUMN auth_time TIMESTAMPTZ;
+
+ALTER TABLE user_lifecycle_audit_events
+    DROP CONSTRAINT chk_user_lifecycle_event_type;
+
+ALTER TABLE user_lifecycle_audit_events
+    ADD CONSTRAINT chk_user_lifecycle_event_type CHECK (
+        event_type IN (
+            'user_created',
+            'role_assigned',
+            'role_revoked',
+            'access_review_recorded',
+            'mfa_enrolled',
+            'mfa_reset'
+        )
+    );
@@ -0,0 +1,78 @@
+-- Per-tenant OIDC single sign-on (authorization code + PKCE).
+--
+-- One identity provider per tenant. Federated users are linked to a local
+-- credentials row by (issuer, subject); first logins either link by verified
+-- email or provision the user just-in-time. IdP groups map to tenant roles.
+
+CREATE TABLE oidc_providers (
+    tenant_id UUID PRIMARY KEY,
+    issuer TEXT NOT NULL,
+    client_id TEXT NOT NULL,
+    -- AES-256-GCM sealed with SSO_SECRET_KEY; NULL for public (PKCE-only) clients
+    client_secret_enc BYTEA,
+    -- Callback registered at the IdP (this service's /api/auth/sso/callback)
+    redirect_uri TEXT NOT NULL,
+    scopes TEXT[] NOT NULL DEFAULT '{openid,email,profile}',
+    -- ID token claim paths (dotted for nested claims, e.g. realm_access.roles)
+    email_claim TEXT NOT NULL DEFAULT 'email',
+    groups_claim TEXT NOT NULL DEFAULT 'groups',
+    jit_provisioning BOOLEAN NOT NULL DEFAULT TRUE,
+    link_by_email BOOLEAN NOT NULL DEFAULT TRUE,
+    password_login_disabled BOOLEAN NOT NULL DEFAULT FALSE,
+    enabled BOOLEAN NOT NULL DEFAULT TRUE,
+    updated_by UUID,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE TABLE oidc_group_role_mappings (
+    tenant_id UUID NOT NULL REFERENCES oidc_providers (tenant_id) ON DELETE CASCADE,
+    idp_group TEXT NOT NULL,
+    role_id UUID NOT NULL REFERENCES roles (id) ON DELETE CASCADE,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (tenant_id, idp_group, role_id)
+);
+
+CREATE TABLE oidc_identities (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    tenant_id UUID NOT NULL,
+    user_id UUID NOT NULL,
+    issuer TEXT NOT NULL,
+    subject TEXT NOT NULL,
+    email TEXT,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    last_login_at TIMESTAMPTZ,
+    UNIQUE (tenant_id, issuer, subject)
+);
+
+CREATE INDEX idx_oidc_identities_user ON oidc_identities (tenant_id, user_id);
+
+-- Pending authorization requests, keyed by the SHA-256 of the `state` value.
+CREATE TABLE oidc_login_states (
+    state_hash TEXT PRIMARY KEY,
+    tenant_id UUID NOT NULL,
+    nonce TEXT NOT NULL,
+    code_verifier TEXT NOT NULL,
+    return_to TEXT,
+    expires_at TIMESTAMPTZ NOT NULL,
+    consumed_at TIMESTAMPTZ,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_oidc_login_states_expiry ON oidc_login_states (expires_at);
+
+ALTER TABLE user_lifecycle_audit_events
+    DROP CONSTRAINT chk_user_lifecycle_event_type;
+
+ALTER TABLE user_lifecycle_audit_events
+    ADD CONSTRAINT chk_user_lifecycle_event_type CHECK (
+        event_type IN (
+            'user_created',
+            'role_assigned',
+            'role_revoked',
+            'access_review_recorded',
+            'mfa_enrolled',
+            'mfa_reset',
+            'sso_identity_linked'
+        )
+    );
@@ -0,0 +1,79 @@
+-- SCIM 2.0 provisioning (RFC 7643 / 7644).
+--
+-- Tenants' IdPs push users and groups with per-tenant bearer tokens. SCIM
+-- users are ordinary credentials rows plus the SCIM attributes below; each
+-- SCIM group is backed by one tenant role, so membership grants the role.
+
+CREATE TABLE scim_tokens (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    tenant_id UUID NOT NULL,
+    -- SHA-256 hex of the raw bearer token; the token is shown once
+    token_hash TEXT NOT NULL UNIQUE,
+    description TEXT NOT NULL DEFAULT '',
+    created_by UUID,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    last_used_at TIMESTAMPTZ,
+    revoked_at TIMESTAMPTZ
+);
+
+CREATE INDEX idx_scim_tokens_tenant ON scim_tokens (tenant_id);
+
+CREATE TABLE scim_users (
+    tenant_id UUID NOT NULL,
+    user_id UUID NOT NULL,
+    user_name TEXT NOT NULL,
+    external_id TEXT,
+    given_name TEXT,
+    family_name TEXT,
+    display_name TEXT,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    -- SCIM DELETE: the credentials row stays (inactive) for audit history
+    deleted_at TIMESTAMPTZ,
+    PRIMARY KEY (tenant_id, user_id)
+);
+
+CREATE UNIQUE INDEX idx_scim_users_user_name
+    ON scim_users (tenant_id, lower(user_name))
+    WHERE deleted_at IS NULL;
+
+CREATE TABLE scim_groups (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    tenant_id UUID NOT NULL,
+    display_name TEXT NOT NULL,
+    external_id TEXT,
+    role_id UUID NOT NULL REFERENCES roles (id) ON DELETE CASCADE,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    UNIQUE (tenant_id, role_id)
+);
+
+CREATE UNIQUE INDEX idx_scim_groups_display_name
+    ON scim_groups (tenant_id, lower(display_name));
+
+CREATE TABLE scim_group_members (
+    group_id UUID NOT NULL REFERENCES scim_groups (id) ON DELETE CASCADE,
+    user_id UUID NOT NULL,
+    added_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (group_id, user_id)
+);
+
+CREATE INDEX idx_scim_group_members_user ON scim_group_members (user_id);
+
+ALTER TABLE user_lifecycle_audit_events
+    DROP CONSTRAINT chk_user_lifecycle_event_type;
+
+ALTER TABLE user_lifecycle_audit_events
+    ADD CONSTRAINT chk_user_lifecycle_event_type CHECK (
+        event_type IN (
+            'user_created',
+            'role_assigned',
+            'role_revoked',
+            'access_review_recorded',
+            'mfa_enrolled',
+            'mfa_reset',
+            'sso_identity_linked',
+            'user_deactivated',
+            'user_reactivated'
+        )
+    );
@@ -0,0 +1,13 @@
+-- Tenant authentication administration: MFA resets and policy, SSO
+-- providers and group mappings, SCIM tokens.
+INSERT INTO permissions (key, description)
+VALUES ('admin.security.write', 'Manage tenant authentication: MFA, SSO and SCIM')
+ON CONFLICT (key) DO NOTHING;
+
+INSERT INTO role_permissions (role_id, permission_id)
+SELECT r.id, p.id
+FROM roles r
+JOIN permissions p ON p.key = 'admin.security.write'
+WHERE r.is_system = true
+  AND r.name = 'admin'
+ON CONFLICT (role_id, permission_id) DO NOTHING;
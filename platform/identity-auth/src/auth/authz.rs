@@ -6,17 +6,20 @@
 //! 3. Queries the DB for the caller's current effective permissions.
 //! 4. Returns 401/403 if the required permission is absent.
 //!
+//! [`SessionUser`] stops after authentication: it identifies the signed-in
+//! user for self-service endpoints without requiring a permission.
+//!
 //! Using extractors rather than route layers keeps permission requirements
 //! visible in the handler signature and exposes them to utoipa OpenAPI docs.
 
 use axum::{
-    extract::FromRequestParts,
+    extract::{FromRequestParts, OptionalFromRequestParts},
     http::{header, request::Parts, StatusCode},
 };
 use std::sync::Arc;
 use uuid::Uuid;
 
-use super::handlers::AuthState;
+use super::handlers::{err, ApiErr, AuthState};
 
 /// Verified caller who holds `admin.users.read`.
 ///
@@ -36,69 +39,178 @@ impl FromRequestParts<Arc<AuthState>> for AdminUsersRead {
         parts: &mut Parts,
         state: &Arc<AuthState>,
     ) -> Result<Self, Self::Rejection> {
-        const PERM: &str = "admin.users.read";
-
-        // 1. Extract Bearer token from Authorization header.
-        let token = parts
-            .headers
-            .get(header::AUTHORIZATION)
-            .and_then(|v| v.to_str().ok())
-            .and_then(|v| v.strip_prefix("Bearer "))
-            .ok_or_else(|| {
-                (
-                    StatusCode::UNAUTHORIZED,
-                    "missing or invalid authentication".to_string(),
-                )
-            })?;
-
-        // 2. Verify JWT with the service's own keys.
-        let claims = state.jwt.validate_access_token(token).map_err(|_| {
-            (
-                StatusCode::UNAUTHORIZED,
-                "missing or invalid authentication".to_string(),
-            )
-        })?;
+        let (user_id, tenant_id) = require_permission(parts, state, "admin.users.read").await?;
+        Ok(AdminUsersRead { user_id, tenant_id })
+    }
+}
 
-        let user_id = Uuid::parse_str(&claims.sub).map_err(|_| {
-            (
-                StatusCode::UNAUTHORIZED,
-                "missing or invalid authentication".to_string(),
-            )
-        })?;
-        let tenant_id = Uuid::parse_str(&claims.tenant_id).map_err(|_| {
-            (
-                StatusCode::UNAUTHORIZED,
-                "missing or invalid authentication".to_string(),
-            )
-        })?;
+/// Verified caller who holds `admin.security.write`.
+///
+/// Gates tenant authentication administration: MFA resets and policy, SSO
+/// providers and group mappings, SCIM tokens, role grants. Handlers must still check that
+/// the tenant they act on is `tenant_id`.
+#[derive(Debug)]
+pub struct AdminSecurityWrite {
+    pub user_id: Uuid,
+    pub tenant_id: Uuid,
+}
+
+impl FromRequestParts<Arc<AuthState>> for AdminSecurityWrite {
+    type Rejection = (StatusCode, String);
+
+    async fn from_request_parts(
+        parts: &mut Parts,
+        state: &Arc<AuthState>,
+    ) -> Result<Self, Self::Rejection> {
+        let (user_id, tenant_id) = require_permission(parts, state, "admin.security.write").await?;
+        Ok(AdminSecurityWrite { user_id, tenant_id })
+    }
+}
+
+impl AdminSecurityWrite {
+    /// 403 unless `tenant_id` is the caller's own tenant.
+    pub(super) fn ensure_tenant(&self, tenant_id: Uuid) -> Result<(), ApiErr> {
+        if tenant_id == self.tenant_id {
+            Ok(())
+        } else {
+            Err(err(
+                StatusCode::FORBIDDEN,
+                "tenant_id does not match caller",
+            ))
+        }
+    }
+}
+
+/// The signed-in user, for self-service account endpoints.
+///
+/// Accepts a Bearer access token or the HttpOnly refresh-session cookie (the
+/// same session `/api/auth/mfa/step-up` accepts). No permission is required;
+/// the identity comes only from the verified credential, never from the
+/// request body.
+#[derive(Debug)]
+pub struct SessionUser {
+    pub user_id: Uuid,
+    pub tenant_id: Uuid,
+}
+
+impl FromRequestParts<Arc<AuthState>> for SessionUser {
+    type Rejection = (StatusCode, String);
 
-        // 3. Service-to-service tokens bypass fine-grained permission checks.
-        let is_service = claims.perms.iter().any(|p| p == "service.internal");
-        if is_service {
-            return Ok(AdminUsersRead { user_id, tenant_id });
+    async fn from_request_parts(
+        parts: &mut Parts,
+        state: &Arc<AuthState>,
+    ) -> Result<Self, Self::Rejection> {
+        if let Some(token) = bearer_token(parts) {
+            let (user_id, tenant_id, _) = verify_access_token(state, token)?;
+            return Ok(SessionUser { user_id, tenant_id });
         }
 
-        // 4. Resolve current effective permissions from DB.
-        //    Do NOT trust `claims.perms` — the JWT can drift behind role changes.
-        let perms = crate::db::rbac::effective_permissions_for_user(&state.db, tenant_id, user_id)
+        let raw =
+            super::cookies::read_refresh_cookie(&parts.headers).ok_or_else(unauthenticated)?;
+        let mut tx = state.db.begin().await.map_err(session_db_error)?;
+        let validated = super::refresh_sessions::find_and_validate(&mut tx, &raw)
             .await
-            .map_err(|e| {
-                tracing::error!(
-                    error = %e,
-                    user_id = %user_id,
-                    tenant_id = %tenant_id,
-                    "authz: db error resolving permissions for admin.users.read"
-                );
-                (StatusCode::INTERNAL_SERVER_ERROR, "db error".to_string())
-            })?;
-
-        if !perms.contains(&PERM.to_string()) {
-            return Err((
-                StatusCode::FORBIDDEN,
-                "insufficient permissions".to_string(),
-            ));
+            .map_err(session_db_error)?;
+        let _ = tx.rollback().await;
+        match validated {
+            Ok((_, tenant_id, user_id, _)) => Ok(SessionUser { user_id, tenant_id }),
+            Err(_) => Err(unauthenticated()),
         }
+    }
+}
 
-        Ok(AdminUsersRead { user_id, tenant_id })
+/// `Option<SessionUser>` is `None` only when the request carries no
+/// credential at all; a presented but invalid one is still rejected.
+impl OptionalFromRequestParts<Arc<AuthState>> for SessionUser {
+    type Rejection = (StatusCode, String);
+
+    async fn from_request_parts(
+        parts: &mut Parts,
+        state: &Arc<AuthState>,
+    ) -> Result<Option<Self>, Self::Rejection> {
+        if bearer_token(parts).is_none()
+            && super::cookies::read_refresh_cookie(&parts.headers).is_none()
+        {
+            return Ok(None);
+        }
+        <SessionUser as FromRequestParts<_>>::from_request_parts(parts, state)
+            .await
+            .map(Some)
     }
 }
+
+fn unauthenticated() -> (StatusCode, String) {
+    (
+        StatusCode::UNAUTHORIZED,
+        "missing or invalid authentication".to_string(),
+    )
+}
+
+fn session_db_error(e: sqlx::Error) -> (StatusCode, String) {
+    tracing::error!(error = %e, "authz: db error validating refresh session");
+    (StatusCode::INTERNAL_SERVER_ERROR, "db error".to_string())
+}
+
+fn bearer_token(parts: &Parts) -> Option<&str> {
+    parts
+        .headers
+        .get(header::AUTHORIZATION)
+        .and_then(|v| v.to_str().ok())
+        .and_then(|v| v.strip_prefix("Bearer "))
+}
+
+/// Verify an access token against the service's own keys.
+/// Returns `(user_id, tenant_id, is_service)`.
+fn verify_access_token(
+    state: &AuthState,
+    token: &str,
+) -> Result<(Uuid, Uuid, bool), (StatusCode, String)> {
+    let claims = state
+        .jwt
+        .validate_access_token(token)
+        .map_err(|_| unauthenticated())?;
+    let user_id = Uuid::parse_str(&claims.sub).map_err(|_| unauthenticated())?;
+    let tenant_id = Uuid::parse_str(&claims.tenant_id).map_err(|_| unauthenticated())?;
+    let is_service = claims.perms.iter().any(|p| p == "service.internal");
+    Ok((user_id, tenant_id, is_service))
+}
+
+/// Steps 1–4 of the module doc for `perm`; returns the caller's
+/// `(user_id, tenant_id)`.
+async fn require_permission(
+    parts: &Parts,
+    state: &AuthState,
+    perm: &str,
+) -> Result<(Uuid, Uuid), (StatusCode, String)> {
+    // 1–2. Bearer token, verified with the service's own keys.
+    let token = bearer_token(parts).ok_or_else(unauthenticated)?;
+    let (user_id, tenant_id, is_service) = verify_access_token(state, token)?;
+
+    // 3. Service-to-service tokens bypass fine-grained permission checks.
+    if is_service {
+        return Ok((user_id, tenant_id));
+    }
+
+    // 4. Resolve current effective permissions from DB.
+    //    Do NOT trust `claims.perms` — the JWT can drift behind role changes.
+    let perms = crate::db::rbac::effective_permissions_for_user(&state.db, tenant_id, user_id)
+        .await
+        .map_err(|e| {
+            tracing::error!(
+                error = %e,
+                user_id = %user_id,
+                tenant_id = %tenant_id,
+                "authz: db error resolving permissions for {perm}"
+            );
+            (StatusCode::INTERNAL_SERVER_ERROR, "db error".to_string())
+        })?;
+
+    if !perms.iter().any(|p| p == perm) {
+        return Err((
+            StatusCode::FORBIDDEN,
+            "insufficient permissions".to_string(),
+        ));
+    }
+
+    Ok((user_id, tenant_id))
+}
@@ -13,6 +13,7 @@ use axum::{
 };
 use chrono::Utc;
 use event_bus::EventEnvelope;
+use security::{ScopeGrant, SCOPE_BUSINESS_UNIT, SCOPE_DEPARTMENT, SCOPE_LOCATION};
 use serde::{Deserialize, Serialize};
 use sqlx::{PgPool, Row};
 use std::sync::Arc;
@@ -21,7 +22,7 @@ use uuid::Uuid;
 use super::{
     concurrency::HashConcurrencyLimiter,
     cookies,
-    jwt::{self, JwtKeys},
+    jwt::{self, JwtKeys, SessionAuth},
     password::{hash_password, verify_password, PasswordPolicy},
     password_policy::{validate_password, PasswordRules},
     refresh::{generate_refresh_token, hash_refresh_token},
@@ -70,6 +71,12 @@ pub struct AuthState {
     // Entitlement client — fetches concurrent_user_limit from tenant-registry with TTL cache.
     // None means use max_concurrent_sessions unconditionally.
     pub tenant_registry: Option<TenantRegistryClient>,
+
+    // TOTP / WebAuthn second factor settings
+    pub mfa: super::handlers_mfa::MfaSettings,
+
+    // Per-tenant OIDC single sign-on settings
+    pub sso: super::handlers_sso::SsoSettings,
 }
 
 pub(super) type ApiErr = (StatusCode, HeaderMap, String);
@@ -688,10 +695,10 @@ pub async fn register(
     security(()),
     request_body = LoginReq,
     responses(
-        (status = 200, description = "Login successful", body = TokenResponse),
+        (status = 200, description = "Login successful, or an MFA challenge (`mfa_required: true`) to answer at /api/auth/mfa/verify", body = TokenResponse),
         (status = 400, description = "Invalid email"),
         (status = 401, description = "Invalid credentials"),
-        (status = 403, description = "Account inactive, locked, or tenant suspended"),
+        (status = 403, description = "Account inactive, tenant suspended, or password login disabled for SSO"),
         (status = 429, description = "Rate limited"),
         (status = 503, description = "Service busy or seat limit reached"),
     ))]
@@ -699,7 +706,7 @@ pub async fn login(
     State(state): State<Arc<AuthState>>,
     extensions: Extensions,
     Json(req): Json<LoginReq>,
-) -> Result<impl IntoResponse, ApiErr> {
+) -> Result<axum::response::Response, ApiErr> {
     let trace_id = get_trace_id_from_extensions(&extensions);
 
     let email = req.email.trim().to_lowercase();
@@ -735,6 +742,29 @@ pub async fn login(
         ));
     }
 
+    // Federated tenants may turn password login off entirely.
+    let sso_only = crate::db::oidc::password_login_disabled(&state.db, req.tenant_id)
+        .await
+        .map_err(|e| {
+            state
+                .metrics
+                .auth_login_total
+                .with_label_values(&["failure", "db_error"])
+                .inc();
+            err(StatusCode::INTERNAL_SERVER_ERROR, format!("db error: {e}"))
+        })?;
+    if sso_only {
+        state
+            .metrics
+            .auth_login_total
+            .with_label_values(&["failure", "sso_required"])
+            .inc();
+        return Err(err(
+            StatusCode::FORBIDDEN,
+            "password login disabled; use single sign-on",
+        ));
+    }
+
     let row = sqlx::query(
         r#"
         SELECT user_id, password_hash, is_active, failed_login_count, lock_until
@@ -878,8 +908,61 @@ pub async fn login(
     .execute(&state.db)
     .await;
 
+    // Second factor: enrolled users, and users a tenant policy covers, must
+    // answer a challenge before any session is opened.
+    let mfa_status = crate::db::mfa::status(&state.db, req.tenant_id, user_id)
+        .await
+        .map_err(|e| {
+            state
+                .metrics
+                .auth_login_total
+                .with_label_values(&["failure", "db_error"])
+                .inc();
+            err(
+                StatusCode::INTERNAL_SERVER_ERROR,
+                format!("mfa status: {e}"),
+            )
+        })?;
+    if mfa_status.challenge_required() {
+        state
+            .metrics
+            .auth_login_total
+            .with_label_values(&["mfa_required", "challenge"])
+            .inc();
+        return super::handlers_mfa::begin_login_challenge(
+            &state,
+            req.tenant_id,
+            user_id,
+            &mfa_status,
+        )
+        .await;
+    }
+
+    open_login_session(
+        &state,
+        &extensions,
+        req.tenant_id,
+        user_id,
+        SessionAuth::password(Utc::now()),
+        trace_id,
+    )
+    .await
+}
+
+/// Open a session for an authenticated user: seat-limit and tenant gates,
+/// refresh token + sliding session rows, access token and login events.
+///
+/// Shared by password login and MFA challenge completion.
+pub(super) async fn open_login_session(
+    state: &Arc<AuthState>,
+    extensions: &Extensions,
+    tenant_id: Uuid,
+    user_id: Uuid,
+    auth: SessionAuth,
+    trace_id: String,
+) -> Result<axum::response::Response, ApiErr> {
     // Resolve RBAC roles and effective permissions for token embedding
-    let roles = crate::db::rbac::list_roles_for_user(&state.db, req.tenant_id, user_id)
+    let roles = crate::db::rbac::list_roles_for_user(&state.db, tenant_id, user_id)
         .await
         .map_err(|e| {
             state
@@ -896,7 +979,7 @@ pub async fn login(
         .map(|r| r.name)
         .collect::<Vec<_>>();
 
-    let perms = crate::db::rbac::effective_permissions_for_user(&state.db, req.tenant_id, user_id)
+    let perms = crate::db::rbac::effective_permissions_for_user(&state.db, tenant_id, user_id)
         .await
         .map_err(|e| {
             state
@@ -910,6 +993,20 @@ pub async fn login(
             )
         })?;
 
+    let scopes = crate::db::rbac::effective_scopes_for_user(&state.db, tenant_id, user_id)
+        .await
+        .map_err(|e| {
+            state
+                .metrics
+                .auth_login_total
+                .with_label_values(&["failure", "db_error"])
+                .inc();
+            err(
+                StatusCode::INTERNAL_SERVER_ERROR,
+                format!("rbac scopes: {e}"),
+            )
+        })?;
+
     let role_snapshot_id = jwt::compute_role_snapshot_id(&roles);
 
     let refresh_raw = generate_refresh_token();
@@ -926,7 +1023,7 @@ pub async fn login(
         err(StatusCode::INTERNAL_SERVER_ERROR, format!("db error: {e}"))
     })?;
 
-    super::concurrency::acquire_tenant_xact_lock(&mut tx, req.tenant_id)
+    super::concurrency::acquire_tenant_xact_lock(&mut tx, tenant_id)
         .await
         .map_err(|e| {
             use super::concurrency::AcquireError::*;
@@ -945,7 +1042,7 @@ pub async fn login(
 
     // Tenant lifecycle gate: deny login for suspended/canceled tenants; deny new login for past_due.
     if let Some(client) = &state.tenant_registry {
-        match client.get_tenant_gate(req.tenant_id, &state.metrics).await {
+        match client.get_tenant_gate(tenant_id, &state.metrics).await {
             Ok(TenantGate::Allow) => {}
             Ok(TenantGate::DenyNewLogin { status }) => {
                 state
@@ -954,7 +1051,7 @@ pub async fn login(
                     .with_label_values(&[&status])
                     .inc();
                 tracing::warn!(
-                    tenant_id = %req.tenant_id,
+                    tenant_id = %tenant_id,
                     user_id = %user_id,
                     status = %status,
                     trace_id = %trace_id,
@@ -970,7 +1067,7 @@ pub async fn login(
                     .with_label_values(&[&status])
                     .inc();
                 tracing::warn!(
-                    tenant_id = %req.tenant_id,
+                    tenant_id = %tenant_id,
                     user_id = %user_id,
                     status = %status,
                     trace_id = %trace_id,
@@ -986,7 +1083,7 @@ pub async fn login(
                     .with_label_values(&["unavailable"])
                     .inc();
                 tracing::warn!(
-                    tenant_id = %req.tenant_id,
+                    tenant_id = %tenant_id,
                     user_id = %user_id,
                     trace_id = %trace_id,
                     "auth.tenant_status_unavailable_deny"
@@ -1000,7 +1097,7 @@ pub async fn login(
         }
     }
 
-    let active = super::concurrency::count_active_leases_in_tx(&mut tx, req.tenant_id)
+    let active = super::concurrency::count_active_leases_in_tx(&mut tx, tenant_id)
         .await
         .map_err(|e| {
             state
@@ -1021,7 +1118,7 @@ pub async fn login(
     let seat_limit = match &state.tenant_registry {
         Some(client) => {
             match client
-                .get_concurrent_user_limit(req.tenant_id, &state.metrics)
+                .get_concurrent_user_limit(tenant_id, &state.metrics)
                 .await
             {
                 Ok(limit) => limit,
@@ -1032,7 +1129,7 @@ pub async fn login(
                         .with_label_values(&["failure", "entitlement_unavailable"])
                         .inc();
                     tracing::warn!(
-                        tenant_id = %req.tenant_id,
+                        tenant_id = %tenant_id,
                         user_id = %user_id,
                         trace_id = %trace_id,
                         "auth.entitlement_unavailable_deny"
@@ -1055,7 +1152,7 @@ pub async fn login(
             .with_label_values(&["failure", "seat_limit"])
             .inc();
         tracing::warn!(
-            tenant_id = %req.tenant_id,
+            tenant_id = %tenant_id,
             user_id = %user_id,
             active_seats = active,
             limit = seat_limit,
@@ -1071,15 +1168,17 @@ pub async fn login(
 
     let new_token_id: Uuid = sqlx::query(
         r#"
-        INSERT INTO refresh_tokens (tenant_id, user_id, token_hash, expires_at)
-        VALUES ($1, $2, $3, $4)
+        INSERT INTO refresh_tokens (tenant_id, user_id, token_hash, expires_at, amr, auth_time)
+        VALUES ($1, $2, $3, $4, $5, $6)
         RETURNING id
         "#,
     )
-    .bind(req.tenant_id)
+    .bind(tenant_id)
     .bind(user_id)
     .bind(&refresh_hash)
     .bind(expires_at)
+    .bind(&auth.amr)
+    .bind(auth.auth_time)
     .fetch_one(&mut *tx)
     .await
     .map_err(|e| {
@@ -1096,7 +1195,7 @@ pub async fn login(
     // legacy refresh_tokens row so apps can opt in to silent refresh without
     // breaking consumers that still read refresh_token from the body.
     let device_info = {
-        let client = crate::middleware::client_ip::get_client_meta(&extensions);
+        let client = crate::middleware::client_ip::get_client_meta(extensions);
         let mut m = serde_json::Map::new();
         if let Some(c) = client {
             m.insert("ip".to_string(), serde_json::Value::String(c.ip));
@@ -1110,7 +1209,7 @@ pub async fn login(
     let (session_id, session_raw_token, session_expires_at, session_absolute_expires_at) =
         refresh_sessions::create_session(
             &mut tx,
-            req.tenant_id,
+            tenant_id,
             user_id,
             device_info,
             state.refresh_idle_minutes,
@@ -1129,7 +1228,21 @@ pub async fn login(
             )
         })?;
 
-    super::concurrency::create_lease_in_tx(&mut tx, req.tenant_id, user_id, new_token_id)
+    refresh_sessions::set_auth(&mut tx, session_id, &auth)
+        .await
+        .map_err(|e| {
+            state
+                .metrics
+                .auth_login_total
+                .with_label_values(&["failure", "db_error"])
+                .inc();
+            err(
+                StatusCode::INTERNAL_SERVER_ERROR,
+                format!("session auth: {e}"),
+            )
+        })?;
+
+    super::concurrency::create_lease_in_tx(&mut tx, tenant_id, user_id, new_token_id)
         .await
         .map_err(|e| {
             state
@@ -1146,10 +1259,12 @@ pub async fn login(
     let access = state
         .jwt
         .sign_access_token_enriched(
-            req.tenant_id,
+            tenant_id,
             user_id,
             roles,
             perms,
+            scopes,
+            &auth,
             jwt::actor_type::USER,
             state.access_ttl_minutes,
             Some(new_token_id),
@@ -1184,7 +1299,7 @@ pub async fn login(
         user_id: String,
     }
     let env = EventEnvelope::new(
-        req.tenant_id.to_string(),
+        tenant_id.to_string(),
         state.producer.clone(),
         "auth.user_logged_in".to_string(),
         Data {
@@ -1219,7 +1334,7 @@ pub async fn login(
         absolute_expires_at: String,
     }
     let session_env = EventEnvelope::new(
-        req.tenant_id.to_string(),
+        tenant_id.to_string(),
         state.producer.clone(),
         "identity_auth.session_created".to_string(),
         SessionCreatedData {
@@ -1269,7 +1384,8 @@ pub async fn login(
             expires_in_seconds: state.access_ttl_minutes * 60,
             refresh_token: refresh_raw,
         }),
-    ))
+    )
+        .into_response())
 }
 
 // ---------------------------------------------------------------------------
@@ -1371,6 +1487,160 @@ pub async fn list_permissions(
     Ok((StatusCode::OK, Json(perms)))
 }
 
+// ---------------------------------------------------------------------------
+// POST /api/auth/admin/role-bindings — grant a role, optionally data-scoped
+// ---------------------------------------------------------------------------
+
+/// Attributes a role grant may be scoped by.
+const SCOPE_ATTRIBUTES: [&str; 3] = [SCOPE_LOCATION, SCOPE_DEPARTMENT, SCOPE_BUSINESS_UNIT];
+
+#[derive(Debug, Deserialize, utoipa::ToSchema)]
+pub struct BindRoleReq {
+    /// Must be the calling administrator's tenant.
+    pub tenant_id: Uuid,
+    pub user_id: Uuid,
+    pub role_id: Uuid,
+    /// Restricts the role's permissions to these attribute values, e.g.
+    /// `{"location": ["WH-EAST"]}`. Omit for a tenant-wide grant.
+    #[schema(value_type = Option<Object>)]
+    pub scope: Option<ScopeGrant>,
+}
+
+#[derive(Debug, Serialize, utoipa::ToSchema)]
+pub struct RoleBindingResponse {
+    pub id: Uuid,
+    pub tenant_id: Uuid,
+    pub user_id: Uuid,
+    pub role_id: Uuid,
+    #[schema(value_type = Option<Object>)]
+    pub scope: Option<ScopeGrant>,
+    pub granted_by: Option<Uuid>,
+    pub granted_at: chrono::DateTime<Utc>,
+}
+
+/// Normalise a requested scope: known attributes only, each with at least one
+/// non-blank value; values are trimmed and de-duplicated.
+fn validate_scope_grant(scope: &ScopeGrant) -> Result<ScopeGrant, String> {
+    if scope.is_empty() {
+        return Err(
+            "scope must name at least one attribute; omit it for a tenant-wide grant".into(),
+        );
+    }
+    let mut normalised = ScopeGrant::new();
+    for (attr, values) in scope {
+        if !SCOPE_ATTRIBUTES.contains(&attr.as_str()) {
+            return Err(format!(
+                "unknown scope attribute '{attr}'; expected one of {}",
+                SCOPE_ATTRIBUTES.join(", ")
+            ));
+        }
+        let mut cleaned: Vec<String> = values
+            .iter()
+            .map(|v| v.trim())
+            .filter(|v| !v.is_empty())
+            .map(str::to_string)
+            .collect();
+        cleaned.sort();
+        cleaned.dedup();
+        if cleaned.is_empty() {
+            return Err(format!("scope attribute '{attr}' has no values"));
+        }
+        normalised.insert(attr.clone(), cleaned);
+    }
+    Ok(normalised)
+}
+
+#[utoipa::path(post, path = "/api/auth/admin/role-bindings", tag = "RBAC",
+    request_body = BindRoleReq,
+    responses(
+        (status = 200, description = "Role granted; re-granting replaces the previous scope", body = RoleBindingResponse),
+        (status = 400, description = "Invalid scope"),
+        (status = 401, description = "Unauthenticated"),
+        (status = 403, description = "Missing admin.security.write, or another tenant"),
+        (status = 404, description = "Unknown role or user in the tenant"),
+        (status = 500, description = "Internal error"),
+    ),
+    security(("bearer" = [])))]
+pub async fn bind_role(
+    State(state): State<Arc<AuthState>>,
+    admin: crate::auth::authz::AdminSecurityWrite,
+    extensions: Extensions,
+    Json(req): Json<BindRoleReq>,
+) -> Result<impl IntoResponse, ApiErr> {
+    admin.ensure_tenant(req.tenant_id)?;
+    let scope = req
+        .scope
+        .as_ref()
+        .map(validate_scope_grant)
+        .transpose()
+        .map_err(|e| err(StatusCode::BAD_REQUEST, e))?;
+
+    let db_err = |e: sqlx::Error| err(StatusCode::INTERNAL_SERVER_ERROR, format!("db error: {e}"));
+    if crate::db::rbac::get_role(&state.db, req.tenant_id, req.role_id)
+        .await
+        .map_err(db_err)?
+        .is_none()
+    {
+        return Err(err(StatusCode::NOT_FOUND, "role not found"));
+    }
+    let user_exists: bool = sqlx::query_scalar(
+        "SELECT EXISTS (SELECT 1 FROM credentials WHERE tenant_id = $1 AND user_id = $2)",
+    )
+    .bind(req.tenant_id)
+    .bind(req.user_id)
+    .fetch_one(&state.db)
+    .await
+    .map_err(db_err)?;
+    if !user_exists {
+        return Err(err(StatusCode::NOT_FOUND, "user not found"));
+    }
+
+    let ctx = crate::db::user_lifecycle_audit::LifecycleAuditContext {
+        producer: state.producer.clone(),
+        trace_id: get_trace_id_from_extensions(&extensions),
+        causation_id: None,
+        idempotency_key: format!(
+            "role-bind:{}:{}:{}:{}",
+            req.tenant_id,
+            req.user_id,
+            req.role_id,
+            Uuid::new_v4()
+        ),
+    };
+    let binding = crate::db::rbac::bind_user_role_scoped_with_audit(
+        &state.db,
+        req.tenant_id,
+        req.user_id,
+        req.role_id,
+        scope.as_ref(),
+        Some(admin.user_id),
+        &ctx,
+    )
+    .await
+    .map_err(db_err)?;
+
+    tracing::info!(
+        tenant_id = %req.tenant_id,
+        user_id = %req.user_id,
+        role_id = %req.role_id,
+        scoped = binding.scope.is_some(),
+        granted_by = %admin.user_id,
+        "auth.role_bound"
+    );
+    Ok((
+        StatusCode::OK,
+        Json(RoleBindingResponse {
+            id: binding.id,
+            tenant_id: binding.tenant_id,
+            user_id: binding.user_id,
+            role_id: binding.role_id,
+            scope: binding.scope.map(|s| s.0),
+            granted_by: binding.granted_by,
+            granted_at: binding.granted_at,
+        }),
+    ))
+}
+
 // ---------------------------------------------------------------------------
 // GET /api/auth/admin/users — list users within a tenant (admin only)
 // ---------------------------------------------------------------------------
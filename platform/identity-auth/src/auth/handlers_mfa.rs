@@ -0,0 +1,1197 @@
+//! Multi-factor authentication endpoints.
+//!
+//! Flow:
+//! 1. `POST /api/auth/login` answers with an [`MfaChallengeResponse`] instead
+//!    of tokens when the user has an enrolled factor or a tenant policy
+//!    requires MFA for them.
+//! 2. Users without a factor enroll one (TOTP or WebAuthn) using the
+//!    challenge's `mfa_token`; enrolled users manage their factors from a
+//!    signed-in session (Bearer access token or refresh-session cookie).
+//!    Resets and tenant policy require `admin.security.write`.
+//! 3. `POST /api/auth/mfa/verify` redeems the `mfa_token` with a TOTP code,
+//!    recovery code or WebAuthn assertion and returns the session tokens
+//!    (`amr` = `pwd` + `otp`|`hwk` + `mfa`).
+//! 4. `POST /api/auth/mfa/step-up` re-challenges an existing session. Verifying
+//!    it re-stamps the session's `auth_time`, so the access token it returns
+//!    (and later refreshes) pass `require_recent_mfa` gates.
+
+// Sync helpers return the handlers' `ApiErr` (which carries a HeaderMap).
+#![allow(clippy::result_large_err)]
+
+use crate::{
+    db::mfa::{self as repo, ChallengePurpose, MfaChallenge, MfaPolicy, MfaStatus, NewChallenge},
+    db::user_lifecycle_audit::{
+        append_lifecycle_event_tx, LifecycleAuditContext, LifecycleEventType,
+    },
+    middleware::tracing::get_trace_id_from_extensions,
+};
+use axum::{
+    extract::{Path, State},
+    http::{Extensions, HeaderMap, StatusCode},
+    response::{IntoResponse, Response},
+    Json,
+};
+use chrono::{DateTime, Utc};
+use security::claims::amr;
+use serde::{Deserialize, Serialize};
+use sqlx::{PgPool, Postgres, Row, Transaction};
+use std::sync::Arc;
+use uuid::Uuid;
+
+use super::{
+    authz::{AdminSecurityWrite, SessionUser},
+    cookies,
+    handlers::{err, open_login_session, ApiErr, AuthState, OkResponse},
+    jwt::{self, SessionAuth},
+    refresh::{generate_refresh_token, hash_refresh_token},
+    refresh_sessions,
+    sealed::SecretKey,
+    session::AccessTokenResponse,
+    totp,
+    webauthn::{self, RelyingParty},
+};
+
+/// MFA configuration resolved at startup.
+#[derive(Clone)]
+pub struct MfaSettings {
+    /// Seals TOTP secrets at rest; TOTP endpoints answer 503 without it.
+    pub secret_key: Option<SecretKey>,
+    pub totp_issuer: String,
+    pub challenge_ttl_seconds: i64,
+    /// WebAuthn endpoints answer 503 when no relying party is configured.
+    pub webauthn: Option<RelyingParty>,
+}
+
+impl Default for MfaSettings {
+    fn default() -> Self {
+        Self {
+            secret_key: None,
+            totp_issuer: "7D Platform".to_string(),
+            challenge_ttl_seconds: 300,
+            webauthn: None,
+        }
+    }
+}
+
+// ── Request / response types ────────────────────────────────────────────────
+
+/// Identifies the user whose factors are managed when the caller has no
+/// session yet: the `mfa_token` of a pending login challenge (first
+/// enrollment only). Omit it to act on the signed-in session's user.
+#[derive(Debug, Default, Deserialize, utoipa::ToSchema)]
+pub struct MfaSubject {
+    #[serde(default)]
+    pub mfa_token: Option<String>,
+}
+
+#[derive(Debug, Serialize, utoipa::ToSchema)]
+pub struct MfaChallengeResponse {
+    pub mfa_required: bool,
+    /// Opaque, single-use; redeem at `/api/auth/mfa/verify`.
+    pub mfa_token: String,
+    pub expires_in_seconds: i64,
+    /// Methods able to answer this challenge (`totp`, `webauthn`, `recovery_code`).
+    pub methods: Vec<String>,
+    /// True when policy requires MFA but the user has no factor yet.
+    pub enrollment_required: bool,
+    /// `PublicKeyCredentialRequestOptions` for `navigator.credentials.get()`.
+    #[schema(value_type = Option<Object>)]
+    pub webauthn: Option<serde_json::Value>,
+}
+
+#[derive(Debug, Serialize, utoipa::ToSchema)]
+pub struct TotpEnrollResponse {
+    /// Base32 secret for manual entry.
+    pub secret: String,
+    pub otpauth_uri: String,
+}
+
+#[derive(Debug, Deserialize, utoipa::ToSchema)]
+pub struct TotpConfirmReq {
+    #[serde(flatten)]
+    pub subject: MfaSubject,
+    pub code: String,
+}
+
+#[derive(Debug, Serialize, utoipa::ToSchema)]
+pub struct RecoveryCodesResponse {
+    /// Shown once. Empty when the user already holds unused codes.
+    pub recovery_codes: Vec<String>,
+}
+
+#[derive(Debug, Deserialize, utoipa::ToSchema)]
+pub struct MfaResetReq {
+    /// Must be the calling administrator's tenant.
+    pub tenant_id: Uuid,
+    pub user_id: Uuid,
+    pub reason: Option<String>,
+}
+
+#[derive(Debug, Deserialize, utoipa::ToSchema)]
+pub struct MfaPolicyReq {
+    /// Must be the calling administrator's tenant.
+    pub tenant_id: Uuid,
+    /// `off` | `all_users` | `roles`
+    pub mode: String,
+    #[serde(default)]
+    pub role_ids: Vec<Uuid>,
+}
+
+#[derive(Debug, Serialize, utoipa::ToSchema)]
+pub struct WebAuthnRegistrationOptions {
+    pub challenge_id: Uuid,
+    /// `PublicKeyCredentialCreationOptions` for `navigator.credentials.create()`.
+    #[schema(value_type = Object)]
+    pub public_key: serde_json::Value,
+}
+
+#[derive(Debug, Deserialize, utoipa::ToSchema)]
+pub struct WebAuthnRegisterReq {
+    #[serde(flatten)]
+    pub subject: MfaSubject,
+    pub challenge_id: Uuid,
+    /// base64url `response.clientDataJSON`
+    pub client_data_json: String,
+    /// base64url `response.attestationObject`
+    pub attestation_object: String,
+    pub name: Option<String>,
+}
+
+#[derive(Debug, Serialize, utoipa::ToSchema)]
+pub struct WebAuthnRegisterResponse {
+    pub credential: repo::WebAuthnCredentialInfo,
+    pub recovery_codes: Vec<String>,
+}
+
+/// base64url fields of a `navigator.credentials.get()` response.
+#[derive(Debug, Deserialize, utoipa::ToSchema)]
+pub struct WebAuthnAssertion {
+    pub credential_id: String,
+    pub client_data_json: String,
+    pub authenticator_data: String,
+    pub signature: String,
+}
+
+#[derive(Debug, Deserialize, utoipa::ToSchema)]
+pub struct MfaVerifyReq {
+    pub mfa_token: String,
+    /// `totp` | `webauthn` | `recovery_code`
+    pub method: String,
+    /// TOTP or recovery code.
+    pub code: Option<String>,
+    pub webauthn: Option<WebAuthnAssertion>,
+}
+
+#[derive(Debug, Deserialize, utoipa::ToSchema)]
+pub struct StepUpReq {
+    pub refresh_token: String,
+}
+
+// ── Helpers ─────────────────────────────────────────────────────────────────
+
+fn db_err(e: sqlx::Error) -> ApiErr {
+    err(StatusCode::INTERNAL_SERVER_ERROR, format!("db error: {e}"))
+}
+
+fn totp_key(state: &AuthState) -> Result<&SecretKey, ApiErr> {
+    state
+        .mfa
+        .secret_key
+        .as_ref()
+        .ok_or_else(|| err(StatusCode::SERVICE_UNAVAILABLE, "totp not configured"))
+}
+
+fn relying_party(state: &AuthState) -> Result<&RelyingParty, ApiErr> {
+    state
+        .mfa
+        .webauthn
+        .as_ref()
+        .ok_or_else(|| err(StatusCode::SERVICE_UNAVAILABLE, "webauthn not configured"))
+}
+
+fn decode_b64url(field: &str, value: &str) -> Result<Vec<u8>, ApiErr> {
+    webauthn::b64url_decode(value)
+        .ok_or_else(|| err(StatusCode::BAD_REQUEST, format!("{field} is not base64url")))
+}
+
+/// Resolve the managed user from an authenticated credential only: the
+/// `mfa_token` of a live login challenge, or the caller's session. An
+/// `mfa_token` may only be used to enroll a first factor; once enrolled, the
+/// login challenge must be answered.
+async fn resolve_subject(
+    state: &AuthState,
+    session: Option<SessionUser>,
+    subject: &MfaSubject,
+) -> Result<(Uuid, Uuid), ApiErr> {
+    if let Some(token) = &subject.mfa_token {
+        let mut tx = state.db.begin().await.map_err(db_err)?;
+        let challenge = repo::challenge_by_token_for_update(&mut tx, &hash_refresh_token(token))
+            .await
+            .map_err(db_err)?;
+        let _ = tx.rollback().await;
+        let challenge = challenge
+            .filter(|c| c.purpose == ChallengePurpose::Login && c.is_live())
+            .ok_or_else(|| err(StatusCode::UNAUTHORIZED, "invalid or expired mfa_token"))?;
+
+        let status = repo::status(&state.db, challenge.tenant_id, challenge.user_id)
+            .await
+            .map_err(db_err)?;
+        if status.enrolled() {
+            return Err(err(
+                StatusCode::FORBIDDEN,
+                "mfa already enrolled; answer the login challenge",
+            ));
+        }
+        return Ok((challenge.tenant_id, challenge.user_id));
+    }
+
+    session
+        .map(|s| (s.tenant_id, s.user_id))
+        .ok_or_else(|| err(StatusCode::UNAUTHORIZED, "mfa_token or session required"))
+}
+
+async fn account_email(pool: &PgPool, tenant_id: Uuid, user_id: Uuid) -> Result<String, ApiErr> {
+    sqlx::query("SELECT email FROM credentials WHERE tenant_id = $1 AND user_id = $2")
+        .bind(tenant_id)
+        .bind(user_id)
+        .fetch_optional(pool)
+        .await
+        .map_err(db_err)?
+        .map(|r| r.get("email"))
+        .ok_or_else(|| err(StatusCode::NOT_FOUND, "user not found"))
+}
+
+/// Generate recovery codes when the user holds none, returning them in clear
+/// (they are only ever shown once).
+async fn issue_recovery_codes_if_missing(
+    tx: &mut Transaction<'_, Postgres>,
+    status: &MfaStatus,
+    tenant_id: Uuid,
+    user_id: Uuid,
+) -> Result<Vec<String>, ApiErr> {
+    if status.recovery_codes_remaining > 0 {
+        return Ok(Vec::new());
+    }
+    let codes = totp::generate_recovery_codes();
+    let hashes: Vec<String> = codes.iter().map(|c| totp::hash_recovery_code(c)).collect();
+    repo::replace_recovery_codes(tx, tenant_id, user_id, &hashes)
+        .await
+        .map_err(db_err)?;
+    Ok(codes)
+}
+
+#[allow(clippy::too_many_arguments)]
+async fn record_lifecycle(
+    tx: &mut Transaction<'_, Postgres>,
+    state: &AuthState,
+    tenant_id: Uuid,
+    user_id: Uuid,
+    event_type: LifecycleEventType,
+    actor_user_id: Option<Uuid>,
+    payload: serde_json::Value,
+    trace_id: String,
+) -> Result<(), ApiErr> {
+    let ctx = LifecycleAuditContext {
+        producer: state.producer.clone(),
+        trace_id,
+        causation_id: None,
+        idempotency_key: format!("mfa:{}:{}", user_id, Uuid::new_v4()),
+    };
+    append_lifecycle_event_tx(
+        tx,
+        tenant_id,
+        user_id,
+        event_type,
+        actor_user_id,
+        None,
+        None,
+        None,
+        payload,
+        &ctx,
+    )
+    .await
+    .map_err(db_err)?;
+    Ok(())
+}
+
+fn request_options(
+    rp: &RelyingParty,
+    challenge: &[u8],
+    credential_ids: &[Vec<u8>],
+    ttl: i64,
+) -> serde_json::Value {
+    serde_json::json!({
+        "challenge": webauthn::b64url(challenge),
+        "rpId": rp.id,
+        "timeout": ttl * 1000,
+        "userVerification": "preferred",
+        "allowCredentials": credential_ids
+            .iter()
+            .map(|id| serde_json::json!({ "type": "public-key", "id": webauthn::b64url(id) }))
+            .collect::<Vec<_>>(),
+    })
+}
+
+/// Create a login or step-up challenge and answer with its `mfa_token`.
+async fn issue_challenge(
+    state: &AuthState,
+    tenant_id: Uuid,
+    user_id: Uuid,
+    status: &MfaStatus,
+    purpose: ChallengePurpose,
+    refresh_token_id: Option<Uuid>,
+    refresh_session_id: Option<Uuid>,
+) -> Result<Response, ApiErr> {
+    let ttl = state.mfa.challenge_ttl_seconds;
+    let mut methods = status.methods();
+
+    // A WebAuthn challenge is offered to users holding credentials, and to
+    // users who still have to enroll (so a passkey registered with this
+    // mfa_token can answer the same challenge).
+    let webauthn = match &state.mfa.webauthn {
+        Some(rp) if status.webauthn_credentials > 0 || !status.enrolled() => {
+            let challenge = webauthn::generate_challenge();
+            let ids = repo::webauthn_credential_ids(&state.db, tenant_id, user_id)
+                .await
+                .map_err(db_err)?;
+            let options = request_options(rp, &challenge, &ids, ttl);
+            Some((challenge, options))
+        }
+        _ => {
+            methods.retain(|m| m != repo::method::WEBAUTHN);
+            None
+        }
+    };
+
+    let raw = generate_refresh_token();
+    let token_hash = hash_refresh_token(&raw);
+    repo::create_challenge(
+        &state.db,
+        tenant_id,
+        user_id,
+        NewChallenge {
+            purpose,
+            token_hash: Some(&token_hash),
+            webauthn_challenge: webauthn.as_ref().map(|(c, _)| c.as_slice()),
+            refresh_token_id,
+            refresh_session_id,
+            ttl_seconds: ttl,
+        },
+    )
+    .await
+    .map_err(db_err)?;
+
+    Ok((
+        StatusCode::OK,
+        Json(MfaChallengeResponse {
+            mfa_required: true,
+            mfa_token: raw,
+            expires_in_seconds: ttl,
+            methods,
+            enrollment_required: !status.enrolled(),
+            webauthn: webauthn.map(|(_, options)| options),
+        }),
+    )
+        .into_response())
+}
+
+/// Called by `login` after the password check when a second factor is due.
+pub(super) async fn begin_login_challenge(
+    state: &Arc<AuthState>,
+    tenant_id: Uuid,
+    user_id: Uuid,
+    status: &MfaStatus,
+) -> Result<Response, ApiErr> {
+    issue_challenge(
+        state,
+        tenant_id,
+        user_id,
+        status,
+        ChallengePurpose::Login,
+        None,
+        None,
+    )
+    .await
+}
+
+// ── TOTP enrollment ─────────────────────────────────────────────────────────
+
+#[utoipa::path(post, path = "/api/auth/mfa/totp/enroll", tag = "MFA",
+    security((), ("bearer" = [])),
+    request_body = MfaSubject,
+    responses(
+        (status = 200, description = "Pending TOTP secret; confirm with a code", body = TotpEnrollResponse),
+        (status = 401, description = "Invalid or expired mfa_token, and no session"),
+        (status = 409, description = "TOTP already enrolled"),
+        (status = 503, description = "TOTP not configured"),
+    ))]
+pub async fn totp_enroll(
+    State(state): State<Arc<AuthState>>,
+    session: Option<SessionUser>,
+    Json(req): Json<MfaSubject>,
+) -> Result<impl IntoResponse, ApiErr> {
+    let key = totp_key(&state)?;
+    let (tenant_id, user_id) = resolve_subject(&state, session, &req).await?;
+    let email = account_email(&state.db, tenant_id, user_id).await?;
+
+    let secret = totp::generate_secret();
+    let stored = repo::upsert_pending_totp(&state.db, tenant_id, user_id, &key.seal(&secret))
+        .await
+        .map_err(db_err)?;
+    if !stored {
+        return Err(err(StatusCode::CONFLICT, "totp already enrolled"));
+    }
+
+    Ok((
+        StatusCode::OK,
+        Json(TotpEnrollResponse {
+            secret: totp::encode_secret(&secret),
+            otpauth_uri: totp::provisioning_uri(&secret, &state.mfa.totp_issuer, &email),
+        }),
+    ))
+}
+
+#[utoipa::path(post, path = "/api/auth/mfa/totp/confirm", tag = "MFA",
+    security((), ("bearer" = [])),
+    request_body = TotpConfirmReq,
+    responses(
+        (status = 200, description = "TOTP enrolled; recovery codes issued on first enrollment", body = RecoveryCodesResponse),
+        (status = 400, description = "Invalid code"),
+        (status = 404, description = "No pending enrollment"),
+        (status = 409, description = "TOTP already enrolled"),
+    ))]
+pub async fn totp_confirm(
+    State(state): State<Arc<AuthState>>,
+    extensions: Extensions,
+    session: Option<SessionUser>,
+    Json(req): Json<TotpConfirmReq>,
+) -> Result<impl IntoResponse, ApiErr> {
+    let trace_id = get_trace_id_from_extensions(&extensions);
+    let key = totp_key(&state)?;
+    let (tenant_id, user_id) = resolve_subject(&state, session, &req.subject).await?;
+    let status = repo::status(&state.db, tenant_id, user_id)
+        .await
+        .map_err(db_err)?;
+
+    let mut tx = state.db.begin().await.map_err(db_err)?;
+    let factor = repo::totp_factor_for_update(&mut tx, tenant_id, user_id)
+        .await
+        .map_err(db_err)?
+        .ok_or_else(|| err(StatusCode::NOT_FOUND, "no pending totp enrollment"))?;
+    if factor.confirmed_at.is_some() {
+        return Err(err(StatusCode::CONFLICT, "totp already enrolled"));
+    }
+    let secret = key
+        .open(&factor.secret_enc)
+        .map_err(|e| err(StatusCode::INTERNAL_SERVER_ERROR, e))?;
+    let step = totp::verify(
+        &secret,
+        &req.code,
+        Utc::now().timestamp(),
+        factor.last_used_step,
+    )
+    .ok_or_else(|| err(StatusCode::BAD_REQUEST, "invalid code"))?;
+
+    repo::record_totp_use(&mut tx, tenant_id, user_id, step)
+        .await
+        .map_err(db_err)?;
+    let recovery_codes =
+        issue_recovery_codes_if_missing(&mut tx, &status, tenant_id, user_id).await?;
+    record_lifecycle(
+        &mut tx,
+        &state,
+        tenant_id,
+        user_id,
+        LifecycleEventType::MfaEnrolled,
+        Some(user_id),
+        serde_json::json!({ "method": repo::method::TOTP }),
+        trace_id,
+    )
+    .await?;
+    tx.commit().await.map_err(db_err)?;
+
+    Ok((
+        StatusCode::OK,
+        Json(RecoveryCodesResponse { recovery_codes }),
+    ))
+}
+
+// ── Recovery codes, status, reset ───────────────────────────────────────────
+
+#[utoipa::path(post, path = "/api/auth/mfa/recovery-codes", tag = "MFA",
+    responses(
+        (status = 200, description = "New recovery codes for the signed-in user; previous codes are invalidated", body = RecoveryCodesResponse),
+        (status = 401, description = "No valid session"),
+        (status = 409, description = "No factor enrolled"),
+    ))]
+pub async fn regenerate_recovery_codes(
+    State(state): State<Arc<AuthState>>,
+    session: SessionUser,
+) -> Result<impl IntoResponse, ApiErr> {
+    let status = repo::status(&state.db, session.tenant_id, session.user_id)
+        .await
+        .map_err(db_err)?;
+    if !status.enrolled() {
+        return Err(err(StatusCode::CONFLICT, "no mfa factor enrolled"));
+    }
+
+    let recovery_codes = totp::generate_recovery_codes();
+    let hashes: Vec<String> = recovery_codes
+        .iter()
+        .map(|c| totp::hash_recovery_code(c))
+        .collect();
+    let mut tx = state.db.begin().await.map_err(db_err)?;
+    repo::replace_recovery_codes(&mut tx, session.tenant_id, session.user_id, &hashes)
+        .await
+        .map_err(db_err)?;
+    tx.commit().await.map_err(db_err)?;
+
+    Ok((
+        StatusCode::OK,
+        Json(RecoveryCodesResponse { recovery_codes }),
+    ))
+}
+
+#[utoipa::path(get, path = "/api/auth/mfa/status", tag = "MFA",
+    responses(
+        (status = 200, description = "The signed-in user's enrolled factors and policy requirement", body = MfaStatus),
+        (status = 401, description = "No valid session"),
+    ))]
+pub async fn mfa_status(
+    State(state): State<Arc<AuthState>>,
+    session: SessionUser,
+) -> Result<impl IntoResponse, ApiErr> {
+    let status = repo::status(&state.db, session.tenant_id, session.user_id)
+        .await
+        .map_err(db_err)?;
+    Ok((StatusCode::OK, Json(status)))
+}
+
+#[utoipa::path(post, path = "/api/auth/mfa/reset", tag = "MFA",
+    request_body = MfaResetReq,
+    responses(
+        (status = 200, description = "All factors and recovery codes removed", body = OkResponse),
+        (status = 401, description = "Unauthenticated"),
+        (status = 403, description = "Missing admin.security.write, or another tenant"),
+    ))]
+pub async fn reset_mfa(
+    State(state): State<Arc<AuthState>>,
+    admin: AdminSecurityWrite,
+    extensions: Extensions,
+    Json(req): Json<MfaResetReq>,
+) -> Result<impl IntoResponse, ApiErr> {
+    admin.ensure_tenant(req.tenant_id)?;
+    let trace_id = get_trace_id_from_extensions(&extensions);
+    let mut tx = state.db.begin().await.map_err(db_err)?;
+    let removed = repo::reset_factors(&mut tx, req.tenant_id, req.user_id)
+        .await
+        .map_err(db_err)?;
+    record_lifecycle(
+        &mut tx,
+        &state,
+        req.tenant_id,
+        req.user_id,
+        LifecycleEventType::MfaReset,
+        Some(admin.user_id),
+        serde_json::json!({ "removed": removed, "reason": req.reason }),
+        trace_id,
+    )
+    .await?;
+    tx.commit().await.map_err(db_err)?;
+
+    tracing::info!(
+        tenant_id = %req.tenant_id,
+        user_id = %req.user_id,
+        reset_by = %admin.user_id,
+        "auth.mfa_reset"
+    );
+    Ok((StatusCode::OK, Json(OkResponse { ok: true })))
+}
+
+// ── Tenant policy ───────────────────────────────────────────────────────────
+
+#[utoipa::path(get, path = "/api/auth/mfa/policy/{tenant_id}", tag = "MFA",
+    params(("tenant_id" = Uuid, Path, description = "Tenant")),
+    responses(
+        (status = 200, description = "Tenant MFA policy (`off` when never set)", body = MfaPolicy),
+    ))]
+pub async fn get_policy(
+    State(state): State<Arc<AuthState>>,
+    Path(tenant_id): Path<Uuid>,
+) -> Result<impl IntoResponse, ApiErr> {
+    let policy = repo::get_policy(&state.db, tenant_id)
+        .await
+        .map_err(db_err)?
+        .unwrap_or_else(|| MfaPolicy {
+            tenant_id,
+            mode: repo::policy_mode::OFF.to_string(),
+            role_ids: Vec::new(),
+            updated_by: None,
+            updated_at: DateTime::<Utc>::UNIX_EPOCH,
+        });
+    Ok((StatusCode::OK, Json(policy)))
+}
+
+#[utoipa::path(put, path = "/api/auth/mfa/policy", tag = "MFA",
+    request_body = MfaPolicyReq,
+    responses(
+        (status = 200, description = "Policy stored", body = MfaPolicy),
+        (status = 400, description = "Unknown mode, or `roles` without role_ids"),
+        (status = 401, description = "Unauthenticated"),
+        (status = 403, description = "Missing admin.security.write, or another tenant"),
+    ))]
+pub async fn put_policy(
+    State(state): State<Arc<AuthState>>,
+    admin: AdminSecurityWrite,
+    Json(req): Json<MfaPolicyReq>,
+) -> Result<impl IntoResponse, ApiErr> {
+    admin.ensure_tenant(req.tenant_id)?;
+    if !repo::policy_mode::is_valid(&req.mode) {
+        return Err(err(
+            StatusCode::BAD_REQUEST,
+            "mode must be off, all_users or roles",
+        ));
+    }
+    if req.mode == repo::policy_mode::ROLES && req.role_ids.is_empty() {
+        return Err(err(StatusCode::BAD_REQUEST, "roles mode requires role_ids"));
+    }
+    let policy = repo::upsert_policy(
+        &state.db,
+        req.tenant_id,
+        &req.mode,
+        &req.role_ids,
+        Some(admin.user_id),
+    )
+    .await
+    .map_err(db_err)?;
+    tracing::info!(tenant_id = %req.tenant_id, mode = %req.mode, "auth.mfa_policy_updated");
+    Ok((StatusCode::OK, Json(policy)))
+}
+
+// ── WebAuthn registration ───────────────────────────────────────────────────
+
+#[utoipa::path(post, path = "/api/auth/mfa/webauthn/register/options", tag = "MFA",
+    security((), ("bearer" = [])),
+    request_body = MfaSubject,
+    responses(
+        (status = 200, description = "Creation options for navigator.credentials.create()", body = WebAuthnRegistrationOptions),
+        (status = 401, description = "Invalid or expired mfa_token, and no session"),
+        (status = 503, description = "WebAuthn not configured"),
+    ))]
+pub async fn webauthn_register_options(
+    State(state): State<Arc<AuthState>>,
+    session: Option<SessionUser>,
+    Json(req): Json<MfaSubject>,
+) -> Result<impl IntoResponse, ApiErr> {
+    let rp = relying_party(&state)?;
+    let (tenant_id, user_id) = resolve_subject(&state, session, &req).await?;
+    let email = account_email(&state.db, tenant_id, user_id).await?;
+    let existing = repo::webauthn_credential_ids(&state.db, tenant_id, user_id)
+        .await
+        .map_err(db_err)?;
+
+    let ttl = state.mfa.challenge_ttl_seconds;
+    let challenge = webauthn::generate_challenge();
+    let challenge_id = repo::create_challenge(
+        &state.db,
+        tenant_id,
+        user_id,
+        NewChallenge {
+            purpose: ChallengePurpose::WebAuthnRegister,
+            token_hash: None,
+            webauthn_challenge: Some(&challenge),
+            refresh_token_id: None,
+            refresh_session_id: None,
+            ttl_seconds: ttl,
+        },
+    )
+    .await
+    .map_err(db_err)?;
+
+    let public_key = serde_json::json!({
+        "challenge": webauthn::b64url(&challenge),
+        "rp": { "id": rp.id, "name": rp.name },
+        "user": {
+            "id": webauthn::b64url(user_id.as_bytes()),
+            "name": email,
+            "displayName": email,
+        },
+        "pubKeyCredParams": [{ "type": "public-key", "alg": webauthn::COSE_ALG_ES256 }],
+        "timeout": ttl * 1000,
+        "attestation": "none",
+        "authenticatorSelection": { "userVerification": "preferred" },
+        "excludeCredentials": existing
+            .iter()
+            .map(|id| serde_json::json!({ "type": "public-key", "id": webauthn::b64url(id) }))
+            .collect::<Vec<_>>(),
+    });
+
+    Ok((
+        StatusCode::OK,
+        Json(WebAuthnRegistrationOptions {
+            challenge_id,
+            public_key,
+        }),
+    ))
+}
+
+#[utoipa::path(post, path = "/api/auth/mfa/webauthn/register", tag = "MFA",
+    security((), ("bearer" = [])),
+    request_body = WebAuthnRegisterReq,
+    responses(
+        (status = 200, description = "Credential registered; recovery codes issued on first enrollment", body = WebAuthnRegisterResponse),
+        (status = 400, description = "Invalid or expired challenge, or attestation rejected"),
+        (status = 409, description = "Credential already registered"),
+        (status = 503, description = "WebAuthn not configured"),
+    ))]
+pub async fn webauthn_register(
+    State(state): State<Arc<AuthState>>,
+    extensions: Extensions,
+    session: Option<SessionUser>,
+    Json(req): Json<WebAuthnRegisterReq>,
+) -> Result<impl IntoResponse, ApiErr> {
+    let trace_id = get_trace_id_from_extensions(&extensions);
+    let rp = relying_party(&state)?;
+    let (tenant_id, user_id) = resolve_subject(&state, session, &req.subject).await?;
+    let client_data_json = decode_b64url("client_data_json", &req.client_data_json)?;
+    let attestation_object = decode_b64url("attestation_object", &req.attestation_object)?;
+    let status = repo::status(&state.db, tenant_id, user_id)
+        .await
+        .map_err(db_err)?;
+
+    let mut tx = state.db.begin().await.map_err(db_err)?;
+    let challenge = repo::challenge_by_id_for_update(&mut tx, req.challenge_id)
+        .await
+        .map_err(db_err)?
+        .filter(|c| {
+            c.purpose == ChallengePurpose::WebAuthnRegister
+                && c.tenant_id == tenant_id
+                && c.user_id == user_id
+                && c.is_live()
+        })
+        .and_then(|c| c.webauthn_challenge.map(|bytes| (c.id, bytes)))
+        .ok_or_else(|| {
+            err(
+                StatusCode::BAD_REQUEST,
+                "registration challenge invalid or expired",
+            )
+        })?;
+
+    let credential = rp
+        .verify_registration(&challenge.1, &client_data_json, &attestation_object)
+        .map_err(|e| err(StatusCode::BAD_REQUEST, e.to_string()))?;
+    repo::consume_challenge(&mut tx, challenge.0)
+        .await
+        .map_err(db_err)?;
+
+    let name = req
+        .name
+        .as_deref()
+        .map(str::trim)
+        .filter(|n| !n.is_empty())
+        .unwrap_or("Security key");
+    let info = repo::insert_webauthn_credential(
+        &mut tx,
+        tenant_id,
+        user_id,
+        &credential.credential_id,
+        &credential.public_key,
+        i64::from(credential.sign_count),
+        name,
+    )
+    .await
+    .map_err(|e| match e.as_database_error().and_then(|d| d.code()) {
+        Some(code) if code == "23505" => err(StatusCode::CONFLICT, "credential already registered"),
+        _ => db_err(e),
+    })?;
+
+    let recovery_codes =
+        issue_recovery_codes_if_missing(&mut tx, &status, tenant_id, user_id).await?;
+    record_lifecycle(
+        &mut tx,
+        &state,
+        tenant_id,
+        user_id,
+        LifecycleEventType::MfaEnrolled,
+        Some(user_id),
+        serde_json::json!({ "method": repo::method::WEBAUTHN, "credential": info.id }),
+        trace_id,
+    )
+    .await?;
+    tx.commit().await.map_err(db_err)?;
+
+    Ok((
+        StatusCode::OK,
+        Json(WebAuthnRegisterResponse {
+            credential: info,
+            recovery_codes,
+        }),
+    ))
+}
+
+// ── Verification ────────────────────────────────────────────────────────────
+
+/// Check the submitted factor. `Ok(Some(amr))` on success, `Ok(None)` when the
+/// answer is wrong (counts as a failed attempt), `Err` for malformed requests.
+async fn check_factor(
+    state: &AuthState,
+    tx: &mut Transaction<'_, Postgres>,
+    challenge: &MfaChallenge,
+    req: &MfaVerifyReq,
+) -> Result<Option<&'static str>, ApiErr> {
+    let (tenant_id, user_id) = (challenge.tenant_id, challenge.user_id);
+    let code = || {
+        req.code
+            .as_deref()
+            .ok_or_else(|| err(StatusCode::BAD_REQUEST, "code required"))
+    };
+
+    match req.method.as_str() {
+        repo::method::TOTP => {
+            let key = totp_key(state)?;
+            let code = code()?;
+            let factor = repo::totp_factor_for_update(tx, tenant_id, user_id)
+                .await
+                .map_err(db_err)?
+                .filter(|f| f.confirmed_at.is_some())
+                .ok_or_else(|| err(StatusCode::BAD_REQUEST, "totp not enrolled"))?;
+            let secret = key
+                .open(&factor.secret_enc)
+                .map_err(|e| err(StatusCode::INTERNAL_SERVER_ERROR, e))?;
+            match totp::verify(&secret, code, Utc::now().timestamp(), factor.last_used_step) {
+                Some(step) => {
+                    repo::record_totp_use(tx, tenant_id, user_id, step)
+                        .await
+                        .map_err(db_err)?;
+                    Ok(Some(amr::OTP))
+                }
+                None => Ok(None),
+            }
+        }
+        repo::method::RECOVERY_CODE => {
+            let used = repo::consume_recovery_code(
+                tx,
+                tenant_id,
+                user_id,
+                &totp::hash_recovery_code(code()?),
+            )
+            .await
+            .map_err(db_err)?;
+            Ok(used.then_some(amr::OTP))
+        }
+        repo::method::WEBAUTHN => {
+            let rp = relying_party(state)?;
+            let assertion = req
+                .webauthn
+                .as_ref()
+                .ok_or_else(|| err(StatusCode::BAD_REQUEST, "webauthn assertion required"))?;
+            let expected = challenge.webauthn_challenge.as_deref().ok_or_else(|| {
+                err(
+                    StatusCode::BAD_REQUEST,
+                    "challenge has no webauthn option; log in again",
+                )
+            })?;
+            let credential_id = decode_b64url("credential_id", &assertion.credential_id)?;
+            let client_data_json = decode_b64url("client_data_json", &assertion.client_data_json)?;
+            let authenticator_data =
+                decode_b64url("authenticator_data", &assertion.authenticator_data)?;
+            let signature = decode_b64url("signature", &assertion.signature)?;
+
+            let Some(stored) =
+                repo::webauthn_credential_for_update(tx, tenant_id, user_id, &credential_id)
+                    .await
+                    .map_err(db_err)?
+            else {
+                return Ok(None);
+            };
+            match rp.verify_assertion(
+                expected,
+                &stored.public_key,
+                u32::try_from(stored.sign_count).unwrap_or(u32::MAX),
+                &client_data_json,
+                &authenticator_data,
+                &signature,
+            ) {
+                Ok(sign_count) => {
+                    repo::record_webauthn_use(tx, stored.id, i64::from(sign_count))
+                        .await
+                        .map_err(db_err)?;
+                    Ok(Some(amr::HWK))
+                }
+                Err(e) => {
+                    tracing::warn!(
+                        tenant_id = %tenant_id,
+                        user_id = %user_id,
+                        credential = %stored.id,
+                        error = %e,
+                        "auth.mfa_webauthn_rejected"
+                    );
+                    Ok(None)
+                }
+            }
+        }
+        other => Err(err(
+            StatusCode::BAD_REQUEST,
+            format!("unknown mfa method: {other}"),
+        )),
+    }
+}
+
+#[utoipa::path(post, path = "/api/auth/mfa/verify", tag = "MFA",
+    security(()),
+    request_body = MfaVerifyReq,
+    responses(
+        (status = 200, description = "Login challenge: session tokens (as /api/auth/login). Step-up challenge: a new access token", body = AccessTokenResponse),
+        (status = 400, description = "Malformed answer or method not enrolled"),
+        (status = 401, description = "Wrong answer, or invalid / expired / exhausted mfa_token"),
+    ))]
+pub async fn verify(
+    State(state): State<Arc<AuthState>>,
+    extensions: Extensions,
+    Json(req): Json<MfaVerifyReq>,
+) -> Result<Response, ApiErr> {
+    let trace_id = get_trace_id_from_extensions(&extensions);
+    let method_label = match req.method.as_str() {
+        m @ (repo::method::TOTP | repo::method::WEBAUTHN | repo::method::RECOVERY_CODE) => m,
+        _ => "unknown",
+    };
+
+    let mut tx = state.db.begin().await.map_err(db_err)?;
+    let challenge =
+        repo::challenge_by_token_for_update(&mut tx, &hash_refresh_token(&req.mfa_token))
+            .await
+            .map_err(db_err)?
+            .filter(|c| c.purpose != ChallengePurpose::WebAuthnRegister && c.is_live())
+            .ok_or_else(|| {
+                state
+                    .metrics
+                    .auth_mfa_verify_total
+                    .with_label_values(&["invalid_token", method_label])
+                    .inc();
+                err(StatusCode::UNAUTHORIZED, "invalid or expired mfa_token")
+            })?;
+
+    let Some(factor_amr) = check_factor(&state, &mut tx, &challenge, &req).await? else {
+        let _ = tx.rollback().await;
+        let _ = repo::record_failed_attempt(&state.db, challenge.id).await;
+        state
+            .metrics
+            .auth_mfa_verify_total
+            .with_label_values(&["failure", method_label])
+            .inc();
+        tracing::warn!(
+            tenant_id = %challenge.tenant_id,
+            user_id = %challenge.user_id,
+            method = %method_label,
+            attempts = challenge.attempts + 1,
+            trace_id = %trace_id,
+            "auth.mfa_verify_failed"
+        );
+        return Err(err(StatusCode::UNAUTHORIZED, "invalid mfa answer"));
+    };
+
+    repo::consume_challenge(&mut tx, challenge.id)
+        .await
+        .map_err(db_err)?;
+    tx.commit().await.map_err(db_err)?;
+    state
+        .metrics
+        .auth_mfa_verify_total
+        .with_label_values(&["success", method_label])
+        .inc();
+
+    let now = Utc::now();
+    match challenge.purpose {
+        ChallengePurpose::Login => {
+            open_login_session(
+                &state,
+                &extensions,
+                challenge.tenant_id,
+                challenge.user_id,
+                SessionAuth::password(now).with_second_factor(factor_amr, now),
+                trace_id,
+            )
+            .await
+        }
+        _ => complete_step_up(&state, &challenge, factor_amr, now).await,
+    }
+}
+
+// ── Step-up ─────────────────────────────────────────────────────────────────
+
+#[utoipa::path(post, path = "/api/auth/mfa/step-up", tag = "MFA",
+    security(()),
+    request_body(content = StepUpReq, content_type = "application/json", description = "Body-based refresh token (legacy). Omit to use the HttpOnly cookie session."),
+    responses(
+        (status = 200, description = "Step-up challenge to answer at /api/auth/mfa/verify", body = MfaChallengeResponse),
+        (status = 401, description = "Invalid or expired session"),
+        (status = 403, description = "No factor enrolled"),
+    ))]
+pub async fn step_up(
+    State(state): State<Arc<AuthState>>,
+    headers: HeaderMap,
+    body: Option<Json<StepUpReq>>,
+) -> Result<Response, ApiErr> {
+    let mut tx = state.db.begin().await.map_err(db_err)?;
+    let (tenant_id, user_id, refresh_token_id, refresh_session_id) = if let Some(raw) =
+        cookies::read_refresh_cookie(&headers)
+    {
+        match refresh_sessions::find_and_validate(&mut tx, &raw)
+            .await
+            .map_err(db_err)?
+        {
+            Ok((session_id, tenant_id, user_id, _)) => (tenant_id, user_id, None, Some(session_id)),
+            Err(_) => return Err(err(StatusCode::UNAUTHORIZED, "invalid session")),
+        }
+    } else {
+        let Json(req) =
+            body.ok_or_else(|| err(StatusCode::BAD_REQUEST, "refresh token required"))?;
+        let row = sqlx::query(
+            r#"
+                SELECT id, tenant_id, user_id FROM refresh_tokens
+                WHERE token_hash = $1 AND revoked_at IS NULL AND expires_at > NOW()
+                "#,
+        )
+        .bind(hash_refresh_token(&req.refresh_token))
+        .fetch_optional(&mut *tx)
+        .await
+        .map_err(db_err)?
+        .ok_or_else(|| err(StatusCode::UNAUTHORIZED, "invalid refresh token"))?;
+        (
+            row.get("tenant_id"),
+            row.get("user_id"),
+            Some(row.get("id")),
+            None,
+        )
+    };
+    let _ = tx.rollback().await;
+
+    let status = repo::status(&state.db, tenant_id, user_id)
+        .await
+        .map_err(db_err)?;
+    if !status.enrolled() {
+        return Err(err(StatusCode::FORBIDDEN, "mfa enrollment required"));
+    }
+
+    issue_challenge(
+        &state,
+        tenant_id,
+        user_id,
+        &status,
+        ChallengePurpose::StepUp,
+        refresh_token_id,
+        refresh_session_id,
+    )
+    .await
+}
+
+/// Stamp the challenged session with the new factor and mint an access token
+/// carrying it.
+async fn complete_step_up(
+    state: &AuthState,
+    challenge: &MfaChallenge,
+    factor_amr: &str,
+    now: DateTime<Utc>,
+) -> Result<Response, ApiErr> {
+    let (tenant_id, user_id) = (challenge.tenant_id, challenge.user_id);
+    let mut tx = state.db.begin().await.map_err(db_err)?;
+
+    let (session_id, updated, auth) =
+        match (challenge.refresh_session_id, challenge.refresh_token_id) {
+            (Some(session_id), _) => {
+                let auth = refresh_sessions::session_auth(&mut tx, session_id)
+                    .await
+                    .map_err(db_err)?
+                    .unwrap_or_default()
+                    .with_second_factor(factor_amr, now);
+                let updated = refresh_sessions::set_auth(&mut tx, session_id, &auth)
+                    .await
+                    .map_err(db_err)?;
+                (session_id, updated, auth)
+            }
+            (None, Some(token_id)) => {
+                let row = sqlx::query("SELECT amr, auth_time FROM refresh_tokens WHERE id = $1")
+                    .bind(token_id)
+                    .fetch_optional(&mut *tx)
+                    .await
+                    .map_err(db_err)?;
+                let auth = row
+                    .map(|r| SessionAuth {
+                        amr: r.get("amr"),
+                        auth_time: r.get("auth_time"),
+                    })
+                    .unwrap_or_default()
+                    .with_second_factor(factor_amr, now);
+                let updated = sqlx::query(
+                    r#"
+                UPDATE refresh_tokens SET amr = $2, auth_time = $3
+                WHERE id = $1 AND revoked_at IS NULL AND expires_at > NOW()
+                "#,
+                )
+                .bind(token_id)
+                .bind(&auth.amr)
+                .bind(auth.auth_time)
+                .execute(&mut *tx)
+                .await
+                .map_err(db_err)?
+                .rows_affected();
+                (token_id, updated, auth)
+            }
+            (None, None) => {
+                return Err(err(
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    "step-up challenge without session",
+                ))
+            }
+        };
+    if updated == 0 {
+        return Err(err(StatusCode::UNAUTHORIZED, "session no longer active"));
+    }
+    tx.commit().await.map_err(db_err)?;
+
+    let roles = crate::db::rbac::list_roles_for_user(&state.db, tenant_id, user_id)
+        .await
+        .map_err(db_err)?
+        .into_iter()
+        .map(|r| r.name)
+        .collect::<Vec<_>>();
+    let perms = crate::db::rbac::effective_permissions_for_user(&state.db, tenant_id, user_id)
+        .await
+        .map_err(db_err)?;
+    let scopes = crate::db::rbac::effective_scopes_for_user(&state.db, tenant_id, user_id)
+        .await
+        .map_err(db_err)?;
+    let role_snapshot_id = jwt::compute_role_snapshot_id(&roles);
+
+    let access_token = state
+        .jwt
+        .sign_access_token_enriched(
+            tenant_id,
+            user_id,
+            roles,
+            perms,
+            scopes,
+            &auth,
+            jwt::actor_type::USER,
+            state.access_ttl_minutes,
+            Some(session_id),
+            Some(role_snapshot_id),
+        )
+        .map_err(|e| err(StatusCode::INTERNAL_SERVER_ERROR, e))?;
+
+    tracing::info!(
+        tenant_id = %tenant_id,
+        user_id = %user_id,
+        session_id = %session_id,
+        "auth.mfa_step_up"
+    );
+    Ok((
+        StatusCode::OK,
+        Json(AccessTokenResponse {
+            token_type: "Bearer",
+            access_token,
+            expires_in_seconds: state.access_ttl_minutes * 60,
+        }),
+    )
+        .into_response())
+}
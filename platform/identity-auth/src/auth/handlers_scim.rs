@@ -0,0 +1,1179 @@
+//! SCIM 2.0 provisioning endpoints (`/api/auth/scim/v2/*`).
+//!
+//! A tenant's IdP authenticates with a per-tenant bearer token minted via
+//! `POST /api/auth/scim/tokens` by a tenant administrator holding
+//! `admin.security.write`; the token alone selects the tenant.
+//!
+//! - Users are `credentials` rows. Every tenant user is visible, so the IdP
+//!   can match accounts that existed before provisioning; a POST whose email
+//!   belongs to such an account adopts it instead of failing.
+//! - `active: false` (and DELETE) deactivates the account and revokes every
+//!   refresh session. DELETE also drops group memberships and hides the user
+//!   from SCIM; the credentials row is kept for audit history.
+//! - Each group is backed by one tenant role (matched by name, else created),
+//!   and membership grants that role. Renaming a group does not rename its
+//!   role, and deleting a group revokes the role but keeps it.
+//! - Lifecycle changes are written to the user lifecycle audit trail.
+//!
+//! List responses omit `groups` / `members`; fetch the resource for them.
+
+use crate::{
+    db::rbac,
+    db::scim::{self as repo, ScimGroupRow, ScimToken, ScimUserRow},
+    db::user_lifecycle_audit::{
+        append_lifecycle_event_tx, LifecycleAuditContext, LifecycleEventType,
+    },
+    middleware::tracing::get_trace_id_from_extensions,
+};
+use axum::{
+    body::Bytes,
+    extract::{FromRequestParts, Path, Query, State},
+    http::{header, request::Parts, Extensions, HeaderValue, StatusCode},
+    response::{IntoResponse, Response},
+    Json,
+};
+use event_bus::EventEnvelope;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use sqlx::{Postgres, Transaction};
+use std::sync::Arc;
+use uuid::Uuid;
+
+use super::{
+    authz::AdminSecurityWrite,
+    handlers::{err, ApiErr, AuthState, OkResponse},
+    oidc,
+    password::hash_password,
+    refresh_sessions,
+    scim::{
+        self, PatchRequest, ScimError, ScimGroup, ScimUser, GROUP_SCHEMA, LIST_RESPONSE_SCHEMA,
+        MAX_RESULTS, RESOURCE_TYPE_SCHEMA, SERVICE_PROVIDER_CONFIG_SCHEMA, USER_SCHEMA,
+    },
+};
+
+const BASE_PATH: &str = "/api/auth/scim/v2";
+
+// ── Authentication ──────────────────────────────────────────────────────────
+
+/// Tenant resolved from a SCIM bearer token.
+#[derive(Debug, Clone, Copy)]
+pub struct ScimAuth {
+    pub tenant_id: Uuid,
+}
+
+impl FromRequestParts<Arc<AuthState>> for ScimAuth {
+    type Rejection = ScimError;
+
+    async fn from_request_parts(
+        parts: &mut Parts,
+        state: &Arc<AuthState>,
+    ) -> Result<Self, Self::Rejection> {
+        let token = parts
+            .headers
+            .get(header::AUTHORIZATION)
+            .and_then(|v| v.to_str().ok())
+            .and_then(|v| v.strip_prefix("Bearer "))
+            .map(str::trim)
+            .filter(|t| !t.is_empty())
+            .ok_or_else(ScimError::unauthorized)?;
+        let tenant_id = repo::authenticate(&state.db, &scim::hash_token(token))
+            .await?
+            .ok_or_else(ScimError::unauthorized)?;
+        Ok(Self { tenant_id })
+    }
+}
+
+// ── Helpers ─────────────────────────────────────────────────────────────────
+
+fn db_err(e: sqlx::Error) -> ApiErr {
+    err(StatusCode::INTERNAL_SERVER_ERROR, format!("db error: {e}"))
+}
+
+/// Count the request and render the outcome.
+fn observe(
+    state: &AuthState,
+    resource: &str,
+    operation: &str,
+    result: Result<Response, ScimError>,
+) -> Response {
+    let outcome = if result.is_ok() { "success" } else { "failure" };
+    state
+        .metrics
+        .auth_scim_requests_total
+        .with_label_values(&[resource, operation, outcome])
+        .inc();
+    match result {
+        Ok(resp) => resp,
+        Err(e) => {
+            if e.status.is_server_error() {
+                tracing::error!(resource, operation, detail = %e.detail, "auth.scim_error");
+            }
+            e.into_response()
+        }
+    }
+}
+
+/// Bodies are parsed by hand so malformed JSON gets a SCIM error response.
+fn parse_body(body: &Bytes) -> Result<Value, ScimError> {
+    serde_json::from_slice(body)
+        .map_err(|e| ScimError::bad_request("invalidSyntax", format!("invalid JSON: {e}")))
+}
+
+fn parse_patch(body: &Bytes) -> Result<PatchRequest, ScimError> {
+    let req: PatchRequest = serde_json::from_slice(body)
+        .map_err(|e| ScimError::bad_request("invalidSyntax", format!("invalid PatchOp: {e}")))?;
+    req.validate()?;
+    Ok(req)
+}
+
+fn lifecycle_ctx(state: &AuthState, trace_id: &str, key: String) -> LifecycleAuditContext {
+    LifecycleAuditContext {
+        producer: state.producer.clone(),
+        trace_id: trace_id.to_string(),
+        causation_id: None,
+        idempotency_key: key,
+    }
+}
+
+fn created(location: String, body: Value) -> Response {
+    let mut resp = scim::scim_json(StatusCode::CREATED, body);
+    if let Ok(v) = HeaderValue::from_str(&location) {
+        resp.headers_mut().insert(header::LOCATION, v);
+    }
+    resp
+}
+
+fn user_location(id: Uuid) -> String {
+    format!("{BASE_PATH}/Users/{id}")
+}
+
+fn group_location(id: Uuid) -> String {
+    format!("{BASE_PATH}/Groups/{id}")
+}
+
+fn user_json(row: &ScimUserRow, groups: Option<&[ScimGroupRow]>) -> Value {
+    let mut body = json!({
+        "schemas": [USER_SCHEMA],
+        "id": row.user_id,
+        "userName": row.user_name,
+        "name": {
+            "givenName": row.given_name,
+            "familyName": row.family_name,
+        },
+        "emails": [{ "value": row.email, "type": "work", "primary": true }],
+        "active": row.active,
+        "meta": {
+            "resourceType": "User",
+            "created": row.created_at,
+            "lastModified": row.updated_at,
+            "location": user_location(row.user_id),
+        },
+    });
+    if let Some(v) = &row.external_id {
+        body["externalId"] = json!(v);
+    }
+    if let Some(v) = &row.display_name {
+        body["displayName"] = json!(v);
+    }
+    if let Some(groups) = groups {
+        body["groups"] = groups
+            .iter()
+            .map(|g| {
+                json!({
+                    "value": g.id,
+                    "display": g.display_name,
+                    "$ref": group_location(g.id),
+                })
+            })
+            .collect();
+    }
+    body
+}
+
+fn group_json(row: &ScimGroupRow, members: Option<&[Uuid]>) -> Value {
+    let mut body = json!({
+        "schemas": [GROUP_SCHEMA],
+        "id": row.id,
+        "displayName": row.display_name,
+        "meta": {
+            "resourceType": "Group",
+            "created": row.created_at,
+            "lastModified": row.updated_at,
+            "location": group_location(row.id),
+        },
+    });
+    if let Some(v) = &row.external_id {
+        body["externalId"] = json!(v);
+    }
+    if let Some(members) = members {
+        body["members"] = members
+            .iter()
+            .map(|m| json!({ "value": m, "type": "User", "$ref": user_location(*m) }))
+            .collect();
+    }
+    body
+}
+
+fn list_response(total: i64, start_index: i64, resources: Vec<Value>) -> Response {
+    scim::scim_json(
+        StatusCode::OK,
+        json!({
+            "schemas": [LIST_RESPONSE_SCHEMA],
+            "totalResults": total,
+            "startIndex": start_index,
+            "itemsPerPage": resources.len(),
+            "Resources": resources,
+        }),
+    )
+}
+
+/// Query parameters of a list request. Numbers are parsed leniently: RFC 7644
+/// §3.4.2.4 treats out-of-range values as the defaults.
+#[derive(Debug, Default, Deserialize, utoipa::IntoParams)]
+pub struct ListQuery {
+    pub filter: Option<String>,
+    /// 1-based index of the first result.
+    #[serde(rename = "startIndex")]
+    #[param(value_type = Option<i64>)]
+    pub start_index: Option<String>,
+    /// Page size (at most 200).
+    #[param(value_type = Option<i64>)]
+    pub count: Option<String>,
+}
+
+impl ListQuery {
+    /// `(filter, startIndex, count)` with defaults applied.
+    fn resolve(
+        &self,
+        columns: &dyn Fn(&str) -> Option<scim::Column>,
+    ) -> Result<(Option<scim::SqlFilter>, i64, i64), ScimError> {
+        let filter = match self.filter.as_deref().map(str::trim) {
+            Some(f) if !f.is_empty() => {
+                Some(scim::filter_to_sql(&scim::parse_filter(f)?, columns, 2)?)
+            }
+            _ => None,
+        };
+        let start_index = self
+            .start_index
+            .as_deref()
+            .and_then(|s| s.parse::<i64>().ok())
+            .unwrap_or(1)
+            .max(1);
+        let count = self
+            .count
+            .as_deref()
+            .and_then(|s| s.parse::<i64>().ok())
+            .unwrap_or(MAX_RESULTS)
+            .clamp(0, MAX_RESULTS);
+        Ok((filter, start_index, count))
+    }
+}
+
+// ── Discovery ───────────────────────────────────────────────────────────────
+
+#[utoipa::path(get, path = "/api/auth/scim/v2/ServiceProviderConfig", tag = "SCIM",
+    security(("scim_token" = [])),
+    responses(
+        (status = 200, description = "Supported SCIM features", content_type = "application/scim+json"),
+        (status = 401, description = "Missing or invalid SCIM token"),
+    ))]
+pub async fn service_provider_config(
+    State(state): State<Arc<AuthState>>,
+    _auth: ScimAuth,
+) -> Response {
+    let body = json!({
+        "schemas": [SERVICE_PROVIDER_CONFIG_SCHEMA],
+        "patch": { "supported": true },
+        "bulk": { "supported": false, "maxOperations": 0, "maxPayloadSize": 0 },
+        "filter": { "supported": true, "maxResults": MAX_RESULTS },
+        "changePassword": { "supported": false },
+        "sort": { "supported": false },
+        "etag": { "supported": false },
+        "authenticationSchemes": [{
+            "type": "oauthbearertoken",
+            "name": "OAuth Bearer Token",
+            "description": "Per-tenant SCIM token issued by the platform",
+            "primary": true,
+        }],
+        "meta": {
+            "resourceType": "ServiceProviderConfig",
+            "location": format!("{BASE_PATH}/ServiceProviderConfig"),
+        },
+    });
+    observe(
+        &state,
+        "discovery",
+        "get",
+        Ok(scim::scim_json(StatusCode::OK, body)),
+    )
+}
+
+#[utoipa::path(get, path = "/api/auth/scim/v2/ResourceTypes", tag = "SCIM",
+    security(("scim_token" = [])),
+    responses(
+        (status = 200, description = "User and Group resource types", content_type = "application/scim+json"),
+        (status = 401, description = "Missing or invalid SCIM token"),
+    ))]
+pub async fn resource_types(State(state): State<Arc<AuthState>>, _auth: ScimAuth) -> Response {
+    let types: Vec<Value> = [
+        ("User", "/Users", USER_SCHEMA),
+        ("Group", "/Groups", GROUP_SCHEMA),
+    ]
+    .into_iter()
+    .map(|(name, endpoint, schema)| {
+        json!({
+            "schemas": [RESOURCE_TYPE_SCHEMA],
+            "id": name,
+            "name": name,
+            "endpoint": endpoint,
+            "schema": schema,
+            "meta": {
+                "resourceType": "ResourceType",
+                "location": format!("{BASE_PATH}/ResourceTypes/{name}"),
+            },
+        })
+    })
+    .collect();
+    let total = types.len() as i64;
+    observe(
+        &state,
+        "discovery",
+        "list",
+        Ok(list_response(total, 1, types)),
+    )
+}
+
+// ── Users ───────────────────────────────────────────────────────────────────
+
+#[utoipa::path(get, path = "/api/auth/scim/v2/Users", tag = "SCIM",
+    security(("scim_token" = [])),
+    params(ListQuery),
+    responses(
+        (status = 200, description = "ListResponse of users", content_type = "application/scim+json"),
+        (status = 400, description = "invalidFilter"),
+        (status = 401, description = "Missing or invalid SCIM token"),
+    ))]
+pub async fn list_users(
+    State(state): State<Arc<AuthState>>,
+    auth: ScimAuth,
+    Query(q): Query<ListQuery>,
+) -> Response {
+    let result = async {
+        let (filter, start_index, count) = q.resolve(&repo::user_column)?;
+        let (total, rows) = repo::list_users(
+            &state.db,
+            auth.tenant_id,
+            filter.as_ref(),
+            start_index - 1,
+            count,
+        )
+        .await?;
+        let resources = rows.iter().map(|r| user_json(r, None)).collect();
+        Ok(list_response(total, start_index, resources))
+    }
+    .await;
+    observe(&state, "User", "list", result)
+}
+
+async fn load_user(state: &AuthState, tenant_id: Uuid, id: &str) -> Result<ScimUserRow, ScimError> {
+    let not_found = || ScimError::not_found(format!("User {id} not found"));
+    let user_id = Uuid::parse_str(id).map_err(|_| not_found())?;
+    repo::get_user(&state.db, tenant_id, user_id)
+        .await?
+        .ok_or_else(not_found)
+}
+
+async fn render_user(
+    state: &AuthState,
+    tenant_id: Uuid,
+    user_id: Uuid,
+) -> Result<Value, ScimError> {
+    let row = repo::get_user(&state.db, tenant_id, user_id)
+        .await?
+        .ok_or_else(|| ScimError::internal("user vanished after write"))?;
+    let groups = repo::groups_for_user(&state.db, tenant_id, user_id).await?;
+    Ok(user_json(&row, Some(&groups)))
+}
+
+#[utoipa::path(get, path = "/api/auth/scim/v2/Users/{id}", tag = "SCIM",
+    security(("scim_token" = [])),
+    params(("id" = Uuid, Path, description = "User id")),
+    responses(
+        (status = 200, description = "User resource", content_type = "application/scim+json"),
+        (status = 404, description = "Unknown or deleted user"),
+    ))]
+pub async fn get_user(
+    State(state): State<Arc<AuthState>>,
+    auth: ScimAuth,
+    Path(id): Path<String>,
+) -> Response {
+    let result = async {
+        let row = load_user(&state, auth.tenant_id, &id).await?;
+        let body = render_user(&state, auth.tenant_id, row.user_id).await?;
+        Ok(scim::scim_json(StatusCode::OK, body))
+    }
+    .await;
+    observe(&state, "User", "get", result)
+}
+
+/// Write SCIM attributes, email and active flag for an existing credential.
+/// Deactivation revokes every session; both transitions are audited.
+async fn write_user_tx(
+    tx: &mut Transaction<'_, Postgres>,
+    state: &AuthState,
+    tenant_id: Uuid,
+    user_id: Uuid,
+    was_active: bool,
+    user: &ScimUser,
+    trace_id: &str,
+) -> Result<(), ScimError> {
+    repo::upsert_user_attributes(tx, tenant_id, user_id, user).await?;
+    repo::update_credentials(tx, tenant_id, user_id, &user.email, user.active).await?;
+
+    let event = match (was_active, user.active) {
+        (true, false) => {
+            let sessions =
+                refresh_sessions::revoke_all_for_user(tx, tenant_id, user_id, "scim_deprovisioned")
+                    .await?;
+            let tokens = repo::revoke_legacy_sessions(tx, tenant_id, user_id).await?;
+            Some((
+                LifecycleEventType::UserDeactivated,
+                json!({
+                    "user_id": user_id,
+                    "source": "scim",
+                    "revoked_sessions": sessions + tokens,
+                }),
+            ))
+        }
+        (false, true) => Some((
+            LifecycleEventType::UserReactivated,
+            json!({ "user_id": user_id, "source": "scim" }),
+        )),
+        _ => None,
+    };
+    if let Some((event_type, payload)) = event {
+        append_lifecycle_event_tx(
+            tx,
+            tenant_id,
+            user_id,
+            event_type,
+            None,
+            None,
+            None,
+            None,
+            payload,
+            &lifecycle_ctx(
+                state,
+                trace_id,
+                format!("scim-active:{tenant_id}:{user_id}:{}", Uuid::new_v4()),
+            ),
+        )
+        .await?;
+        tracing::info!(
+            tenant_id = %tenant_id,
+            user_id = %user_id,
+            active = user.active,
+            "auth.scim_user_active_changed"
+        );
+    }
+    Ok(())
+}
+
+async fn save_user(
+    state: &AuthState,
+    tenant_id: Uuid,
+    user_id: Uuid,
+    was_active: bool,
+    user: &ScimUser,
+    trace_id: &str,
+) -> Result<(), ScimError> {
+    let mut tx = state.db.begin().await?;
+    write_user_tx(
+        &mut tx, state, tenant_id, user_id, was_active, user, trace_id,
+    )
+    .await?;
+    tx.commit().await?;
+    Ok(())
+}
+
+#[utoipa::path(post, path = "/api/auth/scim/v2/Users", tag = "SCIM",
+    security(("scim_token" = [])),
+    request_body(content = Object, content_type = "application/scim+json", description = "SCIM User resource"),
+    responses(
+        (status = 201, description = "User provisioned (or an existing account adopted)", content_type = "application/scim+json"),
+        (status = 400, description = "invalidSyntax / invalidValue"),
+        (status = 409, description = "userName or email already provisioned"),
+    ))]
+pub async fn create_user(
+    State(state): State<Arc<AuthState>>,
+    auth: ScimAuth,
+    extensions: Extensions,
+    body: Bytes,
+) -> Response {
+    let result = async {
+        let user = ScimUser::from_resource(&parse_body(&body)?)?;
+        let trace_id = get_trace_id_from_extensions(&extensions);
+        let tenant_id = auth.tenant_id;
+
+        let user_id =
+            match repo::find_credential_by_email(&state.db, tenant_id, &user.email).await? {
+                Some(existing) if existing.managed => {
+                    return Err(ScimError::conflict(format!(
+                        "a user with email {} is already provisioned",
+                        user.email
+                    )));
+                }
+                Some(existing) => {
+                    save_user(
+                        &state,
+                        tenant_id,
+                        existing.user_id,
+                        existing.active,
+                        &user,
+                        &trace_id,
+                    )
+                    .await?;
+                    tracing::info!(tenant_id = %tenant_id, user_id = %existing.user_id, "auth.scim_user_adopted");
+                    existing.user_id
+                }
+                None => provision_user(&state, tenant_id, &user, &trace_id).await?,
+            };
+
+        let body = render_user(&state, tenant_id, user_id).await?;
+        Ok(created(user_location(user_id), body))
+    }
+    .await;
+    observe(&state, "User", "create", result)
+}
+
+/// Create the credentials row for a new SCIM user. The password is random and
+/// never disclosed; users sign in via SSO or set one through password reset.
+async fn provision_user(
+    state: &AuthState,
+    tenant_id: Uuid,
+    user: &ScimUser,
+    trace_id: &str,
+) -> Result<Uuid, ScimError> {
+    let user_id = Uuid::new_v4();
+    let hash = {
+        let _permit = state.hash_limiter.acquire().await.map_err(|_| ScimError {
+            status: StatusCode::SERVICE_UNAVAILABLE,
+            scim_type: None,
+            detail: "auth busy".to_string(),
+        })?;
+        hash_password(&state.pwd, &oidc::random_token()).map_err(ScimError::internal)?
+    };
+
+    let mut tx = state.db.begin().await?;
+    sqlx::query(
+        r#"
+        INSERT INTO credentials (tenant_id, user_id, email, password_hash, is_active)
+        VALUES ($1, $2, $3, $4, $5)
+        "#,
+    )
+    .bind(tenant_id)
+    .bind(user_id)
+    .bind(&user.email)
+    .bind(hash)
+    .bind(user.active)
+    .execute(&mut *tx)
+    .await?;
+    repo::upsert_user_attributes(&mut tx, tenant_id, user_id, user).await?;
+    append_lifecycle_event_tx(
+        &mut tx,
+        tenant_id,
+        user_id,
+        LifecycleEventType::UserCreated,
+        None,
+        None,
+        None,
+        None,
+        json!({
+            "user_id": user_id.to_string(),
+            "email": user.email,
+            "source": "scim",
+            "external_id": user.external_id,
+        }),
+        &lifecycle_ctx(
+            state,
+            trace_id,
+            format!("scim-provision:{tenant_id}:{user_id}"),
+        ),
+    )
+    .await?;
+    tx.commit().await?;
+
+    #[derive(Serialize)]
+    struct Data {
+        user_id: String,
+        email: String,
+    }
+    let env = EventEnvelope::new(
+        tenant_id.to_string(),
+        state.producer.clone(),
+        "auth.user_registered".to_string(),
+        Data {
+            user_id: user_id.to_string(),
+            email: user.email.clone(),
+        },
+    )
+    .with_schema_version("1.0.0".to_string())
+    .with_trace_id(Some(trace_id.to_string()))
+    .with_mutation_class(Some("user-data".to_string()));
+    if state
+        .events
+        .publish("auth.user_registered", "auth.user.registered.v1.json", &env)
+        .await
+        .is_err()
+    {
+        state
+            .metrics
+            .auth_nats_publish_fail_total
+            .with_label_values(&["auth.user_registered"])
+            .inc();
+    }
+
+    tracing::info!(tenant_id = %tenant_id, user_id = %user_id, "auth.scim_user_provisioned");
+    Ok(user_id)
+}
+
+#[utoipa::path(put, path = "/api/auth/scim/v2/Users/{id}", tag = "SCIM",
+    security(("scim_token" = [])),
+    params(("id" = Uuid, Path, description = "User id")),
+    request_body(content = Object, content_type = "application/scim+json", description = "Full SCIM User resource"),
+    responses(
+        (status = 200, description = "User replaced", content_type = "application/scim+json"),
+        (status = 404, description = "Unknown or deleted user"),
+        (status = 409, description = "userName or email taken"),
+    ))]
+pub async fn replace_user(
+    State(state): State<Arc<AuthState>>,
+    auth: ScimAuth,
+    extensions: Extensions,
+    Path(id): Path<String>,
+    body: Bytes,
+) -> Response {
+    let result = async {
+        let row = load_user(&state, auth.tenant_id, &id).await?;
+        let user = ScimUser::from_resource(&parse_body(&body)?)?;
+        let trace_id = get_trace_id_from_extensions(&extensions);
+        save_user(
+            &state,
+            auth.tenant_id,
+            row.user_id,
+            row.active,
+            &user,
+            &trace_id,
+        )
+        .await?;
+        let body = render_user(&state, auth.tenant_id, row.user_id).await?;
+        Ok(scim::scim_json(StatusCode::OK, body))
+    }
+    .await;
+    observe(&state, "User", "replace", result)
+}
+
+#[utoipa::path(patch, path = "/api/auth/scim/v2/Users/{id}", tag = "SCIM",
+    security(("scim_token" = [])),
+    params(("id" = Uuid, Path, description = "User id")),
+    request_body(content = Object, content_type = "application/scim+json", description = "SCIM PatchOp"),
+    responses(
+        (status = 200, description = "User patched", content_type = "application/scim+json"),
+        (status = 400, description = "invalidSyntax / invalidPath / invalidValue"),
+        (status = 404, description = "Unknown or deleted user"),
+    ))]
+pub async fn patch_user(
+    State(state): State<Arc<AuthState>>,
+    auth: ScimAuth,
+    extensions: Extensions,
+    Path(id): Path<String>,
+    body: Bytes,
+) -> Response {
+    let result = async {
+        let row = load_user(&state, auth.tenant_id, &id).await?;
+        let patch = parse_patch(&body)?;
+        let mut user = row.attributes();
+        user.apply_patch(&patch)?;
+        if user != row.attributes() {
+            let trace_id = get_trace_id_from_extensions(&extensions);
+            save_user(
+                &state,
+                auth.tenant_id,
+                row.user_id,
+                row.active,
+                &user,
+                &trace_id,
+            )
+            .await?;
+        }
+        let body = render_user(&state, auth.tenant_id, row.user_id).await?;
+        Ok(scim::scim_json(StatusCode::OK, body))
+    }
+    .await;
+    observe(&state, "User", "patch", result)
+}
+
+#[utoipa::path(delete, path = "/api/auth/scim/v2/Users/{id}", tag = "SCIM",
+    security(("scim_token" = [])),
+    params(("id" = Uuid, Path, description = "User id")),
+    responses(
+        (status = 204, description = "User deactivated, removed from groups and hidden from SCIM"),
+        (status = 404, description = "Unknown or deleted user"),
+    ))]
+pub async fn delete_user(
+    State(state): State<Arc<AuthState>>,
+    auth: ScimAuth,
+    extensions: Extensions,
+    Path(id): Path<String>,
+) -> Response {
+    let result = async {
+        let tenant_id = auth.tenant_id;
+        let row = load_user(&state, tenant_id, &id).await?;
+        let trace_id = get_trace_id_from_extensions(&extensions);
+        let mut user = row.attributes();
+        user.active = false;
+
+        let mut tx = state.db.begin().await?;
+        write_user_tx(
+            &mut tx,
+            &state,
+            tenant_id,
+            row.user_id,
+            row.active,
+            &user,
+            &trace_id,
+        )
+        .await?;
+        let roles = repo::remove_user_from_groups(&mut tx, tenant_id, row.user_id).await?;
+        repo::mark_user_deleted(&mut tx, tenant_id, row.user_id).await?;
+        tx.commit().await?;
+
+        for role_id in roles {
+            revoke_role(&state, tenant_id, row.user_id, role_id, &trace_id).await?;
+        }
+        tracing::info!(tenant_id = %tenant_id, user_id = %row.user_id, "auth.scim_user_deleted");
+        Ok(StatusCode::NO_CONTENT.into_response())
+    }
+    .await;
+    observe(&state, "User", "delete", result)
+}
+
+// ── Groups ──────────────────────────────────────────────────────────────────
+
+async fn grant_role(
+    state: &AuthState,
+    tenant_id: Uuid,
+    user_id: Uuid,
+    role_id: Uuid,
+    trace_id: &str,
+) -> Result<(), ScimError> {
+    // Keep an existing (possibly data-scoped) grant as it is.
+    let held = rbac::list_roles_for_user(&state.db, tenant_id, user_id)
+        .await?
+        .iter()
+        .any(|r| r.id == role_id);
+    if !held {
+        let ctx = lifecycle_ctx(
+            state,
+            trace_id,
+            format!("scim-role-grant:{user_id}:{role_id}:{}", Uuid::new_v4()),
+        );
+        rbac::bind_user_role_with_audit(&state.db, tenant_id, user_id, role_id, None, &ctx).await?;
+    }
+    Ok(())
+}
+
+async fn revoke_role(
+    state: &AuthState,
+    tenant_id: Uuid,
+    user_id: Uuid,
+    role_id: Uuid,
+    trace_id: &str,
+) -> Result<(), ScimError> {
+    let ctx = lifecycle_ctx(
+        state,
+        trace_id,
+        format!("scim-role-revoke:{user_id}:{role_id}:{}", Uuid::new_v4()),
+    );
+    rbac::revoke_user_role_with_audit(&state.db, tenant_id, user_id, role_id, None, &ctx).await?;
+    Ok(())
+}
+
+async fn check_members(
+    state: &AuthState,
+    tenant_id: Uuid,
+    group: &ScimGroup,
+) -> Result<(), ScimError> {
+    if group.display_name.chars().count() > 128 {
+        return Err(ScimError::bad_request(
+            "invalidValue",
+            "displayName must be at most 128 characters",
+        ));
+    }
+    let known = repo::existing_users(&state.db, tenant_id, &group.members).await?;
+    if let Some(missing) = group.members.iter().find(|m| !known.contains(m)) {
+        return Err(ScimError::bad_request(
+            "invalidValue",
+            format!("member {missing} is not a user of this tenant"),
+        ));
+    }
+    Ok(())
+}
+
+/// Persist group attributes and members, then grant / revoke the group's role
+/// for members that joined / left.
+async fn save_group(
+    state: &AuthState,
+    row: &ScimGroupRow,
+    group: &ScimGroup,
+    trace_id: &str,
+) -> Result<(), ScimError> {
+    check_members(state, row.tenant_id, group).await?;
+    let mut tx = state.db.begin().await?;
+    repo::update_group(
+        &mut tx,
+        row.id,
+        &group.display_name,
+        group.external_id.as_deref(),
+    )
+    .await?;
+    let (added, removed) = repo::set_members(&mut tx, row.id, &group.members).await?;
+    tx.commit().await?;
+
+    for user_id in added {
+        grant_role(state, row.tenant_id, user_id, row.role_id, trace_id).await?;
+    }
+    for user_id in removed {
+        revoke_role(state, row.tenant_id, user_id, row.role_id, trace_id).await?;
+    }
+    Ok(())
+}
+
+async fn load_group(
+    state: &AuthState,
+    tenant_id: Uuid,
+    id: &str,
+) -> Result<ScimGroupRow, ScimError> {
+    let not_found = || ScimError::not_found(format!("Group {id} not found"));
+    let group_id = Uuid::parse_str(id).map_err(|_| not_found())?;
+    repo::get_group(&state.db, tenant_id, group_id)
+        .await?
+        .ok_or_else(not_found)
+}
+
+async fn render_group(
+    state: &AuthState,
+    tenant_id: Uuid,
+    group_id: Uuid,
+) -> Result<Value, ScimError> {
+    let row = repo::get_group(&state.db, tenant_id, group_id)
+        .await?
+        .ok_or_else(|| ScimError::internal("group vanished after write"))?;
+    let members = repo::list_members(&state.db, group_id).await?;
+    Ok(group_json(&row, Some(&members)))
+}
+
+#[utoipa::path(get, path = "/api/auth/scim/v2/Groups", tag = "SCIM",
+    security(("scim_token" = [])),
+    params(ListQuery),
+    responses(
+        (status = 200, description = "ListResponse of groups", content_type = "application/scim+json"),
+        (status = 400, description = "invalidFilter"),
+        (status = 401, description = "Missing or invalid SCIM token"),
+    ))]
+pub async fn list_groups(
+    State(state): State<Arc<AuthState>>,
+    auth: ScimAuth,
+    Query(q): Query<ListQuery>,
+) -> Response {
+    let result = async {
+        let (filter, start_index, count) = q.resolve(&repo::group_column)?;
+        let (total, rows) = repo::list_groups(
+            &state.db,
+            auth.tenant_id,
+            filter.as_ref(),
+            start_index - 1,
+            count,
+        )
+        .await?;
+        let resources = rows.iter().map(|r| group_json(r, None)).collect();
+        Ok(list_response(total, start_index, resources))
+    }
+    .await;
+    observe(&state, "Group", "list", result)
+}
+
+#[utoipa::path(get, path = "/api/auth/scim/v2/Groups/{id}", tag = "SCIM",
+    security(("scim_token" = [])),
+    params(("id" = Uuid, Path, description = "Group id")),
+    responses(
+        (status = 200, description = "Group resource with members", content_type = "application/scim+json"),
+        (status = 404, description = "Unknown group"),
+    ))]
+pub async fn get_group(
+    State(state): State<Arc<AuthState>>,
+    auth: ScimAuth,
+    Path(id): Path<String>,
+) -> Response {
+    let result = async {
+        let row = load_group(&state, auth.tenant_id, &id).await?;
+        let body = render_group(&state, auth.tenant_id, row.id).await?;
+        Ok(scim::scim_json(StatusCode::OK, body))
+    }
+    .await;
+    observe(&state, "Group", "get", result)
+}
+
+#[utoipa::path(post, path = "/api/auth/scim/v2/Groups", tag = "SCIM",
+    security(("scim_token" = [])),
+    request_body(content = Object, content_type = "application/scim+json", description = "SCIM Group resource"),
+    responses(
+        (status = 201, description = "Group created, backed by the tenant role of the same name", content_type = "application/scim+json"),
+        (status = 400, description = "invalidValue (e.g. unknown member)"),
+        (status = 409, description = "displayName taken, or its role already backs a group"),
+    ))]
+pub async fn create_group(
+    State(state): State<Arc<AuthState>>,
+    auth: ScimAuth,
+    extensions: Extensions,
+    body: Bytes,
+) -> Response {
+    let result = async {
+        let tenant_id = auth.tenant_id;
+        let group = ScimGroup::from_resource(&parse_body(&body)?)?;
+        check_members(&state, tenant_id, &group).await?;
+        let trace_id = get_trace_id_from_extensions(&extensions);
+
+        let role = match rbac::get_role_by_name(&state.db, tenant_id, &group.display_name).await? {
+            Some(role) => role,
+            None => {
+                rbac::create_role(
+                    &state.db,
+                    tenant_id,
+                    &group.display_name,
+                    "Provisioned by SCIM",
+                    false,
+                )
+                .await?
+            }
+        };
+
+        let mut tx = state.db.begin().await?;
+        let row = repo::insert_group(
+            &mut tx,
+            tenant_id,
+            &group.display_name,
+            group.external_id.as_deref(),
+            role.id,
+        )
+        .await?;
+        let (added, _) = repo::set_members(&mut tx, row.id, &group.members).await?;
+        tx.commit().await?;
+
+        for user_id in added {
+            grant_role(&state, tenant_id, user_id, role.id, &trace_id).await?;
+        }
+        tracing::info!(tenant_id = %tenant_id, group_id = %row.id, role_id = %role.id, "auth.scim_group_created");
+        let body = render_group(&state, tenant_id, row.id).await?;
+        Ok(created(group_location(row.id), body))
+    }
+    .await;
+    observe(&state, "Group", "create", result)
+}
+
+#[utoipa::path(put, path = "/api/auth/scim/v2/Groups/{id}", tag = "SCIM",
+    security(("scim_token" = [])),
+    params(("id" = Uuid, Path, description = "Group id")),
+    request_body(content = Object, content_type = "application/scim+json", description = "Full SCIM Group resource"),
+    responses(
+        (status = 200, description = "Group replaced; role grants follow membership", content_type = "application/scim+json"),
+        (status = 404, description = "Unknown group"),
+    ))]
+pub async fn replace_group(
+    State(state): State<Arc<AuthState>>,
+    auth: ScimAuth,
+    extensions: Extensions,
+    Path(id): Path<String>,
+    body: Bytes,
+) -> Response {
+    let result = async {
+        let row = load_group(&state, auth.tenant_id, &id).await?;
+        let group = ScimGroup::from_resource(&parse_body(&body)?)?;
+        let trace_id = get_trace_id_from_extensions(&extensions);
+        save_group(&state, &row, &group, &trace_id).await?;
+        let body = render_group(&state, auth.tenant_id, row.id).await?;
+        Ok(scim::scim_json(StatusCode::OK, body))
+    }
+    .await;
+    observe(&state, "Group", "replace", result)
+}
+
+#[utoipa::path(patch, path = "/api/auth/scim/v2/Groups/{id}", tag = "SCIM",
+    security(("scim_token" = [])),
+    params(("id" = Uuid, Path, description = "Group id")),
+    request_body(content = Object, content_type = "application/scim+json", description = "SCIM PatchOp"),
+    responses(
+        (status = 200, description = "Group patched; role grants follow membership", content_type = "application/scim+json"),
+        (status = 400, description = "invalidSyntax / invalidPath / invalidValue"),
+        (status = 404, description = "Unknown group"),
+    ))]
+pub async fn patch_group(
+    State(state): State<Arc<AuthState>>,
+    auth: ScimAuth,
+    extensions: Extensions,
+    Path(id): Path<String>,
+    body: Bytes,
+) -> Response {
+    let result = async {
+        let row = load_group(&state, auth.tenant_id, &id).await?;
+        let patch = parse_patch(&body)?;
+        let mut group = ScimGroup {
+            display_name: row.display_name.clone(),
+            external_id: row.external_id.clone(),
+            members: repo::list_members(&state.db, row.id).await?,
+        };
+        group.apply_patch(&patch)?;
+        let trace_id = get_trace_id_from_extensions(&extensions);
+        save_group(&state, &row, &group, &trace_id).await?;
+        let body = render_group(&state, auth.tenant_id, row.id).await?;
+        Ok(scim::scim_json(StatusCode::OK, body))
+    }
+    .await;
+    observe(&state, "Group", "patch", result)
+}
+
+#[utoipa::path(delete, path = "/api/auth/scim/v2/Groups/{id}", tag = "SCIM",
+    security(("scim_token" = [])),
+    params(("id" = Uuid, Path, description = "Group id")),
+    responses(
+        (status = 204, description = "Group deleted; its role is revoked from members but kept"),
+        (status = 404, description = "Unknown group"),
+    ))]
+pub async fn delete_group(
+    State(state): State<Arc<AuthState>>,
+    auth: ScimAuth,
+    extensions: Extensions,
+    Path(id): Path<String>,
+) -> Response {
+    let result = async {
+        let row = load_group(&state, auth.tenant_id, &id).await?;
+        let trace_id = get_trace_id_from_extensions(&extensions);
+        let members = repo::list_members(&state.db, row.id).await?;
+        repo::delete_group(&state.db, row.id).await?;
+        for user_id in members {
+            revoke_role(&state, row.tenant_id, user_id, row.role_id, &trace_id).await?;
+        }
+        tracing::info!(tenant_id = %row.tenant_id, group_id = %row.id, "auth.scim_group_deleted");
+        Ok(StatusCode::NO_CONTENT.into_response())
+    }
+    .await;
+    observe(&state, "Group", "delete", result)
+}
+
+// ── Token administration ────────────────────────────────────────────────────
+
+#[derive(Debug, Deserialize, utoipa::ToSchema)]
+pub struct CreateScimTokenReq {
+    /// Must be the calling administrator's tenant.
+    pub tenant_id: Uuid,
+    #[serde(default)]
+    pub description: String,
+}
+
+#[derive(Debug, Serialize, utoipa::ToSchema)]
+pub struct CreatedScimToken {
+    /// Bearer token for the IdP. Shown only in this response.
+    pub token: String,
+    pub metadata: ScimToken,
+}
+
+#[utoipa::path(post, path = "/api/auth/scim/tokens", tag = "SCIM",
+    request_body = CreateScimTokenReq,
+    responses(
+        (status = 201, description = "Token issued; the raw value is not retrievable later", body = CreatedScimToken),
+        (status = 401, description = "Unauthenticated"),
+        (status = 403, description = "Missing admin.security.write, or another tenant"),
+    ))]
+pub async fn create_token(
+    State(state): State<Arc<AuthState>>,
+    admin: AdminSecurityWrite,
+    Json(req): Json<CreateScimTokenReq>,
+) -> Result<impl IntoResponse, ApiErr> {
+    admin.ensure_tenant(req.tenant_id)?;
+    let token = scim::generate_token();
+    let metadata = repo::create_token(
+        &state.db,
+        req.tenant_id,
+        &scim::hash_token(&token),
+        req.description.trim(),
+        Some(admin.user_id),
+    )
+    .await
+    .map_err(db_err)?;
+    state
+        .metrics
+        .auth_scim_requests_total
+        .with_label_values(&["token", "create", "success"])
+        .inc();
+    tracing::info!(tenant_id = %req.tenant_id, token_id = %metadata.id, "auth.scim_token_created");
+    Ok((
+        StatusCode::CREATED,
+        Json(CreatedScimToken { token, metadata }),
+    ))
+}
+
+#[utoipa::path(get, path = "/api/auth/scim/tokens/{tenant_id}", tag = "SCIM",
+    params(("tenant_id" = Uuid, Path, description = "Tenant")),
+    responses(
+        (status = 200, description = "Tokens of the tenant, including revoked ones", body = Vec<ScimToken>),
+        (status = 401, description = "Unauthenticated"),
+        (status = 403, description = "Missing admin.security.write, or another tenant"),
+    ))]
+pub async fn list_tokens(
+    State(state): State<Arc<AuthState>>,
+    admin: AdminSecurityWrite,
+    Path(tenant_id): Path<Uuid>,
+) -> Result<impl IntoResponse, ApiErr> {
+    admin.ensure_tenant(tenant_id)?;
+    let tokens = repo::list_tokens(&state.db, tenant_id)
+        .await
+        .map_err(db_err)?;
+    Ok((StatusCode::OK, Json(tokens)))
+}
+
+#[utoipa::path(delete, path = "/api/auth/scim/tokens/{tenant_id}/{token_id}", tag = "SCIM",
+    params(
+        ("tenant_id" = Uuid, Path, description = "Tenant"),
+        ("token_id" = Uuid, Path, description = "Token"),
+    ),
+    responses(
+        (status = 200, description = "Token revoked", body = OkResponse),
+        (status = 401, description = "Unauthenticated"),
+        (status = 403, description = "Missing admin.security.write, or another tenant"),
+        (status = 404, description = "Unknown or already revoked token"),
+    ))]
+pub async fn revoke_token(
+    State(state): State<Arc<AuthState>>,
+    admin: AdminSecurityWrite,
+    Path((tenant_id, token_id)): Path<(Uuid, Uuid)>,
+) -> Result<impl IntoResponse, ApiErr> {
+    admin.ensure_tenant(tenant_id)?;
+    if !repo::revoke_token(&state.db, tenant_id, token_id)
+        .await
+        .map_err(db_err)?
+    {
+        return Err(err(StatusCode::NOT_FOUND, "SCIM token not found"));
+    }
+    state
+        .metrics
+        .auth_scim_requests_total
+        .with_label_values(&["token", "delete", "success"])
+        .inc();
+    tracing::info!(
+        tenant_id = %tenant_id,
+        token_id = %token_id,
+        revoked_by = %admin.user_id,
+        "auth.scim_token_revoked"
+    );
+    Ok((StatusCode::OK, Json(OkResponse { ok: true })))
+}
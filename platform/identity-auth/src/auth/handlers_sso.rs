@@ -0,0 +1,908 @@
+//! Per-tenant OIDC single sign-on endpoints.
+//!
+//! Flow:
+//! 1. An administrator of the tenant (`admin.security.write`) stores its IdP
+//!    with `PUT /api/auth/sso/providers` (discovery is checked before saving)
+//!    and optionally maps IdP groups to tenant roles.
+//! 2. `GET /api/auth/sso/{tenant_id}/authorize` stores `state`, `nonce` and a
+//!    PKCE verifier, then redirects the browser to the IdP.
+//! 3. `GET /api/auth/sso/callback` redeems the code, verifies the ID token and
+//!    resolves the local user: an already linked `(issuer, sub)`, else an
+//!    existing account with the same email when the IdP asserts
+//!    `email_verified: true`, else a just-in-time
+//!    provisioned one. Mapped roles are synced from the groups claim.
+//! 4. The session is opened exactly like a password login (`amr` = `fed`,
+//!    plus the IdP's own `mfa`/`otp`/`hwk`). Users who owe a local second
+//!    factor the IdP did not satisfy get the usual MFA challenge instead.
+//!    With `return_to` the callback answers 303 and sets the refresh cookie;
+//!    the front end then calls `/api/auth/refresh` for an access token.
+
+// Sync helpers return the handlers' `ApiErr` (which carries a HeaderMap).
+#![allow(clippy::result_large_err)]
+
+use crate::{
+    db::oidc::{self as repo, GroupRoleMapping, LoginState, OidcProviderView, ProviderUpsert},
+    db::user_lifecycle_audit::{
+        append_lifecycle_event_tx, LifecycleAuditContext, LifecycleEventType,
+    },
+    middleware::tracing::get_trace_id_from_extensions,
+};
+use axum::{
+    extract::{Path, Query, State},
+    http::{header, Extensions, StatusCode},
+    response::{IntoResponse, Redirect, Response},
+    Json,
+};
+use chrono::Utc;
+use event_bus::EventEnvelope;
+use security::claims::amr;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use sqlx::Row;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use super::{
+    authz::AdminSecurityWrite,
+    handlers::{err, open_login_session, ApiErr, AuthState, OkResponse},
+    jwt::SessionAuth,
+    oidc::{self, AuthorizationRequest, IdTokenClaims, OidcClient, OidcError},
+    password::hash_password,
+    sealed::SecretKey,
+};
+
+/// SSO configuration resolved at startup.
+#[derive(Clone)]
+pub struct SsoSettings {
+    /// Seals IdP client secrets at rest; confidential clients cannot be
+    /// configured (or used) without it.
+    pub secret_key: Option<SecretKey>,
+    /// Lifetime of a pending authorization request.
+    pub state_ttl_seconds: i64,
+    pub client: OidcClient,
+}
+
+impl Default for SsoSettings {
+    fn default() -> Self {
+        Self {
+            secret_key: None,
+            state_ttl_seconds: 600,
+            client: OidcClient::default(),
+        }
+    }
+}
+
+// ── Request / response types ────────────────────────────────────────────────
+
+fn default_scopes() -> Vec<String> {
+    ["openid", "email", "profile"].map(String::from).to_vec()
+}
+
+fn default_email_claim() -> String {
+    "email".to_string()
+}
+
+fn default_groups_claim() -> String {
+    "groups".to_string()
+}
+
+fn default_true() -> bool {
+    true
+}
+
+#[derive(Debug, Deserialize, utoipa::ToSchema)]
+pub struct SsoProviderReq {
+    /// Must be the calling administrator's tenant.
+    pub tenant_id: Uuid,
+    pub issuer: String,
+    pub client_id: String,
+    /// Omit to keep the stored secret (or for public PKCE-only clients).
+    #[serde(default)]
+    pub client_secret: Option<String>,
+    /// This service's callback URL as registered at the IdP.
+    pub redirect_uri: String,
+    #[serde(default = "default_scopes")]
+    pub scopes: Vec<String>,
+    /// Dotted claim path holding the user's email.
+    #[serde(default = "default_email_claim")]
+    pub email_claim: String,
+    /// Dotted claim path holding the user's groups.
+    #[serde(default = "default_groups_claim")]
+    pub groups_claim: String,
+    #[serde(default = "default_true")]
+    pub jit_provisioning: bool,
+    #[serde(default = "default_true")]
+    pub link_by_email: bool,
+    #[serde(default)]
+    pub password_login_disabled: bool,
+    #[serde(default = "default_true")]
+    pub enabled: bool,
+}
+
+#[derive(Debug, Deserialize, utoipa::ToSchema)]
+pub struct GroupMappingsReq {
+    pub mappings: Vec<GroupRoleMapping>,
+}
+
+#[derive(Debug, Deserialize, utoipa::IntoParams)]
+pub struct AuthorizeQuery {
+    /// Relative path to redirect to after login (cookie flow).
+    pub return_to: Option<String>,
+}
+
+#[derive(Debug, Deserialize, utoipa::IntoParams)]
+pub struct CallbackQuery {
+    pub code: Option<String>,
+    pub state: Option<String>,
+    pub error: Option<String>,
+    pub error_description: Option<String>,
+}
+
+// ── Helpers ─────────────────────────────────────────────────────────────────
+
+fn db_err(e: sqlx::Error) -> ApiErr {
+    err(StatusCode::INTERNAL_SERVER_ERROR, format!("db error: {e}"))
+}
+
+fn idp_err(e: OidcError) -> ApiErr {
+    err(StatusCode::BAD_GATEWAY, e.to_string())
+}
+
+fn sso_key(state: &AuthState) -> Result<&SecretKey, ApiErr> {
+    state.sso.secret_key.as_ref().ok_or_else(|| {
+        err(
+            StatusCode::SERVICE_UNAVAILABLE,
+            "SSO secret key not configured",
+        )
+    })
+}
+
+fn state_hash(raw: &str) -> String {
+    hex::encode(Sha256::digest(raw.as_bytes()))
+}
+
+/// Only same-origin relative paths, so the callback is not an open redirect.
+fn valid_return_to(path: &str) -> bool {
+    path.starts_with('/') && !path.starts_with("//") && !path.contains('\\')
+}
+
+/// Count a failed SSO login and build its error.
+fn sso_fail(state: &AuthState, reason: &str, code: StatusCode, msg: impl Into<String>) -> ApiErr {
+    state
+        .metrics
+        .auth_sso_login_total
+        .with_label_values(&["failure", reason])
+        .inc();
+    err(code, msg)
+}
+
+fn lifecycle_ctx(state: &AuthState, trace_id: &str, key: String) -> LifecycleAuditContext {
+    LifecycleAuditContext {
+        producer: state.producer.clone(),
+        trace_id: trace_id.to_string(),
+        causation_id: None,
+        idempotency_key: key,
+    }
+}
+
+// ── Provider administration ─────────────────────────────────────────────────
+
+#[utoipa::path(put, path = "/api/auth/sso/providers", tag = "SSO",
+    request_body = SsoProviderReq,
+    responses(
+        (status = 200, description = "Provider stored", body = OidcProviderView),
+        (status = 400, description = "Invalid issuer, redirect URI or claim path"),
+        (status = 401, description = "Unauthenticated"),
+        (status = 403, description = "Missing admin.security.write, or another tenant"),
+        (status = 502, description = "IdP discovery failed"),
+        (status = 503, description = "Client secret given but SSO_SECRET_KEY not configured"),
+    ))]
+pub async fn put_provider(
+    State(state): State<Arc<AuthState>>,
+    admin: AdminSecurityWrite,
+    Json(req): Json<SsoProviderReq>,
+) -> Result<impl IntoResponse, ApiErr> {
+    admin.ensure_tenant(req.tenant_id)?;
+    let issuer = req.issuer.trim();
+    if reqwest::Url::parse(issuer).is_err() {
+        return Err(err(StatusCode::BAD_REQUEST, "issuer must be a URL"));
+    }
+    if reqwest::Url::parse(req.redirect_uri.trim()).is_err() {
+        return Err(err(StatusCode::BAD_REQUEST, "redirect_uri must be a URL"));
+    }
+    if req.client_id.trim().is_empty() {
+        return Err(err(StatusCode::BAD_REQUEST, "client_id is required"));
+    }
+    if req.email_claim.trim().is_empty() || req.groups_claim.trim().is_empty() {
+        return Err(err(
+            StatusCode::BAD_REQUEST,
+            "claim paths must not be empty",
+        ));
+    }
+
+    // Refuse configurations the login flow could never use.
+    state.sso.client.discover(issuer).await.map_err(idp_err)?;
+
+    let sealed = match req.client_secret.as_deref().filter(|s| !s.is_empty()) {
+        Some(secret) => Some(sso_key(&state)?.seal(secret.as_bytes())),
+        None => None,
+    };
+
+    let provider = repo::upsert_provider(
+        &state.db,
+        req.tenant_id,
+        ProviderUpsert {
+            issuer,
+            client_id: req.client_id.trim(),
+            client_secret_enc: sealed.as_deref(),
+            redirect_uri: req.redirect_uri.trim(),
+            scopes: &req.scopes,
+            email_claim: req.email_claim.trim(),
+            groups_claim: req.groups_claim.trim(),
+            jit_provisioning: req.jit_provisioning,
+            link_by_email: req.link_by_email,
+            password_login_disabled: req.password_login_disabled,
+            enabled: req.enabled,
+            updated_by: Some(admin.user_id),
+        },
+    )
+    .await
+    .map_err(db_err)?;
+    let mappings = repo::list_group_mappings(&state.db, req.tenant_id)
+        .await
+        .map_err(db_err)?;
+
+    tracing::info!(
+        tenant_id = %req.tenant_id,
+        issuer = %provider.issuer,
+        password_login_disabled = provider.password_login_disabled,
+        "auth.sso_provider_updated"
+    );
+    Ok((StatusCode::OK, Json(provider.view(mappings))))
+}
+
+#[utoipa::path(get, path = "/api/auth/sso/providers/{tenant_id}", tag = "SSO",
+    params(("tenant_id" = Uuid, Path, description = "Tenant")),
+    responses(
+        (status = 200, description = "Provider configuration (secret redacted)", body = OidcProviderView),
+        (status = 401, description = "Unauthenticated"),
+        (status = 403, description = "Missing admin.security.write, or another tenant"),
+        (status = 404, description = "No provider configured"),
+    ))]
+pub async fn get_provider(
+    State(state): State<Arc<AuthState>>,
+    admin: AdminSecurityWrite,
+    Path(tenant_id): Path<Uuid>,
+) -> Result<impl IntoResponse, ApiErr> {
+    admin.ensure_tenant(tenant_id)?;
+    let provider = repo::get_provider(&state.db, tenant_id)
+        .await
+        .map_err(db_err)?
+        .ok_or_else(|| err(StatusCode::NOT_FOUND, "no SSO provider configured"))?;
+    let mappings = repo::list_group_mappings(&state.db, tenant_id)
+        .await
+        .map_err(db_err)?;
+    Ok((StatusCode::OK, Json(provider.view(mappings))))
+}
+
+#[utoipa::path(delete, path = "/api/auth/sso/providers/{tenant_id}", tag = "SSO",
+    params(("tenant_id" = Uuid, Path, description = "Tenant")),
+    responses(
+        (status = 200, description = "Provider and group mappings removed; linked identities are kept", body = OkResponse),
+        (status = 401, description = "Unauthenticated"),
+        (status = 403, description = "Missing admin.security.write, or another tenant"),
+        (status = 404, description = "No provider configured"),
+    ))]
+pub async fn delete_provider(
+    State(state): State<Arc<AuthState>>,
+    admin: AdminSecurityWrite,
+    Path(tenant_id): Path<Uuid>,
+) -> Result<impl IntoResponse, ApiErr> {
+    admin.ensure_tenant(tenant_id)?;
+    if !repo::delete_provider(&state.db, tenant_id)
+        .await
+        .map_err(db_err)?
+    {
+        return Err(err(StatusCode::NOT_FOUND, "no SSO provider configured"));
+    }
+    tracing::info!(tenant_id = %tenant_id, deleted_by = %admin.user_id, "auth.sso_provider_deleted");
+    Ok((StatusCode::OK, Json(OkResponse { ok: true })))
+}
+
+#[utoipa::path(put, path = "/api/auth/sso/providers/{tenant_id}/group-mappings", tag = "SSO",
+    params(("tenant_id" = Uuid, Path, description = "Tenant")),
+    request_body = GroupMappingsReq,
+    responses(
+        (status = 200, description = "Mappings replaced", body = Vec<GroupRoleMapping>),
+        (status = 400, description = "Empty group or role outside the tenant"),
+        (status = 401, description = "Unauthenticated"),
+        (status = 403, description = "Missing admin.security.write, or another tenant"),
+        (status = 404, description = "No provider configured"),
+    ))]
+pub async fn put_group_mappings(
+    State(state): State<Arc<AuthState>>,
+    admin: AdminSecurityWrite,
+    Path(tenant_id): Path<Uuid>,
+    Json(req): Json<GroupMappingsReq>,
+) -> Result<impl IntoResponse, ApiErr> {
+    admin.ensure_tenant(tenant_id)?;
+    if repo::get_provider(&state.db, tenant_id)
+        .await
+        .map_err(db_err)?
+        .is_none()
+    {
+        return Err(err(StatusCode::NOT_FOUND, "no SSO provider configured"));
+    }
+    for m in &req.mappings {
+        if m.group.trim().is_empty() {
+            return Err(err(StatusCode::BAD_REQUEST, "group must not be empty"));
+        }
+        if crate::db::rbac::get_role(&state.db, tenant_id, m.role_id)
+            .await
+            .map_err(db_err)?
+            .is_none()
+        {
+            return Err(err(
+                StatusCode::BAD_REQUEST,
+                format!("role {} not found in tenant", m.role_id),
+            ));
+        }
+    }
+    repo::replace_group_mappings(&state.db, tenant_id, &req.mappings)
+        .await
+        .map_err(db_err)?;
+    let mappings = repo::list_group_mappings(&state.db, tenant_id)
+        .await
+        .map_err(db_err)?;
+    Ok((StatusCode::OK, Json(mappings)))
+}
+
+// ── Login ───────────────────────────────────────────────────────────────────
+
+#[utoipa::path(get, path = "/api/auth/sso/{tenant_id}/authorize", tag = "SSO",
+    security(()),
+    params(("tenant_id" = Uuid, Path, description = "Tenant"), AuthorizeQuery),
+    responses(
+        (status = 303, description = "Redirect to the IdP authorization endpoint"),
+        (status = 400, description = "return_to is not a relative path"),
+        (status = 404, description = "SSO not enabled for tenant"),
+        (status = 502, description = "IdP discovery failed"),
+    ))]
+pub async fn authorize(
+    State(state): State<Arc<AuthState>>,
+    Path(tenant_id): Path<Uuid>,
+    Query(q): Query<AuthorizeQuery>,
+) -> Result<Response, ApiErr> {
+    if let Some(rt) = q.return_to.as_deref() {
+        if !valid_return_to(rt) {
+            return Err(err(
+                StatusCode::BAD_REQUEST,
+                "return_to must be a relative path",
+            ));
+        }
+    }
+    let provider = repo::get_provider(&state.db, tenant_id)
+        .await
+        .map_err(db_err)?
+        .filter(|p| p.enabled)
+        .ok_or_else(|| err(StatusCode::NOT_FOUND, "SSO not enabled for tenant"))?;
+    let metadata = state
+        .sso
+        .client
+        .discover(&provider.issuer)
+        .await
+        .map_err(idp_err)?;
+
+    let raw_state = oidc::random_token();
+    let login = LoginState {
+        tenant_id,
+        nonce: oidc::random_token(),
+        code_verifier: oidc::random_token(),
+        return_to: q.return_to,
+    };
+    let url = oidc::authorization_url(
+        &metadata,
+        &AuthorizationRequest {
+            client_id: &provider.client_id,
+            redirect_uri: &provider.redirect_uri,
+            scopes: &provider.scopes,
+            state: &raw_state,
+            nonce: &login.nonce,
+            code_verifier: &login.code_verifier,
+        },
+    )
+    .map_err(idp_err)?;
+    repo::create_login_state(
+        &state.db,
+        &state_hash(&raw_state),
+        &login,
+        state.sso.state_ttl_seconds,
+    )
+    .await
+    .map_err(db_err)?;
+
+    Ok(Redirect::to(&url).into_response())
+}
+
+#[utoipa::path(get, path = "/api/auth/sso/callback", tag = "SSO",
+    security(()),
+    params(CallbackQuery),
+    responses(
+        (status = 200, description = "Login successful, or an MFA challenge (`mfa_required: true`)", body = super::handlers::TokenResponse),
+        (status = 303, description = "Login successful; refresh cookie set, redirect to return_to"),
+        (status = 400, description = "IdP error, missing code, or invalid/expired state"),
+        (status = 401, description = "ID token rejected"),
+        (status = 403, description = "No linked account and provisioning disabled, or account inactive"),
+        (status = 502, description = "IdP unreachable or token exchange failed"),
+    ))]
+pub async fn callback(
+    State(state): State<Arc<AuthState>>,
+    extensions: Extensions,
+    Query(q): Query<CallbackQuery>,
+) -> Result<Response, ApiErr> {
+    let trace_id = get_trace_id_from_extensions(&extensions);
+
+    if let Some(e) = q.error.as_deref() {
+        let detail = q.error_description.as_deref().unwrap_or_default();
+        return Err(sso_fail(
+            &state,
+            "idp_error",
+            StatusCode::BAD_REQUEST,
+            format!("identity provider returned {e}: {detail}"),
+        ));
+    }
+    let (Some(code), Some(raw_state)) = (q.code.as_deref(), q.state.as_deref()) else {
+        return Err(sso_fail(
+            &state,
+            "invalid_request",
+            StatusCode::BAD_REQUEST,
+            "code and state are required",
+        ));
+    };
+
+    let login = repo::take_login_state(&state.db, &state_hash(raw_state))
+        .await
+        .map_err(db_err)?
+        .ok_or_else(|| {
+            sso_fail(
+                &state,
+                "invalid_state",
+                StatusCode::BAD_REQUEST,
+                "invalid or expired state",
+            )
+        })?;
+    let tenant_id = login.tenant_id;
+    let provider = repo::get_provider(&state.db, tenant_id)
+        .await
+        .map_err(db_err)?
+        .filter(|p| p.enabled)
+        .ok_or_else(|| {
+            sso_fail(
+                &state,
+                "disabled",
+                StatusCode::NOT_FOUND,
+                "SSO not enabled for tenant",
+            )
+        })?;
+
+    let client_secret = match provider.client_secret_enc.as_deref() {
+        Some(sealed) => {
+            let opened = sso_key(&state)?
+                .open(sealed)
+                .map_err(|e| err(StatusCode::INTERNAL_SERVER_ERROR, e))?;
+            Some(
+                String::from_utf8(opened)
+                    .map_err(|e| err(StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?,
+            )
+        }
+        None => None,
+    };
+
+    let client = &state.sso.client;
+    let metadata = client.discover(&provider.issuer).await.map_err(|e| {
+        sso_fail(
+            &state,
+            "idp_unavailable",
+            StatusCode::BAD_GATEWAY,
+            e.to_string(),
+        )
+    })?;
+    let id_token = client
+        .exchange_code(
+            &metadata,
+            &provider.client_id,
+            client_secret.as_deref(),
+            &provider.redirect_uri,
+            code,
+            &login.code_verifier,
+        )
+        .await
+        .map_err(|e| {
+            sso_fail(
+                &state,
+                "token_exchange",
+                StatusCode::BAD_GATEWAY,
+                e.to_string(),
+            )
+        })?;
+    let claims = client
+        .validate_id_token(
+            &provider.issuer,
+            &provider.client_id,
+            &id_token,
+            &login.nonce,
+        )
+        .await
+        .map_err(|e| {
+            let code = match e {
+                OidcError::InvalidIdToken(_) => StatusCode::UNAUTHORIZED,
+                _ => StatusCode::BAD_GATEWAY,
+            };
+            sso_fail(&state, "invalid_id_token", code, e.to_string())
+        })?;
+
+    let user_id = resolve_user(&state, &provider, &claims, &trace_id).await?;
+    sync_group_roles(&state, &provider, &claims, user_id, &trace_id).await?;
+
+    // Local MFA obligations still apply unless the IdP already did MFA.
+    let idp_amr = claims.amr();
+    let idp_mfa = idp_amr.iter().any(|a| a == amr::MFA);
+    let mfa_status = crate::db::mfa::status(&state.db, tenant_id, user_id)
+        .await
+        .map_err(db_err)?;
+    if mfa_status.challenge_required() && !idp_mfa {
+        state
+            .metrics
+            .auth_sso_login_total
+            .with_label_values(&["mfa_required", "challenge"])
+            .inc();
+        return super::handlers_mfa::begin_login_challenge(&state, tenant_id, user_id, &mfa_status)
+            .await;
+    }
+
+    let mut auth = SessionAuth {
+        amr: vec![oidc::AMR_FEDERATED.to_string()],
+        auth_time: Some(claims.auth_time().unwrap_or_else(Utc::now)),
+    };
+    for m in idp_amr
+        .iter()
+        .filter(|m| [amr::MFA, amr::OTP, amr::HWK].contains(&m.as_str()))
+    {
+        if !auth.amr.contains(m) {
+            auth.amr.push(m.clone());
+        }
+    }
+
+    let session =
+        open_login_session(&state, &extensions, tenant_id, user_id, auth, trace_id).await?;
+    state
+        .metrics
+        .auth_sso_login_total
+        .with_label_values(&["success", "ok"])
+        .inc();
+
+    match login.return_to {
+        Some(return_to) if session.status().is_success() => {
+            let mut redirect = Redirect::to(&return_to).into_response();
+            for cookie in session.headers().get_all(header::SET_COOKIE) {
+                redirect
+                    .headers_mut()
+                    .append(header::SET_COOKIE, cookie.clone());
+            }
+            Ok(redirect)
+        }
+        _ => Ok(session),
+    }
+}
+
+/// Map the verified identity to a local user: linked identity, then verified
+/// email, then just-in-time provisioning.
+async fn resolve_user(
+    state: &Arc<AuthState>,
+    provider: &repo::OidcProvider,
+    claims: &IdTokenClaims,
+    trace_id: &str,
+) -> Result<Uuid, ApiErr> {
+    let tenant_id = provider.tenant_id;
+    let subject = claims.subject().unwrap_or_default();
+    let email = claims
+        .string(&provider.email_claim)
+        .map(|e| e.trim().to_lowercase())
+        .filter(|e| e.contains('@'));
+
+    if let Some(user_id) = repo::find_identity_user(&state.db, tenant_id, &claims.issuer, &subject)
+        .await
+        .map_err(db_err)?
+    {
+        ensure_active(state, tenant_id, user_id).await?;
+        repo::touch_identity(
+            &state.db,
+            tenant_id,
+            &claims.issuer,
+            &subject,
+            email.as_deref(),
+        )
+        .await
+        .map_err(db_err)?;
+        return Ok(user_id);
+    }
+
+    let Some(email) = email else {
+        return Err(sso_fail(
+            state,
+            "no_email",
+            StatusCode::FORBIDDEN,
+            format!("id_token has no usable `{}` claim", provider.email_claim),
+        ));
+    };
+
+    let existing =
+        sqlx::query("SELECT user_id FROM credentials WHERE tenant_id = $1 AND email = $2")
+            .bind(tenant_id)
+            .bind(&email)
+            .fetch_optional(&state.db)
+            .await
+            .map_err(db_err)?
+            .map(|r| r.get::<Uuid, _>("user_id"));
+
+    if let Some(user_id) = existing {
+        if !provider.link_by_email || claims.email_verified() != Some(true) {
+            return Err(sso_fail(
+                state,
+                "link_refused",
+                StatusCode::FORBIDDEN,
+                "an account with this email exists but cannot be linked automatically",
+            ));
+        }
+        ensure_active(state, tenant_id, user_id).await?;
+
+        let mut tx = state.db.begin().await.map_err(db_err)?;
+        repo::link_identity(
+            &mut tx,
+            tenant_id,
+            user_id,
+            &claims.issuer,
+            &subject,
+            Some(&email),
+        )
+        .await
+        .map_err(db_err)?;
+        append_lifecycle_event_tx(
+            &mut tx,
+            tenant_id,
+            user_id,
+            LifecycleEventType::SsoIdentityLinked,
+            None,
+            None,
+            None,
+            None,
+            serde_json::json!({ "issuer": claims.issuer, "subject": subject, "email": email }),
+            &lifecycle_ctx(
+                state,
+                trace_id,
+                format!("sso-link:{tenant_id}:{}:{subject}", claims.issuer),
+            ),
+        )
+        .await
+        .map_err(db_err)?;
+        tx.commit().await.map_err(db_err)?;
+
+        tracing::info!(tenant_id = %tenant_id, user_id = %user_id, "auth.sso_identity_linked");
+        return Ok(user_id);
+    }
+
+    if !provider.jit_provisioning {
+        return Err(sso_fail(
+            state,
+            "not_provisioned",
+            StatusCode::FORBIDDEN,
+            "no account for this identity and provisioning is disabled",
+        ));
+    }
+    provision_user(state, provider, claims, &subject, &email, trace_id).await
+}
+
+async fn ensure_active(state: &AuthState, tenant_id: Uuid, user_id: Uuid) -> Result<(), ApiErr> {
+    let active: Option<bool> = sqlx::query_scalar(
+        "SELECT is_active FROM credentials WHERE tenant_id = $1 AND user_id = $2",
+    )
+    .bind(tenant_id)
+    .bind(user_id)
+    .fetch_optional(&state.db)
+    .await
+    .map_err(db_err)?;
+    match active {
+        Some(true) => Ok(()),
+        Some(false) => Err(sso_fail(
+            state,
+            "inactive",
+            StatusCode::FORBIDDEN,
+            "account inactive",
+        )),
+        None => Err(sso_fail(
+            state,
+            "not_found",
+            StatusCode::FORBIDDEN,
+            "linked account no longer exists",
+        )),
+    }
+}
+
+/// Create a credentials row for a first-time federated user. The password is
+/// random and never disclosed; the user can still set one via password reset
+/// unless the tenant disables password login.
+async fn provision_user(
+    state: &Arc<AuthState>,
+    provider: &repo::OidcProvider,
+    claims: &IdTokenClaims,
+    subject: &str,
+    email: &str,
+    trace_id: &str,
+) -> Result<Uuid, ApiErr> {
+    let tenant_id = provider.tenant_id;
+    let user_id = Uuid::new_v4();
+
+    let hash = {
+        let _permit = state
+            .hash_limiter
+            .acquire()
+            .await
+            .map_err(|_| err(StatusCode::SERVICE_UNAVAILABLE, "auth busy"))?;
+        hash_password(&state.pwd, &oidc::random_token())
+            .map_err(|e| err(StatusCode::INTERNAL_SERVER_ERROR, e))?
+    };
+
+    let mut tx = state.db.begin().await.map_err(db_err)?;
+    sqlx::query(
+        r#"
+        INSERT INTO credentials (tenant_id, user_id, email, password_hash)
+        VALUES ($1, $2, $3, $4)
+        "#,
+    )
+    .bind(tenant_id)
+    .bind(user_id)
+    .bind(email)
+    .bind(hash)
+    .execute(&mut *tx)
+    .await
+    .map_err(|e| match e.as_database_error().and_then(|d| d.code()) {
+        Some(code) if code == "23505" => err(StatusCode::CONFLICT, "credential already exists"),
+        _ => db_err(e),
+    })?;
+    repo::link_identity(
+        &mut tx,
+        tenant_id,
+        user_id,
+        &claims.issuer,
+        subject,
+        Some(email),
+    )
+    .await
+    .map_err(db_err)?;
+    append_lifecycle_event_tx(
+        &mut tx,
+        tenant_id,
+        user_id,
+        LifecycleEventType::UserCreated,
+        None,
+        None,
+        None,
+        None,
+        serde_json::json!({
+            "user_id": user_id.to_string(),
+            "email": email,
+            "source": "oidc",
+            "issuer": claims.issuer,
+        }),
+        &lifecycle_ctx(
+            state,
+            trace_id,
+            format!("sso-provision:{tenant_id}:{}:{subject}", claims.issuer),
+        ),
+    )
+    .await
+    .map_err(db_err)?;
+    tx.commit().await.map_err(db_err)?;
+
+    #[derive(Serialize)]
+    struct Data {
+        user_id: String,
+        email: String,
+    }
+    let env = EventEnvelope::new(
+        tenant_id.to_string(),
+        state.producer.clone(),
+        "auth.user_registered".to_string(),
+        Data {
+            user_id: user_id.to_string(),
+            email: email.to_string(),
+        },
+    )
+    .with_schema_version("1.0.0".to_string())
+    .with_trace_id(Some(trace_id.to_string()))
+    .with_mutation_class(Some("user-data".to_string()));
+    if state
+        .events
+        .publish("auth.user_registered", "auth.user.registered.v1.json", &env)
+        .await
+        .is_err()
+    {
+        state
+            .metrics
+            .auth_nats_publish_fail_total
+            .with_label_values(&["auth.user_registered"])
+            .inc();
+    }
+
+    tracing::info!(tenant_id = %tenant_id, user_id = %user_id, "auth.sso_user_provisioned");
+    Ok(user_id)
+}
+
+/// Grant mapped roles the user's groups call for and revoke mapped roles they
+/// no longer do. Roles no mapping mentions are left alone.
+async fn sync_group_roles(
+    state: &AuthState,
+    provider: &repo::OidcProvider,
+    claims: &IdTokenClaims,
+    user_id: Uuid,
+    trace_id: &str,
+) -> Result<(), ApiErr> {
+    let tenant_id = provider.tenant_id;
+    let mappings = repo::list_group_mappings(&state.db, tenant_id)
+        .await
+        .map_err(db_err)?;
+    if mappings.is_empty() {
+        return Ok(());
+    }
+    let groups = claims.string_list(&provider.groups_claim);
+    let (desired, managed) = repo::resolve_mapped_roles(&mappings, &groups);
+    let held: Vec<Uuid> = crate::db::rbac::list_roles_for_user(&state.db, tenant_id, user_id)
+        .await
+        .map_err(db_err)?
+        .into_iter()
+        .map(|r| r.id)
+        .collect();
+
+    for role_id in desired.iter().filter(|r| !held.contains(r)) {
+        let ctx = lifecycle_ctx(
+            state,
+            trace_id,
+            format!("sso-role-grant:{user_id}:{role_id}:{}", Uuid::new_v4()),
+        );
+        crate::db::rbac::bind_user_role_with_audit(
+            &state.db, tenant_id, user_id, *role_id, None, &ctx,
+        )
+        .await
+        .map_err(db_err)?;
+    }
+    for role_id in managed
+        .iter()
+        .filter(|r| held.contains(r) && !desired.contains(r))
+    {
+        let ctx = lifecycle_ctx(
+            state,
+            trace_id,
+            format!("sso-role-revoke:{user_id}:{role_id}:{}", Uuid::new_v4()),
+        );
+        crate::db::rbac::revoke_user_role_with_audit(
+            &state.db, tenant_id, user_id, *role_id, None, &ctx,
+        )
+        .await
+        .map_err(db_err)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn return_to_must_stay_on_origin() {
+        assert!(valid_return_to("/app/home?tab=1"));
+        assert!(!valid_return_to("//evil.example/x"));
+        assert!(!valid_return_to("/\\evil.example"));
+        assert!(!valid_return_to("https://evil.example"));
+        assert!(!valid_return_to("app"));
+    }
+}
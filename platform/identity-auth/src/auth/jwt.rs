@@ -1,4 +1,4 @@
-use chrono::{Duration, Utc};
+use chrono::{DateTime, Duration, Utc};
 use jsonwebtoken::{Algorithm, DecodingKey, EncodingKey, Header, Validation};
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
@@ -9,9 +9,11 @@ use base64::Engine as _;
 use rsa::pkcs8::DecodePublicKey;
 use rsa::traits::PublicKeyParts;
 use rsa::RsaPublicKey;
+use security::claims::amr;
+use security::DataScopes;
 
 /// Current claims schema version. Bump when adding/removing fields.
-pub const CLAIMS_VERSION: &str = "2";
+pub const CLAIMS_VERSION: &str = "4";
 
 /// Actor type constants — aligned with EventEnvelope `actor_type`.
 #[allow(dead_code)]
@@ -46,8 +48,19 @@ pub struct AccessClaims {
     pub app_id: Option<String>,
     pub roles: Vec<String>,
     pub perms: Vec<String>,
+    /// Per-permission data scopes from scoped role grants (v3+).
+    #[serde(default, skip_serializing_if = "DataScopes::is_empty")]
+    pub scopes: DataScopes,
     pub actor_type: String,
 
+    // ── Authentication context (v4+) ──
+    /// Authentication methods of the session (RFC 8176: pwd, otp, hwk, mfa).
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub amr: Vec<String>,
+    /// Last interactive authentication (login or MFA step-up), Unix timestamp.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub auth_time: Option<i64>,
+
     // ── Audit enrichment ──
     #[serde(skip_serializing_if = "Option::is_none")]
     pub session_id: Option<String>,
@@ -58,6 +71,37 @@ pub struct AccessClaims {
     pub ver: String,
 }
 
+/// How the session behind a token authenticated, and when.
+///
+/// Stored on `refresh_tokens` / `refresh_sessions` rows so refreshed access
+/// tokens keep the `amr` / `auth_time` of the login or latest step-up.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SessionAuth {
+    pub amr: Vec<String>,
+    pub auth_time: Option<DateTime<Utc>>,
+}
+
+impl SessionAuth {
+    /// Password-only login at `at`.
+    pub fn password(at: DateTime<Utc>) -> Self {
+        Self {
+            amr: vec![amr::PWD.to_string()],
+            auth_time: Some(at),
+        }
+    }
+
+    /// Record a verified second factor (`otp` or `hwk`) at `at`.
+    pub fn with_second_factor(mut self, method: &str, at: DateTime<Utc>) -> Self {
+        for m in [method, amr::MFA] {
+            if !self.amr.iter().any(|a| a == m) {
+                self.amr.push(m.to_string());
+            }
+        }
+        self.auth_time = Some(at);
+        self
+    }
+}
+
 /// Compute a deterministic snapshot ID from a sorted set of role names.
 /// Used to detect stale tokens when role assignments change.
 pub fn compute_role_snapshot_id(roles: &[String]) -> String {
@@ -161,6 +205,8 @@ impl JwtKeys {
             user_id,
             roles,
             perms,
+            DataScopes::new(),
+            &SessionAuth::default(),
             actor_type,
             ttl_minutes,
             None,
@@ -174,6 +220,8 @@ impl JwtKeys {
         user_id: Uuid,
         roles: Vec<String>,
         perms: Vec<String>,
+        scopes: DataScopes,
+        auth: &SessionAuth,
         actor_type: &str,
         ttl_minutes: i64,
         session_id: Option<Uuid>,
@@ -193,7 +241,10 @@ impl JwtKeys {
             jti: Uuid::new_v4().to_string(),
             roles,
             perms,
+            scopes,
             actor_type: actor_type.to_string(),
+            amr: auth.amr.clone(),
+            auth_time: auth.auth_time.map(|t| t.timestamp()),
             session_id: session_id.map(|s| s.to_string()),
             role_snapshot_id,
             ver: CLAIMS_VERSION.to_string(),
@@ -308,6 +359,9 @@ mod tests {
         // Basic sign_access_token doesn't set enriched fields
         assert!(decoded.session_id.is_none());
         assert!(decoded.role_snapshot_id.is_none());
+        assert!(decoded.scopes.is_empty());
+        assert!(decoded.amr.is_empty());
+        assert!(decoded.auth_time.is_none());
     }
 
     #[test]
@@ -317,8 +371,14 @@ mod tests {
         let user = Uuid::new_v4();
         let session = Uuid::new_v4();
         let roles = vec!["operator".to_string(), "admin".to_string()];
-        let perms = vec![];
+        let perms = vec!["ap.bills.approve".to_string()];
         let snapshot = compute_role_snapshot_id(&roles);
+        let grant = [("business_unit".to_string(), vec!["EAST".to_string()])]
+            .into_iter()
+            .collect();
+        let scopes = DataScopes::new().restrict("ap.bills.approve", grant);
+        let login_at = Utc::now() - Duration::minutes(3);
+        let auth = SessionAuth::password(login_at).with_second_factor(amr::OTP, login_at);
 
         let token = keys
             .sign_access_token_enriched(
@@ -326,6 +386,8 @@ mod tests {
                 user,
                 roles.clone(),
                 perms,
+                scopes.clone(),
+                &auth,
                 actor_type::USER,
                 15,
                 Some(session),
@@ -339,6 +401,9 @@ mod tests {
             Some(session.to_string().as_str())
         );
         assert_eq!(decoded.role_snapshot_id.as_deref(), Some(snapshot.as_str()));
+        assert_eq!(decoded.scopes, scopes);
+        assert_eq!(decoded.amr, vec!["pwd", "otp", "mfa"]);
+        assert_eq!(decoded.auth_time, Some(login_at.timestamp()));
     }
 
     #[test]
@@ -354,7 +419,7 @@ mod tests {
 
     #[test]
     fn claims_version_is_set() {
-        assert_eq!(CLAIMS_VERSION, "2");
+        assert_eq!(CLAIMS_VERSION, "4");
     }
 
     #[test]
@@ -423,7 +488,10 @@ mod tests {
             app_id: None,
             roles: vec![],
             perms: vec![],
+            scopes: DataScopes::new(),
             actor_type: "user".to_string(),
+            amr: vec![],
+            auth_time: None,
             session_id: None,
             role_snapshot_id: None,
             ver: "2".to_string(),
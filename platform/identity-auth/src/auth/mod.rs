@@ -2,12 +2,20 @@ pub mod authz;
 pub mod concurrency;
 pub mod cookies;
 pub mod handlers;
+pub mod handlers_mfa;
 pub mod handlers_password_reset;
+pub mod handlers_scim;
+pub mod handlers_sso;
 pub mod jwt;
+pub mod oidc;
 pub mod password;
 pub mod password_policy;
 pub mod password_reset_repo;
 pub mod password_reset_tokens;
 pub mod refresh;
 pub mod refresh_sessions;
+pub mod scim;
+pub mod sealed;
 pub mod session;
+pub mod totp;
+pub mod webauthn;
@@ -0,0 +1,452 @@
+//! OpenID Connect relying-party client (authorization code flow with PKCE).
+//!
+//! - Discovery (`/.well-known/openid-configuration`) and the provider JWKS are
+//!   cached per issuer; an ID token signed with an unknown `kid` forces one
+//!   JWKS refetch so IdP key rotation needs no restart.
+//! - ID tokens are verified against the JWKS with an asymmetric algorithm
+//!   (RS*/PS*/ES*), the discovered issuer, `aud` = client id and the `nonce`
+//!   stored with the authorization request.
+//! - Claim lookups accept dotted paths (`realm_access.roles`) so tenants can
+//!   point at wherever their IdP puts email and groups.
+
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine};
+use chrono::{DateTime, TimeZone, Utc};
+use dashmap::DashMap;
+use jsonwebtoken::{
+    decode, decode_header,
+    jwk::{Jwk, JwkSet},
+    Algorithm, DecodingKey, Validation,
+};
+use rand::RngCore;
+use reqwest::Url;
+use serde::Deserialize;
+use serde_json::{Map, Value};
+use sha2::{Digest, Sha256};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+/// `amr` value recorded on sessions established through an external IdP.
+pub const AMR_FEDERATED: &str = "fed";
+
+/// Clock skew tolerated on `exp` / `iat` / `nbf`.
+const LEEWAY_SECS: u64 = 60;
+
+const ALLOWED_ALGS: &[Algorithm] = &[
+    Algorithm::RS256,
+    Algorithm::RS384,
+    Algorithm::RS512,
+    Algorithm::PS256,
+    Algorithm::PS384,
+    Algorithm::PS512,
+    Algorithm::ES256,
+    Algorithm::ES384,
+];
+
+#[derive(Debug, thiserror::Error)]
+pub enum OidcError {
+    #[error("discovery failed: {0}")]
+    Discovery(String),
+    #[error("issuer mismatch: configured {configured}, discovered {discovered}")]
+    IssuerMismatch {
+        configured: String,
+        discovered: String,
+    },
+    #[error("jwks fetch failed: {0}")]
+    Jwks(String),
+    #[error("token exchange failed: {0}")]
+    TokenExchange(String),
+    #[error("invalid id_token: {0}")]
+    InvalidIdToken(String),
+}
+
+/// The subset of provider metadata the code flow needs.
+#[derive(Debug, Clone, Deserialize)]
+pub struct ProviderMetadata {
+    pub issuer: String,
+    pub authorization_endpoint: String,
+    pub token_endpoint: String,
+    pub jwks_uri: String,
+}
+
+struct CachedProvider {
+    metadata: ProviderMetadata,
+    jwks: JwkSet,
+    fetched_at: Instant,
+}
+
+/// Shared, cloneable OIDC client with a per-issuer discovery/JWKS cache.
+#[derive(Clone)]
+pub struct OidcClient {
+    http: reqwest::Client,
+    cache: Arc<DashMap<String, Arc<CachedProvider>>>,
+    cache_ttl: Duration,
+}
+
+impl Default for OidcClient {
+    fn default() -> Self {
+        Self::new(Duration::from_secs(3600))
+    }
+}
+
+impl OidcClient {
+    pub fn new(cache_ttl: Duration) -> Self {
+        let http = reqwest::Client::builder()
+            .timeout(Duration::from_secs(10))
+            .build()
+            .unwrap_or_default();
+        Self {
+            http,
+            cache: Arc::new(DashMap::new()),
+            cache_ttl,
+        }
+    }
+
+    /// Provider metadata for `issuer`, from cache when fresh.
+    pub async fn discover(&self, issuer: &str) -> Result<ProviderMetadata, OidcError> {
+        Ok(self.provider(issuer, false).await?.metadata.clone())
+    }
+
+    async fn provider(&self, issuer: &str, force: bool) -> Result<Arc<CachedProvider>, OidcError> {
+        let key = normalize_issuer(issuer).to_string();
+        if !force {
+            if let Some(hit) = self.cache.get(&key) {
+                if hit.fetched_at.elapsed() < self.cache_ttl {
+                    return Ok(hit.clone());
+                }
+            }
+        }
+
+        let url = format!("{key}/.well-known/openid-configuration");
+        let metadata: ProviderMetadata = self
+            .http
+            .get(&url)
+            .send()
+            .await
+            .and_then(|r| r.error_for_status())
+            .map_err(|e| OidcError::Discovery(e.to_string()))?
+            .json()
+            .await
+            .map_err(|e| OidcError::Discovery(e.to_string()))?;
+        if normalize_issuer(&metadata.issuer) != key {
+            return Err(OidcError::IssuerMismatch {
+                configured: issuer.to_string(),
+                discovered: metadata.issuer,
+            });
+        }
+
+        let jwks: JwkSet = self
+            .http
+            .get(&metadata.jwks_uri)
+            .send()
+            .await
+            .and_then(|r| r.error_for_status())
+            .map_err(|e| OidcError::Jwks(e.to_string()))?
+            .json()
+            .await
+            .map_err(|e| OidcError::Jwks(e.to_string()))?;
+
+        let entry = Arc::new(CachedProvider {
+            metadata,
+            jwks,
+            fetched_at: Instant::now(),
+        });
+        self.cache.insert(key, entry.clone());
+        Ok(entry)
+    }
+
+    /// Redeem an authorization code (`client_secret_post` when a secret is
+    /// configured, public client otherwise). Returns the raw ID token.
+    pub async fn exchange_code(
+        &self,
+        metadata: &ProviderMetadata,
+        client_id: &str,
+        client_secret: Option<&str>,
+        redirect_uri: &str,
+        code: &str,
+        code_verifier: &str,
+    ) -> Result<String, OidcError> {
+        #[derive(Deserialize)]
+        struct TokenResponse {
+            id_token: Option<String>,
+        }
+
+        let mut form = vec![
+            ("grant_type", "authorization_code"),
+            ("code", code),
+            ("redirect_uri", redirect_uri),
+            ("client_id", client_id),
+            ("code_verifier", code_verifier),
+        ];
+        if let Some(secret) = client_secret {
+            form.push(("client_secret", secret));
+        }
+
+        let resp = self
+            .http
+            .post(&metadata.token_endpoint)
+            .form(&form)
+            .send()
+            .await
+            .map_err(|e| OidcError::TokenExchange(e.to_string()))?;
+        if !resp.status().is_success() {
+            let status = resp.status();
+            let body = resp.text().await.unwrap_or_default();
+            return Err(OidcError::TokenExchange(format!("{status}: {body}")));
+        }
+        let tokens: TokenResponse = resp
+            .json()
+            .await
+            .map_err(|e| OidcError::TokenExchange(e.to_string()))?;
+        tokens
+            .id_token
+            .ok_or_else(|| OidcError::TokenExchange("response has no id_token".into()))
+    }
+
+    /// Verify signature, issuer, audience, expiry and nonce of an ID token.
+    pub async fn validate_id_token(
+        &self,
+        issuer: &str,
+        client_id: &str,
+        id_token: &str,
+        nonce: &str,
+    ) -> Result<IdTokenClaims, OidcError> {
+        let header =
+            decode_header(id_token).map_err(|e| OidcError::InvalidIdToken(e.to_string()))?;
+        if !ALLOWED_ALGS.contains(&header.alg) {
+            return Err(OidcError::InvalidIdToken(format!(
+                "algorithm {:?} not allowed",
+                header.alg
+            )));
+        }
+
+        let mut provider = self.provider(issuer, false).await?;
+        let jwk = match select_jwk(&provider.jwks, header.kid.as_deref()) {
+            Some(jwk) => jwk.clone(),
+            None => {
+                // Unknown kid: the IdP may have rotated keys since we cached.
+                provider = self.provider(issuer, true).await?;
+                select_jwk(&provider.jwks, header.kid.as_deref())
+                    .cloned()
+                    .ok_or_else(|| OidcError::InvalidIdToken("no matching signing key".into()))?
+            }
+        };
+        let key =
+            DecodingKey::from_jwk(&jwk).map_err(|e| OidcError::InvalidIdToken(e.to_string()))?;
+
+        let mut validation = Validation::new(header.alg);
+        validation.leeway = LEEWAY_SECS;
+        validation.set_issuer(&[provider.metadata.issuer.as_str()]);
+        validation.set_audience(&[client_id]);
+        validation.set_required_spec_claims(&["exp", "iss", "aud", "sub"]);
+
+        let data = decode::<Map<String, Value>>(id_token, &key, &validation)
+            .map_err(|e| OidcError::InvalidIdToken(e.to_string()))?;
+        let claims = IdTokenClaims {
+            issuer: provider.metadata.issuer.clone(),
+            claims: data.claims,
+        };
+        if claims.string("nonce").as_deref() != Some(nonce) {
+            return Err(OidcError::InvalidIdToken("nonce mismatch".into()));
+        }
+        if claims.subject().is_none() {
+            return Err(OidcError::InvalidIdToken("sub is empty".into()));
+        }
+        Ok(claims)
+    }
+}
+
+fn select_jwk<'a>(jwks: &'a JwkSet, kid: Option<&str>) -> Option<&'a Jwk> {
+    match kid {
+        Some(kid) => jwks.find(kid),
+        None if jwks.keys.len() == 1 => jwks.keys.first(),
+        None => None,
+    }
+}
+
+fn normalize_issuer(issuer: &str) -> &str {
+    issuer.trim_end_matches('/')
+}
+
+/// Verified ID token claims.
+#[derive(Debug, Clone)]
+pub struct IdTokenClaims {
+    /// Issuer as published in the provider's discovery document.
+    pub issuer: String,
+    claims: Map<String, Value>,
+}
+
+impl IdTokenClaims {
+    pub fn subject(&self) -> Option<String> {
+        self.string("sub").filter(|s| !s.is_empty())
+    }
+
+    /// String claim at a dotted path.
+    pub fn string(&self, path: &str) -> Option<String> {
+        match self.lookup(path)? {
+            Value::String(s) => Some(s.clone()),
+            _ => None,
+        }
+    }
+
+    /// List claim at a dotted path; a lone string is treated as one entry.
+    pub fn string_list(&self, path: &str) -> Vec<String> {
+        match self.lookup(path) {
+            Some(Value::Array(items)) => items
+                .iter()
+                .filter_map(|v| v.as_str().map(ToOwned::to_owned))
+                .collect(),
+            Some(Value::String(s)) => vec![s.clone()],
+            _ => Vec::new(),
+        }
+    }
+
+    /// `email_verified`, accepting the string form some IdPs emit.
+    /// `None` when the claim is absent.
+    pub fn email_verified(&self) -> Option<bool> {
+        match self.claims.get("email_verified")? {
+            Value::Bool(b) => Some(*b),
+            Value::String(s) => Some(s.eq_ignore_ascii_case("true")),
+            _ => None,
+        }
+    }
+
+    pub fn amr(&self) -> Vec<String> {
+        self.string_list("amr")
+    }
+
+    pub fn auth_time(&self) -> Option<DateTime<Utc>> {
+        self.claims
+            .get("auth_time")?
+            .as_i64()
+            .and_then(|t| Utc.timestamp_opt(t, 0).single())
+    }
+
+    fn lookup(&self, path: &str) -> Option<&Value> {
+        let mut parts = path.split('.');
+        let mut cur = self.claims.get(parts.next()?)?;
+        for part in parts {
+            cur = cur.as_object()?.get(part)?;
+        }
+        Some(cur)
+    }
+}
+
+/// URL-safe random token (256 bits) for `state`, `nonce` and PKCE verifiers.
+pub fn random_token() -> String {
+    let mut bytes = [0u8; 32];
+    rand::thread_rng().fill_bytes(&mut bytes);
+    URL_SAFE_NO_PAD.encode(bytes)
+}
+
+/// PKCE `S256` code challenge (RFC 7636 §4.2).
+pub fn pkce_challenge(code_verifier: &str) -> String {
+    URL_SAFE_NO_PAD.encode(Sha256::digest(code_verifier.as_bytes()))
+}
+
+/// Parameters of an authorization request.
+pub struct AuthorizationRequest<'a> {
+    pub client_id: &'a str,
+    pub redirect_uri: &'a str,
+    pub scopes: &'a [String],
+    pub state: &'a str,
+    pub nonce: &'a str,
+    pub code_verifier: &'a str,
+}
+
+/// Build the IdP authorization URL the browser is redirected to.
+pub fn authorization_url(
+    metadata: &ProviderMetadata,
+    req: &AuthorizationRequest<'_>,
+) -> Result<String, OidcError> {
+    let mut url = Url::parse(&metadata.authorization_endpoint)
+        .map_err(|e| OidcError::Discovery(format!("authorization_endpoint: {e}")))?;
+    let mut scopes: Vec<&str> = req.scopes.iter().map(String::as_str).collect();
+    if !scopes.contains(&"openid") {
+        scopes.insert(0, "openid");
+    }
+    url.query_pairs_mut()
+        .append_pair("response_type", "code")
+        .append_pair("client_id", req.client_id)
+        .append_pair("redirect_uri", req.redirect_uri)
+        .append_pair("scope", &scopes.join(" "))
+        .append_pair("state", req.state)
+        .append_pair("nonce", req.nonce)
+        .append_pair("code_challenge", &pkce_challenge(req.code_verifier))
+        .append_pair("code_challenge_method", "S256");
+    Ok(url.into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn pkce_matches_rfc7636_vector() {
+        assert_eq!(
+            pkce_challenge("dBjftJeZ4CVP-mB92K27uhbUJU1p1r_wW1gFWFOEjXk"),
+            "E9Melhoa2OwvFrEMTJguCHaoeK1t8URWbuGJSstw-cM"
+        );
+    }
+
+    #[test]
+    fn claim_paths_and_lists() {
+        let Value::Object(claims) = json!({
+            "sub": "abc",
+            "email": "a@x.io",
+            "email_verified": "true",
+            "groups": "finance",
+            "realm_access": { "roles": ["ap-clerk", "viewer", 7] },
+            "auth_time": 1_700_000_000,
+        }) else {
+            unreachable!()
+        };
+        let c = IdTokenClaims {
+            issuer: "https://idp.example".into(),
+            claims,
+        };
+        assert_eq!(c.subject().as_deref(), Some("abc"));
+        assert_eq!(c.string("email").as_deref(), Some("a@x.io"));
+        assert_eq!(c.email_verified(), Some(true));
+        assert_eq!(c.string_list("groups"), vec!["finance"]);
+        assert_eq!(
+            c.string_list("realm_access.roles"),
+            vec!["ap-clerk", "viewer"]
+        );
+        assert!(c.string_list("realm_access.missing").is_empty());
+        assert_eq!(c.auth_time().map(|t| t.timestamp()), Some(1_700_000_000));
+    }
+
+    #[test]
+    fn authorization_url_carries_pkce_and_openid_scope() {
+        let meta = ProviderMetadata {
+            issuer: "https://idp.example".into(),
+            authorization_endpoint: "https://idp.example/authorize?prompt=login".into(),
+            token_endpoint: "https://idp.example/token".into(),
+            jwks_uri: "https://idp.example/jwks".into(),
+        };
+        let url = authorization_url(
+            &meta,
+            &AuthorizationRequest {
+                client_id: "platform",
+                redirect_uri: "https://auth.example/api/auth/sso/callback",
+                scopes: &["email".to_string()],
+                state: "st",
+                nonce: "nn",
+                code_verifier: "dBjftJeZ4CVP-mB92K27uhbUJU1p1r_wW1gFWFOEjXk",
+            },
+        )
+        .expect("url");
+        let parsed = Url::parse(&url).expect("parse");
+        let q: std::collections::HashMap<_, _> = parsed.query_pairs().into_owned().collect();
+        assert_eq!(q["prompt"], "login");
+        assert_eq!(q["scope"], "openid email");
+        assert_eq!(
+            q["code_challenge"],
+            "E9Melhoa2OwvFrEMTJguCHaoeK1t8URWbuGJSstw-cM"
+        );
+        assert_eq!(q["code_challenge_method"], "S256");
+        assert_eq!(q["state"], "st");
+        assert_eq!(q["nonce"], "nn");
+    }
+}
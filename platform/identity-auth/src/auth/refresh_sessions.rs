@@ -19,6 +19,7 @@ use serde_json::Value;
 use sqlx::{PgPool, Postgres, Row, Transaction};
 use uuid::Uuid;
 
+use super::jwt::SessionAuth;
 use super::refresh::{generate_refresh_token, hash_refresh_token};
 
 /// A single refresh session row, as returned to callers of GET /api/auth/sessions.
@@ -44,6 +45,8 @@ pub struct RefreshResult {
     pub raw_token: String,
     pub expires_at: DateTime<Utc>,
     pub absolute_expires_at: DateTime<Utc>,
+    /// Authentication context carried over from the rotated session.
+    pub auth: SessionAuth,
 }
 
 /// Why a `refresh_sessions` row may be unusable.
@@ -198,14 +201,18 @@ pub async fn rotate(
         expires_at = original_absolute_expires_at;
     }
 
+    // amr / auth_time carry over so MFA survives rotation.
     let row = sqlx::query(
         r#"
         INSERT INTO refresh_sessions (
             tenant_id, user_id, token_hash, device_info,
-            issued_at, last_used_at, expires_at, absolute_expires_at
+            issued_at, last_used_at, expires_at, absolute_expires_at,
+            amr, auth_time
         )
-        VALUES ($1, $2, $3, $4, $5, $5, $6, $7)
-        RETURNING session_id
+        SELECT $1, $2, $3, $4, $5, $5, $6, $7, amr, auth_time
+        FROM refresh_sessions
+        WHERE session_id = $8
+        RETURNING session_id, amr, auth_time
         "#,
     )
     .bind(tenant_id)
@@ -215,6 +222,7 @@ pub async fn rotate(
     .bind(now)
     .bind(expires_at)
     .bind(original_absolute_expires_at)
+    .bind(old_session_id)
     .fetch_one(&mut **tx)
     .await?;
 
@@ -226,9 +234,48 @@ pub async fn rotate(
         raw_token: raw,
         expires_at,
         absolute_expires_at: original_absolute_expires_at,
+        auth: SessionAuth {
+            amr: row.get("amr"),
+            auth_time: row.get("auth_time"),
+        },
     })
 }
 
+/// Record how a session authenticated (login, or an MFA step-up).
+pub async fn set_auth(
+    tx: &mut Transaction<'_, Postgres>,
+    session_id: Uuid,
+    auth: &SessionAuth,
+) -> Result<u64, sqlx::Error> {
+    let res = sqlx::query(
+        r#"
+        UPDATE refresh_sessions
+        SET amr = $2, auth_time = $3
+        WHERE session_id = $1 AND revoked_at IS NULL
+        "#,
+    )
+    .bind(session_id)
+    .bind(&auth.amr)
+    .bind(auth.auth_time)
+    .execute(&mut **tx)
+    .await?;
+    Ok(res.rows_affected())
+}
+
+pub async fn session_auth(
+    tx: &mut Transaction<'_, Postgres>,
+    session_id: Uuid,
+) -> Result<Option<SessionAuth>, sqlx::Error> {
+    let row = sqlx::query("SELECT amr, auth_time FROM refresh_sessions WHERE session_id = $1")
+        .bind(session_id)
+        .fetch_optional(&mut **tx)
+        .await?;
+    Ok(row.map(|r| SessionAuth {
+        amr: r.get("amr"),
+        auth_time: r.get("auth_time"),
+    }))
+}
+
 /// Revoke all live sessions sharing a token hash (single-use revocation in the
 /// logout path; typically one row).
 pub async fn revoke_by_token_hash(
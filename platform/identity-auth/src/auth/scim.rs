@@ -0,0 +1,921 @@
+//! SCIM 2.0 protocol pieces (RFC 7643 / RFC 7644) independent of storage:
+//! error responses, the filter grammar and its SQL rendering, and the User /
+//! Group models that POST, PUT and PATCH operate on.
+//!
+//! Supported filter subset: `eq ne co sw ew pr`, `and` / `or`, `not`,
+//! parentheses. Attribute names are case-insensitive and may carry the core
+//! schema URN prefix.
+
+use axum::{
+    http::{header, HeaderValue, StatusCode},
+    response::{IntoResponse, Response},
+};
+use rand::{rngs::OsRng, RngCore};
+use serde::Deserialize;
+use serde_json::{json, Map, Value};
+use sha2::{Digest, Sha256};
+use uuid::Uuid;
+
+pub const USER_SCHEMA: &str = "urn:ietf:params:scim:schemas:core:2.0:User";
+pub const GROUP_SCHEMA: &str = "urn:ietf:params:scim:schemas:core:2.0:Group";
+pub const LIST_RESPONSE_SCHEMA: &str = "urn:ietf:params:scim:api:messages:2.0:ListResponse";
+pub const PATCH_OP_SCHEMA: &str = "urn:ietf:params:scim:api:messages:2.0:PatchOp";
+pub const ERROR_SCHEMA: &str = "urn:ietf:params:scim:api:messages:2.0:Error";
+pub const SERVICE_PROVIDER_CONFIG_SCHEMA: &str =
+    "urn:ietf:params:scim:schemas:core:2.0:ServiceProviderConfig";
+pub const RESOURCE_TYPE_SCHEMA: &str = "urn:ietf:params:scim:schemas:core:2.0:ResourceType";
+
+pub const CONTENT_TYPE: &str = "application/scim+json";
+
+/// Upper bound on `count` for list requests.
+pub const MAX_RESULTS: i64 = 200;
+
+/// Bearer tokens are `scim_` + 256 random bits (hex).
+pub fn generate_token() -> String {
+    let mut bytes = [0u8; 32];
+    OsRng.fill_bytes(&mut bytes);
+    format!("scim_{}", hex::encode(bytes))
+}
+
+pub fn hash_token(raw: &str) -> String {
+    hex::encode(Sha256::digest(raw.as_bytes()))
+}
+
+/// Serialize a SCIM resource with the SCIM media type.
+pub fn scim_json(status: StatusCode, body: Value) -> Response {
+    let mut resp = (status, body.to_string()).into_response();
+    resp.headers_mut()
+        .insert(header::CONTENT_TYPE, HeaderValue::from_static(CONTENT_TYPE));
+    resp
+}
+
+// ── Errors ──────────────────────────────────────────────────────────────────
+
+/// SCIM error response (RFC 7644 §3.12).
+#[derive(Debug)]
+pub struct ScimError {
+    pub status: StatusCode,
+    pub scim_type: Option<&'static str>,
+    pub detail: String,
+}
+
+impl ScimError {
+    pub fn bad_request(scim_type: &'static str, detail: impl Into<String>) -> Self {
+        Self {
+            status: StatusCode::BAD_REQUEST,
+            scim_type: Some(scim_type),
+            detail: detail.into(),
+        }
+    }
+
+    pub fn not_found(detail: impl Into<String>) -> Self {
+        Self {
+            status: StatusCode::NOT_FOUND,
+            scim_type: None,
+            detail: detail.into(),
+        }
+    }
+
+    pub fn conflict(detail: impl Into<String>) -> Self {
+        Self {
+            status: StatusCode::CONFLICT,
+            scim_type: Some("uniqueness"),
+            detail: detail.into(),
+        }
+    }
+
+    pub fn unauthorized() -> Self {
+        Self {
+            status: StatusCode::UNAUTHORIZED,
+            scim_type: None,
+            detail: "missing or invalid SCIM bearer token".to_string(),
+        }
+    }
+
+    pub fn internal(detail: impl Into<String>) -> Self {
+        Self {
+            status: StatusCode::INTERNAL_SERVER_ERROR,
+            scim_type: None,
+            detail: detail.into(),
+        }
+    }
+}
+
+impl From<sqlx::Error> for ScimError {
+    fn from(e: sqlx::Error) -> Self {
+        if let Some(db) = e.as_database_error() {
+            if db.code().as_deref() == Some("23505") {
+                return Self::conflict("resource already exists");
+            }
+        }
+        Self::internal(format!("db error: {e}"))
+    }
+}
+
+impl IntoResponse for ScimError {
+    fn into_response(self) -> Response {
+        let mut body = json!({
+            "schemas": [ERROR_SCHEMA],
+            "status": self.status.as_u16().to_string(),
+            "detail": self.detail,
+        });
+        if let Some(t) = self.scim_type {
+            body["scimType"] = json!(t);
+        }
+        scim_json(self.status, body)
+    }
+}
+
+// ── Filters ─────────────────────────────────────────────────────────────────
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CompareOp {
+    Eq,
+    Ne,
+    Co,
+    Sw,
+    Ew,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum FilterValue {
+    Str(String),
+    Bool(bool),
+    Null,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Filter {
+    Compare {
+        attr: String,
+        op: CompareOp,
+        value: FilterValue,
+    },
+    Present(String),
+    Not(Box<Filter>),
+    And(Box<Filter>, Box<Filter>),
+    Or(Box<Filter>, Box<Filter>),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Word(String),
+    Str(String),
+    Open,
+    Close,
+}
+
+fn invalid_filter(detail: impl Into<String>) -> ScimError {
+    ScimError::bad_request("invalidFilter", detail)
+}
+
+fn tokenize(input: &str) -> Result<Vec<Token>, ScimError> {
+    let mut tokens = Vec::new();
+    let mut chars = input.chars().peekable();
+    while let Some(&c) = chars.peek() {
+        match c {
+            c if c.is_whitespace() => {
+                chars.next();
+            }
+            '(' => {
+                chars.next();
+                tokens.push(Token::Open);
+            }
+            ')' => {
+                chars.next();
+                tokens.push(Token::Close);
+            }
+            '"' => {
+                chars.next();
+                let mut s = String::new();
+                loop {
+                    match chars.next() {
+                        Some('"') => break,
+                        Some('\\') => match chars.next() {
+                            Some(esc) => s.push(esc),
+                            None => return Err(invalid_filter("unterminated string")),
+                        },
+                        Some(ch) => s.push(ch),
+                        None => return Err(invalid_filter("unterminated string")),
+                    }
+                }
+                tokens.push(Token::Str(s));
+            }
+            _ => {
+                let mut w = String::new();
+                while let Some(&ch) = chars.peek() {
+                    if ch.is_whitespace() || ch == '(' || ch == ')' || ch == '"' {
+                        break;
+                    }
+                    w.push(ch);
+                    chars.next();
+                }
+                tokens.push(Token::Word(w));
+            }
+        }
+    }
+    Ok(tokens)
+}
+
+struct Parser {
+    tokens: Vec<Token>,
+    pos: usize,
+}
+
+impl Parser {
+    fn peek_keyword(&self, kw: &str) -> bool {
+        matches!(self.tokens.get(self.pos), Some(Token::Word(w)) if w.eq_ignore_ascii_case(kw))
+    }
+
+    fn next(&mut self) -> Option<Token> {
+        let t = self.tokens.get(self.pos).cloned();
+        self.pos += 1;
+        t
+    }
+
+    fn or_expr(&mut self) -> Result<Filter, ScimError> {
+        let mut left = self.and_expr()?;
+        while self.peek_keyword("or") {
+            self.pos += 1;
+            left = Filter::Or(Box::new(left), Box::new(self.and_expr()?));
+        }
+        Ok(left)
+    }
+
+    fn and_expr(&mut self) -> Result<Filter, ScimError> {
+        let mut left = self.unary()?;
+        while self.peek_keyword("and") {
+            self.pos += 1;
+            left = Filter::And(Box::new(left), Box::new(self.unary()?));
+        }
+        Ok(left)
+    }
+
+    fn unary(&mut self) -> Result<Filter, ScimError> {
+        if self.peek_keyword("not") {
+            self.pos += 1;
+            return Ok(Filter::Not(Box::new(self.unary()?)));
+        }
+        match self.next() {
+            Some(Token::Open) => {
+                let inner = self.or_expr()?;
+                match self.next() {
+                    Some(Token::Close) => Ok(inner),
+                    _ => Err(invalid_filter("missing ')'")),
+                }
+            }
+            Some(Token::Word(attr)) => self.comparison(attr),
+            _ => Err(invalid_filter("expected attribute")),
+        }
+    }
+
+    fn comparison(&mut self, attr: String) -> Result<Filter, ScimError> {
+        let op = match self.next() {
+            Some(Token::Word(op)) => op.to_ascii_lowercase(),
+            _ => return Err(invalid_filter(format!("expected operator after {attr}"))),
+        };
+        let op = match op.as_str() {
+            "pr" => return Ok(Filter::Present(attr)),
+            "eq" => CompareOp::Eq,
+            "ne" => CompareOp::Ne,
+            "co" => CompareOp::Co,
+            "sw" => CompareOp::Sw,
+            "ew" => CompareOp::Ew,
+            other => return Err(invalid_filter(format!("unsupported operator {other}"))),
+        };
+        let value = match self.next() {
+            Some(Token::Str(s)) => FilterValue::Str(s),
+            Some(Token::Word(w)) if w.eq_ignore_ascii_case("true") => FilterValue::Bool(true),
+            Some(Token::Word(w)) if w.eq_ignore_ascii_case("false") => FilterValue::Bool(false),
+            Some(Token::Word(w)) if w.eq_ignore_ascii_case("null") => FilterValue::Null,
+            _ => return Err(invalid_filter(format!("expected value after {attr}"))),
+        };
+        Ok(Filter::Compare { attr, op, value })
+    }
+}
+
+pub fn parse_filter(input: &str) -> Result<Filter, ScimError> {
+    let mut parser = Parser {
+        tokens: tokenize(input)?,
+        pos: 0,
+    };
+    let filter = parser.or_expr()?;
+    if parser.pos != parser.tokens.len() {
+        return Err(invalid_filter("unexpected trailing tokens"));
+    }
+    Ok(filter)
+}
+
+/// Lowercased attribute path without the core schema URN prefix.
+pub fn normalize_attr(attr: &str) -> String {
+    let lower = attr.to_ascii_lowercase();
+    for schema in [USER_SCHEMA, GROUP_SCHEMA] {
+        let prefix = format!("{}:", schema.to_ascii_lowercase());
+        if let Some(rest) = lower.strip_prefix(&prefix) {
+            return rest.to_string();
+        }
+    }
+    lower
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum ColumnKind {
+    /// Text compared case-insensitively (SCIM `caseExact: false`).
+    Text,
+    /// Text compared exactly (ids, externalId).
+    ExactText,
+    Bool,
+}
+
+/// SQL expression a filterable attribute maps to.
+#[derive(Debug, Clone, Copy)]
+pub struct Column {
+    pub expr: &'static str,
+    pub kind: ColumnKind,
+}
+
+/// A filter rendered to a SQL boolean expression with positional binds.
+#[derive(Debug, Clone, Default)]
+pub struct SqlFilter {
+    pub sql: String,
+    pub binds: Vec<FilterValue>,
+}
+
+fn like_escape(s: &str) -> String {
+    s.replace('\\', "\\\\")
+        .replace('%', "\\%")
+        .replace('_', "\\_")
+}
+
+/// Render `filter` for the attributes `columns` knows; binds are numbered
+/// from `first_bind`.
+pub fn filter_to_sql(
+    filter: &Filter,
+    columns: &dyn Fn(&str) -> Option<Column>,
+    first_bind: usize,
+) -> Result<SqlFilter, ScimError> {
+    let mut out = SqlFilter::default();
+    let sql = render(filter, columns, first_bind, &mut out.binds)?;
+    out.sql = sql;
+    Ok(out)
+}
+
+fn render(
+    filter: &Filter,
+    columns: &dyn Fn(&str) -> Option<Column>,
+    first_bind: usize,
+    binds: &mut Vec<FilterValue>,
+) -> Result<String, ScimError> {
+    let column = |attr: &str| {
+        columns(&normalize_attr(attr))
+            .ok_or_else(|| invalid_filter(format!("unsupported filter attribute {attr}")))
+    };
+    match filter {
+        Filter::And(a, b) => Ok(format!(
+            "({} AND {})",
+            render(a, columns, first_bind, binds)?,
+            render(b, columns, first_bind, binds)?
+        )),
+        Filter::Or(a, b) => Ok(format!(
+            "({} OR {})",
+            render(a, columns, first_bind, binds)?,
+            render(b, columns, first_bind, binds)?
+        )),
+        Filter::Not(inner) => Ok(format!(
+            "NOT COALESCE({}, FALSE)",
+            render(inner, columns, first_bind, binds)?
+        )),
+        Filter::Present(attr) => {
+            let col = column(attr)?;
+            Ok(match col.kind {
+                ColumnKind::Bool => format!("({} IS NOT NULL)", col.expr),
+                _ => format!("({0} IS NOT NULL AND {0} <> '')", col.expr),
+            })
+        }
+        Filter::Compare { attr, op, value } => {
+            let col = column(attr)?;
+            match (col.kind, value) {
+                (_, FilterValue::Null) => match op {
+                    CompareOp::Eq => Ok(format!("({} IS NULL)", col.expr)),
+                    CompareOp::Ne => Ok(format!("({} IS NOT NULL)", col.expr)),
+                    _ => Err(invalid_filter("null only supports eq / ne")),
+                },
+                (ColumnKind::Bool, FilterValue::Bool(b)) => {
+                    binds.push(FilterValue::Bool(*b));
+                    let n = first_bind + binds.len() - 1;
+                    match op {
+                        CompareOp::Eq => Ok(format!("({} = ${n})", col.expr)),
+                        CompareOp::Ne => Ok(format!("({} <> ${n})", col.expr)),
+                        _ => Err(invalid_filter(format!("{attr} only supports eq / ne"))),
+                    }
+                }
+                (ColumnKind::Text | ColumnKind::ExactText, FilterValue::Str(s)) => {
+                    let exact = matches!(col.kind, ColumnKind::ExactText);
+                    let (lhs, rhs_fn): (String, fn(usize) -> String) = if exact {
+                        (col.expr.to_string(), |n| format!("${n}"))
+                    } else {
+                        (format!("lower({})", col.expr), |n| format!("lower(${n})"))
+                    };
+                    let bound = match op {
+                        CompareOp::Eq | CompareOp::Ne => s.clone(),
+                        CompareOp::Co => format!("%{}%", like_escape(s)),
+                        CompareOp::Sw => format!("{}%", like_escape(s)),
+                        CompareOp::Ew => format!("%{}", like_escape(s)),
+                    };
+                    binds.push(FilterValue::Str(bound));
+                    let rhs = rhs_fn(first_bind + binds.len() - 1);
+                    Ok(match op {
+                        CompareOp::Eq => format!("({lhs} = {rhs})"),
+                        CompareOp::Ne => format!("({0} IS NULL OR {lhs} <> {rhs})", col.expr),
+                        _ => format!("({lhs} LIKE {rhs} ESCAPE '\\')"),
+                    })
+                }
+                _ => Err(invalid_filter(format!("value type does not match {attr}"))),
+            }
+        }
+    }
+}
+
+// ── PATCH ───────────────────────────────────────────────────────────────────
+
+#[derive(Debug, Deserialize)]
+pub struct PatchRequest {
+    #[serde(default)]
+    pub schemas: Vec<String>,
+    #[serde(rename = "Operations", alias = "operations")]
+    pub operations: Vec<PatchOperation>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct PatchOperation {
+    pub op: String,
+    #[serde(default)]
+    pub path: Option<String>,
+    #[serde(default)]
+    pub value: Option<Value>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PatchOp {
+    Add,
+    Replace,
+    Remove,
+}
+
+impl PatchOperation {
+    pub fn kind(&self) -> Result<PatchOp, ScimError> {
+        match self.op.to_ascii_lowercase().as_str() {
+            "add" => Ok(PatchOp::Add),
+            "replace" => Ok(PatchOp::Replace),
+            "remove" => Ok(PatchOp::Remove),
+            other => Err(ScimError::bad_request(
+                "invalidSyntax",
+                format!("unknown patch op {other}"),
+            )),
+        }
+    }
+}
+
+impl PatchRequest {
+    pub fn validate(&self) -> Result<(), ScimError> {
+        if !self.schemas.iter().any(|s| s == PATCH_OP_SCHEMA) {
+            return Err(ScimError::bad_request(
+                "invalidSyntax",
+                format!("schemas must contain {PATCH_OP_SCHEMA}"),
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// Split `attr[filter].sub` into its parts.
+fn split_value_path(path: &str) -> Result<(String, Option<Filter>, Option<String>), ScimError> {
+    let Some(open) = path.find('[') else {
+        return Ok((normalize_attr(path), None, None));
+    };
+    let close = path
+        .rfind(']')
+        .filter(|c| *c > open)
+        .ok_or_else(|| ScimError::bad_request("invalidPath", format!("bad path {path}")))?;
+    let filter = parse_filter(&path[open + 1..close])
+        .map_err(|e| ScimError::bad_request("invalidPath", e.detail))?;
+    let sub = path[close + 1..]
+        .strip_prefix('.')
+        .map(|s| s.to_ascii_lowercase());
+    Ok((normalize_attr(&path[..open]), Some(filter), sub))
+}
+
+// ── Attribute helpers ───────────────────────────────────────────────────────
+
+fn get_ci<'a>(obj: &'a Map<String, Value>, key: &str) -> Option<&'a Value> {
+    obj.iter()
+        .find(|(k, _)| k.eq_ignore_ascii_case(key))
+        .map(|(_, v)| v)
+}
+
+fn invalid_value(detail: impl Into<String>) -> ScimError {
+    ScimError::bad_request("invalidValue", detail)
+}
+
+fn opt_string(v: Option<&Value>) -> Result<Option<String>, ScimError> {
+    match v {
+        None | Some(Value::Null) => Ok(None),
+        Some(Value::String(s)) => Ok(Some(s.trim().to_string()).filter(|s| !s.is_empty())),
+        Some(other) => Err(invalid_value(format!("expected string, got {other}"))),
+    }
+}
+
+fn req_string(v: Option<&Value>, attr: &str) -> Result<String, ScimError> {
+    opt_string(v)?.ok_or_else(|| invalid_value(format!("{attr} is required")))
+}
+
+/// Booleans, including the `"True"` / `"False"` strings some IdPs send.
+fn as_bool(v: &Value) -> Result<bool, ScimError> {
+    match v {
+        Value::Bool(b) => Ok(*b),
+        Value::String(s) if s.eq_ignore_ascii_case("true") => Ok(true),
+        Value::String(s) if s.eq_ignore_ascii_case("false") => Ok(false),
+        other => Err(invalid_value(format!("expected boolean, got {other}"))),
+    }
+}
+
+/// Primary (else first) address of a SCIM `emails` array.
+fn primary_email(v: &Value) -> Option<String> {
+    let items = v.as_array()?;
+    let pick = items
+        .iter()
+        .find(|e| e.get("primary").map(|p| as_bool(p).unwrap_or(false)) == Some(true))
+        .or_else(|| items.first())?;
+    pick.get("value")?.as_str().map(|s| s.trim().to_lowercase())
+}
+
+// ── User ────────────────────────────────────────────────────────────────────
+
+/// Writable attributes of a SCIM User.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScimUser {
+    pub user_name: String,
+    /// Login email (`credentials.email`): primary `emails` entry, else
+    /// `userName` when it is an address.
+    pub email: String,
+    pub external_id: Option<String>,
+    pub given_name: Option<String>,
+    pub family_name: Option<String>,
+    pub display_name: Option<String>,
+    pub active: bool,
+}
+
+impl ScimUser {
+    /// Build from a full resource (POST / PUT body).
+    pub fn from_resource(body: &Value) -> Result<Self, ScimError> {
+        let obj = body
+            .as_object()
+            .ok_or_else(|| ScimError::bad_request("invalidSyntax", "body must be an object"))?;
+        let name = get_ci(obj, "name").and_then(Value::as_object);
+        let mut user = Self {
+            user_name: req_string(get_ci(obj, "userName"), "userName")?,
+            email: String::new(),
+            external_id: opt_string(get_ci(obj, "externalId"))?,
+            given_name: opt_string(name.and_then(|n| get_ci(n, "givenName")))?,
+            family_name: opt_string(name.and_then(|n| get_ci(n, "familyName")))?,
+            display_name: opt_string(get_ci(obj, "displayName"))?,
+            active: get_ci(obj, "active")
+                .map(as_bool)
+                .transpose()?
+                .unwrap_or(true),
+        };
+        user.email = get_ci(obj, "emails")
+            .and_then(primary_email)
+            .unwrap_or_default();
+        user.finish()?;
+        Ok(user)
+    }
+
+    /// Fill the email from userName when no address was given and validate.
+    fn finish(&mut self) -> Result<(), ScimError> {
+        if self.email.is_empty() && self.user_name.contains('@') {
+            self.email = self.user_name.trim().to_lowercase();
+        }
+        if !self.email.contains('@') {
+            return Err(invalid_value(
+                "userName or emails must contain an email address",
+            ));
+        }
+        Ok(())
+    }
+
+    pub fn apply_patch(&mut self, req: &PatchRequest) -> Result<(), ScimError> {
+        for op in &req.operations {
+            let kind = op.kind()?;
+            match (&op.path, kind) {
+                (None, PatchOp::Remove) => {
+                    return Err(ScimError::bad_request("noTarget", "remove requires a path"))
+                }
+                (None, _) => {
+                    let obj = op
+                        .value
+                        .as_ref()
+                        .and_then(Value::as_object)
+                        .ok_or_else(|| invalid_value("value must be an object without a path"))?;
+                    for (k, v) in obj {
+                        self.set(k, Some(v))?;
+                    }
+                }
+                (Some(path), PatchOp::Remove) => self.set(path, None)?,
+                (Some(path), _) => {
+                    self.set(path, Some(op.value.as_ref().unwrap_or(&Value::Null)))?
+                }
+            }
+        }
+        self.finish()
+    }
+
+    /// Set (or with `None`, remove) one attribute.
+    fn set(&mut self, path: &str, value: Option<&Value>) -> Result<(), ScimError> {
+        let (attr, _filter, sub) = split_value_path(path)?;
+        match (attr.as_str(), sub.as_deref()) {
+            ("username", None) => {
+                self.user_name = req_string(value, "userName")?;
+            }
+            ("externalid", None) => self.external_id = opt_string(value)?,
+            ("displayname", None) => self.display_name = opt_string(value)?,
+            ("name.givenname", None) | ("name", Some("givenname")) => {
+                self.given_name = opt_string(value)?
+            }
+            ("name.familyname", None) | ("name", Some("familyname")) => {
+                self.family_name = opt_string(value)?
+            }
+            ("name", None) => {
+                let name = value.and_then(Value::as_object);
+                self.given_name = opt_string(name.and_then(|n| get_ci(n, "givenName")))?;
+                self.family_name = opt_string(name.and_then(|n| get_ci(n, "familyName")))?;
+            }
+            ("active", None) => {
+                let v = value.ok_or_else(|| {
+                    ScimError::bad_request("mutability", "active cannot be removed")
+                })?;
+                self.active = as_bool(v)?;
+            }
+            ("emails", None) => {
+                self.email = value.and_then(primary_email).unwrap_or_default();
+            }
+            // emails[type eq "work"].value and friends
+            ("emails", Some("value")) | ("emails.value", None) => {
+                self.email = opt_string(value)?
+                    .map(|e| e.to_lowercase())
+                    .unwrap_or_default();
+            }
+            // Enterprise extension and other attributes are accepted and ignored.
+            _ => {}
+        }
+        Ok(())
+    }
+}
+
+// ── Group ───────────────────────────────────────────────────────────────────
+
+/// Writable attributes of a SCIM Group.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScimGroup {
+    pub display_name: String,
+    pub external_id: Option<String>,
+    pub members: Vec<Uuid>,
+}
+
+fn member_ids(v: Option<&Value>) -> Result<Vec<Uuid>, ScimError> {
+    let Some(v) = v else {
+        return Ok(Vec::new());
+    };
+    let items: Vec<&Value> = match v {
+        Value::Array(items) => items.iter().collect(),
+        Value::Object(_) => vec![v],
+        Value::Null => Vec::new(),
+        other => {
+            return Err(invalid_value(format!(
+                "members must be an array, got {other}"
+            )))
+        }
+    };
+    items
+        .into_iter()
+        .map(|m| {
+            m.get("value")
+                .and_then(Value::as_str)
+                .and_then(|s| Uuid::parse_str(s).ok())
+                .ok_or_else(|| invalid_value("member value must be a user id"))
+        })
+        .collect()
+}
+
+impl ScimGroup {
+    pub fn from_resource(body: &Value) -> Result<Self, ScimError> {
+        let obj = body
+            .as_object()
+            .ok_or_else(|| ScimError::bad_request("invalidSyntax", "body must be an object"))?;
+        let mut group = Self {
+            display_name: req_string(get_ci(obj, "displayName"), "displayName")?,
+            external_id: opt_string(get_ci(obj, "externalId"))?,
+            members: member_ids(get_ci(obj, "members"))?,
+        };
+        group.members.sort();
+        group.members.dedup();
+        Ok(group)
+    }
+
+    pub fn apply_patch(&mut self, req: &PatchRequest) -> Result<(), ScimError> {
+        for op in &req.operations {
+            let kind = op.kind()?;
+            let Some(path) = op.path.as_deref() else {
+                if kind == PatchOp::Remove {
+                    return Err(ScimError::bad_request("noTarget", "remove requires a path"));
+                }
+                let obj = op
+                    .value
+                    .as_ref()
+                    .and_then(Value::as_object)
+                    .ok_or_else(|| invalid_value("value must be an object without a path"))?;
+                for (k, v) in obj {
+                    self.set(k, kind, Some(v))?;
+                }
+                continue;
+            };
+            let (attr, filter, _) = split_value_path(path)?;
+            if attr == "members" {
+                if let Some(filter) = filter {
+                    // members[value eq "<id>"]
+                    let id = match filter {
+                        Filter::Compare {
+                            op: CompareOp::Eq,
+                            value: FilterValue::Str(s),
+                            ..
+                        } => Uuid::parse_str(&s).ok(),
+                        _ => None,
+                    }
+                    .ok_or_else(|| {
+                        ScimError::bad_request("invalidFilter", "expected members[value eq \"id\"]")
+                    })?;
+                    if kind != PatchOp::Remove {
+                        return Err(ScimError::bad_request(
+                            "invalidPath",
+                            "filtered member paths only support remove",
+                        ));
+                    }
+                    self.members.retain(|m| *m != id);
+                    continue;
+                }
+            }
+            self.set(path, kind, op.value.as_ref())?;
+        }
+        self.members.sort();
+        self.members.dedup();
+        Ok(())
+    }
+
+    fn set(&mut self, path: &str, kind: PatchOp, value: Option<&Value>) -> Result<(), ScimError> {
+        match normalize_attr(path).as_str() {
+            "displayname" => {
+                if kind == PatchOp::Remove {
+                    return Err(ScimError::bad_request(
+                        "mutability",
+                        "displayName cannot be removed",
+                    ));
+                }
+                self.display_name = req_string(value, "displayName")?;
+            }
+            "externalid" => {
+                self.external_id = if kind == PatchOp::Remove {
+                    None
+                } else {
+                    opt_string(value)?
+                }
+            }
+            "members" => {
+                let ids = member_ids(value)?;
+                match kind {
+                    PatchOp::Add => self.members.extend(ids),
+                    PatchOp::Replace => self.members = ids,
+                    // No value: remove every member; otherwise the listed ones.
+                    PatchOp::Remove if value.is_none() => self.members.clear(),
+                    PatchOp::Remove => self.members.retain(|m| !ids.contains(m)),
+                }
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn patch(ops: Value) -> PatchRequest {
+        serde_json::from_value(json!({ "schemas": [PATCH_OP_SCHEMA], "Operations": ops }))
+            .expect("patch")
+    }
+
+    fn user_columns(attr: &str) -> Option<Column> {
+        match attr {
+            "username" => Some(Column {
+                expr: "s.user_name",
+                kind: ColumnKind::Text,
+            }),
+            "externalid" => Some(Column {
+                expr: "s.external_id",
+                kind: ColumnKind::ExactText,
+            }),
+            "active" => Some(Column {
+                expr: "c.is_active",
+                kind: ColumnKind::Bool,
+            }),
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn filter_parses_and_renders_with_precedence() {
+        let f = parse_filter(r#"userName sw "J\"o" and not (active eq false) or externalId pr"#)
+            .expect("parse");
+        let sql = filter_to_sql(&f, &user_columns, 2).expect("render");
+        assert_eq!(
+            sql.sql,
+            "(((lower(s.user_name) LIKE lower($2) ESCAPE '\\') \
+             AND NOT COALESCE((c.is_active = $3), FALSE)) \
+             OR (s.external_id IS NOT NULL AND s.external_id <> ''))"
+        );
+        assert_eq!(
+            sql.binds,
+            vec![FilterValue::Str("J\"o%".into()), FilterValue::Bool(false)]
+        );
+
+        let f =
+            parse_filter(r#"urn:ietf:params:scim:schemas:core:2.0:User:USERNAME eq "a_b@x.io""#)
+                .expect("parse");
+        let sql = filter_to_sql(&f, &user_columns, 1).expect("render");
+        assert_eq!(sql.sql, "(lower(s.user_name) = lower($1))");
+
+        assert!(parse_filter(r#"userName eq"#).is_err());
+        assert!(parse_filter(r#"userName gt "a""#).is_err());
+        let unknown = parse_filter(r#"title eq "x""#).expect("parse");
+        assert_eq!(
+            filter_to_sql(&unknown, &user_columns, 1)
+                .unwrap_err()
+                .scim_type,
+            Some("invalidFilter")
+        );
+    }
+
+    #[test]
+    fn user_patch_handles_idp_dialects() {
+        let mut user = ScimUser::from_resource(&json!({
+            "schemas": [USER_SCHEMA],
+            "userName": "jdoe",
+            "emails": [{ "value": "Other@x.io" }, { "value": "JDoe@X.io", "primary": true }],
+            "name": { "givenName": "Jane", "familyName": "Doe" },
+        }))
+        .expect("user");
+        assert_eq!(user.email, "jdoe@x.io");
+        assert!(user.active);
+
+        // Azure AD style: no path, string booleans, dotted keys.
+        user.apply_patch(&patch(json!([
+            { "op": "Replace", "value": { "active": "False", "name.familyName": "Roe" } },
+            { "op": "replace", "path": "emails[type eq \"work\"].value", "value": "jane@x.io" },
+            { "op": "remove", "path": "name.givenName" },
+        ])))
+        .expect("patch");
+        assert!(!user.active);
+        assert_eq!(user.family_name.as_deref(), Some("Roe"));
+        assert_eq!(user.given_name, None);
+        assert_eq!(user.email, "jane@x.io");
+
+        let err = ScimUser::from_resource(&json!({ "userName": "no-email" })).unwrap_err();
+        assert_eq!(err.scim_type, Some("invalidValue"));
+    }
+
+    #[test]
+    fn group_patch_membership_ops() {
+        let (a, b, c) = (Uuid::new_v4(), Uuid::new_v4(), Uuid::new_v4());
+        let mut group = ScimGroup::from_resource(&json!({
+            "displayName": "Finance",
+            "members": [{ "value": a.to_string() }],
+        }))
+        .expect("group");
+
+        group
+            .apply_patch(&patch(json!([
+                { "op": "add", "path": "members", "value": [{ "value": b.to_string() }, { "value": c.to_string() }] },
+                { "op": "remove", "path": format!("members[value eq \"{a}\"]") },
+                { "op": "remove", "path": "members", "value": [{ "value": c.to_string() }] },
+                { "op": "replace", "value": { "displayName": "Finance EU" } },
+            ])))
+            .expect("patch");
+        assert_eq!(group.members, vec![b]);
+        assert_eq!(group.display_name, "Finance EU");
+
+        group
+            .apply_patch(&patch(json!([{ "op": "remove", "path": "members" }])))
+            .expect("patch");
+        assert!(group.members.is_empty());
+    }
+}
@@ -0,0 +1,66 @@
+//! AES-256-GCM sealing for secrets stored in the auth database (TOTP seeds,
+//! SSO client secrets) — the same nonce-prefixed layout the integrations
+//! secret store uses.
+
+use aes_gcm::{
+    aead::{Aead, AeadCore, KeyInit, OsRng},
+    Aes256Gcm, Nonce,
+};
+
+const NONCE_LEN: usize = 12;
+
+/// 32-byte key sealing secrets at rest.
+#[derive(Clone)]
+pub struct SecretKey([u8; 32]);
+
+impl SecretKey {
+    /// Parse a 64-character hex key.
+    pub fn from_hex(hex_key: &str) -> Result<Self, String> {
+        let bytes = hex::decode(hex_key.trim()).map_err(|e| format!("invalid hex key: {e}"))?;
+        let key: [u8; 32] = bytes
+            .try_into()
+            .map_err(|_| "key must be 32 bytes (64 hex chars)".to_string())?;
+        Ok(Self(key))
+    }
+
+    pub fn seal(&self, secret: &[u8]) -> Vec<u8> {
+        let cipher = Aes256Gcm::new_from_slice(&self.0).expect("key is 32 bytes");
+        let nonce = Aes256Gcm::generate_nonce(&mut OsRng);
+        let ct = cipher
+            .encrypt(&nonce, secret)
+            .expect("AES-GCM encryption is infallible for valid inputs");
+        let mut out = Vec::with_capacity(NONCE_LEN + ct.len());
+        out.extend_from_slice(&nonce);
+        out.extend(ct);
+        out
+    }
+
+    pub fn open(&self, sealed: &[u8]) -> Result<Vec<u8>, String> {
+        if sealed.len() < NONCE_LEN {
+            return Err("sealed secret too short".to_string());
+        }
+        let (nonce, ct) = sealed.split_at(NONCE_LEN);
+        let cipher = Aes256Gcm::new_from_slice(&self.0).expect("key is 32 bytes");
+        cipher
+            .decrypt(Nonce::from_slice(nonce), ct)
+            .map_err(|_| "sealed secret failed to decrypt".to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sealed_secret_roundtrip() {
+        let key = SecretKey::from_hex(&"ab".repeat(32)).expect("key");
+        let secret = b"12345678901234567890".to_vec();
+        let sealed = key.seal(&secret);
+        assert_ne!(&sealed[NONCE_LEN..], &secret[..]);
+        assert_eq!(key.open(&sealed).expect("open"), secret);
+
+        let other = SecretKey::from_hex(&"cd".repeat(32)).expect("key");
+        assert!(other.open(&sealed).is_err());
+        assert!(SecretKey::from_hex("abcd").is_err());
+    }
+}
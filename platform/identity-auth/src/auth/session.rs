@@ -16,6 +16,7 @@ use uuid::Uuid;
 
 use super::cookies;
 use super::handlers::{err, err_retry_after, ApiErr, AuthState, OkResponse, TokenResponse};
+use super::jwt::SessionAuth;
 use super::refresh::{generate_refresh_token, hash_refresh_token};
 use super::refresh_sessions::{self, RefreshSession, SessionValidationError};
 
@@ -273,6 +274,14 @@ async fn refresh_via_cookie(
                 format!("rbac perms: {e}"),
             )
         })?;
+    let scopes = crate::db::rbac::effective_scopes_for_user(&state.db, tenant_id, user_id)
+        .await
+        .map_err(|e| {
+            err(
+                StatusCode::INTERNAL_SERVER_ERROR,
+                format!("rbac scopes: {e}"),
+            )
+        })?;
     let role_snapshot_id = super::jwt::compute_role_snapshot_id(&roles);
 
     let access = state
@@ -282,6 +291,8 @@ async fn refresh_via_cookie(
             user_id,
             roles,
             perms,
+            scopes,
+            &rotated.auth,
             super::jwt::actor_type::USER,
             state.access_ttl_minutes,
             Some(rotated.session_id),
@@ -390,7 +401,7 @@ async fn refresh_via_body(
     // Any tenant_id supplied by the client is ignored (back-compat window).
     let row = sqlx::query(
         r#"
-        SELECT id, tenant_id, user_id, expires_at, revoked_at
+        SELECT id, tenant_id, user_id, expires_at, revoked_at, amr, auth_time
         FROM refresh_tokens
         WHERE token_hash = $1
         "#,
@@ -424,6 +435,10 @@ async fn refresh_via_body(
     let user_id: Uuid = row.get("user_id");
     let expires_at: chrono::DateTime<Utc> = row.get("expires_at");
     let revoked_at: Option<chrono::DateTime<Utc>> = row.get("revoked_at");
+    let auth = SessionAuth {
+        amr: row.get("amr"),
+        auth_time: row.get("auth_time"),
+    };
 
     // Emit a structured deprecation log when the client still sends tenant_id.
     // The field is accepted but ignored; the DB row is the sole authority.
@@ -544,8 +559,8 @@ async fn refresh_via_body(
 
     let new_token_id: Uuid = sqlx::query(
         r#"
-        INSERT INTO refresh_tokens (tenant_id, user_id, token_hash, expires_at)
-        VALUES ($1, $2, $3, $4)
+        INSERT INTO refresh_tokens (tenant_id, user_id, token_hash, expires_at, amr, auth_time)
+        VALUES ($1, $2, $3, $4, $5, $6)
         RETURNING id
         "#,
     )
@@ -553,6 +568,8 @@ async fn refresh_via_body(
     .bind(user_id)
     .bind(&new_hash)
     .bind(new_expires_at)
+    .bind(&auth.amr)
+    .bind(auth.auth_time)
     .fetch_one(&mut *tx)
     .await
     .map_err(|e| err(StatusCode::INTERNAL_SERVER_ERROR, format!("db error: {e}")))?
@@ -590,6 +607,14 @@ async fn refresh_via_body(
                 format!("rbac perms: {e}"),
             )
         })?;
+    let scopes = crate::db::rbac::effective_scopes_for_user(&state.db, tenant_id, user_id)
+        .await
+        .map_err(|e| {
+            err(
+                StatusCode::INTERNAL_SERVER_ERROR,
+                format!("rbac scopes: {e}"),
+            )
+        })?;
     let role_snapshot_id = super::jwt::compute_role_snapshot_id(&roles);
 
     let access = state
@@ -599,6 +624,8 @@ async fn refresh_via_body(
             user_id,
             roles,
             perms,
+            scopes,
+            &auth,
             super::jwt::actor_type::USER,
             state.access_ttl_minutes,
             Some(new_token_id),
@@ -0,0 +1,175 @@
+//! TOTP (RFC 6238) second factor and single-use recovery codes.
+//!
+//! Parameters are the authenticator-app defaults: HMAC-SHA1, 6 digits, 30 s
+//! steps, 160-bit secrets. Verification accepts one step of clock skew either
+//! side; callers persist the accepted step and reject codes at or below it so
+//! a code cannot be replayed inside its window.
+//!
+//! Secrets are sealed with [`super::sealed::SecretKey`] (`MFA_SECRET_KEY`)
+//! before storage.
+
+use hmac::{Hmac, Mac};
+use rand::Rng;
+use sha1::Sha1;
+use sha2::{Digest, Sha256};
+
+pub const DIGITS: u32 = 6;
+pub const STEP_SECONDS: i64 = 30;
+/// Accepted clock skew, in steps, on either side of the current step.
+pub const SKEW_STEPS: i64 = 1;
+pub const SECRET_LEN: usize = 20;
+pub const RECOVERY_CODE_COUNT: usize = 10;
+
+pub fn generate_secret() -> Vec<u8> {
+    let mut secret = vec![0u8; SECRET_LEN];
+    rand::thread_rng().fill(&mut secret[..]);
+    secret
+}
+
+/// Base32 (RFC 4648, unpadded) — the form authenticator apps accept.
+pub fn encode_secret(secret: &[u8]) -> String {
+    data_encoding::BASE32_NOPAD.encode(secret)
+}
+
+/// `otpauth://` provisioning URI rendered as a QR code by the client.
+pub fn provisioning_uri(secret: &[u8], issuer: &str, account: &str) -> String {
+    let issuer_enc = percent_encode(issuer);
+    format!(
+        "otpauth://totp/{issuer_enc}:{}?secret={}&issuer={issuer_enc}&algorithm=SHA1&digits={DIGITS}&period={STEP_SECONDS}",
+        percent_encode(account),
+        encode_secret(secret),
+    )
+}
+
+fn percent_encode(s: &str) -> String {
+    s.bytes()
+        .map(|b| match b {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' | b'@' => {
+                (b as char).to_string()
+            }
+            _ => format!("%{b:02X}"),
+        })
+        .collect()
+}
+
+/// Time step containing `unix_seconds`.
+pub fn step_at(unix_seconds: i64) -> i64 {
+    unix_seconds.div_euclid(STEP_SECONDS)
+}
+
+/// HOTP value (RFC 4226) for `counter`, as a zero-padded decimal string.
+pub fn code_at_step(secret: &[u8], step: i64) -> String {
+    let mut mac = <Hmac<Sha1> as Mac>::new_from_slice(secret).expect("HMAC accepts any key length");
+    mac.update(&(step as u64).to_be_bytes());
+    let digest = mac.finalize().into_bytes();
+    let offset = (digest[digest.len() - 1] & 0x0f) as usize;
+    let bin = u32::from_be_bytes([
+        digest[offset] & 0x7f,
+        digest[offset + 1],
+        digest[offset + 2],
+        digest[offset + 3],
+    ]);
+    format!(
+        "{:0width$}",
+        bin % 10u32.pow(DIGITS),
+        width = DIGITS as usize
+    )
+}
+
+/// Verify `code` around `now`, returning the matched step.
+///
+/// Steps at or below `last_used_step` are rejected as replays.
+pub fn verify(secret: &[u8], code: &str, now: i64, last_used_step: Option<i64>) -> Option<i64> {
+    let code = code.trim();
+    if code.len() != DIGITS as usize || !code.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    let current = step_at(now);
+    (current - SKEW_STEPS..=current + SKEW_STEPS)
+        .filter(|step| last_used_step.is_none_or(|last| *step > last))
+        .find(|step| constant_time_eq(code_at_step(secret, *step).as_bytes(), code.as_bytes()))
+}
+
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    a.len() == b.len() && a.iter().zip(b).fold(0u8, |acc, (x, y)| acc | (x ^ y)) == 0
+}
+
+/// Fresh recovery codes, formatted `xxxxx-xxxxx` (lowercase base32 alphabet).
+pub fn generate_recovery_codes() -> Vec<String> {
+    const ALPHABET: &[u8] = b"abcdefghijkmnpqrstuvwxyz23456789";
+    let mut rng = rand::thread_rng();
+    (0..RECOVERY_CODE_COUNT)
+        .map(|_| {
+            let chars: String = (0..10)
+                .map(|_| ALPHABET[rng.gen_range(0..ALPHABET.len())] as char)
+                .collect();
+            format!("{}-{}", &chars[..5], &chars[5..])
+        })
+        .collect()
+}
+
+/// Recovery codes are stored as SHA-256 of their normalised form (case and
+/// separators ignored), like password-reset and refresh tokens.
+pub fn hash_recovery_code(code: &str) -> String {
+    let normalised: String = code
+        .chars()
+        .filter(|c| c.is_ascii_alphanumeric())
+        .map(|c| c.to_ascii_lowercase())
+        .collect();
+    hex::encode(Sha256::digest(normalised.as_bytes()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // RFC 6238 Appendix B, SHA-1 seed, truncated to 6 digits.
+    const RFC_SECRET: &[u8] = b"12345678901234567890";
+
+    #[test]
+    fn rfc6238_vectors() {
+        for (t, expected) in [
+            (59, "287082"),
+            (1_111_111_109, "081804"),
+            (1_111_111_111, "050471"),
+            (1_234_567_890, "005924"),
+            (2_000_000_000, "279037"),
+        ] {
+            assert_eq!(code_at_step(RFC_SECRET, step_at(t)), expected, "t={t}");
+        }
+    }
+
+    #[test]
+    fn verify_allows_one_step_skew_and_rejects_replay() {
+        let now = 1_234_567_890;
+        let prev = code_at_step(RFC_SECRET, step_at(now) - 1);
+        let step = verify(RFC_SECRET, &prev, now, None).expect("skewed code accepted");
+        assert_eq!(step, step_at(now) - 1);
+        assert!(verify(RFC_SECRET, &prev, now, Some(step)).is_none());
+
+        let stale = code_at_step(RFC_SECRET, step_at(now) - 2);
+        assert!(verify(RFC_SECRET, &stale, now, None).is_none());
+        assert!(verify(RFC_SECRET, "12345", now, None).is_none());
+    }
+
+    #[test]
+    fn recovery_codes_are_unique_and_hash_normalised() {
+        let codes = generate_recovery_codes();
+        assert_eq!(codes.len(), RECOVERY_CODE_COUNT);
+        let unique: std::collections::HashSet<_> = codes.iter().collect();
+        assert_eq!(unique.len(), RECOVERY_CODE_COUNT);
+
+        let code = &codes[0];
+        assert_eq!(
+            hash_recovery_code(code),
+            hash_recovery_code(&code.to_uppercase().replace('-', " "))
+        );
+    }
+
+    #[test]
+    fn provisioning_uri_encodes_label() {
+        let uri = provisioning_uri(RFC_SECRET, "7D Platform", "ap.clerk@example.com");
+        assert!(uri.starts_with("otpauth://totp/7D%20Platform:ap.clerk@example.com?secret="));
+        assert!(uri.contains("&issuer=7D%20Platform&"));
+    }
+}
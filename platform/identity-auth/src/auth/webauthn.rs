@@ -0,0 +1,473 @@
+//! WebAuthn / passkey ceremonies (registration and assertion) for ES256 keys.
+//!
+//! Only what a second factor needs is implemented: `none`-style trust of the
+//! attestation (the statement is not checked against vendor roots — the
+//! credential is trusted on the authority of the already-authenticated caller
+//! that registered it), P-256 public keys, and a signature-counter check to
+//! flag cloned authenticators. The browser-side `navigator.credentials` calls
+//! receive the challenge and RP parameters from the options endpoints.
+
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine as _;
+use ciborium::Value;
+use p256::ecdsa::{signature::Verifier, Signature, VerifyingKey};
+use rand::Rng;
+use serde::Deserialize;
+use sha2::{Digest, Sha256};
+
+/// COSE algorithm identifier for ECDSA w/ SHA-256 on P-256.
+pub const COSE_ALG_ES256: i64 = -7;
+pub const CHALLENGE_LEN: usize = 32;
+
+const FLAG_USER_PRESENT: u8 = 0x01;
+const FLAG_ATTESTED_DATA: u8 = 0x40;
+
+#[derive(Debug, thiserror::Error, PartialEq, Eq)]
+pub enum WebAuthnError {
+    #[error("malformed client data")]
+    ClientData,
+    #[error("unexpected ceremony type")]
+    CeremonyType,
+    #[error("challenge mismatch")]
+    Challenge,
+    #[error("origin not allowed")]
+    Origin,
+    #[error("malformed authenticator data")]
+    AuthenticatorData,
+    #[error("relying party ID mismatch")]
+    RpId,
+    #[error("user presence not asserted")]
+    UserPresence,
+    #[error("malformed attestation object")]
+    Attestation,
+    #[error("unsupported credential key (ES256 required)")]
+    UnsupportedKey,
+    #[error("signature verification failed")]
+    Signature,
+    #[error("signature counter did not advance; possible cloned authenticator")]
+    CounterRegression,
+}
+
+/// Relying-party settings (`WEBAUTHN_RP_ID`, `WEBAUTHN_RP_NAME`, `WEBAUTHN_ORIGINS`).
+#[derive(Debug, Clone)]
+pub struct RelyingParty {
+    pub id: String,
+    pub name: String,
+    pub origins: Vec<String>,
+}
+
+/// A credential accepted by [`RelyingParty::verify_registration`].
+#[derive(Debug, Clone)]
+pub struct NewCredential {
+    pub credential_id: Vec<u8>,
+    /// SEC1 uncompressed P-256 point.
+    pub public_key: Vec<u8>,
+    pub sign_count: u32,
+}
+
+#[derive(Debug, Deserialize)]
+struct CollectedClientData {
+    #[serde(rename = "type")]
+    ceremony: String,
+    challenge: String,
+    origin: String,
+}
+
+struct AuthenticatorData<'a> {
+    rp_id_hash: &'a [u8],
+    flags: u8,
+    sign_count: u32,
+    /// Attested credential data (present on registration only).
+    attested: Option<(&'a [u8], &'a [u8])>,
+}
+
+pub fn generate_challenge() -> Vec<u8> {
+    let mut challenge = vec![0u8; CHALLENGE_LEN];
+    rand::thread_rng().fill(&mut challenge[..]);
+    challenge
+}
+
+/// base64url without padding — the encoding WebAuthn uses for binary fields.
+pub fn b64url(bytes: &[u8]) -> String {
+    URL_SAFE_NO_PAD.encode(bytes)
+}
+
+pub fn b64url_decode(s: &str) -> Option<Vec<u8>> {
+    URL_SAFE_NO_PAD.decode(s.trim_end_matches('=')).ok()
+}
+
+impl RelyingParty {
+    /// Verify a `navigator.credentials.create()` response.
+    pub fn verify_registration(
+        &self,
+        challenge: &[u8],
+        client_data_json: &[u8],
+        attestation_object: &[u8],
+    ) -> Result<NewCredential, WebAuthnError> {
+        self.check_client_data(client_data_json, "webauthn.create", challenge)?;
+
+        let attestation: Value =
+            ciborium::from_reader(attestation_object).map_err(|_| WebAuthnError::Attestation)?;
+        let auth_data_bytes = map_get(&attestation, |k| k.as_text() == Some("authData"))
+            .and_then(Value::as_bytes)
+            .ok_or(WebAuthnError::Attestation)?;
+
+        let auth_data = parse_authenticator_data(auth_data_bytes)?;
+        self.check_authenticator_data(&auth_data)?;
+        let (credential_id, cose_key) = auth_data.attested.ok_or(WebAuthnError::Attestation)?;
+
+        Ok(NewCredential {
+            credential_id: credential_id.to_vec(),
+            public_key: cose_es256_to_sec1(cose_key)?,
+            sign_count: auth_data.sign_count,
+        })
+    }
+
+    /// Verify a `navigator.credentials.get()` response against a stored
+    /// credential, returning the authenticator's new signature counter.
+    pub fn verify_assertion(
+        &self,
+        challenge: &[u8],
+        public_key: &[u8],
+        stored_sign_count: u32,
+        client_data_json: &[u8],
+        authenticator_data: &[u8],
+        signature: &[u8],
+    ) -> Result<u32, WebAuthnError> {
+        self.check_client_data(client_data_json, "webauthn.get", challenge)?;
+        let auth_data = parse_authenticator_data(authenticator_data)?;
+        self.check_authenticator_data(&auth_data)?;
+
+        let key =
+            VerifyingKey::from_sec1_bytes(public_key).map_err(|_| WebAuthnError::UnsupportedKey)?;
+        let signature = Signature::from_der(signature).map_err(|_| WebAuthnError::Signature)?;
+        let mut signed = authenticator_data.to_vec();
+        signed.extend_from_slice(&Sha256::digest(client_data_json));
+        key.verify(&signed, &signature)
+            .map_err(|_| WebAuthnError::Signature)?;
+
+        // Authenticators that do not implement counters always report 0.
+        if (stored_sign_count != 0 || auth_data.sign_count != 0)
+            && auth_data.sign_count <= stored_sign_count
+        {
+            return Err(WebAuthnError::CounterRegression);
+        }
+        Ok(auth_data.sign_count)
+    }
+
+    fn check_client_data(
+        &self,
+        client_data_json: &[u8],
+        ceremony: &str,
+        challenge: &[u8],
+    ) -> Result<(), WebAuthnError> {
+        let data: CollectedClientData =
+            serde_json::from_slice(client_data_json).map_err(|_| WebAuthnError::ClientData)?;
+        if data.ceremony != ceremony {
+            return Err(WebAuthnError::CeremonyType);
+        }
+        if b64url_decode(&data.challenge).as_deref() != Some(challenge) {
+            return Err(WebAuthnError::Challenge);
+        }
+        if !self.origins.iter().any(|o| o == &data.origin) {
+            return Err(WebAuthnError::Origin);
+        }
+        Ok(())
+    }
+
+    fn check_authenticator_data(&self, data: &AuthenticatorData<'_>) -> Result<(), WebAuthnError> {
+        if data.rp_id_hash != Sha256::digest(self.id.as_bytes()).as_slice() {
+            return Err(WebAuthnError::RpId);
+        }
+        if data.flags & FLAG_USER_PRESENT == 0 {
+            return Err(WebAuthnError::UserPresence);
+        }
+        Ok(())
+    }
+}
+
+fn parse_authenticator_data(bytes: &[u8]) -> Result<AuthenticatorData<'_>, WebAuthnError> {
+    if bytes.len() < 37 {
+        return Err(WebAuthnError::AuthenticatorData);
+    }
+    let flags = bytes[32];
+    let sign_count = u32::from_be_bytes([bytes[33], bytes[34], bytes[35], bytes[36]]);
+    let attested = if flags & FLAG_ATTESTED_DATA != 0 {
+        // aaguid (16) || credentialIdLength (2) || credentialId || COSE key
+        let rest = bytes.get(37..).ok_or(WebAuthnError::AuthenticatorData)?;
+        let len_bytes = rest.get(16..18).ok_or(WebAuthnError::AuthenticatorData)?;
+        let id_len = u16::from_be_bytes([len_bytes[0], len_bytes[1]]) as usize;
+        let credential_id = rest
+            .get(18..18 + id_len)
+            .ok_or(WebAuthnError::AuthenticatorData)?;
+        let cose_key = rest
+            .get(18 + id_len..)
+            .ok_or(WebAuthnError::AuthenticatorData)?;
+        Some((credential_id, cose_key))
+    } else {
+        None
+    };
+    Ok(AuthenticatorData {
+        rp_id_hash: &bytes[..32],
+        flags,
+        sign_count,
+        attested,
+    })
+}
+
+/// Convert a COSE_Key (EC2, ES256, P-256) to a SEC1 uncompressed point.
+fn cose_es256_to_sec1(cose_key: &[u8]) -> Result<Vec<u8>, WebAuthnError> {
+    // Trailing extension data, if any, follows the key and is ignored.
+    let key: Value = ciborium::from_reader(cose_key).map_err(|_| WebAuthnError::UnsupportedKey)?;
+    let int_param = |label: i64| {
+        map_get(&key, |k| {
+            k.as_integer().and_then(|i| i64::try_from(i).ok()) == Some(label)
+        })
+    };
+    let int_value = |v: Option<&Value>| {
+        v.and_then(Value::as_integer)
+            .and_then(|i| i64::try_from(i).ok())
+    };
+
+    // kty = EC2 (2), alg = ES256 (-7), crv = P-256 (1)
+    if int_value(int_param(1)) != Some(2)
+        || int_value(int_param(3)) != Some(COSE_ALG_ES256)
+        || int_value(int_param(-1)) != Some(1)
+    {
+        return Err(WebAuthnError::UnsupportedKey);
+    }
+    let x = int_param(-2)
+        .and_then(Value::as_bytes)
+        .ok_or(WebAuthnError::UnsupportedKey)?;
+    let y = int_param(-3)
+        .and_then(Value::as_bytes)
+        .ok_or(WebAuthnError::UnsupportedKey)?;
+    if x.len() != 32 || y.len() != 32 {
+        return Err(WebAuthnError::UnsupportedKey);
+    }
+
+    let mut point = Vec::with_capacity(65);
+    point.push(0x04);
+    point.extend_from_slice(x);
+    point.extend_from_slice(y);
+    VerifyingKey::from_sec1_bytes(&point).map_err(|_| WebAuthnError::UnsupportedKey)?;
+    Ok(point)
+}
+
+fn map_get(map: &Value, key: impl Fn(&Value) -> bool) -> Option<&Value> {
+    map.as_map()?.iter().find(|(k, _)| key(k)).map(|(_, v)| v)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use p256::ecdsa::{signature::Signer, SigningKey};
+
+    const ORIGIN: &str = "https://app.example.com";
+
+    fn rp() -> RelyingParty {
+        RelyingParty {
+            id: "app.example.com".to_string(),
+            name: "7D Platform".to_string(),
+            origins: vec![ORIGIN.to_string()],
+        }
+    }
+
+    /// Minimal software authenticator.
+    struct TestAuthenticator {
+        key: SigningKey,
+        credential_id: Vec<u8>,
+    }
+
+    impl TestAuthenticator {
+        fn new() -> Self {
+            Self {
+                key: SigningKey::from_slice(&[0x11; 32]).expect("signing key"),
+                credential_id: vec![0xC0, 0xFF, 0xEE, 0x01],
+            }
+        }
+
+        fn client_data(ceremony: &str, challenge: &[u8], origin: &str) -> Vec<u8> {
+            serde_json::to_vec(&serde_json::json!({
+                "type": ceremony,
+                "challenge": b64url(challenge),
+                "origin": origin,
+            }))
+            .expect("client data")
+        }
+
+        fn auth_data(flags: u8, sign_count: u32) -> Vec<u8> {
+            let mut data = Sha256::digest(b"app.example.com").to_vec();
+            data.push(flags);
+            data.extend_from_slice(&sign_count.to_be_bytes());
+            data
+        }
+
+        fn register(&self, challenge: &[u8]) -> (Vec<u8>, Vec<u8>) {
+            let point = self.key.verifying_key().to_encoded_point(false);
+            let cose = Value::Map(vec![
+                (Value::from(1), Value::from(2)),
+                (Value::from(3), Value::from(COSE_ALG_ES256)),
+                (Value::from(-1), Value::from(1)),
+                (
+                    Value::from(-2),
+                    Value::Bytes(point.x().expect("x").to_vec()),
+                ),
+                (
+                    Value::from(-3),
+                    Value::Bytes(point.y().expect("y").to_vec()),
+                ),
+            ]);
+            let mut auth_data = Self::auth_data(FLAG_USER_PRESENT | FLAG_ATTESTED_DATA, 0);
+            auth_data.extend_from_slice(&[0u8; 16]);
+            auth_data.extend_from_slice(&(self.credential_id.len() as u16).to_be_bytes());
+            auth_data.extend_from_slice(&self.credential_id);
+            ciborium::into_writer(&cose, &mut auth_data).expect("cose");
+
+            let attestation = Value::Map(vec![
+                (Value::from("fmt"), Value::from("none")),
+                (Value::from("attStmt"), Value::Map(vec![])),
+                (Value::from("authData"), Value::Bytes(auth_data)),
+            ]);
+            let mut attestation_object = Vec::new();
+            ciborium::into_writer(&attestation, &mut attestation_object).expect("attestation");
+            (
+                Self::client_data("webauthn.create", challenge, ORIGIN),
+                attestation_object,
+            )
+        }
+
+        fn assert(&self, challenge: &[u8], sign_count: u32) -> (Vec<u8>, Vec<u8>, Vec<u8>) {
+            let client_data = Self::client_data("webauthn.get", challenge, ORIGIN);
+            let auth_data = Self::auth_data(FLAG_USER_PRESENT, sign_count);
+            let mut signed = auth_data.clone();
+            signed.extend_from_slice(&Sha256::digest(&client_data));
+            let signature: Signature = self.key.sign(&signed);
+            (
+                client_data,
+                auth_data,
+                signature.to_der().as_bytes().to_vec(),
+            )
+        }
+    }
+
+    #[test]
+    fn registration_then_assertion_roundtrip() {
+        let authenticator = TestAuthenticator::new();
+        let challenge = generate_challenge();
+        let (client_data, attestation) = authenticator.register(&challenge);
+        let cred = rp()
+            .verify_registration(&challenge, &client_data, &attestation)
+            .expect("registration");
+        assert_eq!(cred.credential_id, authenticator.credential_id);
+        assert_eq!(cred.public_key.len(), 65);
+
+        let challenge = generate_challenge();
+        let (client_data, auth_data, sig) = authenticator.assert(&challenge, 5);
+        let count = rp()
+            .verify_assertion(
+                &challenge,
+                &cred.public_key,
+                0,
+                &client_data,
+                &auth_data,
+                &sig,
+            )
+            .expect("assertion");
+        assert_eq!(count, 5);
+
+        // Replaying the same counter is treated as a cloned authenticator
+        assert_eq!(
+            rp().verify_assertion(
+                &challenge,
+                &cred.public_key,
+                5,
+                &client_data,
+                &auth_data,
+                &sig
+            ),
+            Err(WebAuthnError::CounterRegression)
+        );
+    }
+
+    #[test]
+    fn assertion_rejects_wrong_challenge_origin_and_tampering() {
+        let authenticator = TestAuthenticator::new();
+        let challenge = generate_challenge();
+        let (client_data, attestation) = authenticator.register(&challenge);
+        let cred = rp()
+            .verify_registration(&challenge, &client_data, &attestation)
+            .expect("registration");
+
+        let (client_data, auth_data, sig) = authenticator.assert(&challenge, 1);
+        let other_challenge = generate_challenge();
+        assert_eq!(
+            rp().verify_assertion(
+                &other_challenge,
+                &cred.public_key,
+                0,
+                &client_data,
+                &auth_data,
+                &sig
+            ),
+            Err(WebAuthnError::Challenge)
+        );
+
+        let mut foreign = rp();
+        foreign.origins = vec!["https://evil.example.net".to_string()];
+        assert_eq!(
+            foreign.verify_assertion(
+                &challenge,
+                &cred.public_key,
+                0,
+                &client_data,
+                &auth_data,
+                &sig
+            ),
+            Err(WebAuthnError::Origin)
+        );
+
+        let mut tampered = auth_data.clone();
+        tampered[36] ^= 0x01;
+        assert_eq!(
+            rp().verify_assertion(
+                &challenge,
+                &cred.public_key,
+                0,
+                &client_data,
+                &tampered,
+                &sig
+            ),
+            Err(WebAuthnError::Signature)
+        );
+
+        // A registration response is not accepted as an assertion
+        let (create_data, _) = authenticator.register(&challenge);
+        assert_eq!(
+            rp().verify_assertion(
+                &challenge,
+                &cred.public_key,
+                0,
+                &create_data,
+                &auth_data,
+                &sig
+            ),
+            Err(WebAuthnError::CeremonyType)
+        );
+    }
+
+    #[test]
+    fn registration_rejects_other_rp() {
+        let authenticator = TestAuthenticator::new();
+        let challenge = generate_challenge();
+        let (client_data, attestation) = authenticator.register(&challenge);
+        let mut other = rp();
+        other.id = "other.example.com".to_string();
+        assert_eq!(
+            other
+                .verify_registration(&challenge, &client_data, &attestation)
+                .err(),
+            Some(WebAuthnError::RpId)
+        );
+    }
+}
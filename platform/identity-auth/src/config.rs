@@ -63,6 +63,30 @@ pub struct Config {
     pub forgot_per_min_per_ip: u32,
     pub reset_per_min_per_ip: u32,
 
+    // MFA
+    /// 32-byte AES-256-GCM key (hex) sealing TOTP secrets at rest.
+    /// Leave blank to disable TOTP enrollment.
+    pub mfa_secret_key: Option<String>,
+    /// Issuer shown by authenticator apps. Default: "7D Platform".
+    pub mfa_totp_issuer: String,
+    /// Lifetime of login / step-up / registration challenges. Default: 300.
+    pub mfa_challenge_ttl_seconds: i64,
+    /// WebAuthn relying-party ID (registrable domain, e.g. app.example.com).
+    /// Leave blank to disable WebAuthn.
+    pub webauthn_rp_id: Option<String>,
+    pub webauthn_rp_name: String,
+    /// Origins allowed in WebAuthn client data (comma-separated).
+    pub webauthn_origins: Vec<String>,
+
+    // SSO
+    /// 32-byte AES-256-GCM key (hex) sealing IdP client secrets at rest.
+    /// Leave blank to allow only public (PKCE-only) IdP clients.
+    pub sso_secret_key: Option<String>,
+    /// Lifetime of a pending SSO authorization request. Default: 600.
+    pub sso_state_ttl_seconds: i64,
+    /// TTL of cached IdP discovery documents and JWKS. Default: 3600.
+    pub sso_discovery_ttl_secs: u64,
+
     // CORS
     pub env: String,
     pub cors_origins: Vec<String>,
@@ -168,6 +192,30 @@ impl Config {
                 .unwrap_or_else(|_| "5".to_string())
                 .parse()?,
 
+            mfa_secret_key: env::var("MFA_SECRET_KEY").ok().filter(|s| !s.is_empty()),
+            mfa_totp_issuer: env::var("MFA_TOTP_ISSUER")
+                .unwrap_or_else(|_| "7D Platform".to_string()),
+            mfa_challenge_ttl_seconds: env::var("MFA_CHALLENGE_TTL_SECONDS")
+                .unwrap_or_else(|_| "300".to_string())
+                .parse()?,
+            webauthn_rp_id: env::var("WEBAUTHN_RP_ID").ok().filter(|s| !s.is_empty()),
+            webauthn_rp_name: env::var("WEBAUTHN_RP_NAME")
+                .unwrap_or_else(|_| "7D Platform".to_string()),
+            webauthn_origins: env::var("WEBAUTHN_ORIGINS")
+                .unwrap_or_default()
+                .split(',')
+                .map(|s| s.trim().to_string())
+                .filter(|s| !s.is_empty())
+                .collect(),
+
+            sso_secret_key: env::var("SSO_SECRET_KEY").ok().filter(|s| !s.is_empty()),
+            sso_state_ttl_seconds: env::var("SSO_STATE_TTL_SECONDS")
+                .unwrap_or_else(|_| "600".to_string())
+                .parse()?,
+            sso_discovery_ttl_secs: env::var("SSO_DISCOVERY_TTL_SECS")
+                .unwrap_or_else(|_| "3600".to_string())
+                .parse()?,
+
             env: env::var("ENV").unwrap_or_else(|_| "development".to_string()),
             cors_origins: {
                 let origins: Vec<String> = env::var("CORS_ORIGINS")
@@ -0,0 +1,645 @@
+//! MFA persistence: enrolled factors, recovery codes, pending challenges and
+//! per-tenant enforcement policies (see migration 014_mfa.sql).
+
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::{PgPool, Postgres, Row, Transaction};
+use uuid::Uuid;
+
+/// Failed verifications after which a challenge is burned.
+pub const MAX_CHALLENGE_ATTEMPTS: i32 = 5;
+
+pub mod method {
+    pub const TOTP: &str = "totp";
+    pub const WEBAUTHN: &str = "webauthn";
+    pub const RECOVERY_CODE: &str = "recovery_code";
+}
+
+pub mod policy_mode {
+    pub const OFF: &str = "off";
+    pub const ALL_USERS: &str = "all_users";
+    pub const ROLES: &str = "roles";
+
+    pub fn is_valid(mode: &str) -> bool {
+        matches!(mode, OFF | ALL_USERS | ROLES)
+    }
+}
+
+/// A user's enrolled factors and whether tenant policy demands MFA.
+#[derive(Debug, Clone, Default, Serialize, utoipa::ToSchema)]
+pub struct MfaStatus {
+    pub totp: bool,
+    pub webauthn_credentials: i64,
+    pub recovery_codes_remaining: i64,
+    pub policy_requires: bool,
+}
+
+impl MfaStatus {
+    pub fn enrolled(&self) -> bool {
+        self.totp || self.webauthn_credentials > 0
+    }
+
+    /// Login challenge is required when the user enrolled or policy demands it.
+    pub fn challenge_required(&self) -> bool {
+        self.enrolled() || self.policy_requires
+    }
+
+    /// Methods able to answer a challenge right now.
+    pub fn methods(&self) -> Vec<String> {
+        let mut methods = Vec::new();
+        if self.totp {
+            methods.push(method::TOTP.to_string());
+        }
+        if self.webauthn_credentials > 0 {
+            methods.push(method::WEBAUTHN.to_string());
+        }
+        if self.enrolled() && self.recovery_codes_remaining > 0 {
+            methods.push(method::RECOVERY_CODE.to_string());
+        }
+        methods
+    }
+}
+
+pub async fn status(
+    pool: &PgPool,
+    tenant_id: Uuid,
+    user_id: Uuid,
+) -> Result<MfaStatus, sqlx::Error> {
+    let row = sqlx::query(
+        r#"
+        SELECT
+            EXISTS (
+                SELECT 1 FROM mfa_totp_factors
+                WHERE tenant_id = $1 AND user_id = $2 AND confirmed_at IS NOT NULL
+            ) AS totp,
+            (SELECT COUNT(*) FROM mfa_webauthn_credentials
+             WHERE tenant_id = $1 AND user_id = $2) AS webauthn_credentials,
+            (SELECT COUNT(*) FROM mfa_recovery_codes
+             WHERE tenant_id = $1 AND user_id = $2 AND used_at IS NULL) AS recovery_codes_remaining,
+            COALESCE((
+                SELECT CASE p.mode
+                    WHEN 'all_users' THEN TRUE
+                    WHEN 'roles' THEN EXISTS (
+                        SELECT 1 FROM user_role_bindings b
+                        WHERE b.tenant_id = $1
+                          AND b.user_id = $2
+                          AND b.revoked_at IS NULL
+                          AND b.role_id = ANY (p.role_ids)
+                    )
+                    ELSE FALSE
+                END
+                FROM mfa_policies p
+                WHERE p.tenant_id = $1
+            ), FALSE) AS policy_requires
+        "#,
+    )
+    .bind(tenant_id)
+    .bind(user_id)
+    .fetch_one(pool)
+    .await?;
+
+    Ok(MfaStatus {
+        totp: row.get("totp"),
+        webauthn_credentials: row.get("webauthn_credentials"),
+        recovery_codes_remaining: row.get("recovery_codes_remaining"),
+        policy_requires: row.get("policy_requires"),
+    })
+}
+
+// ── Policies ────────────────────────────────────────────────────────────────
+
+#[derive(Debug, Clone, Serialize, Deserialize, utoipa::ToSchema)]
+pub struct MfaPolicy {
+    pub tenant_id: Uuid,
+    /// `off` | `all_users` | `roles`
+    pub mode: String,
+    /// Roles whose holders must use MFA when `mode = roles`.
+    pub role_ids: Vec<Uuid>,
+    pub updated_by: Option<Uuid>,
+    pub updated_at: DateTime<Utc>,
+}
+
+fn policy_from_row(row: &sqlx::postgres::PgRow) -> MfaPolicy {
+    MfaPolicy {
+        tenant_id: row.get("tenant_id"),
+        mode: row.get("mode"),
+        role_ids: row.get("role_ids"),
+        updated_by: row.get("updated_by"),
+        updated_at: row.get("updated_at"),
+    }
+}
+
+pub async fn get_policy(pool: &PgPool, tenant_id: Uuid) -> Result<Option<MfaPolicy>, sqlx::Error> {
+    let row = sqlx::query(
+        "SELECT tenant_id, mode, role_ids, updated_by, updated_at FROM mfa_policies WHERE tenant_id = $1",
+    )
+    .bind(tenant_id)
+    .fetch_optional(pool)
+    .await?;
+    Ok(row.as_ref().map(policy_from_row))
+}
+
+pub async fn upsert_policy(
+    pool: &PgPool,
+    tenant_id: Uuid,
+    mode: &str,
+    role_ids: &[Uuid],
+    updated_by: Option<Uuid>,
+) -> Result<MfaPolicy, sqlx::Error> {
+    let row = sqlx::query(
+        r#"
+        INSERT INTO mfa_policies (tenant_id, mode, role_ids, updated_by, updated_at)
+        VALUES ($1, $2, $3, $4, NOW())
+        ON CONFLICT (tenant_id) DO UPDATE
+        SET mode = EXCLUDED.mode,
+            role_ids = EXCLUDED.role_ids,
+            updated_by = EXCLUDED.updated_by,
+            updated_at = NOW()
+        RETURNING tenant_id, mode, role_ids, updated_by, updated_at
+        "#,
+    )
+    .bind(tenant_id)
+    .bind(mode)
+    .bind(role_ids)
+    .bind(updated_by)
+    .fetch_one(pool)
+    .await?;
+    Ok(policy_from_row(&row))
+}
+
+// ── TOTP ────────────────────────────────────────────────────────────────────
+
+#[derive(Debug, Clone)]
+pub struct TotpFactor {
+    pub secret_enc: Vec<u8>,
+    pub confirmed_at: Option<DateTime<Utc>>,
+    pub last_used_step: Option<i64>,
+}
+
+/// Store a new unconfirmed secret. Returns false when a confirmed factor
+/// already exists (it must be reset before re-enrolling).
+pub async fn upsert_pending_totp(
+    pool: &PgPool,
+    tenant_id: Uuid,
+    user_id: Uuid,
+    secret_enc: &[u8],
+) -> Result<bool, sqlx::Error> {
+    let res = sqlx::query(
+        r#"
+        INSERT INTO mfa_totp_factors (tenant_id, user_id, secret_enc)
+        VALUES ($1, $2, $3)
+        ON CONFLICT (tenant_id, user_id) DO UPDATE
+        SET secret_enc = EXCLUDED.secret_enc,
+            last_used_step = NULL,
+            created_at = NOW()
+        WHERE mfa_totp_factors.confirmed_at IS NULL
+        "#,
+    )
+    .bind(tenant_id)
+    .bind(user_id)
+    .bind(secret_enc)
+    .execute(pool)
+    .await?;
+    Ok(res.rows_affected() == 1)
+}
+
+pub async fn totp_factor_for_update(
+    tx: &mut Transaction<'_, Postgres>,
+    tenant_id: Uuid,
+    user_id: Uuid,
+) -> Result<Option<TotpFactor>, sqlx::Error> {
+    let row = sqlx::query(
+        r#"
+        SELECT secret_enc, confirmed_at, last_used_step
+        FROM mfa_totp_factors
+        WHERE tenant_id = $1 AND user_id = $2
+        FOR UPDATE
+        "#,
+    )
+    .bind(tenant_id)
+    .bind(user_id)
+    .fetch_optional(&mut **tx)
+    .await?;
+    Ok(row.map(|r| TotpFactor {
+        secret_enc: r.get("secret_enc"),
+        confirmed_at: r.get("confirmed_at"),
+        last_used_step: r.get("last_used_step"),
+    }))
+}
+
+/// Record an accepted code; the first one confirms the factor.
+pub async fn record_totp_use(
+    tx: &mut Transaction<'_, Postgres>,
+    tenant_id: Uuid,
+    user_id: Uuid,
+    step: i64,
+) -> Result<(), sqlx::Error> {
+    sqlx::query(
+        r#"
+        UPDATE mfa_totp_factors
+        SET last_used_step = $3,
+            confirmed_at = COALESCE(confirmed_at, NOW())
+        WHERE tenant_id = $1 AND user_id = $2
+        "#,
+    )
+    .bind(tenant_id)
+    .bind(user_id)
+    .bind(step)
+    .execute(&mut **tx)
+    .await?;
+    Ok(())
+}
+
+// ── Recovery codes ──────────────────────────────────────────────────────────
+
+/// Replace every recovery code of the user with `code_hashes`.
+pub async fn replace_recovery_codes(
+    tx: &mut Transaction<'_, Postgres>,
+    tenant_id: Uuid,
+    user_id: Uuid,
+    code_hashes: &[String],
+) -> Result<(), sqlx::Error> {
+    sqlx::query("DELETE FROM mfa_recovery_codes WHERE tenant_id = $1 AND user_id = $2")
+        .bind(tenant_id)
+        .bind(user_id)
+        .execute(&mut **tx)
+        .await?;
+    sqlx::query(
+        r#"
+        INSERT INTO mfa_recovery_codes (tenant_id, user_id, code_hash)
+        SELECT $1, $2, UNNEST($3::text[])
+        "#,
+    )
+    .bind(tenant_id)
+    .bind(user_id)
+    .bind(code_hashes)
+    .execute(&mut **tx)
+    .await?;
+    Ok(())
+}
+
+/// Burn a recovery code. Returns false when no unused code matches.
+pub async fn consume_recovery_code(
+    tx: &mut Transaction<'_, Postgres>,
+    tenant_id: Uuid,
+    user_id: Uuid,
+    code_hash: &str,
+) -> Result<bool, sqlx::Error> {
+    let res = sqlx::query(
+        r#"
+        UPDATE mfa_recovery_codes
+        SET used_at = NOW()
+        WHERE id = (
+            SELECT id FROM mfa_recovery_codes
+            WHERE tenant_id = $1 AND user_id = $2 AND code_hash = $3 AND used_at IS NULL
+            LIMIT 1
+            FOR UPDATE
+        )
+        "#,
+    )
+    .bind(tenant_id)
+    .bind(user_id)
+    .bind(code_hash)
+    .execute(&mut **tx)
+    .await?;
+    Ok(res.rows_affected() == 1)
+}
+
+// ── WebAuthn credentials ────────────────────────────────────────────────────
+
+#[derive(Debug, Clone, Serialize, utoipa::ToSchema)]
+pub struct WebAuthnCredentialInfo {
+    pub id: Uuid,
+    pub name: String,
+    pub created_at: DateTime<Utc>,
+    pub last_used_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Clone)]
+pub struct StoredCredential {
+    pub id: Uuid,
+    pub public_key: Vec<u8>,
+    pub sign_count: i64,
+}
+
+pub async fn insert_webauthn_credential(
+    tx: &mut Transaction<'_, Postgres>,
+    tenant_id: Uuid,
+    user_id: Uuid,
+    credential_id: &[u8],
+    public_key: &[u8],
+    sign_count: i64,
+    name: &str,
+) -> Result<WebAuthnCredentialInfo, sqlx::Error> {
+    let row = sqlx::query(
+        r#"
+        INSERT INTO mfa_webauthn_credentials
+            (tenant_id, user_id, credential_id, public_key, sign_count, name)
+        VALUES ($1, $2, $3, $4, $5, $6)
+        RETURNING id, name, created_at, last_used_at
+        "#,
+    )
+    .bind(tenant_id)
+    .bind(user_id)
+    .bind(credential_id)
+    .bind(public_key)
+    .bind(sign_count)
+    .bind(name)
+    .fetch_one(&mut **tx)
+    .await?;
+    Ok(WebAuthnCredentialInfo {
+        id: row.get("id"),
+        name: row.get("name"),
+        created_at: row.get("created_at"),
+        last_used_at: row.get("last_used_at"),
+    })
+}
+
+/// Raw credential IDs of the user — `excludeCredentials` / `allowCredentials`.
+pub async fn webauthn_credential_ids(
+    pool: &PgPool,
+    tenant_id: Uuid,
+    user_id: Uuid,
+) -> Result<Vec<Vec<u8>>, sqlx::Error> {
+    let rows = sqlx::query(
+        r#"
+        SELECT credential_id FROM mfa_webauthn_credentials
+        WHERE tenant_id = $1 AND user_id = $2
+        ORDER BY created_at
+        "#,
+    )
+    .bind(tenant_id)
+    .bind(user_id)
+    .fetch_all(pool)
+    .await?;
+    Ok(rows.iter().map(|r| r.get("credential_id")).collect())
+}
+
+pub async fn webauthn_credential_for_update(
+    tx: &mut Transaction<'_, Postgres>,
+    tenant_id: Uuid,
+    user_id: Uuid,
+    credential_id: &[u8],
+) -> Result<Option<StoredCredential>, sqlx::Error> {
+    let row = sqlx::query(
+        r#"
+        SELECT id, public_key, sign_count
+        FROM mfa_webauthn_credentials
+        WHERE tenant_id = $1 AND user_id = $2 AND credential_id = $3
+        FOR UPDATE
+        "#,
+    )
+    .bind(tenant_id)
+    .bind(user_id)
+    .bind(credential_id)
+    .fetch_optional(&mut **tx)
+    .await?;
+    Ok(row.map(|r| StoredCredential {
+        id: r.get("id"),
+        public_key: r.get("public_key"),
+        sign_count: r.get("sign_count"),
+    }))
+}
+
+pub async fn record_webauthn_use(
+    tx: &mut Transaction<'_, Postgres>,
+    id: Uuid,
+    sign_count: i64,
+) -> Result<(), sqlx::Error> {
+    sqlx::query(
+        "UPDATE mfa_webauthn_credentials SET sign_count = $2, last_used_at = NOW() WHERE id = $1",
+    )
+    .bind(id)
+    .bind(sign_count)
+    .execute(&mut **tx)
+    .await?;
+    Ok(())
+}
+
+/// Remove every factor and recovery code of the user (lost device).
+pub async fn reset_factors(
+    tx: &mut Transaction<'_, Postgres>,
+    tenant_id: Uuid,
+    user_id: Uuid,
+) -> Result<u64, sqlx::Error> {
+    let mut removed = 0;
+    for table in [
+        "mfa_totp_factors",
+        "mfa_webauthn_credentials",
+        "mfa_recovery_codes",
+    ] {
+        removed += sqlx::query(&format!(
+            "DELETE FROM {table} WHERE tenant_id = $1 AND user_id = $2"
+        ))
+        .bind(tenant_id)
+        .bind(user_id)
+        .execute(&mut **tx)
+        .await?
+        .rows_affected();
+    }
+    Ok(removed)
+}
+
+// ── Challenges ──────────────────────────────────────────────────────────────
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChallengePurpose {
+    Login,
+    StepUp,
+    WebAuthnRegister,
+}
+
+impl ChallengePurpose {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Login => "login",
+            Self::StepUp => "step_up",
+            Self::WebAuthnRegister => "webauthn_register",
+        }
+    }
+
+    fn parse(s: &str) -> Option<Self> {
+        match s {
+            "login" => Some(Self::Login),
+            "step_up" => Some(Self::StepUp),
+            "webauthn_register" => Some(Self::WebAuthnRegister),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct NewChallenge<'a> {
+    pub purpose: ChallengePurpose,
+    /// SHA-256 of the opaque mfa_token handed to the client.
+    pub token_hash: Option<&'a str>,
+    pub webauthn_challenge: Option<&'a [u8]>,
+    pub refresh_token_id: Option<Uuid>,
+    pub refresh_session_id: Option<Uuid>,
+    pub ttl_seconds: i64,
+}
+
+#[derive(Debug, Clone)]
+pub struct MfaChallenge {
+    pub id: Uuid,
+    pub tenant_id: Uuid,
+    pub user_id: Uuid,
+    pub purpose: ChallengePurpose,
+    pub webauthn_challenge: Option<Vec<u8>>,
+    pub refresh_token_id: Option<Uuid>,
+    pub refresh_session_id: Option<Uuid>,
+    pub attempts: i32,
+    pub expires_at: DateTime<Utc>,
+    pub consumed_at: Option<DateTime<Utc>>,
+}
+
+impl MfaChallenge {
+    /// Unconsumed, unexpired and not burned by failed attempts.
+    pub fn is_live(&self) -> bool {
+        self.consumed_at.is_none()
+            && self.expires_at > Utc::now()
+            && self.attempts < MAX_CHALLENGE_ATTEMPTS
+    }
+}
+
+pub async fn create_challenge(
+    pool: &PgPool,
+    tenant_id: Uuid,
+    user_id: Uuid,
+    new: NewChallenge<'_>,
+) -> Result<Uuid, sqlx::Error> {
+    let row = sqlx::query(
+        r#"
+        INSERT INTO mfa_challenges (
+            tenant_id, user_id, purpose, token_hash, webauthn_challenge,
+            refresh_token_id, refresh_session_id, expires_at
+        )
+        VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+        RETURNING id
+        "#,
+    )
+    .bind(tenant_id)
+    .bind(user_id)
+    .bind(new.purpose.as_str())
+    .bind(new.token_hash)
+    .bind(new.webauthn_challenge)
+    .bind(new.refresh_token_id)
+    .bind(new.refresh_session_id)
+    .bind(Utc::now() + Duration::seconds(new.ttl_seconds))
+    .fetch_one(pool)
+    .await?;
+    Ok(row.get("id"))
+}
+
+const CHALLENGE_COLUMNS: &str = "id, tenant_id, user_id, purpose, webauthn_challenge, \
+     refresh_token_id, refresh_session_id, attempts, expires_at, consumed_at";
+
+fn challenge_from_row(row: &sqlx::postgres::PgRow) -> Result<MfaChallenge, sqlx::Error> {
+    let purpose: String = row.get("purpose");
+    Ok(MfaChallenge {
+        id: row.get("id"),
+        tenant_id: row.get("tenant_id"),
+        user_id: row.get("user_id"),
+        purpose: ChallengePurpose::parse(&purpose)
+            .ok_or_else(|| sqlx::Error::Protocol(format!("unknown challenge purpose {purpose}")))?,
+        webauthn_challenge: row.get("webauthn_challenge"),
+        refresh_token_id: row.get("refresh_token_id"),
+        refresh_session_id: row.get("refresh_session_id"),
+        attempts: row.get("attempts"),
+        expires_at: row.get("expires_at"),
+        consumed_at: row.get("consumed_at"),
+    })
+}
+
+/// Look up a login / step-up challenge by its mfa_token hash, locking the row.
+pub async fn challenge_by_token_for_update(
+    tx: &mut Transaction<'_, Postgres>,
+    token_hash: &str,
+) -> Result<Option<MfaChallenge>, sqlx::Error> {
+    let row = sqlx::query(&format!(
+        "SELECT {CHALLENGE_COLUMNS} FROM mfa_challenges WHERE token_hash = $1 FOR UPDATE"
+    ))
+    .bind(token_hash)
+    .fetch_optional(&mut **tx)
+    .await?;
+    row.as_ref().map(challenge_from_row).transpose()
+}
+
+pub async fn challenge_by_id_for_update(
+    tx: &mut Transaction<'_, Postgres>,
+    id: Uuid,
+) -> Result<Option<MfaChallenge>, sqlx::Error> {
+    let row = sqlx::query(&format!(
+        "SELECT {CHALLENGE_COLUMNS} FROM mfa_challenges WHERE id = $1 FOR UPDATE"
+    ))
+    .bind(id)
+    .fetch_optional(&mut **tx)
+    .await?;
+    row.as_ref().map(challenge_from_row).transpose()
+}
+
+/// Count a failed verification against the challenge (outside the caller's
+/// rolled-back transaction, so failures stick).
+pub async fn record_failed_attempt(pool: &PgPool, id: Uuid) -> Result<(), sqlx::Error> {
+    sqlx::query("UPDATE mfa_challenges SET attempts = attempts + 1 WHERE id = $1")
+        .bind(id)
+        .execute(pool)
+        .await?;
+    Ok(())
+}
+
+pub async fn consume_challenge(
+    tx: &mut Transaction<'_, Postgres>,
+    id: Uuid,
+) -> Result<(), sqlx::Error> {
+    sqlx::query("UPDATE mfa_challenges SET consumed_at = NOW() WHERE id = $1")
+        .bind(id)
+        .execute(&mut **tx)
+        .await?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn methods_follow_enrolled_factors() {
+        let none = MfaStatus::default();
+        assert!(!none.challenge_required());
+        assert!(none.methods().is_empty());
+
+        let forced = MfaStatus {
+            policy_requires: true,
+            ..Default::default()
+        };
+        assert!(forced.challenge_required());
+        assert!(!forced.enrolled());
+
+        let enrolled = MfaStatus {
+            totp: true,
+            webauthn_credentials: 1,
+            recovery_codes_remaining: 3,
+            policy_requires: false,
+        };
+        assert!(enrolled.challenge_required());
+        assert_eq!(
+            enrolled.methods(),
+            vec![method::TOTP, method::WEBAUTHN, method::RECOVERY_CODE]
+        );
+
+        // Leftover recovery codes do not count without an enrolled factor
+        let orphaned = MfaStatus {
+            recovery_codes_remaining: 5,
+            ..Default::default()
+        };
+        assert!(orphaned.methods().is_empty());
+    }
+
+    #[test]
+    fn policy_modes_validate() {
+        assert!(policy_mode::is_valid("all_users"));
+        assert!(policy_mode::is_valid("roles"));
+        assert!(!policy_mode::is_valid("some"));
+    }
+}
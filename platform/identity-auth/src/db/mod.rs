@@ -1,5 +1,8 @@
+pub mod mfa;
+pub mod oidc;
 #[allow(dead_code)] // RBAC functions prepared for upcoming admin endpoints
 pub mod rbac;
+pub mod scim;
 pub mod sod;
 pub mod user_lifecycle_audit;
 
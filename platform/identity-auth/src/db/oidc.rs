@@ -0,0 +1,435 @@
+//! OIDC SSO persistence: per-tenant provider configuration, IdP group → role
+//! mappings, linked federated identities and pending authorization requests
+//! (see migration 015_oidc_sso.sql).
+
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::{PgPool, Postgres, Row, Transaction};
+use uuid::Uuid;
+
+// ── Providers ───────────────────────────────────────────────────────────────
+
+/// Provider row including the sealed client secret. Never serialized.
+#[derive(Debug, Clone)]
+pub struct OidcProvider {
+    pub tenant_id: Uuid,
+    pub issuer: String,
+    pub client_id: String,
+    pub client_secret_enc: Option<Vec<u8>>,
+    pub redirect_uri: String,
+    pub scopes: Vec<String>,
+    pub email_claim: String,
+    pub groups_claim: String,
+    pub jit_provisioning: bool,
+    pub link_by_email: bool,
+    pub password_login_disabled: bool,
+    pub enabled: bool,
+    pub updated_by: Option<Uuid>,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// Provider configuration as returned by the admin API (secret redacted).
+#[derive(Debug, Clone, Serialize, utoipa::ToSchema)]
+pub struct OidcProviderView {
+    pub tenant_id: Uuid,
+    pub issuer: String,
+    pub client_id: String,
+    pub has_client_secret: bool,
+    pub redirect_uri: String,
+    pub scopes: Vec<String>,
+    pub email_claim: String,
+    pub groups_claim: String,
+    pub jit_provisioning: bool,
+    pub link_by_email: bool,
+    pub password_login_disabled: bool,
+    pub enabled: bool,
+    pub updated_by: Option<Uuid>,
+    pub updated_at: DateTime<Utc>,
+    pub group_mappings: Vec<GroupRoleMapping>,
+}
+
+impl OidcProvider {
+    pub fn view(&self, group_mappings: Vec<GroupRoleMapping>) -> OidcProviderView {
+        OidcProviderView {
+            tenant_id: self.tenant_id,
+            issuer: self.issuer.clone(),
+            client_id: self.client_id.clone(),
+            has_client_secret: self.client_secret_enc.is_some(),
+            redirect_uri: self.redirect_uri.clone(),
+            scopes: self.scopes.clone(),
+            email_claim: self.email_claim.clone(),
+            groups_claim: self.groups_claim.clone(),
+            jit_provisioning: self.jit_provisioning,
+            link_by_email: self.link_by_email,
+            password_login_disabled: self.password_login_disabled,
+            enabled: self.enabled,
+            updated_by: self.updated_by,
+            updated_at: self.updated_at,
+            group_mappings,
+        }
+    }
+}
+
+const PROVIDER_COLUMNS: &str = "tenant_id, issuer, client_id, client_secret_enc, redirect_uri, \
+     scopes, email_claim, groups_claim, jit_provisioning, link_by_email, \
+     password_login_disabled, enabled, updated_by, updated_at";
+
+fn provider_from_row(row: &sqlx::postgres::PgRow) -> OidcProvider {
+    OidcProvider {
+        tenant_id: row.get("tenant_id"),
+        issuer: row.get("issuer"),
+        client_id: row.get("client_id"),
+        client_secret_enc: row.get("client_secret_enc"),
+        redirect_uri: row.get("redirect_uri"),
+        scopes: row.get("scopes"),
+        email_claim: row.get("email_claim"),
+        groups_claim: row.get("groups_claim"),
+        jit_provisioning: row.get("jit_provisioning"),
+        link_by_email: row.get("link_by_email"),
+        password_login_disabled: row.get("password_login_disabled"),
+        enabled: row.get("enabled"),
+        updated_by: row.get("updated_by"),
+        updated_at: row.get("updated_at"),
+    }
+}
+
+/// Fields written by [`upsert_provider`].
+#[derive(Debug, Clone)]
+pub struct ProviderUpsert<'a> {
+    pub issuer: &'a str,
+    pub client_id: &'a str,
+    /// `None` keeps the stored secret.
+    pub client_secret_enc: Option<&'a [u8]>,
+    pub redirect_uri: &'a str,
+    pub scopes: &'a [String],
+    pub email_claim: &'a str,
+    pub groups_claim: &'a str,
+    pub jit_provisioning: bool,
+    pub link_by_email: bool,
+    pub password_login_disabled: bool,
+    pub enabled: bool,
+    pub updated_by: Option<Uuid>,
+}
+
+pub async fn upsert_provider(
+    pool: &PgPool,
+    tenant_id: Uuid,
+    p: ProviderUpsert<'_>,
+) -> Result<OidcProvider, sqlx::Error> {
+    let row = sqlx::query(&format!(
+        r#"
+        INSERT INTO oidc_providers (
+            tenant_id, issuer, client_id, client_secret_enc, redirect_uri, scopes,
+            email_claim, groups_claim, jit_provisioning, link_by_email,
+            password_login_disabled, enabled, updated_by, updated_at
+        )
+        VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, NOW())
+        ON CONFLICT (tenant_id) DO UPDATE
+        SET issuer = EXCLUDED.issuer,
+            client_id = EXCLUDED.client_id,
+            client_secret_enc = COALESCE(EXCLUDED.client_secret_enc, oidc_providers.client_secret_enc),
+            redirect_uri = EXCLUDED.redirect_uri,
+            scopes = EXCLUDED.scopes,
+            email_claim = EXCLUDED.email_claim,
+            groups_claim = EXCLUDED.groups_claim,
+            jit_provisioning = EXCLUDED.jit_provisioning,
+            link_by_email = EXCLUDED.link_by_email,
+            password_login_disabled = EXCLUDED.password_login_disabled,
+            enabled = EXCLUDED.enabled,
+            updated_by = EXCLUDED.updated_by,
+            updated_at = NOW()
+        RETURNING {PROVIDER_COLUMNS}
+        "#
+    ))
+    .bind(tenant_id)
+    .bind(p.issuer)
+    .bind(p.client_id)
+    .bind(p.client_secret_enc)
+    .bind(p.redirect_uri)
+    .bind(p.scopes)
+    .bind(p.email_claim)
+    .bind(p.groups_claim)
+    .bind(p.jit_provisioning)
+    .bind(p.link_by_email)
+    .bind(p.password_login_disabled)
+    .bind(p.enabled)
+    .bind(p.updated_by)
+    .fetch_one(pool)
+    .await?;
+    Ok(provider_from_row(&row))
+}
+
+pub async fn get_provider(
+    pool: &PgPool,
+    tenant_id: Uuid,
+) -> Result<Option<OidcProvider>, sqlx::Error> {
+    let row = sqlx::query(&format!(
+        "SELECT {PROVIDER_COLUMNS} FROM oidc_providers WHERE tenant_id = $1"
+    ))
+    .bind(tenant_id)
+    .fetch_optional(pool)
+    .await?;
+    Ok(row.as_ref().map(provider_from_row))
+}
+
+pub async fn delete_provider(pool: &PgPool, tenant_id: Uuid) -> Result<bool, sqlx::Error> {
+    let res = sqlx::query("DELETE FROM oidc_providers WHERE tenant_id = $1")
+        .bind(tenant_id)
+        .execute(pool)
+        .await?;
+    Ok(res.rows_affected() > 0)
+}
+
+/// True when the tenant has an enabled provider that replaces password login.
+pub async fn password_login_disabled(pool: &PgPool, tenant_id: Uuid) -> Result<bool, sqlx::Error> {
+    let disabled: Option<bool> = sqlx::query_scalar(
+        r#"
+        SELECT password_login_disabled FROM oidc_providers
+        WHERE tenant_id = $1 AND enabled
+        "#,
+    )
+    .bind(tenant_id)
+    .fetch_optional(pool)
+    .await?;
+    Ok(disabled.unwrap_or(false))
+}
+
+// ── Group → role mappings ───────────────────────────────────────────────────
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, utoipa::ToSchema)]
+pub struct GroupRoleMapping {
+    /// Group name as it appears in the ID token's groups claim.
+    pub group: String,
+    pub role_id: Uuid,
+}
+
+pub async fn list_group_mappings(
+    pool: &PgPool,
+    tenant_id: Uuid,
+) -> Result<Vec<GroupRoleMapping>, sqlx::Error> {
+    let rows = sqlx::query(
+        r#"
+        SELECT idp_group, role_id FROM oidc_group_role_mappings
+        WHERE tenant_id = $1
+        ORDER BY idp_group, role_id
+        "#,
+    )
+    .bind(tenant_id)
+    .fetch_all(pool)
+    .await?;
+    Ok(rows
+        .iter()
+        .map(|r| GroupRoleMapping {
+            group: r.get("idp_group"),
+            role_id: r.get("role_id"),
+        })
+        .collect())
+}
+
+/// Replace every mapping of the tenant.
+pub async fn replace_group_mappings(
+    pool: &PgPool,
+    tenant_id: Uuid,
+    mappings: &[GroupRoleMapping],
+) -> Result<(), sqlx::Error> {
+    let mut tx = pool.begin().await?;
+    sqlx::query("DELETE FROM oidc_group_role_mappings WHERE tenant_id = $1")
+        .bind(tenant_id)
+        .execute(&mut *tx)
+        .await?;
+    let groups: Vec<&str> = mappings.iter().map(|m| m.group.as_str()).collect();
+    let roles: Vec<Uuid> = mappings.iter().map(|m| m.role_id).collect();
+    sqlx::query(
+        r#"
+        INSERT INTO oidc_group_role_mappings (tenant_id, idp_group, role_id)
+        SELECT $1, g, r FROM UNNEST($2::text[], $3::uuid[]) AS m(g, r)
+        ON CONFLICT DO NOTHING
+        "#,
+    )
+    .bind(tenant_id)
+    .bind(&groups)
+    .bind(&roles)
+    .execute(&mut *tx)
+    .await?;
+    tx.commit().await
+}
+
+/// Roles the user should hold given their IdP groups, and every role the
+/// mappings manage (roles outside this set are never touched by SSO).
+pub fn resolve_mapped_roles(
+    mappings: &[GroupRoleMapping],
+    groups: &[String],
+) -> (Vec<Uuid>, Vec<Uuid>) {
+    let mut desired: Vec<Uuid> = mappings
+        .iter()
+        .filter(|m| groups.iter().any(|g| g == &m.group))
+        .map(|m| m.role_id)
+        .collect();
+    desired.sort();
+    desired.dedup();
+    let mut managed: Vec<Uuid> = mappings.iter().map(|m| m.role_id).collect();
+    managed.sort();
+    managed.dedup();
+    (desired, managed)
+}
+
+// ── Federated identities ────────────────────────────────────────────────────
+
+pub async fn find_identity_user(
+    pool: &PgPool,
+    tenant_id: Uuid,
+    issuer: &str,
+    subject: &str,
+) -> Result<Option<Uuid>, sqlx::Error> {
+    sqlx::query_scalar(
+        r#"
+        SELECT user_id FROM oidc_identities
+        WHERE tenant_id = $1 AND issuer = $2 AND subject = $3
+        "#,
+    )
+    .bind(tenant_id)
+    .bind(issuer)
+    .bind(subject)
+    .fetch_optional(pool)
+    .await
+}
+
+pub async fn link_identity(
+    tx: &mut Transaction<'_, Postgres>,
+    tenant_id: Uuid,
+    user_id: Uuid,
+    issuer: &str,
+    subject: &str,
+    email: Option<&str>,
+) -> Result<(), sqlx::Error> {
+    sqlx::query(
+        r#"
+        INSERT INTO oidc_identities (tenant_id, user_id, issuer, subject, email, last_login_at)
+        VALUES ($1, $2, $3, $4, $5, NOW())
+        "#,
+    )
+    .bind(tenant_id)
+    .bind(user_id)
+    .bind(issuer)
+    .bind(subject)
+    .bind(email)
+    .execute(&mut **tx)
+    .await?;
+    Ok(())
+}
+
+pub async fn touch_identity(
+    pool: &PgPool,
+    tenant_id: Uuid,
+    issuer: &str,
+    subject: &str,
+    email: Option<&str>,
+) -> Result<(), sqlx::Error> {
+    sqlx::query(
+        r#"
+        UPDATE oidc_identities
+        SET last_login_at = NOW(), email = COALESCE($4, email)
+        WHERE tenant_id = $1 AND issuer = $2 AND subject = $3
+        "#,
+    )
+    .bind(tenant_id)
+    .bind(issuer)
+    .bind(subject)
+    .bind(email)
+    .execute(pool)
+    .await?;
+    Ok(())
+}
+
+// ── Authorization requests ──────────────────────────────────────────────────
+
+#[derive(Debug, Clone)]
+pub struct LoginState {
+    pub tenant_id: Uuid,
+    pub nonce: String,
+    pub code_verifier: String,
+    pub return_to: Option<String>,
+}
+
+pub async fn create_login_state(
+    pool: &PgPool,
+    state_hash: &str,
+    login: &LoginState,
+    ttl_seconds: i64,
+) -> Result<(), sqlx::Error> {
+    sqlx::query(
+        r#"
+        INSERT INTO oidc_login_states
+            (state_hash, tenant_id, nonce, code_verifier, return_to, expires_at)
+        VALUES ($1, $2, $3, $4, $5, $6)
+        "#,
+    )
+    .bind(state_hash)
+    .bind(login.tenant_id)
+    .bind(&login.nonce)
+    .bind(&login.code_verifier)
+    .bind(&login.return_to)
+    .bind(Utc::now() + Duration::seconds(ttl_seconds))
+    .execute(pool)
+    .await?;
+    Ok(())
+}
+
+/// Consume a pending authorization request. `None` when unknown, expired or
+/// already used — each `state` is redeemable once.
+pub async fn take_login_state(
+    pool: &PgPool,
+    state_hash: &str,
+) -> Result<Option<LoginState>, sqlx::Error> {
+    let row = sqlx::query(
+        r#"
+        UPDATE oidc_login_states
+        SET consumed_at = NOW()
+        WHERE state_hash = $1 AND consumed_at IS NULL AND expires_at > NOW()
+        RETURNING tenant_id, nonce, code_verifier, return_to
+        "#,
+    )
+    .bind(state_hash)
+    .fetch_optional(pool)
+    .await?;
+    Ok(row.map(|r| LoginState {
+        tenant_id: r.get("tenant_id"),
+        nonce: r.get("nonce"),
+        code_verifier: r.get("code_verifier"),
+        return_to: r.get("return_to"),
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn mapped_roles_follow_groups() {
+        let finance = Uuid::new_v4();
+        let approver = Uuid::new_v4();
+        let mappings = vec![
+            GroupRoleMapping {
+                group: "finance".into(),
+                role_id: finance,
+            },
+            GroupRoleMapping {
+                group: "ap-approvers".into(),
+                role_id: approver,
+            },
+            GroupRoleMapping {
+                group: "controllers".into(),
+                role_id: approver,
+            },
+        ];
+
+        let (desired, managed) =
+            resolve_mapped_roles(&mappings, &["controllers".into(), "staff".into()]);
+        assert_eq!(desired, vec![approver]);
+        assert_eq!(managed.len(), 2);
+        assert!(managed.contains(&finance));
+
+        let (desired, _) = resolve_mapped_roles(&mappings, &[]);
+        assert!(desired.is_empty());
+    }
+}
@@ -1,5 +1,7 @@
 use chrono::{DateTime, Utc};
+use security::{DataScopes, ScopeGrant};
 use serde_json::json;
+use sqlx::types::Json;
 use sqlx::PgPool;
 use uuid::Uuid;
 
@@ -30,6 +32,8 @@ pub struct UserRoleBinding {
     pub tenant_id: Uuid,
     pub user_id: Uuid,
     pub role_id: Uuid,
+    /// Data scope restricting the role's permissions; `None` = tenant-wide.
+    pub scope: Option<Json<ScopeGrant>>,
     pub granted_by: Option<Uuid>,
     pub granted_at: DateTime<Utc>,
     pub revoked_at: Option<DateTime<Utc>>,
@@ -118,6 +122,21 @@ pub async fn get_role(
     .await
 }
 
+pub async fn get_role_by_name(
+    pool: &PgPool,
+    tenant_id: Uuid,
+    name: &str,
+) -> Result<Option<Role>, sqlx::Error> {
+    sqlx::query_as::<_, Role>(
+        r#"SELECT id, tenant_id, name, description, is_system, created_at, updated_at
+           FROM roles WHERE tenant_id = $1 AND name = $2"#,
+    )
+    .bind(tenant_id)
+    .bind(name)
+    .fetch_optional(pool)
+    .await
+}
+
 pub async fn list_roles(pool: &PgPool, tenant_id: Uuid) -> Result<Vec<Role>, sqlx::Error> {
     sqlx::query_as::<_, Role>(
         r#"SELECT id, tenant_id, name, description, is_system, created_at, updated_at
@@ -242,19 +261,35 @@ pub async fn bind_user_role_with_audit(
     role_id: Uuid,
     granted_by: Option<Uuid>,
     ctx: &crate::db::user_lifecycle_audit::LifecycleAuditContext,
+) -> Result<UserRoleBinding, sqlx::Error> {
+    bind_user_role_scoped_with_audit(pool, tenant_id, user_id, role_id, None, granted_by, ctx).await
+}
+
+/// Bind a role restricted to a data scope (e.g. `{"business_unit": ["EAST"]}`).
+///
+/// Re-binding replaces the previous scope; `None` makes the grant tenant-wide.
+pub async fn bind_user_role_scoped_with_audit(
+    pool: &PgPool,
+    tenant_id: Uuid,
+    user_id: Uuid,
+    role_id: Uuid,
+    scope: Option<&ScopeGrant>,
+    granted_by: Option<Uuid>,
+    ctx: &crate::db::user_lifecycle_audit::LifecycleAuditContext,
 ) -> Result<UserRoleBinding, sqlx::Error> {
     let mut tx = pool.begin().await?;
     let binding = sqlx::query_as::<_, UserRoleBinding>(
-        r#"INSERT INTO user_role_bindings (tenant_id, user_id, role_id, granted_by)
-           VALUES ($1, $2, $3, $4)
+        r#"INSERT INTO user_role_bindings (tenant_id, user_id, role_id, granted_by, scope)
+           VALUES ($1, $2, $3, $4, $5)
            ON CONFLICT (tenant_id, user_id, role_id)
-           DO UPDATE SET revoked_at = NULL, granted_by = $4, granted_at = NOW()
-           RETURNING id, tenant_id, user_id, role_id, granted_by, granted_at, revoked_at"#,
+           DO UPDATE SET revoked_at = NULL, granted_by = $4, granted_at = NOW(), scope = $5
+           RETURNING id, tenant_id, user_id, role_id, scope, granted_by, granted_at, revoked_at"#,
     )
     .bind(tenant_id)
     .bind(user_id)
     .bind(role_id)
     .bind(granted_by)
+    .bind(scope.map(Json))
     .fetch_one(&mut *tx)
     .await?;
 
@@ -262,6 +297,7 @@ pub async fn bind_user_role_with_audit(
         "binding_id": binding.id,
         "user_id": user_id,
         "role_id": role_id,
+        "scope": scope,
         "granted_by": granted_by,
         "granted_at": binding.granted_at,
     });
@@ -395,3 +431,109 @@ pub async fn effective_permissions_for_user(
     .await?;
     Ok(rows)
 }
+
+/// Effective data scopes for the user's permissions, for token embedding.
+///
+/// A permission is restricted only when every active binding that confers it
+/// is scoped; one unscoped binding makes it tenant-wide (omitted here).
+pub async fn effective_scopes_for_user(
+    pool: &PgPool,
+    tenant_id: Uuid,
+    user_id: Uuid,
+) -> Result<DataScopes, sqlx::Error> {
+    let rows = sqlx::query_as::<_, (String, Option<Json<ScopeGrant>>)>(
+        r#"SELECT p.key, urb.scope
+           FROM permissions p
+           JOIN role_permissions rp ON rp.permission_id = p.id
+           JOIN user_role_bindings urb ON urb.role_id = rp.role_id
+           WHERE urb.tenant_id = $1
+             AND urb.user_id = $2
+             AND urb.revoked_at IS NULL
+           ORDER BY p.key, urb.granted_at"#,
+    )
+    .bind(tenant_id)
+    .bind(user_id)
+    .fetch_all(pool)
+    .await?;
+    Ok(collapse_scopes(
+        rows.into_iter()
+            .map(|(perm, scope)| (perm, scope.map(|s| s.0))),
+    ))
+}
+
+fn collapse_scopes(rows: impl IntoIterator<Item = (String, Option<ScopeGrant>)>) -> DataScopes {
+    let mut scoped: std::collections::BTreeMap<String, Vec<ScopeGrant>> = Default::default();
+    let mut unscoped = std::collections::BTreeSet::new();
+    for (perm, scope) in rows {
+        match scope {
+            Some(grant) if !grant.is_empty() => {
+                let grants = scoped.entry(perm).or_default();
+                if !grants.contains(&grant) {
+                    grants.push(grant);
+                }
+            }
+            _ => {
+                unscoped.insert(perm);
+            }
+        }
+    }
+    let mut scopes = DataScopes::new();
+    for (perm, grants) in scoped {
+        if unscoped.contains(&perm) {
+            continue;
+        }
+        for grant in grants {
+            scopes = scopes.restrict(perm.clone(), grant);
+        }
+    }
+    scopes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn grant(attribute: &str, values: &[&str]) -> ScopeGrant {
+        [(
+            attribute.to_string(),
+            values.iter().map(|v| v.to_string()).collect(),
+        )]
+        .into_iter()
+        .collect()
+    }
+
+    #[test]
+    fn unscoped_binding_wins_over_scoped() {
+        let scopes = collapse_scopes(vec![
+            (
+                "ap.bills.approve".to_string(),
+                Some(grant("business_unit", &["EAST"])),
+            ),
+            ("ap.bills.approve".to_string(), None),
+            (
+                "inventory.read".to_string(),
+                Some(grant("location", &["W1"])),
+            ),
+            (
+                "inventory.read".to_string(),
+                Some(grant("location", &["W2"])),
+            ),
+            (
+                "inventory.read".to_string(),
+                Some(grant("location", &["W1"])),
+            ),
+        ]);
+
+        assert!(scopes.grants("ap.bills.approve").is_none());
+        assert_eq!(
+            scopes.grants("inventory.read").expect("scoped"),
+            &[grant("location", &["W1"]), grant("location", &["W2"])]
+        );
+    }
+
+    #[test]
+    fn empty_scope_object_is_unscoped() {
+        let scopes = collapse_scopes(vec![("gl.post".to_string(), Some(ScopeGrant::new()))]);
+        assert!(scopes.is_empty());
+    }
+}
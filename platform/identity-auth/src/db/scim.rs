@@ -0,0 +1,579 @@
+//! SCIM 2.0 persistence: per-tenant bearer tokens, SCIM user attributes on
+//! top of `credentials`, and role-backed groups (see migration 016_scim.sql).
+
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use sqlx::{postgres::PgArguments, PgPool, Postgres, Row, Transaction};
+use uuid::Uuid;
+
+use crate::auth::scim::{Column, ColumnKind, FilterValue, ScimUser, SqlFilter};
+
+// ── Tokens ──────────────────────────────────────────────────────────────────
+
+/// Token metadata as returned by the admin API (never the hash).
+#[derive(Debug, Clone, Serialize, utoipa::ToSchema)]
+pub struct ScimToken {
+    pub id: Uuid,
+    pub tenant_id: Uuid,
+    pub description: String,
+    pub created_by: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+    pub last_used_at: Option<DateTime<Utc>>,
+    pub revoked_at: Option<DateTime<Utc>>,
+}
+
+const TOKEN_COLUMNS: &str =
+    "id, tenant_id, description, created_by, created_at, last_used_at, revoked_at";
+
+fn token_from_row(row: &sqlx::postgres::PgRow) -> ScimToken {
+    ScimToken {
+        id: row.get("id"),
+        tenant_id: row.get("tenant_id"),
+        description: row.get("description"),
+        created_by: row.get("created_by"),
+        created_at: row.get("created_at"),
+        last_used_at: row.get("last_used_at"),
+        revoked_at: row.get("revoked_at"),
+    }
+}
+
+pub async fn create_token(
+    pool: &PgPool,
+    tenant_id: Uuid,
+    token_hash: &str,
+    description: &str,
+    created_by: Option<Uuid>,
+) -> Result<ScimToken, sqlx::Error> {
+    let row = sqlx::query(&format!(
+        "INSERT INTO scim_tokens (tenant_id, token_hash, description, created_by) \
+         VALUES ($1, $2, $3, $4) RETURNING {TOKEN_COLUMNS}"
+    ))
+    .bind(tenant_id)
+    .bind(token_hash)
+    .bind(description)
+    .bind(created_by)
+    .fetch_one(pool)
+    .await?;
+    Ok(token_from_row(&row))
+}
+
+pub async fn list_tokens(pool: &PgPool, tenant_id: Uuid) -> Result<Vec<ScimToken>, sqlx::Error> {
+    let rows = sqlx::query(&format!(
+        "SELECT {TOKEN_COLUMNS} FROM scim_tokens WHERE tenant_id = $1 ORDER BY created_at"
+    ))
+    .bind(tenant_id)
+    .fetch_all(pool)
+    .await?;
+    Ok(rows.iter().map(token_from_row).collect())
+}
+
+/// Returns false when the token does not exist or is already revoked.
+pub async fn revoke_token(
+    pool: &PgPool,
+    tenant_id: Uuid,
+    token_id: Uuid,
+) -> Result<bool, sqlx::Error> {
+    let res = sqlx::query(
+        "UPDATE scim_tokens SET revoked_at = NOW() \
+         WHERE tenant_id = $1 AND id = $2 AND revoked_at IS NULL",
+    )
+    .bind(tenant_id)
+    .bind(token_id)
+    .execute(pool)
+    .await?;
+    Ok(res.rows_affected() > 0)
+}
+
+/// Tenant of a live token, recording its use.
+pub async fn authenticate(pool: &PgPool, token_hash: &str) -> Result<Option<Uuid>, sqlx::Error> {
+    sqlx::query_scalar(
+        "UPDATE scim_tokens SET last_used_at = NOW() \
+         WHERE token_hash = $1 AND revoked_at IS NULL RETURNING tenant_id",
+    )
+    .bind(token_hash)
+    .fetch_optional(pool)
+    .await
+}
+
+// ── Filtering ───────────────────────────────────────────────────────────────
+
+type PgQuery<'q> = sqlx::query::Query<'q, Postgres, PgArguments>;
+
+fn bind_filter<'q>(mut q: PgQuery<'q>, filter: Option<&'q SqlFilter>) -> PgQuery<'q> {
+    for value in filter.map(|f| f.binds.as_slice()).unwrap_or_default() {
+        q = match value {
+            FilterValue::Str(s) => q.bind(s.as_str()),
+            FilterValue::Bool(b) => q.bind(*b),
+            FilterValue::Null => q.bind(Option::<String>::None),
+        };
+    }
+    q
+}
+
+fn where_filter(filter: Option<&SqlFilter>) -> String {
+    filter
+        .map(|f| format!(" AND {}", f.sql))
+        .unwrap_or_default()
+}
+
+// ── Users ───────────────────────────────────────────────────────────────────
+
+/// A tenant user as SCIM sees it: every credential not deleted via SCIM,
+/// with SCIM attributes where the IdP supplied them.
+#[derive(Debug, Clone)]
+pub struct ScimUserRow {
+    pub user_id: Uuid,
+    pub email: String,
+    pub active: bool,
+    pub user_name: String,
+    pub external_id: Option<String>,
+    pub given_name: Option<String>,
+    pub family_name: Option<String>,
+    pub display_name: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl ScimUserRow {
+    pub fn attributes(&self) -> ScimUser {
+        ScimUser {
+            user_name: self.user_name.clone(),
+            email: self.email.clone(),
+            external_id: self.external_id.clone(),
+            given_name: self.given_name.clone(),
+            family_name: self.family_name.clone(),
+            display_name: self.display_name.clone(),
+            active: self.active,
+        }
+    }
+}
+
+const USER_SELECT: &str = "SELECT c.user_id, c.email, c.is_active, \
+     COALESCE(s.user_name, c.email) AS user_name, s.external_id, s.given_name, \
+     s.family_name, s.display_name, c.created_at, \
+     GREATEST(c.updated_at, s.updated_at) AS updated_at \
+     FROM credentials c \
+     LEFT JOIN scim_users s ON s.tenant_id = c.tenant_id AND s.user_id = c.user_id \
+     WHERE c.tenant_id = $1 AND s.deleted_at IS NULL";
+
+/// Filterable User attributes (normalized names) and their SQL.
+pub fn user_column(attr: &str) -> Option<Column> {
+    let (expr, kind) = match attr {
+        "id" => ("c.user_id::text", ColumnKind::ExactText),
+        "username" => ("COALESCE(s.user_name, c.email)", ColumnKind::Text),
+        "externalid" => ("s.external_id", ColumnKind::ExactText),
+        "emails" | "emails.value" => ("c.email", ColumnKind::Text),
+        "displayname" => ("s.display_name", ColumnKind::Text),
+        "name.givenname" => ("s.given_name", ColumnKind::Text),
+        "name.familyname" => ("s.family_name", ColumnKind::Text),
+        "active" => ("c.is_active", ColumnKind::Bool),
+        _ => return None,
+    };
+    Some(Column { expr, kind })
+}
+
+fn user_from_row(row: &sqlx::postgres::PgRow) -> ScimUserRow {
+    ScimUserRow {
+        user_id: row.get("user_id"),
+        email: row.get("email"),
+        active: row.get("is_active"),
+        user_name: row.get("user_name"),
+        external_id: row.get("external_id"),
+        given_name: row.get("given_name"),
+        family_name: row.get("family_name"),
+        display_name: row.get("display_name"),
+        created_at: row.get("created_at"),
+        updated_at: row.get("updated_at"),
+    }
+}
+
+/// One page of users (`offset` is zero-based) plus the total match count.
+/// Filter binds must be numbered from `$2`.
+pub async fn list_users(
+    pool: &PgPool,
+    tenant_id: Uuid,
+    filter: Option<&SqlFilter>,
+    offset: i64,
+    limit: i64,
+) -> Result<(i64, Vec<ScimUserRow>), sqlx::Error> {
+    let cond = where_filter(filter);
+    let n = 2 + filter.map_or(0, |f| f.binds.len());
+
+    let count_sql = format!("SELECT COUNT(*) FROM ({USER_SELECT}{cond}) matched");
+    let total: i64 = bind_filter(sqlx::query(&count_sql).bind(tenant_id), filter)
+        .fetch_one(pool)
+        .await?
+        .get(0);
+
+    let page_sql = format!(
+        "{USER_SELECT}{cond} ORDER BY c.created_at, c.user_id LIMIT ${n} OFFSET ${}",
+        n + 1
+    );
+    let rows = bind_filter(sqlx::query(&page_sql).bind(tenant_id), filter)
+        .bind(limit)
+        .bind(offset)
+        .fetch_all(pool)
+        .await?;
+    Ok((total, rows.iter().map(user_from_row).collect()))
+}
+
+pub async fn get_user(
+    pool: &PgPool,
+    tenant_id: Uuid,
+    user_id: Uuid,
+) -> Result<Option<ScimUserRow>, sqlx::Error> {
+    let row = sqlx::query(&format!("{USER_SELECT} AND c.user_id = $2"))
+        .bind(tenant_id)
+        .bind(user_id)
+        .fetch_optional(pool)
+        .await?;
+    Ok(row.as_ref().map(user_from_row))
+}
+
+/// Credential already holding an email, as looked up by SCIM create.
+#[derive(Debug, Clone, Copy)]
+pub struct ExistingCredential {
+    pub user_id: Uuid,
+    pub active: bool,
+    /// A live `scim_users` row exists, i.e. the IdP already provisioned it.
+    pub managed: bool,
+}
+
+pub async fn find_credential_by_email(
+    pool: &PgPool,
+    tenant_id: Uuid,
+    email: &str,
+) -> Result<Option<ExistingCredential>, sqlx::Error> {
+    let row = sqlx::query(
+        "SELECT c.user_id, c.is_active, \
+                (s.user_id IS NOT NULL AND s.deleted_at IS NULL) AS managed \
+         FROM credentials c \
+         LEFT JOIN scim_users s ON s.tenant_id = c.tenant_id AND s.user_id = c.user_id \
+         WHERE c.tenant_id = $1 AND c.email = $2",
+    )
+    .bind(tenant_id)
+    .bind(email)
+    .fetch_optional(pool)
+    .await?;
+    Ok(row.map(|r| ExistingCredential {
+        user_id: r.get("user_id"),
+        active: r.get("is_active"),
+        managed: r.get("managed"),
+    }))
+}
+
+/// Insert or replace the SCIM attributes; clears a previous SCIM delete.
+pub async fn upsert_user_attributes(
+    tx: &mut Transaction<'_, Postgres>,
+    tenant_id: Uuid,
+    user_id: Uuid,
+    user: &ScimUser,
+) -> Result<(), sqlx::Error> {
+    sqlx::query(
+        r#"
+        INSERT INTO scim_users
+            (tenant_id, user_id, user_name, external_id, given_name, family_name, display_name)
+        VALUES ($1, $2, $3, $4, $5, $6, $7)
+        ON CONFLICT (tenant_id, user_id) DO UPDATE SET
+            user_name = EXCLUDED.user_name,
+            external_id = EXCLUDED.external_id,
+            given_name = EXCLUDED.given_name,
+            family_name = EXCLUDED.family_name,
+            display_name = EXCLUDED.display_name,
+            updated_at = NOW(),
+            deleted_at = NULL
+        "#,
+    )
+    .bind(tenant_id)
+    .bind(user_id)
+    .bind(&user.user_name)
+    .bind(&user.external_id)
+    .bind(&user.given_name)
+    .bind(&user.family_name)
+    .bind(&user.display_name)
+    .execute(&mut **tx)
+    .await?;
+    Ok(())
+}
+
+/// Update the login email and active flag on the credentials row.
+pub async fn update_credentials(
+    tx: &mut Transaction<'_, Postgres>,
+    tenant_id: Uuid,
+    user_id: Uuid,
+    email: &str,
+    active: bool,
+) -> Result<(), sqlx::Error> {
+    sqlx::query(
+        "UPDATE credentials SET email = $3, is_active = $4, updated_at = NOW() \
+         WHERE tenant_id = $1 AND user_id = $2",
+    )
+    .bind(tenant_id)
+    .bind(user_id)
+    .bind(email)
+    .bind(active)
+    .execute(&mut **tx)
+    .await?;
+    Ok(())
+}
+
+pub async fn mark_user_deleted(
+    tx: &mut Transaction<'_, Postgres>,
+    tenant_id: Uuid,
+    user_id: Uuid,
+) -> Result<(), sqlx::Error> {
+    sqlx::query(
+        "UPDATE scim_users SET deleted_at = NOW(), updated_at = NOW() \
+         WHERE tenant_id = $1 AND user_id = $2",
+    )
+    .bind(tenant_id)
+    .bind(user_id)
+    .execute(&mut **tx)
+    .await?;
+    Ok(())
+}
+
+/// Revoke legacy refresh tokens and seat leases. `refresh_sessions` rows are
+/// revoked separately via `refresh_sessions::revoke_all_for_user`.
+pub async fn revoke_legacy_sessions(
+    tx: &mut Transaction<'_, Postgres>,
+    tenant_id: Uuid,
+    user_id: Uuid,
+) -> Result<u64, sqlx::Error> {
+    let tokens = sqlx::query(
+        "UPDATE refresh_tokens SET revoked_at = NOW() \
+         WHERE tenant_id = $1 AND user_id = $2 AND revoked_at IS NULL",
+    )
+    .bind(tenant_id)
+    .bind(user_id)
+    .execute(&mut **tx)
+    .await?;
+    sqlx::query(
+        "UPDATE session_leases SET revoked_at = NOW() \
+         WHERE tenant_id = $1 AND user_id = $2 AND revoked_at IS NULL",
+    )
+    .bind(tenant_id)
+    .bind(user_id)
+    .execute(&mut **tx)
+    .await?;
+    Ok(tokens.rows_affected())
+}
+
+/// The subset of `user_ids` that are live users of the tenant.
+pub async fn existing_users(
+    pool: &PgPool,
+    tenant_id: Uuid,
+    user_ids: &[Uuid],
+) -> Result<Vec<Uuid>, sqlx::Error> {
+    sqlx::query_scalar(
+        "SELECT c.user_id FROM credentials c \
+         LEFT JOIN scim_users s ON s.tenant_id = c.tenant_id AND s.user_id = c.user_id \
+         WHERE c.tenant_id = $1 AND c.user_id = ANY($2) AND s.deleted_at IS NULL",
+    )
+    .bind(tenant_id)
+    .bind(user_ids)
+    .fetch_all(pool)
+    .await
+}
+
+// ── Groups ──────────────────────────────────────────────────────────────────
+
+#[derive(Debug, Clone)]
+pub struct ScimGroupRow {
+    pub id: Uuid,
+    pub tenant_id: Uuid,
+    pub display_name: String,
+    pub external_id: Option<String>,
+    pub role_id: Uuid,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+const GROUP_COLUMNS: &str =
+    "id, tenant_id, display_name, external_id, role_id, created_at, updated_at";
+
+/// Filterable Group attributes (normalized names) and their SQL.
+pub fn group_column(attr: &str) -> Option<Column> {
+    let (expr, kind) = match attr {
+        "id" => ("id::text", ColumnKind::ExactText),
+        "displayname" => ("display_name", ColumnKind::Text),
+        "externalid" => ("external_id", ColumnKind::ExactText),
+        _ => return None,
+    };
+    Some(Column { expr, kind })
+}
+
+fn group_from_row(row: &sqlx::postgres::PgRow) -> ScimGroupRow {
+    ScimGroupRow {
+        id: row.get("id"),
+        tenant_id: row.get("tenant_id"),
+        display_name: row.get("display_name"),
+        external_id: row.get("external_id"),
+        role_id: row.get("role_id"),
+        created_at: row.get("created_at"),
+        updated_at: row.get("updated_at"),
+    }
+}
+
+/// One page of groups plus the total match count. Filter binds must be
+/// numbered from `$2`.
+pub async fn list_groups(
+    pool: &PgPool,
+    tenant_id: Uuid,
+    filter: Option<&SqlFilter>,
+    offset: i64,
+    limit: i64,
+) -> Result<(i64, Vec<ScimGroupRow>), sqlx::Error> {
+    let cond = where_filter(filter);
+    let n = 2 + filter.map_or(0, |f| f.binds.len());
+
+    let count_sql = format!("SELECT COUNT(*) FROM scim_groups WHERE tenant_id = $1{cond}");
+    let total: i64 = bind_filter(sqlx::query(&count_sql).bind(tenant_id), filter)
+        .fetch_one(pool)
+        .await?
+        .get(0);
+
+    let page_sql = format!(
+        "SELECT {GROUP_COLUMNS} FROM scim_groups WHERE tenant_id = $1{cond} \
+         ORDER BY created_at, id LIMIT ${n} OFFSET ${}",
+        n + 1
+    );
+    let rows = bind_filter(sqlx::query(&page_sql).bind(tenant_id), filter)
+        .bind(limit)
+        .bind(offset)
+        .fetch_all(pool)
+        .await?;
+    Ok((total, rows.iter().map(group_from_row).collect()))
+}
+
+pub async fn get_group(
+    pool: &PgPool,
+    tenant_id: Uuid,
+    group_id: Uuid,
+) -> Result<Option<ScimGroupRow>, sqlx::Error> {
+    let row = sqlx::query(&format!(
+        "SELECT {GROUP_COLUMNS} FROM scim_groups WHERE tenant_id = $1 AND id = $2"
+    ))
+    .bind(tenant_id)
+    .bind(group_id)
+    .fetch_optional(pool)
+    .await?;
+    Ok(row.as_ref().map(group_from_row))
+}
+
+pub async fn insert_group(
+    tx: &mut Transaction<'_, Postgres>,
+    tenant_id: Uuid,
+    display_name: &str,
+    external_id: Option<&str>,
+    role_id: Uuid,
+) -> Result<ScimGroupRow, sqlx::Error> {
+    let row = sqlx::query(&format!(
+        "INSERT INTO scim_groups (tenant_id, display_name, external_id, role_id) \
+         VALUES ($1, $2, $3, $4) RETURNING {GROUP_COLUMNS}"
+    ))
+    .bind(tenant_id)
+    .bind(display_name)
+    .bind(external_id)
+    .bind(role_id)
+    .fetch_one(&mut **tx)
+    .await?;
+    Ok(group_from_row(&row))
+}
+
+pub async fn update_group(
+    tx: &mut Transaction<'_, Postgres>,
+    group_id: Uuid,
+    display_name: &str,
+    external_id: Option<&str>,
+) -> Result<(), sqlx::Error> {
+    sqlx::query(
+        "UPDATE scim_groups SET display_name = $2, external_id = $3, updated_at = NOW() \
+         WHERE id = $1",
+    )
+    .bind(group_id)
+    .bind(display_name)
+    .bind(external_id)
+    .execute(&mut **tx)
+    .await?;
+    Ok(())
+}
+
+pub async fn delete_group(pool: &PgPool, group_id: Uuid) -> Result<(), sqlx::Error> {
+    sqlx::query("DELETE FROM scim_groups WHERE id = $1")
+        .bind(group_id)
+        .execute(pool)
+        .await?;
+    Ok(())
+}
+
+pub async fn list_members(pool: &PgPool, group_id: Uuid) -> Result<Vec<Uuid>, sqlx::Error> {
+    sqlx::query_scalar(
+        "SELECT user_id FROM scim_group_members WHERE group_id = $1 ORDER BY user_id",
+    )
+    .bind(group_id)
+    .fetch_all(pool)
+    .await
+}
+
+/// Replace the member set; returns `(added, removed)`.
+pub async fn set_members(
+    tx: &mut Transaction<'_, Postgres>,
+    group_id: Uuid,
+    members: &[Uuid],
+) -> Result<(Vec<Uuid>, Vec<Uuid>), sqlx::Error> {
+    let removed: Vec<Uuid> = sqlx::query_scalar(
+        "DELETE FROM scim_group_members WHERE group_id = $1 AND NOT (user_id = ANY($2)) \
+         RETURNING user_id",
+    )
+    .bind(group_id)
+    .bind(members)
+    .fetch_all(&mut **tx)
+    .await?;
+    let added: Vec<Uuid> = sqlx::query_scalar(
+        "INSERT INTO scim_group_members (group_id, user_id) \
+         SELECT $1, u FROM UNNEST($2::uuid[]) AS u \
+         ON CONFLICT DO NOTHING RETURNING user_id",
+    )
+    .bind(group_id)
+    .bind(members)
+    .fetch_all(&mut **tx)
+    .await?;
+    Ok((added, removed))
+}
+
+/// Groups a user belongs to, by display name.
+pub async fn groups_for_user(
+    pool: &PgPool,
+    tenant_id: Uuid,
+    user_id: Uuid,
+) -> Result<Vec<ScimGroupRow>, sqlx::Error> {
+    let rows = sqlx::query(
+        "SELECT g.id, g.tenant_id, g.display_name, g.external_id, g.role_id, \
+                g.created_at, g.updated_at \
+         FROM scim_groups g JOIN scim_group_members m ON m.group_id = g.id \
+         WHERE g.tenant_id = $1 AND m.user_id = $2 ORDER BY g.display_name",
+    )
+    .bind(tenant_id)
+    .bind(user_id)
+    .fetch_all(pool)
+    .await?;
+    Ok(rows.iter().map(group_from_row).collect())
+}
+
+/// Drop the user from every group; returns the roles those groups grant.
+pub async fn remove_user_from_groups(
+    tx: &mut Transaction<'_, Postgres>,
+    tenant_id: Uuid,
+    user_id: Uuid,
+) -> Result<Vec<Uuid>, sqlx::Error> {
+    sqlx::query_scalar(
+        "DELETE FROM scim_group_members m USING scim_groups g \
+         WHERE m.group_id = g.id AND g.tenant_id = $1 AND m.user_id = $2 \
+         RETURNING g.role_id",
+    )
+    .bind(tenant_id)
+    .bind(user_id)
+    .fetch_all(&mut **tx)
+    .await
+}
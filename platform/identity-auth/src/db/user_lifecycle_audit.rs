@@ -11,6 +11,11 @@ pub enum LifecycleEventType {
     RoleAssigned,
     RoleRevoked,
     AccessReviewRecorded,
+    MfaEnrolled,
+    MfaReset,
+    SsoIdentityLinked,
+    UserDeactivated,
+    UserReactivated,
 }
 
 impl LifecycleEventType {
@@ -20,6 +25,11 @@ impl LifecycleEventType {
             Self::RoleAssigned => "role_assigned",
             Self::RoleRevoked => "role_revoked",
             Self::AccessReviewRecorded => "access_review_recorded",
+            Self::MfaEnrolled => "mfa_enrolled",
+            Self::MfaReset => "mfa_reset",
+            Self::SsoIdentityLinked => "sso_identity_linked",
+            Self::UserDeactivated => "user_deactivated",
+            Self::UserReactivated => "user_reactivated",
         }
     }
 
@@ -29,6 +39,11 @@ impl LifecycleEventType {
             Self::RoleAssigned => "auth.user.lifecycle.role_assigned/v1",
             Self::RoleRevoked => "auth.user.lifecycle.role_revoked/v1",
             Self::AccessReviewRecorded => "auth.user.lifecycle.access_review_recorded/v1",
+            Self::MfaEnrolled => "auth.user.lifecycle.mfa_enrolled/v1",
+            Self::MfaReset => "auth.user.lifecycle.mfa_reset/v1",
+            Self::SsoIdentityLinked => "auth.user.lifecycle.sso_identity_linked/v1",
+            Self::UserDeactivated => "auth.user.lifecycle.user_deactivated/v1",
+            Self::UserReactivated => "auth.user.lifecycle.user_reactivated/v1",
         }
     }
 
@@ -38,6 +53,11 @@ impl LifecycleEventType {
             Self::RoleAssigned => "auth.user.lifecycle.role_assigned",
             Self::RoleRevoked => "auth.user.lifecycle.role_revoked",
             Self::AccessReviewRecorded => "auth.user.lifecycle.access_review_recorded",
+            Self::MfaEnrolled => "auth.user.lifecycle.mfa_enrolled",
+            Self::MfaReset => "auth.user.lifecycle.mfa_reset",
+            Self::SsoIdentityLinked => "auth.user.lifecycle.sso_identity_linked",
+            Self::UserDeactivated => "auth.user.lifecycle.user_deactivated",
+            Self::UserReactivated => "auth.user.lifecycle.user_reactivated",
         }
     }
 }
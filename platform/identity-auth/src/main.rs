@@ -97,6 +97,48 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         );
     }
 
+    // MFA: TOTP needs a sealing key, WebAuthn a relying party ID
+    let mfa_secret_key = cfg
+        .mfa_secret_key
+        .as_deref()
+        .map(crate::auth::sealed::SecretKey::from_hex)
+        .transpose()
+        .map_err(|e| format!("MFA_SECRET_KEY: {e}"))?;
+    if mfa_secret_key.is_none() {
+        tracing::warn!("MFA_SECRET_KEY not set; TOTP enrollment and verification disabled");
+    }
+    let mfa = crate::auth::handlers_mfa::MfaSettings {
+        secret_key: mfa_secret_key,
+        totp_issuer: cfg.mfa_totp_issuer.clone(),
+        challenge_ttl_seconds: cfg.mfa_challenge_ttl_seconds,
+        webauthn: cfg
+            .webauthn_rp_id
+            .clone()
+            .map(|id| crate::auth::webauthn::RelyingParty {
+                origins: if cfg.webauthn_origins.is_empty() {
+                    vec![format!("https://{id}")]
+                } else {
+                    cfg.webauthn_origins.clone()
+                },
+                id,
+                name: cfg.webauthn_rp_name.clone(),
+            }),
+    };
+
+    // SSO: confidential IdP clients need a sealing key for their secrets
+    let sso = crate::auth::handlers_sso::SsoSettings {
+        secret_key: cfg
+            .sso_secret_key
+            .as_deref()
+            .map(crate::auth::sealed::SecretKey::from_hex)
+            .transpose()
+            .map_err(|e| format!("SSO_SECRET_KEY: {e}"))?,
+        state_ttl_seconds: cfg.sso_state_ttl_seconds,
+        client: crate::auth::oidc::OidcClient::new(std::time::Duration::from_secs(
+            cfg.sso_discovery_ttl_secs,
+        )),
+    };
+
     let auth_state = Arc::new(crate::auth::handlers::AuthState {
         db: pool.clone(),
         jwt: jwt.clone(),
@@ -122,6 +164,8 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         password_reset_ttl_minutes: cfg.password_reset_ttl_minutes,
         max_concurrent_sessions: cfg.max_concurrent_sessions,
         tenant_registry,
+        mfa,
+        sso,
     });
 
     // Health + Metrics states
@@ -16,6 +16,13 @@ pub struct Metrics {
     pub auth_rate_limited_total: IntCounterVec,
     pub auth_nats_publish_fail_total: IntCounterVec,
     pub auth_refresh_replay_total: IntCounterVec,
+    /// MFA challenge answers: labels `result` ∈ {success, failure}, `method`.
+    pub auth_mfa_verify_total: IntCounterVec,
+    /// SSO callbacks: labels `result` ∈ {success, failure, mfa_required}, `reason`.
+    pub auth_sso_login_total: IntCounterVec,
+    /// SCIM requests: labels `resource` ∈ {User, Group, token, discovery},
+    /// `operation`, `result` ∈ {success, failure}.
+    pub auth_scim_requests_total: IntCounterVec,
 
     // Histograms
     pub http_request_duration_seconds: HistogramVec,
@@ -63,6 +70,30 @@ impl Metrics {
         )
         .expect("metric");
 
+        let auth_mfa_verify_total = IntCounterVec::new(
+            Opts::new("auth_mfa_verify_total", "Total MFA challenge verifications"),
+            &["result", "method"], // method: totp|webauthn|recovery_code
+        )
+        .expect("metric");
+
+        let auth_sso_login_total = IntCounterVec::new(
+            Opts::new(
+                "auth_sso_login_total",
+                "Total OIDC single sign-on callbacks",
+            ),
+            &["result", "reason"], // invalid_state|token_exchange|invalid_id_token|not_provisioned|...
+        )
+        .expect("metric");
+
+        let auth_scim_requests_total = IntCounterVec::new(
+            Opts::new(
+                "auth_scim_requests_total",
+                "Total SCIM 2.0 provisioning requests",
+            ),
+            &["resource", "operation", "result"], // operation: list|get|create|replace|patch|delete
+        )
+        .expect("metric");
+
         let auth_logout_total = IntCounterVec::new(
             Opts::new("auth_logout_total", "Total logout attempts"),
             &["result", "reason"],
@@ -174,6 +205,15 @@ impl Metrics {
         registry
             .register(Box::new(auth_logout_total.clone()))
             .unwrap();
+        registry
+            .register(Box::new(auth_mfa_verify_total.clone()))
+            .unwrap();
+        registry
+            .register(Box::new(auth_sso_login_total.clone()))
+            .unwrap();
+        registry
+            .register(Box::new(auth_scim_requests_total.clone()))
+            .unwrap();
         registry
             .register(Box::new(auth_rate_limited_total.clone()))
             .unwrap();
@@ -218,6 +258,9 @@ impl Metrics {
             auth_rate_limited_total,
             auth_nats_publish_fail_total,
             auth_refresh_replay_total,
+            auth_mfa_verify_total,
+            auth_sso_login_total,
+            auth_scim_requests_total,
             http_request_duration_seconds,
             auth_password_verify_duration_seconds,
             dep_up,
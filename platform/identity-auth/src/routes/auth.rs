@@ -1,11 +1,14 @@
 use axum::{
-    routing::{delete, get, post},
+    routing::{delete, get, post, put},
     Router,
 };
 use std::sync::Arc;
 
 use crate::auth::handlers;
+use crate::auth::handlers_mfa;
 use crate::auth::handlers_password_reset;
+use crate::auth::handlers_scim;
+use crate::auth::handlers_sso;
 use crate::auth::session;
 
 pub fn router(state: Arc<handlers::AuthState>) -> Router {
@@ -49,5 +52,85 @@ pub fn router(state: Arc<handlers::AuthState>) -> Router {
         .route("/api/auth/roles", get(handlers::list_roles))
         .route("/api/auth/permissions", get(handlers::list_permissions))
         .route("/api/auth/admin/users", get(handlers::list_users_admin))
+        .route("/api/auth/admin/role-bindings", post(handlers::bind_role))
+        .route("/api/auth/mfa/status", get(handlers_mfa::mfa_status))
+        .route("/api/auth/mfa/verify", post(handlers_mfa::verify))
+        .route("/api/auth/mfa/step-up", post(handlers_mfa::step_up))
+        .route("/api/auth/mfa/totp/enroll", post(handlers_mfa::totp_enroll))
+        .route(
+            "/api/auth/mfa/totp/confirm",
+            post(handlers_mfa::totp_confirm),
+        )
+        .route(
+            "/api/auth/mfa/webauthn/register/options",
+            post(handlers_mfa::webauthn_register_options),
+        )
+        .route(
+            "/api/auth/mfa/webauthn/register",
+            post(handlers_mfa::webauthn_register),
+        )
+        .route(
+            "/api/auth/mfa/recovery-codes",
+            post(handlers_mfa::regenerate_recovery_codes),
+        )
+        .route("/api/auth/mfa/reset", post(handlers_mfa::reset_mfa))
+        .route("/api/auth/mfa/policy", put(handlers_mfa::put_policy))
+        .route(
+            "/api/auth/mfa/policy/{tenant_id}",
+            get(handlers_mfa::get_policy),
+        )
+        .route("/api/auth/sso/providers", put(handlers_sso::put_provider))
+        .route(
+            "/api/auth/sso/providers/{tenant_id}",
+            get(handlers_sso::get_provider).delete(handlers_sso::delete_provider),
+        )
+        .route(
+            "/api/auth/sso/providers/{tenant_id}/group-mappings",
+            put(handlers_sso::put_group_mappings),
+        )
+        .route(
+            "/api/auth/sso/{tenant_id}/authorize",
+            get(handlers_sso::authorize),
+        )
+        .route("/api/auth/sso/callback", get(handlers_sso::callback))
+        .route("/api/auth/scim/tokens", post(handlers_scim::create_token))
+        .route(
+            "/api/auth/scim/tokens/{tenant_id}",
+            get(handlers_scim::list_tokens),
+        )
+        .route(
+            "/api/auth/scim/tokens/{tenant_id}/{token_id}",
+            delete(handlers_scim::revoke_token),
+        )
+        .route(
+            "/api/auth/scim/v2/ServiceProviderConfig",
+            get(handlers_scim::service_provider_config),
+        )
+        .route(
+            "/api/auth/scim/v2/ResourceTypes",
+            get(handlers_scim::resource_types),
+        )
+        .route(
+            "/api/auth/scim/v2/Users",
+            get(handlers_scim::list_users).post(handlers_scim::create_user),
+        )
+        .route(
+            "/api/auth/scim/v2/Users/{id}",
+            get(handlers_scim::get_user)
+                .put(handlers_scim::replace_user)
+                .patch(handlers_scim::patch_user)
+                .delete(handlers_scim::delete_user),
+        )
+        .route(
+            "/api/auth/scim/v2/Groups",
+            get(handlers_scim::list_groups).post(handlers_scim::create_group),
+        )
+        .route(
+            "/api/auth/scim/v2/Groups/{id}",
+            get(handlers_scim::get_group)
+                .put(handlers_scim::replace_group)
+                .patch(handlers_scim::patch_group)
+                .delete(handlers_scim::delete_group),
+        )
         .with_state(state)
 }
@@ -32,6 +32,7 @@ use utoipa::OpenApi;
         // RBAC
         crate::auth::handlers::list_roles,
         crate::auth::handlers::list_permissions,
+        crate::auth::handlers::bind_role,
         // Lifecycle
         crate::auth::handlers::record_access_review,
         crate::auth::handlers::get_user_lifecycle_timeline,
@@ -40,6 +41,43 @@ use utoipa::OpenApi;
         crate::auth::handlers::evaluate_sod,
         crate::auth::handlers::list_sod_policies,
         crate::auth::handlers::delete_sod_policy,
+        // MFA
+        crate::auth::handlers_mfa::totp_enroll,
+        crate::auth::handlers_mfa::totp_confirm,
+        crate::auth::handlers_mfa::webauthn_register_options,
+        crate::auth::handlers_mfa::webauthn_register,
+        crate::auth::handlers_mfa::verify,
+        crate::auth::handlers_mfa::step_up,
+        crate::auth::handlers_mfa::regenerate_recovery_codes,
+        crate::auth::handlers_mfa::mfa_status,
+        crate::auth::handlers_mfa::reset_mfa,
+        crate::auth::handlers_mfa::get_policy,
+        crate::auth::handlers_mfa::put_policy,
+        // SSO
+        crate::auth::handlers_sso::put_provider,
+        crate::auth::handlers_sso::get_provider,
+        crate::auth::handlers_sso::delete_provider,
+        crate::auth::handlers_sso::put_group_mappings,
+        crate::auth::handlers_sso::authorize,
+        crate::auth::handlers_sso::callback,
+        // SCIM
+        crate::auth::handlers_scim::create_token,
+        crate::auth::handlers_scim::list_tokens,
+        crate::auth::handlers_scim::revoke_token,
+        crate::auth::handlers_scim::service_provider_config,
+        crate::auth::handlers_scim::resource_types,
+        crate::auth::handlers_scim::list_users,
+        crate::auth::handlers_scim::create_user,
+        crate::auth::handlers_scim::get_user,
+        crate::auth::handlers_scim::replace_user,
+        crate::auth::handlers_scim::patch_user,
+        crate::auth::handlers_scim::delete_user,
+        crate::auth::handlers_scim::list_groups,
+        crate::auth::handlers_scim::create_group,
+        crate::auth::handlers_scim::get_group,
+        crate::auth::handlers_scim::replace_group,
+        crate::auth::handlers_scim::patch_group,
+        crate::auth::handlers_scim::delete_group,
     ),
     components(schemas(
         crate::auth::handlers::RegisterReq,
@@ -51,6 +89,8 @@ use utoipa::OpenApi;
         crate::auth::handlers::SodEvaluateReq,
         crate::auth::handlers::UserLookupResponse,
         crate::auth::handlers::PlatformUserDetail,
+        crate::auth::handlers::BindRoleReq,
+        crate::auth::handlers::RoleBindingResponse,
         crate::auth::session::RefreshReq,
         crate::auth::session::LogoutReq,
         crate::auth::handlers_password_reset::ForgotPasswordRequest,
@@ -62,6 +102,30 @@ use utoipa::OpenApi;
         crate::db::sod::SodPolicyUpsertResult,
         crate::db::sod::SodDecisionResult,
         crate::db::user_lifecycle_audit::LifecycleTimelineEntry,
+        crate::auth::session::AccessTokenResponse,
+        crate::auth::handlers_mfa::MfaSubject,
+        crate::auth::handlers_mfa::MfaChallengeResponse,
+        crate::auth::handlers_mfa::TotpEnrollResponse,
+        crate::auth::handlers_mfa::TotpConfirmReq,
+        crate::auth::handlers_mfa::RecoveryCodesResponse,
+        crate::auth::handlers_mfa::MfaResetReq,
+        crate::auth::handlers_mfa::MfaPolicyReq,
+        crate::auth::handlers_mfa::WebAuthnRegistrationOptions,
+        crate::auth::handlers_mfa::WebAuthnRegisterReq,
+        crate::auth::handlers_mfa::WebAuthnRegisterResponse,
+        crate::auth::handlers_mfa::WebAuthnAssertion,
+        crate::auth::handlers_mfa::MfaVerifyReq,
+        crate::auth::handlers_mfa::StepUpReq,
+        crate::db::mfa::MfaStatus,
+        crate::db::mfa::MfaPolicy,
+        crate::db::mfa::WebAuthnCredentialInfo,
+        crate::auth::handlers_sso::SsoProviderReq,
+        crate::auth::handlers_sso::GroupMappingsReq,
+        crate::db::oidc::OidcProviderView,
+        crate::db::oidc::GroupRoleMapping,
+        crate::auth::handlers_scim::CreateScimTokenReq,
+        crate::auth::handlers_scim::CreatedScimToken,
+        crate::db::scim::ScimToken,
     )),
     security(("bearer" = [])),
     modifiers(&SecurityAddon),
@@ -82,5 +146,14 @@ impl utoipa::Modify for SecurityAddon {
                     .build(),
             ),
         );
+        components.add_security_scheme(
+            "scim_token",
+            utoipa::openapi::security::SecurityScheme::Http(
+                utoipa::openapi::security::HttpBuilder::new()
+                    .scheme(utoipa::openapi::security::HttpAuthScheme::Bearer)
+                    .description(Some("Per-tenant SCIM provisioning token"))
+                    .build(),
+            ),
+        );
     }
 }
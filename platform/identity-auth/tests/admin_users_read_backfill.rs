@@ -98,6 +98,8 @@ async fn build_state(pool: PgPool) -> (Arc<AuthState>, JwtKeys) {
         password_reset_ttl_minutes: 30,
         max_concurrent_sessions: 5,
         tenant_registry: None::<TenantRegistryClient>,
+        mfa: Default::default(),
+        sso: Default::default(),
     });
 
     (state, jwt)
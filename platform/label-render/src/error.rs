@@ -0,0 +1,24 @@
+use thiserror::Error;
+
+/// Errors produced while resolving a template or encoding a symbol.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum RenderError {
+    /// The layout itself is malformed (bounds, missing barcode data, …).
+    #[error("Invalid label template: {0}")]
+    Template(String),
+
+    /// A `{{field}}` placeholder refers to a key absent from the payload.
+    #[error("Label payload is missing field '{0}'")]
+    MissingField(String),
+
+    /// The data cannot be encoded in the requested symbology.
+    #[error("Invalid barcode data: {0}")]
+    InvalidData(String),
+
+    /// A GS1 application identifier or its value is not valid.
+    #[error("Invalid GS1 element: {0}")]
+    Gs1(String),
+
+    #[error("PDF rendering failed: {0}")]
+    Pdf(String),
+}
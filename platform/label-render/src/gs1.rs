@@ -0,0 +1,361 @@
+//! GS1 application identifiers, element strings, check digits and SSCC.
+//!
+//! An element string is a sequence of (AI, value) pairs. Predefined-length
+//! AIs (GTIN, SSCC, dates, net weight) run straight into the next AI; a
+//! variable-length value (lot, serial, count) is terminated by FNC1 unless
+//! it is the last element. Symbol encoders receive the element string as
+//! [`Gs1Part`]s so each can emit FNC1 its own way.
+
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::error::RenderError;
+
+/// Definition of a supported application identifier.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AiSpec {
+    pub ai: &'static str,
+    pub title: &'static str,
+    /// Digits only (otherwise GS1 character set 82)
+    pub numeric: bool,
+    /// Predefined length: value must be exactly `max_len` long and needs no
+    /// FNC1 separator
+    pub fixed: bool,
+    pub max_len: usize,
+    /// Last digit is a GS1 mod-10 check digit
+    pub check_digit: bool,
+    /// YYMMDD; an ISO `YYYY-MM-DD` value is accepted and converted
+    pub date: bool,
+}
+
+const fn fixed_num(ai: &'static str, title: &'static str, len: usize, check: bool) -> AiSpec {
+    AiSpec {
+        ai,
+        title,
+        numeric: true,
+        fixed: true,
+        max_len: len,
+        check_digit: check,
+        date: false,
+    }
+}
+
+const fn date(ai: &'static str, title: &'static str) -> AiSpec {
+    AiSpec {
+        ai,
+        title,
+        numeric: true,
+        fixed: true,
+        max_len: 6,
+        check_digit: false,
+        date: true,
+    }
+}
+
+const fn var(ai: &'static str, title: &'static str, numeric: bool, max_len: usize) -> AiSpec {
+    AiSpec {
+        ai,
+        title,
+        numeric,
+        fixed: false,
+        max_len,
+        check_digit: false,
+        date: false,
+    }
+}
+
+/// Application identifiers used on item, lot and shipping labels.
+pub const SUPPORTED_AIS: &[AiSpec] = &[
+    fixed_num("00", "SSCC", 18, true),
+    fixed_num("01", "GTIN", 14, true),
+    fixed_num("02", "CONTENT", 14, true),
+    var("10", "BATCH/LOT", false, 20),
+    date("11", "PROD DATE"),
+    date("13", "PACK DATE"),
+    date("15", "BEST BEFORE"),
+    date("17", "USE BY"),
+    var("21", "SERIAL", false, 20),
+    var("30", "VAR. COUNT", true, 8),
+    fixed_num("3100", "NET WEIGHT (kg)", 6, false),
+    fixed_num("3101", "NET WEIGHT (kg)", 6, false),
+    fixed_num("3102", "NET WEIGHT (kg)", 6, false),
+    fixed_num("3103", "NET WEIGHT (kg)", 6, false),
+    var("37", "COUNT", true, 8),
+    var("400", "ORDER NUMBER", false, 30),
+    var("420", "SHIP TO POST", false, 20),
+];
+
+/// Look up a supported AI.
+pub fn ai_spec(ai: &str) -> Option<&'static AiSpec> {
+    SUPPORTED_AIS.iter().find(|s| s.ai == ai)
+}
+
+/// One (AI, value) pair of an element string.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub struct Gs1Element {
+    /// Application identifier, e.g. "01", "10", "17"
+    pub ai: String,
+    /// Value; in templates may contain `{{field}}` placeholders
+    pub value: String,
+}
+
+/// An element string piece handed to symbol encoders.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Gs1Part {
+    Fnc1,
+    Data(String),
+}
+
+/// A validated GS1 element string.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Gs1ElementString {
+    elements: Vec<(&'static AiSpec, String)>,
+}
+
+impl Gs1ElementString {
+    /// Validate the elements; dates given as `YYYY-MM-DD` become `YYMMDD`.
+    pub fn new(elements: &[Gs1Element]) -> Result<Self, RenderError> {
+        if elements.is_empty() {
+            return Err(RenderError::Gs1(
+                "element string needs at least one AI".to_string(),
+            ));
+        }
+        let mut out = Vec::with_capacity(elements.len());
+        for e in elements {
+            let spec = ai_spec(&e.ai)
+                .ok_or_else(|| RenderError::Gs1(format!("unsupported AI ({})", e.ai)))?;
+            out.push((spec, normalize_value(spec, e.value.trim())?));
+        }
+        Ok(Self { elements: out })
+    }
+
+    /// Human readable interpretation: `(01)09521234543213(10)LOT7`.
+    pub fn hri(&self) -> String {
+        self.elements
+            .iter()
+            .map(|(spec, v)| format!("({}){}", spec.ai, v))
+            .collect()
+    }
+
+    /// Leading FNC1, then AI + value pieces with FNC1 after every
+    /// variable-length value that is not last.
+    pub fn parts(&self) -> Vec<Gs1Part> {
+        let mut parts = vec![Gs1Part::Fnc1];
+        let last = self.elements.len() - 1;
+        for (i, (spec, v)) in self.elements.iter().enumerate() {
+            parts.push(Gs1Part::Data(format!("{}{}", spec.ai, v)));
+            if !spec.fixed && i < last {
+                parts.push(Gs1Part::Fnc1);
+            }
+        }
+        parts
+    }
+
+    /// (AI, normalized value) pairs.
+    pub fn elements(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.elements.iter().map(|(s, v)| (s.ai, v.as_str()))
+    }
+}
+
+fn normalize_value(spec: &AiSpec, value: &str) -> Result<String, RenderError> {
+    let value = if spec.date && value.len() == 10 && value.as_bytes()[4] == b'-' {
+        iso_to_yymmdd(value)
+            .ok_or_else(|| RenderError::Gs1(format!("({}) invalid date '{}'", spec.ai, value)))?
+    } else {
+        value.to_string()
+    };
+
+    if value.is_empty() {
+        return Err(RenderError::Gs1(format!("({}) value is empty", spec.ai)));
+    }
+    if spec.fixed && value.len() != spec.max_len {
+        return Err(RenderError::Gs1(format!(
+            "({}) must be exactly {} characters, got {}",
+            spec.ai,
+            spec.max_len,
+            value.len()
+        )));
+    }
+    if value.len() > spec.max_len {
+        return Err(RenderError::Gs1(format!(
+            "({}) must be at most {} characters",
+            spec.ai, spec.max_len
+        )));
+    }
+    if spec.numeric {
+        if !value.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(RenderError::Gs1(format!("({}) must be numeric", spec.ai)));
+        }
+    } else if let Some(c) = value.chars().find(|c| !is_cset82(*c)) {
+        return Err(RenderError::Gs1(format!(
+            "({}) character '{}' is not in the GS1 character set",
+            spec.ai, c
+        )));
+    }
+    if spec.check_digit && !has_valid_check_digit(&value) {
+        return Err(RenderError::Gs1(format!(
+            "({}) check digit is wrong",
+            spec.ai
+        )));
+    }
+    if spec.date {
+        let b = value.as_bytes();
+        let month = (b[2] - b'0') * 10 + (b[3] - b'0');
+        let day = (b[4] - b'0') * 10 + (b[5] - b'0');
+        // Day 00 means "end of month" in GS1 dates
+        if !(1..=12).contains(&month) || day > 31 {
+            return Err(RenderError::Gs1(format!(
+                "({}) invalid date '{}'",
+                spec.ai, value
+            )));
+        }
+    }
+    Ok(value)
+}
+
+fn iso_to_yymmdd(iso: &str) -> Option<String> {
+    let b = iso.as_bytes();
+    if b.len() != 10 || b[4] != b'-' || b[7] != b'-' {
+        return None;
+    }
+    let digits = [&iso[2..4], &iso[5..7], &iso[8..10]].concat();
+    digits.bytes().all(|c| c.is_ascii_digit()).then_some(digits)
+}
+
+/// GS1 character set 82 (invariant ISO 646 subset).
+fn is_cset82(c: char) -> bool {
+    c.is_ascii_alphanumeric() || "!\"%&'()*+,-./:;<=>?_".contains(c)
+}
+
+/// GS1 mod-10 check digit over `digits` (weights 3,1,3,… from the right).
+pub fn check_digit(digits: &str) -> u8 {
+    let sum: u32 = digits
+        .bytes()
+        .rev()
+        .enumerate()
+        .map(|(i, b)| {
+            let d = (b - b'0') as u32;
+            if i % 2 == 0 {
+                d * 3
+            } else {
+                d
+            }
+        })
+        .sum();
+    ((10 - sum % 10) % 10) as u8
+}
+
+/// True when the last digit of `value` is the check digit of the rest.
+pub fn has_valid_check_digit(value: &str) -> bool {
+    if value.len() < 2 || !value.bytes().all(|b| b.is_ascii_digit()) {
+        return false;
+    }
+    let (body, check) = value.split_at(value.len() - 1);
+    check_digit(body) == check.as_bytes()[0] - b'0'
+}
+
+/// Build an 18-digit SSCC: extension digit, GS1 company prefix (7–10
+/// digits), serial reference zero-padded to fill 16 digits, check digit.
+pub fn sscc(
+    extension_digit: u8,
+    company_prefix: &str,
+    serial_reference: u64,
+) -> Result<String, RenderError> {
+    if extension_digit > 9 {
+        return Err(RenderError::Gs1(
+            "SSCC extension digit must be 0-9".to_string(),
+        ));
+    }
+    if !(7..=10).contains(&company_prefix.len())
+        || !company_prefix.bytes().all(|b| b.is_ascii_digit())
+    {
+        return Err(RenderError::Gs1(
+            "GS1 company prefix must be 7-10 digits".to_string(),
+        ));
+    }
+    let width = 16 - company_prefix.len();
+    if serial_reference >= sscc_serial_capacity(company_prefix.len()) {
+        return Err(RenderError::Gs1(format!(
+            "SSCC serial reference {} does not fit in {} digits",
+            serial_reference, width
+        )));
+    }
+    let body = format!(
+        "{}{}{:0width$}",
+        extension_digit,
+        company_prefix,
+        serial_reference,
+        width = width
+    );
+    let check = check_digit(&body);
+    Ok(format!("{}{}", body, check))
+}
+
+/// Number of serial references available under a company prefix length.
+pub fn sscc_serial_capacity(company_prefix_len: usize) -> u64 {
+    10u64.pow(16u32.saturating_sub(company_prefix_len as u32))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn el(ai: &str, value: &str) -> Gs1Element {
+        Gs1Element {
+            ai: ai.to_string(),
+            value: value.to_string(),
+        }
+    }
+
+    #[test]
+    fn check_digits_match_gs1_examples() {
+        // GS1 General Specifications worked example
+        assert_eq!(check_digit("0952123454321"), 3);
+        assert!(has_valid_check_digit("09521234543213"));
+        assert!(!has_valid_check_digit("09521234543214"));
+        assert_eq!(check_digit("37610425002123456"), 9);
+    }
+
+    #[test]
+    fn sscc_pads_serial_and_appends_check_digit() {
+        assert_eq!(sscc(3, "7610425", 2123456).unwrap(), "376104250021234569");
+        assert!(sscc(0, "123456789", 10_000_000).is_err());
+        assert!(sscc(0, "12345", 1).is_err());
+        assert_eq!(sscc_serial_capacity(9), 10_000_000);
+    }
+
+    #[test]
+    fn element_string_places_fnc1_after_variable_fields_only() {
+        let es = Gs1ElementString::new(&[
+            el("01", "09521234543213"),
+            el("10", "LOT7"),
+            el("17", "2027-03-31"),
+            el("21", "S1"),
+        ])
+        .unwrap();
+        assert_eq!(es.hri(), "(01)09521234543213(10)LOT7(17)270331(21)S1");
+        assert_eq!(
+            es.parts(),
+            vec![
+                Gs1Part::Fnc1,
+                Gs1Part::Data("0109521234543213".to_string()),
+                Gs1Part::Data("10LOT7".to_string()),
+                Gs1Part::Fnc1,
+                Gs1Part::Data("17270331".to_string()),
+                Gs1Part::Data("21S1".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn invalid_elements_rejected() {
+        assert!(Gs1ElementString::new(&[]).is_err());
+        assert!(Gs1ElementString::new(&[el("99", "X")]).is_err());
+        assert!(Gs1ElementString::new(&[el("01", "0952123454321")]).is_err());
+        assert!(Gs1ElementString::new(&[el("01", "09521234543214")]).is_err());
+        assert!(Gs1ElementString::new(&[el("10", "LOT#1")]).is_err());
+        assert!(Gs1ElementString::new(&[el("10", &"X".repeat(21))]).is_err());
+        assert!(Gs1ElementString::new(&[el("17", "271331")]).is_err());
+        assert!(Gs1ElementString::new(&[el("37", "12A")]).is_err());
+    }
+}
@@ -0,0 +1,55 @@
+//! # Label Render
+//!
+//! Printable label templates rendered to ZPL II (thermal printers) or PDF
+//! (office printers, previews).
+//!
+//! A [`LabelLayout`] places text, barcode and box elements on a canvas
+//! measured in printer dots. Element values carry `{{field}}` placeholders
+//! filled from a JSON payload, so one template serves every label of a kind
+//! and the owning module only supplies data.
+//!
+//! ## Symbologies
+//!
+//! Code 128, Code 39, EAN-13, QR, Data Matrix (ECC 200), GS1-128 and GS1
+//! Data Matrix. GS1 symbols take a list of application identifiers
+//! ([`Gs1Element`]) that are validated — fixed lengths, character set,
+//! check digits, `YYMMDD` dates — and joined with FNC1 as GS1 requires.
+//! [`gs1::sscc`] builds an SSCC-18 from a company prefix and serial.
+//!
+//! [`render`] picks the output by [`OutputFormat`]; [`render_zpl`] and
+//! [`render_pdf`] are the format-specific entry points.
+//!
+//! ```rust
+//! use label_render::{render_zpl, LabelLayout};
+//! use serde_json::json;
+//!
+//! let layout: LabelLayout = serde_json::from_value(json!({
+//!     "width_dots": 812,
+//!     "height_dots": 406,
+//!     "elements": [
+//!         {"type": "text", "x": 20, "y": 20, "value": "{{sku}}"},
+//!         {"type": "barcode", "x": 20, "y": 80, "symbology": "gs1_128",
+//!          "gs1": [{"ai": "01", "value": "{{gtin}}"}, {"ai": "10", "value": "{{lot}}"}]}
+//!     ]
+//! }))
+//! .unwrap();
+//! let zpl = render_zpl(&layout, &json!({"sku": "SKU-1", "gtin": "09521234543213", "lot": "L7"}))
+//!     .unwrap();
+//! assert!(zpl.contains("(01)09521234543213(10)L7"));
+//! ```
+
+pub mod error;
+pub mod gs1;
+pub mod output;
+pub mod pdf;
+pub mod symbology;
+pub mod template;
+pub mod zpl;
+
+pub use error::RenderError;
+pub use gs1::{Gs1Element, Gs1ElementString};
+pub use output::{render, OutputFormat};
+pub use pdf::render_pdf;
+pub use symbology::Symbology;
+pub use template::{BarcodeElement, BoxElement, LabelElement, LabelLayout, TextElement};
+pub use zpl::render_zpl;
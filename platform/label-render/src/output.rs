@@ -0,0 +1,55 @@
+//! Output format selection.
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use utoipa::ToSchema;
+
+use crate::error::RenderError;
+use crate::template::LabelLayout;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum OutputFormat {
+    /// ZPL II for thermal printers
+    #[default]
+    Zpl,
+    /// Single-page PDF for laser printers and previews
+    Pdf,
+}
+
+impl OutputFormat {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            OutputFormat::Zpl => "zpl",
+            OutputFormat::Pdf => "pdf",
+        }
+    }
+
+    pub fn parse(s: &str) -> Option<Self> {
+        match s {
+            "zpl" => Some(OutputFormat::Zpl),
+            "pdf" => Some(OutputFormat::Pdf),
+            _ => None,
+        }
+    }
+
+    pub fn content_type(&self) -> &'static str {
+        match self {
+            OutputFormat::Zpl => "application/zpl",
+            OutputFormat::Pdf => "application/pdf",
+        }
+    }
+}
+
+/// Render `layout` filled from `payload` in `format`; `title` names the PDF.
+pub fn render(
+    layout: &LabelLayout,
+    payload: &Value,
+    format: OutputFormat,
+    title: &str,
+) -> Result<Vec<u8>, RenderError> {
+    match format {
+        OutputFormat::Zpl => crate::zpl::render_zpl(layout, payload).map(String::into_bytes),
+        OutputFormat::Pdf => crate::pdf::render_pdf(layout, payload, title),
+    }
+}
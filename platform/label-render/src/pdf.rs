@@ -0,0 +1,172 @@
+//! PDF output for office printers and previews.
+//!
+//! One page sized to the label. Layout dots convert to millimetres at the
+//! layout's dpi and the y axis flips from ZPL's top-left origin to PDF's
+//! bottom-left. Symbols are drawn as filled rectangles from the local
+//! encoders, so what prints matches what the ZPL printer would produce.
+
+use printpdf::{BuiltinFont, IndirectFontRef, Mm, PdfDocument, PdfLayerReference, Rect};
+use serde_json::Value;
+
+use crate::error::RenderError;
+use crate::symbology::Encoded;
+use crate::template::{resolve, LabelLayout, Resolved};
+
+/// Render `layout` filled from `payload` as a single-page PDF.
+pub fn render_pdf(
+    layout: &LabelLayout,
+    payload: &Value,
+    title: &str,
+) -> Result<Vec<u8>, RenderError> {
+    let elements = resolve(layout, payload)?;
+    let page = Page::new(layout);
+    let (doc, page1, layer1) = PdfDocument::new(
+        title,
+        Mm(page.mm(layout.width_dots as f32)),
+        Mm(page.mm(layout.height_dots as f32)),
+        "Label",
+    );
+    let layer = doc.get_page(page1).get_layer(layer1);
+    let font = doc
+        .add_builtin_font(BuiltinFont::Helvetica)
+        .map_err(|e| RenderError::Pdf(e.to_string()))?;
+
+    for el in &elements {
+        match el {
+            Resolved::Text(t, text) => {
+                page.text(
+                    &layer,
+                    &font,
+                    text,
+                    t.x as f32,
+                    t.y as f32,
+                    t.font_height as f32,
+                );
+            }
+            Resolved::Barcode(b, data, encoded) => {
+                let (x, y, m) = (b.x as f32, b.y as f32, b.module_width as f32);
+                match encoded {
+                    Encoded::Linear(modules) => {
+                        let h = b.height as f32;
+                        let mut i = 0;
+                        while i < modules.len() {
+                            let run = modules[i..]
+                                .iter()
+                                .take_while(|&&d| d == modules[i])
+                                .count();
+                            if modules[i] {
+                                page.fill(&layer, x + i as f32 * m, y, run as f32 * m, h);
+                            }
+                            i += run;
+                        }
+                        if b.human_readable {
+                            let hri_height = (m * 10.0).max(18.0);
+                            page.text(
+                                &layer,
+                                &font,
+                                &data.human_readable(),
+                                x,
+                                y + h + hri_height * 0.2,
+                                hri_height,
+                            );
+                        }
+                    }
+                    Encoded::Matrix(matrix) => {
+                        for row in 0..matrix.height() {
+                            for col in 0..matrix.width() {
+                                if matrix.get(col, row) {
+                                    page.fill(&layer, x + col as f32 * m, y + row as f32 * m, m, m);
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+            Resolved::Box(bx) => {
+                let (x, y, w, h) = (bx.x as f32, bx.y as f32, bx.width as f32, bx.height as f32);
+                let t = (bx.thickness as f32).min(w.min(h));
+                page.fill(&layer, x, y, w, t);
+                page.fill(&layer, x, y + h - t, w, t);
+                page.fill(&layer, x, y, t, h);
+                page.fill(&layer, x + w - t, y, t, h);
+            }
+        }
+    }
+
+    doc.save_to_bytes()
+        .map_err(|e| RenderError::Pdf(e.to_string()))
+}
+
+/// Dot → page coordinate conversion.
+struct Page {
+    dpi: f32,
+    height_dots: f32,
+}
+
+impl Page {
+    fn new(layout: &LabelLayout) -> Self {
+        Self {
+            dpi: layout.dpi as f32,
+            height_dots: layout.height_dots as f32,
+        }
+    }
+
+    fn mm(&self, dots: f32) -> f32 {
+        dots * 25.4 / self.dpi
+    }
+
+    /// Fill a rectangle given by its top-left corner in dots.
+    fn fill(&self, layer: &PdfLayerReference, x: f32, y: f32, w: f32, h: f32) {
+        let bottom = self.height_dots - (y + h);
+        layer.add_rect(Rect::new(
+            Mm(self.mm(x)),
+            Mm(self.mm(bottom)),
+            Mm(self.mm(x + w)),
+            Mm(self.mm(bottom + h)),
+        ));
+    }
+
+    /// Text whose cell top is at `y` dots, `height` dots tall.
+    fn text(
+        &self,
+        layer: &PdfLayerReference,
+        font: &IndirectFontRef,
+        text: &str,
+        x: f32,
+        y: f32,
+        height: f32,
+    ) {
+        let baseline = self.height_dots - (y + height * 0.8);
+        layer.use_text(
+            text,
+            height * 72.0 / self.dpi,
+            Mm(self.mm(x)),
+            Mm(self.mm(baseline)),
+            font,
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn renders_a_pdf_page() {
+        let layout: LabelLayout = serde_json::from_value(json!({
+            "dpi": 300, "width_dots": 1200, "height_dots": 600,
+            "elements": [
+                {"type": "text", "x": 30, "y": 30, "value": "{{sku}}"},
+                {"type": "box", "x": 0, "y": 0, "width": 1200, "height": 600},
+                {"type": "barcode", "x": 30, "y": 120, "symbology": "gs1_128",
+                 "gs1": [{"ai": "01", "value": "09521234543213"}, {"ai": "10", "value": "{{lot}}"}]},
+                {"type": "barcode", "x": 900, "y": 120, "symbology": "qr", "value": "{{sku}}", "module_width": 6}
+            ]
+        }))
+        .unwrap();
+        let pdf = render_pdf(&layout, &json!({"sku": "SKU-1", "lot": "L7"}), "Label").unwrap();
+        assert!(pdf.starts_with(b"%PDF-"));
+        assert!(render_pdf(&layout, &json!({"sku": "SKU-1"}), "Label").is_err());
+    }
+}
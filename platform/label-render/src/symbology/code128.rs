@@ -0,0 +1,223 @@
+//! Code 128 (subsets B and C) with FNC1, used for Code128 and GS1-128.
+//!
+//! Digit runs of four or more are packed two per symbol in subset C;
+//! everything else is printable ASCII in subset B.
+
+use crate::error::RenderError;
+
+/// Module patterns for symbol values 0–105, eleven modules each, MSB first
+/// (1 = bar).
+const PATTERNS: [u16; 106] = [
+    0x6CC, 0x66C, 0x666, 0x498, 0x48C, 0x44C, 0x4C8, 0x4C4, 0x464, 0x648, // 0-9
+    0x644, 0x624, 0x59C, 0x4DC, 0x4CE, 0x5CC, 0x4EC, 0x4E6, 0x672, 0x65C, // 10-19
+    0x64E, 0x6E4, 0x674, 0x76E, 0x74C, 0x72C, 0x726, 0x764, 0x734, 0x732, // 20-29
+    0x6D8, 0x6C6, 0x636, 0x518, 0x458, 0x446, 0x588, 0x468, 0x462, 0x688, // 30-39
+    0x628, 0x622, 0x5B8, 0x58E, 0x46E, 0x5D8, 0x5C6, 0x476, 0x776, 0x68E, // 40-49
+    0x62E, 0x6E8, 0x6E2, 0x6EE, 0x758, 0x746, 0x716, 0x768, 0x762, 0x71A, // 50-59
+    0x77A, 0x642, 0x78A, 0x530, 0x50C, 0x4B0, 0x486, 0x42C, 0x426, 0x590, // 60-69
+    0x584, 0x4D0, 0x4C2, 0x434, 0x432, 0x612, 0x650, 0x7BA, 0x614, 0x47A, // 70-79
+    0x53C, 0x4BC, 0x49E, 0x5E4, 0x4F4, 0x4F2, 0x7A4, 0x794, 0x792, 0x6DE, // 80-89
+    0x6F6, 0x7B6, 0x578, 0x51E, 0x45E, 0x5E8, 0x5E2, 0x7A8, 0x7A2, 0x5DE, // 90-99
+    0x5EE, 0x75E, 0x7AE, 0x684, 0x690, 0x69C, // 100-105
+];
+
+/// Stop pattern, thirteen modules including the final bar.
+const STOP: u16 = 0x18EB;
+
+const CODE_C: u8 = 99;
+const CODE_B: u8 = 100;
+const FNC1: u8 = 102;
+const START_B: u8 = 104;
+const START_C: u8 = 105;
+
+/// One input position: a data byte or the FNC1 function character.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Code128Char {
+    Fnc1,
+    Byte(u8),
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Subset {
+    B,
+    C,
+}
+
+/// Encode printable ASCII text.
+pub fn encode_str(data: &str) -> Result<Vec<bool>, RenderError> {
+    let chars: Vec<Code128Char> = data.bytes().map(Code128Char::Byte).collect();
+    encode(&chars)
+}
+
+/// Encode to modules (true = bar), start through stop, no quiet zone.
+pub fn encode(input: &[Code128Char]) -> Result<Vec<bool>, RenderError> {
+    Ok(to_modules(&symbol_values(input)?))
+}
+
+/// Symbol values including start, check symbol and excluding stop.
+pub(crate) fn symbol_values(input: &[Code128Char]) -> Result<Vec<u8>, RenderError> {
+    if !input.iter().any(|c| matches!(c, Code128Char::Byte(_))) {
+        return Err(RenderError::InvalidData(
+            "Code 128 data is empty".to_string(),
+        ));
+    }
+    let digit_run = |from: usize| {
+        input[from..]
+            .iter()
+            .take_while(|c| matches!(c, Code128Char::Byte(b) if b.is_ascii_digit()))
+            .count()
+    };
+    let first_data = input
+        .iter()
+        .position(|c| matches!(c, Code128Char::Byte(_)))
+        .unwrap_or(0);
+    let lead_run = digit_run(first_data);
+    let mut subset = if lead_run >= 4 || (lead_run >= 2 && lead_run % 2 == 0) {
+        Subset::C
+    } else {
+        Subset::B
+    };
+
+    let mut values = vec![if subset == Subset::C {
+        START_C
+    } else {
+        START_B
+    }];
+    let mut i = 0;
+    while i < input.len() {
+        match input[i] {
+            Code128Char::Fnc1 => {
+                values.push(FNC1);
+                i += 1;
+            }
+            Code128Char::Byte(b) => match subset {
+                Subset::C => {
+                    if digit_run(i) >= 2 {
+                        let Code128Char::Byte(next) = input[i + 1] else {
+                            unreachable!("digit run covers the next position")
+                        };
+                        values.push((b - b'0') * 10 + (next - b'0'));
+                        i += 2;
+                    } else {
+                        values.push(CODE_B);
+                        subset = Subset::B;
+                    }
+                }
+                Subset::B => {
+                    let run = digit_run(i);
+                    if run >= 4 && run % 2 == 0 {
+                        values.push(CODE_C);
+                        subset = Subset::C;
+                        continue;
+                    }
+                    if !(32..=126).contains(&b) {
+                        return Err(RenderError::InvalidData(format!(
+                            "byte 0x{:02X} is not printable ASCII",
+                            b
+                        )));
+                    }
+                    values.push(b - 32);
+                    i += 1;
+                }
+            },
+        }
+    }
+
+    let checksum = values
+        .iter()
+        .enumerate()
+        .map(|(pos, &v)| v as u32 * (pos.max(1) as u32))
+        .sum::<u32>()
+        % 103;
+    values.push(checksum as u8);
+    Ok(values)
+}
+
+fn to_modules(values: &[u8]) -> Vec<bool> {
+    let mut modules = Vec::with_capacity(values.len() * 11 + 13);
+    for &v in values {
+        let p = PATTERNS[v as usize];
+        modules.extend((0..11).rev().map(|bit| p >> bit & 1 == 1));
+    }
+    modules.extend((0..13).rev().map(|bit| STOP >> bit & 1 == 1));
+    modules
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn runs(pattern: u16, width: u32) -> Vec<u32> {
+        let mut runs = vec![];
+        let mut prev = None;
+        for bit in (0..width).rev() {
+            let on = pattern >> bit & 1 == 1;
+            if prev == Some(on) {
+                *runs.last_mut().unwrap() += 1;
+            } else {
+                runs.push(1);
+            }
+            prev = Some(on);
+        }
+        runs
+    }
+
+    #[test]
+    fn pattern_table_is_well_formed() {
+        let mut seen = std::collections::HashSet::new();
+        for (v, &p) in PATTERNS.iter().enumerate() {
+            assert!(seen.insert(p), "duplicate pattern for {}", v);
+            let r = runs(p, 11);
+            // Bar, space, bar, space, bar, space; 1–4 modules each
+            assert_eq!(r.len(), 6, "value {}", v);
+            assert!(p >> 10 & 1 == 1 && p & 1 == 0, "value {}", v);
+            assert!(r.iter().all(|w| (1..=4).contains(w)), "value {}", v);
+            // Bars always total an even number of modules
+            assert_eq!((r[0] + r[2] + r[4]) % 2, 0, "value {}", v);
+        }
+        assert_eq!(runs(STOP, 13), vec![2, 3, 3, 1, 1, 1, 2]);
+    }
+
+    #[test]
+    fn text_uses_subset_b_with_mod_103_check() {
+        // "AB": start B (104), A=33, B=34 → (104 + 33 + 68) % 103 = 102
+        assert_eq!(
+            symbol_values(&[Code128Char::Byte(b'A'), Code128Char::Byte(b'B')]).unwrap(),
+            vec![104, 33, 34, 102]
+        );
+        let modules = encode_str("AB").unwrap();
+        assert_eq!(modules.len(), 4 * 11 + 13);
+    }
+
+    #[test]
+    fn digit_runs_switch_to_subset_c() {
+        let chars: Vec<Code128Char> = "A123456".bytes().map(Code128Char::Byte).collect();
+        // A in B; run of 6 → code C; 12 34 56
+        let v = symbol_values(&chars).unwrap();
+        assert_eq!(&v[..5], &[104, 33, 99, 12, 34]);
+        assert_eq!(v[5], 56);
+
+        // Odd run: one digit stays in B before switching
+        let chars: Vec<Code128Char> = "A12345".bytes().map(Code128Char::Byte).collect();
+        let v = symbol_values(&chars).unwrap();
+        assert_eq!(&v[..6], &[104, 33, 17, 99, 23, 45]);
+    }
+
+    #[test]
+    fn gs1_starts_in_c_with_fnc1() {
+        let mut chars = vec![Code128Char::Fnc1];
+        chars.extend("0109521234543213".bytes().map(Code128Char::Byte));
+        chars.extend("10A".bytes().map(Code128Char::Byte));
+        let v = symbol_values(&chars).unwrap();
+        assert_eq!(&v[..3], &[105, 102, 1]);
+        // 18 digits pack into nine C symbols, then code B for the letter
+        assert_eq!(v[v.len() - 3], 100);
+        assert_eq!(v[v.len() - 2], b'A' - 32);
+    }
+
+    #[test]
+    fn non_printable_rejected() {
+        assert!(encode_str("A\tB").is_err());
+        assert!(encode_str("").is_err());
+    }
+}
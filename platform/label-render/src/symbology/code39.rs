@@ -0,0 +1,81 @@
+//! Code 39 (uppercase letters, digits and `-. $/+%`), wide:narrow = 3:1.
+
+use crate::error::RenderError;
+
+const ALPHABET: &[u8] = b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZ-. $/+%";
+
+/// Nine elements per character (bar, space, …, bar), MSB first, 1 = wide.
+const ENCODINGS: [u16; 43] = [
+    0x034, 0x121, 0x061, 0x160, 0x031, 0x130, 0x070, 0x025, 0x124, 0x064, // 0-9
+    0x109, 0x049, 0x148, 0x019, 0x118, 0x058, 0x00D, 0x10C, 0x04C, 0x01C, // A-J
+    0x103, 0x043, 0x142, 0x013, 0x112, 0x052, 0x007, 0x106, 0x046, 0x016, // K-T
+    0x181, 0x0C1, 0x1C0, 0x091, 0x190, 0x0D0, // U-Z
+    0x085, 0x184, 0x0C4, 0x0A8, 0x0A2, 0x08A, 0x02A, // - . space $ / + %
+];
+
+/// Start/stop character `*`.
+const START_STOP: u16 = 0x094;
+
+const WIDE: usize = 3;
+
+/// Encode to modules (true = bar) with `*` start and stop, no check character.
+pub fn encode(data: &str) -> Result<Vec<bool>, RenderError> {
+    if data.is_empty() {
+        return Err(RenderError::InvalidData(
+            "Code 39 data is empty".to_string(),
+        ));
+    }
+    let mut modules = Vec::new();
+    push_char(&mut modules, START_STOP);
+    for c in data.bytes() {
+        let idx = ALPHABET.iter().position(|&a| a == c).ok_or_else(|| {
+            RenderError::InvalidData(format!(
+                "'{}' cannot be encoded in Code 39 (uppercase letters, digits, -. $/+%)",
+                c as char
+            ))
+        })?;
+        push_char(&mut modules, ENCODINGS[idx]);
+    }
+    push_char(&mut modules, START_STOP);
+    // No gap after the stop character
+    modules.pop();
+    Ok(modules)
+}
+
+fn push_char(modules: &mut Vec<bool>, pattern: u16) {
+    for element in 0..9 {
+        let wide = pattern >> (8 - element) & 1 == 1;
+        let bar = element % 2 == 0;
+        let width = if wide { WIDE } else { 1 };
+        modules.extend(std::iter::repeat_n(bar, width));
+    }
+    // Narrow inter-character gap
+    modules.push(false);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn every_character_has_three_wide_elements() {
+        for (i, p) in ENCODINGS.iter().chain([&START_STOP]).enumerate() {
+            assert_eq!(p.count_ones(), 3, "encoding {}", i);
+        }
+        let mut sorted = ENCODINGS.to_vec();
+        sorted.push(START_STOP);
+        sorted.sort_unstable();
+        sorted.dedup();
+        assert_eq!(sorted.len(), 44);
+    }
+
+    #[test]
+    fn encodes_with_start_stop() {
+        let m = encode("A1").unwrap();
+        // 4 characters × (6 narrow + 3 wide × 3 = 15 modules) + 3 gaps
+        assert_eq!(m.len(), 4 * 15 + 3);
+        assert!(m[0] && *m.last().unwrap());
+        assert!(encode("a").is_err());
+        assert!(encode("").is_err());
+    }
+}
@@ -0,0 +1,468 @@
+//! Data Matrix ECC 200, square symbols 10×10 to 48×48.
+//!
+//! ASCII encodation (digit pairs packed into one codeword, FNC1 = 232 for
+//! GS1), Reed-Solomon over GF(256) with polynomial 0x12D, and the standard
+//! codeword placement. Symbols up to 48×48 use a single Reed-Solomon block;
+//! from 32×32 the data area is split into 2×2 regions with their own finder
+//! patterns.
+
+use super::Matrix;
+use crate::error::RenderError;
+
+/// One input position: a byte or the FNC1 function character.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DmChar {
+    Fnc1,
+    Byte(u8),
+}
+
+struct SymbolSize {
+    size: usize,
+    /// Data region edge, without finder pattern
+    region: usize,
+    /// Regions per side
+    regions: usize,
+    data_codewords: usize,
+    ecc_codewords: usize,
+}
+
+const fn sz(
+    size: usize,
+    region: usize,
+    regions: usize,
+    data_codewords: usize,
+    ecc_codewords: usize,
+) -> SymbolSize {
+    SymbolSize {
+        size,
+        region,
+        regions,
+        data_codewords,
+        ecc_codewords,
+    }
+}
+
+const SIZES: [SymbolSize; 14] = [
+    sz(10, 8, 1, 3, 5),
+    sz(12, 10, 1, 5, 7),
+    sz(14, 12, 1, 8, 10),
+    sz(16, 14, 1, 12, 12),
+    sz(18, 16, 1, 18, 14),
+    sz(20, 18, 1, 22, 18),
+    sz(22, 20, 1, 30, 20),
+    sz(24, 22, 1, 36, 24),
+    sz(26, 24, 1, 44, 28),
+    sz(32, 14, 2, 62, 36),
+    sz(36, 16, 2, 86, 42),
+    sz(40, 18, 2, 114, 48),
+    sz(44, 20, 2, 144, 56),
+    sz(48, 22, 2, 174, 68),
+];
+
+const FNC1: u8 = 232;
+const UPPER_SHIFT: u8 = 235;
+const PAD: u8 = 129;
+
+/// Encode text bytes.
+pub fn encode_bytes(data: &[u8]) -> Result<Matrix, RenderError> {
+    let chars: Vec<DmChar> = data.iter().map(|&b| DmChar::Byte(b)).collect();
+    encode(&chars)
+}
+
+/// Encode to a module matrix (true = dark), no quiet zone.
+pub fn encode(input: &[DmChar]) -> Result<Matrix, RenderError> {
+    if input.is_empty() {
+        return Err(RenderError::InvalidData(
+            "Data Matrix data is empty".to_string(),
+        ));
+    }
+    let mut codewords = ascii_codewords(input);
+    let size = SIZES
+        .iter()
+        .find(|s| s.data_codewords >= codewords.len())
+        .ok_or_else(|| {
+            RenderError::InvalidData(format!(
+                "{} codewords exceed the largest supported Data Matrix (48×48, 174)",
+                codewords.len()
+            ))
+        })?;
+    pad(&mut codewords, size.data_codewords);
+    let ecc = reed_solomon(&codewords, size.ecc_codewords);
+    codewords.extend(ecc);
+    Ok(build_symbol(size, &codewords))
+}
+
+pub(crate) fn ascii_codewords(input: &[DmChar]) -> Vec<u8> {
+    let mut out = Vec::with_capacity(input.len());
+    let mut i = 0;
+    while i < input.len() {
+        match input[i] {
+            DmChar::Fnc1 => {
+                out.push(FNC1);
+                i += 1;
+            }
+            DmChar::Byte(a) => {
+                if let Some(DmChar::Byte(b)) = input.get(i + 1) {
+                    if a.is_ascii_digit() && b.is_ascii_digit() {
+                        out.push(130 + (a - b'0') * 10 + (b - b'0'));
+                        i += 2;
+                        continue;
+                    }
+                }
+                if a < 128 {
+                    out.push(a + 1);
+                } else {
+                    out.push(UPPER_SHIFT);
+                    out.push(a - 127);
+                }
+                i += 1;
+            }
+        }
+    }
+    out
+}
+
+fn pad(codewords: &mut Vec<u8>, capacity: usize) {
+    if codewords.len() < capacity {
+        codewords.push(PAD);
+    }
+    while codewords.len() < capacity {
+        // 253-state randomisation of the pad codeword at 1-based position p
+        let p = codewords.len() + 1;
+        let pseudo = (149 * p) % 253 + 1;
+        let v = PAD as usize + pseudo;
+        codewords.push(if v <= 254 { v as u8 } else { (v - 254) as u8 });
+    }
+}
+
+// ============================================================================
+// Reed-Solomon
+// ============================================================================
+
+struct Gf {
+    exp: [u8; 512],
+    log: [u8; 256],
+}
+
+impl Gf {
+    fn new() -> Self {
+        let mut exp = [0u8; 512];
+        let mut log = [0u8; 256];
+        let mut x: u16 = 1;
+        for (i, e) in exp.iter_mut().take(255).enumerate() {
+            *e = x as u8;
+            log[x as usize] = i as u8;
+            x <<= 1;
+            if x & 0x100 != 0 {
+                x ^= 0x12D;
+            }
+        }
+        for i in 255..512 {
+            exp[i] = exp[i - 255];
+        }
+        Self { exp, log }
+    }
+
+    fn mul(&self, a: u8, b: u8) -> u8 {
+        if a == 0 || b == 0 {
+            0
+        } else {
+            self.exp[self.log[a as usize] as usize + self.log[b as usize] as usize]
+        }
+    }
+}
+
+pub(crate) fn reed_solomon(data: &[u8], ecc_len: usize) -> Vec<u8> {
+    let gf = Gf::new();
+    // Generator ∏ (x − α^i), i = 1..=ecc_len, highest degree first
+    let mut generator = vec![1u8];
+    for i in 1..=ecc_len {
+        let root = gf.exp[i];
+        let mut next = vec![0u8; generator.len() + 1];
+        for (j, &c) in generator.iter().enumerate() {
+            next[j] ^= c;
+            next[j + 1] ^= gf.mul(c, root);
+        }
+        generator = next;
+    }
+
+    let mut ecc = vec![0u8; ecc_len];
+    for &d in data {
+        let m = d ^ ecc[0];
+        ecc.rotate_left(1);
+        ecc[ecc_len - 1] = 0;
+        for (e, &g) in ecc.iter_mut().zip(&generator[1..]) {
+            *e ^= gf.mul(m, g);
+        }
+    }
+    ecc
+}
+
+// ============================================================================
+// Placement
+// ============================================================================
+
+/// Codeword placement over the mapping matrix (data regions without
+/// finders). Each cell holds `10 × codeword + bit` (bit 1 = MSB), or
+/// `FIXED_ON` for the lower-right filler of sizes that leave it unused.
+struct Placement {
+    nrow: usize,
+    ncol: usize,
+    cells: Vec<u32>,
+}
+
+const FIXED_ON: u32 = 1;
+const FIXED_OFF: u32 = 2;
+
+impl Placement {
+    fn run(nrow: usize, ncol: usize) -> Self {
+        let mut p = Self {
+            nrow,
+            ncol,
+            cells: vec![0; nrow * ncol],
+        };
+        let (nr, nc) = (nrow as isize, ncol as isize);
+        let mut chr = 1u32;
+        let (mut row, mut col) = (4isize, 0isize);
+        loop {
+            if row == nr && col == 0 {
+                p.corner1(chr);
+                chr += 1;
+            }
+            if row == nr - 2 && col == 0 && !ncol.is_multiple_of(4) {
+                p.corner2(chr);
+                chr += 1;
+            }
+            if row == nr - 2 && col == 0 && ncol % 8 == 4 {
+                p.corner3(chr);
+                chr += 1;
+            }
+            if row == nr + 4 && col == 2 && ncol.is_multiple_of(8) {
+                p.corner4(chr);
+                chr += 1;
+            }
+            // Sweep up and to the right
+            loop {
+                if row < nr && col >= 0 && p.cells[row as usize * ncol + col as usize] == 0 {
+                    p.utah(row, col, chr);
+                    chr += 1;
+                }
+                row -= 2;
+                col += 2;
+                if !(row >= 0 && col < nc) {
+                    break;
+                }
+            }
+            row += 1;
+            col += 3;
+            // Sweep down and to the left
+            loop {
+                if row >= 0 && col < nc && p.cells[row as usize * ncol + col as usize] == 0 {
+                    p.utah(row, col, chr);
+                    chr += 1;
+                }
+                row += 2;
+                col -= 2;
+                if !(row < nr && col >= 0) {
+                    break;
+                }
+            }
+            row += 3;
+            col += 1;
+            if !(row < nr || col < nc) {
+                break;
+            }
+        }
+        let last = nrow * ncol - 1;
+        // Unfilled bottom-right 2×2 gets the fixed checker pattern
+        if p.cells[last] == 0 {
+            p.cells[last] = FIXED_ON;
+            p.cells[last - ncol - 1] = FIXED_ON;
+            p.cells[last - 1] = FIXED_OFF;
+            p.cells[last - ncol] = FIXED_OFF;
+        }
+        p
+    }
+
+    fn module(&mut self, mut row: isize, mut col: isize, chr: u32, bit: u32) {
+        let (nr, nc) = (self.nrow as isize, self.ncol as isize);
+        if row < 0 {
+            row += nr;
+            col += 4 - ((nr + 4) % 8);
+        }
+        if col < 0 {
+            col += nc;
+            row += 4 - ((nc + 4) % 8);
+        }
+        self.cells[row as usize * self.ncol + col as usize] = 10 * chr + bit;
+    }
+
+    fn utah(&mut self, row: isize, col: isize, chr: u32) {
+        self.module(row - 2, col - 2, chr, 1);
+        self.module(row - 2, col - 1, chr, 2);
+        self.module(row - 1, col - 2, chr, 3);
+        self.module(row - 1, col - 1, chr, 4);
+        self.module(row - 1, col, chr, 5);
+        self.module(row, col - 2, chr, 6);
+        self.module(row, col - 1, chr, 7);
+        self.module(row, col, chr, 8);
+    }
+
+    fn corner1(&mut self, chr: u32) {
+        let (nr, nc) = (self.nrow as isize, self.ncol as isize);
+        self.module(nr - 1, 0, chr, 1);
+        self.module(nr - 1, 1, chr, 2);
+        self.module(nr - 1, 2, chr, 3);
+        self.module(0, nc - 2, chr, 4);
+        self.module(0, nc - 1, chr, 5);
+        self.module(1, nc - 1, chr, 6);
+        self.module(2, nc - 1, chr, 7);
+        self.module(3, nc - 1, chr, 8);
+    }
+
+    fn corner2(&mut self, chr: u32) {
+        let (nr, nc) = (self.nrow as isize, self.ncol as isize);
+        self.module(nr - 3, 0, chr, 1);
+        self.module(nr - 2, 0, chr, 2);
+        self.module(nr - 1, 0, chr, 3);
+        self.module(0, nc - 4, chr, 4);
+        self.module(0, nc - 3, chr, 5);
+        self.module(0, nc - 2, chr, 6);
+        self.module(0, nc - 1, chr, 7);
+        self.module(1, nc - 1, chr, 8);
+    }
+
+    fn corner3(&mut self, chr: u32) {
+        let (nr, nc) = (self.nrow as isize, self.ncol as isize);
+        self.module(nr - 3, 0, chr, 1);
+        self.module(nr - 2, 0, chr, 2);
+        self.module(nr - 1, 0, chr, 3);
+        self.module(0, nc - 2, chr, 4);
+        self.module(0, nc - 1, chr, 5);
+        self.module(1, nc - 1, chr, 6);
+        self.module(2, nc - 1, chr, 7);
+        self.module(3, nc - 1, chr, 8);
+    }
+
+    fn corner4(&mut self, chr: u32) {
+        let (nr, nc) = (self.nrow as isize, self.ncol as isize);
+        self.module(nr - 1, 0, chr, 1);
+        self.module(nr - 1, nc - 1, chr, 2);
+        self.module(0, nc - 3, chr, 3);
+        self.module(0, nc - 2, chr, 4);
+        self.module(0, nc - 1, chr, 5);
+        self.module(1, nc - 3, chr, 6);
+        self.module(1, nc - 2, chr, 7);
+        self.module(1, nc - 1, chr, 8);
+    }
+
+    fn is_dark(&self, row: usize, col: usize, codewords: &[u8]) -> bool {
+        match self.cells[row * self.ncol + col] {
+            FIXED_ON => true,
+            0 | FIXED_OFF => false,
+            v => {
+                let (chr, bit) = ((v / 10) as usize, v % 10);
+                codewords[chr - 1] >> (8 - bit) & 1 == 1
+            }
+        }
+    }
+}
+
+fn build_symbol(size: &SymbolSize, codewords: &[u8]) -> Matrix {
+    let mapping = size.region * size.regions;
+    let placement = Placement::run(mapping, mapping);
+    let mut m = Matrix::new(size.size, size.size);
+    let block = size.region + 2;
+
+    for by in 0..size.regions {
+        for bx in 0..size.regions {
+            let (top, left) = (by * block, bx * block);
+            for i in 0..block {
+                // Solid left edge and bottom edge
+                m.set(left, top + i, true);
+                m.set(left + i, top + block - 1, true);
+                // Alternating top edge (dark first) and right edge (dark last)
+                m.set(left + i, top, i % 2 == 0);
+                m.set(left + block - 1, top + i, i % 2 == 1);
+            }
+        }
+    }
+
+    for row in 0..mapping {
+        for col in 0..mapping {
+            let y = row + 1 + 2 * (row / size.region);
+            let x = col + 1 + 2 * (col / size.region);
+            m.set(x, y, placement.is_dark(row, col, codewords));
+        }
+    }
+    m
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn iso_example_123456() {
+        // ISO/IEC 16022 worked example: "123456" in a 10×10 symbol
+        let cw = ascii_codewords(&"123456".bytes().map(DmChar::Byte).collect::<Vec<_>>());
+        assert_eq!(cw, vec![142, 164, 186]);
+        assert_eq!(reed_solomon(&cw, 5), vec![114, 25, 5, 88, 102]);
+        let m = encode_bytes(b"123456").unwrap();
+        assert_eq!(m.width(), 10);
+    }
+
+    #[test]
+    fn pads_with_randomised_codewords() {
+        let mut cw = vec![66]; // "A"
+        pad(&mut cw, 5);
+        // First pad is 129, then 129 + ((149 p) mod 253 + 1) wrapped at 254
+        assert_eq!(cw, vec![66, 129, 70, 220, 115]);
+    }
+
+    #[test]
+    fn placement_covers_every_module_exactly() {
+        for s in &SIZES {
+            let mapping = s.region * s.regions;
+            let p = Placement::run(mapping, mapping);
+            let total = s.data_codewords + s.ecc_codewords;
+            let mut counts = vec![0u32; total * 8];
+            for &v in &p.cells {
+                assert_ne!(v, 0, "unplaced module in {}×{}", s.size, s.size);
+                if v != FIXED_ON && v != FIXED_OFF {
+                    let (chr, bit) = ((v / 10) as usize, (v % 10) as usize);
+                    assert!(chr >= 1 && chr <= total, "{}×{}", s.size, s.size);
+                    counts[(chr - 1) * 8 + bit - 1] += 1;
+                }
+            }
+            assert!(
+                counts.iter().all(|&c| c == 1),
+                "every codeword bit placed once in {}×{}",
+                s.size,
+                s.size
+            );
+        }
+    }
+
+    #[test]
+    fn finder_pattern_frames_each_region() {
+        let m = encode(&[DmChar::Fnc1; 70]).unwrap();
+        assert_eq!(m.width(), 36);
+        for i in 0..36 {
+            assert!(m.get(0, i), "left edge");
+            assert!(m.get(i, 35), "bottom edge");
+            assert_eq!(m.get(i, 0), i % 18 % 2 == 0, "top edge");
+        }
+        // Inner region boundary: solid column at 18, alternating column at 17
+        assert!((0..36).all(|y| m.get(18, y)));
+        assert!(!m.get(17, 0) && m.get(17, 1));
+    }
+
+    #[test]
+    fn gs1_uses_fnc1_codeword_and_upper_shift() {
+        let cw = ascii_codewords(&[DmChar::Fnc1, DmChar::Byte(b'0'), DmChar::Byte(b'1')]);
+        assert_eq!(cw, vec![232, 131]);
+        assert_eq!(ascii_codewords(&[DmChar::Byte(200)]), vec![235, 73]);
+        assert!(encode(&[DmChar::Byte(b'x'); 200]).is_err());
+    }
+}
@@ -0,0 +1,86 @@
+//! EAN-13 (GTIN-13).
+
+use crate::error::RenderError;
+use crate::gs1::check_digit;
+
+/// Left-hand odd parity (L) patterns; R is the complement, G is R reversed.
+const L_CODES: [u8; 10] = [
+    0b0001101, 0b0011001, 0b0010011, 0b0111101, 0b0100011, 0b0110001, 0b0101111, 0b0111011,
+    0b0110111, 0b0001011,
+];
+
+/// Parity of the six left-hand digits by leading digit, 1 = G.
+const PARITY: [u8; 10] = [
+    0b000000, 0b001011, 0b001101, 0b001110, 0b010011, 0b011001, 0b011100, 0b010101, 0b010110,
+    0b011010,
+];
+
+/// Normalize 12 digits (check digit appended) or 13 digits (check verified).
+pub fn normalize(data: &str) -> Result<String, RenderError> {
+    if !data.bytes().all(|b| b.is_ascii_digit()) {
+        return Err(RenderError::InvalidData(
+            "EAN-13 data must be digits".to_string(),
+        ));
+    }
+    match data.len() {
+        12 => Ok(format!("{}{}", data, check_digit(data))),
+        13 if check_digit(&data[..12]) == data.as_bytes()[12] - b'0' => Ok(data.to_string()),
+        13 => Err(RenderError::InvalidData(
+            "EAN-13 check digit is wrong".to_string(),
+        )),
+        _ => Err(RenderError::InvalidData(
+            "EAN-13 data must be 12 or 13 digits".to_string(),
+        )),
+    }
+}
+
+/// Encode to the 95 modules between the quiet zones.
+pub fn encode(data: &str) -> Result<Vec<bool>, RenderError> {
+    let digits: Vec<u8> = normalize(data)?.bytes().map(|b| b - b'0').collect();
+    let mut modules = Vec::with_capacity(95);
+    let mut push = |bits: u8, width: u32| {
+        modules.extend((0..width).rev().map(|i| bits >> i & 1 == 1));
+    };
+
+    push(0b101, 3);
+    let parity = PARITY[digits[0] as usize];
+    for (i, &d) in digits[1..7].iter().enumerate() {
+        let l = L_CODES[d as usize];
+        let g = parity >> (5 - i) & 1 == 1;
+        push(if g { reverse7(!l & 0x7F) } else { l }, 7);
+    }
+    push(0b01010, 5);
+    for &d in &digits[7..13] {
+        push(!L_CODES[d as usize] & 0x7F, 7);
+    }
+    push(0b101, 3);
+    Ok(modules)
+}
+
+fn reverse7(bits: u8) -> u8 {
+    (0..7).fold(0, |acc, i| acc | ((bits >> i & 1) << (6 - i)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn appends_or_verifies_check_digit() {
+        assert_eq!(normalize("400638133393").unwrap(), "4006381333931");
+        assert!(normalize("4006381333932").is_err());
+        assert!(normalize("12345").is_err());
+    }
+
+    #[test]
+    fn encodes_95_modules_with_guards() {
+        let m = encode("4006381333931").unwrap();
+        assert_eq!(m.len(), 95);
+        assert_eq!(&m[..3], &[true, false, true]);
+        assert_eq!(&m[45..50], &[false, true, false, true, false]);
+        // Leading 4 → LGLLGG; first left digit 0 in L = 0001101
+        assert_eq!(&m[3..10], &[false, false, false, true, true, false, true]);
+        // Second left digit 0 in G = 0100111
+        assert_eq!(&m[10..17], &[false, true, false, false, true, true, true]);
+    }
+}
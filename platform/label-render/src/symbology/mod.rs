@@ -0,0 +1,237 @@
+//! Barcode symbologies and their module encoders.
+//!
+//! ZPL output leaves symbol generation to the printer, but every barcode is
+//! still encoded here so both outputs reject the same data.
+
+pub mod code128;
+pub mod code39;
+pub mod datamatrix;
+pub mod ean13;
+
+use qrcode::{EcLevel, QrCode};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::error::RenderError;
+use crate::gs1::{Gs1ElementString, Gs1Part};
+use code128::Code128Char;
+use datamatrix::DmChar;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum Symbology {
+    Code128,
+    Code39,
+    Ean13,
+    Qr,
+    Datamatrix,
+    /// Code 128 carrying a GS1 element string
+    #[serde(rename = "gs1_128")]
+    Gs1_128,
+    /// Data Matrix carrying a GS1 element string
+    Gs1Datamatrix,
+}
+
+impl Symbology {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Symbology::Code128 => "code128",
+            Symbology::Code39 => "code39",
+            Symbology::Ean13 => "ean13",
+            Symbology::Qr => "qr",
+            Symbology::Datamatrix => "datamatrix",
+            Symbology::Gs1_128 => "gs1_128",
+            Symbology::Gs1Datamatrix => "gs1_datamatrix",
+        }
+    }
+
+    pub fn parse(s: &str) -> Option<Self> {
+        [
+            Symbology::Code128,
+            Symbology::Code39,
+            Symbology::Ean13,
+            Symbology::Qr,
+            Symbology::Datamatrix,
+            Symbology::Gs1_128,
+            Symbology::Gs1Datamatrix,
+        ]
+        .into_iter()
+        .find(|sym| sym.as_str() == s)
+    }
+
+    /// Whether the symbol carries a GS1 element string.
+    pub fn is_gs1(&self) -> bool {
+        matches!(self, Symbology::Gs1_128 | Symbology::Gs1Datamatrix)
+    }
+
+    /// Whether the symbol is two-dimensional.
+    pub fn is_matrix(&self) -> bool {
+        matches!(
+            self,
+            Symbology::Qr | Symbology::Datamatrix | Symbology::Gs1Datamatrix
+        )
+    }
+}
+
+/// Square-module symbol, row-major, true = dark.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Matrix {
+    width: usize,
+    height: usize,
+    cells: Vec<bool>,
+}
+
+impl Matrix {
+    pub fn new(width: usize, height: usize) -> Self {
+        Self {
+            width,
+            height,
+            cells: vec![false; width * height],
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> bool {
+        self.cells[y * self.width + x]
+    }
+
+    pub fn set(&mut self, x: usize, y: usize, dark: bool) {
+        self.cells[y * self.width + x] = dark;
+    }
+}
+
+/// Barcode content: plain text or a GS1 element string.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BarcodeData {
+    Text(String),
+    Gs1(Gs1ElementString),
+}
+
+impl BarcodeData {
+    /// Text printed under linear symbols.
+    pub fn human_readable(&self) -> String {
+        match self {
+            BarcodeData::Text(t) => t.clone(),
+            BarcodeData::Gs1(es) => es.hri(),
+        }
+    }
+}
+
+/// An encoded symbol.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Encoded {
+    /// Bar modules left to right, true = bar
+    Linear(Vec<bool>),
+    Matrix(Matrix),
+}
+
+/// Encode `data` in `symbology`.
+pub fn encode(symbology: Symbology, data: &BarcodeData) -> Result<Encoded, RenderError> {
+    match (symbology, data) {
+        (Symbology::Code128, BarcodeData::Text(t)) => code128::encode_str(t).map(Encoded::Linear),
+        (Symbology::Code39, BarcodeData::Text(t)) => code39::encode(t).map(Encoded::Linear),
+        (Symbology::Ean13, BarcodeData::Text(t)) => ean13::encode(t).map(Encoded::Linear),
+        (Symbology::Qr, BarcodeData::Text(t)) => encode_qr(t).map(Encoded::Matrix),
+        (Symbology::Datamatrix, BarcodeData::Text(t)) => {
+            datamatrix::encode_bytes(t.as_bytes()).map(Encoded::Matrix)
+        }
+        (Symbology::Gs1_128, BarcodeData::Gs1(es)) => {
+            let chars: Vec<Code128Char> = flatten(es, Code128Char::Fnc1, Code128Char::Byte);
+            code128::encode(&chars).map(Encoded::Linear)
+        }
+        (Symbology::Gs1Datamatrix, BarcodeData::Gs1(es)) => {
+            let chars: Vec<DmChar> = flatten(es, DmChar::Fnc1, DmChar::Byte);
+            datamatrix::encode(&chars).map(Encoded::Matrix)
+        }
+        (sym, BarcodeData::Text(_)) => Err(RenderError::InvalidData(format!(
+            "{} needs a GS1 element string",
+            sym.as_str()
+        ))),
+        (sym, BarcodeData::Gs1(_)) => Err(RenderError::InvalidData(format!(
+            "{} does not carry GS1 element strings",
+            sym.as_str()
+        ))),
+    }
+}
+
+fn flatten<T>(es: &Gs1ElementString, fnc1: T, byte: fn(u8) -> T) -> Vec<T>
+where
+    T: Copy,
+{
+    let mut out = Vec::new();
+    for part in es.parts() {
+        match part {
+            Gs1Part::Fnc1 => out.push(fnc1),
+            Gs1Part::Data(d) => out.extend(d.bytes().map(byte)),
+        }
+    }
+    out
+}
+
+fn encode_qr(text: &str) -> Result<Matrix, RenderError> {
+    let code = QrCode::with_error_correction_level(text.as_bytes(), EcLevel::M)
+        .map_err(|e| RenderError::InvalidData(format!("QR: {}", e)))?;
+    let width = code.width();
+    let mut m = Matrix::new(width, width);
+    for (i, color) in code.to_colors().into_iter().enumerate() {
+        m.set(i % width, i / width, color == qrcode::Color::Dark);
+    }
+    Ok(m)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::gs1::Gs1Element;
+
+    #[test]
+    fn symbology_names_round_trip() {
+        for s in [
+            "code128",
+            "code39",
+            "ean13",
+            "qr",
+            "datamatrix",
+            "gs1_128",
+            "gs1_datamatrix",
+        ] {
+            let sym = Symbology::parse(s).unwrap();
+            assert_eq!(sym.as_str(), s);
+            assert_eq!(serde_json::to_value(sym).unwrap(), s);
+        }
+        assert!(Symbology::parse("pdf417").is_none());
+    }
+
+    #[test]
+    fn gs1_symbologies_require_element_strings() {
+        let text = BarcodeData::Text("ABC".to_string());
+        assert!(encode(Symbology::Gs1_128, &text).is_err());
+        let es = Gs1ElementString::new(&[Gs1Element {
+            ai: "10".to_string(),
+            value: "LOT1".to_string(),
+        }])
+        .unwrap();
+        assert!(encode(Symbology::Code128, &BarcodeData::Gs1(es.clone())).is_err());
+        assert!(matches!(
+            encode(Symbology::Gs1Datamatrix, &BarcodeData::Gs1(es)),
+            Ok(Encoded::Matrix(_))
+        ));
+    }
+
+    #[test]
+    fn qr_is_square_with_finder_corners() {
+        let Encoded::Matrix(m) = encode(Symbology::Qr, &BarcodeData::Text("LBL-1".into())).unwrap()
+        else {
+            panic!("QR is a matrix symbol")
+        };
+        assert_eq!(m.width(), 21);
+        assert!(m.get(0, 0) && m.get(20, 0) && m.get(0, 20));
+    }
+}
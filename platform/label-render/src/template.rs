@@ -0,0 +1,336 @@
+//! Label layouts and payload binding.
+//!
+//! A layout is a fixed-size canvas in printer dots (origin top-left, as in
+//! ZPL) holding text, barcode and box elements. Text and barcode values may
+//! contain `{{field}}` placeholders (dotted paths reach nested objects) that
+//! are filled from the label's JSON payload. A missing field fails the
+//! render unless the element is marked `optional`, in which case it is left
+//! off the label.
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use utoipa::ToSchema;
+
+use crate::error::RenderError;
+use crate::gs1::{Gs1Element, Gs1ElementString};
+use crate::symbology::{self, BarcodeData, Encoded, Symbology};
+
+pub const MIN_DPI: u32 = 100;
+pub const MAX_DPI: u32 = 600;
+/// Largest edge accepted, in dots (8 inches at 600 dpi)
+pub const MAX_DOTS: u32 = 4800;
+pub const MAX_ELEMENTS: usize = 100;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct LabelLayout {
+    /// Printer resolution; 203 and 300 are the common thermal heads
+    #[serde(default = "default_dpi")]
+    pub dpi: u32,
+    pub width_dots: u32,
+    pub height_dots: u32,
+    pub elements: Vec<LabelElement>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum LabelElement {
+    Text(TextElement),
+    Barcode(BarcodeElement),
+    Box(BoxElement),
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct TextElement {
+    pub x: u32,
+    pub y: u32,
+    /// Character height in dots
+    #[serde(default = "default_font_height")]
+    pub font_height: u32,
+    /// Text with `{{field}}` placeholders
+    pub value: String,
+    #[serde(default)]
+    pub optional: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct BarcodeElement {
+    pub x: u32,
+    pub y: u32,
+    pub symbology: Symbology,
+    /// Data for non-GS1 symbologies, with `{{field}}` placeholders
+    #[serde(default)]
+    pub value: Option<String>,
+    /// Element string for GS1 symbologies; values may use placeholders
+    #[serde(default)]
+    pub gs1: Vec<Gs1Element>,
+    /// Bar height in dots (linear symbols)
+    #[serde(default = "default_bar_height")]
+    pub height: u32,
+    /// Narrow bar / matrix module size in dots
+    #[serde(default = "default_module_width")]
+    pub module_width: u32,
+    /// Print the interpretation line under linear symbols
+    #[serde(default = "default_true")]
+    pub human_readable: bool,
+    #[serde(default)]
+    pub optional: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct BoxElement {
+    pub x: u32,
+    pub y: u32,
+    pub width: u32,
+    pub height: u32,
+    /// Border thickness in dots; equal to the smaller side fills the box
+    #[serde(default = "default_thickness")]
+    pub thickness: u32,
+}
+
+fn default_dpi() -> u32 {
+    203
+}
+fn default_font_height() -> u32 {
+    30
+}
+fn default_bar_height() -> u32 {
+    100
+}
+fn default_module_width() -> u32 {
+    2
+}
+fn default_thickness() -> u32 {
+    2
+}
+fn default_true() -> bool {
+    true
+}
+
+impl LabelLayout {
+    /// Structural checks that do not depend on the payload.
+    pub fn validate(&self) -> Result<(), RenderError> {
+        let err = |m: String| Err(RenderError::Template(m));
+        if !(MIN_DPI..=MAX_DPI).contains(&self.dpi) {
+            return err(format!("dpi must be between {} and {}", MIN_DPI, MAX_DPI));
+        }
+        if !(1..=MAX_DOTS).contains(&self.width_dots) || !(1..=MAX_DOTS).contains(&self.height_dots)
+        {
+            return err(format!("label size must be 1-{} dots per side", MAX_DOTS));
+        }
+        if self.elements.is_empty() || self.elements.len() > MAX_ELEMENTS {
+            return err(format!("a label needs 1-{} elements", MAX_ELEMENTS));
+        }
+        for (i, el) in self.elements.iter().enumerate() {
+            let (x, y) = el.origin();
+            if x >= self.width_dots || y >= self.height_dots {
+                return err(format!("element {} starts outside the label", i));
+            }
+            match el {
+                LabelElement::Text(t) => {
+                    if t.font_height == 0 || t.value.trim().is_empty() {
+                        return err(format!("text element {} needs a value and font_height", i));
+                    }
+                }
+                LabelElement::Barcode(b) => {
+                    if !(1..=10).contains(&b.module_width) || b.height == 0 {
+                        return err(format!(
+                            "barcode element {}: module_width must be 1-10 and height > 0",
+                            i
+                        ));
+                    }
+                    let has_value = b.value.as_deref().is_some_and(|v| !v.trim().is_empty());
+                    if b.symbology.is_gs1() && (b.gs1.is_empty() || has_value) {
+                        return err(format!(
+                            "barcode element {}: {} takes `gs1` elements, not `value`",
+                            i,
+                            b.symbology.as_str()
+                        ));
+                    }
+                    if !b.symbology.is_gs1() && (!has_value || !b.gs1.is_empty()) {
+                        return err(format!(
+                            "barcode element {}: {} takes `value`, not `gs1`",
+                            i,
+                            b.symbology.as_str()
+                        ));
+                    }
+                }
+                LabelElement::Box(bx) => {
+                    if bx.width == 0 || bx.height == 0 || bx.thickness == 0 {
+                        return err(format!("box element {} needs a size and thickness", i));
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+impl LabelElement {
+    fn origin(&self) -> (u32, u32) {
+        match self {
+            LabelElement::Text(t) => (t.x, t.y),
+            LabelElement::Barcode(b) => (b.x, b.y),
+            LabelElement::Box(b) => (b.x, b.y),
+        }
+    }
+}
+
+/// An element with its payload fields filled and its barcode encoded.
+pub(crate) enum Resolved<'a> {
+    Text(&'a TextElement, String),
+    Barcode(&'a BarcodeElement, BarcodeData, Encoded),
+    Box(&'a BoxElement),
+}
+
+/// Validate the layout, fill placeholders and encode every barcode.
+pub(crate) fn resolve<'a>(
+    layout: &'a LabelLayout,
+    payload: &Value,
+) -> Result<Vec<Resolved<'a>>, RenderError> {
+    layout.validate()?;
+    let mut out = Vec::with_capacity(layout.elements.len());
+    for el in &layout.elements {
+        let resolved = match el {
+            LabelElement::Text(t) => fill(&t.value, payload).map(|s| Resolved::Text(t, s)),
+            LabelElement::Barcode(b) => resolve_barcode(b, payload),
+            LabelElement::Box(b) => Ok(Resolved::Box(b)),
+        };
+        match resolved {
+            Ok(r) => out.push(r),
+            Err(RenderError::MissingField(_)) if is_optional(el) => {}
+            Err(e) => return Err(e),
+        }
+    }
+    Ok(out)
+}
+
+fn is_optional(el: &LabelElement) -> bool {
+    match el {
+        LabelElement::Text(t) => t.optional,
+        LabelElement::Barcode(b) => b.optional,
+        LabelElement::Box(_) => false,
+    }
+}
+
+fn resolve_barcode<'a>(
+    b: &'a BarcodeElement,
+    payload: &Value,
+) -> Result<Resolved<'a>, RenderError> {
+    let data = if b.symbology.is_gs1() {
+        let elements = b
+            .gs1
+            .iter()
+            .map(|e| {
+                Ok(Gs1Element {
+                    ai: e.ai.clone(),
+                    value: fill(&e.value, payload)?,
+                })
+            })
+            .collect::<Result<Vec<_>, RenderError>>()?;
+        BarcodeData::Gs1(Gs1ElementString::new(&elements)?)
+    } else {
+        BarcodeData::Text(fill(b.value.as_deref().unwrap_or_default(), payload)?)
+    };
+    let encoded = symbology::encode(b.symbology, &data)?;
+    Ok(Resolved::Barcode(b, data, encoded))
+}
+
+/// Replace `{{path}}` placeholders with payload values.
+pub fn fill(template: &str, payload: &Value) -> Result<String, RenderError> {
+    let mut out = String::with_capacity(template.len());
+    let mut rest = template;
+    while let Some(start) = rest.find("{{") {
+        out.push_str(&rest[..start]);
+        let after = &rest[start + 2..];
+        let end = after.find("}}").ok_or_else(|| {
+            RenderError::Template(format!("unclosed placeholder in '{}'", template))
+        })?;
+        let key = after[..end].trim();
+        out.push_str(
+            &lookup(payload, key).ok_or_else(|| RenderError::MissingField(key.to_string()))?,
+        );
+        rest = &after[end + 2..];
+    }
+    out.push_str(rest);
+    Ok(out)
+}
+
+fn lookup(payload: &Value, path: &str) -> Option<String> {
+    let mut v = payload;
+    for part in path.split('.') {
+        v = v.get(part)?;
+    }
+    match v {
+        Value::String(s) => Some(s.clone()),
+        Value::Number(n) => Some(n.to_string()),
+        Value::Bool(b) => Some(b.to_string()),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn layout(elements: Value) -> LabelLayout {
+        serde_json::from_value(json!({
+            "width_dots": 812,
+            "height_dots": 406,
+            "elements": elements,
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn fills_nested_and_scalar_placeholders() {
+        let p = json!({"sku": "SKU-1", "qty": 12, "lot": {"code": "L7"}});
+        assert_eq!(
+            fill("{{ sku }} x{{qty}} lot {{lot.code}}", &p).unwrap(),
+            "SKU-1 x12 lot L7"
+        );
+        assert_eq!(
+            fill("{{missing}}", &p),
+            Err(RenderError::MissingField("missing".to_string()))
+        );
+        assert!(matches!(fill("{{sku", &p), Err(RenderError::Template(_))));
+    }
+
+    #[test]
+    fn optional_elements_drop_out_when_field_missing() {
+        let l = layout(json!([
+            {"type": "text", "x": 10, "y": 10, "value": "{{sku}}"},
+            {"type": "text", "x": 10, "y": 50, "value": "{{description}}", "optional": true},
+        ]));
+        let resolved = resolve(&l, &json!({"sku": "A"})).unwrap();
+        assert_eq!(resolved.len(), 1);
+        assert!(matches!(
+            resolve(&l, &json!({})),
+            Err(RenderError::MissingField(_))
+        ));
+    }
+
+    #[test]
+    fn validation_rejects_bad_layouts() {
+        let out_of_bounds = layout(json!([{"type": "text", "x": 900, "y": 0, "value": "x"}]));
+        assert!(out_of_bounds.validate().is_err());
+
+        let gs1_with_value = layout(json!([{
+            "type": "barcode", "x": 0, "y": 0, "symbology": "gs1_128", "value": "123"
+        }]));
+        assert!(gs1_with_value.validate().is_err());
+
+        let code128_with_gs1 = layout(json!([{
+            "type": "barcode", "x": 0, "y": 0, "symbology": "code128",
+            "gs1": [{"ai": "10", "value": "L"}]
+        }]));
+        assert!(code128_with_gs1.validate().is_err());
+
+        let ok = layout(json!([{
+            "type": "barcode", "x": 0, "y": 0, "symbology": "gs1_datamatrix",
+            "gs1": [{"ai": "01", "value": "{{gtin}}"}, {"ai": "10", "value": "{{lot}}"}]
+        }]));
+        ok.validate().unwrap();
+        assert_eq!(ok.dpi, 203);
+    }
+}
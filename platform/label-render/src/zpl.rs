@@ -0,0 +1,208 @@
+//! ZPL II output for Zebra-compatible thermal printers.
+//!
+//! Symbols are generated by the printer (`^BC`, `^B3`, `^BE`, `^BQ`, `^BX`);
+//! field data goes through `^FH\` hex escaping so `^`, `~` and `\` in payload
+//! values cannot break out of the field. Text is UTF-8 (`^CI28`).
+//!
+//! GS1-128 uses `^BC` mode D with the parenthesised element string, so the
+//! printer inserts FNC1 and prints the interpretation line. GS1 Data Matrix
+//! uses `_1` for FNC1. Values containing `(`/`)` (GS1-128) or `_` (GS1 Data
+//! Matrix) are rejected because the printer would read them as syntax.
+
+use serde_json::Value;
+use std::fmt::Write;
+
+use crate::error::RenderError;
+use crate::gs1::Gs1Part;
+use crate::symbology::{BarcodeData, Symbology};
+use crate::template::{resolve, BarcodeElement, LabelLayout, Resolved};
+
+/// Render `layout` filled from `payload` as one ZPL label (`^XA` … `^XZ`).
+pub fn render_zpl(layout: &LabelLayout, payload: &Value) -> Result<String, RenderError> {
+    let elements = resolve(layout, payload)?;
+    let mut z = String::new();
+    z.push_str("^XA\n^CI28\n");
+    let _ = writeln!(z, "^PW{}\n^LL{}", layout.width_dots, layout.height_dots);
+
+    for el in &elements {
+        match el {
+            Resolved::Text(t, text) => {
+                let _ = writeln!(
+                    z,
+                    "^FO{},{}^A0N,{},{}^FH\\^FD{}^FS",
+                    t.x,
+                    t.y,
+                    t.font_height,
+                    t.font_height,
+                    escape(text)
+                );
+            }
+            Resolved::Barcode(b, data, _) => barcode(&mut z, b, data)?,
+            Resolved::Box(bx) => {
+                let _ = writeln!(
+                    z,
+                    "^FO{},{}^GB{},{},{}^FS",
+                    bx.x,
+                    bx.y,
+                    bx.width,
+                    bx.height,
+                    bx.thickness.min(bx.width.min(bx.height))
+                );
+            }
+        }
+    }
+    z.push_str("^XZ\n");
+    Ok(z)
+}
+
+fn barcode(z: &mut String, b: &BarcodeElement, data: &BarcodeData) -> Result<(), RenderError> {
+    let hr = if b.human_readable { 'Y' } else { 'N' };
+    let _ = write!(z, "^FO{},{}", b.x, b.y);
+    match (b.symbology, data) {
+        (Symbology::Code128, BarcodeData::Text(t)) => {
+            let _ = write!(
+                z,
+                "^BY{}^BCN,{},{},N,N,A^FH\\^FD{}^FS",
+                b.module_width,
+                b.height,
+                hr,
+                escape(t)
+            );
+        }
+        (Symbology::Code39, BarcodeData::Text(t)) => {
+            let _ = write!(
+                z,
+                "^BY{},3^B3N,N,{},{},N^FD{}^FS",
+                b.module_width, b.height, hr, t
+            );
+        }
+        (Symbology::Ean13, BarcodeData::Text(t)) => {
+            // The printer computes the check digit from the first twelve
+            let _ = write!(
+                z,
+                "^BY{}^BEN,{},{},N^FD{}^FS",
+                b.module_width,
+                b.height,
+                hr,
+                &t[..12]
+            );
+        }
+        (Symbology::Qr, BarcodeData::Text(t)) => {
+            let _ = write!(z, "^BQN,2,{}^FH\\^FDMA,{}^FS", b.module_width, escape(t));
+        }
+        (Symbology::Datamatrix, BarcodeData::Text(t)) => {
+            let _ = write!(z, "^BXN,{},200^FH\\^FD{}^FS", b.module_width, escape(t));
+        }
+        (Symbology::Gs1_128, BarcodeData::Gs1(es)) => {
+            if es.elements().any(|(_, v)| v.contains(['(', ')'])) {
+                return Err(RenderError::InvalidData(
+                    "GS1-128 values containing parentheses cannot be sent to a ZPL printer"
+                        .to_string(),
+                ));
+            }
+            let _ = write!(
+                z,
+                "^BY{}^BCN,{},{},N,N,D^FD{}^FS",
+                b.module_width,
+                b.height,
+                hr,
+                es.hri()
+            );
+        }
+        (Symbology::Gs1Datamatrix, BarcodeData::Gs1(es)) => {
+            if es.elements().any(|(_, v)| v.contains('_')) {
+                return Err(RenderError::InvalidData(
+                    "GS1 Data Matrix values containing '_' cannot be sent to a ZPL printer"
+                        .to_string(),
+                ));
+            }
+            let mut fd = String::new();
+            for part in es.parts() {
+                match part {
+                    Gs1Part::Fnc1 => fd.push_str("_1"),
+                    Gs1Part::Data(d) => fd.push_str(&d),
+                }
+            }
+            let _ = write!(z, "^BXN,{},200,,,,_^FD{}^FS", b.module_width, fd);
+        }
+        (sym, _) => {
+            return Err(RenderError::InvalidData(format!(
+                "mismatched data for {}",
+                sym.as_str()
+            )))
+        }
+    }
+    z.push('\n');
+    Ok(())
+}
+
+/// `^FH\` escaping of ZPL control characters.
+fn escape(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '\\' => out.push_str("\\5C"),
+            '^' => out.push_str("\\5E"),
+            '~' => out.push_str("\\7E"),
+            c => out.push(c),
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn layout(elements: Value) -> LabelLayout {
+        serde_json::from_value(json!({
+            "width_dots": 812, "height_dots": 406, "elements": elements
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn renders_text_box_and_code128() {
+        let l = layout(json!([
+            {"type": "text", "x": 20, "y": 20, "font_height": 40, "value": "{{sku}}"},
+            {"type": "box", "x": 0, "y": 0, "width": 812, "height": 406, "thickness": 4},
+            {"type": "barcode", "x": 20, "y": 100, "symbology": "code128", "value": "{{barcode_value}}"},
+        ]));
+        let z = render_zpl(&l, &json!({"sku": "A^B~C", "barcode_value": "SKU-1-R1"})).unwrap();
+        assert!(z.starts_with("^XA\n^CI28\n^PW812\n^LL406\n"));
+        assert!(z.contains("^FO20,20^A0N,40,40^FH\\^FDA\\5EB\\7EC^FS"));
+        assert!(z.contains("^FO0,0^GB812,406,4^FS"));
+        assert!(z.contains("^FO20,100^BY2^BCN,100,Y,N,N,A^FH\\^FDSKU-1-R1^FS"));
+        assert!(z.ends_with("^XZ\n"));
+    }
+
+    #[test]
+    fn renders_gs1_symbols() {
+        let l = layout(json!([
+            {"type": "barcode", "x": 10, "y": 10, "symbology": "gs1_128",
+             "gs1": [{"ai": "00", "value": "{{sscc}}"}]},
+            {"type": "barcode", "x": 10, "y": 200, "symbology": "gs1_datamatrix", "module_width": 6,
+             "gs1": [{"ai": "01", "value": "09521234543213"}, {"ai": "10", "value": "{{lot}}"},
+                     {"ai": "17", "value": "{{expiry}}"}]},
+        ]));
+        let z = render_zpl(
+            &l,
+            &json!({"sscc": "376104250021234569", "lot": "L7", "expiry": "2027-03-31"}),
+        )
+        .unwrap();
+        assert!(z.contains("^BCN,100,Y,N,N,D^FD(00)376104250021234569^FS"));
+        assert!(z.contains("^BXN,6,200,,,,_^FD_10109521234543213"));
+        assert!(z.contains("10L7_117270331^FS"));
+    }
+
+    #[test]
+    fn rejects_data_the_printer_would_misread() {
+        let l = layout(json!([
+            {"type": "barcode", "x": 10, "y": 10, "symbology": "gs1_128",
+             "gs1": [{"ai": "10", "value": "{{lot}}"}]},
+        ]));
+        assert!(render_zpl(&l, &json!({"lot": "A(1)"})).is_err());
+        assert!(render_zpl(&l, &json!({"lot": "A1"})).is_ok());
+    }
+}
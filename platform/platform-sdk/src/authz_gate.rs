@@ -11,6 +11,15 @@
 //!   configured permissions for that route.
 //! - **Exact path match**: paths are matched literally — no wildcards or templates.
 //!   Wildcard support is a follow-up concern.
+//! - **Data scope**: routes registered with [`AuthzGateConfig::scope_param`]
+//!   deny requests whose query parameter names a value outside the caller's
+//!   data scope (see `security::scope`). Rows are still filtered by the
+//!   module with `security::ScopeFilter`.
+//! - **Recent MFA**: routes registered with
+//!   [`AuthzGateConfig::require_recent_mfa`] deny user tokens whose session did
+//!   not complete MFA within the window (403 `mfa_required`). Unlike permission
+//!   rules these match the route template (`/api/runs/{id}/execute`), and
+//!   `admin:all` does not bypass them. Service tokens are exempt.
 //!
 //! ## Usage
 //!
@@ -22,7 +31,9 @@
 //!     ((Method::GET,  "/api/invoices"),       vec!["invoices:read"]),
 //!     ((Method::POST, "/api/invoices"),        vec!["invoices:write"]),
 //!     ((Method::DELETE, "/api/invoices/{id}"), vec!["invoices:delete", "invoices:admin"]),
-//! ]);
+//! ])
+//! .scope_param(Method::GET, "/api/invoices", security::SCOPE_BUSINESS_UNIT, "business_unit")
+//! .require_recent_mfa(Method::POST, "/api/invoices/{id}/pay", Duration::from_secs(600));
 //!
 //! ModuleBuilder::from_manifest("module.toml")
 //!     .authz_gate(config)
@@ -33,12 +44,13 @@
 
 use std::collections::HashMap;
 use std::sync::Arc;
+use std::time::Duration;
 
-use axum::extract::State;
+use axum::extract::{MatchedPath, State};
 use axum::http::Method;
 use axum::middleware::Next;
 use axum::response::{IntoResponse, Response};
-use security::claims::VerifiedClaims;
+use security::claims::{ActorType, VerifiedClaims};
 
 /// Configuration for the AuthzGate middleware.
 ///
@@ -48,6 +60,10 @@ use security::claims::VerifiedClaims;
 #[derive(Debug, Clone, Default)]
 pub struct AuthzGateConfig {
     rules: HashMap<(Method, String), Vec<String>>,
+    /// Scope attribute → query parameter carrying it, per route.
+    scope_params: HashMap<(Method, String), Vec<(String, String)>>,
+    /// Maximum MFA age, per route template.
+    mfa_rules: HashMap<(Method, String), Duration>,
 }
 
 impl AuthzGateConfig {
@@ -79,7 +95,59 @@ impl AuthzGateConfig {
                 )
             })
             .collect();
-        Self { rules }
+        Self {
+            rules,
+            scope_params: HashMap::new(),
+            mfa_rules: HashMap::new(),
+        }
+    }
+
+    /// Check a data-scope attribute supplied in a query parameter.
+    ///
+    /// When a request to the route carries `query_param`, the caller must hold
+    /// a required permission whose scope admits that value for `attribute`.
+    /// Requests without the parameter are not scope-checked here.
+    pub fn scope_param(
+        mut self,
+        method: Method,
+        path: impl Into<String>,
+        attribute: impl Into<String>,
+        query_param: impl Into<String>,
+    ) -> Self {
+        self.scope_params
+            .entry((method, path.into()))
+            .or_default()
+            .push((attribute.into(), query_param.into()));
+        self
+    }
+
+    /// Require the caller's session to have completed MFA within `max_age`.
+    ///
+    /// `path` is the route template as registered with the router, so
+    /// parameterised routes such as `/api/gl/periods/{period_id}/close` can be
+    /// protected. A stale or missing MFA is answered with 403 `mfa_required`;
+    /// clients step up via identity-auth and retry.
+    pub fn require_recent_mfa(
+        mut self,
+        method: Method,
+        path: impl Into<String>,
+        max_age: Duration,
+    ) -> Self {
+        self.mfa_rules.insert((method, path.into()), max_age);
+        self
+    }
+
+    /// MFA window for a route, looked up by template then by literal path.
+    pub fn mfa_window(
+        &self,
+        method: &Method,
+        template: Option<&str>,
+        path: &str,
+    ) -> Option<Duration> {
+        template
+            .and_then(|t| self.mfa_rules.get(&(method.clone(), t.to_string())))
+            .or_else(|| self.mfa_rules.get(&(method.clone(), path.to_string())))
+            .copied()
     }
 
     /// Look up the required permissions for a given method and path.
@@ -88,6 +156,30 @@ impl AuthzGateConfig {
     pub fn required_perms(&self, method: &Method, path: &str) -> Option<&Vec<String>> {
         self.rules.get(&(method.clone(), path.to_string()))
     }
+
+    /// Scope attributes named by the request's query string for this route.
+    fn request_scope(
+        &self,
+        method: &Method,
+        path: &str,
+        query: Option<&str>,
+    ) -> Vec<(String, String)> {
+        let Some(params) = self.scope_params.get(&(method.clone(), path.to_string())) else {
+            return Vec::new();
+        };
+        let pairs: Vec<(String, String)> = query
+            .and_then(|q| serde_urlencoded::from_str(q).ok())
+            .unwrap_or_default();
+        params
+            .iter()
+            .flat_map(|(attribute, param)| {
+                pairs
+                    .iter()
+                    .filter(move |(k, _)| k == param)
+                    .map(move |(_, v)| (attribute.clone(), v.clone()))
+            })
+            .collect()
+    }
 }
 
 /// Axum middleware function that enforces route-level permission checks.
@@ -104,6 +196,10 @@ impl AuthzGateConfig {
 /// | Yes             | Yes           | `admin:all`       | Pass   |
 /// | Yes             | Yes           | At least one      | Pass   |
 /// | Yes             | Yes           | None              | 403    |
+///
+/// A passing request is then denied with 403 when a configured scope
+/// parameter names a value outside the scope of every held required perm.
+/// Routes with an MFA rule are checked first, before any permission rule.
 pub async fn authz_gate_middleware(
     State(config): State<Arc<AuthzGateConfig>>,
     req: axum::extract::Request,
@@ -112,10 +208,15 @@ pub async fn authz_gate_middleware(
     let method = req.method().clone();
     let path = req.uri().path().to_string();
 
-    let required = match config.required_perms(&method, &path) {
-        None => return next.run(req).await,
-        Some(perms) => perms.clone(),
-    };
+    let template = req
+        .extensions()
+        .get::<MatchedPath>()
+        .map(|m| m.as_str().to_string());
+    let mfa_window = config.mfa_window(&method, template.as_deref(), &path);
+    let required = config.required_perms(&method, &path).cloned();
+    if required.is_none() && mfa_window.is_none() {
+        return next.run(req).await;
+    }
 
     match req.extensions().get::<VerifiedClaims>().cloned() {
         None => {
@@ -127,13 +228,33 @@ pub async fn authz_gate_middleware(
             platform_http_contracts::ApiError::forbidden("Insufficient permissions").into_response()
         }
         Some(claims) => {
+            if let Some(window) = mfa_window {
+                let max_age = chrono::Duration::from_std(window).unwrap_or(chrono::Duration::MAX);
+                if claims.actor_type == ActorType::User && !claims.has_recent_mfa(max_age) {
+                    tracing::warn!(
+                        method = %method,
+                        path = %path,
+                        user_id = %claims.user_id,
+                        "authz denied — recent MFA required",
+                    );
+                    return platform_http_contracts::ApiError::new(
+                        403,
+                        "mfa_required",
+                        "Recent multi-factor authentication required",
+                    )
+                    .into_response();
+                }
+            }
+
+            let Some(required) = required else {
+                return next.run(req).await;
+            };
+
             if claims.perms.iter().any(|p| p == "admin:all") {
                 return next.run(req).await;
             }
 
-            if required.iter().any(|r| claims.perms.contains(r)) {
-                next.run(req).await
-            } else {
+            if !required.iter().any(|r| claims.perms.contains(r)) {
                 tracing::warn!(
                     method = %method,
                     path = %path,
@@ -141,6 +262,28 @@ pub async fn authz_gate_middleware(
                     required = ?required,
                     "authz denied — insufficient permissions",
                 );
+                return platform_http_contracts::ApiError::forbidden("Insufficient permissions")
+                    .into_response();
+            }
+
+            let scope = config.request_scope(&method, &path, req.uri().query());
+            let attrs: Vec<(&str, &str)> = scope
+                .iter()
+                .map(|(a, v)| (a.as_str(), v.as_str()))
+                .collect();
+            if required
+                .iter()
+                .any(|r| security::scope_may_include(&claims, r, &attrs))
+            {
+                next.run(req).await
+            } else {
+                tracing::warn!(
+                    method = %method,
+                    path = %path,
+                    user_id = %claims.user_id,
+                    attrs = ?attrs,
+                    "authz denied — request outside data scope",
+                );
                 platform_http_contracts::ApiError::forbidden("Insufficient permissions")
                     .into_response()
             }
@@ -157,16 +300,24 @@ mod tests {
     use axum::Router;
     use chrono::Utc;
     use security::claims::{ActorType, VerifiedClaims};
+    use security::{DataScopes, SCOPE_LOCATION};
     use tower::ServiceExt;
     use uuid::Uuid;
 
     fn make_claims(perms: Vec<&str>) -> VerifiedClaims {
+        make_scoped_claims(perms, DataScopes::new())
+    }
+
+    fn make_scoped_claims(perms: Vec<&str>, scopes: DataScopes) -> VerifiedClaims {
         VerifiedClaims {
             user_id: Uuid::new_v4(),
             tenant_id: Uuid::new_v4(),
             app_id: None,
             roles: vec![],
             perms: perms.into_iter().map(|s| s.to_string()).collect(),
+            scopes,
+            amr: Vec::new(),
+            auth_time: None,
             actor_type: ActorType::User,
             issued_at: Utc::now(),
             expires_at: Utc::now() + chrono::Duration::hours(1),
@@ -182,6 +333,7 @@ mod tests {
             .route("/api/items", post(|| async { "ok" }))
             .route("/api/items", delete(|| async { "ok" }))
             .route("/public", get(|| async { "public" }))
+            .route("/api/runs/{id}/execute", post(|| async { "ok" }))
             .layer(axum::middleware::from_fn_with_state(
                 config,
                 authz_gate_middleware,
@@ -290,4 +442,83 @@ mod tests {
         let resp = app.oneshot(req).await.expect("test assertion");
         assert_eq!(resp.status(), StatusCode::OK);
     }
+
+    #[tokio::test]
+    async fn out_of_scope_query_param_returns_403() {
+        let app = make_app(config().scope_param(
+            Method::GET,
+            "/api/items",
+            SCOPE_LOCATION,
+            "warehouse_id",
+        ));
+        let grant = [(SCOPE_LOCATION.to_string(), vec!["W1".to_string()])]
+            .into_iter()
+            .collect();
+        let scopes = DataScopes::new().restrict("items:read", grant);
+
+        let in_scope = req_with_claims(
+            Method::GET,
+            "/api/items?warehouse_id=W1",
+            make_scoped_claims(vec!["items:read"], scopes.clone()),
+        );
+        let resp = app.clone().oneshot(in_scope).await.expect("test assertion");
+        assert_eq!(resp.status(), StatusCode::OK);
+
+        let unfiltered = req_with_claims(
+            Method::GET,
+            "/api/items",
+            make_scoped_claims(vec!["items:read"], scopes.clone()),
+        );
+        let resp = app
+            .clone()
+            .oneshot(unfiltered)
+            .await
+            .expect("test assertion");
+        assert_eq!(resp.status(), StatusCode::OK);
+
+        let out_of_scope = req_with_claims(
+            Method::GET,
+            "/api/items?warehouse_id=W2",
+            make_scoped_claims(vec!["items:read"], scopes),
+        );
+        let resp = app.oneshot(out_of_scope).await.expect("test assertion");
+        assert_eq!(resp.status(), StatusCode::FORBIDDEN);
+    }
+
+    #[tokio::test]
+    async fn recent_mfa_required_on_route_template() {
+        let app = make_app(config().require_recent_mfa(
+            Method::POST,
+            "/api/runs/{id}/execute",
+            Duration::from_secs(600),
+        ));
+        let uri = "/api/runs/42/execute";
+        let with_mfa = |perms: Vec<&str>, minutes_ago: i64| {
+            let mut claims = make_claims(perms);
+            claims.amr = vec!["pwd".into(), "otp".into(), "mfa".into()];
+            claims.auth_time = Some(Utc::now() - chrono::Duration::minutes(minutes_ago));
+            claims
+        };
+
+        let fresh = req_with_claims(Method::POST, uri, with_mfa(vec![], 2));
+        let resp = app.clone().oneshot(fresh).await.expect("test assertion");
+        assert_eq!(resp.status(), StatusCode::OK);
+
+        let stale = req_with_claims(Method::POST, uri, with_mfa(vec![], 30));
+        let resp = app.clone().oneshot(stale).await.expect("test assertion");
+        assert_eq!(resp.status(), StatusCode::FORBIDDEN);
+
+        // admin:all does not bypass the MFA requirement
+        let admin = req_with_claims(Method::POST, uri, make_claims(vec!["admin:all"]));
+        let resp = app.clone().oneshot(admin).await.expect("test assertion");
+        assert_eq!(resp.status(), StatusCode::FORBIDDEN);
+
+        let mut service = make_claims(vec![]);
+        service.actor_type = ActorType::Service;
+        let resp = app
+            .oneshot(req_with_claims(Method::POST, uri, service))
+            .await
+            .expect("test assertion");
+        assert_eq!(resp.status(), StatusCode::OK);
+    }
 }
@@ -851,6 +851,15 @@ impl ModuleBuilder {
             );
         }
 
+        // Reload cached feature flags as soon as an admin changes one.
+        if let Some(bus) = phase_a.bus.as_ref() {
+            let handle = Arc::clone(ctx.flag_evaluator())
+                .listen(bus, consumer_handles.shutdown_rx())
+                .await
+                .map_err(|e| StartupError::Config(format!("feature flag listener: {e}")))?;
+            consumer_handles.add_task(handle);
+        }
+
         // Clone context for phase_b before routes_fn consumes the original.
         let phase_b_ctx = ctx.clone();
 
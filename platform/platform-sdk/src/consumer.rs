@@ -103,8 +103,9 @@ impl ConsumerHandles {
 
 /// NATS subject prefix for dead-letter messages.
 ///
-/// Malformed events that cannot be deserialized are published here so
-/// operators can inspect them without losing the raw payload.
+/// Malformed events that cannot be deserialized, and events whose payload
+/// violates its contract schema, are published here so operators can
+/// inspect them without losing the raw payload.
 /// Subject pattern: `dlq.{original_subject}`.
 const DLQ_PREFIX: &str = "dlq";
 
@@ -112,7 +113,7 @@ const DLQ_PREFIX: &str = "dlq";
 ///
 /// The DLQ payload is a JSON object with:
 /// - `original_subject` — where the event arrived
-/// - `error` — the deserialization error message
+/// - `error` — the deserialization error or `schema_violation: ...` message
 /// - `failed_at` — RFC 3339 timestamp
 /// - `raw_payload` — original bytes as a JSON byte array
 ///
@@ -222,6 +223,22 @@ pub async fn wire_consumers(
                                 }
                             };
 
+                        if let Some(registry) = schema_registry::global() {
+                            if let Err(violation) = registry.check(
+                                &envelope.event_type,
+                                &envelope.schema_version,
+                                &envelope.payload,
+                            ) {
+                                tracing::error!(
+                                    subject = %subject, event_id = %envelope.event_id,
+                                    error = %violation,
+                                    "payload violates contract schema — routing to DLQ"
+                                );
+                                publish_to_dlq(&bus_clone, &subject, &msg.payload, &violation.to_string()).await;
+                                continue;
+                            }
+                        }
+
                         let event_id = envelope.event_id;
                         let tracing_ctx = TracingContext::from_envelope(&envelope);
 
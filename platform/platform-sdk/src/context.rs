@@ -67,6 +67,7 @@ pub struct ModuleContext {
     nats_client: Option<async_nats::Client>,
     pool_resolver: Option<Arc<dyn TenantPoolResolver>>,
     tenant_quota: Arc<TenantQuota>,
+    flags: Arc<feature_flags::FlagEvaluator>,
     extensions: Extensions,
 }
 
@@ -85,6 +86,7 @@ impl std::fmt::Debug for ModuleContext {
                 &self.pool_resolver.as_ref().map(|_| "<TenantPoolResolver>"),
             )
             .field("tenant_quota", &self.tenant_quota.default_max_connections())
+            .field("flags", &"<FlagEvaluator>")
             .field("extensions", &format!("{} entries", self.extensions.len()))
             .finish()
     }
@@ -97,6 +99,7 @@ impl ModuleContext {
             manifest.database.as_ref(),
         ));
         Self {
+            flags: Arc::new(feature_flags::FlagEvaluator::new(pool.clone())),
             pool,
             manifest: Arc::new(manifest),
             bus,
@@ -120,6 +123,7 @@ impl ModuleContext {
             manifest.database.as_ref(),
         ));
         Self {
+            flags: Arc::new(feature_flags::FlagEvaluator::new(pool.clone())),
             pool,
             manifest: Arc::new(manifest),
             bus,
@@ -451,13 +455,17 @@ impl ModuleContext {
         security::check_permissions(claims, &[permission])
     }
 
-    /// Returns `true` if the named feature flag is enabled for the tenant in `claims`.
+    /// Returns `true` if the named feature flag is enabled for the caller in `claims`.
     ///
     /// Resolution order:
     /// 1. Per-tenant row for `claims.tenant_id`.
     /// 2. Global row (no tenant).
     /// 3. `false` when neither row exists (absent = disabled by default).
     ///
+    /// The chosen row's schedule, targeting rules (roles, app_id,
+    /// actor_type) and percentage rollout are then applied. Rows are cached
+    /// in-process — see [`feature_flags::FlagEvaluator`].
+    ///
     /// Database errors are logged and mapped to `false` so a flag-store outage
     /// never blocks a request path.
     ///
@@ -475,19 +483,58 @@ impl ModuleContext {
         flag: &str,
         claims: &security::claims::VerifiedClaims,
     ) -> bool {
-        match feature_flags::is_enabled(self.pool(), flag, Some(claims.tenant_id)).await {
-            Ok(v) => v,
+        self.feature_evaluation(flag, claims).await.enabled
+    }
+
+    /// Evaluate a flag for the caller in `claims`, returning the variant,
+    /// its value and the reason.
+    ///
+    /// On a flag-store error the result is disabled with reason `not_found`.
+    ///
+    /// ```rust,ignore
+    /// let eval = ctx.feature_evaluation("checkout_layout", &claims).await;
+    /// match eval.variant.as_deref() {
+    ///     Some("compact") => { /* ... */ }
+    ///     _ => { /* default layout */ }
+    /// }
+    /// ```
+    pub async fn feature_evaluation(
+        &self,
+        flag: &str,
+        claims: &security::claims::VerifiedClaims,
+    ) -> feature_flags::Evaluation {
+        let eval_ctx = flag_context(claims);
+        match self.flags.evaluate(flag, &eval_ctx).await {
+            Ok(eval) => eval,
             Err(e) => {
                 tracing::warn!(
                     flag,
                     tenant_id = %claims.tenant_id,
                     error = %e,
-                    "feature_enabled: DB error — defaulting to false"
+                    "feature_enabled: flag store error — defaulting to false"
                 );
-                false
+                feature_flags::Evaluation::not_found(flag)
             }
         }
     }
+
+    /// The shared in-process flag evaluator, for callers without claims
+    /// (consumers, background jobs) that build their own
+    /// [`feature_flags::EvalContext`].
+    pub fn flag_evaluator(&self) -> &Arc<feature_flags::FlagEvaluator> {
+        &self.flags
+    }
+}
+
+/// Flag evaluation context from verified token claims.
+fn flag_context(claims: &security::claims::VerifiedClaims) -> feature_flags::EvalContext {
+    feature_flags::EvalContext {
+        tenant_id: Some(claims.tenant_id),
+        user_id: Some(claims.user_id),
+        app_id: claims.app_id,
+        roles: claims.roles.clone(),
+        actor_type: Some(claims.actor_type.as_str().to_string()),
+    }
 }
 
 /// Error returned when a module tries to access the event bus without one configured.
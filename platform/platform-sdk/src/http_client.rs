@@ -487,6 +487,9 @@ impl PlatformClient {
             app_id: None,
             roles: vec![],
             perms: vec!["service.internal".to_string()],
+            scopes: Default::default(),
+            amr: Vec::new(),
+            auth_time: None,
             actor_type: security::claims::ActorType::Service,
             issued_at: Utc::now(),
             expires_at: Utc::now() + chrono::TimeDelta::hours(1),
@@ -46,9 +46,10 @@ pub mod vitals;
 
 /// Re-export the feature flag crate for use in module handlers.
 ///
-/// Prefer `ctx.feature_enabled(flag, &claims)` for the idiomatic SDK call.
-/// Use `feature_flags::is_enabled` and `feature_flags::set_flag` directly for
-/// consumers, background tasks, or admin tooling that hold a raw pool.
+/// Prefer `ctx.feature_enabled(flag, &claims)` (or `ctx.feature_evaluation`
+/// for variants) for the idiomatic SDK call. Consumers and background tasks
+/// use `ctx.flag_evaluator()` with their own `EvalContext`; admin tooling
+/// that holds a raw pool uses `feature_flags::set_flag` directly.
 pub use feature_flags;
 
 pub use builder::ModuleBuilder;
@@ -9,6 +9,12 @@ pub struct EventsSection {
     #[serde(default)]
     pub publish: Option<EventsPublishSection>,
 
+    /// Payload schema validation mode (`off`, `warn`, `enforce`) for events
+    /// this module publishes and consumes. `EVENT_SCHEMA_VALIDATION[_<MODULE>]`
+    /// overrides it; defaults to `warn`.
+    #[serde(default)]
+    pub schema_validation: Option<schema_registry::ValidationMode>,
+
     #[serde(flatten)]
     pub extra: BTreeMap<String, toml::Value>,
 }
@@ -499,6 +499,31 @@ outbox_table = "events_outbox"
         assert_eq!(publish.outbox_table, "events_outbox");
     }
 
+    #[test]
+    fn events_schema_validation_parses() {
+        let toml_str = r#"
+[module]
+name = "with-schemas"
+
+[events]
+schema_validation = "enforce"
+"#;
+        let manifest = Manifest::from_str(toml_str, None).expect("events section should parse");
+        assert_eq!(
+            manifest.events.expect("events section").schema_validation,
+            Some(schema_registry::ValidationMode::Enforce)
+        );
+
+        let bad = r#"
+[module]
+name = "bad-schemas"
+
+[events]
+schema_validation = "strict"
+"#;
+        assert!(Manifest::from_str(bad, None).is_err());
+    }
+
     #[test]
     fn empty_outbox_table_fails() {
         let toml_str = r#"
@@ -19,7 +19,8 @@ use tracing_subscriber::EnvFilter;
 use uuid::Uuid;
 
 use crate::startup_helpers::{
-    build_cors_layer, parse_body_limit, parse_duration_str, shutdown_signal,
+    build_cors_layer, install_schema_registry, parse_body_limit, parse_duration_str,
+    shutdown_signal,
 };
 
 use crate::consumer::ConsumerHandles;
@@ -241,7 +242,7 @@ pub(crate) async fn init_blob_storage(
 /// 3. Parse DATABASE_URL from env
 /// 4. Connect DB pool
 /// 5. Log migration intent (actual run is in Phase B)
-/// 6. Create EventBus (if bus_type != "none")
+/// 6. Create EventBus (if bus_type != "none"), install the event schema registry
 /// 7. Detect undeclared outbox tables / spawn outbox publisher
 /// 8. Build JWT verifier (optional)
 /// 9. Build rate limiter
@@ -372,6 +373,10 @@ pub(crate) async fn phase_a(
         _ => None, // "none", missing section
     };
 
+    // Step 6b: event schema registry — payload validation at outbox enqueue
+    // and on consumption
+    install_schema_registry(manifest)?;
+
     // Step 7: outbox publisher / undeclared outbox detection
     let publish_section = manifest.events.as_ref().and_then(|e| e.publish.as_ref());
     let outbox_table = publish_section.map(|p| p.outbox_table.clone());
@@ -33,6 +33,47 @@ pub(crate) fn parse_duration_str(s: &str) -> std::time::Duration {
     }
 }
 
+/// Install the process-wide event schema registry.
+///
+/// The mode comes from `EVENT_SCHEMA_VALIDATION_<MODULE>` /
+/// `EVENT_SCHEMA_VALIDATION`, then `[events] schema_validation`, then `warn`.
+/// Contracts are loaded from `EVENT_CONTRACTS_DIR`; when it is unset, schema
+/// validation stays off. A set-but-unloadable directory aborts startup.
+pub(crate) fn install_schema_registry(manifest: &Manifest) -> Result<(), StartupError> {
+    let module = &manifest.module.name;
+    let mode = schema_registry::ValidationMode::from_env(module)
+        .or_else(|| manifest.events.as_ref().and_then(|e| e.schema_validation))
+        .unwrap_or_default();
+
+    if mode == schema_registry::ValidationMode::Off {
+        tracing::info!(module = %module, "event schema validation off");
+        return Ok(());
+    }
+
+    let Ok(dir) = std::env::var(schema_registry::ENV_CONTRACTS_DIR) else {
+        tracing::info!(
+            module = %module,
+            "event schema validation disabled — {} not set",
+            schema_registry::ENV_CONTRACTS_DIR
+        );
+        return Ok(());
+    };
+
+    let registry = schema_registry::SchemaRegistry::load_dir(std::path::Path::new(&dir))
+        .map_err(|e| StartupError::Config(format!("event schema registry: {e}")))?
+        .with_mode(mode);
+    let schemas = registry.len();
+    if schema_registry::install(registry).is_err() {
+        tracing::debug!(module = %module, "event schema registry already installed");
+        return Ok(());
+    }
+    tracing::info!(
+        module = %module, mode = %mode, schemas, dir = %dir,
+        "event schema registry installed"
+    );
+    Ok(())
+}
+
 /// Returns `true` if `pattern` is broad enough to match any origin (wildcard).
 ///
 /// Checks common literal wildcards first, then compiles and tests against a
@@ -225,6 +225,9 @@ mod tests {
             app_id: None,
             roles: vec!["admin".into()],
             perms: vec![],
+            scopes: Default::default(),
+            amr: Vec::new(),
+            auth_time: None,
             actor_type: ActorType::User,
             issued_at: chrono::Utc::now(),
             expires_at: chrono::Utc::now() + chrono::Duration::minutes(15),
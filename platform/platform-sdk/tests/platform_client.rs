@@ -16,6 +16,9 @@ fn test_claims() -> VerifiedClaims {
         app_id: Some(Uuid::new_v4()),
         roles: vec!["admin".into()],
         perms: vec![],
+        scopes: Default::default(),
+        amr: Vec::new(),
+        auth_time: None,
         actor_type: ActorType::User,
         issued_at: chrono::Utc::now(),
         expires_at: chrono::Utc::now() + chrono::Duration::hours(1),
@@ -0,0 +1,58 @@
+//! # Event Schema Registry
+//!
+//! Loads the event contracts in `contracts/events` (`catalog.json` plus the
+//! `*.v<N>.json` schemas) and validates event payloads against them at
+//! runtime.
+//!
+//! ## Enforcement points
+//!
+//! - **Publish**: `event_bus::outbox::validate_and_serialize_envelope` checks
+//!   the payload before the outbox insert.
+//! - **Consume**: `event_consumer` checks after envelope validation and
+//!   dead-letters violations with `FailureKind::SchemaViolation`.
+//!
+//! Both use the process-wide registry set with [`install`]. The platform SDK
+//! installs it at startup from `EVENT_CONTRACTS_DIR`; when nothing is
+//! installed, no schema checks run.
+//!
+//! ## Rollout
+//!
+//! [`ValidationMode::Warn`] logs violations without rejecting events, so a
+//! module can be observed before it is switched to `Enforce`. The mode is
+//! set per module via `[events] schema_validation` in `module.toml` or
+//! `EVENT_SCHEMA_VALIDATION[_<MODULE>]`.
+//!
+//! ```rust,no_run
+//! use schema_registry::{SchemaRegistry, ValidationMode};
+//!
+//! let registry = SchemaRegistry::load_dir("contracts/events".as_ref())
+//!     .expect("contracts load")
+//!     .with_mode(ValidationMode::Enforce);
+//! schema_registry::install(registry).expect("installed once");
+//! ```
+
+mod mode;
+mod registry;
+#[cfg(feature = "test-support")]
+pub mod test_support;
+
+use std::sync::OnceLock;
+
+pub use mode::{ValidationMode, ENV_MODE};
+pub use registry::{RegistryError, SchemaRegistry, SchemaViolation};
+
+/// Env var pointing at the contracts directory (e.g. `/app/contracts/events`).
+pub const ENV_CONTRACTS_DIR: &str = "EVENT_CONTRACTS_DIR";
+
+static GLOBAL: OnceLock<SchemaRegistry> = OnceLock::new();
+
+/// Install the process-wide registry. The first call wins; later calls
+/// return the registry they were given.
+pub fn install(registry: SchemaRegistry) -> Result<(), SchemaRegistry> {
+    GLOBAL.set(registry)
+}
+
+/// The process-wide registry, if one has been installed.
+pub fn global() -> Option<&'static SchemaRegistry> {
+    GLOBAL.get()
+}
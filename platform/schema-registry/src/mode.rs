@@ -0,0 +1,88 @@
+//! Validation mode — how a schema violation is handled.
+
+use std::fmt;
+use std::str::FromStr;
+
+/// Process-wide env var selecting the validation mode.
+pub const ENV_MODE: &str = "EVENT_SCHEMA_VALIDATION";
+
+/// How the registry reacts to a payload that fails its schema.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ValidationMode {
+    /// Schemas are not evaluated.
+    Off,
+    /// Violations are logged and the event proceeds. Used during rollout.
+    #[default]
+    Warn,
+    /// Violations reject the event (outbox) or dead-letter it (consumer).
+    Enforce,
+}
+
+impl ValidationMode {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ValidationMode::Off => "off",
+            ValidationMode::Warn => "warn",
+            ValidationMode::Enforce => "enforce",
+        }
+    }
+
+    /// Resolve the mode for `module` from the environment.
+    ///
+    /// `EVENT_SCHEMA_VALIDATION_<MODULE>` (upper-cased, `-` → `_`) wins over
+    /// `EVENT_SCHEMA_VALIDATION`. Returns `None` when neither is set or the
+    /// value is not a known mode.
+    pub fn from_env(module: &str) -> Option<Self> {
+        let module_var = format!(
+            "{ENV_MODE}_{}",
+            module.to_ascii_uppercase().replace('-', "_")
+        );
+        std::env::var(&module_var)
+            .ok()
+            .or_else(|| std::env::var(ENV_MODE).ok())
+            .and_then(|v| v.parse().ok())
+    }
+}
+
+impl FromStr for ValidationMode {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "off" => Ok(ValidationMode::Off),
+            "warn" => Ok(ValidationMode::Warn),
+            "enforce" => Ok(ValidationMode::Enforce),
+            other => Err(format!(
+                "unknown schema validation mode '{other}' (expected off, warn or enforce)"
+            )),
+        }
+    }
+}
+
+impl fmt::Display for ValidationMode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_known_modes_case_insensitively() {
+        assert_eq!("off".parse::<ValidationMode>(), Ok(ValidationMode::Off));
+        assert_eq!(" Warn ".parse::<ValidationMode>(), Ok(ValidationMode::Warn));
+        assert_eq!(
+            "ENFORCE".parse::<ValidationMode>(),
+            Ok(ValidationMode::Enforce)
+        );
+        assert!("strict".parse::<ValidationMode>().is_err());
+    }
+
+    #[test]
+    fn default_is_warn() {
+        assert_eq!(ValidationMode::default(), ValidationMode::Warn);
+    }
+}
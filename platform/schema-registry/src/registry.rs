@@ -0,0 +1,404 @@
+//! Schema loading and payload validation.
+//!
+//! Schemas are indexed by `(event_type, major version)`. The major version
+//! comes from the file name (`ar-invoice-issued.v1.json` → 1) and is matched
+//! against the major part of the envelope's `schema_version`. A file is
+//! registered under every `event_type` the catalog maps to it, plus the
+//! `properties.event_type.const` it declares itself, so schemas not yet in
+//! the generated catalog are still enforced.
+
+use std::collections::HashMap;
+use std::fmt;
+use std::fs;
+use std::path::Path;
+use std::sync::Arc;
+
+use jsonschema::JSONSchema;
+use serde::Deserialize;
+use serde_json::Value;
+
+use crate::mode::ValidationMode;
+
+/// Error loading the contract catalog or a schema file.
+#[derive(Debug, thiserror::Error)]
+pub enum RegistryError {
+    #[error("failed to read {path}: {source}")]
+    Io {
+        path: String,
+        source: std::io::Error,
+    },
+
+    #[error("failed to parse {path}: {source}")]
+    Json {
+        path: String,
+        source: serde_json::Error,
+    },
+
+    #[error("schema {file} does not compile: {message}")]
+    Compile { file: String, message: String },
+}
+
+/// A payload that does not match the schema for its event type and version.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SchemaViolation {
+    pub event_type: String,
+    pub schema_version: String,
+    /// Contract file the payload was checked against.
+    pub schema_file: String,
+    /// One entry per failing keyword, prefixed with the instance path.
+    pub errors: Vec<String>,
+}
+
+impl fmt::Display for SchemaViolation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "schema_violation: {} schema_version={} ({}): {}",
+            self.event_type,
+            self.schema_version,
+            self.schema_file,
+            self.errors.join("; ")
+        )
+    }
+}
+
+impl std::error::Error for SchemaViolation {}
+
+#[derive(Deserialize)]
+struct Catalog {
+    subjects: Vec<CatalogEntry>,
+}
+
+#[derive(Deserialize)]
+struct CatalogEntry {
+    event_type: String,
+    #[serde(default)]
+    schema_file: String,
+}
+
+struct CompiledSchema {
+    file: String,
+    validator: JSONSchema,
+}
+
+/// Compiled payload schemas keyed by `(event_type, major version)`.
+pub struct SchemaRegistry {
+    schemas: HashMap<(String, u64), Arc<CompiledSchema>>,
+    mode: ValidationMode,
+}
+
+impl fmt::Debug for SchemaRegistry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SchemaRegistry")
+            .field("schemas", &self.schemas.len())
+            .field("mode", &self.mode)
+            .finish()
+    }
+}
+
+impl SchemaRegistry {
+    /// An empty registry — every event passes.
+    pub fn empty() -> Self {
+        Self {
+            schemas: HashMap::new(),
+            mode: ValidationMode::default(),
+        }
+    }
+
+    /// Load `catalog.json` and every `*.v<N>.json` schema in `dir`
+    /// (normally `contracts/events`).
+    pub fn load_dir(dir: &Path) -> Result<Self, RegistryError> {
+        let catalog_path = dir.join("catalog.json");
+        let catalog: Catalog = read_json(&catalog_path)?;
+
+        // file name → event types the catalog publishes it under
+        let mut aliases: HashMap<String, Vec<String>> = HashMap::new();
+        for entry in catalog.subjects {
+            if entry.schema_file.is_empty() {
+                continue;
+            }
+            let file = entry
+                .schema_file
+                .rsplit('/')
+                .next()
+                .unwrap_or(&entry.schema_file)
+                .to_string();
+            aliases.entry(file).or_default().push(entry.event_type);
+        }
+
+        let mut entries: Vec<_> = fs::read_dir(dir)
+            .map_err(|source| RegistryError::Io {
+                path: dir.display().to_string(),
+                source,
+            })?
+            .filter_map(|e| e.ok().map(|e| e.path()))
+            .collect();
+        entries.sort();
+
+        let mut registry = Self::empty();
+        for path in entries {
+            let Some(file) = path.file_name().and_then(|f| f.to_str()) else {
+                continue;
+            };
+            let Some(major) = major_from_file_name(file) else {
+                continue;
+            };
+
+            let schema: Value = read_json(&path)?;
+            let mut event_types = aliases.remove(file).unwrap_or_default();
+            if let Some(declared) = schema
+                .pointer("/properties/event_type/const")
+                .and_then(Value::as_str)
+            {
+                event_types.push(declared.to_string());
+            }
+            if event_types.is_empty() {
+                tracing::debug!(
+                    file,
+                    "schema has no catalog entry or event_type const, skipping"
+                );
+                continue;
+            }
+
+            let validator = JSONSchema::compile(&payload_schema(&schema)).map_err(|e| {
+                RegistryError::Compile {
+                    file: file.to_string(),
+                    message: e.to_string(),
+                }
+            })?;
+            let compiled = Arc::new(CompiledSchema {
+                file: file.to_string(),
+                validator,
+            });
+            for event_type in event_types {
+                registry
+                    .schemas
+                    .insert((event_type, major), compiled.clone());
+            }
+        }
+
+        Ok(registry)
+    }
+
+    /// Set how violations are handled by [`check`](Self::check).
+    pub fn with_mode(mut self, mode: ValidationMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    pub fn mode(&self) -> ValidationMode {
+        self.mode
+    }
+
+    /// Number of `(event_type, version)` pairs with a schema.
+    pub fn len(&self) -> usize {
+        self.schemas.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.schemas.is_empty()
+    }
+
+    /// Whether a schema is registered for this event type and version.
+    pub fn has_schema(&self, event_type: &str, schema_version: &str) -> bool {
+        major_version(schema_version)
+            .map(|major| self.schemas.contains_key(&(event_type.to_string(), major)))
+            .unwrap_or(false)
+    }
+
+    /// Highest major version with a schema for `event_type` — the current
+    /// contract version.
+    pub fn latest_major(&self, event_type: &str) -> Option<u64> {
+        self.schemas
+            .keys()
+            .filter(|(et, _)| et == event_type)
+            .map(|(_, major)| *major)
+            .max()
+    }
+
+    /// Event types registered under a schema file (e.g. `ap-po-approved.v1.json`).
+    pub fn event_types_in(&self, schema_file: &str) -> Vec<&str> {
+        let mut out: Vec<&str> = self
+            .schemas
+            .iter()
+            .filter(|(_, schema)| schema.file == schema_file)
+            .map(|((et, _), _)| et.as_str())
+            .collect();
+        out.sort_unstable();
+        out
+    }
+
+    /// Validate `payload` regardless of mode.
+    ///
+    /// Events without a registered schema pass — the catalog does not yet
+    /// cover every event type.
+    pub fn validate_payload(
+        &self,
+        event_type: &str,
+        schema_version: &str,
+        payload: &Value,
+    ) -> Result<(), SchemaViolation> {
+        let Some(major) = major_version(schema_version) else {
+            return Ok(());
+        };
+        let Some(schema) = self.schemas.get(&(event_type.to_string(), major)) else {
+            return Ok(());
+        };
+
+        match schema.validator.validate(payload) {
+            Ok(()) => Ok(()),
+            Err(errors) => Err(SchemaViolation {
+                event_type: event_type.to_string(),
+                schema_version: schema_version.to_string(),
+                schema_file: schema.file.clone(),
+                errors: errors
+                    .map(|e| {
+                        let path = e.instance_path.to_string();
+                        if path.is_empty() {
+                            e.to_string()
+                        } else {
+                            format!("{path}: {e}")
+                        }
+                    })
+                    .collect(),
+            }),
+        }
+    }
+
+    /// Validate `payload` according to the registry's mode.
+    ///
+    /// - `Off` — always `Ok`.
+    /// - `Warn` — logs the violation and returns `Ok`.
+    /// - `Enforce` — returns the violation.
+    pub fn check(
+        &self,
+        event_type: &str,
+        schema_version: &str,
+        payload: &Value,
+    ) -> Result<(), SchemaViolation> {
+        if self.mode == ValidationMode::Off {
+            return Ok(());
+        }
+        match self.validate_payload(event_type, schema_version, payload) {
+            Ok(()) => Ok(()),
+            Err(violation) if self.mode == ValidationMode::Warn => {
+                tracing::warn!(
+                    event_type = %violation.event_type,
+                    schema_version = %violation.schema_version,
+                    schema_file = %violation.schema_file,
+                    errors = ?violation.errors,
+                    "event payload violates contract schema (warn-only)"
+                );
+                Ok(())
+            }
+            Err(violation) => Err(violation),
+        }
+    }
+}
+
+fn read_json<T: serde::de::DeserializeOwned>(path: &Path) -> Result<T, RegistryError> {
+    let contents = fs::read_to_string(path).map_err(|source| RegistryError::Io {
+        path: path.display().to_string(),
+        source,
+    })?;
+    serde_json::from_str(&contents).map_err(|source| RegistryError::Json {
+        path: path.display().to_string(),
+        source,
+    })
+}
+
+/// `ar-invoice-issued.v1.json` → `Some(1)`.
+fn major_from_file_name(file: &str) -> Option<u64> {
+    let stem = file.strip_suffix(".json")?;
+    let (_, version) = stem.rsplit_once(".v")?;
+    version.parse().ok()
+}
+
+/// `"1.2.0"` → `Some(1)`.
+fn major_version(schema_version: &str) -> Option<u64> {
+    schema_version.split('.').next()?.parse().ok()
+}
+
+/// The part of a contract schema that describes the payload.
+///
+/// Contract files describe the whole envelope with the payload under
+/// `properties.payload`; a few older files describe the payload directly.
+/// Root-level `$defs`/`definitions` are carried over so local `$ref`s
+/// still resolve.
+fn payload_schema(schema: &Value) -> Value {
+    let Some(payload) = schema.pointer("/properties/payload") else {
+        let mut whole = schema.clone();
+        if let Some(obj) = whole.as_object_mut() {
+            obj.remove("$id");
+        }
+        return whole;
+    };
+
+    let mut out = payload.clone();
+    if let Some(obj) = out.as_object_mut() {
+        for key in ["$schema", "$defs", "definitions"] {
+            if let Some(v) = schema.get(key) {
+                obj.entry(key).or_insert_with(|| v.clone());
+            }
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn major_from_file_name_parses_suffix() {
+        assert_eq!(major_from_file_name("ar-invoice-issued.v1.json"), Some(1));
+        assert_eq!(
+            major_from_file_name("gl-posting-request.v12.json"),
+            Some(12)
+        );
+        assert_eq!(major_from_file_name("catalog.json"), None);
+        assert_eq!(
+            major_from_file_name("integrations.sync.push.failed.json"),
+            None
+        );
+    }
+
+    #[test]
+    fn major_version_reads_first_component() {
+        assert_eq!(major_version("1.0.0"), Some(1));
+        assert_eq!(major_version("2.3.1"), Some(2));
+        assert_eq!(major_version("x.0.0"), None);
+    }
+
+    #[test]
+    fn payload_schema_extracts_payload_and_keeps_defs() {
+        let schema = json!({
+            "$schema": "https://json-schema.org/draft/2020-12/schema",
+            "$id": "https://example.test/x.v1.json",
+            "$defs": { "money": { "type": "integer" } },
+            "properties": {
+                "payload": {
+                    "type": "object",
+                    "properties": { "amount": { "$ref": "#/$defs/money" } }
+                }
+            }
+        });
+        let out = payload_schema(&schema);
+        assert_eq!(out["type"], "object");
+        assert_eq!(out["$defs"]["money"]["type"], "integer");
+        assert!(out.get("$id").is_none());
+    }
+
+    #[test]
+    fn payload_schema_uses_whole_schema_without_payload_property() {
+        let schema = json!({
+            "$id": "https://example.test/gl.v1.json",
+            "type": "object",
+            "required": ["entry_id"]
+        });
+        let out = payload_schema(&schema);
+        assert_eq!(out["required"][0], "entry_id");
+        assert!(out.get("$id").is_none());
+    }
+}
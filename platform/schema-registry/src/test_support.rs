@@ -0,0 +1,31 @@
+//! Fixtures for schema validation tests in crates that enforce the registry
+//! (`event-bus` at publish, `event-consumer` on consumption).
+//!
+//! Enabled by the `test-support` feature; not for production code.
+
+use std::path::PathBuf;
+
+use serde_json::Value;
+
+use crate::{SchemaRegistry, ValidationMode};
+
+/// Event type with a `contracts/events` schema used by the fixtures.
+pub const PAYMENT_EXECUTED: &str = "ap.payment_executed";
+
+/// The repository's `contracts/events` directory.
+pub fn contracts_dir() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("../../contracts/events")
+}
+
+/// Registry loaded from the real contracts, in the given mode.
+pub fn contracts_registry(mode: ValidationMode) -> SchemaRegistry {
+    SchemaRegistry::load_dir(&contracts_dir())
+        .expect("contracts load")
+        .with_mode(mode)
+}
+
+/// `ap.payment_executed` payload; a non-integer `amount_minor` violates the
+/// v1 schema.
+pub fn payment_executed_payload(amount_minor: Value) -> Value {
+    serde_json::json!({"payment_id": "p-1", "amount_minor": amount_minor})
+}
@@ -0,0 +1,118 @@
+//! Loads the real `contracts/events` directory and checks the registry
+//! against the contract examples.
+
+use std::path::PathBuf;
+
+use schema_registry::{SchemaRegistry, ValidationMode};
+use serde_json::Value;
+
+fn contracts_dir() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("../../contracts/events")
+}
+
+fn load() -> SchemaRegistry {
+    SchemaRegistry::load_dir(&contracts_dir()).expect("all contract schemas compile")
+}
+
+fn example(name: &str) -> Value {
+    let path = contracts_dir().join("examples").join(name);
+    serde_json::from_str(&std::fs::read_to_string(path).expect("example exists")).unwrap()
+}
+
+#[test]
+fn loads_catalog_and_schemas() {
+    let registry = load();
+    assert!(registry.len() >= 100, "got {} schemas", registry.len());
+    // Catalogued event type
+    assert!(registry.has_schema("ap.payment_executed", "1.0.0"));
+    // Not in the catalog, found through properties.event_type.const
+    assert!(registry.has_schema("crm_pipeline.lead_created", "1.2.0"));
+    // No v2 contract
+    assert!(!registry.has_schema("ap.payment_executed", "2.0.0"));
+    assert_eq!(registry.latest_major("ap.payment_executed"), Some(1));
+    assert_eq!(registry.latest_major("not.a.contract"), None);
+    assert!(registry
+        .event_types_in("ap-payment-executed.v1.json")
+        .contains(&"ap.payment_executed"));
+}
+
+/// Event type a schema file is registered under: its catalog entry, or the
+/// `event_type` const it declares.
+fn event_type_for(schema_file: &str) -> Option<String> {
+    let catalog: Value = serde_json::from_str(
+        &std::fs::read_to_string(contracts_dir().join("catalog.json")).unwrap(),
+    )
+    .unwrap();
+    let catalogued = catalog["subjects"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find_map(|s| {
+            s["schema_file"]
+                .as_str()
+                .filter(|f| f.ends_with(&format!("/{schema_file}")))
+                .and(s["event_type"].as_str())
+                .map(str::to_string)
+        });
+    catalogued.or_else(|| {
+        let schema: Value =
+            serde_json::from_str(&std::fs::read_to_string(contracts_dir().join(schema_file)).ok()?)
+                .ok()?;
+        schema
+            .pointer("/properties/event_type/const")
+            .and_then(Value::as_str)
+            .map(str::to_string)
+    })
+}
+
+#[test]
+fn every_example_payload_passes() {
+    let registry = load();
+    let mut checked = 0;
+    for entry in std::fs::read_dir(contracts_dir().join("examples")).unwrap() {
+        let name = entry.unwrap().file_name().into_string().unwrap();
+        let schema_file = name.replace(".example.json", ".json");
+        let Some(event_type) = event_type_for(&schema_file) else {
+            continue;
+        };
+        let envelope = example(&name);
+        registry
+            .validate_payload(&event_type, "1.0.0", &envelope["payload"])
+            .unwrap_or_else(|v| panic!("{name}: {v}"));
+        checked += 1;
+    }
+    assert!(checked > 0);
+}
+
+#[test]
+fn enforce_rejects_bad_payload_and_warn_lets_it_through() {
+    let payload = serde_json::json!({
+        "payment_id": "p-1",
+        "amount_minor": "not-a-number"
+    });
+
+    let enforce = load().with_mode(ValidationMode::Enforce);
+    let violation = enforce
+        .check("ap.payment_executed", "1.0.0", &payload)
+        .expect_err("enforce rejects");
+    assert_eq!(violation.schema_file, "ap-payment-executed.v1.json");
+    assert!(!violation.errors.is_empty());
+    assert!(violation.to_string().starts_with("schema_violation:"));
+
+    let warn = load().with_mode(ValidationMode::Warn);
+    assert!(warn.check("ap.payment_executed", "1.0.0", &payload).is_ok());
+    assert!(warn
+        .validate_payload("ap.payment_executed", "1.0.0", &payload)
+        .is_err());
+
+    let off = load().with_mode(ValidationMode::Off);
+    assert!(off.check("ap.payment_executed", "1.0.0", &payload).is_ok());
+}
+
+#[test]
+fn unknown_event_types_pass() {
+    let registry = load().with_mode(ValidationMode::Enforce);
+    assert!(registry
+        .check("not.a.contract", "1.0.0", &serde_json::json!(42))
+        .is_ok());
+}
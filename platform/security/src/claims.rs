@@ -11,6 +11,7 @@ use jsonwebtoken::{Algorithm, DecodingKey, Validation};
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
+use crate::scope::DataScopes;
 use crate::SecurityError;
 
 /// Actor types aligned with EventEnvelope `actor_type`.
@@ -42,6 +43,18 @@ impl ActorType {
     }
 }
 
+/// Authentication method references (RFC 8176) carried in the `amr` claim.
+pub mod amr {
+    /// Password.
+    pub const PWD: &str = "pwd";
+    /// One-time password (TOTP or recovery code).
+    pub const OTP: &str = "otp";
+    /// Proof of possession of a hardware-secured key (WebAuthn/passkey).
+    pub const HWK: &str = "hwk";
+    /// Multiple factors were presented.
+    pub const MFA: &str = "mfa";
+}
+
 /// Verified JWT claims returned after successful token validation.
 ///
 /// All string UUIDs from the raw JWT are parsed into typed [`Uuid`] values.
@@ -53,6 +66,12 @@ pub struct VerifiedClaims {
     pub app_id: Option<Uuid>,
     pub roles: Vec<String>,
     pub perms: Vec<String>,
+    /// Data scopes of scoped permission grants (see [`crate::scope`]).
+    pub scopes: DataScopes,
+    /// Authentication methods used by the session (see [`amr`]).
+    pub amr: Vec<String>,
+    /// When the session last authenticated interactively (login or step-up).
+    pub auth_time: Option<DateTime<Utc>>,
     pub actor_type: ActorType,
     pub issued_at: DateTime<Utc>,
     pub expires_at: DateTime<Utc>,
@@ -60,6 +79,14 @@ pub struct VerifiedClaims {
     pub version: String,
 }
 
+impl VerifiedClaims {
+    /// True when the session completed MFA no longer than `max_age` ago.
+    pub fn has_recent_mfa(&self, max_age: chrono::Duration) -> bool {
+        self.amr.iter().any(|m| m == amr::MFA)
+            && self.auth_time.is_some_and(|at| Utc::now() - at <= max_age)
+    }
+}
+
 /// Raw JWT payload (deserialization target matching identity-auth AccessClaims).
 #[derive(Debug, Deserialize)]
 struct RawAccessClaims {
@@ -75,6 +102,12 @@ struct RawAccessClaims {
     pub app_id: Option<String>,
     pub roles: Vec<String>,
     pub perms: Vec<String>,
+    #[serde(default)]
+    pub scopes: DataScopes,
+    #[serde(default)]
+    pub amr: Vec<String>,
+    #[serde(default)]
+    pub auth_time: Option<i64>,
     pub actor_type: String,
     pub ver: String,
 }
@@ -271,6 +304,10 @@ impl JwtVerifier {
         let actor_type = ActorType::from_str(&raw.actor_type).ok_or(SecurityError::InvalidToken)?;
         let issued_at = DateTime::from_timestamp(raw.iat, 0).ok_or(SecurityError::InvalidToken)?;
         let expires_at = DateTime::from_timestamp(raw.exp, 0).ok_or(SecurityError::InvalidToken)?;
+        let auth_time = raw
+            .auth_time
+            .map(|t| DateTime::from_timestamp(t, 0).ok_or(SecurityError::InvalidToken))
+            .transpose()?;
 
         Ok(VerifiedClaims {
             user_id,
@@ -278,6 +315,9 @@ impl JwtVerifier {
             app_id,
             roles: raw.roles,
             perms: raw.perms,
+            scopes: raw.scopes,
+            amr: raw.amr,
+            auth_time,
             actor_type,
             issued_at,
             expires_at,
@@ -351,6 +391,12 @@ mod tests {
         app_id: Option<String>,
         roles: Vec<String>,
         perms: Vec<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        scopes: Option<serde_json::Value>,
+        #[serde(skip_serializing_if = "Vec::is_empty")]
+        amr: Vec<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        auth_time: Option<i64>,
         actor_type: String,
         ver: String,
     }
@@ -385,6 +431,9 @@ mod tests {
             app_id: None,
             roles: vec!["admin".into()],
             perms: vec!["ar.create".into(), "gl.post".into()],
+            scopes: None,
+            amr: vec![],
+            auth_time: None,
             actor_type: "user".to_string(),
             ver: "1".to_string(),
         }
@@ -447,6 +496,59 @@ mod tests {
         assert_eq!(verified.app_id, Some(app));
     }
 
+    #[test]
+    fn verify_with_scopes() {
+        let (enc, pub_pem) = make_keys();
+        let mut claims = default_claims();
+        claims.scopes = Some(serde_json::json!({
+            "ar.create": [{"business_unit": ["EAST"]}]
+        }));
+        let token = sign_test_token(&enc, &claims);
+
+        let verifier = JwtVerifier::from_public_pem(&pub_pem).expect("verifier");
+        let verified = verifier.verify(&token).expect("verify");
+        let grants = verified.scopes.grants("ar.create").expect("scoped");
+        assert_eq!(grants[0]["business_unit"], vec!["EAST"]);
+        assert!(verified.scopes.grants("gl.post").is_none());
+
+        // Tokens without the claim (v1/v2) decode as unscoped
+        let unscoped = sign_test_token(&enc, &default_claims());
+        assert!(verifier
+            .verify(&unscoped)
+            .expect("verify")
+            .scopes
+            .is_empty());
+    }
+
+    #[test]
+    fn verify_with_amr_and_recent_mfa() {
+        let (enc, pub_pem) = make_keys();
+        let verifier = JwtVerifier::from_public_pem(&pub_pem).expect("verifier");
+        let window = chrono::Duration::minutes(10);
+
+        let mut claims = default_claims();
+        claims.amr = vec![amr::PWD.into(), amr::OTP.into(), amr::MFA.into()];
+        claims.auth_time = Some((Utc::now() - chrono::Duration::minutes(2)).timestamp());
+        let verified = verifier
+            .verify(&sign_test_token(&enc, &claims))
+            .expect("verify");
+        assert_eq!(verified.amr, vec!["pwd", "otp", "mfa"]);
+        assert!(verified.has_recent_mfa(window));
+
+        claims.auth_time = Some((Utc::now() - chrono::Duration::minutes(30)).timestamp());
+        let stale = verifier
+            .verify(&sign_test_token(&enc, &claims))
+            .expect("verify");
+        assert!(!stale.has_recent_mfa(window));
+
+        // Password-only and pre-v4 tokens never satisfy an MFA requirement
+        let pwd_only = verifier
+            .verify(&sign_test_token(&enc, &default_claims()))
+            .expect("verify");
+        assert!(pwd_only.amr.is_empty());
+        assert!(!pwd_only.has_recent_mfa(window));
+    }
+
     #[test]
     fn actor_type_roundtrip() {
         assert_eq!(ActorType::from_str("user"), Some(ActorType::User));
@@ -43,6 +43,7 @@ pub mod permissions;
 pub mod ratelimit;
 pub mod rbac;
 pub mod redaction;
+pub mod scope;
 pub mod service_auth;
 pub mod tracing;
 pub mod webhook_verify;
@@ -71,6 +72,12 @@ pub use rbac::{
     PERM_PROJECTION_VERIFY, PERM_TENANT_DEPROVISION, PERM_TENANT_SUSPEND,
 };
 
+// Re-export attribute-based data scoping
+pub use scope::{
+    check_scope, scope_access, scope_allows, scope_may_include, DataScopes, ScopeAccess,
+    ScopeFilter, ScopeGrant, ScopeSql, SCOPE_BUSINESS_UNIT, SCOPE_DEPARTMENT, SCOPE_LOCATION,
+};
+
 // Re-export JWT-backed authz middleware types
 pub use authz_middleware::{
     optional_claims_mw, ClaimsLayer, ClaimsMiddleware, RawBearerToken, RequirePermissionsLayer,
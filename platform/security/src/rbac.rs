@@ -443,6 +443,9 @@ mod tests {
             app_id: None,
             roles: vec!["admin".into()],
             perms,
+            scopes: Default::default(),
+            amr: Vec::new(),
+            auth_time: None,
             actor_type: crate::claims::ActorType::User,
             issued_at: Utc::now(),
             expires_at: Utc::now() + chrono::Duration::minutes(15),
@@ -0,0 +1,423 @@
+//! Attribute-based data scoping for RBAC grants
+//!
+//! A role grant in identity-auth may carry a scope: attribute → allowed
+//! values, e.g. `{"business_unit": ["EAST"]}`. Access tokens carry, per
+//! permission, the scope of every grant that confers it ([`DataScopes`]):
+//!
+//! - permission not in `scopes` → unrestricted (some grant is unscoped)
+//! - permission in `scopes` → the resource must match at least one grant; a
+//!   grant matches when the resource's value for **each** of its attributes is
+//!   in the allowed list. A resource missing an attribute does not match.
+//!
+//! Modules apply this on read/mutate paths with [`check_scope`] and on list
+//! paths with [`ScopeFilter`], which renders a SQL predicate:
+//!
+//! ```ignore
+//! check_scope(&claims, "ap.bills.approve", &[(SCOPE_BUSINESS_UNIT, &bill.business_unit)])?;
+//!
+//! let scope = ScopeFilter::new(&claims, "inventory.read")
+//!     .column(SCOPE_LOCATION, "i.warehouse_id")
+//!     .to_sql(2);
+//! let sql = format!("SELECT ... FROM items i WHERE i.tenant_id = $1 AND {}", scope.clause);
+//! let mut q = sqlx::query_as::<_, Item>(&sql).bind(tenant_id);
+//! for values in scope.binds { q = q.bind(values); }
+//! ```
+
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+use tracing::warn;
+
+use crate::claims::VerifiedClaims;
+use crate::SecurityError;
+
+pub const SCOPE_LOCATION: &str = "location";
+pub const SCOPE_DEPARTMENT: &str = "department";
+pub const SCOPE_BUSINESS_UNIT: &str = "business_unit";
+
+/// Permissions that bypass every scope check: the superuser perm honoured by
+/// the platform-sdk authz gate and the service-to-service perm honoured by
+/// `RequirePermissionsLayer`.
+const BYPASS_PERMS: [&str; 2] = ["admin:all", "service.internal"];
+
+/// One grant's constraint: attribute → allowed values.
+pub type ScopeGrant = BTreeMap<String, Vec<String>>;
+
+/// Per-permission grant scopes carried in access tokens.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct DataScopes(BTreeMap<String, Vec<ScopeGrant>>);
+
+impl DataScopes {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Add a scoped grant for `perm`.
+    pub fn restrict(mut self, perm: impl Into<String>, grant: ScopeGrant) -> Self {
+        self.0.entry(perm.into()).or_default().push(grant);
+        self
+    }
+
+    /// Scoped grants for `perm`; `None` when the permission is unrestricted.
+    pub fn grants(&self, perm: &str) -> Option<&[ScopeGrant]> {
+        self.0.get(perm).map(Vec::as_slice)
+    }
+}
+
+/// How far a permission reaches for the caller.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ScopeAccess<'a> {
+    /// The caller lacks the permission.
+    Denied,
+    Unrestricted,
+    /// Only resources matching one of these grants.
+    Restricted(&'a [ScopeGrant]),
+}
+
+/// Resolve the caller's reach for `perm`.
+pub fn scope_access<'a>(claims: &'a VerifiedClaims, perm: &str) -> ScopeAccess<'a> {
+    if claims
+        .perms
+        .iter()
+        .any(|p| BYPASS_PERMS.contains(&p.as_str()))
+    {
+        return ScopeAccess::Unrestricted;
+    }
+    if !claims.perms.iter().any(|p| p == perm) {
+        return ScopeAccess::Denied;
+    }
+    match claims.scopes.grants(perm) {
+        Some(grants) if !grants.is_empty() && grants.iter().all(|g| !g.is_empty()) => {
+            ScopeAccess::Restricted(grants)
+        }
+        _ => ScopeAccess::Unrestricted,
+    }
+}
+
+fn grant_matches(grant: &ScopeGrant, attrs: &[(&str, &str)]) -> bool {
+    grant.iter().all(|(attribute, allowed)| {
+        attrs
+            .iter()
+            .any(|(a, v)| a == attribute && allowed.iter().any(|x| x == v))
+    })
+}
+
+/// Whether the caller may use `perm` on a resource with `attrs`.
+pub fn scope_allows(claims: &VerifiedClaims, perm: &str, attrs: &[(&str, &str)]) -> bool {
+    match scope_access(claims, perm) {
+        ScopeAccess::Denied => false,
+        ScopeAccess::Unrestricted => true,
+        ScopeAccess::Restricted(grants) => grants.iter().any(|g| grant_matches(g, attrs)),
+    }
+}
+
+/// Whether some resource the caller may use `perm` on could carry `attrs`.
+///
+/// Unlike [`scope_allows`], attributes a grant constrains but `attrs` omits
+/// are not required — for request-level checks (e.g. a list filtered by
+/// `?location=W1`) where the full resource is not yet known.
+pub fn scope_may_include(claims: &VerifiedClaims, perm: &str, attrs: &[(&str, &str)]) -> bool {
+    match scope_access(claims, perm) {
+        ScopeAccess::Denied => false,
+        ScopeAccess::Unrestricted => true,
+        ScopeAccess::Restricted(grants) => grants.iter().any(|grant| {
+            attrs.iter().all(|(attribute, value)| {
+                grant
+                    .get(*attribute)
+                    .is_none_or(|allowed| allowed.iter().any(|x| x == value))
+            })
+        }),
+    }
+}
+
+/// Check that the caller holds `perm` for a resource with `attrs`.
+///
+/// Returns `Err(SecurityError::InsufficientPermissions)` with a warning log
+/// when the permission is missing or the resource is out of scope.
+pub fn check_scope(
+    claims: &VerifiedClaims,
+    perm: &str,
+    attrs: &[(&str, &str)],
+) -> Result<(), SecurityError> {
+    if scope_allows(claims, perm, attrs) {
+        return Ok(());
+    }
+    warn!(
+        user_id = %claims.user_id,
+        tenant_id = %claims.tenant_id,
+        perm = perm,
+        attrs = ?attrs,
+        "authorization denied — resource outside data scope"
+    );
+    Err(SecurityError::InsufficientPermissions)
+}
+
+/// Builds a SQL predicate restricting rows to the caller's scope for one
+/// permission.
+///
+/// Map each scope attribute to the column holding it with
+/// [`column`](Self::column). Grants naming an unmapped attribute match no
+/// rows, and NULL columns never match, so the filter fails closed.
+pub struct ScopeFilter<'a> {
+    access: ScopeAccess<'a>,
+    columns: Vec<(String, String)>,
+}
+
+/// Rendered predicate plus its `text[]` bind values, in parameter order.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ScopeSql {
+    pub clause: String,
+    pub binds: Vec<Vec<String>>,
+}
+
+impl<'a> ScopeFilter<'a> {
+    pub fn new(claims: &'a VerifiedClaims, perm: &str) -> Self {
+        Self {
+            access: scope_access(claims, perm),
+            columns: Vec::new(),
+        }
+    }
+
+    /// Map a scope attribute to a SQL column expression (e.g. `"b.business_unit"`).
+    pub fn column(mut self, attribute: &str, sql_column: &str) -> Self {
+        self.columns
+            .push((attribute.to_string(), sql_column.to_string()));
+        self
+    }
+
+    /// Render the predicate, numbering placeholders from `$first_param`.
+    pub fn to_sql(&self, first_param: usize) -> ScopeSql {
+        let grants = match self.access {
+            ScopeAccess::Denied => return ScopeSql::literal("FALSE"),
+            ScopeAccess::Unrestricted => return ScopeSql::unrestricted(),
+            ScopeAccess::Restricted(grants) => grants,
+        };
+
+        let mut binds = Vec::new();
+        let mut alternatives = Vec::new();
+        for grant in grants {
+            let mut terms = Vec::new();
+            for attribute in grant.keys() {
+                let Some((_, column)) = self.columns.iter().find(|(a, _)| a == attribute) else {
+                    terms.clear();
+                    break;
+                };
+                terms.push(format!(
+                    "{}::text = ANY(${})",
+                    column,
+                    first_param + binds.len() + terms.len()
+                ));
+            }
+            if terms.is_empty() {
+                continue;
+            }
+            binds.extend(grant.values().cloned());
+            alternatives.push(format!("({})", terms.join(" AND ")));
+        }
+
+        if alternatives.is_empty() {
+            return ScopeSql::literal("FALSE");
+        }
+        ScopeSql {
+            clause: format!("({})", alternatives.join(" OR ")),
+            binds,
+        }
+    }
+}
+
+impl ScopeSql {
+    /// The always-true predicate, for internal callers that apply no scope.
+    pub fn unrestricted() -> Self {
+        Self::literal("TRUE")
+    }
+
+    fn literal(clause: &str) -> Self {
+        Self {
+            clause: clause.to_string(),
+            binds: Vec::new(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::claims::ActorType;
+    use chrono::Utc;
+    use uuid::Uuid;
+
+    fn grant(pairs: &[(&str, &[&str])]) -> ScopeGrant {
+        pairs
+            .iter()
+            .map(|(a, vs)| (a.to_string(), vs.iter().map(|v| v.to_string()).collect()))
+            .collect()
+    }
+
+    fn claims(perms: &[&str], scopes: DataScopes) -> VerifiedClaims {
+        VerifiedClaims {
+            user_id: Uuid::new_v4(),
+            tenant_id: Uuid::new_v4(),
+            app_id: None,
+            roles: vec![],
+            perms: perms.iter().map(|p| p.to_string()).collect(),
+            scopes,
+            amr: Vec::new(),
+            auth_time: None,
+            actor_type: ActorType::User,
+            issued_at: Utc::now(),
+            expires_at: Utc::now() + chrono::Duration::minutes(15),
+            token_id: Uuid::new_v4(),
+            version: "3".to_string(),
+        }
+    }
+
+    #[test]
+    fn unscoped_permission_is_unrestricted() {
+        let c = claims(&["ap.bills.approve"], DataScopes::new());
+        assert!(check_scope(&c, "ap.bills.approve", &[(SCOPE_BUSINESS_UNIT, "WEST")]).is_ok());
+        assert!(check_scope(&c, "ap.bills.void", &[]).is_err());
+    }
+
+    #[test]
+    fn scoped_permission_requires_matching_grant() {
+        let scopes = DataScopes::new()
+            .restrict(
+                "ap.bills.approve",
+                grant(&[(SCOPE_BUSINESS_UNIT, &["EAST"])]),
+            )
+            .restrict(
+                "ap.bills.approve",
+                grant(&[(SCOPE_BUSINESS_UNIT, &["WEST"]), (SCOPE_LOCATION, &["W1"])]),
+            );
+        let c = claims(&["ap.bills.approve"], scopes);
+
+        assert!(scope_allows(
+            &c,
+            "ap.bills.approve",
+            &[(SCOPE_BUSINESS_UNIT, "EAST")]
+        ));
+        assert!(scope_allows(
+            &c,
+            "ap.bills.approve",
+            &[(SCOPE_BUSINESS_UNIT, "WEST"), (SCOPE_LOCATION, "W1")]
+        ));
+        assert!(!scope_allows(
+            &c,
+            "ap.bills.approve",
+            &[(SCOPE_BUSINESS_UNIT, "WEST")]
+        ));
+        assert!(!scope_allows(&c, "ap.bills.approve", &[]));
+    }
+
+    #[test]
+    fn may_include_checks_only_named_attributes() {
+        let scopes = DataScopes::new().restrict(
+            "ap.bills.read",
+            grant(&[(SCOPE_BUSINESS_UNIT, &["EAST"]), (SCOPE_LOCATION, &["W1"])]),
+        );
+        let c = claims(&["ap.bills.read"], scopes);
+
+        assert!(scope_may_include(&c, "ap.bills.read", &[]));
+        assert!(scope_may_include(
+            &c,
+            "ap.bills.read",
+            &[(SCOPE_BUSINESS_UNIT, "EAST")]
+        ));
+        assert!(scope_may_include(
+            &c,
+            "ap.bills.read",
+            &[(SCOPE_DEPARTMENT, "OPS")]
+        ));
+        assert!(!scope_may_include(
+            &c,
+            "ap.bills.read",
+            &[(SCOPE_BUSINESS_UNIT, "WEST")]
+        ));
+        assert!(!scope_may_include(&c, "ap.bills.void", &[]));
+    }
+
+    #[test]
+    fn admin_all_and_service_bypass_scope() {
+        let scopes =
+            DataScopes::new().restrict("inventory.read", grant(&[(SCOPE_LOCATION, &["W1"])]));
+        for perm in ["admin:all", "service.internal"] {
+            let c = claims(&[perm], scopes.clone());
+            assert!(scope_allows(
+                &c,
+                "inventory.read",
+                &[(SCOPE_LOCATION, "W9")]
+            ));
+        }
+    }
+
+    #[test]
+    fn filter_renders_or_of_ands_with_numbered_binds() {
+        let scopes = DataScopes::new()
+            .restrict("inventory.read", grant(&[(SCOPE_LOCATION, &["W1", "W2"])]))
+            .restrict(
+                "inventory.read",
+                grant(&[(SCOPE_BUSINESS_UNIT, &["EAST"]), (SCOPE_LOCATION, &["W3"])]),
+            );
+        let c = claims(&["inventory.read"], scopes);
+        let sql = ScopeFilter::new(&c, "inventory.read")
+            .column(SCOPE_LOCATION, "i.warehouse_id")
+            .column(SCOPE_BUSINESS_UNIT, "i.business_unit")
+            .to_sql(3);
+
+        assert_eq!(
+            sql.clause,
+            "((i.warehouse_id::text = ANY($3)) OR \
+             (i.business_unit::text = ANY($4) AND i.warehouse_id::text = ANY($5)))"
+        );
+        assert_eq!(
+            sql.binds,
+            vec![
+                vec!["W1".to_string(), "W2".to_string()],
+                vec!["EAST".to_string()],
+                vec!["W3".to_string()],
+            ]
+        );
+    }
+
+    #[test]
+    fn filter_fails_closed() {
+        let scopes =
+            DataScopes::new().restrict("timekeeping.read", grant(&[(SCOPE_DEPARTMENT, &["OPS"])]));
+        let c = claims(&["timekeeping.read"], scopes);
+
+        // No column mapped for the grant's attribute
+        let unmapped = ScopeFilter::new(&c, "timekeeping.read")
+            .column(SCOPE_LOCATION, "e.site")
+            .to_sql(1);
+        assert_eq!(unmapped.clause, "FALSE");
+        assert!(unmapped.binds.is_empty());
+
+        // Permission not held at all
+        assert_eq!(
+            ScopeFilter::new(&c, "ap.bills.read").to_sql(1).clause,
+            "FALSE"
+        );
+
+        let unscoped = claims(&["timekeeping.read"], DataScopes::new());
+        assert_eq!(
+            ScopeFilter::new(&unscoped, "timekeeping.read")
+                .to_sql(1)
+                .clause,
+            "TRUE"
+        );
+    }
+
+    #[test]
+    fn data_scopes_serialize_as_plain_map() {
+        let scopes = DataScopes::new().restrict("p", grant(&[(SCOPE_DEPARTMENT, &["OPS"])]));
+        let json = serde_json::to_value(&scopes).unwrap();
+        assert_eq!(json, serde_json::json!({"p": [{"department": ["OPS"]}]}));
+        let back: DataScopes = serde_json::from_value(json).unwrap();
+        assert_eq!(back, scopes);
+    }
+}
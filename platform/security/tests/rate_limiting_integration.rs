@@ -236,6 +236,9 @@ mod rate_limiting_integration {
                 app_id: None,
                 roles: vec![],
                 perms: vec![],
+                scopes: Default::default(),
+                amr: Vec::new(),
+                auth_time: None,
                 actor_type: ActorType::User,
                 issued_at: chrono::Utc::now(),
                 expires_at: chrono::Utc::now(),
@@ -0,0 +1,49 @@
+-- Feature flags: multivariate values, percentage rollouts, claim targeting,
+-- schedule windows, and an evaluation audit.
+--
+-- Existing boolean rows keep working: empty variants/rules and NULL
+-- rollout/schedule columns evaluate exactly like the old `enabled` lookup.
+--
+-- variants: [{"key": "blue", "value": <json>, "weight": 50}, ...]
+--   Weighted pick, bucketed by tenant or user. Empty → value is `true`.
+-- rules: [{"roles": [...], "app_ids": [...], "actor_types": [...],
+--          "variant": "blue", "enabled": true}, ...]
+--   First matching rule wins and bypasses the rollout percentage.
+-- rollout_percent: 0-100; NULL means everyone who reaches the rollout step.
+-- bucket_by: 'tenant' or 'user' — the ID hashed for rollout and variants.
+-- active_from / active_until: the flag is off outside [from, until).
+
+ALTER TABLE feature_flags
+    ADD COLUMN IF NOT EXISTS variants        JSONB       NOT NULL DEFAULT '[]'::jsonb,
+    ADD COLUMN IF NOT EXISTS rules           JSONB       NOT NULL DEFAULT '[]'::jsonb,
+    ADD COLUMN IF NOT EXISTS rollout_percent SMALLINT,
+    ADD COLUMN IF NOT EXISTS bucket_by       TEXT        NOT NULL DEFAULT 'tenant',
+    ADD COLUMN IF NOT EXISTS active_from     TIMESTAMPTZ,
+    ADD COLUMN IF NOT EXISTS active_until    TIMESTAMPTZ;
+
+ALTER TABLE feature_flags
+    ADD CONSTRAINT feature_flags_rollout_percent_chk
+        CHECK (rollout_percent IS NULL OR rollout_percent BETWEEN 0 AND 100),
+    ADD CONSTRAINT feature_flags_bucket_by_chk
+        CHECK (bucket_by IN ('tenant', 'user'));
+
+-- One row per distinct evaluation outcome per (flag, tenant, user) seen by a
+-- process, so support can see which variant a tenant got and why.
+CREATE TABLE IF NOT EXISTS feature_flag_evaluations (
+    id              BIGSERIAL   PRIMARY KEY,
+    flag_name       TEXT        NOT NULL,
+    tenant_id       UUID,
+    user_id         UUID,
+    enabled         BOOLEAN     NOT NULL,
+    variant         TEXT,
+    reason          JSONB       NOT NULL,
+    source          TEXT        NOT NULL,
+    flag_updated_at TIMESTAMPTZ,
+    evaluated_at    TIMESTAMPTZ NOT NULL DEFAULT now()
+);
+
+CREATE INDEX IF NOT EXISTS feature_flag_evaluations_lookup_idx
+    ON feature_flag_evaluations (flag_name, tenant_id, evaluated_at DESC);
+
+COMMENT ON TABLE feature_flag_evaluations IS
+    'Feature flag evaluation audit: one row per changed outcome per flag/tenant/user.';
@@ -5,11 +5,14 @@
 //! - Close hash from accounting_periods
 //! - Reopen history (if any)
 //! - References to compliance-export manifest with checksums
+//! - Audit hash chain verification and the signed checkpoint covering the
+//!   period close record
 //!
 //! The pack is a self-contained JSON file suitable for audit review.
 
 use anyhow::{Context, Result};
 use chrono::{DateTime, Utc};
+use platform_audit::chain::{self, ChainIssue, Checkpoint, CheckpointKey};
 use serde::{Deserialize, Serialize};
 use sha2::{Digest, Sha256};
 use sqlx::{PgPool, Row};
@@ -29,6 +32,7 @@ pub struct EvidencePack {
     pub snapshot: Option<SnapshotSummary>,
     pub reopen_history: Vec<ReopenEntry>,
     pub export_manifest_ref: Option<ManifestReference>,
+    pub audit_chain: Option<AuditChainProof>,
     pub pack_hash: String,
 }
 
@@ -80,12 +84,126 @@ pub struct ManifestReference {
     pub manifest_checksum: String,
 }
 
+/// Audit hash chain evidence for the tenant at pack generation time
+#[derive(Debug, Serialize, Deserialize)]
+pub struct AuditChainProof {
+    pub chain_intact: bool,
+    pub records: usize,
+    pub head_seq: Option<i64>,
+    pub head_hash: Option<String>,
+    pub signatures_checked: bool,
+    /// Why checkpoint signatures were not checked, e.g. "unverified: key invalid"
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub signature_status: Option<String>,
+    pub issues: Vec<ChainIssue>,
+    /// The chained `ClosePeriod` audit record for this period
+    pub close_record: Option<ChainedRecordRef>,
+    /// First signed checkpoint at or after the close record
+    pub checkpoint: Option<Checkpoint>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ChainedRecordRef {
+    pub audit_id: Uuid,
+    pub chain_seq: i64,
+    pub record_hash: String,
+    pub occurred_at: DateTime<Utc>,
+}
+
+impl AuditChainProof {
+    /// Whether the period close is covered by a checkpoint on an intact chain
+    pub fn close_is_checkpointed(&self) -> bool {
+        self.chain_intact
+            && match (&self.close_record, &self.checkpoint) {
+                (Some(close), Some(checkpoint)) => checkpoint.chain_seq >= close.chain_seq,
+                _ => false,
+            }
+    }
+}
+
+/// Verify the tenant's audit chain in `pool` and locate the signed checkpoint
+/// covering the period's `ClosePeriod` record.
+///
+/// Checkpoint signatures are checked when `AUDIT_CHECKPOINT_KEY` is set. An
+/// unset or invalid key does not fail the pack; the proof records the
+/// signatures as unverified instead.
+pub async fn build_audit_chain_proof(
+    pool: &PgPool,
+    tenant_id: &str,
+    period_id: Uuid,
+) -> Result<AuditChainProof> {
+    let (key, signature_status) = checkpoint_key(CheckpointKey::from_env());
+    let report = chain::verify_chain(pool, tenant_id, key.as_ref())
+        .await
+        .context("Failed to verify audit chain")?;
+
+    let close_row = sqlx::query(
+        r#"
+        SELECT audit_id, chain_seq, record_hash, occurred_at
+        FROM audit_events
+        WHERE tenant_id = $1 AND chain_seq IS NOT NULL
+          AND action = 'ClosePeriod'
+          AND entity_type = 'AccountingPeriod' AND entity_id = $2
+        ORDER BY chain_seq DESC
+        LIMIT 1
+        "#,
+    )
+    .bind(tenant_id)
+    .bind(period_id.to_string())
+    .fetch_optional(pool)
+    .await
+    .context("Failed to query period close audit record")?;
+
+    let close_record = close_row.map(|r| ChainedRecordRef {
+        audit_id: r.get("audit_id"),
+        chain_seq: r.get("chain_seq"),
+        record_hash: r.get("record_hash"),
+        occurred_at: r.get("occurred_at"),
+    });
+
+    let checkpoint = match &close_record {
+        Some(close) => chain::list_checkpoints(pool, tenant_id)
+            .await
+            .context("Failed to query audit checkpoints")?
+            .into_iter()
+            .find(|c| c.chain_seq >= close.chain_seq),
+        None => None,
+    };
+
+    Ok(AuditChainProof {
+        chain_intact: report.is_intact(),
+        records: report.records,
+        head_seq: report.head_seq,
+        head_hash: report.head_hash,
+        signatures_checked: report.signatures_checked,
+        signature_status,
+        issues: report.issues,
+        close_record,
+        checkpoint,
+    })
+}
+
+/// The key to check checkpoint signatures with, or why they go unverified.
+fn checkpoint_key(
+    from_env: chain::Result<Option<CheckpointKey>>,
+) -> (Option<CheckpointKey>, Option<String>) {
+    match from_env {
+        Ok(Some(key)) => (Some(key), None),
+        Ok(None) => (None, Some("unverified: key unset".to_string())),
+        Err(e) => {
+            tracing::warn!(error = %e, "Invalid AUDIT_CHECKPOINT_KEY; checkpoint signatures not checked");
+            (None, Some("unverified: key invalid".to_string()))
+        }
+    }
+}
+
 /// Generate an evidence pack for a closed period.
 ///
 /// Queries GL database for:
 /// - Period close state (accounting_periods)
 /// - Sealed snapshot (period_summary_snapshots)
 /// - Reopen history (period_reopen_requests)
+/// - Audit hash chain (audit_events, audit_checkpoints)
 ///
 /// Optionally references a compliance-export manifest file on disk.
 pub async fn generate_evidence_pack(
@@ -221,10 +339,13 @@ pub async fn generate_evidence_pack(
         None
     };
 
-    // 5. Compute pack hash over all content
+    // 5. Verify the audit chain and attach the checkpoint proof
+    let audit_chain = Some(build_audit_chain_proof(gl_pool, tenant_id, period_id).await?);
+
+    // 6. Compute pack hash over all content
     let now = Utc::now();
     let mut pack = EvidencePack {
-        pack_version: "1.0",
+        pack_version: "1.1",
         generated_at: now,
         tenant_id: tenant_id.to_string(),
         period_id,
@@ -234,6 +355,7 @@ pub async fn generate_evidence_pack(
         snapshot,
         reopen_history,
         export_manifest_ref,
+        audit_chain,
         pack_hash: String::new(), // placeholder
     };
 
@@ -357,6 +479,7 @@ mod tests {
             }),
             reopen_history: vec![],
             export_manifest_ref: None,
+            audit_chain: None,
             pack_hash: "placeholder".to_string(),
         };
 
@@ -394,6 +517,7 @@ mod tests {
             snapshot: None,
             reopen_history: vec![],
             export_manifest_ref: None,
+            audit_chain: None,
             pack_hash: "test-hash".to_string(),
         };
 
@@ -445,6 +569,53 @@ mod tests {
         assert_eq!(entry.status, parsed.status);
     }
 
+    #[test]
+    fn test_close_is_checkpointed_requires_covering_checkpoint() {
+        let key = CheckpointKey::new("k1", b"0123456789abcdef0123456789abcdef".to_vec());
+        let mut proof = AuditChainProof {
+            chain_intact: true,
+            records: 10,
+            head_seq: Some(10),
+            head_hash: Some("h10".to_string()),
+            signatures_checked: true,
+            signature_status: None,
+            issues: vec![],
+            close_record: Some(ChainedRecordRef {
+                audit_id: Uuid::new_v4(),
+                chain_seq: 7,
+                record_hash: "h7".to_string(),
+                occurred_at: Utc::now(),
+            }),
+            checkpoint: Some(Checkpoint::sign(&key, "t1", 8, "h8", Utc::now())),
+        };
+        assert!(proof.close_is_checkpointed());
+
+        proof.checkpoint = None;
+        assert!(!proof.close_is_checkpointed());
+
+        proof.checkpoint = Some(Checkpoint::sign(&key, "t1", 9, "h9", Utc::now()));
+        proof.chain_intact = false;
+        assert!(!proof.close_is_checkpointed());
+    }
+
+    #[test]
+    fn test_invalid_checkpoint_key_leaves_signatures_unverified() {
+        let (key, status) = checkpoint_key(Err(chain::ChainError::Key(
+            "AUDIT_CHECKPOINT_KEY must be at least 32 bytes".to_string(),
+        )));
+        assert!(key.is_none());
+        assert_eq!(status.as_deref(), Some("unverified: key invalid"));
+
+        let (key, status) = checkpoint_key(Ok(None));
+        assert!(key.is_none());
+        assert_eq!(status.as_deref(), Some("unverified: key unset"));
+
+        let valid = CheckpointKey::new("k1", b"0123456789abcdef0123456789abcdef".to_vec());
+        let (key, status) = checkpoint_key(Ok(Some(valid)));
+        assert!(key.is_some());
+        assert!(status.is_none());
+    }
+
     #[test]
     fn test_manifest_reference_serialization_roundtrip() {
         let reference = ManifestReference {
@@ -6,18 +6,23 @@
 //! **Commands:**
 //! - export: Export audit and ledger data for a tenant
 //! - evidence-pack: Generate evidence pack for a closed period
+//! - verify-chain: Walk a tenant's audit hash chain and report gaps or edits
+//! - checkpoint: Sign the current audit chain heads (run periodically)
 //!
 //! **Usage:**
 //! ```bash
 //! cargo run -p compliance-export -- --help
 //! cargo run -p compliance-export -- export --tenant t1 --output ./export/
 //! cargo run -p compliance-export -- evidence-pack --tenant t1 --period-id <uuid> --output ./pack/
+//! cargo run -p compliance-export -- verify-chain --tenant t1 --database-env GL_DATABASE_URL
+//! cargo run -p compliance-export -- checkpoint --database-env GL_DATABASE_URL
 //! ```
 
 use anyhow::Result;
 use chrono::NaiveDate;
 use clap::{Parser, Subcommand};
 use compliance_export::export_compliance_data;
+use platform_audit::chain;
 use tracing_subscriber::{fmt, prelude::*, EnvFilter};
 
 // ============================================================================
@@ -77,6 +82,39 @@ enum Commands {
         #[arg(long)]
         manifest: Option<String>,
     },
+
+    /// Verify a tenant's audit hash chain and checkpoints
+    VerifyChain {
+        /// Tenant ID
+        #[arg(long)]
+        tenant: String,
+
+        /// Environment variable holding the database URL to verify
+        #[arg(long, default_value = "PLATFORM_AUDIT_DATABASE_URL")]
+        database_env: String,
+
+        /// Write the full JSON report to this file
+        #[arg(long)]
+        output: Option<String>,
+    },
+
+    /// Sign checkpoints of audit chain heads that moved since the last run
+    Checkpoint {
+        /// Only checkpoint this tenant (default: every tenant)
+        #[arg(long)]
+        tenant: Option<String>,
+
+        /// Environment variable holding the database URL to checkpoint
+        #[arg(long, default_value = "PLATFORM_AUDIT_DATABASE_URL")]
+        database_env: String,
+    },
+}
+
+async fn connect_env(var: &str) -> Result<sqlx::PgPool> {
+    let url = std::env::var(var).map_err(|_| anyhow::anyhow!("{} not set", var))?;
+    sqlx::PgPool::connect(&url)
+        .await
+        .map_err(|e| anyhow::anyhow!("Failed to connect to {}: {}", var, e))
 }
 
 // ============================================================================
@@ -176,11 +214,110 @@ async fn main() -> Result<()> {
             }
             println!("  Reopen count: {}", pack.close_state.reopen_count);
             println!("  Reopen history: {} entries", pack.reopen_history.len());
+            if let Some(ref proof) = pack.audit_chain {
+                println!(
+                    "  Audit chain: {} ({} records, {} issues)",
+                    if proof.chain_intact {
+                        "intact"
+                    } else {
+                        "BROKEN"
+                    },
+                    proof.records,
+                    proof.issues.len()
+                );
+                println!(
+                    "  Close checkpointed: {}{}",
+                    proof.close_is_checkpointed(),
+                    match &proof.signature_status {
+                        Some(status) => format!(" (signatures {})", status),
+                        None => String::new(),
+                    }
+                );
+            }
             println!("  Pack hash: {}...", &pack.pack_hash[..16]);
             println!("  Output: {}", output_path.display());
 
             Ok(())
         }
+        Commands::VerifyChain {
+            tenant,
+            database_env,
+            output,
+        } => {
+            tracing::info!(tenant = %tenant, database = %database_env, "Verify chain command invoked");
+
+            let pool = connect_env(&database_env).await?;
+            let key = chain::CheckpointKey::from_env()?;
+            if key.is_none() {
+                tracing::warn!(
+                    "AUDIT_CHECKPOINT_KEY not set; checkpoint signatures will not be checked"
+                );
+            }
+            let report = chain::verify_chain(&pool, &tenant, key.as_ref()).await?;
+            pool.close().await;
+
+            if let Some(output) = output {
+                let file = std::fs::File::create(&output)?;
+                serde_json::to_writer_pretty(std::io::BufWriter::new(file), &report)?;
+            }
+
+            println!("Audit chain verification");
+            println!("  Tenant: {}", tenant);
+            println!("  Records: {}", report.records);
+            println!(
+                "  Head: seq {} {}",
+                report.head_seq.unwrap_or(0),
+                report.head_hash.as_deref().unwrap_or("-")
+            );
+            println!(
+                "  Checkpoints verified: {}{}",
+                report.checkpoints_verified,
+                if report.signatures_checked {
+                    ""
+                } else {
+                    " (unsigned check)"
+                }
+            );
+            for issue in &report.issues {
+                println!("  ISSUE: {}", serde_json::to_string(issue)?);
+            }
+            if report.issues_truncated > 0 {
+                println!("  ... and {} more issues", report.issues_truncated);
+            }
+
+            if !report.is_intact() {
+                anyhow::bail!("audit chain for tenant {} is not intact", tenant);
+            }
+            println!("  Result: intact");
+            Ok(())
+        }
+        Commands::Checkpoint {
+            tenant,
+            database_env,
+        } => {
+            let key = chain::CheckpointKey::from_env()?
+                .ok_or_else(|| anyhow::anyhow!("AUDIT_CHECKPOINT_KEY not set"))?;
+            let pool = connect_env(&database_env).await?;
+            let written = match tenant {
+                Some(tenant) => chain::create_checkpoint(&pool, &tenant, &key)
+                    .await?
+                    .into_iter()
+                    .collect(),
+                None => chain::checkpoint_all(&pool, &key).await?,
+            };
+            pool.close().await;
+
+            println!("Checkpoints written: {}", written.len());
+            for checkpoint in &written {
+                println!(
+                    "  {:?} seq {} {}...",
+                    checkpoint.tenant_id,
+                    checkpoint.chain_seq,
+                    &checkpoint.head_hash[..16]
+                );
+            }
+            Ok(())
+        }
     }
 }
 
@@ -0,0 +1,151 @@
+//! event-replay - Targeted Replay
+//!
+//! **Purpose:** Re-deliver a filtered slice of a JetStream stream into one
+//! module's consumer handlers with `event_consumer::replay::Replayer`. By
+//! default only reports which events would be covered, counted by event type;
+//! `--execute` runs them through the module's handler registry.
+//!
+//! **Usage:**
+//! ```bash
+//! # Dry run
+//! cargo run -p event-replay -- --stream AR_EVENTS --subject 'ar.events.>' \
+//!   --tenant tenant-x --from 2026-03-01T00:00:00Z --to 2026-03-08T00:00:00Z
+//!
+//! # Replay into GL, re-running credit notes GL already saw
+//! DATABASE_URL=postgres://... cargo run -p event-replay -- \
+//!   --stream AR_EVENTS --subject 'ar.events.>' --tenant tenant-x \
+//!   --module gl --execute --bypass-dedupe ar.credit_note_issued
+//! ```
+
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use clap::{Parser, ValueEnum};
+use event_consumer::replay::{ReplayFilter, Replayer};
+use event_consumer::EventRouter;
+use sqlx::postgres::PgPoolOptions;
+use sqlx::PgPool;
+use tracing_subscriber::{fmt, prelude::*, EnvFilter};
+
+/// event-replay - replay a slice of a stream into one module's handlers
+#[derive(Parser, Debug)]
+#[command(name = "event-replay")]
+#[command(about = "Targeted JetStream replay into one module's consumer", long_about = None)]
+#[command(version)]
+struct Cli {
+    /// NATS server URL
+    #[arg(long, env = "NATS_URL", default_value = "nats://localhost:4222")]
+    nats_url: String,
+
+    /// JetStream stream name (e.g. AR_EVENTS)
+    #[arg(long)]
+    stream: String,
+
+    /// NATS subject filter (e.g. 'ar.events.>')
+    #[arg(long)]
+    subject: String,
+
+    /// Only events for this tenant
+    #[arg(long)]
+    tenant: Option<String>,
+
+    /// Inclusive lower bound on occurred_at (RFC 3339)
+    #[arg(long)]
+    from: Option<DateTime<Utc>>,
+
+    /// Exclusive upper bound on occurred_at (RFC 3339)
+    #[arg(long)]
+    to: Option<DateTime<Utc>>,
+
+    /// Event type pattern, repeatable ('*' = one token, '>' = rest)
+    #[arg(long = "event-type")]
+    event_types: Vec<String>,
+
+    /// Module whose handler registry receives the replay
+    #[arg(long, value_enum)]
+    module: Option<Module>,
+
+    /// Dispatch matching events to the module's handlers (default: dry run)
+    #[arg(long, requires = "module")]
+    execute: bool,
+
+    /// Re-run handlers for this event type even if dedupe has seen the
+    /// event, repeatable
+    #[arg(long = "bypass-dedupe", requires = "execute")]
+    bypass_dedupe: Vec<String>,
+
+    /// The module's database (holds its `event_dedupe` table)
+    #[arg(long, env = "DATABASE_URL")]
+    database_url: Option<String>,
+
+    /// Print the report as JSON
+    #[arg(long)]
+    json: bool,
+}
+
+/// Modules that expose a replay handler registry.
+#[derive(Debug, Clone, Copy, ValueEnum)]
+enum Module {
+    Gl,
+}
+
+impl Module {
+    fn router(self, pool: PgPool) -> EventRouter {
+        match self {
+            Module::Gl => EventRouter::new(gl_rs::consumers::replay::handler_registry(pool)),
+        }
+    }
+}
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    tracing_subscriber::registry()
+        .with(fmt::layer().with_writer(std::io::stderr))
+        .with(EnvFilter::from_default_env().add_directive(tracing::Level::INFO.into()))
+        .init();
+
+    let cli = Cli::parse();
+
+    let mut filter = ReplayFilter::new(&cli.subject).between(cli.from, cli.to);
+    if let Some(tenant) = &cli.tenant {
+        filter = filter.tenant(tenant);
+    }
+    for pattern in &cli.event_types {
+        filter = filter.event_type(pattern);
+    }
+
+    let client = async_nats::connect(&cli.nats_url)
+        .await
+        .with_context(|| format!("connect to {}", cli.nats_url))?;
+    let js = async_nats::jetstream::new(client);
+
+    let mut replayer = Replayer::new(filter).dry_run(!cli.execute);
+    if let (true, Some(module)) = (cli.execute, cli.module) {
+        let database_url = cli
+            .database_url
+            .as_deref()
+            .context("--execute needs --database-url or DATABASE_URL")?;
+        let pool = PgPoolOptions::new()
+            .max_connections(5)
+            .connect(database_url)
+            .await
+            .context("connect to module database")?;
+        replayer = replayer.target(module.router(pool.clone()), pool);
+        for event_type in &cli.bypass_dedupe {
+            replayer = replayer.bypass_dedupe_for(event_type);
+        }
+    }
+
+    let report = replayer.run(&js, &cli.stream).await?;
+
+    if cli.json {
+        println!("{}", serde_json::to_string_pretty(&report)?);
+    } else {
+        print!("{}", report.summary());
+    }
+
+    let failed = report.totals().failed;
+    if failed > 0 {
+        anyhow::bail!("{} event(s) failed during replay", failed);
+    }
+    Ok(())
+}
@@ -271,7 +271,8 @@ async fn write_lifecycle_audit_entry(
     .with_metadata(serde_json::json!({
         "source": "tenantctl",
         "operation": action,
-    }));
+    }))
+    .with_tenant(tenant_id.to_string());
 
     writer.write(request).await?;
 